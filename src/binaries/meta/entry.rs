@@ -72,7 +72,7 @@ pub async fn entry(conf: Config) -> anyhow::Result<()> {
 
     set_panic_hook();
 
-    let _guards = init_logging("databend-meta", &conf.log);
+    let _guards = init_logging("databend-meta", &conf.log)?;
 
     info!("Databend Meta version: {}", METASRV_COMMIT_VERSION.as_str());
     info!(
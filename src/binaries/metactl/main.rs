@@ -30,6 +30,7 @@ use common_tracing::init_logging;
 use common_tracing::Config as LogConfig;
 use common_tracing::FileConfig;
 use common_tracing::StderrConfig;
+use common_tracing::TracingConfig;
 use databend_meta::version::METASRV_COMMIT_VERSION;
 use serde::Deserialize;
 use serde::Serialize;
@@ -203,9 +204,10 @@ async fn main() -> anyhow::Result<()> {
             format: "text".to_string(),
         },
         stderr: StderrConfig::default(),
+        tracing: TracingConfig::default(),
     };
 
-    let _guards = init_logging("metactl", &log_config);
+    let _guards = init_logging("metactl", &log_config)?;
 
     eprintln!();
     eprintln!("╔╦╗╔═╗╔╦╗╔═╗   ╔═╗╔╦╗╦  ");
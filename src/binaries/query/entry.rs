@@ -34,6 +34,7 @@ use databend_query::servers::FlightSQLServer;
 use databend_query::servers::HttpHandler;
 use databend_query::servers::HttpHandlerKind;
 use databend_query::servers::MySQLHandler;
+use databend_query::servers::PGWireHandler;
 use databend_query::servers::Server;
 use databend_query::servers::ShutdownHandle;
 use databend_query::GlobalServices;
@@ -153,6 +154,23 @@ pub async fn start_services(conf: &InnerConfig) -> Result<()> {
         );
     }
 
+    // PostgreSQL handler, only started if the port is configured.
+    if conf.query.postgres_handler_port != 0 {
+        let hostname = conf.query.postgres_handler_host.clone();
+        let listening = format!("{}:{}", hostname, conf.query.postgres_handler_port);
+        let tcp_keepalive_timeout_secs = conf.query.postgres_handler_tcp_keepalive_timeout_secs;
+        let mut handler = PGWireHandler::create(tcp_keepalive_timeout_secs)?;
+        let listening = handler.start(listening.parse()?).await?;
+        shutdown_handle.add_service(handler);
+
+        info!(
+            "Listening for PostgreSQL compatibility protocol: {}, Usage: psql -h{} -p{} -U root -d default",
+            listening,
+            listening.ip(),
+            listening.port(),
+        );
+    }
+
     // ClickHouse HTTP handler.
     {
         let hostname = conf.query.clickhouse_http_handler_host.clone();
@@ -243,6 +261,7 @@ pub async fn start_services(conf: &InnerConfig) -> Result<()> {
     println!("Logging:");
     println!("    file: {}", conf.log.file);
     println!("    stderr: {}", conf.log.stderr);
+    println!("    tracing: {}", conf.log.tracing);
     println!(
         "Meta: {}",
         if conf.meta.is_embedded_meta()? {
@@ -298,6 +317,17 @@ pub async fn start_services(conf: &InnerConfig) -> Result<()> {
         "    connect via: mysql -uroot -h{} -P{}",
         conf.query.mysql_handler_host, conf.query.mysql_handler_port
     );
+    if conf.query.postgres_handler_port != 0 {
+        println!("PostgreSQL");
+        println!(
+            "    listened at {}:{}",
+            conf.query.postgres_handler_host, conf.query.postgres_handler_port
+        );
+        println!(
+            "    connect via: psql -h{} -p{} -U root -d default",
+            conf.query.postgres_handler_host, conf.query.postgres_handler_port
+        );
+    }
     println!("Clickhouse(http)");
     println!(
         "    listened at {}:{}",
@@ -145,6 +145,8 @@ build_exceptions! {
     VirtualColumnNotFound(1115),
     VirtualColumnAlreadyExists(1116),
     ColumnReferencedByComputedColumn(1117),
+    RecursiveCteDepthExceeded(1118),
+    ReadOnlyReplicaPermissionDenied(1119),
 
     // Data Related Errors
 
@@ -259,6 +261,47 @@ build_exceptions! {
     IllegalUDFFormat(2601),
     UnknownUDF(2602),
     UdfAlreadyExists(2603),
+    UDFServerError(2608),
+    UDFRuntimeError(2630),
+
+    // Dictionary error codes.
+    IllegalDictionaryFormat(2604),
+    UnknownDictionary(2605),
+    DictionaryAlreadyExists(2606),
+    DictionarySourceError(2607),
+
+    // Row access policy error codes.
+    IllegalRowAccessPolicyFormat(2609),
+    UnknownRowAccessPolicy(2610),
+    RowAccessPolicyAlreadyExists(2611),
+
+    // Network policy error codes.
+    IllegalNetworkPolicyFormat(2612),
+    UnknownNetworkPolicy(2613),
+    NetworkPolicyAlreadyExists(2614),
+    NetworkPolicyIsUsedByUser(2615),
+
+    // Sequence error codes.
+    IllegalSequenceFormat(2616),
+    UnknownSequence(2617),
+    SequenceAlreadyExists(2618),
+
+    // Task error codes.
+    IllegalTaskFormat(2619),
+    UnknownTask(2620),
+    TaskAlreadyExists(2621),
+
+    // Notification integration error codes.
+    IllegalNotificationIntegrationFormat(2622),
+    UnknownNotificationIntegration(2623),
+    NotificationIntegrationAlreadyExists(2624),
+    NotificationDeliveryFailure(2625),
+
+    // Pipe error codes.
+    IllegalPipeFormat(2626),
+    UnknownPipe(2627),
+    PipeAlreadyExists(2628),
+    PipeSourceFailure(2629),
 
     // Database error codes.
     UnknownDatabaseEngine(2701),
@@ -18,14 +18,16 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use metrics_exporter_prometheus::PrometheusHandle;
 
-#[derive(Debug)]
+use crate::dump_ephemeral_samples;
+
+#[derive(Debug, Clone)]
 pub struct MetricSample {
     pub name: String,
     pub labels: HashMap<String, String>,
     pub value: MetricValue,
 }
 
-#[derive(Debug, PartialEq, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
 pub enum MetricValue {
     Counter(f64),
     Gauge(f64),
@@ -74,13 +76,13 @@ impl From<prometheus_parse::Value> for MetricValue {
     }
 }
 
-#[derive(Debug, PartialEq, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
 pub struct HistogramCount {
     pub less_than: f64,
     pub count: f64,
 }
 
-#[derive(Debug, PartialEq, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
 pub struct SummaryCount {
     pub quantile: f64,
     pub count: f64,
@@ -105,6 +107,7 @@ pub fn dump_metric_samples(handle: PrometheusHandle) -> Result<Vec<MetricSample>
 
     let proc_stats = dump_proc_stats().unwrap_or_default();
     samples.extend(proc_stats);
+    samples.extend(dump_ephemeral_samples());
     Ok(samples)
 }
 
@@ -0,0 +1,45 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+use parking_lot::RwLock;
+
+use crate::MetricSample;
+
+/// The samples of the short-lived objects, such as the running pipelines, by their source.
+///
+/// They are dumped together with the recorded metrics, but never exported to prometheus,
+/// whose series are kept forever once recorded.
+static EPHEMERAL_SAMPLES: Lazy<RwLock<HashMap<&'static str, Vec<MetricSample>>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Replaces all the samples of the source.
+pub fn set_ephemeral_samples(source: &'static str, samples: Vec<MetricSample>) {
+    let mut ephemeral_samples = EPHEMERAL_SAMPLES.write();
+    if samples.is_empty() {
+        ephemeral_samples.remove(source);
+    } else {
+        ephemeral_samples.insert(source, samples);
+    }
+}
+
+pub fn dump_ephemeral_samples() -> Vec<MetricSample> {
+    EPHEMERAL_SAMPLES
+        .read()
+        .values()
+        .flat_map(|samples| samples.iter().cloned())
+        .collect()
+}
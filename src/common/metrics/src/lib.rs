@@ -16,6 +16,7 @@
 
 pub mod counter;
 mod dump;
+mod ephemeral;
 mod recorder;
 
 pub use dump::dump_metric_samples;
@@ -23,6 +24,8 @@ pub use dump::HistogramCount;
 pub use dump::MetricSample;
 pub use dump::MetricValue;
 pub use dump::SummaryCount;
+pub use ephemeral::dump_ephemeral_samples;
+pub use ephemeral::set_ephemeral_samples;
 pub use metrics::Counter;
 pub use metrics::Gauge;
 pub use metrics::Histogram;
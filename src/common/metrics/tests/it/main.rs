@@ -16,7 +16,9 @@ use std::collections::HashMap;
 
 use common_metrics::dump_metric_samples;
 use common_metrics::init_default_metrics_recorder;
+use common_metrics::set_ephemeral_samples;
 use common_metrics::try_handle;
+use common_metrics::MetricSample;
 use common_metrics::MetricValue;
 
 #[tokio::test(flavor = "multi_thread")]
@@ -51,3 +53,31 @@ async fn test_dump_metric_samples() -> common_exception::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_dump_ephemeral_samples() -> common_exception::Result<()> {
+    init_default_metrics_recorder();
+    let sample = MetricSample {
+        name: "test_ephemeral_gauge".to_string(),
+        labels: HashMap::from([("query_id".to_string(), "q1".to_string())]),
+        value: MetricValue::Gauge(0.5),
+    };
+    set_ephemeral_samples("test", vec![sample]);
+
+    let handle = crate::try_handle().unwrap();
+    let samples = dump_metric_samples(handle)?;
+    let sample = samples
+        .iter()
+        .find(|s| s.name == "test_ephemeral_gauge")
+        .unwrap();
+    assert_eq!(MetricValue::Gauge(0.5), sample.value);
+    assert_eq!(Some(&"q1".to_string()), sample.labels.get("query_id"));
+
+    // the samples are gone with their source
+    set_ephemeral_samples("test", vec![]);
+    let handle = crate::try_handle().unwrap();
+    let samples = dump_metric_samples(handle)?;
+    assert!(!samples.iter().any(|s| s.name == "test_ephemeral_gauge"));
+
+    Ok(())
+}
@@ -35,11 +35,18 @@ impl ColumnNodes {
         let mut column_nodes = Vec::with_capacity(schema.fields.len());
 
         let leaf_column_ids = table_schema.map(|table_schema| table_schema.to_leaf_column_ids());
-        for field in &schema.fields {
+        for (i, field) in schema.fields.iter().enumerate() {
             let mut column_node = Self::traverse_fields_dfs(field, false, &mut leaf_id);
             if let Some(ref leaf_column_ids) = leaf_column_ids {
                 column_node.build_leaf_column_ids(leaf_column_ids);
             }
+            if let Some(table_schema) = table_schema {
+                column_node.origin_column_ids = table_schema.fields()[i]
+                    .origins()
+                    .iter()
+                    .map(|origin| origin.column_id)
+                    .collect();
+            }
             column_nodes.push(column_node);
         }
 
@@ -159,6 +166,8 @@ pub struct ColumnNode {
     // Optional children column for nested types.
     pub children: Option<Vec<ColumnNode>>,
     pub leaf_column_ids: Vec<ColumnId>,
+    // The column ids which store the data of the column before its data type was modified.
+    pub origin_column_ids: Vec<ColumnId>,
 }
 
 impl ColumnNode {
@@ -174,6 +183,7 @@ impl ColumnNode {
             leaf_indices,
             children,
             leaf_column_ids: vec![],
+            origin_column_ids: vec![],
         }
     }
 
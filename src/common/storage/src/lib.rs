@@ -56,6 +56,9 @@ pub use parquet::read_parquet_schema_async;
 
 mod stage;
 pub use stage::init_stage_operator;
+pub use stage::StageFileFilter;
 pub use stage::StageFileInfo;
 pub use stage::StageFileStatus;
 pub use stage::StageFilesInfo;
+pub use stage::StageMetadataColumn;
+pub use stage::REJECTED_RECORDS_DIR;
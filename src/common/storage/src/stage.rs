@@ -18,6 +18,9 @@ use chrono::DateTime;
 use chrono::Utc;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::TableDataType;
+use common_expression::TableField;
 use common_meta_app::principal::StageInfo;
 use common_meta_app::principal::StageType;
 use common_meta_app::principal::UserIdentity;
@@ -68,6 +71,10 @@ impl StageFileInfo {
     }
 }
 
+/// The directory of the rejected-records files written by `COPY ... ON_ERROR = CONTINUE`,
+/// the files under it are not listed unless the directory is scanned explicitly.
+pub const REJECTED_RECORDS_DIR: &str = "_rejected/";
+
 pub fn init_stage_operator(stage_info: &StageInfo) -> Result<Operator> {
     if stage_info.stage_type == StageType::External {
         Ok(init_operator(&stage_info.stage_params.storage)?)
@@ -80,6 +87,93 @@ pub fn init_stage_operator(stage_info: &StageInfo) -> Result<Operator> {
         Ok(init_operator(&param)?)
     }
 }
+
+/// The pseudo columns of the files, which could be selected with the file content:
+/// `select metadata$filename, $1 from @s1`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StageMetadataColumn {
+    FileName,
+    /// Starts from 1, NULL if the file is read in splits and the row is not in the first one.
+    FileRowNumber,
+    FileLastModified,
+}
+
+impl StageMetadataColumn {
+    pub const ALL: [StageMetadataColumn; 3] = [
+        StageMetadataColumn::FileName,
+        StageMetadataColumn::FileRowNumber,
+        StageMetadataColumn::FileLastModified,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            StageMetadataColumn::FileName => "metadata$filename",
+            StageMetadataColumn::FileRowNumber => "metadata$file_row_number",
+            StageMetadataColumn::FileLastModified => "metadata$file_last_modified",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<StageMetadataColumn> {
+        Self::ALL.into_iter().find(|c| c.name() == name)
+    }
+
+    pub fn data_type(&self) -> TableDataType {
+        match self {
+            StageMetadataColumn::FileName => TableDataType::String,
+            StageMetadataColumn::FileRowNumber => {
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64)))
+            }
+            StageMetadataColumn::FileLastModified => TableDataType::Timestamp,
+        }
+    }
+
+    pub fn table_fields() -> Vec<TableField> {
+        Self::ALL
+            .iter()
+            .map(|c| TableField::new(c.name(), c.data_type()))
+            .collect()
+    }
+}
+
+/// Filters on the metadata of the files, applied while listing the files.
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Eq, Debug, Default)]
+pub struct StageFileFilter {
+    pub last_modified_after: Option<DateTime<Utc>>,
+    pub last_modified_before: Option<DateTime<Utc>>,
+    pub min_file_size: Option<u64>,
+    pub max_file_size: Option<u64>,
+}
+
+impl StageFileFilter {
+    pub fn is_empty(&self) -> bool {
+        self == &StageFileFilter::default()
+    }
+
+    fn matches(&self, meta: &Metadata) -> bool {
+        let size = meta.content_length();
+        if self.min_file_size.is_some_and(|min| size < min)
+            || self.max_file_size.is_some_and(|max| size > max)
+        {
+            return false;
+        }
+        if self.last_modified_after.is_none() && self.last_modified_before.is_none() {
+            return true;
+        }
+        match meta.last_modified() {
+            Some(modified) => {
+                !self
+                    .last_modified_after
+                    .is_some_and(|after| modified <= after)
+                    && !self
+                        .last_modified_before
+                        .is_some_and(|before| modified >= before)
+            }
+            // can not tell, keep the file.
+            None => true,
+        }
+    }
+}
+
 /// select * from @s1/<path> (FILES => <files> PATTERN => <pattern>)
 /// copy from @s1/<path> FILES = <files> PATTERN => <pattern>
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Eq, Debug)]
@@ -87,6 +181,8 @@ pub struct StageFilesInfo {
     pub path: String,
     pub files: Option<Vec<String>>,
     pub pattern: Option<String>,
+    #[serde(default)]
+    pub filter: StageFileFilter,
 }
 
 impl StageFilesInfo {
@@ -121,6 +217,9 @@ impl StageFilesInfo {
                     .to_string();
                 let meta = operator.stat(&full_path).await?;
                 if meta.mode().is_file() {
+                    if !self.filter.matches(&meta) {
+                        continue;
+                    }
                     res.push(StageFileInfo::new(full_path, &meta))
                 } else {
                     return Err(ErrorCode::BadArguments(format!(
@@ -139,7 +238,12 @@ impl StageFilesInfo {
         } else {
             let pattern = self.get_pattern()?;
             StageFilesInfo::list_files_with_pattern(
-                operator, &self.path, pattern, first_only, max_files,
+                operator,
+                &self.path,
+                pattern,
+                &self.filter,
+                first_only,
+                max_files,
             )
             .await
         }
@@ -179,6 +283,9 @@ impl StageFilesInfo {
                     .to_string();
                 let meta = operator.blocking().stat(&full_path)?;
                 if meta.mode().is_file() {
+                    if !self.filter.matches(&meta) {
+                        continue;
+                    }
                     res.push(StageFileInfo::new(full_path, &meta))
                 } else {
                     return Err(ErrorCode::BadArguments(format!(
@@ -196,7 +303,14 @@ impl StageFilesInfo {
             Ok(res)
         } else {
             let pattern = self.get_pattern()?;
-            blocking_list_files_with_pattern(operator, &self.path, pattern, first_only, max_files)
+            blocking_list_files_with_pattern(
+                operator,
+                &self.path,
+                pattern,
+                &self.filter,
+                first_only,
+                max_files,
+            )
         }
     }
 
@@ -205,12 +319,14 @@ impl StageFilesInfo {
         operator: &Operator,
         path: &str,
         pattern: Option<Regex>,
+        filter: &StageFileFilter,
         first_only: bool,
         max_files: usize,
     ) -> Result<Vec<StageFileInfo>> {
         let root_meta = operator.stat(path).await;
         match root_meta {
             Ok(meta) => match meta.mode() {
+                EntryMode::FILE if !filter.matches(&meta) => return Ok(vec![]),
                 EntryMode::FILE => return Ok(vec![StageFileInfo::new(path.to_string(), &meta)]),
                 EntryMode::DIR => {}
                 EntryMode::Unknown => {
@@ -232,7 +348,7 @@ impl StageFilesInfo {
         let mut limit: usize = 0;
         while let Some(obj) = list.try_next().await? {
             let meta = operator.metadata(&obj, StageFileInfo::meta_query()).await?;
-            if check_file(obj.path(), meta.mode(), &pattern) {
+            if check_file(path, obj.path(), &meta, &pattern, filter) {
                 files.push(StageFileInfo::new(obj.path().to_string(), &meta));
                 if first_only {
                     return Ok(files);
@@ -247,12 +363,24 @@ impl StageFilesInfo {
     }
 }
 
-fn check_file(path: &str, mode: EntryMode, pattern: &Option<Regex>) -> bool {
-    if mode.is_file() {
-        match pattern {
+fn check_file(
+    root: &str,
+    path: &str,
+    meta: &Metadata,
+    pattern: &Option<Regex>,
+    filter: &StageFileFilter,
+) -> bool {
+    // the rejected records are only listed if they are scanned explicitly.
+    let rejected = path.starts_with(REJECTED_RECORDS_DIR)
+        && !root
+            .trim_start_matches('/')
+            .starts_with(REJECTED_RECORDS_DIR);
+    if meta.mode().is_file() && !rejected {
+        let matched = match pattern {
             Some(p) => p.is_match(path),
             None => true,
-        }
+        };
+        matched && filter.matches(meta)
     } else {
         false
     }
@@ -262,6 +390,7 @@ fn blocking_list_files_with_pattern(
     operator: &Operator,
     path: &str,
     pattern: Option<Regex>,
+    filter: &StageFileFilter,
     first_only: bool,
     max_files: usize,
 ) -> Result<Vec<StageFileInfo>> {
@@ -270,6 +399,7 @@ fn blocking_list_files_with_pattern(
     let root_meta = operator.stat(path);
     match root_meta {
         Ok(meta) => match meta.mode() {
+            EntryMode::FILE if !filter.matches(&meta) => return Ok(vec![]),
             EntryMode::FILE => return Ok(vec![StageFileInfo::new(path.to_string(), &meta)]),
             EntryMode::DIR => {}
             EntryMode::Unknown => return Err(ErrorCode::BadArguments("object mode is unknown")),
@@ -290,7 +420,7 @@ fn blocking_list_files_with_pattern(
     for obj in list {
         let obj = obj?;
         let meta = operator.metadata(&obj, StageFileInfo::meta_query())?;
-        if check_file(obj.path(), meta.mode(), &pattern) {
+        if check_file(path, obj.path(), &meta, &pattern, filter) {
             files.push(StageFileInfo::new(obj.path().to_string(), &meta));
             if first_only {
                 return Ok(files);
@@ -20,6 +20,7 @@ use std::fmt::Formatter;
 pub struct Config {
     pub file: FileConfig,
     pub stderr: StderrConfig,
+    pub tracing: TracingConfig,
 }
 
 impl Config {
@@ -37,6 +38,7 @@ impl Config {
                 level: "INFO".to_string(),
                 format: "text".to_string(),
             },
+            tracing: TracingConfig::default(),
         }
     }
 }
@@ -103,3 +105,31 @@ impl Default for StderrConfig {
         }
     }
 }
+
+/// Config for exporting the spans to an OpenTelemetry collector with OTLP.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct TracingConfig {
+    pub on: bool,
+    pub level: String,
+    pub otlp_endpoint: String,
+}
+
+impl Display for TracingConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "enabled={}, level={}, otlp_endpoint={}",
+            self.on, self.level, self.otlp_endpoint
+        )
+    }
+}
+
+impl Default for TracingConfig {
+    fn default() -> Self {
+        Self {
+            on: false,
+            level: "INFO".to_string(),
+            otlp_endpoint: "http://127.0.0.1:4317".to_string(),
+        }
+    }
+}
@@ -25,6 +25,7 @@ mod tracing_to_jaeger;
 pub use config::Config;
 pub use config::FileConfig;
 pub use config::StderrConfig;
+pub use config::TracingConfig;
 pub use logging::init_logging;
 pub use logging::init_query_logger;
 pub use logging::QueryLogger;
@@ -32,6 +33,8 @@ pub use panic_hook::log_panic;
 pub use panic_hook::set_panic_hook;
 pub use tracing_to_jaeger::extract_remote_span_as_parent;
 pub use tracing_to_jaeger::inject_span_to_tonic_request;
+pub use tracing_to_jaeger::set_remote_span_parent;
+pub use tracing_to_jaeger::set_w3c_span_parent;
 
 #[macro_export]
 macro_rules! func_name {
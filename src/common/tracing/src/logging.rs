@@ -17,9 +17,13 @@ use std::io;
 use std::sync::Arc;
 
 use common_base::base::GlobalInstance;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use opentelemetry::global;
 use opentelemetry::sdk::propagation::TraceContextPropagator;
+use opentelemetry::sdk::trace;
+use opentelemetry::sdk::Resource;
+use opentelemetry::KeyValue;
 use sentry_tracing::EventFilter;
 use tracing::Event;
 use tracing::Level;
@@ -64,7 +68,7 @@ use crate::Config;
 /// Registry is composed by generic type parameters.
 ///
 /// To make rust happy, we need to push `Option<Layer>` into it.
-pub fn init_logging(name: &str, cfg: &Config) -> Vec<WorkerGuard> {
+pub fn init_logging(name: &str, cfg: &Config) -> Result<Vec<WorkerGuard>> {
     let mut guards = vec![];
 
     let subscriber = Registry::default();
@@ -164,6 +168,37 @@ pub fn init_logging(name: &str, cfg: &Config) -> Vec<WorkerGuard> {
     }
     let subscriber = subscriber.with(jaeger_layer);
 
+    // OpenTelemetry layer, the spans are exported to the collector with OTLP.
+    let mut otlp_layer = None;
+    if cfg.tracing.on {
+        global::set_text_map_propagator(TraceContextPropagator::new());
+
+        let exporter = opentelemetry_otlp::new_exporter()
+            .tonic()
+            .with_endpoint(cfg.tracing.otlp_endpoint.clone());
+        let tracer =
+            opentelemetry_otlp::new_pipeline()
+                .tracing()
+                .with_exporter(exporter)
+                .with_trace_config(trace::config().with_resource(Resource::new(vec![
+                    KeyValue::new("service.name", name.to_string()),
+                ])))
+                .install_batch(opentelemetry::runtime::Tokio)
+                .map_err(|e| {
+                    ErrorCode::InvalidConfig(format!(
+                        "cannot install the otlp tracer with the endpoint '{}': {}",
+                        cfg.tracing.otlp_endpoint, e
+                    ))
+                })?;
+
+        otlp_layer = Some(
+            tracing_opentelemetry::layer()
+                .with_tracer(tracer)
+                .with_filter(EnvFilter::new(&cfg.tracing.level)),
+        );
+    }
+    let subscriber = subscriber.with(otlp_layer);
+
     let tracing_layer = if env::var("DATABEND_TRACING_LOG_ENABLED").is_ok() {
         let span_rolling_appender =
             RollingFileAppender::new(Rotation::HOURLY, format!("{}/tracing", cfg.file.dir), name);
@@ -219,7 +254,7 @@ pub fn init_logging(name: &str, cfg: &Config) -> Vec<WorkerGuard> {
     // Ignore errors returned by set_global_default.
     let _ = tracing::subscriber::set_global_default(subscriber);
 
-    guards
+    Ok(guards)
 }
 
 pub fn init_query_logger(
@@ -258,7 +293,7 @@ pub struct QueryLogger {
 impl QueryLogger {
     pub fn init(app_name_shuffle: String, config: &Config) -> Result<()> {
         let app_name = format!("databend-query-{}", app_name_shuffle);
-        let mut _log_guards = init_logging(app_name.as_str(), config);
+        let mut _log_guards = init_logging(app_name.as_str(), config)?;
         let query_detail_dir = format!("{}/query-detail", config.file.dir);
 
         GlobalInstance::set(match config.file.on {
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
 use opentelemetry::global;
 use opentelemetry::propagation::Extractor;
 use opentelemetry::propagation::Injector;
@@ -80,10 +82,29 @@ pub fn inject_span_to_tonic_request<T>(mes: impl tonic::IntoRequest<T>) -> tonic
 ///
 /// The global propagator must be installed, e.g. by calling: TODO
 pub fn extract_remote_span_as_parent<T>(request: &tonic::Request<T>) {
+    set_remote_span_parent(&tracing::Span::current(), request);
+}
+
+/// Extract tracing context from tonic request meta as the parent of `span`.
+pub fn set_remote_span_parent<T>(span: &tracing::Span, request: &tonic::Request<T>) {
     let parent_cx = global::get_text_map_propagator(|prop| {
         prop.extract(&MetadataMapExtractor(request.metadata()))
     });
 
-    let span = tracing::Span::current();
+    span.set_parent(parent_cx);
+}
+
+/// Set the parent of `span` to the W3C trace context from clients,
+/// i.e. the `traceparent` and `tracestate` HTTP headers.
+///
+/// Does nothing if the propagator is not installed, i.e. tracing is disabled.
+pub fn set_w3c_span_parent(span: &tracing::Span, traceparent: &str, tracestate: Option<&str>) {
+    let mut headers = HashMap::new();
+    headers.insert("traceparent".to_string(), traceparent.to_string());
+    if let Some(tracestate) = tracestate {
+        headers.insert("tracestate".to_string(), tracestate.to_string());
+    }
+
+    let parent_cx = global::get_text_map_propagator(|prop| prop.extract(&headers));
     span.set_parent(parent_cx);
 }
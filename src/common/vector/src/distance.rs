@@ -15,8 +15,35 @@
 use common_exception::ErrorCode;
 use common_exception::Result;
 use ndarray::ArrayView;
+use ndarray::Zip;
 
 pub fn cosine_distance(from: &[f32], to: &[f32]) -> Result<f32> {
+    check_length(from, to)?;
+
+    let a = ArrayView::from(from);
+    let b = ArrayView::from(to);
+    // `dot` is unrolled, so that it's vectorized by the compiler.
+    let aa_sum = a.dot(&a);
+    let bb_sum = b.dot(&b);
+
+    Ok(1.0 - a.dot(&b) / ((aa_sum).sqrt() * (bb_sum).sqrt()))
+}
+
+/// The euclidean distance between the vectors.
+pub fn l2_distance(from: &[f32], to: &[f32]) -> Result<f32> {
+    check_length(from, to)?;
+
+    let a = ArrayView::from(from);
+    let b = ArrayView::from(to);
+    let sum = Zip::from(&a).and(&b).fold(0.0, |acc, x, y| {
+        let diff = x - y;
+        acc + diff * diff
+    });
+
+    Ok(sum.sqrt())
+}
+
+fn check_length(from: &[f32], to: &[f32]) -> Result<()> {
     if from.len() != to.len() {
         return Err(ErrorCode::InvalidArgument(format!(
             "Vector length not equal: {:} != {:}",
@@ -24,11 +51,5 @@ pub fn cosine_distance(from: &[f32], to: &[f32]) -> Result<f32> {
             to.len(),
         )));
     }
-
-    let a = ArrayView::from(from);
-    let b = ArrayView::from(to);
-    let aa_sum = (&a * &a).sum();
-    let bb_sum = (&b * &b).sum();
-
-    Ok(1.0 - (&a * &b).sum() / ((aa_sum).sqrt() * (bb_sum).sqrt()))
+    Ok(())
 }
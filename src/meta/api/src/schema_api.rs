@@ -44,6 +44,8 @@ use common_meta_app::schema::GetTableReq;
 use common_meta_app::schema::IndexMeta;
 use common_meta_app::schema::ListDatabaseReq;
 use common_meta_app::schema::ListIndexesReq;
+use common_meta_app::schema::ListTableCopiedFileReply;
+use common_meta_app::schema::ListTableCopiedFileReq;
 use common_meta_app::schema::ListTableLockRevReq;
 use common_meta_app::schema::ListTableReq;
 use common_meta_app::schema::ListVirtualColumnsReq;
@@ -61,6 +63,8 @@ use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpdateVirtualColumnReply;
@@ -176,6 +180,11 @@ pub trait SchemaApi: Send + Sync {
         req: GetTableCopiedFileReq,
     ) -> Result<GetTableCopiedFileReply, KVAppError>;
 
+    async fn list_table_copied_file_info(
+        &self,
+        req: ListTableCopiedFileReq,
+    ) -> Result<ListTableCopiedFileReply, KVAppError>;
+
     async fn truncate_table(&self, req: TruncateTableReq)
     -> Result<TruncateTableReply, KVAppError>;
 
@@ -189,6 +198,11 @@ pub trait SchemaApi: Send + Sync {
         req: UpdateTableMetaReq,
     ) -> Result<UpdateTableMetaReply, KVAppError>;
 
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply, KVAppError>;
+
     // gc dropped {table|db} which out of retention time.
     async fn gc_dropped_data(
         &self,
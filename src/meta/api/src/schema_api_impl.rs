@@ -44,6 +44,7 @@ use common_meta_app::app_error::UnknownTableId;
 use common_meta_app::app_error::VirtualColumnAlreadyExists;
 use common_meta_app::app_error::WrongShare;
 use common_meta_app::app_error::WrongShareObject;
+use common_meta_app::principal::PIPE_BATCH_LABEL_PREFIX;
 use common_meta_app::schema::CountTablesKey;
 use common_meta_app::schema::CountTablesReply;
 use common_meta_app::schema::CountTablesReq;
@@ -88,6 +89,8 @@ use common_meta_app::schema::IndexMeta;
 use common_meta_app::schema::IndexNameIdent;
 use common_meta_app::schema::ListDatabaseReq;
 use common_meta_app::schema::ListIndexesReq;
+use common_meta_app::schema::ListTableCopiedFileReply;
+use common_meta_app::schema::ListTableCopiedFileReq;
 use common_meta_app::schema::ListTableLockRevReq;
 use common_meta_app::schema::ListTableReq;
 use common_meta_app::schema::ListVirtualColumnsReq;
@@ -112,6 +115,8 @@ use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpdateVirtualColumnReply;
@@ -2342,6 +2347,47 @@ impl<KV: kvapi::KVApi<Error = MetaError>> SchemaApi for KV {
         })
     }
 
+    async fn list_table_copied_file_info(
+        &self,
+        req: ListTableCopiedFileReq,
+    ) -> Result<ListTableCopiedFileReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        let table_id = req.table_id;
+
+        let tbid = TableId { table_id };
+
+        let (tb_meta_seq, _tb_meta): (_, Option<TableMeta>) = get_pb_value(self, &tbid).await?;
+
+        if tb_meta_seq == 0 {
+            return Err(KVAppError::AppError(AppError::UnknownTableId(
+                UnknownTableId::new(table_id, ""),
+            )));
+        }
+
+        let copied_files = list_table_copied_files(self, table_id).await?;
+
+        let mut file_infos = BTreeMap::new();
+
+        for chunk in copied_files.chunks(DEFAULT_MGET_SIZE) {
+            let str_keys: Vec<_> = chunk.iter().map(|f| f.to_string_key()).collect();
+
+            let seq_infos: Vec<(u64, Option<TableCopiedFileInfo>)> =
+                mget_pb_values(self, &str_keys).await?;
+
+            for (ident, (_seq, file_info)) in chunk.iter().zip(seq_infos) {
+                // The file may be expired after it is listed.
+                if let Some(f_info) = file_info {
+                    file_infos.insert(ident.file.clone(), f_info);
+                }
+            }
+        }
+
+        Ok(ListTableCopiedFileReply {
+            file_info: file_infos,
+        })
+    }
+
     #[tracing::instrument(level = "debug", ret, err, skip_all)]
     async fn truncate_table(
         &self,
@@ -2648,6 +2694,125 @@ impl<KV: kvapi::KVApi<Error = MetaError>> SchemaApi for KV {
         }
     }
 
+    #[tracing::instrument(level = "debug", ret, skip_all)]
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply, KVAppError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        loop {
+            let mut txn_req = TxnRequest {
+                condition: vec![],
+                if_then: vec![],
+                else_then: vec![],
+            };
+            let mut table_metas = Vec::with_capacity(req.update_table_metas.len());
+
+            for update in &req.update_table_metas {
+                let tbid = TableId {
+                    table_id: update.table_id,
+                };
+                let (tb_meta_seq, table_meta): (_, Option<TableMeta>) =
+                    get_pb_value(self, &tbid).await?;
+
+                debug!(ident = display(&tbid), "update_multi_table_meta");
+
+                let table_meta = match table_meta {
+                    Some(table_meta) if tb_meta_seq != 0 => table_meta,
+                    _ => {
+                        return Err(KVAppError::AppError(AppError::UnknownTableId(
+                            UnknownTableId::new(update.table_id, "update_multi_table_meta"),
+                        )));
+                    }
+                };
+                if update.seq.match_seq(tb_meta_seq).is_err() {
+                    return Err(KVAppError::AppError(AppError::from(
+                        TableVersionMismatched::new(
+                            update.table_id,
+                            update.seq,
+                            tb_meta_seq,
+                            "update_multi_table_meta",
+                        ),
+                    )));
+                }
+
+                // table is not changed
+                txn_req.condition.push(txn_cond_seq(&tbid, Eq, tb_meta_seq));
+                // tb_id -> tb_meta
+                txn_req
+                    .if_then
+                    .push(txn_op_put(&tbid, serialize_struct(&update.new_table_meta)?));
+
+                if let Some(copied_files) = &update.copied_files {
+                    let (conditions, match_operations) =
+                        build_upsert_table_copied_file_info_conditions(
+                            &tbid,
+                            copied_files,
+                            tb_meta_seq,
+                            copied_files.fail_if_duplicated,
+                        )?;
+                    txn_req.condition.extend(conditions);
+                    txn_req.if_then.extend(match_operations)
+                }
+
+                if let Some(deduplicated_label) = update.deduplicated_label.clone() {
+                    txn_req
+                        .if_then
+                        .push(build_upsert_table_deduplicated_label(deduplicated_label))
+                }
+
+                table_metas.push(table_meta);
+            }
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(succ = display(succ), "update_multi_table_meta");
+
+            if succ {
+                let mut share_table_info: Option<Vec<ShareTableInfoMap>> = None;
+                for table_meta in &table_metas {
+                    if let Some(infos) = get_share_table_info_map(self, table_meta).await? {
+                        share_table_info.get_or_insert_with(Vec::new).extend(infos);
+                    }
+                }
+                return Ok(UpdateMultiTableMetaReply { share_table_info });
+            }
+
+            // The txn failed, either some of the tables are changed, or some of the copied
+            // files are duplicated.
+            for update in &req.update_table_metas {
+                let tbid = TableId {
+                    table_id: update.table_id,
+                };
+                let (tb_meta_seq, _): (_, Option<TableMeta>) = get_pb_value(self, &tbid).await?;
+                if update.seq.match_seq(tb_meta_seq).is_err() {
+                    return Err(KVAppError::AppError(AppError::from(
+                        TableVersionMismatched::new(
+                            update.table_id,
+                            update.seq,
+                            tb_meta_seq,
+                            "update_multi_table_meta",
+                        ),
+                    )));
+                }
+            }
+            let duplicated = req.update_table_metas.iter().find(|update| {
+                update
+                    .copied_files
+                    .as_ref()
+                    .map(|v| v.fail_if_duplicated)
+                    .unwrap_or(false)
+            });
+            if let Some(update) = duplicated {
+                return Err(KVAppError::AppError(AppError::from(
+                    DuplicatedUpsertFiles::new(update.table_id, "update_multi_table_meta"),
+                )));
+            }
+            // continue and try update the "table copied files"
+        }
+    }
+
     #[tracing::instrument(level = "debug", ret, err, skip_all)]
     async fn gc_dropped_data(
         &self,
@@ -3445,7 +3610,12 @@ fn build_upsert_table_copied_file_info_conditions(
 }
 
 fn build_upsert_table_deduplicated_label(deduplicated_label: String) -> TxnOp {
-    let expire_at = Some(SeqV::<()>::now_ms() / 1000 + 24 * 60 * 60);
+    // the labels of pipe batches are removed by the pipe, so that a batch is never loaded twice.
+    let expire_at = if deduplicated_label.starts_with(&format!("{}/", PIPE_BATCH_LABEL_PREFIX)) {
+        None
+    } else {
+        Some(SeqV::<()>::now_ms() / 1000 + 24 * 60 * 60)
+    };
     TxnOp {
         request: Some(Request::Put(TxnPutRequest {
             key: deduplicated_label,
@@ -53,6 +53,7 @@ use common_meta_app::schema::IndexNameIdent;
 use common_meta_app::schema::IndexType;
 use common_meta_app::schema::ListDatabaseReq;
 use common_meta_app::schema::ListIndexesReq;
+use common_meta_app::schema::ListTableCopiedFileReq;
 use common_meta_app::schema::ListTableLockRevReq;
 use common_meta_app::schema::ListTableReq;
 use common_meta_app::schema::ListVirtualColumnsReq;
@@ -72,6 +73,7 @@ use common_meta_app::schema::TableStatistics;
 use common_meta_app::schema::TruncateTableReq;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpdateVirtualColumnReq;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
@@ -252,6 +254,9 @@ impl SchemaApiTestSuite {
         suite.table_create_get_drop(&b.build().await).await?;
         suite.table_rename(&b.build().await).await?;
         suite.table_update_meta(&b.build().await).await?;
+        suite
+            .table_update_multi_table_meta(&b.build().await)
+            .await?;
         suite.table_upsert_option(&b.build().await).await?;
         suite.table_list(&b.build().await).await?;
         suite.table_list_all(&b.build().await).await?;
@@ -1975,6 +1980,7 @@ impl SchemaApiTestSuite {
                     etag: Some("tag".to_string()),
                     content_length: 1,
                     last_modified: None,
+                    load_time: None,
                 });
 
                 let upsert_source_table = UpsertTableCopiedFileReq {
@@ -2014,6 +2020,7 @@ impl SchemaApiTestSuite {
                     etag: Some("tag_not_exist".to_string()),
                     content_length: 1,
                     last_modified: None,
+                    load_time: None,
                 });
 
                 let upsert_source_table = UpsertTableCopiedFileReq {
@@ -2053,6 +2060,7 @@ impl SchemaApiTestSuite {
                     etag: Some("tag".to_string()),
                     content_length: 1,
                     last_modified: None,
+                    load_time: None,
                 });
 
                 let upsert_source_table = UpsertTableCopiedFileReq {
@@ -2077,6 +2085,116 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn table_update_multi_table_meta<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+        let tbl_names = ["tb1", "tb2"];
+
+        let schema = || {
+            Arc::new(TableSchema::new(vec![TableField::new(
+                "number",
+                TableDataType::Number(NumberDataType::UInt64),
+            )]))
+        };
+
+        let table_meta = |created_on| TableMeta {
+            schema: schema(),
+            engine: "JSON".to_string(),
+            options: Default::default(),
+            created_on,
+            ..TableMeta::default()
+        };
+
+        info!("--- prepare db and tables");
+        {
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta {
+                    engine: "".to_string(),
+                    ..DatabaseMeta::default()
+                },
+            };
+            mt.create_database(plan).await?;
+
+            for tbl_name in tbl_names {
+                let req = CreateTableReq {
+                    if_not_exists: false,
+                    name_ident: TableNameIdent {
+                        tenant: tenant.to_string(),
+                        db_name: db_name.to_string(),
+                        table_name: tbl_name.to_string(),
+                    },
+                    table_meta: table_meta(Utc::now()),
+                };
+                mt.create_table(req).await?;
+            }
+        }
+
+        let update_req = |table: &TableInfo, data_bytes: u64, seq: u64| {
+            let mut new_table_meta = table.meta.clone();
+            new_table_meta.statistics = TableStatistics {
+                data_bytes,
+                ..Default::default()
+            };
+            UpdateTableMetaReq {
+                table_id: table.ident.table_id,
+                seq: MatchSeq::Exact(seq),
+                new_table_meta,
+                copied_files: None,
+                deduplicated_label: None,
+            }
+        };
+
+        info!("--- update multi table meta, normal case");
+        {
+            let tb1 = mt.get_table((tenant, db_name, "tb1").into()).await?;
+            let tb2 = mt.get_table((tenant, db_name, "tb2").into()).await?;
+
+            let req1 = update_req(tb1.as_ref(), 1, tb1.ident.seq);
+            let req2 = update_req(tb2.as_ref(), 2, tb2.ident.seq);
+            mt.update_multi_table_meta(UpdateMultiTableMetaReq {
+                update_table_metas: vec![req1.clone(), req2.clone()],
+            })
+            .await?;
+
+            let tb1 = mt.get_table((tenant, db_name, "tb1").into()).await?;
+            let tb2 = mt.get_table((tenant, db_name, "tb2").into()).await?;
+            assert_eq!(tb1.meta, req1.new_table_meta);
+            assert_eq!(tb2.meta, req2.new_table_meta);
+        }
+
+        info!("--- update multi table meta: version mismatch, none of the tables is updated");
+        {
+            let tb1 = mt.get_table((tenant, db_name, "tb1").into()).await?;
+            let tb2 = mt.get_table((tenant, db_name, "tb2").into()).await?;
+
+            let res = mt
+                .update_multi_table_meta(UpdateMultiTableMetaReq {
+                    update_table_metas: vec![
+                        update_req(tb1.as_ref(), 3, tb1.ident.seq),
+                        update_req(tb2.as_ref(), 4, tb2.ident.seq + 1),
+                    ],
+                })
+                .await;
+
+            let err = res.unwrap_err();
+            let err = ErrorCode::from(err);
+            assert_eq!(ErrorCode::TABLE_VERSION_MISMATCHED, err.code());
+
+            let got1 = mt.get_table((tenant, db_name, "tb1").into()).await?;
+            let got2 = mt.get_table((tenant, db_name, "tb2").into()).await?;
+            assert_eq!(got1.meta, tb1.meta);
+            assert_eq!(got2.meta, tb2.meta);
+        }
+
+        Ok(())
+    }
+
     #[tracing::instrument(level = "debug", skip_all)]
     async fn table_upsert_option<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
         let tenant = "tenant1";
@@ -2530,6 +2648,7 @@ impl SchemaApiTestSuite {
                 etag: Some("etag".to_owned()),
                 content_length: 1024,
                 last_modified: Some(Utc::now()),
+                load_time: None,
             };
             let mut file_info = BTreeMap::new();
             file_info.insert("file".to_string(), stage_info.clone());
@@ -3258,6 +3377,7 @@ impl SchemaApiTestSuite {
                 etag: Some("etag".to_owned()),
                 content_length: 1024,
                 last_modified: Some(Utc::now()),
+                load_time: None,
             };
             let mut file_info = BTreeMap::new();
             file_info.insert("file".to_string(), stage_info.clone());
@@ -3295,6 +3415,7 @@ impl SchemaApiTestSuite {
                 etag: Some("etag".to_owned()),
                 content_length: 1024,
                 last_modified: Some(Utc::now()),
+                load_time: None,
             };
             let mut file_info = BTreeMap::new();
             file_info.insert("file2".to_string(), stage_info.clone());
@@ -3354,6 +3475,14 @@ impl SchemaApiTestSuite {
             assert_eq!(file_infos, resp.file_info);
         }
 
+        info!("--- list copied file infos");
+        {
+            let req = ListTableCopiedFileReq { table_id };
+
+            let resp = mt.list_table_copied_file_info(req).await?;
+            assert_eq!(file_infos, resp.file_info);
+        }
+
         info!("--- truncate table and get stage file info again");
         {
             let req = TruncateTableReq {
@@ -3370,6 +3499,11 @@ impl SchemaApiTestSuite {
 
             let resp = mt.get_table_copied_file_info(req).await?;
             assert_eq!(resp.file_info.len(), 0);
+
+            let req = ListTableCopiedFileReq { table_id };
+
+            let resp = mt.list_table_copied_file_info(req).await?;
+            assert_eq!(resp.file_info.len(), 0);
         }
 
         Ok(())
@@ -4601,6 +4735,7 @@ impl SchemaApiTestSuite {
                 etag: Some("etag".to_owned()),
                 content_length: 1024,
                 last_modified: Some(Utc::now()),
+                load_time: None,
             };
             let mut file_info = BTreeMap::new();
             file_info.insert("file".to_string(), stage_info.clone());
@@ -4646,6 +4781,7 @@ impl SchemaApiTestSuite {
                 etag: Some("etag".to_owned()),
                 content_length: 1024,
                 last_modified: Some(Utc::now()),
+                load_time: None,
             };
             let mut file_info = BTreeMap::new();
             file_info.insert("file".to_string(), stage_info.clone());
@@ -4689,6 +4825,7 @@ impl SchemaApiTestSuite {
                 etag: Some("etag".to_owned()),
                 content_length: 1024,
                 last_modified: Some(Utc::now()),
+                load_time: None,
             };
             let mut file_info = BTreeMap::new();
             file_info.insert("file".to_string(), stage_info.clone());
@@ -4843,6 +4980,7 @@ where MT: SchemaApi
                 etag: Some(format!("etag{}", i)),
                 content_length: 1024,
                 last_modified: Some(Utc::now()),
+                load_time: None,
             };
             file_infos.insert(format!("file{}", i), stage_info);
         }
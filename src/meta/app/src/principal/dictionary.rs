@@ -0,0 +1,110 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::TableSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A dictionary maps the keys of its primary key column to the values of
+/// its other columns, which are looked up in an external source.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct DictionaryMeta {
+    pub name: String,
+    pub source: DictionarySource,
+    /// the primary key column and the attribute columns
+    pub schema: TableSchema,
+    pub primary_key: String,
+    pub comment: String,
+    pub created_on: DateTime<Utc>,
+}
+
+impl DictionaryMeta {
+    /// The attribute columns, which can be looked up by `dict_get`.
+    pub fn attributes(&self) -> impl Iterator<Item = &str> {
+        self.schema
+            .fields()
+            .iter()
+            .map(|f| f.name().as_str())
+            .filter(|name| *name != self.primary_key)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum DictionarySource {
+    Mysql(MysqlDictionarySource),
+    Redis(RedisDictionarySource),
+}
+
+/// Rows are looked up in `table` by the primary key column,
+/// the attributes are the columns of the same names.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct MysqlDictionarySource {
+    pub host: String,
+    pub port: u16,
+    pub username: String,
+    pub password: String,
+    pub database: String,
+    pub table: String,
+}
+
+/// The value of a key is the string stored under it, so a redis
+/// dictionary has exactly one attribute column.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct RedisDictionarySource {
+    pub host: String,
+    pub port: u16,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub db_index: i64,
+}
+
+impl Display for DictionarySource {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        // passwords are left out
+        match self {
+            DictionarySource::Mysql(s) => write!(
+                f,
+                "MYSQL(host='{}' port='{}' username='{}' db='{}' table='{}')",
+                s.host, s.port, s.username, s.database, s.table
+            ),
+            DictionarySource::Redis(s) => write!(
+                f,
+                "REDIS(host='{}' port='{}' db_index='{}')",
+                s.host, s.port, s.db_index
+            ),
+        }
+    }
+}
+
+impl TryFrom<Vec<u8>> for DictionaryMeta {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(dictionary) => Ok(dictionary),
+            Err(serialize_error) => Err(ErrorCode::IllegalDictionaryFormat(format!(
+                "Cannot deserialize dictionary from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
@@ -96,6 +96,8 @@ pub enum FileFormatParams {
     Json(JsonFileFormatParams),
     Xml(XmlFileFormatParams),
     Parquet(ParquetFileFormatParams),
+    Avro(AvroFileFormatParams),
+    Orc(OrcFileFormatParams),
 }
 
 impl FileFormatParams {
@@ -107,6 +109,8 @@ impl FileFormatParams {
             FileFormatParams::Json(_) => StageFileFormatType::Json,
             FileFormatParams::Xml(_) => StageFileFormatType::Xml,
             FileFormatParams::Parquet(_) => StageFileFormatType::Parquet,
+            FileFormatParams::Avro(_) => StageFileFormatType::Avro,
+            FileFormatParams::Orc(_) => StageFileFormatType::Orc,
         }
     }
 
@@ -124,6 +128,10 @@ impl FileFormatParams {
                 Ok(FileFormatParams::Json(JsonFileFormatParams::default()))
             }
             StageFileFormatType::Xml => Ok(FileFormatParams::Xml(XmlFileFormatParams::default())),
+            StageFileFormatType::Avro => {
+                Ok(FileFormatParams::Avro(AvroFileFormatParams::default()))
+            }
+            StageFileFormatType::Orc => Ok(FileFormatParams::Orc(OrcFileFormatParams::default())),
             _ => Err(ErrorCode::IllegalFileFormat(format!(
                 "Unsupported file format type: {:?}",
                 format_type
@@ -139,6 +147,8 @@ impl FileFormatParams {
             FileFormatParams::Json(v) => v.compression,
             FileFormatParams::Xml(v) => v.compression,
             FileFormatParams::Parquet(_) => StageFileCompression::None,
+            FileFormatParams::Avro(_) => StageFileCompression::None,
+            FileFormatParams::Orc(_) => StageFileCompression::None,
         }
     }
 
@@ -164,6 +174,8 @@ impl FileFormatParams {
                 FileFormatParams::NdJson(NdJsonFileFormatParams { compression })
             }
             StageFileFormatType::Parquet => FileFormatParams::Parquet(ParquetFileFormatParams {}),
+            StageFileFormatType::Avro => FileFormatParams::Avro(AvroFileFormatParams {}),
+            StageFileFormatType::Orc => FileFormatParams::Orc(OrcFileFormatParams {}),
             StageFileFormatType::Csv => {
                 let default = CsvFileFormatParams::default();
                 let compression = ast.take_compression()?;
@@ -403,6 +415,15 @@ impl NdJsonFileFormatParams {
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ParquetFileFormatParams {}
 
+/// Avro container files declare their codec (deflate, snappy) in the file header,
+/// so there is no compression option here.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AvroFileFormatParams {}
+
+/// ORC compression (zlib, snappy, zstd, ...) is declared in the postscript of the file.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcFileFormatParams {}
+
 impl Display for FileFormatParams {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -448,6 +469,12 @@ impl Display for FileFormatParams {
             FileFormatParams::Parquet(_) => {
                 write!(f, "TYPE = PARQUET")
             }
+            FileFormatParams::Avro(_) => {
+                write!(f, "TYPE = AVRO")
+            }
+            FileFormatParams::Orc(_) => {
+                write!(f, "TYPE = ORC")
+            }
         }
     }
 }
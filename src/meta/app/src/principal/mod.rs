@@ -14,9 +14,16 @@
 
 //! Principal is a user or role that accesses an entity.
 
+mod dictionary;
 mod file_format;
+mod network_policy;
+mod notification;
+mod pipe;
 mod principal_identity;
 mod role_info;
+mod row_access_policy;
+mod sequence;
+mod task;
 mod user_auth;
 mod user_defined_file_format;
 mod user_defined_function;
@@ -28,14 +35,36 @@ mod user_quota;
 mod user_setting;
 mod user_stage;
 
+pub use dictionary::DictionaryMeta;
+pub use dictionary::DictionarySource;
+pub use dictionary::MysqlDictionarySource;
+pub use dictionary::RedisDictionarySource;
 pub use file_format::*;
+pub use network_policy::NetworkPolicy;
+pub use notification::NotificationIntegration;
+pub use notification::NotificationTarget;
+pub use pipe::GtidSet;
+pub use pipe::PipeBatch;
+pub use pipe::PipeInfo;
+pub use pipe::PipeLoad;
+pub use pipe::PipeSource;
+pub use pipe::PIPE_BATCH_LABEL_PREFIX;
 pub use principal_identity::PrincipalIdentity;
 pub use role_info::RoleInfo;
 pub use role_info::RoleInfoSerdeError;
+pub use row_access_policy::RowAccessPolicyMeta;
+pub use sequence::SequenceMeta;
+pub use task::TaskMeta;
+pub use task::TaskRun;
+pub use task::TaskRunTrigger;
+pub use task::TaskSchedule;
+pub use task::TaskState;
 pub use user_auth::AuthInfo;
 pub use user_auth::AuthType;
 pub use user_auth::PasswordHashMethod;
 pub use user_defined_file_format::UserDefinedFileFormat;
+pub use user_defined_function::UDFServer;
+pub use user_defined_function::UDFWasmModule;
 pub use user_defined_function::UserDefinedFunction;
 pub use user_grant::GrantEntry;
 pub use user_grant::GrantObject;
@@ -0,0 +1,47 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::convert::TryFrom;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A network policy restricts the client addresses the users attached to it
+/// can connect from. The lists hold IPv4/IPv6 addresses or CIDR blocks.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct NetworkPolicy {
+    pub name: String,
+    pub allowed_ip_list: Vec<String>,
+    /// the addresses that are rejected even if they are in `allowed_ip_list`
+    pub blocked_ip_list: Vec<String>,
+    pub comment: String,
+    pub created_on: DateTime<Utc>,
+}
+
+impl TryFrom<Vec<u8>> for NetworkPolicy {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(policy) => Ok(policy),
+            Err(serialize_error) => Err(ErrorCode::IllegalNetworkPolicyFormat(format!(
+                "Cannot deserialize network policy from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
@@ -0,0 +1,165 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Where the notifications of an integration are sent, built from the `TYPE` and
+/// the `CONNECTION` options of `CREATE NOTIFICATION INTEGRATION`.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub enum NotificationTarget {
+    /// The payload is the body of a `POST` or `PUT` request to the url.
+    Webhook {
+        url: String,
+        method: String,
+        authorization_header: String,
+    },
+    /// The payload is sent as a message to the Amazon SQS queue, the requests are
+    /// signed with the access key.
+    Sqs {
+        queue_url: String,
+        region: String,
+        access_key_id: String,
+        secret_access_key: String,
+    },
+    /// The payload is produced as a record of the topic through a Kafka REST proxy.
+    Kafka {
+        rest_proxy_url: String,
+        topic: String,
+        authorization_header: String,
+    },
+}
+
+impl NotificationTarget {
+    pub fn from_options(target_type: &str, options: &BTreeMap<String, String>) -> Result<Self> {
+        let mut options = options.clone();
+        let mut take = |key: &str, required: bool| match options.remove(key) {
+            Some(value) if !value.is_empty() => Ok(value),
+            _ if required => Err(ErrorCode::IllegalNotificationIntegrationFormat(format!(
+                "Missing connection option {} of the {} notification integration",
+                key, target_type
+            ))),
+            _ => Ok(String::new()),
+        };
+
+        let target = match target_type.to_lowercase().as_str() {
+            "webhook" => {
+                let url = check_url(take("url", true)?)?;
+                let method = match take("method", false)?.to_uppercase().as_str() {
+                    "" | "POST" => "POST".to_string(),
+                    "PUT" => "PUT".to_string(),
+                    method => {
+                        return Err(ErrorCode::IllegalNotificationIntegrationFormat(format!(
+                            "Invalid webhook method {}, expect POST or PUT",
+                            method
+                        )));
+                    }
+                };
+                NotificationTarget::Webhook {
+                    url,
+                    method,
+                    authorization_header: take("authorization_header", false)?,
+                }
+            }
+            "sqs" => NotificationTarget::Sqs {
+                queue_url: check_url(take("queue_url", true)?)?,
+                region: take("region", true)?,
+                access_key_id: take("access_key_id", true)?,
+                secret_access_key: take("secret_access_key", true)?,
+            },
+            "kafka" => NotificationTarget::Kafka {
+                rest_proxy_url: check_url(take("rest_proxy_url", true)?)?,
+                topic: take("topic", true)?,
+                authorization_header: take("authorization_header", false)?,
+            },
+            _ => {
+                return Err(ErrorCode::IllegalNotificationIntegrationFormat(format!(
+                    "Unknown notification integration type {}, expect WEBHOOK, SQS or KAFKA",
+                    target_type
+                )));
+            }
+        };
+
+        if let Some(key) = options.keys().next() {
+            return Err(ErrorCode::IllegalNotificationIntegrationFormat(format!(
+                "Unknown connection option {} of the {} notification integration",
+                key, target_type
+            )));
+        }
+        Ok(target)
+    }
+
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            NotificationTarget::Webhook { .. } => "WEBHOOK",
+            NotificationTarget::Sqs { .. } => "SQS",
+            NotificationTarget::Kafka { .. } => "KAFKA",
+        }
+    }
+
+    /// Where the notifications are sent, without the credentials.
+    pub fn endpoint(&self) -> String {
+        match self {
+            NotificationTarget::Webhook { url, .. } => url.clone(),
+            NotificationTarget::Sqs { queue_url, .. } => queue_url.clone(),
+            NotificationTarget::Kafka {
+                rest_proxy_url,
+                topic,
+                ..
+            } => format!("{}/topics/{}", rest_proxy_url.trim_end_matches('/'), topic),
+        }
+    }
+}
+
+fn check_url(url: String) -> Result<String> {
+    if url.starts_with("http://") || url.starts_with("https://") {
+        Ok(url)
+    } else {
+        Err(ErrorCode::IllegalNotificationIntegrationFormat(format!(
+            "Invalid url {}, expect http:// or https://",
+            url
+        )))
+    }
+}
+
+/// A notification integration, the tables subscribe to it by the
+/// `notification_integration` table option.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct NotificationIntegration {
+    pub name: String,
+    pub target: NotificationTarget,
+    pub comment: String,
+    pub created_on: DateTime<Utc>,
+}
+
+impl TryFrom<Vec<u8>> for NotificationIntegration {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(integration) => Ok(integration),
+            Err(serialize_error) => Err(ErrorCode::IllegalNotificationIntegrationFormat(format!(
+                "Cannot deserialize notification integration from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
@@ -0,0 +1,383 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::principal::FileFormatParams;
+use crate::principal::UserIdentity;
+
+/// Where a pipe loads the messages from, built from the options of `FROM KAFKA (...)`
+/// and `FROM MYSQL (...)`, or from the stage of `FROM @<stage>` of an `AUTO_INGEST` pipe.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub enum PipeSource {
+    /// The messages of all the partitions of the topic, consumed through a Kafka REST proxy.
+    Kafka {
+        rest_proxy_url: String,
+        topic: String,
+        authorization_header: String,
+    },
+    /// The files created under the path of an external stage on S3, copied as the
+    /// object-created events of the bucket arrive, by the webhook of the pipe or from
+    /// the SQS queue.
+    Stage {
+        stage_name: String,
+        /// The path in the stage, starting with '/'.
+        path: String,
+        /// Only the files matching the regex are loaded, empty to load all.
+        pattern: String,
+        /// The options of `FILE_FORMAT = (...)` of the copy, empty to use the format
+        /// of the stage.
+        file_format_options: BTreeMap<String, String>,
+        /// Empty if the events are only posted to the webhook.
+        aws_sqs_queue_url: String,
+    },
+    /// The row events of a table of MySQL, read from the binlog by an external
+    /// coordinator and posted to the pipe, merged into the table by the primary key.
+    MySql {
+        database: String,
+        table: String,
+        /// The columns matching the rows of MySQL to the rows of the table.
+        primary_key: Vec<String>,
+    },
+}
+
+impl PipeSource {
+    pub fn from_kafka_options(options: &BTreeMap<String, String>) -> Result<Self> {
+        let mut options = options.clone();
+        let mut take = |key: &str, required: bool| match options.remove(key) {
+            Some(value) if !value.is_empty() => Ok(value),
+            _ if required => Err(ErrorCode::IllegalPipeFormat(format!(
+                "Missing option {} of the KAFKA source",
+                key
+            ))),
+            _ => Ok(String::new()),
+        };
+
+        let rest_proxy_url = take("rest_proxy_url", true)?;
+        if !rest_proxy_url.starts_with("http://") && !rest_proxy_url.starts_with("https://") {
+            return Err(ErrorCode::IllegalPipeFormat(format!(
+                "Invalid rest_proxy_url {}, expect http:// or https://",
+                rest_proxy_url
+            )));
+        }
+        let source = PipeSource::Kafka {
+            rest_proxy_url: rest_proxy_url.trim_end_matches('/').to_string(),
+            topic: take("topic", true)?,
+            authorization_header: take("authorization_header", false)?,
+        };
+
+        if let Some(key) = options.keys().next() {
+            return Err(ErrorCode::IllegalPipeFormat(format!(
+                "Unknown option {} of the KAFKA source",
+                key
+            )));
+        }
+        Ok(source)
+    }
+
+    pub fn from_mysql_options(options: &BTreeMap<String, String>) -> Result<Self> {
+        let mut options = options.clone();
+        let mut take = |key: &str| match options.remove(key) {
+            Some(value) if !value.is_empty() => Ok(value),
+            _ => Err(ErrorCode::IllegalPipeFormat(format!(
+                "Missing option {} of the MYSQL source",
+                key
+            ))),
+        };
+
+        let database = take("database")?;
+        let table = take("table")?;
+        let primary_key = take("primary_key")?
+            .split(',')
+            .map(|column| column.trim().to_string())
+            .filter(|column| !column.is_empty())
+            .collect::<Vec<_>>();
+        if primary_key.is_empty() {
+            return Err(ErrorCode::IllegalPipeFormat(
+                "Missing option primary_key of the MYSQL source",
+            ));
+        }
+        let source = PipeSource::MySql {
+            database,
+            table,
+            primary_key,
+        };
+
+        if let Some(key) = options.keys().next() {
+            return Err(ErrorCode::IllegalPipeFormat(format!(
+                "Unknown option {} of the MYSQL source",
+                key
+            )));
+        }
+        Ok(source)
+    }
+
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            PipeSource::Kafka { .. } => "KAFKA",
+            PipeSource::Stage { .. } => "STAGE",
+            PipeSource::MySql { .. } => "MYSQL",
+        }
+    }
+
+    /// Where the messages are consumed from, without the credentials.
+    pub fn endpoint(&self) -> String {
+        match self {
+            PipeSource::Kafka {
+                rest_proxy_url,
+                topic,
+                ..
+            } => format!("{}/topics/{}", rest_proxy_url, topic),
+            PipeSource::Stage {
+                stage_name, path, ..
+            } => format!("@{}{}", stage_name, path),
+            PipeSource::MySql {
+                database, table, ..
+            } => format!("{}.{}", database, table),
+        }
+    }
+}
+
+/// The transactions of MySQL, as the `gtid_executed` of the server:
+/// `<server_uuid>:<start>[-<end>][:<start>[-<end>]...][,<server_uuid>:...]`.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct GtidSet {
+    /// The sorted and merged intervals `[start, end]` of the transaction ids of each
+    /// server.
+    pub intervals: BTreeMap<String, Vec<(u64, u64)>>,
+}
+
+impl GtidSet {
+    pub fn parse(s: &str) -> Result<Self> {
+        let invalid = || ErrorCode::BadArguments(format!("Invalid GTID set {}", s));
+        let mut set = GtidSet::default();
+        for server in s.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+            let mut parts = server.split(':');
+            let uuid = parts.next().ok_or_else(invalid)?.to_lowercase();
+            for interval in parts {
+                let (start, end) = match interval.split_once('-') {
+                    Some((start, end)) => (start.parse::<u64>(), end.parse::<u64>()),
+                    None => (interval.parse::<u64>(), interval.parse::<u64>()),
+                };
+                match (start, end) {
+                    (Ok(start), Ok(end)) if 0 < start && start <= end => {
+                        set.add_interval(&uuid, start, end)
+                    }
+                    _ => return Err(invalid()),
+                }
+            }
+        }
+        Ok(set)
+    }
+
+    /// Parse the GTID `<server_uuid>:<transaction_id>` of a transaction.
+    pub fn parse_gtid(gtid: &str) -> Result<(String, u64)> {
+        match gtid.trim().rsplit_once(':') {
+            Some((uuid, id)) if !uuid.is_empty() => match id.parse::<u64>() {
+                Ok(id) if id > 0 => Ok((uuid.to_lowercase(), id)),
+                _ => Err(ErrorCode::BadArguments(format!("Invalid GTID {}", gtid))),
+            },
+            _ => Err(ErrorCode::BadArguments(format!("Invalid GTID {}", gtid))),
+        }
+    }
+
+    pub fn contains(&self, uuid: &str, id: u64) -> bool {
+        self.intervals.get(uuid).map_or(false, |intervals| {
+            intervals
+                .iter()
+                .any(|(start, end)| *start <= id && id <= *end)
+        })
+    }
+
+    pub fn add(&mut self, uuid: &str, id: u64) {
+        self.add_interval(uuid, id, id)
+    }
+
+    fn add_interval(&mut self, uuid: &str, start: u64, end: u64) {
+        let intervals = self.intervals.entry(uuid.to_string()).or_default();
+        intervals.push((start, end));
+        intervals.sort();
+        let mut merged: Vec<(u64, u64)> = Vec::with_capacity(intervals.len());
+        for (start, end) in intervals.drain(..) {
+            match merged.last_mut() {
+                Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+        *intervals = merged;
+    }
+}
+
+impl Display for GtidSet {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        for (i, (uuid, intervals)) in self.intervals.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}", uuid)?;
+            for (start, end) in intervals {
+                match start == end {
+                    true => write!(f, ":{}", start)?,
+                    false => write!(f, ":{}-{}", start, end)?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The prefix of the deduplicate labels of pipe batches. Unlike other deduplicate
+/// labels, they don't expire, the pipe removes the label of a batch after its offsets
+/// advance past it.
+pub const PIPE_BATCH_LABEL_PREFIX: &str = "__fd_pipe_batches";
+
+/// The messages of a batch, the offsets `[start, end)` of each partition.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct PipeBatch {
+    pub id: u64,
+    pub ranges: BTreeMap<u32, (u64, u64)>,
+}
+
+/// A pipe loads the messages of its source into the table continuously, as the user
+/// who created it.
+///
+/// The offsets consumed are kept here, not in the source. A batch is recorded as
+/// pending before it's loaded, and the offsets advance past it after the load. The
+/// load commits the deduplicate label of the batch along with the snapshot, so a
+/// pending batch left by a failure is loaded again with the same messages, and is
+/// skipped if it was committed already: every message is loaded exactly once, however
+/// long the pipe stays failed, as the label of a batch never expires.
+///
+/// A pipe from MySQL keeps the transactions merged instead, the events of them are
+/// skipped when they are posted again.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct PipeInfo {
+    pub name: String,
+    pub owner: UserIdentity,
+    pub database: String,
+    pub table: String,
+    pub source: PipeSource,
+    pub file_format: FileFormatParams,
+    pub comment: String,
+    pub created_on: DateTime<Utc>,
+    /// The offset of the next message to load of each partition, the partitions not
+    /// in it are loaded from their earliest messages.
+    pub offsets: BTreeMap<u32, u64>,
+    pub pending_batch: Option<PipeBatch>,
+    pub next_batch_id: u64,
+    pub loaded_messages: u64,
+    /// The files copied by a pipe from a stage.
+    #[serde(default)]
+    pub loaded_files: u64,
+    /// The transactions merged by a pipe from MySQL.
+    #[serde(default)]
+    pub gtid_executed: GtidSet,
+    /// The GTID of the last transaction merged, empty if none. Its events may go on in
+    /// the next post, so they are merged again instead of skipped.
+    #[serde(default)]
+    pub last_gtid: String,
+    pub last_loaded_at: Option<DateTime<Utc>>,
+    /// The error of the last batch or copy, empty if it's loaded.
+    pub last_error: String,
+}
+
+impl PipeInfo {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        name: &str,
+        owner: UserIdentity,
+        database: String,
+        table: String,
+        source: PipeSource,
+        file_format: FileFormatParams,
+        comment: String,
+    ) -> Self {
+        PipeInfo {
+            name: name.to_string(),
+            owner,
+            database,
+            table,
+            source,
+            file_format,
+            comment,
+            created_on: Utc::now(),
+            offsets: BTreeMap::new(),
+            pending_batch: None,
+            next_batch_id: 0,
+            loaded_messages: 0,
+            loaded_files: 0,
+            gtid_executed: GtidSet::default(),
+            last_gtid: String::new(),
+            last_loaded_at: None,
+            last_error: String::new(),
+        }
+    }
+
+    /// The deduplicate label of a batch, a pipe created again with the same name
+    /// doesn't collide with the batches of the dropped one.
+    pub fn batch_label(&self, tenant: &str, batch: &PipeBatch) -> String {
+        format!(
+            "{}{}/{}",
+            Self::batch_labels_prefix(tenant, &self.name),
+            self.created_on.timestamp_micros(),
+            batch.id
+        )
+    }
+
+    /// The prefix of the deduplicate labels of all the batches of the pipes named `name`.
+    pub fn batch_labels_prefix(tenant: &str, name: &str) -> String {
+        format!("{}/{}/{}/", PIPE_BATCH_LABEL_PREFIX, tenant, name)
+    }
+}
+
+impl TryFrom<Vec<u8>> for PipeInfo {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(pipe) => Ok(pipe),
+            Err(serialize_error) => Err(ErrorCode::IllegalPipeFormat(format!(
+                "Cannot deserialize pipe from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
+
+/// A load of a pipe, a batch of messages or a copy of files, kept for
+/// `system.pipe_history`.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct PipeLoad {
+    pub pipe_name: String,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+    /// The messages of the batch, for a pipe from Kafka, or the row events merged, for
+    /// a pipe from MySQL.
+    pub messages: u64,
+    /// The files of the copy, for a pipe from a stage.
+    pub files: Vec<String>,
+    pub succeeded: bool,
+    pub error_code: u16,
+    pub error_message: String,
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::fmt::Display;
 use std::fmt::Formatter;
 
@@ -28,6 +29,9 @@ pub struct RoleInfo {
     pub name: String,
 
     pub grants: UserGrantSet,
+
+    /// The settings applied to the sessions using the role as the current role.
+    pub settings: BTreeMap<String, String>,
 }
 
 /// Error when ser/de RoleInfo
@@ -42,6 +46,7 @@ impl RoleInfo {
         Self {
             name: name.to_string(),
             grants: UserGrantSet::empty(),
+            settings: BTreeMap::new(),
         }
     }
 
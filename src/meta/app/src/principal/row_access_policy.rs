@@ -0,0 +1,58 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A row access policy is a boolean expression over its arguments. Once
+/// attached to a table, the arguments are bound to columns of the table and
+/// only the rows for which the expression is true are visible to the roles
+/// that are not exempt from the policy.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct RowAccessPolicyMeta {
+    pub name: String,
+    /// (arg_name, arg_type)
+    pub args: Vec<(String, String)>,
+    pub body: String,
+    /// the roles that see all rows of the tables attached to this policy
+    pub exempt_roles: Vec<String>,
+    pub comment: String,
+    pub created_on: DateTime<Utc>,
+}
+
+impl RowAccessPolicyMeta {
+    pub fn is_exempt(&self, role: &str) -> bool {
+        self.exempt_roles.iter().any(|r| r == role)
+    }
+}
+
+impl TryFrom<Vec<u8>> for RowAccessPolicyMeta {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(policy) => Ok(policy),
+            Err(serialize_error) => Err(ErrorCode::IllegalRowAccessPolicyFormat(format!(
+                "Cannot deserialize row access policy from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A sequence generates unique numbers: `start`, `start + increment`, ...
+///
+/// The values are allocated from the meta service in ranges, so they are unique
+/// across the concurrent writers and the nodes of the cluster, but there may be
+/// gaps between the values used by different writers.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct SequenceMeta {
+    pub name: String,
+    pub start: u64,
+    pub increment: u64,
+    /// The next value to allocate.
+    pub current: u64,
+    pub comment: String,
+    pub created_on: DateTime<Utc>,
+}
+
+impl SequenceMeta {
+    pub fn new(name: &str, start: u64, increment: u64, comment: String) -> Self {
+        SequenceMeta {
+            name: name.to_string(),
+            start,
+            increment,
+            current: start,
+            comment,
+            created_on: Utc::now(),
+        }
+    }
+
+    /// Allocate `count` values, returns the first one.
+    pub fn allocate(&mut self, count: u64) -> Result<u64> {
+        let first = self.current;
+        self.current = count
+            .checked_mul(self.increment)
+            .and_then(|n| first.checked_add(n))
+            .ok_or_else(|| {
+                ErrorCode::Overflow(format!("Sequence {} exceeds the max value", self.name))
+            })?;
+        Ok(first)
+    }
+}
+
+impl TryFrom<Vec<u8>> for SequenceMeta {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(sequence) => Ok(sequence),
+            Err(serialize_error) => Err(ErrorCode::IllegalSequenceFormat(format!(
+                "Cannot deserialize sequence from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
@@ -0,0 +1,248 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use chrono::DateTime;
+use chrono::Utc;
+use chrono_tz::Tz;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use cron::Schedule;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::principal::UserIdentity;
+
+/// When a task runs, parsed from the `SCHEDULE` of `CREATE TASK`:
+///
+/// - `'<n> SECOND'`, `'<n> MINUTE'` or `'<n> HOUR'`, every interval since the last run
+/// - `'USING CRON <expr> [<time_zone>]'`, the cron expression has 5 fields, or 6 and 7
+///   fields starting with the seconds, the time zone defaults to UTC
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub enum TaskSchedule {
+    Interval { secs: u64 },
+    Cron { expr: String, time_zone: String },
+}
+
+impl TaskSchedule {
+    pub fn parse(schedule: &str) -> Result<Self> {
+        let invalid = |reason: &str| {
+            ErrorCode::IllegalTaskFormat(format!(
+                "Invalid task schedule '{}', {}",
+                schedule, reason
+            ))
+        };
+
+        let words = schedule.split_whitespace().collect::<Vec<_>>();
+        if words.len() > 2
+            && words[0].eq_ignore_ascii_case("USING")
+            && words[1].eq_ignore_ascii_case("CRON")
+        {
+            let mut fields = words[2..].to_vec();
+            let mut time_zone = "UTC".to_string();
+            if fields.len() > 5 {
+                if let Some(tz) = fields.last().and_then(|tz| tz.parse::<Tz>().ok()) {
+                    time_zone = tz.name().to_string();
+                    fields.pop();
+                }
+            }
+            let expr = match fields.len() {
+                5 => format!("0 {}", fields.join(" ")),
+                6 | 7 => fields.join(" "),
+                _ => return Err(invalid("expect 5, 6 or 7 fields of the cron expression")),
+            };
+            Schedule::from_str(&expr).map_err(|e| invalid(&e.to_string()))?;
+            return Ok(TaskSchedule::Cron { expr, time_zone });
+        }
+
+        if let [n, unit] = words.as_slice() {
+            let n = n
+                .parse::<u64>()
+                .map_err(|_| invalid("the interval must be a positive integer"))?;
+            let unit = unit.to_ascii_uppercase();
+            let secs = match unit.trim_end_matches('S') {
+                "SECOND" => n,
+                "MINUTE" => n.saturating_mul(60),
+                "HOUR" => n.saturating_mul(3600),
+                _ => return Err(invalid("the interval unit must be SECOND, MINUTE or HOUR")),
+            };
+            if secs == 0 {
+                return Err(invalid("the interval must be a positive integer"));
+            }
+            return Ok(TaskSchedule::Interval { secs });
+        }
+
+        Err(invalid(
+            "expect '<n> SECOND | MINUTE | HOUR' or 'USING CRON <expr> [<time_zone>]'",
+        ))
+    }
+
+    /// The first time to run after `after`.
+    pub fn next_after(&self, after: DateTime<Utc>) -> Result<DateTime<Utc>> {
+        match self {
+            TaskSchedule::Interval { secs } => Ok(after + chrono::Duration::seconds(*secs as i64)),
+            TaskSchedule::Cron { expr, time_zone } => {
+                let schedule = Schedule::from_str(expr).map_err(|e| {
+                    ErrorCode::IllegalTaskFormat(format!("Invalid cron expression {}, {}", expr, e))
+                })?;
+                let tz = time_zone.parse::<Tz>().map_err(|e| {
+                    ErrorCode::IllegalTaskFormat(format!("Invalid time zone {}, {}", time_zone, e))
+                })?;
+                schedule
+                    .after(&after.with_timezone(&tz))
+                    .next()
+                    .map(|next| next.with_timezone(&Utc))
+                    .ok_or_else(|| {
+                        ErrorCode::IllegalTaskFormat(format!(
+                            "Cron expression {} has no time after {}",
+                            expr, after
+                        ))
+                    })
+            }
+        }
+    }
+}
+
+impl Display for TaskSchedule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TaskSchedule::Interval { secs } if secs % 3600 == 0 => {
+                write!(f, "{} HOUR", secs / 3600)
+            }
+            TaskSchedule::Interval { secs } if secs % 60 == 0 => write!(f, "{} MINUTE", secs / 60),
+            TaskSchedule::Interval { secs } => write!(f, "{} SECOND", secs),
+            TaskSchedule::Cron { expr, time_zone } => {
+                write!(f, "USING CRON {} {}", expr, time_zone)
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TaskState {
+    Started,
+    Suspended,
+}
+
+impl Display for TaskState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TaskState::Started => write!(f, "STARTED"),
+            TaskState::Suspended => write!(f, "SUSPENDED"),
+        }
+    }
+}
+
+/// A task runs its statement on schedule, as the user who created it and in the
+/// database current when it was created.
+///
+/// The runs are started by the scheduler of the tenant, which is elected among the
+/// query nodes, `next_scheduled_at` is advanced before each run so that a run is
+/// never started twice, even if the scheduler moves to another node.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TaskMeta {
+    pub name: String,
+    pub owner: UserIdentity,
+    pub database: String,
+    pub schedule: TaskSchedule,
+    pub sql: String,
+    /// How many times a failed run is retried.
+    pub retry_attempts: u64,
+    pub state: TaskState,
+    pub comment: String,
+    pub created_on: DateTime<Utc>,
+    pub updated_on: DateTime<Utc>,
+    /// None until the scheduler picks up the task, and while it's suspended.
+    pub next_scheduled_at: Option<DateTime<Utc>>,
+}
+
+impl TaskMeta {
+    /// Creates a task, suspended until `ALTER TASK ... RESUME` like in Snowflake.
+    pub fn new(
+        name: &str,
+        owner: UserIdentity,
+        database: String,
+        schedule: TaskSchedule,
+        sql: String,
+        retry_attempts: u64,
+        comment: String,
+    ) -> Self {
+        let now = Utc::now();
+        TaskMeta {
+            name: name.to_string(),
+            owner,
+            database,
+            schedule,
+            sql,
+            retry_attempts,
+            state: TaskState::Suspended,
+            comment,
+            created_on: now,
+            updated_on: now,
+            next_scheduled_at: None,
+        }
+    }
+}
+
+impl TryFrom<Vec<u8>> for TaskMeta {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(task) => Ok(task),
+            Err(serialize_error) => Err(ErrorCode::IllegalTaskFormat(format!(
+                "Cannot deserialize task from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TaskRunTrigger {
+    /// Started by the scheduler.
+    Schedule,
+    /// Started by `EXECUTE TASK`.
+    Execute,
+}
+
+impl Display for TaskRunTrigger {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TaskRunTrigger::Schedule => write!(f, "SCHEDULE"),
+            TaskRunTrigger::Execute => write!(f, "EXECUTE"),
+        }
+    }
+}
+
+/// A finished run of a task, `attempts` counts the retries as well.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TaskRun {
+    pub task_name: String,
+    pub trigger: TaskRunTrigger,
+    /// The id of the query of the last attempt.
+    pub query_id: String,
+    pub scheduled_at: DateTime<Utc>,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+    pub attempts: u64,
+    pub succeeded: bool,
+    pub error_code: u16,
+    pub error_message: String,
+}
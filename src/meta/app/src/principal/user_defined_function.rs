@@ -27,6 +27,39 @@ pub struct UserDefinedFunction {
 
     pub description: String,
     pub definition: String,
+
+    /// The SQL types of the parameters of a SQL UDF, empty for a lambda UDF.
+    pub arg_types: Vec<String>,
+    /// The SQL type returned by a scalar SQL UDF.
+    pub return_type: Option<String>,
+    /// The columns returned by a table SQL UDF, as pairs of name and SQL type.
+    pub return_columns: Option<Vec<(String, String)>>,
+    /// The external server that evaluates the UDF.
+    pub udf_server: Option<UDFServer>,
+    /// The fields of the intermediate state of an aggregate UDF, as pairs of
+    /// name and SQL type.
+    pub state_fields: Option<Vec<(String, String)>>,
+    /// The WebAssembly module that evaluates the UDF in-process.
+    pub wasm_module: Option<UDFWasmModule>,
+}
+
+/// An external UDF server, which evaluates the UDF over Arrow Flight.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct UDFServer {
+    pub address: String,
+    pub handler: String,
+    pub language: String,
+}
+
+/// A WebAssembly module uploaded to a stage, which evaluates the UDF in-process.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct UDFWasmModule {
+    /// The location of the module, like `@stage/path/to/module.wasm`.
+    pub location: String,
+    /// The name of the function exported by the module.
+    pub handler: String,
 }
 
 impl UserDefinedFunction {
@@ -36,8 +69,120 @@ impl UserDefinedFunction {
             parameters,
             definition: definition.to_string(),
             description: description.to_string(),
+            ..Default::default()
         }
     }
+
+    pub fn create_sql_udf(
+        name: &str,
+        arguments: Vec<(String, String)>,
+        return_type: &str,
+        definition: &str,
+        description: &str,
+    ) -> Self {
+        let (parameters, arg_types) = arguments.into_iter().unzip();
+        Self {
+            name: name.to_string(),
+            parameters,
+            definition: definition.to_string(),
+            description: description.to_string(),
+            arg_types,
+            return_type: Some(return_type.to_string()),
+            return_columns: None,
+            udf_server: None,
+            state_fields: None,
+            wasm_module: None,
+        }
+    }
+
+    pub fn create_table_udf(
+        name: &str,
+        arguments: Vec<(String, String)>,
+        return_columns: Vec<(String, String)>,
+        definition: &str,
+        description: &str,
+    ) -> Self {
+        let (parameters, arg_types) = arguments.into_iter().unzip();
+        Self {
+            name: name.to_string(),
+            parameters,
+            definition: definition.to_string(),
+            description: description.to_string(),
+            arg_types,
+            return_type: None,
+            return_columns: Some(return_columns),
+            udf_server: None,
+            state_fields: None,
+            wasm_module: None,
+        }
+    }
+
+    pub fn create_udf_server(
+        name: &str,
+        arg_types: Vec<String>,
+        return_type: &str,
+        udf_server: UDFServer,
+        description: &str,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            parameters: vec![],
+            definition: String::new(),
+            description: description.to_string(),
+            arg_types,
+            return_type: Some(return_type.to_string()),
+            return_columns: None,
+            udf_server: Some(udf_server),
+            state_fields: None,
+            wasm_module: None,
+        }
+    }
+
+    pub fn create_wasm_udf(
+        name: &str,
+        arg_types: Vec<String>,
+        return_type: &str,
+        wasm_module: UDFWasmModule,
+        description: &str,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            parameters: vec![],
+            definition: String::new(),
+            description: description.to_string(),
+            arg_types,
+            return_type: Some(return_type.to_string()),
+            return_columns: None,
+            udf_server: None,
+            state_fields: None,
+            wasm_module: Some(wasm_module),
+        }
+    }
+
+    pub fn create_udaf_server(
+        name: &str,
+        arg_types: Vec<String>,
+        state_fields: Vec<(String, String)>,
+        return_type: &str,
+        udf_server: UDFServer,
+        description: &str,
+    ) -> Self {
+        Self {
+            state_fields: Some(state_fields),
+            ..Self::create_udf_server(name, arg_types, return_type, udf_server, description)
+        }
+    }
+
+    /// Returns true if the UDF returns a table and can only be called in the
+    /// `FROM` clause.
+    pub fn is_table_function(&self) -> bool {
+        self.return_columns.is_some()
+    }
+
+    /// Returns true if the UDF is an aggregate function.
+    pub fn is_aggregate_function(&self) -> bool {
+        self.state_fields.is_some()
+    }
 }
 
 impl TryFrom<Vec<u8>> for UserDefinedFunction {
@@ -26,11 +26,12 @@ pub enum GrantObject {
     Global,
     Database(String, String),
     Table(String, String, String),
+    Column(String, String, String, String),
 }
 
 impl GrantObject {
     /// Comparing the grant objects, the Database object contains all the Table objects inside it.
-    /// Global object contains all the Database objects.
+    /// Global object contains all the Database objects, a Table object contains all its Columns.
     pub fn contains(&self, object: &GrantObject) -> bool {
         match (self, object) {
             (GrantObject::Global, _) => true,
@@ -38,14 +39,21 @@ impl GrantObject {
             (GrantObject::Database(lcat, ldb), GrantObject::Database(rcat, rdb)) => {
                 lcat == rcat && ldb == rdb
             }
-            (GrantObject::Database(lcat, ldb), GrantObject::Table(rcat, rdb, _)) => {
+            (GrantObject::Database(lcat, ldb), GrantObject::Table(rcat, rdb, _))
+            | (GrantObject::Database(lcat, ldb), GrantObject::Column(rcat, rdb, _, _)) => {
                 lcat == rcat && ldb == rdb
             }
             (
                 GrantObject::Table(lcat, lhs_db, lhs_table),
                 GrantObject::Table(rcat, rhs_db, rhs_table),
+            )
+            | (
+                GrantObject::Table(lcat, lhs_db, lhs_table),
+                GrantObject::Column(rcat, rhs_db, rhs_table, _),
             ) => lcat == rcat && (lhs_db == rhs_db) && (lhs_table == rhs_table),
             (GrantObject::Table(_, _, _), _) => false,
+            (GrantObject::Column(..), GrantObject::Column(..)) => self == object,
+            (GrantObject::Column(..), _) => false,
         }
     }
 
@@ -55,6 +63,7 @@ impl GrantObject {
             GrantObject::Global => UserPrivilegeSet::available_privileges_on_global(),
             GrantObject::Database(_, _) => UserPrivilegeSet::available_privileges_on_database(),
             GrantObject::Table(_, _, _) => UserPrivilegeSet::available_privileges_on_table(),
+            GrantObject::Column(_, _, _, _) => UserPrivilegeSet::available_privileges_on_column(),
         }
     }
 }
@@ -67,6 +76,9 @@ impl fmt::Display for GrantObject {
             GrantObject::Table(ref cat, ref db, ref table) => {
                 write!(f, "'{}'.'{}'.'{}'", cat, db, table)
             }
+            GrantObject::Column(ref cat, ref db, ref table, ref column) => {
+                write!(f, "'{}'.'{}'.'{}'('{}')", cat, db, table, column)
+            }
         }
     }
 }
@@ -121,6 +133,14 @@ impl GrantEntry {
 impl fmt::Display for GrantEntry {
     fn fmt(&self, f: &mut fmt::Formatter) -> std::result::Result<(), fmt::Error> {
         let privileges: UserPrivilegeSet = self.privileges.into();
+        // Column grants are displayed the way they are granted: `GRANT SELECT(c) ON t`.
+        if let GrantObject::Column(cat, db, table, column) = &self.object {
+            return write!(
+                f,
+                "GRANT {}({}) ON '{}'.'{}'.'{}'",
+                privileges, column, cat, db, table
+            );
+        }
         let privileges_str = if self.has_all_available_privileges() {
             "ALL".to_string()
         } else {
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use core::fmt;
+use std::collections::BTreeMap;
 use std::convert::TryFrom;
 
 use common_exception::ErrorCode;
@@ -109,6 +110,11 @@ pub struct UserOption {
     flags: BitFlags<UserOptionFlag>,
 
     default_role: Option<String>,
+
+    network_policy: Option<String>,
+
+    /// The settings applied to the sessions of the user.
+    settings: BTreeMap<String, String>,
 }
 
 impl UserOption {
@@ -116,6 +122,8 @@ impl UserOption {
         Self {
             flags,
             default_role: None,
+            network_policy: None,
+            settings: BTreeMap::new(),
         }
     }
 
@@ -133,6 +141,16 @@ impl UserOption {
         self
     }
 
+    pub fn with_network_policy(mut self, network_policy: Option<String>) -> Self {
+        self.network_policy = network_policy;
+        self
+    }
+
+    pub fn with_settings(mut self, settings: BTreeMap<String, String>) -> Self {
+        self.settings = settings;
+        self
+    }
+
     pub fn with_set_flag(mut self, flag: UserOptionFlag) -> Self {
         self.flags.insert(flag);
         self
@@ -150,6 +168,26 @@ impl UserOption {
         self.default_role = default_role;
     }
 
+    pub fn network_policy(&self) -> Option<&String> {
+        self.network_policy.as_ref()
+    }
+
+    pub fn set_network_policy(&mut self, network_policy: Option<String>) {
+        self.network_policy = network_policy;
+    }
+
+    pub fn settings(&self) -> &BTreeMap<String, String> {
+        &self.settings
+    }
+
+    pub fn set_setting(&mut self, name: String, value: String) {
+        self.settings.insert(name, value);
+    }
+
+    pub fn unset_setting(&mut self, name: &str) {
+        self.settings.remove(name);
+    }
+
     pub fn set_all_flag(&mut self) {
         self.flags = BitFlags::all();
     }
@@ -63,6 +63,8 @@ pub use table::ExtendTableLockRevReq;
 pub use table::GetTableCopiedFileReply;
 pub use table::GetTableCopiedFileReq;
 pub use table::GetTableReq;
+pub use table::ListTableCopiedFileReply;
+pub use table::ListTableCopiedFileReq;
 pub use table::ListTableLockRevReq;
 pub use table::ListTableReq;
 pub use table::RenameTableReply;
@@ -84,6 +86,8 @@ pub use table::TruncateTableReply;
 pub use table::TruncateTableReq;
 pub use table::UndropTableReply;
 pub use table::UndropTableReq;
+pub use table::UpdateMultiTableMetaReply;
+pub use table::UpdateMultiTableMetaReq;
 pub use table::UpdateTableMetaReply;
 pub use table::UpdateTableMetaReq;
 pub use table::UpsertTableCopiedFileReply;
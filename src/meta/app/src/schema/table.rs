@@ -24,6 +24,7 @@ use std::sync::Arc;
 use chrono::DateTime;
 use chrono::Utc;
 use common_exception::Result;
+use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchema;
 use common_meta_types::MatchSeq;
@@ -256,6 +257,20 @@ impl TableMeta {
         self.schema = Arc::new(new_schema);
         Ok(())
     }
+
+    pub fn rename_column(&mut self, old_column: &str, new_column: &str) -> Result<()> {
+        let mut new_schema = self.schema.as_ref().to_owned();
+        new_schema.rename_field(old_column, new_column)?;
+        self.schema = Arc::new(new_schema);
+        Ok(())
+    }
+
+    pub fn modify_column_type(&mut self, column: &str, data_type: TableDataType) -> Result<()> {
+        let mut new_schema = self.schema.as_ref().to_owned();
+        new_schema.modify_field_type(column, data_type)?;
+        self.schema = Arc::new(new_schema);
+        Ok(())
+    }
 }
 
 impl TableInfo {
@@ -613,6 +628,18 @@ pub struct UpdateTableMetaReply {
     pub share_table_info: Option<Vec<ShareTableInfoMap>>,
 }
 
+/// Updates the meta of several tables in one transaction: either all of them are
+/// updated, or none of them is.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateMultiTableMetaReq {
+    pub update_table_metas: Vec<UpdateTableMetaReq>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateMultiTableMetaReply {
+    pub share_table_info: Option<Vec<ShareTableInfoMap>>,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct GetTableReq {
     pub inner: TableNameIdent,
@@ -707,6 +734,8 @@ pub struct TableCopiedFileInfo {
     pub etag: Option<String>,
     pub content_length: u64,
     pub last_modified: Option<DateTime<Utc>>,
+    /// When the file is loaded into the table, `None` for the files copied by old versions.
+    pub load_time: Option<DateTime<Utc>>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -720,6 +749,17 @@ pub struct GetTableCopiedFileReply {
     pub file_info: BTreeMap<String, TableCopiedFileInfo>,
 }
 
+/// List all the copied files of a table that are not expired yet.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ListTableCopiedFileReq {
+    pub table_id: u64,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ListTableCopiedFileReply {
+    pub file_info: BTreeMap<String, TableCopiedFileInfo>,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct UpsertTableCopiedFileReq {
     pub file_info: BTreeMap<String, TableCopiedFileInfo>,
@@ -13,6 +13,9 @@
 //  limitations under the License.
 
 mod file_format;
+mod notification;
+mod pipe;
+mod task;
 mod user_defined_function;
 mod user_grant;
 mod user_info;
@@ -0,0 +1,95 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_exception::exception::Result;
+use common_meta_app::principal::NotificationTarget;
+
+fn options(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_notification_target_webhook() -> Result<()> {
+    let target = NotificationTarget::from_options(
+        "WEBHOOK",
+        &options(&[("url", "https://example.com/hook")]),
+    )?;
+    assert_eq!(target, NotificationTarget::Webhook {
+        url: "https://example.com/hook".to_string(),
+        method: "POST".to_string(),
+        authorization_header: "".to_string(),
+    });
+    assert_eq!(target.type_name(), "WEBHOOK");
+
+    assert!(NotificationTarget::from_options("webhook", &options(&[])).is_err());
+    assert!(
+        NotificationTarget::from_options("webhook", &options(&[("url", "example.com")])).is_err()
+    );
+    assert!(
+        NotificationTarget::from_options(
+            "webhook",
+            &options(&[("url", "http://example.com"), ("method", "GET")])
+        )
+        .is_err()
+    );
+    assert!(
+        NotificationTarget::from_options(
+            "webhook",
+            &options(&[("url", "http://example.com"), ("topic", "t")])
+        )
+        .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_notification_target_kafka_sqs() -> Result<()> {
+    let target = NotificationTarget::from_options(
+        "kafka",
+        &options(&[
+            ("rest_proxy_url", "http://proxy:8082/"),
+            ("topic", "changes"),
+        ]),
+    )?;
+    assert_eq!(target.endpoint(), "http://proxy:8082/topics/changes");
+
+    let target = NotificationTarget::from_options(
+        "sqs",
+        &options(&[
+            ("queue_url", "https://sqs.us-east-1.amazonaws.com/123/q"),
+            ("region", "us-east-1"),
+            ("access_key_id", "key"),
+            ("secret_access_key", "secret"),
+        ]),
+    )?;
+    assert_eq!(
+        target.endpoint(),
+        "https://sqs.us-east-1.amazonaws.com/123/q"
+    );
+
+    assert!(
+        NotificationTarget::from_options(
+            "sqs",
+            &options(&[("queue_url", "https://sqs.us-east-1.amazonaws.com/123/q")])
+        )
+        .is_err()
+    );
+    assert!(NotificationTarget::from_options("sns", &options(&[])).is_err());
+    Ok(())
+}
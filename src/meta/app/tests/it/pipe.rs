@@ -0,0 +1,137 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_exception::exception::Result;
+use common_meta_app::principal::GtidSet;
+use common_meta_app::principal::PipeSource;
+
+fn options(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_pipe_source_kafka() -> Result<()> {
+    let source = PipeSource::from_kafka_options(&options(&[
+        ("rest_proxy_url", "http://proxy:8082/"),
+        ("topic", "orders"),
+    ]))?;
+    assert_eq!(source, PipeSource::Kafka {
+        rest_proxy_url: "http://proxy:8082".to_string(),
+        topic: "orders".to_string(),
+        authorization_header: "".to_string(),
+    });
+    assert_eq!(source.endpoint(), "http://proxy:8082/topics/orders");
+
+    assert!(PipeSource::from_kafka_options(&options(&[("topic", "orders")])).is_err());
+    assert!(
+        PipeSource::from_kafka_options(&options(&[
+            ("rest_proxy_url", "proxy:8082"),
+            ("topic", "orders")
+        ]))
+        .is_err()
+    );
+    assert!(
+        PipeSource::from_kafka_options(&options(&[
+            ("rest_proxy_url", "http://proxy:8082"),
+            ("topic", "orders"),
+            ("group", "g")
+        ]))
+        .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_pipe_source_stage() -> Result<()> {
+    let source = PipeSource::Stage {
+        stage_name: "s1".to_string(),
+        path: "/orders/".to_string(),
+        pattern: "".to_string(),
+        file_format_options: options(&[("type", "CSV")]),
+        aws_sqs_queue_url: "".to_string(),
+    };
+    assert_eq!(source.type_name(), "STAGE");
+    assert_eq!(source.endpoint(), "@s1/orders/");
+    Ok(())
+}
+
+#[test]
+fn test_pipe_source_mysql() -> Result<()> {
+    let source = PipeSource::from_mysql_options(&options(&[
+        ("database", "shop"),
+        ("table", "orders"),
+        ("primary_key", "id, line"),
+    ]))?;
+    assert_eq!(source, PipeSource::MySql {
+        database: "shop".to_string(),
+        table: "orders".to_string(),
+        primary_key: vec!["id".to_string(), "line".to_string()],
+    });
+    assert_eq!(source.type_name(), "MYSQL");
+    assert_eq!(source.endpoint(), "shop.orders");
+
+    assert!(
+        PipeSource::from_mysql_options(&options(&[("database", "shop"), ("table", "orders")]))
+            .is_err()
+    );
+    assert!(
+        PipeSource::from_mysql_options(&options(&[
+            ("database", "shop"),
+            ("table", "orders"),
+            ("primary_key", "id"),
+            ("server_id", "1")
+        ]))
+        .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_gtid_set() -> Result<()> {
+    let uuid = "3e11fa47-71ca-11e1-9e33-c80aa9429562";
+    let mut set = GtidSet::parse(&format!(
+        "{}:1-5:7, 4E11FA47-71CA-11E1-9E33-C80AA9429562:2",
+        uuid
+    ))?;
+    assert_eq!(
+        set.to_string(),
+        format!("{}:1-5:7,4e11fa47-71ca-11e1-9e33-c80aa9429562:2", uuid)
+    );
+    assert!(set.contains(uuid, 3));
+    assert!(!set.contains(uuid, 6));
+
+    set.add(uuid, 6);
+    set.add(uuid, 9);
+    assert_eq!(
+        set.to_string(),
+        format!("{}:1-7:9,4e11fa47-71ca-11e1-9e33-c80aa9429562:2", uuid)
+    );
+    assert_eq!(GtidSet::parse(&set.to_string())?, set);
+    assert_eq!(GtidSet::parse("")?, GtidSet::default());
+    assert!(GtidSet::parse(&format!("{}:5-1", uuid)).is_err());
+    assert!(GtidSet::parse(&format!("{}:a", uuid)).is_err());
+
+    assert_eq!(
+        GtidSet::parse_gtid(&format!("{}:23", uuid.to_uppercase()))?,
+        (uuid.to_string(), 23)
+    );
+    assert!(GtidSet::parse_gtid(uuid).is_err());
+    assert!(GtidSet::parse_gtid(&format!("{}:0", uuid)).is_err());
+    Ok(())
+}
@@ -0,0 +1,74 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use common_exception::exception::Result;
+use common_meta_app::principal::TaskSchedule;
+
+#[test]
+fn test_task_schedule_interval() -> Result<()> {
+    let schedule = TaskSchedule::parse("5 minute")?;
+    assert_eq!(schedule, TaskSchedule::Interval { secs: 300 });
+    assert_eq!(schedule.to_string(), "5 MINUTE");
+    assert_eq!(TaskSchedule::parse("2 HOURS")?, TaskSchedule::Interval {
+        secs: 7200
+    });
+
+    let now = Utc.with_ymd_and_hms(2023, 6, 1, 10, 0, 0).unwrap();
+    assert_eq!(
+        schedule.next_after(now)?,
+        Utc.with_ymd_and_hms(2023, 6, 1, 10, 5, 0).unwrap()
+    );
+
+    assert!(TaskSchedule::parse("0 SECOND").is_err());
+    assert!(TaskSchedule::parse("5 DAY").is_err());
+    assert!(TaskSchedule::parse("MINUTE").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_task_schedule_cron() -> Result<()> {
+    let schedule = TaskSchedule::parse("USING CRON 30 9 * * * Asia/Shanghai")?;
+    assert_eq!(schedule, TaskSchedule::Cron {
+        expr: "0 30 9 * * *".to_string(),
+        time_zone: "Asia/Shanghai".to_string(),
+    });
+    assert_eq!(
+        schedule.to_string(),
+        "USING CRON 0 30 9 * * * Asia/Shanghai"
+    );
+
+    // 09:30 in Shanghai is 01:30 in UTC.
+    let now = Utc.with_ymd_and_hms(2023, 6, 1, 10, 0, 0).unwrap();
+    assert_eq!(
+        schedule.next_after(now)?,
+        Utc.with_ymd_and_hms(2023, 6, 2, 1, 30, 0).unwrap()
+    );
+
+    // 6 fields starting with the seconds, in UTC.
+    let schedule = TaskSchedule::parse("using cron */10 * * * * *")?;
+    assert_eq!(schedule, TaskSchedule::Cron {
+        expr: "*/10 * * * * *".to_string(),
+        time_zone: "UTC".to_string(),
+    });
+    assert_eq!(
+        schedule.next_after(now)?,
+        Utc.with_ymd_and_hms(2023, 6, 1, 10, 0, 10).unwrap()
+    );
+
+    assert!(TaskSchedule::parse("USING CRON * * *").is_err());
+    assert!(TaskSchedule::parse("USING CRON 61 * * * *").is_err());
+    Ok(())
+}
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use common_exception::exception::Result;
+use common_meta_app::principal::UDFServer;
+use common_meta_app::principal::UDFWasmModule;
 use common_meta_app::principal::UserDefinedFunction;
 
 #[test]
@@ -30,3 +32,89 @@ fn test_udf() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_sql_udf() -> Result<()> {
+    let udf = UserDefinedFunction::create_sql_udf(
+        "add_one",
+        vec![("x".to_string(), "INT32".to_string())],
+        "INT64",
+        "x + 1",
+        "",
+    );
+    let de = UserDefinedFunction::try_from(serde_json::to_vec(&udf)?)?;
+    assert_eq!(udf, de);
+    assert!(!de.is_table_function());
+
+    let udf = UserDefinedFunction::create_table_udf(
+        "numbers_of",
+        vec![("n".to_string(), "UINT64".to_string())],
+        vec![("number".to_string(), "UINT64".to_string())],
+        "SELECT number FROM numbers(n)",
+        "",
+    );
+    let de = UserDefinedFunction::try_from(serde_json::to_vec(&udf)?)?;
+    assert_eq!(udf, de);
+    assert!(de.is_table_function());
+
+    let udf = UserDefinedFunction::create_udf_server(
+        "gcd",
+        vec!["INT32".to_string(), "INT32".to_string()],
+        "INT32",
+        UDFServer {
+            address: "http://127.0.0.1:8815".to_string(),
+            handler: "gcd".to_string(),
+            language: "python".to_string(),
+        },
+        "",
+    );
+    let de = UserDefinedFunction::try_from(serde_json::to_vec(&udf)?)?;
+    assert_eq!(udf, de);
+    assert!(!de.is_aggregate_function());
+
+    let udf = UserDefinedFunction::create_udaf_server(
+        "weighted_avg",
+        vec!["INT32".to_string(), "INT32".to_string()],
+        vec![
+            ("sum".to_string(), "INT64".to_string()),
+            ("weight".to_string(), "INT64".to_string()),
+        ],
+        "FLOAT64",
+        UDFServer {
+            address: "http://127.0.0.1:8815".to_string(),
+            handler: "weighted_avg".to_string(),
+            language: "python".to_string(),
+        },
+        "",
+    );
+    let de = UserDefinedFunction::try_from(serde_json::to_vec(&udf)?)?;
+    assert_eq!(udf, de);
+    assert!(de.is_aggregate_function());
+
+    let udf = UserDefinedFunction::create_wasm_udf(
+        "gcd",
+        vec!["INT32".to_string(), "INT32".to_string()],
+        "INT32",
+        UDFWasmModule {
+            location: "@udfs/gcd.wasm".to_string(),
+            handler: "gcd".to_string(),
+        },
+        "",
+    );
+    let de = UserDefinedFunction::try_from(serde_json::to_vec(&udf)?)?;
+    assert_eq!(udf, de);
+    assert_eq!(de.udf_server, None);
+    assert!(!de.is_aggregate_function());
+
+    // A lambda UDF stored before SQL UDFs were supported.
+    let old = r#"{"name":"is_not_null","parameters":["p"],"description":"","definition":"not(is_null(p))"}"#;
+    let de = UserDefinedFunction::try_from(old.as_bytes().to_vec())?;
+    assert!(de.arg_types.is_empty());
+    assert_eq!(de.return_type, None);
+    assert_eq!(de.udf_server, None);
+    assert_eq!(de.wasm_module, None);
+    assert!(!de.is_aggregate_function());
+    assert!(!de.is_table_function());
+
+    Ok(())
+}
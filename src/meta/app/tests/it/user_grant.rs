@@ -77,6 +77,26 @@ fn test_grant_object_contains() -> Result<()> {
             rhs: GrantObject::Database("default".into(), "db1".into()),
             expect: false,
         },
+        Test {
+            lhs: GrantObject::Table("default".into(), "db1".into(), "c".into()),
+            rhs: GrantObject::Column("default".into(), "db1".into(), "c".into(), "x".into()),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::Database("default".into(), "db1".into()),
+            rhs: GrantObject::Column("default".into(), "db1".into(), "c".into(), "x".into()),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::Column("default".into(), "db1".into(), "c".into(), "x".into()),
+            rhs: GrantObject::Column("default".into(), "db1".into(), "c".into(), "y".into()),
+            expect: false,
+        },
+        Test {
+            lhs: GrantObject::Column("default".into(), "db1".into(), "c".into(), "x".into()),
+            rhs: GrantObject::Table("default".into(), "db1".into(), "c".into()),
+            expect: false,
+        },
     ];
     for t in tests {
         assert_eq!(
@@ -91,6 +111,19 @@ fn test_grant_object_contains() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_column_grant_entry_display() -> Result<()> {
+    let grant = GrantEntry::new(
+        GrantObject::Column("default".into(), "db1".into(), "t1".into(), "c1".into()),
+        make_bitflags!(UserPrivilegeType::{Select}),
+    );
+    assert_eq!(
+        grant.to_string(),
+        "GRANT SELECT(c1) ON 'default'.'db1'.'t1'"
+    );
+    Ok(())
+}
+
 #[test]
 fn test_user_grant_entry() -> Result<()> {
     let grant = GrantEntry::new(
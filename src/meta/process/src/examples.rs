@@ -61,7 +61,7 @@ impl Default for RaftConfig {
 async fn upgrade_09() -> anyhow::Result<()> {
     let config = Config::parse();
 
-    let _guards = init_logging("databend-meta-upgrade-09", &LogConfig::default());
+    let _guards = init_logging("databend-meta-upgrade-09", &LogConfig::default())?;
 
     eprintln!("config: {}", pretty(&config)?);
 
@@ -76,6 +76,11 @@ impl FromToProto for ex::TableField {
             None => None,
         };
 
+        let mut origins = Vec::with_capacity(p.origins.len());
+        for origin in p.origins {
+            origins.push(ex::ColumnOrigin::from_pb(origin)?);
+        }
+
         let v = ex::TableField::new_from_column_id(
             &p.name,
             ex::TableDataType::from_pb(p.data_type.ok_or_else(|| Incompatible {
@@ -84,7 +89,8 @@ impl FromToProto for ex::TableField {
             p.column_id,
         )
         .with_default_expr(p.default_expr)
-        .with_computed_expr(computed_expr);
+        .with_computed_expr(computed_expr)
+        .with_origins(origins);
         Ok(v)
     }
 
@@ -93,6 +99,10 @@ impl FromToProto for ex::TableField {
             Some(computed_expr) => Some(computed_expr.to_pb()?),
             None => None,
         };
+        let mut origins = Vec::with_capacity(self.origins().len());
+        for origin in self.origins() {
+            origins.push(origin.to_pb()?);
+        }
         let p = pb::DataField {
             ver: VER,
             min_reader_ver: MIN_READER_VER,
@@ -101,6 +111,35 @@ impl FromToProto for ex::TableField {
             data_type: Some(self.data_type().to_pb()?),
             column_id: self.column_id(),
             computed_expr,
+            origins,
+        };
+        Ok(p)
+    }
+}
+
+impl FromToProto for ex::ColumnOrigin {
+    type PB = pb::ColumnOrigin;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::ColumnOrigin) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let v = ex::ColumnOrigin {
+            column_id: p.column_id,
+            data_type: ex::TableDataType::from_pb(p.data_type.ok_or_else(|| Incompatible {
+                reason: "ColumnOrigin.data_type can not be None".to_string(),
+            })?)?,
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::ColumnOrigin, Incompatible> {
+        let p = pb::ColumnOrigin {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            column_id: self.column_id,
+            data_type: Some(self.data_type.to_pb()?),
         };
         Ok(p)
     }
@@ -246,6 +285,8 @@ impl FromToProto for ex::TableDataType {
                         Box::into_inner(x),
                     )?)),
                     Dt24::BitmapT(_) => ex::TableDataType::Bitmap,
+                    Dt24::GeometryT(_) => ex::TableDataType::Geometry,
+                    Dt24::BinaryT(_) => ex::TableDataType::Binary,
                     Dt24::TupleT(t) => {
                         reader_check_msg(t.ver, t.min_reader_ver)?;
 
@@ -305,6 +346,8 @@ impl FromToProto for ex::TableDataType {
                 new_pb_dt24(Dt24::MapT(Box::new(x)))
             }
             TableDataType::Bitmap => new_pb_dt24(Dt24::BitmapT(pb::Empty {})),
+            TableDataType::Geometry => new_pb_dt24(Dt24::GeometryT(pb::Empty {})),
+            TableDataType::Binary => new_pb_dt24(Dt24::BinaryT(pb::Empty {})),
             TableDataType::Tuple {
                 fields_name,
                 fields_type,
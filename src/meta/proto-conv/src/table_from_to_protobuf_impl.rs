@@ -47,6 +47,10 @@ impl FromToProto for mt::TableCopiedFileInfo {
                 None => None,
                 Some(last_modified) => Some(DateTime::<Utc>::from_pb(last_modified)?),
             },
+            load_time: match p.load_time {
+                None => None,
+                Some(load_time) => Some(DateTime::<Utc>::from_pb(load_time)?),
+            },
         };
         Ok(v)
     }
@@ -61,6 +65,10 @@ impl FromToProto for mt::TableCopiedFileInfo {
                 None => None,
                 Some(last_modified) => Some(last_modified.to_pb()?),
             },
+            load_time: match self.load_time {
+                None => None,
+                Some(load_time) => Some(load_time.to_pb()?),
+            },
         };
         Ok(p)
     }
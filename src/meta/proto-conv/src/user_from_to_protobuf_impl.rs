@@ -96,7 +96,9 @@ impl FromToProto for mt::principal::UserOption {
 
         Ok(mt::principal::UserOption::default()
             .with_flags(flags)
-            .with_default_role(p.default_role))
+            .with_default_role(p.default_role)
+            .with_network_policy(p.network_policy)
+            .with_settings(p.settings))
     }
 
     fn to_pb(&self) -> Result<pb::UserOption, Incompatible> {
@@ -105,6 +107,8 @@ impl FromToProto for mt::principal::UserOption {
             min_reader_ver: MIN_READER_VER,
             flags: self.flags().bits(),
             default_role: self.default_role().cloned(),
+            network_policy: self.network_policy().cloned(),
+            settings: self.settings().clone(),
         })
     }
 }
@@ -158,6 +162,14 @@ impl FromToProto for mt::principal::GrantObject {
                 db,
                 table,
             })) => Ok(mt::principal::GrantObject::Table(catalog, db, table)),
+            Some(pb::grant_object::Object::Column(pb::grant_object::GrantColumnObject {
+                catalog,
+                db,
+                table,
+                column,
+            })) => Ok(mt::principal::GrantObject::Column(
+                catalog, db, table, column,
+            )),
             _ => Err(Incompatible {
                 reason: "GrantObject cannot be None".to_string(),
             }),
@@ -182,6 +194,14 @@ impl FromToProto for mt::principal::GrantObject {
                     table: table.clone(),
                 }),
             ),
+            mt::principal::GrantObject::Column(catalog, db, table, column) => Some(
+                pb::grant_object::Object::Column(pb::grant_object::GrantColumnObject {
+                    catalog: catalog.clone(),
+                    db: db.clone(),
+                    table: table.clone(),
+                    column: column.clone(),
+                }),
+            ),
         };
         Ok(pb::GrantObject {
             ver: VER,
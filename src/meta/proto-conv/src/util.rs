@@ -74,7 +74,16 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (42, "2023-06-03: Add allow_anonymous in S3 Config", ),
     (43, "2023-06-05: Add fields `number_of_segments` and `number_of_blocks` to TableStatistics", ),
     (44, "2023-06-07: Add: metadata.proto/ComputedExpr", ),
-    (45, "2023-06-06: Add: background_tasks.proto and background_jobs.proto", )
+    (45, "2023-06-06: Add: background_tasks.proto and background_jobs.proto", ),
+    (46, "2023-06-09: Add: metadata.proto/DataField::origins", ),
+    (47, "2023-06-12: Add: datatype.proto/DataType Geometry type", ),
+    (48, "2023-06-14: Add: file_format.proto/AvroFileFormatParams", ),
+    (49, "2023-06-15: Add: file_format.proto/OrcFileFormatParams", ),
+    (50, "2023-06-16: Add: user.proto/GrantColumnObject", ),
+    (51, "2023-06-19: Add: user.proto/UserOption::network_policy", ),
+    (52, "2023-06-21: Add: table.proto/TableCopiedFileInfo::load_time", ),
+    (53, "2023-06-22: Add: user.proto/UserOption::settings", ),
+    (54, "2023-06-23: Add: datatype.proto/DataType Binary type", )
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
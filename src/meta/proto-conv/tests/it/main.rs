@@ -50,3 +50,12 @@ mod v042_s3_stage_new_field;
 mod v043_table_statistics;
 mod v044_table_meta;
 mod v045_background;
+mod v046_column_origin;
+mod v047_geometry_type;
+mod v048_avro_file_format_params;
+mod v049_orc_file_format_params;
+mod v050_grant_column_object;
+mod v051_user_option_network_policy;
+mod v052_copied_file_info_load_time;
+mod v053_user_option_settings;
+mod v054_binary_type;
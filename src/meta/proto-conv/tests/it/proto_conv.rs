@@ -242,6 +242,8 @@ pub(crate) fn new_latest_schema() -> TableSchema {
         ),
         TableField::new("empty_map", TableDataType::EmptyMap),
         TableField::new("bitmap", TableDataType::Bitmap),
+        TableField::new("geometry", TableDataType::Geometry),
+        TableField::new("binary", TableDataType::Binary),
     ];
     TableSchema::new(fields)
 }
@@ -251,6 +253,7 @@ pub(crate) fn new_table_copied_file_info_v6() -> mt::TableCopiedFileInfo {
         etag: Some("etag".to_string()),
         content_length: 1024,
         last_modified: Some(Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 9).unwrap()),
+        load_time: None,
     }
 }
 
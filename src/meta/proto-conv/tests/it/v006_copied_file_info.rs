@@ -38,6 +38,7 @@ fn test_decode_v6_copied_file_info() -> anyhow::Result<()> {
         etag: Some("etag".to_string()),
         content_length: 1024,
         last_modified: Some(Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 9).unwrap()),
+        load_time: None,
     };
 
     common::test_pb_from_to(func_name!(), want())?;
@@ -0,0 +1,73 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression as ce;
+use common_expression::types::NumberDataType;
+use maplit::btreemap;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_build_pb_buf()`
+#[test]
+fn test_decode_v46_column_origin() -> anyhow::Result<()> {
+    let bytes = vec![
+        10, 95, 10, 1, 97, 26, 26, 178, 2, 17, 154, 2, 8, 66, 0, 160, 6, 46, 168, 6, 24, 160, 6,
+        46, 168, 6, 24, 160, 6, 46, 168, 6, 24, 32, 2, 50, 27, 8, 1, 18, 17, 154, 2, 8, 66, 0, 160,
+        6, 46, 168, 6, 24, 160, 6, 46, 168, 6, 24, 160, 6, 46, 168, 6, 24, 50, 25, 18, 17, 154, 2,
+        8, 58, 0, 160, 6, 46, 168, 6, 24, 160, 6, 46, 168, 6, 24, 160, 6, 46, 168, 6, 24, 160, 6,
+        46, 168, 6, 24, 10, 22, 10, 1, 98, 26, 9, 146, 2, 0, 160, 6, 46, 168, 6, 24, 32, 3, 160, 6,
+        46, 168, 6, 24, 24, 4, 160, 6, 46, 168, 6, 24,
+    ];
+
+    let want = || {
+        ce::TableSchema::new_from_column_ids(
+            vec![
+                ce::TableField::new_from_column_id(
+                    "a",
+                    ce::TableDataType::Nullable(Box::new(ce::TableDataType::Number(
+                        NumberDataType::Int64,
+                    ))),
+                    2,
+                )
+                .with_origins(vec![
+                    ce::ColumnOrigin {
+                        column_id: 1,
+                        data_type: ce::TableDataType::Number(NumberDataType::Int64),
+                    },
+                    ce::ColumnOrigin {
+                        column_id: 0,
+                        data_type: ce::TableDataType::Number(NumberDataType::Int32),
+                    },
+                ]),
+                ce::TableField::new_from_column_id("b", ce::TableDataType::String, 3),
+            ],
+            btreemap! {},
+            4,
+        )
+    };
+
+    common::test_load_old(func_name!(), bytes.as_slice(), 46, want())?;
+    common::test_pb_from_to(func_name!(), want())?;
+
+    Ok(())
+}
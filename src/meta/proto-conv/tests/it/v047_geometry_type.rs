@@ -0,0 +1,44 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_build_pb_buf()`
+#[test]
+fn test_decode_v47_geometry_type() -> anyhow::Result<()> {
+    let bytes = vec![
+        10, 23, 10, 4, 103, 101, 111, 109, 26, 9, 242, 2, 0, 160, 6, 47, 168, 6, 24, 160, 6, 47,
+        168, 6, 24, 24, 1, 160, 6, 47, 168, 6, 24,
+    ];
+
+    let want = || TableSchema::new(vec![TableField::new("geom", TableDataType::Geometry)]);
+
+    common::test_load_old(func_name!(), bytes.as_slice(), 47, want())?;
+    common::test_pb_from_to(func_name!(), want())?;
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_meta_app as mt;
+use common_meta_app::principal::UserOptionFlag;
+use enumflags2::make_bitflags;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_build_pb_buf()`
+#[test]
+fn test_decode_v51_user_option_network_policy() -> anyhow::Result<()> {
+    let user_option_v51 = vec![
+        8, 1, 18, 5, 114, 111, 108, 101, 49, 26, 13, 111, 102, 102, 105, 99, 101, 95, 112, 111,
+        108, 105, 99, 121, 160, 6, 51, 168, 6, 24,
+    ];
+
+    let want = || {
+        mt::principal::UserOption::default()
+            .with_flags(make_bitflags!(UserOptionFlag::{TenantSetting}))
+            .with_default_role(Some("role1".to_string()))
+            .with_network_policy(Some("office_policy".to_string()))
+    };
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), user_option_v51.as_slice(), 51, want())
+}
@@ -45,7 +45,8 @@ macro_rules! init_raft_store_ut {
         common_meta_sled_store::init_temp_sled_db(t);
 
         let guards =
-            common_tracing::init_logging("meta_unittests", &common_tracing::Config::new_testing());
+            common_tracing::init_logging("meta_unittests", &common_tracing::Config::new_testing())
+                .unwrap();
 
         guards
     }};
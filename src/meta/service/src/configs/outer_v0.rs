@@ -573,6 +573,7 @@ impl Into<InnerLogConfig> for LogConfig {
         InnerLogConfig {
             file: self.file.into(),
             stderr: self.stderr.into(),
+            tracing: Default::default(),
         }
     }
 }
@@ -28,7 +28,8 @@ macro_rules! init_sled_ut {
 
         common_meta_sled_store::init_temp_sled_db(t);
         let guards =
-            common_tracing::init_logging("sled_unittests", &common_tracing::Config::new_testing());
+            common_tracing::init_logging("sled_unittests", &common_tracing::Config::new_testing())
+                .unwrap();
 
         let name = common_tracing::func_name!();
         let span = tracing::debug_span!("ut", "{}", name.split("::").last().unwrap());
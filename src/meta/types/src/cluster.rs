@@ -79,6 +79,8 @@ pub struct NodeInfo {
     pub version: u32,
     pub flight_address: String,
     pub binary_version: String,
+    /// The node is draining before leaving the cluster, new queries are not scheduled on it.
+    pub draining: bool,
 }
 
 impl NodeInfo {
@@ -94,6 +96,7 @@ impl NodeInfo {
             version: 0,
             flight_address,
             binary_version,
+            draining: false,
         }
     }
 
@@ -180,6 +180,8 @@ pub enum Expr {
         args: Vec<Expr>,
         params: Vec<Literal>,
         window: Option<Window>,
+        /// The lambda argument of higher-order functions, like `array_filter(arr, x -> x > 1)`
+        lambda: Option<Lambda>,
     },
     /// `CASE ... WHEN ... ELSE ...` expression
     Case {
@@ -255,6 +257,8 @@ pub enum Literal {
     },
     // Quoted string literal value
     String(String),
+    // Hex string literal value, e.g. `x'0AFF'`
+    Binary(Vec<u8>),
     Boolean(bool),
     CurrentTimestamp,
     Null,
@@ -306,6 +310,8 @@ pub enum TypeName {
         fields_type: Vec<TypeName>,
     },
     Variant,
+    Geometry,
+    Binary,
     Nullable(Box<TypeName>),
 }
 
@@ -363,6 +369,14 @@ pub struct WindowFrame {
 pub enum WindowFrameUnits {
     Rows,
     Range,
+    Groups,
+}
+
+/// `x -> x + 1` or `(acc, x) -> acc + x`, only used as the last argument of higher-order functions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lambda {
+    pub params: Vec<Identifier>,
+    pub expr: Box<Expr>,
 }
 
 /// Specifies [WindowFrame]'s `start_bound` and `end_bound`
@@ -749,6 +763,12 @@ impl Display for TypeName {
             TypeName::Variant => {
                 write!(f, "VARIANT")?;
             }
+            TypeName::Geometry => {
+                write!(f, "GEOMETRY")?;
+            }
+            TypeName::Binary => {
+                write!(f, "BINARY")?;
+            }
             TypeName::Nullable(ty) => {
                 write!(f, "{} NULL", ty)?;
             }
@@ -782,6 +802,13 @@ impl Display for Literal {
             Literal::String(val) => {
                 write!(f, "\'{}\'", escape_string_with_quote(val, Some('\'')))
             }
+            Literal::Binary(val) => {
+                write!(f, "X'")?;
+                for b in val {
+                    write!(f, "{b:02X}")?;
+                }
+                write!(f, "'")
+            }
             Literal::Boolean(val) => {
                 if *val {
                     write!(f, "TRUE")
@@ -815,6 +842,19 @@ impl Display for Window {
     }
 }
 
+impl Display for Lambda {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.params.len() == 1 {
+            write!(f, "{}", self.params[0])?;
+        } else {
+            write!(f, "(")?;
+            write_comma_separated_list(f, &self.params)?;
+            write!(f, ")")?;
+        }
+        write!(f, " -> {}", self.expr)
+    }
+}
+
 impl Display for WindowRef {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "WINDOW {}", self.window_name)
@@ -860,6 +900,9 @@ impl Display for WindowSpec {
                 WindowFrameUnits::Range => {
                     write!(f, "RANGE")?;
                 }
+                WindowFrameUnits::Groups => {
+                    write!(f, "GROUPS")?;
+                }
             }
 
             let format_frame = |frame: &WindowFrameBound| -> String {
@@ -1043,6 +1086,7 @@ impl Display for Expr {
                 args,
                 params,
                 window,
+                lambda,
                 ..
             } => {
                 write!(f, "{name}")?;
@@ -1056,6 +1100,12 @@ impl Display for Expr {
                     write!(f, "DISTINCT ")?;
                 }
                 write_comma_separated_list(f, args)?;
+                if let Some(lambda) = lambda {
+                    if !args.is_empty() {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{lambda}")?;
+                }
                 write!(f, ")")?;
 
                 if let Some(window) = window {
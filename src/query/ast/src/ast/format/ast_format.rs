@@ -95,6 +95,148 @@ impl AstFormatVisitor {
     pub fn new() -> Self {
         Self { children: vec![] }
     }
+
+    fn format_udf_arguments(
+        name: &str,
+        arguments: &[UDFArgument],
+    ) -> Option<FormatTreeNode<AstFormatContext>> {
+        if arguments.is_empty() {
+            return None;
+        }
+        let arguments_children = arguments
+            .iter()
+            .map(|argument| FormatTreeNode::new(AstFormatContext::new(argument.to_string())))
+            .collect::<Vec<_>>();
+        let format_ctx =
+            AstFormatContext::with_children(name.to_string(), arguments_children.len());
+        Some(FormatTreeNode::with_children(
+            format_ctx,
+            arguments_children,
+        ))
+    }
+
+    fn format_udf_arg_types(arg_types: &[TypeName]) -> Option<FormatTreeNode<AstFormatContext>> {
+        if arg_types.is_empty() {
+            return None;
+        }
+        let arg_types_children = arg_types
+            .iter()
+            .map(|arg_type| FormatTreeNode::new(AstFormatContext::new(arg_type.to_string())))
+            .collect::<Vec<_>>();
+        let format_ctx =
+            AstFormatContext::with_children("UdfArgTypes".to_string(), arg_types_children.len());
+        Some(FormatTreeNode::with_children(
+            format_ctx,
+            arg_types_children,
+        ))
+    }
+
+    fn format_udf_definition(
+        &mut self,
+        definition: &UDFDefinition,
+    ) -> Vec<FormatTreeNode<AstFormatContext>> {
+        let mut children = Vec::new();
+        let definition_child = match definition {
+            UDFDefinition::LambdaUDF {
+                parameters,
+                definition,
+            } => {
+                if !parameters.is_empty() {
+                    let mut parameters_children = Vec::with_capacity(parameters.len());
+                    for parameter in parameters.iter() {
+                        self.visit_identifier(parameter);
+                        parameters_children.push(self.children.pop().unwrap());
+                    }
+                    let parameters_name = "UdfParameters".to_string();
+                    let parameters_format_ctx =
+                        AstFormatContext::with_children(parameters_name, parameters_children.len());
+                    children.push(FormatTreeNode::with_children(
+                        parameters_format_ctx,
+                        parameters_children,
+                    ));
+                }
+                self.visit_expr(definition);
+                self.children.pop().unwrap()
+            }
+            UDFDefinition::ScalarUDF {
+                arguments,
+                return_type,
+                definition,
+            } => {
+                children.extend(Self::format_udf_arguments("UdfArguments", arguments));
+                let return_type_format_ctx =
+                    AstFormatContext::new(format!("UdfReturnType {}", return_type));
+                children.push(FormatTreeNode::new(return_type_format_ctx));
+                self.visit_expr(definition);
+                self.children.pop().unwrap()
+            }
+            UDFDefinition::TableUDF {
+                arguments,
+                return_columns,
+                definition,
+            } => {
+                children.extend(Self::format_udf_arguments("UdfArguments", arguments));
+                children.extend(Self::format_udf_arguments(
+                    "UdfReturnColumns",
+                    return_columns,
+                ));
+                self.visit_query(definition);
+                self.children.pop().unwrap()
+            }
+            UDFDefinition::UDFServer {
+                arg_types,
+                return_type,
+                address,
+                handler,
+                language,
+            } => {
+                children.extend(Self::format_udf_arg_types(arg_types));
+                let return_type_format_ctx =
+                    AstFormatContext::new(format!("UdfReturnType {}", return_type));
+                children.push(FormatTreeNode::new(return_type_format_ctx));
+                FormatTreeNode::new(AstFormatContext::new(format!(
+                    "UdfServer {language} {handler} {address}"
+                )))
+            }
+            UDFDefinition::UDAFServer {
+                arg_types,
+                state_fields,
+                return_type,
+                address,
+                handler,
+                language,
+            } => {
+                children.extend(Self::format_udf_arg_types(arg_types));
+                children.extend(Self::format_udf_arguments("UdafStateFields", state_fields));
+                let return_type_format_ctx =
+                    AstFormatContext::new(format!("UdfReturnType {}", return_type));
+                children.push(FormatTreeNode::new(return_type_format_ctx));
+                FormatTreeNode::new(AstFormatContext::new(format!(
+                    "UdafServer {language} {handler} {address}"
+                )))
+            }
+            UDFDefinition::WasmUDF {
+                arg_types,
+                return_type,
+                handler,
+                location,
+            } => {
+                children.extend(Self::format_udf_arg_types(arg_types));
+                let return_type_format_ctx =
+                    AstFormatContext::new(format!("UdfReturnType {}", return_type));
+                children.push(FormatTreeNode::new(return_type_format_ctx));
+                FormatTreeNode::new(AstFormatContext::new(format!(
+                    "WasmUdf {handler} {location}"
+                )))
+            }
+        };
+        let definition_name = "UdfDefinition".to_string();
+        let definition_format_ctx = AstFormatContext::with_children(definition_name, 1);
+        children.push(FormatTreeNode::with_children(definition_format_ctx, vec![
+            definition_child,
+        ]));
+        children
+    }
 }
 
 impl<'ast> Visitor<'ast> for AstFormatVisitor {
@@ -431,12 +573,30 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         args: &'ast [Expr],
         _params: &'ast [Literal],
         _over: &'ast Option<Window>,
+        lambda: &'ast Option<Lambda>,
     ) {
-        let mut children = Vec::with_capacity(args.len());
+        let mut children = Vec::with_capacity(args.len() + 1);
         for arg in args.iter() {
             self.visit_expr(arg);
             children.push(self.children.pop().unwrap());
         }
+        if let Some(lambda) = lambda {
+            self.visit_expr(&lambda.expr);
+            let lambda_child = self.children.pop().unwrap();
+            let lambda_name = format!(
+                "Lambda ({})",
+                lambda
+                    .params
+                    .iter()
+                    .map(|param| param.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+            let lambda_format_ctx = AstFormatContext::with_children(lambda_name, 1);
+            children.push(FormatTreeNode::with_children(lambda_format_ctx, vec![
+                lambda_child,
+            ]));
+        }
         let node_name = if distinct {
             format!("Function {name}Distinct")
         } else {
@@ -769,6 +929,33 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         let disable_variant_check_node = FormatTreeNode::new(disable_variant_check_ctx);
         children.push(disable_variant_check_node);
 
+        if let Some(match_by_column_name) = &copy.match_by_column_name {
+            let match_by_column_name = format!("MatchByColumnName {}", match_by_column_name);
+            let match_by_column_name_ctx = AstFormatContext::new(match_by_column_name);
+            let match_by_column_name_node = FormatTreeNode::new(match_by_column_name_ctx);
+            children.push(match_by_column_name_node);
+        }
+        if copy.error_on_column_count_mismatch {
+            let error_on_mismatch_ctx =
+                AstFormatContext::new("ErrorOnColumnCountMismatch true".to_string());
+            let error_on_mismatch_node = FormatTreeNode::new(error_on_mismatch_ctx);
+            children.push(error_on_mismatch_node);
+        }
+
+        if let Some(partition_by) = &copy.partition_by {
+            let partition_by_name = format!(
+                "PartitionBy {}",
+                partition_by
+                    .iter()
+                    .map(|t| t.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+            let partition_by_ctx = AstFormatContext::new(partition_by_name);
+            let partition_by_node = FormatTreeNode::new(partition_by_ctx);
+            children.push(partition_by_node);
+        }
+
         let name = "Copy".to_string();
         let format_ctx = AstFormatContext::with_children(name, children.len());
         let node = FormatTreeNode::with_children(format_ctx, children);
@@ -1111,6 +1298,36 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_backup_database(&mut self, stmt: &'ast BackupDatabaseStmt) {
+        self.visit_database_ref(&stmt.catalog, &stmt.database);
+        let database_child = self.children.pop().unwrap();
+        let location_format_ctx = AstFormatContext::new(format!("Location @{}", stmt.location));
+        let location_child = FormatTreeNode::new(location_format_ctx);
+        let with_data_format_ctx = AstFormatContext::new(format!("WithData {}", stmt.with_data));
+        let with_data_child = FormatTreeNode::new(with_data_format_ctx);
+
+        let name = "BackupDatabase".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 3);
+        let node = FormatTreeNode::with_children(format_ctx, vec![
+            database_child,
+            location_child,
+            with_data_child,
+        ]);
+        self.children.push(node);
+    }
+
+    fn visit_restore_database(&mut self, stmt: &'ast RestoreDatabaseStmt) {
+        self.visit_database_ref(&stmt.catalog, &stmt.database);
+        let database_child = self.children.pop().unwrap();
+        let location_format_ctx = AstFormatContext::new(format!("Location @{}", stmt.location));
+        let location_child = FormatTreeNode::new(location_format_ctx);
+
+        let name = "RestoreDatabase".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![database_child, location_child]);
+        self.children.push(node);
+    }
+
     fn visit_alter_database(&mut self, stmt: &'ast AlterDatabaseStmt) {
         self.visit_database_ref(&stmt.catalog, &stmt.database);
         let database_child = self.children.pop().unwrap();
@@ -1216,6 +1433,24 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_show_drop_tables(&mut self, stmt: &'ast ShowDropTablesStmt) {
+        let mut children = Vec::new();
+        if let Some(database) = &stmt.database {
+            let database_name = format!("Database {}", database);
+            let database_format_ctx = AstFormatContext::new(database_name);
+            let database_node = FormatTreeNode::new(database_format_ctx);
+            children.push(database_node);
+        }
+        if let Some(limit) = &stmt.limit {
+            self.visit_show_limit(limit);
+            children.push(self.children.pop().unwrap());
+        }
+        let name = "ShowDropTables".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
     fn visit_create_table(&mut self, stmt: &'ast CreateTableStmt) {
         let mut children = Vec::new();
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
@@ -1270,6 +1505,48 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_attach_table(&mut self, stmt: &'ast AttachTableStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+        let location_format_ctx =
+            AstFormatContext::new(format!("UriLocation {}", stmt.uri_location));
+        let location_child = FormatTreeNode::new(location_format_ctx);
+
+        let name = "AttachTable".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![table_child, location_child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_external_table(&mut self, stmt: &'ast CreateExternalTableStmt) {
+        let mut children = Vec::new();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        children.push(self.children.pop().unwrap());
+        for (name, columns) in [
+            ("ColumnsDefinition", &stmt.columns),
+            ("PartitionedBy", &stmt.partitioned_by),
+        ] {
+            if columns.is_empty() {
+                continue;
+            }
+            let mut column_children = Vec::with_capacity(columns.len());
+            for column in columns.iter() {
+                self.visit_column_definition(column);
+                column_children.push(self.children.pop().unwrap());
+            }
+            let format_ctx = AstFormatContext::with_children(name.to_string(), columns.len());
+            children.push(FormatTreeNode::with_children(format_ctx, column_children));
+        }
+        let location_format_ctx =
+            AstFormatContext::new(format!("UriLocation {}", stmt.uri_location));
+        children.push(FormatTreeNode::new(location_format_ctx));
+
+        let name = "CreateExternalTable".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
     fn visit_create_table_source(&mut self, source: &'ast CreateTableSource) {
         match source {
             CreateTableSource::Columns(columns) => {
@@ -1295,6 +1572,31 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let node = FormatTreeNode::with_children(format_ctx, vec![child]);
                 self.children.push(node);
             }
+            CreateTableSource::Template(query) => {
+                self.visit_query(query);
+                let child = self.children.pop().unwrap();
+                let name = "UsingTemplate".to_string();
+                let format_ctx = AstFormatContext::with_children(name, 1);
+                let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+                self.children.push(node);
+            }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            } => {
+                self.visit_table_ref(catalog, database, table);
+                let mut children = vec![self.children.pop().unwrap()];
+                if let Some(travel_point) = travel_point {
+                    self.visit_time_travel_point(travel_point);
+                    children.push(self.children.pop().unwrap());
+                }
+                let name = "CloneTable".to_string();
+                let format_ctx = AstFormatContext::with_children(name, children.len());
+                let node = FormatTreeNode::with_children(format_ctx, children);
+                self.children.push(node);
+            }
         }
     }
 
@@ -1339,6 +1641,14 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
+            AlterTableAction::RenameColumn {
+                old_column,
+                new_column,
+            } => {
+                let action_name = format!("Action Rename column {} to {}", old_column, new_column);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
             AlterTableAction::AddColumn { column } => {
                 let action_name = format!("Action Add column {}", column);
                 let action_format_ctx = AstFormatContext::new(action_name);
@@ -1354,7 +1664,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
-            AlterTableAction::AlterTableClusterKey { cluster_by } => {
+            AlterTableAction::AlterTableClusterKey { cluster_by, .. } => {
                 let mut cluster_by_children = Vec::with_capacity(cluster_by.len());
                 for cluster_by_expr in cluster_by.iter() {
                     self.visit_expr(cluster_by_expr);
@@ -1396,6 +1706,22 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                let columns = columns
+                    .iter()
+                    .map(|c| c.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let action_name =
+                    format!("Action Add row access policy {} on ({})", policy, columns);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::DropRowAccessPolicy { policy } => {
+                let action_name = format!("Action Drop row access policy {}", policy);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
         };
 
         let name = "AlterTable".to_string();
@@ -1509,6 +1835,38 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_materialized_view(&mut self, stmt: &'ast CreateMaterializedViewStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.view);
+        let view_child = self.children.pop().unwrap();
+        self.visit_query(&stmt.query);
+        let query_child = self.children.pop().unwrap();
+
+        let name = "CreateMaterializedView".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![view_child, query_child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_materialized_view(&mut self, stmt: &'ast DropMaterializedViewStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.view);
+        let child = self.children.pop().unwrap();
+
+        let name = "DropMaterializedView".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_refresh_materialized_view(&mut self, stmt: &'ast RefreshMaterializedViewStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.view);
+        let child = self.children.pop().unwrap();
+
+        let name = "RefreshMaterializedView".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_create_index(&mut self, stmt: &'ast CreateIndexStmt) {
         self.visit_index_ref(&stmt.index_name);
         let index_child = self.children.pop().unwrap();
@@ -1721,6 +2079,31 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_alter_role(&mut self, stmt: &'ast AlterRoleStmt) {
+        let role_name = format!("Role {}", stmt.role_name);
+        let role_format_ctx = AstFormatContext::new(role_name);
+        let role_child = FormatTreeNode::new(role_format_ctx);
+
+        let action_name = match &stmt.action {
+            AlterRoleAction::SetSettings(settings) => format!(
+                "SetSettings {}",
+                settings
+                    .iter()
+                    .map(|(k, v)| format!("{k} = '{v}'"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            AlterRoleAction::UnsetSettings(names) => format!("UnsetSettings {}", names.join(", ")),
+        };
+        let action_format_ctx = AstFormatContext::new(action_name);
+        let action_child = FormatTreeNode::new(action_format_ctx);
+
+        let name = "AlterRole".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![role_child, action_child]);
+        self.children.push(node);
+    }
+
     fn visit_grant(&mut self, grant: &'ast GrantStmt) {
         let source_child = match &grant.source {
             AccountMgrSource::Role { role } => {
@@ -1745,6 +2128,20 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let all_format_ctx = AstFormatContext::new(all_name);
                 FormatTreeNode::new(all_format_ctx)
             }
+            AccountMgrSource::ColumnPrivs {
+                privilege, columns, ..
+            } => {
+                let mut columns_children = Vec::with_capacity(columns.len());
+                for column in columns.iter() {
+                    let column_name = format!("Column {}", column);
+                    let column_format_ctx = AstFormatContext::new(column_name);
+                    columns_children.push(FormatTreeNode::new(column_format_ctx));
+                }
+                let privilege_name = format!("Privilege {}", privilege);
+                let privilege_format_ctx =
+                    AstFormatContext::with_children(privilege_name, columns_children.len());
+                FormatTreeNode::with_children(privilege_format_ctx, columns_children)
+            }
         };
         let principal_name = match &grant.principal {
             PrincipalIdentity::User(user) => format!("User {}", user),
@@ -1799,6 +2196,20 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let all_format_ctx = AstFormatContext::new(all_name);
                 FormatTreeNode::new(all_format_ctx)
             }
+            AccountMgrSource::ColumnPrivs {
+                privilege, columns, ..
+            } => {
+                let mut columns_children = Vec::with_capacity(columns.len());
+                for column in columns.iter() {
+                    let column_name = format!("Column {}", column);
+                    let column_format_ctx = AstFormatContext::new(column_name);
+                    columns_children.push(FormatTreeNode::new(column_format_ctx));
+                }
+                let privilege_name = format!("Privilege {}", privilege);
+                let privilege_format_ctx =
+                    AstFormatContext::with_children(privilege_name, columns_children.len());
+                FormatTreeNode::with_children(privilege_format_ctx, columns_children)
+            }
         };
         let principal_name = match &revoke.principal {
             PrincipalIdentity::User(user) => format!("User {}", user),
@@ -1817,34 +2228,13 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         &mut self,
         _if_not_exists: bool,
         udf_name: &'ast Identifier,
-        parameters: &'ast [Identifier],
-        definition: &'ast Expr,
+        definition: &'ast UDFDefinition,
         description: &'ast Option<String>,
     ) {
         let mut children = Vec::new();
         let udf_name_format_ctx = AstFormatContext::new(format!("UdfNameIdentifier {}", udf_name));
         children.push(FormatTreeNode::new(udf_name_format_ctx));
-        if !parameters.is_empty() {
-            let mut parameters_children = Vec::with_capacity(parameters.len());
-            for parameter in parameters.iter() {
-                self.visit_identifier(parameter);
-                parameters_children.push(self.children.pop().unwrap());
-            }
-            let parameters_name = "UdfParameters".to_string();
-            let parameters_format_ctx =
-                AstFormatContext::with_children(parameters_name, parameters_children.len());
-            children.push(FormatTreeNode::with_children(
-                parameters_format_ctx,
-                parameters_children,
-            ));
-        }
-        self.visit_expr(definition);
-        let definition_child = self.children.pop().unwrap();
-        let definition_name = "UdfDefinition".to_string();
-        let definition_format_ctx = AstFormatContext::with_children(definition_name, 1);
-        children.push(FormatTreeNode::with_children(definition_format_ctx, vec![
-            definition_child,
-        ]));
+        children.extend(self.format_udf_definition(definition));
         if let Some(description) = description {
             let description_name = format!("UdfDescription {}", description);
             let description_format_ctx = AstFormatContext::new(description_name);
@@ -1870,34 +2260,13 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
     fn visit_alter_udf(
         &mut self,
         udf_name: &'ast Identifier,
-        parameters: &'ast [Identifier],
-        definition: &'ast Expr,
+        definition: &'ast UDFDefinition,
         description: &'ast Option<String>,
     ) {
         let mut children = Vec::new();
         let udf_name_format_ctx = AstFormatContext::new(format!("UdfNameIdentifier {}", udf_name));
         children.push(FormatTreeNode::new(udf_name_format_ctx));
-        if !parameters.is_empty() {
-            let mut parameters_children = Vec::with_capacity(parameters.len());
-            for parameter in parameters.iter() {
-                self.visit_identifier(parameter);
-                parameters_children.push(self.children.pop().unwrap());
-            }
-            let parameters_name = "UdfParameters".to_string();
-            let parameters_format_ctx =
-                AstFormatContext::with_children(parameters_name, parameters_children.len());
-            children.push(FormatTreeNode::with_children(
-                parameters_format_ctx,
-                parameters_children,
-            ));
-        }
-        self.visit_expr(definition);
-        let definition_child = self.children.pop().unwrap();
-        let definition_name = "UdfDefinition".to_string();
-        let definition_format_ctx = AstFormatContext::with_children(definition_name, 1);
-        children.push(FormatTreeNode::with_children(definition_format_ctx, vec![
-            definition_child,
-        ]));
+        children.extend(self.format_udf_definition(definition));
         if let Some(description) = description {
             let description_name = format!("UdfDescription {}", description);
             let description_format_ctx = AstFormatContext::new(description_name);
@@ -2202,6 +2571,179 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_row_access_policy(&mut self, stmt: &'ast CreateRowAccessPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("RowAccessPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "CreateRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_row_access_policy(&mut self, stmt: &'ast DropRowAccessPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("RowAccessPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DropRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_network_policy(&mut self, stmt: &'ast CreateNetworkPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("NetworkPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "CreateNetworkPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_network_policy(&mut self, stmt: &'ast DropNetworkPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("NetworkPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DropNetworkPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_dictionary(&mut self, stmt: &'ast CreateDictionaryStmt) {
+        let ctx = AstFormatContext::new(format!("DictionaryName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "CreateDictionary".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_dictionary(&mut self, stmt: &'ast DropDictionaryStmt) {
+        let ctx = AstFormatContext::new(format!("DictionaryName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DropDictionary".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_sequence(&mut self, stmt: &'ast CreateSequenceStmt) {
+        let ctx = AstFormatContext::new(format!("SequenceName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "CreateSequence".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_sequence(&mut self, stmt: &'ast DropSequenceStmt) {
+        let ctx = AstFormatContext::new(format!("SequenceName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DropSequence".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_task(&mut self, stmt: &'ast CreateTaskStmt) {
+        let ctx = AstFormatContext::new(format!("TaskName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "CreateTask".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_task(&mut self, stmt: &'ast DropTaskStmt) {
+        let ctx = AstFormatContext::new(format!("TaskName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DropTask".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_alter_task(&mut self, stmt: &'ast AlterTaskStmt) {
+        let ctx = AstFormatContext::new(format!("TaskName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "AlterTask".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_execute_task(&mut self, stmt: &'ast ExecuteTaskStmt) {
+        let ctx = AstFormatContext::new(format!("TaskName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "ExecuteTask".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_notification_integration(
+        &mut self,
+        stmt: &'ast CreateNotificationIntegrationStmt,
+    ) {
+        let ctx = AstFormatContext::new(format!("NotificationIntegrationName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "CreateNotificationIntegration".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_notification_integration(&mut self, stmt: &'ast DropNotificationIntegrationStmt) {
+        let ctx = AstFormatContext::new(format!("NotificationIntegrationName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DropNotificationIntegration".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_pipe(&mut self, stmt: &'ast CreatePipeStmt) {
+        let ctx = AstFormatContext::new(format!("PipeName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "CreatePipe".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_pipe(&mut self, stmt: &'ast DropPipeStmt) {
+        let ctx = AstFormatContext::new(format!("PipeName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DropPipe".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_alter_cluster_node(&mut self, stmt: &'ast AlterClusterNodeStmt) {
+        let ctx = AstFormatContext::new(format!("NodeId {}", stmt.node_id));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "AlterClusterNode".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_with(&mut self, with: &'ast With) {
         let mut children = Vec::with_capacity(with.ctes.len());
         for cte in with.ctes.iter() {
@@ -2443,6 +2985,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 travel_point,
                 pivot,
                 unpivot,
+                sample,
             } => {
                 let mut name = String::new();
                 name.push_str("TableIdentifier ");
@@ -2456,6 +2999,11 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 }
                 name.push_str(&table.to_string());
 
+                if let Some(sample) = sample {
+                    name.push(' ');
+                    name.push_str(&sample.to_string());
+                }
+
                 if let Some(pivot) = pivot {
                     name.push(' ');
                     name.push_str(&pivot.to_string());
@@ -2485,12 +3033,27 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             }
             TableReference::Subquery {
                 span: _,
+                lateral,
                 subquery,
                 alias,
+                pivot,
+                unpivot,
             } => {
                 self.visit_query(subquery);
                 let child = self.children.pop().unwrap();
-                let name = "Subquery".to_string();
+                let mut name = if *lateral {
+                    "LateralSubquery".to_string()
+                } else {
+                    "Subquery".to_string()
+                };
+                if let Some(pivot) = pivot {
+                    name.push(' ');
+                    name.push_str(&pivot.to_string());
+                }
+                if let Some(unpivot) = unpivot {
+                    name.push(' ');
+                    name.push_str(&unpivot.to_string());
+                }
                 let format_ctx = if let Some(alias) = alias {
                     AstFormatContext::with_children_alias(name, 1, Some(format!("{}", alias)))
                 } else {
@@ -2501,6 +3064,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             }
             TableReference::TableFunction {
                 span: _,
+                lateral,
                 name,
                 params,
                 named_params,
@@ -2520,7 +3084,11 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                     );
                     children.push(node);
                 }
-                let func_name = format!("TableFunction {}", name);
+                let func_name = if *lateral {
+                    format!("LateralTableFunction {}", name)
+                } else {
+                    format!("TableFunction {}", name)
+                };
                 let format_ctx = if let Some(alias) = alias {
                     AstFormatContext::with_children_alias(
                         func_name,
@@ -70,7 +70,7 @@ pub(crate) fn pretty_create_table(stmt: CreateTableStmt) -> RcDoc<'static> {
         })
         .append(if !stmt.cluster_by.is_empty() {
             RcDoc::line()
-                .append(RcDoc::text("CLUSTER BY "))
+                .append(RcDoc::text(format!("CLUSTER BY {}", stmt.cluster_type)))
                 .append(parenthesized(
                     interweave_comma(stmt.cluster_by.into_iter().map(pretty_expr)).group(),
                 ))
@@ -131,6 +131,13 @@ fn pretty_table_source(source: CreateTableSource) -> RcDoc<'static> {
                 RcDoc::nil()
             })
             .append(RcDoc::text(table.to_string())),
+        CreateTableSource::Template(query) => RcDoc::space()
+            .append(RcDoc::text("USING TEMPLATE"))
+            .append(RcDoc::space())
+            .append(parenthesized(pretty_query(*query))),
+        clone @ CreateTableSource::Clone { .. } => {
+            RcDoc::space().append(RcDoc::text(clone.to_string()))
+        }
     }
 }
 
@@ -154,6 +161,14 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
         AlterTableAction::RenameTable { new_table } => RcDoc::line()
             .append(RcDoc::text("RENAME TO "))
             .append(RcDoc::text(new_table.to_string())),
+        AlterTableAction::RenameColumn {
+            old_column,
+            new_column,
+        } => RcDoc::line()
+            .append(RcDoc::text("RENAME COLUMN "))
+            .append(RcDoc::text(old_column.to_string()))
+            .append(RcDoc::text(" TO "))
+            .append(RcDoc::text(new_column.to_string())),
         AlterTableAction::AddColumn { column } => RcDoc::line()
             .append(RcDoc::text("ADD COLUMN "))
             .append(RcDoc::text(column.to_string())),
@@ -164,8 +179,11 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
         AlterTableAction::DropColumn { column } => RcDoc::line()
             .append(RcDoc::text("DROP COLUMN "))
             .append(RcDoc::text(column.to_string())),
-        AlterTableAction::AlterTableClusterKey { cluster_by } => RcDoc::line()
-            .append(RcDoc::text("CLUSTER BY "))
+        AlterTableAction::AlterTableClusterKey {
+            cluster_type,
+            cluster_by,
+        } => RcDoc::line()
+            .append(RcDoc::text(format!("CLUSTER BY {cluster_type}")))
             .append(parenthesized(
                 interweave_comma(cluster_by.into_iter().map(pretty_expr)).group(),
             )),
@@ -203,6 +221,21 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
             }
             doc
         }
+        AlterTableAction::AddRowAccessPolicy { policy, columns } => RcDoc::line()
+            .append(RcDoc::text("ADD ROW ACCESS POLICY "))
+            .append(RcDoc::text(policy.to_string()))
+            .append(RcDoc::text(" ON "))
+            .append(parenthesized(
+                interweave_comma(
+                    columns
+                        .into_iter()
+                        .map(|column| RcDoc::text(column.to_string())),
+                )
+                .group(),
+            )),
+        AlterTableAction::DropRowAccessPolicy { policy } => RcDoc::line()
+            .append(RcDoc::text("DROP ROW ACCESS POLICY "))
+            .append(RcDoc::text(policy.to_string())),
     }
 }
 
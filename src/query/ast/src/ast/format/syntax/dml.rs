@@ -236,6 +236,20 @@ pub(crate) fn pretty_copy(copy_stmt: CopyStmt) -> RcDoc<'static> {
                 .append(RcDoc::text("DISABLE_VARIANT_CHECK = "))
                 .append(RcDoc::text(format!("{}", copy_stmt.disable_variant_check))),
         )
+        .append(
+            if let Some(match_by_column_name) = &copy_stmt.match_by_column_name {
+                RcDoc::line()
+                    .append(RcDoc::text("MATCH_BY_COLUMN_NAME = "))
+                    .append(RcDoc::text(match_by_column_name.clone()))
+            } else {
+                RcDoc::nil()
+            },
+        )
+        .append(if copy_stmt.error_on_column_count_mismatch {
+            RcDoc::line().append(RcDoc::text("ERROR_ON_COLUMN_COUNT_MISMATCH = true"))
+        } else {
+            RcDoc::nil()
+        })
 }
 
 fn pretty_copy_unit(copy_unit: CopyUnit) -> RcDoc<'static> {
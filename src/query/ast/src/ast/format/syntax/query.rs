@@ -301,6 +301,7 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
             travel_point,
             pivot,
             unpivot,
+            sample,
         } => if let Some(catalog) = catalog {
             RcDoc::text(catalog.to_string()).append(RcDoc::text("."))
         } else {
@@ -312,6 +313,11 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
             RcDoc::nil()
         })
         .append(RcDoc::text(table.to_string()))
+        .append(if let Some(sample) = sample {
+            RcDoc::text(format!(" {sample}"))
+        } else {
+            RcDoc::nil()
+        })
         .append(if let Some(pivot) = pivot {
             RcDoc::text(format!(" {pivot}"))
         } else {
@@ -336,15 +342,35 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
         }),
         TableReference::Subquery {
             span: _,
+            lateral,
             subquery,
             alias,
-        } => parenthesized(pretty_query(*subquery)).append(if let Some(alias) = alias {
+            pivot,
+            unpivot,
+        } => (if lateral {
+            RcDoc::text("LATERAL ")
+        } else {
+            RcDoc::nil()
+        })
+        .append(parenthesized(pretty_query(*subquery)))
+        .append(if let Some(alias) = alias {
             RcDoc::text(format!(" AS {alias}"))
         } else {
             RcDoc::nil()
+        })
+        .append(if let Some(pivot) = pivot {
+            RcDoc::text(format!(" {pivot}"))
+        } else {
+            RcDoc::nil()
+        })
+        .append(if let Some(unpivot) = unpivot {
+            RcDoc::text(format!(" {unpivot}"))
+        } else {
+            RcDoc::nil()
         }),
         TableReference::TableFunction {
             span: _,
+            lateral,
             name,
             params,
             named_params,
@@ -355,21 +381,26 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
             } else {
                 RcDoc::nil()
             };
-            RcDoc::text(name.to_string())
-                .append(RcDoc::text("("))
-                .append(inline_comma(params.into_iter().map(pretty_expr)))
-                .append(separator)
-                .append(inline_comma(named_params.into_iter().map(|(k, v)| {
-                    RcDoc::text(k)
-                        .append(RcDoc::text("=>"))
-                        .append(pretty_expr(v))
-                })))
-                .append(RcDoc::text(")"))
-                .append(if let Some(alias) = alias {
-                    RcDoc::text(format!(" AS {alias}"))
-                } else {
-                    RcDoc::nil()
-                })
+            (if lateral {
+                RcDoc::text("LATERAL ")
+            } else {
+                RcDoc::nil()
+            })
+            .append(RcDoc::text(name.to_string()))
+            .append(RcDoc::text("("))
+            .append(inline_comma(params.into_iter().map(pretty_expr)))
+            .append(separator)
+            .append(inline_comma(named_params.into_iter().map(|(k, v)| {
+                RcDoc::text(k)
+                    .append(RcDoc::text("=>"))
+                    .append(pretty_expr(v))
+            })))
+            .append(RcDoc::text(")"))
+            .append(if let Some(alias) = alias {
+                RcDoc::text(format!(" AS {alias}"))
+            } else {
+                RcDoc::nil()
+            })
         }
         TableReference::Join { span: _, join } => pretty_table(*join.left)
             .append(RcDoc::line())
@@ -214,6 +214,20 @@ pub struct Unpivot {
     pub names: Vec<Identifier>,
 }
 
+/// `SAMPLE [ROW | BLOCK] (<probability> [PERCENT])`
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableSample {
+    pub level: SampleLevel,
+    /// The percentage of rows or blocks to pick, in `(0, 100]`.
+    pub percent: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SampleLevel {
+    Row,
+    Block,
+}
+
 /// A table name or a parenthesized subquery with an optional alias
 #[derive(Debug, Clone, PartialEq)]
 pub enum TableReference {
@@ -227,10 +241,14 @@ pub enum TableReference {
         travel_point: Option<TimeTravelPoint>,
         pivot: Option<Box<Pivot>>,
         unpivot: Option<Box<Unpivot>>,
+        sample: Option<TableSample>,
     },
-    // `TABLE(expr)[ AS alias ]`
+    // `[LATERAL] TABLE(expr)[ AS alias ]`
     TableFunction {
         span: Span,
+        /// If the table function is marked as LATERAL, its arguments can
+        /// reference columns of the preceding tables in the FROM clause.
+        lateral: bool,
         name: Identifier,
         params: Vec<Expr>,
         named_params: Vec<(String, Expr)>,
@@ -239,8 +257,13 @@ pub enum TableReference {
     // Derived table, which can be a subquery or joined tables or combination of them
     Subquery {
         span: Span,
+        /// If the subquery is marked as LATERAL, it can reference columns of
+        /// the preceding tables in the FROM clause.
+        lateral: bool,
         subquery: Box<Query>,
         alias: Option<TableAlias>,
+        pivot: Option<Box<Pivot>>,
+        unpivot: Option<Box<Unpivot>>,
     },
     Join {
         span: Span,
@@ -255,16 +278,30 @@ pub enum TableReference {
 }
 
 impl TableReference {
+    pub fn span(&self) -> Span {
+        match self {
+            TableReference::Table { span, .. }
+            | TableReference::TableFunction { span, .. }
+            | TableReference::Subquery { span, .. }
+            | TableReference::Join { span, .. }
+            | TableReference::Stage { span, .. } => *span,
+        }
+    }
+
     pub fn pivot(&self) -> Option<&Pivot> {
         match self {
-            TableReference::Table { pivot, .. } => pivot.as_ref().map(|b| b.as_ref()),
+            TableReference::Table { pivot, .. } | TableReference::Subquery { pivot, .. } => {
+                pivot.as_ref().map(|b| b.as_ref())
+            }
             _ => None,
         }
     }
 
     pub fn unpivot(&self) -> Option<&Unpivot> {
         match self {
-            TableReference::Table { unpivot, .. } => unpivot.as_ref().map(|b| b.as_ref()),
+            TableReference::Table { unpivot, .. } | TableReference::Subquery { unpivot, .. } => {
+                unpivot.as_ref().map(|b| b.as_ref())
+            }
             _ => None,
         }
     }
@@ -387,6 +424,15 @@ impl Display for Unpivot {
     }
 }
 
+impl Display for TableSample {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.level {
+            SampleLevel::Row => write!(f, "SAMPLE ROW ({})", self.percent),
+            SampleLevel::Block => write!(f, "SAMPLE BLOCK ({})", self.percent),
+        }
+    }
+}
+
 impl Display for TableReference {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -399,6 +445,7 @@ impl Display for TableReference {
                 travel_point,
                 pivot,
                 unpivot,
+                sample,
             } => {
                 write_period_separated_list(
                     f,
@@ -416,6 +463,9 @@ impl Display for TableReference {
                 if let Some(alias) = alias {
                     write!(f, " AS {alias}")?;
                 }
+                if let Some(sample) = sample {
+                    write!(f, " {sample}")?;
+                }
                 if let Some(pivot) = pivot {
                     write!(f, " {pivot}")?;
                 }
@@ -426,11 +476,15 @@ impl Display for TableReference {
             }
             TableReference::TableFunction {
                 span: _,
+                lateral,
                 name,
                 params,
                 named_params,
                 alias,
             } => {
+                if *lateral {
+                    write!(f, "LATERAL ")?;
+                }
                 write!(f, "{name}(")?;
                 write_comma_separated_list(f, params)?;
                 if !params.is_empty() && !named_params.is_empty() {
@@ -449,13 +503,25 @@ impl Display for TableReference {
             }
             TableReference::Subquery {
                 span: _,
+                lateral,
                 subquery,
                 alias,
+                pivot,
+                unpivot,
             } => {
+                if *lateral {
+                    write!(f, "LATERAL ")?;
+                }
                 write!(f, "({subquery})")?;
                 if let Some(alias) = alias {
                     write!(f, " AS {alias}")?;
                 }
+                if let Some(pivot) = pivot {
+                    write!(f, " {pivot}")?;
+                }
+                if let Some(unpivot) = unpivot {
+                    write!(f, " {unpivot}")?;
+                }
             }
             TableReference::Join { span: _, join } => {
                 write!(f, "{}", join.left)?;
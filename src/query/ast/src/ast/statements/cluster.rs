@@ -0,0 +1,44 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlterClusterNodeAction {
+    /// Stop scheduling new queries on the node, and drop it from the cluster after the
+    /// running queries finish, or the timeout.
+    Drain { timeout_secs: Option<u64> },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlterClusterNodeStmt {
+    pub node_id: String,
+    pub action: AlterClusterNodeAction,
+}
+
+impl Display for AlterClusterNodeStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "ALTER CLUSTER NODE '{}' ", self.node_id)?;
+        match self.action {
+            AlterClusterNodeAction::Drain { timeout_secs } => {
+                write!(f, "DRAIN")?;
+                if let Some(timeout_secs) = timeout_secs {
+                    write!(f, " TIMEOUT = {timeout_secs}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
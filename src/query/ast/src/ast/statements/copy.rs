@@ -23,11 +23,13 @@ use std::io::Result;
 use itertools::Itertools;
 use url::Url;
 
+use crate::ast::write_comma_separated_list;
 use crate::ast::write_quoted_comma_separated_list;
 use crate::ast::write_space_separated_map;
 use crate::ast::Hint;
 use crate::ast::Identifier;
 use crate::ast::Query;
+use crate::ast::SelectTarget;
 use crate::parser::unescape::escape_at_string;
 
 /// CopyStmt is the parsed statement of `COPY`.
@@ -56,6 +58,15 @@ pub struct CopyStmt {
     pub force: bool,
     pub disable_variant_check: bool,
     pub on_error: String,
+    /// Load the columns of the files into the columns of the table with the same name,
+    /// `CASE_SENSITIVE` or `CASE_INSENSITIVE`.
+    pub match_by_column_name: Option<String>,
+    /// Whether to fail if the files have columns that are not in the table while
+    /// matching by column name.
+    pub error_on_column_count_mismatch: bool,
+    /// Hive-style partition keys for `COPY INTO <location>`, each one becomes a
+    /// `key=value` directory level of the unloaded files.
+    pub partition_by: Option<Vec<SelectTarget>>,
 }
 
 impl CopyStmt {
@@ -74,6 +85,9 @@ impl CopyStmt {
             CopyOption::Force(v) => self.force = v,
             CopyOption::DisableVariantCheck(v) => self.disable_variant_check = v,
             CopyOption::OnError(v) => self.on_error = v,
+            CopyOption::MatchByColumnName(v) => self.match_by_column_name = Some(v),
+            CopyOption::ErrorOnColumnCountMismatch(v) => self.error_on_column_count_mismatch = v,
+            CopyOption::PartitionBy(v) => self.partition_by = Some(v),
         }
     }
 }
@@ -131,6 +145,20 @@ impl Display for CopyStmt {
         write!(f, " DISABLE_VARIANT_CHECK = {}", self.disable_variant_check)?;
         write!(f, " ON_ERROR = '{}'", self.on_error)?;
 
+        if let Some(match_by_column_name) = &self.match_by_column_name {
+            write!(f, " MATCH_BY_COLUMN_NAME = {}", match_by_column_name)?;
+        }
+
+        if self.error_on_column_count_mismatch {
+            write!(f, " ERROR_ON_COLUMN_COUNT_MISMATCH = true")?;
+        }
+
+        if let Some(partition_by) = &self.partition_by {
+            write!(f, " PARTITION BY (")?;
+            write_comma_separated_list(f, partition_by)?;
+            write!(f, ")")?;
+        }
+
         Ok(())
     }
 }
@@ -410,4 +438,7 @@ pub enum CopyOption {
     Force(bool),
     DisableVariantCheck(bool),
     OnError(String),
+    MatchByColumnName(String),
+    ErrorOnColumnCountMismatch(bool),
+    PartitionBy(Vec<SelectTarget>),
 }
@@ -126,6 +126,41 @@ impl Display for UndropDatabaseStmt {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackupDatabaseStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Identifier,
+    pub location: String,
+    pub with_data: bool,
+}
+
+impl Display for BackupDatabaseStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BACKUP DATABASE ")?;
+        write_period_separated_list(f, self.catalog.iter().chain(Some(&self.database)))?;
+        write!(f, " TO @{}", self.location)?;
+        if self.with_data {
+            write!(f, " WITH DATA")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RestoreDatabaseStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Identifier,
+    pub location: String,
+}
+
+impl Display for RestoreDatabaseStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RESTORE DATABASE ")?;
+        write_period_separated_list(f, self.catalog.iter().chain(Some(&self.database)))?;
+        write!(f, " FROM @{}", self.location)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AlterDatabaseStmt {
     pub if_exists: bool,
@@ -0,0 +1,86 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::ast::write_comma_separated_list;
+use crate::ast::write_space_separated_map;
+use crate::ast::Identifier;
+use crate::ast::TypeName;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DictionaryColumn {
+    pub name: Identifier,
+    pub data_type: TypeName,
+}
+
+impl Display for DictionaryColumn {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{} {}", self.name, self.data_type)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateDictionaryStmt {
+    pub if_not_exists: bool,
+    pub name: Identifier,
+    pub columns: Vec<DictionaryColumn>,
+    pub primary_key: Identifier,
+    pub source_type: Identifier,
+    pub source_options: BTreeMap<String, String>,
+    pub comment: Option<String>,
+}
+
+impl Display for CreateDictionaryStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE DICTIONARY ")?;
+        if self.if_not_exists {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write!(f, "{} (", self.name)?;
+        write_comma_separated_list(f, &self.columns)?;
+        write!(
+            f,
+            ") PRIMARY KEY {} SOURCE ({}(",
+            self.primary_key, self.source_type
+        )?;
+        write_space_separated_map(f, &self.source_options)?;
+        write!(f, "))")?;
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT = '{}'", comment)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DropDictionaryStmt {
+    pub if_exists: bool,
+    pub name: Identifier,
+}
+
+impl Display for DropDictionaryStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DROP DICTIONARY ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", self.name)?;
+
+        Ok(())
+    }
+}
@@ -18,9 +18,11 @@ use std::fmt::Formatter;
 
 use crate::ast::write_comma_separated_list;
 use crate::ast::write_period_separated_list;
+use crate::ast::Expr;
 use crate::ast::Hint;
 use crate::ast::Identifier;
 use crate::ast::Query;
+use crate::ast::UpdateExpr;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct InsertStmt {
@@ -31,6 +33,23 @@ pub struct InsertStmt {
     pub columns: Vec<Identifier>,
     pub source: InsertSource,
     pub overwrite: bool,
+    pub on_conflict: Option<OnConflict>,
+}
+
+/// `ON CONFLICT (<column>, ...) DO NOTHING | DO UPDATE SET ... [WHERE <condition>]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct OnConflict {
+    pub columns: Vec<Identifier>,
+    pub action: OnConflictAction,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum OnConflictAction {
+    Nothing,
+    Update {
+        update_list: Vec<UpdateExpr>,
+        selection: Option<Expr>,
+    },
 }
 
 impl Display for InsertStmt {
@@ -56,7 +75,33 @@ impl Display for InsertStmt {
             write_comma_separated_list(f, &self.columns)?;
             write!(f, ")")?;
         }
-        write!(f, " {}", self.source)
+        write!(f, " {}", self.source)?;
+        if let Some(on_conflict) = &self.on_conflict {
+            write!(f, " {on_conflict}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for OnConflict {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "ON CONFLICT (")?;
+        write_comma_separated_list(f, &self.columns)?;
+        write!(f, ") DO ")?;
+        match &self.action {
+            OnConflictAction::Nothing => write!(f, "NOTHING"),
+            OnConflictAction::Update {
+                update_list,
+                selection,
+            } => {
+                write!(f, "UPDATE SET ")?;
+                write_comma_separated_list(f, update_list)?;
+                if let Some(selection) = selection {
+                    write!(f, " WHERE {selection}")?;
+                }
+                Ok(())
+            }
+        }
     }
 }
 
@@ -14,22 +14,32 @@
 
 mod call;
 mod catalog;
+mod cluster;
 mod columns;
 mod copy;
 mod data_mask;
 mod database;
+mod dictionary;
 mod explain;
 mod hint;
 mod index;
 mod insert;
 mod kill;
+mod merge_into;
+mod network_policy;
+mod notification;
+mod pipe;
 mod presign;
 mod replace;
+mod row_access_policy;
+mod sequence;
 mod share;
 mod show;
 mod stage;
 mod statement;
 mod table;
+mod task;
+mod udf;
 mod unset;
 mod update;
 mod user;
@@ -38,22 +48,32 @@ mod virtual_columns;
 
 pub use call::*;
 pub use catalog::*;
+pub use cluster::*;
 pub use columns::*;
 pub use copy::*;
 pub use data_mask::*;
 pub use database::*;
+pub use dictionary::*;
 pub use explain::*;
 pub use hint::*;
 pub use index::*;
 pub use insert::*;
 pub use kill::*;
+pub use merge_into::*;
+pub use network_policy::*;
+pub use notification::*;
+pub use pipe::*;
 pub use presign::*;
 pub use replace::*;
+pub use row_access_policy::*;
+pub use sequence::*;
 pub use share::*;
 pub use show::*;
 pub use stage::*;
 pub use statement::*;
 pub use table::*;
+pub use task::*;
+pub use udf::*;
 pub use unset::*;
 pub use update::*;
 pub use user::*;
@@ -0,0 +1,68 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::ast::write_space_separated_map;
+use crate::ast::Identifier;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateNotificationIntegrationStmt {
+    pub if_not_exists: bool,
+    pub name: Identifier,
+    pub integration_type: Identifier,
+    pub connection: BTreeMap<String, String>,
+    pub comment: Option<String>,
+}
+
+impl Display for CreateNotificationIntegrationStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE NOTIFICATION INTEGRATION ")?;
+        if self.if_not_exists {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write!(
+            f,
+            "{} TYPE = {} CONNECTION = (",
+            self.name, self.integration_type
+        )?;
+        write_space_separated_map(f, &self.connection)?;
+        write!(f, ")")?;
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT = '{}'", comment)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DropNotificationIntegrationStmt {
+    pub if_exists: bool,
+    pub name: Identifier,
+}
+
+impl Display for DropNotificationIntegrationStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DROP NOTIFICATION INTEGRATION ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", self.name)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::ast::write_period_separated_list;
+use crate::ast::write_space_separated_map;
+use crate::ast::Identifier;
+use crate::ast::StageLocation;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PipeSourceLocation {
+    Kafka(BTreeMap<String, String>),
+    MySql(BTreeMap<String, String>),
+    Stage {
+        location: StageLocation,
+        pattern: Option<String>,
+    },
+}
+
+impl Display for PipeSourceLocation {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            PipeSourceLocation::Kafka(options) => {
+                write!(f, "KAFKA (")?;
+                write_space_separated_map(f, options)?;
+                write!(f, ")")
+            }
+            PipeSourceLocation::MySql(options) => {
+                write!(f, "MYSQL (")?;
+                write_space_separated_map(f, options)?;
+                write!(f, ")")
+            }
+            PipeSourceLocation::Stage { location, pattern } => {
+                write!(f, "{location}")?;
+                if let Some(pattern) = pattern {
+                    write!(f, " PATTERN = '{pattern}'")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreatePipeStmt {
+    pub if_not_exists: bool,
+    pub name: Identifier,
+    pub auto_ingest: bool,
+    pub aws_sqs_queue_url: Option<String>,
+    pub comment: Option<String>,
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+    pub source: PipeSourceLocation,
+    pub file_format: BTreeMap<String, String>,
+}
+
+impl Display for CreatePipeStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE PIPE ")?;
+        if self.if_not_exists {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write!(f, "{}", self.name)?;
+        if self.auto_ingest {
+            write!(f, " AUTO_INGEST = TRUE")?;
+        }
+        if let Some(queue_url) = &self.aws_sqs_queue_url {
+            write!(f, " AWS_SQS_QUEUE_URL = '{queue_url}'")?;
+        }
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT = '{comment}'")?;
+        }
+        write!(f, " AS COPY INTO ")?;
+        write_period_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )?;
+        write!(f, " FROM {}", self.source)?;
+        if !self.file_format.is_empty() {
+            write!(f, " FILE_FORMAT = (")?;
+            write_space_separated_map(f, &self.file_format)?;
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DropPipeStmt {
+    pub if_exists: bool,
+    pub name: Identifier,
+}
+
+impl Display for DropPipeStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DROP PIPE ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", self.name)
+    }
+}
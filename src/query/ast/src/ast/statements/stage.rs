@@ -79,6 +79,10 @@ pub enum SelectStageOption {
     Files(Vec<String>),
     Pattern(String),
     FileFormat(String),
+    LastModifiedAfter(String),
+    LastModifiedBefore(String),
+    MinFileSize(u64),
+    MaxFileSize(u64),
     Connection((String, String)),
 }
 
@@ -87,6 +91,10 @@ pub struct SelectStageOptions {
     pub files: Option<Vec<String>>,
     pub pattern: Option<String>,
     pub file_format: Option<String>,
+    pub last_modified_after: Option<String>,
+    pub last_modified_before: Option<String>,
+    pub min_file_size: Option<u64>,
+    pub max_file_size: Option<u64>,
     pub connection: BTreeMap<String, String>,
 }
 
@@ -95,6 +103,10 @@ impl SelectStageOptions {
         self.files.is_none()
             && self.pattern.is_none()
             && self.file_format.is_none()
+            && self.last_modified_after.is_none()
+            && self.last_modified_before.is_none()
+            && self.min_file_size.is_none()
+            && self.max_file_size.is_none()
             && self.connection.is_empty()
     }
 }
@@ -104,6 +116,10 @@ impl SelectStageOptions {
 // [ PATTERN => '<regex_pattern>']
 // [ FILE_FORMAT => '<format_name>']
 // [ FILES => ( 'file_name' [ , 'file_name' ... ] ) ]
+// [ LAST_MODIFIED_AFTER => '<timestamp>' ]
+// [ LAST_MODIFIED_BEFORE => '<timestamp>' ]
+// [ MIN_FILE_SIZE => <num> ]
+// [ MAX_FILE_SIZE => <num> ]
 // [ ENDPOINT_URL => <'url'> ]
 // [ AWS_KEY_ID => <'aws_key_id'> ]
 // [ AWS_KEY_SECRET => <'aws_key_secret'> ]
@@ -139,6 +155,22 @@ impl Display for SelectStageOptions {
             output.push(pattern);
         }
 
+        if let Some(after) = &self.last_modified_after {
+            output.push(format!("LAST_MODIFIED_AFTER => '{}'", after));
+        }
+
+        if let Some(before) = &self.last_modified_before {
+            output.push(format!("LAST_MODIFIED_BEFORE => '{}'", before));
+        }
+
+        if let Some(size) = self.min_file_size {
+            output.push(format!("MIN_FILE_SIZE => {}", size));
+        }
+
+        if let Some(size) = self.max_file_size {
+            output.push(format!("MAX_FILE_SIZE => {}", size));
+        }
+
         if !self.connection.is_empty() {
             for (k, v) in self.connection.iter() {
                 output.push(format!(" {} => '{}'", k, v));
@@ -159,6 +191,10 @@ impl SelectStageOptions {
                 SelectStageOption::Files(v) => options.files = Some(v),
                 SelectStageOption::Pattern(v) => options.pattern = Some(v),
                 SelectStageOption::FileFormat(v) => options.file_format = Some(v),
+                SelectStageOption::LastModifiedAfter(v) => options.last_modified_after = Some(v),
+                SelectStageOption::LastModifiedBefore(v) => options.last_modified_before = Some(v),
+                SelectStageOption::MinFileSize(v) => options.min_file_size = Some(v),
+                SelectStageOption::MaxFileSize(v) => options.max_file_size = Some(v),
                 SelectStageOption::Connection((k, v)) => {
                     options.connection.insert(k, v);
                 }
@@ -76,6 +76,7 @@ pub enum Statement {
 
     Insert(InsertStmt),
     Replace(ReplaceStmt),
+    MergeInto(MergeIntoStmt),
 
     Delete {
         hints: Option<Hint>,
@@ -85,6 +86,11 @@ pub enum Statement {
 
     Update(UpdateStmt),
 
+    // Transactions
+    Begin,
+    Commit,
+    Abort,
+
     // Catalogs
     ShowCatalogs(ShowCatalogsStmt),
     ShowCreateCatalog(ShowCreateCatalogStmt),
@@ -97,6 +103,8 @@ pub enum Statement {
     CreateDatabase(CreateDatabaseStmt),
     DropDatabase(DropDatabaseStmt),
     UndropDatabase(UndropDatabaseStmt),
+    BackupDatabase(BackupDatabaseStmt),
+    RestoreDatabase(RestoreDatabaseStmt),
     AlterDatabase(AlterDatabaseStmt),
     UseDatabase {
         database: Identifier,
@@ -104,10 +112,13 @@ pub enum Statement {
 
     // Tables
     ShowTables(ShowTablesStmt),
+    ShowDropTables(ShowDropTablesStmt),
     ShowCreateTable(ShowCreateTableStmt),
     DescribeTable(DescribeTableStmt),
     ShowTablesStatus(ShowTablesStatusStmt),
     CreateTable(CreateTableStmt),
+    AttachTable(AttachTableStmt),
+    CreateExternalTable(CreateExternalTableStmt),
     DropTable(DropTableStmt),
     UndropTable(UndropTableStmt),
     AlterTable(AlterTableStmt),
@@ -125,6 +136,11 @@ pub enum Statement {
     AlterView(AlterViewStmt),
     DropView(DropViewStmt),
 
+    // Materialized views
+    CreateMaterializedView(CreateMaterializedViewStmt),
+    DropMaterializedView(DropMaterializedViewStmt),
+    RefreshMaterializedView(RefreshMaterializedViewStmt),
+
     // Indexes
     CreateIndex(CreateIndexStmt),
     DropIndex(DropIndexStmt),
@@ -152,6 +168,7 @@ pub enum Statement {
         if_exists: bool,
         role_name: String,
     },
+    AlterRole(AlterRoleStmt),
     Grant(GrantStmt),
     ShowGrants {
         principal: Option<PrincipalIdentity>,
@@ -162,8 +179,7 @@ pub enum Statement {
     CreateUDF {
         if_not_exists: bool,
         udf_name: Identifier,
-        parameters: Vec<Identifier>,
-        definition: Box<Expr>,
+        definition: UDFDefinition,
         description: Option<String>,
     },
     DropUDF {
@@ -172,8 +188,7 @@ pub enum Statement {
     },
     AlterUDF {
         udf_name: Identifier,
-        parameters: Vec<Identifier>,
-        definition: Box<Expr>,
+        definition: UDFDefinition,
         description: Option<String>,
     },
 
@@ -227,6 +242,39 @@ pub enum Statement {
     CreateDatamaskPolicy(CreateDatamaskPolicyStmt),
     DropDatamaskPolicy(DropDatamaskPolicyStmt),
     DescDatamaskPolicy(DescDatamaskPolicyStmt),
+
+    // row access policy
+    CreateRowAccessPolicy(CreateRowAccessPolicyStmt),
+    DropRowAccessPolicy(DropRowAccessPolicyStmt),
+
+    // network policy
+    CreateNetworkPolicy(CreateNetworkPolicyStmt),
+    DropNetworkPolicy(DropNetworkPolicyStmt),
+
+    // dictionary
+    CreateDictionary(CreateDictionaryStmt),
+    DropDictionary(DropDictionaryStmt),
+
+    // sequence
+    CreateSequence(CreateSequenceStmt),
+    DropSequence(DropSequenceStmt),
+
+    // task
+    CreateTask(CreateTaskStmt),
+    DropTask(DropTaskStmt),
+    AlterTask(AlterTaskStmt),
+    ExecuteTask(ExecuteTaskStmt),
+
+    // notification integration
+    CreateNotificationIntegration(CreateNotificationIntegrationStmt),
+    DropNotificationIntegration(DropNotificationIntegrationStmt),
+
+    // pipe
+    CreatePipe(CreatePipeStmt),
+    DropPipe(DropPipeStmt),
+
+    // cluster
+    AlterClusterNode(AlterClusterNodeStmt),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -287,6 +335,7 @@ impl Display for Statement {
             Statement::Query(query) => write!(f, "{query}")?,
             Statement::Insert(insert) => write!(f, "{insert}")?,
             Statement::Replace(replace) => write!(f, "{replace}")?,
+            Statement::MergeInto(merge_into) => write!(f, "{merge_into}")?,
             Statement::Delete {
                 table_reference,
                 selection,
@@ -301,6 +350,9 @@ impl Display for Statement {
                 }
             }
             Statement::Update(update) => write!(f, "{update}")?,
+            Statement::Begin => write!(f, "BEGIN")?,
+            Statement::Commit => write!(f, "COMMIT")?,
+            Statement::Abort => write!(f, "ABORT")?,
             Statement::Copy(stmt) => write!(f, "{stmt}")?,
             Statement::ShowSettings { like } => {
                 write!(f, "SHOW SETTINGS")?;
@@ -367,14 +419,19 @@ impl Display for Statement {
             Statement::CreateDatabase(stmt) => write!(f, "{stmt}")?,
             Statement::DropDatabase(stmt) => write!(f, "{stmt}")?,
             Statement::UndropDatabase(stmt) => write!(f, "{stmt}")?,
+            Statement::BackupDatabase(stmt) => write!(f, "{stmt}")?,
+            Statement::RestoreDatabase(stmt) => write!(f, "{stmt}")?,
             Statement::AlterDatabase(stmt) => write!(f, "{stmt}")?,
             Statement::UseDatabase { database } => write!(f, "USE {database}")?,
             Statement::ShowTables(stmt) => write!(f, "{stmt}")?,
+            Statement::ShowDropTables(stmt) => write!(f, "{stmt}")?,
             Statement::ShowColumns(stmt) => write!(f, "{stmt}")?,
             Statement::ShowCreateTable(stmt) => write!(f, "{stmt}")?,
             Statement::DescribeTable(stmt) => write!(f, "{stmt}")?,
             Statement::ShowTablesStatus(stmt) => write!(f, "{stmt}")?,
             Statement::CreateTable(stmt) => write!(f, "{stmt}")?,
+            Statement::AttachTable(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateExternalTable(stmt) => write!(f, "{stmt}")?,
             Statement::DropTable(stmt) => write!(f, "{stmt}")?,
             Statement::UndropTable(stmt) => write!(f, "{stmt}")?,
             Statement::AlterTable(stmt) => write!(f, "{stmt}")?,
@@ -387,6 +444,9 @@ impl Display for Statement {
             Statement::CreateView(stmt) => write!(f, "{stmt}")?,
             Statement::AlterView(stmt) => write!(f, "{stmt}")?,
             Statement::DropView(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateMaterializedView(stmt) => write!(f, "{stmt}")?,
+            Statement::DropMaterializedView(stmt) => write!(f, "{stmt}")?,
+            Statement::RefreshMaterializedView(stmt) => write!(f, "{stmt}")?,
             Statement::CreateIndex(stmt) => write!(f, "{stmt}")?,
             Statement::DropIndex(stmt) => write!(f, "{stmt}")?,
             Statement::CreateVirtualColumns(stmt) => write!(f, "{stmt}")?,
@@ -414,6 +474,7 @@ impl Display for Statement {
                 }
                 write!(f, " '{role}'")?;
             }
+            Statement::AlterRole(stmt) => write!(f, "{stmt}")?,
             Statement::DropRole {
                 if_exists,
                 role_name: role,
@@ -436,7 +497,6 @@ impl Display for Statement {
             Statement::CreateUDF {
                 if_not_exists,
                 udf_name,
-                parameters,
                 definition,
                 description,
             } => {
@@ -444,9 +504,7 @@ impl Display for Statement {
                 if *if_not_exists {
                     write!(f, " IF NOT EXISTS")?;
                 }
-                write!(f, " {udf_name} AS (")?;
-                write_comma_separated_list(f, parameters)?;
-                write!(f, ") -> {definition}")?;
+                write!(f, " {udf_name} {definition}")?;
                 if let Some(description) = description {
                     write!(f, " DESC = '{description}'")?;
                 }
@@ -463,13 +521,10 @@ impl Display for Statement {
             }
             Statement::AlterUDF {
                 udf_name,
-                parameters,
                 definition,
                 description,
             } => {
-                write!(f, "ALTER FUNCTION {udf_name} AS (")?;
-                write_comma_separated_list(f, parameters)?;
-                write!(f, ") -> {definition}")?;
+                write!(f, "ALTER FUNCTION {udf_name} {definition}")?;
                 if let Some(description) = description {
                     write!(f, " DESC = '{description}'")?;
                 }
@@ -536,6 +591,23 @@ impl Display for Statement {
             Statement::CreateDatamaskPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DropDatamaskPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DescDatamaskPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DropRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateNetworkPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DropNetworkPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateDictionary(stmt) => write!(f, "{stmt}")?,
+            Statement::DropDictionary(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateSequence(stmt) => write!(f, "{stmt}")?,
+            Statement::DropSequence(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateTask(stmt) => write!(f, "{stmt}")?,
+            Statement::DropTask(stmt) => write!(f, "{stmt}")?,
+            Statement::AlterTask(stmt) => write!(f, "{stmt}")?,
+            Statement::ExecuteTask(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateNotificationIntegration(stmt) => write!(f, "{stmt}")?,
+            Statement::DropNotificationIntegration(stmt) => write!(f, "{stmt}")?,
+            Statement::CreatePipe(stmt) => write!(f, "{stmt}")?,
+            Statement::DropPipe(stmt) => write!(f, "{stmt}")?,
+            Statement::AlterClusterNode(stmt) => write!(f, "{stmt}")?,
         }
         Ok(())
     }
@@ -62,6 +62,31 @@ impl Display for ShowTablesStmt {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShowDropTablesStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub limit: Option<ShowLimit>,
+}
+
+impl Display for ShowDropTablesStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "SHOW DROP TABLES")?;
+        if let Some(database) = &self.database {
+            write!(f, " FROM ")?;
+            if let Some(catalog) = &self.catalog {
+                write!(f, "{catalog}.",)?;
+            }
+            write!(f, "{database}")?;
+        }
+        if let Some(limit) = &self.limit {
+            write!(f, " {limit}")?;
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ShowCreateTableStmt {
     pub catalog: Option<Identifier>,
@@ -111,6 +136,7 @@ pub struct CreateTableStmt {
     pub source: Option<CreateTableSource>,
     pub engine: Option<Engine>,
     pub uri_location: Option<UriLocation>,
+    pub cluster_type: ClusterType,
     pub cluster_by: Vec<Expr>,
     pub table_options: BTreeMap<String, String>,
     pub as_query: Option<Box<Query>>,
@@ -144,7 +170,7 @@ impl Display for CreateTableStmt {
         }
 
         if !self.cluster_by.is_empty() {
-            write!(f, " CLUSTER BY (")?;
+            write!(f, " CLUSTER BY {}(", self.cluster_type)?;
             write_comma_separated_list(f, &self.cluster_by)?;
             write!(f, ")")?
         }
@@ -159,6 +185,72 @@ impl Display for CreateTableStmt {
     }
 }
 
+/// `ATTACH TABLE <table> '<uri>'`, the uri is the data location of a fuse table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttachTableStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+    pub uri_location: UriLocation,
+}
+
+impl Display for AttachTableStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "ATTACH TABLE ")?;
+        write_period_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )?;
+        write!(f, " {}", self.uri_location)
+    }
+}
+
+/// `CREATE EXTERNAL TABLE <table> (<columns>) LOCATION = '<uri>' PARTITIONED BY (<columns>)`,
+/// the table reads the parquet files under the uri, partitioned in hive style directories.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateExternalTableStmt {
+    pub if_not_exists: bool,
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+    pub columns: Vec<ColumnDefinition>,
+    pub uri_location: UriLocation,
+    pub partitioned_by: Vec<ColumnDefinition>,
+    pub table_options: BTreeMap<String, String>,
+}
+
+impl Display for CreateExternalTableStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE EXTERNAL TABLE ")?;
+        if self.if_not_exists {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write_period_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )?;
+        write!(f, " (")?;
+        write_comma_separated_list(f, &self.columns)?;
+        write!(f, ") LOCATION = {}", self.uri_location)?;
+        if !self.partitioned_by.is_empty() {
+            write!(f, " PARTITIONED BY (")?;
+            write_comma_separated_list(f, &self.partitioned_by)?;
+            write!(f, ")")?;
+        }
+        if !self.table_options.is_empty() {
+            write!(f, " ")?;
+            write_space_separated_map(f, self.table_options.iter())?;
+        }
+        Ok(())
+    }
+}
+
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, PartialEq)]
 pub enum CreateTableSource {
@@ -168,6 +260,16 @@ pub enum CreateTableSource {
         database: Option<Identifier>,
         table: Identifier,
     },
+    /// `USING TEMPLATE (<query>)`, the query returns the columns like `infer_schema`.
+    Template(Box<Query>),
+    /// `CLONE <table> [AT (SNAPSHOT => ... | TIMESTAMP => ...)]`, the new table starts
+    /// from a snapshot of the source table without copying its data.
+    Clone {
+        catalog: Option<Identifier>,
+        database: Option<Identifier>,
+        table: Identifier,
+        travel_point: Option<TimeTravelPoint>,
+    },
 }
 
 impl Display for CreateTableSource {
@@ -186,6 +288,23 @@ impl Display for CreateTableSource {
                 write!(f, "LIKE ")?;
                 write_period_separated_list(f, catalog.iter().chain(database).chain(Some(table)))
             }
+            CreateTableSource::Template(query) => {
+                write!(f, "USING TEMPLATE ({query})")
+            }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            } => {
+                write!(f, "CLONE ")?;
+                write_period_separated_list(f, catalog.iter().chain(database).chain(Some(table)))?;
+                match travel_point {
+                    Some(TimeTravelPoint::Snapshot(sid)) => write!(f, " AT (SNAPSHOT => '{sid}')"),
+                    Some(TimeTravelPoint::Timestamp(ts)) => write!(f, " AT (TIMESTAMP => {ts})"),
+                    None => Ok(()),
+                }
+            }
         }
     }
 }
@@ -282,6 +401,10 @@ pub enum AlterTableAction {
     RenameTable {
         new_table: Identifier,
     },
+    RenameColumn {
+        old_column: Identifier,
+        new_column: Identifier,
+    },
     AddColumn {
         column: ColumnDefinition,
     },
@@ -293,6 +416,7 @@ pub enum AlterTableAction {
         column: Identifier,
     },
     AlterTableClusterKey {
+        cluster_type: ClusterType,
         cluster_by: Vec<Expr>,
     },
     DropTableClusterKey,
@@ -306,6 +430,13 @@ pub enum AlterTableAction {
     SetOptions {
         set_options: BTreeMap<String, String>,
     },
+    AddRowAccessPolicy {
+        policy: Identifier,
+        columns: Vec<Identifier>,
+    },
+    DropRowAccessPolicy {
+        policy: Identifier,
+    },
 }
 
 impl Display for AlterTableAction {
@@ -318,6 +449,12 @@ impl Display for AlterTableAction {
             AlterTableAction::RenameTable { new_table } => {
                 write!(f, "RENAME TO {new_table}")
             }
+            AlterTableAction::RenameColumn {
+                old_column,
+                new_column,
+            } => {
+                write!(f, "RENAME COLUMN {old_column} TO {new_column}")
+            }
             AlterTableAction::AddColumn { column } => {
                 write!(f, "ADD COLUMN {column}")
             }
@@ -327,9 +464,13 @@ impl Display for AlterTableAction {
             AlterTableAction::DropColumn { column } => {
                 write!(f, "DROP COLUMN {column}")
             }
-            AlterTableAction::AlterTableClusterKey { cluster_by } => {
-                write!(f, "CLUSTER BY ")?;
-                write_comma_separated_list(f, cluster_by)
+            AlterTableAction::AlterTableClusterKey {
+                cluster_type,
+                cluster_by,
+            } => {
+                write!(f, "CLUSTER BY {cluster_type}(")?;
+                write_comma_separated_list(f, cluster_by)?;
+                write!(f, ")")
             }
             AlterTableAction::DropTableClusterKey => {
                 write!(f, "DROP CLUSTER KEY")
@@ -351,6 +492,14 @@ impl Display for AlterTableAction {
                 write!(f, "REVERT TO {}", point)?;
                 Ok(())
             }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                write!(f, "ADD ROW ACCESS POLICY {policy} ON (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ")")
+            }
+            AlterTableAction::DropRowAccessPolicy { policy } => {
+                write!(f, "DROP ROW ACCESS POLICY {policy}")
+            }
         }
     }
 }
@@ -513,6 +662,7 @@ pub enum Engine {
     Fuse,
     View,
     Random,
+    External,
 }
 
 impl Display for Engine {
@@ -523,6 +673,27 @@ impl Display for Engine {
             Engine::Fuse => write!(f, "FUSE"),
             Engine::View => write!(f, "VIEW"),
             Engine::Random => write!(f, "RANDOM"),
+            Engine::External => write!(f, "EXTERNAL"),
+        }
+    }
+}
+
+/// How the rows are ordered by the cluster keys.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ClusterType {
+    /// Sort lexicographically by the cluster keys.
+    #[default]
+    Linear,
+    /// Sort by the index of the cluster keys on a hilbert curve, so that every key
+    /// column is clustered.
+    Hilbert,
+}
+
+impl Display for ClusterType {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ClusterType::Linear => Ok(()),
+            ClusterType::Hilbert => write!(f, "HILBERT"),
         }
     }
 }
@@ -561,6 +732,10 @@ pub enum OptimizeTableAction {
         target: CompactTarget,
         limit: Option<Expr>,
     },
+    Recluster {
+        selection: Option<Expr>,
+        limit: Option<Expr>,
+    },
 }
 
 impl Display for OptimizeTableAction {
@@ -588,6 +763,16 @@ impl Display for OptimizeTableAction {
                 }
                 Ok(())
             }
+            OptimizeTableAction::Recluster { selection, limit } => {
+                write!(f, "RECLUSTER")?;
+                if let Some(selection) = selection {
+                    write!(f, " WHERE {selection}")?;
+                }
+                if let Some(limit) = limit {
+                    write!(f, " LIMIT {limit}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -597,6 +782,11 @@ pub enum ColumnExpr {
     Default(Box<Expr>),
     Virtual(Box<Expr>),
     Stored(Box<Expr>),
+    /// `AUTOINCREMENT | IDENTITY [(<start>, <increment>)]`
+    Identity {
+        start: u64,
+        increment: u64,
+    },
 }
 
 impl Display for ColumnExpr {
@@ -611,6 +801,9 @@ impl Display for ColumnExpr {
             ColumnExpr::Stored(expr) => {
                 write!(f, " AS ({expr}) STORED")?;
             }
+            ColumnExpr::Identity { start, increment } => {
+                write!(f, " AUTOINCREMENT ({start}, {increment})")?;
+            }
         }
         Ok(())
     }
@@ -645,12 +838,14 @@ impl Display for ColumnDefinition {
 #[derive(Debug, Clone, PartialEq)]
 pub enum ModifyColumnAction {
     SetMaskingPolicy(String),
+    SetDataType(TypeName),
 }
 
 impl Display for ModifyColumnAction {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match &self {
             ModifyColumnAction::SetMaskingPolicy(name) => write!(f, "SET MASKING POLICY {}", name)?,
+            ModifyColumnAction::SetDataType(data_type) => write!(f, "{}", data_type)?,
         }
 
         Ok(())
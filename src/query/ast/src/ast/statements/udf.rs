@@ -0,0 +1,160 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::ast::write_comma_separated_list;
+use crate::ast::Expr;
+use crate::ast::Identifier;
+use crate::ast::Query;
+use crate::ast::TypeName;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum UDFDefinition {
+    /// `AS (p1, p2) -> <expr>`
+    LambdaUDF {
+        parameters: Vec<Identifier>,
+        definition: Box<Expr>,
+    },
+    /// `(p1 <type>, p2 <type>) RETURNS <type> AS <expr>`
+    ScalarUDF {
+        arguments: Vec<UDFArgument>,
+        return_type: TypeName,
+        definition: Box<Expr>,
+    },
+    /// `(p1 <type>, p2 <type>) RETURNS TABLE (c1 <type>, c2 <type>) AS <query>`
+    TableUDF {
+        arguments: Vec<UDFArgument>,
+        return_columns: Vec<UDFArgument>,
+        definition: Box<Query>,
+    },
+    /// `(<type>, ...) RETURNS <type> LANGUAGE <language> HANDLER = '<handler>' ADDRESS = '<address>'`
+    UDFServer {
+        arg_types: Vec<TypeName>,
+        return_type: TypeName,
+        address: String,
+        handler: String,
+        language: String,
+    },
+    /// `(<type>, ...) STATE { s1 <type>, ... } RETURNS <type> LANGUAGE <language> HANDLER = '<handler>' ADDRESS = '<address>'`
+    UDAFServer {
+        arg_types: Vec<TypeName>,
+        state_fields: Vec<UDFArgument>,
+        return_type: TypeName,
+        address: String,
+        handler: String,
+        language: String,
+    },
+    /// `(<type>, ...) RETURNS <type> LANGUAGE WASM HANDLER = '<handler>' AS '<stage location>'`
+    WasmUDF {
+        arg_types: Vec<TypeName>,
+        return_type: TypeName,
+        handler: String,
+        location: String,
+    },
+}
+
+/// A typed argument of a SQL UDF, or a column returned by a table UDF.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UDFArgument {
+    pub name: Identifier,
+    pub data_type: TypeName,
+}
+
+impl Display for UDFArgument {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{} {}", self.name, self.data_type)
+    }
+}
+
+impl Display for UDFDefinition {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            UDFDefinition::LambdaUDF {
+                parameters,
+                definition,
+            } => {
+                write!(f, "AS (")?;
+                write_comma_separated_list(f, parameters)?;
+                write!(f, ") -> {definition}")?;
+            }
+            UDFDefinition::ScalarUDF {
+                arguments,
+                return_type,
+                definition,
+            } => {
+                write!(f, "(")?;
+                write_comma_separated_list(f, arguments)?;
+                write!(f, ") RETURNS {return_type} AS {definition}")?;
+            }
+            UDFDefinition::TableUDF {
+                arguments,
+                return_columns,
+                definition,
+            } => {
+                write!(f, "(")?;
+                write_comma_separated_list(f, arguments)?;
+                write!(f, ") RETURNS TABLE (")?;
+                write_comma_separated_list(f, return_columns)?;
+                write!(f, ") AS {definition}")?;
+            }
+            UDFDefinition::UDFServer {
+                arg_types,
+                return_type,
+                address,
+                handler,
+                language,
+            } => {
+                write!(f, "(")?;
+                write_comma_separated_list(f, arg_types)?;
+                write!(
+                    f,
+                    ") RETURNS {return_type} LANGUAGE {language} HANDLER = '{handler}' ADDRESS = '{address}'"
+                )?;
+            }
+            UDFDefinition::UDAFServer {
+                arg_types,
+                state_fields,
+                return_type,
+                address,
+                handler,
+                language,
+            } => {
+                write!(f, "(")?;
+                write_comma_separated_list(f, arg_types)?;
+                write!(f, ") STATE {{ ")?;
+                write_comma_separated_list(f, state_fields)?;
+                write!(
+                    f,
+                    " }} RETURNS {return_type} LANGUAGE {language} HANDLER = '{handler}' ADDRESS = '{address}'"
+                )?;
+            }
+            UDFDefinition::WasmUDF {
+                arg_types,
+                return_type,
+                handler,
+                location,
+            } => {
+                write!(f, "(")?;
+                write_comma_separated_list(f, arg_types)?;
+                write!(
+                    f,
+                    ") RETURNS {return_type} LANGUAGE WASM HANDLER = '{handler}' AS '{location}'"
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::fmt::Display;
 use std::fmt::Formatter;
 
@@ -23,6 +24,7 @@ use common_meta_app::principal::UserOptionFlag;
 use common_meta_app::principal::UserPrivilegeType;
 
 use crate::ast::write_comma_separated_list;
+use crate::ast::Identifier;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CreateUserStmt {
@@ -101,6 +103,40 @@ impl Display for AlterUserStmt {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlterRoleStmt {
+    pub role_name: String,
+    pub action: AlterRoleAction,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlterRoleAction {
+    SetSettings(BTreeMap<String, String>),
+    UnsetSettings(Vec<String>),
+}
+
+impl Display for AlterRoleStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ALTER ROLE '{}' ", self.role_name)?;
+        match &self.action {
+            AlterRoleAction::SetSettings(settings) => write_settings(f, settings),
+            AlterRoleAction::UnsetSettings(names) => write_unset_settings(f, names),
+        }
+    }
+}
+
+fn write_settings(f: &mut Formatter<'_>, settings: &BTreeMap<String, String>) -> std::fmt::Result {
+    write!(f, "SET SETTINGS (")?;
+    write_comma_separated_list(f, settings.iter().map(|(k, v)| format!("{k} = '{v}'")))?;
+    write!(f, ")")
+}
+
+fn write_unset_settings(f: &mut Formatter<'_>, names: &[String]) -> std::fmt::Result {
+    write!(f, "UNSET SETTINGS (")?;
+    write_comma_separated_list(f, names)?;
+    write!(f, ")")
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GrantStmt {
     pub source: AccountMgrSource,
@@ -145,6 +181,12 @@ pub enum AccountMgrSource {
     ALL {
         level: AccountMgrLevel,
     },
+    /// `SELECT(c1, c2) ON t`, only a table level can be given.
+    ColumnPrivs {
+        privilege: UserPrivilegeType,
+        columns: Vec<Identifier>,
+        level: AccountMgrLevel,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -158,6 +200,10 @@ pub enum AccountMgrLevel {
 pub enum UserOptionItem {
     TenantSetting(bool),
     DefaultRole(String),
+    SetNetworkPolicy(String),
+    UnsetNetworkPolicy,
+    SetSettings(BTreeMap<String, String>),
+    UnsetSettings(Vec<String>),
 }
 
 impl UserOptionItem {
@@ -167,6 +213,18 @@ impl UserOptionItem {
                 option.switch_option_flag(UserOptionFlag::TenantSetting, *enabled);
             }
             Self::DefaultRole(v) => option.set_default_role(Some(v.clone())),
+            Self::SetNetworkPolicy(v) => option.set_network_policy(Some(v.clone())),
+            Self::UnsetNetworkPolicy => option.set_network_policy(None),
+            Self::SetSettings(settings) => {
+                for (k, v) in settings {
+                    option.set_setting(k.clone(), v.clone());
+                }
+            }
+            Self::UnsetSettings(names) => {
+                for name in names {
+                    option.unset_setting(name);
+                }
+            }
         }
     }
 }
@@ -178,57 +236,58 @@ impl Display for AccountMgrSource {
             AccountMgrSource::Privs { privileges, level } => {
                 write!(f, " ")?;
                 write_comma_separated_list(f, privileges.iter().map(|p| p.to_string()))?;
-                write!(f, " ON")?;
-                match level {
-                    AccountMgrLevel::Global => write!(f, " *.*")?,
-                    AccountMgrLevel::Database(database_name) => {
-                        if let Some(database_name) = database_name {
-                            write!(f, " {database_name}.*")?;
-                        } else {
-                            write!(f, " *")?;
-                        }
-                    }
-                    AccountMgrLevel::Table(database_name, table_name) => {
-                        if let Some(database_name) = database_name {
-                            write!(f, " {database_name}.{table_name}")?;
-                        } else {
-                            write!(f, " {table_name}")?;
-                        }
-                    }
-                }
+                write!(f, " ON {level}")?;
             }
             AccountMgrSource::ALL { level, .. } => {
                 write!(f, " ALL PRIVILEGES")?;
-                write!(f, " ON")?;
-                match level {
-                    AccountMgrLevel::Global => write!(f, " *.*")?,
-                    AccountMgrLevel::Database(database_name) => {
-                        if let Some(database_name) = database_name {
-                            write!(f, " {database_name}.*")?;
-                        } else {
-                            write!(f, " *")?;
-                        }
-                    }
-                    AccountMgrLevel::Table(database_name, table_name) => {
-                        if let Some(database_name) = database_name {
-                            write!(f, " {database_name}.{table_name}")?;
-                        } else {
-                            write!(f, " {table_name}")?;
-                        }
-                    }
-                }
+                write!(f, " ON {level}")?;
+            }
+            AccountMgrSource::ColumnPrivs {
+                privilege,
+                columns,
+                level,
+            } => {
+                write!(f, " {privilege}(")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ") ON {level}")?;
             }
         }
         Ok(())
     }
 }
 
+impl Display for AccountMgrLevel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AccountMgrLevel::Global => write!(f, "*.*"),
+            AccountMgrLevel::Database(database_name) => {
+                if let Some(database_name) = database_name {
+                    write!(f, "{database_name}.*")
+                } else {
+                    write!(f, "*")
+                }
+            }
+            AccountMgrLevel::Table(database_name, table_name) => {
+                if let Some(database_name) = database_name {
+                    write!(f, "{database_name}.{table_name}")
+                } else {
+                    write!(f, "{table_name}")
+                }
+            }
+        }
+    }
+}
+
 impl Display for UserOptionItem {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
             UserOptionItem::TenantSetting(true) => write!(f, "TENANTSETTING"),
             UserOptionItem::TenantSetting(false) => write!(f, "NOTENANTSETTING"),
             UserOptionItem::DefaultRole(v) => write!(f, "DEFAULT_ROLE = '{}'", v),
+            UserOptionItem::SetNetworkPolicy(v) => write!(f, "SET NETWORK POLICY = '{}'", v),
+            UserOptionItem::UnsetNetworkPolicy => write!(f, "UNSET NETWORK POLICY"),
+            UserOptionItem::SetSettings(settings) => write_settings(f, settings),
+            UserOptionItem::UnsetSettings(names) => write_unset_settings(f, names),
         }
     }
 }
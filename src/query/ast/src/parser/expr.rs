@@ -249,6 +249,14 @@ pub enum ExprElement {
     PgCast {
         target_type: TypeName,
     },
+    /// `AT TIME ZONE <timezone>` expression
+    AtTimeZone {
+        timezone: Box<Expr>,
+    },
+    /// `COLLATE <collation>` expression
+    Collate {
+        collation: String,
+    },
     /// EXTRACT(IntervalKind FROM <expr>)
     Extract {
         field: IntervalKind,
@@ -293,6 +301,7 @@ pub enum ExprElement {
         args: Vec<Expr>,
         window: Option<Window>,
         params: Vec<Literal>,
+        lambda: Option<Lambda>,
     },
     /// `CASE ... WHEN ... ELSE ...` expression
     Case {
@@ -417,6 +426,8 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 BinaryOperator::Caret => Affix::Infix(Precedence(40), Associativity::Left),
             },
             ExprElement::PgCast { .. } => Affix::Postfix(Precedence(60)),
+            ExprElement::AtTimeZone { .. } => Affix::Postfix(Precedence(45)),
+            ExprElement::Collate { .. } => Affix::Postfix(Precedence(45)),
             _ => Affix::Nilfix,
         };
         Ok(affix)
@@ -491,6 +502,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 args,
                 params,
                 window,
+                lambda,
             } => Expr::FunctionCall {
                 span: transform_span(elem.span.0),
                 distinct,
@@ -498,6 +510,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 args,
                 params,
                 window,
+                lambda,
             },
             ExprElement::Case {
                 operand,
@@ -642,6 +655,27 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 target_type,
                 pg_style: true,
             },
+            ExprElement::AtTimeZone { timezone } => Expr::FunctionCall {
+                span: transform_span(elem.span.0),
+                distinct: false,
+                name: Identifier::from_name("convert_timezone"),
+                args: vec![*timezone, lhs],
+                params: vec![],
+                window: None,
+                lambda: None,
+            },
+            ExprElement::Collate { collation } => Expr::FunctionCall {
+                span: transform_span(elem.span.0),
+                distinct: false,
+                name: Identifier::from_name("collate"),
+                args: vec![lhs, Expr::Literal {
+                    span: transform_span(elem.span.0),
+                    lit: Literal::String(collation),
+                }],
+                params: vec![],
+                window: None,
+                lambda: None,
+            },
             ExprElement::UnaryOp { op } => Expr::UnaryOp {
                 span: transform_span(elem.span.0),
                 op,
@@ -726,6 +760,20 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
         },
         |(_, target_type)| ExprElement::PgCast { target_type },
     );
+    let at_time_zone = map(
+        rule! {
+            AT ~ TIME ~ ^ZONE ~ ^#subexpr(50)
+        },
+        |(_, _, _, timezone)| ExprElement::AtTimeZone {
+            timezone: Box::new(timezone),
+        },
+    );
+    let collate = map(
+        rule! {
+            COLLATE ~ ^( #literal_string | #map(ident, |ident| ident.name) )
+        },
+        |(_, collation)| ExprElement::Collate { collation },
+    );
     let extract = map(
         rule! {
             EXTRACT ~ "(" ~ ^#interval_kind ~ ^FROM ~ ^#subexpr(0) ~ ^")"
@@ -830,6 +878,25 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             args: opt_args.unwrap_or_default(),
             params: vec![],
             window: None,
+            lambda: None,
+        },
+    );
+
+    let function_call_with_lambda = map(
+        rule! {
+            #function_name
+            ~ "(" ~ #subexpr(0) ~ "," ~ #lambda_params ~ "->" ~ #subexpr(0) ~ ")"
+        },
+        |(name, _, arg, _, params, _, expr, _)| ExprElement::FunctionCall {
+            distinct: false,
+            name,
+            args: vec![arg],
+            params: vec![],
+            window: None,
+            lambda: Some(Lambda {
+                params,
+                expr: Box::new(expr),
+            }),
         },
     );
 
@@ -845,6 +912,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             args: opt_args.unwrap_or_default(),
             params: vec![],
             window: Some(window.1),
+            lambda: None,
         },
     );
 
@@ -860,6 +928,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             args: opt_args.unwrap_or_default(),
             params: params.map(|x| x.1).unwrap_or_default(),
             window: None,
+            lambda: None,
         },
     );
 
@@ -1019,6 +1088,8 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             | #timestamp_expr: "`TIMESTAMP <str_literal>`"
             | #interval: "`INTERVAL ... (YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | DOY | DOW)`"
             | #pg_cast : "`::<type_name>`"
+            | #at_time_zone : "`AT TIME ZONE <timezone>`"
+            | #collate : "`COLLATE <collation>`"
             | #extract : "`EXTRACT((YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND) FROM ...)`"
         ),
         rule!(
@@ -1028,6 +1099,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             | #trim_from : "`TRIM([(BOTH | LEADEING | TRAILING) ... FROM ...)`"
             | #is_distinct_from: "`... IS [NOT] DISTINCT FROM ...`"
             | #count_all_with_window : "`COUNT(*) OVER ...`"
+            | #function_call_with_lambda : "<function>"
             | #function_call_with_window : "<function>"
             | #function_call_with_params : "<function>"
             | #function_call : "<function>"
@@ -1045,6 +1117,17 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
     Ok((rest, WithSpan { span, elem }))
 }
 
+/// The parameters of a lambda expression, like `x` or `(acc, x)`.
+pub fn lambda_params(i: Input) -> IResult<Vec<Identifier>> {
+    let single_param = map(rule! { #ident }, |param| vec![param]);
+    let multi_params = map(
+        rule! { "(" ~ #comma_separated_list1(ident) ~ ")" },
+        |(_, params, _)| params,
+    );
+
+    alt((single_param, multi_params))(i)
+}
+
 pub fn unary_op(i: Input) -> IResult<UnaryOperator> {
     // Plus and Minus are parsed as binary op at first.
     alt((
@@ -1098,12 +1181,18 @@ pub fn binary_op(i: Input) -> IResult<BinaryOperator> {
 
 pub fn literal(i: Input) -> IResult<Literal> {
     let string = map(literal_string, Literal::String);
+    // x'FFFF', unlike 0xFFFF it's a binary value instead of a number.
+    let binary = map_res(rule! { PGLiteralHex }, |token| {
+        let hex = &token.text()[2..token.text().len() - 1];
+        literal_hex_bytes(hex).ok_or(ErrorKind::Other("invalid hex literal"))
+    });
     let boolean = map(literal_bool, Literal::Boolean);
     let current_timestamp = value(Literal::CurrentTimestamp, rule! { CURRENT_TIMESTAMP });
     let null = value(Literal::Null, rule! { NULL });
 
     rule!(
         #string
+        | #binary
         | #boolean
         | #literal_number
         | #current_timestamp
@@ -1111,6 +1200,16 @@ pub fn literal(i: Input) -> IResult<Literal> {
     )(i)
 }
 
+fn literal_hex_bytes(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect()
+}
+
 pub fn literal_hex_str(i: Input) -> IResult<&str> {
     // 0XFFFF
     let mysql_hex = map(
@@ -1313,9 +1412,14 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
     );
     let ty_string = value(
         TypeName::String,
-        rule! { ( STRING | VARCHAR | CHAR | CHARACTER | TEXT | BINARY | VARBINARY ) ~ ( "(" ~ #literal_u64 ~ ")" )? },
+        rule! { ( STRING | VARCHAR | CHAR | CHARACTER | TEXT ) ~ ( "(" ~ #literal_u64 ~ ")" )? },
+    );
+    let ty_binary = value(
+        TypeName::Binary,
+        rule! { ( BINARY | VARBINARY | BYTEA | BLOB ) ~ ( "(" ~ #literal_u64 ~ ")" )? },
     );
     let ty_variant = value(TypeName::Variant, rule! { VARIANT | JSON });
+    let ty_geometry = value(TypeName::Geometry, rule! { GEOMETRY });
     map(
         alt((
             rule! {
@@ -1342,7 +1446,9 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
             ( #ty_date
             | #ty_datetime
             | #ty_string
+            | #ty_binary
             | #ty_variant
+            | #ty_geometry
             | #ty_nullable
             ) ~ NULL? : "type name" },
         )),
@@ -418,6 +418,28 @@ pub fn alias_name(i: Input) -> IResult<Identifier> {
     )(i)
 }
 
+// SAMPLE [ROW | BLOCK] (<probability> [PERCENT])
+pub fn table_sample(i: Input) -> IResult<TableSample> {
+    let percent = map_res(
+        rule! {
+            LiteralInteger | LiteralFloat
+        },
+        |token| Ok(fast_float::parse(token.text())?),
+    );
+    map(
+        rule! {
+            SAMPLE ~ (ROW | BLOCK)? ~ "(" ~ #percent ~ PERCENT? ~ ")"
+        },
+        |(_, level, _, percent, _, _)| TableSample {
+            level: match level.map(|token| token.kind) {
+                Some(BLOCK) => SampleLevel::Block,
+                _ => SampleLevel::Row,
+            },
+            percent,
+        },
+    )(i)
+}
+
 pub fn table_alias(i: Input) -> IResult<TableAlias> {
     map(
         rule! { #alias_name ~ ( "(" ~ ^#comma_separated_list1(ident) ~ ")")? },
@@ -477,11 +499,13 @@ pub enum TableFunctionParam {
 }
 
 pub fn table_function_param(i: Input) -> IResult<TableFunctionParam> {
-    let named = map(rule! { #ident ~ "=>" ~ #expr  }, |(name, _, value)| {
-        TableFunctionParam::Named {
-            name: name.to_string(),
-            value,
-        }
+    // `OUTER` is a reserved keyword, but also the name of an argument of `FLATTEN`.
+    let param_name = alt((
+        map(ident, |name| name.to_string()),
+        map(rule! { OUTER }, |token| token.text().to_string()),
+    ));
+    let named = map(rule! { #param_name ~ "=>" ~ #expr  }, |(name, _, value)| {
+        TableFunctionParam::Named { name, value }
     });
     let normal = map(rule! { #expr }, TableFunctionParam::Normal);
 
@@ -500,17 +524,22 @@ pub enum TableReferenceElement {
         travel_point: Option<TimeTravelPoint>,
         pivot: Option<Box<Pivot>>,
         unpivot: Option<Box<Unpivot>>,
+        sample: Option<TableSample>,
     },
-    // `TABLE(expr)[ AS alias ]`
+    // `[LATERAL] TABLE(expr)[ AS alias ]`
     TableFunction {
+        lateral: bool,
         name: Identifier,
         params: Vec<TableFunctionParam>,
         alias: Option<TableAlias>,
     },
     // Derived table, which can be a subquery or joined tables or combination of them
     Subquery {
+        lateral: bool,
         subquery: Box<Query>,
         alias: Option<TableAlias>,
+        pivot: Option<Box<Pivot>>,
+        unpivot: Option<Box<Unpivot>>,
     },
     // [NATURAL] [INNER|OUTER|CROSS|...] JOIN
     Join {
@@ -529,32 +558,41 @@ pub enum TableReferenceElement {
 
 pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceElement>> {
     // PIVOT(expr FOR col IN (ident, ...))
-    let pivot = map(
-        rule! {
-           PIVOT ~ "(" ~ #expr ~ FOR ~ #ident ~ IN ~ "(" ~ #comma_separated_list1(expr) ~ ")" ~ ")"
-        },
-        |(_pivot, _, aggregate, _for, value_column, _in, _, values, _, _)| Pivot {
-            aggregate,
-            value_column,
-            values,
-        },
-    );
+    let pivot = |i| {
+        map(
+            rule! {
+               PIVOT ~ "(" ~ #expr ~ FOR ~ #ident ~ IN ~ "(" ~ #comma_separated_list1(expr) ~ ")" ~ ")"
+            },
+            |(_pivot, _, aggregate, _for, value_column, _in, _, values, _, _)| Pivot {
+                aggregate,
+                value_column,
+                values,
+            },
+        )(i)
+    };
     // UNPIVOT(ident for ident IN (ident, ...))
-    let unpivot = map(
-        rule! {
-            UNPIVOT ~ "(" ~ #ident ~ FOR ~ #ident ~ IN ~ "(" ~ #comma_separated_list1(ident) ~ ")" ~ ")"
-        },
-        |(_unpivot, _, value_column, _for, column_name, _in, _, names, _, _)| Unpivot {
-            value_column,
-            column_name,
-            names,
-        },
-    );
+    let unpivot = |i| {
+        map(
+            rule! {
+                UNPIVOT ~ "(" ~ #ident ~ FOR ~ #ident ~ IN ~ "(" ~ #comma_separated_list1(ident) ~ ")" ~ ")"
+            },
+            |(_unpivot, _, value_column, _for, column_name, _in, _, names, _, _)| Unpivot {
+                value_column,
+                column_name,
+                names,
+            },
+        )(i)
+    };
+    // `SAMPLE` is not reserved, try it before taking it as the alias.
+    let alias_and_sample = alt((
+        map(table_sample, |sample| (None, Some(sample))),
+        rule! { #table_alias? ~ #table_sample? },
+    ));
     let aliased_table = map(
         rule! {
-            #period_separated_idents_1_to_3 ~ (AT ~ #travel_point)? ~ #table_alias? ~ #pivot? ~ #unpivot?
+            #period_separated_idents_1_to_3 ~ (AT ~ #travel_point)? ~ #alias_and_sample ~ #pivot? ~ #unpivot?
         },
-        |((catalog, database, table), travel_point_opt, alias, pivot, unpivot)| {
+        |((catalog, database, table), travel_point_opt, (alias, sample), pivot, unpivot)| {
             TableReferenceElement::Table {
                 catalog,
                 database,
@@ -563,6 +601,7 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
                 travel_point: travel_point_opt.map(|p| p.1),
                 pivot: pivot.map(Box::new),
                 unpivot: unpivot.map(Box::new),
+                sample,
             }
         },
     );
@@ -589,9 +628,10 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
     );
     let table_function = map(
         rule! {
-            #function_name ~ "(" ~ #comma_separated_list0(table_function_param) ~ ")" ~ #table_alias?
+            LATERAL? ~ #function_name ~ "(" ~ #comma_separated_list0(table_function_param) ~ ")" ~ #table_alias?
         },
-        |(name, _, params, _, alias)| TableReferenceElement::TableFunction {
+        |(lateral, name, _, params, _, alias)| TableReferenceElement::TableFunction {
+            lateral: lateral.is_some(),
             name,
             params,
             alias,
@@ -599,11 +639,14 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
     );
     let subquery = map(
         rule! {
-            "(" ~ #query ~ ")" ~ #table_alias?
+            LATERAL? ~ "(" ~ #query ~ ")" ~ #table_alias? ~ #pivot? ~ #unpivot?
         },
-        |(_, subquery, _, alias)| TableReferenceElement::Subquery {
+        |(lateral, _, subquery, _, alias, pivot, unpivot)| TableReferenceElement::Subquery {
+            lateral: lateral.is_some(),
             subquery: Box::new(subquery),
             alias,
+            pivot: pivot.map(Box::new),
+            unpivot: unpivot.map(Box::new),
         },
     );
 
@@ -635,8 +678,8 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
     let (rest, (span, elem)) = consumed(rule! {
         #aliased_stage
         | #table_function
-        | #aliased_table
         | #subquery
+        | #aliased_table
         | #group
         | #join
         | #join_condition_on
@@ -674,6 +717,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 travel_point,
                 pivot,
                 unpivot,
+                sample,
             } => TableReference::Table {
                 span: transform_span(input.span.0),
                 catalog,
@@ -683,8 +727,10 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 travel_point,
                 pivot,
                 unpivot,
+                sample,
             },
             TableReferenceElement::TableFunction {
+                lateral,
                 name,
                 params,
                 alias,
@@ -705,16 +751,26 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                     .collect();
                 TableReference::TableFunction {
                     span: transform_span(input.span.0),
+                    lateral,
                     name,
                     params: normal_params,
                     named_params,
                     alias,
                 }
             }
-            TableReferenceElement::Subquery { subquery, alias } => TableReference::Subquery {
+            TableReferenceElement::Subquery {
+                lateral,
+                subquery,
+                alias,
+                pivot,
+                unpivot,
+            } => TableReference::Subquery {
                 span: transform_span(input.span.0),
+                lateral,
                 subquery,
                 alias,
+                pivot,
+                unpivot,
             },
             TableReferenceElement::Stage {
                 location,
@@ -859,7 +915,7 @@ pub fn window_spec(i: Input) -> IResult<WindowSpec> {
         rule! {
             (#ident )? ~ (PARTITION ~ ^BY ~ #comma_separated_list1(subexpr(0)))?
             ~ ( ORDER ~ ^BY ~ ^#comma_separated_list1(order_by_expr) )?
-            ~ ((ROWS | RANGE) ~ #window_frame_between)?
+            ~ ((ROWS | RANGE | GROUPS) ~ #window_frame_between)?
         },
         |(existing_window_name, opt_partition, opt_order, between)| WindowSpec {
             existing_window_name,
@@ -869,6 +925,7 @@ pub fn window_spec(i: Input) -> IResult<WindowSpec> {
                 let unit = match x.0.kind {
                     ROWS => WindowFrameUnits::Rows,
                     RANGE => WindowFrameUnits::Range,
+                    GROUPS => WindowFrameUnits::Groups,
                     _ => unreachable!(),
                 };
                 let bw = x.1;
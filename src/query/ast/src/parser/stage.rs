@@ -193,6 +193,22 @@ pub fn select_stage_option(i: Input) -> IResult<SelectStageOption> {
             rule! { FILE_FORMAT ~ "=>" ~ #literal_string },
             |(_, _, file_format)| SelectStageOption::FileFormat(file_format),
         ),
+        map(
+            rule! { LAST_MODIFIED_AFTER ~ "=>" ~ #literal_string },
+            |(_, _, after)| SelectStageOption::LastModifiedAfter(after),
+        ),
+        map(
+            rule! { LAST_MODIFIED_BEFORE ~ "=>" ~ #literal_string },
+            |(_, _, before)| SelectStageOption::LastModifiedBefore(before),
+        ),
+        map(
+            rule! { MIN_FILE_SIZE ~ "=>" ~ #literal_u64 },
+            |(_, _, size)| SelectStageOption::MinFileSize(size),
+        ),
+        map(
+            rule! { MAX_FILE_SIZE ~ "=>" ~ #literal_u64 },
+            |(_, _, size)| SelectStageOption::MaxFileSize(size),
+        ),
         map(connection_opt, SelectStageOption::Connection),
     ))(i)
 }
@@ -112,6 +112,45 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                     .unwrap_or_default(),
                 source,
                 overwrite: overwrite.kind == OVERWRITE,
+                on_conflict: None,
+            })
+        },
+    );
+
+    // Unlike the plain `VALUES`, the rows are parsed to find the `ON CONFLICT` clause.
+    let on_conflict_values = map(
+        consumed(rule! {
+            VALUES ~ #comma_separated_list1(values_row)
+        }),
+        |(span, _)| {
+            let first_token = &span.0[1];
+            let last_token = span.0.last().unwrap();
+            InsertSource::Values {
+                rest_str: first_token.source[first_token.span.start..last_token.span.end]
+                    .to_string(),
+            }
+        },
+    );
+    let insert_on_conflict = map(
+        rule! {
+            INSERT ~ #hint? ~ INTO ~ TABLE?
+            ~ #period_separated_idents_1_to_3
+            ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
+            ~ ( #on_conflict_values | #map(query, |query| InsertSource::Select { query: Box::new(query) }) )
+            ~ #on_conflict
+        },
+        |(_, opt_hints, _, _, (catalog, database, table), opt_columns, source, on_conflict)| {
+            Statement::Insert(InsertStmt {
+                hints: opt_hints,
+                catalog,
+                database,
+                table,
+                columns: opt_columns
+                    .map(|(_, columns, _)| columns)
+                    .unwrap_or_default(),
+                source,
+                overwrite: false,
+                on_conflict: Some(on_conflict),
             })
         },
     );
@@ -147,6 +186,42 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let merge_into = map(
+        rule! {
+            MERGE ~ INTO ~ #period_separated_idents_1_to_3 ~ #table_alias?
+            ~ USING ~ #merge_source
+            ~ ON ~ ^#expr
+            ~ #merge_option+
+        },
+        |(_, _, (catalog, database, table), target_alias, _, source, _, join_expr, options)| {
+            let mut matched_clauses = vec![];
+            let mut unmatched_clauses = vec![];
+            for option in options {
+                match option {
+                    MergeOption::Match(clause) => matched_clauses.push(clause),
+                    MergeOption::Unmatch(clause) => unmatched_clauses.push(clause),
+                }
+            }
+            Statement::MergeInto(MergeIntoStmt {
+                catalog,
+                database,
+                table,
+                target_alias,
+                source,
+                join_expr,
+                matched_clauses,
+                unmatched_clauses,
+            })
+        },
+    );
+
+    let begin = value(Statement::Begin, rule! { BEGIN ~ ( TRANSACTION | WORK )? });
+    let commit = value(Statement::Commit, rule! { COMMIT ~ ( WORK )? });
+    let abort = value(
+        Statement::Abort,
+        rule! { ( ABORT | ROLLBACK ) ~ ( TRANSACTION | WORK )? },
+    );
+
     let delete = map(
         rule! {
             DELETE ~ #hint? ~ FROM ~ #table_reference_only
@@ -366,6 +441,33 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let backup_database = map(
+        rule! {
+            BACKUP ~ DATABASE ~ #period_separated_idents_1_to_2 ~ TO ~ #at_string ~ ( WITH ~ DATA )?
+        },
+        |(_, _, (catalog, database), _, location, opt_with_data)| {
+            Statement::BackupDatabase(BackupDatabaseStmt {
+                catalog,
+                database,
+                location,
+                with_data: opt_with_data.is_some(),
+            })
+        },
+    );
+
+    let restore_database = map(
+        rule! {
+            RESTORE ~ DATABASE ~ #period_separated_idents_1_to_2 ~ FROM ~ #at_string
+        },
+        |(_, _, (catalog, database), _, location)| {
+            Statement::RestoreDatabase(RestoreDatabaseStmt {
+                catalog,
+                database,
+                location,
+            })
+        },
+    );
+
     let alter_database = map(
         rule! {
             ALTER ~ DATABASE ~ ( IF ~ EXISTS )? ~ #period_separated_idents_1_to_2 ~ #alter_database_action
@@ -404,6 +506,23 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let show_drop_tables = map(
+        rule! {
+            SHOW ~ DROP ~ TABLES ~ ( ( FROM | IN ) ~ #period_separated_idents_1_to_2 )? ~ #show_limit?
+        },
+        |(_, _, _, ctl_db, limit)| {
+            let (catalog, database) = match ctl_db {
+                Some((_, (Some(c), d))) => (Some(c), Some(d)),
+                Some((_, (None, d))) => (None, Some(d)),
+                _ => (None, None),
+            };
+            Statement::ShowDropTables(ShowDropTablesStmt {
+                catalog,
+                database,
+                limit,
+            })
+        },
+    );
     let show_columns = map(
         rule! {
             SHOW ~ FULL? ~ COLUMNS ~ ( FROM | IN ) ~ #ident ~ ((FROM | IN) ~ #period_separated_idents_1_to_2)? ~ #show_limit?
@@ -480,7 +599,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             ~ #create_table_source?
             ~ ( #engine )?
             ~ ( #uri_location )?
-            ~ ( CLUSTER ~ ^BY ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" )?
+            ~ ( CLUSTER ~ ^BY ~ #cluster_type? ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" )?
             ~ ( #table_option )?
             ~ ( AS ~ ^#query )?
         },
@@ -505,8 +624,12 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                 source,
                 engine,
                 uri_location,
+                cluster_type: opt_cluster_by
+                    .as_ref()
+                    .and_then(|(_, _, cluster_type, _, _, _)| *cluster_type)
+                    .unwrap_or_default(),
                 cluster_by: opt_cluster_by
-                    .map(|(_, _, _, exprs, _)| exprs)
+                    .map(|(_, _, _, _, exprs, _)| exprs)
                     .unwrap_or_default(),
                 table_options: opt_table_options.unwrap_or_default(),
                 as_query: opt_as_query.map(|(_, query)| Box::new(query)),
@@ -514,6 +637,57 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let create_external_table = map(
+        rule! {
+            CREATE ~ EXTERNAL ~ ^TABLE ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #period_separated_idents_1_to_3
+            ~ ^"(" ~ ^#comma_separated_list1(column_def) ~ ^")"
+            ~ ^LOCATION ~ "="? ~ ^#uri_location
+            ~ ( PARTITIONED ~ ^BY ~ ^"(" ~ ^#comma_separated_list1(column_def) ~ ^")" )?
+            ~ ( #table_option )?
+        },
+        |(
+            _,
+            _,
+            _,
+            opt_if_not_exists,
+            (catalog, database, table),
+            _,
+            columns,
+            _,
+            _,
+            _,
+            uri_location,
+            opt_partitioned_by,
+            opt_table_options,
+        )| {
+            Statement::CreateExternalTable(CreateExternalTableStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                catalog,
+                database,
+                table,
+                columns,
+                uri_location,
+                partitioned_by: opt_partitioned_by
+                    .map(|(_, _, _, columns, _)| columns)
+                    .unwrap_or_default(),
+                table_options: opt_table_options.unwrap_or_default(),
+            })
+        },
+    );
+    let attach_table = map(
+        rule! {
+            ATTACH ~ TABLE ~ #period_separated_idents_1_to_3 ~ #uri_location
+        },
+        |(_, _, (catalog, database, table), uri_location)| {
+            Statement::AttachTable(AttachTableStmt {
+                catalog,
+                database,
+                table,
+                uri_location,
+            })
+        },
+    );
     let drop_table = map(
         rule! {
             DROP ~ TABLE ~ ( IF ~ EXISTS )? ~ #period_separated_idents_1_to_3 ~ ( ALL )?
@@ -691,6 +865,48 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let create_materialized_view = map(
+        rule! {
+            CREATE ~ MATERIALIZED ~ VIEW ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #period_separated_idents_1_to_3
+            ~ AS ~ #query
+        },
+        |(_, _, _, opt_if_not_exists, (catalog, database, view), _, query)| {
+            Statement::CreateMaterializedView(CreateMaterializedViewStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                catalog,
+                database,
+                view,
+                query: Box::new(query),
+            })
+        },
+    );
+    let drop_materialized_view = map(
+        rule! {
+            DROP ~ MATERIALIZED ~ VIEW ~ ( IF ~ EXISTS )? ~ #period_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, (catalog, database, view))| {
+            Statement::DropMaterializedView(DropMaterializedViewStmt {
+                if_exists: opt_if_exists.is_some(),
+                catalog,
+                database,
+                view,
+            })
+        },
+    );
+    let refresh_materialized_view = map(
+        rule! {
+            REFRESH ~ MATERIALIZED ~ VIEW ~ #period_separated_idents_1_to_3
+        },
+        |(_, _, _, (catalog, database, view))| {
+            Statement::RefreshMaterializedView(RefreshMaterializedViewStmt {
+                catalog,
+                database,
+                view,
+            })
+        },
+    );
+
     let create_index = map(
         rule! {
             CREATE ~ AGGREGATING ~ INDEX ~ ( IF ~ NOT ~ EXISTS )?
@@ -733,6 +949,20 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let create_virtual_columns_on = map(
+        rule! {
+            CREATE ~ VIRTUAL ~ ( COLUMN | COLUMNS ) ~ ON ~ #period_separated_idents_1_to_3 ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")"
+        },
+        |(_, _, _, _, (catalog, database, table), _, virtual_columns, _)| {
+            Statement::CreateVirtualColumns(CreateVirtualColumnsStmt {
+                catalog,
+                database,
+                table,
+                virtual_columns,
+            })
+        },
+    );
+
     let alter_virtual_columns = map(
         rule! {
             ALTER ~ VIRTUAL ~ COLUMNS ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" ~ FOR ~ #period_separated_idents_1_to_3
@@ -795,11 +1025,19 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    // `SET SETTINGS` and `UNSET SETTINGS` can be given without `WITH`.
+    let alter_user_options = alt((
+        map(
+            rule! { WITH ~ ^#comma_separated_list1(user_option) },
+            |(_, user_options)| user_options,
+        ),
+        map(user_settings_option, |user_option| vec![user_option]),
+    ));
     let alter_user = map(
         rule! {
             ALTER ~ USER ~ ( #map(rule! { USER ~ "(" ~ ")" }, |_| None) | #map(user_identity, Some) )
             ~ ( IDENTIFIED ~ ( WITH ~ ^#auth_type )? ~ ( BY ~ ^#literal_string )? )?
-            ~ ( WITH ~ ^#comma_separated_list1(user_option) )?
+            ~ #alter_user_options?
         },
         |(_, _, user, opt_auth_option, opt_user_option)| {
             Statement::AlterUser(AlterUserStmt {
@@ -808,9 +1046,7 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                     auth_type: opt_auth_type.map(|(_, auth_type)| auth_type),
                     password: opt_password.map(|(_, password)| password),
                 }),
-                user_options: opt_user_option
-                    .map(|(_, user_options)| user_options)
-                    .unwrap_or_default(),
+                user_options: opt_user_option.unwrap_or_default(),
             })
         },
     );
@@ -833,6 +1069,16 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             role_name,
         },
     );
+    let alter_role_action = alt((
+        map(set_settings, AlterRoleAction::SetSettings),
+        map(unset_settings, AlterRoleAction::UnsetSettings),
+    ));
+    let alter_role = map(
+        rule! {
+            ALTER ~ ROLE ~ #literal_string ~ #alter_role_action
+        },
+        |(_, _, role_name, action)| Statement::AlterRole(AlterRoleStmt { role_name, action }),
+    );
     let drop_role = map(
         rule! {
             DROP ~ ROLE ~ ( IF ~ EXISTS )? ~ #literal_string
@@ -885,30 +1131,14 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         rule! {
             CREATE ~ FUNCTION ~ ( IF ~ NOT ~ EXISTS )?
             ~ #ident
-            ~ AS ~ "(" ~ #comma_separated_list0(ident) ~ ")"
-            ~ "->" ~ #expr
+            ~ #udf_definition
             ~ ( DESC ~ ^"=" ~ ^#literal_string )?
         },
-        |(
-            _,
-            _,
-            opt_if_not_exists,
+        |(_, _, opt_if_not_exists, udf_name, definition, opt_description)| Statement::CreateUDF {
+            if_not_exists: opt_if_not_exists.is_some(),
             udf_name,
-            _,
-            _,
-            parameters,
-            _,
-            _,
             definition,
-            opt_description,
-        )| {
-            Statement::CreateUDF {
-                if_not_exists: opt_if_not_exists.is_some(),
-                udf_name,
-                parameters,
-                definition: Box::new(definition),
-                description: opt_description.map(|(_, _, description)| description),
-            }
+            description: opt_description.map(|(_, _, description)| description),
         },
     );
     let drop_udf = map(
@@ -924,17 +1154,13 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         rule! {
             ALTER ~ FUNCTION
             ~ #ident
-            ~ AS ~ "(" ~ #comma_separated_list0(ident) ~ ")"
-            ~ "->" ~ #expr
+            ~ #udf_definition
             ~ ( DESC ~ ^"=" ~ ^#literal_string )?
         },
-        |(_, _, udf_name, _, _, parameters, _, _, definition, opt_description)| {
-            Statement::AlterUDF {
-                udf_name,
-                parameters,
-                definition: Box::new(definition),
-                description: opt_description.map(|(_, _, description)| description),
-            }
+        |(_, _, udf_name, definition, opt_description)| Statement::AlterUDF {
+            udf_name,
+            definition,
+            description: opt_description.map(|(_, _, description)| description),
         },
     );
 
@@ -1042,6 +1268,9 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
                 force: Default::default(),
                 disable_variant_check: Default::default(),
                 on_error: "abort".to_string(),
+                match_by_column_name: Default::default(),
+                error_on_column_count_mismatch: Default::default(),
+                partition_by: Default::default(),
             };
             for opt in opts {
                 copy_stmt.apply_option(opt);
@@ -1266,92 +1495,480 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
-    let statement_body = alt((
-        rule!(
-            #map(query, |query| Statement::Query(Box::new(query)))
-            | #explain : "`EXPLAIN [PIPELINE | GRAPH] <statement>`"
-            | #explain_analyze : "`EXPLAIN ANALYZE <statement>`"
-            | #delete : "`DELETE FROM <table> [WHERE ...]`"
-            | #update : "`UPDATE <table> SET <column> = <expr> [, <column> = <expr> , ... ] [WHERE ...]`"
-            | #show_settings : "`SHOW SETTINGS [<show_limit>]`"
-            | #show_stages : "`SHOW STAGES`"
-            | #show_engines : "`SHOW ENGINES`"
-            | #show_process_list : "`SHOW PROCESSLIST`"
-            | #show_metrics : "`SHOW METRICS`"
-            | #show_functions : "`SHOW FUNCTIONS [<show_limit>]`"
-            | #show_indexes : "`SHOW INDEXES`"
-            | #kill_stmt : "`KILL (QUERY | CONNECTION) <object_id>`"
-            | #set_role: "`SET [DEFAULT] ROLE <role>`"
-            | #show_databases : "`SHOW [FULL] DATABASES [(FROM | IN) <catalog>] [<show_limit>]`"
-            | #undrop_database : "`UNDROP DATABASE <database>`"
-            | #show_create_database : "`SHOW CREATE DATABASE <database>`"
-            | #create_database : "`CREATE DATABASE [IF NOT EXIST] <database> [ENGINE = <engine>]`"
-            | #drop_database : "`DROP DATABASE [IF EXISTS] <database>`"
-            | #alter_database : "`ALTER DATABASE [IF EXISTS] <action>`"
-            | #use_database : "`USE <database>`"
-        ),
-        rule!(
-            #insert : "`INSERT INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
-            | #replace : "`REPLACE INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
-        ),
-        rule!(
-            #set_variable : "`SET <variable> = <value>`"
-            | #unset_variable : "`UNSET <variable>`"
-        ),
-        rule!(
-            #show_tables : "`SHOW [FULL] TABLES [FROM <database>] [<show_limit>]`"
-            | #show_columns : "`SHOW [FULL] COLUMNS FROM <table> [FROM|IN <catalog>.<database>] [<show_limit>]`"
-            | #show_create_table : "`SHOW CREATE TABLE [<database>.]<table>`"
-            | #describe_table : "`DESCRIBE [<database>.]<table>`"
-            | #show_fields : "`SHOW FIELDS FROM [<database>.]<table>`"
-            | #show_tables_status : "`SHOW TABLES STATUS [FROM <database>] [<show_limit>]`"
-            | #create_table : "`CREATE TABLE [IF NOT EXISTS] [<database>.]<table> [<source>] [<table_options>]`"
-            | #drop_table : "`DROP TABLE [IF EXISTS] [<database>.]<table>`"
-            | #undrop_table : "`UNDROP TABLE [<database>.]<table>`"
-            | #alter_table : "`ALTER TABLE [<database>.]<table> <action>`"
-            | #rename_table : "`RENAME TABLE [<database>.]<table> TO <new_table>`"
-            | #truncate_table : "`TRUNCATE TABLE [<database>.]<table> [PURGE]`"
-            | #optimize_table : "`OPTIMIZE TABLE [<database>.]<table> (ALL | PURGE | COMPACT [SEGMENT])`"
-            | #vacuum_table : "`VACUUM TABLE [<database>.]<table> [RETAIN number HOURS] [DRY RUN]`"
-            | #analyze_table : "`ANALYZE TABLE [<database>.]<table>`"
-            | #exists_table : "`EXISTS TABLE [<database>.]<table>`"
-            | #show_table_functions : "`SHOW TABLE_FUNCTIONS [<show_limit>]`"
-        ),
-        rule!(
-            #create_view : "`CREATE VIEW [IF NOT EXISTS] [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
-            | #drop_view : "`DROP VIEW [IF EXISTS] [<database>.]<view>`"
-            | #alter_view : "`ALTER VIEW [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
-        ),
-        rule!(
-            #create_index: "`CREATE AGGREGATING INDEX [IF NOT EXISTS] <index> AS SELECT ...`"
-            | #drop_index: "`DROP AGGREGATING INDEX [IF EXISTS] <index>`"
-        ),
-        rule!(
-            #create_virtual_columns: "`CREATE VIRTUAL COLUMNS (expr, ...) FOR [<database>.]<table>`"
-            | #alter_virtual_columns: "`ALTER VIRTUAL COLUMNS (expr, ...) FOR [<database>.]<table>`"
-            | #drop_virtual_columns: "`DROP VIRTUAL COLUMNS FOR [<database>.]<table>`"
-            | #generate_virtual_columns: "`GENERATE VIRTUAL COLUMNS FOR [<database>.]<table>`"
-        ),
-        rule!(
-            #show_users : "`SHOW USERS`"
-            | #create_user : "`CREATE USER [IF NOT EXISTS] '<username>'@'hostname' IDENTIFIED [WITH <auth_type>] [BY <password>] [WITH <user_option>, ...]`"
-            | #alter_user : "`ALTER USER ('<username>'@'hostname' | USER()) [IDENTIFIED [WITH <auth_type>] [BY <password>]] [WITH <user_option>, ...]`"
-            | #drop_user : "`DROP USER [IF EXISTS] '<username>'@'hostname'`"
-            | #show_roles : "`SHOW ROLES`"
-            | #create_role : "`CREATE ROLE [IF NOT EXISTS] '<role_name>']`"
-            | #drop_role : "`DROP ROLE [IF EXISTS] '<role_name>'`"
-            | #create_udf : "`CREATE FUNCTION [IF NOT EXISTS] <udf_name> (<parameter>, ...) -> <definition expr> [DESC = <description>]`"
-            | #drop_udf : "`DROP FUNCTION [IF EXISTS] <udf_name>`"
-            | #alter_udf : "`ALTER FUNCTION <udf_name> (<parameter>, ...) -> <definition_expr> [DESC = <description>]`"
-        ),
-        rule!(
-            #create_stage: "`CREATE STAGE [ IF NOT EXISTS ] <stage_name>
-                [ FILE_FORMAT = ( { TYPE = { CSV | PARQUET } [ formatTypeOptions ] ) } ]
-                [ COPY_OPTIONS = ( copyOptions ) ]
-                [ COMMENT = '<string_literal>' ]`"
-            | #desc_stage: "`DESC STAGE <stage_name>`"
-            | #list_stage: "`LIST @<stage_name> [pattern = '<pattern>']`"
-            | #remove_stage: "`REMOVE @<stage_name> [pattern = '<pattern>']`"
+    // row access policy
+    let row_access_policy_arg = map(rule! { #ident ~ #type_name }, |(name, data_type)| {
+        RowAccessPolicyArg { name, data_type }
+    });
+    let create_row_access_policy = map(
+        rule! {
+            CREATE ~ ROW ~ ACCESS ~ POLICY ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident
+            ~ AS ~ ^"(" ~ ^#comma_separated_list1(row_access_policy_arg) ~ ^")"
+            ~ ^RETURNS ~ ^BOOLEAN ~ ^"->" ~ ^#expr
+            ~ ( EXEMPT ~ ^ROLES ~ ^"=" ~ ^"(" ~ ^#comma_separated_list1(literal_string) ~ ^")" )?
+            ~ ( COMMENT ~ "=" ~ #literal_string )?
+        },
+        |(
+            _,
+            _,
+            _,
+            _,
+            opt_if_not_exists,
+            name,
+            _,
+            _,
+            args,
+            _,
+            _,
+            _,
+            _,
+            body,
+            opt_exempt_roles,
+            opt_comment,
+        )| {
+            Statement::CreateRowAccessPolicy(CreateRowAccessPolicyStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name,
+                args,
+                body,
+                exempt_roles: opt_exempt_roles
+                    .map(|(_, _, _, _, roles, _)| roles)
+                    .unwrap_or_default(),
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+    let drop_row_access_policy = map(
+        rule! {
+            DROP ~ ROW ~ ACCESS ~ POLICY ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, _, _, opt_if_exists, name)| {
+            Statement::DropRowAccessPolicy(DropRowAccessPolicyStmt {
+                if_exists: opt_if_exists.is_some(),
+                name,
+            })
+        },
+    );
+
+    // network policy
+    let create_network_policy = map(
+        rule! {
+            CREATE ~ NETWORK ~ ^POLICY ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident
+            ~ ALLOWED_IP_LIST ~ ^"=" ~ ^"(" ~ ^#comma_separated_list1(literal_string) ~ ^")"
+            ~ ( BLOCKED_IP_LIST ~ ^"=" ~ ^"(" ~ ^#comma_separated_list1(literal_string) ~ ^")" )?
+            ~ ( COMMENT ~ "=" ~ #literal_string )?
+        },
+        |(
+            _,
+            _,
+            _,
+            opt_if_not_exists,
+            name,
+            _,
+            _,
+            _,
+            allowed_ip_list,
+            _,
+            opt_blocked_ip_list,
+            opt_comment,
+        )| {
+            Statement::CreateNetworkPolicy(CreateNetworkPolicyStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name,
+                allowed_ip_list,
+                blocked_ip_list: opt_blocked_ip_list
+                    .map(|(_, _, _, ips, _)| ips)
+                    .unwrap_or_default(),
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+    let drop_network_policy = map(
+        rule! {
+            DROP ~ NETWORK ~ ^POLICY ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, _, opt_if_exists, name)| {
+            Statement::DropNetworkPolicy(DropNetworkPolicyStmt {
+                if_exists: opt_if_exists.is_some(),
+                name,
+            })
+        },
+    );
+
+    // dictionary
+    let dictionary_column = map(rule! { #ident ~ #type_name }, |(name, data_type)| {
+        DictionaryColumn { name, data_type }
+    });
+    let create_dictionary = map(
+        rule! {
+            CREATE ~ DICTIONARY ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident
+            ~ "(" ~ ^#comma_separated_list1(dictionary_column) ~ ^")"
+            ~ PRIMARY ~ ^KEY ~ ^#ident
+            ~ SOURCE ~ ^"(" ~ ^#ident ~ ^#connection_options ~ ^")"
+            ~ ( COMMENT ~ "=" ~ #literal_string )?
+        },
+        |(
+            _,
+            _,
+            opt_if_not_exists,
+            name,
+            _,
+            columns,
+            _,
+            _,
+            _,
+            primary_key,
+            _,
+            _,
+            source_type,
+            source_options,
+            _,
+            opt_comment,
+        )| {
+            Statement::CreateDictionary(CreateDictionaryStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name,
+                columns,
+                primary_key,
+                source_type,
+                source_options,
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+    let drop_dictionary = map(
+        rule! {
+            DROP ~ DICTIONARY ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, name)| {
+            Statement::DropDictionary(DropDictionaryStmt {
+                if_exists: opt_if_exists.is_some(),
+                name,
+            })
+        },
+    );
+
+    // sequence
+    let create_sequence = map(
+        rule! {
+            CREATE ~ SEQUENCE ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident
+            ~ ( START ~ WITH? ~ ^#literal_u64 )?
+            ~ ( INCREMENT ~ BY? ~ ^#literal_u64 )?
+            ~ ( COMMENT ~ "=" ~ #literal_string )?
+        },
+        |(_, _, opt_if_not_exists, name, opt_start, opt_increment, opt_comment)| {
+            Statement::CreateSequence(CreateSequenceStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name,
+                start: opt_start.map(|(_, _, start)| start),
+                increment: opt_increment.map(|(_, _, increment)| increment),
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+    let drop_sequence = map(
+        rule! {
+            DROP ~ SEQUENCE ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, name)| {
+            Statement::DropSequence(DropSequenceStmt {
+                if_exists: opt_if_exists.is_some(),
+                name,
+            })
+        },
+    );
+
+    // task
+    let create_task = map(
+        rule! {
+            CREATE ~ TASK ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident
+            ~ SCHEDULE ~ ^"=" ~ ^#literal_string
+            ~ ( RETRY_ATTEMPTS ~ ^"=" ~ ^#literal_u64 )?
+            ~ ( COMMENT ~ "=" ~ #literal_string )?
+            ~ AS ~ ^#statement
+        },
+        |(
+            _,
+            _,
+            opt_if_not_exists,
+            name,
+            _,
+            _,
+            schedule,
+            opt_retry_attempts,
+            opt_comment,
+            _,
+            sql,
+        )| {
+            Statement::CreateTask(CreateTaskStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name,
+                schedule,
+                retry_attempts: opt_retry_attempts.map(|(_, _, retry_attempts)| retry_attempts),
+                comment: opt_comment.map(|(_, _, comment)| comment),
+                sql: Box::new(sql.stmt),
+            })
+        },
+    );
+    let drop_task = map(
+        rule! {
+            DROP ~ TASK ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, name)| {
+            Statement::DropTask(DropTaskStmt {
+                if_exists: opt_if_exists.is_some(),
+                name,
+            })
+        },
+    );
+    let alter_task = map(
+        rule! {
+            ALTER ~ TASK ~ #ident ~ ( SUSPEND | RESUME )
+        },
+        |(_, _, name, action)| {
+            let action = match action.kind {
+                TokenKind::SUSPEND => AlterTaskAction::Suspend,
+                _ => AlterTaskAction::Resume,
+            };
+            Statement::AlterTask(AlterTaskStmt { name, action })
+        },
+    );
+    let alter_cluster_node = map(
+        rule! {
+            ALTER ~ CLUSTER ~ NODE ~ #literal_string ~ DRAIN ~ ( TIMEOUT ~ ^"=" ~ ^#literal_u64 )?
+        },
+        |(_, _, _, node_id, _, opt_timeout)| {
+            Statement::AlterClusterNode(AlterClusterNodeStmt {
+                node_id,
+                action: AlterClusterNodeAction::Drain {
+                    timeout_secs: opt_timeout.map(|(_, _, timeout_secs)| timeout_secs),
+                },
+            })
+        },
+    );
+    let execute_task = map(
+        rule! {
+            EXECUTE ~ TASK ~ #ident
+        },
+        |(_, _, name)| Statement::ExecuteTask(ExecuteTaskStmt { name }),
+    );
+
+    // notification integration
+    let create_notification_integration = map(
+        rule! {
+            CREATE ~ NOTIFICATION ~ ^INTEGRATION ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident
+            ~ TYPE ~ ^"=" ~ ^#ident
+            ~ CONNECTION ~ ^"=" ~ ^#connection_options
+            ~ ( COMMENT ~ "=" ~ #literal_string )?
+        },
+        |(
+            _,
+            _,
+            _,
+            opt_if_not_exists,
+            name,
+            _,
+            _,
+            integration_type,
+            _,
+            _,
+            connection,
+            opt_comment,
+        )| {
+            Statement::CreateNotificationIntegration(CreateNotificationIntegrationStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name,
+                integration_type,
+                connection,
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+    let drop_notification_integration = map(
+        rule! {
+            DROP ~ NOTIFICATION ~ ^INTEGRATION ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, _, opt_if_exists, name)| {
+            Statement::DropNotificationIntegration(DropNotificationIntegrationStmt {
+                if_exists: opt_if_exists.is_some(),
+                name,
+            })
+        },
+    );
+
+    // pipe
+    let pipe_source = alt((
+        map(
+            rule! { KAFKA ~ ^#connection_options ~ ^#file_format_clause },
+            |(_, options, file_format)| (PipeSourceLocation::Kafka(options), file_format),
+        ),
+        map(rule! { MYSQL ~ ^#connection_options }, |(_, options)| {
+            (PipeSourceLocation::MySql(options), BTreeMap::new())
+        }),
+        map(
+            rule! {
+                #stage_location
+                ~ ( PATTERN ~ ^"=" ~ ^#literal_string )?
+                ~ #file_format_clause?
+            },
+            |(location, opt_pattern, opt_file_format)| {
+                let source = PipeSourceLocation::Stage {
+                    location,
+                    pattern: opt_pattern.map(|(_, _, pattern)| pattern),
+                };
+                (source, opt_file_format.unwrap_or_default())
+            },
+        ),
+    ));
+    let create_pipe = map(
+        rule! {
+            CREATE ~ PIPE ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident
+            ~ ( AUTO_INGEST ~ ^"=" ~ ^#literal_bool )?
+            ~ ( AWS_SQS_QUEUE_URL ~ ^"=" ~ ^#literal_string )?
+            ~ ( COMMENT ~ "=" ~ #literal_string )?
+            ~ AS ~ ^COPY ~ ^INTO ~ ^#period_separated_idents_1_to_3
+            ~ ^FROM ~ ^#pipe_source
+        },
+        |(
+            _,
+            _,
+            opt_if_not_exists,
+            name,
+            opt_auto_ingest,
+            opt_queue_url,
+            opt_comment,
+            _,
+            _,
+            _,
+            (catalog, database, table),
+            _,
+            (source, file_format),
+        )| {
+            Statement::CreatePipe(CreatePipeStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name,
+                auto_ingest: opt_auto_ingest.map_or(false, |(_, _, auto_ingest)| auto_ingest),
+                aws_sqs_queue_url: opt_queue_url.map(|(_, _, queue_url)| queue_url),
+                comment: opt_comment.map(|(_, _, comment)| comment),
+                catalog,
+                database,
+                table,
+                source,
+                file_format,
+            })
+        },
+    );
+    let drop_pipe = map(
+        rule! {
+            DROP ~ PIPE ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, name)| {
+            Statement::DropPipe(DropPipeStmt {
+                if_exists: opt_if_exists.is_some(),
+                name,
+            })
+        },
+    );
+
+    let statement_body = alt((
+        rule!(
+            #map(query, |query| Statement::Query(Box::new(query)))
+            | #explain : "`EXPLAIN [PIPELINE | GRAPH] <statement>`"
+            | #explain_analyze : "`EXPLAIN ANALYZE <statement>`"
+            | #delete : "`DELETE FROM <table> [WHERE ...]`"
+            | #update : "`UPDATE <table> SET <column> = <expr> [, <column> = <expr> , ... ] [WHERE ...]`"
+            | #show_settings : "`SHOW SETTINGS [<show_limit>]`"
+            | #show_stages : "`SHOW STAGES`"
+            | #show_engines : "`SHOW ENGINES`"
+            | #show_process_list : "`SHOW PROCESSLIST`"
+            | #show_metrics : "`SHOW METRICS`"
+            | #show_functions : "`SHOW FUNCTIONS [<show_limit>]`"
+            | #show_indexes : "`SHOW INDEXES`"
+            | #kill_stmt : "`KILL (QUERY | CONNECTION) <object_id>`"
+            | #set_role: "`SET [DEFAULT] ROLE <role>`"
+            | #show_databases : "`SHOW [FULL] DATABASES [(FROM | IN) <catalog>] [<show_limit>]`"
+            | #undrop_database : "`UNDROP DATABASE <database>`"
+            | #show_create_database : "`SHOW CREATE DATABASE <database>`"
+            | #create_database : "`CREATE DATABASE [IF NOT EXIST] <database> [ENGINE = <engine>]`"
+            | #drop_database : "`DROP DATABASE [IF EXISTS] <database>`"
+            | #alter_database : "`ALTER DATABASE [IF EXISTS] <action>`"
+            | #use_database : "`USE <database>`"
+        ),
+        rule!(
+            #insert_on_conflict : "`INSERT INTO [TABLE] <table> [(<column>, ...)] (VALUES <values> | <query>) ON CONFLICT (<column>, ...) DO (NOTHING | UPDATE SET <column> = <expr>, ... [WHERE ...])`"
+            | #insert : "`INSERT INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
+            | #replace : "`REPLACE INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
+            | #merge_into : "`MERGE INTO <table> USING <source> ON <condition> WHEN [NOT] MATCHED [AND <condition>] THEN ...`"
+        ),
+        rule!(
+            #set_variable : "`SET <variable> = <value>`"
+            | #unset_variable : "`UNSET <variable>`"
+            | #backup_database : "`BACKUP DATABASE <database> TO @<stage>[/<path>] [WITH DATA]`"
+            | #restore_database : "`RESTORE DATABASE <database> FROM @<stage>[/<path>]`"
+        ),
+        rule!(
+            #show_tables : "`SHOW [FULL] TABLES [FROM <database>] [<show_limit>]`"
+            | #show_columns : "`SHOW [FULL] COLUMNS FROM <table> [FROM|IN <catalog>.<database>] [<show_limit>]`"
+            | #show_create_table : "`SHOW CREATE TABLE [<database>.]<table>`"
+            | #describe_table : "`DESCRIBE [<database>.]<table>`"
+            | #show_fields : "`SHOW FIELDS FROM [<database>.]<table>`"
+            | #show_tables_status : "`SHOW TABLES STATUS [FROM <database>] [<show_limit>]`"
+            | #show_drop_tables : "`SHOW DROP TABLES [FROM <database>] [<show_limit>]`"
+            | #create_table : "`CREATE TABLE [IF NOT EXISTS] [<database>.]<table> [<source>] [<table_options>]`"
+            | #create_external_table : "`CREATE EXTERNAL TABLE [IF NOT EXISTS] [<database>.]<table> (<columns>) LOCATION = <uri> [PARTITIONED BY (<columns>)]`"
+            | #attach_table : "`ATTACH TABLE [<database>.]<table> <uri>`"
+            | #drop_table : "`DROP TABLE [IF EXISTS] [<database>.]<table>`"
+            | #undrop_table : "`UNDROP TABLE [<database>.]<table>`"
+            | #alter_table : "`ALTER TABLE [<database>.]<table> <action>`"
+            | #rename_table : "`RENAME TABLE [<database>.]<table> TO <new_table>`"
+            | #truncate_table : "`TRUNCATE TABLE [<database>.]<table> [PURGE]`"
+            | #optimize_table : "`OPTIMIZE TABLE [<database>.]<table> (ALL | PURGE | COMPACT [SEGMENT])`"
+            | #vacuum_table : "`VACUUM TABLE [<database>.]<table> [RETAIN number HOURS] [DRY RUN]`"
+            | #analyze_table : "`ANALYZE TABLE [<database>.]<table>`"
+            | #exists_table : "`EXISTS TABLE [<database>.]<table>`"
+            | #show_table_functions : "`SHOW TABLE_FUNCTIONS [<show_limit>]`"
+        ),
+        rule!(
+            #create_view : "`CREATE VIEW [IF NOT EXISTS] [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
+            | #drop_view : "`DROP VIEW [IF EXISTS] [<database>.]<view>`"
+            | #alter_view : "`ALTER VIEW [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
+        ),
+        rule!(
+            #create_materialized_view : "`CREATE MATERIALIZED VIEW [IF NOT EXISTS] [<database>.]<view> AS SELECT ...`"
+            | #drop_materialized_view : "`DROP MATERIALIZED VIEW [IF EXISTS] [<database>.]<view>`"
+            | #refresh_materialized_view : "`REFRESH MATERIALIZED VIEW [<database>.]<view>`"
+        ),
+        rule!(
+            #create_index: "`CREATE AGGREGATING INDEX [IF NOT EXISTS] <index> AS SELECT ...`"
+            | #drop_index: "`DROP AGGREGATING INDEX [IF EXISTS] <index>`"
+        ),
+        rule!(
+            #create_virtual_columns: "`CREATE VIRTUAL COLUMNS (expr, ...) FOR [<database>.]<table>`"
+            | #create_virtual_columns_on: "`CREATE VIRTUAL COLUMN ON [<database>.]<table> (expr, ...)`"
+            | #alter_virtual_columns: "`ALTER VIRTUAL COLUMNS (expr, ...) FOR [<database>.]<table>`"
+            | #drop_virtual_columns: "`DROP VIRTUAL COLUMNS FOR [<database>.]<table>`"
+            | #generate_virtual_columns: "`GENERATE VIRTUAL COLUMNS FOR [<database>.]<table>`"
+        ),
+        rule!(
+            #show_users : "`SHOW USERS`"
+            | #create_user : "`CREATE USER [IF NOT EXISTS] '<username>'@'hostname' IDENTIFIED [WITH <auth_type>] [BY <password>] [WITH <user_option>, ...]`"
+            | #alter_user : "`ALTER USER ('<username>'@'hostname' | USER()) [IDENTIFIED [WITH <auth_type>] [BY <password>]] [WITH <user_option>, ...]`"
+            | #alter_role : "`ALTER ROLE '<role_name>' (SET SETTINGS (<name> = <value>, ...) | UNSET SETTINGS (<name>, ...))`"
+            | #drop_user : "`DROP USER [IF EXISTS] '<username>'@'hostname'`"
+            | #show_roles : "`SHOW ROLES`"
+            | #create_role : "`CREATE ROLE [IF NOT EXISTS] '<role_name>']`"
+            | #drop_role : "`DROP ROLE [IF EXISTS] '<role_name>'`"
+            | #create_udf : "`CREATE FUNCTION [IF NOT EXISTS] <udf_name> { AS (<parameter>, ...) -> <definition expr> | (<arg> <type>, ...) RETURNS { <type> AS <definition expr> | TABLE (<column> <type>, ...) AS <query> } | (<type>, ...) [STATE { <name> <type>, ... }] RETURNS <type> LANGUAGE { WASM HANDLER = '<handler>' AS '<stage location>' | <language> HANDLER = '<handler>' ADDRESS = '<address>' } } [DESC = <description>]`"
+            | #drop_udf : "`DROP FUNCTION [IF EXISTS] <udf_name>`"
+            | #alter_udf : "`ALTER FUNCTION <udf_name> { AS (<parameter>, ...) -> <definition expr> | (<arg> <type>, ...) RETURNS { <type> AS <definition expr> | TABLE (<column> <type>, ...) AS <query> } | (<type>, ...) [STATE { <name> <type>, ... }] RETURNS <type> LANGUAGE { WASM HANDLER = '<handler>' AS '<stage location>' | <language> HANDLER = '<handler>' ADDRESS = '<address>' } } [DESC = <description>]`"
+        ),
+        rule!(
+            #create_stage: "`CREATE STAGE [ IF NOT EXISTS ] <stage_name>
+                [ FILE_FORMAT = ( { TYPE = { CSV | PARQUET } [ formatTypeOptions ] ) } ]
+                [ COPY_OPTIONS = ( copyOptions ) ]
+                [ COMMENT = '<string_literal>' ]`"
+            | #desc_stage: "`DESC STAGE <stage_name>`"
+            | #list_stage: "`LIST @<stage_name> [pattern = '<pattern>']`"
+            | #remove_stage: "`REMOVE @<stage_name> [pattern = '<pattern>']`"
             | #drop_stage: "`DROP STAGE <stage_name>`"
         ),
         rule!(
@@ -1385,6 +2002,29 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             #create_data_mask_policy: "`CREATE MASKING POLICY [IF NOT EXISTS] mask_name as (val1 val_type1 [, val type]) return type -> case`"
             | #drop_data_mask_policy: "`DROP MASKING POLICY [IF EXISTS] mask_name`"
             | #describe_data_mask_policy: "`DESC MASKING POLICY mask_name`"
+            | #create_row_access_policy: "`CREATE ROW ACCESS POLICY [IF NOT EXISTS] <name> AS (<arg> <type>, ...) RETURNS BOOLEAN -> <expr> [EXEMPT ROLES = ('<role>', ...)] [COMMENT = '<string_literal>']`"
+            | #drop_row_access_policy: "`DROP ROW ACCESS POLICY [IF EXISTS] <name>`"
+            | #create_network_policy: "`CREATE NETWORK POLICY [IF NOT EXISTS] <name> ALLOWED_IP_LIST = ('<ip>', ...) [BLOCKED_IP_LIST = ('<ip>', ...)] [COMMENT = '<string_literal>']`"
+            | #drop_network_policy: "`DROP NETWORK POLICY [IF EXISTS] <name>`"
+        ),
+        // dictionary and sequence
+        rule!(
+            #create_dictionary: "`CREATE DICTIONARY [IF NOT EXISTS] <name> (<column> <type>, ...) PRIMARY KEY <column> SOURCE (<source_type> (<key> = '<value>' ...)) [COMMENT = '<string_literal>']`"
+            | #drop_dictionary: "`DROP DICTIONARY [IF EXISTS] <name>`"
+            | #create_sequence: "`CREATE SEQUENCE [IF NOT EXISTS] <name> [START [WITH] <start>] [INCREMENT [BY] <increment>] [COMMENT = '<string_literal>']`"
+            | #drop_sequence: "`DROP SEQUENCE [IF EXISTS] <name>`"
+        ),
+        // task, notification integration, pipe and cluster
+        rule!(
+            #create_task: "`CREATE TASK [IF NOT EXISTS] <name> SCHEDULE = '<schedule>' [RETRY_ATTEMPTS = <n>] [COMMENT = '<string_literal>'] AS <statement>`"
+            | #drop_task: "`DROP TASK [IF EXISTS] <name>`"
+            | #alter_task: "`ALTER TASK <name> { SUSPEND | RESUME }`"
+            | #execute_task: "`EXECUTE TASK <name>`"
+            | #create_notification_integration: "`CREATE NOTIFICATION INTEGRATION [IF NOT EXISTS] <name> TYPE = { WEBHOOK | SQS | KAFKA } CONNECTION = (<key> = '<value>' ...) [COMMENT = '<string_literal>']`"
+            | #drop_notification_integration: "`DROP NOTIFICATION INTEGRATION [IF EXISTS] <name>`"
+            | #create_pipe: "`CREATE PIPE [IF NOT EXISTS] <name> [AUTO_INGEST = TRUE] [AWS_SQS_QUEUE_URL = '<url>'] [COMMENT = '<string_literal>'] AS COPY INTO [<database>.]<table> FROM { KAFKA (<key> = '<value>' ...) | MYSQL (<key> = '<value>' ...) | @<stage_name>[/<path>] [PATTERN = '<regex>'] } FILE_FORMAT = (<format options>)`"
+            | #drop_pipe: "`DROP PIPE [IF EXISTS] <name>`"
+            | #alter_cluster_node: "`ALTER CLUSTER NODE '<node_id>' DRAIN [TIMEOUT = <seconds>]`"
         ),
         // share
         rule!(
@@ -1399,6 +2039,12 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             | #desc_share: "`{DESC | DESCRIBE} SHARE <share_name>`"
             | #show_shares: "`SHOW SHARES`"
         ),
+        // transaction
+        rule!(
+            #begin : "`BEGIN [TRANSACTION | WORK]`"
+            | #commit : "`COMMIT [WORK]`"
+            | #abort : "`{ABORT | ROLLBACK} [TRANSACTION | WORK]`"
+        ),
         // catalog
         rule!(
          #show_catalogs : "`SHOW CATALOGS [<show_limit>]`"
@@ -1461,6 +2107,32 @@ pub fn insert_source(i: Input) -> IResult<InsertSource> {
     )(i)
 }
 
+fn values_row(i: Input) -> IResult<Vec<Expr>> {
+    map(
+        rule! { "(" ~ #comma_separated_list1(expr) ~ ")" },
+        |(_, row, _)| row,
+    )(i)
+}
+
+pub fn on_conflict(i: Input) -> IResult<OnConflict> {
+    let nothing = value(OnConflictAction::Nothing, rule! { NOTHING });
+    let update = map(
+        rule! {
+            UPDATE ~ ^SET ~ ^#comma_separated_list1(update_expr) ~ ( WHERE ~ ^#expr )?
+        },
+        |(_, _, update_list, opt_selection)| OnConflictAction::Update {
+            update_list,
+            selection: opt_selection.map(|(_, selection)| selection),
+        },
+    );
+    map(
+        rule! {
+            ON ~ ^CONFLICT ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")" ~ ^DO ~ ^( #nothing | #update )
+        },
+        |(_, _, _, columns, _, _, action)| OnConflict { columns, action },
+    )(i)
+}
+
 pub fn unset_source(i: Input) -> IResult<UnSetSource> {
     //#ident ~ ( "(" ~ ^#comma_separated_list1(ident) ~ ")")?
     let var = map(
@@ -1520,6 +2192,134 @@ pub fn rest_str(i: Input) -> IResult<(String, usize)> {
     ))
 }
 
+pub fn udf_argument(i: Input) -> IResult<UDFArgument> {
+    map(rule! { #ident ~ #type_name }, |(name, data_type)| {
+        UDFArgument { name, data_type }
+    })(i)
+}
+
+pub fn udf_definition(i: Input) -> IResult<UDFDefinition> {
+    let lambda_udf = map(
+        rule! {
+            AS ~ "(" ~ #comma_separated_list0(ident) ~ ")"
+            ~ "->" ~ #expr
+        },
+        |(_, _, parameters, _, _, definition)| UDFDefinition::LambdaUDF {
+            parameters,
+            definition: Box::new(definition),
+        },
+    );
+    let table_udf = map(
+        rule! {
+            "(" ~ #comma_separated_list0(udf_argument) ~ ")"
+            ~ RETURNS ~ TABLE ~ "(" ~ #comma_separated_list1(udf_argument) ~ ")"
+            ~ AS ~ #query
+        },
+        |(_, arguments, _, _, _, _, return_columns, _, _, definition)| UDFDefinition::TableUDF {
+            arguments,
+            return_columns,
+            definition: Box::new(definition),
+        },
+    );
+    let scalar_udf = map(
+        rule! {
+            "(" ~ #comma_separated_list0(udf_argument) ~ ")"
+            ~ RETURNS ~ #type_name
+            ~ AS ~ #expr
+        },
+        |(_, arguments, _, _, return_type, _, definition)| UDFDefinition::ScalarUDF {
+            arguments,
+            return_type,
+            definition: Box::new(definition),
+        },
+    );
+
+    let udf_server = map(
+        rule! {
+            "(" ~ #comma_separated_list0(type_name) ~ ")"
+            ~ RETURNS ~ #type_name
+            ~ LANGUAGE ~ #ident
+            ~ HANDLER ~ "="? ~ #literal_string
+            ~ ADDRESS ~ "="? ~ #literal_string
+        },
+        |(_, arg_types, _, _, return_type, _, language, _, _, handler, _, _, address)| {
+            UDFDefinition::UDFServer {
+                arg_types,
+                return_type,
+                address,
+                handler,
+                language: language.to_string(),
+            }
+        },
+    );
+
+    let wasm_udf = map(
+        rule! {
+            "(" ~ #comma_separated_list0(type_name) ~ ")"
+            ~ RETURNS ~ #type_name
+            ~ LANGUAGE ~ WASM
+            ~ HANDLER ~ "="? ~ #literal_string
+            ~ AS ~ #literal_string
+        },
+        |(_, arg_types, _, _, return_type, _, _, _, _, handler, _, location)| {
+            UDFDefinition::WasmUDF {
+                arg_types,
+                return_type,
+                handler,
+                location,
+            }
+        },
+    );
+
+    let udaf_server = map(
+        rule! {
+            "(" ~ #comma_separated_list0(type_name) ~ ")"
+            ~ STATE ~ "{" ~ #comma_separated_list1(udf_argument) ~ "}"
+            ~ RETURNS ~ #type_name
+            ~ LANGUAGE ~ #ident
+            ~ HANDLER ~ "="? ~ #literal_string
+            ~ ADDRESS ~ "="? ~ #literal_string
+        },
+        |(
+            _,
+            arg_types,
+            _,
+            _,
+            _,
+            state_fields,
+            _,
+            _,
+            return_type,
+            _,
+            language,
+            _,
+            _,
+            handler,
+            _,
+            _,
+            address,
+        )| {
+            UDFDefinition::UDAFServer {
+                arg_types,
+                state_fields,
+                return_type,
+                address,
+                handler,
+                language: language.to_string(),
+            }
+        },
+    );
+
+    rule!(
+        #lambda_udf: "AS (<parameter>, ...) -> <definition expr>"
+        | #table_udf: "(<arg> <type>, ...) RETURNS TABLE (<column> <type>, ...) AS <query>"
+        | #scalar_udf: "(<arg> <type>, ...) RETURNS <type> AS <definition expr>"
+        | #wasm_udf: "(<type>, ...) RETURNS <type> LANGUAGE WASM HANDLER = '<handler>' AS '<stage location>'"
+        | #udf_server: "(<type>, ...) RETURNS <type> LANGUAGE <language> HANDLER = '<handler>' ADDRESS = '<address>'"
+        | #udaf_server: "(<type>, ...) STATE { <name> <type>, ... } RETURNS <type> LANGUAGE <language> HANDLER = '<handler>' ADDRESS = '<address>'"
+    )(i)
+}
+
 pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
     #[derive(Clone)]
     enum ColumnConstraint {
@@ -1527,6 +2327,7 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
         DefaultExpr(Box<Expr>),
         VirtualExpr(Box<Expr>),
         StoredExpr(Box<Expr>),
+        Identity(u64, u64),
     }
 
     let nullable = alt((
@@ -1552,6 +2353,18 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
             },
             |(_, _, stored_expr, _, _)| ColumnConstraint::StoredExpr(Box::new(stored_expr)),
         ),
+        map(
+            rule! {
+                ( AUTOINCREMENT | IDENTITY )
+                ~ ( "(" ~ ^#literal_u64 ~ ^"," ~ ^#literal_u64 ~ ^")" )?
+            },
+            |(_, opt_args)| {
+                let (start, increment) = opt_args
+                    .map(|(_, start, _, increment, _)| (start, increment))
+                    .unwrap_or((1, 1));
+                ColumnConstraint::Identity(start, increment)
+            },
+        ),
     ));
 
     let comment = map(
@@ -1567,7 +2380,7 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
             ~ #type_name
             ~ ( #nullable | #expr )*
             ~ ( #comment )?
-            : "`<column name> <type> [DEFAULT <expr>] [AS (<expr>) VIRTUAL] [AS (<expr>) STORED] [COMMENT '<comment>']`"
+            : "`<column name> <type> [DEFAULT <expr>] [AS (<expr>) VIRTUAL] [AS (<expr>) STORED] [AUTOINCREMENT [(<start>, <increment>)]] [COMMENT '<comment>']`"
         },
         |(name, data_type, constraints, comment)| {
             let mut def = ColumnDefinition {
@@ -1592,6 +2405,9 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
                     ColumnConstraint::StoredExpr(stored_expr) => {
                         def.expr = Some(ColumnExpr::Stored(stored_expr))
                     }
+                    ColumnConstraint::Identity(start, increment) => {
+                        def.expr = Some(ColumnExpr::Identity { start, increment })
+                    }
                 }
             }
             def
@@ -1619,9 +2435,20 @@ pub fn grant_source(i: Input) -> IResult<AccountMgrSource> {
         rule! { ALL ~ PRIVILEGES? ~ ON ~ #grant_level },
         |(_, _, _, level)| AccountMgrSource::ALL { level },
     );
+    let column_privs = map(
+        rule! {
+            SELECT ~ "(" ~ #comma_separated_list1(ident) ~ ")" ~ ON ~ #grant_level
+        },
+        |(_, _, columns, _, _, level)| AccountMgrSource::ColumnPrivs {
+            privilege: UserPrivilegeType::Select,
+            columns,
+            level,
+        },
+    );
 
     rule!(
         #role : "ROLE <role_name>"
+        | #column_privs : "SELECT(<column>, ...) ON <table>"
         | #privs : "<privileges> ON <privileges_level>"
         | #all : "ALL [ PRIVILEGES ] ON <privileges_level>"
     )(i)
@@ -1783,10 +2610,29 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
             table,
         },
     );
+    let template = map(
+        rule! {
+            USING ~ ^TEMPLATE ~ ^"(" ~ ^#query ~ ^")"
+        },
+        |(_, _, _, query, _)| CreateTableSource::Template(Box::new(query)),
+    );
+    let clone = map(
+        rule! {
+            CLONE ~ ^#period_separated_idents_1_to_3 ~ (AT ~ ^#travel_point)?
+        },
+        |(_, (catalog, database, table), travel_point)| CreateTableSource::Clone {
+            catalog,
+            database,
+            table,
+            travel_point: travel_point.map(|(_, p)| p),
+        },
+    );
 
     rule!(
         #columns
         | #like
+        | #template
+        | #clone
     )(i)
 }
 
@@ -1810,6 +2656,15 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         },
         |(_, _, new_table)| AlterTableAction::RenameTable { new_table },
     );
+    let rename_column = map(
+        rule! {
+            RENAME ~ COLUMN ~ #ident ~ TO ~ #ident
+        },
+        |(_, _, old_column, _, new_column)| AlterTableAction::RenameColumn {
+            old_column,
+            new_column,
+        },
+    );
     let add_column = map(
         rule! {
             ADD ~ COLUMN ~ #column_def
@@ -1825,6 +2680,15 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
             action: ModifyColumnAction::SetMaskingPolicy(mask_name.to_string()),
         },
     );
+    let modify_column_type = map(
+        rule! {
+            MODIFY ~ COLUMN ~ #ident ~ #type_name
+        },
+        |(_, _, column, data_type)| AlterTableAction::ModifyColumn {
+            column,
+            action: ModifyColumnAction::SetDataType(data_type),
+        },
+    );
     let drop_column = map(
         rule! {
             DROP ~ COLUMN ~ #ident
@@ -1833,9 +2697,12 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
     );
     let alter_table_cluster_key = map(
         rule! {
-            CLUSTER ~ ^BY ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")"
+            CLUSTER ~ ^BY ~ #cluster_type? ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")"
+        },
+        |(_, _, cluster_type, _, cluster_by, _)| AlterTableAction::AlterTableClusterKey {
+            cluster_type: cluster_type.unwrap_or_default(),
+            cluster_by,
         },
-        |(_, _, _, cluster_by, _)| AlterTableAction::AlterTableClusterKey { cluster_by },
     );
 
     let drop_table_cluster_key = map(
@@ -1869,16 +2736,37 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, _, set_options, _)| AlterTableAction::SetOptions { set_options },
     );
 
+    let add_row_access_policy = map(
+        rule! {
+            ADD ~ ROW ~ ^ACCESS ~ ^POLICY ~ ^#ident ~ ^ON ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, _, _, policy, _, _, columns, _)| AlterTableAction::AddRowAccessPolicy {
+            policy,
+            columns,
+        },
+    );
+
+    let drop_row_access_policy = map(
+        rule! {
+            DROP ~ ROW ~ ^ACCESS ~ ^POLICY ~ ^#ident
+        },
+        |(_, _, _, _, policy)| AlterTableAction::DropRowAccessPolicy { policy },
+    );
+
     rule!(
         #rename_table
+        | #rename_column
         | #add_column
         | #drop_column
         | #modify_column
+        | #modify_column_type
         | #alter_table_cluster_key
         | #drop_table_cluster_key
         | #recluster_table
         | #revert_table
         | #set_table_options
+        | #add_row_access_policy
+        | #drop_row_access_policy
     )(i)
 }
 
@@ -1898,6 +2786,13 @@ pub fn optimize_table_action(i: Input) -> IResult<OptimizeTableAction> {
                 limit: opt_limit.map(|(_, limit)| limit),
             },
         ),
+        map(
+            rule! { RECLUSTER ~ ( WHERE ~ ^#expr )? ~ ( LIMIT ~ ^#expr )? },
+            |(_, opt_selection, opt_limit)| OptimizeTableAction::Recluster {
+                selection: opt_selection.map(|(_, selection)| selection),
+                limit: opt_limit.map(|(_, limit)| limit),
+            },
+        ),
     ))(i)
 }
 
@@ -2056,6 +2951,13 @@ pub fn engine(i: Input) -> IResult<Engine> {
     )(i)
 }
 
+pub fn cluster_type(i: Input) -> IResult<ClusterType> {
+    alt((
+        value(ClusterType::Linear, rule! { LINEAR }),
+        value(ClusterType::Hilbert, rule! { HILBERT }),
+    ))(i)
+}
+
 pub fn database_engine(i: Input) -> IResult<DatabaseEngine> {
     let engine = alt((value(DatabaseEngine::Default, rule! {DEFAULT}),));
 
@@ -2112,6 +3014,18 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
         },
         |(_, _, role)| UserOptionItem::DefaultRole(role),
     );
+    let set_network_policy = map(
+        rule! {
+            SET ~ NETWORK ~ ^POLICY ~ ^"=" ~ ^#literal_string
+        },
+        |(_, _, _, _, policy)| UserOptionItem::SetNetworkPolicy(policy),
+    );
+    let unset_network_policy = map(
+        rule! {
+            UNSET ~ NETWORK ~ ^POLICY
+        },
+        |(_, _, _)| UserOptionItem::UnsetNetworkPolicy,
+    );
     alt((
         value(UserOptionItem::TenantSetting(true), rule! { TENANTSETTING }),
         value(
@@ -2119,9 +3033,37 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
             rule! { NOTENANTSETTING },
         ),
         default_role_option,
+        set_network_policy,
+        unset_network_policy,
+        user_settings_option,
     ))(i)
 }
 
+pub fn user_settings_option(i: Input) -> IResult<UserOptionItem> {
+    alt((
+        map(set_settings, UserOptionItem::SetSettings),
+        map(unset_settings, UserOptionItem::UnsetSettings),
+    ))(i)
+}
+
+pub fn set_settings(i: Input) -> IResult<BTreeMap<String, String>> {
+    map(
+        rule! {
+            SET ~ SETTINGS ~ ^"(" ~ ^#set_table_option ~ ^")"
+        },
+        |(_, _, _, settings, _)| settings,
+    )(i)
+}
+
+pub fn unset_settings(i: Input) -> IResult<Vec<String>> {
+    map(
+        rule! {
+            UNSET ~ SETTINGS ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, _, names, _)| names.into_iter().map(|n| n.name.to_lowercase()).collect(),
+    )(i)
+}
+
 pub fn user_identity(i: Input) -> IResult<UserIdentity> {
     map(
         rule! {
@@ -2194,6 +3136,22 @@ pub fn copy_option(i: Input) -> IResult<CopyOption> {
             rule! {DISABLE_VARIANT_CHECK ~ "=" ~ #literal_bool},
             |(_, _, disable_variant_check)| CopyOption::DisableVariantCheck(disable_variant_check),
         ),
+        map(
+            rule! { MATCH_BY_COLUMN_NAME ~ "=" ~ #ident },
+            |(_, _, match_by_column_name)| {
+                CopyOption::MatchByColumnName(match_by_column_name.to_string())
+            },
+        ),
+        map(
+            rule! { ERROR_ON_COLUMN_COUNT_MISMATCH ~ "=" ~ #literal_bool },
+            |(_, _, error_on_column_count_mismatch)| {
+                CopyOption::ErrorOnColumnCountMismatch(error_on_column_count_mismatch)
+            },
+        ),
+        map(
+            rule! { PARTITION ~ BY ~ "(" ~ #comma_separated_list1(select_target) ~ ")" },
+            |(_, _, _, partition_by, _)| CopyOption::PartitionBy(partition_by),
+        ),
     ))(i)
 }
 
@@ -2239,10 +3197,88 @@ pub fn table_reference_only(i: Input) -> IResult<TableReference> {
             travel_point: None,
             pivot: None,
             unpivot: None,
+            sample: None,
         },
     )(i)
 }
 
+pub fn merge_source(i: Input) -> IResult<TableReference> {
+    let table = map(
+        consumed(rule! {
+            #period_separated_idents_1_to_3 ~ #table_alias?
+        }),
+        |(span, ((catalog, database, table), alias))| TableReference::Table {
+            span: transform_span(span.0),
+            catalog,
+            database,
+            table,
+            alias,
+            travel_point: None,
+            pivot: None,
+            unpivot: None,
+            sample: None,
+        },
+    );
+    let subquery = map(
+        consumed(rule! {
+            "(" ~ #query ~ ")" ~ #table_alias
+        }),
+        |(span, (_, subquery, _, alias))| TableReference::Subquery {
+            span: transform_span(span.0),
+            lateral: false,
+            subquery: Box::new(subquery),
+            alias: Some(alias),
+            pivot: None,
+            unpivot: None,
+        },
+    );
+
+    rule!(
+        #table
+        | #subquery
+    )(i)
+}
+
+pub fn merge_option(i: Input) -> IResult<MergeOption> {
+    let matched_update = map(
+        rule! {
+            UPDATE ~ SET ~ ^#comma_separated_list1(update_expr)
+        },
+        |(_, _, update_list)| MatchOperation::Update { update_list },
+    );
+    let matched_delete = value(MatchOperation::Delete, rule! { DELETE });
+    let matched = map(
+        rule! {
+            WHEN ~ MATCHED ~ ( AND ~ ^#expr )? ~ THEN ~ ( #matched_update | #matched_delete )
+        },
+        |(_, _, opt_selection, _, operation)| {
+            MergeOption::Match(MatchedClause {
+                selection: opt_selection.map(|(_, selection)| selection),
+                operation,
+            })
+        },
+    );
+    let unmatched = map(
+        rule! {
+            WHEN ~ NOT ~ MATCHED ~ ( AND ~ ^#expr )? ~ THEN ~ INSERT
+            ~ ( "(" ~ ^#comma_separated_list1(ident) ~ ^")" )?
+            ~ VALUES ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")"
+        },
+        |(_, _, _, opt_selection, _, _, opt_columns, _, _, values, _)| {
+            MergeOption::Unmatch(UnmatchedClause {
+                selection: opt_selection.map(|(_, selection)| selection),
+                columns: opt_columns.map(|(_, columns, _)| columns),
+                values,
+            })
+        },
+    );
+
+    rule!(
+        #matched
+        | #unmatched
+    )(i)
+}
+
 pub fn update_expr(i: Input) -> IResult<UpdateExpr> {
     map(rule! { ( #ident ~ "=" ~ ^#expr ) }, |(name, _, expr)| {
         UpdateExpr { name, expr }
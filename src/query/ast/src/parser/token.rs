@@ -289,8 +289,12 @@ pub enum TokenKind {
     //    reserved list.
     #[token("ALL", ignore(ascii_case))]
     ALL,
+    #[token("ACCESS", ignore(ascii_case))]
+    ACCESS,
     #[token("ADD", ignore(ascii_case))]
     ADD,
+    #[token("ADDRESS", ignore(ascii_case))]
+    ADDRESS,
     #[token("AGGREGATING", ignore(ascii_case))]
     AGGREGATING,
     #[token("ANY", ignore(ascii_case))]
@@ -299,10 +303,18 @@ pub enum TokenKind {
     ARGS,
     #[token("AUTO", ignore(ascii_case))]
     AUTO,
+    #[token("AUTOINCREMENT", ignore(ascii_case))]
+    AUTOINCREMENT,
+    #[token("AUTO_INGEST", ignore(ascii_case))]
+    AUTO_INGEST,
     #[token("SOME", ignore(ascii_case))]
     SOME,
+    #[token("ABORT", ignore(ascii_case))]
+    ABORT,
     #[token("ALTER", ignore(ascii_case))]
     ALTER,
+    #[token("ALLOWED_IP_LIST", ignore(ascii_case))]
+    ALLOWED_IP_LIST,
     #[token("ANALYZE", ignore(ascii_case))]
     ANALYZE,
     #[token("AND", ignore(ascii_case))]
@@ -315,12 +327,20 @@ pub enum TokenKind {
     AST,
     #[token("AT", ignore(ascii_case))]
     AT,
+    #[token("ATTACH", ignore(ascii_case))]
+    ATTACH,
+    #[token("AWS_SQS_QUEUE_URL", ignore(ascii_case))]
+    AWS_SQS_QUEUE_URL,
     #[token("ASC", ignore(ascii_case))]
     ASC,
     #[token("ANTI", ignore(ascii_case))]
     ANTI,
+    #[token("BACKUP", ignore(ascii_case))]
+    BACKUP,
     #[token("BEFORE", ignore(ascii_case))]
     BEFORE,
+    #[token("BEGIN", ignore(ascii_case))]
+    BEGIN,
     #[token("BETWEEN", ignore(ascii_case))]
     BETWEEN,
     #[token("BIGINT", ignore(ascii_case))]
@@ -329,6 +349,12 @@ pub enum TokenKind {
     BINARY,
     #[token("BITMAP", ignore(ascii_case))]
     BITMAP,
+    #[token("BLOB", ignore(ascii_case))]
+    BLOB,
+    #[token("BLOCK", ignore(ascii_case))]
+    BLOCK,
+    #[token("BLOCKED_IP_LIST", ignore(ascii_case))]
+    BLOCKED_IP_LIST,
     #[token("BOOL", ignore(ascii_case))]
     BOOL,
     #[token("BOOLEAN", ignore(ascii_case))]
@@ -337,6 +363,8 @@ pub enum TokenKind {
     BOTH,
     #[token("BY", ignore(ascii_case))]
     BY,
+    #[token("BYTEA", ignore(ascii_case))]
+    BYTEA,
     #[token("BROTLI", ignore(ascii_case))]
     BROTLI,
     #[token("BZ2", ignore(ascii_case))]
@@ -353,12 +381,16 @@ pub enum TokenKind {
     CATALOGS,
     #[token("CENTURY", ignore(ascii_case))]
     CENTURY,
+    #[token("CLONE", ignore(ascii_case))]
+    CLONE,
     #[token("CLUSTER", ignore(ascii_case))]
     CLUSTER,
     #[token("COMMENT", ignore(ascii_case))]
     COMMENT,
     #[token("COMMENTS", ignore(ascii_case))]
     COMMENTS,
+    #[token("COMMIT", ignore(ascii_case))]
+    COMMIT,
     #[token("COMPACT", ignore(ascii_case))]
     COMPACT,
     #[token("CONNECTION", ignore(ascii_case))]
@@ -367,6 +399,8 @@ pub enum TokenKind {
     CONTENT_TYPE,
     #[token("CHAR", ignore(ascii_case))]
     CHAR,
+    #[token("COLLATE", ignore(ascii_case))]
+    COLLATE,
     #[token("COLUMN", ignore(ascii_case))]
     COLUMN,
     #[token("COLUMNS", ignore(ascii_case))]
@@ -395,6 +429,8 @@ pub enum TokenKind {
     CURRENT,
     #[token("CURRENT_TIMESTAMP", ignore(ascii_case))]
     CURRENT_TIMESTAMP,
+    #[token("DATA", ignore(ascii_case))]
+    DATA,
     #[token("DATABASE", ignore(ascii_case))]
     DATABASE,
     #[token("DATABASES", ignore(ascii_case))]
@@ -425,12 +461,16 @@ pub enum TokenKind {
     DESC,
     #[token("DESCRIBE", ignore(ascii_case))]
     DESCRIBE,
+    #[token("DICTIONARY", ignore(ascii_case))]
+    DICTIONARY,
     #[token("DISABLE_VARIANT_CHECK", ignore(ascii_case))]
     DISABLE_VARIANT_CHECK,
     #[token("DISTINCT", ignore(ascii_case))]
     DISTINCT,
     #[token("DIV", ignore(ascii_case))]
     DIV,
+    #[token("DO", ignore(ascii_case))]
+    DO,
     #[token("DOUBLE_SHA1_PASSWORD", ignore(ascii_case))]
     DOUBLE_SHA1_PASSWORD,
     #[token("DOUBLE", ignore(ascii_case))]
@@ -441,6 +481,8 @@ pub enum TokenKind {
     DOY,
     #[token("DOWNLOAD", ignore(ascii_case))]
     DOWNLOAD,
+    #[token("DRAIN", ignore(ascii_case))]
+    DRAIN,
     #[token("DROP", ignore(ascii_case))]
     DROP,
     #[token("DRY", ignore(ascii_case))]
@@ -463,14 +505,22 @@ pub enum TokenKind {
     ENGINES,
     #[token("EPOCH", ignore(ascii_case))]
     EPOCH,
+    #[token("ERROR_ON_COLUMN_COUNT_MISMATCH", ignore(ascii_case))]
+    ERROR_ON_COLUMN_COUNT_MISMATCH,
     #[token("ESCAPE", ignore(ascii_case))]
     ESCAPE,
+    #[token("EXEMPT", ignore(ascii_case))]
+    EXEMPT,
     #[token("EXISTS", ignore(ascii_case))]
     EXISTS,
+    #[token("EXECUTE", ignore(ascii_case))]
+    EXECUTE,
     #[token("EXPLAIN", ignore(ascii_case))]
     EXPLAIN,
     #[token("EXPIRE", ignore(ascii_case))]
     EXPIRE,
+    #[token("EXTERNAL", ignore(ascii_case))]
+    EXTERNAL,
     #[token("EXTRACT", ignore(ascii_case))]
     EXTRACT,
     #[token("FALSE", ignore(ascii_case))]
@@ -527,16 +577,24 @@ pub enum TokenKind {
     FUSE,
     #[token("GENERATE", ignore(ascii_case))]
     GENERATE,
+    #[token("GEOMETRY", ignore(ascii_case))]
+    GEOMETRY,
     #[token("GLOBAL", ignore(ascii_case))]
     GLOBAL,
     #[token("GRAPH", ignore(ascii_case))]
     GRAPH,
     #[token("GROUP", ignore(ascii_case))]
     GROUP,
+    #[token("GROUPS", ignore(ascii_case))]
+    GROUPS,
     #[token("GZIP", ignore(ascii_case))]
     GZIP,
+    #[token("HANDLER", ignore(ascii_case))]
+    HANDLER,
     #[token("HAVING", ignore(ascii_case))]
     HAVING,
+    #[token("HILBERT", ignore(ascii_case))]
+    HILBERT,
     #[token("HISTORY", ignore(ascii_case))]
     HISTORY,
     #[token("HIVE", ignore(ascii_case))]
@@ -551,10 +609,14 @@ pub enum TokenKind {
     INTERSECT,
     #[token("IDENTIFIED", ignore(ascii_case))]
     IDENTIFIED,
+    #[token("IDENTITY", ignore(ascii_case))]
+    IDENTITY,
     #[token("IF", ignore(ascii_case))]
     IF,
     #[token("IN", ignore(ascii_case))]
     IN,
+    #[token("INCREMENT", ignore(ascii_case))]
+    INCREMENT,
     #[token("INDEX", ignore(ascii_case))]
     INDEX,
     #[token("INNER", ignore(ascii_case))]
@@ -573,6 +635,8 @@ pub enum TokenKind {
     INT8,
     #[token("INTEGER", ignore(ascii_case))]
     INTEGER,
+    #[token("INTEGRATION", ignore(ascii_case))]
+    INTEGRATION,
     #[token("INTERVAL", ignore(ascii_case))]
     INTERVAL,
     #[token("INTO", ignore(ascii_case))]
@@ -591,14 +655,26 @@ pub enum TokenKind {
     JULIAN,
     #[token("JWT", ignore(ascii_case))]
     JWT,
+    #[token("KAFKA", ignore(ascii_case))]
+    KAFKA,
     #[token("KEY", ignore(ascii_case))]
     KEY,
     #[token("KILL", ignore(ascii_case))]
     KILL,
+    #[token("LOCATION", ignore(ascii_case))]
+    LOCATION,
     #[token("LOCATION_PREFIX", ignore(ascii_case))]
     LOCATION_PREFIX,
     #[token("ROLES", ignore(ascii_case))]
     ROLES,
+    #[token("LANGUAGE", ignore(ascii_case))]
+    LANGUAGE,
+    #[token("LAST_MODIFIED_AFTER", ignore(ascii_case))]
+    LAST_MODIFIED_AFTER,
+    #[token("LAST_MODIFIED_BEFORE", ignore(ascii_case))]
+    LAST_MODIFIED_BEFORE,
+    #[token("LATERAL", ignore(ascii_case))]
+    LATERAL,
     #[token("LEADING", ignore(ascii_case))]
     LEADING,
     #[token("LEFT", ignore(ascii_case))]
@@ -607,6 +683,8 @@ pub enum TokenKind {
     LIKE,
     #[token("LIMIT", ignore(ascii_case))]
     LIMIT,
+    #[token("LINEAR", ignore(ascii_case))]
+    LINEAR,
     #[token("LIST", ignore(ascii_case))]
     LIST,
     #[token("LZO", ignore(ascii_case))]
@@ -619,10 +697,18 @@ pub enum TokenKind {
     MAX_FILE_SIZE,
     #[token("MASTER_KEY", ignore(ascii_case))]
     MASTER_KEY,
+    #[token("MATCH_BY_COLUMN_NAME", ignore(ascii_case))]
+    MATCH_BY_COLUMN_NAME,
+    #[token("MATCHED", ignore(ascii_case))]
+    MATCHED,
+    #[token("MATERIALIZED", ignore(ascii_case))]
+    MATERIALIZED,
     #[token("MEMO", ignore(ascii_case))]
     MEMO,
     #[token("MEMORY", ignore(ascii_case))]
     MEMORY,
+    #[token("MERGE", ignore(ascii_case))]
+    MERGE,
     #[token("METRICS", ignore(ascii_case))]
     METRICS,
     #[token("MICROSECONDS", ignore(ascii_case))]
@@ -631,26 +717,38 @@ pub enum TokenKind {
     MILLENNIUM,
     #[token("MILLISECONDS", ignore(ascii_case))]
     MILLISECONDS,
+    #[token("MIN_FILE_SIZE", ignore(ascii_case))]
+    MIN_FILE_SIZE,
     #[token("MINUTE", ignore(ascii_case))]
     MINUTE,
     #[token("MONTH", ignore(ascii_case))]
     MONTH,
     #[token("MODIFY", ignore(ascii_case))]
     MODIFY,
+    #[token("MYSQL", ignore(ascii_case))]
+    MYSQL,
     #[token("NON_DISPLAY", ignore(ascii_case))]
     NON_DISPLAY,
     #[token("NATURAL", ignore(ascii_case))]
     NATURAL,
     #[token("NDJSON", ignore(ascii_case))]
     NDJSON,
+    #[token("NETWORK", ignore(ascii_case))]
+    NETWORK,
     #[token("NO_PASSWORD", ignore(ascii_case))]
     NO_PASSWORD,
+    #[token("NODE", ignore(ascii_case))]
+    NODE,
     #[token("NONE", ignore(ascii_case))]
     NONE,
     #[token("NOT", ignore(ascii_case))]
     NOT,
     #[token("NOTENANTSETTING", ignore(ascii_case))]
     NOTENANTSETTING,
+    #[token("NOTHING", ignore(ascii_case))]
+    NOTHING,
+    #[token("NOTIFICATION", ignore(ascii_case))]
+    NOTIFICATION,
     #[token("NULL", ignore(ascii_case))]
     NULL,
     #[token("NULLABLE", ignore(ascii_case))]
@@ -681,10 +779,16 @@ pub enum TokenKind {
     OVERWRITE,
     #[token("PARTITION", ignore(ascii_case))]
     PARTITION,
+    #[token("PARTITIONED", ignore(ascii_case))]
+    PARTITIONED,
     #[token("PARQUET", ignore(ascii_case))]
     PARQUET,
     #[token("PATTERN", ignore(ascii_case))]
     PATTERN,
+    #[token("PERCENT", ignore(ascii_case))]
+    PERCENT,
+    #[token("PIPE", ignore(ascii_case))]
+    PIPE,
     #[token("PIPELINE", ignore(ascii_case))]
     PIPELINE,
     #[token("PLAINTEXT_PASSWORD", ignore(ascii_case))]
@@ -713,12 +817,16 @@ pub enum TokenKind {
     RECORD_DELIMITER,
     #[token("REFERENCE_USAGE", ignore(ascii_case))]
     REFERENCE_USAGE,
+    #[token("REFRESH", ignore(ascii_case))]
+    REFRESH,
     #[token("REGEXP", ignore(ascii_case))]
     REGEXP,
     #[token("RENAME", ignore(ascii_case))]
     RENAME,
     #[token("REPLACE", ignore(ascii_case))]
     REPLACE,
+    #[token("ROLLBACK", ignore(ascii_case))]
+    ROLLBACK,
     #[token("ROW", ignore(ascii_case))]
     ROW,
     #[token("ROWS", ignore(ascii_case))]
@@ -735,10 +843,16 @@ pub enum TokenKind {
     PRECISION,
     #[token("PRESIGN", ignore(ascii_case))]
     PRESIGN,
+    #[token("PRIMARY", ignore(ascii_case))]
+    PRIMARY,
     #[token("PRIVILEGES", ignore(ascii_case))]
     PRIVILEGES,
     #[token("REMOVE", ignore(ascii_case))]
     REMOVE,
+    #[token("RESTORE", ignore(ascii_case))]
+    RESTORE,
+    #[token("RESUME", ignore(ascii_case))]
+    RESUME,
     #[token("RETAIN", ignore(ascii_case))]
     RETAIN,
     #[token("REVOKE", ignore(ascii_case))]
@@ -747,6 +861,10 @@ pub enum TokenKind {
     RECURSIVE,
     #[token("RETURN", ignore(ascii_case))]
     RETURN,
+    #[token("RETRY_ATTEMPTS", ignore(ascii_case))]
+    RETRY_ATTEMPTS,
+    #[token("RETURNS", ignore(ascii_case))]
+    RETURNS,
     #[token("RUN", ignore(ascii_case))]
     RUN,
     #[token("GRANTS", ignore(ascii_case))]
@@ -757,14 +875,20 @@ pub enum TokenKind {
     RLIKE,
     #[token("RAW", ignore(ascii_case))]
     RAW,
+    #[token("SAMPLE", ignore(ascii_case))]
+    SAMPLE,
     #[token("SCHEMA", ignore(ascii_case))]
     SCHEMA,
+    #[token("SCHEDULE", ignore(ascii_case))]
+    SCHEDULE,
     #[token("SCHEMAS", ignore(ascii_case))]
     SCHEMAS,
     #[token("SECOND", ignore(ascii_case))]
     SECOND,
     #[token("SELECT", ignore(ascii_case))]
     SELECT,
+    #[token("SEQUENCE", ignore(ascii_case))]
+    SEQUENCE,
     #[token("PIVOT", ignore(ascii_case))]
     PIVOT,
     #[token("UNPIVOT", ignore(ascii_case))]
@@ -781,6 +905,8 @@ pub enum TokenKind {
     STAGES,
     #[token("STATISTIC", ignore(ascii_case))]
     STATISTIC,
+    #[token("STATE", ignore(ascii_case))]
+    STATE,
     #[token("SHA256_PASSWORD", ignore(ascii_case))]
     SHA256_PASSWORD,
     #[token("SHOW", ignore(ascii_case))]
@@ -805,6 +931,8 @@ pub enum TokenKind {
     SPLIT_SIZE,
     #[token("STAGE", ignore(ascii_case))]
     STAGE,
+    #[token("START", ignore(ascii_case))]
+    START,
     #[token("SYNTAX", ignore(ascii_case))]
     SYNTAX,
     #[token("USAGE", ignore(ascii_case))]
@@ -819,6 +947,8 @@ pub enum TokenKind {
     SHARES,
     #[token("SUPER", ignore(ascii_case))]
     SUPER,
+    #[token("SUSPEND", ignore(ascii_case))]
+    SUSPEND,
     #[token("STATUS", ignore(ascii_case))]
     STATUS,
     #[token("STORED", ignore(ascii_case))]
@@ -831,14 +961,20 @@ pub enum TokenKind {
     SUBSTR,
     #[token("SEMI", ignore(ascii_case))]
     SEMI,
+    #[token("SOURCE", ignore(ascii_case))]
+    SOURCE,
     #[token("SOUNDS", ignore(ascii_case))]
     SOUNDS,
     #[token("TABLE", ignore(ascii_case))]
     TABLE,
+    #[token("TASK", ignore(ascii_case))]
+    TASK,
     #[token("TABLES", ignore(ascii_case))]
     TABLES,
     #[token("TEXT", ignore(ascii_case))]
     TEXT,
+    #[token("TEMPLATE", ignore(ascii_case))]
+    TEMPLATE,
     #[token("TENANTSETTING", ignore(ascii_case))]
     TENANTSETTING,
     #[token("TENANTS", ignore(ascii_case))]
@@ -847,8 +983,12 @@ pub enum TokenKind {
     TENANT,
     #[token("THEN", ignore(ascii_case))]
     THEN,
+    #[token("TIME", ignore(ascii_case))]
+    TIME,
     #[token("TIMESTAMP", ignore(ascii_case))]
     TIMESTAMP,
+    #[token("TIMEOUT", ignore(ascii_case))]
+    TIMEOUT,
     #[token("TIMEZONE_HOUR", ignore(ascii_case))]
     TIMEZONE_HOUR,
     #[token("TIMEZONE_MINUTE", ignore(ascii_case))]
@@ -863,6 +1003,8 @@ pub enum TokenKind {
     TOKEN,
     #[token("TRAILING", ignore(ascii_case))]
     TRAILING,
+    #[token("TRANSACTION", ignore(ascii_case))]
+    TRANSACTION,
     #[token("TRANSIENT", ignore(ascii_case))]
     TRANSIENT,
     #[token("TRIM", ignore(ascii_case))]
@@ -921,6 +1063,8 @@ pub enum TokenKind {
     VIEW,
     #[token("VIRTUAL", ignore(ascii_case))]
     VIRTUAL,
+    #[token("WASM", ignore(ascii_case))]
+    WASM,
     #[token("WEEK", ignore(ascii_case))]
     WEEK,
     #[token("WHEN", ignore(ascii_case))]
@@ -931,6 +1075,8 @@ pub enum TokenKind {
     WINDOW,
     #[token("WITH", ignore(ascii_case))]
     WITH,
+    #[token("WORK", ignore(ascii_case))]
+    WORK,
     #[token("XML", ignore(ascii_case))]
     XML,
     #[token("XOR", ignore(ascii_case))]
@@ -939,6 +1085,8 @@ pub enum TokenKind {
     XZ,
     #[token("YEAR", ignore(ascii_case))]
     YEAR,
+    #[token("ZONE", ignore(ascii_case))]
+    ZONE,
     #[token("ZSTD", ignore(ascii_case))]
     ZSTD,
     #[token("NULLIF", ignore(ascii_case))]
@@ -1284,6 +1432,7 @@ impl TokenKind {
             | TokenKind::PARTITION
             | TokenKind::ROWS
             | TokenKind::RANGE
+            | TokenKind::GROUPS
             // | TokenKind::OVERLAPS
             // | TokenKind::RETURNING
             | TokenKind::STAGE
@@ -1297,6 +1446,7 @@ impl TokenKind {
             | TokenKind::IGNORE_RESULT
             | TokenKind::MASKING
             | TokenKind::POLICY
+            | TokenKind::LATERAL
             if !after_as => true,
             _ => false
         }
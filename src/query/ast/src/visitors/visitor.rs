@@ -222,6 +222,7 @@ pub trait Visitor<'ast>: Sized {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn visit_function_call(
         &mut self,
         _span: Span,
@@ -230,6 +231,7 @@ pub trait Visitor<'ast>: Sized {
         args: &'ast [Expr],
         _params: &'ast [Literal],
         over: &'ast Option<Window>,
+        lambda: &'ast Option<Lambda>,
     ) {
         for arg in args {
             walk_expr(self, arg);
@@ -238,6 +240,10 @@ pub trait Visitor<'ast>: Sized {
         if let Some(over) = over {
             self.visit_window(over);
         }
+
+        if let Some(lambda) = lambda {
+            walk_expr(self, &lambda.expr);
+        }
     }
 
     fn visit_window(&mut self, window: &'ast Window) {
@@ -399,6 +405,13 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_insert(&mut self, _insert: &'ast InsertStmt) {}
     fn visit_replace(&mut self, _replace: &'ast ReplaceStmt) {}
+    fn visit_merge_into(&mut self, _merge_into: &'ast MergeIntoStmt) {}
+
+    fn visit_begin(&mut self) {}
+
+    fn visit_commit(&mut self) {}
+
+    fn visit_abort(&mut self) {}
 
     fn visit_insert_source(&mut self, _insert_source: &'ast InsertSource) {}
 
@@ -429,6 +442,10 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_undrop_database(&mut self, _stmt: &'ast UndropDatabaseStmt) {}
 
+    fn visit_backup_database(&mut self, _stmt: &'ast BackupDatabaseStmt) {}
+
+    fn visit_restore_database(&mut self, _stmt: &'ast RestoreDatabaseStmt) {}
+
     fn visit_alter_database(&mut self, _stmt: &'ast AlterDatabaseStmt) {}
 
     fn visit_use_database(&mut self, _database: &'ast Identifier) {}
@@ -443,8 +460,14 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_show_tables_status(&mut self, _stmt: &'ast ShowTablesStatusStmt) {}
 
+    fn visit_show_drop_tables(&mut self, _stmt: &'ast ShowDropTablesStmt) {}
+
     fn visit_create_table(&mut self, _stmt: &'ast CreateTableStmt) {}
 
+    fn visit_attach_table(&mut self, _stmt: &'ast AttachTableStmt) {}
+
+    fn visit_create_external_table(&mut self, _stmt: &'ast CreateExternalTableStmt) {}
+
     fn visit_create_table_source(&mut self, _source: &'ast CreateTableSource) {}
 
     fn visit_column_definition(&mut self, _column_definition: &'ast ColumnDefinition) {}
@@ -473,6 +496,12 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_drop_view(&mut self, _stmt: &'ast DropViewStmt) {}
 
+    fn visit_create_materialized_view(&mut self, _stmt: &'ast CreateMaterializedViewStmt) {}
+
+    fn visit_drop_materialized_view(&mut self, _stmt: &'ast DropMaterializedViewStmt) {}
+
+    fn visit_refresh_materialized_view(&mut self, _stmt: &'ast RefreshMaterializedViewStmt) {}
+
     fn visit_create_index(&mut self, _stmt: &'ast CreateIndexStmt) {}
 
     fn visit_drop_index(&mut self, _stmt: &'ast DropIndexStmt) {}
@@ -499,6 +528,8 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_drop_role(&mut self, _if_exists: bool, _role_name: &'ast str) {}
 
+    fn visit_alter_role(&mut self, _stmt: &'ast AlterRoleStmt) {}
+
     fn visit_grant(&mut self, _grant: &'ast GrantStmt) {}
 
     fn visit_show_grant(&mut self, _principal: &'ast Option<PrincipalIdentity>) {}
@@ -509,8 +540,7 @@ pub trait Visitor<'ast>: Sized {
         &mut self,
         _if_not_exists: bool,
         _udf_name: &'ast Identifier,
-        _parameters: &'ast [Identifier],
-        _definition: &'ast Expr,
+        _definition: &'ast UDFDefinition,
         _description: &'ast Option<String>,
     ) {
     }
@@ -520,8 +550,7 @@ pub trait Visitor<'ast>: Sized {
     fn visit_alter_udf(
         &mut self,
         _udf_name: &'ast Identifier,
-        _parameters: &'ast [Identifier],
-        _definition: &'ast Expr,
+        _definition: &'ast UDFDefinition,
         _description: &'ast Option<String>,
     ) {
     }
@@ -582,6 +611,48 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_desc_data_mask_policy(&mut self, _stmt: &'ast DescDatamaskPolicyStmt) {}
 
+    fn visit_create_row_access_policy(&mut self, _stmt: &'ast CreateRowAccessPolicyStmt) {}
+
+    fn visit_drop_row_access_policy(&mut self, _stmt: &'ast DropRowAccessPolicyStmt) {}
+
+    fn visit_create_network_policy(&mut self, _stmt: &'ast CreateNetworkPolicyStmt) {}
+
+    fn visit_drop_network_policy(&mut self, _stmt: &'ast DropNetworkPolicyStmt) {}
+
+    fn visit_create_dictionary(&mut self, _stmt: &'ast CreateDictionaryStmt) {}
+
+    fn visit_drop_dictionary(&mut self, _stmt: &'ast DropDictionaryStmt) {}
+
+    fn visit_create_sequence(&mut self, _stmt: &'ast CreateSequenceStmt) {}
+
+    fn visit_drop_sequence(&mut self, _stmt: &'ast DropSequenceStmt) {}
+
+    fn visit_create_task(&mut self, _stmt: &'ast CreateTaskStmt) {}
+
+    fn visit_drop_task(&mut self, _stmt: &'ast DropTaskStmt) {}
+
+    fn visit_alter_task(&mut self, _stmt: &'ast AlterTaskStmt) {}
+
+    fn visit_execute_task(&mut self, _stmt: &'ast ExecuteTaskStmt) {}
+
+    fn visit_create_notification_integration(
+        &mut self,
+        _stmt: &'ast CreateNotificationIntegrationStmt,
+    ) {
+    }
+
+    fn visit_drop_notification_integration(
+        &mut self,
+        _stmt: &'ast DropNotificationIntegrationStmt,
+    ) {
+    }
+
+    fn visit_create_pipe(&mut self, _stmt: &'ast CreatePipeStmt) {}
+
+    fn visit_drop_pipe(&mut self, _stmt: &'ast DropPipeStmt) {}
+
+    fn visit_alter_cluster_node(&mut self, _stmt: &'ast AlterClusterNodeStmt) {}
+
     fn visit_with(&mut self, with: &'ast With) {
         let With { ctes, .. } = with;
         for cte in ctes.iter() {
@@ -94,7 +94,7 @@ pub trait VisitorMut: Sized {
     }
 
     fn visit_is_null(&mut self, _span: Span, expr: &mut Expr, _not: bool) {
-        walk_expr_mut(self, expr);
+        self.visit_expr(expr);
     }
 
     fn visit_is_distinct_from(
@@ -104,14 +104,14 @@ pub trait VisitorMut: Sized {
         right: &mut Expr,
         _not: bool,
     ) {
-        walk_expr_mut(self, left);
-        walk_expr_mut(self, right);
+        self.visit_expr(left);
+        self.visit_expr(right);
     }
 
     fn visit_in_list(&mut self, _span: Span, expr: &mut Expr, list: &mut [Expr], _not: bool) {
-        walk_expr_mut(self, expr);
+        self.visit_expr(expr);
         for expr in list {
-            walk_expr_mut(self, expr);
+            self.visit_expr(expr);
         }
     }
 
@@ -122,7 +122,7 @@ pub trait VisitorMut: Sized {
         subquery: &mut Query,
         _not: bool,
     ) {
-        walk_expr_mut(self, expr);
+        self.visit_expr(expr);
         walk_query_mut(self, subquery);
     }
 
@@ -134,9 +134,9 @@ pub trait VisitorMut: Sized {
         high: &mut Expr,
         _not: bool,
     ) {
-        walk_expr_mut(self, expr);
-        walk_expr_mut(self, low);
-        walk_expr_mut(self, high);
+        self.visit_expr(expr);
+        self.visit_expr(low);
+        self.visit_expr(high);
     }
 
     fn visit_binary_op(
@@ -146,12 +146,12 @@ pub trait VisitorMut: Sized {
         left: &mut Expr,
         right: &mut Expr,
     ) {
-        walk_expr_mut(self, left);
-        walk_expr_mut(self, right);
+        self.visit_expr(left);
+        self.visit_expr(right);
     }
 
     fn visit_unary_op(&mut self, _span: Span, _op: &mut UnaryOperator, expr: &mut Expr) {
-        walk_expr_mut(self, expr);
+        self.visit_expr(expr);
     }
 
     fn visit_cast(
@@ -161,20 +161,20 @@ pub trait VisitorMut: Sized {
         _target_type: &mut TypeName,
         _pg_style: bool,
     ) {
-        walk_expr_mut(self, expr);
+        self.visit_expr(expr);
     }
 
     fn visit_try_cast(&mut self, _span: Span, expr: &mut Expr, _target_type: &mut TypeName) {
-        walk_expr_mut(self, expr);
+        self.visit_expr(expr);
     }
 
     fn visit_extract(&mut self, _span: Span, _kind: &mut IntervalKind, expr: &mut Expr) {
-        walk_expr_mut(self, expr);
+        self.visit_expr(expr);
     }
 
     fn visit_position(&mut self, _span: Span, substr_expr: &mut Expr, str_expr: &mut Expr) {
-        walk_expr_mut(self, substr_expr);
-        walk_expr_mut(self, str_expr);
+        self.visit_expr(substr_expr);
+        self.visit_expr(str_expr);
     }
 
     fn visit_substring(
@@ -184,11 +184,11 @@ pub trait VisitorMut: Sized {
         substring_from: &mut Box<Expr>,
         substring_for: &mut Option<Box<Expr>>,
     ) {
-        walk_expr_mut(self, expr);
-        walk_expr_mut(self, substring_from);
+        self.visit_expr(expr);
+        self.visit_expr(substring_from);
 
         if let Some(substring_for) = substring_for {
-            walk_expr_mut(self, substring_for);
+            self.visit_expr(substring_for);
         }
     }
 
@@ -198,10 +198,10 @@ pub trait VisitorMut: Sized {
         expr: &mut Expr,
         trim_where: &mut Option<(TrimWhere, Box<Expr>)>,
     ) {
-        walk_expr_mut(self, expr);
+        self.visit_expr(expr);
 
         if let Some((_, trim_where_expr)) = trim_where {
-            walk_expr_mut(self, trim_where_expr);
+            self.visit_expr(trim_where_expr);
         }
     }
 
@@ -216,10 +216,10 @@ pub trait VisitorMut: Sized {
                 Window::WindowSpec(spec) => {
                     spec.partition_by
                         .iter_mut()
-                        .for_each(|expr| walk_expr_mut(self, expr));
+                        .for_each(|expr| self.visit_expr(expr));
                     spec.order_by
                         .iter_mut()
-                        .for_each(|expr| walk_expr_mut(self, &mut expr.expr));
+                        .for_each(|expr| self.visit_expr(&mut expr.expr));
 
                     if let Some(frame) = &mut spec.window_frame {
                         self.visit_frame_bound(&mut frame.start_bound);
@@ -232,10 +232,11 @@ pub trait VisitorMut: Sized {
 
     fn visit_tuple(&mut self, _span: Span, elements: &mut [Expr]) {
         for elem in elements.iter_mut() {
-            walk_expr_mut(self, elem);
+            self.visit_expr(elem);
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn visit_function_call(
         &mut self,
         _span: Span,
@@ -244,9 +245,10 @@ pub trait VisitorMut: Sized {
         args: &mut [Expr],
         _params: &mut [Literal],
         over: &mut Option<Window>,
+        lambda: &mut Option<Lambda>,
     ) {
         for arg in args.iter_mut() {
-            walk_expr_mut(self, arg);
+            self.visit_expr(arg);
         }
 
         if let Some(over) = over {
@@ -257,10 +259,10 @@ pub trait VisitorMut: Sized {
                 Window::WindowSpec(spec) => {
                     spec.partition_by
                         .iter_mut()
-                        .for_each(|expr| walk_expr_mut(self, expr));
+                        .for_each(|expr| self.visit_expr(expr));
                     spec.order_by
                         .iter_mut()
-                        .for_each(|expr| walk_expr_mut(self, &mut expr.expr));
+                        .for_each(|expr| self.visit_expr(&mut expr.expr));
 
                     if let Some(frame) = &mut spec.window_frame {
                         self.visit_frame_bound(&mut frame.start_bound);
@@ -269,12 +271,16 @@ pub trait VisitorMut: Sized {
                 }
             }
         }
+
+        if let Some(lambda) = lambda {
+            self.visit_expr(&mut lambda.expr);
+        }
     }
 
     fn visit_frame_bound(&mut self, bound: &mut WindowFrameBound) {
         match bound {
-            WindowFrameBound::Preceding(Some(expr)) => walk_expr_mut(self, expr.as_mut()),
-            WindowFrameBound::Following(Some(expr)) => walk_expr_mut(self, expr.as_mut()),
+            WindowFrameBound::Preceding(Some(expr)) => self.visit_expr(expr.as_mut()),
+            WindowFrameBound::Following(Some(expr)) => self.visit_expr(expr.as_mut()),
             _ => {}
         }
     }
@@ -288,19 +294,19 @@ pub trait VisitorMut: Sized {
         else_result: &mut Option<Box<Expr>>,
     ) {
         if let Some(operand) = operand {
-            walk_expr_mut(self, operand);
+            self.visit_expr(operand);
         }
 
         for condition in conditions.iter_mut() {
-            walk_expr_mut(self, condition);
+            self.visit_expr(condition);
         }
 
         for result in results.iter_mut() {
-            walk_expr_mut(self, result);
+            self.visit_expr(result);
         }
 
         if let Some(else_result) = else_result {
-            walk_expr_mut(self, else_result);
+            self.visit_expr(else_result);
         }
     }
 
@@ -318,24 +324,24 @@ pub trait VisitorMut: Sized {
     }
 
     fn visit_map_access(&mut self, _span: Span, expr: &mut Expr, _accessor: &mut MapAccessor) {
-        walk_expr_mut(self, expr);
+        self.visit_expr(expr);
     }
 
     fn visit_array(&mut self, _span: Span, elements: &mut [Expr]) {
         for elem in elements.iter_mut() {
-            walk_expr_mut(self, elem);
+            self.visit_expr(elem);
         }
     }
 
     fn visit_map(&mut self, _span: Span, kvs: &mut [(Expr, Expr)]) {
         for (key_expr, val_expr) in kvs {
-            walk_expr_mut(self, key_expr);
-            walk_expr_mut(self, val_expr);
+            self.visit_expr(key_expr);
+            self.visit_expr(val_expr);
         }
     }
 
     fn visit_interval(&mut self, _span: Span, expr: &mut Expr, _unit: &mut IntervalKind) {
-        walk_expr_mut(self, expr);
+        self.visit_expr(expr);
     }
 
     fn visit_date_add(
@@ -345,8 +351,8 @@ pub trait VisitorMut: Sized {
         interval: &mut Expr,
         date: &mut Expr,
     ) {
-        walk_expr_mut(self, date);
-        walk_expr_mut(self, interval);
+        self.visit_expr(date);
+        self.visit_expr(interval);
     }
 
     fn visit_date_sub(
@@ -356,12 +362,12 @@ pub trait VisitorMut: Sized {
         interval: &mut Expr,
         date: &mut Expr,
     ) {
-        walk_expr_mut(self, date);
-        walk_expr_mut(self, interval);
+        self.visit_expr(date);
+        self.visit_expr(interval);
     }
 
     fn visit_date_trunc(&mut self, _span: Span, _unit: &mut IntervalKind, date: &mut Expr) {
-        walk_expr_mut(self, date);
+        self.visit_expr(date);
     }
 
     fn visit_statement(&mut self, statement: &mut Statement) {
@@ -414,6 +420,13 @@ pub trait VisitorMut: Sized {
 
     fn visit_insert(&mut self, _insert: &mut InsertStmt) {}
     fn visit_replace(&mut self, _replace: &mut ReplaceStmt) {}
+    fn visit_merge_into(&mut self, _merge_into: &mut MergeIntoStmt) {}
+
+    fn visit_begin(&mut self) {}
+
+    fn visit_commit(&mut self) {}
+
+    fn visit_abort(&mut self) {}
 
     fn visit_insert_source(&mut self, _insert_source: &mut InsertSource) {}
 
@@ -444,6 +457,10 @@ pub trait VisitorMut: Sized {
 
     fn visit_undrop_database(&mut self, _stmt: &mut UndropDatabaseStmt) {}
 
+    fn visit_backup_database(&mut self, _stmt: &mut BackupDatabaseStmt) {}
+
+    fn visit_restore_database(&mut self, _stmt: &mut RestoreDatabaseStmt) {}
+
     fn visit_alter_database(&mut self, _stmt: &mut AlterDatabaseStmt) {}
 
     fn visit_use_database(&mut self, _database: &mut Identifier) {}
@@ -458,8 +475,14 @@ pub trait VisitorMut: Sized {
 
     fn visit_show_tables_status(&mut self, _stmt: &mut ShowTablesStatusStmt) {}
 
+    fn visit_show_drop_tables(&mut self, _stmt: &mut ShowDropTablesStmt) {}
+
     fn visit_create_table(&mut self, _stmt: &mut CreateTableStmt) {}
 
+    fn visit_attach_table(&mut self, _stmt: &mut AttachTableStmt) {}
+
+    fn visit_create_external_table(&mut self, _stmt: &mut CreateExternalTableStmt) {}
+
     fn visit_create_table_source(&mut self, _source: &mut CreateTableSource) {}
 
     fn visit_column_definition(&mut self, _column_definition: &mut ColumnDefinition) {}
@@ -488,6 +511,12 @@ pub trait VisitorMut: Sized {
 
     fn visit_drop_view(&mut self, _stmt: &mut DropViewStmt) {}
 
+    fn visit_create_materialized_view(&mut self, _stmt: &mut CreateMaterializedViewStmt) {}
+
+    fn visit_drop_materialized_view(&mut self, _stmt: &mut DropMaterializedViewStmt) {}
+
+    fn visit_refresh_materialized_view(&mut self, _stmt: &mut RefreshMaterializedViewStmt) {}
+
     fn visit_create_index(&mut self, _stmt: &mut CreateIndexStmt) {}
 
     fn visit_drop_index(&mut self, _stmt: &mut DropIndexStmt) {}
@@ -514,6 +543,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_drop_role(&mut self, _if_exists: bool, _role_name: &mut String) {}
 
+    fn visit_alter_role(&mut self, _stmt: &mut AlterRoleStmt) {}
+
     fn visit_grant(&mut self, _grant: &mut GrantStmt) {}
 
     fn visit_show_grant(&mut self, _principal: &mut Option<PrincipalIdentity>) {}
@@ -524,8 +555,7 @@ pub trait VisitorMut: Sized {
         &mut self,
         _if_not_exists: bool,
         _udf_name: &mut Identifier,
-        _parameters: &mut [Identifier],
-        _definition: &mut Expr,
+        _definition: &mut UDFDefinition,
         _description: &mut Option<String>,
     ) {
     }
@@ -535,8 +565,7 @@ pub trait VisitorMut: Sized {
     fn visit_alter_udf(
         &mut self,
         _udf_name: &mut Identifier,
-        _parameters: &mut [Identifier],
-        _definition: &mut Expr,
+        _definition: &mut UDFDefinition,
         _description: &mut Option<String>,
     ) {
     }
@@ -597,6 +626,45 @@ pub trait VisitorMut: Sized {
 
     fn visit_desc_data_mask_policy(&mut self, _stmt: &mut DescDatamaskPolicyStmt) {}
 
+    fn visit_create_row_access_policy(&mut self, _stmt: &mut CreateRowAccessPolicyStmt) {}
+
+    fn visit_drop_row_access_policy(&mut self, _stmt: &mut DropRowAccessPolicyStmt) {}
+
+    fn visit_create_network_policy(&mut self, _stmt: &mut CreateNetworkPolicyStmt) {}
+
+    fn visit_drop_network_policy(&mut self, _stmt: &mut DropNetworkPolicyStmt) {}
+
+    fn visit_create_dictionary(&mut self, _stmt: &mut CreateDictionaryStmt) {}
+
+    fn visit_drop_dictionary(&mut self, _stmt: &mut DropDictionaryStmt) {}
+
+    fn visit_create_sequence(&mut self, _stmt: &mut CreateSequenceStmt) {}
+
+    fn visit_drop_sequence(&mut self, _stmt: &mut DropSequenceStmt) {}
+
+    fn visit_create_task(&mut self, _stmt: &mut CreateTaskStmt) {}
+
+    fn visit_drop_task(&mut self, _stmt: &mut DropTaskStmt) {}
+
+    fn visit_alter_task(&mut self, _stmt: &mut AlterTaskStmt) {}
+
+    fn visit_execute_task(&mut self, _stmt: &mut ExecuteTaskStmt) {}
+
+    fn visit_create_notification_integration(
+        &mut self,
+        _stmt: &mut CreateNotificationIntegrationStmt,
+    ) {
+    }
+
+    fn visit_drop_notification_integration(&mut self, _stmt: &mut DropNotificationIntegrationStmt) {
+    }
+
+    fn visit_create_pipe(&mut self, _stmt: &mut CreatePipeStmt) {}
+
+    fn visit_drop_pipe(&mut self, _stmt: &mut DropPipeStmt) {}
+
+    fn visit_alter_cluster_node(&mut self, _stmt: &mut AlterClusterNodeStmt) {}
+
     fn visit_with(&mut self, with: &mut With) {
         let With { ctes, .. } = with;
         for cte in ctes.iter_mut() {
@@ -617,7 +685,7 @@ pub trait VisitorMut: Sized {
 
     fn visit_order_by(&mut self, order_by: &mut OrderByExpr) {
         let OrderByExpr { expr, .. } = order_by;
-        walk_expr_mut(self, expr);
+        self.visit_expr(expr);
     }
 
     fn visit_select_stmt(&mut self, stmt: &mut SelectStmt) {
@@ -639,19 +707,19 @@ pub trait VisitorMut: Sized {
         }
 
         if let Some(selection) = selection {
-            walk_expr_mut(self, selection);
+            self.visit_expr(selection);
         }
 
         match group_by {
             Some(GroupBy::Normal(exprs)) => {
                 for expr in exprs {
-                    walk_expr_mut(self, expr);
+                    self.visit_expr(expr);
                 }
             }
             Some(GroupBy::GroupingSets(sets)) => {
                 for set in sets {
                     for expr in set {
-                        walk_expr_mut(self, expr);
+                        self.visit_expr(expr);
                     }
                 }
             }
@@ -659,7 +727,7 @@ pub trait VisitorMut: Sized {
         }
 
         if let Some(having) = having {
-            walk_expr_mut(self, having);
+            self.visit_expr(having);
         }
     }
 
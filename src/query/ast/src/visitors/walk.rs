@@ -94,7 +94,8 @@ pub fn walk_expr<'a, V: Visitor<'a>>(visitor: &mut V, expr: &'a Expr) {
             args,
             params,
             window,
-        } => visitor.visit_function_call(*span, *distinct, name, args, params, window),
+            lambda,
+        } => visitor.visit_function_call(*span, *distinct, name, args, params, window, lambda),
         Expr::Case {
             span,
             operand,
@@ -328,6 +329,10 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::Query(query) => visitor.visit_query(query),
         Statement::Insert(insert) => visitor.visit_insert(insert),
         Statement::Replace(replace) => visitor.visit_replace(replace),
+        Statement::MergeInto(merge_into) => visitor.visit_merge_into(merge_into),
+        Statement::Begin => visitor.visit_begin(),
+        Statement::Commit => visitor.visit_commit(),
+        Statement::Abort => visitor.visit_abort(),
         Statement::Delete {
             table_reference,
             selection,
@@ -365,6 +370,8 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::CreateDatabase(stmt) => visitor.visit_create_database(stmt),
         Statement::DropDatabase(stmt) => visitor.visit_drop_database(stmt),
         Statement::UndropDatabase(stmt) => visitor.visit_undrop_database(stmt),
+        Statement::BackupDatabase(stmt) => visitor.visit_backup_database(stmt),
+        Statement::RestoreDatabase(stmt) => visitor.visit_restore_database(stmt),
         Statement::AlterDatabase(stmt) => visitor.visit_alter_database(stmt),
         Statement::UseDatabase { database } => visitor.visit_use_database(database),
         Statement::ShowTables(stmt) => visitor.visit_show_tables(stmt),
@@ -372,7 +379,10 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::ShowCreateTable(stmt) => visitor.visit_show_create_table(stmt),
         Statement::DescribeTable(stmt) => visitor.visit_describe_table(stmt),
         Statement::ShowTablesStatus(stmt) => visitor.visit_show_tables_status(stmt),
+        Statement::ShowDropTables(stmt) => visitor.visit_show_drop_tables(stmt),
         Statement::CreateTable(stmt) => visitor.visit_create_table(stmt),
+        Statement::AttachTable(stmt) => visitor.visit_attach_table(stmt),
+        Statement::CreateExternalTable(stmt) => visitor.visit_create_external_table(stmt),
         Statement::DropTable(stmt) => visitor.visit_drop_table(stmt),
         Statement::UndropTable(stmt) => visitor.visit_undrop_table(stmt),
         Statement::AlterTable(stmt) => visitor.visit_alter_table(stmt),
@@ -385,6 +395,9 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::CreateView(stmt) => visitor.visit_create_view(stmt),
         Statement::AlterView(stmt) => visitor.visit_alter_view(stmt),
         Statement::DropView(stmt) => visitor.visit_drop_view(stmt),
+        Statement::CreateMaterializedView(stmt) => visitor.visit_create_materialized_view(stmt),
+        Statement::DropMaterializedView(stmt) => visitor.visit_drop_materialized_view(stmt),
+        Statement::RefreshMaterializedView(stmt) => visitor.visit_refresh_materialized_view(stmt),
         Statement::CreateIndex(stmt) => visitor.visit_create_index(stmt),
         Statement::DropIndex(stmt) => visitor.visit_drop_index(stmt),
         Statement::CreateVirtualColumns(stmt) => visitor.visit_create_virtual_columns(stmt),
@@ -404,32 +417,25 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
             if_exists,
             role_name,
         } => visitor.visit_drop_role(*if_exists, role_name),
+        Statement::AlterRole(stmt) => visitor.visit_alter_role(stmt),
         Statement::Grant(stmt) => visitor.visit_grant(stmt),
         Statement::ShowGrants { principal } => visitor.visit_show_grant(principal),
         Statement::Revoke(stmt) => visitor.visit_revoke(stmt),
         Statement::CreateUDF {
             if_not_exists,
             udf_name,
-            parameters,
             definition,
             description,
-        } => visitor.visit_create_udf(
-            *if_not_exists,
-            udf_name,
-            parameters,
-            definition,
-            description,
-        ),
+        } => visitor.visit_create_udf(*if_not_exists, udf_name, definition, description),
         Statement::DropUDF {
             if_exists,
             udf_name,
         } => visitor.visit_drop_udf(*if_exists, udf_name),
         Statement::AlterUDF {
             udf_name,
-            parameters,
             definition,
             description,
-        } => visitor.visit_alter_udf(udf_name, parameters, definition, description),
+        } => visitor.visit_alter_udf(udf_name, definition, description),
         Statement::ListStage { location, pattern } => visitor.visit_list_stage(location, pattern),
         Statement::ShowStages => visitor.visit_show_stages(),
         Statement::DropStage {
@@ -469,5 +475,26 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::CreateDatamaskPolicy(stmt) => visitor.visit_create_data_mask_policy(stmt),
         Statement::DropDatamaskPolicy(stmt) => visitor.visit_drop_data_mask_policy(stmt),
         Statement::DescDatamaskPolicy(stmt) => visitor.visit_desc_data_mask_policy(stmt),
+        Statement::CreateRowAccessPolicy(stmt) => visitor.visit_create_row_access_policy(stmt),
+        Statement::DropRowAccessPolicy(stmt) => visitor.visit_drop_row_access_policy(stmt),
+        Statement::CreateNetworkPolicy(stmt) => visitor.visit_create_network_policy(stmt),
+        Statement::DropNetworkPolicy(stmt) => visitor.visit_drop_network_policy(stmt),
+        Statement::CreateDictionary(stmt) => visitor.visit_create_dictionary(stmt),
+        Statement::DropDictionary(stmt) => visitor.visit_drop_dictionary(stmt),
+        Statement::CreateSequence(stmt) => visitor.visit_create_sequence(stmt),
+        Statement::DropSequence(stmt) => visitor.visit_drop_sequence(stmt),
+        Statement::CreateTask(stmt) => visitor.visit_create_task(stmt),
+        Statement::DropTask(stmt) => visitor.visit_drop_task(stmt),
+        Statement::AlterTask(stmt) => visitor.visit_alter_task(stmt),
+        Statement::ExecuteTask(stmt) => visitor.visit_execute_task(stmt),
+        Statement::CreateNotificationIntegration(stmt) => {
+            visitor.visit_create_notification_integration(stmt)
+        }
+        Statement::DropNotificationIntegration(stmt) => {
+            visitor.visit_drop_notification_integration(stmt)
+        }
+        Statement::CreatePipe(stmt) => visitor.visit_create_pipe(stmt),
+        Statement::DropPipe(stmt) => visitor.visit_drop_pipe(stmt),
+        Statement::AlterClusterNode(stmt) => visitor.visit_alter_cluster_node(stmt),
     }
 }
@@ -74,10 +74,18 @@ fn test_statement() {
         r#"show full tables"#,
         r#"show full tables from db"#,
         r#"show full tables from ctl.db"#,
+        r#"show drop tables from db"#,
+        r#"backup database db1 to @s1/backups/ with data;"#,
+        r#"restore database ctl.db1 from @s1/backups/;"#,
         r#"show full columns in t in db"#,
         r#"show columns in t from ctl.db"#,
         r#"show full columns from t from db like 'id%'"#,
         r#"show processlist;"#,
+        r#"begin;"#,
+        r#"begin transaction;"#,
+        r#"commit work;"#,
+        r#"rollback;"#,
+        r#"abort transaction;"#,
         r#"show create table a.b;"#,
         r#"show create table a.b format TabSeparatedWithNamesAndTypes;"#,
         r#"explain pipeline select a from b;"#,
@@ -92,10 +100,14 @@ fn test_statement() {
         r#"create table if not exists a.b (a int, b int, c int as (a + b) virtual );"#,
         r#"create table a.b like c.d;"#,
         r#"create table t like t2 engine = memory;"#,
+        r#"create table t using template (select * from t2);"#,
+        r#"create table t2 clone db.t1 at (snapshot => 'abc');"#,
         r#"create table if not exists a.b (a int) 's3://testbucket/admin/data/' connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='http://127.0.0.1:9900');"#,
         r#"create table if not exists a.b (a int) 's3://testbucket/admin/data/'
              connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='http://127.0.0.1:9900')
              location_prefix = 'db';"#,
+        r#"attach table t 's3://testbucket/admin/data/1/2/' connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin');"#,
+        r#"create external table t (id int, name string) location = 's3://testbucket/events/' connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin') partitioned by (dt string);"#,
         r#"truncate table a;"#,
         r#"truncate table "a".b;"#,
         r#"drop table a;"#,
@@ -161,6 +173,7 @@ fn test_statement() {
         r#"insert into t (c1, c2) values (1, 2), (3, 4);"#,
         r#"insert into table t format json;"#,
         r#"insert into table t select * from t2;"#,
+        r#"insert into t (a) values (1) on conflict (a) do nothing;"#,
         r#"select parse_json('{"k1": [0, 1, 2]}').k1[0];"#,
         r#"CREATE STAGE ~"#,
         r#"CREATE STAGE IF NOT EXISTS test_stage url='s3://load/files/' credentials=(aws_key_id='1a2b3c' aws_secret_key='4x5y6z') file_format=(type = CSV compression = GZIP record_delimiter=',')"#,
@@ -179,7 +192,9 @@ fn test_statement() {
         r#"ALTER TABLE t RECLUSTER FINAL WHERE c1 > 0;"#,
         r#"ALTER TABLE t ADD COLUMN a float default 101 COMMENT 'hello';"#,
         r#"ALTER TABLE t DROP COLUMN b;"#,
+        r#"ALTER TABLE t RENAME COLUMN a TO b;"#,
         r#"ALTER TABLE t MODIFY COLUMN b SET MASKING POLICY mask;"#,
+        r#"ALTER TABLE t MODIFY COLUMN b BIGINT NULL;"#,
         r#"ALTER TABLE t SET OPTIONS(SNAPSHOT_LOCATION='1/7/_ss/101fd790dbbe4238a31a8f2e2f856179_v4.mpk',block_per_segment = 500);"#,
         r#"ALTER DATABASE IF EXISTS ctl.c RENAME TO a;"#,
         r#"ALTER DATABASE c RENAME TO a;"#,
@@ -208,6 +223,7 @@ fn test_statement() {
         r#"GRANT SELECT ON db01.tb1 TO ROLE 'role1';"#,
         r#"GRANT SELECT ON tb1 TO ROLE 'role1';"#,
         r#"GRANT ALL ON tb1 TO 'u1';"#,
+        r#"GRANT SELECT(c1, c2) ON db01.tb1 TO ROLE 'role1';"#,
         r#"SHOW GRANTS;"#,
         r#"SHOW GRANTS FOR 'test-grant'@'localhost';"#,
         r#"SHOW GRANTS FOR USER 'test-grant'@'localhost';"#,
@@ -215,6 +231,7 @@ fn test_statement() {
         r#"REVOKE SELECT, CREATE ON * FROM 'test-grant'@'localhost';"#,
         r#"REVOKE SELECT ON tb1 FROM ROLE 'role1';"#,
         r#"REVOKE ALL ON tb1 FROM 'u1';"#,
+        r#"REVOKE SELECT(c1) ON tb1 FROM ROLE 'role1';"#,
         r#"COPY INTO mytable
                 FROM @~/mybucket/data.csv
                 FILE_FORMAT = (
@@ -289,6 +306,7 @@ fn test_statement() {
                     skip_header = 1
                 )
                 size_limit=10;"#,
+        r#"COPY INTO @my_stage FROM mytable FILE_FORMAT = (type = PARQUET) MAX_FILE_SIZE = 1024 PARTITION BY (region, to_year(ts) AS y);"#,
         r#"COPY INTO mytable
                 FROM 's3://mybucket/data.csv'
                 CREDENTIALS = (
@@ -339,6 +357,11 @@ fn test_statement() {
                 )
                 size_limit=10
                 disable_variant_check=true;"#,
+        r#"COPY INTO mytable
+                FROM @my_stage
+                FILE_FORMAT = (type = PARQUET)
+                match_by_column_name = case_insensitive
+                error_on_column_count_mismatch = true;"#,
         // We used to support COPY FROM a quoted at string
         // r#"COPY INTO mytable
         //         FROM '@external_stage/path/to/file.csv'
@@ -407,6 +430,31 @@ fn test_statement() {
         r#"ALTER VIRTUAL COLUMNS (a['k1']['k2'], b[0][1]) FOR t"#,
         r#"DROP VIRTUAL COLUMNS FOR t"#,
         r#"GENERATE VIRTUAL COLUMNS FOR t"#,
+        r#"CREATE DICTIONARY d (k String, v String) PRIMARY KEY k SOURCE (redis (host='127.0.0.1' port='6379'))"#,
+        r#"DROP DICTIONARY IF EXISTS d"#,
+        r#"CREATE ROW ACCESS POLICY rap AS (r String) RETURNS BOOLEAN -> r = 'EU' EXEMPT ROLES = ('account_admin')"#,
+        r#"ALTER TABLE t ADD ROW ACCESS POLICY rap ON (region)"#,
+        r#"DROP ROW ACCESS POLICY IF EXISTS rap"#,
+        r#"CREATE NETWORK POLICY np ALLOWED_IP_LIST = ('192.168.1.0/24') BLOCKED_IP_LIST = ('192.168.1.99') COMMENT = 'office'"#,
+        r#"DROP NETWORK POLICY IF EXISTS np"#,
+        r#"ALTER USER u1 WITH SET NETWORK POLICY = 'np'"#,
+        r#"ALTER USER u1 WITH UNSET NETWORK POLICY"#,
+        r#"ALTER USER u1 SET SETTINGS (max_threads = 4, timezone = 'UTC')"#,
+        r#"ALTER ROLE 'r1' UNSET SETTINGS (max_threads)"#,
+        r#"CREATE SEQUENCE IF NOT EXISTS seq1 START WITH 10 INCREMENT BY 5"#,
+        r#"DROP SEQUENCE IF EXISTS seq1"#,
+        r#"CREATE TASK IF NOT EXISTS task1 SCHEDULE = '5 MINUTE' RETRY_ATTEMPTS = 2 COMMENT = 'purge' AS TRUNCATE TABLE t1"#,
+        r#"ALTER TASK task1 SUSPEND"#,
+        r#"EXECUTE TASK task1"#,
+        r#"DROP TASK IF EXISTS task1"#,
+        r#"CREATE NOTIFICATION INTEGRATION IF NOT EXISTS hook1 TYPE = webhook CONNECTION = (url = 'https://example.com/hook' method = 'POST') COMMENT = 'orders'"#,
+        r#"DROP NOTIFICATION INTEGRATION IF EXISTS hook1"#,
+        r#"CREATE PIPE IF NOT EXISTS pipe1 COMMENT = 'orders' AS COPY INTO db1.t1 FROM KAFKA (rest_proxy_url = 'http://proxy:8082' topic = 'orders') FILE_FORMAT = (type = NDJSON)"#,
+        r#"CREATE PIPE pipe2 AUTO_INGEST = TRUE AWS_SQS_QUEUE_URL = 'https://sqs.us-east-1.amazonaws.com/123456789012/orders' AS COPY INTO t1 FROM @s1/orders/ PATTERN = '.*[.]csv' FILE_FORMAT = (type = CSV)"#,
+        r#"CREATE PIPE pipe3 AS COPY INTO shop.orders FROM MYSQL (database = 'shop' table = 'orders' primary_key = 'id')"#,
+        r#"DROP PIPE IF EXISTS pipe1"#,
+        r#"ALTER CLUSTER NODE 'node-1' DRAIN"#,
+        r#"ALTER CLUSTER NODE 'node-1' DRAIN TIMEOUT = 60"#,
         "--各环节转各环节转各环节转各环节转各\n  select 34343",
         "-- xxxxx\n  select 34343;",
     ];
@@ -533,9 +581,12 @@ fn test_query() {
         r#"select * from monthly_sales pivot(sum(amount) for month in ('JAN', 'FEB', 'MAR', 'APR')) order by empid"#,
         r#"select * from monthly_sales_1 unpivot(sales for month in (jan, feb, mar, april)) order by empid"#,
         r#"select * from range(1, 2)"#,
+        r#"select * from t, lateral range(1, t.a)"#,
         r#"select sum(a) over w from customer window w as (partition by a order by b)"#,
         r#"select a, sum(a) over w, sum(a) over w1, sum(a) over w2 from t1 window w as (partition by a), w2 as (w1 rows current row), w1 as (w order by a) order by a"#,
         r#"SELECT * FROM ((SELECT * FROM xyu ORDER BY x, y)) AS xyu"#,
+        r#"SELECT * FROM t SAMPLE BLOCK (10)"#,
+        r#"SELECT * FROM t AS t1 SAMPLE ROW (0.5 PERCENT)"#,
     ];
 
     for case in cases {
@@ -644,6 +695,8 @@ fn test_expr() {
         r#"COUNT() OVER (ORDER BY hire_date ROWS UNBOUNDED PRECEDING)"#,
         r#"COUNT() OVER (ORDER BY hire_date ROWS CURRENT ROW)"#,
         r#"COUNT() OVER (ORDER BY hire_date ROWS 3 PRECEDING)"#,
+        r#"ts AT TIME ZONE 'Asia/Tokyo'"#,
+        r#"name COLLATE 'utf8_unicode_ci'"#,
     ];
 
     for case in cases {
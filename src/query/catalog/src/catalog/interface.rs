@@ -40,6 +40,8 @@ use common_meta_app::schema::GetTableCopiedFileReply;
 use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::IndexMeta;
 use common_meta_app::schema::ListIndexesReq;
+use common_meta_app::schema::ListTableCopiedFileReply;
+use common_meta_app::schema::ListTableCopiedFileReq;
 use common_meta_app::schema::ListVirtualColumnsReq;
 use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
@@ -54,6 +56,8 @@ use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpdateVirtualColumnReply;
@@ -191,6 +195,11 @@ pub trait Catalog: DynClone + Send + Sync {
         req: UpdateTableMetaReq,
     ) -> Result<UpdateTableMetaReply>;
 
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply>;
+
     async fn count_tables(&self, req: CountTablesReq) -> Result<CountTablesReply>;
 
     async fn get_table_copied_file_info(
@@ -200,6 +209,13 @@ pub trait Catalog: DynClone + Send + Sync {
         req: GetTableCopiedFileReq,
     ) -> Result<GetTableCopiedFileReply>;
 
+    async fn list_table_copied_file_info(
+        &self,
+        tenant: &str,
+        db_name: &str,
+        req: ListTableCopiedFileReq,
+    ) -> Result<ListTableCopiedFileReply>;
+
     async fn truncate_table(
         &self,
         table_info: &TableInfo,
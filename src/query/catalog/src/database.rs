@@ -24,6 +24,8 @@ use common_meta_app::schema::DropTableByIdReq;
 use common_meta_app::schema::DropTableReply;
 use common_meta_app::schema::GetTableCopiedFileReply;
 use common_meta_app::schema::GetTableCopiedFileReq;
+use common_meta_app::schema::ListTableCopiedFileReply;
+use common_meta_app::schema::ListTableCopiedFileReq;
 use common_meta_app::schema::RenameTableReply;
 use common_meta_app::schema::RenameTableReq;
 use common_meta_app::schema::TableInfo;
@@ -167,6 +169,17 @@ pub trait Database: DynClone + Sync + Send {
         )))
     }
 
+    #[async_backtrace::framed]
+    async fn list_table_copied_file_info(
+        &self,
+        _req: ListTableCopiedFileReq,
+    ) -> Result<ListTableCopiedFileReply> {
+        Err(ErrorCode::Unimplemented(format!(
+            "UnImplement list_table_copied_file_info in {} Database",
+            self.name()
+        )))
+    }
+
     #[async_backtrace::framed]
     async fn truncate_table(&self, _req: TruncateTableReq) -> Result<TruncateTableReply> {
         Err(ErrorCode::Unimplemented(format!(
@@ -23,3 +23,4 @@ pub mod table;
 pub mod table_args;
 pub mod table_context;
 pub mod table_function;
+pub mod txn;
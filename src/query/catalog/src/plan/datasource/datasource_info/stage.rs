@@ -31,6 +31,9 @@ pub struct StageTableInfo {
     pub stage_info: StageInfo,
     pub files_to_copy: Option<Vec<StageFileInfo>>,
     pub is_select: bool,
+    /// Hive-style partition keys of an unload, the trailing columns of the input blocks
+    /// carry their values and are not written into the files.
+    pub partition_by: Vec<String>,
 }
 
 impl StageTableInfo {
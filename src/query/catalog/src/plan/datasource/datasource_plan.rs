@@ -43,6 +43,10 @@ pub struct DataSourcePlan {
 
     // data mask policy for `output_schema` columns
     pub data_mask_policy: Option<BTreeMap<FieldIndex, RemoteExpr>>,
+
+    /// The index of the table in the query metadata, used to find the
+    /// runtime filters pushed down to the scan.
+    pub table_index: Option<usize>,
 }
 
 impl DataSourcePlan {
@@ -15,6 +15,8 @@
 use std::fmt::Debug;
 
 use common_expression::types::DataType;
+use common_expression::types::F32;
+use common_expression::types::F64;
 use common_expression::RemoteExpr;
 use common_expression::Scalar;
 use common_expression::TableDataType;
@@ -72,6 +74,75 @@ pub struct AggIndexInfo {
     // The index in aggregating index is the offset in the output list.
     pub selection: Vec<RemoteExpr>,
     pub filter: Option<RemoteExpr>,
+    /// Set if the query merges the partial aggregate states stored in the index,
+    /// instead of aggregating the raw data of the table.
+    pub merge: Option<AggIndexMerge>,
+}
+
+/// How an aggregate query reads the partial aggregate states of an aggregating index.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AggIndexMerge {
+    /// Offsets in the index data of the group items, followed by the ones of the
+    /// aggregate functions of the query.
+    pub projection: Vec<usize>,
+    /// Computes the same columns from the raw data, for the blocks not indexed yet.
+    pub aggregation: AggIndexAggregation,
+}
+
+/// The partial aggregation of a block, the columns of the table are referred by names.
+///
+/// The output of a block are the group items and the serialized aggregate states of
+/// each group.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AggIndexAggregation {
+    /// The rows not matching the filter are skipped.
+    pub filter: Option<RemoteExpr<String>>,
+    pub group_items: Vec<RemoteExpr<String>>,
+    pub aggregate_functions: Vec<AggIndexFunction>,
+    /// Offsets of the output columns in the group items followed by the aggregate functions.
+    pub output: Vec<usize>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AggIndexFunction {
+    pub name: String,
+    pub params: Vec<Scalar>,
+    pub args: Vec<RemoteExpr<String>>,
+}
+
+/// Level of the sampling of `SELECT ... FROM t SAMPLE [ROW | BLOCK] (p)`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SampleLevel {
+    /// Rows are picked with the probability, after being read.
+    Row,
+    /// Blocks are picked with the probability while pruning, the others are not read.
+    Block,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SampleConfig {
+    pub level: SampleLevel,
+    /// The probability of a row or block to be picked, in `(0, 1]`.
+    pub probability: F64,
+}
+
+/// The distance functions between vectors.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum VectorDistance {
+    /// `cosine_distance`
+    Cosine,
+    /// `l2_distance`
+    L2,
+}
+
+/// The nearest rows to a constant vector of `ORDER BY cosine_distance(col, [..]) LIMIT k`,
+/// the blocks that can't contain them are pruned with the vector index.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VectorTopN {
+    pub column: String,
+    pub distance: VectorDistance,
+    pub query: Vec<F32>,
+    pub limit: usize,
 }
 
 /// Extras is a wrapper for push down items.
@@ -101,6 +172,10 @@ pub struct PushDownInfo {
     pub lazy_materialization: bool,
     /// Aggregating index information.
     pub agg_index: Option<AggIndexInfo>,
+    /// Optional table sample.
+    pub sample: Option<SampleConfig>,
+    /// Optional nearest rows by a vector distance.
+    pub vector_topn: Option<VectorTopN>,
 }
 
 /// TopK is a wrapper for topk push down items.
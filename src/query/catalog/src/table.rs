@@ -215,6 +215,21 @@ pub trait Table: Sync + Send {
         )))
     }
 
+    /// Apply the output of the `MERGE INTO` join to the table.
+    ///
+    /// The input blocks consist of the row id of the matched row (NULL if not matched),
+    /// the action to be taken, followed by the new values of the table columns.
+    #[async_backtrace::framed]
+    async fn merge_into(&self, ctx: Arc<dyn TableContext>, pipeline: &mut Pipeline) -> Result<()> {
+        let (_, _) = (ctx, pipeline);
+
+        Err(ErrorCode::Unimplemented(format!(
+            "merge_into operation for table {} is not implemented. table engine : {}",
+            self.name(),
+            self.get_table_info().meta.engine
+        )))
+    }
+
     fn commit_insertion(
         &self,
         ctx: Arc<dyn TableContext>,
@@ -396,6 +411,10 @@ pub trait TableExt: Table {
         let name = table_info.name.clone();
         let tid = table_info.ident.table_id;
         let catalog = ctx.get_catalog(table_info.catalog())?;
+        let buffered = ctx.txn_mgr().lock().get_table_from_buffer(tid);
+        if let Some(table_info) = buffered {
+            return catalog.get_table_by_info(&table_info);
+        }
         let (ident, meta) = catalog.get_table_meta_by_id(tid).await?;
         let table_info: TableInfo = TableInfo {
             ident,
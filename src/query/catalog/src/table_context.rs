@@ -17,12 +17,14 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
 use std::time::SystemTime;
 
 use common_base::base::Progress;
 use common_base::base::ProgressValues;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::Expr;
 use common_expression::FunctionContext;
 use common_io::prelude::FormatSettings;
 use common_meta_app::principal::FileFormatParams;
@@ -44,6 +46,7 @@ use crate::plan::DataSourcePlan;
 use crate::plan::PartInfoPtr;
 use crate::plan::Partitions;
 use crate::table::Table;
+use crate::txn::TxnManagerRef;
 
 #[derive(Debug)]
 pub struct ProcessInfo {
@@ -64,6 +67,83 @@ pub struct ProcessInfo {
     pub status_info: Option<String>,
 }
 
+/// A process of a node of the cluster, as listed by `system.processes`. The nodes send
+/// their processes to the node listing the processes of the cluster.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct NodeProcessInfo {
+    pub node: String,
+    pub id: String,
+    pub typ: String,
+    pub host: Option<String>,
+    pub user: String,
+    pub state: String,
+    pub database: String,
+    pub extra_info: String,
+    pub memory_usage: i64,
+    pub data_read_bytes: u64,
+    pub data_write_bytes: u64,
+    pub scan_progress_read_rows: u64,
+    pub scan_progress_read_bytes: u64,
+    pub mysql_connection_id: Option<u32>,
+    /// Seconds since the query started, or the session if it's idle.
+    pub time: u64,
+    pub status: String,
+}
+
+impl NodeProcessInfo {
+    pub fn create(node: &str, info: &ProcessInfo) -> NodeProcessInfo {
+        let scan_progress = info.scan_progress_value.clone().unwrap_or_default();
+        let (data_read_bytes, data_write_bytes) = match &info.data_metrics {
+            Some(data_metrics) => (
+                data_metrics.get_read_bytes() as u64,
+                data_metrics.get_write_bytes() as u64,
+            ),
+            None => (0, 0),
+        };
+
+        NodeProcessInfo {
+            node: node.to_string(),
+            id: info.id.clone(),
+            typ: info.typ.clone(),
+            host: info.client_address.map(|address| address.to_string()),
+            user: info
+                .user
+                .as_ref()
+                .map(|user| user.name.clone())
+                .unwrap_or_default(),
+            state: info.state.clone(),
+            database: info.database.clone(),
+            extra_info: info.session_extra_info.clone().unwrap_or_default(),
+            memory_usage: info.memory_usage,
+            data_read_bytes,
+            data_write_bytes,
+            scan_progress_read_rows: scan_progress.rows as u64,
+            scan_progress_read_bytes: scan_progress.bytes as u64,
+            mysql_connection_id: info.mysql_connection_id,
+            time: info
+                .created_time
+                .elapsed()
+                .unwrap_or(Duration::from_secs(0))
+                .as_secs(),
+            status: info.status_info.clone().unwrap_or_default(),
+        }
+    }
+}
+
+/// A query admitted to or queued in a workload group.
+#[derive(Debug, Clone)]
+pub struct WorkloadQueryInfo {
+    pub workload_group: String,
+    pub query_id: String,
+    pub user: String,
+    /// `Queued` or `Running`.
+    pub state: String,
+    /// Time spent in the queue, up to now if it's still queued.
+    pub queued_time: Duration,
+    pub memory_usage: i64,
+    pub query_text: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct StageAttachment {
     pub location: String,
@@ -96,6 +176,9 @@ pub trait TableContext: Send + Sync {
     fn set_partitions(&self, partitions: Partitions) -> Result<()>;
     fn add_partitions_sha(&self, sha: String);
     fn get_partitions_shas(&self) -> Vec<String>;
+    /// Add the runtime filters pushed down from a hash join to the scan of `table_index`.
+    fn set_runtime_filter(&self, filters: (usize, Vec<Expr<String>>));
+    fn get_runtime_filters(&self, table_index: usize) -> Vec<Expr<String>>;
     fn get_cacheable(&self) -> bool;
     fn set_cacheable(&self, cacheable: bool);
 
@@ -111,6 +194,8 @@ pub trait TableContext: Send + Sync {
     fn get_current_database(&self) -> String;
     fn get_current_user(&self) -> Result<UserInfo>;
     fn get_current_role(&self) -> Option<RoleInfo>;
+    /// Get the current role and all the roles granted to it.
+    async fn get_all_effective_roles(&self) -> Result<Vec<RoleInfo>>;
     fn get_fuse_version(&self) -> String;
     fn get_format_settings(&self) -> Result<FormatSettings>;
     fn get_tenant(&self) -> String;
@@ -122,6 +207,9 @@ pub trait TableContext: Send + Sync {
     fn get_shard_settings(&self) -> Arc<Settings>;
     fn get_cluster(&self) -> Arc<Cluster>;
     fn get_processes_info(&self) -> Vec<ProcessInfo>;
+    /// Get the processes of all the nodes of the cluster.
+    async fn get_cluster_processes_info(&self) -> Result<Vec<NodeProcessInfo>>;
+    fn get_workload_queries_info(&self) -> Vec<WorkloadQueryInfo>;
     fn get_stage_attachment(&self) -> Option<StageAttachment>;
     fn get_last_query_id(&self, index: i32) -> String;
     fn get_query_id_history(&self) -> HashSet<String>;
@@ -136,6 +224,8 @@ pub trait TableContext: Send + Sync {
     fn apply_changed_settings(&self, changes: HashMap<String, ChangeValue>) -> Result<()>;
     fn get_changed_settings(&self) -> HashMap<String, ChangeValue>;
 
+    fn txn_mgr(&self) -> TxnManagerRef;
+
     fn get_query_profile_manager(&self) -> Arc<QueryProfileManager>;
 
     // Get the storage data accessor operator from the session manager.
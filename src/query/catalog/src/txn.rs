@@ -0,0 +1,163 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_app::schema::UpsertTableCopiedFileReq;
+use common_meta_types::MatchSeq;
+use parking_lot::Mutex;
+use serde::Deserialize;
+use serde::Serialize;
+
+pub type TxnManagerRef = Arc<Mutex<TxnManager>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TxnState {
+    /// Each statement commits its changes on its own.
+    #[default]
+    AutoCommit,
+    /// The changes of the statements are buffered until `COMMIT`.
+    Active,
+}
+
+/// A table mutated inside the transaction.
+#[derive(Debug, Clone)]
+pub struct TxnTable {
+    /// The table as of the first mutation inside the transaction, the transaction
+    /// conflicts with the concurrent commits made after it.
+    pub base: TableInfo,
+    /// The table with all the mutations of the transaction applied.
+    pub pending: TableInfo,
+    pub copied_files: Option<UpsertTableCopiedFileReq>,
+    pub deduplicated_label: Option<String>,
+}
+
+/// Keeps the explicit transaction of a session.
+///
+/// Inside a transaction, the fuse commit path hands the new table meta over to the
+/// transaction instead of updating the meta service, later statements of the
+/// transaction read the buffered tables. `COMMIT` updates all the buffered tables
+/// in a single meta service transaction.
+#[derive(Debug, Default)]
+pub struct TxnManager {
+    state: TxnState,
+    tables: BTreeMap<u64, TxnTable>,
+}
+
+impl TxnManager {
+    pub fn init() -> TxnManagerRef {
+        Arc::new(Mutex::new(TxnManager::default()))
+    }
+
+    pub fn begin(&mut self) {
+        if self.state == TxnState::AutoCommit {
+            self.state = TxnState::Active;
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.state == TxnState::Active
+    }
+
+    pub fn state(&self) -> TxnState {
+        self.state
+    }
+
+    /// Buffers the update of the table meta, `table_info` is the table the update is
+    /// based on.
+    pub fn update_table_meta(
+        &mut self,
+        table_info: &TableInfo,
+        req: UpdateTableMetaReq,
+    ) -> Result<()> {
+        if let Some(other) = self.tables.values().next() {
+            if other.base.catalog() != table_info.catalog() {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "Transaction across catalogs is not supported, table {} is in catalog {}, while table {} is in catalog {}",
+                    other.base.desc,
+                    other.base.catalog(),
+                    table_info.desc,
+                    table_info.catalog()
+                )));
+            }
+        }
+
+        let table = self.tables.entry(req.table_id).or_insert_with(|| TxnTable {
+            base: table_info.clone(),
+            pending: table_info.clone(),
+            copied_files: None,
+            deduplicated_label: None,
+        });
+        table.pending.meta = req.new_table_meta;
+        table.copied_files = match (table.copied_files.take(), req.copied_files) {
+            (Some(mut copied), Some(files)) => {
+                copied.file_info.extend(files.file_info);
+                copied.fail_if_duplicated |= files.fail_if_duplicated;
+                Some(copied)
+            }
+            (copied, files) => files.or(copied),
+        };
+        if req.deduplicated_label.is_some() {
+            table.deduplicated_label = req.deduplicated_label;
+        }
+        Ok(())
+    }
+
+    /// Returns the table with the buffered mutations applied, if it is mutated
+    /// inside the transaction.
+    pub fn get_table_from_buffer(&self, table_id: u64) -> Option<TableInfo> {
+        self.tables
+            .get(&table_id)
+            .map(|table| table.pending.clone())
+    }
+
+    pub fn tables(&self) -> Vec<TxnTable> {
+        self.tables.values().cloned().collect()
+    }
+
+    /// Replaces the buffered table with one rebased on a newer version of the table.
+    pub fn rebase_table(&mut self, base: TableInfo, pending: TableInfo) {
+        if let Some(table) = self.tables.get_mut(&base.ident.table_id) {
+            table.base = base;
+            table.pending = pending;
+        }
+    }
+
+    pub fn req(&self) -> UpdateMultiTableMetaReq {
+        let update_table_metas = self
+            .tables
+            .iter()
+            .map(|(table_id, table)| UpdateTableMetaReq {
+                table_id: *table_id,
+                seq: MatchSeq::Exact(table.base.ident.seq),
+                new_table_meta: table.pending.meta.clone(),
+                copied_files: table.copied_files.clone(),
+                deduplicated_label: table.deduplicated_label.clone(),
+            })
+            .collect();
+        UpdateMultiTableMetaReq { update_table_metas }
+    }
+
+    /// Ends the transaction, the buffered mutations are discarded.
+    pub fn clear(&mut self) {
+        self.state = TxnState::AutoCommit;
+        self.tables.clear();
+    }
+}
@@ -44,6 +44,7 @@ use common_storage::StorageConfig as InnerStorageConfig;
 use common_tracing::Config as InnerLogConfig;
 use common_tracing::FileConfig as InnerFileLogConfig;
 use common_tracing::StderrConfig as InnerStderrLogConfig;
+use common_tracing::TracingConfig as InnerTracingLogConfig;
 use common_users::idm_config::IDMConfig as InnerIDMConfig;
 use serde::Deserialize;
 use serde::Serialize;
@@ -59,6 +60,7 @@ use super::inner::InnerConfig;
 use super::inner::LocalConfig as InnerLocalConfig;
 use super::inner::MetaConfig as InnerMetaConfig;
 use super::inner::QueryConfig as InnerQueryConfig;
+use super::inner::WorkloadGroup as InnerWorkloadGroup;
 use crate::background_config::BackgroundConfig;
 use crate::DATABEND_COMMIT_VERSION;
 
@@ -1265,6 +1267,16 @@ pub struct QueryConfig {
     #[clap(long, default_value = "120")]
     pub mysql_handler_tcp_keepalive_timeout_secs: u64,
 
+    #[clap(long, default_value = "127.0.0.1")]
+    pub postgres_handler_host: String,
+
+    /// The port of the PostgreSQL compatibility protocol, 0 disables it.
+    #[clap(long, default_value = "0")]
+    pub postgres_handler_port: u16,
+
+    #[clap(long, default_value = "120")]
+    pub postgres_handler_tcp_keepalive_timeout_secs: u64,
+
     #[clap(long, default_value = "256")]
     pub max_active_sessions: u64,
 
@@ -1361,6 +1373,46 @@ pub struct QueryConfig {
 
     #[clap(long, default_value = "10000")]
     pub max_query_log_size: usize,
+
+    /// Interval of the background compaction of the fuse tables with the `auto_compact` option,
+    /// 0 means the background compaction is disabled. A round compacts at most one table of
+    /// the cluster, on the node elected to compact.
+    #[clap(long, default_value = "0")]
+    pub auto_compaction_interval_secs: u64,
+
+    /// Max number of segments compacted for a table in one round of the background compaction.
+    #[clap(long, default_value = "100")]
+    pub auto_compaction_segment_limit: u64,
+
+    /// Interval of the background refresh of the materialized views,
+    /// 0 means the background refresh is disabled.
+    #[clap(long, default_value = "0")]
+    pub materialized_view_refresh_interval_secs: u64,
+
+    /// Interval of flushing the finished queries to `system_history.query_history`,
+    /// 0 means the query history is not persisted.
+    #[clap(long, default_value = "0")]
+    pub query_history_flush_interval_secs: u64,
+
+    /// Days the persisted query history is kept, 0 means it's kept forever.
+    #[clap(long, default_value = "7")]
+    pub query_history_retention_days: u64,
+
+    /// Interval of the background replication of the fuse tables with the `replication_stage`
+    /// option, 0 means the replication is disabled.
+    #[clap(long, default_value = "0")]
+    pub replication_interval_secs: u64,
+
+    /// Serve the queries as a read-only replica: the statements writing to meta are rejected,
+    /// and the tables are read from the snapshots pinned by the node.
+    #[clap(long)]
+    pub read_only_replica: bool,
+
+    /// Interval of refreshing the snapshots pinned by a read-only replica,
+    /// 0 means the snapshots are pinned until the node restarts.
+    #[clap(long, default_value = "60")]
+    pub replica_refresh_interval_secs: u64,
+
     /// Parquet file with smaller size will be read as a whole file, instead of column by column.
     /// For example:
     /// parquet_fast_read_bytes = 52428800
@@ -1386,6 +1438,26 @@ pub struct QueryConfig {
     #[clap(skip)]
     pub jwt_key_files: Vec<String>,
 
+    /// The OIDC issuer of the jwt, the `iss` claim of a token must be it.
+    /// The JWKS is discovered from `<jwt_issuer>/.well-known/openid-configuration` if no jwt_key_file is configured.
+    /// The `tenant_id`, `role` and `ensure_user.roles` claims of its tokens are ignored, the roles only come from jwt_role_mapping.
+    #[clap(long, default_value_t)]
+    pub jwt_issuer: String,
+
+    /// The `aud` claim of a token must contain it if it's not empty, it's required with jwt_issuer.
+    #[clap(long, default_value_t)]
+    pub jwt_audience: String,
+
+    /// The claim holds the roles of the jwt user, like `groups` or `realm_access.roles`.
+    /// The mapped roles are granted to the user in the session of the token only.
+    #[clap(long, default_value_t)]
+    pub jwt_role_claim: String,
+
+    /// Map the values of jwt_role_claim to roles, each entry is like `<claim value>=<role>`.
+    /// It's required with jwt_role_claim, the claim values not mapped are ignored.
+    #[clap(skip)]
+    pub jwt_role_mapping: Vec<String>,
+
     #[clap(long, default_value = "auto")]
     pub default_storage_format: String,
 
@@ -1404,6 +1476,11 @@ pub struct QueryConfig {
     #[clap(skip)]
     quota: Option<TenantQuota>,
 
+    /// Queries of a user run in the workload group named by the `workload_group` setting
+    /// of the user or its role, they are queued when the group is saturated.
+    #[clap(skip)]
+    pub workload_groups: Vec<WorkloadGroupConfig>,
+
     #[clap(long)]
     pub internal_enable_sandbox_tenant: bool,
 
@@ -1509,6 +1586,10 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             mysql_handler_host: self.mysql_handler_host,
             mysql_handler_port: self.mysql_handler_port,
             mysql_handler_tcp_keepalive_timeout_secs: self.mysql_handler_tcp_keepalive_timeout_secs,
+            postgres_handler_host: self.postgres_handler_host,
+            postgres_handler_port: self.postgres_handler_port,
+            postgres_handler_tcp_keepalive_timeout_secs: self
+                .postgres_handler_tcp_keepalive_timeout_secs,
             max_active_sessions: self.max_active_sessions,
             max_server_memory_usage: self.max_server_memory_usage,
             max_memory_limit_enabled: self.max_memory_limit_enabled,
@@ -1537,12 +1618,24 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             table_engine_memory_enabled: self.table_engine_memory_enabled,
             wait_timeout_mills: self.wait_timeout_mills,
             max_query_log_size: self.max_query_log_size,
+            auto_compaction_interval_secs: self.auto_compaction_interval_secs,
+            auto_compaction_segment_limit: self.auto_compaction_segment_limit,
+            materialized_view_refresh_interval_secs: self.materialized_view_refresh_interval_secs,
+            query_history_flush_interval_secs: self.query_history_flush_interval_secs,
+            query_history_retention_days: self.query_history_retention_days,
+            replication_interval_secs: self.replication_interval_secs,
+            read_only_replica: self.read_only_replica,
+            replica_refresh_interval_secs: self.replica_refresh_interval_secs,
             databend_enterprise_license: self.databend_enterprise_license,
             management_mode: self.management_mode,
             parquet_fast_read_bytes: self.parquet_fast_read_bytes,
             max_storage_io_requests: self.max_storage_io_requests,
             jwt_key_file: self.jwt_key_file,
             jwt_key_files: self.jwt_key_files,
+            jwt_issuer: self.jwt_issuer,
+            jwt_audience: self.jwt_audience,
+            jwt_role_claim: self.jwt_role_claim,
+            jwt_role_mapping: self.jwt_role_mapping,
             default_storage_format: self.default_storage_format,
             default_compression: self.default_compression,
             idm: InnerIDMConfig {
@@ -1551,6 +1644,7 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             share_endpoint_address: self.share_endpoint_address,
             share_endpoint_auth_token_file: self.share_endpoint_auth_token_file,
             tenant_quota: self.quota,
+            workload_groups: workload_groups_to_inner(self.workload_groups)?,
             internal_enable_sandbox_tenant: self.internal_enable_sandbox_tenant,
             internal_merge_on_read_mutation: self.internal_merge_on_read_mutation,
             disable_system_table_load: self.disable_system_table_load,
@@ -1575,6 +1669,10 @@ impl From<InnerQueryConfig> for QueryConfig {
             mysql_handler_port: inner.mysql_handler_port,
             mysql_handler_tcp_keepalive_timeout_secs: inner
                 .mysql_handler_tcp_keepalive_timeout_secs,
+            postgres_handler_host: inner.postgres_handler_host,
+            postgres_handler_port: inner.postgres_handler_port,
+            postgres_handler_tcp_keepalive_timeout_secs: inner
+                .postgres_handler_tcp_keepalive_timeout_secs,
             max_active_sessions: inner.max_active_sessions,
             max_server_memory_usage: inner.max_server_memory_usage,
             max_memory_limit_enabled: inner.max_memory_limit_enabled,
@@ -1608,18 +1706,35 @@ impl From<InnerQueryConfig> for QueryConfig {
             table_engine_memory_enabled: inner.table_engine_memory_enabled,
             wait_timeout_mills: inner.wait_timeout_mills,
             max_query_log_size: inner.max_query_log_size,
+            auto_compaction_interval_secs: inner.auto_compaction_interval_secs,
+            auto_compaction_segment_limit: inner.auto_compaction_segment_limit,
+            materialized_view_refresh_interval_secs: inner.materialized_view_refresh_interval_secs,
+            query_history_flush_interval_secs: inner.query_history_flush_interval_secs,
+            query_history_retention_days: inner.query_history_retention_days,
+            replication_interval_secs: inner.replication_interval_secs,
+            read_only_replica: inner.read_only_replica,
+            replica_refresh_interval_secs: inner.replica_refresh_interval_secs,
             databend_enterprise_license: inner.databend_enterprise_license,
             management_mode: inner.management_mode,
             parquet_fast_read_bytes: inner.parquet_fast_read_bytes,
             max_storage_io_requests: inner.max_storage_io_requests,
             jwt_key_file: inner.jwt_key_file,
             jwt_key_files: inner.jwt_key_files,
+            jwt_issuer: inner.jwt_issuer,
+            jwt_audience: inner.jwt_audience,
+            jwt_role_claim: inner.jwt_role_claim,
+            jwt_role_mapping: inner.jwt_role_mapping,
             default_storage_format: inner.default_storage_format,
             default_compression: inner.default_compression,
             users: users_from_inner(inner.idm.users),
             share_endpoint_address: inner.share_endpoint_address,
             share_endpoint_auth_token_file: inner.share_endpoint_auth_token_file,
             quota: inner.tenant_quota,
+            workload_groups: inner
+                .workload_groups
+                .into_iter()
+                .map(WorkloadGroupConfig::from)
+                .collect(),
             internal_enable_sandbox_tenant: inner.internal_enable_sandbox_tenant,
             internal_merge_on_read_mutation: false,
             // obsoleted config entries
@@ -1677,6 +1792,9 @@ pub struct LogConfig {
 
     #[clap(flatten)]
     pub stderr: StderrLogConfig,
+
+    #[clap(flatten)]
+    pub tracing: TracingLogConfig,
 }
 
 impl Default for LogConfig {
@@ -1716,6 +1834,7 @@ impl TryInto<InnerLogConfig> for LogConfig {
         Ok(InnerLogConfig {
             file,
             stderr: self.stderr.try_into()?,
+            tracing: self.tracing.try_into()?,
         })
     }
 }
@@ -1728,6 +1847,7 @@ impl From<InnerLogConfig> for LogConfig {
             query_enabled: false,
             file: inner.file.into(),
             stderr: inner.stderr.into(),
+            tracing: inner.tracing.into(),
 
             // Deprecated fields
             log_dir: None,
@@ -1835,6 +1955,55 @@ impl From<InnerStderrLogConfig> for StderrLogConfig {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
+#[serde(default)]
+pub struct TracingLogConfig {
+    /// Export the spans to an OpenTelemetry collector (e.g. Jaeger, Tempo)
+    #[clap(long = "log-tracing-on")]
+    #[serde(rename = "on")]
+    pub tracing_on: bool,
+
+    #[clap(long = "log-tracing-level", default_value = "INFO")]
+    #[serde(rename = "level")]
+    pub tracing_level: String,
+
+    /// gRPC endpoint of the collector to export the spans with OTLP
+    #[clap(
+        long = "log-tracing-otlp-endpoint",
+        default_value = "http://127.0.0.1:4317"
+    )]
+    #[serde(rename = "otlp_endpoint")]
+    pub tracing_otlp_endpoint: String,
+}
+
+impl Default for TracingLogConfig {
+    fn default() -> Self {
+        InnerTracingLogConfig::default().into()
+    }
+}
+
+impl TryInto<InnerTracingLogConfig> for TracingLogConfig {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<InnerTracingLogConfig> {
+        Ok(InnerTracingLogConfig {
+            on: self.tracing_on,
+            level: self.tracing_level,
+            otlp_endpoint: self.tracing_otlp_endpoint,
+        })
+    }
+}
+
+impl From<InnerTracingLogConfig> for TracingLogConfig {
+    fn from(inner: InnerTracingLogConfig) -> Self {
+        Self {
+            tracing_on: inner.on,
+            tracing_level: inner.level,
+            tracing_otlp_endpoint: inner.otlp_endpoint,
+        }
+    }
+}
+
 /// Meta config group.
 /// deny_unknown_fields to check unknown field, like the deprecated `address`.
 /// TODO(xuanwo): All meta_xxx should be rename to xxx.
@@ -2007,6 +2176,61 @@ fn users_to_inner(outer: Vec<UserConfig>) -> Result<HashMap<String, AuthInfo>> {
     Ok(inner)
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkloadGroupConfig {
+    pub name: String,
+    /// Max number of queries running in the group at the same time.
+    pub max_concurrency: u64,
+    /// Queries are queued while the memory used by the running queries
+    /// of the group exceeds it, 0 means unlimited.
+    #[serde(default)]
+    pub memory_quota: u64,
+    /// Max seconds a query waits in the queue, 0 means wait forever.
+    #[serde(default)]
+    pub queue_timeout_secs: u64,
+}
+
+impl From<InnerWorkloadGroup> for WorkloadGroupConfig {
+    fn from(inner: InnerWorkloadGroup) -> Self {
+        Self {
+            name: inner.name,
+            max_concurrency: inner.max_concurrency,
+            memory_quota: inner.memory_quota,
+            queue_timeout_secs: inner.queue_timeout_secs,
+        }
+    }
+}
+
+fn workload_groups_to_inner(outer: Vec<WorkloadGroupConfig>) -> Result<Vec<InnerWorkloadGroup>> {
+    let mut inner: Vec<InnerWorkloadGroup> = Vec::with_capacity(outer.len());
+    for c in outer.into_iter() {
+        if c.name.is_empty() {
+            return Err(ErrorCode::InvalidConfig(
+                "name of workload group must not be empty",
+            ));
+        }
+        if inner.iter().any(|g| g.name == c.name) {
+            return Err(ErrorCode::InvalidConfig(format!(
+                "duplicated workload group {}",
+                c.name
+            )));
+        }
+        if c.max_concurrency == 0 {
+            return Err(ErrorCode::InvalidConfig(format!(
+                "max_concurrency of workload group {} must be greater than 0",
+                c.name
+            )));
+        }
+        inner.push(InnerWorkloadGroup {
+            name: c.name,
+            max_concurrency: c.max_concurrency,
+            memory_quota: c.memory_quota,
+            queue_timeout_secs: c.queue_timeout_secs,
+        });
+    }
+    Ok(inner)
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct UserConfig {
     pub name: String,
@@ -2131,6 +2355,10 @@ pub struct CacheConfig {
     #[clap(long = "cache-table-meta-statistic-count", default_value = "256")]
     pub table_meta_statistic_count: u64,
 
+    /// Seconds that the cached table snapshots and segments live, 0 means never expire
+    #[clap(long = "cache-table-meta-ttl-secs", default_value = "0")]
+    pub table_meta_ttl_secs: u64,
+
     /// Enable bloom index cache. Default is enabled. Set it to false to disable all the bloom index caches
     #[clap(long = "cache-enable-table-bloom-index-cache", default_value = "true")]
     #[serde(default = "bool_true")]
@@ -2301,6 +2529,7 @@ mod cache_config_converters {
                 table_meta_snapshot_count: value.table_meta_snapshot_count,
                 table_meta_segment_bytes: value.table_meta_segment_bytes,
                 table_meta_statistic_count: value.table_meta_statistic_count,
+                table_meta_ttl_secs: value.table_meta_ttl_secs,
                 enable_table_index_bloom: value.enable_table_bloom_index_cache,
                 table_bloom_index_meta_count: value.table_bloom_index_meta_count,
                 table_bloom_index_filter_count: value.table_bloom_index_filter_count,
@@ -2321,6 +2550,7 @@ mod cache_config_converters {
                 table_meta_snapshot_count: value.table_meta_snapshot_count,
                 table_meta_segment_bytes: value.table_meta_segment_bytes,
                 table_meta_statistic_count: value.table_meta_statistic_count,
+                table_meta_ttl_secs: value.table_meta_ttl_secs,
                 enable_table_bloom_index_cache: value.enable_table_index_bloom,
                 table_bloom_index_meta_count: value.table_bloom_index_meta_count,
                 table_bloom_index_filter_count: value.table_bloom_index_filter_count,
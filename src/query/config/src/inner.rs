@@ -132,6 +132,10 @@ pub struct QueryConfig {
     pub mysql_handler_host: String,
     pub mysql_handler_port: u16,
     pub mysql_handler_tcp_keepalive_timeout_secs: u64,
+    pub postgres_handler_host: String,
+    /// 0 disables the PostgreSQL compatibility protocol.
+    pub postgres_handler_port: u16,
+    pub postgres_handler_tcp_keepalive_timeout_secs: u64,
     pub max_active_sessions: u64,
     pub max_server_memory_usage: u64,
     pub max_memory_limit_enabled: bool,
@@ -164,6 +168,21 @@ pub struct QueryConfig {
     pub table_engine_memory_enabled: bool,
     pub wait_timeout_mills: u64,
     pub max_query_log_size: usize,
+    /// Interval of the background compaction, 0 means disabled.
+    pub auto_compaction_interval_secs: u64,
+    pub auto_compaction_segment_limit: u64,
+    /// Interval of the background refresh of materialized views, 0 means disabled.
+    pub materialized_view_refresh_interval_secs: u64,
+    /// Interval of flushing the query history, 0 means the query history is not persisted.
+    pub query_history_flush_interval_secs: u64,
+    /// Days the persisted query history is kept, 0 means forever.
+    pub query_history_retention_days: u64,
+    /// Interval of the background replication of tables, 0 means disabled.
+    pub replication_interval_secs: u64,
+    /// If in read-only replica mode, only the statements without meta writes are allowed.
+    pub read_only_replica: bool,
+    /// Interval of refreshing the pinned snapshots of a replica, 0 means never.
+    pub replica_refresh_interval_secs: u64,
     pub databend_enterprise_license: Option<String>,
     /// If in management mode, only can do some meta level operations(database/table/user/stage etc.) with metasrv.
     pub management_mode: bool,
@@ -173,12 +192,17 @@ pub struct QueryConfig {
 
     pub jwt_key_file: String,
     pub jwt_key_files: Vec<String>,
+    pub jwt_issuer: String,
+    pub jwt_audience: String,
+    pub jwt_role_claim: String,
+    pub jwt_role_mapping: Vec<String>,
     pub default_storage_format: String,
     pub default_compression: String,
     pub idm: IDMConfig,
     pub share_endpoint_address: String,
     pub share_endpoint_auth_token_file: String,
     pub tenant_quota: Option<TenantQuota>,
+    pub workload_groups: Vec<WorkloadGroup>,
     pub internal_enable_sandbox_tenant: bool,
     pub internal_merge_on_read_mutation: bool,
     /// Disable some system load(For example system.configs) for cloud security.
@@ -202,6 +226,9 @@ impl Default for QueryConfig {
             mysql_handler_host: "127.0.0.1".to_string(),
             mysql_handler_port: 3307,
             mysql_handler_tcp_keepalive_timeout_secs: 120,
+            postgres_handler_host: "127.0.0.1".to_string(),
+            postgres_handler_port: 0,
+            postgres_handler_tcp_keepalive_timeout_secs: 120,
             max_active_sessions: 256,
             max_server_memory_usage: 0,
             max_memory_limit_enabled: false,
@@ -229,18 +256,31 @@ impl Default for QueryConfig {
             table_engine_memory_enabled: true,
             wait_timeout_mills: 5000,
             max_query_log_size: 10_000,
+            auto_compaction_interval_secs: 0,
+            auto_compaction_segment_limit: 100,
+            materialized_view_refresh_interval_secs: 0,
+            query_history_flush_interval_secs: 0,
+            query_history_retention_days: 7,
+            replication_interval_secs: 0,
+            read_only_replica: false,
+            replica_refresh_interval_secs: 60,
             databend_enterprise_license: None,
             management_mode: false,
             parquet_fast_read_bytes: None,
             max_storage_io_requests: None,
             jwt_key_file: "".to_string(),
             jwt_key_files: Vec::new(),
+            jwt_issuer: "".to_string(),
+            jwt_audience: "".to_string(),
+            jwt_role_claim: "".to_string(),
+            jwt_role_mapping: Vec::new(),
             default_storage_format: "auto".to_string(),
             default_compression: "auto".to_string(),
             idm: IDMConfig::default(),
             share_endpoint_address: "".to_string(),
             share_endpoint_auth_token_file: "".to_string(),
             tenant_quota: None,
+            workload_groups: Vec::new(),
             internal_enable_sandbox_tenant: false,
             internal_merge_on_read_mutation: false,
             disable_system_table_load: false,
@@ -264,6 +304,17 @@ impl QueryConfig {
     }
 }
 
+/// A group of queries sharing the concurrency and memory quota.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WorkloadGroup {
+    pub name: String,
+    pub max_concurrency: u64,
+    /// 0 means unlimited.
+    pub memory_quota: u64,
+    /// 0 means wait forever.
+    pub queue_timeout_secs: u64,
+}
+
 #[derive(Clone, PartialEq, Eq)]
 pub struct MetaConfig {
     /// The dir to store persisted meta state for a embedded meta store
@@ -459,6 +510,9 @@ pub struct CacheConfig {
     /// Max number of cached table segment
     pub table_meta_statistic_count: u64,
 
+    /// Seconds that the cached table snapshots and segments live, 0 means never expire
+    pub table_meta_ttl_secs: u64,
+
     /// Enable bloom index cache. Default is enabled. Set it to false to disable all the bloom index caches
     pub enable_table_index_bloom: bool,
 
@@ -549,6 +603,7 @@ impl Default for CacheConfig {
             table_meta_snapshot_count: 256,
             table_meta_segment_bytes: 1073741824,
             table_meta_statistic_count: 256,
+            table_meta_ttl_secs: 0,
             enable_table_index_bloom: true,
             table_bloom_index_meta_count: 3000,
             table_bloom_index_filter_count: 1048576,
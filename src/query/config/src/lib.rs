@@ -46,5 +46,6 @@ pub use inner::CatalogConfig;
 pub use inner::CatalogHiveConfig;
 pub use inner::InnerConfig;
 pub use inner::ThriftProtocol;
+pub use inner::WorkloadGroup;
 pub use version::DATABEND_COMMIT_VERSION;
 pub use version::QUERY_SEMVER;
@@ -16,11 +16,14 @@ use std::sync::Arc;
 
 use common_base::base::GlobalInstance;
 use common_catalog::catalog::Catalog;
+use common_catalog::plan::AggIndexAggregation;
+use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_meta_app::schema::CreateIndexReply;
 use common_meta_app::schema::CreateIndexReq;
 use common_meta_app::schema::DropIndexReply;
 use common_meta_app::schema::DropIndexReq;
+use common_storages_fuse::FuseTable;
 
 #[async_trait::async_trait]
 pub trait AggregatingIndexHandler: Sync + Send {
@@ -35,6 +38,14 @@ pub trait AggregatingIndexHandler: Sync + Send {
         catalog: Arc<dyn Catalog>,
         req: DropIndexReq,
     ) -> Result<DropIndexReply>;
+
+    async fn do_refresh_index(
+        &self,
+        fuse_table: &FuseTable,
+        ctx: Arc<dyn TableContext>,
+        index_id: u64,
+        aggregation: AggIndexAggregation,
+    ) -> Result<()>;
 }
 
 pub struct AggregatingIndexHandlerWrapper {
@@ -63,6 +74,19 @@ impl AggregatingIndexHandlerWrapper {
     ) -> Result<DropIndexReply> {
         self.handler.do_drop_index(catalog, req).await
     }
+
+    #[async_backtrace::framed]
+    pub async fn do_refresh_index(
+        &self,
+        fuse_table: &FuseTable,
+        ctx: Arc<dyn TableContext>,
+        index_id: u64,
+        aggregation: AggIndexAggregation,
+    ) -> Result<()> {
+        self.handler
+            .do_refresh_index(fuse_table, ctx, index_id, aggregation)
+            .await
+    }
 }
 
 pub fn get_agg_index_handler() -> Arc<AggregatingIndexHandlerWrapper> {
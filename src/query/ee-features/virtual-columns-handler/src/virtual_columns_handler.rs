@@ -60,6 +60,13 @@ pub trait VirtualColumnsHandler: Sync + Send {
         ctx: Arc<dyn TableContext>,
         virtual_columns: Vec<String>,
     ) -> Result<()>;
+
+    async fn do_refresh_virtual_columns(
+        &self,
+        fuse_table: &FuseTable,
+        ctx: Arc<dyn TableContext>,
+        virtual_columns: Vec<String>,
+    ) -> Result<()>;
 }
 
 pub struct VirtualColumnsHandlerWrapper {
@@ -118,6 +125,18 @@ impl VirtualColumnsHandlerWrapper {
             .do_generate_virtual_columns(fuse_table, ctx, virtual_columns)
             .await
     }
+
+    #[async_backtrace::framed]
+    pub async fn do_refresh_virtual_columns(
+        &self,
+        fuse_table: &FuseTable,
+        ctx: Arc<dyn TableContext>,
+        virtual_columns: Vec<String>,
+    ) -> Result<()> {
+        self.handler
+            .do_refresh_virtual_columns(fuse_table, ctx, virtual_columns)
+            .await
+    }
 }
 
 pub fn get_virtual_columns_handler() -> Arc<VirtualColumnsHandlerWrapper> {
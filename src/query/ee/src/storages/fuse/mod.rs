@@ -16,5 +16,7 @@ pub mod io;
 pub mod operations;
 
 pub use io::snapshots::get_snapshot_referenced_segments;
+pub use operations::agg_index::do_refresh_agg_index;
 pub use operations::vacuum::do_vacuum;
 pub use operations::virtual_columns::do_generate_virtual_columns;
+pub use operations::virtual_columns::do_refresh_virtual_columns;
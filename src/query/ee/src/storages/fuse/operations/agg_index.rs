@@ -0,0 +1,91 @@
+// Copyright 2023 Databend Cloud
+//
+// Licensed under the Elastic License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.elastic.co/licensing/elastic-license
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::AggIndexAggregation;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_storages_fuse::io::MetaReaders;
+use common_storages_fuse::io::ReadSettings;
+use common_storages_fuse::io::TableMetaLocationGenerator;
+use common_storages_fuse::operations::write_agg_index_data;
+use common_storages_fuse::operations::AggIndexAggregator;
+use common_storages_fuse::FuseTable;
+use storages_common_cache::LoadParams;
+
+/// Write the partial aggregate states of aggregating index `index_id` for the blocks
+/// of the current snapshot which are not indexed yet.
+#[async_backtrace::framed]
+pub async fn do_refresh_agg_index(
+    fuse_table: &FuseTable,
+    ctx: Arc<dyn TableContext>,
+    index_id: u64,
+    aggregation: AggIndexAggregation,
+) -> Result<()> {
+    let snapshot = match fuse_table.read_table_snapshot().await? {
+        Some(snapshot) => snapshot,
+        None => return Ok(()),
+    };
+
+    let table_schema = &fuse_table.get_table_info().meta.schema;
+    let projection = AggIndexAggregator::projection(table_schema, &aggregation)?;
+    let block_reader = fuse_table.create_block_reader(projection, false, ctx.clone())?;
+    let aggregator = AggIndexAggregator::try_create(
+        ctx.get_function_context()?,
+        &block_reader.data_schema(),
+        &aggregation,
+    )?;
+    let index_schema = aggregator.output_schema()?;
+
+    let segment_reader =
+        MetaReaders::segment_info_reader(fuse_table.get_operator(), table_schema.clone());
+    let settings = ReadSettings::from_ctx(&ctx)?;
+    let storage_format = fuse_table.get_write_settings().storage_format;
+    let operator = fuse_table.get_operator_ref();
+
+    for (location, ver) in &snapshot.segments {
+        let segment_info = segment_reader
+            .read(&LoadParams {
+                location: location.to_string(),
+                len_hint: None,
+                ver: *ver,
+                put_cache: false,
+            })
+            .await?;
+
+        for block_meta in segment_info.block_metas()? {
+            // The index data can not tell the deleted rows.
+            if block_meta.deletion_vector_location.is_some() {
+                continue;
+            }
+            let index_loc = TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
+                &block_meta.location.0,
+                index_id,
+            );
+            if operator.is_exist(&index_loc).await? {
+                continue;
+            }
+
+            let block = block_reader
+                .read_by_meta(&settings, &block_meta, &storage_format)
+                .await?;
+            let index_block = aggregator.aggregate(block)?;
+            write_agg_index_data(operator, &index_loc, &index_schema, index_block).await?;
+        }
+    }
+
+    Ok(())
+}
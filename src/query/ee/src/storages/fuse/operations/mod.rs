@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod agg_index;
 pub mod handler;
 pub mod vacuum;
 pub mod virtual_columns;
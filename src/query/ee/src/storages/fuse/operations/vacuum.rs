@@ -28,6 +28,9 @@ use common_storages_fuse::io::SnapshotLiteExtended;
 use common_storages_fuse::io::SnapshotsIO;
 use common_storages_fuse::io::TableMetaLocationGenerator;
 use common_storages_fuse::FuseTable;
+use common_storages_fuse::FUSE_TBL_BLOCK_PREFIX;
+use common_storages_fuse::FUSE_TBL_SEGMENT_PREFIX;
+use common_storages_fuse::FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
 use storages_common_cache::LoadParams;
 use storages_common_table_meta::meta::CompactSegmentInfo;
 use tracing::info;
@@ -127,11 +130,36 @@ pub async fn get_snapshot_referenced_files(
     segments_vec.into_iter().for_each(|(location, _)| {
         segments.insert(location);
     });
-    Ok(Some(SnapshotReferencedFiles {
+    let mut referenced_files = SnapshotReferencedFiles {
         segments,
         blocks: locations_referenced.block_location,
         blocks_index: locations_referenced.bloom_location,
-    }))
+    };
+
+    // 3. The files of the source table referenced by a clone are not the orphans of the
+    // clone, the orphans are listed under the prefix of the first referenced file.
+    let owned = |location: &String| fuse_table.owns_location(location);
+    referenced_files.segments.retain(owned);
+    referenced_files.blocks.retain(owned);
+    referenced_files.blocks_index.retain(owned);
+
+    // 4. The files shared with the clones of the table are not orphans, each of them is
+    // added to the set of its kind, the sets are listed by the directory of their files.
+    let referenced_by_clones = fuse_table.get_clone_referenced_locations(ctx).await?;
+    let prefix = fuse_table.meta_location_generator().prefix();
+    let segment_dir = format!("{prefix}/{FUSE_TBL_SEGMENT_PREFIX}/");
+    let block_dir = format!("{prefix}/{FUSE_TBL_BLOCK_PREFIX}/");
+    let bloom_dir = format!("{prefix}/{FUSE_TBL_XOR_BLOOM_INDEX_PREFIX}/");
+    for location in referenced_by_clones {
+        if location.starts_with(&segment_dir) {
+            referenced_files.segments.insert(location);
+        } else if location.starts_with(&block_dir) {
+            referenced_files.blocks.insert(location);
+        } else if location.starts_with(&bloom_dir) {
+            referenced_files.blocks_index.insert(location);
+        }
+    }
+    Ok(Some(referenced_files))
 }
 
 // return orphan files to be purged
@@ -50,6 +50,27 @@ pub async fn do_generate_virtual_columns(
     fuse_table: &FuseTable,
     ctx: Arc<dyn TableContext>,
     virtual_columns: Vec<String>,
+) -> Result<()> {
+    generate_virtual_columns(fuse_table, ctx, virtual_columns, true).await
+}
+
+/// Materialize the virtual columns of the blocks that have no virtual column file yet,
+/// e.g. the blocks just written by an insertion.
+#[async_backtrace::framed]
+pub async fn do_refresh_virtual_columns(
+    fuse_table: &FuseTable,
+    ctx: Arc<dyn TableContext>,
+    virtual_columns: Vec<String>,
+) -> Result<()> {
+    generate_virtual_columns(fuse_table, ctx, virtual_columns, false).await
+}
+
+#[async_backtrace::framed]
+async fn generate_virtual_columns(
+    fuse_table: &FuseTable,
+    ctx: Arc<dyn TableContext>,
+    virtual_columns: Vec<String>,
+    overwrite: bool,
 ) -> Result<()> {
     if virtual_columns.is_empty() {
         return Ok(());
@@ -116,11 +137,14 @@ pub async fn do_generate_virtual_columns(
 
         let block_metas = segment_info.block_metas()?;
         for block_meta in block_metas {
+            let virtual_loc =
+                TableMetaLocationGenerator::gen_virtual_block_location(&block_meta.location.0);
+            if !overwrite && operator.is_exist(&virtual_loc).await? {
+                continue;
+            }
             let block = block_reader
                 .read_by_meta(&settings, &block_meta, &storage_format)
                 .await?;
-            let virtual_loc =
-                TableMetaLocationGenerator::gen_virtual_block_location(&block_meta.location.0);
 
             materialize_virtual_columns(
                 operator,
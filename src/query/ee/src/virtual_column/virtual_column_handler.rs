@@ -31,6 +31,7 @@ use virtual_columns_handler::VirtualColumnsHandler;
 use virtual_columns_handler::VirtualColumnsHandlerWrapper;
 
 use crate::storages::fuse::do_generate_virtual_columns;
+use crate::storages::fuse::do_refresh_virtual_columns;
 
 pub struct RealVirtualColumnsHandler {}
 
@@ -80,6 +81,15 @@ impl VirtualColumnsHandler for RealVirtualColumnsHandler {
     ) -> Result<()> {
         do_generate_virtual_columns(fuse_table, ctx, virtual_columns).await
     }
+
+    async fn do_refresh_virtual_columns(
+        &self,
+        fuse_table: &FuseTable,
+        ctx: Arc<dyn TableContext>,
+        virtual_columns: Vec<String>,
+    ) -> Result<()> {
+        do_refresh_virtual_columns(fuse_table, ctx, virtual_columns).await
+    }
 }
 
 impl RealVirtualColumnsHandler {
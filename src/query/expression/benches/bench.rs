@@ -0,0 +1,64 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate criterion;
+
+use common_expression::types::number::NumberScalar;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::ColumnBuilder;
+use common_expression::ScalarRef;
+use criterion::Criterion;
+
+fn push_rows(builder: &mut ColumnBuilder, n: usize) {
+    for i in 0..n {
+        builder.push(ScalarRef::Number(NumberScalar::Int64(i as i64)));
+    }
+}
+
+fn bench(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bench_column_builder");
+    let ty = DataType::Number(NumberDataType::Int64).wrap_nullable();
+
+    for n in [1000, 100000, 1000000] {
+        group.bench_function(format!("push/{n}"), |b| {
+            b.iter(|| {
+                let mut builder = ColumnBuilder::with_capacity(&ty, 0);
+                push_rows(&mut builder, n);
+                builder.build()
+            })
+        });
+
+        group.bench_function(format!("reserve_push/{n}"), |b| {
+            b.iter(|| {
+                let mut builder = ColumnBuilder::with_capacity(&ty, 0);
+                builder.reserve(n);
+                push_rows(&mut builder, n);
+                builder.build()
+            })
+        });
+
+        group.bench_function(format!("with_capacity_push/{n}"), |b| {
+            b.iter(|| {
+                let mut builder = ColumnBuilder::with_capacity(&ty, n);
+                push_rows(&mut builder, n);
+                builder.build()
+            })
+        });
+    }
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -29,9 +29,11 @@ use crate::types::NumberDataType;
 use crate::with_number_type;
 use crate::DataField;
 use crate::DataSchema;
+use crate::ARROW_EXT_TYPE_BINARY;
 use crate::ARROW_EXT_TYPE_BITMAP;
 use crate::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use crate::ARROW_EXT_TYPE_EMPTY_MAP;
+use crate::ARROW_EXT_TYPE_GEOMETRY;
 use crate::ARROW_EXT_TYPE_VARIANT;
 use crate::EXTENSION_KEY;
 
@@ -40,7 +42,9 @@ impl From<&DataType> for ArrowDataType {
         match ty {
             DataType::Null => ArrowDataType::Null,
             DataType::Boolean => ArrowDataType::Boolean,
-            DataType::String | DataType::Bitmap => ArrowDataType::LargeBinary,
+            DataType::String | DataType::Bitmap | DataType::Geometry | DataType::Binary => {
+                ArrowDataType::LargeBinary
+            }
             DataType::Number(ty) => with_number_type!(|TYPE| match ty {
                 NumberDataType::TYPE => ArrowDataType::TYPE,
             }),
@@ -144,6 +148,15 @@ impl From<&DataField> for ArrowField {
             DataType::Bitmap => {
                 metadata.insert(EXTENSION_KEY.to_string(), ARROW_EXT_TYPE_BITMAP.to_string());
             }
+            DataType::Geometry => {
+                metadata.insert(
+                    EXTENSION_KEY.to_string(),
+                    ARROW_EXT_TYPE_GEOMETRY.to_string(),
+                );
+            }
+            DataType::Binary => {
+                metadata.insert(EXTENSION_KEY.to_string(), ARROW_EXT_TYPE_BINARY.to_string());
+            }
             _ => Default::default(),
         };
         match ty {
@@ -201,6 +214,8 @@ impl TryFrom<&ArrowField> for DataType {
             Some(ARROW_EXT_TYPE_EMPTY_MAP) => Some(DataType::EmptyMap),
             Some(ARROW_EXT_TYPE_VARIANT) => Some(DataType::Variant),
             Some(ARROW_EXT_TYPE_BITMAP) => Some(DataType::Bitmap),
+            Some(ARROW_EXT_TYPE_GEOMETRY) => Some(DataType::Geometry),
+            Some(ARROW_EXT_TYPE_BINARY) => Some(DataType::Binary),
             _ => None,
         };
 
@@ -230,10 +245,10 @@ impl TryFrom<&ArrowField> for DataType {
             ArrowDataType::Float64 => DataType::Number(NumberDataType::Float64),
             ArrowDataType::Timestamp(_unit, _tz) => DataType::Timestamp,
             ArrowDataType::Date32 | ArrowDataType::Date64 => DataType::Date,
-            ArrowDataType::Utf8
-            | ArrowDataType::LargeUtf8
-            | ArrowDataType::Binary
-            | ArrowDataType::LargeBinary => DataType::String,
+            ArrowDataType::Binary => DataType::Binary,
+            ArrowDataType::Utf8 | ArrowDataType::LargeUtf8 | ArrowDataType::LargeBinary => {
+                DataType::String
+            }
             ArrowDataType::Decimal128(p, s) => {
                 DataType::Decimal(DecimalDataType::Decimal128(DecimalSize {
                     precision: *p,
@@ -43,6 +43,7 @@ use crate::utils::arrow::constant_bitmap;
 use crate::values::Column;
 use crate::values::ColumnBuilder;
 use crate::values::Scalar;
+use crate::values::ScalarRef;
 use crate::values::Value;
 use crate::BlockEntry;
 use crate::ColumnIndex;
@@ -161,6 +162,15 @@ impl<'a> Evaluator<'a> {
                 ctx.render_error(*span, id.params(), &args, &function.signature.name)?;
                 Ok(result)
             }
+            Expr::LambdaFunctionCall {
+                name,
+                args,
+                lambda_expr,
+                ..
+            } => {
+                let arg = self.partial_run(&args[0], validity)?;
+                self.run_lambda(name, arg, args[0].data_type(), lambda_expr)
+            }
         };
 
         #[cfg(debug_assertions)]
@@ -196,6 +206,184 @@ impl<'a> Evaluator<'a> {
         result
     }
 
+    /// Evaluate the higher-order function `func_name`, the lambda expression is evaluated
+    /// once over the flattened elements of all the arrays.
+    fn run_lambda(
+        &self,
+        func_name: &str,
+        arg: Value<AnyType>,
+        arg_type: &DataType,
+        lambda_expr: &Expr,
+    ) -> Result<Value<AnyType>> {
+        let inner_type = match arg_type.remove_nullable() {
+            DataType::Null => return Ok(Value::Scalar(Scalar::Null)),
+            DataType::EmptyArray if func_name == "array_reduce" => {
+                return Ok(Value::Scalar(Scalar::Null));
+            }
+            DataType::EmptyArray => return Ok(arg),
+            DataType::Array(inner_type) => *inner_type,
+            _ => unreachable!("{func_name} expects an array argument, but got {arg_type}"),
+        };
+
+        let (num_rows, is_scalar) = match &arg {
+            Value::Scalar(_) => (1, true),
+            Value::Column(col) => (col.len(), false),
+        };
+        let (array, validity) = match arg.convert_to_full_column(arg_type, num_rows) {
+            Column::Nullable(box NullableColumn { column, validity }) => (column, Some(validity)),
+            column => (column, None),
+        };
+        let array = array.into_array().unwrap();
+
+        // Only keep the elements referenced by the offsets.
+        let start = array.offsets[0];
+        let end = array.offsets[array.len()];
+        let values = array.values.slice(start as usize..end as usize);
+        let offsets = array
+            .offsets
+            .iter()
+            .map(|offset| offset - start)
+            .collect::<Vec<_>>();
+
+        let result = match func_name {
+            "array_transform" => {
+                let block = DataBlock::new(
+                    vec![BlockEntry::new(inner_type, Value::Column(values))],
+                    (end - start) as usize,
+                );
+                let evaluator = Evaluator::new(&block, self.func_ctx, self.fn_registry);
+                let result = evaluator.run(lambda_expr)?;
+                let values =
+                    result.convert_to_full_column(lambda_expr.data_type(), block.num_rows());
+                Column::Array(Box::new(ArrayColumn {
+                    values,
+                    offsets: offsets.into(),
+                }))
+            }
+            "array_filter" => {
+                let num_values = (end - start) as usize;
+                let block = DataBlock::new(
+                    vec![BlockEntry::new(inner_type, Value::Column(values.clone()))],
+                    num_values,
+                );
+                let evaluator = Evaluator::new(&block, self.func_ctx, self.fn_registry);
+                let result = evaluator.run(lambda_expr)?;
+                let filter: Bitmap =
+                    match result.convert_to_full_column(lambda_expr.data_type(), num_values) {
+                        Column::Boolean(bitmap) => bitmap,
+                        Column::Nullable(box NullableColumn {
+                            column: Column::Boolean(bitmap),
+                            validity,
+                        }) => bitmap::and(&bitmap, &validity),
+                        Column::Null { .. } => constant_bitmap(false, num_values).into(),
+                        _ => unreachable!(),
+                    };
+
+                let mut filtered_offsets = Vec::with_capacity(offsets.len());
+                filtered_offsets.push(0);
+                let mut count = 0;
+                for window in offsets.windows(2) {
+                    count += (window[0]..window[1])
+                        .filter(|index| filter.get_bit(*index as usize))
+                        .count() as u64;
+                    filtered_offsets.push(count);
+                }
+                Column::Array(Box::new(ArrayColumn {
+                    values: values.filter(&filter),
+                    offsets: filtered_offsets.into(),
+                }))
+            }
+            "array_reduce" => {
+                self.run_array_reduce(inner_type, values, &offsets, validity.as_ref(), lambda_expr)?
+            }
+            _ => unreachable!("unknown lambda function {func_name}"),
+        };
+
+        let result = if func_name == "array_reduce" {
+            result
+        } else {
+            match validity {
+                Some(validity) => Column::Nullable(Box::new(NullableColumn {
+                    column: result,
+                    validity,
+                })),
+                None => result,
+            }
+        };
+
+        if is_scalar {
+            Ok(Value::Scalar(result.index(0).unwrap().to_owned()))
+        } else {
+            Ok(Value::Column(result))
+        }
+    }
+
+    /// Reduce the elements of each array from left to right with `lambda_expr`, whose
+    /// parameters are the accumulator and the next element. At each step, the lambda
+    /// expression is evaluated over all the arrays that still have elements to reduce.
+    fn run_array_reduce(
+        &self,
+        inner_type: DataType,
+        values: Column,
+        offsets: &[u64],
+        validity: Option<&Bitmap>,
+        lambda_expr: &Expr,
+    ) -> Result<Column> {
+        let num_rows = offsets.len() - 1;
+        let array_len = |row: usize| (offsets[row + 1] - offsets[row]) as usize;
+        let return_type = inner_type.wrap_nullable();
+
+        // The rows to reduce, sorted by the length of the array in descending order, so that
+        // the rows that still have elements to reduce are always a prefix of them.
+        let mut rows = (0..num_rows)
+            .filter(|row| array_len(*row) > 0 && validity.map_or(true, |v| v.get_bit(*row)))
+            .collect::<Vec<_>>();
+        if rows.is_empty() {
+            return Ok(ColumnBuilder::repeat(&ScalarRef::Null, num_rows, &return_type).build());
+        }
+        rows.sort_by_key(|row| std::cmp::Reverse(array_len(*row)));
+
+        let first_indices = rows
+            .iter()
+            .map(|row| offsets[*row] as u32)
+            .collect::<Vec<_>>();
+        let mut acc = values.take(&first_indices);
+        for step in 1..array_len(rows[0]) {
+            let num_active = rows.partition_point(|row| array_len(*row) > step);
+            let indices = rows[..num_active]
+                .iter()
+                .map(|row| (offsets[*row] as usize + step) as u32)
+                .collect::<Vec<_>>();
+            let block = DataBlock::new(
+                vec![
+                    BlockEntry::new(inner_type.clone(), Value::Column(acc.slice(0..num_active))),
+                    BlockEntry::new(inner_type.clone(), Value::Column(values.take(&indices))),
+                ],
+                num_active,
+            );
+            let evaluator = Evaluator::new(&block, self.func_ctx, self.fn_registry);
+            let reduced = evaluator
+                .run(lambda_expr)?
+                .convert_to_full_column(&inner_type, num_active);
+            acc = if num_active == acc.len() {
+                reduced
+            } else {
+                Column::concat(&[reduced, acc.slice(num_active..acc.len())])
+            };
+        }
+
+        // Move the results back to the origin rows, the empty and null arrays are reduced to null.
+        let mut positions = vec![0_u32; num_rows];
+        let mut result_validity = constant_bitmap(false, num_rows);
+        for (position, row) in rows.iter().enumerate() {
+            positions[*row] = position as u32;
+            result_validity.set(*row, true);
+        }
+        Ok(acc
+            .take(&positions)
+            .wrap_nullable(Some(result_validity.into())))
+    }
+
     fn run_cast(
         &self,
         span: Span,
@@ -1215,6 +1403,48 @@ impl<'a, Index: ColumnIndex> ConstantFolder<'a, Index> {
 
                 (func_expr, func_domain)
             }
+            Expr::LambdaFunctionCall {
+                span,
+                name,
+                args,
+                lambda_expr,
+                lambda_display,
+                return_type,
+            } => {
+                let args_expr = args
+                    .iter()
+                    .map(|arg| self.fold_once(arg).0)
+                    .collect::<Vec<_>>();
+                let all_args_is_scalar = args_expr.iter().all(|arg| arg.as_constant().is_some());
+
+                let func_expr = Expr::LambdaFunctionCall {
+                    span: *span,
+                    name: name.clone(),
+                    args: args_expr,
+                    lambda_expr: lambda_expr.clone(),
+                    lambda_display: lambda_display.clone(),
+                    return_type: return_type.clone(),
+                };
+
+                if all_args_is_scalar {
+                    let block = DataBlock::empty();
+                    let evaluator = Evaluator::new(&block, self.func_ctx, self.fn_registry);
+                    // Since we know the expression is constant, it'll be safe to change its column index type.
+                    let func_expr = func_expr.project_column_ref(|_| unreachable!());
+                    if let Ok(Value::Scalar(scalar)) = evaluator.run(&func_expr) {
+                        return (
+                            Expr::Constant {
+                                span: *span,
+                                scalar,
+                                data_type: return_type.clone(),
+                            },
+                            None,
+                        );
+                    }
+                }
+
+                (func_expr, None)
+            }
         };
 
         debug_assert_eq!(expr.data_type(), new_expr.data_type());
@@ -25,11 +25,13 @@ use crate::types::string::StringColumnBuilder;
 use crate::types::AnyType;
 use crate::types::ArgType;
 use crate::types::ArrayType;
+use crate::types::BinaryType;
 use crate::types::BitmapType;
 use crate::types::BooleanType;
 use crate::types::DateType;
 use crate::types::EmptyArrayType;
 use crate::types::EmptyMapType;
+use crate::types::GeometryType;
 use crate::types::MapType;
 use crate::types::NullType;
 use crate::types::NullableType;
@@ -168,6 +170,16 @@ impl Column {
                 let builder = StringColumnBuilder::with_capacity(capacity, data_capacity);
                 Self::concat_value_types::<BitmapType>(builder, columns)
             }
+            Column::Geometry(_) => {
+                let data_capacity = columns.iter().map(|c| c.memory_size() - c.len() * 8).sum();
+                let builder = StringColumnBuilder::with_capacity(capacity, data_capacity);
+                Self::concat_value_types::<GeometryType>(builder, columns)
+            }
+            Column::Binary(_) => {
+                let data_capacity = columns.iter().map(|c| c.memory_size() - c.len() * 8).sum();
+                let builder = StringColumnBuilder::with_capacity(capacity, data_capacity);
+                Self::concat_value_types::<BinaryType>(builder, columns)
+            }
             Column::Nullable(_) => {
                 let mut bitmaps = Vec::with_capacity(columns.len());
                 let mut inners = Vec::with_capacity(columns.len());
@@ -160,6 +160,14 @@ impl Column {
                 let column = Self::filter_string_scalars(column, filter);
                 Column::Bitmap(column)
             }
+            Column::Geometry(column) => {
+                let column = Self::filter_string_scalars(column, filter);
+                Column::Geometry(column)
+            }
+            Column::Binary(column) => {
+                let column = Self::filter_string_scalars(column, filter);
+                Column::Binary(column)
+            }
 
             Column::Nullable(c) => {
                 let column = Self::filter(&c.column, filter);
@@ -649,7 +649,7 @@ pub fn serialize_column_binary(column: &Column, row: usize, vec: &mut Vec<u8>) {
                 serialize_column_binary(&data, i, vec);
             }
         }
-        Column::Bitmap(v) => {
+        Column::Bitmap(v) | Column::Geometry(v) | Column::Binary(v) => {
             BinaryWrite::write_binary(vec, unsafe { v.index_unchecked(row) }).unwrap()
         }
         Column::Nullable(c) => {
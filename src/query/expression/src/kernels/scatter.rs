@@ -18,8 +18,10 @@ use itertools::Itertools;
 
 use crate::types::array::ArrayColumn;
 use crate::types::array::ArrayColumnBuilder;
+use crate::types::binary::BinaryType;
 use crate::types::bitmap::BitmapType;
 use crate::types::decimal::DecimalColumn;
+use crate::types::geometry::GeometryType;
 use crate::types::map::KvColumnBuilder;
 use crate::types::nullable::NullableColumn;
 use crate::types::number::NumberColumn;
@@ -228,6 +230,18 @@ impl Column {
                 indices,
                 scatter_size,
             ),
+            Column::Geometry(column) => Self::scatter_scalars::<GeometryType, _>(
+                column,
+                StringColumnBuilder::with_capacity(length, 0),
+                indices,
+                scatter_size,
+            ),
+            Column::Binary(column) => Self::scatter_scalars::<BinaryType, _>(
+                column,
+                StringColumnBuilder::with_capacity(length, 0),
+                indices,
+                scatter_size,
+            ),
             Column::Nullable(c) => {
                 let columns = c.column.scatter(data_type, indices, scatter_size);
                 let validities = Self::scatter_scalars::<BooleanType, _>(
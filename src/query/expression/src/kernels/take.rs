@@ -17,8 +17,10 @@ use itertools::Itertools;
 
 use crate::types::array::ArrayColumn;
 use crate::types::array::ArrayColumnBuilder;
+use crate::types::binary::BinaryType;
 use crate::types::bitmap::BitmapType;
 use crate::types::decimal::DecimalColumn;
+use crate::types::geometry::GeometryType;
 use crate::types::map::KvColumnBuilder;
 use crate::types::nullable::NullableColumn;
 use crate::types::number::NumberColumn;
@@ -133,6 +135,8 @@ impl Column {
                 Self::take_value_types::<MapType<AnyType, AnyType>, _>(&column, builder, indices)
             }
             Column::Bitmap(column) => Self::take_arg_types::<BitmapType, _>(column, indices),
+            Column::Geometry(column) => Self::take_arg_types::<GeometryType, _>(column, indices),
+            Column::Binary(column) => Self::take_arg_types::<BinaryType, _>(column, indices),
             Column::Nullable(c) => {
                 let column = c.column.take(indices);
                 let validity = Self::take_arg_types::<BooleanType, _>(&c.validity, indices);
@@ -16,8 +16,10 @@ use common_arrow::arrow::compute::merge_sort::MergeSlice;
 use itertools::Itertools;
 
 use crate::types::array::ArrayColumnBuilder;
+use crate::types::binary::BinaryType;
 use crate::types::bitmap::BitmapType;
 use crate::types::decimal::DecimalColumn;
+use crate::types::geometry::GeometryType;
 use crate::types::map::KvColumnBuilder;
 use crate::types::nullable::NullableColumn;
 use crate::types::number::NumberColumn;
@@ -265,6 +267,14 @@ impl Column {
                 let builder = BitmapType::create_builder(result_size, &[]);
                 Self::take_block_value_types::<BitmapType>(columns, builder, indices)
             }
+            Column::Geometry(_) => {
+                let builder = GeometryType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<GeometryType>(columns, builder, indices)
+            }
+            Column::Binary(_) => {
+                let builder = BinaryType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<BinaryType>(columns, builder, indices)
+            }
             Column::Nullable(_) => {
                 let inner_ty = datatype.as_nullable().unwrap();
                 let inner_columns = columns
@@ -19,8 +19,10 @@ use common_exception::Result;
 
 use crate::types::array::ArrayColumn;
 use crate::types::array::ArrayColumnBuilder;
+use crate::types::binary::BinaryType;
 use crate::types::bitmap::BitmapType;
 use crate::types::decimal::DecimalColumn;
+use crate::types::geometry::GeometryType;
 use crate::types::map::KvColumnBuilder;
 use crate::types::nullable::NullableColumn;
 use crate::types::number::NumberColumn;
@@ -154,6 +156,12 @@ impl Column {
             Column::Bitmap(column) => {
                 BitmapType::upcast_column(Self::take_string_types(column, indices, row_num))
             }
+            Column::Geometry(column) => {
+                GeometryType::upcast_column(Self::take_string_types(column, indices, row_num))
+            }
+            Column::Binary(column) => {
+                BinaryType::upcast_column(Self::take_string_types(column, indices, row_num))
+            }
             Column::Nullable(c) => {
                 let column = c.column.take_compacted_indices(indices, row_num);
                 let validity = BooleanType::upcast_column(Self::take_bool_types(
@@ -201,7 +201,9 @@ impl Domain {
                 };
                 Domain::Map(Some(inner_domain))
             }
-            DataType::Bitmap | DataType::Variant => Domain::Undefined,
+            DataType::Bitmap | DataType::Variant | DataType::Geometry | DataType::Binary => {
+                Domain::Undefined
+            }
             DataType::Generic(_) => unreachable!(),
         }
     }
@@ -34,9 +34,11 @@ use crate::types::DataType;
 use crate::types::NumberDataType;
 use crate::with_number_type;
 use crate::Scalar;
+use crate::ARROW_EXT_TYPE_BINARY;
 use crate::ARROW_EXT_TYPE_BITMAP;
 use crate::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use crate::ARROW_EXT_TYPE_EMPTY_MAP;
+use crate::ARROW_EXT_TYPE_GEOMETRY;
 use crate::ARROW_EXT_TYPE_VARIANT;
 
 // Column id of TableField
@@ -110,6 +112,18 @@ pub struct TableField {
     #[serde(default = "uninit_column_id")]
     pub column_id: ColumnId,
     pub computed_expr: Option<ComputedExpr>,
+    // the column ids and data types the field was stored with before its data type
+    // was modified, the most recent one comes first.
+    #[serde(default)]
+    pub origins: Vec<ColumnOrigin>,
+}
+
+/// The column id and data type of a field before its data type was modified,
+/// blocks written before the modification store the data of the field with them.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ColumnOrigin {
+    pub column_id: ColumnId,
+    pub data_type: TableDataType,
 }
 
 /// DataType with more information that is only available for table field, e.g, the
@@ -134,6 +148,8 @@ pub enum TableDataType {
         fields_type: Vec<TableDataType>,
     },
     Variant,
+    Geometry,
+    Binary,
 }
 
 impl DataSchema {
@@ -354,7 +370,7 @@ impl TableSchema {
 
     pub fn is_column_deleted(&self, column_id: ColumnId) -> bool {
         for field in &self.fields {
-            if field.contain_column_id(column_id) {
+            if field.contain_column_id(column_id) || field.contain_origin_column_id(column_id) {
                 return false;
             }
         }
@@ -403,6 +419,62 @@ impl TableSchema {
         Ok(())
     }
 
+    /// Rename the field `old` to `new`, the column id of the field is kept unchanged,
+    /// so the data written with the old name is still readable.
+    pub fn rename_field(&mut self, old: &str, new: &str) -> Result<()> {
+        if self.index_of(new).is_ok() {
+            return Err(ErrorCode::AddColumnExistError(format!(
+                "rename column {} failed, column {} already exist",
+                old, new,
+            )));
+        }
+        let i = self.index_of(old)?;
+        self.fields[i].name = new.to_string();
+
+        Ok(())
+    }
+
+    /// Modify the data type of the field `name` to `data_type`, the new data type
+    /// must be losslessly castable from the current one.
+    ///
+    /// The field is assigned a new column id, and the current column id and data type
+    /// are recorded as an origin of the field, so the blocks written before the
+    /// modification can be read and cast to the new data type.
+    pub fn modify_field_type(&mut self, name: &str, data_type: TableDataType) -> Result<()> {
+        let i = self.index_of(name)?;
+        let field = &self.fields[i];
+        if field.data_type == data_type {
+            return Ok(());
+        }
+        if field.computed_expr.is_some() {
+            return Err(ErrorCode::UnmatchColumnDataType(format!(
+                "computed column {} can not modify data type",
+                name
+            )));
+        }
+        if !field.data_type.can_lossless_cast_to(&data_type) {
+            return Err(ErrorCode::UnmatchColumnDataType(format!(
+                "column {} of type {} can not be losslessly cast to {}",
+                name, field.data_type, data_type
+            )));
+        }
+
+        let mut origins = Vec::with_capacity(field.origins.len() + 1);
+        origins.push(ColumnOrigin {
+            column_id: field.column_id,
+            data_type: field.data_type.clone(),
+        });
+        origins.extend(field.origins.iter().cloned());
+
+        let new_field = TableField::new(name, data_type)
+            .with_default_expr(field.default_expr.clone())
+            .build_column_id(&mut self.next_column_id)
+            .with_origins(origins);
+        self.fields[i] = new_field;
+
+        Ok(())
+    }
+
     pub fn to_leaf_column_id_set(&self) -> HashSet<ColumnId> {
         HashSet::from_iter(self.to_leaf_column_ids().iter().cloned())
     }
@@ -913,6 +985,7 @@ impl TableField {
             data_type,
             column_id: 0,
             computed_expr: None,
+            origins: vec![],
         }
     }
 
@@ -923,6 +996,7 @@ impl TableField {
             data_type,
             column_id,
             computed_expr: None,
+            origins: vec![],
         }
     }
 
@@ -969,6 +1043,7 @@ impl TableField {
             data_type: self.data_type.clone(),
             column_id,
             computed_expr: self.computed_expr.clone(),
+            origins: self.origins.clone(),
         }
     }
 
@@ -976,6 +1051,10 @@ impl TableField {
         self.column_ids().contains(&column_id)
     }
 
+    pub fn contain_origin_column_id(&self, column_id: ColumnId) -> bool {
+        self.origins.iter().any(|o| o.column_id == column_id)
+    }
+
     // `leaf_column_ids` return only the child column id.
     // if field is Tuple(t1, t2), it will return a column id vector of 2 column id.
     pub fn leaf_column_ids(&self) -> Vec<ColumnId> {
@@ -1012,6 +1091,12 @@ impl TableField {
         self
     }
 
+    #[must_use]
+    pub fn with_origins(mut self, origins: Vec<ColumnOrigin>) -> Self {
+        self.origins = origins;
+        self
+    }
+
     pub fn name(&self) -> &String {
         &self.name
     }
@@ -1028,6 +1113,10 @@ impl TableField {
         self.computed_expr.as_ref()
     }
 
+    pub fn origins(&self) -> &[ColumnOrigin] {
+        &self.origins
+    }
+
     #[inline]
     pub fn is_nullable(&self) -> bool {
         self.data_type.is_nullable()
@@ -1059,6 +1148,8 @@ impl From<&TableDataType> for DataType {
                 DataType::Tuple(fields_type.iter().map(Into::into).collect())
             }
             TableDataType::Variant => DataType::Variant,
+            TableDataType::Geometry => DataType::Geometry,
+            TableDataType::Binary => DataType::Binary,
         }
     }
 }
@@ -1083,6 +1174,33 @@ impl TableDataType {
         !self.is_nullable_or_null()
     }
 
+    /// Returns true if all the values of this type can be cast to `dest` without
+    /// losing information, e.g. `Int32` to `Int64`, or `Int32` to `Nullable(Int32)`.
+    pub fn can_lossless_cast_to(&self, dest: &TableDataType) -> bool {
+        match (self, dest) {
+            (src, dest) if src == dest => true,
+            (TableDataType::Nullable(src), TableDataType::Nullable(dest)) => {
+                src.can_lossless_cast_to(dest)
+            }
+            (TableDataType::Nullable(_), _) => false,
+            (src, TableDataType::Nullable(dest)) => src.can_lossless_cast_to(dest),
+            (TableDataType::Number(src), TableDataType::Number(dest)) => {
+                src.can_lossless_cast_to(*dest)
+            }
+            (TableDataType::Number(src), TableDataType::Decimal(dest)) => {
+                match src.get_decimal_properties() {
+                    Some(size) => size.precision <= dest.leading_digits(),
+                    None => false,
+                }
+            }
+            (TableDataType::Decimal(src), TableDataType::Decimal(dest)) => {
+                src.scale() <= dest.scale() && src.leading_digits() <= dest.leading_digits()
+            }
+            (TableDataType::Date, TableDataType::Timestamp) => true,
+            _ => false,
+        }
+    }
+
     pub fn remove_nullable(&self) -> Self {
         match self {
             TableDataType::Nullable(ty) => (**ty).clone(),
@@ -1292,15 +1410,25 @@ impl From<&ArrowField> for TableDataType {
             | ArrowDataType::FixedSizeList(f, _) =>
                 TableDataType::Array(Box::new(f.as_ref().into())),
 
-            ArrowDataType::Binary
-            | ArrowDataType::LargeBinary
-            | ArrowDataType::Utf8
-            | ArrowDataType::LargeUtf8 => TableDataType::String,
+            // `Binary` comes from the BYTE_ARRAY columns of parquet files without the UTF8
+            // annotation, the strings of Databend itself are written as `LargeBinary`.
+            ArrowDataType::Binary => TableDataType::Binary,
+            ArrowDataType::LargeBinary | ArrowDataType::Utf8 | ArrowDataType::LargeUtf8 => {
+                TableDataType::String
+            }
 
             ArrowDataType::Timestamp(_, _) => TableDataType::Timestamp,
             ArrowDataType::Date32 | ArrowDataType::Date64 => TableDataType::Date,
             ArrowDataType::Map(f, _) => {
-                let inner_ty = f.as_ref().into();
+                // the entries written by other parquet writers may be nullable or
+                // named differently, but they are never null.
+                let inner_ty = match f.data_type() {
+                    ArrowDataType::Struct(fields) if fields.len() == 2 => TableDataType::Tuple {
+                        fields_name: vec!["key".to_string(), "value".to_string()],
+                        fields_type: fields.iter().map(|f| f.into()).collect(),
+                    },
+                    _ => unimplemented!("data_type: {:?}", f.data_type()),
+                };
                 TableDataType::Map(Box::new(inner_ty))
             }
             ArrowDataType::Struct(fields) => {
@@ -1316,6 +1444,8 @@ impl From<&ArrowField> for TableDataType {
                 ARROW_EXT_TYPE_EMPTY_ARRAY => TableDataType::EmptyArray,
                 ARROW_EXT_TYPE_EMPTY_MAP => TableDataType::EmptyMap,
                 ARROW_EXT_TYPE_BITMAP => TableDataType::Bitmap,
+                ARROW_EXT_TYPE_GEOMETRY => TableDataType::Geometry,
+                ARROW_EXT_TYPE_BINARY => TableDataType::Binary,
                 _ => unimplemented!("data_type: {:?}", f.data_type()),
             },
             // this is safe, because we define the datatype firstly
@@ -1449,6 +1579,16 @@ impl From<&DataType> for ArrowDataType {
                 Box::new(ArrowDataType::LargeBinary),
                 None,
             ),
+            DataType::Geometry => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_GEOMETRY.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
+            DataType::Binary => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_BINARY.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
 
             _ => unreachable!(),
         }
@@ -1533,6 +1673,16 @@ impl From<&TableDataType> for ArrowDataType {
                 Box::new(ArrowDataType::LargeBinary),
                 None,
             ),
+            TableDataType::Geometry => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_GEOMETRY.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
+            TableDataType::Binary => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_BINARY.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
         }
     }
 }
@@ -1566,6 +1716,8 @@ pub fn infer_schema_type(data_type: &DataType) -> Result<TableDataType> {
         }
         DataType::Bitmap => Ok(TableDataType::Bitmap),
         DataType::Variant => Ok(TableDataType::Variant),
+        DataType::Geometry => Ok(TableDataType::Geometry),
+        DataType::Binary => Ok(TableDataType::Binary),
         DataType::Tuple(fields) => {
             let fields_type = fields
                 .iter()
@@ -126,7 +126,98 @@ pub fn check<Index: ColumnIndex>(
 
             check_function(*span, name, params, &args_expr, fn_registry)
         }
+        RawExpr::LambdaFunctionCall {
+            span,
+            name,
+            args,
+            lambda_expr,
+            lambda_display,
+        } => {
+            let args_expr: Vec<_> = args
+                .iter()
+                .map(|arg| check(arg, fn_registry))
+                .try_collect()?;
+            let lambda_expr = lambda_expr.as_expr(fn_registry);
+
+            check_lambda_function(*span, name, args_expr, lambda_expr, lambda_display)
+        }
+    }
+}
+
+/// Check the higher-order function `name`, the return type is inferred from the type of
+/// the array argument and the type of the lambda expression.
+pub fn check_lambda_function<Index: ColumnIndex>(
+    span: Span,
+    name: &str,
+    args: Vec<Expr<Index>>,
+    lambda_expr: Expr,
+    lambda_display: &str,
+) -> Result<Expr<Index>> {
+    if args.len() != 1 {
+        return Err(ErrorCode::SemanticError(format!(
+            "function `{name}` expects 1 argument and a lambda expression, but got {} arguments",
+            args.len()
+        ))
+        .set_span(span));
     }
+
+    let arg_type = args[0].data_type();
+    let lambda_type = lambda_expr.data_type();
+    let return_type = match (name, arg_type.remove_nullable()) {
+        (_, DataType::Null) => DataType::Null,
+        ("array_transform", DataType::EmptyArray) => arg_type.clone(),
+        ("array_transform", DataType::Array(_)) => {
+            let array_type = DataType::Array(Box::new(lambda_type.clone()));
+            if arg_type.is_nullable() {
+                array_type.wrap_nullable()
+            } else {
+                array_type
+            }
+        }
+        ("array_filter", DataType::EmptyArray | DataType::Array(_)) => {
+            if !matches!(
+                lambda_type.remove_nullable(),
+                DataType::Boolean | DataType::Null
+            ) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "the lambda expression of `{name}` must return boolean, but got {lambda_type}"
+                ))
+                .set_span(span));
+            }
+            arg_type.clone()
+        }
+        ("array_reduce", DataType::EmptyArray) => DataType::Null,
+        ("array_reduce", DataType::Array(inner_type)) => {
+            if lambda_type != inner_type.as_ref() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "the lambda expression of `{name}` must return {inner_type}, but got {lambda_type}"
+                ))
+                .set_span(span));
+            }
+            inner_type.wrap_nullable()
+        }
+        ("array_transform" | "array_filter" | "array_reduce", _) => {
+            return Err(ErrorCode::SemanticError(format!(
+                "function `{name}` expects an array argument, but got {arg_type}"
+            ))
+            .set_span(span));
+        }
+        _ => {
+            return Err(
+                ErrorCode::UnknownFunction(format!("function `{name}` does not exist"))
+                    .set_span(span),
+            );
+        }
+    };
+
+    Ok(Expr::LambdaFunctionCall {
+        span,
+        name: name.to_string(),
+        args,
+        lambda_expr: Box::new(lambda_expr),
+        lambda_display: lambda_display.to_string(),
+        return_type,
+    })
 }
 
 pub fn check_cast<Index: ColumnIndex>(
@@ -693,6 +784,8 @@ pub const ALL_SIMPLE_CAST_FUNCTIONS: &[&str] = &[
     "to_variant",
     "to_boolean",
     "to_decimal",
+    "to_geometry",
+    "to_binary",
 ];
 
 pub fn is_simple_cast_function(name: &str) -> bool {
@@ -832,6 +832,12 @@ impl DecimalColumnBuilder {
         })
     }
 
+    pub fn reserve(&mut self, additional: usize) {
+        crate::with_decimal_type!(|DECIMAL_TYPE| match self {
+            DecimalColumnBuilder::DECIMAL_TYPE(builder, _) => builder.reserve(additional),
+        })
+    }
+
     pub fn push(&mut self, item: DecimalScalar) {
         crate::with_decimal_type!(|DECIMAL_TYPE| match (self, item) {
             (
@@ -0,0 +1,425 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Range;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use geo::Coord;
+use geo::Geometry;
+use geo::GeometryCollection;
+use geo::LineString;
+use geo::MultiLineString;
+use geo::MultiPoint;
+use geo::MultiPolygon;
+use geo::Point;
+use geo::Polygon;
+use wkt::ToWkt;
+use wkt::TryFromWkt;
+
+use crate::property::Domain;
+use crate::types::string::StringColumn;
+use crate::types::string::StringColumnBuilder;
+use crate::types::string::StringIterator;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+/// Geometry values are stored as WKB (well-known binary) in a binary column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeometryType;
+
+impl ValueType for GeometryType {
+    type Scalar = Vec<u8>;
+    type ScalarRef<'a> = &'a [u8];
+    type Column = StringColumn;
+    type Domain = ();
+    type ColumnIterator<'a> = StringIterator<'a>;
+    type ColumnBuilder = StringColumnBuilder;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: Self::ScalarRef<'long>) -> Self::ScalarRef<'short> {
+        long
+    }
+
+    fn to_owned_scalar<'a>(scalar: Self::ScalarRef<'a>) -> Self::Scalar {
+        scalar.to_vec()
+    }
+
+    fn to_scalar_ref<'a>(scalar: &'a Self::Scalar) -> Self::ScalarRef<'a> {
+        scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        scalar.as_geometry().cloned()
+    }
+
+    fn try_downcast_column<'a>(col: &'a Column) -> Option<Self::Column> {
+        col.as_geometry().cloned()
+    }
+
+    fn try_downcast_builder<'a>(
+        builder: &'a mut ColumnBuilder,
+    ) -> Option<&'a mut Self::ColumnBuilder> {
+        match builder {
+            crate::ColumnBuilder::Geometry(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<Self::Domain> {
+        if domain.is_undefined() {
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::Geometry(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::Geometry(col)
+    }
+
+    fn upcast_domain(_domain: Self::Domain) -> Domain {
+        Domain::Undefined
+    }
+
+    fn column_len<'a>(col: &'a Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column<'a>(col: &'a Self::Column, index: usize) -> Option<Self::ScalarRef<'a>> {
+        col.index(index)
+    }
+
+    unsafe fn index_column_unchecked<'a>(
+        col: &'a Self::Column,
+        index: usize,
+    ) -> Self::ScalarRef<'a> {
+        col.index_unchecked(index)
+    }
+
+    fn slice_column<'a>(col: &'a Self::Column, range: Range<usize>) -> Self::Column {
+        col.slice(range)
+    }
+
+    fn iter_column<'a>(col: &'a Self::Column) -> Self::ColumnIterator<'a> {
+        col.iter()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        StringColumnBuilder::from_column(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::ScalarRef<'_>) {
+        builder.put_slice(item);
+        builder.commit_row();
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.commit_row();
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.append_column(other)
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.build()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        builder.build_scalar()
+    }
+
+    fn scalar_memory_size<'a>(scalar: &Self::ScalarRef<'a>) -> usize {
+        scalar.len()
+    }
+
+    fn column_memory_size(col: &Self::Column) -> usize {
+        col.data.len() + col.offsets.len() * 8
+    }
+}
+
+impl ArgType for GeometryType {
+    fn data_type() -> DataType {
+        DataType::Geometry
+    }
+
+    fn full_domain() -> Self::Domain {}
+
+    fn create_builder(capacity: usize, _: &GenericMap) -> Self::ColumnBuilder {
+        StringColumnBuilder::with_capacity(capacity, 0)
+    }
+}
+
+const WKB_POINT: u32 = 1;
+const WKB_LINE_STRING: u32 = 2;
+const WKB_POLYGON: u32 = 3;
+const WKB_MULTI_POINT: u32 = 4;
+const WKB_MULTI_LINE_STRING: u32 = 5;
+const WKB_MULTI_POLYGON: u32 = 6;
+const WKB_GEOMETRY_COLLECTION: u32 = 7;
+
+/// WKB of `GEOMETRYCOLLECTION EMPTY`, used as the default value of geometry.
+pub const EMPTY_GEOMETRY_WKB: &[u8] = &[1, 7, 0, 0, 0, 0, 0, 0, 0];
+
+/// Parses a WKT string, e.g. `POINT(1 2)`, into WKB.
+pub fn wkt_to_wkb(wkt: &str) -> Result<Vec<u8>> {
+    let geometry = Geometry::<f64>::try_from_wkt_str(wkt)
+        .map_err(|e| ErrorCode::BadArguments(format!("invalid WKT '{}': {}", wkt, e)))?;
+    Ok(geometry_to_wkb(&geometry))
+}
+
+/// Formats a WKB value as a WKT string.
+pub fn wkb_to_wkt(wkb: &[u8]) -> Result<String> {
+    Ok(wkb_to_geometry(wkb)?.wkt_string())
+}
+
+/// Encodes the geometry as little endian WKB.
+pub fn geometry_to_wkb(geometry: &Geometry<f64>) -> Vec<u8> {
+    let mut buf = Vec::new();
+    write_geometry(&mut buf, geometry);
+    buf
+}
+
+pub fn wkb_to_geometry(wkb: &[u8]) -> Result<Geometry<f64>> {
+    let mut reader = WkbReader { buf: wkb, pos: 0 };
+    let geometry = reader.read_geometry()?;
+    if reader.pos != wkb.len() {
+        return Err(ErrorCode::BadBytes("invalid WKB: trailing bytes"));
+    }
+    Ok(geometry)
+}
+
+fn write_header(buf: &mut Vec<u8>, ty: u32) {
+    buf.push(1);
+    buf.extend_from_slice(&ty.to_le_bytes());
+}
+
+fn write_coords(buf: &mut Vec<u8>, coords: &[Coord<f64>]) {
+    buf.extend_from_slice(&(coords.len() as u32).to_le_bytes());
+    for coord in coords {
+        buf.extend_from_slice(&coord.x.to_le_bytes());
+        buf.extend_from_slice(&coord.y.to_le_bytes());
+    }
+}
+
+fn write_polygon_rings(buf: &mut Vec<u8>, polygon: &Polygon<f64>) {
+    buf.extend_from_slice(&(polygon.interiors().len() as u32 + 1).to_le_bytes());
+    write_coords(buf, &polygon.exterior().0);
+    for ring in polygon.interiors() {
+        write_coords(buf, &ring.0);
+    }
+}
+
+fn write_geometry(buf: &mut Vec<u8>, geometry: &Geometry<f64>) {
+    match geometry {
+        Geometry::Point(p) => {
+            write_header(buf, WKB_POINT);
+            buf.extend_from_slice(&p.x().to_le_bytes());
+            buf.extend_from_slice(&p.y().to_le_bytes());
+        }
+        Geometry::Line(l) => {
+            write_header(buf, WKB_LINE_STRING);
+            write_coords(buf, &[l.start, l.end]);
+        }
+        Geometry::LineString(l) => {
+            write_header(buf, WKB_LINE_STRING);
+            write_coords(buf, &l.0);
+        }
+        Geometry::Polygon(p) => {
+            write_header(buf, WKB_POLYGON);
+            write_polygon_rings(buf, p);
+        }
+        Geometry::Rect(r) => write_geometry(buf, &Geometry::Polygon(r.to_polygon())),
+        Geometry::Triangle(t) => write_geometry(buf, &Geometry::Polygon(t.to_polygon())),
+        Geometry::MultiPoint(m) => {
+            write_header(buf, WKB_MULTI_POINT);
+            buf.extend_from_slice(&(m.0.len() as u32).to_le_bytes());
+            for p in &m.0 {
+                write_geometry(buf, &Geometry::Point(*p));
+            }
+        }
+        Geometry::MultiLineString(m) => {
+            write_header(buf, WKB_MULTI_LINE_STRING);
+            buf.extend_from_slice(&(m.0.len() as u32).to_le_bytes());
+            for l in &m.0 {
+                write_header(buf, WKB_LINE_STRING);
+                write_coords(buf, &l.0);
+            }
+        }
+        Geometry::MultiPolygon(m) => {
+            write_header(buf, WKB_MULTI_POLYGON);
+            buf.extend_from_slice(&(m.0.len() as u32).to_le_bytes());
+            for p in &m.0 {
+                write_header(buf, WKB_POLYGON);
+                write_polygon_rings(buf, p);
+            }
+        }
+        Geometry::GeometryCollection(c) => {
+            write_header(buf, WKB_GEOMETRY_COLLECTION);
+            buf.extend_from_slice(&(c.0.len() as u32).to_le_bytes());
+            for g in &c.0 {
+                write_geometry(buf, g);
+            }
+        }
+    }
+}
+
+struct WkbReader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> WkbReader<'a> {
+    fn read_bytes<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let bytes = self
+            .buf
+            .get(self.pos..self.pos + N)
+            .ok_or_else(|| ErrorCode::BadBytes("invalid WKB: unexpected end of data"))?;
+        self.pos += N;
+        Ok(bytes.try_into().unwrap())
+    }
+
+    fn read_u32(&mut self, little_endian: bool) -> Result<u32> {
+        let bytes = self.read_bytes::<4>()?;
+        Ok(if little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn read_f64(&mut self, little_endian: bool) -> Result<f64> {
+        let bytes = self.read_bytes::<8>()?;
+        Ok(if little_endian {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        })
+    }
+
+    fn read_header(&mut self) -> Result<(bool, u32)> {
+        let little_endian = match self.read_bytes::<1>()?[0] {
+            0 => false,
+            1 => true,
+            b => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "invalid WKB: unknown byte order {}",
+                    b
+                )));
+            }
+        };
+        let ty = self.read_u32(little_endian)?;
+        Ok((little_endian, ty))
+    }
+
+    fn read_coords(&mut self, little_endian: bool) -> Result<Vec<Coord<f64>>> {
+        let n = self.read_u32(little_endian)? as usize;
+        // Each coordinate takes 16 bytes, check the length before allocating.
+        if n > (self.buf.len() - self.pos) / 16 {
+            return Err(ErrorCode::BadBytes("invalid WKB: unexpected end of data"));
+        }
+        let mut coords = Vec::with_capacity(n);
+        for _ in 0..n {
+            let x = self.read_f64(little_endian)?;
+            let y = self.read_f64(little_endian)?;
+            coords.push(Coord { x, y });
+        }
+        Ok(coords)
+    }
+
+    fn read_polygon(&mut self, little_endian: bool) -> Result<Polygon<f64>> {
+        let n = self.read_u32(little_endian)? as usize;
+        let mut rings = Vec::new();
+        for _ in 0..n {
+            rings.push(LineString(self.read_coords(little_endian)?));
+        }
+        let mut rings = rings.into_iter();
+        let exterior = rings.next().unwrap_or_else(|| LineString(vec![]));
+        Ok(Polygon::new(exterior, rings.collect()))
+    }
+
+    /// Reads a member of a multi geometry, which must be of the type `T`.
+    fn read_member<T: TryFrom<Geometry<f64>>>(&mut self) -> Result<T> {
+        let geometry = self.read_geometry()?;
+        T::try_from(geometry)
+            .map_err(|_| ErrorCode::BadBytes("invalid WKB: unexpected member of multi geometry"))
+    }
+
+    fn read_geometry(&mut self) -> Result<Geometry<f64>> {
+        let (le, ty) = self.read_header()?;
+        let geometry = match ty {
+            WKB_POINT => {
+                let x = self.read_f64(le)?;
+                let y = self.read_f64(le)?;
+                Geometry::Point(Point::new(x, y))
+            }
+            WKB_LINE_STRING => Geometry::LineString(LineString(self.read_coords(le)?)),
+            WKB_POLYGON => Geometry::Polygon(self.read_polygon(le)?),
+            WKB_MULTI_POINT => {
+                let n = self.read_u32(le)?;
+                let mut points = Vec::new();
+                for _ in 0..n {
+                    points.push(self.read_member()?);
+                }
+                Geometry::MultiPoint(MultiPoint(points))
+            }
+            WKB_MULTI_LINE_STRING => {
+                let n = self.read_u32(le)?;
+                let mut lines = Vec::new();
+                for _ in 0..n {
+                    lines.push(self.read_member()?);
+                }
+                Geometry::MultiLineString(MultiLineString(lines))
+            }
+            WKB_MULTI_POLYGON => {
+                let n = self.read_u32(le)?;
+                let mut polygons = Vec::new();
+                for _ in 0..n {
+                    polygons.push(self.read_member()?);
+                }
+                Geometry::MultiPolygon(MultiPolygon(polygons))
+            }
+            WKB_GEOMETRY_COLLECTION => {
+                let n = self.read_u32(le)?;
+                let mut geometries = Vec::new();
+                for _ in 0..n {
+                    geometries.push(self.read_geometry()?);
+                }
+                Geometry::GeometryCollection(GeometryCollection(geometries))
+            }
+            _ => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "invalid WKB: unsupported geometry type {}",
+                    ty
+                )));
+            }
+        };
+        Ok(geometry)
+    }
+}
@@ -351,6 +351,11 @@ impl<T: ArgType> NullableColumnBuilder<T> {
 }
 
 impl NullableColumnBuilder<AnyType> {
+    pub fn reserve(&mut self, additional: usize) {
+        self.builder.reserve(additional);
+        self.validity.reserve(additional);
+    }
+
     pub fn pop(&mut self) -> Option<Option<Scalar>> {
         if self.validity.pop()? {
             Some(Some(self.builder.pop().unwrap()))
@@ -523,6 +523,12 @@ impl NumberColumnBuilder {
         })
     }
 
+    pub fn reserve(&mut self, additional: usize) {
+        crate::with_number_type!(|NUM_TYPE| match self {
+            NumberColumnBuilder::NUM_TYPE(builder) => builder.reserve(additional),
+        })
+    }
+
     pub fn push(&mut self, item: NumberScalar) {
         crate::with_number_type!(|NUM_TYPE| match (self, item) {
             (NumberColumnBuilder::NUM_TYPE(builder), NumberScalar::NUM_TYPE(value)) => {
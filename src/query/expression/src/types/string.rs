@@ -289,6 +289,12 @@ impl StringColumnBuilder {
         self.offsets.len() - 1
     }
 
+    /// Reserves capacity for at least `additional` more rows, and `data_additional` more bytes.
+    pub fn reserve(&mut self, additional: usize, data_additional: usize) {
+        self.offsets.reserve(additional);
+        self.data.reserve(data_additional);
+    }
+
     pub fn put_u8(&mut self, item: u8) {
         self.data.push(item);
     }
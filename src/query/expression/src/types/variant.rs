@@ -15,6 +15,7 @@
 use std::ops::Range;
 
 use super::date::date_to_string;
+use super::geometry::wkb_to_wkt;
 use super::number::NumberScalar;
 use super::timestamp::timestamp_to_string;
 use crate::date_helper::TzLUT;
@@ -226,6 +227,8 @@ pub fn cast_scalar_to_variant(scalar: ScalarRef, tz: TzLUT, buf: &mut Vec<u8>) {
             buf.extend_from_slice(b);
             return;
         }
+        ScalarRef::Geometry(g) => jsonb::Value::String(wkb_to_wkt(g).unwrap_or_default().into()),
+        ScalarRef::Binary(b) => jsonb::Value::String(hex::encode_upper(b).into()),
         ScalarRef::Tuple(fields) => {
             let values = cast_scalars_to_variants(fields, tz);
             jsonb::build_object(
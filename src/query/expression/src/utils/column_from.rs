@@ -58,7 +58,9 @@ macro_rules! for_common_scalar_values {
             { DateType },
             { TimestampType },
             { VariantType },
-            { BitmapType }
+            { BitmapType },
+            { GeometryType },
+            { BinaryType }
         }
     };
 }
@@ -125,6 +127,24 @@ impl<'a, D: AsRef<[&'a [u8]]>> FromData<D, [Vec<u8>; 2]> for BitmapType {
     }
 }
 
+impl<'a, D: AsRef<[&'a [u8]]>> FromData<D, [Vec<u8>; 2]> for GeometryType {
+    fn from_data(d: D) -> Column {
+        GeometryType::upcast_column(GeometryType::column_from_ref_iter(
+            d.as_ref().iter().copied(),
+            &[],
+        ))
+    }
+}
+
+impl<'a, D: AsRef<[&'a [u8]]>> FromData<D, [Vec<u8>; 2]> for BinaryType {
+    fn from_data(d: D) -> Column {
+        BinaryType::upcast_column(BinaryType::column_from_ref_iter(
+            d.as_ref().iter().copied(),
+            &[],
+        ))
+    }
+}
+
 impl<D: AsRef<[f32]>> FromData<D, [Vec<f32>; 0]> for Float32Type {
     fn from_data(d: D) -> Column {
         Float32Type::upcast_column(Float32Type::column_from_iter(
@@ -32,6 +32,7 @@ use once_cell::sync::Lazy;
 use crate::types::date::check_date;
 use crate::types::timestamp::check_timestamp;
 use crate::types::timestamp::MICROS_IN_A_SEC;
+use crate::utils::serialize::EPOCH_DAYS_FROM_CE;
 
 #[derive(Debug, Clone, Copy)]
 pub struct TzLUT {
@@ -164,9 +165,7 @@ impl TzLUT {
                 (us + MICROS_IN_A_SEC - us_div) / us_div * us_div
             };
         }
-        let datetime = self.to_datetime_from_us(us);
-        let fix = datetime.offset().fix().local_minus_utc() as i64;
-        fix + (us - fix * MICROS_IN_A_SEC) / us_div * us_div
+        self.round_down_local(us, us_div)
     }
 
     #[inline]
@@ -179,9 +178,15 @@ impl TzLUT {
                 (us + MICROS_IN_A_SEC - us_div) / us_div * us_div
             };
         }
+        self.round_down_local(us, us_div)
+    }
+
+    /// Rounds down the local wall-clock time of `us` to a multiple of `us_div`.
+    #[inline]
+    fn round_down_local(&self, us: i64, us_div: i64) -> i64 {
         let datetime = self.to_datetime_from_us(us);
-        let fix = datetime.offset().fix().local_minus_utc() as i64;
-        fix + (us - fix * MICROS_IN_A_SEC) / us_div * us_div
+        let fix = datetime.offset().fix().local_minus_utc() as i64 * MICROS_IN_A_SEC;
+        (us + fix).div_euclid(us_div) * us_div - fix
     }
 
     #[inline]
@@ -235,6 +240,25 @@ impl TzLUT {
         let datetime = self.to_datetime_from_us(us);
         datetime.hour() as u8
     }
+
+    /// The local date of the timestamp, in days since 1970-01-01.
+    #[inline]
+    pub fn to_days(&self, us: i64) -> i32 {
+        let datetime = self.to_datetime_from_us(us);
+        datetime.date_naive().num_days_from_ce() - EPOCH_DAYS_FROM_CE
+    }
+
+    /// The timestamp of the local midnight of the date.
+    #[inline]
+    pub fn date_to_micros(&self, days: i32) -> i64 {
+        let midnight = NaiveDate::from_num_days_from_ce_opt(days + EPOCH_DAYS_FROM_CE)
+            .and_then(|d| d.and_hms_opt(0, 0, 0));
+        match midnight.and_then(|t| self.tz.from_local_datetime(&t).earliest()) {
+            Some(datetime) => datetime.timestamp_micros(),
+            // the midnight is skipped by a daylight saving change.
+            None => days as i64 * 24 * 3600 * MICROS_IN_A_SEC,
+        }
+    }
 }
 
 pub trait DateConverter {
@@ -381,8 +405,8 @@ impl AddTimesImpl {
 }
 
 #[inline]
-pub fn today_date() -> i32 {
-    let now = Utc::now();
+pub fn today_date(tz: Tz) -> i32 {
+    let now = Utc::now().with_timezone(&tz);
     NaiveDate::from_ymd_opt(now.year(), now.month(), now.day())
         .unwrap()
         .signed_duration_since(NaiveDate::from_ymd_opt(1970, 1, 1).unwrap())
@@ -40,6 +40,7 @@ use crate::types::decimal::DecimalColumn;
 use crate::types::decimal::DecimalDataType;
 use crate::types::decimal::DecimalDomain;
 use crate::types::decimal::DecimalScalar;
+use crate::types::geometry::wkb_to_wkt;
 use crate::types::map::KvPair;
 use crate::types::nullable::NullableDomain;
 use crate::types::number::NumberColumn;
@@ -157,6 +158,11 @@ impl<'a> Debug for ScalarRef<'a> {
                 write!(f, ")")
             }
             ScalarRef::Variant(s) => write!(f, "0x{}", &hex::encode(s)),
+            ScalarRef::Geometry(s) => match wkb_to_wkt(s) {
+                Ok(wkt) => write!(f, "{:?}", wkt),
+                Err(_) => write!(f, "0x{}", &hex::encode(s)),
+            },
+            ScalarRef::Binary(s) => write!(f, "0x{}", &hex::encode(s)),
         }
     }
 }
@@ -179,6 +185,8 @@ impl Debug for Column {
             Column::Nullable(col) => write!(f, "{col:?}"),
             Column::Tuple(fields) => f.debug_tuple("Tuple").field(fields).finish(),
             Column::Variant(col) => write!(f, "{col:?}"),
+            Column::Geometry(col) => write!(f, "{col:?}"),
+            Column::Binary(col) => write!(f, "{col:?}"),
         }
     }
 }
@@ -239,6 +247,11 @@ impl<'a> Display for ScalarRef<'a> {
                 let value = jsonb::to_string(s);
                 write!(f, "{value}")
             }
+            ScalarRef::Geometry(s) => match wkb_to_wkt(s) {
+                Ok(wkt) => write!(f, "'{wkt}'"),
+                Err(_) => write!(f, "0x{}", &hex::encode(s)),
+            },
+            ScalarRef::Binary(s) => write!(f, "X'{}'", &hex::encode_upper(s)),
         }
     }
 }
@@ -430,6 +443,18 @@ impl<Index: ColumnIndex> Display for RawExpr<Index> {
                 }
                 write!(f, ")")
             }
+            RawExpr::LambdaFunctionCall {
+                name,
+                args,
+                lambda_display,
+                ..
+            } => {
+                write!(f, "{name}(")?;
+                for arg in args {
+                    write!(f, "{arg}, ")?;
+                }
+                write!(f, "{lambda_display})")
+            }
         }
     }
 }
@@ -469,6 +494,8 @@ impl Display for DataType {
                 write!(f, ")")
             }
             DataType::Variant => write!(f, "Variant"),
+            DataType::Geometry => write!(f, "Geometry"),
+            DataType::Binary => write!(f, "Binary"),
             DataType::Generic(index) => write!(f, "T{index}"),
         }
     }
@@ -515,6 +542,8 @@ impl Display for TableDataType {
                 write!(f, ")")
             }
             TableDataType::Variant => write!(f, "Variant"),
+            TableDataType::Geometry => write!(f, "Geometry"),
+            TableDataType::Binary => write!(f, "Binary"),
         }
     }
 }
@@ -614,6 +643,18 @@ impl<Index: ColumnIndex> Display for Expr<Index> {
                 }
                 write!(f, ")")
             }
+            Expr::LambdaFunctionCall {
+                name,
+                args,
+                lambda_display,
+                ..
+            } => {
+                write!(f, "{name}(")?;
+                for arg in args {
+                    write!(f, "{arg}, ")?;
+                }
+                write!(f, "{lambda_display})")
+            }
         }
     }
 }
@@ -746,6 +787,23 @@ impl<Index: ColumnIndex> Expr<Index> {
                         s
                     }
                 },
+                Expr::LambdaFunctionCall {
+                    name,
+                    args,
+                    lambda_display,
+                    ..
+                } => {
+                    let mut s = String::new();
+                    s += name;
+                    s += "(";
+                    for arg in args {
+                        s += &arg.sql_display();
+                        s += ", ";
+                    }
+                    s += lambda_display;
+                    s += ")";
+                    s
+                }
             }
         }
 
@@ -55,6 +55,8 @@ use crate::types::decimal::DecimalColumnBuilder;
 use crate::types::decimal::DecimalDataType;
 use crate::types::decimal::DecimalScalar;
 use crate::types::decimal::DecimalSize;
+use crate::types::geometry::geometry_to_wkb;
+use crate::types::geometry::EMPTY_GEOMETRY_WKB;
 use crate::types::nullable::NullableColumn;
 use crate::types::nullable::NullableColumnBuilder;
 use crate::types::nullable::NullableDomain;
@@ -110,6 +112,8 @@ pub enum Scalar {
     Array(Column),
     Map(Column),
     Bitmap(Vec<u8>),
+    Geometry(Vec<u8>),
+    Binary(Vec<u8>),
     Tuple(Vec<Scalar>),
     Variant(Vec<u8>),
 }
@@ -129,6 +133,8 @@ pub enum ScalarRef<'a> {
     Array(Column),
     Map(Column),
     Bitmap(&'a [u8]),
+    Geometry(&'a [u8]),
+    Binary(&'a [u8]),
     Tuple(Vec<ScalarRef<'a>>),
     Variant(&'a [u8]),
 }
@@ -147,6 +153,8 @@ pub enum Column {
     Array(Box<ArrayColumn<AnyType>>),
     Map(Box<ArrayColumn<AnyType>>),
     Bitmap(StringColumn),
+    Geometry(StringColumn),
+    Binary(StringColumn),
     Nullable(Box<NullableColumn<AnyType>>),
     Tuple(Vec<Column>),
     Variant(StringColumn),
@@ -166,6 +174,8 @@ pub enum ColumnBuilder {
     Array(Box<ArrayColumnBuilder<AnyType>>),
     Map(Box<ArrayColumnBuilder<AnyType>>),
     Bitmap(StringColumnBuilder),
+    Geometry(StringColumnBuilder),
+    Binary(StringColumnBuilder),
     Nullable(Box<NullableColumnBuilder<AnyType>>),
     Tuple(Vec<ColumnBuilder>),
     Variant(StringColumnBuilder),
@@ -311,6 +321,8 @@ impl Scalar {
             Scalar::Array(col) => ScalarRef::Array(col.clone()),
             Scalar::Map(col) => ScalarRef::Map(col.clone()),
             Scalar::Bitmap(b) => ScalarRef::Bitmap(b.as_slice()),
+            Scalar::Geometry(b) => ScalarRef::Geometry(b.as_slice()),
+            Scalar::Binary(b) => ScalarRef::Binary(b.as_slice()),
             Scalar::Tuple(fields) => ScalarRef::Tuple(fields.iter().map(Scalar::as_ref).collect()),
             Scalar::Variant(s) => ScalarRef::Variant(s.as_slice()),
         }
@@ -350,6 +362,8 @@ impl Scalar {
                 Scalar::Map(col)
             }
             DataType::Bitmap => Scalar::Bitmap(vec![]),
+            DataType::Geometry => Scalar::Geometry(EMPTY_GEOMETRY_WKB.to_vec()),
+            DataType::Binary => Scalar::Binary(vec![]),
             DataType::Tuple(tys) => Scalar::Tuple(tys.iter().map(Scalar::default_value).collect()),
             DataType::Variant => Scalar::Variant(vec![]),
 
@@ -383,6 +397,8 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::Array(col) => Scalar::Array(col.clone()),
             ScalarRef::Map(col) => Scalar::Map(col.clone()),
             ScalarRef::Bitmap(b) => Scalar::Bitmap(b.to_vec()),
+            ScalarRef::Geometry(b) => Scalar::Geometry(b.to_vec()),
+            ScalarRef::Binary(b) => Scalar::Binary(b.to_vec()),
             ScalarRef::Tuple(fields) => {
                 Scalar::Tuple(fields.iter().map(ScalarRef::to_owned).collect())
             }
@@ -455,7 +471,10 @@ impl<'a> ScalarRef<'a> {
                         .collect(),
                 )
             }
-            ScalarRef::Bitmap(_) | ScalarRef::Variant(_) => Domain::Undefined,
+            ScalarRef::Bitmap(_)
+            | ScalarRef::Variant(_)
+            | ScalarRef::Geometry(_)
+            | ScalarRef::Binary(_) => Domain::Undefined,
         }
     }
 
@@ -481,6 +500,8 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::Array(col) => col.memory_size(),
             ScalarRef::Map(col) => col.memory_size(),
             ScalarRef::Bitmap(b) => b.len(),
+            ScalarRef::Geometry(b) => b.len(),
+            ScalarRef::Binary(b) => b.len(),
             ScalarRef::Tuple(scalars) => scalars.iter().map(|s| s.memory_size()).sum(),
             ScalarRef::Variant(buf) => buf.len(),
         }
@@ -508,6 +529,8 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::Array(array) => DataType::Array(Box::new(array.data_type())),
             ScalarRef::Map(col) => DataType::Map(Box::new(col.data_type())),
             ScalarRef::Bitmap(_) => DataType::Bitmap,
+            ScalarRef::Geometry(_) => DataType::Geometry,
+            ScalarRef::Binary(_) => DataType::Binary,
             ScalarRef::Tuple(fields) => {
                 let inner = fields
                     .iter()
@@ -543,6 +566,8 @@ impl PartialOrd for Scalar {
             (Scalar::Variant(v1), Scalar::Variant(v2)) => {
                 jsonb::compare(v1.as_slice(), v2.as_slice()).ok()
             }
+            (Scalar::Geometry(g1), Scalar::Geometry(g2)) => g1.partial_cmp(g2),
+            (Scalar::Binary(b1), Scalar::Binary(b2)) => b1.partial_cmp(b2),
             _ => None,
         }
     }
@@ -581,6 +606,8 @@ impl PartialOrd for ScalarRef<'_> {
             }
             (ScalarRef::Tuple(t1), ScalarRef::Tuple(t2)) => t1.partial_cmp(t2),
             (ScalarRef::Variant(v1), ScalarRef::Variant(v2)) => jsonb::compare(v1, v2).ok(),
+            (ScalarRef::Geometry(g1), ScalarRef::Geometry(g2)) => g1.partial_cmp(g2),
+            (ScalarRef::Binary(b1), ScalarRef::Binary(b2)) => b1.partial_cmp(b2),
             _ => None,
         }
     }
@@ -625,6 +652,8 @@ impl Hash for ScalarRef<'_> {
                 str.hash(state);
             }
             ScalarRef::Bitmap(v) => v.hash(state),
+            ScalarRef::Geometry(v) => v.hash(state),
+            ScalarRef::Binary(v) => v.hash(state),
             ScalarRef::Tuple(v) => {
                 v.hash(state);
             }
@@ -673,6 +702,10 @@ impl PartialOrd for Column {
             (Column::Variant(col1), Column::Variant(col2)) => col1
                 .iter()
                 .partial_cmp_by(col2.iter(), |v1, v2| jsonb::compare(v1, v2).ok()),
+            (Column::Geometry(col1), Column::Geometry(col2)) => {
+                col1.iter().partial_cmp(col2.iter())
+            }
+            (Column::Binary(col1), Column::Binary(col2)) => col1.iter().partial_cmp(col2.iter()),
             _ => None,
         }
     }
@@ -689,6 +722,8 @@ pub const ARROW_EXT_TYPE_EMPTY_ARRAY: &str = "EmptyArray";
 pub const ARROW_EXT_TYPE_EMPTY_MAP: &str = "EmptyMap";
 pub const ARROW_EXT_TYPE_VARIANT: &str = "Variant";
 pub const ARROW_EXT_TYPE_BITMAP: &str = "Bitmap";
+pub const ARROW_EXT_TYPE_GEOMETRY: &str = "Geometry";
+pub const ARROW_EXT_TYPE_BINARY: &str = "Binary";
 
 impl Column {
     pub fn len(&self) -> usize {
@@ -705,6 +740,8 @@ impl Column {
             Column::Array(col) => col.len(),
             Column::Map(col) => col.len(),
             Column::Bitmap(col) => col.len(),
+            Column::Geometry(col) => col.len(),
+            Column::Binary(col) => col.len(),
             Column::Nullable(col) => col.len(),
             Column::Tuple(fields) => fields[0].len(),
             Column::Variant(col) => col.len(),
@@ -725,6 +762,8 @@ impl Column {
             Column::Array(col) => Some(ScalarRef::Array(col.index(index)?)),
             Column::Map(col) => Some(ScalarRef::Map(col.index(index)?)),
             Column::Bitmap(col) => Some(ScalarRef::Bitmap(col.index(index)?)),
+            Column::Geometry(col) => Some(ScalarRef::Geometry(col.index(index)?)),
+            Column::Binary(col) => Some(ScalarRef::Binary(col.index(index)?)),
             Column::Nullable(col) => Some(col.index(index)?.unwrap_or(ScalarRef::Null)),
             Column::Tuple(fields) => Some(ScalarRef::Tuple(
                 fields
@@ -753,6 +792,8 @@ impl Column {
             Column::Array(col) => ScalarRef::Array(col.index_unchecked(index)),
             Column::Map(col) => ScalarRef::Map(col.index_unchecked(index)),
             Column::Bitmap(col) => ScalarRef::Bitmap(col.index_unchecked(index)),
+            Column::Geometry(col) => ScalarRef::Geometry(col.index_unchecked(index)),
+            Column::Binary(col) => ScalarRef::Binary(col.index_unchecked(index)),
             Column::Nullable(col) => col.index_unchecked(index).unwrap_or(ScalarRef::Null),
             Column::Tuple(fields) => ScalarRef::Tuple(
                 fields
@@ -802,6 +843,8 @@ impl Column {
             Column::Array(col) => Column::Array(Box::new(col.slice(range))),
             Column::Map(col) => Column::Map(Box::new(col.slice(range))),
             Column::Bitmap(col) => Column::Bitmap(col.slice(range)),
+            Column::Geometry(col) => Column::Geometry(col.slice(range)),
+            Column::Binary(col) => Column::Binary(col.slice(range)),
             Column::Nullable(col) => Column::Nullable(Box::new(col.slice(range))),
             Column::Tuple(fields) => Column::Tuple(
                 fields
@@ -890,7 +933,9 @@ impl Column {
                 let domains = fields.iter().map(|col| col.domain()).collect::<Vec<_>>();
                 Domain::Tuple(domains)
             }
-            Column::Bitmap(_) | Column::Variant(_) => Domain::Undefined,
+            Column::Bitmap(_) | Column::Variant(_) | Column::Geometry(_) | Column::Binary(_) => {
+                Domain::Undefined
+            }
         }
     }
 
@@ -919,6 +964,8 @@ impl Column {
                 DataType::Map(Box::new(inner))
             }
             Column::Bitmap(_) => DataType::Bitmap,
+            Column::Geometry(_) => DataType::Geometry,
+            Column::Binary(_) => DataType::Binary,
             Column::Nullable(inner) => {
                 let inner = inner.column.data_type();
                 inner.wrap_nullable()
@@ -1128,7 +1175,7 @@ impl Column {
                     .unwrap(),
                 )
             }
-            Column::Bitmap(col) => {
+            Column::Bitmap(col) | Column::Geometry(col) | Column::Binary(col) => {
                 let offsets: Buffer<i64> =
                     col.offsets.iter().map(|offset| *offset as i64).collect();
                 Box::new(
@@ -1330,10 +1377,14 @@ impl Column {
                 let offsets = arrow_col.offsets().clone().into_inner();
 
                 let offsets = unsafe { std::mem::transmute::<Buffer<i64>, Buffer<u64>>(offsets) };
-                Column::String(StringColumn {
+                let col = StringColumn {
                     data: arrow_col.values().clone(),
                     offsets,
-                })
+                };
+                match data_type {
+                    DataType::Binary => Column::Binary(col),
+                    _ => Column::String(col),
+                }
             }
             // TODO: deprecate it and use LargeBinary instead
             ArrowDataType::Binary => {
@@ -1348,10 +1399,14 @@ impl Column {
                     .map(|x| *x as u64)
                     .collect::<Vec<_>>();
 
-                Column::String(StringColumn {
+                let col = StringColumn {
                     data: arrow_col.values().clone(),
                     offsets: offsets.into(),
-                })
+                };
+                match data_type {
+                    DataType::Binary => Column::Binary(col),
+                    _ => Column::String(col),
+                }
             }
             // TODO: deprecate it and use LargeBinary instead
             ArrowDataType::Utf8 => {
@@ -1556,6 +1611,32 @@ impl Column {
                     offsets,
                 })
             }
+            ArrowDataType::Extension(name, _, None) if name == ARROW_EXT_TYPE_GEOMETRY => {
+                let arrow_col = arrow_col
+                    .as_any()
+                    .downcast_ref::<common_arrow::arrow::array::BinaryArray<i64>>()
+                    .expect("fail to read from arrow: array should be `BinaryArray<i64>`");
+                let offsets = arrow_col.offsets().clone().into_inner();
+
+                let offsets = unsafe { std::mem::transmute::<Buffer<i64>, Buffer<u64>>(offsets) };
+                Column::Geometry(StringColumn {
+                    data: arrow_col.values().clone(),
+                    offsets,
+                })
+            }
+            ArrowDataType::Extension(name, _, None) if name == ARROW_EXT_TYPE_BINARY => {
+                let arrow_col = arrow_col
+                    .as_any()
+                    .downcast_ref::<common_arrow::arrow::array::BinaryArray<i64>>()
+                    .expect("fail to read from arrow: array should be `BinaryArray<i64>`");
+                let offsets = arrow_col.offsets().clone().into_inner();
+
+                let offsets = unsafe { std::mem::transmute::<Buffer<i64>, Buffer<u64>>(offsets) };
+                Column::Binary(StringColumn {
+                    data: arrow_col.values().clone(),
+                    offsets,
+                })
+            }
             ty => unimplemented!("unsupported arrow type {ty:?}"),
         };
 
@@ -1666,6 +1747,14 @@ impl Column {
                     .expect("failed serialize roaring treemap");
                 buf
             })),
+            DataType::Binary => BinaryType::from_data((0..len).map(|_| {
+                let mut rng = SmallRng::from_entropy();
+                (0..5).map(|_| rng.gen::<u8>()).collect::<Vec<_>>()
+            })),
+            DataType::Geometry => GeometryType::from_data((0..len).map(|_| {
+                let mut rng = SmallRng::from_entropy();
+                geometry_to_wkb(&geo::Geometry::Point(geo::Point::new(rng.gen(), rng.gen())))
+            })),
             DataType::Tuple(fields) => {
                 let fields = fields
                     .iter()
@@ -1777,6 +1866,8 @@ impl Column {
             Column::Array(col) => col.values.memory_size() + col.offsets.len() * 8,
             Column::Map(col) => col.values.memory_size() + col.offsets.len() * 8,
             Column::Bitmap(col) => col.data.len() + col.offsets.len() * 8,
+            Column::Geometry(col) => col.data.len() + col.offsets.len() * 8,
+            Column::Binary(col) => col.data.len() + col.offsets.len() * 8,
             Column::Nullable(c) => c.column.memory_size() + c.validity.as_slice().0.len(),
             Column::Tuple(fields) => fields.iter().map(|f| f.memory_size()).sum(),
             Column::Variant(col) => col.data.len() + col.offsets.len() * 8,
@@ -1856,6 +1947,8 @@ impl ColumnBuilder {
                 ColumnBuilder::Map(Box::new(ArrayColumnBuilder::from_column(col)))
             }
             Column::Bitmap(col) => ColumnBuilder::Bitmap(StringColumnBuilder::from_column(col)),
+            Column::Geometry(col) => ColumnBuilder::Geometry(StringColumnBuilder::from_column(col)),
+            Column::Binary(col) => ColumnBuilder::Binary(StringColumnBuilder::from_column(col)),
             Column::Nullable(box col) => {
                 ColumnBuilder::Nullable(Box::new(NullableColumnBuilder::from_column(col)))
             }
@@ -1920,6 +2013,8 @@ impl ColumnBuilder {
                     .expect("failed to serialize bitmap");
                 ColumnBuilder::Bitmap(StringColumnBuilder::repeat(&buf, n))
             }
+            ScalarRef::Geometry(g) => ColumnBuilder::Geometry(StringColumnBuilder::repeat(g, n)),
+            ScalarRef::Binary(b) => ColumnBuilder::Binary(StringColumnBuilder::repeat(b, n)),
             ScalarRef::Tuple(fields) => {
                 let fields_ty = match data_type {
                     DataType::Tuple(fields_ty) => fields_ty,
@@ -1951,12 +2046,45 @@ impl ColumnBuilder {
             ColumnBuilder::Array(builder) => builder.len(),
             ColumnBuilder::Map(builder) => builder.len(),
             ColumnBuilder::Bitmap(builder) => builder.len(),
+            ColumnBuilder::Geometry(builder) => builder.len(),
+            ColumnBuilder::Binary(builder) => builder.len(),
             ColumnBuilder::Nullable(builder) => builder.len(),
             ColumnBuilder::Tuple(fields) => fields[0].len(),
             ColumnBuilder::Variant(builder) => builder.len(),
         }
     }
 
+    /// Reserves capacity for at least `additional` more rows.
+    ///
+    /// The variable-length data of the string-like columns and the items of the
+    /// array-like columns are not reserved, as their sizes are unknown.
+    pub fn reserve(&mut self, additional: usize) {
+        match self {
+            ColumnBuilder::Null { .. }
+            | ColumnBuilder::EmptyArray { .. }
+            | ColumnBuilder::EmptyMap { .. } => {}
+            ColumnBuilder::Number(builder) => builder.reserve(additional),
+            ColumnBuilder::Decimal(builder) => builder.reserve(additional),
+            ColumnBuilder::Boolean(builder) => builder.reserve(additional),
+            ColumnBuilder::String(builder)
+            | ColumnBuilder::Bitmap(builder)
+            | ColumnBuilder::Geometry(builder)
+            | ColumnBuilder::Binary(builder)
+            | ColumnBuilder::Variant(builder) => builder.reserve(additional, 0),
+            ColumnBuilder::Timestamp(builder) => builder.reserve(additional),
+            ColumnBuilder::Date(builder) => builder.reserve(additional),
+            ColumnBuilder::Array(builder) | ColumnBuilder::Map(builder) => {
+                builder.reserve(additional)
+            }
+            ColumnBuilder::Nullable(builder) => builder.reserve(additional),
+            ColumnBuilder::Tuple(fields) => {
+                for field in fields {
+                    field.reserve(additional);
+                }
+            }
+        }
+    }
+
     pub fn memory_size(&self) -> usize {
         match self {
             ColumnBuilder::Null { .. } => std::mem::size_of::<usize>(),
@@ -1985,6 +2113,8 @@ impl ColumnBuilder {
             ColumnBuilder::Array(col) => col.builder.memory_size() + col.offsets.len() * 8,
             ColumnBuilder::Map(col) => col.builder.memory_size() + col.offsets.len() * 8,
             ColumnBuilder::Bitmap(col) => col.data.len() + col.offsets.len() * 8,
+            ColumnBuilder::Geometry(col) => col.data.len() + col.offsets.len() * 8,
+            ColumnBuilder::Binary(col) => col.data.len() + col.offsets.len() * 8,
             ColumnBuilder::Nullable(c) => c.builder.memory_size() + c.validity.as_slice().len(),
             ColumnBuilder::Tuple(fields) => fields.iter().map(|f| f.memory_size()).sum(),
             ColumnBuilder::Variant(col) => col.data.len() + col.offsets.len() * 8,
@@ -2016,6 +2146,8 @@ impl ColumnBuilder {
                 DataType::Map(Box::new(inner))
             }
             ColumnBuilder::Bitmap(_) => DataType::Bitmap,
+            ColumnBuilder::Geometry(_) => DataType::Geometry,
+            ColumnBuilder::Binary(_) => DataType::Binary,
             ColumnBuilder::Nullable(col) => DataType::Nullable(Box::new(col.builder.data_type())),
             ColumnBuilder::Tuple(fields) => {
                 DataType::Tuple(fields.iter().map(|f| f.data_type()).collect::<Vec<_>>())
@@ -2087,6 +2219,14 @@ impl ColumnBuilder {
                 let data_capacity = if enable_datasize_hint { 0 } else { capacity };
                 ColumnBuilder::Bitmap(StringColumnBuilder::with_capacity(capacity, data_capacity))
             }
+            DataType::Geometry => {
+                let data_capacity = if enable_datasize_hint { 0 } else { capacity };
+                ColumnBuilder::Geometry(StringColumnBuilder::with_capacity(capacity, data_capacity))
+            }
+            DataType::Binary => {
+                let data_capacity = if enable_datasize_hint { 0 } else { capacity };
+                ColumnBuilder::Binary(StringColumnBuilder::with_capacity(capacity, data_capacity))
+            }
             DataType::Variant => {
                 let data_capacity = if enable_datasize_hint { 0 } else { capacity };
                 ColumnBuilder::Variant(StringColumnBuilder::with_capacity(capacity, data_capacity))
@@ -2123,6 +2263,14 @@ impl ColumnBuilder {
                 builder.put_slice(value);
                 builder.commit_row();
             }
+            (ColumnBuilder::Geometry(builder), ScalarRef::Geometry(value)) => {
+                builder.put_slice(value);
+                builder.commit_row();
+            }
+            (ColumnBuilder::Binary(builder), ScalarRef::Binary(value)) => {
+                builder.put_slice(value);
+                builder.commit_row();
+            }
             (ColumnBuilder::Nullable(builder), ScalarRef::Null) => {
                 builder.push_null();
             }
@@ -2167,6 +2315,11 @@ impl ColumnBuilder {
                 builder.put_slice(JSONB_NULL);
                 builder.commit_row();
             }
+            ColumnBuilder::Geometry(builder) => {
+                builder.put_slice(EMPTY_GEOMETRY_WKB);
+                builder.commit_row();
+            }
+            ColumnBuilder::Binary(builder) => builder.commit_row(),
         }
     }
 
@@ -2193,7 +2346,9 @@ impl ColumnBuilder {
             }
             ColumnBuilder::String(builder)
             | ColumnBuilder::Variant(builder)
-            | ColumnBuilder::Bitmap(builder) => {
+            | ColumnBuilder::Bitmap(builder)
+            | ColumnBuilder::Geometry(builder)
+            | ColumnBuilder::Binary(builder) => {
                 let offset: u64 = reader.read_uvarint()?;
                 builder.data.resize(offset as usize + builder.data.len(), 0);
                 let last = *builder.offsets.last().unwrap() as usize;
@@ -2279,7 +2434,9 @@ impl ColumnBuilder {
             }
             ColumnBuilder::String(builder)
             | ColumnBuilder::Variant(builder)
-            | ColumnBuilder::Bitmap(builder) => {
+            | ColumnBuilder::Bitmap(builder)
+            | ColumnBuilder::Geometry(builder)
+            | ColumnBuilder::Binary(builder) => {
                 for row in 0..rows {
                     let reader = &reader[step * row..];
                     builder.put_slice(reader);
@@ -2376,6 +2533,8 @@ impl ColumnBuilder {
             ColumnBuilder::Array(builder) => builder.pop().map(Scalar::Array),
             ColumnBuilder::Map(builder) => builder.pop().map(Scalar::Map),
             ColumnBuilder::Bitmap(builder) => builder.pop().map(Scalar::Bitmap),
+            ColumnBuilder::Geometry(builder) => builder.pop().map(Scalar::Geometry),
+            ColumnBuilder::Binary(builder) => builder.pop().map(Scalar::Binary),
             ColumnBuilder::Nullable(builder) => Some(builder.pop()?.unwrap_or(Scalar::Null)),
             ColumnBuilder::Tuple(fields) => {
                 if fields[0].len() > 0 {
@@ -2434,6 +2593,12 @@ impl ColumnBuilder {
             (ColumnBuilder::Bitmap(builder), Column::Bitmap(other)) => {
                 builder.append_column(other);
             }
+            (ColumnBuilder::Geometry(builder), Column::Geometry(other)) => {
+                builder.append_column(other);
+            }
+            (ColumnBuilder::Binary(builder), Column::Binary(other)) => {
+                builder.append_column(other);
+            }
             (ColumnBuilder::Nullable(builder), Column::Nullable(other)) => {
                 builder.append_column(other);
             }
@@ -2465,6 +2630,8 @@ impl ColumnBuilder {
             ColumnBuilder::Array(builder) => Column::Array(Box::new(builder.build())),
             ColumnBuilder::Map(builder) => Column::Map(Box::new(builder.build())),
             ColumnBuilder::Bitmap(builder) => Column::Bitmap(builder.build()),
+            ColumnBuilder::Geometry(builder) => Column::Geometry(builder.build()),
+            ColumnBuilder::Binary(builder) => Column::Binary(builder.build()),
             ColumnBuilder::Nullable(builder) => Column::Nullable(Box::new(builder.build())),
             ColumnBuilder::Tuple(fields) => {
                 assert!(fields.iter().map(|field| field.len()).all_equal());
@@ -2489,6 +2656,8 @@ impl ColumnBuilder {
             ColumnBuilder::Array(builder) => Scalar::Array(builder.build_scalar()),
             ColumnBuilder::Map(builder) => Scalar::Map(builder.build_scalar()),
             ColumnBuilder::Bitmap(builder) => Scalar::Bitmap(builder.build_scalar()),
+            ColumnBuilder::Geometry(builder) => Scalar::Geometry(builder.build_scalar()),
+            ColumnBuilder::Binary(builder) => Scalar::Binary(builder.build_scalar()),
             ColumnBuilder::Nullable(builder) => builder.build_scalar().unwrap_or(Scalar::Null),
             ColumnBuilder::Tuple(fields) => Scalar::Tuple(
                 fields
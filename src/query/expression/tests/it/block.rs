@@ -1,5 +1,11 @@
+use common_expression::types::number::NumberColumnBuilder;
+use common_expression::types::number::NumberScalar;
 use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
 use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
 
 use crate::common::new_block;
 
@@ -17,3 +23,47 @@ fn test_split_block() {
         .collect::<Vec<_>>();
     assert_eq!(sizes, vec![3, 3, 4]);
 }
+
+#[test]
+fn test_column_builder_reserve() {
+    let ty = DataType::Tuple(vec![
+        DataType::Number(NumberDataType::Int32).wrap_nullable(),
+        DataType::String,
+    ]);
+    let n = 1000;
+
+    let mut builder = ColumnBuilder::with_capacity(&ty, 0);
+    builder.reserve(n);
+    match &builder {
+        ColumnBuilder::Tuple(fields) => match (&fields[0], &fields[1]) {
+            (ColumnBuilder::Nullable(nullable), ColumnBuilder::String(string)) => {
+                match &nullable.builder {
+                    ColumnBuilder::Number(NumberColumnBuilder::Int32(values)) => {
+                        assert!(values.capacity() >= n)
+                    }
+                    _ => unreachable!(),
+                }
+                assert!(nullable.validity.capacity() >= n);
+                assert!(string.offsets.capacity() > n);
+            }
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+
+    let mut expected = ColumnBuilder::with_capacity(&ty, n);
+    for i in 0..n {
+        let item = Scalar::Tuple(vec![
+            if i % 3 == 0 {
+                Scalar::Null
+            } else {
+                Scalar::Number(NumberScalar::Int32(i as i32))
+            },
+            Scalar::String(i.to_string().into_bytes()),
+        ]);
+        builder.push(item.as_ref());
+        expected.push(item.as_ref());
+    }
+    assert_eq!(builder.len(), n);
+    assert_eq!(builder.build(), expected.build());
+}
@@ -14,10 +14,13 @@
 
 use std::collections::BTreeMap;
 
+use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::datatypes::Field as ArrowField;
 use common_exception::Result;
 use common_expression::create_test_complex_schema;
 use common_expression::types::NumberDataType;
 use common_expression::ColumnId;
+use common_expression::ColumnOrigin;
 use common_expression::Scalar;
 use common_expression::TableDataType;
 use common_expression::TableField;
@@ -524,6 +527,48 @@ fn test_schema_modify_field() -> Result<()> {
     assert_eq!(schema.to_leaf_column_ids(), vec![0, 2, 6]);
     assert!(schema.column_id_of("s").is_err());
 
+    // rename column c
+    schema.rename_field("c", "c1")?;
+    assert!(schema.column_id_of("c").is_err());
+    assert_eq!(schema.column_id_of("c1").unwrap(), 2);
+    assert_eq!(schema.to_column_ids(), vec![0, 2, 6, 6, 6]);
+    assert_eq!(schema.to_leaf_column_ids(), vec![0, 2, 6]);
+    assert_eq!(schema.next_column_id(), 7);
+
+    // rename to an existing column
+    assert!(schema.rename_field("c1", "a").is_err());
+    // rename a not exist column
+    assert!(schema.rename_field("c", "c2").is_err());
+
+    // modify data type of column c1
+    let nullable_u64 =
+        TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64)));
+    schema.modify_field_type("c1", nullable_u64.clone())?;
+    let field = schema.field_with_name("c1")?;
+    assert_eq!(field.data_type(), &nullable_u64);
+    assert_eq!(field.column_id(), 7);
+    assert_eq!(field.origins(), &[ColumnOrigin {
+        column_id: 2,
+        data_type: TableDataType::Number(NumberDataType::UInt64),
+    }]);
+    assert_eq!(schema.is_column_deleted(2), false);
+    assert_eq!(schema.is_column_deleted(7), false);
+    assert_eq!(schema.to_leaf_column_ids(), vec![0, 7, 6]);
+    assert_eq!(schema.next_column_id(), 8);
+
+    // can not be losslessly cast
+    assert!(
+        schema
+            .modify_field_type("a", TableDataType::Number(NumberDataType::UInt8))
+            .is_err()
+    );
+    assert!(
+        schema
+            .modify_field_type("c1", TableDataType::Number(NumberDataType::UInt64))
+            .is_err()
+    );
+    assert_eq!(schema.next_column_id(), 8);
+
     Ok(())
 }
 
@@ -537,32 +582,76 @@ fn test_leaf_columns_of() -> Result<()> {
                 TableDataType::Tuple {
                     fields_name: vec!["b11".to_string(), "b12".to_string()],
                     fields_type: vec![TableDataType::Boolean, TableDataType::String],
-                }
+                },
                 TableDataType::Number(NumberDataType::UInt64),
             ],
-        }
-        TableField::new("c", TableDataType::Array(Box::new(TableDataType::Number(NumberDataType::UInt64)))),
-        TableField::new("d", TableDataType::Map(Box::new(
-            TableDataType::Tuple {
+        }),
+        TableField::new(
+            "c",
+            TableDataType::Array(Box::new(TableDataType::Number(NumberDataType::UInt64))),
+        ),
+        TableField::new(
+            "d",
+            TableDataType::Map(Box::new(TableDataType::Tuple {
                 fields_name: vec!["key".to_string(), "value".to_string()],
                 fields_type: vec![TableDataType::String, TableDataType::String],
-            }
-        )),
+            })),
+        ),
         TableField::new("e", TableDataType::String),
     ];
-    let mut schema = TableSchema::new(fields);
+    let schema = TableSchema::new(fields);
+
+    let leaf_columns_of = |name: &str| schema.leaf_columns_of(&name.to_string());
+    assert_eq!(leaf_columns_of("a"), vec![0]);
+    assert_eq!(leaf_columns_of("b"), vec![1, 2, 3]);
+    assert_eq!(leaf_columns_of("b:b1"), vec![1, 2]);
+    assert_eq!(leaf_columns_of("b:1"), vec![1, 2]);
+    assert_eq!(leaf_columns_of("b:b1:b11"), vec![1]);
+    assert_eq!(leaf_columns_of("b:1:1"), vec![1]);
+    assert_eq!(leaf_columns_of("b:b1:b12"), vec![2]);
+    assert_eq!(leaf_columns_of("b:1:2"), vec![2]);
+    assert_eq!(leaf_columns_of("b:b2"), vec![3]);
+    assert_eq!(leaf_columns_of("b:2"), vec![3]);
+    assert_eq!(leaf_columns_of("c"), vec![4]);
+    assert_eq!(leaf_columns_of("d"), vec![5, 6]);
+    assert_eq!(leaf_columns_of("e"), vec![7]);
 
-    assert_eq!(schema.leaf_columns_of("a"), vec![0]);
-    assert_eq!(schema.leaf_columns_of("b"), vec![1,2,3]);
-    assert_eq!(schema.leaf_columns_of("b:b1"), vec![1,2]);
-    assert_eq!(schema.leaf_columns_of("b:1"), vec![1,2]);
-    assert_eq!(schema.leaf_columns_of("b:b1:b11"), vec![1]);
-    assert_eq!(schema.leaf_columns_of("b:1:1"), vec![1]);
-    assert_eq!(schema.leaf_columns_of("b:b1:b12"), vec![2]);
-    assert_eq!(schema.leaf_columns_of("b:1:2"), vec![2]);
-    assert_eq!(schema.leaf_columns_of("b:b2"), vec![3]);
-    assert_eq!(schema.leaf_columns_of("b:2"), vec![3]);
-    assert_eq!(schema.leaf_columns_of("c"), vec![4]);
-    assert_eq!(schema.leaf_columns_of("d"), vec![5,6]);
-    assert_eq!(schema.leaf_columns_of("e"), vec![7]);
+    Ok(())
+}
+
+#[test]
+fn test_map_type_from_arrow() -> Result<()> {
+    let map_ty = TableDataType::Map(Box::new(TableDataType::Tuple {
+        fields_name: vec!["key".to_string(), "value".to_string()],
+        fields_type: vec![
+            TableDataType::Number(NumberDataType::Int64),
+            TableDataType::Nullable(Box::new(TableDataType::String)),
+        ],
+    }));
+    let schema = TableSchema::new(vec![TableField::new("m", map_ty.clone())]);
+    let schema = TableSchema::from(&schema.to_arrow());
+    assert_eq!(schema.field(0).data_type(), &map_ty);
+
+    // maps written by other parquet writers
+    let entries = ArrowField::new(
+        "key_value",
+        ArrowDataType::Struct(vec![
+            ArrowField::new("k", ArrowDataType::Date32, false),
+            ArrowField::new("v", ArrowDataType::Int32, true),
+        ]),
+        true,
+    );
+    let field = ArrowField::new("m", ArrowDataType::Map(Box::new(entries), false), true);
+    let expected = TableDataType::Nullable(Box::new(TableDataType::Map(Box::new(
+        TableDataType::Tuple {
+            fields_name: vec!["key".to_string(), "value".to_string()],
+            fields_type: vec![
+                TableDataType::Date,
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::Int32))),
+            ],
+        },
+    ))));
+    assert_eq!(TableDataType::from(&field), expected);
+
+    Ok(())
 }
@@ -23,6 +23,8 @@ const SUFFIX_WITH_NAMES: &str = "withnames";
 const SUFFIX_COMPACT: &str = "compact";
 const SUFFIX_STRINGS: &str = "strings";
 const SUFFIX_EACHROW: &str = "eachrow";
+const ROW_BINARY: &str = "rowbinary";
+const NATIVE: &str = "native";
 
 #[derive(Default, Clone)]
 pub struct ClickhouseTypeSuffixJson {
@@ -93,3 +95,35 @@ impl ClickhouseFormatType {
         })
     }
 }
+
+/// The binary formats of ClickHouse, only accepted as the data of `INSERT ... FORMAT`
+/// from the ClickHouse handler.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ClickhouseBinaryFormat {
+    /// Rows of values, after the names (and the types) of the columns if `headers` > 0.
+    RowBinary { headers: usize },
+    /// Blocks of columns, each with its name and type.
+    Native,
+}
+
+impl ClickhouseBinaryFormat {
+    pub fn parse(name: &str) -> Option<ClickhouseBinaryFormat> {
+        let lower = name.to_lowercase();
+        if lower == NATIVE {
+            return Some(ClickhouseBinaryFormat::Native);
+        }
+
+        let (base, ok) = try_remove_suffix(&lower, SUFFIX_WITH_NAMES_AND_TYPES);
+        let (base, headers) = match ok {
+            true => (base, 2),
+            false => match try_remove_suffix(base, SUFFIX_WITH_NAMES) {
+                (base, true) => (base, 1),
+                (base, false) => (base, 0),
+            },
+        };
+        match base == ROW_BINARY {
+            true => Some(ClickhouseBinaryFormat::RowBinary { headers }),
+            false => None,
+        }
+    }
+}
@@ -29,6 +29,7 @@ use common_expression::types::date::check_date;
 use common_expression::types::decimal::Decimal;
 use common_expression::types::decimal::DecimalColumnBuilder;
 use common_expression::types::decimal::DecimalSize;
+use common_expression::types::geometry::wkt_to_wkb;
 use common_expression::types::nullable::NullableColumnBuilder;
 use common_expression::types::number::Number;
 use common_expression::types::string::StringColumnBuilder;
@@ -137,6 +138,8 @@ impl FastFieldDecoderValues {
             ColumnBuilder::Bitmap(_) => Err(ErrorCode::Unimplemented("not implement")),
             ColumnBuilder::Tuple(fields) => self.read_tuple(fields, reader, positions),
             ColumnBuilder::Variant(c) => self.read_variant(c, reader, positions),
+            ColumnBuilder::Geometry(c) => self.read_geometry(c, reader, positions),
+            ColumnBuilder::Binary(c) => self.read_binary(c, reader, positions),
             _ => unimplemented!(),
         }
     }
@@ -400,6 +403,32 @@ impl FastFieldDecoderValues {
         Ok(())
     }
 
+    fn read_geometry<R: AsRef<[u8]>>(
+        &self,
+        column: &mut StringColumnBuilder,
+        reader: &mut Cursor<R>,
+        positions: &mut VecDeque<usize>,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, positions)?;
+        let wkt = std::str::from_utf8(&buf).map_err(|e| ErrorCode::BadBytes(e.to_string()))?;
+        column.put_slice(&wkt_to_wkb(wkt)?);
+        column.commit_row();
+        Ok(())
+    }
+
+    /// Same as `CAST(<string> AS BINARY)`, the bytes of the string are taken as is.
+    fn read_binary<R: AsRef<[u8]>>(
+        &self,
+        column: &mut StringColumnBuilder,
+        reader: &mut Cursor<R>,
+        positions: &mut VecDeque<usize>,
+    ) -> Result<()> {
+        self.read_string_inner(reader, &mut column.data, positions)?;
+        column.commit_row();
+        Ok(())
+    }
+
     fn read_variant<R: AsRef<[u8]>>(
         &self,
         column: &mut StringColumnBuilder,
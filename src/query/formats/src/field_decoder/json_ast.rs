@@ -89,6 +89,7 @@ impl FieldJsonAstDecoder {
             ColumnBuilder::Map(c) => self.read_map(c, value),
             ColumnBuilder::Tuple(fields) => self.read_tuple(fields, value),
             ColumnBuilder::Variant(c) => self.read_variant(c, value),
+            ColumnBuilder::Binary(c) => self.read_binary(c, value),
             _ => unimplemented!(),
         }
     }
@@ -188,6 +189,20 @@ impl FieldJsonAstDecoder {
         }
     }
 
+    fn read_binary(&self, column: &mut StringColumnBuilder, value: &Value) -> Result<()> {
+        match value {
+            Value::String(s) => {
+                let data = hex::decode(s).map_err(|e| ErrorCode::BadBytes(e.to_string()))?;
+                column.put_slice(&data);
+                column.commit_row();
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes(
+                "Incorrect json value, must be hex string",
+            )),
+        }
+    }
+
     fn read_date(&self, column: &mut Vec<i32>, value: &Value) -> Result<()> {
         match value {
             Value::String(v) => {
@@ -26,6 +26,7 @@ use common_expression::types::date::check_date;
 use common_expression::types::decimal::Decimal;
 use common_expression::types::decimal::DecimalColumnBuilder;
 use common_expression::types::decimal::DecimalSize;
+use common_expression::types::geometry::wkt_to_wkb;
 use common_expression::types::nullable::NullableColumnBuilder;
 use common_expression::types::number::Number;
 use common_expression::types::string::StringColumnBuilder;
@@ -91,6 +92,8 @@ pub trait FieldDecoderRowBased: FieldDecoder {
             ColumnBuilder::Bitmap(c) => self.read_string(c, reader, raw),
             ColumnBuilder::Tuple(fields) => self.read_tuple(fields, reader, raw),
             ColumnBuilder::Variant(c) => self.read_variant(c, reader, raw),
+            ColumnBuilder::Geometry(c) => self.read_geometry(c, reader, raw),
+            ColumnBuilder::Binary(c) => self.read_binary(c, reader, raw),
             _ => unimplemented!(),
         }
     }
@@ -250,6 +253,34 @@ pub trait FieldDecoderRowBased: FieldDecoder {
         Ok(())
     }
 
+    fn read_geometry<R: AsRef<[u8]>>(
+        &self,
+        column: &mut StringColumnBuilder,
+        reader: &mut Cursor<R>,
+        raw: bool,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, raw)?;
+        let wkt = std::str::from_utf8(&buf).map_err(|e| ErrorCode::BadBytes(e.to_string()))?;
+        column.put_slice(&wkt_to_wkb(wkt)?);
+        column.commit_row();
+        Ok(())
+    }
+
+    fn read_binary<R: AsRef<[u8]>>(
+        &self,
+        column: &mut StringColumnBuilder,
+        reader: &mut Cursor<R>,
+        raw: bool,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, raw)?;
+        let data = hex::decode(&buf).map_err(|e| ErrorCode::BadBytes(e.to_string()))?;
+        column.put_slice(&data);
+        column.commit_row();
+        Ok(())
+    }
+
     fn read_variant<R: AsRef<[u8]>>(
         &self,
         column: &mut StringColumnBuilder,
@@ -17,6 +17,7 @@ use common_arrow::arrow::buffer::Buffer;
 use common_expression::types::array::ArrayColumn;
 use common_expression::types::date::date_to_string;
 use common_expression::types::decimal::DecimalColumn;
+use common_expression::types::geometry::wkb_to_wkt;
 use common_expression::types::nullable::NullableColumn;
 use common_expression::types::number::NumberColumn;
 use common_expression::types::string::StringColumn;
@@ -66,6 +67,8 @@ pub trait FieldEncoderRowBased {
             Column::Bitmap(b) => self.write_string(b, row_index, out_buf, raw),
             Column::Tuple(fields) => self.write_tuple(fields, row_index, out_buf, raw),
             Column::Variant(c) => self.write_variant(c, row_index, out_buf, raw),
+            Column::Geometry(c) => self.write_geometry(c, row_index, out_buf, raw),
+            Column::Binary(c) => self.write_binary(c, row_index, out_buf, raw),
         }
     }
 
@@ -177,6 +180,29 @@ pub trait FieldEncoderRowBased {
         self.write_string_inner(s.as_bytes(), out_buf, raw);
     }
 
+    fn write_geometry(
+        &self,
+        column: &StringColumn,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        raw: bool,
+    ) {
+        let v = unsafe { column.index_unchecked(row_index) };
+        let s = wkb_to_wkt(v).unwrap_or_default();
+        self.write_string_inner(s.as_bytes(), out_buf, raw);
+    }
+
+    fn write_binary(
+        &self,
+        column: &StringColumn,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        raw: bool,
+    ) {
+        let v = unsafe { column.index_unchecked(row_index) };
+        self.write_string_inner(hex::encode_upper(v).as_bytes(), out_buf, raw);
+    }
+
     fn write_array<T: ValueType>(
         &self,
         column: &ArrayColumn<T>,
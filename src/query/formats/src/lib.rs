@@ -24,6 +24,7 @@ pub mod field_encoder;
 mod file_format_type;
 pub mod output_format;
 
+pub use clickhouse::ClickhouseBinaryFormat;
 pub use clickhouse::ClickhouseFormatType;
 pub use delimiter::RecordDelimiter;
 pub use field_decoder::*;
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_expression::date_helper::DateConverter;
+use common_expression::types::geometry::wkb_to_wkt;
 use common_expression::types::number::NumberScalar;
 use common_expression::DataBlock;
 use common_expression::ScalarRef;
@@ -128,6 +129,8 @@ fn scalar_to_json(s: ScalarRef<'_>, format: &FormatSettings) -> JsonValue {
                 .collect::<Vec<_>>();
             JsonValue::Array(data)
         }
+        ScalarRef::Geometry(g) => JsonValue::String(wkb_to_wkt(g).unwrap_or_default()),
+        ScalarRef::Binary(b) => JsonValue::String(hex::encode_upper(b)),
         ScalarRef::Tuple(x) => {
             let vals = x
                 .iter()
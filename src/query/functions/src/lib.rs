@@ -30,6 +30,7 @@ pub fn is_builtin_function(name: &str) -> bool {
     BUILTIN_FUNCTIONS.contains(name)
         || AggregateFunctionFactory::instance().contains(name)
         || GENERAL_WINDOW_FUNCTIONS.contains(&name)
+        || GENERAL_LAMBDA_FUNCTIONS.contains(&name)
 }
 
 #[ctor]
@@ -49,6 +50,13 @@ pub const GENERAL_WINDOW_FUNCTIONS: [&str; 11] = [
     "nth_value",
 ];
 
+pub const GENERAL_LAMBDA_FUNCTIONS: [&str; 4] = [
+    "array_transform",
+    "array_map",
+    "array_filter",
+    "array_reduce",
+];
+
 fn builtin_functions() -> FunctionRegistry {
     let mut registry = FunctionRegistry::empty();
 
@@ -71,6 +71,7 @@ use num_traits::AsPrimitive;
 use super::arithmetic_modulo::vectorize_modulo;
 use super::decimal::register_decimal_to_float32;
 use super::decimal::register_decimal_to_float64;
+use super::decimal::register_decimal_to_int;
 use crate::scalars::decimal::register_decimal_arithmetic;
 
 pub fn register(registry: &mut FunctionRegistry) {
@@ -601,13 +602,18 @@ pub fn register_number_to_number(registry: &mut FunctionRegistry) {
                     }
                 }),
                 NumberClass::Decimal128 => {
-                    // todo(youngsofun): add decimal try_cast and decimal to int
+                    // todo(youngsofun): add decimal try_cast to float
                     if matches!(dest_type, NumberDataType::Float32) {
                         register_decimal_to_float32(registry);
                     }
                     if matches!(dest_type, NumberDataType::Float64) {
                         register_decimal_to_float64(registry);
                     }
+                    with_number_mapped_type!(|DEST_TYPE| match dest_type {
+                        NumberDataType::DEST_TYPE => {
+                            register_decimal_to_int::<DEST_TYPE>(registry);
+                        }
+                    });
                 }
                 NumberClass::Decimal256 => {
                     // already registered in Decimal128 branch
@@ -0,0 +1,139 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use base64::engine::general_purpose;
+use base64::prelude::*;
+use common_expression::error_to_null;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::BinaryType;
+use common_expression::types::StringType;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::EvalContext;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use common_expression::Value;
+use common_expression::ValueRef;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // The bytes of the string are taken as is, it's how `CAST(<string> AS BINARY)` works.
+    registry.register_passthrough_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "to_binary",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<StringType, BinaryType>(|s, builder, _| {
+            builder.put_slice(s);
+            builder.commit_row();
+        }),
+    );
+
+    registry.register_combine_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "try_to_binary",
+        |_, _| FunctionDomain::Full,
+        error_to_null(vectorize_with_builder_1_arg::<StringType, BinaryType>(
+            |s, builder, _| {
+                builder.put_slice(s);
+                builder.commit_row();
+            },
+        )),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, BinaryType, _, _>(
+        "to_binary",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<StringType, StringType, BinaryType>(
+            |s, format, builder, ctx| {
+                let decoded = match format.to_ascii_lowercase().as_slice() {
+                    b"hex" => hex::decode(s).map_err(|e| e.to_string()),
+                    b"base64" => general_purpose::STANDARD
+                        .decode(s)
+                        .map_err(|e| e.to_string()),
+                    b"utf-8" | b"utf8" => Ok(s.to_vec()),
+                    _ => Err(format!(
+                        "unknown binary format '{}', expect 'hex', 'base64' or 'utf-8'",
+                        String::from_utf8_lossy(format)
+                    )),
+                };
+                match decoded {
+                    Ok(data) => builder.put_slice(&data),
+                    Err(e) => ctx.set_error(builder.len(), e),
+                }
+                builder.commit_row();
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::MayThrow,
+        eval_binary_to_string,
+    );
+
+    registry.register_combine_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "try_to_string",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_binary_to_string),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "to_hex",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<BinaryType, StringType>(|b, builder, _| {
+            let old_len = builder.data.len();
+            builder.data.resize(old_len + b.len() * 2, 0);
+            hex::encode_to_slice(b, &mut builder.data[old_len..]).unwrap();
+            builder.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "from_hex",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<StringType, BinaryType>(|s, builder, ctx| {
+            match hex::decode(s) {
+                Ok(data) => builder.put_slice(&data),
+                Err(e) => ctx.set_error(builder.len(), e.to_string()),
+            }
+            builder.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "to_base64",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<BinaryType, StringType>(|b, builder, _| {
+            base64::write::EncoderWriter::new(&mut builder.data, &general_purpose::STANDARD)
+                .write_all(b)
+                .unwrap();
+            builder.commit_row();
+        }),
+    );
+
+    registry.register_1_arg::<BinaryType, UInt64Type, _, _>(
+        "length",
+        |_, _| FunctionDomain::Full,
+        |b, _| b.len() as u64,
+    );
+}
+
+fn eval_binary_to_string(val: ValueRef<BinaryType>, ctx: &mut EvalContext) -> Value<StringType> {
+    vectorize_with_builder_1_arg::<BinaryType, StringType>(|b, builder, ctx| {
+        match std::str::from_utf8(b) {
+            Ok(s) => builder.put_str(s),
+            Err(e) => ctx.set_error(builder.len(), format!("invalid utf-8 binary: {e}")),
+        }
+        builder.commit_row();
+    })(val, ctx)
+}
@@ -22,6 +22,7 @@ use common_expression::types::string::StringDomain;
 use common_expression::types::AnyType;
 use common_expression::types::ArgType;
 use common_expression::types::ArrayType;
+use common_expression::types::BinaryType;
 use common_expression::types::BooleanType;
 use common_expression::types::DataType;
 use common_expression::types::DateType;
@@ -55,6 +56,7 @@ use crate::scalars::string_multi_args::regexp;
 pub fn register(registry: &mut FunctionRegistry) {
     register_variant_cmp(registry);
     register_string_cmp(registry);
+    register_binary_cmp(registry);
     register_date_cmp(registry);
     register_timestamp_cmp(registry);
     register_number_cmp(registry);
@@ -160,6 +162,39 @@ fn register_string_cmp(registry: &mut FunctionRegistry) {
     register_simple_domain_type_cmp!(registry, StringType);
 }
 
+fn register_binary_cmp(registry: &mut FunctionRegistry) {
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "eq",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs == rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "noteq",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs != rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "gt",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs > rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "gte",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs >= rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "lt",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs < rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "lte",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs <= rhs,
+    );
+}
+
 fn register_date_cmp(registry: &mut FunctionRegistry) {
     register_simple_domain_type_cmp!(registry, DateType);
 }
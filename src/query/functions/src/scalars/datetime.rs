@@ -34,7 +34,6 @@ use common_expression::types::number::UInt64Type;
 use common_expression::types::number::UInt8Type;
 use common_expression::types::string::StringDomain;
 use common_expression::types::timestamp::check_timestamp;
-use common_expression::types::timestamp::microseconds_to_days;
 use common_expression::types::timestamp::string_to_timestamp;
 use common_expression::types::timestamp::timestamp_to_string;
 use common_expression::types::timestamp::MICROS_IN_A_MILLI;
@@ -97,6 +96,10 @@ pub fn register(registry: &mut FunctionRegistry) {
 
     // [date | timestamp] +/- number
     register_timestamp_add_sub(registry);
+
+    // convert_timezone(string, timestamp)
+    // timestamp AT TIME ZONE string
+    register_convert_timezone(registry);
 }
 
 /// Check if timestamp is within range, and return the timestamp in micros.
@@ -211,22 +214,22 @@ fn register_string_to_timestamp(registry: &mut FunctionRegistry) {
 fn register_date_to_timestamp(registry: &mut FunctionRegistry) {
     registry.register_passthrough_nullable_1_arg::<DateType, TimestampType, _, _>(
         "to_timestamp",
-        |_, domain| {
+        |ctx, domain| {
             FunctionDomain::Domain(SimpleDomain {
-                min: domain.min as i64 * 24 * 3600 * 1000000,
-                max: domain.max as i64 * 24 * 3600 * 1000000,
+                min: ctx.tz.date_to_micros(domain.min),
+                max: ctx.tz.date_to_micros(domain.max),
             })
         },
         eval_date_to_timestamp,
     );
     registry.register_combine_nullable_1_arg::<DateType, TimestampType, _, _>(
         "try_to_timestamp",
-        |_, domain| {
+        |ctx, domain| {
             FunctionDomain::Domain(NullableDomain {
                 has_null: false,
                 value: Some(Box::new(SimpleDomain {
-                    min: domain.min as i64 * 24 * 3600 * 1000000,
-                    max: domain.max as i64 * 24 * 3600 * 1000000,
+                    min: ctx.tz.date_to_micros(domain.min),
+                    max: ctx.tz.date_to_micros(domain.max),
                 })),
             })
         },
@@ -237,9 +240,8 @@ fn register_date_to_timestamp(registry: &mut FunctionRegistry) {
         val: ValueRef<DateType>,
         ctx: &mut EvalContext,
     ) -> Value<TimestampType> {
-        vectorize_with_builder_1_arg::<DateType, TimestampType>(|val, output, _| {
-            let ts = (val as i64) * 24 * 3600 * MICROS_IN_A_SEC;
-            output.push(ts);
+        vectorize_with_builder_1_arg::<DateType, TimestampType>(|val, output, ctx| {
+            output.push(ctx.func_ctx.tz.date_to_micros(val));
         })(val, ctx)
     }
 }
@@ -313,22 +315,22 @@ fn register_string_to_date(registry: &mut FunctionRegistry) {
 fn register_timestamp_to_date(registry: &mut FunctionRegistry) {
     registry.register_passthrough_nullable_1_arg::<TimestampType, DateType, _, _>(
         "to_date",
-        |_, domain| {
+        |ctx, domain| {
             FunctionDomain::Domain(SimpleDomain {
-                min: (domain.min / 1000000 / 24 / 3600) as i32,
-                max: (domain.max / 1000000 / 24 / 3600) as i32,
+                min: ctx.tz.to_days(domain.min),
+                max: ctx.tz.to_days(domain.max),
             })
         },
         eval_timestamp_to_date,
     );
     registry.register_combine_nullable_1_arg::<TimestampType, DateType, _, _>(
         "try_to_date",
-        |_, domain| {
+        |ctx, domain| {
             FunctionDomain::Domain(NullableDomain {
                 has_null: false,
                 value: Some(Box::new(SimpleDomain {
-                    min: (domain.min / 1000000 / 24 / 3600) as i32,
-                    max: (domain.max / 1000000 / 24 / 3600) as i32,
+                    min: ctx.tz.to_days(domain.min),
+                    max: ctx.tz.to_days(domain.max),
                 })),
             })
         },
@@ -339,8 +341,8 @@ fn register_timestamp_to_date(registry: &mut FunctionRegistry) {
         val: ValueRef<TimestampType>,
         ctx: &mut EvalContext,
     ) -> Value<DateType> {
-        vectorize_with_builder_1_arg::<TimestampType, DateType>(|val, output, _| {
-            output.push(microseconds_to_days(val));
+        vectorize_with_builder_1_arg::<TimestampType, DateType>(|val, output, ctx| {
+            output.push(ctx.func_ctx.tz.to_days(val));
         })(val, ctx)
     }
 }
@@ -677,7 +679,7 @@ macro_rules! impl_register_arith_functions {
                 |_, _, _| FunctionDomain::MayThrow,
                 vectorize_with_builder_2_arg::<DateType, Int64Type, TimestampType>(
                     |ts, delta, builder, ctx| {
-                        let val = (ts as i64) * 24 * 3600 * MICROS_IN_A_SEC;
+                        let val = ctx.func_ctx.tz.date_to_micros(ts);
                         match AddTimesImpl::eval_timestamp(
                             val,
                             $signed_wrapper!{delta},
@@ -719,7 +721,7 @@ macro_rules! impl_register_arith_functions {
                 |_, _, _| FunctionDomain::MayThrow,
                 vectorize_with_builder_2_arg::<DateType, Int64Type, TimestampType>(
                     |ts, delta, builder, ctx| {
-                        let val = (ts as i64) * 24 * 3600 * MICROS_IN_A_SEC;
+                        let val = ctx.func_ctx.tz.date_to_micros(ts);
 
                         match AddTimesImpl::eval_timestamp(
                             val,
@@ -762,7 +764,7 @@ macro_rules! impl_register_arith_functions {
                 |_, _, _| FunctionDomain::MayThrow,
                 vectorize_with_builder_2_arg::<DateType, Int64Type, TimestampType>(
                     |ts, delta, builder, ctx| {
-                        let val = (ts as i64) * 24 * 3600 * MICROS_IN_A_SEC;
+                        let val = ctx.func_ctx.tz.date_to_micros(ts);
 
                         match AddTimesImpl::eval_timestamp(
                             val,
@@ -832,19 +834,19 @@ fn register_real_time_functions(registry: &mut FunctionRegistry) {
     registry.register_0_arg_core::<DateType, _, _>(
         "today",
         |_| FunctionDomain::Full,
-        |_| Value::Scalar(today_date()),
+        |ctx| Value::Scalar(today_date(ctx.func_ctx.tz.tz)),
     );
 
     registry.register_0_arg_core::<DateType, _, _>(
         "yesterday",
         |_| FunctionDomain::Full,
-        |_| Value::Scalar(today_date() - 1),
+        |ctx| Value::Scalar(today_date(ctx.func_ctx.tz.tz) - 1),
     );
 
     registry.register_0_arg_core::<DateType, _, _>(
         "tomorrow",
         |_| FunctionDomain::Full,
-        |_| Value::Scalar(today_date() + 1),
+        |ctx| Value::Scalar(today_date(ctx.func_ctx.tz.tz) + 1),
     );
 }
 
@@ -1312,3 +1314,39 @@ fn register_rounder_functions(registry: &mut FunctionRegistry) {
         }),
     );
 }
+
+fn register_convert_timezone(registry: &mut FunctionRegistry) {
+    // Shifts the timestamp so that its wall-clock time in the session timezone
+    // is the wall-clock time of the original timestamp in the target timezone.
+    registry.register_passthrough_nullable_2_arg::<StringType, TimestampType, TimestampType, _, _>(
+        "convert_timezone",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<StringType, TimestampType, TimestampType>(
+            |tz, ts, output, ctx| {
+                let tz = String::from_utf8_lossy(tz);
+                let target = match TzFactory::instance().get_by_name(&tz) {
+                    Ok(lut) => lut.tz,
+                    Err(_) => {
+                        ctx.set_error(output.len(), format!("Invalid timezone: {tz}"));
+                        output.push(0);
+                        return;
+                    }
+                };
+                let target_offset = ts.to_timestamp(target).offset().fix().local_minus_utc();
+                let session_offset = ts
+                    .to_timestamp(ctx.func_ctx.tz.tz)
+                    .offset()
+                    .fix()
+                    .local_minus_utc();
+                let shifted = ts + (target_offset - session_offset) as i64 * MICROS_IN_A_SEC;
+                match check_timestamp(shifted) {
+                    Ok(shifted) => output.push(shifted),
+                    Err(e) => {
+                        ctx.set_error(output.len(), e);
+                        output.push(0);
+                    }
+                }
+            },
+        ),
+    );
+}
@@ -526,6 +526,61 @@ pub(crate) fn register_decimal_to_float32(registry: &mut FunctionRegistry) {
     });
 }
 
+pub(crate) fn register_decimal_to_int<T: Number>(registry: &mut FunctionRegistry) {
+    if T::data_type().is_float() {
+        return;
+    }
+
+    let name = format!("to_{}", T::data_type()).to_lowercase();
+    let try_name = format!("try_to_{}", T::data_type()).to_lowercase();
+
+    let factory = |_params: &[usize], args_type: &[DataType]| {
+        if args_type.len() != 1 {
+            return None;
+        }
+
+        let has_null = args_type[0].is_nullable_or_null();
+        let arg_type = args_type[0].remove_nullable();
+        if !arg_type.is_decimal() {
+            return None;
+        }
+
+        let f = Function {
+            signature: FunctionSignature {
+                name: format!("to_{}", T::data_type()).to_lowercase(),
+                args_type: vec![arg_type.clone()],
+                return_type: DataType::Number(T::data_type()),
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::MayThrow),
+                eval: Box::new(move |args, ctx| {
+                    decimal_to_int::<T>(&args[0], arg_type.clone(), ctx)
+                }),
+            },
+        };
+        Some((f, has_null))
+    };
+
+    registry.register_function_factory(&name, move |params, args_type| {
+        let (f, has_null) = factory(params, args_type)?;
+        if has_null {
+            Some(Arc::new(f.wrap_nullable()))
+        } else {
+            Some(Arc::new(f))
+        }
+    });
+    let try_name_clone = try_name.clone();
+    registry.register_function_factory(&try_name, move |params, args_type| {
+        let (mut f, has_null) = factory(params, args_type)?;
+        f.signature.name = try_name_clone.clone();
+        if has_null {
+            Some(Arc::new(f.error_to_null().wrap_nullable()))
+        } else {
+            Some(Arc::new(f.error_to_null()))
+        }
+    });
+}
+
 fn convert_to_decimal(
     arg: &ValueRef<AnyType>,
     ctx: &mut EvalContext,
@@ -1026,3 +1081,75 @@ fn decimal_to_float32(
         Value::Column(result)
     }
 }
+
+fn decimal_to_int<T: Number>(
+    arg: &ValueRef<AnyType>,
+    from_type: DataType,
+    ctx: &mut EvalContext,
+) -> Value<AnyType> {
+    let mut is_scalar = false;
+    let column = match arg {
+        ValueRef::Column(column) => column.clone(),
+        ValueRef::Scalar(s) => {
+            is_scalar = true;
+            let builder = ColumnBuilder::repeat(s, 1, &from_type);
+            builder.build()
+        }
+    };
+
+    let from_type = from_type.as_decimal().unwrap();
+
+    // the fractional part is truncated towards zero.
+    let result = match from_type {
+        DecimalDataType::Decimal128(_) => {
+            let (buffer, from_size) = i128::try_downcast_column(&column).unwrap();
+
+            let div = i128::e(from_size.scale as u32);
+
+            let mut values = Vec::<T>::with_capacity(buffer.len());
+            for (row, x) in buffer.iter().enumerate() {
+                match num_traits::cast::cast::<i128, T>(*x / div) {
+                    Some(v) => values.push(v),
+                    None => {
+                        ctx.set_error(row, "Decimal overflow when casting to integer");
+                        values.push(T::default());
+                    }
+                }
+            }
+            NumberType::<T>::upcast_column(values.into())
+        }
+
+        DecimalDataType::Decimal256(_) => {
+            let (buffer, from_size) = i256::try_downcast_column(&column).unwrap();
+
+            let div = i256::e(from_size.scale as u32);
+            let min = i256::from(i128::MIN);
+            let max = i256::from(i128::MAX);
+
+            let mut values = Vec::<T>::with_capacity(buffer.len());
+            for (row, x) in buffer.iter().enumerate() {
+                let x = *x / div;
+                let value = if x < min || x > max {
+                    None
+                } else {
+                    num_traits::cast::cast::<i128, T>(x.as_i128())
+                };
+                match value {
+                    Some(v) => values.push(v),
+                    None => {
+                        ctx.set_error(row, "Decimal overflow when casting to integer");
+                        values.push(T::default());
+                    }
+                }
+            }
+            NumberType::<T>::upcast_column(values.into())
+        }
+    };
+
+    if is_scalar {
+        let scalar = result.index(0).unwrap();
+        Value::Scalar(scalar.to_owned())
+    } else {
+        Value::Column(result)
+    }
+}
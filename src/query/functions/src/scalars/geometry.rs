@@ -0,0 +1,307 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::error_to_null;
+use common_expression::types::geometry::geometry_to_wkb;
+use common_expression::types::geometry::wkb_to_geometry;
+use common_expression::types::geometry::wkb_to_wkt;
+use common_expression::types::geometry::wkt_to_wkb;
+use common_expression::types::number::F64;
+use common_expression::types::BooleanType;
+use common_expression::types::GeometryType;
+use common_expression::types::NumberType;
+use common_expression::types::StringType;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::EvalContext;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use common_expression::Value;
+use common_expression::ValueRef;
+use geo::Coord;
+use geo::EuclideanDistance;
+use geo::Geometry;
+use geo::Line;
+use geo::LineString;
+use geo::Point;
+use geo::Polygon;
+use geo::Relate;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "to_geometry",
+        |_, _| FunctionDomain::MayThrow,
+        eval_string_to_geometry,
+    );
+
+    registry.register_combine_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "try_to_geometry",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_string_to_geometry),
+    );
+
+    registry.register_aliases("to_geometry", &["st_geomfromtext"]);
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::MayThrow,
+        eval_geometry_to_string,
+    );
+
+    registry.register_combine_nullable_1_arg::<GeometryType, StringType, _, _>(
+        "try_to_string",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_geometry_to_string),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<NumberType<F64>, NumberType<F64>, GeometryType, _, _>(
+        "st_point",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<NumberType<F64>, NumberType<F64>, GeometryType>(
+            |x, y, builder, _| {
+                let point = Geometry::Point(Point::new(x.0, y.0));
+                builder.put_slice(&geometry_to_wkb(&point));
+                builder.commit_row();
+            },
+        ),
+    );
+
+    registry
+        .register_passthrough_nullable_2_arg::<GeometryType, GeometryType, NumberType<F64>, _, _>(
+            "st_distance",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<GeometryType, GeometryType, NumberType<F64>>(
+                |l, r, builder, ctx| match (wkb_to_geometry(l), wkb_to_geometry(r)) {
+                    (Ok(l), Ok(r)) => builder.push(F64::from(euclidean_distance(&l, &r))),
+                    (Err(e), _) | (_, Err(e)) => {
+                        ctx.set_error(builder.len(), e.message());
+                        builder.push(F64::from(0.0));
+                    }
+                },
+            ),
+        );
+
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, BooleanType, _, _>(
+        "st_contains",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, BooleanType>(
+            |l, r, builder, ctx| match (wkb_to_geometry(l), wkb_to_geometry(r)) {
+                (Ok(l), Ok(r)) => builder.push(l.relate(&r).is_contains()),
+                (Err(e), _) | (_, Err(e)) => {
+                    ctx.set_error(builder.len(), e.message());
+                    builder.push(false);
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, StringType, _, _>(
+        "st_asgeojson",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, StringType>(|g, builder, ctx| {
+            match wkb_to_geometry(g) {
+                Ok(geometry) => {
+                    let mut json = String::new();
+                    write_geojson(&geometry, &mut json);
+                    builder.put_str(&json);
+                }
+                Err(e) => ctx.set_error(builder.len(), e.message()),
+            }
+            builder.commit_row();
+        }),
+    );
+}
+
+fn eval_string_to_geometry(
+    val: ValueRef<StringType>,
+    ctx: &mut EvalContext,
+) -> Value<GeometryType> {
+    vectorize_with_builder_1_arg::<StringType, GeometryType>(|s, builder, ctx| {
+        match std::str::from_utf8(s)
+            .map_err(|e| e.to_string())
+            .and_then(|s| wkt_to_wkb(s).map_err(|e| e.message()))
+        {
+            Ok(wkb) => builder.put_slice(&wkb),
+            Err(e) => ctx.set_error(builder.len(), e),
+        }
+        builder.commit_row();
+    })(val, ctx)
+}
+
+fn eval_geometry_to_string(
+    val: ValueRef<GeometryType>,
+    ctx: &mut EvalContext,
+) -> Value<StringType> {
+    vectorize_with_builder_1_arg::<GeometryType, StringType>(|g, builder, ctx| {
+        match wkb_to_wkt(g) {
+            Ok(wkt) => builder.put_str(&wkt),
+            Err(e) => ctx.set_error(builder.len(), e.message()),
+        }
+        builder.commit_row();
+    })(val, ctx)
+}
+
+/// The minimum euclidean distance between two geometries, 0 if they intersect.
+fn euclidean_distance(l: &Geometry<f64>, r: &Geometry<f64>) -> f64 {
+    if l.relate(r).is_intersects() {
+        return 0.0;
+    }
+
+    let (mut l_points, mut l_lines) = (vec![], vec![]);
+    let (mut r_points, mut r_lines) = (vec![], vec![]);
+    decompose(l, &mut l_points, &mut l_lines);
+    decompose(r, &mut r_points, &mut r_lines);
+
+    let mut distance = f64::INFINITY;
+    for p in &l_points {
+        for q in &r_points {
+            distance = distance.min(p.euclidean_distance(q));
+        }
+        for line in &r_lines {
+            distance = distance.min(p.euclidean_distance(line));
+        }
+    }
+    for line in &l_lines {
+        for q in &r_points {
+            distance = distance.min(line.euclidean_distance(q));
+        }
+        for other in &r_lines {
+            distance = distance.min(line.euclidean_distance(other));
+        }
+    }
+    distance
+}
+
+/// Splits the geometry into the isolated points and the line segments of its boundaries.
+fn decompose(geometry: &Geometry<f64>, points: &mut Vec<Point<f64>>, lines: &mut Vec<Line<f64>>) {
+    fn decompose_line_string(
+        l: &LineString<f64>,
+        points: &mut Vec<Point<f64>>,
+        lines: &mut Vec<Line<f64>>,
+    ) {
+        if l.0.len() == 1 {
+            points.push(Point(l.0[0]));
+        }
+        lines.extend(l.lines());
+    }
+
+    fn decompose_polygon(
+        p: &Polygon<f64>,
+        points: &mut Vec<Point<f64>>,
+        lines: &mut Vec<Line<f64>>,
+    ) {
+        decompose_line_string(p.exterior(), points, lines);
+        for ring in p.interiors() {
+            decompose_line_string(ring, points, lines);
+        }
+    }
+
+    match geometry {
+        Geometry::Point(p) => points.push(*p),
+        Geometry::Line(l) => lines.push(*l),
+        Geometry::LineString(l) => decompose_line_string(l, points, lines),
+        Geometry::Polygon(p) => decompose_polygon(p, points, lines),
+        Geometry::MultiPoint(m) => points.extend(m.0.iter().copied()),
+        Geometry::MultiLineString(m) => {
+            for l in &m.0 {
+                decompose_line_string(l, points, lines);
+            }
+        }
+        Geometry::MultiPolygon(m) => {
+            for p in &m.0 {
+                decompose_polygon(p, points, lines);
+            }
+        }
+        Geometry::Rect(r) => decompose_polygon(&r.to_polygon(), points, lines),
+        Geometry::Triangle(t) => decompose_polygon(&t.to_polygon(), points, lines),
+        Geometry::GeometryCollection(c) => {
+            for g in &c.0 {
+                decompose(g, points, lines);
+            }
+        }
+    }
+}
+
+fn write_geojson(geometry: &Geometry<f64>, out: &mut String) {
+    fn write_coord(c: &Coord<f64>, out: &mut String) {
+        out.push_str(&format!("[{},{}]", c.x, c.y));
+    }
+
+    fn write_coords<'a>(coords: impl Iterator<Item = &'a Coord<f64>>, out: &mut String) {
+        out.push('[');
+        for (i, c) in coords.enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            write_coord(c, out);
+        }
+        out.push(']');
+    }
+
+    fn write_polygon(p: &Polygon<f64>, out: &mut String) {
+        out.push('[');
+        write_coords(p.exterior().0.iter(), out);
+        for ring in p.interiors() {
+            out.push(',');
+            write_coords(ring.0.iter(), out);
+        }
+        out.push(']');
+    }
+
+    fn write_list<T>(items: &[T], out: &mut String, f: impl Fn(&T, &mut String)) {
+        out.push('[');
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            f(item, out);
+        }
+        out.push(']');
+    }
+
+    let ty = match geometry {
+        Geometry::Point(_) => "Point",
+        Geometry::Line(_) | Geometry::LineString(_) => "LineString",
+        Geometry::Polygon(_) | Geometry::Rect(_) | Geometry::Triangle(_) => "Polygon",
+        Geometry::MultiPoint(_) => "MultiPoint",
+        Geometry::MultiLineString(_) => "MultiLineString",
+        Geometry::MultiPolygon(_) => "MultiPolygon",
+        Geometry::GeometryCollection(_) => "GeometryCollection",
+    };
+    out.push_str(&format!("{{\"type\":\"{}\",", ty));
+
+    if let Geometry::GeometryCollection(c) = geometry {
+        out.push_str("\"geometries\":");
+        write_list(&c.0, out, write_geojson);
+        out.push('}');
+        return;
+    }
+
+    out.push_str("\"coordinates\":");
+    match geometry {
+        Geometry::Point(p) => write_coord(&p.0, out),
+        Geometry::Line(l) => write_coords([l.start, l.end].iter(), out),
+        Geometry::LineString(l) => write_coords(l.0.iter(), out),
+        Geometry::Polygon(p) => write_polygon(p, out),
+        Geometry::Rect(r) => write_polygon(&r.to_polygon(), out),
+        Geometry::Triangle(t) => write_polygon(&t.to_polygon(), out),
+        Geometry::MultiPoint(m) => write_list(&m.0, out, |p, out| write_coord(&p.0, out)),
+        Geometry::MultiLineString(m) => {
+            write_list(&m.0, out, |l, out| write_coords(l.0.iter(), out))
+        }
+        Geometry::MultiPolygon(m) => write_list(&m.0, out, write_polygon),
+        Geometry::GeometryCollection(_) => unreachable!(),
+    }
+    out.push('}');
+}
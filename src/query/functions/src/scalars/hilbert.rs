@@ -0,0 +1,194 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_expression::types::number::NumberScalar;
+use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::DataType;
+use common_expression::Column;
+use common_expression::Function;
+use common_expression::FunctionDomain;
+use common_expression::FunctionEval;
+use common_expression::FunctionRegistry;
+use common_expression::FunctionSignature;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_expression::Value;
+use common_expression::ValueRef;
+
+const SIGN_BIT: u64 = 1 << 63;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // `CLUSTER BY HILBERT(a, b)` is stored as the cluster key `HILBERT(a, b)`.
+    registry.register_aliases("hilbert_key", &["hilbert"]);
+
+    // `hilbert_key(a, b, ...)` maps the arguments to their index on a hilbert curve, the
+    // index is encoded as big-endian bytes, so sorting by the key clusters the rows on
+    // all the arguments rather than lexicographically.
+    registry.register_function_factory("hilbert_key", |_, args_type| {
+        if args_type.is_empty()
+            || !args_type
+                .iter()
+                .all(|ty| is_supported_type(&ty.remove_nullable()))
+        {
+            return None;
+        }
+        Some(Arc::new(Function {
+            signature: FunctionSignature {
+                name: "hilbert_key".to_string(),
+                args_type: args_type.to_vec(),
+                return_type: DataType::String,
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::Full),
+                eval: Box::new(|args, _| {
+                    let len = args.iter().find_map(|arg| match arg {
+                        ValueRef::Column(col) => Some(col.len()),
+                        _ => None,
+                    });
+
+                    let size = len.unwrap_or(1);
+                    let mut builder =
+                        StringColumnBuilder::with_capacity(size, size * args.len() * 8);
+                    let mut coords = vec![0u64; args.len()];
+                    for row in 0..size {
+                        for (coord, arg) in coords.iter_mut().zip(args.iter()) {
+                            *coord = match arg {
+                                ValueRef::Scalar(scalar) => to_coordinate(scalar),
+                                ValueRef::Column(col) => {
+                                    to_coordinate(unsafe { &col.index_unchecked(row) })
+                                }
+                            };
+                        }
+                        builder.put_slice(&hilbert_index(&mut coords));
+                        builder.commit_row();
+                    }
+
+                    match len {
+                        Some(_) => Value::Column(Column::String(builder.build())),
+                        _ => Value::Scalar(Scalar::String(builder.build_scalar())),
+                    }
+                }),
+            },
+        }))
+    });
+}
+
+fn is_supported_type(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Null
+            | DataType::Boolean
+            | DataType::Number(_)
+            | DataType::Decimal(_)
+            | DataType::String
+            | DataType::Timestamp
+            | DataType::Date
+    )
+}
+
+/// Maps a scalar to an unsigned integer with the same order, NULL is mapped to 0.
+/// Strings are compared by their first 8 bytes.
+fn to_coordinate(scalar: &ScalarRef) -> u64 {
+    match scalar {
+        ScalarRef::Null => 0,
+        ScalarRef::Boolean(v) => *v as u64,
+        ScalarRef::Number(n) => match n {
+            NumberScalar::UInt8(v) => *v as u64,
+            NumberScalar::UInt16(v) => *v as u64,
+            NumberScalar::UInt32(v) => *v as u64,
+            NumberScalar::UInt64(v) => *v,
+            NumberScalar::Int8(v) => signed_coordinate(*v as i64),
+            NumberScalar::Int16(v) => signed_coordinate(*v as i64),
+            NumberScalar::Int32(v) => signed_coordinate(*v as i64),
+            NumberScalar::Int64(v) => signed_coordinate(*v),
+            NumberScalar::Float32(v) => float_coordinate(v.0 as f64),
+            NumberScalar::Float64(v) => float_coordinate(v.0),
+        },
+        ScalarRef::Decimal(v) => float_coordinate(v.to_float64()),
+        ScalarRef::Timestamp(v) => signed_coordinate(*v),
+        ScalarRef::Date(v) => signed_coordinate(*v as i64),
+        ScalarRef::String(v) => {
+            let mut bytes = [0u8; 8];
+            let len = v.len().min(8);
+            bytes[..len].copy_from_slice(&v[..len]);
+            u64::from_be_bytes(bytes)
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn signed_coordinate(v: i64) -> u64 {
+    (v as u64) ^ SIGN_BIT
+}
+
+fn float_coordinate(v: f64) -> u64 {
+    let bits = v.to_bits();
+    if bits & SIGN_BIT != 0 {
+        !bits
+    } else {
+        bits | SIGN_BIT
+    }
+}
+
+/// Computes the hilbert index of the point with Skilling's algorithm, the coordinates
+/// are transposed in place and then interleaved from the most significant bit.
+fn hilbert_index(coords: &mut [u64]) -> Vec<u8> {
+    let n = coords.len();
+
+    // Inverse undo.
+    let mut q = SIGN_BIT;
+    while q > 1 {
+        let p = q - 1;
+        for i in 0..n {
+            if coords[i] & q != 0 {
+                coords[0] ^= p;
+            } else {
+                let t = (coords[0] ^ coords[i]) & p;
+                coords[0] ^= t;
+                coords[i] ^= t;
+            }
+        }
+        q >>= 1;
+    }
+
+    // Gray encode.
+    for i in 1..n {
+        coords[i] ^= coords[i - 1];
+    }
+    let mut t = 0;
+    let mut q = SIGN_BIT;
+    while q > 1 {
+        if coords[n - 1] & q != 0 {
+            t ^= q - 1;
+        }
+        q >>= 1;
+    }
+    for coord in coords.iter_mut() {
+        *coord ^= t;
+    }
+
+    let mut key = vec![0u8; n * 8];
+    let mut pos = 0;
+    for bit in (0..64).rev() {
+        for coord in coords.iter() {
+            if (coord >> bit) & 1 == 1 {
+                key[pos / 8] |= 1 << (7 - pos % 8);
+            }
+            pos += 1;
+        }
+    }
+    key
+}
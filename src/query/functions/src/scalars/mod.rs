@@ -17,6 +17,7 @@ use common_expression::FunctionRegistry;
 mod arithmetic;
 mod arithmetic_modulo;
 mod array;
+mod binary;
 mod bitmap;
 mod boolean;
 mod comparison;
@@ -24,10 +25,13 @@ mod control;
 mod datetime;
 mod decimal;
 mod geo;
+mod geometry;
 mod hash;
+mod hilbert;
 mod map;
 mod math;
 mod other;
+mod search;
 mod string;
 mod string_multi_args;
 mod tuple;
@@ -38,6 +42,7 @@ pub use comparison::check_pattern_type;
 pub use comparison::is_like_pattern_escape;
 pub use comparison::PatternType;
 pub use comparison::ALL_COMP_FUNC_NAMES;
+pub use search::tokenize;
 
 pub fn register(registry: &mut FunctionRegistry) {
     variant::register(registry);
@@ -54,8 +59,12 @@ pub fn register(registry: &mut FunctionRegistry) {
     tuple::register(registry);
     geo::register(registry);
     hash::register(registry);
+    hilbert::register(registry);
     other::register(registry);
     decimal::register(registry);
     vector::register(registry);
     bitmap::register(registry);
+    geometry::register(registry);
+    binary::register(registry);
+    search::register(registry);
 }
@@ -0,0 +1,47 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use common_expression::types::BooleanType;
+use common_expression::types::StringType;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // `match(text, query)` is true if the text contains all the terms of the query.
+    registry.register_2_arg::<StringType, StringType, BooleanType, _, _>(
+        "match",
+        |_, _, _| FunctionDomain::Full,
+        |text, query, _| {
+            let query_terms = tokenize(query);
+            if query_terms.is_empty() {
+                return false;
+            }
+            let text_terms = tokenize(text).into_iter().collect::<HashSet<_>>();
+            query_terms.iter().all(|term| text_terms.contains(term))
+        },
+    );
+}
+
+/// Split the text into lowercase terms at the non-alphanumeric characters.
+///
+/// The terms are the keys of the inverted index, and the unit searched by `match`.
+pub fn tokenize(text: &[u8]) -> Vec<String> {
+    String::from_utf8_lossy(text)
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .map(|term| term.to_lowercase())
+        .collect()
+}
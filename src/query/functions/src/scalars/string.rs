@@ -23,6 +23,7 @@ use common_expression::types::number::UInt64Type;
 use common_expression::types::string::StringColumn;
 use common_expression::types::string::StringColumnBuilder;
 use common_expression::types::string::StringDomain;
+use common_expression::types::BooleanType;
 use common_expression::types::NumberType;
 use common_expression::types::StringType;
 use common_expression::vectorize_with_builder_1_arg;
@@ -75,17 +76,33 @@ pub fn register(registry: &mut FunctionRegistry) {
         vectorize_string_to_string(
             |col| col.data.len(),
             |val, output, _| {
-                for (start, end, ch) in val.char_indices() {
-                    if ch == '\u{FFFD}' {
-                        // If char is invalid, just copy it.
-                        output.put_slice(&val.as_bytes()[start..end]);
-                    } else if ch.is_ascii() {
-                        output.put_u8(ch.to_ascii_lowercase() as u8);
-                    } else {
-                        for x in ch.to_lowercase() {
-                            output.put_char(x);
-                        }
-                    }
+                put_lowercase(val, output);
+                output.commit_row();
+            },
+        ),
+    );
+
+    // The collation key of `<expr> COLLATE <collation>`, the binder only
+    // calls it for the case-insensitive collations.
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, StringType, _, _>(
+        "collate",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<StringType, StringType, StringType>(
+            |val, collation, output, ctx| {
+                if collation.eq_ignore_ascii_case(b"utf8_unicode_ci")
+                    || collation.eq_ignore_ascii_case(b"utf8_general_ci")
+                {
+                    put_lowercase(val, output);
+                } else if collation.eq_ignore_ascii_case(b"utf8")
+                    || collation.eq_ignore_ascii_case(b"utf8_bin")
+                    || collation.eq_ignore_ascii_case(b"binary")
+                {
+                    output.put_slice(val);
+                } else {
+                    ctx.set_error(
+                        output.len(),
+                        format!("unknown collation '{}'", String::from_utf8_lossy(collation)),
+                    );
                 }
                 output.commit_row();
             },
@@ -298,6 +315,12 @@ pub fn register(registry: &mut FunctionRegistry) {
         move |substr: &[u8], str: &[u8], pos: u64, _| find_at(str, substr, pos),
     );
 
+    registry.register_2_arg::<StringType, StringType, BooleanType, _, _>(
+        "starts_with",
+        |_, _, _| FunctionDomain::Full,
+        |str: &[u8], prefix: &[u8], _| str.starts_with(prefix),
+    );
+
     registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
         "to_base64",
         |_, _| FunctionDomain::Full,
@@ -880,3 +903,18 @@ fn vectorize_string_to_string_2_arg(
         }
     }
 }
+
+fn put_lowercase(val: &[u8], output: &mut StringColumnBuilder) {
+    for (start, end, ch) in val.char_indices() {
+        if ch == '\u{FFFD}' {
+            // If char is invalid, just copy it.
+            output.put_slice(&val.as_bytes()[start..end]);
+        } else if ch.is_ascii() {
+            output.put_u8(ch.to_ascii_lowercase() as u8);
+        } else {
+            for x in ch.to_lowercase() {
+                output.put_char(x);
+            }
+        }
+    }
+}
@@ -0,0 +1,287 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_expression::types::number::NumberScalar;
+use common_expression::types::AnyType;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::EvalContext;
+use common_expression::Function;
+use common_expression::FunctionEval;
+use common_expression::FunctionKind;
+use common_expression::FunctionProperty;
+use common_expression::FunctionRegistry;
+use common_expression::FunctionSignature;
+use common_expression::ScalarRef;
+use common_expression::Value;
+use jsonb::get_by_path;
+use jsonb::jsonpath::parse_json_path;
+
+/// Names of the arguments of `flatten`, in positional order.
+pub const FLATTEN_ARGS: [&str; 5] = ["input", "path", "outer", "recursive", "mode"];
+
+/// Names of the columns produced by `flatten` in the `FROM` clause, in the
+/// order of the fields of its result.
+pub const FLATTEN_COLUMNS: [&str; 6] = ["seq", "key", "path", "index", "value", "this"];
+
+/// `SEQ` only has to be unique for each input row, so a process wide counter
+/// is enough.
+static FLATTEN_SEQ: AtomicU64 = AtomicU64::new(1);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum FlattenMode {
+    Object,
+    Array,
+    Both,
+}
+
+impl FlattenMode {
+    fn parse(mode: &[u8]) -> Option<Self> {
+        match mode.to_ascii_lowercase().as_slice() {
+            b"object" => Some(FlattenMode::Object),
+            b"array" => Some(FlattenMode::Array),
+            b"both" => Some(FlattenMode::Both),
+            _ => None,
+        }
+    }
+}
+
+struct FlattenRow<'a> {
+    seq: u64,
+    key: Option<&'a str>,
+    path: &'a str,
+    index: Option<u64>,
+    value: Option<&'a [u8]>,
+    this: Option<&'a [u8]>,
+}
+
+impl<'a> FlattenRow<'a> {
+    fn push(self, builder: &mut ColumnBuilder) {
+        builder.push(ScalarRef::Tuple(vec![
+            ScalarRef::Number(NumberScalar::UInt64(self.seq)),
+            self.key
+                .map(|key| ScalarRef::String(key.as_bytes()))
+                .unwrap_or(ScalarRef::Null),
+            ScalarRef::String(self.path.as_bytes()),
+            self.index
+                .map(|index| ScalarRef::Number(NumberScalar::UInt64(index)))
+                .unwrap_or(ScalarRef::Null),
+            self.value
+                .map(ScalarRef::Variant)
+                .unwrap_or(ScalarRef::Null),
+            self.this.map(ScalarRef::Variant).unwrap_or(ScalarRef::Null),
+        ]));
+    }
+}
+
+/// The result of each row is a single nullable tuple, so that it can be
+/// padded with NULLs like the results of the other set-returning functions.
+fn flatten_return_type() -> DataType {
+    let nullable = |ty| DataType::Nullable(Box::new(ty));
+    nullable(DataType::Tuple(vec![
+        nullable(DataType::Number(NumberDataType::UInt64)),
+        nullable(DataType::String),
+        nullable(DataType::String),
+        nullable(DataType::Number(NumberDataType::UInt64)),
+        nullable(DataType::Variant),
+        nullable(DataType::Variant),
+    ]))
+}
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.properties.insert(
+        "flatten".to_string(),
+        FunctionProperty::default().kind(FunctionKind::SRF),
+    );
+
+    // flatten(input [, path [, outer [, recursive [, mode]]]])
+    registry.register_function_factory("flatten", |_, args_type| {
+        if args_type.is_empty() || args_type.len() > FLATTEN_ARGS.len() {
+            return None;
+        }
+        let expected = [
+            DataType::Variant,
+            DataType::String,
+            DataType::Boolean,
+            DataType::Boolean,
+            DataType::String,
+        ];
+        for (arg_type, expected) in args_type.iter().zip(expected.iter()) {
+            if arg_type.remove_nullable() != *expected && *arg_type != DataType::Null {
+                return None;
+            }
+        }
+
+        Some(Arc::new(Function {
+            signature: FunctionSignature {
+                name: "flatten".to_string(),
+                args_type: args_type.to_vec(),
+                return_type: DataType::Tuple(vec![flatten_return_type()]),
+            },
+            eval: FunctionEval::SRF {
+                eval: Box::new(|args, ctx| {
+                    let args = args
+                        .iter()
+                        .map(|arg| arg.clone().to_owned())
+                        .collect::<Vec<_>>();
+                    (0..ctx.num_rows)
+                        .map(|row| flatten_row(&args, row, ctx))
+                        .collect()
+                }),
+            },
+        }))
+    });
+}
+
+fn flatten_row(
+    args: &[Value<AnyType>],
+    row: usize,
+    ctx: &mut EvalContext,
+) -> (Value<AnyType>, usize) {
+    let arg = |i: usize| args.get(i).map(|arg| arg.index(row).unwrap());
+    let path = match arg(1) {
+        Some(ScalarRef::String(path)) => path,
+        _ => &[][..],
+    };
+    let outer = matches!(arg(2), Some(ScalarRef::Boolean(true)));
+    let recursive = matches!(arg(3), Some(ScalarRef::Boolean(true)));
+    let mode = match arg(4) {
+        Some(ScalarRef::String(mode)) => match FlattenMode::parse(mode) {
+            Some(mode) => mode,
+            None => {
+                ctx.set_error(
+                    row,
+                    format!(
+                        "Invalid FLATTEN mode '{}', expect OBJECT, ARRAY or BOTH",
+                        String::from_utf8_lossy(mode)
+                    ),
+                );
+                FlattenMode::Both
+            }
+        },
+        _ => FlattenMode::Both,
+    };
+
+    let input = match arg(0) {
+        Some(ScalarRef::Variant(val)) if path.is_empty() => Some(val.to_vec()),
+        Some(ScalarRef::Variant(val)) => match parse_json_path(path) {
+            Ok(json_path) => get_by_path(val, json_path).into_iter().next(),
+            Err(_) => {
+                ctx.set_error(
+                    row,
+                    format!("Invalid JSON Path '{}'", String::from_utf8_lossy(path)),
+                );
+                None
+            }
+        },
+        _ => None,
+    };
+
+    let seq = FLATTEN_SEQ.fetch_add(1, Ordering::Relaxed);
+    let path = String::from_utf8_lossy(path);
+    let mut builder = ColumnBuilder::with_capacity(&flatten_return_type(), 0);
+    if let Some(input) = &input {
+        if let Ok(value) = jsonb::from_slice(input) {
+            flatten_value(&mut builder, seq, &value, input, &path, mode, recursive);
+        }
+    }
+    // With `outer`, an input that can't be expanded still produces one row.
+    if builder.len() == 0 && outer {
+        FlattenRow {
+            seq,
+            key: None,
+            path: &path,
+            index: None,
+            value: None,
+            this: input.as_deref(),
+        }
+        .push(&mut builder);
+    }
+
+    let column = builder.build();
+    let num_rows = column.len();
+    (Value::Column(Column::Tuple(vec![column])), num_rows)
+}
+
+/// Expands the elements of an array or the fields of an object, and their
+/// descendants if `recursive` is set.
+fn flatten_value(
+    builder: &mut ColumnBuilder,
+    seq: u64,
+    value: &jsonb::Value,
+    this: &[u8],
+    path: &str,
+    mode: FlattenMode,
+    recursive: bool,
+) {
+    match value {
+        jsonb::Value::Array(vals) if mode != FlattenMode::Object => {
+            for (index, val) in vals.iter().enumerate() {
+                let elem_path = format!("{path}[{index}]");
+                let mut buf = Vec::new();
+                val.write_to_vec(&mut buf);
+                FlattenRow {
+                    seq,
+                    key: None,
+                    path: &elem_path,
+                    index: Some(index as u64),
+                    value: Some(&buf),
+                    this: Some(this),
+                }
+                .push(builder);
+                if recursive {
+                    flatten_value(builder, seq, val, &buf, &elem_path, mode, recursive);
+                }
+            }
+        }
+        jsonb::Value::Object(obj) if mode != FlattenMode::Array => {
+            for (key, val) in obj.iter() {
+                let elem_path = object_path(path, key);
+                let mut buf = Vec::new();
+                val.write_to_vec(&mut buf);
+                FlattenRow {
+                    seq,
+                    key: Some(key.as_str()),
+                    path: &elem_path,
+                    index: None,
+                    value: Some(&buf),
+                    this: Some(this),
+                }
+                .push(builder);
+                if recursive {
+                    flatten_value(builder, seq, val, &buf, &elem_path, mode, recursive);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn object_path(path: &str, key: &str) -> String {
+    let is_identifier = !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    match (path.is_empty(), is_identifier) {
+        (true, true) => key.to_string(),
+        (false, true) => format!("{path}.{key}"),
+        (_, false) => format!("{path}['{key}']"),
+    }
+}
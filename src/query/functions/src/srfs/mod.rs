@@ -30,7 +30,14 @@ use common_expression::Value;
 use jsonb::get_by_path;
 use jsonb::jsonpath::parse_json_path;
 
+mod flatten;
+
+pub use flatten::FLATTEN_ARGS;
+pub use flatten::FLATTEN_COLUMNS;
+
 pub fn register(registry: &mut FunctionRegistry) {
+    flatten::register(registry);
+
     registry.properties.insert(
         "unnest".to_string(),
         FunctionProperty::default().kind(FunctionKind::SRF),
@@ -517,6 +517,8 @@ fn transform_data_type(target_type: common_ast::ast::TypeName) -> DataType {
             DataType::Map(Box::new(DataType::Tuple(vec![key_type, val_type])))
         }
         common_ast::ast::TypeName::Bitmap => DataType::Bitmap,
+        common_ast::ast::TypeName::Geometry => DataType::Geometry,
+        common_ast::ast::TypeName::Binary => DataType::Binary,
         common_ast::ast::TypeName::Tuple { fields_type, .. } => {
             DataType::Tuple(fields_type.into_iter().map(transform_data_type).collect())
         }
@@ -539,6 +541,7 @@ pub fn transform_literal(lit: ASTLiteral) -> Scalar {
             scale,
         })),
         ASTLiteral::String(s) => Scalar::String(s.as_bytes().to_vec()),
+        ASTLiteral::Binary(b) => Scalar::Binary(b),
         ASTLiteral::Boolean(b) => Scalar::Boolean(b),
         ASTLiteral::Null => Scalar::Null,
         ASTLiteral::Float64(f) => Scalar::Number(NumberScalar::Float64(OrderedFloat(f))),
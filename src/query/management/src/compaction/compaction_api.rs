@@ -0,0 +1,24 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_exception::Result;
+
+#[async_trait::async_trait]
+pub trait CompactionApi: Sync + Send {
+    // Take or renew the lease of the background compaction of the tenant, returns
+    // whether `holder` holds the lease for `ttl` from now on.
+    async fn renew_compactor_lease(&self, holder: &str, ttl: Duration) -> Result<bool>;
+}
@@ -0,0 +1,82 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::KVMeta;
+use common_meta_types::MatchSeq;
+use common_meta_types::MetaError;
+use common_meta_types::Operation;
+
+use crate::compaction::CompactionApi;
+
+static COMPACTOR_API_KEY_PREFIX: &str = "__fd_compactor";
+
+pub struct CompactionMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    compactor_key: String,
+}
+
+impl CompactionMgr {
+    pub fn create(kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>, tenant: &str) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while compaction mgr create)",
+            ));
+        }
+
+        let tenant = escape_for_key(tenant)?;
+        Ok(CompactionMgr {
+            kv_api,
+            compactor_key: format!("{}/{}", COMPACTOR_API_KEY_PREFIX, tenant),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl CompactionApi for CompactionMgr {
+    #[async_backtrace::framed]
+    async fn renew_compactor_lease(&self, holder: &str, ttl: Duration) -> Result<bool> {
+        // an expired lease is not returned by the meta service, anyone can take it.
+        let seq = match self.kv_api.get_kv(&self.compactor_key).await? {
+            Some(lease) if lease.data != holder.as_bytes() => return Ok(false),
+            Some(lease) => lease.seq,
+            None => 0,
+        };
+
+        let expire_at = (SystemTime::now() + ttl)
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards");
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &self.compactor_key,
+                MatchSeq::Exact(seq),
+                Operation::Update(holder.as_bytes().to_vec()),
+                Some(KVMeta {
+                    expire_at: Some(expire_at.as_secs()),
+                }),
+            ))
+            .await?;
+        Ok(res.is_changed())
+    }
+}
@@ -15,29 +15,55 @@
 #![allow(clippy::uninlined_format_args)]
 
 mod cluster;
+mod compaction;
+mod dictionary;
 mod file_format;
+mod network_policy;
+mod notification;
+mod pipe;
 mod quota;
 mod role;
+mod row_access_policy;
+mod sequence;
 mod serde;
 mod setting;
 mod stage;
+mod task;
 mod udf;
 mod user;
 
 pub use cluster::ClusterApi;
 pub use cluster::ClusterMgr;
+pub use compaction::CompactionApi;
+pub use compaction::CompactionMgr;
+pub use dictionary::DictionaryApi;
+pub use dictionary::DictionaryMgr;
 pub use file_format::FileFormatApi;
 pub use file_format::FileFormatMgr;
+pub use network_policy::NetworkPolicyApi;
+pub use network_policy::NetworkPolicyMgr;
+pub use notification::NotificationApi;
+pub use notification::NotificationMgr;
+pub use pipe::PipeApi;
+pub use pipe::PipeMgr;
+pub use pipe::MAX_PIPE_LOADS;
 pub use quota::QuotaApi;
 pub use quota::QuotaMgr;
 pub use role::RoleApi;
 pub use role::RoleMgr;
+pub use row_access_policy::RowAccessPolicyApi;
+pub use row_access_policy::RowAccessPolicyMgr;
+pub use sequence::SequenceApi;
+pub use sequence::SequenceMgr;
 pub use serde::deserialize_struct;
 pub use serde::serialize_struct;
 pub use setting::SettingApi;
 pub use setting::SettingMgr;
 pub use stage::StageApi;
 pub use stage::StageMgr;
+pub use task::TaskApi;
+pub use task::TaskMgr;
+pub use task::MAX_TASK_RUNS;
 pub use udf::UdfApi;
 pub use udf::UdfMgr;
 pub use user::UserApi;
@@ -0,0 +1,40 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_meta_app::principal::NotificationIntegration;
+use common_meta_types::MatchSeq;
+use common_meta_types::SeqV;
+
+#[async_trait::async_trait]
+pub trait NotificationApi: Sync + Send {
+    // Add a notification integration to /tenant/integration-name.
+    async fn add_notification_integration(
+        &self,
+        integration: NotificationIntegration,
+    ) -> Result<u64>;
+
+    // Get notification integration by name.
+    async fn get_notification_integration(
+        &self,
+        name: &str,
+        seq: MatchSeq,
+    ) -> Result<SeqV<NotificationIntegration>>;
+
+    // Get all the notification integrations for a tenant.
+    async fn get_notification_integrations(&self) -> Result<Vec<NotificationIntegration>>;
+
+    // Drop the tenant's notification integration by name.
+    async fn drop_notification_integration(&self, name: &str, seq: MatchSeq) -> Result<()>;
+}
@@ -0,0 +1,138 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::NotificationIntegration;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::IntoSeqV;
+use common_meta_types::MatchSeq;
+use common_meta_types::MatchSeqExt;
+use common_meta_types::MetaError;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+
+use crate::notification::NotificationApi;
+
+static NOTIFICATION_API_KEY_PREFIX: &str = "__fd_notification_integrations";
+
+pub struct NotificationMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    integration_prefix: String,
+}
+
+impl NotificationMgr {
+    pub fn create(kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>, tenant: &str) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while notification mgr create)",
+            ));
+        }
+
+        Ok(NotificationMgr {
+            kv_api,
+            integration_prefix: format!(
+                "{}/{}",
+                NOTIFICATION_API_KEY_PREFIX,
+                escape_for_key(tenant)?
+            ),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl NotificationApi for NotificationMgr {
+    #[async_backtrace::framed]
+    async fn add_notification_integration(
+        &self,
+        integration: NotificationIntegration,
+    ) -> Result<u64> {
+        let seq = MatchSeq::Exact(0);
+        let val = Operation::Update(serde_json::to_vec(&integration)?);
+        let key = format!(
+            "{}/{}",
+            self.integration_prefix,
+            escape_for_key(&integration.name)?
+        );
+        let upsert_info = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, val, None));
+
+        let res = upsert_info.await?.added_or_else(|v| {
+            ErrorCode::NotificationIntegrationAlreadyExists(format!(
+                "Notification integration already exists, seq [{}]",
+                v.seq
+            ))
+        })?;
+
+        Ok(res.seq)
+    }
+
+    #[async_backtrace::framed]
+    async fn get_notification_integration(
+        &self,
+        name: &str,
+        seq: MatchSeq,
+    ) -> Result<SeqV<NotificationIntegration>> {
+        let key = format!("{}/{}", self.integration_prefix, escape_for_key(name)?);
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value = res.ok_or_else(|| {
+            ErrorCode::UnknownNotificationIntegration(format!(
+                "Unknown notification integration {}",
+                name
+            ))
+        })?;
+
+        match seq.match_seq(&seq_value) {
+            Ok(_) => Ok(seq_value.into_seqv()?),
+            Err(_) => Err(ErrorCode::UnknownNotificationIntegration(format!(
+                "Unknown notification integration {}",
+                name
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn get_notification_integrations(&self) -> Result<Vec<NotificationIntegration>> {
+        let values = self.kv_api.prefix_list_kv(&self.integration_prefix).await?;
+
+        let mut integrations = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let integration = NotificationIntegration::try_from(value.data)?;
+            integrations.push(integration);
+        }
+        Ok(integrations)
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_notification_integration(&self, name: &str, seq: MatchSeq) -> Result<()> {
+        let key = format!("{}/{}", self.integration_prefix, escape_for_key(name)?);
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, Operation::Delete, None))
+            .await?;
+        if res.prev.is_some() && res.result.is_none() {
+            Ok(())
+        } else {
+            Err(ErrorCode::UnknownNotificationIntegration(format!(
+                "Unknown notification integration {}",
+                name
+            )))
+        }
+    }
+}
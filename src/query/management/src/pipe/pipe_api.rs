@@ -0,0 +1,62 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_exception::Result;
+use common_meta_app::principal::PipeBatch;
+use common_meta_app::principal::PipeInfo;
+use common_meta_app::principal::PipeLoad;
+use common_meta_types::MatchSeq;
+use common_meta_types::SeqV;
+
+#[async_trait::async_trait]
+pub trait PipeApi: Sync + Send {
+    // Add a pipe to /tenant/pipe-name.
+    async fn add_pipe(&self, pipe: PipeInfo) -> Result<u64>;
+
+    // Update the pipe if the seq matches.
+    async fn update_pipe(&self, pipe: PipeInfo, seq: MatchSeq) -> Result<u64>;
+
+    // Get pipe by name.
+    async fn get_pipe(&self, name: &str, seq: MatchSeq) -> Result<SeqV<PipeInfo>>;
+
+    // Get all the pipes for a tenant.
+    async fn get_pipes(&self) -> Result<Vec<PipeInfo>>;
+
+    // Drop the tenant's pipe by name.
+    async fn drop_pipe(&self, name: &str, seq: MatchSeq) -> Result<()>;
+
+    // Remove the deduplicate label of a batch once the offsets of the pipe advance past it.
+    async fn remove_batch_label(&self, pipe: &PipeInfo, batch: &PipeBatch) -> Result<()>;
+
+    // Add the files of a pipe from a stage to copy, the files added already are kept.
+    async fn add_pipe_files(&self, name: &str, files: &[String]) -> Result<()>;
+
+    // Get the files of a pipe waiting to be copied.
+    async fn get_pipe_files(&self, name: &str) -> Result<Vec<String>>;
+
+    // Remove the files of a pipe once they are copied.
+    async fn remove_pipe_files(&self, name: &str, files: &[String]) -> Result<()>;
+
+    // Record a load of a pipe, only the latest loads of a pipe are kept.
+    async fn add_pipe_load(&self, load: PipeLoad) -> Result<()>;
+
+    // Get the loads of a pipe, or of all the pipes of the tenant.
+    async fn get_pipe_loads(&self, name: Option<&str>) -> Result<Vec<PipeLoad>>;
+
+    // Take or renew the lease of the pipe runner of the tenant, returns whether
+    // `holder` holds the lease for `ttl` from now on.
+    async fn renew_runner_lease(&self, holder: &str, ttl: Duration) -> Result<bool>;
+}
@@ -0,0 +1,298 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::PipeBatch;
+use common_meta_app::principal::PipeInfo;
+use common_meta_app::principal::PipeLoad;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::IntoSeqV;
+use common_meta_types::KVMeta;
+use common_meta_types::MatchSeq;
+use common_meta_types::MatchSeqExt;
+use common_meta_types::MetaError;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+
+use crate::pipe::PipeApi;
+
+static PIPE_API_KEY_PREFIX: &str = "__fd_pipes";
+static PIPE_FILE_API_KEY_PREFIX: &str = "__fd_pipe_files";
+static PIPE_LOAD_API_KEY_PREFIX: &str = "__fd_pipe_loads";
+static PIPE_RUNNER_API_KEY_PREFIX: &str = "__fd_pipe_runner";
+
+/// How many of the latest loads of a pipe are kept.
+pub const MAX_PIPE_LOADS: usize = 100;
+
+pub struct PipeMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    tenant: String,
+    pipe_prefix: String,
+    pipe_file_prefix: String,
+    pipe_load_prefix: String,
+    runner_key: String,
+}
+
+impl PipeMgr {
+    pub fn create(kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>, tenant: &str) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while pipe mgr create)",
+            ));
+        }
+
+        let escaped = escape_for_key(tenant)?;
+        Ok(PipeMgr {
+            kv_api,
+            tenant: tenant.to_string(),
+            pipe_prefix: format!("{}/{}", PIPE_API_KEY_PREFIX, escaped),
+            pipe_file_prefix: format!("{}/{}", PIPE_FILE_API_KEY_PREFIX, escaped),
+            pipe_load_prefix: format!("{}/{}", PIPE_LOAD_API_KEY_PREFIX, escaped),
+            runner_key: format!("{}/{}", PIPE_RUNNER_API_KEY_PREFIX, escaped),
+        })
+    }
+
+    fn pipe_key(&self, name: &str) -> Result<String> {
+        Ok(format!("{}/{}", self.pipe_prefix, escape_for_key(name)?))
+    }
+
+    fn pipe_files_prefix(&self, name: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}/",
+            self.pipe_file_prefix,
+            escape_for_key(name)?
+        ))
+    }
+
+    fn pipe_loads_prefix(&self, name: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}/",
+            self.pipe_load_prefix,
+            escape_for_key(name)?
+        ))
+    }
+
+    #[async_backtrace::framed]
+    async fn delete_keys(&self, keys: impl IntoIterator<Item = String>) -> Result<()> {
+        for key in keys {
+            self.kv_api
+                .upsert_kv(UpsertKVReq::new(
+                    &key,
+                    MatchSeq::GE(0),
+                    Operation::Delete,
+                    None,
+                ))
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl PipeApi for PipeMgr {
+    #[async_backtrace::framed]
+    async fn add_pipe(&self, pipe: PipeInfo) -> Result<u64> {
+        let seq = MatchSeq::Exact(0);
+        let val = Operation::Update(serde_json::to_vec(&pipe)?);
+        let key = self.pipe_key(&pipe.name)?;
+        let upsert_info = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, val, None));
+
+        let res = upsert_info.await?.added_or_else(|v| {
+            ErrorCode::PipeAlreadyExists(format!("Pipe already exists, seq [{}]", v.seq))
+        })?;
+
+        Ok(res.seq)
+    }
+
+    #[async_backtrace::framed]
+    async fn update_pipe(&self, pipe: PipeInfo, seq: MatchSeq) -> Result<u64> {
+        let val = Operation::Update(serde_json::to_vec(&pipe)?);
+        let key = self.pipe_key(&pipe.name)?;
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, val, None))
+            .await?;
+
+        match (res.is_changed(), res.result) {
+            (true, Some(SeqV { seq: s, .. })) => Ok(s),
+            _ => Err(ErrorCode::UnknownPipe(format!(
+                "Unknown pipe, or seq not match {}",
+                pipe.name
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn get_pipe(&self, name: &str, seq: MatchSeq) -> Result<SeqV<PipeInfo>> {
+        let key = self.pipe_key(name)?;
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value =
+            res.ok_or_else(|| ErrorCode::UnknownPipe(format!("Unknown pipe {}", name)))?;
+
+        match seq.match_seq(&seq_value) {
+            Ok(_) => Ok(seq_value.into_seqv()?),
+            Err(_) => Err(ErrorCode::UnknownPipe(format!("Unknown pipe {}", name))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn get_pipes(&self) -> Result<Vec<PipeInfo>> {
+        let values = self.kv_api.prefix_list_kv(&self.pipe_prefix).await?;
+
+        let mut pipes = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let pipe = PipeInfo::try_from(value.data)?;
+            pipes.push(pipe);
+        }
+        Ok(pipes)
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_pipe(&self, name: &str, seq: MatchSeq) -> Result<()> {
+        let key = self.pipe_key(name)?;
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, Operation::Delete, None))
+            .await?;
+        if res.prev.is_none() || res.result.is_some() {
+            return Err(ErrorCode::UnknownPipe(format!("Unknown pipe {}", name)));
+        }
+
+        // the labels of the batches left by a failed pipe are removed as well.
+        let mut keys = vec![];
+        for prefix in [
+            self.pipe_files_prefix(name)?,
+            self.pipe_loads_prefix(name)?,
+            PipeInfo::batch_labels_prefix(&self.tenant, name),
+        ] {
+            let values = self.kv_api.prefix_list_kv(&prefix).await?;
+            keys.extend(values.into_iter().map(|(key, _)| key));
+        }
+        self.delete_keys(keys).await
+    }
+
+    #[async_backtrace::framed]
+    async fn remove_batch_label(&self, pipe: &PipeInfo, batch: &PipeBatch) -> Result<()> {
+        self.delete_keys([pipe.batch_label(&self.tenant, batch)])
+            .await
+    }
+
+    #[async_backtrace::framed]
+    async fn add_pipe_files(&self, name: &str, files: &[String]) -> Result<()> {
+        let prefix = self.pipe_files_prefix(name)?;
+        for file in files {
+            let key = format!("{}{}", prefix, escape_for_key(file)?);
+            let val = Operation::Update(file.as_bytes().to_vec());
+            self.kv_api
+                .upsert_kv(UpsertKVReq::new(&key, MatchSeq::GE(0), val, None))
+                .await?;
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn get_pipe_files(&self, name: &str) -> Result<Vec<String>> {
+        let values = self
+            .kv_api
+            .prefix_list_kv(&self.pipe_files_prefix(name)?)
+            .await?;
+
+        let mut files = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let file = String::from_utf8(value.data).map_err(|e| {
+                ErrorCode::IllegalPipeFormat(format!("Invalid file of pipe {}, {}", name, e))
+            })?;
+            files.push(file);
+        }
+        Ok(files)
+    }
+
+    #[async_backtrace::framed]
+    async fn remove_pipe_files(&self, name: &str, files: &[String]) -> Result<()> {
+        let prefix = self.pipe_files_prefix(name)?;
+        let mut keys = Vec::with_capacity(files.len());
+        for file in files {
+            keys.push(format!("{}{}", prefix, escape_for_key(file)?));
+        }
+        self.delete_keys(keys).await
+    }
+
+    #[async_backtrace::framed]
+    async fn add_pipe_load(&self, load: PipeLoad) -> Result<()> {
+        // the keys are ordered by the start time of the loads.
+        let prefix = self.pipe_loads_prefix(&load.pipe_name)?;
+        let started_at = load.started_at.timestamp_micros().max(0);
+        let key = format!("{}{:020}", prefix, started_at);
+        let val = Operation::Update(serde_json::to_vec(&load)?);
+        self.kv_api
+            .upsert_kv(UpsertKVReq::new(&key, MatchSeq::GE(0), val, None))
+            .await?;
+
+        let loads = self.kv_api.prefix_list_kv(&prefix).await?;
+        let expired = loads.len().saturating_sub(MAX_PIPE_LOADS);
+        self.delete_keys(loads.into_iter().take(expired).map(|(key, _)| key))
+            .await
+    }
+
+    #[async_backtrace::framed]
+    async fn get_pipe_loads(&self, name: Option<&str>) -> Result<Vec<PipeLoad>> {
+        let prefix = match name {
+            Some(name) => self.pipe_loads_prefix(name)?,
+            None => format!("{}/", self.pipe_load_prefix),
+        };
+        let values = self.kv_api.prefix_list_kv(&prefix).await?;
+
+        let mut loads = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            loads.push(serde_json::from_slice::<PipeLoad>(&value.data)?);
+        }
+        Ok(loads)
+    }
+
+    #[async_backtrace::framed]
+    async fn renew_runner_lease(&self, holder: &str, ttl: Duration) -> Result<bool> {
+        // an expired lease is not returned by the meta service, anyone can take it.
+        let seq = match self.kv_api.get_kv(&self.runner_key).await? {
+            Some(lease) if lease.data != holder.as_bytes() => return Ok(false),
+            Some(lease) => lease.seq,
+            None => 0,
+        };
+
+        let expire_at = (SystemTime::now() + ttl)
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards");
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &self.runner_key,
+                MatchSeq::Exact(seq),
+                Operation::Update(holder.as_bytes().to_vec()),
+                Some(KVMeta {
+                    expire_at: Some(expire_at.as_secs()),
+                }),
+            ))
+            .await?;
+        Ok(res.is_changed())
+    }
+}
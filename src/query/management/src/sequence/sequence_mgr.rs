@@ -0,0 +1,156 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::SequenceMeta;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::IntoSeqV;
+use common_meta_types::MatchSeq;
+use common_meta_types::MatchSeqExt;
+use common_meta_types::MetaError;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+
+use crate::sequence::SequenceApi;
+
+static SEQUENCE_API_KEY_PREFIX: &str = "__fd_sequences";
+
+pub struct SequenceMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    sequence_prefix: String,
+}
+
+impl SequenceMgr {
+    pub fn create(kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>, tenant: &str) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while sequence mgr create)",
+            ));
+        }
+
+        Ok(SequenceMgr {
+            kv_api,
+            sequence_prefix: format!("{}/{}", SEQUENCE_API_KEY_PREFIX, escape_for_key(tenant)?),
+        })
+    }
+
+    fn sequence_key(&self, name: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}",
+            self.sequence_prefix,
+            escape_for_key(name)?
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl SequenceApi for SequenceMgr {
+    #[async_backtrace::framed]
+    async fn add_sequence(&self, sequence: SequenceMeta) -> Result<u64> {
+        let seq = MatchSeq::Exact(0);
+        let val = Operation::Update(serde_json::to_vec(&sequence)?);
+        let key = self.sequence_key(&sequence.name)?;
+        let upsert_info = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, val, None));
+
+        let res = upsert_info.await?.added_or_else(|v| {
+            ErrorCode::SequenceAlreadyExists(format!("Sequence already exists, seq [{}]", v.seq))
+        })?;
+
+        Ok(res.seq)
+    }
+
+    #[async_backtrace::framed]
+    async fn get_sequence(&self, name: &str, seq: MatchSeq) -> Result<SeqV<SequenceMeta>> {
+        let key = self.sequence_key(name)?;
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value =
+            res.ok_or_else(|| ErrorCode::UnknownSequence(format!("Unknown sequence {}", name)))?;
+
+        match seq.match_seq(&seq_value) {
+            Ok(_) => Ok(seq_value.into_seqv()?),
+            Err(_) => Err(ErrorCode::UnknownSequence(format!(
+                "Unknown sequence {}",
+                name
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn get_sequences(&self) -> Result<Vec<SequenceMeta>> {
+        let values = self.kv_api.prefix_list_kv(&self.sequence_prefix).await?;
+
+        let mut sequences = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let sequence = serde_json::from_slice::<SequenceMeta>(&value.data)?;
+            sequences.push(sequence);
+        }
+        Ok(sequences)
+    }
+
+    #[async_backtrace::framed]
+    async fn next_sequence_values(&self, name: &str, count: u64) -> Result<(u64, u64)> {
+        let key = self.sequence_key(name)?;
+        loop {
+            let seq_value = self.get_sequence(name, MatchSeq::GE(1)).await?;
+            let mut sequence = seq_value.data;
+            let first = sequence.allocate(count)?;
+
+            // Only one of the concurrent writers updates the same version,
+            // the others read the sequence again and retry.
+            let val = Operation::Update(serde_json::to_vec(&sequence)?);
+            let res = self
+                .kv_api
+                .upsert_kv(UpsertKVReq::new(
+                    &key,
+                    MatchSeq::Exact(seq_value.seq),
+                    val,
+                    None,
+                ))
+                .await?;
+            if res.is_changed() {
+                return Ok((first, sequence.increment));
+            }
+            if res.prev.is_none() {
+                return Err(ErrorCode::UnknownSequence(format!(
+                    "Unknown sequence {}",
+                    name
+                )));
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_sequence(&self, name: &str, seq: MatchSeq) -> Result<()> {
+        let key = self.sequence_key(name)?;
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, Operation::Delete, None))
+            .await?;
+        if res.prev.is_some() && res.result.is_none() {
+            Ok(())
+        } else {
+            Err(ErrorCode::UnknownSequence(format!(
+                "Unknown sequence {}",
+                name
+            )))
+        }
+    }
+}
@@ -0,0 +1,49 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_exception::Result;
+use common_meta_app::principal::TaskMeta;
+use common_meta_app::principal::TaskRun;
+use common_meta_types::MatchSeq;
+use common_meta_types::SeqV;
+
+#[async_trait::async_trait]
+pub trait TaskApi: Sync + Send {
+    // Add a task to /tenant/task-name.
+    async fn add_task(&self, task: TaskMeta) -> Result<u64>;
+
+    // Update the task if the seq matches.
+    async fn update_task(&self, task: TaskMeta, seq: MatchSeq) -> Result<u64>;
+
+    // Get task by name.
+    async fn get_task(&self, name: &str, seq: MatchSeq) -> Result<SeqV<TaskMeta>>;
+
+    // Get all the tasks for a tenant.
+    async fn get_tasks(&self) -> Result<Vec<TaskMeta>>;
+
+    // Drop the tenant's task by name, with its runs.
+    async fn drop_task(&self, name: &str, seq: MatchSeq) -> Result<()>;
+
+    // Record a finished run of a task, only the latest runs of each task are kept.
+    async fn add_task_run(&self, run: TaskRun) -> Result<()>;
+
+    // Get the runs of a task, or of all the tasks, from the oldest to the latest.
+    async fn get_task_runs(&self, name: Option<&str>) -> Result<Vec<TaskRun>>;
+
+    // Take or renew the lease of the task scheduler of the tenant, returns whether
+    // `holder` holds the lease for `ttl` from now on.
+    async fn renew_scheduler_lease(&self, holder: &str, ttl: Duration) -> Result<bool>;
+}
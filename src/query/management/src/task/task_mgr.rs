@@ -0,0 +1,232 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::TaskMeta;
+use common_meta_app::principal::TaskRun;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::IntoSeqV;
+use common_meta_types::KVMeta;
+use common_meta_types::MatchSeq;
+use common_meta_types::MatchSeqExt;
+use common_meta_types::MetaError;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+
+use crate::task::TaskApi;
+
+static TASK_API_KEY_PREFIX: &str = "__fd_tasks";
+static TASK_RUN_API_KEY_PREFIX: &str = "__fd_task_runs";
+static TASK_SCHEDULER_API_KEY_PREFIX: &str = "__fd_task_scheduler";
+
+/// How many of the latest runs of a task are kept.
+pub const MAX_TASK_RUNS: usize = 100;
+
+pub struct TaskMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    task_prefix: String,
+    task_run_prefix: String,
+    scheduler_key: String,
+}
+
+impl TaskMgr {
+    pub fn create(kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>, tenant: &str) -> Result<Self> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty(while task mgr create)",
+            ));
+        }
+
+        let tenant = escape_for_key(tenant)?;
+        Ok(TaskMgr {
+            kv_api,
+            task_prefix: format!("{}/{}", TASK_API_KEY_PREFIX, tenant),
+            task_run_prefix: format!("{}/{}", TASK_RUN_API_KEY_PREFIX, tenant),
+            scheduler_key: format!("{}/{}", TASK_SCHEDULER_API_KEY_PREFIX, tenant),
+        })
+    }
+
+    fn task_key(&self, name: &str) -> Result<String> {
+        Ok(format!("{}/{}", self.task_prefix, escape_for_key(name)?))
+    }
+
+    fn task_runs_prefix(&self, name: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}/",
+            self.task_run_prefix,
+            escape_for_key(name)?
+        ))
+    }
+
+    #[async_backtrace::framed]
+    async fn delete_keys(&self, keys: impl IntoIterator<Item = String>) -> Result<()> {
+        for key in keys {
+            self.kv_api
+                .upsert_kv(UpsertKVReq::new(
+                    &key,
+                    MatchSeq::GE(0),
+                    Operation::Delete,
+                    None,
+                ))
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl TaskApi for TaskMgr {
+    #[async_backtrace::framed]
+    async fn add_task(&self, task: TaskMeta) -> Result<u64> {
+        let seq = MatchSeq::Exact(0);
+        let val = Operation::Update(serde_json::to_vec(&task)?);
+        let key = self.task_key(&task.name)?;
+        let upsert_info = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, val, None));
+
+        let res = upsert_info.await?.added_or_else(|v| {
+            ErrorCode::TaskAlreadyExists(format!("Task already exists, seq [{}]", v.seq))
+        })?;
+
+        Ok(res.seq)
+    }
+
+    #[async_backtrace::framed]
+    async fn update_task(&self, task: TaskMeta, seq: MatchSeq) -> Result<u64> {
+        let val = Operation::Update(serde_json::to_vec(&task)?);
+        let key = self.task_key(&task.name)?;
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, val, None))
+            .await?;
+
+        match (res.is_changed(), res.result) {
+            (true, Some(SeqV { seq: s, .. })) => Ok(s),
+            _ => Err(ErrorCode::UnknownTask(format!(
+                "Unknown task, or seq not match {}",
+                task.name
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn get_task(&self, name: &str, seq: MatchSeq) -> Result<SeqV<TaskMeta>> {
+        let key = self.task_key(name)?;
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value =
+            res.ok_or_else(|| ErrorCode::UnknownTask(format!("Unknown task {}", name)))?;
+
+        match seq.match_seq(&seq_value) {
+            Ok(_) => Ok(seq_value.into_seqv()?),
+            Err(_) => Err(ErrorCode::UnknownTask(format!("Unknown task {}", name))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn get_tasks(&self) -> Result<Vec<TaskMeta>> {
+        let values = self.kv_api.prefix_list_kv(&self.task_prefix).await?;
+
+        let mut tasks = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let task = TaskMeta::try_from(value.data)?;
+            tasks.push(task);
+        }
+        Ok(tasks)
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_task(&self, name: &str, seq: MatchSeq) -> Result<()> {
+        let key = self.task_key(name)?;
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, Operation::Delete, None))
+            .await?;
+        if res.prev.is_none() || res.result.is_some() {
+            return Err(ErrorCode::UnknownTask(format!("Unknown task {}", name)));
+        }
+
+        let runs = self
+            .kv_api
+            .prefix_list_kv(&self.task_runs_prefix(name)?)
+            .await?;
+        self.delete_keys(runs.into_iter().map(|(key, _)| key)).await
+    }
+
+    #[async_backtrace::framed]
+    async fn add_task_run(&self, run: TaskRun) -> Result<()> {
+        // the keys are ordered by the start time of the runs.
+        let prefix = self.task_runs_prefix(&run.task_name)?;
+        let started_at = run.started_at.timestamp_micros().max(0);
+        let key = format!("{}{:020}", prefix, started_at);
+        let val = Operation::Update(serde_json::to_vec(&run)?);
+        self.kv_api
+            .upsert_kv(UpsertKVReq::new(&key, MatchSeq::GE(0), val, None))
+            .await?;
+
+        let runs = self.kv_api.prefix_list_kv(&prefix).await?;
+        let expired = runs.len().saturating_sub(MAX_TASK_RUNS);
+        self.delete_keys(runs.into_iter().take(expired).map(|(key, _)| key))
+            .await
+    }
+
+    #[async_backtrace::framed]
+    async fn get_task_runs(&self, name: Option<&str>) -> Result<Vec<TaskRun>> {
+        let prefix = match name {
+            Some(name) => self.task_runs_prefix(name)?,
+            None => format!("{}/", self.task_run_prefix),
+        };
+        let values = self.kv_api.prefix_list_kv(&prefix).await?;
+
+        let mut runs = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            runs.push(serde_json::from_slice::<TaskRun>(&value.data)?);
+        }
+        Ok(runs)
+    }
+
+    #[async_backtrace::framed]
+    async fn renew_scheduler_lease(&self, holder: &str, ttl: Duration) -> Result<bool> {
+        // an expired lease is not returned by the meta service, anyone can take it.
+        let seq = match self.kv_api.get_kv(&self.scheduler_key).await? {
+            Some(lease) if lease.data != holder.as_bytes() => return Ok(false),
+            Some(lease) => lease.seq,
+            None => 0,
+        };
+
+        let expire_at = (SystemTime::now() + ttl)
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards");
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &self.scheduler_key,
+                MatchSeq::Exact(seq),
+                Operation::Update(holder.as_bytes().to_vec()),
+                Some(KVMeta {
+                    expire_at: Some(expire_at.as_secs()),
+                }),
+            ))
+            .await?;
+        Ok(res.is_changed())
+    }
+}
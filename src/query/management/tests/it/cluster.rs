@@ -139,6 +139,49 @@ async fn test_successfully_heartbeat_node() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_successfully_update_node() -> Result<()> {
+    let current_time = current_seconds_time();
+    let (kv_api, cluster_api) = new_cluster_api().await?;
+
+    let mut node_info = create_test_node_info();
+    cluster_api.add_node(node_info.clone()).await?;
+
+    node_info.draining = true;
+    cluster_api
+        .update_node(node_info.clone(), MatchSeq::GE(1))
+        .await?;
+
+    let value = kv_api
+        .get_kv("__fd_clusters/test%2dtenant%2did/test%2dcluster%2did/databend_query/test_node")
+        .await?;
+    match value {
+        Some(SeqV {
+            seq: 2,
+            meta,
+            data: value,
+        }) => {
+            assert!(meta.unwrap().expire_at.unwrap() - current_time >= 60);
+            assert_eq!(value, serde_json::to_vec(&node_info)?);
+        }
+        catch => panic!("GetKVActionReply{:?}", catch),
+    }
+
+    let nodes = cluster_api.get_nodes().await?;
+    assert_eq!(nodes, vec![node_info.clone()]);
+
+    // the node dropped can't be updated.
+    cluster_api
+        .drop_node(node_info.id.clone(), MatchSeq::GE(1))
+        .await?;
+    match cluster_api.update_node(node_info, MatchSeq::GE(1)).await {
+        Ok(_) => panic!("Unknown node update node must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2401),
+    }
+
+    Ok(())
+}
+
 fn current_seconds_time() -> u64 {
     let now = std::time::SystemTime::now();
     now.duration_since(UNIX_EPOCH)
@@ -153,6 +196,7 @@ fn create_test_node_info() -> NodeInfo {
         version: 0,
         flight_address: String::from("ip:port"),
         binary_version: "binary_version".to_string(),
+        draining: false,
     }
 }
 
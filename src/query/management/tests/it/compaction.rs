@@ -0,0 +1,43 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_embedded::MetaEmbedded;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_renew_compactor_lease() -> Result<()> {
+    let (kv_api, compaction_api) = new_compaction_api().await?;
+
+    let ttl = Duration::from_secs(60);
+    assert!(compaction_api.renew_compactor_lease("node1", ttl).await?);
+    assert!(!compaction_api.renew_compactor_lease("node2", ttl).await?);
+    assert!(compaction_api.renew_compactor_lease("node1", ttl).await?);
+
+    // the lease of another tenant is not shared.
+    let other = CompactionMgr::create(kv_api, "other")?;
+    assert!(other.renew_compactor_lease("node2", ttl).await?);
+
+    Ok(())
+}
+
+async fn new_compaction_api() -> Result<(Arc<MetaEmbedded>, CompactionMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = CompactionMgr::create(test_api.clone(), "admin")?;
+    Ok((test_api, mgr))
+}
@@ -0,0 +1,128 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_base::base::tokio;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_management::*;
+use common_meta_app::principal::DictionaryMeta;
+use common_meta_app::principal::DictionarySource;
+use common_meta_app::principal::MysqlDictionarySource;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_types::MatchSeq;
+use common_meta_types::SeqV;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_dictionary() -> Result<()> {
+    let (kv_api, dictionary_api) = new_dictionary_api().await?;
+
+    let dictionary = create_test_dictionary();
+    dictionary_api.add_dictionary(dictionary.clone()).await?;
+    let value = kv_api.get_kv("__fd_dictionaries/admin/user_info").await?;
+
+    match value {
+        Some(SeqV {
+            seq: 1,
+            meta: _,
+            data: value,
+        }) => {
+            assert_eq!(value, serde_json::to_vec(&dictionary)?);
+        }
+        catch => panic!("GetKVActionReply{:?}", catch),
+    }
+
+    let got = dictionary_api
+        .get_dictionary("user_info", MatchSeq::GE(0))
+        .await?;
+    assert_eq!(got.data, dictionary);
+    assert_eq!(dictionary.attributes().collect::<Vec<_>>(), vec!["name"]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_already_exists_add_dictionary() -> Result<()> {
+    let (_, dictionary_api) = new_dictionary_api().await?;
+
+    let dictionary = create_test_dictionary();
+    dictionary_api.add_dictionary(dictionary.clone()).await?;
+
+    match dictionary_api.add_dictionary(dictionary.clone()).await {
+        Ok(_) => panic!("Already exists add dictionary must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2606),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_successfully_drop_dictionary() -> Result<()> {
+    let (_, dictionary_api) = new_dictionary_api().await?;
+
+    let dictionary = create_test_dictionary();
+    dictionary_api.add_dictionary(dictionary.clone()).await?;
+
+    let dictionaries = dictionary_api.get_dictionaries().await?;
+    assert_eq!(dictionaries, vec![dictionary.clone()]);
+
+    dictionary_api
+        .drop_dictionary(&dictionary.name, MatchSeq::GE(1))
+        .await?;
+
+    let dictionaries = dictionary_api.get_dictionaries().await?;
+    assert_eq!(dictionaries, vec![]);
+
+    match dictionary_api
+        .drop_dictionary(&dictionary.name, MatchSeq::GE(1))
+        .await
+    {
+        Ok(_) => panic!("Unknown dictionary drop must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2605),
+    }
+    Ok(())
+}
+
+fn create_test_dictionary() -> DictionaryMeta {
+    DictionaryMeta {
+        name: "user_info".to_string(),
+        source: DictionarySource::Mysql(MysqlDictionarySource {
+            host: "127.0.0.1".to_string(),
+            port: 3306,
+            username: "root".to_string(),
+            password: "".to_string(),
+            database: "db".to_string(),
+            table: "users".to_string(),
+        }),
+        schema: TableSchema::new(vec![
+            TableField::new("id", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("name", TableDataType::String),
+        ]),
+        primary_key: "id".to_string(),
+        comment: "".to_string(),
+        created_on: Utc::now(),
+    }
+}
+
+async fn new_dictionary_api() -> Result<(Arc<MetaEmbedded>, DictionaryMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = DictionaryMgr::create(test_api.clone(), "admin")?;
+    Ok((test_api, mgr))
+}
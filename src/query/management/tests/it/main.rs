@@ -15,7 +15,15 @@
 #![allow(clippy::uninlined_format_args)]
 
 mod cluster;
+mod compaction;
+mod dictionary;
+mod network_policy;
+mod notification;
+mod pipe;
+mod row_access_policy;
+mod sequence;
 mod setting;
 mod stage;
+mod task;
 mod udf;
 mod user;
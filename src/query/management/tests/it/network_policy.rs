@@ -0,0 +1,112 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_base::base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_app::principal::NetworkPolicy;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_types::MatchSeq;
+use common_meta_types::SeqV;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_network_policy() -> Result<()> {
+    let (kv_api, policy_api) = new_network_policy_api().await?;
+
+    let policy = create_test_policy();
+    policy_api.add_network_policy(policy.clone()).await?;
+    let value = kv_api
+        .get_kv("__fd_network_policies/admin/office_policy")
+        .await?;
+
+    match value {
+        Some(SeqV {
+            seq: 1,
+            meta: _,
+            data: value,
+        }) => {
+            assert_eq!(value, serde_json::to_vec(&policy)?);
+        }
+        catch => panic!("GetKVActionReply{:?}", catch),
+    }
+
+    let got = policy_api
+        .get_network_policy("office_policy", MatchSeq::GE(0))
+        .await?;
+    assert_eq!(got.data, policy);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_already_exists_add_network_policy() -> Result<()> {
+    let (_, policy_api) = new_network_policy_api().await?;
+
+    let policy = create_test_policy();
+    policy_api.add_network_policy(policy.clone()).await?;
+
+    match policy_api.add_network_policy(policy.clone()).await {
+        Ok(_) => panic!("Already exists add network policy must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2614),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_successfully_drop_network_policy() -> Result<()> {
+    let (_, policy_api) = new_network_policy_api().await?;
+
+    let policy = create_test_policy();
+    policy_api.add_network_policy(policy.clone()).await?;
+
+    let policies = policy_api.get_network_policies().await?;
+    assert_eq!(policies, vec![policy.clone()]);
+
+    policy_api
+        .drop_network_policy(&policy.name, MatchSeq::GE(1))
+        .await?;
+
+    let policies = policy_api.get_network_policies().await?;
+    assert_eq!(policies, vec![]);
+
+    match policy_api
+        .drop_network_policy(&policy.name, MatchSeq::GE(1))
+        .await
+    {
+        Ok(_) => panic!("Unknown network policy drop must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2613),
+    }
+    Ok(())
+}
+
+fn create_test_policy() -> NetworkPolicy {
+    NetworkPolicy {
+        name: "office_policy".to_string(),
+        allowed_ip_list: vec!["192.168.1.0/24".to_string()],
+        blocked_ip_list: vec!["192.168.1.99".to_string()],
+        comment: "".to_string(),
+        created_on: Utc::now(),
+    }
+}
+
+async fn new_network_policy_api() -> Result<(Arc<MetaEmbedded>, NetworkPolicyMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = NetworkPolicyMgr::create(test_api.clone(), "admin")?;
+    Ok((test_api, mgr))
+}
@@ -0,0 +1,113 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_base::base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_app::principal::NotificationIntegration;
+use common_meta_app::principal::NotificationTarget;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_types::MatchSeq;
+use common_meta_types::SeqV;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_notification_integration() -> Result<()> {
+    let (kv_api, notification_api) = new_notification_api().await?;
+
+    let integration = create_test_integration();
+    notification_api
+        .add_notification_integration(integration.clone())
+        .await?;
+    let value = kv_api
+        .get_kv("__fd_notification_integrations/admin/orders_hook")
+        .await?;
+
+    match value {
+        Some(SeqV {
+            seq: 1,
+            meta: _,
+            data: value,
+        }) => {
+            assert_eq!(value, serde_json::to_vec(&integration)?);
+        }
+        catch => panic!("GetKVActionReply{:?}", catch),
+    }
+
+    let got = notification_api
+        .get_notification_integration("orders_hook", MatchSeq::GE(0))
+        .await?;
+    assert_eq!(got.data, integration);
+
+    match notification_api
+        .add_notification_integration(integration.clone())
+        .await
+    {
+        Ok(_) => panic!("Already exists add notification integration must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2624),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_successfully_drop_notification_integration() -> Result<()> {
+    let (_, notification_api) = new_notification_api().await?;
+
+    let integration = create_test_integration();
+    notification_api
+        .add_notification_integration(integration.clone())
+        .await?;
+
+    let integrations = notification_api.get_notification_integrations().await?;
+    assert_eq!(integrations, vec![integration.clone()]);
+
+    notification_api
+        .drop_notification_integration(&integration.name, MatchSeq::GE(1))
+        .await?;
+
+    let integrations = notification_api.get_notification_integrations().await?;
+    assert_eq!(integrations, vec![]);
+
+    match notification_api
+        .drop_notification_integration(&integration.name, MatchSeq::GE(1))
+        .await
+    {
+        Ok(_) => panic!("Unknown notification integration drop must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2623),
+    }
+    Ok(())
+}
+
+fn create_test_integration() -> NotificationIntegration {
+    NotificationIntegration {
+        name: "orders_hook".to_string(),
+        target: NotificationTarget::Webhook {
+            url: "https://example.com/hook".to_string(),
+            method: "POST".to_string(),
+            authorization_header: "".to_string(),
+        },
+        comment: "".to_string(),
+        created_on: Utc::now(),
+    }
+}
+
+async fn new_notification_api() -> Result<(Arc<MetaEmbedded>, NotificationMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = NotificationMgr::create(test_api.clone(), "admin")?;
+    Ok((test_api, mgr))
+}
@@ -0,0 +1,240 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::TimeZone;
+use chrono::Utc;
+use common_base::base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_app::principal::FileFormatParams;
+use common_meta_app::principal::NdJsonFileFormatParams;
+use common_meta_app::principal::PipeBatch;
+use common_meta_app::principal::PipeInfo;
+use common_meta_app::principal::PipeLoad;
+use common_meta_app::principal::PipeSource;
+use common_meta_app::principal::UserIdentity;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::MatchSeq;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_pipe() -> Result<()> {
+    let (kv_api, pipe_api) = new_pipe_api().await?;
+
+    let pipe = create_test_pipe();
+    pipe_api.add_pipe(pipe.clone()).await?;
+    let value = kv_api.get_kv("__fd_pipes/admin/orders_pipe").await?;
+
+    match value {
+        Some(SeqV {
+            seq: 1,
+            meta: _,
+            data: value,
+        }) => {
+            assert_eq!(value, serde_json::to_vec(&pipe)?);
+        }
+        catch => panic!("GetKVActionReply{:?}", catch),
+    }
+
+    match pipe_api.add_pipe(pipe.clone()).await {
+        Ok(_) => panic!("Already exists add pipe must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2628),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_update_pipe_with_seq() -> Result<()> {
+    let (_, pipe_api) = new_pipe_api().await?;
+
+    let pipe = create_test_pipe();
+    pipe_api.add_pipe(pipe.clone()).await?;
+
+    let got = pipe_api.get_pipe(&pipe.name, MatchSeq::GE(0)).await?;
+    let mut pending = got.data.clone();
+    pending.pending_batch = Some(PipeBatch {
+        id: 0,
+        ranges: [(0, (0, 10))].into_iter().collect(),
+    });
+    pending.next_batch_id = 1;
+    pipe_api
+        .update_pipe(pending.clone(), MatchSeq::Exact(got.seq))
+        .await?;
+
+    // another runner updating from the same read must fail.
+    match pipe_api
+        .update_pipe(pending, MatchSeq::Exact(got.seq))
+        .await
+    {
+        Ok(_) => panic!("Update pipe with a stale seq must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2627),
+    }
+
+    let got = pipe_api.get_pipe(&pipe.name, MatchSeq::GE(0)).await?;
+    assert_eq!(got.data.next_batch_id, 1);
+    assert!(got.data.pending_batch.is_some());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_successfully_drop_pipe() -> Result<()> {
+    let (_, pipe_api) = new_pipe_api().await?;
+
+    let pipe = create_test_pipe();
+    pipe_api.add_pipe(pipe.clone()).await?;
+
+    let pipes = pipe_api.get_pipes().await?;
+    assert_eq!(pipes, vec![pipe.clone()]);
+
+    pipe_api.drop_pipe(&pipe.name, MatchSeq::GE(1)).await?;
+
+    let pipes = pipe_api.get_pipes().await?;
+    assert_eq!(pipes, vec![]);
+
+    match pipe_api.drop_pipe(&pipe.name, MatchSeq::GE(1)).await {
+        Ok(_) => panic!("Unknown pipe drop must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2627),
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_pipe_files() -> Result<()> {
+    let (kv_api, pipe_api) = new_pipe_api().await?;
+
+    let pipe = create_test_pipe();
+    pipe_api.add_pipe(pipe.clone()).await?;
+    let files = vec!["a/1.csv".to_string(), "a/2.csv".to_string()];
+    pipe_api.add_pipe_files(&pipe.name, &files).await?;
+    // the events of a file may be delivered more than once.
+    pipe_api.add_pipe_files(&pipe.name, &files[..1]).await?;
+    assert_eq!(pipe_api.get_pipe_files(&pipe.name).await?, files);
+
+    pipe_api.remove_pipe_files(&pipe.name, &files[..1]).await?;
+    assert_eq!(pipe_api.get_pipe_files(&pipe.name).await?, files[1..]);
+
+    pipe_api.drop_pipe(&pipe.name, MatchSeq::GE(1)).await?;
+    let values = kv_api
+        .prefix_list_kv("__fd_pipe_files/admin/orders_pipe/")
+        .await?;
+    assert!(values.is_empty());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_pipe_batch_labels() -> Result<()> {
+    let (kv_api, pipe_api) = new_pipe_api().await?;
+
+    let pipe = create_test_pipe();
+    pipe_api.add_pipe(pipe.clone()).await?;
+    let batches = (0..2)
+        .map(|id| PipeBatch {
+            id,
+            ranges: [(0, (id * 10, id * 10 + 10))].into_iter().collect(),
+        })
+        .collect::<Vec<_>>();
+    // the labels are committed along with the snapshots of the loads.
+    for batch in &batches {
+        let label = pipe.batch_label("admin", batch);
+        kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &label,
+                MatchSeq::GE(0),
+                Operation::Update(vec![1]),
+                None,
+            ))
+            .await?;
+    }
+
+    let prefix = PipeInfo::batch_labels_prefix("admin", &pipe.name);
+    pipe_api.remove_batch_label(&pipe, &batches[0]).await?;
+    let values = kv_api.prefix_list_kv(&prefix).await?;
+    assert_eq!(values.len(), 1);
+    assert_eq!(values[0].0, pipe.batch_label("admin", &batches[1]));
+
+    pipe_api.drop_pipe(&pipe.name, MatchSeq::GE(1)).await?;
+    assert!(kv_api.prefix_list_kv(&prefix).await?.is_empty());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_pipe_loads() -> Result<()> {
+    let (kv_api, pipe_api) = new_pipe_api().await?;
+
+    let pipe = create_test_pipe();
+    pipe_api.add_pipe(pipe.clone()).await?;
+    for i in 0..MAX_PIPE_LOADS as i64 + 5 {
+        pipe_api
+            .add_pipe_load(new_pipe_load(&pipe.name, 1_000_000 + i))
+            .await?;
+    }
+
+    // only the latest loads are kept, from the oldest to the latest.
+    let loads = pipe_api.get_pipe_loads(Some(&pipe.name)).await?;
+    assert_eq!(loads.len(), MAX_PIPE_LOADS);
+    assert_eq!(loads[0], new_pipe_load(&pipe.name, 1_000_005));
+    assert_eq!(pipe_api.get_pipe_loads(None).await?.len(), MAX_PIPE_LOADS);
+
+    pipe_api.drop_pipe(&pipe.name, MatchSeq::GE(1)).await?;
+    assert!(pipe_api.get_pipe_loads(None).await?.is_empty());
+    let values = kv_api
+        .prefix_list_kv("__fd_pipe_loads/admin/orders_pipe/")
+        .await?;
+    assert!(values.is_empty());
+    Ok(())
+}
+
+fn new_pipe_load(name: &str, micros: i64) -> PipeLoad {
+    let at = Utc
+        .timestamp_opt(micros / 1_000_000, (micros % 1_000_000 * 1000) as u32)
+        .unwrap();
+    PipeLoad {
+        pipe_name: name.to_string(),
+        started_at: at,
+        finished_at: at,
+        messages: 10,
+        files: vec![],
+        succeeded: true,
+        error_code: 0,
+        error_message: "".to_string(),
+    }
+}
+
+fn create_test_pipe() -> PipeInfo {
+    PipeInfo::new(
+        "orders_pipe",
+        UserIdentity::new("root", "%"),
+        "default".to_string(),
+        "orders".to_string(),
+        PipeSource::Kafka {
+            rest_proxy_url: "http://proxy:8082".to_string(),
+            topic: "orders".to_string(),
+            authorization_header: "".to_string(),
+        },
+        FileFormatParams::NdJson(NdJsonFileFormatParams::default()),
+        "".to_string(),
+    )
+}
+
+async fn new_pipe_api() -> Result<(Arc<MetaEmbedded>, PipeMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = PipeMgr::create(test_api.clone(), "admin")?;
+    Ok((test_api, mgr))
+}
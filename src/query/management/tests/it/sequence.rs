@@ -0,0 +1,125 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_app::principal::SequenceMeta;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_types::MatchSeq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_sequence() -> Result<()> {
+    let (kv_api, sequence_api) = new_sequence_api().await?;
+
+    let sequence = SequenceMeta::new("seq1", 10, 5, "".to_string());
+    sequence_api.add_sequence(sequence.clone()).await?;
+    let value = kv_api.get_kv("__fd_sequences/admin/seq1").await?;
+    assert_eq!(value.unwrap().data, serde_json::to_vec(&sequence)?);
+
+    match sequence_api.add_sequence(sequence.clone()).await {
+        Ok(_) => panic!("Already exists add sequence must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2618),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_next_sequence_values() -> Result<()> {
+    let (_, sequence_api) = new_sequence_api().await?;
+
+    sequence_api
+        .add_sequence(SequenceMeta::new("seq1", 10, 5, "".to_string()))
+        .await?;
+    assert_eq!(sequence_api.next_sequence_values("seq1", 3).await?, (10, 5));
+    assert_eq!(sequence_api.next_sequence_values("seq1", 1).await?, (25, 5));
+
+    let got = sequence_api.get_sequence("seq1", MatchSeq::GE(0)).await?;
+    assert_eq!(got.data.current, 30);
+
+    match sequence_api.next_sequence_values("seq2", 1).await {
+        Ok(_) => panic!("Unknown sequence must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2617),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_concurrent_next_sequence_values() -> Result<()> {
+    let (_, sequence_api) = new_sequence_api().await?;
+    let sequence_api = Arc::new(sequence_api);
+
+    sequence_api
+        .add_sequence(SequenceMeta::new("seq1", 1, 1, "".to_string()))
+        .await?;
+
+    let mut handles = vec![];
+    for _ in 0..8 {
+        let sequence_api = sequence_api.clone();
+        handles.push(tokio::spawn(async move {
+            let mut firsts = vec![];
+            for _ in 0..10 {
+                firsts.push(sequence_api.next_sequence_values("seq1", 10).await?.0);
+            }
+            Result::<Vec<u64>>::Ok(firsts)
+        }));
+    }
+
+    let mut values = HashSet::new();
+    for handle in handles {
+        for first in handle.await.unwrap()? {
+            for v in first..first + 10 {
+                assert!(values.insert(v), "value {} is allocated twice", v);
+            }
+        }
+    }
+    assert_eq!(values.len(), 800);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_drop_sequence() -> Result<()> {
+    let (_, sequence_api) = new_sequence_api().await?;
+
+    let sequence = SequenceMeta::new("seq1", 1, 1, "".to_string());
+    sequence_api.add_sequence(sequence.clone()).await?;
+    assert_eq!(sequence_api.get_sequences().await?, vec![sequence.clone()]);
+
+    sequence_api
+        .drop_sequence(&sequence.name, MatchSeq::GE(1))
+        .await?;
+    assert_eq!(sequence_api.get_sequences().await?, vec![]);
+
+    match sequence_api
+        .drop_sequence(&sequence.name, MatchSeq::GE(1))
+        .await
+    {
+        Ok(_) => panic!("Unknown sequence drop must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2617),
+    }
+    Ok(())
+}
+
+async fn new_sequence_api() -> Result<(Arc<MetaEmbedded>, SequenceMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = SequenceMgr::create(test_api.clone(), "admin")?;
+    Ok((test_api, mgr))
+}
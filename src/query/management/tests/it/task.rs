@@ -0,0 +1,169 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::TimeZone;
+use chrono::Utc;
+use common_base::base::tokio;
+use common_exception::Result;
+use common_management::*;
+use common_meta_app::principal::TaskMeta;
+use common_meta_app::principal::TaskRun;
+use common_meta_app::principal::TaskRunTrigger;
+use common_meta_app::principal::TaskSchedule;
+use common_meta_app::principal::TaskState;
+use common_meta_app::principal::UserIdentity;
+use common_meta_embedded::MetaEmbedded;
+use common_meta_kvapi::kvapi::KVApi;
+use common_meta_types::MatchSeq;
+
+fn new_task(name: &str) -> TaskMeta {
+    TaskMeta::new(
+        name,
+        UserIdentity::new("root", "%"),
+        "default".to_string(),
+        TaskSchedule::Interval { secs: 60 },
+        "INSERT INTO t1 VALUES (1)".to_string(),
+        2,
+        "".to_string(),
+    )
+}
+
+fn new_task_run(name: &str, micros: i64) -> TaskRun {
+    let at = Utc
+        .timestamp_opt(micros / 1_000_000, (micros % 1_000_000 * 1000) as u32)
+        .unwrap();
+    TaskRun {
+        task_name: name.to_string(),
+        trigger: TaskRunTrigger::Schedule,
+        query_id: format!("query-{}", micros),
+        scheduled_at: at,
+        started_at: at,
+        finished_at: at,
+        attempts: 1,
+        succeeded: true,
+        error_code: 0,
+        error_message: "".to_string(),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_add_task() -> Result<()> {
+    let (kv_api, task_api) = new_task_api().await?;
+
+    let task = new_task("task1");
+    task_api.add_task(task.clone()).await?;
+    let value = kv_api.get_kv("__fd_tasks/admin/task1").await?;
+    assert_eq!(value.unwrap().data, serde_json::to_vec(&task)?);
+
+    match task_api.add_task(task.clone()).await {
+        Ok(_) => panic!("Already exists add task must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2621),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_update_task() -> Result<()> {
+    let (_, task_api) = new_task_api().await?;
+
+    task_api.add_task(new_task("task1")).await?;
+    let got = task_api.get_task("task1", MatchSeq::GE(0)).await?;
+    let mut task = got.data;
+    task.state = TaskState::Started;
+    task_api
+        .update_task(task.clone(), MatchSeq::Exact(got.seq))
+        .await?;
+    assert_eq!(task_api.get_tasks().await?, vec![task.clone()]);
+
+    // the task has been updated since it was read.
+    match task_api.update_task(task, MatchSeq::Exact(got.seq)).await {
+        Ok(_) => panic!("Update task of a stale seq must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2620),
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_task_runs() -> Result<()> {
+    let (kv_api, task_api) = new_task_api().await?;
+
+    task_api.add_task(new_task("task1")).await?;
+    task_api.add_task(new_task("task11")).await?;
+    for i in 0..MAX_TASK_RUNS as i64 + 5 {
+        task_api
+            .add_task_run(new_task_run("task1", 1_000_000 + i))
+            .await?;
+    }
+    task_api
+        .add_task_run(new_task_run("task11", 1_000_000))
+        .await?;
+
+    // only the latest runs are kept, from the oldest to the latest.
+    let runs = task_api.get_task_runs(Some("task1")).await?;
+    assert_eq!(runs.len(), MAX_TASK_RUNS);
+    assert_eq!(runs[0], new_task_run("task1", 1_000_005));
+    assert_eq!(
+        runs[MAX_TASK_RUNS - 1],
+        new_task_run("task1", 1_000_000 + MAX_TASK_RUNS as i64 + 4)
+    );
+    assert_eq!(task_api.get_task_runs(None).await?.len(), MAX_TASK_RUNS + 1);
+
+    task_api.drop_task("task1", MatchSeq::GE(1)).await?;
+    assert!(task_api.get_task_runs(Some("task1")).await?.is_empty());
+    assert_eq!(task_api.get_task_runs(Some("task11")).await?, vec![
+        new_task_run("task11", 1_000_000)
+    ]);
+    let runs = kv_api.prefix_list_kv("__fd_task_runs/admin/task1/").await?;
+    assert!(runs.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_drop_task() -> Result<()> {
+    let (_, task_api) = new_task_api().await?;
+
+    task_api.add_task(new_task("task1")).await?;
+    task_api.drop_task("task1", MatchSeq::GE(1)).await?;
+    assert_eq!(task_api.get_tasks().await?, vec![]);
+
+    match task_api.drop_task("task1", MatchSeq::GE(1)).await {
+        Ok(_) => panic!("Unknown task drop must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2620),
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_renew_scheduler_lease() -> Result<()> {
+    let (_, task_api) = new_task_api().await?;
+
+    let ttl = Duration::from_secs(60);
+    assert!(task_api.renew_scheduler_lease("node1", ttl).await?);
+    assert!(!task_api.renew_scheduler_lease("node2", ttl).await?);
+    assert!(task_api.renew_scheduler_lease("node1", ttl).await?);
+
+    Ok(())
+}
+
+async fn new_task_api() -> Result<(Arc<MetaEmbedded>, TaskMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = TaskMgr::create(test_api.clone(), "admin")?;
+    Ok((test_api, mgr))
+}
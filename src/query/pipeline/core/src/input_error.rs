@@ -18,4 +18,17 @@ use common_exception::ErrorCode;
 pub struct InputError {
     pub err: ErrorCode,
     pub num: usize,
+    /// The rows skipped with this error in `ON_ERROR = CONTINUE` mode.
+    pub rejected: Vec<RejectedRecord>,
+}
+
+/// A malformed row skipped by `ON_ERROR = CONTINUE`, which is quarantined into the
+/// rejected-records file of the COPY.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RejectedRecord {
+    pub file: String,
+    /// 1-based row number in the file, unknown if the file is read in parallel splits.
+    pub line: Option<usize>,
+    pub error: String,
+    pub record: String,
 }
@@ -21,6 +21,7 @@ pub mod pipeline_display;
 pub mod unsafe_cell_wrap;
 
 pub use input_error::InputError;
+pub use input_error::RejectedRecord;
 pub use pipe::SinkPipeBuilder;
 pub use pipe::SourcePipeBuilder;
 pub use pipe::TransformPipeBuilder;
@@ -18,6 +18,7 @@ use std::sync::Arc;
 
 use common_exception::Result;
 use common_expression::DataBlock;
+use common_profile::ProcessorStats;
 
 use crate::processors::UpdateTrigger;
 use crate::unsafe_cell_wrap::UnSafeCellWrap;
@@ -216,6 +217,10 @@ impl OutputPort {
 
     #[inline(always)]
     pub fn push_data(&self, data: Result<DataBlock>) {
+        if let Ok(block) = &data {
+            ProcessorStats::record_output(block.num_rows(), block.memory_size());
+        }
+
         unsafe {
             UpdateTrigger::update_output(&self.update_trigger);
 
@@ -0,0 +1,263 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_compress::CompressAlgorithm;
+use common_compress::DecompressDecoder;
+use common_exception::Result;
+use common_meta_app::principal::AvroFileFormatParams;
+use common_meta_app::principal::CsvFileFormatParams;
+use common_meta_app::principal::FileFormatParams;
+use common_meta_app::principal::NdJsonFileFormatParams;
+use common_meta_app::principal::OrcFileFormatParams;
+use common_meta_app::principal::ParquetFileFormatParams;
+use common_meta_app::principal::StageFileCompression;
+use common_meta_app::principal::XmlFileFormatParams;
+use opendal::Operator;
+
+use crate::input_formats::input_format_text::decompress;
+use crate::input_formats::InputContext;
+
+/// Number of bytes read from the head of a file to detect its format.
+pub const FORMAT_DETECT_SAMPLE_SIZE: u64 = 64 * 1024;
+
+/// Max number of lines used by the CSV heuristics.
+const MAX_SAMPLE_LINES: usize = 100;
+
+const CSV_FIELD_DELIMITERS: [u8; 4] = [b',', b'\t', b'|', b';'];
+const CSV_QUOTES: [u8; 2] = [b'"', b'\''];
+
+/// Detect the format of a stage file by reading the head of it.
+pub async fn detect_file_format(
+    operator: &Operator,
+    path: &str,
+    size: u64,
+) -> Result<FileFormatParams> {
+    let (sample, is_complete) =
+        read_sample(operator, path, size, StageFileCompression::None).await?;
+    detect_file_format_by_content(path, &sample, is_complete)
+}
+
+/// Reads the head of a file and decompresses it, returns the sample and
+/// whether it covers the whole file.
+pub(crate) async fn read_sample(
+    operator: &Operator,
+    path: &str,
+    size: u64,
+    compression: StageFileCompression,
+) -> Result<(Vec<u8>, bool)> {
+    let len = size.min(FORMAT_DETECT_SAMPLE_SIZE);
+    let sample = if len == 0 {
+        vec![]
+    } else {
+        operator.range_read(path, 0..len).await?
+    };
+    let is_complete = len == size;
+    match InputContext::get_compression_alg_copy(compression, path)? {
+        Some(algo) => {
+            let mut decoder = DecompressDecoder::new(algo);
+            Ok((decompress(&mut decoder, &sample)?, is_complete))
+        }
+        None => Ok((sample, is_complete)),
+    }
+}
+
+/// Detect the format from the first bytes of a file.
+///
+/// `is_complete` tells whether `sample` is the whole file, if not,
+/// the last (possibly truncated) line is ignored by the text heuristics.
+pub fn detect_file_format_by_content(
+    path: &str,
+    sample: &[u8],
+    is_complete: bool,
+) -> Result<FileFormatParams> {
+    if sample.starts_with(b"PAR1") {
+        return Ok(FileFormatParams::Parquet(ParquetFileFormatParams {}));
+    }
+    if sample.starts_with(b"Obj\x01") {
+        return Ok(FileFormatParams::Avro(AvroFileFormatParams {}));
+    }
+    if sample.starts_with(b"ORC") {
+        return Ok(FileFormatParams::Orc(OrcFileFormatParams {}));
+    }
+
+    let (compression, algo) = match detect_compression(sample) {
+        Some((compression, algo)) => (compression, Some(algo)),
+        None => match CompressAlgorithm::from_path(path) {
+            // formats without magic bytes (brotli, raw deflate) are
+            // left to the file extension.
+            Some(algo) => (StageFileCompression::Auto, Some(algo)),
+            None => (StageFileCompression::None, None),
+        },
+    };
+
+    let (text, is_complete) = match algo {
+        Some(algo) => {
+            let mut decoder = DecompressDecoder::new(algo);
+            match decompress(&mut decoder, sample) {
+                Ok(text) => (text, is_complete),
+                // the sample is not what the extension claims, sniff it as is.
+                Err(_) if compression == StageFileCompression::Auto => {
+                    return detect_text_format(sample, is_complete, StageFileCompression::None);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        None => (sample.to_vec(), is_complete),
+    };
+    detect_text_format(&text, is_complete, compression)
+}
+
+fn detect_compression(sample: &[u8]) -> Option<(StageFileCompression, CompressAlgorithm)> {
+    if sample.starts_with(&[0x1f, 0x8b]) {
+        Some((StageFileCompression::Gzip, CompressAlgorithm::Gzip))
+    } else if sample.starts_with(&[0x28, 0xb5, 0x2f, 0xfd]) {
+        Some((StageFileCompression::Zstd, CompressAlgorithm::Zstd))
+    } else if sample.starts_with(b"BZh") {
+        Some((StageFileCompression::Bz2, CompressAlgorithm::Bz2))
+    } else if sample.starts_with(&[0xfd, b'7', b'z', b'X', b'Z', 0x00]) {
+        Some((StageFileCompression::Xz, CompressAlgorithm::Xz))
+    } else {
+        None
+    }
+}
+
+fn detect_text_format(
+    text: &[u8],
+    is_complete: bool,
+    compression: StageFileCompression,
+) -> Result<FileFormatParams> {
+    let text = text.strip_prefix(b"\xef\xbb\xbf").unwrap_or(text);
+    let first = text.iter().find(|c| !c.is_ascii_whitespace());
+    match first {
+        Some(b'{') => Ok(FileFormatParams::NdJson(NdJsonFileFormatParams {
+            compression,
+        })),
+        Some(b'<') => Ok(FileFormatParams::Xml(XmlFileFormatParams {
+            compression,
+            ..Default::default()
+        })),
+        _ => Ok(FileFormatParams::Csv(detect_csv(
+            text,
+            is_complete,
+            compression,
+        ))),
+    }
+}
+
+fn detect_csv(
+    text: &[u8],
+    is_complete: bool,
+    compression: StageFileCompression,
+) -> CsvFileFormatParams {
+    let mut lines: Vec<&[u8]> = text.split(|c| *c == b'\n').collect();
+    if !is_complete && lines.len() > 1 {
+        lines.pop();
+    }
+    let crlf = lines.iter().any(|l| l.ends_with(b"\r"));
+    let lines: Vec<&[u8]> = lines
+        .into_iter()
+        .map(|l| l.strip_suffix(b"\r").unwrap_or(l))
+        .filter(|l| !l.is_empty())
+        .take(MAX_SAMPLE_LINES)
+        .collect();
+
+    let mut params = CsvFileFormatParams {
+        compression,
+        ..Default::default()
+    };
+    if crlf {
+        params.record_delimiter = "\r\n".to_string();
+    }
+
+    // (fields per line, quoted fields, delimiter, quote)
+    let mut best: Option<(usize, usize, u8, u8)> = None;
+    for delimiter in CSV_FIELD_DELIMITERS {
+        for quote in CSV_QUOTES {
+            if let Some((fields, quoted)) = consistent_fields(&lines, delimiter, quote) {
+                // a single field means the delimiter never shows up.
+                if fields < 2 {
+                    continue;
+                }
+                let better = match best {
+                    None => true,
+                    Some((best_fields, best_quoted, _, _)) => {
+                        fields > best_fields || (fields == best_fields && quoted > best_quoted)
+                    }
+                };
+                if better {
+                    best = Some((fields, quoted, delimiter, quote));
+                }
+            }
+        }
+    }
+    if let Some((_, _, delimiter, quote)) = best {
+        params.field_delimiter = (delimiter as char).to_string();
+        params.quote = (quote as char).to_string();
+    }
+    params
+}
+
+/// Returns the number of fields if every line has the same number of fields,
+/// together with the number of quoted fields seen.
+fn consistent_fields(lines: &[&[u8]], delimiter: u8, quote: u8) -> Option<(usize, usize)> {
+    let mut fields = None;
+    let mut quoted = 0;
+    for line in lines {
+        let (n, q) = count_fields(line, delimiter, quote)?;
+        match fields {
+            None => fields = Some(n),
+            Some(f) if f != n => return None,
+            _ => {}
+        }
+        quoted += q;
+    }
+    fields.map(|f| (f, quoted))
+}
+
+/// Counts the fields of a line, a quote only opens a quoted field at the
+/// start of a field. Returns None if a quoted field is not closed.
+fn count_fields(line: &[u8], delimiter: u8, quote: u8) -> Option<(usize, usize)> {
+    let mut fields = 1;
+    let mut quoted = 0;
+    let mut in_quote = false;
+    let mut field_start = true;
+    let mut i = 0;
+    while i < line.len() {
+        let c = line[i];
+        if in_quote {
+            if c == quote {
+                if line.get(i + 1) == Some(&quote) {
+                    i += 1;
+                } else {
+                    in_quote = false;
+                }
+            }
+        } else if c == delimiter {
+            fields += 1;
+            field_start = true;
+            i += 1;
+            continue;
+        } else if c == quote && field_start {
+            in_quote = true;
+            quoted += 1;
+        }
+        field_start = false;
+        i += 1;
+    }
+    if in_quote {
+        None
+    } else {
+        Some((fields, quoted))
+    }
+}
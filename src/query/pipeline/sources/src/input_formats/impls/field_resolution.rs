@@ -0,0 +1,70 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::compute::cast as arrow_cast;
+use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::datatypes::Field as ArrowField;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::TableSchemaRef;
+
+/// Resolve the fields found in a self-describing file (avro, orc) against the target table schema.
+///
+/// Fields are matched by name, case-insensitively. A table column missing from the file
+/// is filled with NULLs if it is nullable, and rejected otherwise.
+pub(crate) fn resolve_fields(
+    file_fields: &[ArrowField],
+    schema: &TableSchemaRef,
+    format: &str,
+    path: &str,
+) -> Result<Vec<Option<usize>>> {
+    let mut resolved = Vec::with_capacity(schema.fields().len());
+    for f in schema.fields().iter() {
+        let pos = file_fields
+            .iter()
+            .rposition(|c| c.name.eq_ignore_ascii_case(f.name()));
+        if pos.is_none() && !f.is_nullable() {
+            return Err(ErrorCode::TableSchemaMismatch(format!(
+                "{} file {} has no field for non-nullable column: {}",
+                format,
+                path,
+                f.name()
+            )));
+        }
+        resolved.push(pos);
+    }
+    Ok(resolved)
+}
+
+/// Cast a decoded array to the arrow type of the target column, e.g. the avro
+/// `timestamp-millis` logical type to microsecond timestamps, `int` to `bigint`,
+/// or a decimal to the precision and scale of the table.
+pub(crate) fn cast_to_target(array: &dyn Array, to: &ArrowDataType) -> Result<Box<dyn Array>> {
+    if array.data_type() == to {
+        return Ok(array.to_boxed());
+    }
+    arrow_cast::cast(array, to, arrow_cast::CastOptions {
+        wrapped: true,
+        partial: false,
+    })
+    .map_err(|e| {
+        ErrorCode::BadBytes(format!(
+            "cannot convert file type {:?} to {:?}: {}",
+            array.data_type(),
+            to,
+            e
+        ))
+    })
+}
@@ -0,0 +1,257 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::io::Cursor;
+use std::mem;
+use std::sync::Arc;
+
+use common_arrow::arrow::array::new_null_array;
+use common_arrow::arrow::chunk::Chunk as ArrowChunk;
+use common_arrow::arrow::datatypes::Field as ArrowField;
+use common_arrow::arrow::io::avro::avro_schema;
+use common_arrow::arrow::io::avro::read as avro_read;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_meta_app::principal::StageInfo;
+use common_pipeline_core::Pipeline;
+use common_settings::Settings;
+use common_storage::StageFileInfo;
+use opendal::Operator;
+
+use crate::input_formats::impls::field_resolution::cast_to_target;
+use crate::input_formats::impls::field_resolution::resolve_fields;
+use crate::input_formats::input_pipeline::AligningStateTrait;
+use crate::input_formats::input_pipeline::BlockBuilderTrait;
+use crate::input_formats::input_pipeline::InputFormatPipe;
+use crate::input_formats::input_pipeline::RowBatchTrait;
+use crate::input_formats::input_split::FileInfo;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormat;
+use crate::input_formats::SplitInfo;
+
+/// Avro object container files.
+///
+/// A container file carries its writer schema in the header and is only decodable as a
+/// whole, so every file is read as a single split. Block codecs (deflate, snappy) are
+/// declared in the header as well and handled by the decoder, so the stage `COMPRESSION`
+/// option does not apply here.
+pub struct InputFormatAvro;
+
+#[async_trait::async_trait]
+impl InputFormat for InputFormatAvro {
+    #[async_backtrace::framed]
+    async fn get_splits(
+        &self,
+        file_infos: Vec<StageFileInfo>,
+        _stage_info: &StageInfo,
+        _op: &Operator,
+        _settings: &Arc<Settings>,
+    ) -> Result<Vec<Arc<SplitInfo>>> {
+        let mut infos = vec![];
+        for info in file_infos {
+            let size = info.size as usize;
+            let file = Arc::new(FileInfo {
+                path: info.path.clone(),
+                size,
+                num_splits: 1,
+                compress_alg: None,
+                last_modified: info.last_modified.timestamp_micros(),
+            });
+            infos.push(Arc::new(SplitInfo {
+                file,
+                seq_in_file: 0,
+                offset: 0,
+                size,
+                num_file_splits: 1,
+                format_info: None,
+            }));
+        }
+        Ok(infos)
+    }
+
+    #[async_backtrace::framed]
+    async fn infer_schema(&self, path: &str, op: &Operator) -> Result<TableSchemaRef> {
+        let data = op.read(path).await?;
+        let mut cursor = Cursor::new(data);
+        let metadata = read_avro_metadata(&mut cursor, path)?;
+        let arrow_schema = avro_read::infer_schema(&metadata.record)?;
+        Ok(Arc::new(TableSchema::from(&arrow_schema)))
+    }
+
+    fn exec_copy(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        AvroFormatPipe::execute_copy_with_aligner(ctx, pipeline)
+    }
+
+    fn exec_stream(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        AvroFormatPipe::execute_stream(ctx, pipeline)
+    }
+}
+
+pub struct AvroFormatPipe;
+
+#[async_trait::async_trait]
+impl InputFormatPipe for AvroFormatPipe {
+    type SplitMeta = ();
+    type ReadBatch = Vec<u8>;
+    type RowBatch = AvroFileInMemory;
+    type AligningState = AvroAligningState;
+    type BlockBuilder = AvroBlockBuilder;
+
+    fn try_create_align_state(
+        _ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<AvroAligningState> {
+        Ok(AvroAligningState {
+            split_info: split_info.clone(),
+            buffers: vec![],
+        })
+    }
+
+    fn try_create_block_builder(ctx: &Arc<InputContext>) -> Result<AvroBlockBuilder> {
+        Ok(AvroBlockBuilder { ctx: ctx.clone() })
+    }
+}
+
+pub struct AvroFileInMemory {
+    pub path: String,
+    pub data: Vec<u8>,
+}
+
+impl Debug for AvroFileInMemory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "AvroFileInMemory({}, {} bytes)",
+            self.path,
+            self.data.len()
+        )
+    }
+}
+
+impl RowBatchTrait for AvroFileInMemory {
+    fn size(&self) -> usize {
+        self.data.len()
+    }
+
+    fn rows(&self) -> usize {
+        // the row count is only known after the blocks are decoded.
+        0
+    }
+}
+
+pub struct AvroAligningState {
+    split_info: Arc<SplitInfo>,
+    buffers: Vec<Vec<u8>>,
+}
+
+impl AligningStateTrait for AvroAligningState {
+    type Pipe = AvroFormatPipe;
+
+    fn align(&mut self, read_batch: Option<Vec<u8>>) -> Result<Vec<AvroFileInMemory>> {
+        if let Some(data) = read_batch {
+            self.buffers.push(data);
+            Ok(vec![])
+        } else {
+            let data = mem::take(&mut self.buffers).concat();
+            tracing::debug!(
+                "aligning avro file {} of {} bytes",
+                self.split_info.file.path,
+                data.len(),
+            );
+            Ok(vec![AvroFileInMemory {
+                path: self.split_info.file.path.clone(),
+                data,
+            }])
+        }
+    }
+}
+
+pub struct AvroBlockBuilder {
+    ctx: Arc<InputContext>,
+}
+
+impl BlockBuilderTrait for AvroBlockBuilder {
+    type Pipe = AvroFormatPipe;
+
+    fn deserialize(&mut self, batch: Option<AvroFileInMemory>) -> Result<Vec<DataBlock>> {
+        let batch = match batch {
+            Some(batch) => batch,
+            None => return Ok(vec![]),
+        };
+        let mut cursor = Cursor::new(batch.data);
+        let metadata = read_avro_metadata(&mut cursor, &batch.path)?;
+        let avro_fields = avro_read::infer_schema(&metadata.record)?.fields;
+
+        let target_schema = &self.ctx.schema;
+        let resolved = resolve_fields(&avro_fields, target_schema, "avro", &batch.path)?;
+        let projection = avro_fields
+            .iter()
+            .enumerate()
+            .map(|(i, _)| resolved.iter().any(|r| *r == Some(i)))
+            .collect::<Vec<_>>();
+        // positions of the projected fields inside the decoded chunks.
+        let mut projected_pos = vec![None; avro_fields.len()];
+        for (pos, i) in (0..avro_fields.len())
+            .filter(|i| projection[*i])
+            .enumerate()
+        {
+            projected_pos[i] = Some(pos);
+        }
+
+        let target_fields = target_schema
+            .fields()
+            .iter()
+            .map(ArrowField::from)
+            .collect::<Vec<_>>();
+        let data_schema = DataSchema::from(target_schema);
+
+        let reader = avro_read::Reader::new(cursor, metadata, avro_fields, Some(projection));
+        let mut blocks = vec![];
+        for chunk in reader {
+            let chunk = chunk?;
+            let num_rows = chunk.len();
+            let arrays = chunk.into_arrays();
+            let mut columns = Vec::with_capacity(target_fields.len());
+            for (target, source) in target_fields.iter().zip(resolved.iter()) {
+                let array = match source.and_then(|i| projected_pos[i]) {
+                    Some(pos) => cast_to_target(arrays[pos].as_ref(), &target.data_type)?,
+                    None => new_null_array(target.data_type.clone(), num_rows),
+                };
+                columns.push(array);
+            }
+            let chunk = ArrowChunk::try_new(columns)?;
+            blocks.push(DataBlock::from_arrow_chunk(&chunk, &data_schema)?);
+        }
+        tracing::debug!(
+            "decoded avro file {} into {} blocks",
+            batch.path,
+            blocks.len()
+        );
+        Ok(blocks)
+    }
+}
+
+fn read_avro_metadata(
+    reader: &mut Cursor<Vec<u8>>,
+    path: &str,
+) -> Result<avro_schema::file::FileMetadata> {
+    avro_schema::read::read_metadata(reader)
+        .map_err(|e| ErrorCode::BadBytes(format!("invalid avro file {}: {:?}", path, e)))
+}
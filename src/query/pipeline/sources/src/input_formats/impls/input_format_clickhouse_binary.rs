@@ -0,0 +1,683 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::marker::PhantomData;
+use std::mem;
+use std::sync::Arc;
+
+use common_arrow::arrow::array::new_null_array;
+use common_arrow::arrow::chunk::Chunk as ArrowChunk;
+use common_arrow::arrow::datatypes::Field as ArrowField;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::decimal::DecimalScalar;
+use common_expression::types::DataType;
+use common_expression::types::DecimalDataType;
+use common_expression::types::DecimalSize;
+use common_expression::types::NumberDataType;
+use common_expression::types::F32;
+use common_expression::types::F64;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::DataField;
+use common_expression::DataSchema;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_expression::TableSchemaRef;
+use common_meta_app::principal::StageInfo;
+use common_pipeline_core::Pipeline;
+use common_settings::Settings;
+use common_storage::StageFileInfo;
+use ethnum::i256;
+use opendal::Operator;
+
+use crate::input_formats::impls::field_resolution::cast_to_target;
+use crate::input_formats::impls::field_resolution::resolve_fields;
+use crate::input_formats::input_pipeline::AligningStateTrait;
+use crate::input_formats::input_pipeline::BlockBuilderTrait;
+use crate::input_formats::input_pipeline::InputFormatPipe;
+use crate::input_formats::input_pipeline::RowBatchTrait;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormat;
+use crate::input_formats::SplitInfo;
+
+/// Decodes the data of a binary format of ClickHouse into blocks of the columns found
+/// in the data, by their names.
+pub trait ClickhouseBinaryDecoder: Send + Sync + 'static {
+    const NAME: &'static str;
+
+    fn decode(ctx: &InputContext, data: &[u8]) -> Result<Vec<DataBlock>>;
+}
+
+/// The binary formats of ClickHouse, only accepted as the data of `INSERT ... FORMAT`
+/// from the ClickHouse handler.
+///
+/// Like the Avro files, the data is decoded as a whole after it's all received. The
+/// values are decoded by the ClickHouse types of the columns, which are given by the
+/// data except for plain `RowBinary`, then cast to the types of the table.
+pub struct InputFormatClickhouseBinary<D> {
+    _decoder: PhantomData<D>,
+}
+
+pub type InputFormatRowBinary = InputFormatClickhouseBinary<RowBinaryDecoder>;
+pub type InputFormatNative = InputFormatClickhouseBinary<NativeDecoder>;
+
+impl<D: ClickhouseBinaryDecoder> InputFormatClickhouseBinary<D> {
+    pub fn create() -> Self {
+        InputFormatClickhouseBinary {
+            _decoder: PhantomData,
+        }
+    }
+
+    fn unsupported() -> ErrorCode {
+        ErrorCode::Unimplemented(format!(
+            "{} format is only supported by INSERT of the ClickHouse handler",
+            D::NAME
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl<D: ClickhouseBinaryDecoder> InputFormat for InputFormatClickhouseBinary<D> {
+    #[async_backtrace::framed]
+    async fn get_splits(
+        &self,
+        _file_infos: Vec<StageFileInfo>,
+        _stage_info: &StageInfo,
+        _op: &Operator,
+        _settings: &Arc<Settings>,
+    ) -> Result<Vec<Arc<SplitInfo>>> {
+        Err(Self::unsupported())
+    }
+
+    #[async_backtrace::framed]
+    async fn infer_schema(&self, _path: &str, _op: &Operator) -> Result<TableSchemaRef> {
+        Err(Self::unsupported())
+    }
+
+    fn exec_copy(&self, _ctx: Arc<InputContext>, _pipeline: &mut Pipeline) -> Result<()> {
+        Err(Self::unsupported())
+    }
+
+    fn exec_stream(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        ClickhouseBinaryPipe::<D>::execute_stream(ctx, pipeline)
+    }
+}
+
+pub struct ClickhouseBinaryPipe<D> {
+    _decoder: PhantomData<D>,
+}
+
+#[async_trait::async_trait]
+impl<D: ClickhouseBinaryDecoder> InputFormatPipe for ClickhouseBinaryPipe<D> {
+    type SplitMeta = ();
+    type ReadBatch = Vec<u8>;
+    type RowBatch = ClickhouseBinaryInMemory;
+    type AligningState = ClickhouseBinaryAligningState<D>;
+    type BlockBuilder = ClickhouseBinaryBlockBuilder<D>;
+
+    fn try_create_align_state(
+        _ctx: &Arc<InputContext>,
+        _split_info: &Arc<SplitInfo>,
+    ) -> Result<ClickhouseBinaryAligningState<D>> {
+        Ok(ClickhouseBinaryAligningState {
+            buffers: vec![],
+            _decoder: PhantomData,
+        })
+    }
+
+    fn try_create_block_builder(
+        ctx: &Arc<InputContext>,
+    ) -> Result<ClickhouseBinaryBlockBuilder<D>> {
+        Ok(ClickhouseBinaryBlockBuilder {
+            ctx: ctx.clone(),
+            _decoder: PhantomData,
+        })
+    }
+}
+
+pub struct ClickhouseBinaryInMemory {
+    pub data: Vec<u8>,
+}
+
+impl Debug for ClickhouseBinaryInMemory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ClickhouseBinaryInMemory({} bytes)", self.data.len())
+    }
+}
+
+impl RowBatchTrait for ClickhouseBinaryInMemory {
+    fn size(&self) -> usize {
+        self.data.len()
+    }
+
+    fn rows(&self) -> usize {
+        // the row count is only known after the blocks are decoded.
+        0
+    }
+}
+
+pub struct ClickhouseBinaryAligningState<D> {
+    buffers: Vec<Vec<u8>>,
+    _decoder: PhantomData<D>,
+}
+
+impl<D: ClickhouseBinaryDecoder> AligningStateTrait for ClickhouseBinaryAligningState<D> {
+    type Pipe = ClickhouseBinaryPipe<D>;
+
+    fn align(&mut self, read_batch: Option<Vec<u8>>) -> Result<Vec<ClickhouseBinaryInMemory>> {
+        if let Some(data) = read_batch {
+            self.buffers.push(data);
+            Ok(vec![])
+        } else {
+            let data = mem::take(&mut self.buffers).concat();
+            tracing::debug!("aligning {} data of {} bytes", D::NAME, data.len());
+            Ok(vec![ClickhouseBinaryInMemory { data }])
+        }
+    }
+}
+
+pub struct ClickhouseBinaryBlockBuilder<D> {
+    ctx: Arc<InputContext>,
+    _decoder: PhantomData<D>,
+}
+
+impl<D: ClickhouseBinaryDecoder> BlockBuilderTrait for ClickhouseBinaryBlockBuilder<D> {
+    type Pipe = ClickhouseBinaryPipe<D>;
+
+    fn deserialize(&mut self, batch: Option<ClickhouseBinaryInMemory>) -> Result<Vec<DataBlock>> {
+        match batch {
+            Some(batch) => D::decode(&self.ctx, &batch.data),
+            None => Ok(vec![]),
+        }
+    }
+}
+
+/// `RowBinary`, the values of each row one after another. The columns are the ones of
+/// the insert, or named by the header of `RowBinaryWithNames[AndTypes]`.
+pub struct RowBinaryDecoder;
+
+impl ClickhouseBinaryDecoder for RowBinaryDecoder {
+    const NAME: &'static str = "RowBinary";
+
+    fn decode(ctx: &InputContext, data: &[u8]) -> Result<Vec<DataBlock>> {
+        let mut reader = BinaryReader::new(data, Self::NAME);
+        let table_type = |name: &str| match ctx
+            .schema
+            .fields()
+            .iter()
+            .rfind(|f| f.name().eq_ignore_ascii_case(name))
+        {
+            Some(field) => ClickhouseType::from_data_type(&field.data_type().into()),
+            None => Err(ErrorCode::BadBytes(format!(
+                "RowBinary data has unknown column {}",
+                name
+            ))),
+        };
+
+        let columns = match ctx.file_format_options_ext.headers {
+            0 => ctx
+                .schema
+                .fields()
+                .iter()
+                .map(|f| Ok((f.name().clone(), table_type(f.name())?)))
+                .collect::<Result<Vec<_>>>()?,
+            headers => {
+                let num_columns = reader.read_varint()? as usize;
+                let names = (0..num_columns)
+                    .map(|_| reader.read_string())
+                    .collect::<Result<Vec<_>>>()?;
+                let mut columns = Vec::with_capacity(names.len());
+                for name in names {
+                    let typ = match headers {
+                        1 => table_type(&name)?,
+                        _ => ClickhouseType::parse(&reader.read_string()?)?,
+                    };
+                    columns.push((name, typ));
+                }
+                columns
+            }
+        };
+
+        let names = columns
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>();
+        let max_rows = ctx.block_compact_thresholds.max_rows_per_block.max(1);
+        let mut blocks = vec![];
+        while !reader.is_empty() {
+            let mut builders = columns
+                .iter()
+                .map(|(_, typ)| ColumnBuilder::with_capacity(&typ.data_type(), 0))
+                .collect::<Vec<_>>();
+            let mut num_rows = 0;
+            while num_rows < max_rows && !reader.is_empty() {
+                for ((_, typ), builder) in columns.iter().zip(builders.iter_mut()) {
+                    builder.push(reader.read_value(typ)?.as_ref());
+                }
+                num_rows += 1;
+            }
+            let decoded = builders.into_iter().map(|b| b.build()).collect();
+            blocks.push(to_table_block(ctx, Self::NAME, &names, decoded, num_rows)?);
+        }
+        tracing::debug!("decoded RowBinary data into {} blocks", blocks.len());
+        Ok(blocks)
+    }
+}
+
+/// `Native`, blocks of columns, each column with its name, its type and the values of
+/// all the rows of the block.
+pub struct NativeDecoder;
+
+impl ClickhouseBinaryDecoder for NativeDecoder {
+    const NAME: &'static str = "Native";
+
+    fn decode(ctx: &InputContext, data: &[u8]) -> Result<Vec<DataBlock>> {
+        let mut reader = BinaryReader::new(data, Self::NAME);
+        let mut blocks = vec![];
+        while !reader.is_empty() {
+            let num_columns = reader.read_varint()? as usize;
+            let num_rows = reader.read_varint()? as usize;
+            let mut names = vec![];
+            let mut decoded = vec![];
+            for _ in 0..num_columns {
+                names.push(reader.read_string()?);
+                let typ = ClickhouseType::parse(&reader.read_string()?)?;
+                decoded.push(reader.read_column(&typ, num_rows)?);
+            }
+            // the clients may send an empty block with the structure first.
+            if num_rows > 0 {
+                blocks.push(to_table_block(ctx, Self::NAME, &names, decoded, num_rows)?);
+            }
+        }
+        tracing::debug!("decoded Native data into {} blocks", blocks.len());
+        Ok(blocks)
+    }
+}
+
+/// Match the decoded columns to the columns of the insert by name and cast them to the
+/// types of the table.
+fn to_table_block(
+    ctx: &InputContext,
+    format: &str,
+    names: &[String],
+    columns: Vec<Column>,
+    num_rows: usize,
+) -> Result<DataBlock> {
+    let fields = names
+        .iter()
+        .zip(columns.iter())
+        .map(|(name, column)| ArrowField::from(&DataField::new(name, column.data_type())))
+        .collect::<Vec<_>>();
+    let resolved = resolve_fields(&fields, &ctx.schema, format, "clickhouse_insert")?;
+
+    let mut arrays = Vec::with_capacity(resolved.len());
+    for (target, source) in ctx.schema.fields().iter().zip(resolved.iter()) {
+        let target = ArrowField::from(target);
+        let array = match source {
+            Some(i) => cast_to_target(columns[*i].as_arrow().as_ref(), &target.data_type)?,
+            None => new_null_array(target.data_type.clone(), num_rows),
+        };
+        arrays.push(array);
+    }
+    let chunk = ArrowChunk::try_new(arrays)?;
+    DataBlock::from_arrow_chunk(&chunk, &DataSchema::from(&ctx.schema))
+}
+
+/// The types of ClickHouse that can be decoded, the other types (e.g. `Tuple`, `Map`
+/// and `UUID`) are rejected.
+#[derive(Clone, Debug, PartialEq)]
+enum ClickhouseType {
+    UInt8,
+    UInt16,
+    UInt32,
+    UInt64,
+    Int8,
+    Int16,
+    Int32,
+    Int64,
+    Float32,
+    Float64,
+    Bool,
+    String,
+    FixedString(usize),
+    Date,
+    Date32,
+    DateTime,
+    DateTime64(u32),
+    Decimal(DecimalDataType),
+    Nullable(Box<ClickhouseType>),
+    Array(Box<ClickhouseType>),
+    LowCardinality(Box<ClickhouseType>),
+}
+
+impl ClickhouseType {
+    /// Parse a type name, e.g. `Nullable(Decimal(10, 2))` or `DateTime64(3, 'UTC')`.
+    fn parse(name: &str) -> Result<ClickhouseType> {
+        let invalid = || ErrorCode::BadBytes(format!("unsupported ClickHouse type {}", name));
+        let name = name.trim();
+        let (base, args) = match name.find('(') {
+            Some(pos) if name.ends_with(')') => (
+                &name[..pos],
+                split_type_args(&name[pos + 1..name.len() - 1]),
+            ),
+            Some(_) => return Err(invalid()),
+            None => (name, vec![]),
+        };
+        let number = |arg: &str| arg.parse::<u32>().map_err(|_| invalid());
+        let decimal = |precision: u32, scale: u32| {
+            let size = DecimalSize {
+                precision: u8::try_from(precision).map_err(|_| invalid())?,
+                scale: u8::try_from(scale).map_err(|_| invalid())?,
+            };
+            Ok(ClickhouseType::Decimal(DecimalDataType::from_size(size)?))
+        };
+
+        let typ = match (base, args.as_slice()) {
+            ("UInt8", []) => ClickhouseType::UInt8,
+            ("UInt16", []) => ClickhouseType::UInt16,
+            ("UInt32", []) => ClickhouseType::UInt32,
+            ("UInt64", []) => ClickhouseType::UInt64,
+            ("Int8", []) => ClickhouseType::Int8,
+            ("Int16", []) => ClickhouseType::Int16,
+            ("Int32", []) => ClickhouseType::Int32,
+            ("Int64", []) => ClickhouseType::Int64,
+            ("Float32", []) => ClickhouseType::Float32,
+            ("Float64", []) => ClickhouseType::Float64,
+            ("Bool" | "Boolean", []) => ClickhouseType::Bool,
+            ("String", []) => ClickhouseType::String,
+            ("FixedString", [n]) => ClickhouseType::FixedString(number(n)? as usize),
+            ("Date", []) => ClickhouseType::Date,
+            ("Date32", []) => ClickhouseType::Date32,
+            // the time zone only applies to the text of the values.
+            ("DateTime", [] | [_]) => ClickhouseType::DateTime,
+            ("DateTime64", [precision] | [precision, _]) => match number(precision)? {
+                precision if precision <= 9 => ClickhouseType::DateTime64(precision),
+                _ => return Err(invalid()),
+            },
+            ("Decimal", [precision, scale]) => decimal(number(precision)?, number(scale)?)?,
+            ("Decimal32", [scale]) => decimal(9, number(scale)?)?,
+            ("Decimal64", [scale]) => decimal(18, number(scale)?)?,
+            ("Decimal128", [scale]) => decimal(38, number(scale)?)?,
+            ("Decimal256", [scale]) => decimal(76, number(scale)?)?,
+            ("Nullable", [inner]) => ClickhouseType::Nullable(Box::new(Self::parse(inner)?)),
+            ("Array", [inner]) => ClickhouseType::Array(Box::new(Self::parse(inner)?)),
+            ("LowCardinality", [inner]) => {
+                ClickhouseType::LowCardinality(Box::new(Self::parse(inner)?))
+            }
+            _ => return Err(invalid()),
+        };
+        Ok(typ)
+    }
+
+    /// The type a column of the table is encoded as by the clients, when the data has
+    /// no types.
+    fn from_data_type(data_type: &DataType) -> Result<ClickhouseType> {
+        let typ = match data_type {
+            DataType::Boolean => ClickhouseType::Bool,
+            DataType::String | DataType::Binary => ClickhouseType::String,
+            DataType::Number(number) => match number {
+                NumberDataType::UInt8 => ClickhouseType::UInt8,
+                NumberDataType::UInt16 => ClickhouseType::UInt16,
+                NumberDataType::UInt32 => ClickhouseType::UInt32,
+                NumberDataType::UInt64 => ClickhouseType::UInt64,
+                NumberDataType::Int8 => ClickhouseType::Int8,
+                NumberDataType::Int16 => ClickhouseType::Int16,
+                NumberDataType::Int32 => ClickhouseType::Int32,
+                NumberDataType::Int64 => ClickhouseType::Int64,
+                NumberDataType::Float32 => ClickhouseType::Float32,
+                NumberDataType::Float64 => ClickhouseType::Float64,
+            },
+            DataType::Decimal(decimal) => ClickhouseType::Decimal(*decimal),
+            DataType::Timestamp => ClickhouseType::DateTime64(6),
+            DataType::Date => ClickhouseType::Date32,
+            DataType::Nullable(inner) => {
+                ClickhouseType::Nullable(Box::new(Self::from_data_type(inner)?))
+            }
+            DataType::Array(inner) => ClickhouseType::Array(Box::new(Self::from_data_type(inner)?)),
+            _ => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "column type {} is not supported by ClickHouse binary formats",
+                    data_type
+                )));
+            }
+        };
+        Ok(typ)
+    }
+
+    /// The type the values are decoded as, before they are cast to the table.
+    fn data_type(&self) -> DataType {
+        match self {
+            ClickhouseType::UInt8 => DataType::Number(NumberDataType::UInt8),
+            ClickhouseType::UInt16 => DataType::Number(NumberDataType::UInt16),
+            ClickhouseType::UInt32 => DataType::Number(NumberDataType::UInt32),
+            ClickhouseType::UInt64 => DataType::Number(NumberDataType::UInt64),
+            ClickhouseType::Int8 => DataType::Number(NumberDataType::Int8),
+            ClickhouseType::Int16 => DataType::Number(NumberDataType::Int16),
+            ClickhouseType::Int32 => DataType::Number(NumberDataType::Int32),
+            ClickhouseType::Int64 => DataType::Number(NumberDataType::Int64),
+            ClickhouseType::Float32 => DataType::Number(NumberDataType::Float32),
+            ClickhouseType::Float64 => DataType::Number(NumberDataType::Float64),
+            ClickhouseType::Bool => DataType::Boolean,
+            ClickhouseType::String | ClickhouseType::FixedString(_) => DataType::String,
+            ClickhouseType::Date | ClickhouseType::Date32 => DataType::Date,
+            ClickhouseType::DateTime | ClickhouseType::DateTime64(_) => DataType::Timestamp,
+            ClickhouseType::Decimal(decimal) => DataType::Decimal(*decimal),
+            ClickhouseType::Nullable(inner) => inner.data_type().wrap_nullable(),
+            ClickhouseType::Array(inner) => DataType::Array(Box::new(inner.data_type())),
+            ClickhouseType::LowCardinality(inner) => inner.data_type(),
+        }
+    }
+}
+
+/// Split the arguments of a type by the commas out of the nested types and quotes.
+fn split_type_args(args: &str) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut depth = 0;
+    let mut quoted = false;
+    let mut start = 0;
+    for (i, c) in args.char_indices() {
+        match c {
+            '\'' => quoted = !quoted,
+            '(' if !quoted => depth += 1,
+            ')' if !quoted => depth -= 1,
+            ',' if !quoted && depth == 0 => {
+                parts.push(args[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(args[start..].trim());
+    parts
+}
+
+struct BinaryReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+    format: &'static str,
+}
+
+impl<'a> BinaryReader<'a> {
+    fn new(data: &'a [u8], format: &'static str) -> Self {
+        BinaryReader {
+            data,
+            pos: 0,
+            format,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pos >= self.data.len()
+    }
+
+    fn remaining(&self) -> usize {
+        self.data.len() - self.pos
+    }
+
+    fn invalid(&self, reason: &str) -> ErrorCode {
+        ErrorCode::BadBytes(format!(
+            "invalid {} data at byte {}, {}",
+            self.format, self.pos, reason
+        ))
+    }
+
+    fn read_bytes(&mut self, n: usize) -> Result<&'a [u8]> {
+        if self.remaining() < n {
+            return Err(self.invalid("unexpected end of data"));
+        }
+        let bytes = &self.data[self.pos..self.pos + n];
+        self.pos += n;
+        Ok(bytes)
+    }
+
+    fn read_fixed<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut fixed = [0; N];
+        fixed.copy_from_slice(self.read_bytes(N)?);
+        Ok(fixed)
+    }
+
+    /// LEB128, the lengths of the strings and arrays and the counts of the headers.
+    fn read_varint(&mut self) -> Result<u64> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.read_fixed::<1>()?[0];
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(self.invalid("varint too long"))
+    }
+
+    fn read_string(&mut self) -> Result<String> {
+        let len = self.read_varint()? as usize;
+        let bytes = self.read_bytes(len)?;
+        String::from_utf8(bytes.to_vec()).map_err(|_| self.invalid("invalid utf-8 of a name"))
+    }
+
+    fn read_value(&mut self, typ: &ClickhouseType) -> Result<Scalar> {
+        let value = match typ {
+            ClickhouseType::UInt8 => Scalar::from(self.read_fixed::<1>()?[0]),
+            ClickhouseType::UInt16 => Scalar::from(u16::from_le_bytes(self.read_fixed()?)),
+            ClickhouseType::UInt32 => Scalar::from(u32::from_le_bytes(self.read_fixed()?)),
+            ClickhouseType::UInt64 => Scalar::from(u64::from_le_bytes(self.read_fixed()?)),
+            ClickhouseType::Int8 => Scalar::from(self.read_fixed::<1>()?[0] as i8),
+            ClickhouseType::Int16 => Scalar::from(i16::from_le_bytes(self.read_fixed()?)),
+            ClickhouseType::Int32 => Scalar::from(i32::from_le_bytes(self.read_fixed()?)),
+            ClickhouseType::Int64 => Scalar::from(i64::from_le_bytes(self.read_fixed()?)),
+            ClickhouseType::Float32 => {
+                Scalar::from(F32::from(f32::from_le_bytes(self.read_fixed()?)))
+            }
+            ClickhouseType::Float64 => {
+                Scalar::from(F64::from(f64::from_le_bytes(self.read_fixed()?)))
+            }
+            ClickhouseType::Bool => Scalar::Boolean(self.read_fixed::<1>()?[0] != 0),
+            ClickhouseType::String => {
+                let len = self.read_varint()? as usize;
+                Scalar::String(self.read_bytes(len)?.to_vec())
+            }
+            ClickhouseType::FixedString(n) => Scalar::String(self.read_bytes(*n)?.to_vec()),
+            ClickhouseType::Date => Scalar::Date(u16::from_le_bytes(self.read_fixed()?) as i32),
+            ClickhouseType::Date32 => Scalar::Date(i32::from_le_bytes(self.read_fixed()?)),
+            ClickhouseType::DateTime => {
+                let secs = u32::from_le_bytes(self.read_fixed()?) as i64;
+                Scalar::Timestamp(secs * 1_000_000)
+            }
+            ClickhouseType::DateTime64(precision) => {
+                let ticks = i64::from_le_bytes(self.read_fixed()?);
+                let micros = match *precision {
+                    p if p <= 6 => ticks.saturating_mul(10i64.pow(6 - p)),
+                    p => ticks.div_euclid(10i64.pow(p - 6)),
+                };
+                Scalar::Timestamp(micros)
+            }
+            // the width of a decimal of ClickHouse depends on its precision.
+            ClickhouseType::Decimal(DecimalDataType::Decimal128(size)) => {
+                let value = match size.precision {
+                    p if p <= 9 => i32::from_le_bytes(self.read_fixed()?) as i128,
+                    p if p <= 18 => i64::from_le_bytes(self.read_fixed()?) as i128,
+                    _ => i128::from_le_bytes(self.read_fixed()?),
+                };
+                Scalar::Decimal(DecimalScalar::Decimal128(value, *size))
+            }
+            ClickhouseType::Decimal(DecimalDataType::Decimal256(size)) => {
+                let value = i256::from_le_bytes(self.read_fixed()?);
+                Scalar::Decimal(DecimalScalar::Decimal256(value, *size))
+            }
+            ClickhouseType::Nullable(inner) => match self.read_fixed::<1>()?[0] {
+                0 => self.read_value(inner)?,
+                _ => Scalar::Null,
+            },
+            ClickhouseType::Array(inner) => {
+                let len = self.read_varint()? as usize;
+                let mut builder =
+                    ColumnBuilder::with_capacity(&inner.data_type(), len.min(self.remaining()));
+                for _ in 0..len {
+                    builder.push(self.read_value(inner)?.as_ref());
+                }
+                Scalar::Array(builder.build())
+            }
+            // the values of a row are not encoded by dictionary.
+            ClickhouseType::LowCardinality(inner) => self.read_value(inner)?,
+        };
+        Ok(value)
+    }
+
+    /// Read the values of a column of a `Native` block, the nulls and the array offsets
+    /// of all the rows come before the values.
+    fn read_column(&mut self, typ: &ClickhouseType, num_rows: usize) -> Result<Column> {
+        let capacity = num_rows.min(self.remaining());
+        let mut builder = ColumnBuilder::with_capacity(&typ.data_type(), capacity);
+        match typ {
+            ClickhouseType::Nullable(inner) => {
+                let nulls = self.read_bytes(num_rows)?;
+                let values = self.read_column(inner, num_rows)?;
+                for (row, is_null) in nulls.iter().enumerate() {
+                    match *is_null {
+                        0 => builder.push(values.index(row).unwrap()),
+                        _ => builder.push(ScalarRef::Null),
+                    }
+                }
+            }
+            ClickhouseType::Array(inner) => {
+                let mut offsets = Vec::with_capacity(capacity);
+                for _ in 0..num_rows {
+                    offsets.push(u64::from_le_bytes(self.read_fixed()?) as usize);
+                }
+                let values = self.read_column(inner, offsets.last().copied().unwrap_or(0))?;
+                let mut start = 0;
+                for end in offsets {
+                    if end < start || end > values.len() {
+                        return Err(self.invalid("invalid array offsets"));
+                    }
+                    builder.push(ScalarRef::Array(values.slice(start..end)));
+                    start = end;
+                }
+            }
+            ClickhouseType::LowCardinality(_) => {
+                return Err(self.invalid("LowCardinality columns are not supported"));
+            }
+            _ => {
+                for _ in 0..num_rows {
+                    builder.push(self.read_value(typ)?.as_ref());
+                }
+            }
+        }
+        Ok(builder.build())
+    }
+}
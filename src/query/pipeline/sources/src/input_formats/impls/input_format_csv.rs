@@ -20,13 +20,18 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::NumberDataType;
 use common_expression::ColumnBuilder;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
 use common_formats::FieldDecoder;
 use common_formats::FieldDecoderCSV;
 use common_formats::FieldDecoderRowBased;
 use common_formats::FileFormatOptionsExt;
 use common_formats::RecordDelimiter;
+use common_io::constants::NULL_BYTES_ESCAPE;
 use common_io::cursor_ext::*;
 use common_io::format_diagnostic::verbose_char;
 use common_meta_app::principal::CsvFileFormatParams;
@@ -35,7 +40,9 @@ use common_meta_app::principal::OnErrorMode;
 use common_meta_app::principal::StageFileFormatType;
 use common_pipeline_core::InputError;
 use csv_core::ReadRecordResult;
+use opendal::Operator;
 
+use crate::input_formats::format_detector::read_sample;
 use crate::input_formats::impls::input_format_tsv::format_column_error;
 use crate::input_formats::AligningStateCommon;
 use crate::input_formats::AligningStateTextBased;
@@ -181,6 +188,10 @@ impl InputFormatTextBase for InputFormatCSV {
         let mut num_rows = 0usize;
         let mut error_map: HashMap<u16, InputError> = HashMap::new();
         let mut field_end_idx = 0;
+        let field_delimiter =
+            CsvFileFormatParams::downcast_unchecked(&builder.ctx.file_format_params)
+                .field_delimiter
+                .as_bytes()[0];
         let field_decoder = builder
             .field_decoder
             .as_any()
@@ -199,7 +210,20 @@ impl InputFormatTextBase for InputFormatCSV {
             ) {
                 match builder.ctx.on_error_mode {
                     OnErrorMode::Continue => {
-                        Self::on_error_continue(columns, num_rows, e.clone(), &mut error_map);
+                        // the fields are unescaped, join them back for the rejected record.
+                        let field_ends =
+                            &batch.field_ends[field_end_idx..field_end_idx + num_fields];
+                        let mut record = Vec::with_capacity(buf.len() + num_fields);
+                        let mut field_start = 0;
+                        for (j, field_end) in field_ends.iter().enumerate() {
+                            if j > 0 {
+                                record.push(field_delimiter);
+                            }
+                            record.extend_from_slice(&buf[field_start..*field_end]);
+                            field_start = *field_end;
+                        }
+                        let rejected = batch.rejected_record(&e, i, &record);
+                        Self::on_error_continue(columns, num_rows, e, rejected, &mut error_map);
                         start = *end;
                         field_end_idx += n_column;
                         continue;
@@ -440,3 +464,195 @@ impl CsvReaderState {
         )
     }
 }
+
+/// Max number of records used to infer the schema of a CSV file.
+const MAX_INFER_RECORDS: usize = 1000;
+
+impl InputFormatCSV {
+    /// Infers the schema of a CSV file from the head of it. Columns are named by the
+    /// header if the format has one, or `c1`, `c2`, ... otherwise.
+    pub async fn infer_schema_by_sample(
+        params: &CsvFileFormatParams,
+        path: &str,
+        size: u64,
+        op: &Operator,
+    ) -> Result<TableSchemaRef> {
+        let (sample, is_complete) = read_sample(op, path, size, params.compression).await?;
+        let records = read_sample_records(params, &sample, is_complete)?;
+        let headers = params.headers as usize;
+        let num_columns = records.iter().map(|r| r.len()).max().unwrap_or(0);
+
+        let mut fields = Vec::with_capacity(num_columns);
+        for i in 0..num_columns {
+            let name = match records.first() {
+                Some(header) if headers > 0 && i < header.len() && !header[i].is_empty() => {
+                    header[i].clone()
+                }
+                _ => format!("c{}", i + 1),
+            };
+            let mut ty = SampleType::Null;
+            let mut nullable = false;
+            for record in records.iter().skip(headers) {
+                match record.get(i).map(|v| SampleType::of(v.as_str())) {
+                    None | Some(SampleType::Null) => nullable = true,
+                    Some(t) => ty = ty.merge(t),
+                }
+            }
+            let data_type = ty.to_table_data_type();
+            let data_type = if nullable {
+                data_type.wrap_nullable()
+            } else {
+                data_type
+            };
+            fields.push(TableField::new(&name, data_type));
+        }
+        Ok(Arc::new(TableSchema::new(fields)))
+    }
+}
+
+/// Splits the sample into records, the last record is dropped if the sample is truncated.
+fn read_sample_records(
+    params: &CsvFileFormatParams,
+    sample: &[u8],
+    is_complete: bool,
+) -> Result<Vec<Vec<String>>> {
+    let escape = params.escape.as_bytes().first().copied();
+    let mut reader = csv_core::ReaderBuilder::new()
+        .delimiter(params.field_delimiter.as_bytes()[0])
+        .quote(params.quote.as_bytes()[0])
+        .escape(escape)
+        .terminator(match params.record_delimiter.as_str().try_into()? {
+            RecordDelimiter::Crlf => csv_core::Terminator::CRLF,
+            RecordDelimiter::Any(v) => csv_core::Terminator::Any(v),
+        })
+        .build();
+
+    let mut out = vec![0u8; sample.len() + 1];
+    let mut field_ends = vec![0usize; MAX_CSV_COLUMNS];
+    let mut input = sample;
+    let mut records = vec![];
+    let mut record_start = 0;
+    let mut out_pos = 0;
+    let mut n_end = 0;
+    while records.len() < MAX_INFER_RECORDS {
+        let (result, n_in, n_out, n) =
+            reader.read_record(input, &mut out[out_pos..], &mut field_ends[n_end..]);
+        input = &input[n_in..];
+        out_pos += n_out;
+        n_end += n;
+        match result {
+            ReadRecordResult::Record => {
+                let record = &out[record_start..out_pos];
+                let mut start = 0;
+                let mut fields = Vec::with_capacity(n_end);
+                for end in &field_ends[..n_end] {
+                    fields.push(String::from_utf8_lossy(&record[start..*end]).to_string());
+                    start = *end;
+                }
+                // skip empty lines.
+                if fields.len() != 1 || !fields[0].is_empty() {
+                    records.push(fields);
+                }
+                record_start = out_pos;
+                n_end = 0;
+            }
+            // with an empty input, the next read flushes the last record of a complete file.
+            ReadRecordResult::InputEmpty if is_complete => {}
+            ReadRecordResult::InputEmpty | ReadRecordResult::End => break,
+            ReadRecordResult::OutputFull => {
+                return Err(ErrorCode::BadBytes(
+                    "Bug: CSV Reader return output longer then input.",
+                ));
+            }
+            ReadRecordResult::OutputEndsFull => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "infer schema from CSV allow at most {} fields",
+                    MAX_CSV_COLUMNS
+                )));
+            }
+        }
+    }
+    Ok(records)
+}
+
+/// Type of the values seen in a CSV column, from the narrowest to the widest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SampleType {
+    Null,
+    Boolean,
+    Int64,
+    Float64,
+    Date,
+    Timestamp,
+    String,
+}
+
+impl SampleType {
+    fn of(v: &str) -> SampleType {
+        if v.is_empty() || v == NULL_BYTES_ESCAPE {
+            SampleType::Null
+        } else if v.eq_ignore_ascii_case("true") || v.eq_ignore_ascii_case("false") {
+            SampleType::Boolean
+        } else if v.parse::<i64>().is_ok() {
+            SampleType::Int64
+        } else if v.parse::<f64>().is_ok() && v.bytes().any(|c| c.is_ascii_digit()) {
+            SampleType::Float64
+        } else if is_date(v.as_bytes()) {
+            SampleType::Date
+        } else if is_timestamp(v.as_bytes()) {
+            SampleType::Timestamp
+        } else {
+            SampleType::String
+        }
+    }
+
+    fn merge(self, other: SampleType) -> SampleType {
+        match (self, other) {
+            (a, b) if a == b => a,
+            (SampleType::Null, t) | (t, SampleType::Null) => t,
+            (SampleType::Int64, SampleType::Float64) | (SampleType::Float64, SampleType::Int64) => {
+                SampleType::Float64
+            }
+            (SampleType::Date, SampleType::Timestamp)
+            | (SampleType::Timestamp, SampleType::Date) => SampleType::Timestamp,
+            _ => SampleType::String,
+        }
+    }
+
+    fn to_table_data_type(self) -> TableDataType {
+        match self {
+            SampleType::Boolean => TableDataType::Boolean,
+            SampleType::Int64 => TableDataType::Number(NumberDataType::Int64),
+            SampleType::Float64 => TableDataType::Number(NumberDataType::Float64),
+            SampleType::Date => TableDataType::Date,
+            SampleType::Timestamp => TableDataType::Timestamp,
+            SampleType::Null | SampleType::String => TableDataType::String,
+        }
+    }
+}
+
+/// `YYYY-MM-DD`
+fn is_date(v: &[u8]) -> bool {
+    v.len() == 10
+        && v.iter().enumerate().all(|(i, c)| match i {
+            4 | 7 => *c == b'-',
+            _ => c.is_ascii_digit(),
+        })
+}
+
+/// `YYYY-MM-DD HH:MM:SS[.ffffff]`, `T` is also accepted as the separator.
+fn is_timestamp(v: &[u8]) -> bool {
+    if v.len() < 19 || !is_date(&v[..10]) || !matches!(v[10], b' ' | b'T') {
+        return false;
+    }
+    let time_ok = v[11..19].iter().enumerate().all(|(i, c)| match i {
+        2 | 5 => *c == b':',
+        _ => c.is_ascii_digit(),
+    });
+    let fraction_ok = match &v[19..] {
+        [] => true,
+        [b'.', digits @ ..] => !digits.is_empty() && digits.iter().all(|c| c.is_ascii_digit()),
+        _ => false,
+    };
+    time_ok && fraction_ok
+}
@@ -0,0 +1,802 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::mem;
+use std::sync::Arc;
+
+use common_arrow::arrow::array::new_null_array;
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::array::ListArray;
+use common_arrow::arrow::array::MapArray;
+use common_arrow::arrow::array::PrimitiveArray;
+use common_arrow::arrow::array::StructArray;
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::chunk::Chunk as ArrowChunk;
+use common_arrow::arrow::compute::take::take;
+use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::datatypes::Field as ArrowField;
+use common_arrow::arrow::datatypes::TimeUnit;
+use common_arrow::arrow::io::orc::format;
+use common_arrow::arrow::io::orc::format::proto::column_encoding::Kind as EncodingKind;
+use common_arrow::arrow::io::orc::format::proto::stream::Kind as StreamKind;
+use common_arrow::arrow::io::orc::format::proto::r#type::Kind;
+use common_arrow::arrow::io::orc::format::proto::ColumnStatistics as OrcColumnStatistics;
+use common_arrow::arrow::io::orc::format::proto::StripeFooter;
+use common_arrow::arrow::io::orc::format::read::decode::BooleanIter;
+use common_arrow::arrow::io::orc::format::read::decode::UnsignedRleV2Iter;
+use common_arrow::arrow::io::orc::format::read::Column as OrcColumn;
+use common_arrow::arrow::io::orc::format::read::FileMetadata;
+use common_arrow::arrow::io::orc::read as orc_read;
+use common_arrow::arrow::offset::OffsetsBuffer;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberScalar;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_meta_app::principal::StageInfo;
+use common_pipeline_core::Pipeline;
+use common_settings::Settings;
+use common_storage::StageFileInfo;
+use opendal::Operator;
+use serde::Deserializer;
+use serde::Serializer;
+use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::meta::StatisticsOfColumns;
+
+use crate::input_formats::impls::field_resolution::cast_to_target;
+use crate::input_formats::impls::field_resolution::resolve_fields;
+use crate::input_formats::input_pipeline::AligningStateTrait;
+use crate::input_formats::input_pipeline::BlockBuilderTrait;
+use crate::input_formats::input_pipeline::InputFormatPipe;
+use crate::input_formats::input_pipeline::ReadBatchTrait;
+use crate::input_formats::input_pipeline::RowBatchTrait;
+use crate::input_formats::input_split::DynData;
+use crate::input_formats::input_split::FileInfo;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormat;
+use crate::input_formats::SplitInfo;
+
+/// Bytes fetched from the end of a file to locate the postscript, footer and metadata.
+/// A second read of the whole file is only needed for files with very large footers.
+const ORC_TAIL_SIZE: u64 = 256 * 1024;
+
+/// ORC files, read one stripe per split.
+///
+/// Stripe statistics are kept in the split meta, so the stage table can skip stripes
+/// that can not match the pushed down filter before any stripe data is fetched.
+pub struct InputFormatOrc;
+
+impl InputFormatOrc {
+    #[async_backtrace::framed]
+    async fn read_file_meta(op: &Operator, path: &str, size: u64) -> Result<Arc<OrcFileMeta>> {
+        let tail_size = ORC_TAIL_SIZE.min(size);
+        let tail = op.range_read(path, size - tail_size..size).await?;
+        let mut reader = PositionedCursor::new(size - tail_size, Arc::new(tail));
+        let metadata = match format::read::read_metadata(&mut reader) {
+            Ok(metadata) => metadata,
+            Err(_) if tail_size < size => {
+                let data = op.read(path).await?;
+                let mut reader = PositionedCursor::new(0, Arc::new(data));
+                read_orc_metadata(&mut reader, path)?
+            }
+            Err(e) => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "invalid orc file {}: {:?}",
+                    path, e
+                )));
+            }
+        };
+        OrcFileMeta::try_create(metadata)
+    }
+}
+
+#[async_trait::async_trait]
+impl InputFormat for InputFormatOrc {
+    #[async_backtrace::framed]
+    async fn get_splits(
+        &self,
+        file_infos: Vec<StageFileInfo>,
+        _stage_info: &StageInfo,
+        op: &Operator,
+        _settings: &Arc<Settings>,
+    ) -> Result<Vec<Arc<SplitInfo>>> {
+        let mut infos = vec![];
+        for info in file_infos {
+            let file_meta = Self::read_file_meta(op, &info.path, info.size).await?;
+            let stripes = &file_meta.metadata.footer.stripes;
+            let file_info = Arc::new(FileInfo {
+                path: info.path.clone(),
+                size: info.size as usize,
+                num_splits: stripes.len(),
+                compress_alg: None,
+                last_modified: info.last_modified.timestamp_micros(),
+            });
+            for (i, stripe) in stripes.iter().enumerate() {
+                let offset = stripe.offset() as usize;
+                let size = (stripe.index_length() + stripe.data_length() + stripe.footer_length())
+                    as usize;
+                infos.push(Arc::new(SplitInfo {
+                    file: file_info.clone(),
+                    seq_in_file: i,
+                    offset,
+                    size,
+                    num_file_splits: stripes.len(),
+                    format_info: Some(Arc::new(SplitMeta {
+                        file: file_meta.clone(),
+                        stripe: i,
+                    })),
+                }));
+            }
+        }
+        Ok(infos)
+    }
+
+    #[async_backtrace::framed]
+    async fn infer_schema(&self, path: &str, op: &Operator) -> Result<TableSchemaRef> {
+        let size = op.stat(path).await?.content_length();
+        let file_meta = Self::read_file_meta(op, path, size).await?;
+        Ok(Arc::new(TableSchema::from(&file_meta.arrow_schema())))
+    }
+
+    fn split_statistics(
+        &self,
+        split: &SplitInfo,
+        schema: &TableSchemaRef,
+    ) -> Option<StatisticsOfColumns> {
+        let meta = split
+            .format_info
+            .as_ref()?
+            .as_any()
+            .downcast_ref::<SplitMeta>()?;
+        meta.file.stripe_statistics(meta.stripe, schema)
+    }
+
+    fn exec_copy(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        OrcFormatPipe::execute_copy_aligned(ctx, pipeline)
+    }
+
+    fn exec_stream(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        OrcFormatPipe::execute_stream(ctx, pipeline)
+    }
+}
+
+pub struct OrcFormatPipe;
+
+#[async_trait::async_trait]
+impl InputFormatPipe for OrcFormatPipe {
+    type SplitMeta = SplitMeta;
+    type ReadBatch = ReadBatch;
+    type RowBatch = StripeInMemory;
+    type AligningState = OrcAligningState;
+    type BlockBuilder = OrcBlockBuilder;
+
+    #[async_backtrace::framed]
+    async fn read_split(
+        ctx: Arc<InputContext>,
+        split_info: Arc<SplitInfo>,
+    ) -> Result<Self::RowBatch> {
+        let meta = Self::get_split_meta(&split_info).expect("must success");
+        let op = ctx.source.get_operator()?;
+        let offset = split_info.offset as u64;
+        let data = op
+            .range_read(
+                &split_info.file.path,
+                offset..offset + split_info.size as u64,
+            )
+            .await?;
+        Ok(StripeInMemory {
+            split_info: split_info.to_string(),
+            file: meta.file.clone(),
+            stripe: meta.stripe,
+            base: offset,
+            data: Arc::new(data),
+        })
+    }
+
+    fn try_create_align_state(
+        _ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<OrcAligningState> {
+        Ok(OrcAligningState {
+            split_info: split_info.clone(),
+            buffers: vec![],
+        })
+    }
+
+    fn try_create_block_builder(ctx: &Arc<InputContext>) -> Result<OrcBlockBuilder> {
+        Ok(OrcBlockBuilder { ctx: ctx.clone() })
+    }
+}
+
+pub struct OrcFileMeta {
+    pub metadata: FileMetadata,
+    // top level fields of the file, in the order of the root struct.
+    pub fields: Vec<ArrowField>,
+    // orc column id of each top level field.
+    pub column_ids: Vec<u32>,
+}
+
+impl OrcFileMeta {
+    fn try_create(metadata: FileMetadata) -> Result<Arc<Self>> {
+        let types = &metadata.footer.types;
+        let root = types
+            .first()
+            .ok_or_else(|| ErrorCode::BadBytes("orc file without root type"))?;
+        let mut fields = Vec::with_capacity(root.subtypes.len());
+        for (name, column_id) in root.field_names.iter().zip(root.subtypes.iter()) {
+            let data_type = orc_type_to_arrow(types, *column_id)?;
+            fields.push(ArrowField::new(name, data_type, true));
+        }
+        Ok(Arc::new(OrcFileMeta {
+            column_ids: root.subtypes.clone(),
+            metadata,
+            fields,
+        }))
+    }
+
+    fn arrow_schema(&self) -> common_arrow::arrow::datatypes::Schema {
+        common_arrow::arrow::datatypes::Schema::from(self.fields.clone())
+    }
+
+    /// Convert the statistics of a stripe into the statistics of the target schema,
+    /// keyed by column id. Only primitive top level columns are covered.
+    fn stripe_statistics(
+        &self,
+        stripe: usize,
+        schema: &TableSchemaRef,
+    ) -> Option<StatisticsOfColumns> {
+        let stripe_stats = self.metadata.metadata.stripe_stats.get(stripe)?;
+        let num_rows = self.metadata.footer.stripes.get(stripe)?.number_of_rows();
+        let resolved = resolve_fields(&self.fields, schema, "orc", "").ok()?;
+        let mut stats = StatisticsOfColumns::new();
+        for (field, pos) in schema.fields().iter().zip(resolved.into_iter()) {
+            let pos = match pos {
+                Some(pos) => pos,
+                None => continue,
+            };
+            let column_id = self.column_ids[pos] as usize;
+            if let Some(col_stats) = stripe_stats.col_stats.get(column_id) {
+                let data_type = field.data_type().remove_nullable();
+                if let Some(s) = convert_column_statistics(col_stats, &data_type, num_rows) {
+                    stats.insert(field.column_id(), s);
+                }
+            }
+        }
+        Some(stats)
+    }
+}
+
+#[derive(Clone)]
+pub struct SplitMeta {
+    pub file: Arc<OrcFileMeta>,
+    pub stripe: usize,
+}
+
+impl Debug for SplitMeta {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "orc split meta of stripe {}", self.stripe)
+    }
+}
+
+impl serde::Serialize for SplitMeta {
+    fn serialize<S>(&self, _serializer: S) -> Result<S::Ok, S::Error>
+    where S: Serializer {
+        unimplemented!()
+    }
+}
+
+impl<'a> serde::Deserialize<'a> for SplitMeta {
+    fn deserialize<D: Deserializer<'a>>(_deserializer: D) -> Result<Self, D::Error> {
+        unimplemented!()
+    }
+}
+
+#[typetag::serde(name = "orc_split")]
+impl DynData for SplitMeta {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+pub struct StripeInMemory {
+    pub split_info: String,
+    pub file: Arc<OrcFileMeta>,
+    pub stripe: usize,
+    // position of `data` in the file: the stripe offset when read by split,
+    // 0 when the whole file is buffered by streaming load.
+    pub base: u64,
+    pub data: Arc<Vec<u8>>,
+}
+
+impl Debug for StripeInMemory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "StripeInMemory({}, stripe {})",
+            self.split_info, self.stripe
+        )
+    }
+}
+
+impl RowBatchTrait for StripeInMemory {
+    fn size(&self) -> usize {
+        self.file.metadata.footer.stripes[self.stripe].data_length() as usize
+    }
+
+    fn rows(&self) -> usize {
+        self.file.metadata.footer.stripes[self.stripe].number_of_rows() as usize
+    }
+}
+
+#[derive(Debug)]
+pub enum ReadBatch {
+    Buffer(Vec<u8>),
+}
+
+impl From<Vec<u8>> for ReadBatch {
+    fn from(v: Vec<u8>) -> Self {
+        Self::Buffer(v)
+    }
+}
+
+impl ReadBatchTrait for ReadBatch {
+    fn size(&self) -> usize {
+        match self {
+            ReadBatch::Buffer(v) => v.len(),
+        }
+    }
+}
+
+pub struct OrcAligningState {
+    split_info: Arc<SplitInfo>,
+    buffers: Vec<Vec<u8>>,
+}
+
+impl AligningStateTrait for OrcAligningState {
+    type Pipe = OrcFormatPipe;
+
+    fn align(&mut self, read_batch: Option<ReadBatch>) -> Result<Vec<StripeInMemory>> {
+        if let Some(ReadBatch::Buffer(b)) = read_batch {
+            self.buffers.push(b);
+            return Ok(vec![]);
+        }
+        let data = Arc::new(mem::take(&mut self.buffers).concat());
+        let path = &self.split_info.file.path;
+        let mut reader = PositionedCursor::new(0, data.clone());
+        let file = OrcFileMeta::try_create(read_orc_metadata(&mut reader, path)?)?;
+        let num_stripes = file.metadata.footer.stripes.len();
+        tracing::info!(
+            "align orc file {} of {} bytes to {} stripes",
+            path,
+            data.len(),
+            num_stripes
+        );
+        Ok((0..num_stripes)
+            .map(|stripe| StripeInMemory {
+                split_info: self.split_info.to_string(),
+                file: file.clone(),
+                stripe,
+                base: 0,
+                data: data.clone(),
+            })
+            .collect())
+    }
+}
+
+pub struct OrcBlockBuilder {
+    ctx: Arc<InputContext>,
+}
+
+impl BlockBuilderTrait for OrcBlockBuilder {
+    type Pipe = OrcFormatPipe;
+
+    fn deserialize(&mut self, batch: Option<StripeInMemory>) -> Result<Vec<DataBlock>> {
+        let batch = match batch {
+            Some(batch) => batch,
+            None => return Ok(vec![]),
+        };
+        let file = &batch.file;
+        let target_schema = &self.ctx.schema;
+        let resolved = resolve_fields(&file.fields, target_schema, "orc", &batch.split_info)?;
+        let num_rows = batch.rows();
+
+        let mut reader = PositionedCursor::new(batch.base, batch.data.clone());
+        let stripe_footer = Arc::new(format::read::read_stripe_footer(
+            &mut reader,
+            &file.metadata,
+            batch.stripe,
+            vec![],
+        )?);
+        let mut stripe = StripeReader {
+            reader: &mut reader,
+            metadata: &file.metadata,
+            stripe: batch.stripe,
+            footer: stripe_footer,
+        };
+
+        let mut columns = Vec::with_capacity(resolved.len());
+        for (field, pos) in target_schema.fields().iter().zip(resolved.iter()) {
+            let target = ArrowField::from(field);
+            let array = match pos {
+                Some(pos) => {
+                    let source = &file.fields[*pos];
+                    let array =
+                        stripe.deserialize(file.column_ids[*pos], &source.data_type, num_rows)?;
+                    cast_to_target(array.as_ref(), &target.data_type)?
+                }
+                None => new_null_array(target.data_type.clone(), num_rows),
+            };
+            columns.push(array);
+        }
+        let chunk = ArrowChunk::try_new(columns)?;
+        let block = DataBlock::from_arrow_chunk(&chunk, &DataSchema::from(target_schema))?;
+        Ok(vec![block])
+    }
+}
+
+struct StripeReader<'a, R: Read + Seek> {
+    reader: &'a mut R,
+    metadata: &'a FileMetadata,
+    stripe: usize,
+    footer: Arc<StripeFooter>,
+}
+
+impl<'a, R: Read + Seek> StripeReader<'a, R> {
+    fn column(&mut self, column_id: u32) -> Result<OrcColumn> {
+        Ok(format::read::read_stripe_column(
+            &mut self.reader,
+            self.metadata,
+            self.stripe,
+            self.footer.clone(),
+            column_id,
+            vec![],
+        )?)
+    }
+
+    /// Deserialize `num_rows` values of the column `column_id`.
+    ///
+    /// Nested columns store their children without the rows that are null in the parent,
+    /// and list/map children hold `sum(lengths)` values, so the children are decoded with
+    /// the row count derived from the parent before being assembled.
+    fn deserialize(
+        &mut self,
+        column_id: u32,
+        data_type: &ArrowDataType,
+        num_rows: usize,
+    ) -> Result<Box<dyn Array>> {
+        let types = &self.metadata.footer.types;
+        let orc_type = &types[column_id as usize];
+        match data_type {
+            ArrowDataType::Struct(fields) => {
+                let column = self.column(column_id)?;
+                let validity = read_validity(&column, num_rows)?;
+                let num_values = count_valid(validity.as_ref(), num_rows);
+                let mut children = Vec::with_capacity(fields.len());
+                for (f, child_id) in fields.iter().zip(orc_type.subtypes.iter()) {
+                    let child = self.deserialize(*child_id, &f.data_type, num_values)?;
+                    children.push(spread_by_validity(child, validity.as_ref(), num_rows)?);
+                }
+                Ok(StructArray::try_new(data_type.clone(), children, validity)?.boxed())
+            }
+            ArrowDataType::LargeList(item) => {
+                let column = self.column(column_id)?;
+                let validity = read_validity(&column, num_rows)?;
+                let offsets = read_offsets(&column, validity.as_ref(), num_rows)?;
+                let child_rows = *offsets.last() as usize;
+                let values = self.deserialize(orc_type.subtypes[0], &item.data_type, child_rows)?;
+                Ok(ListArray::<i64>::try_new(
+                    data_type.clone(),
+                    OffsetsBuffer::try_from(offsets)?,
+                    values,
+                    validity,
+                )?
+                .boxed())
+            }
+            ArrowDataType::Map(entries, _) => {
+                let column = self.column(column_id)?;
+                let validity = read_validity(&column, num_rows)?;
+                let offsets = read_offsets(&column, validity.as_ref(), num_rows)?;
+                let child_rows = *offsets.last() as usize;
+                let kv_fields = match &entries.data_type {
+                    ArrowDataType::Struct(kv_fields) => kv_fields,
+                    _ => unreachable!("map entries are always a struct"),
+                };
+                let keys =
+                    self.deserialize(orc_type.subtypes[0], &kv_fields[0].data_type, child_rows)?;
+                let values =
+                    self.deserialize(orc_type.subtypes[1], &kv_fields[1].data_type, child_rows)?;
+                let entries_array =
+                    StructArray::try_new(entries.data_type.clone(), vec![keys, values], None)?;
+                let offsets = offsets.iter().map(|o| *o as i32).collect::<Vec<_>>();
+                Ok(MapArray::try_new(
+                    data_type.clone(),
+                    OffsetsBuffer::try_from(offsets)?,
+                    entries_array.boxed(),
+                    validity,
+                )?
+                .boxed())
+            }
+            _ => {
+                let column = self.column(column_id)?;
+                let array = orc_read::deserialize(data_type.clone(), &column)?;
+                if array.len() < num_rows {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "orc column {} has {} values, expect {}",
+                        column_id,
+                        array.len(),
+                        num_rows
+                    )));
+                }
+                Ok(array.sliced(0, num_rows))
+            }
+        }
+    }
+}
+
+fn read_validity(column: &OrcColumn, num_rows: usize) -> Result<Option<Bitmap>> {
+    match column.get_stream(StreamKind::Present, vec![]) {
+        Ok(reader) => {
+            let validity = BooleanIter::new(reader, num_rows).collect::<Result<Bitmap, _>>()?;
+            Ok(Some(validity))
+        }
+        Err(_) => Ok(None),
+    }
+}
+
+fn count_valid(validity: Option<&Bitmap>, num_rows: usize) -> usize {
+    validity
+        .map(|v| v.len() - v.unset_bits())
+        .unwrap_or(num_rows)
+}
+
+/// Decode the LENGTH stream of a list or map column into offsets over its children.
+fn read_offsets(
+    column: &OrcColumn,
+    validity: Option<&Bitmap>,
+    num_rows: usize,
+) -> Result<Vec<i64>> {
+    if column.encoding().kind() != EncodingKind::DirectV2 {
+        return Err(ErrorCode::Unimplemented(format!(
+            "orc list/map length encoding {:?} is not supported",
+            column.encoding().kind()
+        )));
+    }
+    let num_values = count_valid(validity, num_rows);
+    let reader = column.get_stream(StreamKind::Length, vec![])?;
+    let mut lengths = UnsignedRleV2Iter::new(reader, num_values, vec![]);
+    let mut offsets = Vec::with_capacity(num_rows + 1);
+    let mut last = 0i64;
+    offsets.push(last);
+    for row in 0..num_rows {
+        if validity.map(|v| v.get_bit(row)).unwrap_or(true) {
+            let length = lengths.next().ok_or_else(|| {
+                ErrorCode::BadBytes(format!("orc length stream ends before row {}", row))
+            })??;
+            last += length as i64;
+        }
+        offsets.push(last);
+    }
+    Ok(offsets)
+}
+
+/// Place the values of a struct child, which only holds the non-null rows of the
+/// struct, back to the positions of those rows.
+fn spread_by_validity(
+    child: Box<dyn Array>,
+    validity: Option<&Bitmap>,
+    num_rows: usize,
+) -> Result<Box<dyn Array>> {
+    let validity = match validity {
+        Some(v) if v.unset_bits() > 0 => v,
+        _ => return Ok(child),
+    };
+    let mut next = 0u32;
+    let indices = (0..num_rows)
+        .map(|row| {
+            validity.get_bit(row).then(|| {
+                next += 1;
+                next - 1
+            })
+        })
+        .collect::<PrimitiveArray<u32>>();
+    Ok(take(child.as_ref(), &indices)?)
+}
+
+/// Map an ORC type onto the arrow type that converts into the matching
+/// `TableDataType`: struct to `Tuple`, list to `Array` and map to `Map`.
+fn orc_type_to_arrow(types: &[format::proto::Type], column_id: u32) -> Result<ArrowDataType> {
+    let t = types.get(column_id as usize).ok_or_else(|| {
+        ErrorCode::BadBytes(format!("orc column {} has no type in footer", column_id))
+    })?;
+    let data_type = match t.kind() {
+        Kind::Boolean => ArrowDataType::Boolean,
+        Kind::Byte => ArrowDataType::Int8,
+        Kind::Short => ArrowDataType::Int16,
+        Kind::Int => ArrowDataType::Int32,
+        Kind::Long => ArrowDataType::Int64,
+        Kind::Float => ArrowDataType::Float32,
+        Kind::Double => ArrowDataType::Float64,
+        Kind::String | Kind::Varchar | Kind::Char => ArrowDataType::Utf8,
+        Kind::Binary => ArrowDataType::Binary,
+        Kind::Date => ArrowDataType::Date32,
+        Kind::Timestamp | Kind::TimestampInstant => {
+            ArrowDataType::Timestamp(TimeUnit::Nanosecond, None)
+        }
+        Kind::Decimal => ArrowDataType::Decimal(t.precision() as usize, t.scale() as usize),
+        Kind::List => {
+            let item = orc_type_to_arrow(types, t.subtypes[0])?;
+            ArrowDataType::LargeList(Box::new(ArrowField::new("item", item, true)))
+        }
+        Kind::Map => {
+            let key = orc_type_to_arrow(types, t.subtypes[0])?;
+            let value = orc_type_to_arrow(types, t.subtypes[1])?;
+            let entries = ArrowDataType::Struct(vec![
+                ArrowField::new("key", key, false),
+                ArrowField::new("value", value, true),
+            ]);
+            ArrowDataType::Map(Box::new(ArrowField::new("entries", entries, false)), false)
+        }
+        Kind::Struct => {
+            let mut fields = Vec::with_capacity(t.subtypes.len());
+            for (name, child) in t.field_names.iter().zip(t.subtypes.iter()) {
+                fields.push(ArrowField::new(
+                    name,
+                    orc_type_to_arrow(types, *child)?,
+                    true,
+                ));
+            }
+            ArrowDataType::Struct(fields)
+        }
+        kind => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "orc type {:?} is not supported",
+                kind
+            )));
+        }
+    };
+    Ok(data_type)
+}
+
+fn convert_column_statistics(
+    stats: &OrcColumnStatistics,
+    data_type: &TableDataType,
+    num_rows: u64,
+) -> Option<ColumnStatistics> {
+    let (min, max) = match data_type {
+        TableDataType::Number(num_type) => {
+            match (&stats.int_statistics, &stats.double_statistics) {
+                (Some(s), _) => (
+                    int_scalar(num_type, s.minimum?)?,
+                    int_scalar(num_type, s.maximum?)?,
+                ),
+                (_, Some(s)) => (
+                    float_scalar(num_type, s.minimum?)?,
+                    float_scalar(num_type, s.maximum?)?,
+                ),
+                _ => return None,
+            }
+        }
+        TableDataType::String => {
+            let s = stats.string_statistics.as_ref()?;
+            (
+                Scalar::String(s.minimum.clone()?.into_bytes()),
+                Scalar::String(s.maximum.clone()?.into_bytes()),
+            )
+        }
+        TableDataType::Date => {
+            let s = stats.date_statistics.as_ref()?;
+            (Scalar::Date(s.minimum?), Scalar::Date(s.maximum?))
+        }
+        _ => return None,
+    };
+    let null_count = num_rows.saturating_sub(stats.number_of_values());
+    Some(ColumnStatistics {
+        min,
+        max,
+        null_count,
+        in_memory_size: 0, // this field is not used.
+        distinct_of_values: None,
+    })
+}
+
+fn int_scalar(num_type: &NumberDataType, v: i64) -> Option<Scalar> {
+    let n = match num_type {
+        NumberDataType::Int8 => NumberScalar::Int8(v.try_into().ok()?),
+        NumberDataType::Int16 => NumberScalar::Int16(v.try_into().ok()?),
+        NumberDataType::Int32 => NumberScalar::Int32(v.try_into().ok()?),
+        NumberDataType::Int64 => NumberScalar::Int64(v),
+        NumberDataType::UInt8 => NumberScalar::UInt8(v.try_into().ok()?),
+        NumberDataType::UInt16 => NumberScalar::UInt16(v.try_into().ok()?),
+        NumberDataType::UInt32 => NumberScalar::UInt32(v.try_into().ok()?),
+        NumberDataType::UInt64 => NumberScalar::UInt64(v.try_into().ok()?),
+        NumberDataType::Float32 | NumberDataType::Float64 => {
+            return float_scalar(num_type, v as f64);
+        }
+    };
+    Some(Scalar::Number(n))
+}
+
+fn float_scalar(num_type: &NumberDataType, v: f64) -> Option<Scalar> {
+    let n = match num_type {
+        NumberDataType::Float32 => NumberScalar::Float32((v as f32).into()),
+        NumberDataType::Float64 => NumberScalar::Float64(v.into()),
+        _ => return None,
+    };
+    Some(Scalar::Number(n))
+}
+
+fn read_orc_metadata<R: Read + Seek>(reader: &mut R, path: &str) -> Result<FileMetadata> {
+    format::read::read_metadata(reader)
+        .map_err(|e| ErrorCode::BadBytes(format!("invalid orc file {}: {:?}", path, e)))
+}
+
+/// A cursor over a byte range of a file that seeks with file positions.
+struct PositionedCursor {
+    base: u64,
+    pos: u64,
+    data: Arc<Vec<u8>>,
+}
+
+impl PositionedCursor {
+    fn new(base: u64, data: Arc<Vec<u8>>) -> Self {
+        PositionedCursor {
+            base,
+            pos: base,
+            data,
+        }
+    }
+}
+
+impl Read for PositionedCursor {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let start = (self.pos - self.base) as usize;
+        let n = buf.len().min(self.data.len().saturating_sub(start));
+        buf[..n].copy_from_slice(&self.data[start..start + n]);
+        self.pos += n as u64;
+        Ok(n)
+    }
+}
+
+impl Seek for PositionedCursor {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let end = self.base + self.data.len() as u64;
+        let new_pos = match pos {
+            SeekFrom::Start(p) => p as i64,
+            SeekFrom::End(p) => end as i64 + p,
+            SeekFrom::Current(p) => self.pos as i64 + p,
+        };
+        if new_pos < self.base as i64 || new_pos as u64 > end {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "seek to {} out of the buffered range {}..{}",
+                    new_pos, self.base, end
+                ),
+            ));
+        }
+        self.pos = new_pos as u64;
+        Ok(self.pos)
+    }
+}
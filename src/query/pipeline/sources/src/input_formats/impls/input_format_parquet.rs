@@ -89,6 +89,7 @@ impl InputFormatParquet {
                 size,
                 num_splits: row_groups.len(),
                 compress_alg: None,
+                last_modified: info.last_modified.timestamp_micros(),
             });
 
             let num_file_splits = row_groups.len();
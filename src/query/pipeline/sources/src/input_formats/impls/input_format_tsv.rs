@@ -179,7 +179,8 @@ impl InputFormatTextBase for InputFormatTSV {
             if let Err(e) = Self::read_row(field_delimiter, field_decoder, buf, columns, schema) {
                 match builder.ctx.on_error_mode {
                     OnErrorMode::Continue => {
-                        Self::on_error_continue(columns, num_rows, e.clone(), &mut error_map);
+                        let rejected = batch.rejected_record(&e, i, buf);
+                        Self::on_error_continue(columns, num_rows, e, rejected, &mut error_map);
                         start = *end;
                         continue;
                     }
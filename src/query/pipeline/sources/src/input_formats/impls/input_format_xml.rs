@@ -30,6 +30,7 @@ use common_meta_app::principal::OnErrorMode;
 use common_meta_app::principal::StageFileFormatType;
 use common_meta_app::principal::XmlFileFormatParams;
 use common_pipeline_core::InputError;
+use common_pipeline_core::RejectedRecord;
 use xml::reader::XmlEvent;
 use xml::ParserConfig;
 
@@ -252,10 +253,24 @@ impl InputFormatTextBase for InputFormatXML {
                             ) {
                                 match builder.ctx.on_error_mode {
                                     OnErrorMode::Continue => {
+                                        let mut fields = cols
+                                            .iter()
+                                            .map(|(k, v)| {
+                                                format!("<{k}>{}</{k}>", String::from_utf8_lossy(v))
+                                            })
+                                            .collect::<Vec<_>>();
+                                        fields.sort();
+                                        let rejected = RejectedRecord {
+                                            file: path.clone(),
+                                            line: None,
+                                            error: e.message(),
+                                            record: fields.join(""),
+                                        };
                                         Self::on_error_continue(
                                             columns,
                                             num_rows,
-                                            e.clone(),
+                                            e,
+                                            rejected,
                                             &mut error_map,
                                         );
                                         continue;
@@ -28,6 +28,7 @@ use common_exception::Result;
 use common_expression::BlockThresholds;
 use common_expression::DataSchema;
 use common_expression::TableSchemaRef;
+use common_formats::ClickhouseBinaryFormat;
 use common_formats::ClickhouseFormatType;
 use common_formats::FileFormatOptionsExt;
 use common_meta_app::principal::FileFormatParams;
@@ -36,12 +37,17 @@ use common_meta_app::principal::StageFileCompression;
 use common_meta_app::principal::StageInfo;
 use common_pipeline_core::InputError;
 use common_settings::Settings;
+use common_storage::StageMetadataColumn;
 use dashmap::DashMap;
 use opendal::Operator;
 
+use crate::input_formats::impls::InputFormatAvro;
 use crate::input_formats::impls::InputFormatCSV;
 use crate::input_formats::impls::InputFormatNDJson;
+use crate::input_formats::impls::InputFormatNative;
+use crate::input_formats::impls::InputFormatOrc;
 use crate::input_formats::impls::InputFormatParquet;
+use crate::input_formats::impls::InputFormatRowBinary;
 use crate::input_formats::impls::InputFormatTSV;
 use crate::input_formats::impls::InputFormatXML;
 use crate::input_formats::InputFormat;
@@ -125,6 +131,8 @@ pub struct InputContext {
     pub on_error_count: AtomicU64,
     pub on_error_map: Option<Arc<DashMap<String, HashMap<u16, InputError>>>>,
     pub projection: Option<Vec<usize>>,
+    /// Filled after the columns of `schema`, only supported by the text formats.
+    pub metadata_columns: Vec<StageMetadataColumn>,
 }
 
 impl Debug for InputContext {
@@ -146,6 +154,8 @@ impl InputContext {
             FileFormatParams::NdJson(_) => Ok(Arc::new(InputFormatNDJson::create())),
             FileFormatParams::Parquet(_) => Ok(Arc::new(InputFormatParquet {})),
             FileFormatParams::Xml(_) => Ok(Arc::new(InputFormatXML::create())),
+            FileFormatParams::Avro(_) => Ok(Arc::new(InputFormatAvro {})),
+            FileFormatParams::Orc(_) => Ok(Arc::new(InputFormatOrc {})),
             format => Err(ErrorCode::Internal(format!(
                 "Unsupported file format: {:?}",
                 format
@@ -193,6 +203,7 @@ impl InputContext {
             on_error_count: AtomicU64::new(0),
             on_error_map: Some(on_error_map),
             projection,
+            metadata_columns: vec![],
         })
     }
 
@@ -205,25 +216,48 @@ impl InputContext {
         scan_progress: Arc<Progress>,
         block_compact_thresholds: BlockThresholds,
     ) -> Result<Self> {
-        let typ = ClickhouseFormatType::parse_clickhouse_format(format_name)?;
-        let file_format_options_ext =
-            FileFormatOptionsExt::create_from_clickhouse_format(typ.clone(), &settings)?;
-        let mut file_format_params = FileFormatParams::default_by_type(typ.typ)?;
-
-        let headers = file_format_options_ext.headers as u64;
-        if headers > 0 {
-            match &mut file_format_params {
-                FileFormatParams::Csv(p) => {
-                    p.headers = headers;
+        let (format, file_format_params, file_format_options_ext) =
+            match ClickhouseBinaryFormat::parse(format_name) {
+                Some(binary) => {
+                    let mut file_format_options_ext =
+                        FileFormatOptionsExt::create_from_settings(&settings, false)?;
+                    let format: Arc<dyn InputFormat> = match binary {
+                        ClickhouseBinaryFormat::RowBinary { headers } => {
+                            file_format_options_ext.headers = headers;
+                            Arc::new(InputFormatRowBinary::create())
+                        }
+                        ClickhouseBinaryFormat::Native => Arc::new(InputFormatNative::create()),
+                    };
+                    // the binary formats have no options of a file format, the params are
+                    // only shown in the errors.
+                    (format, FileFormatParams::default(), file_format_options_ext)
                 }
-                FileFormatParams::Tsv(p) => {
-                    p.headers = headers;
+                None => {
+                    let typ = ClickhouseFormatType::parse_clickhouse_format(format_name)?;
+                    let file_format_options_ext =
+                        FileFormatOptionsExt::create_from_clickhouse_format(
+                            typ.clone(),
+                            &settings,
+                        )?;
+                    let mut file_format_params = FileFormatParams::default_by_type(typ.typ)?;
+
+                    let headers = file_format_options_ext.headers as u64;
+                    if headers > 0 {
+                        match &mut file_format_params {
+                            FileFormatParams::Csv(p) => {
+                                p.headers = headers;
+                            }
+                            FileFormatParams::Tsv(p) => {
+                                p.headers = headers;
+                            }
+                            _ => {}
+                        }
+                    }
+
+                    let format = Self::get_input_format(&file_format_params)?;
+                    (format, file_format_params, file_format_options_ext)
                 }
-                _ => {}
-            }
-        }
-
-        let format = Self::get_input_format(&file_format_params)?;
+            };
         let read_batch_size = settings.get_input_read_buffer_size()? as usize;
         let compression = StageFileCompression::Auto;
         let plan = StreamPlan {
@@ -247,6 +281,7 @@ impl InputContext {
             on_error_count: AtomicU64::new(0),
             on_error_map: None,
             projection: None,
+            metadata_columns: vec![],
         })
     }
 
@@ -285,6 +320,7 @@ impl InputContext {
             on_error_count: AtomicU64::new(0),
             on_error_map: None,
             projection: None,
+            metadata_columns: vec![],
         })
     }
 
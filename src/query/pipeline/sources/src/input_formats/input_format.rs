@@ -21,6 +21,7 @@ use common_pipeline_core::Pipeline;
 use common_settings::Settings;
 use common_storage::StageFileInfo;
 use opendal::Operator;
+use storages_common_table_meta::meta::StatisticsOfColumns;
 
 use crate::input_formats::InputContext;
 use crate::input_formats::SplitInfo;
@@ -37,6 +38,16 @@ pub trait InputFormat: Send + Sync {
 
     async fn infer_schema(&self, path: &str, op: &Operator) -> Result<TableSchemaRef>;
 
+    /// Statistics of the columns of `schema` in a split, for formats that keep them
+    /// in file metadata. Splits without statistics are never pruned.
+    fn split_statistics(
+        &self,
+        _split: &SplitInfo,
+        _schema: &TableSchemaRef,
+    ) -> Option<StatisticsOfColumns> {
+        None
+    }
+
     fn exec_copy(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()>;
 
     fn exec_stream(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()>;
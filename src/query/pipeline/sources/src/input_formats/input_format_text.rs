@@ -23,10 +23,13 @@ use common_compress::DecompressDecoder;
 use common_compress::DecompressState;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::number::NumberScalar;
 use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::DataType;
 use common_expression::Column;
 use common_expression::ColumnBuilder;
 use common_expression::DataBlock;
+use common_expression::ScalarRef;
 use common_expression::TableSchemaRef;
 use common_formats::FieldDecoder;
 use common_formats::FileFormatOptionsExt;
@@ -35,8 +38,10 @@ use common_meta_app::principal::StageFileFormatType;
 use common_meta_app::principal::StageInfo;
 use common_pipeline_core::InputError;
 use common_pipeline_core::Pipeline;
+use common_pipeline_core::RejectedRecord;
 use common_settings::Settings;
 use common_storage::StageFileInfo;
+use common_storage::StageMetadataColumn;
 use opendal::Operator;
 
 use crate::input_formats::input_pipeline::AligningStateTrait;
@@ -251,6 +256,7 @@ pub trait InputFormatTextBase: Sized + Send + Sync + 'static {
         columns: &mut Vec<ColumnBuilder>,
         num_rows: usize,
         e: ErrorCode,
+        rejected: RejectedRecord,
         error_map: &mut HashMap<u16, InputError>,
     ) {
         columns.iter_mut().for_each(|c| {
@@ -259,10 +265,13 @@ pub trait InputFormatTextBase: Sized + Send + Sync + 'static {
                 c.pop().expect("must success");
             }
         });
-        error_map
-            .entry(e.code())
-            .and_modify(|input_error| input_error.num += 1)
-            .or_insert(InputError { err: e, num: 1 });
+        let input_error = error_map.entry(e.code()).or_insert(InputError {
+            err: e,
+            num: 0,
+            rejected: vec![],
+        });
+        input_error.num += 1;
+        input_error.rejected.push(rejected);
     }
 
     fn on_error_abort(
@@ -323,6 +332,7 @@ impl<T: InputFormatTextBase> InputFormat for T {
         for info in file_infos {
             let size = info.size as usize;
             let path = info.path.clone();
+            let last_modified = info.last_modified.timestamp_micros();
 
             let compress_alg = InputContext::get_compression_alg_copy(
                 stage_info.file_format_params.compression(),
@@ -344,6 +354,7 @@ impl<T: InputFormatTextBase> InputFormat for T {
                     size,
                     num_splits: split_offsets.len(),
                     compress_alg,
+                    last_modified,
                 });
                 for (i, (offset, size)) in split_offsets.into_iter().enumerate() {
                     infos.push(Arc::new(SplitInfo {
@@ -361,6 +372,7 @@ impl<T: InputFormatTextBase> InputFormat for T {
                     size, // dummy
                     num_splits: 1,
                     compress_alg,
+                    last_modified,
                 });
                 infos.push(Arc::new(SplitInfo {
                     file,
@@ -415,6 +427,17 @@ impl RowBatch {
             self.start_row_of_split,
         )
     }
+
+    pub fn rejected_record(&self, e: &ErrorCode, row: usize, record: &[u8]) -> RejectedRecord {
+        RejectedRecord {
+            file: self.split_info.file.path.clone(),
+            line: self
+                .start_row_of_split
+                .map(|r| r + self.start_row_in_split + row + 1),
+            error: e.message(),
+            record: String::from_utf8_lossy(record).trim_end().to_string(),
+        }
+    }
 }
 
 impl RowBatchTrait for RowBatch {
@@ -486,6 +509,7 @@ pub struct BlockBuilder<T> {
     pub mutable_columns: Vec<ColumnBuilder>,
     pub num_rows: usize,
     pub projection: Option<Vec<usize>>,
+    metadata_columns: Vec<(StageMetadataColumn, ColumnBuilder)>,
     phantom: PhantomData<T>,
 }
 
@@ -507,6 +531,14 @@ impl<T: InputFormatTextBase> BlockBuilder<T> {
         let field_decoder =
             T::create_field_decoder(&ctx.file_format_params, &ctx.file_format_options_ext);
         let projection = ctx.projection.clone();
+        let metadata_columns = ctx
+            .metadata_columns
+            .iter()
+            .map(|c| {
+                let data_type = DataType::from(&c.data_type());
+                (*c, ColumnBuilder::with_capacity(&data_type, 1024))
+            })
+            .collect();
 
         BlockBuilder {
             ctx,
@@ -515,6 +547,43 @@ impl<T: InputFormatTextBase> BlockBuilder<T> {
             field_decoder,
             phantom: PhantomData,
             projection,
+            metadata_columns,
+        }
+    }
+
+    fn num_built_rows(&self) -> usize {
+        self.mutable_columns.first().map_or(0, |c| c.len())
+    }
+
+    fn append_metadata_columns(
+        &mut self,
+        file: &FileInfo,
+        start_row: Option<usize>,
+        num_rows: usize,
+    ) {
+        for (column, builder) in self.metadata_columns.iter_mut() {
+            match column {
+                StageMetadataColumn::FileName => {
+                    let path = ScalarRef::String(file.path.as_bytes());
+                    for _ in 0..num_rows {
+                        builder.push(path.clone());
+                    }
+                }
+                StageMetadataColumn::FileRowNumber => {
+                    for i in 0..num_rows {
+                        let row = start_row.map(|start| (start + i + 1) as u64);
+                        builder.push(match row {
+                            Some(row) => ScalarRef::Number(NumberScalar::UInt64(row)),
+                            None => ScalarRef::Null,
+                        });
+                    }
+                }
+                StageMetadataColumn::FileLastModified => {
+                    for _ in 0..num_rows {
+                        builder.push(ScalarRef::Timestamp(file.last_modified));
+                    }
+                }
+            }
         }
     }
 
@@ -529,7 +598,7 @@ impl<T: InputFormatTextBase> BlockBuilder<T> {
             })
             .collect();
 
-        let columns = if let Some(projection) = &self.projection {
+        let mut columns = if let Some(projection) = &self.projection {
             columns
                 .into_iter()
                 .enumerate()
@@ -551,6 +620,10 @@ impl<T: InputFormatTextBase> BlockBuilder<T> {
         } else {
             columns
         };
+        for (_, builder) in self.metadata_columns.iter_mut() {
+            let empty_builder = ColumnBuilder::with_capacity(&builder.data_type(), 1024);
+            columns.push(std::mem::replace(builder, empty_builder).build());
+        }
         self.num_rows = 0;
 
         if columns.is_empty() || columns[0].len() == 0 {
@@ -570,7 +643,15 @@ impl<T: InputFormatTextBase> BlockBuilder<T> {
                 .entry(file_name)
                 .and_modify(|x| {
                     for (k, v) in error_map.clone() {
-                        x.entry(k).and_modify(|y| y.num += v.num).or_insert(v);
+                        match x.get_mut(&k) {
+                            Some(y) => {
+                                y.num += v.num;
+                                y.rejected.extend(v.rejected);
+                            }
+                            None => {
+                                x.insert(k, v);
+                            }
+                        }
                     }
                 })
                 .or_insert(error_map);
@@ -585,7 +666,14 @@ impl<T: InputFormatTextBase> BlockBuilderTrait for BlockBuilder<T> {
         if let Some(b) = batch {
             let file_name = b.split_info.file.path.clone();
             self.num_rows += b.row_ends.len();
+            let file = b.split_info.file.clone();
+            let start_row = b.start_row_of_split.map(|s| s + b.start_row_in_split);
+            let rows_before = self.num_built_rows();
             let r = T::deserialize(self, b)?;
+            if !self.metadata_columns.is_empty() {
+                let num_rows = self.num_built_rows() - rows_before;
+                self.append_metadata_columns(&file, start_row, num_rows);
+            }
             self.merge_map(r, file_name);
             let mem = self.memory_size();
             tracing::debug!(
@@ -606,7 +694,7 @@ impl<T: InputFormatTextBase> BlockBuilderTrait for BlockBuilder<T> {
     }
 }
 
-fn decompress(decoder: &mut DecompressDecoder, compressed: &[u8]) -> Result<Vec<u8>> {
+pub(crate) fn decompress(decoder: &mut DecompressDecoder, compressed: &[u8]) -> Result<Vec<u8>> {
     let mut decompress_bufs = vec![];
     let mut amt = 0;
     loop {
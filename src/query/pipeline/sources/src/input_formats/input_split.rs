@@ -35,6 +35,8 @@ pub struct FileInfo {
     pub size: usize,
     pub num_splits: usize,
     pub compress_alg: Option<CompressAlgorithm>,
+    /// Last modified time of the file in microseconds, 0 for the streaming load.
+    pub last_modified: i64,
 }
 
 impl PartialEq for FileInfo {
@@ -138,6 +140,7 @@ impl SplitInfo {
                 size: 0,
                 num_splits: 1,
                 compress_alg,
+                last_modified: 0,
             }),
             seq_in_file: 0,
             offset: 0,
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod beyond_end_reader;
+mod format_detector;
 mod impls;
 mod input_context;
 mod input_format;
@@ -24,6 +25,12 @@ mod source_deserializer;
 mod transform_deserializer;
 
 pub use beyond_end_reader::BeyondEndReader;
+pub use format_detector::detect_file_format;
+pub use format_detector::detect_file_format_by_content;
+pub use format_detector::FORMAT_DETECT_SAMPLE_SIZE;
+pub use impls::InputFormatAvro;
+pub use impls::InputFormatCSV;
+pub use impls::InputFormatOrc;
 pub use input_context::InputContext;
 pub use input_context::InputPlan;
 pub use input_context::StreamPlan;
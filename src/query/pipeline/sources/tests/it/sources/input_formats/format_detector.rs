@@ -0,0 +1,78 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_meta_app::principal::FileFormatParams;
+use common_meta_app::principal::StageFileCompression;
+use common_meta_app::principal::StageFileFormatType;
+use common_pipeline_sources::input_formats::detect_file_format_by_content;
+
+#[test]
+fn test_detect_binary_formats() -> Result<()> {
+    let detect = |sample: &[u8]| detect_file_format_by_content("f", sample, false);
+    assert_eq!(
+        detect(b"PAR1\x15\x04")?.get_type(),
+        StageFileFormatType::Parquet
+    );
+    assert_eq!(
+        detect(b"Obj\x01\x04")?.get_type(),
+        StageFileFormatType::Avro
+    );
+    assert_eq!(detect(b"ORC\x0a")?.get_type(), StageFileFormatType::Orc);
+    Ok(())
+}
+
+#[test]
+fn test_detect_text_formats() -> Result<()> {
+    let format = detect_file_format_by_content("a.json", b"{\"a\": 1}\n{\"a\": 2}\n", true)?;
+    assert_eq!(format.get_type(), StageFileFormatType::NdJson);
+    assert_eq!(format.compression(), StageFileCompression::None);
+
+    let format = detect_file_format_by_content("a", b"<data><row><a>1</a></row></data>", true)?;
+    assert_eq!(format.get_type(), StageFileFormatType::Xml);
+    Ok(())
+}
+
+#[test]
+fn test_detect_csv() -> Result<()> {
+    let csv = |sample: &[u8], is_complete: bool| match detect_file_format_by_content(
+        "a.csv",
+        sample,
+        is_complete,
+    )
+    .unwrap()
+    {
+        FileFormatParams::Csv(params) => params,
+        other => panic!("expect csv, got {other}"),
+    };
+
+    let params = csv(b"1,a\n2,b\n", true);
+    assert_eq!(params.field_delimiter, ",");
+    assert_eq!(params.quote, "\"");
+    assert_eq!(params.record_delimiter, "\n");
+
+    let params = csv(b"1|'a|b'|x\r\n2|'c'|y\r\n", true);
+    assert_eq!(params.field_delimiter, "|");
+    assert_eq!(params.quote, "'");
+    assert_eq!(params.record_delimiter, "\r\n");
+
+    let params = csv(b"1\t\"a,b\"\n2\t\"c\"\n3\t", false);
+    assert_eq!(params.field_delimiter, "\t");
+    assert_eq!(params.quote, "\"");
+
+    // no consistent delimiter, keep the defaults.
+    let params = csv(b"1,2\n3\n", true);
+    assert_eq!(params.field_delimiter, ",");
+    Ok(())
+}
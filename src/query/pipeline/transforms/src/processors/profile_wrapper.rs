@@ -12,12 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
 use std::time::Instant;
 
+use common_base::runtime::MemStat;
+use common_base::runtime::ThreadTracker;
 use common_exception::Result;
 use common_pipeline_core::processors::processor::Event;
 use common_pipeline_core::processors::Processor;
 use common_profile::ProcessorProfile;
+use common_profile::ProcessorStats;
 use common_profile::SharedProcessorProfiles;
 
 pub struct ProfileWrapper<T> {
@@ -26,6 +30,8 @@ pub struct ProfileWrapper<T> {
     prof_span_set: SharedProcessorProfiles,
 
     prof: ProcessorProfile,
+    /// Memory accounting node of the plan node, created on the first process.
+    mem_stat: Option<Arc<MemStat>>,
 }
 
 impl<T> ProfileWrapper<T>
@@ -41,8 +47,36 @@ where T: Processor + 'static
             prof_span_id,
             prof_span_set,
             prof: ProcessorProfile::default(),
+            mem_stat: None,
         })
     }
+
+    fn mem_stat(&mut self) -> Arc<MemStat> {
+        match &self.mem_stat {
+            Some(mem_stat) => mem_stat.clone(),
+            None => {
+                // the current one is the memory stat of the query on the executor threads.
+                let mem_stat = self
+                    .prof_span_set
+                    .lock()
+                    .unwrap()
+                    .mem_stat(self.prof_span_id, MemStat::current());
+                self.mem_stat = Some(mem_stat.clone());
+                mem_stat
+            }
+        }
+    }
+
+    /// Add the stats recorded by the inner processor since the last take.
+    fn record_stats(&mut self, stats: ProcessorStats) {
+        self.prof = self.prof
+            + ProcessorProfile {
+                output_rows: stats.output_rows,
+                output_bytes: stats.output_bytes,
+                spill_bytes: stats.spill_bytes,
+                ..Default::default()
+            };
+    }
 }
 
 #[async_trait::async_trait]
@@ -58,7 +92,12 @@ where T: Processor + 'static
     }
 
     fn event(&mut self) -> Result<Event> {
-        match self.inner.event()? {
+        // drop the stats left by the processors not profiled.
+        ProcessorStats::take();
+        let event = self.inner.event()?;
+        self.record_stats(ProcessorStats::take());
+
+        match event {
             Event::Finished => {
                 self.prof_span_set
                     .lock()
@@ -71,16 +110,33 @@ where T: Processor + 'static
     }
 
     fn process(&mut self) -> Result<()> {
+        let _guard = ThreadTracker::enter(Some(self.mem_stat()));
+        ProcessorStats::take();
         let instant = Instant::now();
         self.inner.process()?;
         let elapsed = instant.elapsed();
-        self.prof = self.prof + ProcessorProfile { cpu_time: elapsed };
+        self.record_stats(ProcessorStats::take());
+        self.prof = self.prof
+            + ProcessorProfile {
+                cpu_time: elapsed,
+                ..Default::default()
+            };
         Ok(())
     }
 
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
-        // TODO: record profile information for async process
-        self.inner.async_process().await
+        ProcessorStats::take();
+        let instant = Instant::now();
+        self.inner.async_process().await?;
+        let elapsed = instant.elapsed();
+        // the stats are recorded by the last poll, which runs on the current thread.
+        self.record_stats(ProcessorStats::take());
+        self.prof = self.prof
+            + ProcessorProfile {
+                wait_time: elapsed,
+                ..Default::default()
+            };
+        Ok(())
     }
 }
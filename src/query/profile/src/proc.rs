@@ -12,12 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cell::Cell;
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::sync::Arc;
 use std::sync::Mutex;
 use std::time::Duration;
 
+use common_base::runtime::MemStat;
+
 pub type SharedProcessorProfiles = Arc<Mutex<ProcessorProfiles<u32>>>;
 
 /// Execution profile information of a `Processor`.
@@ -34,6 +37,19 @@ pub type SharedProcessorProfiles = Arc<Mutex<ProcessorProfiles<u32>>>;
 pub struct ProcessorProfile {
     /// The time spent to process in nanoseconds
     pub cpu_time: Duration,
+    /// Bytes allocated and not yet released by the processors, the memory
+    /// released by the downstream processors is accounted to them.
+    pub memory_usage: i64,
+    /// Peak of `memory_usage`
+    pub peak_memory_usage: i64,
+    /// The time spent to wait for async process (e.g. IO) in nanoseconds
+    pub wait_time: Duration,
+    /// Rows pushed to the output ports
+    pub output_rows: usize,
+    /// Bytes pushed to the output ports
+    pub output_bytes: usize,
+    /// Bytes spilled to the storage
+    pub spill_bytes: usize,
 }
 
 impl std::ops::Add for ProcessorProfile {
@@ -42,13 +58,61 @@ impl std::ops::Add for ProcessorProfile {
     fn add(self, rhs: Self) -> Self::Output {
         Self {
             cpu_time: self.cpu_time + rhs.cpu_time,
+            memory_usage: self.memory_usage + rhs.memory_usage,
+            peak_memory_usage: self.peak_memory_usage + rhs.peak_memory_usage,
+            wait_time: self.wait_time + rhs.wait_time,
+            output_rows: self.output_rows + rhs.output_rows,
+            output_bytes: self.output_bytes + rhs.output_bytes,
+            spill_bytes: self.spill_bytes + rhs.spill_bytes,
         }
     }
 }
 
+/// Statistics recorded by the processor running on the current thread.
+#[derive(Default, Clone, Copy, Debug)]
+pub struct ProcessorStats {
+    pub output_rows: usize,
+    pub output_bytes: usize,
+    pub spill_bytes: usize,
+}
+
+thread_local! {
+    static PROCESSOR_STATS: Cell<ProcessorStats> = Cell::new(ProcessorStats::default());
+}
+
+impl ProcessorStats {
+    /// Record a data block pushed to an output port.
+    pub fn record_output(rows: usize, bytes: usize) {
+        PROCESSOR_STATS.with(|stats| {
+            let mut v = stats.get();
+            v.output_rows += rows;
+            v.output_bytes += bytes;
+            stats.set(v);
+        })
+    }
+
+    /// Record the bytes spilled to the storage, must be called after the last
+    /// `.await` of the async process, the stats are per thread.
+    pub fn record_spill(bytes: usize) {
+        PROCESSOR_STATS.with(|stats| {
+            let mut v = stats.get();
+            v.spill_bytes += bytes;
+            stats.set(v);
+        })
+    }
+
+    /// Take the stats recorded on the current thread since the last take.
+    pub fn take() -> ProcessorStats {
+        PROCESSOR_STATS.with(|stats| stats.take())
+    }
+}
+
 #[derive(Default)]
 pub struct ProcessorProfiles<K = u32> {
     spans: HashMap<K, ProcessorProfile>,
+    /// Memory accounting nodes shared by the processors of the same key,
+    /// they are children of the memory stat of the query.
+    mem_stats: HashMap<K, Arc<MemStat>>,
 }
 
 impl<K> ProcessorProfiles<K>
@@ -62,11 +126,29 @@ where K: std::hash::Hash + Eq + PartialEq + Clone + Debug
         *entry = *entry + span;
     }
 
+    /// Get the memory accounting node of the key, create it under `parent` if not exists.
+    pub fn mem_stat(&mut self, key: K, parent: Option<Arc<MemStat>>) -> Arc<MemStat> {
+        self.mem_stats
+            .entry(key)
+            .or_insert_with_key(|key| MemStat::create_child(format!("{:?}", key), parent))
+            .clone()
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = (&K, &ProcessorProfile)> {
         self.spans.iter()
     }
 
-    pub fn get(&self, k: &K) -> Option<&ProcessorProfile> {
-        self.spans.get(k)
+    /// Get the profile of the key, the memory usage is read from the accounting node,
+    /// so it's available even if the processors did not finish (e.g. out of memory).
+    pub fn get(&self, k: &K) -> Option<ProcessorProfile> {
+        let span = self.spans.get(k).copied();
+        match self.mem_stats.get(k) {
+            Some(mem_stat) => Some(ProcessorProfile {
+                memory_usage: mem_stat.get_memory_usage(),
+                peak_memory_usage: mem_stat.get_peak_memory_usage(),
+                ..span.unwrap_or_default()
+            }),
+            None => span,
+        }
     }
 }
@@ -46,6 +46,24 @@ pub struct PlanNodeProfile {
 
     /// The time spent to process in nanoseconds
     pub cpu_time: Duration,
+
+    /// Bytes allocated and not yet released by the plan node
+    pub memory_usage: i64,
+
+    /// Peak bytes allocated by the plan node
+    pub peak_memory_usage: i64,
+
+    /// The time spent to wait for async process in nanoseconds
+    pub wait_time: Duration,
+
+    /// Rows produced by the plan node
+    pub output_rows: usize,
+
+    /// Bytes produced by the plan node
+    pub output_bytes: usize,
+
+    /// Bytes spilled to the storage by the plan node
+    pub spill_bytes: usize,
 }
 
 impl PlanNodeProfile {
@@ -55,6 +73,12 @@ impl PlanNodeProfile {
             plan_node_name,
             description,
             cpu_time,
+            memory_usage: 0,
+            peak_memory_usage: 0,
+            wait_time: Duration::default(),
+            output_rows: 0,
+            output_bytes: 0,
+            spill_bytes: 0,
         }
     }
 }
@@ -13,14 +13,20 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_exception::Result;
 use common_meta_types::NodeInfo;
 use poem::http::StatusCode;
 use poem::web::IntoResponse;
 use poem::web::Json;
+use poem::web::Query;
+use serde::Deserialize;
 
+use crate::api::http::v1::instance_status::instance_status;
+use crate::clusters::ClusterDiscovery;
 use crate::clusters::ClusterHelper;
+use crate::clusters::DRAIN_TIMEOUT_SECS;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionType;
 use crate::sessions::TableContext;
@@ -43,6 +49,34 @@ pub async fn cluster_list_handler() -> poem::Result<impl IntoResponse> {
     Ok(Json(nodes))
 }
 
+#[derive(Deserialize, Debug)]
+pub struct DrainQuery {
+    pub timeout_secs: Option<u64>,
+}
+
+// POST /v1/cluster/drain
+// drain current databend-query node before shutdown, new queries are not scheduled on it
+// and it leaves the cluster after the running queries finish.
+// request: `timeout_secs`, how long the running queries may run before they are killed
+// return: the instance status of the node, draining
+#[poem::handler]
+#[async_backtrace::framed]
+pub async fn cluster_drain_handler(
+    Query(query): Query<DrainQuery>,
+) -> poem::Result<impl IntoResponse> {
+    let timeout_secs = query.timeout_secs.unwrap_or(DRAIN_TIMEOUT_SECS);
+    ClusterDiscovery::instance()
+        .drain(Duration::from_secs(timeout_secs))
+        .await
+        .map_err(|cause| {
+            poem::Error::from_string(
+                format!("Failed to drain the node. cause: {cause}"),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            )
+        })?;
+    Ok(Json(instance_status()))
+}
+
 async fn list_nodes(sessions: &Arc<SessionManager>) -> Result<Vec<Arc<NodeInfo>>> {
     let watch_cluster_session = sessions
         .create_session(SessionType::HTTPAPI("WatchCluster".to_string()))
@@ -19,6 +19,7 @@ use poem::IntoResponse;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::clusters::ClusterDiscovery;
 use crate::sessions::SessionManager;
 
 #[derive(Serialize, Deserialize, Eq, PartialEq, Debug)]
@@ -30,6 +31,8 @@ pub struct InstanceStatus {
     pub last_query_finished_at: Option<u64>,
     // secs since epoch
     instance_started_at: u64,
+    // the node is draining before it leaves the cluster
+    pub draining: bool,
 }
 
 fn secs_since_epoch(t: SystemTime) -> u64 {
@@ -43,13 +46,17 @@ fn secs_since_epoch(t: SystemTime) -> u64 {
 #[poem::handler]
 #[async_backtrace::framed]
 pub async fn instance_status_handler() -> poem::Result<impl IntoResponse> {
+    Ok(Json(instance_status()))
+}
+
+pub fn instance_status() -> InstanceStatus {
     let session_manager = SessionManager::instance();
     let status = session_manager.get_current_session_status();
-    let status = InstanceStatus {
+    InstanceStatus {
         running_queries_count: status.running_queries_count,
         last_query_started_at: status.last_query_started_at.map(secs_since_epoch),
         last_query_finished_at: status.last_query_finished_at.map(secs_since_epoch),
         instance_started_at: secs_since_epoch(status.instance_started_at),
-    };
-    Ok(Json(status))
+        draining: ClusterDiscovery::instance().is_draining(),
+    }
 }
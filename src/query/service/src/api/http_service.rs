@@ -30,6 +30,7 @@ use common_meta_types::anyerror::AnyError;
 use poem::get;
 use poem::listener::RustlsCertificate;
 use poem::listener::RustlsConfig;
+use poem::post;
 use poem::Endpoint;
 use poem::Route;
 use tracing::info;
@@ -68,6 +69,10 @@ impl HttpService {
                 "/v1/cluster/list",
                 get(super::http::v1::cluster::cluster_list_handler),
             )
+            .at(
+                "/v1/cluster/drain",
+                post(super::http::v1::cluster::cluster_drain_handler),
+            )
             .at("/debug/home", get(debug_home_handler))
             .at("/debug/pprof/profile", get(debug_pprof_handler))
             .at("/debug/async_tasks/dump", get(debug_dump_stack));
@@ -15,6 +15,7 @@
 // The api module only used for internal communication, such as GRPC between cluster and the managed HTTP REST API.
 
 pub use http_service::HttpService;
+pub(crate) use rpc::get_hash_values;
 pub use rpc::serialize_block;
 pub use rpc::BroadcastExchange;
 pub use rpc::BroadcastFlightScatter;
@@ -76,11 +76,72 @@ impl TryInto<Vec<u8>> for InitNodesChannel {
     }
 }
 
+/// Kill the query or the connection of a session on the node owning it.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct KillSession {
+    pub session_id: String,
+    pub kill_connection: bool,
+}
+
+impl TryInto<KillSession> for Vec<u8> {
+    type Error = Status;
+
+    fn try_into(self) -> Result<KillSession, Self::Error> {
+        match serde_json::from_slice::<KillSession>(&self) {
+            Err(cause) => Err(Status::invalid_argument(cause.to_string())),
+            Ok(action) => Ok(action),
+        }
+    }
+}
+
+impl TryInto<Vec<u8>> for KillSession {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<Vec<u8>, Self::Error> {
+        serde_json::to_vec(&self).map_err_to_code(
+            ErrorCode::Internal,
+            || "Logical error: cannot serialize KillSession.",
+        )
+    }
+}
+
+/// Drain the node before it leaves the cluster.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct DrainNode {
+    pub timeout_secs: u64,
+}
+
+impl TryInto<DrainNode> for Vec<u8> {
+    type Error = Status;
+
+    fn try_into(self) -> Result<DrainNode, Self::Error> {
+        match serde_json::from_slice::<DrainNode>(&self) {
+            Err(cause) => Err(Status::invalid_argument(cause.to_string())),
+            Ok(action) => Ok(action),
+        }
+    }
+}
+
+impl TryInto<Vec<u8>> for DrainNode {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<Vec<u8>, Self::Error> {
+        serde_json::to_vec(&self).map_err_to_code(
+            ErrorCode::Internal,
+            || "Logical error: cannot serialize DrainNode.",
+        )
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum FlightAction {
     InitQueryFragmentsPlan(InitQueryFragmentsPlan),
     InitNodesChannel(InitNodesChannel),
     ExecutePartialQuery(String),
+    /// Get the processes of the node, the body is the id of the node in the cluster.
+    GetProcessesInfo(String),
+    KillSession(KillSession),
+    DrainNode(DrainNode),
 }
 
 impl TryInto<FlightAction> for Action {
@@ -98,6 +159,12 @@ impl TryInto<FlightAction> for Action {
                     buf, length, capacity,
                 )))
             },
+            "GetProcessesInfo" => match String::from_utf8(self.body) {
+                Ok(node) => Ok(FlightAction::GetProcessesInfo(node)),
+                Err(cause) => Err(Status::invalid_argument(cause.to_string())),
+            },
+            "KillSession" => Ok(FlightAction::KillSession(self.body.try_into()?)),
+            "DrainNode" => Ok(FlightAction::DrainNode(self.body.try_into()?)),
             un_implemented => Err(Status::unimplemented(format!(
                 "UnImplement action {}",
                 un_implemented
@@ -123,6 +190,18 @@ impl TryInto<Action> for FlightAction {
                 r#type: String::from("ExecutePartialQuery"),
                 body: query_id.into_bytes(),
             }),
+            FlightAction::GetProcessesInfo(node) => Ok(Action {
+                r#type: String::from("GetProcessesInfo"),
+                body: node.into_bytes(),
+            }),
+            FlightAction::KillSession(kill_session) => Ok(Action {
+                r#type: String::from("KillSession"),
+                body: kill_session.try_into()?,
+            }),
+            FlightAction::DrainNode(drain_node) => Ok(Action {
+                r#type: String::from("DrainNode"),
+                body: drain_node.try_into()?,
+            }),
         }
     }
 }
@@ -24,15 +24,19 @@ use common_arrow::arrow_format::flight::service::flight_service_client::FlightSe
 use common_base::base::tokio::time::Duration;
 use common_base::runtime::GlobalIORuntime;
 use common_base::runtime::TrySpawn;
+use common_catalog::table_context::NodeProcessInfo;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_exception::ToErrorCode;
 use futures::StreamExt;
 use tonic::transport::channel::Channel;
 use tonic::Request;
 use tonic::Status;
 use tonic::Streaming;
 
+use crate::api::rpc::flight_actions::DrainNode;
 use crate::api::rpc::flight_actions::FlightAction;
+use crate::api::rpc::flight_actions::KillSession;
 use crate::api::rpc::packets::DataPacket;
 use crate::api::rpc::request_builder::RequestBuilder;
 
@@ -58,6 +62,50 @@ impl FlightClient {
         Ok(())
     }
 
+    /// Get the processes of the node, listed as the processes of `node` of the cluster.
+    #[async_backtrace::framed]
+    pub async fn get_processes_info(
+        &mut self,
+        node: &str,
+        timeout: u64,
+    ) -> Result<Vec<NodeProcessInfo>> {
+        let action = FlightAction::GetProcessesInfo(node.to_string());
+        match self.do_action(action, timeout).await {
+            Ok(body) => serde_json::from_slice(&body).map_err_to_code(
+                ErrorCode::BadBytes,
+                || "Cannot deserialize the processes info from flight server",
+            ),
+            Err(cause) => Err(cause.add_message_back("(while in query flight)")),
+        }
+    }
+
+    /// Kill the query or the connection of the session if it's on the node, returns
+    /// false if it's not.
+    #[async_backtrace::framed]
+    pub async fn kill_session(
+        &mut self,
+        session_id: &str,
+        kill_connection: bool,
+        timeout: u64,
+    ) -> Result<bool> {
+        let action = FlightAction::KillSession(KillSession {
+            session_id: session_id.to_string(),
+            kill_connection,
+        });
+        match self.do_action(action, timeout).await {
+            Ok(body) => Ok(body.first() == Some(&1)),
+            Err(cause) => Err(cause.add_message_back("(while in query flight)")),
+        }
+    }
+
+    /// Drain the node, its running queries finish up to the timeout before it leaves
+    /// the cluster.
+    #[async_backtrace::framed]
+    pub async fn drain_node(&mut self, timeout_secs: u64, timeout: u64) -> Result<()> {
+        let action = FlightAction::DrainNode(DrainNode { timeout_secs });
+        self.execute_action(action, timeout).await
+    }
+
     #[async_backtrace::framed]
     pub async fn request_server_exchange(
         &mut self,
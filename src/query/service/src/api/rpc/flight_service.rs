@@ -14,6 +14,7 @@
 
 use std::convert::TryInto;
 use std::pin::Pin;
+use std::time::Duration;
 
 use common_arrow::arrow_format::flight::data::Action;
 use common_arrow::arrow_format::flight::data::ActionType;
@@ -42,6 +43,7 @@ use tonic::Streaming;
 use crate::api::rpc::flight_actions::FlightAction;
 use crate::api::rpc::request_builder::RequestGetter;
 use crate::api::DataExchangeManager;
+use crate::clusters::ClusterDiscovery;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionType;
 
@@ -146,6 +148,9 @@ impl FlightService for DatabendQueryFlightService {
     #[async_backtrace::framed]
     async fn do_action(&self, request: Request<Action>) -> Response<Self::DoActionStream> {
         common_tracing::extract_remote_span_as_parent(&request);
+        // the span of the fragments outlives this call, parent it to the remote query directly.
+        let query_span = tracing::info_span!("query_fragments", query_id = tracing::field::Empty);
+        common_tracing::set_remote_span_parent(&query_span, &request);
 
         let action = request.into_inner();
         let flight_action: FlightAction = action.try_into()?;
@@ -170,6 +175,11 @@ impl FlightService for DatabendQueryFlightService {
                 let ctx = session.create_query_context().await?;
                 // Keep query id
                 ctx.set_id(init_query_fragments_plan.executor_packet.query_id.clone());
+                query_span.record(
+                    "query_id",
+                    init_query_fragments_plan.executor_packet.query_id.as_str(),
+                );
+                ctx.set_query_span(query_span);
 
                 let spawner = ctx.clone();
                 let query_id = init_query_fragments_plan.executor_packet.query_id.clone();
@@ -207,6 +217,25 @@ impl FlightService for DatabendQueryFlightService {
 
                 FlightResult { body: vec![] }
             }
+            FlightAction::GetProcessesInfo(node) => {
+                let processes_info = SessionManager::instance().node_processes_info(&node);
+                match serde_json::to_vec(&processes_info) {
+                    Ok(body) => FlightResult { body },
+                    Err(cause) => return Err(Status::internal(cause.to_string())),
+                }
+            }
+            FlightAction::KillSession(kill_session) => {
+                let killed = SessionManager::instance()
+                    .kill_session(&kill_session.session_id, kill_session.kill_connection);
+                FlightResult {
+                    body: vec![killed as u8],
+                }
+            }
+            FlightAction::DrainNode(drain_node) => {
+                let timeout = Duration::from_secs(drain_node.timeout_secs);
+                ClusterDiscovery::instance().drain(timeout).await?;
+                FlightResult { body: vec![] }
+            }
         };
 
         Ok(RawResponse::new(
@@ -43,6 +43,7 @@ pub use exchange::ShuffleExchangeParams;
 pub use exchange::TransformExchangeDeserializer;
 pub use flight_scatter::FlightScatter;
 pub use flight_scatter_broadcast::BroadcastFlightScatter;
+pub(crate) use flight_scatter_hash::get_hash_values;
 pub use flight_scatter_hash::HashFlightScatter;
 pub use packets::ConnectionInfo;
 pub use packets::DataPacket;
@@ -33,6 +33,7 @@ pub struct AuthMgr {
 pub enum Credential {
     Jwt {
         token: String,
+        client_ip: Option<String>,
     },
     Password {
         name: String,
@@ -43,7 +44,7 @@ pub enum Credential {
 
 impl AuthMgr {
     pub fn init(cfg: &InnerConfig) -> Result<()> {
-        GlobalInstance::set(AuthMgr::create(cfg));
+        GlobalInstance::set(AuthMgr::create(cfg)?);
         Ok(())
     }
 
@@ -51,20 +52,37 @@ impl AuthMgr {
         GlobalInstance::get()
     }
 
-    fn create(cfg: &InnerConfig) -> Arc<AuthMgr> {
-        Arc::new(AuthMgr {
-            jwt_auth: JwtAuthenticator::create(
-                cfg.query.jwt_key_file.clone(),
-                cfg.query.jwt_key_files.clone(),
+    fn create(cfg: &InnerConfig) -> Result<Arc<AuthMgr>> {
+        let mut jwt_auth = JwtAuthenticator::create(
+            cfg.query.jwt_key_file.clone(),
+            cfg.query.jwt_key_files.clone(),
+        );
+        let issuer = &cfg.query.jwt_issuer;
+        if !issuer.is_empty() {
+            // the keys are discovered from the issuer if no key file is configured
+            jwt_auth = Some(match jwt_auth {
+                Some(auth) => auth.with_issuer(issuer),
+                None => JwtAuthenticator::create_oidc(issuer),
+            });
+        }
+        let jwt_auth = match jwt_auth {
+            Some(auth) => Some(
+                auth.with_audience(&cfg.query.jwt_audience)?
+                    .with_role_mapping(&cfg.query.jwt_role_claim, &cfg.query.jwt_role_mapping)?,
             ),
-        })
+            None => None,
+        };
+        Ok(Arc::new(AuthMgr { jwt_auth }))
     }
 
     #[async_backtrace::framed]
     pub async fn auth(&self, session: Arc<Session>, credential: &Credential) -> Result<()> {
         let user_api = UserApiProvider::instance();
         match credential {
-            Credential::Jwt { token: t } => {
+            Credential::Jwt {
+                token: t,
+                client_ip,
+            } => {
                 let jwt_auth = self
                     .jwt_auth
                     .as_ref()
@@ -76,16 +94,28 @@ impl AuthMgr {
                     )
                 })?;
 
+                // the tokens of an external issuer can't choose the tenant or the roles,
+                // the roles only come from the role mapping.
+                let trust_claims = !jwt_auth.has_issuer();
+
                 // setup tenant if the JWT claims contain extra.tenant_id
-                if let Some(tenant) = jwt.custom.tenant_id {
+                if let Some(tenant) = jwt.custom.tenant_id.filter(|_| trust_claims) {
                     session.set_current_tenant(tenant);
                 };
 
                 let tenant = session.get_current_tenant();
                 let identity = UserIdentity::new(&user_name, "%");
 
+                // the roles mapped from the claims, the unknown roles are ignored
+                let mut mapped_roles = vec![];
+                for role in jwt_auth.mapped_roles(&jwt.custom) {
+                    if user_api.get_role(&tenant, role.clone()).await.is_ok() {
+                        mapped_roles.push(role);
+                    }
+                }
+
                 // create a new user for this identity if not exists
-                let user = match user_api.get_user(&tenant, identity.clone()).await {
+                let mut user = match user_api.get_user(&tenant, identity).await {
                     Ok(user_info) => match user_info.auth_info {
                         AuthInfo::JWT => user_info,
                         _ => return Err(ErrorCode::AuthenticateFailure("wrong auth type")),
@@ -94,14 +124,14 @@ impl AuthMgr {
                         if e.code() != ErrorCode::UNKNOWN_USER {
                             return Err(ErrorCode::AuthenticateFailure(e.message()));
                         }
-                        let ensure_user = jwt
-                            .custom
-                            .ensure_user
-                            .ok_or(ErrorCode::AuthenticateFailure(e.message()))?;
+                        let ensure_user = match jwt.custom.ensure_user {
+                            Some(ensure_user) => ensure_user,
+                            None => return Err(ErrorCode::AuthenticateFailure(e.message())),
+                        };
                         // create a new user if not exists
                         let mut user_info = UserInfo::new(&user_name, "%", AuthInfo::JWT);
-                        if let Some(ref roles) = ensure_user.roles {
-                            for role in roles.clone().into_iter() {
+                        if let Some(roles) = ensure_user.roles.filter(|_| trust_claims) {
+                            for role in roles.into_iter() {
                                 user_info.grants.grant_role(role);
                             }
                         }
@@ -110,7 +140,20 @@ impl AuthMgr {
                     }
                 };
 
-                session.set_authed_user(user, jwt.custom.role).await?;
+                user_api
+                    .enforce_network_policy(&tenant, &user, client_ip.as_deref())
+                    .await?;
+
+                // the mapped roles are granted to the user in this session only, they follow
+                // the claims of the tokens and are never kept in the user.
+                for role in mapped_roles {
+                    if !user.grants.roles().contains(&role) {
+                        user.grants.grant_role(role);
+                    }
+                }
+
+                let auth_role = jwt.custom.role.filter(|_| trust_claims);
+                session.set_authed_user(user, auth_role).await?;
             }
             Credential::Password {
                 name: n,
@@ -138,6 +181,9 @@ impl AuthMgr {
                     },
                     _ => return Err(ErrorCode::AuthenticateFailure("wrong auth type")),
                 };
+                user_api
+                    .enforce_network_policy(&tenant, &user, h.as_deref())
+                    .await?;
                 session.set_authed_user(user, None).await?;
             }
         };
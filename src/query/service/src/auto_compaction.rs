@@ -0,0 +1,207 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_base::base::tokio::time::sleep;
+use common_base::base::GlobalUniqName;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_catalog::table::CompactTarget;
+use common_catalog::table::Table;
+use common_config::InnerConfig;
+use common_exception::Result;
+use common_storages_fuse::FuseTable;
+use common_users::UserApiProvider;
+use tracing::info;
+use tracing::warn;
+
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::pipelines::Pipeline;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+
+/// Compacts the fuse tables with the `auto_compact` option periodically, without
+/// user-issued `OPTIMIZE TABLE`.
+///
+/// Every query node runs the service, but only the one holding the lease in meta
+/// compacts, which throttles the background workload of the whole cluster: a round
+/// compacts at most one table, at most `segment_limit` segments of it, and the tables
+/// take turns in the order of their ids. A table locked by a mutation is skipped in
+/// the round, the commit of the compaction takes the table lock itself.
+pub struct AutoCompactionService {
+    tenant: String,
+    holder: String,
+    interval: Duration,
+    segment_limit: usize,
+    // the table compacted last, the next round starts from the table after it.
+    last_table_id: u64,
+}
+
+impl AutoCompactionService {
+    pub fn init(config: &InnerConfig) -> Result<()> {
+        // a read-only replica never commits to the tables.
+        if config.query.auto_compaction_interval_secs == 0 || config.query.read_only_replica {
+            return Ok(());
+        }
+
+        let service = AutoCompactionService {
+            tenant: config.query.tenant_id.clone(),
+            holder: GlobalUniqName::unique(),
+            interval: Duration::from_secs(config.query.auto_compaction_interval_secs),
+            segment_limit: std::cmp::max(config.query.auto_compaction_segment_limit, 1) as usize,
+            last_table_id: 0,
+        };
+        GlobalIORuntime::instance().try_spawn(async move { service.run().await })?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn run(mut self) {
+        // the lease is renewed every round, the leader is replaced if it misses two
+        // rounds, e.g. it's down.
+        let lease_ttl = self.interval * 2;
+        let mut leading = false;
+        loop {
+            sleep(self.interval).await;
+
+            match UserApiProvider::instance()
+                .renew_compactor_lease(&self.tenant, &self.holder, lease_ttl)
+                .await
+            {
+                Ok(true) => {
+                    if !leading {
+                        info!("auto compaction: {} takes the lead", self.holder);
+                    }
+                    leading = true;
+                }
+                Ok(false) => {
+                    leading = false;
+                    continue;
+                }
+                Err(cause) => {
+                    warn!("auto compaction: renew lease failed, {}", cause);
+                    continue;
+                }
+            }
+
+            if let Err(cause) = self.compact_round().await {
+                warn!("auto compaction: round failed, {}", cause);
+            }
+        }
+    }
+
+    /// Compact the first table worth compacting, starting from the table after the
+    /// one compacted last. The snapshots of the tables after it are not read.
+    #[async_backtrace::framed]
+    async fn compact_round(&mut self) -> Result<()> {
+        let session = SessionManager::instance()
+            .create_session(SessionType::Dummy)
+            .await?;
+
+        let ctx = session.create_query_context().await?;
+        let catalog = ctx.get_catalog("default")?;
+        let mut tables = vec![];
+        for database in catalog.list_databases(&self.tenant).await? {
+            for table in database.list_tables().await? {
+                let enabled = matches!(
+                    FuseTable::try_from_table(table.as_ref()),
+                    Ok(fuse_table) if fuse_table.auto_compact_enabled()
+                );
+                if enabled {
+                    let table_id = table.get_table_info().ident.table_id;
+                    tables.push((table_id, database.name().to_string(), table));
+                }
+            }
+        }
+        tables.sort_by_key(|(table_id, _, _)| *table_id);
+        let next = tables
+            .iter()
+            .position(|(table_id, _, _)| *table_id > self.last_table_id)
+            .unwrap_or(0);
+        tables.rotate_left(next);
+
+        for (table_id, database, table) in tables {
+            let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+            let target = match fuse_table.auto_compact_target().await? {
+                Some(target) => target,
+                None => continue,
+            };
+
+            // The table being mutated by users, skip it in this round.
+            if !catalog.list_table_lock_revs(table_id).await?.is_empty() {
+                continue;
+            }
+
+            self.last_table_id = table_id;
+            let ctx = session.create_query_context().await?;
+            if let Err(cause) = self.compact_table(ctx, table.clone(), target).await {
+                warn!(
+                    "auto compaction: failed to compact table {}.{}, {}",
+                    database,
+                    table.name(),
+                    cause
+                );
+            }
+            break;
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn compact_table(
+        &self,
+        ctx: Arc<QueryContext>,
+        table: Arc<dyn Table>,
+        target: CompactTarget,
+    ) -> Result<()> {
+        let start = Instant::now();
+        let target_name = match target {
+            CompactTarget::Blocks => "blocks",
+            CompactTarget::Segments => "segments",
+        };
+
+        let mut pipeline = Pipeline::create();
+        table
+            .compact(ctx.clone(), target, Some(self.segment_limit), &mut pipeline)
+            .await?;
+        if pipeline.is_empty() {
+            return Ok(());
+        }
+
+        let settings = ctx.get_settings();
+        pipeline.set_max_threads(settings.get_max_threads()? as usize);
+        let executor_settings = ExecutorSettings::try_create(&settings, ctx.get_id())?;
+        GlobalIORuntime::instance()
+            .spawn_blocking(move || {
+                let executor = PipelineCompleteExecutor::try_create(pipeline, executor_settings)?;
+                ctx.set_executor(executor.get_inner())?;
+                executor.execute()
+            })
+            .await?;
+
+        info!(
+            "auto compaction: compacted {} of table {}, cost:{} sec",
+            target_name,
+            table.name(),
+            start.elapsed().as_secs()
+        );
+        Ok(())
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_base::base::GlobalInstance;
 use common_catalog::catalog::Catalog;
@@ -33,6 +34,7 @@ use common_storages_iceberg::IcebergCatalog;
 use dashmap::DashMap;
 
 use crate::catalogs::DatabaseCatalog;
+use crate::catalogs::ReplicaCatalog;
 
 #[async_trait::async_trait]
 pub trait CatalogManagerHelper {
@@ -77,8 +79,17 @@ impl CatalogManagerHelper for CatalogManager {
 
     #[async_backtrace::framed]
     async fn register_build_in_catalogs(&self, conf: &InnerConfig) -> Result<()> {
-        let default_catalog: Arc<dyn Catalog> =
+        let mut default_catalog: Arc<dyn Catalog> =
             Arc::new(DatabaseCatalog::try_create_with_config(conf.clone()).await?);
+        if conf.query.read_only_replica {
+            let replica_catalog = ReplicaCatalog::create(default_catalog);
+            if conf.query.replica_refresh_interval_secs > 0 {
+                replica_catalog.start_refresh(Duration::from_secs(
+                    conf.query.replica_refresh_interval_secs,
+                ))?;
+            }
+            default_catalog = Arc::new(replica_catalog);
+        }
         self.catalogs
             .insert(CATALOG_DEFAULT.to_owned(), default_catalog);
         Ok(())
@@ -103,7 +114,7 @@ impl CatalogManagerHelper for CatalogManager {
                     #[cfg(feature = "hive")]
                     {
                         let hms_address = ctl.address.clone();
-                        let hive_catalog = Arc::new(HiveCatalog::try_create(hms_address)?);
+                        let hive_catalog = Arc::new(HiveCatalog::try_create(name, hms_address)?);
                         self.catalogs.insert(name.to_string(), hive_catalog);
                     }
                 }
@@ -131,8 +142,9 @@ impl CatalogManagerHelper for CatalogManager {
                 }
                 #[cfg(feature = "hive")]
                 {
-                    let catalog: Arc<dyn Catalog> = Arc::new(HiveCatalog::try_create(address)?);
                     let ctl_name = &req.name_ident.catalog_name;
+                    let catalog: Arc<dyn Catalog> =
+                        Arc::new(HiveCatalog::try_create(ctl_name, address)?);
                     let if_not_exists = req.if_not_exists;
 
                     self.insert_catalog(ctl_name, catalog, if_not_exists)
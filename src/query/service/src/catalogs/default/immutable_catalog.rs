@@ -41,6 +41,8 @@ use common_meta_app::schema::GetTableCopiedFileReply;
 use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::IndexMeta;
 use common_meta_app::schema::ListIndexesReq;
+use common_meta_app::schema::ListTableCopiedFileReply;
+use common_meta_app::schema::ListTableCopiedFileReq;
 use common_meta_app::schema::ListVirtualColumnsReq;
 use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
@@ -55,6 +57,8 @@ use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpdateVirtualColumnReply;
@@ -240,6 +244,19 @@ impl Catalog for ImmutableCatalog {
         )))
     }
 
+    #[async_backtrace::framed]
+    async fn list_table_copied_file_info(
+        &self,
+        _tenant: &str,
+        _db_name: &str,
+        req: ListTableCopiedFileReq,
+    ) -> Result<ListTableCopiedFileReply> {
+        Err(ErrorCode::Unimplemented(format!(
+            "list_table_copied_file_info not allowed for system database {:?}",
+            req
+        )))
+    }
+
     #[async_backtrace::framed]
     async fn truncate_table(
         &self,
@@ -277,6 +294,17 @@ impl Catalog for ImmutableCatalog {
         )))
     }
 
+    #[async_backtrace::framed]
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply> {
+        Err(ErrorCode::Unimplemented(format!(
+            "update table meta not allowed for system database {:?}",
+            req
+        )))
+    }
+
     #[async_backtrace::framed]
     async fn list_table_lock_revs(&self, _table_id: u64) -> Result<Vec<u64>> {
         Err(ErrorCode::Unimplemented(
@@ -16,6 +16,7 @@ mod catalog_context;
 mod database_catalog;
 mod immutable_catalog;
 mod mutable_catalog;
+mod replica_catalog;
 pub mod table_id_ranges;
 pub mod table_memory_meta;
 
@@ -23,3 +24,4 @@ pub use database_catalog::DatabaseCatalog;
 // for "unit" test
 pub use immutable_catalog::ImmutableCatalog;
 pub use mutable_catalog::MutableCatalog;
+pub use replica_catalog::ReplicaCatalog;
@@ -52,6 +52,8 @@ use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::IndexMeta;
 use common_meta_app::schema::ListDatabaseReq;
 use common_meta_app::schema::ListIndexesReq;
+use common_meta_app::schema::ListTableCopiedFileReply;
+use common_meta_app::schema::ListTableCopiedFileReq;
 use common_meta_app::schema::ListTableLockRevReq;
 use common_meta_app::schema::ListVirtualColumnsReq;
 use common_meta_app::schema::RenameDatabaseReply;
@@ -67,6 +69,8 @@ use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpdateVirtualColumnReply;
@@ -391,6 +395,21 @@ impl Catalog for MutableCatalog {
         }
     }
 
+    #[async_backtrace::framed]
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply> {
+        info!(
+            "updating multi table meta. table ids: {:?}",
+            req.update_table_metas
+                .iter()
+                .map(|req| req.table_id)
+                .collect::<Vec<_>>()
+        );
+        Ok(self.ctx.meta.update_multi_table_meta(req).await?)
+    }
+
     #[async_backtrace::framed]
     async fn get_table_copied_file_info(
         &self,
@@ -402,6 +421,17 @@ impl Catalog for MutableCatalog {
         db.get_table_copied_file_info(req).await
     }
 
+    #[async_backtrace::framed]
+    async fn list_table_copied_file_info(
+        &self,
+        tenant: &str,
+        db_name: &str,
+        req: ListTableCopiedFileReq,
+    ) -> Result<ListTableCopiedFileReply> {
+        let db = self.get_database(tenant, db_name).await?;
+        db.list_table_copied_file_info(req).await
+    }
+
     #[async_backtrace::framed]
     async fn truncate_table(
         &self,
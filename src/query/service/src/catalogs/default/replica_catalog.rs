@@ -0,0 +1,429 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::base::tokio::time::sleep;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_catalog::table_args::TableArgs;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::CountTablesReply;
+use common_meta_app::schema::CountTablesReq;
+use common_meta_app::schema::CreateDatabaseReply;
+use common_meta_app::schema::CreateDatabaseReq;
+use common_meta_app::schema::CreateIndexReply;
+use common_meta_app::schema::CreateIndexReq;
+use common_meta_app::schema::CreateTableLockRevReply;
+use common_meta_app::schema::CreateTableReply;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::CreateVirtualColumnReply;
+use common_meta_app::schema::CreateVirtualColumnReq;
+use common_meta_app::schema::DropDatabaseReply;
+use common_meta_app::schema::DropDatabaseReq;
+use common_meta_app::schema::DropIndexReply;
+use common_meta_app::schema::DropIndexReq;
+use common_meta_app::schema::DropTableByIdReq;
+use common_meta_app::schema::DropTableReply;
+use common_meta_app::schema::DropVirtualColumnReply;
+use common_meta_app::schema::DropVirtualColumnReq;
+use common_meta_app::schema::GetTableCopiedFileReply;
+use common_meta_app::schema::GetTableCopiedFileReq;
+use common_meta_app::schema::IndexMeta;
+use common_meta_app::schema::ListIndexesReq;
+use common_meta_app::schema::ListTableCopiedFileReply;
+use common_meta_app::schema::ListTableCopiedFileReq;
+use common_meta_app::schema::ListVirtualColumnsReq;
+use common_meta_app::schema::RenameDatabaseReply;
+use common_meta_app::schema::RenameDatabaseReq;
+use common_meta_app::schema::RenameTableReply;
+use common_meta_app::schema::RenameTableReq;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TruncateTableReply;
+use common_meta_app::schema::TruncateTableReq;
+use common_meta_app::schema::UndropDatabaseReply;
+use common_meta_app::schema::UndropDatabaseReq;
+use common_meta_app::schema::UndropTableReply;
+use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
+use common_meta_app::schema::UpdateTableMetaReply;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_app::schema::UpdateVirtualColumnReply;
+use common_meta_app::schema::UpdateVirtualColumnReq;
+use common_meta_app::schema::UpsertTableOptionReply;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_app::schema::VirtualColumnMeta;
+use common_meta_types::MetaId;
+use parking_lot::RwLock;
+use tracing::info;
+use tracing::warn;
+
+use crate::catalogs::catalog::Catalog;
+use crate::databases::Database;
+use crate::storages::StorageDescription;
+use crate::storages::Table;
+use crate::table_functions::TableFunction;
+
+/// (tenant, database, table)
+type PinnedTableKey = (String, String, String);
+
+/// The catalog of a read-only replica.
+/// - the fuse tables are pinned to the snapshots they have when first read, until the
+///   next refresh, which re-pins all the pinned tables together
+/// - every operation writing to meta is rejected
+#[derive(Clone)]
+pub struct ReplicaCatalog {
+    inner: Arc<dyn Catalog>,
+    pinned: Arc<RwLock<HashMap<PinnedTableKey, Arc<dyn Table>>>>,
+}
+
+impl ReplicaCatalog {
+    pub fn create(inner: Arc<dyn Catalog>) -> Self {
+        Self {
+            inner,
+            pinned: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Refreshes the pinned tables every `interval` in the background.
+    pub fn start_refresh(&self, interval: Duration) -> Result<()> {
+        let catalog = self.clone();
+        GlobalIORuntime::instance().try_spawn(async move {
+            loop {
+                sleep(interval).await;
+
+                if let Err(cause) = catalog.refresh().await {
+                    warn!("replica: failed to refresh the pinned tables, {}", cause);
+                }
+            }
+        })?;
+        Ok(())
+    }
+
+    /// Re-pins the pinned tables to their latest snapshots, the dropped ones are unpinned.
+    ///
+    /// The new set replaces the old one at once, a query never reads some tables of the old
+    /// set and others of the new one.
+    #[async_backtrace::framed]
+    pub async fn refresh(&self) -> Result<()> {
+        let keys: Vec<PinnedTableKey> = self.pinned.read().keys().cloned().collect();
+        let mut tables = HashMap::with_capacity(keys.len());
+        for key in keys {
+            match self.inner.get_table(&key.0, &key.1, &key.2).await {
+                Ok(table) => {
+                    tables.insert(key, table);
+                }
+                Err(e)
+                    if e.code() == ErrorCode::UNKNOWN_TABLE
+                        || e.code() == ErrorCode::UNKNOWN_DATABASE =>
+                {
+                    continue;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        info!("replica: refreshed {} pinned tables", tables.len());
+        *self.pinned.write() = tables;
+        Ok(())
+    }
+
+    fn denied<T>(operation: &str) -> Result<T> {
+        Err(ErrorCode::ReadOnlyReplicaPermissionDenied(format!(
+            "Access denied for operation:{} in read-only replica",
+            operation
+        )))
+    }
+}
+
+#[async_trait::async_trait]
+impl Catalog for ReplicaCatalog {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    #[async_backtrace::framed]
+    async fn get_database(&self, tenant: &str, db_name: &str) -> Result<Arc<dyn Database>> {
+        self.inner.get_database(tenant, db_name).await
+    }
+
+    #[async_backtrace::framed]
+    async fn list_databases(&self, tenant: &str) -> Result<Vec<Arc<dyn Database>>> {
+        self.inner.list_databases(tenant).await
+    }
+
+    #[async_backtrace::framed]
+    async fn create_database(&self, _req: CreateDatabaseReq) -> Result<CreateDatabaseReply> {
+        Self::denied("create_database")
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_database(&self, _req: DropDatabaseReq) -> Result<DropDatabaseReply> {
+        Self::denied("drop_database")
+    }
+
+    #[async_backtrace::framed]
+    async fn undrop_database(&self, _req: UndropDatabaseReq) -> Result<UndropDatabaseReply> {
+        Self::denied("undrop_database")
+    }
+
+    #[async_backtrace::framed]
+    async fn create_index(&self, _req: CreateIndexReq) -> Result<CreateIndexReply> {
+        Self::denied("create_index")
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_index(&self, _req: DropIndexReq) -> Result<DropIndexReply> {
+        Self::denied("drop_index")
+    }
+
+    #[async_backtrace::framed]
+    async fn list_indexes(&self, req: ListIndexesReq) -> Result<Vec<(u64, String, IndexMeta)>> {
+        self.inner.list_indexes(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn create_virtual_column(
+        &self,
+        _req: CreateVirtualColumnReq,
+    ) -> Result<CreateVirtualColumnReply> {
+        Self::denied("create_virtual_column")
+    }
+
+    #[async_backtrace::framed]
+    async fn update_virtual_column(
+        &self,
+        _req: UpdateVirtualColumnReq,
+    ) -> Result<UpdateVirtualColumnReply> {
+        Self::denied("update_virtual_column")
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_virtual_column(
+        &self,
+        _req: DropVirtualColumnReq,
+    ) -> Result<DropVirtualColumnReply> {
+        Self::denied("drop_virtual_column")
+    }
+
+    #[async_backtrace::framed]
+    async fn list_virtual_columns(
+        &self,
+        req: ListVirtualColumnsReq,
+    ) -> Result<Vec<VirtualColumnMeta>> {
+        self.inner.list_virtual_columns(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn rename_database(&self, _req: RenameDatabaseReq) -> Result<RenameDatabaseReply> {
+        Self::denied("rename_database")
+    }
+
+    fn get_table_by_info(&self, table_info: &TableInfo) -> Result<Arc<dyn Table>> {
+        self.inner.get_table_by_info(table_info)
+    }
+
+    #[async_backtrace::framed]
+    async fn get_table_meta_by_id(&self, table_id: MetaId) -> Result<(TableIdent, Arc<TableMeta>)> {
+        self.inner.get_table_meta_by_id(table_id).await
+    }
+
+    #[async_backtrace::framed]
+    async fn get_table(
+        &self,
+        tenant: &str,
+        db_name: &str,
+        table_name: &str,
+    ) -> Result<Arc<dyn Table>> {
+        let key = (
+            tenant.to_string(),
+            db_name.to_string(),
+            table_name.to_string(),
+        );
+        if let Some(table) = self.pinned.read().get(&key) {
+            return Ok(table.clone());
+        }
+
+        let table = self.inner.get_table(tenant, db_name, table_name).await?;
+        // Only the fuse tables have snapshots to pin, the others, such as the system
+        // tables, are always read as they are.
+        if table.engine() != "FUSE" {
+            return Ok(table);
+        }
+        Ok(self.pinned.write().entry(key).or_insert(table).clone())
+    }
+
+    #[async_backtrace::framed]
+    async fn list_tables(&self, tenant: &str, db_name: &str) -> Result<Vec<Arc<dyn Table>>> {
+        let tables = self.inner.list_tables(tenant, db_name).await?;
+        let pinned = self.pinned.read();
+        Ok(tables
+            .into_iter()
+            .map(|table| {
+                let key = (
+                    tenant.to_string(),
+                    db_name.to_string(),
+                    table.name().to_string(),
+                );
+                pinned.get(&key).cloned().unwrap_or(table)
+            })
+            .collect())
+    }
+
+    #[async_backtrace::framed]
+    async fn list_tables_history(
+        &self,
+        tenant: &str,
+        db_name: &str,
+    ) -> Result<Vec<Arc<dyn Table>>> {
+        self.inner.list_tables_history(tenant, db_name).await
+    }
+
+    #[async_backtrace::framed]
+    async fn create_table(&self, _req: CreateTableReq) -> Result<CreateTableReply> {
+        Self::denied("create_table")
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_table_by_id(&self, _req: DropTableByIdReq) -> Result<DropTableReply> {
+        Self::denied("drop_table")
+    }
+
+    #[async_backtrace::framed]
+    async fn undrop_table(&self, _req: UndropTableReq) -> Result<UndropTableReply> {
+        Self::denied("undrop_table")
+    }
+
+    #[async_backtrace::framed]
+    async fn rename_table(&self, _req: RenameTableReq) -> Result<RenameTableReply> {
+        Self::denied("rename_table")
+    }
+
+    #[async_backtrace::framed]
+    async fn upsert_table_option(
+        &self,
+        _tenant: &str,
+        _db_name: &str,
+        _req: UpsertTableOptionReq,
+    ) -> Result<UpsertTableOptionReply> {
+        Self::denied("upsert_table_option")
+    }
+
+    #[async_backtrace::framed]
+    async fn update_table_meta(
+        &self,
+        _table_info: &TableInfo,
+        _req: UpdateTableMetaReq,
+    ) -> Result<UpdateTableMetaReply> {
+        Self::denied("update_table_meta")
+    }
+
+    #[async_backtrace::framed]
+    async fn update_multi_table_meta(
+        &self,
+        _req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply> {
+        Self::denied("update_multi_table_meta")
+    }
+
+    #[async_backtrace::framed]
+    async fn count_tables(&self, req: CountTablesReq) -> Result<CountTablesReply> {
+        self.inner.count_tables(req).await
+    }
+
+    #[async_backtrace::framed]
+    async fn get_table_copied_file_info(
+        &self,
+        tenant: &str,
+        db_name: &str,
+        req: GetTableCopiedFileReq,
+    ) -> Result<GetTableCopiedFileReply> {
+        self.inner
+            .get_table_copied_file_info(tenant, db_name, req)
+            .await
+    }
+
+    #[async_backtrace::framed]
+    async fn list_table_copied_file_info(
+        &self,
+        tenant: &str,
+        db_name: &str,
+        req: ListTableCopiedFileReq,
+    ) -> Result<ListTableCopiedFileReply> {
+        self.inner
+            .list_table_copied_file_info(tenant, db_name, req)
+            .await
+    }
+
+    #[async_backtrace::framed]
+    async fn truncate_table(
+        &self,
+        _table_info: &TableInfo,
+        _req: TruncateTableReq,
+    ) -> Result<TruncateTableReply> {
+        Self::denied("truncate_table")
+    }
+
+    #[async_backtrace::framed]
+    async fn list_table_lock_revs(&self, table_id: u64) -> Result<Vec<u64>> {
+        self.inner.list_table_lock_revs(table_id).await
+    }
+
+    #[async_backtrace::framed]
+    async fn create_table_lock_rev(
+        &self,
+        _expire_secs: u64,
+        _table_info: &TableInfo,
+    ) -> Result<CreateTableLockRevReply> {
+        Self::denied("create_table_lock_rev")
+    }
+
+    #[async_backtrace::framed]
+    async fn extend_table_lock_rev(
+        &self,
+        _expire_secs: u64,
+        _table_info: &TableInfo,
+        _revision: u64,
+    ) -> Result<()> {
+        Self::denied("extend_table_lock_rev")
+    }
+
+    #[async_backtrace::framed]
+    async fn delete_table_lock_rev(&self, _table_info: &TableInfo, _revision: u64) -> Result<()> {
+        Self::denied("delete_table_lock_rev")
+    }
+
+    fn get_table_function(
+        &self,
+        func_name: &str,
+        tbl_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        self.inner.get_table_function(func_name, tbl_args)
+    }
+
+    fn list_table_functions(&self) -> Vec<String> {
+        self.inner.list_table_functions()
+    }
+
+    fn get_table_engines(&self) -> Vec<StorageDescription> {
+        self.inner.get_table_engines()
+    }
+}
@@ -0,0 +1,354 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_channel::Receiver;
+use async_channel::Sender;
+use chrono::Utc;
+use common_base::base::tokio::time::sleep;
+use common_base::base::GlobalInstance;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_config::InnerConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::NotificationTarget;
+use common_storages_fuse::operations::set_table_change_notifier;
+use common_storages_fuse::operations::TableChangeEvent;
+use common_storages_fuse::operations::TableChangeNotifier;
+use common_storages_system::NotificationHistoryLogElement;
+use common_storages_system::NotificationHistoryQueue;
+use common_users::UserApiProvider;
+use hmac::Hmac;
+use hmac::Mac;
+use reqwest::header::AUTHORIZATION;
+use reqwest::header::CONTENT_TYPE;
+use reqwest::RequestBuilder;
+use serde_json::json;
+use sha2::Digest;
+use sha2::Sha256;
+use tracing::warn;
+
+/// The notifications are dropped if the targets can't keep up with the commits.
+const MAX_PENDING_NOTIFICATIONS: usize = 10000;
+const DELIVERY_ATTEMPTS: u64 = 3;
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+const RETRY_BACKOFF: Duration = Duration::from_secs(1);
+
+const KAFKA_CONTENT_TYPE: &str = "application/vnd.kafka.json.v2+json";
+const SQS_CONTENT_TYPE: &str = "application/x-www-form-urlencoded";
+
+/// Delivers the notifications of the snapshots committed on the tables with the
+/// `notification_integration` option, each one is logged to `system.notification_history`.
+///
+/// The commits never wait for the delivery, the notifications are queued in memory and
+/// sent one by one in the order of the commits. A notification is lost if the queue is
+/// full, the node restarts, or it still fails after the retries.
+pub struct ChangeNotificationService {
+    sender: Sender<TableChangeEvent>,
+    client: reqwest::Client,
+}
+
+impl ChangeNotificationService {
+    pub fn init(_config: &InnerConfig) -> Result<()> {
+        let client = reqwest::Client::builder()
+            .timeout(DELIVERY_TIMEOUT)
+            .build()
+            .map_err(|e| ErrorCode::NotificationDeliveryFailure(e.to_string()))?;
+        let (sender, receiver) = async_channel::bounded(MAX_PENDING_NOTIFICATIONS);
+        let service = Arc::new(ChangeNotificationService { sender, client });
+        GlobalInstance::set(service.clone());
+        set_table_change_notifier(Arc::new(ChangeNotifier));
+
+        GlobalIORuntime::instance().try_spawn(async move { service.run(receiver).await })?;
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<ChangeNotificationService> {
+        GlobalInstance::get()
+    }
+
+    #[async_backtrace::framed]
+    async fn run(self: Arc<Self>, receiver: Receiver<TableChangeEvent>) {
+        while let Ok(event) = receiver.recv().await {
+            let (attempts, delivered) = self.deliver(&event).await;
+            let (status, error_message) = match delivered {
+                Ok(_) => ("delivered", String::new()),
+                Err(cause) => {
+                    warn!(
+                        "change notification: failed to notify {} of {}.{}, {}",
+                        event.integration, event.database, event.table, cause
+                    );
+                    ("failed", cause.message())
+                }
+            };
+
+            let element = NotificationHistoryLogElement {
+                event_time: Utc::now().timestamp_micros(),
+                integration: event.integration.clone(),
+                database: event.database.clone(),
+                table: event.table.clone(),
+                snapshot_id: event.snapshot_id.clone(),
+                row_delta: event.row_delta(),
+                attempts,
+                status: status.to_string(),
+                error_message,
+            };
+            if let Err(cause) =
+                NotificationHistoryQueue::instance().and_then(|queue| queue.append_data(element))
+            {
+                warn!(
+                    "change notification: failed to log the notification, {}",
+                    cause
+                );
+            }
+        }
+    }
+
+    /// Returns the attempts and the result of the last one, only the failures of the
+    /// targets are retried.
+    #[async_backtrace::framed]
+    async fn deliver(&self, event: &TableChangeEvent) -> (u64, Result<()>) {
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            let res = self.try_deliver(event).await;
+            let retry = match &res {
+                Ok(_) => false,
+                Err(cause) => cause.code() == ErrorCode::NOTIFICATION_DELIVERY_FAILURE,
+            };
+            if !retry || attempts >= DELIVERY_ATTEMPTS {
+                return (attempts, res);
+            }
+            sleep(RETRY_BACKOFF * attempts as u32).await;
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn try_deliver(&self, event: &TableChangeEvent) -> Result<()> {
+        let integration = UserApiProvider::instance()
+            .get_notification_integration(&event.tenant, &event.integration)
+            .await?;
+
+        let payload = notification_payload(event);
+        let endpoint = integration.target.endpoint();
+        let request = match &integration.target {
+            NotificationTarget::Webhook {
+                url,
+                method,
+                authorization_header,
+            } => {
+                let request = match method.as_str() {
+                    "PUT" => self.client.put(url),
+                    _ => self.client.post(url),
+                };
+                with_authorization(request.json(&payload), authorization_header)
+            }
+            NotificationTarget::Kafka {
+                authorization_header,
+                ..
+            } => {
+                // the records of a table go to the same partition, to keep them in order.
+                let records = json!({
+                    "records": [{
+                        "key": format!("{}.{}", event.database, event.table),
+                        "value": payload,
+                    }]
+                });
+                let request = self
+                    .client
+                    .post(&endpoint)
+                    .header(CONTENT_TYPE, KAFKA_CONTENT_TYPE)
+                    .body(records.to_string());
+                with_authorization(request, authorization_header)
+            }
+            NotificationTarget::Sqs {
+                queue_url,
+                region,
+                access_key_id,
+                secret_access_key,
+            } => sqs_send_message(
+                &self.client,
+                queue_url,
+                region,
+                access_key_id,
+                secret_access_key,
+                &payload.to_string(),
+            )?,
+        };
+
+        let response = request.send().await.map_err(|e| {
+            ErrorCode::NotificationDeliveryFailure(format!("failed to send to {}, {}", endpoint, e))
+        })?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(ErrorCode::NotificationDeliveryFailure(format!(
+                "{} responded {}, {}",
+                endpoint, status, body
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Registered to the fuse tables, hands the events over to the service.
+struct ChangeNotifier;
+
+impl TableChangeNotifier for ChangeNotifier {
+    fn notify(&self, event: TableChangeEvent) {
+        let service = ChangeNotificationService::instance();
+        if let Err(cause) = service.sender.try_send(event) {
+            warn!("change notification: notification dropped, {}", cause);
+        }
+    }
+}
+
+fn notification_payload(event: &TableChangeEvent) -> serde_json::Value {
+    json!({
+        "integration": event.integration,
+        "database": event.database,
+        "table": event.table,
+        "table_id": event.table_id,
+        "snapshot_id": event.snapshot_id,
+        "previous_snapshot_id": event.previous_snapshot_id,
+        "row_count": event.row_count,
+        "previous_row_count": event.previous_row_count,
+        "row_delta": event.row_delta(),
+        "committed_at": event.committed_at.to_rfc3339(),
+    })
+}
+
+fn with_authorization(request: RequestBuilder, authorization_header: &str) -> RequestBuilder {
+    match authorization_header.is_empty() {
+        true => request,
+        false => request.header(AUTHORIZATION, authorization_header),
+    }
+}
+
+/// Builds the `SendMessage` request of the SQS query API.
+fn sqs_send_message(
+    client: &reqwest::Client,
+    queue_url: &str,
+    region: &str,
+    access_key_id: &str,
+    secret_access_key: &str,
+    message: &str,
+) -> Result<RequestBuilder> {
+    let body = serde_urlencoded::to_string([
+        ("Action", "SendMessage"),
+        ("MessageBody", message),
+        ("Version", "2012-11-05"),
+    ])
+    .map_err(|e| ErrorCode::NotificationDeliveryFailure(e.to_string()))?;
+
+    sqs_signed_request(
+        client,
+        queue_url,
+        region,
+        access_key_id,
+        secret_access_key,
+        &[("content-type", SQS_CONTENT_TYPE)],
+        body,
+    )
+    .map_err(|e| ErrorCode::NotificationDeliveryFailure(e.message()))
+}
+
+/// Builds a `POST` request to SQS signed by AWS Signature Version 4, the `headers`
+/// are signed along with the host and the date.
+pub(crate) fn sqs_signed_request(
+    client: &reqwest::Client,
+    url: &str,
+    region: &str,
+    access_key_id: &str,
+    secret_access_key: &str,
+    headers: &[(&str, &str)],
+    body: String,
+) -> Result<RequestBuilder> {
+    let invalid =
+        |reason: String| ErrorCode::BadArguments(format!("invalid url {}, {}", url, reason));
+    let url = reqwest::Url::parse(url).map_err(|e| invalid(e.to_string()))?;
+    let host = match (url.host_str(), url.port()) {
+        (Some(host), Some(port)) => format!("{}:{}", host, port),
+        (Some(host), None) => host.to_string(),
+        _ => return Err(invalid("missing host".to_string())),
+    };
+
+    let now = Utc::now();
+    let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+    let date = now.format("%Y%m%d").to_string();
+    let mut signed = headers
+        .iter()
+        .map(|(name, value)| (name.to_lowercase(), value.to_string()))
+        .collect::<Vec<_>>();
+    signed.push(("host".to_string(), host));
+    signed.push(("x-amz-date".to_string(), amz_date.clone()));
+    signed.sort();
+    let canonical_headers = signed
+        .iter()
+        .map(|(name, value)| format!("{}:{}\n", name, value))
+        .collect::<String>();
+    let signed_headers = signed
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<_>>()
+        .join(";");
+    let canonical_request = format!(
+        "POST\n{}\n\n{}\n{}\n{}",
+        url.path(),
+        canonical_headers,
+        signed_headers,
+        to_hex(&Sha256::digest(body.as_bytes()))
+    );
+    let scope = format!("{}/{}/sqs/aws4_request", date, region);
+    let string_to_sign = format!(
+        "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+        amz_date,
+        scope,
+        to_hex(&Sha256::digest(canonical_request.as_bytes()))
+    );
+
+    let mut key = hmac_sha256(format!("AWS4{}", secret_access_key).as_bytes(), &date);
+    for part in [region, "sqs", "aws4_request"] {
+        key = hmac_sha256(&key, part);
+    }
+    let authorization = format!(
+        "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+        access_key_id,
+        scope,
+        signed_headers,
+        to_hex(&hmac_sha256(&key, &string_to_sign))
+    );
+
+    let mut request = client.post(url);
+    for (name, value) in headers {
+        request = request.header(*name, *value);
+    }
+    Ok(request
+        .header("x-amz-date", amz_date)
+        .header(AUTHORIZATION, authorization)
+        .body(body))
+}
+
+fn hmac_sha256(key: &[u8], data: &str) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC can take key of any size");
+    mac.update(data.as_bytes());
+    mac.finalize().into_bytes().to_vec()
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
@@ -19,6 +19,7 @@ use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::time::Duration;
+use std::time::Instant;
 
 use common_arrow::arrow_format::flight::service::flight_service_client::FlightServiceClient;
 use common_base::base::tokio;
@@ -31,6 +32,8 @@ use common_base::base::GlobalInstance;
 use common_base::base::GlobalUniqName;
 use common_base::base::SignalStream;
 use common_base::base::SignalType;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
 pub use common_catalog::cluster_info::Cluster;
 use common_config::InnerConfig;
 use common_config::DATABEND_COMMIT_VERSION;
@@ -52,9 +55,15 @@ use metrics::gauge;
 use rand::thread_rng;
 use rand::Rng;
 use tracing::error;
+use tracing::info;
 use tracing::warn;
 
 use crate::api::FlightClient;
+use crate::sessions::SessionManager;
+
+/// How long the running queries of a node draining may run by default, before they
+/// are killed and the node leaves the cluster.
+pub const DRAIN_TIMEOUT_SECS: u64 = 300;
 
 pub struct ClusterDiscovery {
     local_id: String,
@@ -63,6 +72,7 @@ pub struct ClusterDiscovery {
     cluster_id: String,
     tenant_id: String,
     flight_address: String,
+    draining: AtomicBool,
 }
 
 // avoid leak FlightClient to common-xxx
@@ -179,6 +189,7 @@ impl ClusterDiscovery {
             cluster_id: cfg.query.cluster_id.clone(),
             tenant_id: cfg.query.tenant_id.clone(),
             flight_address: cfg.query.flight_api_address.clone(),
+            draining: AtomicBool::new(false),
         }))
     }
 
@@ -234,6 +245,11 @@ impl ClusterDiscovery {
             Ok(cluster_nodes) => {
                 let mut res = Vec::with_capacity(cluster_nodes.len());
                 for node in &cluster_nodes {
+                    // No new queries are scheduled on the nodes draining.
+                    if node.draining && node.id != self.local_id {
+                        continue;
+                    }
+
                     if node.id != self.local_id {
                         if let Err(cause) = create_client(config, &node.flight_address).await {
                             warn!(
@@ -399,6 +415,90 @@ impl ClusterDiscovery {
         }
     }
 
+    pub fn is_draining(&self) -> bool {
+        self.draining.load(Ordering::Relaxed)
+    }
+
+    /// Drain the node before it leaves the cluster: the node is marked as draining in the
+    /// cluster so the other nodes don't schedule new queries on it, then the queries
+    /// running on it finish in the background, up to the timeout, before they are killed
+    /// and the node is dropped from the cluster.
+    #[async_backtrace::framed]
+    pub async fn drain(self: &Arc<Self>, timeout: Duration) -> Result<()> {
+        if self
+            .draining
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::Acquire)
+            .is_err()
+        {
+            return Ok(());
+        }
+
+        let mut node_info = match self.get_local_node().await {
+            Ok(node_info) => node_info,
+            Err(cause) => {
+                self.draining.store(false, Ordering::SeqCst);
+                return Err(cause);
+            }
+        };
+        node_info.draining = true;
+
+        // The heartbeat keeps the node alive as is, draining.
+        let update_node = self.api_provider.update_node(node_info, MatchSeq::GE(1));
+        if let Err(cause) = update_node.await {
+            self.draining.store(false, Ordering::SeqCst);
+            return Err(cause.add_message_back("(while cluster api update_node)."));
+        }
+
+        info!(
+            "Draining node {}, waiting up to {:?} for the running queries",
+            self.local_id, timeout
+        );
+        GlobalIORuntime::instance()
+            .spawn(async_backtrace::location!().frame(self.clone().leave_after_queries(timeout)));
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn leave_after_queries(self: Arc<Self>, timeout: Duration) {
+        let session_manager = SessionManager::instance();
+        let started_at = Instant::now();
+        while session_manager.running_queries_count() > 0 {
+            if started_at.elapsed() >= timeout {
+                warn!(
+                    "Draining node {} timed out, kill the running queries",
+                    self.local_id
+                );
+                session_manager.kill_running_queries();
+                break;
+            }
+            tokio_async_sleep(Duration::from_millis(500)).await;
+        }
+
+        let mut heartbeat = self.heartbeat.lock().await;
+        if let Err(cause) = heartbeat.shutdown().await {
+            warn!("Cannot shutdown cluster heartbeat, cause {:?}", cause);
+        }
+        let drop_node = self
+            .api_provider
+            .drop_node(self.local_id.clone(), MatchSeq::GE(1));
+        match drop_node.await {
+            Ok(_) => info!("Drained node {} left the cluster", self.local_id),
+            Err(cause) => warn!("Cannot drop drained node, cause {:?}", cause),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn get_local_node(&self) -> Result<NodeInfo> {
+        let nodes = self.api_provider.get_nodes().await?;
+        match nodes.into_iter().find(|node| node.id == self.local_id) {
+            Some(node) => Ok(node),
+            None => Err(ErrorCode::ClusterUnknownNode(format!(
+                "The node \"{}\" not found in the cluster",
+                self.local_id
+            ))),
+        }
+    }
+
     #[async_backtrace::framed]
     async fn start_heartbeat(self: &Arc<Self>, node_info: NodeInfo) -> Result<()> {
         let mut heartbeat = self.heartbeat.lock().await;
@@ -18,3 +18,4 @@ mod metrics;
 pub use cluster::Cluster;
 pub use cluster::ClusterDiscovery;
 pub use cluster::ClusterHelper;
+pub use cluster::DRAIN_TIMEOUT_SECS;
@@ -25,6 +25,8 @@ use common_meta_app::schema::DropTableReply;
 use common_meta_app::schema::GetTableCopiedFileReply;
 use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::GetTableReq;
+use common_meta_app::schema::ListTableCopiedFileReply;
+use common_meta_app::schema::ListTableCopiedFileReq;
 use common_meta_app::schema::ListTableReq;
 use common_meta_app::schema::RenameTableReply;
 use common_meta_app::schema::RenameTableReq;
@@ -178,6 +180,15 @@ impl Database for DefaultDatabase {
         Ok(res)
     }
 
+    #[async_backtrace::framed]
+    async fn list_table_copied_file_info(
+        &self,
+        req: ListTableCopiedFileReq,
+    ) -> Result<ListTableCopiedFileReply> {
+        let res = self.ctx.meta.list_table_copied_file_info(req).await?;
+        Ok(res)
+    }
+
     #[async_backtrace::framed]
     async fn truncate_table(&self, req: TruncateTableReq) -> Result<TruncateTableReply> {
         let res = self.ctx.meta.truncate_table(req).await?;
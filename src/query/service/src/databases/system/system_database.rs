@@ -36,17 +36,26 @@ use common_storages_system::IndexesTable;
 use common_storages_system::MallocStatsTable;
 use common_storages_system::MallocStatsTotalsTable;
 use common_storages_system::MetricsTable;
+use common_storages_system::NotificationHistoryTable;
+use common_storages_system::NotificationIntegrationsTable;
 use common_storages_system::OneTable;
+use common_storages_system::PipeHistoryTable;
+use common_storages_system::PipesTable;
 use common_storages_system::ProcessesTable;
 use common_storages_system::QueryCacheTable;
 use common_storages_system::QueryLogTable;
 use common_storages_system::QueryProfileTable;
+use common_storages_system::QueryQueueTable;
+use common_storages_system::ReclusterProgressTable;
+use common_storages_system::ReplicationHistoryTable;
 use common_storages_system::RolesTable;
 use common_storages_system::SettingsTable;
 use common_storages_system::StagesTable;
 use common_storages_system::TableFunctionsTable;
 use common_storages_system::TablesTableWithHistory;
 use common_storages_system::TablesTableWithoutHistory;
+use common_storages_system::TaskHistoryTable;
+use common_storages_system::TasksTable;
 use common_storages_system::TracingTable;
 use common_storages_system::UsersTable;
 
@@ -105,6 +114,24 @@ impl SystemDatabase {
             CachesTable::create(sys_db_meta.next_table_id()),
             IndexesTable::create(sys_db_meta.next_table_id()),
             QueryProfileTable::create(sys_db_meta.next_table_id()),
+            QueryQueueTable::create(sys_db_meta.next_table_id()),
+            TasksTable::create(sys_db_meta.next_table_id()),
+            TaskHistoryTable::create(sys_db_meta.next_table_id()),
+            Arc::new(ReclusterProgressTable::create(
+                sys_db_meta.next_table_id(),
+                config.query.max_query_log_size,
+            )),
+            Arc::new(ReplicationHistoryTable::create(
+                sys_db_meta.next_table_id(),
+                config.query.max_query_log_size,
+            )),
+            NotificationIntegrationsTable::create(sys_db_meta.next_table_id()),
+            Arc::new(NotificationHistoryTable::create(
+                sys_db_meta.next_table_id(),
+                config.query.max_query_log_size,
+            )),
+            PipesTable::create(sys_db_meta.next_table_id()),
+            PipeHistoryTable::create(sys_db_meta.next_table_id()),
         ];
 
         let disable_tables = Self::disable_system_tables();
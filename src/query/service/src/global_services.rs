@@ -30,10 +30,19 @@ use storages_common_cache_manager::CacheManager;
 
 use crate::api::DataExchangeManager;
 use crate::auth::AuthMgr;
+use crate::auto_compaction::AutoCompactionService;
 use crate::catalogs::CatalogManagerHelper;
+use crate::change_notification::ChangeNotificationService;
 use crate::clusters::ClusterDiscovery;
+use crate::materialized_view_refresh::MaterializedViewRefreshService;
+use crate::pipelines::executor::ExecutorMetricsSampler;
+use crate::pipes::PipeRunner;
+use crate::query_history::QueryHistoryService;
+use crate::replication::ReplicationService;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::SessionManager;
+use crate::sessions::WorkloadManager;
+use crate::task_scheduler::TaskScheduler;
 
 pub struct GlobalServices;
 
@@ -71,6 +80,7 @@ impl GlobalServices {
         HttpQueryManager::init(&config).await?;
         DataExchangeManager::init()?;
         SessionManager::init(&config)?;
+        WorkloadManager::init(&config)?;
         AuthMgr::init(&config)?;
         UserApiProvider::init(
             config.meta.to_meta_grpc_client_conf(),
@@ -82,6 +92,14 @@ impl GlobalServices {
         RoleCacheManager::init()?;
         ShareEndpointManager::init()?;
         QueryProfileManager::init();
+        AutoCompactionService::init(&config)?;
+        MaterializedViewRefreshService::init(&config)?;
+        QueryHistoryService::init(&config)?;
+        ReplicationService::init(&config)?;
+        TaskScheduler::init(&config)?;
+        ChangeNotificationService::init(&config)?;
+        PipeRunner::init(&config)?;
+        ExecutorMetricsSampler::init()?;
 
         Ok(())
     }
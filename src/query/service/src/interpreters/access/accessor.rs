@@ -18,6 +18,7 @@ use std::sync::Arc;
 use common_exception::Result;
 
 use crate::interpreters::access::PrivilegeAccess;
+use crate::interpreters::access::ReadOnlyReplicaAccess;
 use crate::interpreters::ManagementModeAccess;
 use crate::sessions::QueryContext;
 use crate::sql::plans::Plan;
@@ -36,6 +37,7 @@ impl Accessor {
     pub fn create(ctx: Arc<QueryContext>) -> Self {
         let mut accessors: HashMap<String, Box<dyn AccessChecker>> = Default::default();
         accessors.insert("management".to_string(), ManagementModeAccess::create());
+        accessors.insert("replica".to_string(), ReadOnlyReplicaAccess::create());
         accessors.insert("privilege".to_string(), PrivilegeAccess::create(ctx));
         Accessor { accessors }
     }
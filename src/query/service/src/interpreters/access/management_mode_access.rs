@@ -41,6 +41,7 @@ impl AccessChecker for ManagementModeAccess {
                         Some(ref v) => matches!(v,
                             RewriteKind::ShowDatabases
                             | RewriteKind::ShowTables
+                            | RewriteKind::ShowDropTables
                             | RewriteKind::ShowColumns
                             | RewriteKind::ShowEngines
                             | RewriteKind::ShowSettings
@@ -88,6 +89,35 @@ impl AccessChecker for ManagementModeAccess {
                 | Plan::CreateUDF(_)
                 | Plan::AlterUDF(_)
                 | Plan::DropUDF(_)
+
+                // Dictionary
+                | Plan::CreateDictionary(_)
+                | Plan::DropDictionary(_)
+
+                // Row access policy
+                | Plan::CreateRowAccessPolicy(_)
+                | Plan::DropRowAccessPolicy(_)
+
+                // Sequence
+                | Plan::CreateSequence(_)
+                | Plan::DropSequence(_)
+
+                // Task
+                | Plan::CreateTask(_)
+                | Plan::DropTask(_)
+                | Plan::AlterTask(_)
+
+                // Notification integration
+                | Plan::CreateNotificationIntegration(_)
+                | Plan::DropNotificationIntegration(_)
+
+                // Pipe
+                | Plan::CreatePipe(_)
+                | Plan::DropPipe(_)
+
+                // Network policy
+                | Plan::CreateNetworkPolicy(_)
+                | Plan::DropNetworkPolicy(_)
                 | Plan::UseDatabase(_)
                 | Plan::Call(_) => true,
                 _ => false
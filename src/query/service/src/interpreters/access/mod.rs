@@ -15,8 +15,10 @@
 mod accessor;
 mod management_mode_access;
 mod privilege_access;
+mod read_only_replica_access;
 
 pub use accessor::AccessChecker;
 pub use accessor::Accessor;
 pub use management_mode_access::ManagementModeAccess;
 pub use privilege_access::PrivilegeAccess;
+pub use read_only_replica_access::ReadOnlyReplicaAccess;
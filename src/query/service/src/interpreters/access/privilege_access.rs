@@ -50,6 +50,7 @@ impl AccessChecker for PrivilegeAccess {
                 match rewrite_kind {
                     Some(RewriteKind::ShowDatabases)
                     | Some(RewriteKind::ShowTables)
+                    | Some(RewriteKind::ShowDropTables)
                     | Some(RewriteKind::ShowColumns)
                     | Some(RewriteKind::ShowEngines)
                     | Some(RewriteKind::ShowFunctions)
@@ -63,6 +64,10 @@ impl AccessChecker for PrivilegeAccess {
                     if table.is_source_of_view() {
                         continue;
                     }
+                    // The column privileges are checked by the binder.
+                    if metadata.granted_columns(table.index()).is_some() {
+                        continue;
+                    }
                     session
                         .validate_privilege(
                             &GrantObject::Table(
@@ -86,7 +91,15 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?
             }
-            Plan::CreateUDF(_) | Plan::CreateDatabase(_) | Plan::CreateIndex(_) => {
+            Plan::CreateUDF(_)
+            | Plan::CreateDatabase(_)
+            | Plan::CreateIndex(_)
+            | Plan::CreateDictionary(_)
+            | Plan::CreateRowAccessPolicy(_)
+            | Plan::CreateSequence(_)
+            | Plan::CreateTask(_)
+            | Plan::CreateNotificationIntegration(_)
+            | Plan::CreatePipe(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Create])
                     .await?;
@@ -94,7 +107,13 @@ impl AccessChecker for PrivilegeAccess {
             Plan::DropDatabase(_)
             | Plan::UndropDatabase(_)
             | Plan::DropUDF(_)
-            | Plan::DropIndex(_) => {
+            | Plan::DropIndex(_)
+            | Plan::DropDictionary(_)
+            | Plan::DropRowAccessPolicy(_)
+            | Plan::DropSequence(_)
+            | Plan::DropTask(_)
+            | Plan::DropNotificationIntegration(_)
+            | Plan::DropPipe(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Drop])
                     .await?;
@@ -244,6 +263,30 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::AddTableRowAccessPolicy(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
+            Plan::DropTableRowAccessPolicy(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
             Plan::AddTableColumn(plan) => {
                 session
                     .validate_privilege(
@@ -268,6 +311,18 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::RenameTableColumn(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                    )
+                    .await?;
+            }
             Plan::DropTableColumn(plan) => {
                 session
                     .validate_privilege(
@@ -389,6 +444,22 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::MergeInto(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![
+                            UserPrivilegeType::Insert,
+                            UserPrivilegeType::Update,
+                            UserPrivilegeType::Delete,
+                        ],
+                    )
+                    .await?;
+            }
             Plan::Delete(plan) => {
                 session
                     .validate_privilege(
@@ -437,17 +508,37 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
-            Plan::CreateUser(_) => {
+            Plan::CreateMaterializedView(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        vec![UserPrivilegeType::Create],
+                    )
+                    .await?;
+            }
+            Plan::RefreshMaterializedView(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.view_name.clone(),
+                        ),
+                        vec![UserPrivilegeType::Insert],
+                    )
+                    .await?;
+            }
+            Plan::CreateUser(_) | Plan::CreateNetworkPolicy(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::CreateUser])
                     .await?;
             }
-            Plan::DropUser(_) => {
+            Plan::DropUser(_) | Plan::DropNetworkPolicy(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::DropUser])
                     .await?;
             }
-            Plan::CreateRole(_) => {
+            Plan::CreateRole(_) | Plan::AlterRole(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::CreateRole])
                     .await?;
@@ -473,7 +564,10 @@ impl AccessChecker for PrivilegeAccess {
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Grant])
                     .await?;
             }
-            Plan::SetVariable(_) | Plan::UnSetVariable(_) | Plan::Kill(_) => {
+            Plan::SetVariable(_)
+            | Plan::UnSetVariable(_)
+            | Plan::Kill(_)
+            | Plan::DrainClusterNode(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Super])
                     .await?;
@@ -481,7 +575,9 @@ impl AccessChecker for PrivilegeAccess {
             Plan::AlterUser(_)
             | Plan::AlterUDF(_)
             | Plan::RenameDatabase(_)
-            | Plan::RevertTable(_) => {
+            | Plan::RevertTable(_)
+            | Plan::AlterTask(_)
+            | Plan::ExecuteTask(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Alter])
                     .await?;
@@ -520,6 +616,8 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::CreateStage(_)
             | Plan::DropStage(_)
             | Plan::RemoveStage(_)
+            | Plan::BackupDatabase(_)
+            | Plan::RestoreDatabase(_)
             | Plan::CreateFileFormat(_)
             | Plan::DropFileFormat(_)
             | Plan::ShowFileFormats(_) => {
@@ -541,6 +639,7 @@ impl AccessChecker for PrivilegeAccess {
             // just used in clickhouse-sqlalchemy, no need to check
             Plan::ExistsTable(_) => {}
             Plan::DescDatamaskPolicy(_) => {}
+            Plan::Begin | Plan::Commit | Plan::Abort => {}
         }
 
         Ok(())
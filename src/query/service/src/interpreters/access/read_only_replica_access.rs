@@ -0,0 +1,84 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_config::GlobalConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_sql::plans::PresignAction;
+
+use crate::interpreters::access::AccessChecker;
+use crate::sql::plans::Plan;
+
+pub struct ReadOnlyReplicaAccess;
+
+impl ReadOnlyReplicaAccess {
+    pub fn create() -> Box<dyn AccessChecker> {
+        Box::new(ReadOnlyReplicaAccess)
+    }
+}
+
+#[async_trait::async_trait]
+impl AccessChecker for ReadOnlyReplicaAccess {
+    // A read-only replica never writes to meta, only the reads are allowed.
+    #[async_backtrace::framed]
+    async fn check(&self, plan: &Plan) -> Result<()> {
+        if GlobalConfig::instance().query.read_only_replica {
+            let ok = match plan {
+                // Query, including the SHOW statements rewritten to queries.
+                Plan::Query { .. }
+                | Plan::Explain { .. }
+                | Plan::ExplainAst { .. }
+                | Plan::ExplainSyntax { .. }
+                | Plan::ExplainAnalyze { .. }
+
+                // Show.
+                | Plan::ShowCreateCatalog(_)
+                | Plan::ShowCreateDatabase(_)
+                | Plan::ShowCreateTable(_)
+                | Plan::DescribeTable(_)
+                | Plan::ExistsTable(_)
+                | Plan::ShowGrants(_)
+                | Plan::ShowRoles(_)
+                | Plan::ShowFileFormats(_)
+                | Plan::DescDatamaskPolicy(_)
+
+                // Session.
+                | Plan::UseDatabase(_)
+                | Plan::SetRole(_)
+                | Plan::Kill(_)
+                | Plan::Begin
+                | Plan::Commit
+                | Plan::Abort
+
+                // Cluster, the node is drained from the cluster without writing data.
+                | Plan::DrainClusterNode(_) => true,
+
+                // Only the session settings, the global settings are kept in meta.
+                Plan::SetVariable(plan) => plan.vars.iter().all(|var| !var.is_global),
+
+                Plan::Presign(plan) => matches!(plan.action, PresignAction::Download),
+                _ => false,
+            };
+
+            if !ok {
+                return Err(ErrorCode::ReadOnlyReplicaPermissionDenied(format!(
+                    "Access denied for operation:{:?} in read-only replica",
+                    plan.format_indent()
+                )));
+            }
+        };
+
+        Ok(())
+    }
+}
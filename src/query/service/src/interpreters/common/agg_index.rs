@@ -0,0 +1,86 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use aggregating_index::get_agg_index_handler;
+use common_catalog::catalog::CatalogManager;
+use common_catalog::table::Table;
+use common_exception::Result;
+use common_license::license_manager::get_license_manager;
+use common_meta_app::schema::ListIndexesReq;
+use common_sql::Binder;
+use common_sql::Metadata;
+use common_sql::NameResolutionContext;
+use common_storages_fuse::FuseTable;
+use parking_lot::RwLock;
+
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Write the partial aggregate states of the aggregating indexes of the table
+/// for its new blocks.
+///
+/// The table is loaded from the catalog, so the blocks just committed are visible.
+#[async_backtrace::framed]
+pub async fn refresh_agg_indexes(
+    ctx: Arc<QueryContext>,
+    catalog: &str,
+    database: &str,
+    table: &str,
+) -> Result<()> {
+    let tenant = ctx.get_tenant();
+    let license_manager = get_license_manager();
+    if license_manager
+        .manager
+        .check_enterprise_enabled(
+            &ctx.get_settings(),
+            tenant.clone(),
+            "aggregating_index".to_string(),
+        )
+        .is_err()
+    {
+        return Ok(());
+    }
+
+    let catalog = ctx.get_catalog(catalog)?;
+    let table = catalog.get_table(&tenant, database, table).await?;
+    if !table.support_index() {
+        return Ok(());
+    }
+    let indexes = catalog
+        .list_indexes(ListIndexesReq::new(&tenant, Some(table.get_id())))
+        .await?;
+    if indexes.is_empty() {
+        return Ok(());
+    }
+
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let handler = get_agg_index_handler();
+    for (index_id, _, index_meta) in indexes {
+        let binder = Binder::new(
+            ctx.clone(),
+            CatalogManager::instance(),
+            NameResolutionContext::try_from(ctx.get_settings().as_ref())?,
+            Arc::new(RwLock::new(Metadata::default())),
+        );
+        if let Some(aggregation) = binder.bind_agg_index_aggregation(&index_meta.query).await? {
+            handler
+                .do_refresh_index(fuse_table, ctx.clone(), index_id, aggregation)
+                .await?;
+        }
+    }
+
+    Ok(())
+}
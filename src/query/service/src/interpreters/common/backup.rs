@@ -0,0 +1,80 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::DatabaseMeta;
+use common_meta_app::schema::TableMeta;
+use common_storages_fuse::operations::SnapshotFiles;
+use opendal::Operator;
+use serde::Deserialize;
+use serde::Serialize;
+
+pub const BACKUP_MANIFEST_VERSION: u64 = 1;
+pub const BACKUP_MANIFEST_FILE: &str = "manifest.json";
+/// The fuse objects copied by `BACKUP DATABASE ... WITH DATA` are put under this
+/// directory of the backup, with the same paths as in the storage of the tables.
+pub const BACKUP_DATA_DIR: &str = "data/";
+
+/// The manifest of a backup of a database, it's written after all the files of the
+/// backup, so a backup without manifest is incomplete.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BackupManifest {
+    pub version: u64,
+    pub database: String,
+    pub database_meta: DatabaseMeta,
+    pub created_on: DateTime<Utc>,
+    pub with_data: bool,
+    pub tables: Vec<BackupTable>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BackupTable {
+    pub name: String,
+    pub table_meta: TableMeta,
+    /// The fuse objects referenced by the current snapshot of the table, [None] if the
+    /// table is not a fuse table, is attached, or has no snapshot.
+    pub files: Option<SnapshotFiles>,
+}
+
+impl BackupManifest {
+    #[async_backtrace::framed]
+    pub async fn read(operator: &Operator, path: &str) -> Result<BackupManifest> {
+        let location = format!("{path}{BACKUP_MANIFEST_FILE}");
+        if !operator.is_exist(&location).await? {
+            return Err(ErrorCode::BadArguments(format!(
+                "No backup is found at {path}, the manifest {location} does not exist"
+            )));
+        }
+        let data = operator.read(&location).await?;
+        let manifest: BackupManifest = serde_json::from_slice(&data)?;
+        if manifest.version > BACKUP_MANIFEST_VERSION {
+            return Err(ErrorCode::BadArguments(format!(
+                "Unsupported version {} of the backup manifest {location}",
+                manifest.version
+            )));
+        }
+        Ok(manifest)
+    }
+
+    #[async_backtrace::framed]
+    pub async fn write(&self, operator: &Operator, path: &str) -> Result<()> {
+        let location = format!("{path}{BACKUP_MANIFEST_FILE}");
+        let data = serde_json::to_vec_pretty(self)?;
+        operator.write(&location, data).await?;
+        Ok(())
+    }
+}
@@ -60,6 +60,18 @@ pub async fn validate_grant_object_exists(
                 )));
             }
         }
+        GrantObject::Column(catalog_name, database_name, table_name, column_name) => {
+            let catalog = ctx.get_catalog(catalog_name)?;
+            let table = catalog
+                .get_table(tenant.as_str(), database_name, table_name)
+                .await?;
+            if table.schema().field_with_name(column_name).is_err() {
+                return Err(common_exception::ErrorCode::UnknownColumn(format!(
+                    "column {} not exists in table {}.{}",
+                    column_name, database_name, table_name,
+                )));
+            }
+        }
         GrantObject::Global => (),
     }
 
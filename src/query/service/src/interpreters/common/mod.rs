@@ -12,12 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod agg_index;
+mod backup;
 mod grant;
 mod stage;
 mod table;
 mod util;
+mod virtual_column;
+pub use agg_index::refresh_agg_indexes;
+pub use backup::BackupManifest;
+pub use backup::BackupTable;
+pub use backup::BACKUP_DATA_DIR;
+pub use backup::BACKUP_MANIFEST_FILE;
+pub use backup::BACKUP_MANIFEST_VERSION;
 pub use grant::validate_grant_object_exists;
 pub use stage::try_purge_files;
 pub use table::append2table;
 pub use table::fill_missing_columns;
 pub use util::check_deduplicate_label;
+pub use virtual_column::refresh_virtual_columns;
@@ -17,14 +17,19 @@ use std::sync::Arc;
 use common_catalog::table::AppendMode;
 use common_catalog::table::Table;
 use common_exception::Result;
+use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
+use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::Pipeline;
+use common_sql::parse_sequence_default_expr;
 
 use crate::pipelines::processors::transforms::TransformAddComputedColumns;
+use crate::pipelines::processors::transforms::TransformAddSequenceColumns;
 use crate::pipelines::processors::TransformResortAddOn;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
 
 pub fn fill_missing_columns(
     ctx: Arc<QueryContext>,
@@ -37,6 +42,37 @@ pub fn fill_missing_columns(
     let default_schema: DataSchemaRef = Arc::new(table_default_schema.into());
     let computed_schema: DataSchemaRef = Arc::new(table_computed_schema.into());
 
+    // Fill missing columns taking their values from sequences, they can't be evaluated
+    // as expressions like the other defaults.
+    let sequence_fields = default_schema
+        .fields()
+        .iter()
+        .filter(|f| !source_schema.has_field(f.name()))
+        .filter(|f| {
+            f.default_expr()
+                .map_or(false, |expr| parse_sequence_default_expr(expr).is_some())
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+    let source_schema = if sequence_fields.is_empty() {
+        source_schema
+    } else {
+        let tenant = ctx.get_tenant();
+        let func_ctx = ctx.get_function_context()?;
+        pipeline.add_transform(|transform_input_port, transform_output_port| {
+            Ok(ProcessorPtr::create(TransformAddSequenceColumns::create(
+                transform_input_port,
+                transform_output_port,
+                tenant.clone(),
+                func_ctx.clone(),
+                &sequence_fields,
+            )))
+        })?;
+        let mut fields = source_schema.fields().clone();
+        fields.extend(sequence_fields);
+        Arc::new(DataSchema::new(fields))
+    };
+
     // Fill missing default columns and resort the columns.
     if source_schema != default_schema {
         pipeline.add_transform(|transform_input_port, transform_output_port| {
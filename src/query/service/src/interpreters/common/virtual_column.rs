@@ -0,0 +1,71 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_exception::Result;
+use common_license::license_manager::get_license_manager;
+use common_meta_app::schema::ListVirtualColumnsReq;
+use common_storages_fuse::FuseTable;
+use virtual_columns_handler::get_virtual_columns_handler;
+
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Materialize the virtual columns of the table for its new blocks.
+///
+/// The table is loaded from the catalog, so the blocks just committed are visible.
+#[async_backtrace::framed]
+pub async fn refresh_virtual_columns(
+    ctx: Arc<QueryContext>,
+    catalog: &str,
+    database: &str,
+    table: &str,
+) -> Result<()> {
+    let tenant = ctx.get_tenant();
+    let license_manager = get_license_manager();
+    if license_manager
+        .manager
+        .check_enterprise_enabled(
+            &ctx.get_settings(),
+            tenant.clone(),
+            "virtual_column".to_string(),
+        )
+        .is_err()
+    {
+        return Ok(());
+    }
+
+    let catalog = ctx.get_catalog(catalog)?;
+    let table = catalog.get_table(&tenant, database, table).await?;
+    if !table.support_virtual_columns() {
+        return Ok(());
+    }
+    let handler = get_virtual_columns_handler();
+    let res = handler
+        .do_list_virtual_columns(catalog, ListVirtualColumnsReq {
+            tenant,
+            table_id: Some(table.get_id()),
+        })
+        .await?;
+    if res.is_empty() {
+        return Ok(());
+    }
+
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    handler
+        .do_refresh_virtual_columns(fuse_table, ctx.clone(), res[0].virtual_columns.clone())
+        .await
+}
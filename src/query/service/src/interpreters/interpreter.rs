@@ -47,6 +47,12 @@ pub trait Interpreter: Sync + Send {
         DataSchemaRefExt::create(vec![])
     }
 
+    /// Whether the statement skips the admission of workload groups, management statements
+    /// do so that a saturated group can still be inspected and drained.
+    fn bypass_workload_group(&self) -> bool {
+        false
+    }
+
     /// The core of the databend processor which will execute the logical plan and get the DataBlock
     #[async_backtrace::framed]
     async fn execute(&self, ctx: Arc<QueryContext>) -> Result<SendableDataBlockStream> {
@@ -58,9 +64,26 @@ pub trait Interpreter: Sync + Send {
             return Err(err);
         }
 
+        // queued here if the workload group of the session is saturated.
+        let with_permit = match self.bypass_workload_group() {
+            true => Ok(false),
+            false => ctx.acquire_workload_permit().await,
+        };
+        let with_permit = match with_permit {
+            Ok(with_permit) => with_permit,
+            Err(err) => {
+                InterpreterMetrics::record_query_error(&ctx);
+                log_query_finished(&ctx, Some(err.clone()));
+                return Err(err);
+            }
+        };
+
         let mut build_res = match self.execute2().await {
             Ok(build_res) => build_res,
             Err(build_error) => {
+                if with_permit {
+                    ctx.release_workload_permit();
+                }
                 InterpreterMetrics::record_query_error(&ctx);
                 log_query_finished(&ctx, Some(build_error.clone()));
                 return Err(build_error);
@@ -68,14 +91,20 @@ pub trait Interpreter: Sync + Send {
         };
 
         if build_res.main_pipeline.is_empty() {
+            if with_permit {
+                ctx.release_workload_permit();
+            }
             InterpreterMetrics::record_query_finished(&ctx, None);
             log_query_finished(&ctx, None);
 
-            return Ok(Box::pin(DataBlockStream::create(None, vec![])));
+            return self.inject_result();
         }
 
         let query_ctx = ctx.clone();
         build_res.main_pipeline.set_on_finished(move |may_error| {
+            if with_permit {
+                query_ctx.release_workload_permit();
+            }
             InterpreterMetrics::record_query_finished(&query_ctx, may_error.clone());
             log_query_finished(&query_ctx, may_error.clone());
 
@@ -98,7 +127,7 @@ pub trait Interpreter: Sync + Send {
 
             ctx.set_executor(complete_executor.get_inner())?;
             complete_executor.execute()?;
-            Ok(Box::pin(DataBlockStream::create(None, vec![])))
+            self.inject_result()
         } else {
             let pulling_executor = PipelinePullingExecutor::from_pipelines(build_res, settings)?;
 
@@ -113,6 +142,12 @@ pub trait Interpreter: Sync + Send {
     /// The core of the databend processor which will execute the logical plan and build the pipeline
     async fn execute2(&self) -> Result<PipelineBuildResult>;
 
+    /// The result of the interpreter whose pipeline is complete, which is built after the
+    /// pipeline is finished.
+    fn inject_result(&self) -> Result<SendableDataBlockStream> {
+        Ok(Box::pin(DataBlockStream::create(None, vec![])))
+    }
+
     fn set_source_pipe_builder(&self, _builder: Option<SourcePipeBuilder>) -> Result<()> {
         Err(ErrorCode::Unimplemented(format!(
             "UnImplement set_source_pipe_builder method for {:?}",
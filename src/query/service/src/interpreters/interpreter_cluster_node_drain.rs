@@ -0,0 +1,74 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_config::GlobalConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_sql::plans::DrainClusterNodePlan;
+
+use crate::clusters::ClusterDiscovery;
+use crate::clusters::ClusterHelper;
+use crate::clusters::DRAIN_TIMEOUT_SECS;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DrainClusterNodeInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DrainClusterNodePlan,
+}
+
+impl DrainClusterNodeInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DrainClusterNodePlan) -> Result<Self> {
+        Ok(DrainClusterNodeInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DrainClusterNodeInterpreter {
+    fn name(&self) -> &str {
+        "DrainClusterNodeInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let node_id = &self.plan.node_id;
+        let timeout_secs = self.plan.timeout_secs.unwrap_or(DRAIN_TIMEOUT_SECS);
+        let cluster = self.ctx.get_cluster();
+        if node_id == &cluster.local_id() {
+            ClusterDiscovery::instance()
+                .drain(Duration::from_secs(timeout_secs))
+                .await?;
+            return Ok(PipelineBuildResult::create());
+        }
+
+        // The nodes draining already are not in the cluster any more.
+        if !cluster.get_nodes().iter().any(|node| &node.id == node_id) {
+            return Err(ErrorCode::ClusterUnknownNode(format!(
+                "Node {} is not in the cluster, or is draining already",
+                node_id
+            )));
+        }
+
+        let config = GlobalConfig::instance();
+        let timeout = self.ctx.get_settings().get_flight_client_timeout()?;
+        let mut conn = cluster.create_node_conn(node_id, &config).await?;
+        conn.drain_node(timeout_secs, timeout).await?;
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -24,11 +24,17 @@ use common_catalog::table::Table;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::infer_table_schema;
+use common_expression::types::StringType;
+use common_expression::types::UInt64Type;
 use common_expression::BlockThresholds;
+use common_expression::DataBlock;
 use common_expression::DataField;
 use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
+use common_expression::FromData;
+use common_expression::FromOptData;
 use common_expression::Scalar;
+use common_expression::SendableDataBlockStream;
 use common_meta_app::principal::StageInfo;
 use common_meta_app::schema::TableCopiedFileInfo;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
@@ -38,6 +44,7 @@ use common_sql::plans::CopyIntoTableMode;
 use common_sql::plans::CopyIntoTablePlan;
 use common_storage::StageFileInfo;
 use common_storage::StageFilesInfo;
+use common_storage::REJECTED_RECORDS_DIR;
 use common_storages_fuse::io::Files;
 use common_storages_stage::StageTable;
 use tracing::debug;
@@ -55,6 +62,7 @@ use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
 use crate::sql::plans::CopyPlan;
 use crate::sql::plans::Plan;
+use crate::stream::DataBlockStream;
 
 pub struct CopyInterpreter {
     ctx: Arc<QueryContext>,
@@ -69,14 +77,13 @@ impl CopyInterpreter {
 
     #[async_backtrace::framed]
     async fn build_query(&self, query: &Plan) -> Result<(PipelineBuildResult, DataSchemaRef)> {
-        let (s_expr, metadata, bind_context, formatted_ast) = match query {
+        let (s_expr, metadata, bind_context) = match query {
             Plan::Query {
                 s_expr,
                 metadata,
                 bind_context,
-                formatted_ast,
                 ..
-            } => (s_expr, metadata, bind_context, formatted_ast),
+            } => (s_expr, metadata, bind_context),
             v => unreachable!("Input plan must be Query, but it's {}", v),
         };
 
@@ -85,7 +92,6 @@ impl CopyInterpreter {
             *(bind_context.clone()),
             *s_expr.clone(),
             metadata.clone(),
-            formatted_ast.clone(),
             false,
         )?;
 
@@ -112,6 +118,7 @@ impl CopyInterpreter {
         &self,
         stage: &StageInfo,
         path: &str,
+        partition_by: &[String],
         query: &Plan,
     ) -> Result<PipelineBuildResult> {
         let (mut build_res, data_schema) = self.build_query(query).await?;
@@ -123,9 +130,11 @@ impl CopyInterpreter {
                 path: path.to_string(),
                 files: None,
                 pattern: None,
+                filter: Default::default(),
             },
             files_to_copy: None,
             is_select: false,
+            partition_by: partition_by.to_vec(),
         };
         let table = StageTable::try_create(stage_table_info)?;
         append2table(
@@ -351,7 +360,12 @@ impl CopyInterpreter {
                             }
                         }
 
-                        // 2. Try to purge copied files if purge option is true, if error will skip.
+                        // 2. quarantine the rejected records of on_error=continue, if error will skip.
+                        if let Err(e) = CopyInterpreter::write_rejected_records(ctx.clone()).await {
+                            error!("copy: failed to write rejected records: {}", e);
+                        }
+
+                        // 3. Try to purge copied files if purge option is true, if error will skip.
                         // If a file is already copied(status with AlreadyCopied) we will try to purge them.
                         if purge {
                             CopyInterpreter::try_purge_files(
@@ -384,6 +398,33 @@ impl CopyInterpreter {
         Ok(build_res)
     }
 
+    /// Writes the rows skipped by `ON_ERROR = CONTINUE` into the user stage as NDJSON, one
+    /// rejected record with the error reason and line number per line.
+    #[async_backtrace::framed]
+    async fn write_rejected_records(ctx: Arc<QueryContext>) -> Result<()> {
+        let mut buf = vec![];
+        if let Some(on_error_map) = ctx.get_on_error_map() {
+            for entry in on_error_map.iter() {
+                for input_error in entry.value().values() {
+                    for record in &input_error.rejected {
+                        serde_json::to_writer(&mut buf, record)?;
+                        buf.push(b'\n');
+                    }
+                }
+            }
+        }
+        if buf.is_empty() {
+            return Ok(());
+        }
+
+        let path = rejected_records_path(&ctx.get_id());
+        let stage_info = StageInfo::new_user_stage(&ctx.get_current_user()?.name);
+        let op = StageTable::get_op(&stage_info)?;
+        op.write(&path, buf).await?;
+        info!("copy: rejected records are written to @~/{}", path);
+        Ok(())
+    }
+
     fn upsert_copied_files_request(
         ctx: Arc<QueryContext>,
         to_table: Arc<dyn Table>,
@@ -392,6 +433,7 @@ impl CopyInterpreter {
         force: bool,
     ) -> Result<Option<UpsertTableCopiedFileReq>> {
         let mut copied_file_tree = BTreeMap::new();
+        let load_time = Utc::now();
         for file in &copied_files {
             // Short the etag to 7 bytes for less space in metasrv.
             let short_etag = file.etag.clone().map(|mut v| {
@@ -402,6 +444,7 @@ impl CopyInterpreter {
                 etag: short_etag,
                 content_length: file.size,
                 last_modified: Some(file.last_modified),
+                load_time: Some(load_time),
             });
         }
 
@@ -440,6 +483,10 @@ impl Interpreter for CopyInterpreter {
         "CopyInterpreterV2"
     }
 
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
     #[tracing::instrument(level = "debug", name = "copy_interpreter_execute_v2", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
@@ -451,11 +498,48 @@ impl Interpreter for CopyInterpreter {
             CopyPlan::IntoTable(plan) => self.build_copy_into_table_pipeline(plan).await,
 
             CopyPlan::IntoStage {
-                stage, from, path, ..
-            } => self.build_copy_into_stage_pipeline(stage, path, from).await,
+                stage,
+                from,
+                path,
+                partition_by,
+                ..
+            } => {
+                self.build_copy_into_stage_pipeline(stage, path, partition_by, from)
+                    .await
+            }
             CopyPlan::NoFileToCopy => Ok(PipelineBuildResult::create()),
         }
     }
+
+    fn inject_result(&self) -> Result<SendableDataBlockStream> {
+        if !self.plan.has_result_set() {
+            return Ok(Box::pin(DataBlockStream::create(None, vec![])));
+        }
+
+        let rows_loaded = self.ctx.get_write_progress_value().rows as u64;
+        let rows_rejected = match self.ctx.get_on_error_map() {
+            Some(on_error_map) => on_error_map
+                .iter()
+                .map(|entry| entry.value().values().map(|e| e.num).sum::<usize>())
+                .sum::<usize>() as u64,
+            None => 0,
+        };
+        let rejected_file = if rows_rejected > 0 {
+            Some(format!("@~/{}", rejected_records_path(&self.ctx.get_id())).into_bytes())
+        } else {
+            None
+        };
+        let block = DataBlock::new_from_columns(vec![
+            UInt64Type::from_data(vec![rows_loaded]),
+            UInt64Type::from_data(vec![rows_rejected]),
+            StringType::from_opt_data(vec![rejected_file]),
+        ]);
+        Ok(Box::pin(DataBlockStream::create(None, vec![block])))
+    }
+}
+
+fn rejected_records_path(query_id: &str) -> String {
+    format!("{REJECTED_RECORDS_DIR}{query_id}.ndjson")
 }
 
 fn fill_const_columns(
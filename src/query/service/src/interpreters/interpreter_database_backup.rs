@@ -0,0 +1,160 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::types::UInt64Type;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::FromData;
+use common_sql::plans::BackupDatabasePlan;
+use common_storages_fuse::operations::copy_snapshot_files;
+use common_storages_fuse::FuseTable;
+use common_storages_stage::StageTable;
+use tracing::info;
+
+use crate::interpreters::common::BackupManifest;
+use crate::interpreters::common::BackupTable;
+use crate::interpreters::common::BACKUP_DATA_DIR;
+use crate::interpreters::common::BACKUP_MANIFEST_FILE;
+use crate::interpreters::common::BACKUP_MANIFEST_VERSION;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Writes the meta of a database and its tables, and the files referenced by the fuse
+/// tables, to a manifest in a stage. The files are copied too if `WITH DATA`.
+pub struct BackupDatabaseInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: BackupDatabasePlan,
+}
+
+impl BackupDatabaseInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: BackupDatabasePlan) -> Result<Self> {
+        Ok(BackupDatabaseInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for BackupDatabaseInterpreter {
+    fn name(&self) -> &str {
+        "BackupDatabaseInterpreter"
+    }
+
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog(&plan.catalog)?;
+        let database = catalog.get_database(&plan.tenant, &plan.database).await?;
+        let database_meta = database.get_db_info().meta.clone();
+        if database_meta.from_share.is_some() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Cannot backup database {}, it's created from a share",
+                plan.database
+            )));
+        }
+
+        let stage_operator = StageTable::get_op(&plan.stage)?;
+        let manifest_location = format!("{}{BACKUP_MANIFEST_FILE}", plan.path);
+        if stage_operator.is_exist(&manifest_location).await? {
+            return Err(ErrorCode::StageFileAlreadyExists(format!(
+                "A backup already exists at @{}/{}",
+                plan.stage.stage_name, plan.path
+            )));
+        }
+
+        let max_io_requests = self.ctx.get_settings().get_max_storage_io_requests()? as usize;
+        let data_dir = format!("{}{BACKUP_DATA_DIR}", plan.path);
+        let mut tables = vec![];
+        let mut names = vec![];
+        let mut engines = vec![];
+        let mut num_files = vec![];
+        let mut copied_files = vec![];
+        let mut copied_bytes = vec![];
+        for table in database.list_tables().await? {
+            let mut files = None;
+            if let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) {
+                if !fuse_table.is_attached() {
+                    files = fuse_table
+                        .list_referenced_files(self.ctx.clone())
+                        .await?
+                        .map(|(_, files)| files);
+                }
+            }
+
+            let (copied, bytes) = match &files {
+                Some(files) if plan.with_data => {
+                    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+                    copy_snapshot_files(
+                        fuse_table.get_operator_ref(),
+                        "",
+                        &stage_operator,
+                        &data_dir,
+                        files,
+                        max_io_requests,
+                    )
+                    .await?
+                }
+                _ => (0, 0),
+            };
+
+            names.push(table.name().as_bytes().to_vec());
+            engines.push(table.engine().as_bytes().to_vec());
+            num_files.push(files.as_ref().map_or(0, |files| {
+                (files.data.len() + files.optional.len() + files.meta.len() + 1) as u64
+            }));
+            copied_files.push(copied);
+            copied_bytes.push(bytes);
+            tables.push(BackupTable {
+                name: table.name().to_string(),
+                table_meta: table.get_table_info().meta.clone(),
+                files,
+            });
+        }
+
+        let manifest = BackupManifest {
+            version: BACKUP_MANIFEST_VERSION,
+            database: plan.database.clone(),
+            database_meta,
+            created_on: Utc::now(),
+            with_data: plan.with_data,
+            tables,
+        };
+        manifest.write(&stage_operator, &plan.path).await?;
+        info!(
+            "backup of database {} written to @{}/{}, {} tables",
+            plan.database,
+            plan.stage.stage_name,
+            plan.path,
+            manifest.tables.len()
+        );
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(engines),
+            UInt64Type::from_data(num_files),
+            UInt64Type::from_data(copied_files),
+            UInt64Type::from_data(copied_bytes),
+        ])])
+    }
+}
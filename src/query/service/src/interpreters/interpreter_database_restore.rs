@@ -0,0 +1,158 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::CreateDatabaseReq;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::DatabaseNameIdent;
+use common_meta_app::schema::TableNameIdent;
+use common_sql::plans::RestoreDatabasePlan;
+use common_storage::init_operator;
+use common_storages_fuse::operations::copy_snapshot_files;
+use common_storages_fuse::FUSE_OPT_KEY_NOTIFICATION_INTEGRATION;
+use common_storages_fuse::FUSE_OPT_KEY_REPLICATION_STAGE;
+use common_storages_stage::StageTable;
+use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use tracing::info;
+
+use crate::interpreters::common::BackupManifest;
+use crate::interpreters::common::BACKUP_DATA_DIR;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Creates a database and its tables from the manifest of `BACKUP DATABASE`.
+///
+/// The restored fuse tables start from the snapshots in the manifest, which are copied
+/// back to the storage first if the backup has the data. The files keep their paths in
+/// the storage, the new commits of the tables are written under their own prefixes.
+pub struct RestoreDatabaseInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RestoreDatabasePlan,
+}
+
+impl RestoreDatabaseInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RestoreDatabasePlan) -> Result<Self> {
+        Ok(RestoreDatabaseInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RestoreDatabaseInterpreter {
+    fn name(&self) -> &str {
+        "RestoreDatabaseInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let stage_operator = StageTable::get_op(&plan.stage)?;
+        let manifest = BackupManifest::read(&stage_operator, &plan.path).await?;
+
+        // copy the data back, or check it's still there, before creating anything.
+        let max_io_requests = self.ctx.get_settings().get_max_storage_io_requests()? as usize;
+        let data_dir = format!("{}{BACKUP_DATA_DIR}", plan.path);
+        for table in manifest.tables.iter() {
+            let files = match &table.files {
+                Some(files) => files,
+                None => continue,
+            };
+            let operator = match &table.table_meta.storage_params {
+                Some(storage_params) => init_operator(storage_params)?,
+                None => self.ctx.get_data_operator()?.operator(),
+            };
+            if manifest.with_data {
+                copy_snapshot_files(
+                    &stage_operator,
+                    &data_dir,
+                    &operator,
+                    "",
+                    files,
+                    max_io_requests,
+                )
+                .await?;
+            } else if !operator.is_exist(&files.snapshot).await? {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Cannot restore table {}, its snapshot {} does not exist and the backup has no data",
+                    table.name, files.snapshot
+                )));
+            }
+        }
+
+        let catalog = self.ctx.get_catalog(&plan.catalog)?;
+        let mut database_meta = manifest.database_meta.clone();
+        database_meta.created_on = Utc::now();
+        database_meta.updated_on = Utc::now();
+        database_meta.drop_on = None;
+        database_meta.shared_by.clear();
+        let reply = catalog
+            .create_database(CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: plan.tenant.clone(),
+                    db_name: plan.database.clone(),
+                },
+                meta: database_meta,
+            })
+            .await?;
+
+        for table in manifest.tables.iter() {
+            let mut table_meta = table.table_meta.clone();
+            table_meta.drop_on = None;
+            table_meta.shared_by.clear();
+            let options = &mut table_meta.options;
+            if options.contains_key(OPT_KEY_DATABASE_ID) {
+                options.insert(OPT_KEY_DATABASE_ID.to_string(), reply.db_id.to_string());
+            }
+            options.remove(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+            options.remove(OPT_KEY_SNAPSHOT_LOCATION);
+            options.remove(FUSE_OPT_KEY_REPLICATION_STAGE);
+            options.remove(FUSE_OPT_KEY_NOTIFICATION_INTEGRATION);
+            if let Some(files) = &table.files {
+                options.insert(
+                    OPT_KEY_SNAPSHOT_LOCATION.to_string(),
+                    files.snapshot.clone(),
+                );
+            }
+
+            catalog
+                .create_table(CreateTableReq {
+                    if_not_exists: false,
+                    name_ident: TableNameIdent {
+                        tenant: plan.tenant.clone(),
+                        db_name: plan.database.clone(),
+                        table_name: table.name.clone(),
+                    },
+                    table_meta,
+                })
+                .await?;
+        }
+
+        info!(
+            "database {} restored from @{}/{}, {} tables",
+            plan.database,
+            plan.stage.stage_name,
+            plan.path,
+            manifest.tables.len()
+        );
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -259,7 +259,6 @@ pub async fn subquery_filter(
         BindContext::new(),
         expr,
         metadata.clone(),
-        None,
         false,
     )?;
     // Build physical plan
@@ -334,6 +333,11 @@ pub fn replace_subquery(
                 replace_subquery(filters, arg)?;
             }
         }
+        ScalarExpr::LambdaFunction(func) => {
+            for arg in &mut func.args {
+                replace_subquery(filters, arg)?;
+            }
+        }
         ScalarExpr::SubqueryExpr { .. } => {
             let filter = filters.pop_back().unwrap();
             *selection = filter;
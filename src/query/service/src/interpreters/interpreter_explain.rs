@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use common_ast::ast::ExplainKind;
+use common_base::base::convert_byte_size;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -25,10 +26,13 @@ use common_expression::DataField;
 use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
 use common_expression::FromData;
+use common_profile::PlanNodeProfile;
+use common_profile::QueryProfile;
 use common_profile::SharedProcessorProfiles;
 use common_sql::executor::ProfileHelper;
 use common_sql::MetadataRef;
 
+use crate::interpreters::InsertInterpreter;
 use crate::interpreters::Interpreter;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::PipelineCompleteExecutor;
@@ -41,6 +45,8 @@ use crate::sessions::QueryContext;
 use crate::sql::executor::PhysicalPlan;
 use crate::sql::executor::PhysicalPlanBuilder;
 use crate::sql::optimizer::SExpr;
+use crate::sql::plans::Insert;
+use crate::sql::plans::InsertInputSource;
 use crate::sql::plans::Plan;
 
 pub struct ExplainInterpreter {
@@ -82,6 +88,9 @@ impl Interpreter for ExplainInterpreter {
                     let plan = builder.build(s_expr).await?;
                     self.explain_physical_plan(&plan, metadata)?
                 }
+                Plan::Insert(insert) if insert.has_select_plan() => {
+                    self.explain_insert_select(insert).await?
+                }
                 _ => self.explain_plan(&self.plan)?,
             },
 
@@ -210,6 +219,28 @@ impl ExplainInterpreter {
         Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])])
     }
 
+    // The physical plan of `INSERT INTO ... SELECT` is built by the insert interpreter.
+    #[async_backtrace::framed]
+    async fn explain_insert_select(&self, insert: &Insert) -> Result<Vec<DataBlock>> {
+        let select_plan = match &insert.source {
+            InsertInputSource::SelectPlan(select_plan) => select_plan,
+            _ => unreachable!(),
+        };
+        let metadata = match select_plan.as_ref() {
+            Plan::Query { metadata, .. } => metadata,
+            _ => return self.explain_plan(&self.plan),
+        };
+        let table = self
+            .ctx
+            .get_table(&insert.catalog, &insert.database, &insert.table)
+            .await?;
+        let interpreter = InsertInterpreter::create(self.ctx.clone(), insert.clone());
+        let plan = interpreter
+            .build_insert_select_plan(table, select_plan)
+            .await?;
+        self.explain_physical_plan(&plan, metadata)
+    }
+
     pub fn explain_join_order(
         &self,
         plan: &PhysicalPlan,
@@ -297,23 +328,41 @@ impl ExplainInterpreter {
         let settings = ExecutorSettings::try_create(&settings, query_id.clone())?;
 
         // Drain the data
-        if build_res.main_pipeline.is_complete_pipeline()? {
+        let executed = if build_res.main_pipeline.is_complete_pipeline()? {
             let mut pipelines = build_res.sources_pipelines;
             pipelines.push(build_res.main_pipeline);
 
             let complete_executor = PipelineCompleteExecutor::from_pipelines(pipelines, settings)?;
-            complete_executor.execute()?;
+            complete_executor.execute()
         } else {
             let mut pulling_executor =
                 PipelinePullingExecutor::from_pipelines(build_res, settings)?;
             pulling_executor.start();
-            while (pulling_executor.pull_data()?).is_some() {}
-        }
+            loop {
+                match pulling_executor.pull_data() {
+                    Ok(Some(_)) => continue,
+                    Ok(None) => break Ok(()),
+                    Err(e) => break Err(e),
+                }
+            }
+        };
 
         let profile =
-            ProfileHelper::build_query_profile(&query_id, &plan, &prof_span_set.lock().unwrap())?;
-
-        // Record the query profile
+            ProfileHelper::build_query_profile(&query_id, &plan, &prof_span_set.lock().unwrap());
+
+        // Record the query profile, also for the failed query, e.g. out of memory,
+        // so that the memory usage of the plan nodes can be checked.
+        let profile = match (executed, profile) {
+            (Ok(_), profile) => profile?,
+            (Err(e), Ok(profile)) => {
+                let breakdown = memory_breakdown(&profile);
+                self.ctx
+                    .get_query_profile_manager()
+                    .insert(Arc::new(profile));
+                return Err(e.add_message_back(breakdown));
+            }
+            (Err(e), Err(_)) => return Err(e),
+        };
         let prof_mgr = self.ctx.get_query_profile_manager();
         prof_mgr.insert(Arc::new(profile));
 
@@ -325,3 +374,30 @@ impl ExplainInterpreter {
         Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])])
     }
 }
+
+/// The plan nodes using the most memory, to be appended to the error of the failed query.
+fn memory_breakdown(profile: &QueryProfile) -> String {
+    const MAX_PLAN_NODES: usize = 5;
+
+    let mut plan_nodes: Vec<&PlanNodeProfile> = profile.plan_node_profs.iter().collect();
+    plan_nodes.sort_by_key(|prof| std::cmp::Reverse(prof.peak_memory_usage));
+    let breakdown = plan_nodes
+        .iter()
+        .take(MAX_PLAN_NODES)
+        .filter(|prof| prof.peak_memory_usage > 0)
+        .map(|prof| {
+            format!(
+                "{}(#{}): peak {}, current {}",
+                prof.plan_node_name,
+                prof.id,
+                convert_byte_size(prof.peak_memory_usage as f64),
+                convert_byte_size(prof.memory_usage.max(0) as f64)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!(
+        ". Memory usage by plan node: [{}], see system.query_profile of query {}",
+        breakdown, profile.query_id
+    )
+}
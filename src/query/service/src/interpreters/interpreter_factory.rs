@@ -36,6 +36,7 @@ use crate::interpreters::interpreter_presign::PresignInterpreter;
 use crate::interpreters::interpreter_role_show::ShowRolesInterpreter;
 use crate::interpreters::interpreter_table_create::CreateTableInterpreter;
 use crate::interpreters::interpreter_table_revert::RevertTableInterpreter;
+use crate::interpreters::AlterRoleInterpreter;
 use crate::interpreters::AlterUserInterpreter;
 use crate::interpreters::CreateShareEndpointInterpreter;
 use crate::interpreters::CreateShareInterpreter;
@@ -83,14 +84,12 @@ impl InterpreterFactory {
                 bind_context,
                 metadata,
                 ignore_result,
-                formatted_ast,
                 ..
             } => Ok(Arc::new(SelectInterpreter::try_create(
                 ctx,
                 *bind_context.clone(),
                 *s_expr.clone(),
                 metadata.clone(),
-                formatted_ast.clone(),
                 *ignore_result,
             )?)),
             Plan::Explain { kind, plan } => Ok(Arc::new(ExplainInterpreter::try_create(
@@ -145,6 +144,12 @@ impl InterpreterFactory {
             Plan::UndropDatabase(undrop_database) => Ok(Arc::new(
                 UndropDatabaseInterpreter::try_create(ctx, *undrop_database.clone())?,
             )),
+            Plan::BackupDatabase(backup_database) => Ok(Arc::new(
+                BackupDatabaseInterpreter::try_create(ctx, *backup_database.clone())?,
+            )),
+            Plan::RestoreDatabase(restore_database) => Ok(Arc::new(
+                RestoreDatabaseInterpreter::try_create(ctx, *restore_database.clone())?,
+            )),
 
             Plan::RenameDatabase(rename_database) => Ok(Arc::new(
                 RenameDatabaseInterpreter::try_create(ctx, *rename_database.clone())?,
@@ -177,12 +182,21 @@ impl InterpreterFactory {
                 ctx,
                 *set_options.clone(),
             )?)),
+            Plan::AddTableRowAccessPolicy(p) => Ok(Arc::new(
+                AddTableRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DropTableRowAccessPolicy(p) => Ok(Arc::new(
+                DropTableRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
             Plan::AddTableColumn(add_table_column) => Ok(Arc::new(
                 AddTableColumnInterpreter::try_create(ctx, *add_table_column.clone())?,
             )),
             Plan::ModifyTableColumn(modify_table_column) => Ok(Arc::new(
                 ModifyTableColumnInterpreter::try_create(ctx, *modify_table_column.clone())?,
             )),
+            Plan::RenameTableColumn(rename_table_column) => Ok(Arc::new(
+                RenameTableColumnInterpreter::try_create(ctx, *rename_table_column.clone())?,
+            )),
             Plan::DropTableColumn(drop_table_column) => Ok(Arc::new(
                 DropTableColumnInterpreter::try_create(ctx, *drop_table_column.clone())?,
             )),
@@ -228,6 +242,14 @@ impl InterpreterFactory {
                 *drop_view.clone(),
             )?)),
 
+            // Materialized views
+            Plan::CreateMaterializedView(create_view) => Ok(Arc::new(
+                CreateMaterializedViewInterpreter::try_create(ctx, *create_view.clone())?,
+            )),
+            Plan::RefreshMaterializedView(refresh_view) => Ok(Arc::new(
+                RefreshMaterializedViewInterpreter::try_create(ctx, *refresh_view.clone())?,
+            )),
+
             // Indexes
             Plan::CreateIndex(index) => Ok(Arc::new(CreateIndexInterpreter::try_create(
                 ctx,
@@ -273,6 +295,10 @@ impl InterpreterFactory {
 
             Plan::Replace(replace) => ReplaceInterpreter::try_create(ctx, *replace.clone()),
 
+            Plan::MergeInto(merge_into) => {
+                MergeIntoInterpreter::try_create(ctx, *merge_into.clone())
+            }
+
             Plan::Delete(delete) => Ok(Arc::new(DeleteInterpreter::try_create(
                 ctx,
                 *delete.clone(),
@@ -292,6 +318,10 @@ impl InterpreterFactory {
                 ctx,
                 *drop_role.clone(),
             )?)),
+            Plan::AlterRole(alter_role) => Ok(Arc::new(AlterRoleInterpreter::try_create(
+                ctx,
+                *alter_role.clone(),
+            )?)),
             Plan::SetRole(set_role) => Ok(Arc::new(SetRoleInterpreter::try_create(
                 ctx,
                 *set_role.clone(),
@@ -358,6 +388,56 @@ impl InterpreterFactory {
                 ctx,
                 *drop_udf.clone(),
             )?)),
+            Plan::CreateRowAccessPolicy(p) => Ok(Arc::new(
+                CreateRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DropRowAccessPolicy(p) => Ok(Arc::new(
+                DropRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::CreateNetworkPolicy(p) => Ok(Arc::new(
+                CreateNetworkPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DropNetworkPolicy(p) => Ok(Arc::new(DropNetworkPolicyInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::CreateDictionary(p) => Ok(Arc::new(CreateDictionaryInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropDictionary(p) => Ok(Arc::new(DropDictionaryInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::CreateSequence(p) => Ok(Arc::new(CreateSequenceInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropSequence(p) => Ok(Arc::new(DropSequenceInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::CreateTask(p) => Ok(Arc::new(CreateTaskInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropTask(p) => Ok(Arc::new(DropTaskInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::AlterTask(p) => Ok(Arc::new(AlterTaskInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::ExecuteTask(p) => Ok(Arc::new(ExecuteTaskInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::CreateNotificationIntegration(p) => Ok(Arc::new(
+                CreateNotificationIntegrationInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DropNotificationIntegration(p) => Ok(Arc::new(
+                DropNotificationIntegrationInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::CreatePipe(p) => Ok(Arc::new(CreatePipeInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropPipe(p) => Ok(Arc::new(DropPipeInterpreter::try_create(ctx, *p.clone())?)),
 
             Plan::Presign(presign) => Ok(Arc::new(PresignInterpreter::try_create(
                 ctx,
@@ -377,6 +457,15 @@ impl InterpreterFactory {
                 *p.clone(),
             )?)),
             Plan::Kill(p) => Ok(Arc::new(KillInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::DrainClusterNode(p) => Ok(Arc::new(DrainClusterNodeInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+
+            // transaction plans
+            Plan::Begin => Ok(Arc::new(BeginInterpreter::try_create(ctx)?)),
+            Plan::Commit => Ok(Arc::new(CommitInterpreter::try_create(ctx)?)),
+            Plan::Abort => Ok(Arc::new(AbortInterpreter::try_create(ctx)?)),
 
             // share plans
             Plan::CreateShareEndpoint(p) => Ok(Arc::new(
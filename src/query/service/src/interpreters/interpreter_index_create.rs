@@ -24,6 +24,7 @@ use common_meta_app::schema::IndexMeta;
 use common_meta_app::schema::IndexNameIdent;
 use common_meta_app::schema::IndexType;
 use common_sql::plans::CreateIndexPlan;
+use common_storages_fuse::FuseTable;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -65,7 +66,8 @@ impl Interpreter for CreateIndexInterpreter {
             ));
         }
 
-        let catalog = self.ctx.get_catalog(&catalog)?;
+        let catalog_name = catalog;
+        let catalog = self.ctx.get_catalog(&catalog_name)?;
 
         let create_index_req = CreateIndexReq {
             if_not_exists: self.plan.if_not_exists,
@@ -80,7 +82,25 @@ impl Interpreter for CreateIndexInterpreter {
         };
 
         let handler = get_agg_index_handler();
-        let _ = handler.do_create_index(catalog, create_index_req).await?;
+        let reply = handler.do_create_index(catalog, create_index_req).await?;
+
+        // Index the existing blocks of the table, the blocks written later are indexed
+        // by the insertion.
+        if let Some(aggregation) = &self.plan.aggregation {
+            let table = self
+                .ctx
+                .get_table(&catalog_name, &self.plan.database, &self.plan.table)
+                .await?;
+            let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+            handler
+                .do_refresh_index(
+                    fuse_table,
+                    self.ctx.clone(),
+                    reply.index_id,
+                    aggregation.clone(),
+                )
+                .await?;
+        }
 
         Ok(PipelineBuildResult::create())
     }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::collections::VecDeque;
 use std::io::BufRead;
 use std::io::Cursor;
@@ -22,26 +23,35 @@ use std::sync::Arc;
 use aho_corasick::AhoCorasick;
 use common_ast::parser::parse_comma_separated_exprs;
 use common_ast::parser::tokenize_sql;
+use common_base::runtime::GlobalIORuntime;
 use common_catalog::table::AppendMode;
+use common_catalog::table::Table;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::type_check::check_cast;
+use common_expression::types::DataType;
 use common_expression::ColumnBuilder;
 use common_expression::DataBlock;
 use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
+use common_expression::Expr;
 use common_formats::FastFieldDecoderValues;
+use common_functions::BUILTIN_FUNCTIONS;
 use common_io::cursor_ext::ReadBytesExt;
 use common_io::cursor_ext::ReadCheckPointExt;
 use common_meta_app::principal::StageFileFormatType;
 use common_pipeline_sources::AsyncSource;
 use common_pipeline_sources::AsyncSourcer;
 use common_sql::executor::DistributedInsertSelect;
+use common_sql::executor::Exchange;
+use common_sql::executor::FragmentKind;
 use common_sql::executor::PhysicalPlan;
 use common_sql::executor::PhysicalPlanBuilder;
 use common_sql::plans::Insert;
 use common_sql::plans::InsertInputSource;
 use common_sql::plans::Plan;
 use common_sql::BindContext;
+use common_sql::ColumnBinding;
 use common_sql::Metadata;
 use common_sql::MetadataRef;
 use common_sql::NameResolutionContext;
@@ -51,6 +61,8 @@ use parking_lot::RwLock;
 
 use crate::interpreters::common::append2table;
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::refresh_agg_indexes;
+use crate::interpreters::common::refresh_virtual_columns;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::pipelines::processors::transforms::TransformRuntimeCastSchema;
@@ -74,11 +86,158 @@ pub struct InsertInterpreter {
 
 impl InsertInterpreter {
     pub fn try_create(ctx: Arc<QueryContext>, plan: Insert) -> Result<InterpreterPtr> {
-        Ok(Arc::new(InsertInterpreter {
+        Ok(Arc::new(InsertInterpreter::create(ctx, plan)))
+    }
+
+    pub fn create(ctx: Arc<QueryContext>, plan: Insert) -> Self {
+        InsertInterpreter {
             ctx,
             plan,
             source_pipe_builder: Mutex::new(None),
-        }))
+        }
+    }
+
+    /// Index the blocks written by the insertion and materialize their virtual columns
+    /// after they are committed.
+    fn refresh_indexes_on_finished(&self, build_res: &mut PipelineBuildResult) {
+        let ctx = self.ctx.clone();
+        let catalog = self.plan.catalog.clone();
+        let database = self.plan.database.clone();
+        let table = self.plan.table.clone();
+        build_res
+            .main_pipeline
+            .set_on_finished(move |may_error| match may_error {
+                None => GlobalIORuntime::instance().block_on(async move {
+                    refresh_agg_indexes(ctx.clone(), &catalog, &database, &table).await?;
+                    refresh_virtual_columns(ctx, &catalog, &database, &table).await
+                }),
+                Some(error_code) => Err(error_code.clone()),
+            });
+    }
+
+    /// Repartition the rows of a distributed `INSERT INTO ... SELECT` by the cluster key
+    /// of the target table, so the rows with the same cluster key are written by the
+    /// same node, instead of every node writing fragments of every key range.
+    ///
+    /// The rows are shuffled by the values of the cluster key expressions evaluated over
+    /// the selected columns, e.g. `a % 10` instead of `a`. The cluster keys referring to
+    /// the columns not in the insert list are skipped, those columns are filled with
+    /// default values after the shuffle.
+    fn shuffle_by_cluster_key(
+        &self,
+        table: &dyn Table,
+        input: Box<PhysicalPlan>,
+        plan_id: u32,
+        select_column_bindings: &[ColumnBinding],
+    ) -> Result<Box<PhysicalPlan>> {
+        let cluster_keys = table.cluster_keys(self.ctx.clone());
+        if cluster_keys.is_empty() {
+            return Ok(input);
+        }
+
+        let input_schema = input.output_schema()?;
+        let insert_schema = self.plan.schema();
+        let mut columns = HashMap::new();
+        for (position, field) in insert_schema.fields().iter().enumerate() {
+            if let Some(binding) = select_column_bindings.get(position) {
+                let offset = input_schema.index_of(&binding.index.to_string())?;
+                let data_type = input_schema.field(offset).data_type().clone();
+                columns.insert(field.name().clone(), (offset, data_type));
+            }
+        }
+
+        let mut keys = Vec::with_capacity(cluster_keys.len());
+        for cluster_key in cluster_keys.iter() {
+            let expr = cluster_key.as_expr(&BUILTIN_FUNCTIONS);
+            let column_refs = expr.column_refs();
+            if column_refs.is_empty() || column_refs.keys().any(|c| !columns.contains_key(c)) {
+                continue;
+            }
+            let key = bind_cluster_key(&expr, &columns)?;
+            keys.push(key.as_remote_expr());
+        }
+        if keys.is_empty() {
+            return Ok(input);
+        }
+
+        Ok(Box::new(PhysicalPlan::Exchange(Exchange {
+            plan_id,
+            input,
+            kind: FragmentKind::Normal,
+            keys,
+        })))
+    }
+
+    /// Builds the physical plan of an `INSERT INTO ... SELECT`, the rows are inserted by the
+    /// nodes running the select if it is distributed.
+    pub async fn build_insert_select_plan(
+        &self,
+        table: Arc<dyn Table>,
+        plan: &Plan,
+    ) -> Result<PhysicalPlan> {
+        let (mut select_plan, select_column_bindings, shuffle_plan_id) = match plan {
+            Plan::Query {
+                s_expr,
+                metadata,
+                bind_context,
+                ..
+            } => {
+                let mut builder1 = PhysicalPlanBuilder::new(metadata.clone(), self.ctx.clone());
+                let select_plan = builder1.build(s_expr).await?;
+                (
+                    select_plan,
+                    bind_context.columns.clone(),
+                    builder1.next_plan_id(),
+                )
+            }
+            _ => unreachable!(),
+        };
+
+        let catalog = self.plan.catalog.clone();
+
+        let insert_select_plan = match select_plan {
+            PhysicalPlan::Exchange(ref mut exchange) => {
+                // insert can be dispatched to different nodes
+                let input = self.shuffle_by_cluster_key(
+                    table.as_ref(),
+                    exchange.input.clone(),
+                    shuffle_plan_id,
+                    &select_column_bindings,
+                )?;
+                exchange.input = Box::new(PhysicalPlan::DistributedInsertSelect(Box::new(
+                    DistributedInsertSelect {
+                        // TODO(leiysky): we reuse the id of exchange here,
+                        // which is not correct. We should generate a new id for insert.
+                        plan_id: exchange.plan_id,
+                        input,
+                        catalog,
+                        table_info: table.get_table_info().clone(),
+                        select_schema: plan.schema(),
+                        select_column_bindings,
+                        insert_schema: self.plan.schema(),
+                        cast_needed: self.check_schema_cast(plan)?,
+                    },
+                )));
+                select_plan
+            }
+            other_plan => {
+                // insert should wait until all nodes finished
+                PhysicalPlan::DistributedInsertSelect(Box::new(DistributedInsertSelect {
+                    // TODO: we reuse the id of other plan here,
+                    // which is not correct. We should generate a new id for insert.
+                    plan_id: other_plan.get_id(),
+                    input: Box::new(other_plan),
+                    catalog,
+                    table_info: table.get_table_info().clone(),
+                    select_schema: plan.schema(),
+                    select_column_bindings,
+                    insert_schema: self.plan.schema(),
+                    cast_needed: self.check_schema_cast(plan)?,
+                }))
+            }
+        };
+
+        Ok(insert_select_plan)
     }
 
     fn check_schema_cast(&self, plan: &Plan) -> Result<bool> {
@@ -189,59 +348,7 @@ impl Interpreter for InsertInterpreter {
                 }
             }
             InsertInputSource::SelectPlan(plan) => {
-                let table1 = table.clone();
-                let (mut select_plan, select_column_bindings) = match plan.as_ref() {
-                    Plan::Query {
-                        s_expr,
-                        metadata,
-                        bind_context,
-                        ..
-                    } => {
-                        let mut builder1 =
-                            PhysicalPlanBuilder::new(metadata.clone(), self.ctx.clone());
-                        (builder1.build(s_expr).await?, bind_context.columns.clone())
-                    }
-                    _ => unreachable!(),
-                };
-
-                let catalog = self.plan.catalog.clone();
-
-                let insert_select_plan = match select_plan {
-                    PhysicalPlan::Exchange(ref mut exchange) => {
-                        // insert can be dispatched to different nodes
-                        let input = exchange.input.clone();
-                        exchange.input = Box::new(PhysicalPlan::DistributedInsertSelect(Box::new(
-                            DistributedInsertSelect {
-                                // TODO(leiysky): we reuse the id of exchange here,
-                                // which is not correct. We should generate a new id for insert.
-                                plan_id: exchange.plan_id,
-                                input,
-                                catalog,
-                                table_info: table1.get_table_info().clone(),
-                                select_schema: plan.schema(),
-                                select_column_bindings,
-                                insert_schema: self.plan.schema(),
-                                cast_needed: self.check_schema_cast(plan)?,
-                            },
-                        )));
-                        select_plan
-                    }
-                    other_plan => {
-                        // insert should wait until all nodes finished
-                        PhysicalPlan::DistributedInsertSelect(Box::new(DistributedInsertSelect {
-                            // TODO: we reuse the id of other plan here,
-                            // which is not correct. We should generate a new id for insert.
-                            plan_id: other_plan.get_id(),
-                            input: Box::new(other_plan),
-                            catalog,
-                            table_info: table1.get_table_info().clone(),
-                            select_schema: plan.schema(),
-                            select_column_bindings,
-                            insert_schema: self.plan.schema(),
-                            cast_needed: self.check_schema_cast(plan)?,
-                        }))
-                    }
-                };
+                let insert_select_plan = self.build_insert_select_plan(table.clone(), plan).await?;
 
                 let mut build_res = if !insert_select_plan.is_distributed_plan() {
                     build_local_pipeline(&self.ctx, &insert_select_plan, false).await
@@ -255,6 +362,7 @@ impl Interpreter for InsertInterpreter {
                     None,
                     self.plan.overwrite,
                 )?;
+                self.refresh_indexes_on_finished(&mut build_res);
 
                 return Ok(build_res);
             }
@@ -275,6 +383,7 @@ impl Interpreter for InsertInterpreter {
             self.plan.overwrite,
             append_mode,
         )?;
+        self.refresh_indexes_on_finished(&mut build_res);
 
         Ok(build_res)
     }
@@ -543,3 +652,84 @@ pub fn skip_to_next_row<R: AsRef<[u8]>>(reader: &mut Cursor<R>, mut balance: i32
     }
     Ok(())
 }
+
+/// Binds the columns of a cluster key to the offsets of the selected columns, the selected
+/// columns are cast to the types of the table columns the cluster key is checked against.
+fn bind_cluster_key(
+    expr: &Expr<String>,
+    columns: &HashMap<String, (usize, DataType)>,
+) -> Result<Expr> {
+    Ok(match expr {
+        Expr::Constant {
+            span,
+            scalar,
+            data_type,
+        } => Expr::Constant {
+            span: *span,
+            scalar: scalar.clone(),
+            data_type: data_type.clone(),
+        },
+        Expr::ColumnRef {
+            span,
+            id,
+            data_type,
+            display_name,
+        } => {
+            let (offset, input_type) = &columns[id];
+            let column = Expr::ColumnRef {
+                span: *span,
+                id: *offset,
+                data_type: input_type.clone(),
+                display_name: display_name.clone(),
+            };
+            check_cast(*span, false, column, data_type, &BUILTIN_FUNCTIONS)?
+        }
+        Expr::Cast {
+            span,
+            is_try,
+            expr,
+            dest_type,
+        } => Expr::Cast {
+            span: *span,
+            is_try: *is_try,
+            expr: Box::new(bind_cluster_key(expr, columns)?),
+            dest_type: dest_type.clone(),
+        },
+        Expr::FunctionCall {
+            span,
+            id,
+            function,
+            generics,
+            args,
+            return_type,
+        } => Expr::FunctionCall {
+            span: *span,
+            id: id.clone(),
+            function: function.clone(),
+            generics: generics.clone(),
+            args: args
+                .iter()
+                .map(|arg| bind_cluster_key(arg, columns))
+                .collect::<Result<_>>()?,
+            return_type: return_type.clone(),
+        },
+        Expr::LambdaFunctionCall {
+            span,
+            name,
+            args,
+            lambda_expr,
+            lambda_display,
+            return_type,
+        } => Expr::LambdaFunctionCall {
+            span: *span,
+            name: name.clone(),
+            args: args
+                .iter()
+                .map(|arg| bind_cluster_key(arg, columns))
+                .collect::<Result<_>>()?,
+            lambda_expr: lambda_expr.clone(),
+            lambda_display: lambda_display.clone(),
+            return_type: return_type.clone(),
+        },
+    })
+}
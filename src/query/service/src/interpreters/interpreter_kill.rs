@@ -14,13 +14,18 @@
 
 use std::sync::Arc;
 
+use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_sql::plans::KillPlan;
+use tracing::warn;
 
+use crate::clusters::ClusterHelper;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::TableContext;
 
 pub struct KillInterpreter {
     ctx: Arc<QueryContext>,
@@ -34,22 +39,42 @@ impl KillInterpreter {
 
     #[async_backtrace::framed]
     async fn execute_kill(&self, session_id: &String) -> Result<PipelineBuildResult> {
-        match self.ctx.get_session_by_id(session_id) {
-            None => Err(ErrorCode::UnknownSession(format!(
-                "Not found session id {}",
-                session_id
-            ))),
-            Some(kill_session) if self.plan.kill_connection => {
-                kill_session.force_kill_session();
-                Ok(PipelineBuildResult::create())
+        let kill_connection = self.plan.kill_connection;
+        if SessionManager::instance().kill_session(session_id, kill_connection) {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        // The session may be on another node of the cluster, the id of the sessions
+        // listed by `system.processes` is unique in the cluster.
+        let cluster = self.ctx.get_cluster();
+        let config = GlobalConfig::instance();
+        let timeout = self.ctx.get_settings().get_flight_client_timeout()?;
+        for node in cluster.get_nodes() {
+            if cluster.is_local(&node) {
+                continue;
             }
-            Some(kill_session) => {
-                kill_session.force_kill_query(ErrorCode::AbortedQuery(
-                    "Aborted query, because the server is shutting down or the query was killed",
-                ));
-                Ok(PipelineBuildResult::create())
+
+            let killed = match cluster.create_node_conn(&node.id, &config).await {
+                Ok(mut conn) => {
+                    conn.kill_session(session_id, kill_connection, timeout)
+                        .await
+                }
+                Err(cause) => Err(cause),
+            };
+            match killed {
+                Ok(true) => return Ok(PipelineBuildResult::create()),
+                Ok(false) => {}
+                Err(cause) => warn!(
+                    "Cannot kill session {} on node {}, cause {:?}",
+                    session_id, node.id, cause
+                ),
             }
         }
+
+        Err(ErrorCode::UnknownSession(format!(
+            "Not found session id {}",
+            session_id
+        )))
     }
 }
 
@@ -59,6 +84,10 @@ impl Interpreter for KillInterpreter {
         "KillInterpreter"
     }
 
+    fn bypass_workload_group(&self) -> bool {
+        true
+    }
+
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let id = &self.plan.id;
@@ -0,0 +1,98 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableNameIdent;
+use common_sql::plans::CreateMaterializedViewPlan;
+use common_sql::plans::RefreshMaterializedViewPlan;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::RefreshMaterializedViewInterpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateMaterializedViewPlan,
+}
+
+impl CreateMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateMaterializedViewPlan) -> Result<Self> {
+        Ok(CreateMaterializedViewInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "CreateMaterializedViewInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.plan.tenant.clone();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog)?;
+        if catalog
+            .exists_table(&tenant, &self.plan.database, &self.plan.view_name)
+            .await?
+        {
+            return if self.plan.if_not_exists {
+                Ok(PipelineBuildResult::create())
+            } else {
+                Err(ErrorCode::TableAlreadyExists(format!(
+                    "{}.{} as materialized view Already Exists",
+                    self.plan.database, self.plan.view_name
+                )))
+            };
+        }
+
+        // The materialized view options are reserved, so create the table directly
+        // instead of going through `CreateTableInterpreter`.
+        let req = CreateTableReq {
+            if_not_exists: self.plan.if_not_exists,
+            name_ident: TableNameIdent {
+                tenant: tenant.clone(),
+                db_name: self.plan.database.clone(),
+                table_name: self.plan.view_name.clone(),
+            },
+            table_meta: TableMeta {
+                schema: self.plan.schema.clone(),
+                engine: "FUSE".to_string(),
+                options: self.plan.options.clone(),
+                ..Default::default()
+            },
+        };
+        let reply = catalog.create_table(req).await?;
+        if !reply.new_table {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        // Populate the view with the current data of the source table.
+        let refresh_plan = RefreshMaterializedViewPlan {
+            tenant,
+            catalog: self.plan.catalog.clone(),
+            database: self.plan.database.clone(),
+            view_name: self.plan.view_name.clone(),
+        };
+        RefreshMaterializedViewInterpreter::try_create(self.ctx.clone(), refresh_plan)?
+            .execute2()
+            .await
+    }
+}
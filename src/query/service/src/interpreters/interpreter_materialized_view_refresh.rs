@@ -0,0 +1,359 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_ast::ast::BinaryOperator;
+use common_ast::ast::ColumnID;
+use common_ast::ast::Expr;
+use common_ast::ast::Identifier;
+use common_ast::ast::Literal;
+use common_ast::ast::Query;
+use common_ast::ast::SetExpr;
+use common_ast::ast::Statement;
+use common_ast::ast::TableReference;
+use common_ast::ast::TimeTravelPoint;
+use common_ast::parser::parse_sql;
+use common_ast::parser::tokenize_sql;
+use common_ast::Dialect;
+use common_base::runtime::GlobalIORuntime;
+use common_catalog::table::Table;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::SEGMENT_NAME_COL_NAME;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::MatchSeq;
+use common_sql::optimizer::SExpr;
+use common_sql::plans::Plan;
+use common_sql::plans::RefreshMaterializedViewPlan;
+use common_sql::plans::RelOperator;
+use common_sql::Planner;
+use common_storages_fuse::io::MetaReaders;
+use common_storages_fuse::io::TableMetaLocationGenerator;
+use common_storages_fuse::FuseTable;
+use storages_common_cache::LoadParams;
+use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_REFRESHED_ON;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SNAPSHOT;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCE;
+use tracing::info;
+
+use crate::interpreters::InsertInterpreter;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::plans::insert::Insert;
+use crate::sql::plans::insert::InsertInputSource;
+
+/// Refresh a materialized view with the current snapshot of its source table.
+///
+/// If the view query is a plain projection (with optional filter) of the source table,
+/// and the segments consumed by the last refresh are still referenced by the current
+/// snapshot, only the rows of the new segments are appended to the view. Otherwise,
+/// the view is overwritten with the result of its query.
+pub struct RefreshMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RefreshMaterializedViewPlan,
+}
+
+impl RefreshMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RefreshMaterializedViewPlan) -> Result<Self> {
+        Ok(RefreshMaterializedViewInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RefreshMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "RefreshMaterializedViewInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.plan.tenant.clone();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog)?;
+        // Always refresh with the latest version of the view.
+        let view = catalog
+            .get_table(&tenant, &self.plan.database, &self.plan.view_name)
+            .await?;
+        let options = view.options();
+        let (query, source_id) = match (
+            options.get(OPT_KEY_MATERIALIZED_VIEW_QUERY),
+            options.get(OPT_KEY_MATERIALIZED_VIEW_SOURCE),
+        ) {
+            (Some(query), Some(source_id)) => (query.clone(), source_id.clone()),
+            _ => {
+                return Err(ErrorCode::Internal(format!(
+                    "Invalid MATERIALIZED VIEW object: {}.{}",
+                    self.plan.database, self.plan.view_name
+                )));
+            }
+        };
+        let consumed_location = options
+            .get(OPT_KEY_MATERIALIZED_VIEW_SNAPSHOT)
+            .cloned()
+            .unwrap_or_default();
+
+        let source = catalog
+            .list_tables(&tenant, &self.plan.database)
+            .await?
+            .into_iter()
+            .find(|table| table.get_id().to_string() == source_id)
+            .ok_or_else(|| {
+                ErrorCode::UnknownTable(format!(
+                    "Source table of materialized view {}.{} not exists",
+                    self.plan.database, self.plan.view_name
+                ))
+            })?;
+        let source = FuseTable::try_from_table(source.as_ref())?;
+
+        let snapshot_location = source.snapshot_loc().await?.unwrap_or_default();
+        if snapshot_location == consumed_location {
+            // Already up to date.
+            return Ok(PipelineBuildResult::create());
+        }
+        let snapshot = match source.read_table_snapshot().await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(PipelineBuildResult::create()),
+        };
+
+        let tokens = tokenize_sql(&query)?;
+        let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL)?;
+        let mut query = match stmt {
+            Statement::Query(query) => *query,
+            _ => {
+                return Err(ErrorCode::Internal(format!(
+                    "Invalid MATERIALIZED VIEW object: {}.{}",
+                    self.plan.database, self.plan.view_name
+                )));
+            }
+        };
+        // Read the snapshot recorded in the view, even if the source table is changed
+        // during the refresh. Time travel also prevents the query from being answered
+        // by materialized views.
+        set_travel_point(&mut query, snapshot.snapshot_id.simple().to_string());
+
+        let mut select_plan = self.plan_query(&query).await?;
+        let mut overwrite = true;
+        if is_incremental_plan(&select_plan) {
+            if let Some(new_segments) = self
+                .new_segments(source, &consumed_location, &snapshot)
+                .await?
+            {
+                if new_segments.is_empty() {
+                    // e.g. The new snapshot is generated by purging.
+                    let ctx = self.ctx.clone();
+                    let plan = self.plan.clone();
+                    update_refreshed_snapshot(ctx, plan, snapshot_location).await?;
+                    return Ok(PipelineBuildResult::create());
+                }
+
+                add_segment_filter(&mut query, new_segments);
+                select_plan = self.plan_query(&query).await?;
+                overwrite = false;
+            }
+        }
+
+        info!(
+            "refresh materialized view {}.{}, incremental: {}",
+            self.plan.database, self.plan.view_name, !overwrite
+        );
+        let insert_plan = Insert {
+            catalog: self.plan.catalog.clone(),
+            database: self.plan.database.clone(),
+            table: self.plan.view_name.clone(),
+            table_id: view.get_id(),
+            schema: view.schema(),
+            overwrite,
+            source: InsertInputSource::SelectPlan(Box::new(select_plan)),
+        };
+        let mut build_res = InsertInterpreter::try_create(self.ctx.clone(), insert_plan)?
+            .execute2()
+            .await?;
+
+        let ctx = self.ctx.clone();
+        let plan = self.plan.clone();
+        if build_res.main_pipeline.is_empty() {
+            update_refreshed_snapshot(ctx, plan, snapshot_location).await?;
+        } else {
+            build_res
+                .main_pipeline
+                .set_on_finished(move |may_error| match may_error {
+                    None => GlobalIORuntime::instance().block_on(async move {
+                        update_refreshed_snapshot(ctx, plan, snapshot_location).await
+                    }),
+                    Some(error_code) => Err(error_code.clone()),
+                });
+        }
+        Ok(build_res)
+    }
+}
+
+impl RefreshMaterializedViewInterpreter {
+    #[async_backtrace::framed]
+    async fn plan_query(&self, query: &Query) -> Result<Plan> {
+        let mut planner = Planner::new(self.ctx.clone());
+        let (plan, _) = planner.plan_sql(&query.to_string()).await?;
+        Ok(plan)
+    }
+
+    /// Get the segments added since the snapshot consumed by the last refresh.
+    ///
+    /// Returns `None` if some of the consumed segments are not referenced by
+    /// the current snapshot (e.g. the source table is compacted, or rows are
+    /// deleted), or the consumed snapshot can not be read any more.
+    #[async_backtrace::framed]
+    async fn new_segments(
+        &self,
+        source: &FuseTable,
+        consumed_location: &str,
+        snapshot: &TableSnapshot,
+    ) -> Result<Option<Vec<String>>> {
+        let consumed_segments = if consumed_location.is_empty() {
+            HashSet::new()
+        } else {
+            let reader = MetaReaders::table_snapshot_reader(source.get_operator());
+            let params = LoadParams {
+                location: consumed_location.to_string(),
+                len_hint: None,
+                ver: TableMetaLocationGenerator::snapshot_version(consumed_location),
+                put_cache: false,
+            };
+            match reader.read(&params).await {
+                Ok(consumed) => consumed
+                    .segments
+                    .iter()
+                    .map(|(location, _)| location.clone())
+                    .collect(),
+                Err(_) => return Ok(None),
+            }
+        };
+
+        let segments = snapshot
+            .segments
+            .iter()
+            .map(|(location, _)| location.clone())
+            .collect::<HashSet<_>>();
+        if !consumed_segments.is_subset(&segments) {
+            return Ok(None);
+        }
+
+        Ok(Some(
+            segments
+                .into_iter()
+                .filter(|location| !consumed_segments.contains(location))
+                .collect(),
+        ))
+    }
+}
+
+/// Record the snapshot of the source table consumed by the refresh.
+#[async_backtrace::framed]
+async fn update_refreshed_snapshot(
+    ctx: Arc<QueryContext>,
+    plan: RefreshMaterializedViewPlan,
+    snapshot_location: String,
+) -> Result<()> {
+    let catalog = ctx.get_catalog(&plan.catalog)?;
+    let view = catalog
+        .get_table(&plan.tenant, &plan.database, &plan.view_name)
+        .await?;
+
+    let mut options = HashMap::new();
+    options.insert(
+        OPT_KEY_MATERIALIZED_VIEW_SNAPSHOT.to_string(),
+        Some(snapshot_location),
+    );
+    options.insert(
+        OPT_KEY_MATERIALIZED_VIEW_REFRESHED_ON.to_string(),
+        Some(Utc::now().timestamp().to_string()),
+    );
+    let req = UpsertTableOptionReq {
+        table_id: view.get_id(),
+        seq: MatchSeq::Exact(view.get_table_info().ident.seq),
+        options,
+    };
+    catalog
+        .upsert_table_option(&plan.tenant, &plan.database, req)
+        .await?;
+    Ok(())
+}
+
+/// The view can be refreshed incrementally if its query is a plain
+/// projection (with optional filter) of the source table.
+fn is_incremental_plan(plan: &Plan) -> bool {
+    fn check(s_expr: &SExpr) -> bool {
+        match s_expr.plan() {
+            RelOperator::EvalScalar(_) | RelOperator::Filter(_) | RelOperator::Exchange(_) => {
+                s_expr.children().iter().all(|child| check(child))
+            }
+            RelOperator::Scan(_) => true,
+            _ => false,
+        }
+    }
+
+    match plan {
+        Plan::Query { s_expr, .. } => check(s_expr),
+        _ => false,
+    }
+}
+
+fn set_travel_point(query: &mut Query, snapshot_id: String) {
+    if let SetExpr::Select(stmt) = &mut query.body {
+        if let TableReference::Table { travel_point, .. } = &mut stmt.from[0] {
+            *travel_point = Some(TimeTravelPoint::Snapshot(snapshot_id));
+        }
+    }
+}
+
+/// Only read the rows of `segments` by filtering on the `_segment_name` internal column.
+fn add_segment_filter(query: &mut Query, segments: Vec<String>) {
+    if let SetExpr::Select(stmt) = &mut query.body {
+        let filter = Expr::InList {
+            span: None,
+            expr: Box::new(Expr::ColumnRef {
+                span: None,
+                database: None,
+                table: None,
+                column: ColumnID::Name(Identifier {
+                    name: SEGMENT_NAME_COL_NAME.to_string(),
+                    quote: None,
+                    span: None,
+                }),
+            }),
+            list: segments
+                .into_iter()
+                .map(|location| Expr::Literal {
+                    span: None,
+                    lit: Literal::String(location),
+                })
+                .collect(),
+            not: false,
+        };
+        stmt.selection = Some(match stmt.selection.take() {
+            Some(selection) => Expr::BinaryOp {
+                span: None,
+                op: BinaryOperator::And,
+                left: Box::new(selection),
+                right: Box::new(filter),
+            },
+            None => filter,
+        });
+    }
+}
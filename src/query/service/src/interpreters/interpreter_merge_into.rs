@@ -0,0 +1,119 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::DataField;
+use common_expression::DataSchema;
+use common_expression::DataSchemaRefExt;
+use common_sql::plans::MergeInto;
+use common_sql::plans::Plan;
+use common_sql::plans::MERGE_INTO_ACTION_COL_NAME;
+use common_sql::plans::MERGE_INTO_ROW_ID_COL_NAME;
+
+use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::interpreters::SelectInterpreter;
+use crate::pipelines::processors::TransformCastSchema;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct MergeIntoInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: MergeInto,
+}
+
+impl MergeIntoInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: MergeInto) -> Result<InterpreterPtr> {
+        Ok(Arc::new(MergeIntoInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for MergeIntoInterpreter {
+    fn name(&self) -> &str {
+        "MergeIntoInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        if check_deduplicate_label(self.ctx.clone()).await? {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        let plan = &self.plan;
+        let table = self
+            .ctx
+            .get_table(&plan.catalog, &plan.database, &plan.table)
+            .await?;
+
+        let (s_expr, metadata, bind_context) = match plan.input.as_ref() {
+            Plan::Query {
+                s_expr,
+                metadata,
+                bind_context,
+                ..
+            } => (s_expr, metadata, bind_context),
+            v => unreachable!("Input plan must be Query, but it's {}", v),
+        };
+        let select_interpreter = SelectInterpreter::try_create(
+            self.ctx.clone(),
+            *(bind_context.clone()),
+            *s_expr.clone(),
+            metadata.clone(),
+            false,
+        )?;
+        let mut build_res = select_interpreter.execute2().await?;
+
+        // The row id, the action, then the new values of the target table columns.
+        let table_schema = DataSchema::from(table.schema());
+        let mut fields = Vec::with_capacity(table_schema.num_fields() + 2);
+        fields.push(DataField::new(
+            MERGE_INTO_ROW_ID_COL_NAME,
+            DataType::Number(NumberDataType::UInt64).wrap_nullable(),
+        ));
+        fields.push(DataField::new(
+            MERGE_INTO_ACTION_COL_NAME,
+            DataType::Number(NumberDataType::UInt8),
+        ));
+        fields.extend(table_schema.fields().iter().cloned());
+        let target_schema = DataSchemaRefExt::create(fields);
+
+        let select_schema = plan.input.schema();
+        if select_schema != target_schema {
+            let func_ctx = self.ctx.get_function_context()?;
+            build_res.main_pipeline.add_transform(
+                |transform_input_port, transform_output_port| {
+                    TransformCastSchema::try_create(
+                        transform_input_port,
+                        transform_output_port,
+                        select_schema.clone(),
+                        target_schema.clone(),
+                        func_ctx.clone(),
+                    )
+                },
+            )?;
+        }
+
+        table
+            .merge_into(self.ctx.clone(), &mut build_res.main_pipeline)
+            .await?;
+        Ok(build_res)
+    }
+}
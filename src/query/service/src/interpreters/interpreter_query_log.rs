@@ -30,6 +30,7 @@ use tracing::error;
 use tracing::info;
 use tracing::subscriber;
 
+use crate::query_history::QueryHistoryService;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
 
@@ -59,7 +60,11 @@ fn error_fields(log_type: LogType, err: Option<ErrorCode>) -> (LogType, i32, Str
 }
 
 impl InterpreterQueryLog {
-    fn write_log(event: QueryLogElement) -> Result<()> {
+    fn write_log(ctx: &QueryContext, event: QueryLogElement) -> Result<()> {
+        if !matches!(event.log_type, LogType::Start) {
+            QueryHistoryService::instance().append(ctx, &event)?;
+        }
+
         let event_str = serde_json::to_string(&event)?;
         if let Some(logger) = QueryLogger::instance().get_subscriber() {
             subscriber::with_default(logger, || {
@@ -133,7 +138,7 @@ impl InterpreterQueryLog {
         let (log_type, exception_code, exception_text, stack_trace) =
             error_fields(LogType::Start, err);
 
-        Self::write_log(QueryLogElement {
+        Self::write_log(ctx, QueryLogElement {
             log_type,
             handler_type,
             tenant_id,
@@ -244,7 +249,7 @@ impl InterpreterQueryLog {
         let (log_type, exception_code, exception_text, stack_trace) =
             error_fields(LogType::Finish, err);
 
-        Self::write_log(QueryLogElement {
+        Self::write_log(ctx, QueryLogElement {
             log_type,
             handler_type,
             tenant_id,
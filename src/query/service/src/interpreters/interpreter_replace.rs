@@ -165,14 +165,13 @@ impl ReplaceInterpreter {
         self_schema: DataSchemaRef,
         query_plan: &Plan,
     ) -> Result<PipelineBuildResult> {
-        let (s_expr, metadata, bind_context, formatted_ast) = match query_plan {
+        let (s_expr, metadata, bind_context) = match query_plan {
             Plan::Query {
                 s_expr,
                 metadata,
                 bind_context,
-                formatted_ast,
                 ..
-            } => (s_expr, metadata, bind_context, formatted_ast),
+            } => (s_expr, metadata, bind_context),
             v => unreachable!("Input plan must be Query, but it's {}", v),
         };
 
@@ -181,7 +180,6 @@ impl ReplaceInterpreter {
             *(bind_context.clone()),
             *s_expr.clone(),
             metadata.clone(),
-            formatted_ast.clone(),
             false,
         )?;
 
@@ -12,6 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::Write;
+use std::hash::Hash;
+use std::hash::Hasher;
 use std::sync::Arc;
 
 use common_catalog::table::Table;
@@ -34,6 +38,7 @@ use common_storages_result_cache::gen_result_cache_key;
 use common_storages_result_cache::ResultCacheReader;
 use common_storages_result_cache::WriteResultCacheSink;
 use common_users::UserApiProvider;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -50,7 +55,6 @@ pub struct SelectInterpreter {
     s_expr: SExpr,
     bind_context: BindContext,
     metadata: MetadataRef,
-    formatted_ast: Option<String>,
     ignore_result: bool,
 }
 
@@ -60,7 +64,6 @@ impl SelectInterpreter {
         bind_context: BindContext,
         s_expr: SExpr,
         metadata: MetadataRef,
-        formatted_ast: Option<String>,
         ignore_result: bool,
     ) -> Result<Self> {
         Ok(SelectInterpreter {
@@ -68,7 +71,6 @@ impl SelectInterpreter {
             s_expr,
             bind_context,
             metadata,
-            formatted_ast,
             ignore_result,
         })
     }
@@ -96,6 +98,7 @@ impl SelectInterpreter {
     fn add_result_cache(
         &self,
         key: &str,
+        table_snapshots: Vec<String>,
         schema: TableSchemaRef,
         pipeline: &mut Pipeline,
         kv_store: Arc<MetaStore>,
@@ -150,6 +153,7 @@ impl SelectInterpreter {
                 self.ctx.clone(),
                 key,
                 schema,
+                table_snapshots,
                 sink_inputs.clone(),
                 kv_store,
             )?,
@@ -162,6 +166,58 @@ impl SelectInterpreter {
         Ok(())
     }
 
+    /// Build the fingerprint of the normalized plan, which is used to generate the query
+    /// result cache key. Data changes of the participating tables are detected by the
+    /// table snapshots stored in the cache value, see [`Self::table_snapshots`].
+    fn result_cache_fingerprint(&self) -> Result<String> {
+        let mut fingerprint = self.s_expr.to_format_tree(&self.metadata).format_pretty()?;
+
+        // The format tree omits the details of some operators, so the hash of the
+        // plan is also involved.
+        let mut hasher = DefaultHasher::new();
+        self.s_expr.hash(&mut hasher);
+        let _ = writeln!(fingerprint, "plan: {:x}", hasher.finish());
+
+        for column in self.bind_context.columns.iter() {
+            let _ = writeln!(
+                fingerprint,
+                "output: {}#{}",
+                column.column_name, column.index
+            );
+        }
+
+        let r_lock = self.metadata.read();
+        for entry in r_lock.tables() {
+            let _ = writeln!(
+                fingerprint,
+                "table: {}.{}.{}{:?}",
+                entry.catalog(),
+                entry.database(),
+                entry.name(),
+                entry.table().table_args(),
+            );
+        }
+
+        Ok(fingerprint)
+    }
+
+    /// The snapshot of each table participating in the query.
+    fn table_snapshots(&self) -> Vec<String> {
+        let r_lock = self.metadata.read();
+        r_lock
+            .tables()
+            .iter()
+            .map(|entry| {
+                let table = entry.table();
+                let table_info = table.get_table_info();
+                match table_info.options().get(OPT_KEY_SNAPSHOT_LOCATION) {
+                    Some(location) => location.clone(),
+                    None => format!("{}.{}", table_info.ident.table_id, table_info.ident.seq),
+                }
+            })
+            .collect()
+    }
+
     fn result_scan_table(&self) -> Result<Option<Arc<dyn Table>>> {
         let r_lock = self.metadata.read();
         let tables = r_lock.tables();
@@ -190,6 +246,17 @@ impl Interpreter for SelectInterpreter {
         self.bind_context.output_schema()
     }
 
+    // e.g. SHOW PROCESSLIST and SELECT * FROM system.query_queue.
+    fn bypass_workload_group(&self) -> bool {
+        let metadata = self.metadata.read();
+        let tables = metadata.tables();
+        !tables.is_empty()
+            && tables.iter().all(|t| {
+                t.database().eq_ignore_ascii_case("system")
+                    && matches!(t.name(), "processes" | "query_queue")
+            })
+    }
+
     /// This method will create a new pipeline
     /// The QueryPipelineBuilder will use the optimized plan to generate a Pipeline
     #[tracing::instrument(level = "debug", name = "select_interpreter_execute", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
@@ -198,7 +265,8 @@ impl Interpreter for SelectInterpreter {
         // 0. Need to build physical plan first to get the partitions.
         let physical_plan = self.build_physical_plan().await?;
         if self.ctx.get_settings().get_enable_query_result_cache()? && self.ctx.get_cacheable() {
-            let key = gen_result_cache_key(self.formatted_ast.as_ref().unwrap());
+            let key = gen_result_cache_key(&self.result_cache_fingerprint()?);
+            let table_snapshots = self.table_snapshots();
             // 1. Try to get result from cache.
             let kv_store = UserApiProvider::instance().get_meta_store_client();
 
@@ -225,6 +293,7 @@ impl Interpreter for SelectInterpreter {
             let cache_reader = ResultCacheReader::create(
                 self.ctx.clone(),
                 &key,
+                table_snapshots.clone(),
                 kv_store.clone(),
                 self.ctx
                     .get_settings()
@@ -244,7 +313,13 @@ impl Interpreter for SelectInterpreter {
                     let mut build_res = self.build_pipeline(physical_plan).await?;
                     // 2.2 If not found result in cache, add pipelines to write the result to cache.
                     let schema = infer_table_schema(&self.schema())?;
-                    self.add_result_cache(&key, schema, &mut build_res.main_pipeline, kv_store)?;
+                    self.add_result_cache(
+                        &key,
+                        table_snapshots,
+                        schema,
+                        &mut build_res.main_pipeline,
+                        kv_store,
+                    )?;
                     return Ok(build_res);
                 }
                 Err(e) => {
@@ -42,6 +42,10 @@ impl Interpreter for SettingInterpreter {
         "SettingInterpreter"
     }
 
+    fn bypass_workload_group(&self) -> bool {
+        true
+    }
+
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let plan = self.set.clone();
@@ -0,0 +1,103 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::AddTableRowAccessPolicyPlan;
+use common_storages_view::view_table::VIEW_ENGINE;
+use common_users::UserApiProvider;
+use storages_common_table_meta::table::OPT_KEY_ROW_ACCESS_POLICY;
+use storages_common_table_meta::table::OPT_KEY_ROW_ACCESS_POLICY_COLUMNS;
+
+use super::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct AddTableRowAccessPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AddTableRowAccessPolicyPlan,
+}
+
+impl AddTableRowAccessPolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AddTableRowAccessPolicyPlan) -> Result<Self> {
+        Ok(AddTableRowAccessPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AddTableRowAccessPolicyInterpreter {
+    fn name(&self) -> &str {
+        "AddTableRowAccessPolicyInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog(&plan.catalog)?;
+        let table = catalog
+            .get_table(&plan.tenant, &plan.database, &plan.table)
+            .await?;
+
+        if table.engine() == VIEW_ENGINE {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} is a view, row access policies can only be added to tables",
+                &plan.database, &plan.table
+            )));
+        }
+        if let Some(policy) = table.options().get(OPT_KEY_ROW_ACCESS_POLICY) {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "table {}.{} already has row access policy {}",
+                &plan.database, &plan.table, policy
+            )));
+        }
+
+        let policy = UserApiProvider::instance()
+            .get_row_access_policy(&plan.tenant, &plan.policy)
+            .await?;
+        if policy.args.len() != plan.columns.len() {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "row access policy {} expects {} columns, but got {}",
+                policy.name,
+                policy.args.len(),
+                plan.columns.len()
+            )));
+        }
+
+        let mut options = HashMap::new();
+        options.insert(
+            OPT_KEY_ROW_ACCESS_POLICY.to_string(),
+            Some(plan.policy.clone()),
+        );
+        options.insert(
+            OPT_KEY_ROW_ACCESS_POLICY_COLUMNS.to_string(),
+            Some(plan.columns.join(",")),
+        );
+        let req = UpsertTableOptionReq {
+            table_id: table.get_id(),
+            seq: MatchSeq::Exact(table.get_table_info().ident.seq),
+            options,
+        };
+        catalog
+            .upsert_table_option(&plan.tenant, &plan.database, req)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -13,45 +13,84 @@
 // limitations under the License.
 
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::DataBlock;
+use common_expression::ScalarRef;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
 use common_expression::TableSchemaRefExt;
 use common_license::license_manager::get_license_manager;
+use common_meta_app::principal::StageType;
 use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::DropTableByIdReq;
 use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::TableNameIdent;
 use common_meta_app::schema::TableStatistics;
+use common_meta_app::schema::UpsertTableOptionReq;
 use common_meta_types::MatchSeq;
 use common_sql::binder::INTERNAL_COLUMN_FACTORY;
 use common_sql::field_default_value;
 use common_sql::plans::CreateTablePlan;
+use common_sql::resolve_type_name_by_str;
+use common_storage::init_operator;
+use common_storages_fuse::io::parse_inverted_index_columns;
+use common_storages_fuse::io::parse_vector_index_columns;
 use common_storages_fuse::io::MetaReaders;
+use common_storages_fuse::io::TableMetaLocationGenerator;
+use common_storages_fuse::operations::parse_table_clones;
+use common_storages_fuse::FuseTable;
+use common_storages_fuse::FUSE_OPT_KEY_AUTO_COMPACT;
 use common_storages_fuse::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use common_storages_fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
+use common_storages_fuse::FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS;
+use common_storages_fuse::FUSE_OPT_KEY_INVERTED_INDEX_COLUMNS;
+use common_storages_fuse::FUSE_OPT_KEY_MAX_PREFETCH_BLOCKS;
+use common_storages_fuse::FUSE_OPT_KEY_NOTIFICATION_INTEGRATION;
+use common_storages_fuse::FUSE_OPT_KEY_REPLICATION_STAGE;
 use common_storages_fuse::FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD;
 use common_storages_fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use common_storages_fuse::FUSE_OPT_KEY_ROW_PER_PAGE;
+use common_storages_fuse::FUSE_OPT_KEY_SEGMENT_MAX_BLOCKS;
+use common_storages_fuse::FUSE_OPT_KEY_VARIANT_SHREDDING;
+use common_storages_fuse::FUSE_OPT_KEY_VECTOR_INDEX_COLUMNS;
+use common_storages_fuse::MAX_DATA_RETENTION_PERIOD_IN_HOURS;
+use common_storages_parquet::EXTERNAL_OPT_KEY_PARTITION_REFRESH_INTERVAL;
 use common_users::UserApiProvider;
+use futures_util::TryStreamExt;
 use once_cell::sync::Lazy;
 use storages_common_cache::LoadParams;
-use storages_common_table_meta::meta::TableSnapshot;
-use storages_common_table_meta::meta::Versioned;
+use storages_common_table_meta::table::parse_unique_keys;
+use storages_common_table_meta::table::split_table_data_uri;
+use storages_common_table_meta::table::OPT_KEY_CLONE_SOURCE_TABLE_ID;
 use storages_common_table_meta::table::OPT_KEY_COMMENT;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use storages_common_table_meta::table::OPT_KEY_ENGINE;
 use storages_common_table_meta::table::OPT_KEY_EXTERNAL_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
+use storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
+use storages_common_table_meta::table::OPT_KEY_TABLE_CLONES;
 use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+use storages_common_table_meta::table::OPT_KEY_TRANSIENT;
+use storages_common_table_meta::table::OPT_KEY_UNIQUE_KEYS;
 use tracing::error;
 
 use crate::interpreters::InsertInterpreter;
 use crate::interpreters::Interpreter;
+use crate::interpreters::SelectInterpreter;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelinePullingExecutor;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
@@ -59,6 +98,7 @@ use crate::sql::plans::insert::Insert;
 use crate::sql::plans::insert::InsertInputSource;
 use crate::sql::plans::Plan;
 use crate::storages::StorageDescription;
+use crate::stream::PullingExecutorStream;
 
 pub struct CreateTableInterpreter {
     ctx: Arc<QueryContext>,
@@ -79,6 +119,17 @@ impl Interpreter for CreateTableInterpreter {
 
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
+        if let Some(template) = &self.plan.template {
+            let plan = CreateTablePlan {
+                schema: self.schema_from_template(template).await?,
+                template: None,
+                ..self.plan.clone()
+            };
+            return CreateTableInterpreter::try_create(self.ctx.clone(), plan)?
+                .execute2()
+                .await;
+        }
+
         let tenant = self.plan.tenant.clone();
         let has_computed_column = self
             .plan
@@ -132,14 +183,128 @@ impl Interpreter for CreateTableInterpreter {
             }
         }
 
+        is_valid_replication_stage(&tenant, &self.plan.options).await?;
+        is_valid_notification_integration(&tenant, &self.plan.options).await?;
+
+        // The sequences may be left if the table fails to be created, they are only
+        // referenced by the defaults of the identity columns.
+        for sequence in &self.plan.identity_sequences {
+            UserApiProvider::instance()
+                .add_sequence(&tenant, sequence.clone(), true)
+                .await?;
+        }
+
         match &self.plan.as_select {
             Some(select_plan_node) => self.create_table_as_select(select_plan_node.clone()).await,
+            None if self
+                .plan
+                .options
+                .contains_key(OPT_KEY_TABLE_ATTACHED_DATA_URI) =>
+            {
+                self.attach_table().await
+            }
             None => self.create_table().await,
         }
     }
 }
 
 impl CreateTableInterpreter {
+    /// Runs the `USING TEMPLATE` query, each row of the result is a column of the table,
+    /// given by `column_name`, `type` and the optional `nullable`, like `infer_schema`.
+    #[async_backtrace::framed]
+    async fn schema_from_template(&self, template: &Plan) -> Result<TableSchemaRef> {
+        let (s_expr, metadata, bind_context) = match template {
+            Plan::Query {
+                s_expr,
+                metadata,
+                bind_context,
+                ..
+            } => (s_expr, metadata, bind_context),
+            _ => {
+                return Err(ErrorCode::Internal(
+                    "Logical error, USING TEMPLATE must be a query.",
+                ));
+            }
+        };
+        let column_offset = |name: &str| {
+            bind_context
+                .columns
+                .iter()
+                .position(|c| c.column_name.eq_ignore_ascii_case(name))
+        };
+        let (name_offset, type_offset) = match (column_offset("column_name"), column_offset("type"))
+        {
+            (Some(name_offset), Some(type_offset)) => (name_offset, type_offset),
+            _ => {
+                return Err(ErrorCode::BadArguments(
+                    "USING TEMPLATE query must return the columns `column_name` and `type`",
+                ));
+            }
+        };
+        let nullable_offset = column_offset("nullable");
+
+        let select_interpreter = SelectInterpreter::try_create(
+            self.ctx.clone(),
+            *bind_context.clone(),
+            *s_expr.clone(),
+            metadata.clone(),
+            false,
+        )?;
+        let physical_plan = select_interpreter.build_physical_plan().await?;
+        let build_res = select_interpreter.build_pipeline(physical_plan).await?;
+        let settings = ExecutorSettings::try_create(&self.ctx.get_settings(), self.ctx.get_id())?;
+        let pulling_executor = PipelinePullingExecutor::from_pipelines(build_res, settings)?;
+        self.ctx.set_executor(pulling_executor.get_inner())?;
+        let blocks = PullingExecutorStream::create(pulling_executor)?
+            .try_collect::<Vec<DataBlock>>()
+            .await?;
+
+        let string_value = |block: &DataBlock, offset: usize, row: usize| match block
+            .get_by_offset(offset)
+            .value
+            .index(row)
+        {
+            Some(ScalarRef::String(s)) => Ok(String::from_utf8_lossy(s).to_string()),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "USING TEMPLATE query must return `{}` as a non-null string",
+                bind_context.columns[offset].column_name
+            ))),
+        };
+        let mut fields = vec![];
+        let mut names = HashSet::new();
+        for block in &blocks {
+            for row in 0..block.num_rows() {
+                let name = string_value(block, name_offset, row)?;
+                let data_type = resolve_type_name_by_str(&string_value(block, type_offset, row)?)?;
+                let nullable = match nullable_offset {
+                    Some(offset) => matches!(
+                        block.get_by_offset(offset).value.index(row),
+                        Some(ScalarRef::Boolean(true))
+                    ),
+                    None => false,
+                };
+                let data_type = if nullable {
+                    data_type.wrap_nullable()
+                } else {
+                    data_type
+                };
+                if !names.insert(name.clone()) {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Duplicated column name: {}",
+                        name
+                    )));
+                }
+                fields.push(TableField::new(&name, data_type));
+            }
+        }
+        if fields.is_empty() {
+            return Err(ErrorCode::BadArguments(
+                "USING TEMPLATE query returns no column",
+            ));
+        }
+        Ok(TableSchemaRefExt::create(fields))
+    }
+
     #[async_backtrace::framed]
     async fn create_table_as_select(&self, select_plan: Box<Plan>) -> Result<PipelineBuildResult> {
         let tenant = self.ctx.get_tenant();
@@ -190,7 +355,7 @@ impl CreateTableInterpreter {
                 let params = LoadParams {
                     location: snapshot_loc.clone(),
                     len_hint: None,
-                    ver: TableSnapshot::VERSION,
+                    ver: TableMetaLocationGenerator::snapshot_version(snapshot_loc),
                     put_cache: true,
                 };
 
@@ -205,7 +370,118 @@ impl CreateTableInterpreter {
                 });
             }
         }
-        catalog.create_table(self.build_request(stat)?).await?;
+        let reply = catalog.create_table(self.build_request(stat)?).await?;
+        if reply.new_table {
+            if let Some(source_id) = self.plan.options.get(OPT_KEY_CLONE_SOURCE_TABLE_ID) {
+                // a clone unknown to its source may lose the shared files when the source
+                // is purged, so it's dropped if the registration fails.
+                if let Err(cause) = self.register_table_clone(source_id, reply.table_id).await {
+                    let req = DropTableByIdReq {
+                        if_exists: true,
+                        tb_id: reply.table_id,
+                    };
+                    if let Err(e) = catalog.drop_table_by_id(req).await {
+                        error!(
+                            "failed to drop the clone {} after its registration failed: {}",
+                            reply.table_id, e
+                        );
+                    }
+                    return Err(cause);
+                }
+            }
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+
+    /// Adds the clone to the `table_clones` option of its source table, so that the files
+    /// shared with the clone are kept when the source table is purged.
+    #[async_backtrace::framed]
+    async fn register_table_clone(&self, source_id: &str, clone_id: u64) -> Result<()> {
+        let catalog = self.ctx.get_catalog(self.plan.catalog.as_str())?;
+        let source_id = source_id.parse::<u64>().map_err(|_| {
+            ErrorCode::Internal(format!("Invalid source table id of the clone: {source_id}"))
+        })?;
+        // the option may be updated concurrently by another clone, retry on seq mismatch.
+        let mut retries = 0;
+        loop {
+            let (ident, meta) = catalog.get_table_meta_by_id(source_id).await?;
+            let mut clones = parse_table_clones(meta.options.get(OPT_KEY_TABLE_CLONES));
+            clones.push(clone_id);
+            let clones = clones
+                .iter()
+                .map(|id| id.to_string())
+                .collect::<Vec<_>>()
+                .join(",");
+            let req = UpsertTableOptionReq {
+                table_id: source_id,
+                seq: MatchSeq::Exact(ident.seq),
+                options: HashMap::from([(OPT_KEY_TABLE_CLONES.to_string(), Some(clones))]),
+            };
+            match catalog
+                .upsert_table_option(&self.plan.tenant, &self.plan.database, req)
+                .await
+            {
+                Err(e) if e.code() == ErrorCode::TABLE_VERSION_MISMATCHED && retries < 10 => {
+                    retries += 1;
+                }
+                Err(e) => return Err(e),
+                Ok(_) => return Ok(()),
+            }
+        }
+    }
+
+    /// Creates the table of `ATTACH TABLE`, the schema is taken from the latest snapshot of
+    /// the attached table, it's not changed by the later commits of the writer.
+    #[async_backtrace::framed]
+    async fn attach_table(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_catalog(self.plan.catalog.as_str())?;
+        let storage_params = self
+            .plan
+            .storage_params
+            .clone()
+            .ok_or_else(|| ErrorCode::Internal("Attached table should have storage params"))?;
+        let operator = init_operator(&storage_params)?;
+        let data_uri = &self.plan.options[OPT_KEY_TABLE_ATTACHED_DATA_URI];
+        let (_, prefix) = split_table_data_uri(data_uri).ok_or_else(|| {
+            ErrorCode::BadArguments(format!("Invalid data uri of attached table: {data_uri}"))
+        })?;
+        let location_generator = TableMetaLocationGenerator::with_prefix(prefix);
+        let snapshot_loc =
+            FuseTable::read_attached_snapshot_location(&operator, &location_generator)
+                .await?
+                .ok_or_else(|| {
+                    ErrorCode::BadArguments(format!(
+                        "No snapshot of the attached table is found at {data_uri}"
+                    ))
+                })?;
+
+        let reader = MetaReaders::table_snapshot_reader(operator);
+        let params = LoadParams {
+            ver: TableMetaLocationGenerator::snapshot_version(&snapshot_loc),
+            location: snapshot_loc,
+            len_hint: None,
+            put_cache: true,
+        };
+        let snapshot = reader.read(&params).await?;
+
+        let table_meta = TableMeta {
+            schema: Arc::new(snapshot.schema.clone()),
+            engine: self.plan.engine.to_string(),
+            storage_params: Some(storage_params),
+            options: self.plan.options.clone(),
+            ..Default::default()
+        };
+        let req = CreateTableReq {
+            if_not_exists: self.plan.if_not_exists,
+            name_ident: TableNameIdent {
+                tenant: self.plan.tenant.to_string(),
+                db_name: self.plan.database.to_string(),
+                table_name: self.plan.table.to_string(),
+            },
+            table_meta,
+        };
+        catalog.create_table(req).await?;
 
         Ok(PipelineBuildResult::create())
     }
@@ -246,7 +522,24 @@ impl CreateTableInterpreter {
             ..Default::default()
         };
 
+        // `transient = true` is the same as `CREATE TRANSIENT TABLE`.
+        if let Some(value) = table_meta.options.remove("transient") {
+            if parse_transient_option(&value)? {
+                table_meta
+                    .options
+                    .insert(OPT_KEY_TRANSIENT.to_string(), "T".to_string());
+            }
+        }
+
         is_valid_block_per_segment(&table_meta.options)?;
+        is_valid_segment_max_blocks(&table_meta.options)?;
+        is_valid_auto_compact(&table_meta.options)?;
+        is_valid_data_retention_period(&table_meta.options)?;
+        is_valid_partition_refresh_interval(&table_meta.options)?;
+        is_valid_variant_shredding(&table_meta.options)?;
+        is_valid_inverted_index_columns(&table_meta.options, &table_meta.schema)?;
+        is_valid_vector_index_columns(&table_meta.options, &table_meta.schema)?;
+        is_valid_unique_keys(&table_meta.options, &table_meta.schema)?;
 
         for table_option in table_meta.options.iter() {
             let key = table_option.0.to_lowercase();
@@ -284,12 +577,25 @@ pub static CREATE_TABLE_OPTIONS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     r.insert(FUSE_OPT_KEY_ROW_PER_BLOCK);
     r.insert(FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD);
     r.insert(FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD);
+    r.insert(FUSE_OPT_KEY_MAX_PREFETCH_BLOCKS);
+    r.insert(FUSE_OPT_KEY_SEGMENT_MAX_BLOCKS);
+    r.insert(FUSE_OPT_KEY_AUTO_COMPACT);
+    r.insert(FUSE_OPT_KEY_VARIANT_SHREDDING);
+    r.insert(FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS);
+    r.insert(FUSE_OPT_KEY_REPLICATION_STAGE);
+    r.insert(FUSE_OPT_KEY_NOTIFICATION_INTEGRATION);
+    r.insert(FUSE_OPT_KEY_INVERTED_INDEX_COLUMNS);
+    r.insert(FUSE_OPT_KEY_VECTOR_INDEX_COLUMNS);
+    r.insert(OPT_KEY_UNIQUE_KEYS);
 
     r.insert(OPT_KEY_SNAPSHOT_LOCATION);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
     r.insert(OPT_KEY_TABLE_COMPRESSION);
     r.insert(OPT_KEY_STORAGE_FORMAT);
     r.insert(OPT_KEY_DATABASE_ID);
+    r.insert(OPT_KEY_CLONE_SOURCE_TABLE_ID);
+    r.insert(OPT_KEY_PARTITION_BY);
+    r.insert(EXTERNAL_OPT_KEY_PARTITION_REFRESH_INTERVAL);
 
     r.insert(OPT_KEY_COMMENT);
     r.insert(OPT_KEY_EXTERNAL_LOCATION);
@@ -315,3 +621,214 @@ pub fn is_valid_block_per_segment(options: &BTreeMap<String, String>) -> Result<
     }
     Ok(())
 }
+
+pub fn is_valid_segment_max_blocks(options: &BTreeMap<String, String>) -> Result<()> {
+    // check segment_max_blocks is not over 1000.
+    if let Some(value) = options.get(FUSE_OPT_KEY_SEGMENT_MAX_BLOCKS) {
+        let segment_max_blocks = value.parse::<u64>()?;
+        let error_str = "invalid segment_max_blocks option, can't be over 1000";
+        if segment_max_blocks > 1000 {
+            error!(error_str);
+            return Err(ErrorCode::TableOptionInvalid(error_str));
+        }
+    }
+    Ok(())
+}
+
+pub fn is_valid_auto_compact(options: &BTreeMap<String, String>) -> Result<()> {
+    // check auto_compact is a boolean.
+    if let Some(value) = options.get(FUSE_OPT_KEY_AUTO_COMPACT) {
+        if value.parse::<bool>().is_err() {
+            let error_str = "invalid auto_compact option, must be true or false";
+            error!(error_str);
+            return Err(ErrorCode::TableOptionInvalid(error_str));
+        }
+    }
+    Ok(())
+}
+
+/// Parse the value of the `transient` option, `T` is how `SHOW CREATE TABLE` shows it.
+pub fn parse_transient_option(value: &str) -> Result<bool> {
+    if value.eq_ignore_ascii_case("t") {
+        return Ok(true);
+    }
+    value.to_lowercase().parse::<bool>().map_err(|_| {
+        let error_str = "invalid transient option, must be true or false";
+        error!(error_str);
+        ErrorCode::TableOptionInvalid(error_str)
+    })
+}
+
+pub fn is_valid_data_retention_period(options: &BTreeMap<String, String>) -> Result<()> {
+    // check data_retention_period_in_hours is a number of hours, at most 90 days.
+    if let Some(value) = options.get(FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS) {
+        match value.parse::<u64>() {
+            Ok(hours) if hours <= MAX_DATA_RETENTION_PERIOD_IN_HOURS => {}
+            _ => {
+                let error_str = format!(
+                    "invalid data_retention_period_in_hours option, must be an integer between 0 and {}",
+                    MAX_DATA_RETENTION_PERIOD_IN_HOURS
+                );
+                error!("{}", error_str);
+                return Err(ErrorCode::TableOptionInvalid(error_str));
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn is_valid_partition_refresh_interval(options: &BTreeMap<String, String>) -> Result<()> {
+    // check partition_refresh_interval_in_secs is a number of seconds.
+    if let Some(value) = options.get(EXTERNAL_OPT_KEY_PARTITION_REFRESH_INTERVAL) {
+        if value.parse::<u64>().is_err() {
+            let error_str =
+                "invalid partition_refresh_interval_in_secs option, must be a non-negative integer";
+            error!(error_str);
+            return Err(ErrorCode::TableOptionInvalid(error_str));
+        }
+    }
+    Ok(())
+}
+
+/// The replication stage should be an external stage, the empty value disables the
+/// replication of the table.
+pub async fn is_valid_replication_stage(
+    tenant: &str,
+    options: &BTreeMap<String, String>,
+) -> Result<()> {
+    let stage_name = match options.get(FUSE_OPT_KEY_REPLICATION_STAGE) {
+        Some(stage_name) if !stage_name.is_empty() => stage_name,
+        _ => return Ok(()),
+    };
+    let stage = UserApiProvider::instance()
+        .get_stage(tenant, stage_name)
+        .await?;
+    if stage.stage_type != StageType::External {
+        return Err(ErrorCode::TableOptionInvalid(format!(
+            "invalid {FUSE_OPT_KEY_REPLICATION_STAGE}: stage {stage_name} is not an external stage"
+        )));
+    }
+    Ok(())
+}
+
+/// The notification integration should exist, the empty value unsubscribes the table
+/// from the notifications of its commits.
+pub async fn is_valid_notification_integration(
+    tenant: &str,
+    options: &BTreeMap<String, String>,
+) -> Result<()> {
+    match options.get(FUSE_OPT_KEY_NOTIFICATION_INTEGRATION) {
+        Some(name) if !name.is_empty() => {
+            UserApiProvider::instance()
+                .get_notification_integration(tenant, name)
+                .await?;
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+pub fn is_valid_variant_shredding(options: &BTreeMap<String, String>) -> Result<()> {
+    // check variant_shredding is a boolean, and only enabled in native storage format.
+    if let Some(value) = options.get(FUSE_OPT_KEY_VARIANT_SHREDDING) {
+        let variant_shredding = match value.parse::<bool>() {
+            Ok(variant_shredding) => variant_shredding,
+            Err(_) => {
+                let error_str = "invalid variant_shredding option, must be true or false";
+                error!(error_str);
+                return Err(ErrorCode::TableOptionInvalid(error_str));
+            }
+        };
+        let is_native = options
+            .get(OPT_KEY_STORAGE_FORMAT)
+            .map(|v| v.eq_ignore_ascii_case("native"))
+            .unwrap_or(false);
+        if variant_shredding && !is_native {
+            let error_str = "invalid variant_shredding option, only support native storage format";
+            error!(error_str);
+            return Err(ErrorCode::TableOptionInvalid(error_str));
+        }
+    }
+    Ok(())
+}
+
+pub fn is_valid_inverted_index_columns(
+    options: &BTreeMap<String, String>,
+    schema: &TableSchema,
+) -> Result<()> {
+    // check inverted_index_columns are string columns of the table.
+    if let Some(value) = options.get(FUSE_OPT_KEY_INVERTED_INDEX_COLUMNS) {
+        for name in parse_inverted_index_columns(value) {
+            let field = schema.field_with_name(&name).map_err(|_| {
+                let error_str =
+                    format!("invalid inverted_index_columns option, column {name} not found");
+                error!("{}", error_str);
+                ErrorCode::TableOptionInvalid(error_str)
+            })?;
+            if field.data_type().remove_nullable() != TableDataType::String {
+                let error_str = format!(
+                    "invalid inverted_index_columns option, column {name} must be of type String"
+                );
+                error!("{}", error_str);
+                return Err(ErrorCode::TableOptionInvalid(error_str));
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn is_valid_vector_index_columns(
+    options: &BTreeMap<String, String>,
+    schema: &TableSchema,
+) -> Result<()> {
+    // check vector_index_columns are float32 array columns of the table.
+    if let Some(value) = options.get(FUSE_OPT_KEY_VECTOR_INDEX_COLUMNS) {
+        let vector_type =
+            TableDataType::Array(Box::new(TableDataType::Number(NumberDataType::Float32)));
+        for name in parse_vector_index_columns(value) {
+            let field = schema.field_with_name(&name).map_err(|_| {
+                let error_str =
+                    format!("invalid vector_index_columns option, column {name} not found");
+                error!("{}", error_str);
+                ErrorCode::TableOptionInvalid(error_str)
+            })?;
+            if field.data_type().remove_nullable() != vector_type {
+                let error_str = format!(
+                    "invalid vector_index_columns option, column {name} must be of type Array(Float32)"
+                );
+                error!("{}", error_str);
+                return Err(ErrorCode::TableOptionInvalid(error_str));
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn is_valid_unique_keys(
+    options: &BTreeMap<String, String>,
+    schema: &TableSchema,
+) -> Result<()> {
+    // check unique_keys are columns of the table.
+    if let Some(value) = options.get(OPT_KEY_UNIQUE_KEYS) {
+        let keys = parse_unique_keys(value);
+        if keys.is_empty() {
+            let error_str = "invalid unique_keys option, no key is given";
+            error!(error_str);
+            return Err(ErrorCode::TableOptionInvalid(error_str));
+        }
+        for name in keys.iter().flatten() {
+            let field = schema.field_with_name(name).map_err(|_| {
+                let error_str = format!("invalid unique_keys option, column {name} not found");
+                error!("{}", error_str);
+                ErrorCode::TableOptionInvalid(error_str)
+            })?;
+            if field.computed_expr().is_some() {
+                let error_str =
+                    format!("invalid unique_keys option, column {name} is a computed column");
+                error!("{}", error_str);
+                return Err(ErrorCode::TableOptionInvalid(error_str));
+            }
+        }
+    }
+    Ok(())
+}
@@ -16,15 +16,22 @@ use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use common_ast::ast::ModifyColumnAction;
+use common_ast::ast::TypeName;
 use common_catalog::table::Table;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::ComputedExpr;
+use common_expression::DataSchemaRefExt;
+use common_functions::BUILTIN_FUNCTIONS;
 use common_license::license_manager::get_license_manager;
 use common_meta_app::schema::DatabaseType;
 use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_types::MatchSeq;
+use common_sql::parse_computed_expr;
 use common_sql::plans::ModifyTableColumnPlan;
+use common_sql::resolve_type_name;
+use common_storages_fuse::FuseTable;
 use common_storages_share::save_share_table_info;
 use common_storages_view::view_table::VIEW_ENGINE;
 use common_users::UserApiProvider;
@@ -92,6 +99,76 @@ impl ModifyTableColumnInterpreter {
         new_table_meta.column_mask_policy = Some(column_mask_policy);
         Ok(new_table_meta)
     }
+
+    // Set data type of a column, the new data type must be losslessly castable from
+    // the old one, the blocks written before are cast to the new data type on read.
+    fn do_set_data_type(
+        &self,
+        table: &Arc<dyn Table>,
+        table_meta: TableMeta,
+        type_name: &TypeName,
+    ) -> Result<TableMeta> {
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        if fuse_table.is_native() {
+            return Err(ErrorCode::Unimplemented(format!(
+                "{}.{} with native storage format doesn't support modify column data type",
+                &self.plan.database, &self.plan.table
+            )));
+        }
+
+        let column = self.plan.column.as_str();
+        let table_schema = table_meta.schema.clone();
+        table_schema.field_with_name(column)?;
+
+        if let Some(column_mask_policy) = &table_meta.column_mask_policy {
+            if column_mask_policy.contains_key(column) {
+                return Err(ErrorCode::UnmatchColumnDataType(format!(
+                    "column `{}` has a masking policy, it can not modify data type",
+                    column
+                )));
+            }
+        }
+
+        // The cluster statistics of the old blocks are built with the old data type.
+        for cluster_key in table.cluster_keys(self.ctx.clone()) {
+            let expr = cluster_key.as_expr(&BUILTIN_FUNCTIONS);
+            if expr.column_refs().contains_key(column) {
+                return Err(ErrorCode::InvalidClusterKeys(format!(
+                    "column `{}` is referenced by the cluster key, it can not modify data type",
+                    column
+                )));
+            }
+        }
+
+        // Check if this column is referenced by computed columns.
+        let fields = table_schema
+            .fields()
+            .iter()
+            .filter(|f| f.name != column)
+            .map(|f| f.into())
+            .collect::<Vec<_>>();
+        let schema = DataSchemaRefExt::create(fields);
+        for f in schema.fields() {
+            if let Some(computed_expr) = f.computed_expr() {
+                let expr = match computed_expr {
+                    ComputedExpr::Stored(expr) => expr.clone(),
+                    ComputedExpr::Virtual(expr) => expr.clone(),
+                };
+                if parse_computed_expr(self.ctx.clone(), schema.clone(), &expr).is_err() {
+                    return Err(ErrorCode::ColumnReferencedByComputedColumn(format!(
+                        "column `{}` is referenced by computed column `{}`",
+                        column,
+                        &f.name()
+                    )));
+                }
+            }
+        }
+
+        let data_type = resolve_type_name(type_name)?;
+        let mut new_table_meta = table_meta;
+        new_table_meta.modify_column_type(column, data_type)?;
+        Ok(new_table_meta)
+    }
 }
 
 #[async_trait::async_trait]
@@ -136,13 +213,14 @@ impl Interpreter for ModifyTableColumnInterpreter {
         let catalog = self.ctx.get_catalog(catalog_name)?;
         let table_meta = table.get_table_info().meta.clone();
 
-        // NOTICE: if we support modify column data type,
-        // need to check whether this column is referenced by other computed columns.
         let new_table_meta = match &self.plan.action {
             ModifyColumnAction::SetMaskingPolicy(mask_name) => {
                 self.do_set_data_mask_policy(table, table_meta, mask_name.clone())
                     .await?
             }
+            ModifyColumnAction::SetDataType(type_name) => {
+                self.do_set_data_type(table, table_meta, type_name)?
+            }
         };
 
         let table_id = table_info.ident.table_id;
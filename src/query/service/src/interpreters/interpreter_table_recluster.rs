@@ -14,9 +14,15 @@
 
 use std::sync::Arc;
 use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
 use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
 use common_exception::Result;
+use common_storages_fuse::table_functions::ClusteringInformation;
+use common_storages_fuse::FuseTable;
+use common_storages_system::ReclusterProgressLogElement;
+use common_storages_system::ReclusterProgressQueue;
 
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterClusteringHistory;
@@ -49,8 +55,6 @@ impl Interpreter for ReclusterTableInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let plan = &self.plan;
         let ctx = self.ctx.clone();
-        let settings = ctx.get_settings();
-        let tenant = ctx.get_tenant();
         let start = SystemTime::now();
 
         // Build extras via push down scalar
@@ -75,6 +79,36 @@ impl Interpreter for ReclusterTableInterpreter {
             tracing::info!(status);
         }
         let mut times = 0;
+        let res = self.run_recluster(extras, start, &mut times).await;
+        let status = match &res {
+            Ok(_) => "Finished",
+            Err(_) => "Interrupted",
+        };
+        self.write_progress(times, status).await?;
+        res?;
+
+        InterpreterClusteringHistory::write_log(&ctx, start, &plan.database, &plan.table)?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
+
+impl ReclusterTableInterpreter {
+    /// Run the recluster tasks until the table is well clustered, every task commits
+    /// a new snapshot.
+    ///
+    /// The job is bounded by the `limit` iterations of the plan if it is final.
+    #[async_backtrace::framed]
+    async fn run_recluster(
+        &self,
+        extras: Option<PushDownInfo>,
+        start: SystemTime,
+        times: &mut u64,
+    ) -> Result<()> {
+        let plan = &self.plan;
+        let ctx = self.ctx.clone();
+        let settings = ctx.get_settings();
+        let tenant = ctx.get_tenant();
         loop {
             let table = self
                 .ctx
@@ -99,7 +133,7 @@ impl Interpreter for ReclusterTableInterpreter {
             ctx.set_executor(executor.get_inner())?;
             executor.execute()?;
 
-            times += 1;
+            *times += 1;
             // Status.
             {
                 let status = format!(
@@ -110,14 +144,54 @@ impl Interpreter for ReclusterTableInterpreter {
                 ctx.set_status_info(&status);
                 tracing::info!(status);
             }
+            self.write_progress(*times, "Running").await?;
 
-            if !plan.is_final {
+            if !plan.is_final || plan.limit.is_some_and(|limit| *times >= limit as u64) {
                 break;
             }
         }
+        Ok(())
+    }
 
-        InterpreterClusteringHistory::write_log(&ctx, start, &plan.database, &plan.table)?;
+    /// Log the progress of the recluster job to `system.recluster_progress`, with the
+    /// clustering statistics of the latest snapshot.
+    #[async_backtrace::framed]
+    async fn write_progress(&self, iteration: u64, status: &str) -> Result<()> {
+        let plan = &self.plan;
+        let table = self
+            .ctx
+            .get_catalog(&plan.catalog)?
+            .get_table(self.ctx.get_tenant().as_str(), &plan.database, &plan.table)
+            .await?;
+        let (average_overlaps, average_depth) = match FuseTable::try_from_table(table.as_ref()) {
+            Ok(fuse_table) => {
+                let cluster_keys = fuse_table.cluster_keys(self.ctx.clone());
+                // The blocks without the statistics of the cluster keys are not counted.
+                ClusteringInformation::new(
+                    self.ctx.clone(),
+                    fuse_table,
+                    String::new(),
+                    cluster_keys,
+                )
+                .get_average_overlaps_and_depth()
+                .await
+                .unwrap_or_default()
+            }
+            Err(_) => (0.0, 0.0),
+        };
 
-        Ok(PipelineBuildResult::create())
+        ReclusterProgressQueue::instance()?.append_data(ReclusterProgressLogElement {
+            query_id: self.ctx.get_id(),
+            event_time: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("Time went backwards")
+                .as_micros() as i64,
+            database: plan.database.clone(),
+            table: plan.table.clone(),
+            iteration,
+            average_overlaps,
+            average_depth,
+            status: status.to_string(),
+        })
     }
 }
@@ -0,0 +1,150 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::ComputedExpr;
+use common_expression::DataSchemaRefExt;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::schema::DatabaseType;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_sql::parse_computed_expr;
+use common_sql::plans::RenameTableColumnPlan;
+use common_storages_share::save_share_table_info;
+use common_storages_view::view_table::VIEW_ENGINE;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct RenameTableColumnInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RenameTableColumnPlan,
+}
+
+impl RenameTableColumnInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RenameTableColumnPlan) -> Result<Self> {
+        Ok(RenameTableColumnInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RenameTableColumnInterpreter {
+    fn name(&self) -> &str {
+        "RenameTableColumnInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog_name = self.plan.catalog.as_str();
+        let db_name = self.plan.database.as_str();
+        let tbl_name = self.plan.table.as_str();
+        let table = self
+            .ctx
+            .get_catalog(catalog_name)?
+            .get_table(self.ctx.get_tenant().as_str(), db_name, tbl_name)
+            .await?;
+
+        let table_info = table.get_table_info();
+        if table_info.engine() == VIEW_ENGINE {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} engine is VIEW that doesn't support alter",
+                &self.plan.database, &self.plan.table
+            )));
+        }
+        if table_info.db_type != DatabaseType::NormalDB {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} doesn't support alter",
+                &self.plan.database, &self.plan.table
+            )));
+        }
+
+        let old_column = self.plan.old_column.as_str();
+        let new_column = self.plan.new_column.as_str();
+
+        // Cluster keys are stored as sql text, they must not refer to the old column name.
+        for cluster_key in table.cluster_keys(self.ctx.clone()) {
+            let expr = cluster_key.as_expr(&BUILTIN_FUNCTIONS);
+            if expr.column_refs().contains_key(old_column) {
+                return Err(ErrorCode::InvalidClusterKeys(format!(
+                    "column `{}` is referenced by the cluster key, it can not be renamed",
+                    old_column
+                )));
+            }
+        }
+
+        let mut new_table_meta = table_info.meta.clone();
+        new_table_meta.rename_column(old_column, new_column)?;
+
+        // Computed columns are stored as sql text, they must not refer to the old column name.
+        let schema = DataSchemaRefExt::create(
+            new_table_meta
+                .schema
+                .fields()
+                .iter()
+                .map(|f| f.into())
+                .collect(),
+        );
+        for f in schema.fields() {
+            if let Some(computed_expr) = f.computed_expr() {
+                let expr = match computed_expr {
+                    ComputedExpr::Stored(expr) => expr.clone(),
+                    ComputedExpr::Virtual(expr) => expr.clone(),
+                };
+                if parse_computed_expr(self.ctx.clone(), schema.clone(), &expr).is_err() {
+                    return Err(ErrorCode::ColumnReferencedByComputedColumn(format!(
+                        "column `{}` is referenced by computed column `{}`",
+                        old_column,
+                        &f.name()
+                    )));
+                }
+            }
+        }
+
+        // The masking policy is attached by column name.
+        if let Some(column_mask_policy) = new_table_meta.column_mask_policy.as_mut() {
+            if let Some(policy) = column_mask_policy.remove(old_column) {
+                column_mask_policy.insert(new_column.to_string(), policy);
+            }
+        }
+
+        let catalog = self.ctx.get_catalog(catalog_name)?;
+        let table_id = table_info.ident.table_id;
+        let table_version = table_info.ident.seq;
+
+        let req = UpdateTableMetaReq {
+            table_id,
+            seq: MatchSeq::Exact(table_version),
+            new_table_meta,
+            copied_files: None,
+            deduplicated_label: None,
+        };
+
+        let res = catalog.update_table_meta(table_info, req).await?;
+        if let Some(share_table_info) = res.share_table_info {
+            save_share_table_info(
+                &self.ctx.get_tenant(),
+                self.ctx.get_data_operator()?.operator(),
+                share_table_info,
+            )
+            .await?;
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -22,10 +22,22 @@ use common_meta_types::MatchSeq;
 use common_sql::plans::SetOptionsPlan;
 use common_storages_fuse::TableContext;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
+use storages_common_table_meta::table::OPT_KEY_TRANSIENT;
 use tracing::error;
 
+use super::interpreter_table_create::is_valid_auto_compact;
 use super::interpreter_table_create::is_valid_block_per_segment;
 use super::interpreter_table_create::is_valid_create_opt;
+use super::interpreter_table_create::is_valid_data_retention_period;
+use super::interpreter_table_create::is_valid_inverted_index_columns;
+use super::interpreter_table_create::is_valid_notification_integration;
+use super::interpreter_table_create::is_valid_partition_refresh_interval;
+use super::interpreter_table_create::is_valid_replication_stage;
+use super::interpreter_table_create::is_valid_segment_max_blocks;
+use super::interpreter_table_create::is_valid_unique_keys;
+use super::interpreter_table_create::is_valid_variant_shredding;
+use super::interpreter_table_create::is_valid_vector_index_columns;
+use super::interpreter_table_create::parse_transient_option;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -53,6 +65,18 @@ impl Interpreter for SetOptionsInterpreter {
         let mut options_map = HashMap::new();
         // check block_per_segment
         is_valid_block_per_segment(&self.plan.set_options)?;
+        // check segment_max_blocks
+        is_valid_segment_max_blocks(&self.plan.set_options)?;
+        // check auto_compact
+        is_valid_auto_compact(&self.plan.set_options)?;
+        // check data_retention_period_in_hours
+        is_valid_data_retention_period(&self.plan.set_options)?;
+        // check partition_refresh_interval_in_secs
+        is_valid_partition_refresh_interval(&self.plan.set_options)?;
+        // check replication_stage
+        is_valid_replication_stage(&self.ctx.get_tenant(), &self.plan.set_options).await?;
+        // check notification_integration
+        is_valid_notification_integration(&self.ctx.get_tenant(), &self.plan.set_options).await?;
         // check storage_format
         let error_str = "invalid opt for fuse table in alter table statement";
         if self.plan.set_options.get(OPT_KEY_STORAGE_FORMAT).is_some() {
@@ -70,6 +94,15 @@ impl Interpreter for SetOptionsInterpreter {
                     "table option {key} is invalid for alter table statement",
                 )));
             }
+            if key == "transient" {
+                // the flag of transient tables, removed if the table is not transient any more.
+                let transient = parse_transient_option(table_option.1)?;
+                options_map.insert(
+                    OPT_KEY_TRANSIENT.to_string(),
+                    transient.then(|| "T".to_string()),
+                );
+                continue;
+            }
             options_map.insert(key, Some(table_option.1.clone()));
         }
         let catalog = self.ctx.get_catalog(self.plan.catalog.as_str())?;
@@ -86,6 +119,17 @@ impl Interpreter for SetOptionsInterpreter {
             return Err(ErrorCode::UnknownTable(self.plan.table.as_str()));
         };
 
+        // check variant_shredding with the storage_format of the table
+        let mut table_options = table.options().clone();
+        table_options.extend(self.plan.set_options.clone());
+        is_valid_variant_shredding(&table_options)?;
+        // check inverted_index_columns with the schema of the table
+        is_valid_inverted_index_columns(&self.plan.set_options, &table.schema())?;
+        // check vector_index_columns with the schema of the table
+        is_valid_vector_index_columns(&self.plan.set_options, &table.schema())?;
+        // check unique_keys with the schema of the table
+        is_valid_unique_keys(&self.plan.set_options, &table.schema())?;
+
         let req = UpsertTableOptionReq {
             table_id: table.get_id(),
             seq: MatchSeq::Exact(table.get_table_info().ident.seq),
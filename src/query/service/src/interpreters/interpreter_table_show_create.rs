@@ -27,6 +27,7 @@ use common_sql::plans::ShowCreateTablePlan;
 use common_storages_view::view_table::QUERY;
 use common_storages_view::view_table::VIEW_ENGINE;
 use storages_common_table_meta::table::is_internal_opt_key;
+use storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
 use tracing::debug;
 
 use crate::interpreters::Interpreter;
@@ -156,6 +157,10 @@ impl Interpreter for ShowCreateTableInterpreter {
         let table_engine = format!(") ENGINE={}", engine);
         table_create_sql.push_str(table_engine.as_str());
 
+        if let Some(partition_by) = table.options().get(OPT_KEY_PARTITION_BY) {
+            table_create_sql.push_str(format!(" PARTITIONED BY ({})", partition_by).as_str());
+        }
+
         let table_info = table.get_table_info();
         if let Some((_, cluster_keys_str)) = table_info.meta.cluster_key() {
             table_create_sql.push_str(format!(" CLUSTER BY {}", cluster_keys_str).as_str());
@@ -71,14 +71,15 @@ impl Interpreter for VacuumTableInterpreter {
             .ctx
             .get_table(&catalog_name, &db_name, &tbl_name)
             .await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        fuse_table.check_mutable()?;
         let hours = match self.plan.option.retain_hours {
             Some(hours) => hours as i64,
-            None => ctx.get_settings().get_retention_period()? as i64,
+            None => fuse_table.get_data_retention_period(ctx.as_ref())? as i64,
         };
         let retention_time = chrono::Utc::now() - chrono::Duration::hours(hours);
         let ctx = self.ctx.clone();
 
-        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
         let handler = get_vacuum_handler();
         let purge_files_opt = handler
             .do_vacuum(
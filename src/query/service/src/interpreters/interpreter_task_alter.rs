@@ -0,0 +1,77 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::TaskState;
+use common_sql::plans::AlterTaskPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct AlterTaskInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterTaskPlan,
+}
+
+impl AlterTaskInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterTaskPlan) -> Result<Self> {
+        Ok(AlterTaskInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterTaskInterpreter {
+    fn name(&self) -> &str {
+        "AlterTaskInterpreter"
+    }
+
+    #[tracing::instrument(level = "info", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = self.plan.clone();
+        let user_api = UserApiProvider::instance();
+        loop {
+            let seq_task = user_api.get_task(&plan.tenant, &plan.name).await?;
+            let mut task = seq_task.data;
+            let now = Utc::now();
+            match plan.state {
+                // a started task keeps its schedule.
+                TaskState::Started if task.state == TaskState::Started => {}
+                TaskState::Started => {
+                    task.next_scheduled_at = Some(task.schedule.next_after(now)?);
+                }
+                TaskState::Suspended => task.next_scheduled_at = None,
+            }
+            task.state = plan.state;
+            task.updated_on = now;
+
+            // the scheduler may have claimed the task since read, try again.
+            match user_api.update_task(&plan.tenant, task, seq_task.seq).await {
+                Err(cause) if cause.code() == ErrorCode::UNKNOWN_TASK => continue,
+                Err(cause) => return Err(cause),
+                Ok(_) => break,
+            }
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
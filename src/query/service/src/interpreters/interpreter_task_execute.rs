@@ -0,0 +1,60 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_exception::Result;
+use common_meta_app::principal::TaskRunTrigger;
+use common_sql::plans::ExecuteTaskPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::task_scheduler::run_task;
+
+#[derive(Debug)]
+pub struct ExecuteTaskInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ExecuteTaskPlan,
+}
+
+impl ExecuteTaskInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ExecuteTaskPlan) -> Result<Self> {
+        Ok(ExecuteTaskInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ExecuteTaskInterpreter {
+    fn name(&self) -> &str {
+        "ExecuteTaskInterpreter"
+    }
+
+    #[tracing::instrument(level = "info", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = self.plan.clone();
+        let task = UserApiProvider::instance()
+            .get_task(&plan.tenant, &plan.name)
+            .await?
+            .data;
+        // runs now regardless of the state and the schedule of the task.
+        run_task(&plan.tenant, &task, Utc::now(), TaskRunTrigger::Execute).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -41,6 +41,10 @@ impl Interpreter for UnSettingInterpreter {
         "SettingInterpreter"
     }
 
+    fn bypass_workload_group(&self) -> bool {
+        true
+    }
+
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let plan = self.set.clone();
@@ -58,6 +58,7 @@ impl Interpreter for RemoveUserStageInterpreter {
             path: plan.path.clone(),
             files: None,
             pattern,
+            filter: Default::default(),
         };
         let files: Vec<String> = files_info
             .list(&op, false, None)
@@ -20,17 +20,22 @@ mod interpreter_catalog_create;
 mod interpreter_catalog_drop;
 mod interpreter_cluster_key_alter;
 mod interpreter_cluster_key_drop;
+mod interpreter_cluster_node_drain;
 mod interpreter_clustering_history;
 mod interpreter_copy;
 mod interpreter_data_mask_create;
 mod interpreter_data_mask_desc;
 mod interpreter_data_mask_drop;
+mod interpreter_database_backup;
 mod interpreter_database_create;
 mod interpreter_database_drop;
 mod interpreter_database_rename;
+mod interpreter_database_restore;
 mod interpreter_database_show_create;
 mod interpreter_database_undrop;
 mod interpreter_delete;
+mod interpreter_dictionary_create;
+mod interpreter_dictionary_drop;
 mod interpreter_explain;
 mod interpreter_factory;
 mod interpreter_file_format_create;
@@ -40,19 +45,33 @@ mod interpreter_index_create;
 mod interpreter_index_drop;
 mod interpreter_insert;
 mod interpreter_kill;
+mod interpreter_materialized_view_create;
+mod interpreter_materialized_view_refresh;
+mod interpreter_merge_into;
 mod interpreter_metrics;
+mod interpreter_network_policy_create;
+mod interpreter_network_policy_drop;
+mod interpreter_notification_integration_create;
+mod interpreter_notification_integration_drop;
+mod interpreter_pipe_create;
+mod interpreter_pipe_drop;
 mod interpreter_presign;
 mod interpreter_privilege_grant;
 mod interpreter_privilege_revoke;
 mod interpreter_query_log;
 mod interpreter_replace;
+mod interpreter_role_alter;
 mod interpreter_role_create;
 mod interpreter_role_drop;
 mod interpreter_role_grant;
 mod interpreter_role_revoke;
 mod interpreter_role_set;
 mod interpreter_role_show;
+mod interpreter_row_access_policy_create;
+mod interpreter_row_access_policy_drop;
 mod interpreter_select;
+mod interpreter_sequence_create;
+mod interpreter_sequence_drop;
 mod interpreter_setting;
 mod interpreter_share_alter_tenants;
 mod interpreter_share_create;
@@ -68,22 +87,32 @@ mod interpreter_share_show_grant_tenants;
 mod interpreter_show_grants;
 mod interpreter_show_object_grant_privileges;
 mod interpreter_table_add_column;
+mod interpreter_table_add_row_access_policy;
 mod interpreter_table_analyze;
 mod interpreter_table_create;
 mod interpreter_table_describe;
 mod interpreter_table_drop;
 mod interpreter_table_drop_column;
+mod interpreter_table_drop_row_access_policy;
 mod interpreter_table_exists;
 mod interpreter_table_modify_column;
 mod interpreter_table_optimize;
 mod interpreter_table_recluster;
 mod interpreter_table_rename;
+mod interpreter_table_rename_column;
 mod interpreter_table_revert;
 mod interpreter_table_set_options;
 mod interpreter_table_show_create;
 mod interpreter_table_truncate;
 mod interpreter_table_undrop;
 mod interpreter_table_vacuum;
+mod interpreter_task_alter;
+mod interpreter_task_create;
+mod interpreter_task_drop;
+mod interpreter_task_execute;
+mod interpreter_txn_abort;
+mod interpreter_txn_begin;
+mod interpreter_txn_commit;
 mod interpreter_unsetting;
 mod interpreter_update;
 mod interpreter_use_database;
@@ -112,31 +141,50 @@ pub use interpreter::InterpreterPtr;
 pub use interpreter_call::CallInterpreter;
 pub use interpreter_cluster_key_alter::AlterTableClusterKeyInterpreter;
 pub use interpreter_cluster_key_drop::DropTableClusterKeyInterpreter;
+pub use interpreter_cluster_node_drain::DrainClusterNodeInterpreter;
 pub use interpreter_clustering_history::InterpreterClusteringHistory;
 pub use interpreter_data_mask_create::CreateDataMaskInterpreter;
 pub use interpreter_data_mask_desc::DescDataMaskInterpreter;
 pub use interpreter_data_mask_drop::DropDataMaskInterpreter;
+pub use interpreter_database_backup::BackupDatabaseInterpreter;
 pub use interpreter_database_create::CreateDatabaseInterpreter;
 pub use interpreter_database_drop::DropDatabaseInterpreter;
 pub use interpreter_database_rename::RenameDatabaseInterpreter;
+pub use interpreter_database_restore::RestoreDatabaseInterpreter;
 pub use interpreter_database_show_create::ShowCreateDatabaseInterpreter;
 pub use interpreter_database_undrop::UndropDatabaseInterpreter;
 pub use interpreter_delete::DeleteInterpreter;
+pub use interpreter_dictionary_create::CreateDictionaryInterpreter;
+pub use interpreter_dictionary_drop::DropDictionaryInterpreter;
 pub use interpreter_explain::ExplainInterpreter;
 pub use interpreter_factory::InterpreterFactory;
 pub use interpreter_insert::InsertInterpreter;
 pub use interpreter_kill::KillInterpreter;
+pub use interpreter_materialized_view_create::CreateMaterializedViewInterpreter;
+pub use interpreter_materialized_view_refresh::RefreshMaterializedViewInterpreter;
+pub use interpreter_merge_into::MergeIntoInterpreter;
 pub use interpreter_metrics::InterpreterMetrics;
+pub use interpreter_network_policy_create::CreateNetworkPolicyInterpreter;
+pub use interpreter_network_policy_drop::DropNetworkPolicyInterpreter;
+pub use interpreter_notification_integration_create::CreateNotificationIntegrationInterpreter;
+pub use interpreter_notification_integration_drop::DropNotificationIntegrationInterpreter;
+pub use interpreter_pipe_create::CreatePipeInterpreter;
+pub use interpreter_pipe_drop::DropPipeInterpreter;
 pub use interpreter_privilege_grant::GrantPrivilegeInterpreter;
 pub use interpreter_privilege_revoke::RevokePrivilegeInterpreter;
 pub use interpreter_query_log::InterpreterQueryLog;
 pub use interpreter_replace::ReplaceInterpreter;
+pub use interpreter_role_alter::AlterRoleInterpreter;
 pub use interpreter_role_create::CreateRoleInterpreter;
 pub use interpreter_role_drop::DropRoleInterpreter;
 pub use interpreter_role_grant::GrantRoleInterpreter;
 pub use interpreter_role_revoke::RevokeRoleInterpreter;
 pub use interpreter_role_set::SetRoleInterpreter;
+pub use interpreter_row_access_policy_create::CreateRowAccessPolicyInterpreter;
+pub use interpreter_row_access_policy_drop::DropRowAccessPolicyInterpreter;
 pub use interpreter_select::SelectInterpreter;
+pub use interpreter_sequence_create::CreateSequenceInterpreter;
+pub use interpreter_sequence_drop::DropSequenceInterpreter;
 pub use interpreter_setting::SettingInterpreter;
 pub use interpreter_share_alter_tenants::AlterShareTenantsInterpreter;
 pub use interpreter_share_create::CreateShareInterpreter;
@@ -151,20 +199,30 @@ pub use interpreter_share_show_grant_tenants::ShowGrantTenantsOfShareInterpreter
 pub use interpreter_show_grants::ShowGrantsInterpreter;
 pub use interpreter_show_object_grant_privileges::ShowObjectGrantPrivilegesInterpreter;
 pub use interpreter_table_add_column::AddTableColumnInterpreter;
+pub use interpreter_table_add_row_access_policy::AddTableRowAccessPolicyInterpreter;
 pub use interpreter_table_analyze::AnalyzeTableInterpreter;
 pub use interpreter_table_create::CreateTableInterpreter;
 pub use interpreter_table_describe::DescribeTableInterpreter;
 pub use interpreter_table_drop::DropTableInterpreter;
 pub use interpreter_table_drop_column::DropTableColumnInterpreter;
+pub use interpreter_table_drop_row_access_policy::DropTableRowAccessPolicyInterpreter;
 pub use interpreter_table_exists::ExistsTableInterpreter;
 pub use interpreter_table_modify_column::ModifyTableColumnInterpreter;
 pub use interpreter_table_optimize::OptimizeTableInterpreter;
 pub use interpreter_table_recluster::ReclusterTableInterpreter;
 pub use interpreter_table_rename::RenameTableInterpreter;
+pub use interpreter_table_rename_column::RenameTableColumnInterpreter;
 pub use interpreter_table_show_create::ShowCreateTableInterpreter;
 pub use interpreter_table_truncate::TruncateTableInterpreter;
 pub use interpreter_table_undrop::UndropTableInterpreter;
 pub use interpreter_table_vacuum::VacuumTableInterpreter;
+pub use interpreter_task_alter::AlterTaskInterpreter;
+pub use interpreter_task_create::CreateTaskInterpreter;
+pub use interpreter_task_drop::DropTaskInterpreter;
+pub use interpreter_task_execute::ExecuteTaskInterpreter;
+pub use interpreter_txn_abort::AbortInterpreter;
+pub use interpreter_txn_begin::BeginInterpreter;
+pub use interpreter_txn_commit::CommitInterpreter;
 pub use interpreter_unsetting::UnSettingInterpreter;
 pub use interpreter_update::UpdateInterpreter;
 pub use interpreter_use_database::UseDatabaseInterpreter;
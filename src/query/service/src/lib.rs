@@ -35,18 +35,25 @@ extern crate core;
 
 pub mod api;
 pub mod auth;
+pub mod auto_compaction;
 pub mod catalogs;
+pub mod change_notification;
 pub mod clusters;
 pub mod databases;
 pub mod interpreters;
+pub mod materialized_view_refresh;
 pub mod metrics;
 pub mod pipelines;
+pub mod pipes;
 pub mod procedures;
+pub mod query_history;
+pub mod replication;
 pub mod schedulers;
 pub mod servers;
 pub mod sessions;
 pub mod stream;
 pub mod table_functions;
+pub mod task_scheduler;
 pub mod test_kits;
 
 mod global_services;
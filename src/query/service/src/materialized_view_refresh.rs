@@ -0,0 +1,133 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+use std::time::Instant;
+
+use common_base::base::tokio::time::sleep;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_config::InnerConfig;
+use common_exception::Result;
+use common_sql::plans::RefreshMaterializedViewPlan;
+use futures_util::TryStreamExt;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use tracing::info;
+use tracing::warn;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::RefreshMaterializedViewInterpreter;
+use crate::sessions::Session;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+
+/// Refreshes the materialized views periodically, so that they keep up with
+/// their source tables without user-issued `REFRESH MATERIALIZED VIEW`.
+///
+/// The views are refreshed one by one, views already up to date are skipped
+/// by the refresh interpreter cheaply.
+pub struct MaterializedViewRefreshService {
+    tenant: String,
+    interval: Duration,
+}
+
+impl MaterializedViewRefreshService {
+    pub fn init(config: &InnerConfig) -> Result<()> {
+        // a read-only replica never commits to the tables.
+        if config.query.materialized_view_refresh_interval_secs == 0
+            || config.query.read_only_replica
+        {
+            return Ok(());
+        }
+
+        let service = MaterializedViewRefreshService {
+            tenant: config.query.tenant_id.clone(),
+            interval: Duration::from_secs(config.query.materialized_view_refresh_interval_secs),
+        };
+        GlobalIORuntime::instance().try_spawn(async move { service.run().await })?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn run(self) {
+        loop {
+            sleep(self.interval).await;
+
+            if let Err(cause) = self.refresh_round().await {
+                warn!("materialized view refresh: round failed, {}", cause);
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn refresh_round(&self) -> Result<()> {
+        let session = SessionManager::instance()
+            .create_session(SessionType::Dummy)
+            .await?;
+
+        let ctx = session.create_query_context().await?;
+        let catalog = ctx.get_catalog("default")?;
+        for database in catalog.list_databases(&self.tenant).await? {
+            for table in database.list_tables().await? {
+                if !table
+                    .options()
+                    .contains_key(OPT_KEY_MATERIALIZED_VIEW_QUERY)
+                {
+                    continue;
+                }
+
+                let plan = RefreshMaterializedViewPlan {
+                    tenant: self.tenant.clone(),
+                    catalog: "default".to_string(),
+                    database: database.name().to_string(),
+                    view_name: table.name().to_string(),
+                };
+                if let Err(cause) = self.refresh_view(&session, plan).await {
+                    warn!(
+                        "materialized view refresh: failed to refresh {}.{}, {}",
+                        database.name(),
+                        table.name(),
+                        cause
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn refresh_view(
+        &self,
+        session: &Session,
+        plan: RefreshMaterializedViewPlan,
+    ) -> Result<()> {
+        let start = Instant::now();
+        let ctx = session.create_query_context().await?;
+        let view_name = format!("{}.{}", plan.database, plan.view_name);
+        let interpreter = RefreshMaterializedViewInterpreter::try_create(ctx.clone(), plan)?;
+        interpreter
+            .execute(ctx)
+            .await?
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        info!(
+            "materialized view refresh: refreshed {}, cost:{} sec",
+            view_name,
+            start.elapsed().as_secs_f32()
+        );
+        Ok(())
+    }
+}
@@ -57,6 +57,10 @@ impl WorkersCondvar {
         self.waiting_async_task.load(Ordering::Relaxed) != 0
     }
 
+    pub fn active_async_tasks(&self) -> usize {
+        self.waiting_async_task.load(Ordering::Relaxed)
+    }
+
     pub fn inc_active_async_worker(&self) {
         self.waiting_async_task.fetch_add(1, Ordering::Release);
     }
@@ -343,6 +343,7 @@ impl ScheduleQueue {
                     proc.clone(),
                     global_queue,
                     workers_condvar,
+                    executor.metrics.clone(),
                     process_future,
                 )));
         }
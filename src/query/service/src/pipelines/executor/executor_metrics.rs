@@ -0,0 +1,299 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Weak;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_base::base::tokio::time::sleep;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_exception::Result;
+use common_metrics::set_ephemeral_samples;
+use common_metrics::MetricSample;
+use common_metrics::MetricValue;
+use metrics::gauge;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+
+use crate::pipelines::executor::executor_condvar::WorkersCondvar;
+use crate::pipelines::executor::executor_tasks::ExecutorTasksQueue;
+
+macro_rules! key {
+    ($key: literal) => {
+        concat!("pipeline_executor_", $key)
+    };
+}
+
+const SAMPLE_INTERVAL: Duration = Duration::from_millis(500);
+
+static NEXT_EXECUTOR_ID: AtomicU64 = AtomicU64::new(0);
+
+static RUNNING_EXECUTORS: Lazy<Mutex<Vec<Weak<ExecutorMetrics>>>> =
+    Lazy::new(|| Mutex::new(Vec::new()));
+
+struct WorkerIdleState {
+    /// Nanoseconds since the executor started when the worker began waiting for tasks,
+    /// plus one, 0 means the worker is running a task.
+    idle_since: AtomicU64,
+    /// Whether the worker is waiting while some async tasks are running.
+    blocked_on_async: AtomicBool,
+}
+
+/// Statistics of a running pipeline executor, updated by its workers and async tasks.
+///
+/// They are sampled into the `pipeline_executor_*` gauges by [`ExecutorMetricsSampler`].
+pub struct ExecutorMetrics {
+    executor_id: u64,
+    query_id: Arc<String>,
+    started: Instant,
+    queue: Arc<ExecutorTasksQueue>,
+    workers_condvar: Arc<WorkersCondvar>,
+    workers: Vec<WorkerIdleState>,
+    /// Nanoseconds of the finished waits of the workers.
+    idle_nanos: AtomicU64,
+    /// Nanoseconds of the finished waits of the workers, while some async tasks were running.
+    async_blocked_nanos: AtomicU64,
+    /// Nanoseconds of the finished async tasks, from being scheduled to being completed.
+    async_tasks_nanos: AtomicU64,
+    async_tasks: AtomicU64,
+}
+
+impl ExecutorMetrics {
+    pub fn create(
+        query_id: Arc<String>,
+        threads_num: usize,
+        queue: Arc<ExecutorTasksQueue>,
+        workers_condvar: Arc<WorkersCondvar>,
+    ) -> Arc<ExecutorMetrics> {
+        let workers = (0..threads_num)
+            .map(|_| WorkerIdleState {
+                idle_since: AtomicU64::new(0),
+                blocked_on_async: AtomicBool::new(false),
+            })
+            .collect();
+        let metrics = Arc::new(ExecutorMetrics {
+            executor_id: NEXT_EXECUTOR_ID.fetch_add(1, Ordering::Relaxed),
+            query_id,
+            started: Instant::now(),
+            queue,
+            workers_condvar,
+            workers,
+            idle_nanos: AtomicU64::new(0),
+            async_blocked_nanos: AtomicU64::new(0),
+            async_tasks_nanos: AtomicU64::new(0),
+            async_tasks: AtomicU64::new(0),
+        });
+
+        let mut running = RUNNING_EXECUTORS.lock();
+        running.retain(|executor| executor.strong_count() > 0);
+        running.push(Arc::downgrade(&metrics));
+        metrics
+    }
+
+    fn elapsed_nanos(&self) -> u64 {
+        self.started.elapsed().as_nanos() as u64
+    }
+
+    pub fn worker_idle_begin(&self, worker_id: usize) {
+        let worker = &self.workers[worker_id];
+        let blocked_on_async = self.workers_condvar.has_waiting_async_task();
+        worker
+            .blocked_on_async
+            .store(blocked_on_async, Ordering::Relaxed);
+        worker
+            .idle_since
+            .store(self.elapsed_nanos() + 1, Ordering::Release);
+    }
+
+    pub fn worker_idle_end(&self, worker_id: usize) {
+        let worker = &self.workers[worker_id];
+        let idle_since = worker.idle_since.swap(0, Ordering::AcqRel);
+        if idle_since == 0 {
+            return;
+        }
+
+        let idle = self.elapsed_nanos().saturating_sub(idle_since - 1);
+        self.idle_nanos.fetch_add(idle, Ordering::Relaxed);
+        if worker.blocked_on_async.load(Ordering::Relaxed) {
+            self.async_blocked_nanos.fetch_add(idle, Ordering::Relaxed);
+        }
+    }
+
+    pub fn async_task_completed(&self, elapsed: Duration) {
+        self.async_tasks_nanos
+            .fetch_add(elapsed.as_nanos() as u64, Ordering::Relaxed);
+        self.async_tasks.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// The idle time of the workers so far, including the waits not finished yet.
+    fn snapshot(&self) -> ExecutorMetricsSnapshot {
+        let now = self.elapsed_nanos();
+        let mut idle_nanos = self.idle_nanos.load(Ordering::Relaxed);
+        let mut async_blocked_nanos = self.async_blocked_nanos.load(Ordering::Relaxed);
+        for worker in &self.workers {
+            let idle_since = worker.idle_since.load(Ordering::Acquire);
+            if idle_since != 0 {
+                let idle = now.saturating_sub(idle_since - 1);
+                idle_nanos += idle;
+                if worker.blocked_on_async.load(Ordering::Relaxed) {
+                    async_blocked_nanos += idle;
+                }
+            }
+        }
+
+        ExecutorMetricsSnapshot {
+            elapsed_nanos: now,
+            idle_nanos,
+            async_blocked_nanos,
+            async_tasks_nanos: self.async_tasks_nanos.load(Ordering::Relaxed),
+            async_tasks: self.async_tasks.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+struct ExecutorMetricsSnapshot {
+    elapsed_nanos: u64,
+    idle_nanos: u64,
+    async_blocked_nanos: u64,
+    async_tasks_nanos: u64,
+    async_tasks: u64,
+}
+
+/// Samples the running pipeline executors every 500ms.
+///
+/// - the gauges of the node, summed up over the running executors, are recorded
+/// - the gauges of each executor, labeled by `query_id` and `executor_id`, are only
+///   dumped to `system.metrics` while the executor is running
+///
+/// The ratios are of the last sample interval: `worker_idle_ratio` is the share of the
+/// worker time waiting for tasks, `async_io_blocked_ratio` the share waiting while some
+/// async tasks, usually reading or writing the storage, are running.
+pub struct ExecutorMetricsSampler {
+    previous: HashMap<u64, ExecutorMetricsSnapshot>,
+}
+
+impl ExecutorMetricsSampler {
+    pub fn init() -> Result<()> {
+        let mut sampler = ExecutorMetricsSampler {
+            previous: HashMap::new(),
+        };
+        GlobalIORuntime::instance().try_spawn(async move {
+            loop {
+                sleep(SAMPLE_INTERVAL).await;
+                sampler.sample();
+            }
+        })?;
+        Ok(())
+    }
+
+    fn sample(&mut self) {
+        let running = {
+            let mut running = RUNNING_EXECUTORS.lock();
+            running.retain(|executor| executor.strong_count() > 0);
+            running
+                .iter()
+                .filter_map(|executor| executor.upgrade())
+                .filter(|executor| !executor.queue.is_finished())
+                .collect::<Vec<_>>()
+        };
+
+        let mut samples = Vec::with_capacity(running.len() * 7);
+        let mut previous = HashMap::with_capacity(running.len());
+        let mut workers = 0;
+        let mut window_nanos = 0;
+        let mut idle_nanos = 0;
+        let mut async_blocked_nanos = 0;
+        let mut async_tasks_running = 0;
+        let mut tasks_queued = 0;
+        for executor in running {
+            let current = executor.snapshot();
+            let last = self
+                .previous
+                .get(&executor.executor_id)
+                .copied()
+                .unwrap_or_default();
+            let threads_num = executor.workers.len() as u64;
+            let window = threads_num * (current.elapsed_nanos - last.elapsed_nanos);
+            let idle = current.idle_nanos.saturating_sub(last.idle_nanos);
+            let blocked = current
+                .async_blocked_nanos
+                .saturating_sub(last.async_blocked_nanos);
+            let running_async = executor.workers_condvar.active_async_tasks() as u64;
+            let queued = executor.queue.tasks_size() as u64;
+
+            let labels = HashMap::from([
+                ("query_id".to_string(), executor.query_id.to_string()),
+                ("executor_id".to_string(), executor.executor_id.to_string()),
+            ]);
+            let mut gauge_sample = |name: &str, value: f64| {
+                samples.push(MetricSample {
+                    name: name.to_string(),
+                    labels: labels.clone(),
+                    value: MetricValue::Gauge(value),
+                });
+            };
+            gauge_sample(key!("threads"), threads_num as f64);
+            gauge_sample(key!("worker_idle_ratio"), ratio(idle, window));
+            gauge_sample(key!("async_io_blocked_ratio"), ratio(blocked, window));
+            gauge_sample(
+                key!("async_io_blocked_seconds"),
+                current.async_blocked_nanos as f64 / 1e9,
+            );
+            gauge_sample(key!("async_tasks_running"), running_async as f64);
+            gauge_sample(
+                key!("async_task_avg_seconds"),
+                ratio(
+                    current.async_tasks_nanos,
+                    current.async_tasks * 1_000_000_000,
+                ),
+            );
+            gauge_sample(key!("tasks_queued"), queued as f64);
+
+            workers += threads_num;
+            window_nanos += window;
+            idle_nanos += idle;
+            async_blocked_nanos += blocked;
+            async_tasks_running += running_async;
+            tasks_queued += queued;
+            previous.insert(executor.executor_id, current);
+        }
+
+        self.previous = previous;
+        set_ephemeral_samples("pipeline_executor", samples);
+
+        gauge!(key!("running"), self.previous.len() as f64);
+        gauge!(key!("workers"), workers as f64);
+        gauge!(key!("worker_idle_ratio"), ratio(idle_nanos, window_nanos));
+        gauge!(
+            key!("async_io_blocked_ratio"),
+            ratio(async_blocked_nanos, window_nanos)
+        );
+        gauge!(key!("async_tasks_running"), async_tasks_running as f64);
+        gauge!(key!("tasks_queued"), tasks_queued as f64);
+    }
+}
+
+fn ratio(part: u64, total: u64) -> f64 {
+    match total {
+        0 => 0.0,
+        total => (part as f64 / total as f64).min(1.0),
+    }
+}
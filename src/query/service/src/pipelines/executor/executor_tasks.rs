@@ -186,6 +186,11 @@ impl ExecutorTasksQueue {
         self.finished_notify.clone()
     }
 
+    /// The sync tasks and the completed async tasks waiting for the workers.
+    pub fn tasks_size(&self) -> usize {
+        self.workers_tasks.lock().tasks_size
+    }
+
     pub fn active_workers(&self) -> usize {
         let workers_tasks = self.workers_tasks.lock();
         workers_tasks.workers_waiting_status.total_size()
@@ -35,6 +35,7 @@ use tracing::warn;
 use crate::pipelines::executor::executor_condvar::WorkersCondvar;
 use crate::pipelines::executor::executor_graph::RunningGraph;
 use crate::pipelines::executor::executor_graph::ScheduleQueue;
+use crate::pipelines::executor::executor_metrics::ExecutorMetrics;
 use crate::pipelines::executor::executor_tasks::ExecutorTasksQueue;
 use crate::pipelines::executor::executor_worker_context::ExecutorWorkerContext;
 use crate::pipelines::executor::ExecutorSettings;
@@ -51,6 +52,7 @@ pub struct PipelineExecutor {
     workers_condvar: Arc<WorkersCondvar>,
     pub async_runtime: Arc<Runtime>,
     pub global_tasks_queue: Arc<ExecutorTasksQueue>,
+    pub metrics: Arc<ExecutorMetrics>,
     on_init_callback: Mutex<Option<InitCallback>>,
     on_finished_callback: Mutex<Option<FinishedCallback>>,
     settings: ExecutorSettings,
@@ -164,12 +166,19 @@ impl PipelineExecutor {
     ) -> Result<Arc<PipelineExecutor>> {
         let workers_condvar = WorkersCondvar::create(threads_num);
         let global_tasks_queue = ExecutorTasksQueue::create(threads_num);
+        let metrics = ExecutorMetrics::create(
+            settings.query_id.clone(),
+            threads_num,
+            global_tasks_queue.clone(),
+            workers_condvar.clone(),
+        );
 
         Ok(Arc::new(PipelineExecutor {
             graph,
             threads_num,
             workers_condvar,
             global_tasks_queue,
+            metrics,
             on_init_callback,
             on_finished_callback,
             async_runtime: GlobalIORuntime::instance(),
@@ -368,7 +377,9 @@ impl PipelineExecutor {
         while !self.global_tasks_queue.is_finished() {
             // When there are not enough tasks, the thread will be blocked, so we need loop check.
             while !self.global_tasks_queue.is_finished() && !context.has_task() {
+                self.metrics.worker_idle_begin(thread_num);
                 self.global_tasks_queue.steal_task_to_context(&mut context);
+                self.metrics.worker_idle_end(thread_num);
             }
 
             while !self.global_tasks_queue.is_finished() && context.has_task() {
@@ -30,6 +30,7 @@ use futures_util::FutureExt;
 use petgraph::prelude::NodeIndex;
 
 use crate::pipelines::executor::executor_condvar::WorkersCondvar;
+use crate::pipelines::executor::executor_metrics::ExecutorMetrics;
 use crate::pipelines::executor::executor_tasks::CompletedAsyncTask;
 use crate::pipelines::executor::executor_tasks::ExecutorTasksQueue;
 
@@ -48,6 +49,7 @@ impl ProcessorAsyncTask {
         processor: ProcessorPtr,
         queue: Arc<ExecutorTasksQueue>,
         workers_condvar: Arc<WorkersCondvar>,
+        metrics: Arc<ExecutorMetrics>,
         inner: Inner,
     ) -> ProcessorAsyncTask {
         let finished_notify = queue.get_finished_notify();
@@ -86,6 +88,7 @@ impl ProcessorAsyncTask {
                         );
                     }
                     Either::Right((res, _)) => {
+                        metrics.async_task_completed(start.elapsed());
                         return res;
                     }
                 }
@@ -35,6 +35,10 @@ pub struct PipelineBuildResult {
     pub prof_span_set: SharedProcessorProfiles,
 
     pub exchange_injector: Arc<dyn ExchangeInjector>,
+
+    /// The upper bound of the threads to execute the pipelines, chosen by the
+    /// estimated data volume of the query. `None` means no extra limit.
+    pub max_threads_limit: Option<usize>,
 }
 
 impl PipelineBuildResult {
@@ -44,6 +48,7 @@ impl PipelineBuildResult {
             sources_pipelines: vec![],
             prof_span_set: SharedProcessorProfiles::default(),
             exchange_injector: DefaultExchangeInjector::create(),
+            max_threads_limit: None,
         }
     }
 
@@ -63,10 +68,15 @@ impl PipelineBuildResult {
             sources_pipelines: vec![],
             prof_span_set: SharedProcessorProfiles::default(),
             exchange_injector: DefaultExchangeInjector::create(),
+            max_threads_limit: None,
         })
     }
 
     pub fn set_max_threads(&mut self, max_threads: usize) {
+        let max_threads = match self.max_threads_limit {
+            Some(limit) => std::cmp::min(max_threads, limit),
+            None => max_threads,
+        };
         self.main_pipeline.set_max_threads(max_threads);
 
         for source_pipeline in &mut self.sources_pipelines {
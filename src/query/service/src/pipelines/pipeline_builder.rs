@@ -12,9 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_channel::Receiver;
+use common_catalog::plan::SampleConfig;
+use common_catalog::plan::SampleLevel;
 use common_catalog::table::AppendMode;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -50,6 +54,7 @@ use common_sql::executor::AggregateExpand;
 use common_sql::executor::AggregateFinal;
 use common_sql::executor::AggregateFunctionDesc;
 use common_sql::executor::AggregatePartial;
+use common_sql::executor::DictGet;
 use common_sql::executor::DistributedInsertSelect;
 use common_sql::executor::EvalScalar;
 use common_sql::executor::ExchangeSink;
@@ -61,10 +66,13 @@ use common_sql::executor::PhysicalPlan;
 use common_sql::executor::Project;
 use common_sql::executor::ProjectSet;
 use common_sql::executor::RangeJoin;
+use common_sql::executor::RecursiveCte;
+use common_sql::executor::RecursiveCteScan;
 use common_sql::executor::RowFetch;
 use common_sql::executor::RuntimeFilterSource;
 use common_sql::executor::Sort;
 use common_sql::executor::TableScan;
+use common_sql::executor::Udf;
 use common_sql::executor::UnionAll;
 use common_sql::executor::Window;
 use common_sql::plans::JoinType;
@@ -83,12 +91,17 @@ use crate::api::ExchangeInjector;
 use crate::interpreters::fill_missing_columns;
 use crate::pipelines::processors::transforms::build_partition_bucket;
 use crate::pipelines::processors::transforms::AggregateInjector;
+use crate::pipelines::processors::transforms::AggregateUdfFunction;
 use crate::pipelines::processors::transforms::FinalSingleStateAggregator;
 use crate::pipelines::processors::transforms::HashJoinDesc;
 use crate::pipelines::processors::transforms::PartialSingleStateAggregator;
 use crate::pipelines::processors::transforms::RangeJoinState;
+use crate::pipelines::processors::transforms::RecursiveCteScanSource;
+use crate::pipelines::processors::transforms::RecursiveCteSource;
+use crate::pipelines::processors::transforms::RecursiveCteWorkingTable;
 use crate::pipelines::processors::transforms::RuntimeFilterState;
 use crate::pipelines::processors::transforms::TransformAggregateSpillWriter;
+use crate::pipelines::processors::transforms::TransformDictGet;
 use crate::pipelines::processors::transforms::TransformGroupBySpillWriter;
 use crate::pipelines::processors::transforms::TransformMarkJoin;
 use crate::pipelines::processors::transforms::TransformMergeBlock;
@@ -96,7 +109,11 @@ use crate::pipelines::processors::transforms::TransformPartialAggregate;
 use crate::pipelines::processors::transforms::TransformPartialGroupBy;
 use crate::pipelines::processors::transforms::TransformRangeJoinLeft;
 use crate::pipelines::processors::transforms::TransformRangeJoinRight;
+use crate::pipelines::processors::transforms::TransformSample;
+use crate::pipelines::processors::transforms::TransformUdf;
 use crate::pipelines::processors::transforms::TransformWindow;
+use crate::pipelines::processors::transforms::UdfRequestOptions;
+use crate::pipelines::processors::transforms::WasmUdfLimits;
 use crate::pipelines::processors::AggregatorParams;
 use crate::pipelines::processors::JoinHashTable;
 use crate::pipelines::processors::MarkJoinCompactor;
@@ -121,6 +138,8 @@ pub struct PipelineBuilder {
     pub join_state: Option<Arc<JoinHashTable>>,
     // record the index of join build side pipeline in `pipelines`
     pub index: Option<usize>,
+    // Working tables of the recursive ctes being evaluated, by cte index
+    pub recursive_cte_working_tables: HashMap<IndexType, Arc<RecursiveCteWorkingTable>>,
 
     enable_profiling: bool,
     prof_span_set: SharedProcessorProfiles,
@@ -142,6 +161,7 @@ impl PipelineBuilder {
             prof_span_set,
             exchange_injector: DefaultExchangeInjector::create(),
             index: None,
+            recursive_cte_working_tables: HashMap::new(),
         }
     }
 
@@ -161,6 +181,7 @@ impl PipelineBuilder {
             sources_pipelines: self.pipelines,
             prof_span_set: self.prof_span_set,
             exchange_injector: self.exchange_injector,
+            max_threads_limit: None,
         })
     }
 
@@ -185,6 +206,10 @@ impl PipelineBuilder {
                 self.build_distributed_insert_select(insert_select)
             }
             PhysicalPlan::ProjectSet(project_set) => self.build_project_set(project_set),
+            PhysicalPlan::DictGet(dict_get) => self.build_dict_get(dict_get),
+            PhysicalPlan::Udf(udf) => self.build_udf(udf),
+            PhysicalPlan::RecursiveCte(recursive_cte) => self.build_recursive_cte(recursive_cte),
+            PhysicalPlan::RecursiveCteScan(scan) => self.build_recursive_cte_scan(scan),
             PhysicalPlan::Exchange(_) => Err(ErrorCode::Internal(
                 "Invalid physical plan with PhysicalPlan::Exchange",
             )),
@@ -376,6 +401,21 @@ impl PipelineBuilder {
             }
         }
 
+        // Sample rows if needed, blocks have been sampled while pruning.
+        if let Some(SampleConfig {
+            level: SampleLevel::Row,
+            probability,
+        }) = scan.source.push_downs.as_ref().and_then(|p| p.sample)
+        {
+            self.main_pipeline.add_transform(|input, output| {
+                Ok(TransformSample::create(
+                    input,
+                    output,
+                    probability.into_inner(),
+                ))
+            })?;
+        }
+
         let schema = scan.source.schema();
         let projection = scan
             .name_mapping
@@ -403,6 +443,30 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    /// Find the table scan reading the partial aggregate states from the aggregating index.
+    fn agg_index_merge_scan(plan: &PhysicalPlan) -> Option<&TableScan> {
+        match plan {
+            PhysicalPlan::EvalScalar(EvalScalar { input, .. })
+            | PhysicalPlan::Filter(Filter { input, .. }) => Self::agg_index_merge_scan(input),
+            PhysicalPlan::TableScan(scan) => scan
+                .source
+                .push_downs
+                .as_ref()
+                .and_then(|p| p.agg_index.as_ref())
+                .and_then(|agg_index| agg_index.merge.as_ref())
+                .map(|_| scan),
+            _ => None,
+        }
+    }
+
+    /// The scan outputs the group columns followed by the partial aggregate states, the filter
+    /// and the evaluation between the scan and the partial aggregation are done by the storage.
+    fn build_agg_index_scan(&mut self, scan: &TableScan) -> Result<()> {
+        let table = self.ctx.build_table_from_source_plan(&scan.source)?;
+        self.ctx.set_partitions(scan.source.parts.clone())?;
+        table.read_data(self.ctx.clone(), &scan.source, &mut self.main_pipeline)
+    }
+
     fn build_filter(&mut self, filter: &Filter) -> Result<()> {
         self.build_pipeline(&filter.input)?;
 
@@ -550,6 +614,117 @@ impl PipelineBuilder {
         })
     }
 
+    fn build_dict_get(&mut self, dict_get: &DictGet) -> Result<()> {
+        self.build_pipeline(&dict_get.input)?;
+
+        let func_ctx = self.ctx.get_function_context()?;
+        let cache_ttl =
+            Duration::from_secs(self.ctx.get_settings().get_dictionary_cache_ttl_secs()?);
+
+        self.main_pipeline.add_transform(|input, output| {
+            let transform = TransformDictGet::create(
+                input,
+                output,
+                func_ctx.clone(),
+                dict_get.items.clone(),
+                cache_ttl,
+            );
+
+            if self.enable_profiling {
+                Ok(ProcessorPtr::create(ProfileWrapper::create(
+                    transform,
+                    dict_get.plan_id,
+                    self.prof_span_set.clone(),
+                )))
+            } else {
+                Ok(ProcessorPtr::create(transform))
+            }
+        })
+    }
+
+    fn build_udf(&mut self, udf: &Udf) -> Result<()> {
+        self.build_pipeline(&udf.input)?;
+
+        let options = self.udf_request_options()?;
+        let settings = self.ctx.get_settings();
+        let wasm_limits = WasmUdfLimits {
+            max_fuel: settings.get_wasm_udf_max_fuel()?,
+            max_memory_bytes: settings.get_wasm_udf_max_memory_bytes()? as usize,
+        };
+
+        self.main_pipeline.add_transform(|input, output| {
+            let transform = TransformUdf::create(
+                input,
+                output,
+                self.ctx.clone(),
+                udf.udf_funcs.clone(),
+                options.clone(),
+                wasm_limits.clone(),
+            )?;
+
+            if self.enable_profiling {
+                Ok(ProcessorPtr::create(ProfileWrapper::create(
+                    transform,
+                    udf.plan_id,
+                    self.prof_span_set.clone(),
+                )))
+            } else {
+                Ok(ProcessorPtr::create(transform))
+            }
+        })
+    }
+
+    fn udf_request_options(&self) -> Result<UdfRequestOptions> {
+        let settings = self.ctx.get_settings();
+        Ok(UdfRequestOptions {
+            connect_timeout: Duration::from_secs(
+                settings.get_external_server_connect_timeout_secs()?,
+            ),
+            request_timeout: Duration::from_secs(
+                settings.get_external_server_request_timeout_secs()?,
+            ),
+            batch_rows: settings.get_external_server_request_batch_rows()? as usize,
+            max_retries: settings.get_external_server_request_max_retries()?,
+        })
+    }
+
+    fn build_recursive_cte(&mut self, recursive_cte: &RecursiveCte) -> Result<()> {
+        let mut working_tables = self.recursive_cte_working_tables.clone();
+        working_tables.insert(recursive_cte.cte_index, RecursiveCteWorkingTable::create());
+
+        self.main_pipeline.add_source(
+            |output| {
+                RecursiveCteSource::create(
+                    self.ctx.clone(),
+                    output,
+                    recursive_cte.clone(),
+                    working_tables.clone(),
+                )
+            },
+            1,
+        )
+    }
+
+    fn build_recursive_cte_scan(&mut self, scan: &RecursiveCteScan) -> Result<()> {
+        let working_table = self
+            .recursive_cte_working_tables
+            .get(&scan.cte_index)
+            .cloned()
+            .ok_or_else(|| {
+                ErrorCode::Internal(format!(
+                    "Working table of recursive cte {} not found",
+                    scan.cte_index
+                ))
+            })?;
+
+        self.main_pipeline.add_source(
+            |output| {
+                RecursiveCteScanSource::create(self.ctx.clone(), output, working_table.clone())
+            },
+            1,
+        )
+    }
+
     fn build_aggregate_expand(&mut self, expand: &AggregateExpand) -> Result<()> {
         self.build_pipeline(&expand.input)?;
         let input_schema = expand.input.output_schema()?;
@@ -603,14 +778,21 @@ impl PipelineBuilder {
     }
 
     fn build_aggregate_partial(&mut self, aggregate: &AggregatePartial) -> Result<()> {
-        self.build_pipeline(&aggregate.input)?;
+        let agg_index_scan = Self::agg_index_merge_scan(&aggregate.input);
+        match agg_index_scan {
+            Some(scan) => self.build_agg_index_scan(scan)?,
+            None => self.build_pipeline(&aggregate.input)?,
+        }
 
-        let params = Self::build_aggregator_params(
+        let mut params = self.build_aggregator_params(
             aggregate.input.output_schema()?,
             &aggregate.group_by,
             &aggregate.agg_funcs,
             None,
         )?;
+        if agg_index_scan.is_some() {
+            params = params.with_merge_states();
+        }
 
         if params.group_columns.is_empty() {
             return self.main_pipeline.add_transform(|input, output| {
@@ -672,11 +854,8 @@ impl PipelineBuilder {
 
         // If cluster mode, spill write will be completed in exchange serialize, because we need scatter the block data first
         if self.ctx.get_cluster().is_empty()
-            && !self
-                .ctx
-                .get_settings()
-                .get_spilling_bytes_threshold_per_proc()?
-                .is_zero()
+            && (!settings.get_spilling_bytes_threshold_per_proc()?.is_zero()
+                || !settings.get_spilling_memory_ratio()?.is_zero())
         {
             let operator = DataOperator::instance().operator();
             let location_prefix = format!("_aggregate_spill/{}", self.ctx.get_tenant());
@@ -739,7 +918,7 @@ impl PipelineBuilder {
     }
 
     fn build_aggregate_final(&mut self, aggregate: &AggregateFinal) -> Result<()> {
-        let params = Self::build_aggregator_params(
+        let params = self.build_aggregator_params(
             aggregate.before_group_by_schema.clone(),
             &aggregate.group_by,
             &aggregate.agg_funcs,
@@ -823,6 +1002,7 @@ impl PipelineBuilder {
     }
 
     pub fn build_aggregator_params(
+        &self,
         input_schema: DataSchemaRef,
         group_by: &[IndexType],
         agg_funcs: &[AggregateFunctionDesc],
@@ -843,11 +1023,19 @@ impl PipelineBuilder {
             .iter()
             .map(|agg_func| {
                 agg_args.push(agg_func.args.clone());
-                AggregateFunctionFactory::instance().get(
-                    agg_func.sig.name.as_str(),
-                    agg_func.sig.params.clone(),
-                    agg_func.sig.args.clone(),
-                )
+                match &agg_func.sig.udaf {
+                    Some(udaf) => Ok(AggregateUdfFunction::create(
+                        &agg_func.sig.name,
+                        udaf.clone(),
+                        self.ctx.get_function_context()?,
+                        self.udf_request_options()?,
+                    )),
+                    None => AggregateFunctionFactory::instance().get(
+                        agg_func.sig.name.as_str(),
+                        agg_func.sig.params.clone(),
+                        agg_func.sig.args.clone(),
+                    ),
+                }
             })
             .collect::<Result<_>>()?;
 
@@ -926,6 +1114,17 @@ impl PipelineBuilder {
                     order_by.clone(),
                     (start_bound, end_bound),
                 )?) as Box<dyn Processor>
+            } else if window.window_frame.units.is_groups() {
+                let start_bound = FrameBound::try_from(&window.window_frame.start_bound)?;
+                let end_bound = FrameBound::try_from(&window.window_frame.end_bound)?;
+                Box::new(TransformWindow::<u64>::try_create_groups(
+                    input,
+                    output,
+                    func.clone(),
+                    partition_by.clone(),
+                    order_by.clone(),
+                    (start_bound, end_bound),
+                )?) as Box<dyn Processor>
             } else {
                 if order_by.len() == 1 {
                     // If the length of order_by is 1, there may be a RANGE frame.
@@ -34,3 +34,5 @@ pub use transforms::TransformResortAddOn;
 pub use transforms::TransformRuntimeFilter;
 pub use transforms::TransformSortPartial;
 pub use transforms::TransformWindow;
+pub use transforms::WasmUdfLimits;
+pub use transforms::WasmUdfModule;
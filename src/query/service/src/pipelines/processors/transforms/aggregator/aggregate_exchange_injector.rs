@@ -246,11 +246,9 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> ExchangeInjector
         let method = &self.method;
         let params = self.aggregator_params.clone();
 
-        if self
-            .ctx
-            .get_settings()
-            .get_spilling_bytes_threshold_per_proc()?
-            != 0
+        let settings = self.ctx.get_settings();
+        if settings.get_spilling_bytes_threshold_per_proc()? != 0
+            || settings.get_spilling_memory_ratio()? != 0
         {
             let operator = DataOperator::instance().operator();
             let location_prefix = format!("_aggregate_spill/{}", self.tenant);
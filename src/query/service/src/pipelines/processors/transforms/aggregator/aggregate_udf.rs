@@ -0,0 +1,479 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_base::base::tokio::time::sleep;
+use common_base::runtime::GlobalIORuntime;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::type_check::check_cast;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::UInt32Type;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FromData;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_expression::Value;
+use common_functions::aggregates::AggregateFunction;
+use common_functions::aggregates::AggregateFunctionRef;
+use common_functions::aggregates::StateAddr;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_io::prelude::deserialize_from_slice;
+use common_io::prelude::serialize_into_buf;
+use common_sql::executor::UdafDesc;
+use parking_lot::Mutex;
+
+use crate::pipelines::processors::transforms::UdfFlightClient;
+use crate::pipelines::processors::transforms::UdfRequestOptions;
+
+/// Number of partial states kept by a group before they are merged by the
+/// UDF server.
+const MAX_PARTIAL_STATES: usize = 16;
+
+/// The state of an aggregate UDF. Each partial state returned by the UDF
+/// server is a row of the declared state fields.
+struct UdafState {
+    states: Vec<Vec<Scalar>>,
+}
+
+/// An aggregate UDF evaluated by a UDF server.
+///
+/// The phase is the second element of the path of the flight descriptor:
+/// - `accumulate`: takes a `UInt32` group column followed by the arguments,
+///   and returns the state fields of each group.
+/// - `merge`: takes a group column followed by the state fields, and returns
+///   the merged state of each group. A row of NULLs is the initial state.
+/// - `finalize`: takes the state fields, and returns the result of each row.
+///
+/// The groups of a request are numbered from 0. The states are serialized by
+/// the aggregator like the ones of the builtin functions, so the function
+/// works in the two phases of a distributed aggregation.
+pub struct AggregateUdfFunction {
+    display_name: String,
+    udaf: UdafDesc,
+    func_ctx: FunctionContext,
+    options: UdfRequestOptions,
+    client: Mutex<Option<UdfFlightClient>>,
+}
+
+impl AggregateUdfFunction {
+    pub fn create(
+        display_name: &str,
+        udaf: UdafDesc,
+        func_ctx: FunctionContext,
+        options: UdfRequestOptions,
+    ) -> AggregateFunctionRef {
+        Arc::new(AggregateUdfFunction {
+            display_name: display_name.to_string(),
+            udaf,
+            func_ctx,
+            options,
+            client: Mutex::new(None),
+        })
+    }
+
+    fn call(&self, phase: &str, block: DataBlock) -> Result<DataBlock> {
+        let client = self.client.lock().take();
+        let addr = self.udaf.server_addr.clone();
+        let path = vec![self.udaf.handler.clone(), phase.to_string()];
+        let func_name = format!("{} ({phase})", self.udaf.handler);
+        let options = self.options.clone();
+        let (client, result) = GlobalIORuntime::instance().block_on(async move {
+            let mut client = client;
+            let mut retries = 0;
+            loop {
+                if client.is_none() {
+                    match UdfFlightClient::connect(&addr, &options).await {
+                        Ok(new_client) => client = Some(new_client),
+                        Err(e) if retries < options.max_retries => {
+                            retries += 1;
+                            tracing::warn!("{e}, retry {retries}");
+                            sleep(Duration::from_millis(100 * retries)).await;
+                            continue;
+                        }
+                        Err(e) => return Err(e),
+                    }
+                }
+                let result = client
+                    .as_mut()
+                    .unwrap()
+                    .exchange(path.clone(), &func_name, block.clone())
+                    .await;
+                match result {
+                    Ok(result) => return Ok((client, result)),
+                    Err(e) if retries < options.max_retries => {
+                        retries += 1;
+                        tracing::warn!("{e}, retry {retries}");
+                        // The connection may be broken.
+                        client = None;
+                        sleep(Duration::from_millis(100 * retries)).await;
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+        })?;
+
+        let mut slot = self.client.lock();
+        if slot.is_none() {
+            *slot = client;
+        }
+        Ok(result)
+    }
+
+    /// Calls a phase returning a column per state field, and returns the
+    /// state of each group.
+    fn call_states(
+        &self,
+        phase: &str,
+        block: DataBlock,
+        num_groups: usize,
+    ) -> Result<Vec<Vec<Scalar>>> {
+        let result = self.call(phase, block)?;
+        let num_fields = self.udaf.state_fields.len();
+        if result.num_columns() != num_fields || result.num_rows() != num_groups {
+            return Err(ErrorCode::UDFServerError(format!(
+                "function {} should return {num_fields} columns and {num_groups} rows in phase {phase}, but got {} columns and {} rows",
+                self.udaf.handler,
+                result.num_columns(),
+                result.num_rows()
+            )));
+        }
+        let columns = result
+            .columns()
+            .iter()
+            .zip(self.udaf.state_fields.iter())
+            .map(|(entry, (_, data_type))| self.cast_column(entry, num_groups, data_type))
+            .collect::<Result<Vec<_>>>()?;
+        Ok((0..num_groups)
+            .map(|row| {
+                columns
+                    .iter()
+                    .map(|column| column.index(row).unwrap().to_owned())
+                    .collect()
+            })
+            .collect())
+    }
+
+    fn cast_column(
+        &self,
+        entry: &BlockEntry,
+        num_rows: usize,
+        data_type: &DataType,
+    ) -> Result<Column> {
+        let block = DataBlock::new(vec![entry.clone()], num_rows);
+        let cast = check_cast(
+            None,
+            false,
+            Expr::ColumnRef {
+                span: None,
+                id: 0,
+                data_type: entry.data_type.clone(),
+                display_name: "".to_string(),
+            },
+            data_type,
+            &BUILTIN_FUNCTIONS,
+        )?;
+        let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let value = evaluator.run(&cast)?;
+        Ok(value.convert_to_full_column(data_type, num_rows))
+    }
+
+    fn state_entries(&self, builders: Vec<ColumnBuilder>) -> Vec<BlockEntry> {
+        builders
+            .into_iter()
+            .zip(self.udaf.state_fields.iter())
+            .map(|(builder, (_, data_type))| BlockEntry {
+                data_type: data_type.clone(),
+                value: Value::Column(builder.build()),
+            })
+            .collect()
+    }
+
+    /// Accumulates the rows into the states, `places` has a state per row.
+    fn accumulate_places(&self, places: &[StateAddr], columns: &[Column]) -> Result<()> {
+        let batch_rows = self.options.batch_rows.max(1);
+        for start in (0..places.len()).step_by(batch_rows) {
+            let end = (start + batch_rows).min(places.len());
+            let mut groups = Vec::new();
+            let mut group_ids = HashMap::new();
+            let mut ids = Vec::with_capacity(end - start);
+            for place in &places[start..end] {
+                let id = *group_ids.entry(place.addr()).or_insert_with(|| {
+                    groups.push(*place);
+                    groups.len() as u32 - 1
+                });
+                ids.push(id);
+            }
+
+            let mut entries = Vec::with_capacity(columns.len() + 1);
+            entries.push(BlockEntry {
+                data_type: DataType::Number(NumberDataType::UInt32),
+                value: Value::Column(UInt32Type::from_data(ids)),
+            });
+            for column in columns {
+                let column = column.slice(start..end);
+                entries.push(BlockEntry {
+                    data_type: column.data_type(),
+                    value: Value::Column(column),
+                });
+            }
+            let block = DataBlock::new(entries, end - start);
+            let states = self.call_states("accumulate", block, groups.len())?;
+
+            let mut full_places = Vec::new();
+            for (place, state) in groups.into_iter().zip(states) {
+                let udaf_state = place.get::<UdafState>();
+                udaf_state.states.push(state);
+                if udaf_state.states.len() >= MAX_PARTIAL_STATES {
+                    full_places.push(place);
+                }
+            }
+            self.compact(&full_places)?;
+        }
+        Ok(())
+    }
+
+    /// Merges the partial states of each place into one.
+    fn compact(&self, places: &[StateAddr]) -> Result<()> {
+        if places.is_empty() {
+            return Ok(());
+        }
+        let groups = places
+            .iter()
+            .map(|place| place.get::<UdafState>().states.as_slice())
+            .collect::<Vec<_>>();
+        let merged = self.merge_states(&groups)?;
+        for (place, state) in places.iter().zip(merged) {
+            place.get::<UdafState>().states = vec![state];
+        }
+        Ok(())
+    }
+
+    /// Returns the merged state of each group of partial states.
+    fn merge_states(&self, groups: &[&[Vec<Scalar>]]) -> Result<Vec<Vec<Scalar>>> {
+        let batch_rows = self.options.batch_rows.max(1);
+        let mut merged = Vec::with_capacity(groups.len());
+        let mut start = 0;
+        while start < groups.len() {
+            // A group is never split between two requests.
+            let mut end = start;
+            let mut num_rows = 0;
+            while end < groups.len() && (end == start || num_rows < batch_rows) {
+                num_rows += groups[end].len().max(1);
+                end += 1;
+            }
+
+            let mut ids = Vec::with_capacity(num_rows);
+            let mut builders = self
+                .udaf
+                .state_fields
+                .iter()
+                .map(|(_, data_type)| ColumnBuilder::with_capacity(data_type, num_rows))
+                .collect::<Vec<_>>();
+            for (id, states) in groups[start..end].iter().enumerate() {
+                if states.is_empty() {
+                    ids.push(id as u32);
+                    for builder in builders.iter_mut() {
+                        builder.push(ScalarRef::Null);
+                    }
+                }
+                for state in states.iter() {
+                    ids.push(id as u32);
+                    for (builder, value) in builders.iter_mut().zip(state.iter()) {
+                        builder.push(value.as_ref());
+                    }
+                }
+            }
+
+            let mut entries = Vec::with_capacity(builders.len() + 1);
+            entries.push(BlockEntry {
+                data_type: DataType::Number(NumberDataType::UInt32),
+                value: Value::Column(UInt32Type::from_data(ids)),
+            });
+            entries.extend(self.state_entries(builders));
+            let block = DataBlock::new(entries, num_rows);
+            merged.extend(self.call_states("merge", block, end - start)?);
+            start = end;
+        }
+        Ok(merged)
+    }
+}
+
+impl AggregateFunction for AggregateUdfFunction {
+    fn name(&self) -> &str {
+        "AggregateUdfFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(self.udaf.return_type.clone())
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| UdafState { states: vec![] });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<UdafState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        let (columns, input_rows) = match validity {
+            Some(validity) => (
+                columns
+                    .iter()
+                    .map(|column| column.filter(validity))
+                    .collect::<Vec<_>>(),
+                input_rows - validity.unset_bits(),
+            ),
+            None => (columns.to_vec(), input_rows),
+        };
+        if input_rows == 0 {
+            return Ok(());
+        }
+        self.accumulate_places(&vec![place; input_rows], &columns)
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[Column],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let places = places
+            .iter()
+            .map(|place| place.next(offset))
+            .collect::<Vec<_>>();
+        self.accumulate_places(&places, columns)
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let columns = columns
+            .iter()
+            .map(|column| column.slice(row..row + 1))
+            .collect::<Vec<_>>();
+        self.accumulate_places(&[place], &columns)
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<UdafState>();
+        serialize_into_buf(writer, &state.states)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<UdafState>();
+        state.states = deserialize_from_slice(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let rhs = rhs.get::<UdafState>();
+        let state = place.get::<UdafState>();
+        state.states.extend(rhs.states.iter().cloned());
+        if state.states.len() >= MAX_PARTIAL_STATES {
+            self.compact(&[place])?;
+        }
+        Ok(())
+    }
+
+    fn batch_merge_result(&self, places: &[StateAddr], builder: &mut ColumnBuilder) -> Result<()> {
+        // The groups with a single partial state don't need to be merged.
+        let mut states = vec![None; places.len()];
+        let mut unmerged = Vec::new();
+        for (i, place) in places.iter().enumerate() {
+            let state = place.get::<UdafState>();
+            if state.states.len() == 1 {
+                states[i] = Some(state.states[0].clone());
+            } else {
+                unmerged.push(i);
+            }
+        }
+        let groups = unmerged
+            .iter()
+            .map(|i| places[*i].get::<UdafState>().states.as_slice())
+            .collect::<Vec<_>>();
+        let merged = self.merge_states(&groups)?;
+        for (i, state) in unmerged.into_iter().zip(merged) {
+            states[i] = Some(state);
+        }
+
+        let batch_rows = self.options.batch_rows.max(1);
+        for batch in states.chunks(batch_rows) {
+            let mut builders = self
+                .udaf
+                .state_fields
+                .iter()
+                .map(|(_, data_type)| ColumnBuilder::with_capacity(data_type, batch.len()))
+                .collect::<Vec<_>>();
+            for state in batch.iter() {
+                for (builder, value) in builders.iter_mut().zip(state.as_ref().unwrap().iter()) {
+                    builder.push(value.as_ref());
+                }
+            }
+            let block = DataBlock::new(self.state_entries(builders), batch.len());
+            let result = self.call("finalize", block)?;
+            if result.num_columns() != 1 || result.num_rows() != batch.len() {
+                return Err(ErrorCode::UDFServerError(format!(
+                    "function {} should return 1 column and {} rows in phase finalize, but got {} columns and {} rows",
+                    self.udaf.handler,
+                    batch.len(),
+                    result.num_columns(),
+                    result.num_rows()
+                )));
+            }
+            let column =
+                self.cast_column(result.get_by_offset(0), batch.len(), &self.udaf.return_type)?;
+            builder.append_column(&column);
+        }
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        self.batch_merge_result(&[place], builder)
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<UdafState>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+impl fmt::Display for AggregateUdfFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
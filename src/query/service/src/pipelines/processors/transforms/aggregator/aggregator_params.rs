@@ -17,7 +17,9 @@ use std::sync::Arc;
 
 use common_exception::Result;
 use common_expression::types::DataType;
+use common_expression::DataField;
 use common_expression::DataSchemaRef;
+use common_expression::DataSchemaRefExt;
 use common_functions::aggregates::get_layout_offsets;
 use common_functions::aggregates::AggregateFunctionRef;
 use common_functions::aggregates::StateAddr;
@@ -40,6 +42,11 @@ pub struct AggregatorParams {
 
     // Limit is push down to AggregatorTransform
     pub limit: Option<usize>,
+
+    // If the input are the group columns followed by the serialized partial aggregate
+    // states (e.g. read from aggregating index), the states are merged instead of
+    // accumulating the arguments.
+    pub merge_states: bool,
 }
 
 impl AggregatorParams {
@@ -67,9 +74,41 @@ impl AggregatorParams {
             layout: states_layout,
             offsets_aggregate_states: states_offsets,
             limit,
+            merge_states: false,
         }))
     }
 
+    /// Create the params merging the serialized partial aggregate states,
+    /// the input are the group columns followed by the states of each aggregate function.
+    pub fn with_merge_states(&self) -> Arc<AggregatorParams> {
+        let group_len = self.group_columns.len();
+        let mut fields = self
+            .group_columns
+            .iter()
+            .map(|i| self.input_schema.field(*i).clone())
+            .collect::<Vec<_>>();
+        fields.extend(
+            self.aggregate_functions
+                .iter()
+                .enumerate()
+                .map(|(i, _)| DataField::new(&format!("_state_{i}"), DataType::String)),
+        );
+
+        Arc::new(AggregatorParams {
+            input_schema: DataSchemaRefExt::create(fields),
+            group_columns: (0..group_len).collect(),
+            group_data_types: self.group_data_types.clone(),
+            aggregate_functions: self.aggregate_functions.clone(),
+            aggregate_functions_arguments: (0..self.aggregate_functions.len())
+                .map(|i| vec![group_len + i])
+                .collect(),
+            layout: self.layout,
+            offsets_aggregate_states: self.offsets_aggregate_states.clone(),
+            limit: self.limit,
+            merge_states: true,
+        })
+    }
+
     pub fn alloc_layout(&self, area: &mut Area) -> StateAddr {
         let layout = self.layout.unwrap();
         let place = Into::<StateAddr>::into(area.alloc_layout(layout));
@@ -15,6 +15,7 @@
 mod aggregate_cell;
 mod aggregate_exchange_injector;
 mod aggregate_meta;
+mod aggregate_udf;
 mod aggregator_params;
 mod serde;
 mod transform_aggregate_expand;
@@ -29,6 +30,7 @@ mod utils;
 pub use aggregate_cell::HashTableCell;
 pub use aggregate_cell::PartitionedHashTableDropper;
 pub use aggregate_exchange_injector::AggregateInjector;
+pub use aggregate_udf::AggregateUdfFunction;
 pub use aggregator_params::AggregatorParams;
 pub use transform_aggregate_expand::TransformExpandGroupingSets;
 pub use transform_aggregate_final::TransformFinalAggregate;
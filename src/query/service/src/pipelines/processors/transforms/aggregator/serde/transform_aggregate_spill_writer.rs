@@ -27,6 +27,7 @@ use common_pipeline_core::processors::port::InputPort;
 use common_pipeline_core::processors::port::OutputPort;
 use common_pipeline_core::processors::processor::Event;
 use common_pipeline_core::processors::Processor;
+use common_profile::ProcessorStats;
 use futures_util::future::BoxFuture;
 use opendal::Operator;
 use tracing::info;
@@ -47,7 +48,7 @@ pub struct TransformAggregateSpillWriter<Method: HashMethodBounds> {
     location_prefix: String,
     output_block: Option<DataBlock>,
     spilling_meta: Option<AggregateMeta<Method, usize>>,
-    spilling_future: Option<BoxFuture<'static, Result<()>>>,
+    spilling_future: Option<BoxFuture<'static, Result<usize>>>,
 }
 
 impl<Method: HashMethodBounds> TransformAggregateSpillWriter<Method> {
@@ -163,7 +164,8 @@ impl<Method: HashMethodBounds> Processor for TransformAggregateSpillWriter<Metho
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
         if let Some(spilling_future) = self.spilling_future.take() {
-            return spilling_future.await;
+            let spilled_bytes = spilling_future.await?;
+            ProcessorStats::record_spill(spilled_bytes);
         }
 
         Ok(())
@@ -201,7 +203,7 @@ pub fn spilling_aggregate_payload<Method: HashMethodBounds>(
     location_prefix: &str,
     params: &Arc<AggregatorParams>,
     payload: HashTablePayload<Method, usize>,
-) -> Result<(DataBlock, BoxFuture<'static, Result<()>>)> {
+) -> Result<(DataBlock, BoxFuture<'static, Result<usize>>)> {
     let (bucket, total_size, data) = serialize_spill_file(method, params, payload)?;
 
     let unique_name = GlobalUniqName::unique();
@@ -231,7 +233,7 @@ pub fn spilling_aggregate_payload<Method: HashMethodBounds>(
                 instant.elapsed()
             );
 
-            Ok(())
+            Ok(total_size)
         }),
     ))
 }
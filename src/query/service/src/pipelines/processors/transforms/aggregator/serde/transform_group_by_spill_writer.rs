@@ -27,6 +27,7 @@ use common_pipeline_core::processors::port::InputPort;
 use common_pipeline_core::processors::port::OutputPort;
 use common_pipeline_core::processors::processor::Event;
 use common_pipeline_core::processors::Processor;
+use common_profile::ProcessorStats;
 use futures_util::future::BoxFuture;
 use opendal::Operator;
 use tracing::info;
@@ -45,7 +46,7 @@ pub struct TransformGroupBySpillWriter<Method: HashMethodBounds> {
     location_prefix: String,
     output_block: Option<DataBlock>,
     spilling_meta: Option<AggregateMeta<Method, ()>>,
-    spilling_future: Option<BoxFuture<'static, Result<()>>>,
+    spilling_future: Option<BoxFuture<'static, Result<usize>>>,
 }
 
 impl<Method: HashMethodBounds> TransformGroupBySpillWriter<Method> {
@@ -160,7 +161,8 @@ impl<Method: HashMethodBounds> Processor for TransformGroupBySpillWriter<Method>
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
         if let Some(spilling_future) = self.spilling_future.take() {
-            return spilling_future.await;
+            let spilled_bytes = spilling_future.await?;
+            ProcessorStats::record_spill(spilled_bytes);
         }
 
         Ok(())
@@ -196,7 +198,7 @@ pub fn spilling_group_by_payload<Method: HashMethodBounds>(
     method: &Method,
     location_prefix: &str,
     payload: HashTablePayload<Method, ()>,
-) -> Result<(DataBlock, BoxFuture<'static, Result<()>>)> {
+) -> Result<(DataBlock, BoxFuture<'static, Result<usize>>)> {
     let (bucket, total_size, data) = serialize_spill_file(method, payload)?;
 
     let unique_name = GlobalUniqName::unique();
@@ -226,7 +228,7 @@ pub fn spilling_group_by_payload<Method: HashMethodBounds>(
                 instant.elapsed()
             );
 
-            Ok(())
+            Ok(total_size)
         }),
     ))
 }
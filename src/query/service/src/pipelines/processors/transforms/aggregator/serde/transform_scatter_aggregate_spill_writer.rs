@@ -22,6 +22,7 @@ use common_pipeline_core::processors::port::InputPort;
 use common_pipeline_core::processors::port::OutputPort;
 use common_pipeline_core::processors::processor::Event;
 use common_pipeline_core::processors::Processor;
+use common_profile::ProcessorStats;
 use futures_util::future::BoxFuture;
 use opendal::Operator;
 
@@ -41,7 +42,7 @@ pub struct TransformScatterAggregateSpillWriter<Method: HashMethodBounds> {
     params: Arc<AggregatorParams>,
     input_data_block: Option<DataBlock>,
     output_data_block: Option<DataBlock>,
-    spilling_futures: Vec<BoxFuture<'static, Result<()>>>,
+    spilling_futures: Vec<BoxFuture<'static, Result<usize>>>,
 }
 
 impl<Method: HashMethodBounds> TransformScatterAggregateSpillWriter<Method> {
@@ -164,7 +165,8 @@ impl<Method: HashMethodBounds> Processor for TransformScatterAggregateSpillWrite
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
         let spilling_futures = std::mem::take(&mut self.spilling_futures);
-        futures::future::try_join_all(spilling_futures).await?;
+        let spilled_bytes = futures::future::try_join_all(spilling_futures).await?;
+        ProcessorStats::record_spill(spilled_bytes.into_iter().sum());
         Ok(())
     }
 }
@@ -15,6 +15,7 @@
 use std::sync::Arc;
 use std::vec;
 
+use common_base::runtime::GLOBAL_MEM_STAT;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -32,6 +33,7 @@ use common_pipeline_transforms::processors::transforms::AccumulatingTransformer;
 
 use crate::pipelines::processors::transforms::aggregator::aggregate_cell::AggregateHashTableDropper;
 use crate::pipelines::processors::transforms::aggregator::aggregate_meta::AggregateMeta;
+use crate::pipelines::processors::transforms::aggregator::utils::merge_serialized_states;
 use crate::pipelines::processors::transforms::group_by::HashMethodBounds;
 use crate::pipelines::processors::transforms::group_by::PartitionedHashMethod;
 use crate::pipelines::processors::transforms::group_by::PolymorphicKeysHelper;
@@ -56,6 +58,8 @@ impl<Method: HashMethodBounds> Default for HashTable<Method> {
 struct AggregateSettings {
     convert_threshold: usize,
     spilling_bytes_threshold_per_proc: usize,
+    // The process memory usage in bytes above which the hash table is spilled.
+    spilling_memory_threshold: usize,
 }
 
 impl TryFrom<Arc<QueryContext>> for AggregateSettings {
@@ -65,6 +69,8 @@ impl TryFrom<Arc<QueryContext>> for AggregateSettings {
         let settings = ctx.get_settings();
         let convert_threshold = settings.get_group_by_two_level_threshold()? as usize;
         let value = settings.get_spilling_bytes_threshold_per_proc()?;
+        let ratio = settings.get_spilling_memory_ratio()?.min(100);
+        let max_memory_usage = settings.get_max_memory_usage()? as usize;
 
         Ok(AggregateSettings {
             convert_threshold,
@@ -72,10 +78,21 @@ impl TryFrom<Arc<QueryContext>> for AggregateSettings {
                 true => usize::MAX,
                 false => value,
             },
+            spilling_memory_threshold: match ratio == 0 || max_memory_usage == 0 {
+                true => usize::MAX,
+                false => max_memory_usage / 100 * ratio,
+            },
         })
     }
 }
 
+impl AggregateSettings {
+    fn need_spill(&self, allocated_bytes: usize) -> bool {
+        allocated_bytes > self.spilling_bytes_threshold_per_proc
+            || GLOBAL_MEM_STAT.get_memory_usage() as usize >= self.spilling_memory_threshold
+    }
+}
+
 // SELECT column_name, agg(xxx) FROM table_name GROUP BY column_name
 pub struct TransformPartialAggregate<Method: HashMethodBounds> {
     method: Method,
@@ -165,7 +182,11 @@ impl<Method: HashMethodBounds> TransformPartialAggregate<Method> {
             let function = &aggregate_functions[index];
             let state_offset = offsets_aggregate_states[index];
             let function_arguments = &aggr_arg_columns_slice[index];
-            function.accumulate_keys(places, state_offset, function_arguments, rows)?;
+            if params.merge_states {
+                merge_serialized_states(function, places, state_offset, &function_arguments[0])?;
+            } else {
+                function.accumulate_keys(places, state_offset, function_arguments, rows)?;
+            }
         }
 
         Ok(())
@@ -235,7 +256,7 @@ impl<Method: HashMethodBounds> AccumulatingTransform for TransformPartialAggrega
         if Method::SUPPORT_PARTITIONED {
             if matches!(&self.hash_table, HashTable::HashTable(cell)
                 if cell.len() >= self.settings.convert_threshold ||
-                    cell.allocated_bytes() >= self.settings.spilling_bytes_threshold_per_proc
+                    self.settings.need_spill(cell.allocated_bytes())
             ) {
                 if let HashTable::HashTable(cell) = std::mem::take(&mut self.hash_table) {
                     self.hash_table = HashTable::PartitionedHashTable(
@@ -244,7 +265,7 @@ impl<Method: HashMethodBounds> AccumulatingTransform for TransformPartialAggrega
                 }
             }
 
-            if matches!(&self.hash_table, HashTable::PartitionedHashTable(cell) if cell.allocated_bytes() > self.settings.spilling_bytes_threshold_per_proc)
+            if matches!(&self.hash_table, HashTable::PartitionedHashTable(cell) if self.settings.need_spill(cell.allocated_bytes()))
             {
                 if let HashTable::PartitionedHashTable(v) = std::mem::take(&mut self.hash_table) {
                     let _dropper = v._dropper.clone();
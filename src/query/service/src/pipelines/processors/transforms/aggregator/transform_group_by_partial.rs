@@ -15,6 +15,7 @@
 use std::sync::Arc;
 use std::vec;
 
+use common_base::runtime::GLOBAL_MEM_STAT;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -53,6 +54,8 @@ impl<Method: HashMethodBounds> Default for HashTable<Method> {
 struct GroupBySettings {
     convert_threshold: usize,
     spilling_bytes_threshold_per_proc: usize,
+    // The process memory usage in bytes above which the hash table is spilled.
+    spilling_memory_threshold: usize,
 }
 
 impl TryFrom<Arc<QueryContext>> for GroupBySettings {
@@ -62,6 +65,8 @@ impl TryFrom<Arc<QueryContext>> for GroupBySettings {
         let settings = ctx.get_settings();
         let convert_threshold = settings.get_group_by_two_level_threshold()? as usize;
         let value = settings.get_spilling_bytes_threshold_per_proc()?;
+        let ratio = settings.get_spilling_memory_ratio()?.min(100);
+        let max_memory_usage = settings.get_max_memory_usage()? as usize;
 
         Ok(GroupBySettings {
             convert_threshold,
@@ -69,10 +74,21 @@ impl TryFrom<Arc<QueryContext>> for GroupBySettings {
                 true => usize::MAX,
                 false => value,
             },
+            spilling_memory_threshold: match ratio == 0 || max_memory_usage == 0 {
+                true => usize::MAX,
+                false => max_memory_usage / 100 * ratio,
+            },
         })
     }
 }
 
+impl GroupBySettings {
+    fn need_spill(&self, allocated_bytes: usize) -> bool {
+        allocated_bytes > self.spilling_bytes_threshold_per_proc
+            || GLOBAL_MEM_STAT.get_memory_usage() as usize >= self.spilling_memory_threshold
+    }
+}
+
 // SELECT column_name FROM table_name GROUP BY column_name
 pub struct TransformPartialGroupBy<Method: HashMethodBounds> {
     method: Method,
@@ -144,7 +160,7 @@ impl<Method: HashMethodBounds> AccumulatingTransform for TransformPartialGroupBy
             if Method::SUPPORT_PARTITIONED {
                 if matches!(&self.hash_table, HashTable::HashTable(cell)
                     if cell.len() >= self.settings.convert_threshold ||
-                        cell.allocated_bytes() >= self.settings.spilling_bytes_threshold_per_proc
+                        self.settings.need_spill(cell.allocated_bytes())
                 ) {
                     if let HashTable::HashTable(cell) = std::mem::take(&mut self.hash_table) {
                         self.hash_table = HashTable::PartitionedHashTable(
@@ -153,7 +169,7 @@ impl<Method: HashMethodBounds> AccumulatingTransform for TransformPartialGroupBy
                     }
                 }
 
-                if matches!(&self.hash_table, HashTable::PartitionedHashTable(cell) if cell.allocated_bytes() > self.settings.spilling_bytes_threshold_per_proc)
+                if matches!(&self.hash_table, HashTable::PartitionedHashTable(cell) if self.settings.need_spill(cell.allocated_bytes()))
                 {
                     if let HashTable::PartitionedHashTable(v) = std::mem::take(&mut self.hash_table)
                     {
@@ -34,6 +34,7 @@ use common_pipeline_core::processors::Processor;
 use common_pipeline_transforms::processors::transforms::transform_accumulating::AccumulatingTransform;
 use common_pipeline_transforms::processors::transforms::transform_accumulating::AccumulatingTransformer;
 
+use crate::pipelines::processors::transforms::aggregator::utils::merge_serialized_states;
 use crate::pipelines::processors::AggregatorParams;
 
 /// SELECT COUNT | SUM FROM table;
@@ -43,6 +44,7 @@ pub struct PartialSingleStateAggregator {
     places: Vec<StateAddr>,
     arg_indices: Vec<Vec<usize>>,
     funcs: Vec<AggregateFunctionRef>,
+    merge_states: bool,
 }
 
 impl PartialSingleStateAggregator {
@@ -75,6 +77,7 @@ impl PartialSingleStateAggregator {
                 places,
                 funcs: params.aggregate_functions.clone(),
                 arg_indices: params.aggregate_functions_arguments.clone(),
+                merge_states: params.merge_states,
             },
         ))
     }
@@ -99,7 +102,12 @@ impl AccumulatingTransform for PartialSingleStateAggregator {
                 );
             }
             let place = self.places[idx];
-            func.accumulate(place, &arg_columns, None, block.num_rows())?;
+            if self.merge_states {
+                let places = vec![place; block.num_rows()];
+                merge_serialized_states(func, &places, 0, &arg_columns[0])?;
+            } else {
+                func.accumulate(place, &arg_columns, None, block.num_rows())?;
+            }
         }
 
         Ok(vec![])
@@ -12,9 +12,46 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use bumpalo::Bump;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::Column;
+use common_functions::aggregates::AggregateFunctionRef;
+use common_functions::aggregates::StateAddr;
 use common_hashtable::HashtableLike;
 
 #[inline]
 pub fn estimated_key_size<Table: HashtableLike>(table: &Table) -> usize {
     table.unsize_key_size().unwrap_or_default()
 }
+
+/// Merge the serialized aggregate state of each row in `column` into the state at
+/// `state_offset` of the place of the row.
+pub fn merge_serialized_states(
+    function: &AggregateFunctionRef,
+    places: &[StateAddr],
+    state_offset: usize,
+    column: &Column,
+) -> Result<()> {
+    let states = column
+        .as_string()
+        .ok_or_else(|| ErrorCode::IllegalDataType("binary array should be string type"))?;
+
+    let arena = Bump::new();
+    let layout = function.state_layout();
+    for (row, place) in places.iter().enumerate() {
+        let rhs: StateAddr = arena.alloc_layout(layout).into();
+        function.init_state(rhs);
+
+        let mut data = unsafe { states.index_unchecked(row) };
+        let res = function
+            .deserialize(rhs, &mut data)
+            .and_then(|_| function.merge(place.next(state_offset), rhs));
+
+        if function.need_manual_drop_state() {
+            unsafe { function.drop_state(rhs) }
+        }
+        res?;
+    }
+    Ok(())
+}
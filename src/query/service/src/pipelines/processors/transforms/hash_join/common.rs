@@ -18,6 +18,7 @@ use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_expression::arrow::constant_bitmap;
 use common_expression::arrow::or_validities;
+use common_expression::type_check::check_function;
 use common_expression::types::nullable::NullableColumn;
 use common_expression::types::AnyType;
 use common_expression::types::BooleanType;
@@ -28,7 +29,9 @@ use common_expression::DataBlock;
 use common_expression::Evaluator;
 use common_expression::Expr;
 use common_expression::Scalar;
+use common_expression::ScalarRef;
 use common_expression::Value;
+use common_functions::aggregates::eval_aggr;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_hashtable::HashJoinHashtableLike;
 use common_hashtable::MarkerKind;
@@ -241,23 +244,146 @@ impl JoinHashTable {
     }
 
     // Add `data_block` for build table to `row_space`
-    pub(crate) fn add_build_block(&self, data_block: DataBlock) -> Result<()> {
-        let mut data_block = data_block;
-        if matches!(
+    // Wrap the columns of build block to nullable for left and full join.
+    pub(crate) fn build_block_wrap_nullable(&self, data_block: DataBlock) -> DataBlock {
+        if !matches!(
             self.hash_join_desc.join_type,
             JoinType::Left | JoinType::Full
         ) {
-            let mut validity = MutableBitmap::new();
-            validity.extend_constant(data_block.num_rows(), true);
-            let validity: Bitmap = validity.into();
+            return data_block;
+        }
+
+        let mut validity = MutableBitmap::new();
+        validity.extend_constant(data_block.num_rows(), true);
+        let validity: Bitmap = validity.into();
+
+        let nullable_columns = data_block
+            .columns()
+            .iter()
+            .map(|c| Self::set_validity(c, validity.len(), &validity))
+            .collect::<Vec<_>>();
+        DataBlock::new(nullable_columns, data_block.num_rows())
+    }
+
+    // Wrap the columns of probe block to nullable for right and full join.
+    pub(crate) fn probe_block_wrap_nullable(&self, input: &DataBlock) -> DataBlock {
+        if !matches!(
+            self.hash_join_desc.join_type,
+            JoinType::Right | JoinType::Full
+        ) {
+            return input.clone();
+        }
+
+        let mut validity = MutableBitmap::new();
+        validity.extend_constant(input.num_rows(), true);
+        let validity: Bitmap = validity.into();
+
+        let nullable_columns = input
+            .columns()
+            .iter()
+            .map(|c| Self::set_validity(c, validity.len(), &validity))
+            .collect::<Vec<_>>();
+        DataBlock::new(nullable_columns, input.num_rows())
+    }
+
+    // Buffer the build block, and add the buffered blocks to row space once they exceed the limit.
+    pub(crate) fn buffer_build_block(&self, input: DataBlock) -> Result<()> {
+        let mut buffer = self.row_space.buffer.write();
+        buffer.push(input);
+        let buffer_row_size = buffer.iter().fold(0, |acc, x| acc + x.num_rows());
+        if buffer_row_size < *self.data_block_size_limit {
+            Ok(())
+        } else {
+            let data_block = DataBlock::concat(buffer.as_slice())?;
+            buffer.clear();
+            drop(buffer);
+            self.add_build_block(data_block)
+        }
+    }
+
+    // Generate the min/max filters of build keys and push them down to the scans of probe side,
+    // so the blocks which can't match any row of build side are pruned.
+    pub(crate) fn generate_runtime_filters(&self) -> Result<()> {
+        let func_ctx = self.ctx.get_function_context()?;
+        let chunks = self.row_space.chunks.read();
+        for (build_key, probe_key_rt) in self
+            .hash_join_desc
+            .build_keys
+            .iter()
+            .zip(self.hash_join_desc.probe_keys_rt.iter())
+        {
+            let (probe_key, table_index) = match probe_key_rt {
+                Some(probe_key_rt) => probe_key_rt,
+                None => continue,
+            };
+
+            let mut min_max: Option<(Scalar, Scalar)> = None;
+            for chunk in chunks.iter() {
+                let num_rows = chunk.num_rows();
+                let evaluator = Evaluator::new(&chunk.data_block, &func_ctx, &BUILTIN_FUNCTIONS);
+                let column = evaluator
+                    .run(build_key)?
+                    .convert_to_full_column(build_key.data_type(), num_rows);
+                let (mins, _) = eval_aggr("min", vec![], &[column.clone()], num_rows)?;
+                let (maxs, _) = eval_aggr("max", vec![], &[column], num_rows)?;
+                let (min, max) = match (mins.index(0), maxs.index(0)) {
+                    (Some(min), Some(max)) if !matches!(min, ScalarRef::Null) => {
+                        (min.to_owned(), max.to_owned())
+                    }
+                    _ => continue,
+                };
+                min_max = match min_max {
+                    None => Some((min, max)),
+                    Some((old_min, old_max)) => Some((
+                        if min < old_min { min } else { old_min },
+                        if max > old_max { max } else { old_max },
+                    )),
+                };
+            }
 
-            let nullable_columns = data_block
-                .columns()
-                .iter()
-                .map(|c| Self::set_validity(c, validity.len(), &validity))
-                .collect::<Vec<_>>();
-            data_block = DataBlock::new(nullable_columns, data_block.num_rows());
+            let data_type = build_key.data_type().remove_nullable();
+            let filter = match min_max {
+                // All the supported join types produce nothing if no build key can be matched.
+                None => Expr::Constant {
+                    span: None,
+                    scalar: Scalar::Boolean(false),
+                    data_type: DataType::Boolean,
+                },
+                Some((min, max)) => {
+                    let min = Expr::Constant {
+                        span: None,
+                        scalar: min,
+                        data_type: data_type.clone(),
+                    };
+                    let max = Expr::Constant {
+                        span: None,
+                        scalar: max,
+                        data_type,
+                    };
+                    let gte = check_function(
+                        None,
+                        "gte",
+                        &[],
+                        &[probe_key.clone(), min],
+                        &BUILTIN_FUNCTIONS,
+                    )?;
+                    let lte = check_function(
+                        None,
+                        "lte",
+                        &[],
+                        &[probe_key.clone(), max],
+                        &BUILTIN_FUNCTIONS,
+                    )?;
+                    check_function(None, "and", &[], &[gte, lte], &BUILTIN_FUNCTIONS)?
+                }
+            };
+            self.ctx.set_runtime_filter((*table_index, vec![filter]));
         }
+        Ok(())
+    }
+
+    pub(crate) fn add_build_block(&self, data_block: DataBlock) -> Result<()> {
+        let data_block = self.build_block_wrap_nullable(data_block);
 
         let chunk = Chunk {
             data_block,
@@ -45,6 +45,8 @@ impl JoinState {
 pub struct HashJoinDesc {
     pub(crate) build_keys: Vec<Expr>,
     pub(crate) probe_keys: Vec<Expr>,
+    /// Probe keys over the columns of probe side scans, and the table index of the scans.
+    pub(crate) probe_keys_rt: Vec<Option<(Expr<String>, usize)>>,
     pub(crate) join_type: JoinType,
     pub(crate) other_predicate: Option<Expr>,
     pub(crate) marker_join_desc: MarkJoinDesc,
@@ -68,10 +70,20 @@ impl HashJoinDesc {
             .map(|k| k.as_expr(&BUILTIN_FUNCTIONS))
             .collect();
 
+        let probe_keys_rt: Vec<Option<(Expr<String>, usize)>> = join
+            .probe_keys_rt
+            .iter()
+            .map(|k| {
+                k.as_ref()
+                    .map(|(expr, table_index)| (expr.as_expr(&BUILTIN_FUNCTIONS), *table_index))
+            })
+            .collect();
+
         Ok(HashJoinDesc {
             join_type: join.join_type.clone(),
             build_keys,
             probe_keys,
+            probe_keys_rt,
             other_predicate,
             marker_join_desc: MarkJoinDesc {
                 has_null: RwLock::new(false),
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_exception::Result;
 use common_expression::DataBlock;
 
@@ -99,4 +101,16 @@ pub trait HashJoinState: Send + Sync {
 
     /// Get mark join results.
     fn mark_join_blocks(&self) -> Result<Vec<DataBlock>>;
+
+    /// Whether the build side exceeds `join_spilling_threshold` and is spilled to storage.
+    fn is_spilled(&self) -> bool;
+
+    /// Get one spilled partition to restore.
+    fn restore_task(&self) -> Option<usize>;
+
+    /// Restore the build side of the spilled partition into a new in-memory hash join state.
+    fn restore_build(&self, partition: usize) -> Result<Arc<dyn HashJoinState>>;
+
+    /// Restore the next probe block of the spilled partition.
+    fn restore_probe(&self, partition: usize) -> Result<Option<DataBlock>>;
 }
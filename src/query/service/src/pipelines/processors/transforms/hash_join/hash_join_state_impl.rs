@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::sync::atomic::Ordering;
+use std::sync::Arc;
 
 use common_arrow::arrow::bitmap::Bitmap;
 use common_arrow::arrow::bitmap::MutableBitmap;
@@ -54,20 +55,19 @@ use crate::sql::planner::plans::JoinType;
 #[async_trait::async_trait]
 impl HashJoinState for JoinHashTable {
     fn build(&self, input: DataBlock) -> Result<()> {
-        let mut buffer = self.row_space.buffer.write();
-        buffer.push(input);
-        let buffer_row_size = buffer.iter().fold(0, |acc, x| acc + x.num_rows());
-        if buffer_row_size < *self.data_block_size_limit {
-            Ok(())
-        } else {
-            let data_block = DataBlock::concat(buffer.as_slice())?;
-            buffer.clear();
-            drop(buffer);
-            self.add_build_block(data_block)
+        match self.spill_state() {
+            Some(state) => self.build_or_spill(state, input),
+            None => self.buffer_build_block(input),
         }
     }
 
     fn probe(&self, input: &DataBlock, probe_state: &mut ProbeState) -> Result<Vec<DataBlock>> {
+        if self.is_spilled() {
+            // The probe block is joined after the spilled partitions are restored.
+            self.spill_probe_block(input)?;
+            return Ok(vec![]);
+        }
+
         match self.hash_join_desc.join_type {
             JoinType::Inner
             | JoinType::LeftSemi
@@ -105,9 +105,19 @@ impl HashJoinState for JoinHashTable {
         let mut count = self.build_count.lock();
         *count -= 1;
         if *count == 0 {
+            if let Some(state) = self.spill_state() {
+                if state.is_spilled() {
+                    state.flush_build()?;
+                }
+            }
+
             // Divide the finalize phase into multiple tasks.
             self.generate_finalize_task()?;
 
+            if !self.is_spilled() {
+                self.generate_runtime_filters()?;
+            }
+
             // Get the number of rows of the build side.
             let chunks = self.row_space.chunks.read();
             let mut row_num = 0;
@@ -461,6 +471,13 @@ impl HashJoinState for JoinHashTable {
         let mut count = self.probe_count.lock();
         *count -= 1;
         if *count == 0 {
+            if let Some(state) = self.spill_state() {
+                if state.is_spilled() {
+                    state.flush_probe()?;
+                    state.generate_restore_tasks();
+                }
+            }
+
             // Divide the outer scan phase into multiple tasks.
             self.generate_outer_scan_task()?;
 
@@ -471,6 +488,27 @@ impl HashJoinState for JoinHashTable {
         Ok(())
     }
 
+    fn is_spilled(&self) -> bool {
+        self.spill_state()
+            .map(|state| state.is_spilled())
+            .unwrap_or(false)
+    }
+
+    fn restore_task(&self) -> Option<usize> {
+        self.spill_state().and_then(|state| state.restore_task())
+    }
+
+    fn restore_build(&self, partition: usize) -> Result<Arc<dyn HashJoinState>> {
+        self.restore_partition(partition)
+    }
+
+    fn restore_probe(&self, partition: usize) -> Result<Option<DataBlock>> {
+        match self.spill_state() {
+            Some(state) => state.restore_probe_block(partition),
+            None => Ok(None),
+        }
+    }
+
     fn need_outer_scan(&self) -> bool {
         matches!(
             self.hash_join_desc.join_type,
@@ -19,7 +19,6 @@ use std::sync::atomic::AtomicU32;
 use std::sync::atomic::AtomicUsize;
 use std::sync::Arc;
 
-use common_arrow::arrow::bitmap::Bitmap;
 use common_arrow::arrow::bitmap::MutableBitmap;
 use common_base::base::tokio::sync::Notify;
 use common_exception::ErrorCode;
@@ -48,6 +47,7 @@ use parking_lot::RwLock;
 use super::ProbeState;
 use crate::pipelines::processors::transforms::hash_join::desc::HashJoinDesc;
 use crate::pipelines::processors::transforms::hash_join::row::RowSpace;
+use crate::pipelines::processors::transforms::hash_join::spill::JoinSpillState;
 use crate::pipelines::processors::transforms::hash_join::util::build_schema_wrap_nullable;
 use crate::pipelines::processors::transforms::hash_join::util::probe_schema_wrap_nullable;
 use crate::sessions::QueryContext;
@@ -111,6 +111,8 @@ pub struct JoinHashTable {
     pub(crate) finalize_tasks: Arc<RwLock<VecDeque<(usize, usize)>>>,
     /// OuterScan tasks
     pub(crate) outer_scan_tasks: Arc<RwLock<VecDeque<usize>>>,
+    /// Spill state, only available when `join_spilling_threshold` is set
+    pub(crate) spill_state: Option<JoinSpillState>,
 }
 
 impl JoinHashTable {
@@ -126,13 +128,11 @@ impl JoinHashTable {
             .map(|expr| expr.as_expr(&BUILTIN_FUNCTIONS).data_type().clone())
             .collect::<Vec<_>>();
         let method = DataBlock::choose_hash_method_with_types(&hash_key_types, false)?;
-        Ok(Arc::new(JoinHashTable::try_create(
-            ctx,
-            build_schema,
-            probe_schema,
-            hash_join_desc,
-            method,
-        )?))
+        let spill_state = JoinSpillState::try_create(&ctx, &hash_join_desc)?;
+        let mut join_hash_table =
+            JoinHashTable::try_create(ctx, build_schema, probe_schema, hash_join_desc, method)?;
+        join_hash_table.spill_state = spill_state;
+        Ok(Arc::new(join_hash_table))
     }
 
     pub fn try_create(
@@ -179,6 +179,7 @@ impl JoinHashTable {
             build_worker_num: Arc::new(AtomicU32::new(0)),
             finalize_tasks: Arc::new(RwLock::new(VecDeque::new())),
             outer_scan_tasks: Arc::new(RwLock::new(VecDeque::new())),
+            spill_state: None,
         })
     }
 
@@ -187,23 +188,7 @@ impl JoinHashTable {
         input: &DataBlock,
         probe_state: &mut ProbeState,
     ) -> Result<Vec<DataBlock>> {
-        let mut input = (*input).clone();
-        if matches!(
-            self.hash_join_desc.join_type,
-            JoinType::Right | JoinType::Full
-        ) {
-            let nullable_columns = input
-                .columns()
-                .iter()
-                .map(|c| {
-                    let mut validity = MutableBitmap::new();
-                    validity.extend_constant(input.num_rows(), true);
-                    let validity: Bitmap = validity.into();
-                    Self::set_validity(c, validity.len(), &validity)
-                })
-                .collect::<Vec<_>>();
-            input = DataBlock::new(nullable_columns, input.num_rows());
-        }
+        let input = self.probe_block_wrap_nullable(input);
         let evaluator = Evaluator::new(&input, &probe_state.func_ctx, &BUILTIN_FUNCTIONS);
 
         let probe_keys = self
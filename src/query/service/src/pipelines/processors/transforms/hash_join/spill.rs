@@ -0,0 +1,442 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::VecDeque;
+use std::hash::Hasher;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Instant;
+
+use common_base::base::GlobalUniqName;
+use common_base::runtime::GlobalIORuntime;
+use common_exception::Result;
+use common_expression::arrow::deserialize_column;
+use common_expression::arrow::serialize_column;
+use common_expression::type_check::check_function;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Value;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_profile::ProcessorStats;
+use common_storage::DataOperator;
+use opendal::Operator;
+use parking_lot::Mutex;
+use parking_lot::RwLock;
+use tracing::error;
+use tracing::info;
+
+use crate::api::get_hash_values;
+use crate::pipelines::processors::transforms::hash_join::desc::HashJoinDesc;
+use crate::pipelines::processors::transforms::hash_join::desc::JoinState;
+use crate::pipelines::processors::transforms::hash_join::desc::MarkJoinDesc;
+use crate::pipelines::processors::HashJoinState;
+use crate::pipelines::processors::JoinHashTable;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::plans::JoinType;
+
+/// The number of partitions that both sides of a spilled hash join are divided into.
+pub const JOIN_SPILL_PARTITION_NUM: usize = 16;
+
+struct SpilledBlock {
+    location: String,
+    columns_layout: Vec<usize>,
+    num_rows: usize,
+}
+
+/// Rows of one side of the join, divided into partitions by the hash of join keys.
+/// Rows are buffered in memory and written to storage once a partition has enough rows.
+struct SpilledPartitions {
+    buffers: Mutex<Vec<Vec<DataBlock>>>,
+    files: Mutex<Vec<VecDeque<SpilledBlock>>>,
+}
+
+impl SpilledPartitions {
+    fn create() -> Self {
+        SpilledPartitions {
+            buffers: Mutex::new((0..JOIN_SPILL_PARTITION_NUM).map(|_| vec![]).collect()),
+            files: Mutex::new(
+                (0..JOIN_SPILL_PARTITION_NUM)
+                    .map(|_| VecDeque::new())
+                    .collect(),
+            ),
+        }
+    }
+
+    fn is_empty(&self, partition: usize) -> bool {
+        self.files.lock()[partition].is_empty()
+    }
+}
+
+/// Spill state of a hash join, used when the build side exceeds `join_spilling_threshold`.
+///
+/// Once spilled, both the build side and the probe side are partitioned by the hash of
+/// the join keys and written to storage. After the probe side is finished, each partition
+/// is restored into a separate in-memory hash table and joined independently, like a
+/// grace hash join.
+pub struct JoinSpillState {
+    operator: Operator,
+    location_prefix: String,
+    func_ctx: FunctionContext,
+    threshold: usize,
+    flush_rows: usize,
+    build_bytes: AtomicUsize,
+    spilled: RwLock<bool>,
+    build_hash_keys: Vec<Expr>,
+    probe_hash_keys: Vec<Expr>,
+    build_partitions: SpilledPartitions,
+    probe_partitions: SpilledPartitions,
+    restore_tasks: Mutex<VecDeque<usize>>,
+}
+
+impl JoinSpillState {
+    pub fn try_create(ctx: &Arc<QueryContext>, desc: &HashJoinDesc) -> Result<Option<Self>> {
+        let settings = ctx.get_settings();
+        let threshold = settings.get_join_spilling_threshold()?;
+        // Mark join and cross join need all rows of the build side to produce the result.
+        if threshold == 0
+            || desc.build_keys.is_empty()
+            || matches!(
+                desc.join_type,
+                JoinType::Cross | JoinType::LeftMark | JoinType::RightMark
+            )
+        {
+            return Ok(None);
+        }
+
+        let siphash = |keys: &[Expr]| {
+            keys.iter()
+                .map(|key| check_function(None, "siphash", &[], &[key.clone()], &BUILTIN_FUNCTIONS))
+                .collect::<Result<Vec<_>>>()
+        };
+
+        Ok(Some(JoinSpillState {
+            operator: DataOperator::instance().operator(),
+            location_prefix: format!("_hash_join_spill/{}", ctx.get_tenant()),
+            func_ctx: ctx.get_function_context()?,
+            threshold,
+            flush_rows: settings.get_max_block_size()? as usize,
+            build_bytes: AtomicUsize::new(0),
+            spilled: RwLock::new(false),
+            build_hash_keys: siphash(&desc.build_keys)?,
+            probe_hash_keys: siphash(&desc.probe_keys)?,
+            build_partitions: SpilledPartitions::create(),
+            probe_partitions: SpilledPartitions::create(),
+            restore_tasks: Mutex::new(VecDeque::new()),
+        }))
+    }
+
+    pub fn is_spilled(&self) -> bool {
+        *self.spilled.read()
+    }
+
+    pub fn spill_build_block(&self, block: DataBlock) -> Result<()> {
+        self.spill_block(&self.build_partitions, &self.build_hash_keys, block)
+    }
+
+    pub fn spill_probe_block(&self, block: DataBlock) -> Result<()> {
+        self.spill_block(&self.probe_partitions, &self.probe_hash_keys, block)
+    }
+
+    pub fn flush_build(&self) -> Result<()> {
+        self.flush(&self.build_partitions)
+    }
+
+    pub fn flush_probe(&self) -> Result<()> {
+        self.flush(&self.probe_partitions)
+    }
+
+    pub fn generate_restore_tasks(&self) {
+        let mut tasks = self.restore_tasks.lock();
+        for partition in 0..JOIN_SPILL_PARTITION_NUM {
+            // Partitions without rows on either side can't produce any result.
+            if !self.build_partitions.is_empty(partition)
+                || !self.probe_partitions.is_empty(partition)
+            {
+                tasks.push_back(partition);
+            }
+        }
+    }
+
+    pub fn restore_task(&self) -> Option<usize> {
+        self.restore_tasks.lock().pop_front()
+    }
+
+    pub fn restore_build_blocks(&self, partition: usize) -> Result<Vec<DataBlock>> {
+        let files = std::mem::take(&mut self.build_partitions.files.lock()[partition]);
+        files.iter().map(|file| self.read_block(file)).collect()
+    }
+
+    pub fn restore_probe_block(&self, partition: usize) -> Result<Option<DataBlock>> {
+        let file = self.probe_partitions.files.lock()[partition].pop_front();
+        file.map(|file| self.read_block(&file)).transpose()
+    }
+
+    fn partition_block(&self, block: &DataBlock, hash_keys: &[Expr]) -> Result<Vec<DataBlock>> {
+        let num_rows = block.num_rows();
+        let evaluator = Evaluator::new(block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+
+        // Rehash the siphash values, so that the partitions are independent of
+        // the `siphash % n` used to shuffle the join between nodes.
+        let mut hashes = vec![DefaultHasher::default(); num_rows];
+        for expr in hash_keys {
+            let values = get_hash_values(&evaluator.run(expr)?, num_rows)?;
+            for (hasher, value) in hashes.iter_mut().zip(values) {
+                hasher.write_u64(value);
+            }
+        }
+
+        let indices = hashes
+            .iter()
+            .map(|hasher| hasher.finish() % JOIN_SPILL_PARTITION_NUM as u64)
+            .collect::<Vec<_>>();
+        DataBlock::scatter(block, &indices, JOIN_SPILL_PARTITION_NUM)
+    }
+
+    fn spill_block(
+        &self,
+        partitions: &SpilledPartitions,
+        hash_keys: &[Expr],
+        block: DataBlock,
+    ) -> Result<()> {
+        if block.is_empty() {
+            return Ok(());
+        }
+
+        let block = block.convert_to_full();
+        let mut flush_blocks = vec![];
+        {
+            let mut buffers = partitions.buffers.lock();
+            for (partition, block) in self
+                .partition_block(&block, hash_keys)?
+                .into_iter()
+                .enumerate()
+            {
+                if block.is_empty() {
+                    continue;
+                }
+
+                buffers[partition].push(block);
+                let rows = buffers[partition]
+                    .iter()
+                    .map(DataBlock::num_rows)
+                    .sum::<usize>();
+                if rows >= self.flush_rows {
+                    flush_blocks.push((partition, std::mem::take(&mut buffers[partition])));
+                }
+            }
+        }
+
+        for (partition, blocks) in flush_blocks {
+            self.write_partition(partitions, partition, blocks)?;
+        }
+        Ok(())
+    }
+
+    fn flush(&self, partitions: &SpilledPartitions) -> Result<()> {
+        let buffers = partitions
+            .buffers
+            .lock()
+            .iter_mut()
+            .map(std::mem::take)
+            .collect::<Vec<_>>();
+        for (partition, blocks) in buffers.into_iter().enumerate() {
+            if !blocks.is_empty() {
+                self.write_partition(partitions, partition, blocks)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_partition(
+        &self,
+        partitions: &SpilledPartitions,
+        partition: usize,
+        blocks: Vec<DataBlock>,
+    ) -> Result<()> {
+        let block = DataBlock::concat(&blocks)?;
+        let file = self.write_block(block)?;
+        partitions.files.lock()[partition].push_back(file);
+        Ok(())
+    }
+
+    fn write_block(&self, block: DataBlock) -> Result<SpilledBlock> {
+        let num_rows = block.num_rows();
+        let mut columns_layout = Vec::with_capacity(block.num_columns());
+        let mut write_data = vec![];
+        for entry in block.columns() {
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, num_rows);
+            let column_data = serialize_column(&column);
+            columns_layout.push(column_data.len());
+            write_data.extend(column_data);
+        }
+
+        let location = format!("{}/{}", self.location_prefix, GlobalUniqName::unique());
+        let operator = self.operator.clone();
+        let instant = Instant::now();
+        {
+            let location = location.clone();
+            GlobalIORuntime::instance().block_on(async move {
+                operator.write(&location, write_data).await?;
+                Ok(())
+            })?;
+        }
+        ProcessorStats::record_spill(columns_layout.iter().sum());
+
+        info!(
+            "Write hash join spill {} successfully, elapsed: {:?}",
+            location,
+            instant.elapsed()
+        );
+
+        Ok(SpilledBlock {
+            location,
+            columns_layout,
+            num_rows,
+        })
+    }
+
+    fn read_block(&self, file: &SpilledBlock) -> Result<DataBlock> {
+        let operator = self.operator.clone();
+        let location = file.location.clone();
+        let instant = Instant::now();
+        let data = GlobalIORuntime::instance().block_on(async move {
+            let data = operator.read(&location).await?;
+
+            if let Err(cause) = operator.delete(&location).await {
+                error!("Cannot delete spill file {}, cause: {:?}", location, cause);
+            }
+            Ok(data)
+        })?;
+
+        info!(
+            "Read hash join spill {} successfully, elapsed: {:?}",
+            file.location,
+            instant.elapsed()
+        );
+
+        let mut begin = 0;
+        let mut columns = Vec::with_capacity(file.columns_layout.len());
+        for column_layout in file.columns_layout.iter() {
+            let column = deserialize_column(&data[begin..begin + column_layout]).unwrap();
+            columns.push(BlockEntry::new(column.data_type(), Value::Column(column)));
+            begin += column_layout;
+        }
+        Ok(DataBlock::new(columns, file.num_rows))
+    }
+}
+
+/// Spilling methods for hash join.
+impl JoinHashTable {
+    pub(crate) fn spill_state(&self) -> Option<&JoinSpillState> {
+        self.spill_state.as_ref()
+    }
+
+    /// Add the build block to the row space, or spill it once the build side
+    /// exceeds `join_spilling_threshold`.
+    pub(crate) fn build_or_spill(&self, state: &JoinSpillState, input: DataBlock) -> Result<()> {
+        let memory_size = input.memory_size();
+        let build_bytes = state.build_bytes.fetch_add(memory_size, Ordering::Relaxed);
+
+        let spilled = state.spilled.read();
+        if !*spilled && build_bytes + memory_size <= state.threshold {
+            // Keep holding the read lock, rows must not be added to the row space
+            // after they have been moved to the spilled partitions.
+            return self.buffer_build_block(input);
+        }
+        drop(spilled);
+
+        self.spill_build_block(state, input)
+    }
+
+    /// Spill the build block, moving all the rows that were kept in memory to
+    /// the spilled partitions the first time this is called.
+    fn spill_build_block(&self, state: &JoinSpillState, input: DataBlock) -> Result<()> {
+        {
+            let mut spilled = state.spilled.write();
+            if !*spilled {
+                *spilled = true;
+                info!(
+                    "Hash join build side exceeds join_spilling_threshold {}, start spilling",
+                    state.threshold
+                );
+
+                let buffer = std::mem::take(&mut *self.row_space.buffer.write());
+                let chunks = std::mem::take(&mut *self.row_space.chunks.write());
+                let outer_scan_map = unsafe { &mut *self.outer_scan_map.get() };
+                outer_scan_map.clear();
+
+                for block in buffer {
+                    state.spill_build_block(self.build_block_wrap_nullable(block))?;
+                }
+                for chunk in chunks {
+                    state.spill_build_block(chunk.data_block)?;
+                }
+            }
+        }
+
+        state.spill_build_block(self.build_block_wrap_nullable(input))
+    }
+
+    pub(crate) fn spill_probe_block(&self, input: &DataBlock) -> Result<()> {
+        let state = self.spill_state().unwrap();
+        state.spill_probe_block(self.probe_block_wrap_nullable(input))
+    }
+
+    /// Build an in-memory hash join state over the build side of the spilled partition.
+    pub(crate) fn restore_partition(&self, partition: usize) -> Result<Arc<dyn HashJoinState>> {
+        let state = self.spill_state().unwrap();
+        let desc = &self.hash_join_desc;
+        let hash_join_desc = HashJoinDesc {
+            build_keys: desc.build_keys.clone(),
+            probe_keys: desc.probe_keys.clone(),
+            // The runtime filters are pushed down by the origin hash join state.
+            probe_keys_rt: vec![],
+            join_type: desc.join_type.clone(),
+            other_predicate: desc.other_predicate.clone(),
+            marker_join_desc: MarkJoinDesc {
+                has_null: RwLock::new(false),
+            },
+            from_correlated_subquery: desc.from_correlated_subquery,
+            join_state: JoinState::create()?,
+        };
+        let partition_table = JoinHashTable::try_create(
+            self.ctx.clone(),
+            self.row_space.data_schema.clone(),
+            self.probe_schema.clone(),
+            hash_join_desc,
+            (*self.method).clone(),
+        )?;
+
+        partition_table.build_attach()?;
+        for block in state.restore_build_blocks(partition)? {
+            partition_table.build(block)?;
+        }
+        partition_table.build_done()?;
+        while let Some(task) = partition_table.finalize_task() {
+            partition_table.finalize(task)?;
+        }
+        partition_table.finalize_done()?;
+        partition_table.probe_attach()?;
+
+        Ok(Arc::new(partition_table))
+    }
+}
@@ -17,6 +17,7 @@ pub mod group_by;
 pub(crate) mod hash_join;
 mod transform_cast_schema;
 mod transform_create_sets;
+mod transform_dict_get;
 mod transform_hash_join;
 mod transform_limit;
 mod transform_mark_join;
@@ -26,14 +27,20 @@ mod range_join;
 mod runtime_filter;
 mod transform_add_computed_columns;
 mod transform_add_const_columns;
+mod transform_add_sequence_columns;
 mod transform_ie_join;
 mod transform_merge_block;
+mod transform_recursive_cte;
 mod transform_resort_addon;
 mod transform_runtime_cast_schema;
 mod transform_runtime_filter;
+mod transform_sample;
+mod transform_udf;
+mod wasm_udf;
 
 pub use aggregator::build_partition_bucket;
 pub use aggregator::AggregateInjector;
+pub use aggregator::AggregateUdfFunction;
 pub use aggregator::AggregatorParams;
 pub use aggregator::FinalSingleStateAggregator;
 pub use aggregator::HashTableCell;
@@ -63,6 +70,7 @@ pub use range_join::RangeJoinState;
 pub use runtime_filter::RuntimeFilterState;
 pub use transform_add_computed_columns::TransformAddComputedColumns;
 pub use transform_add_const_columns::TransformAddConstColumns;
+pub use transform_add_sequence_columns::TransformAddSequenceColumns;
 pub use transform_block_compact::BlockCompactor;
 pub use transform_block_compact::TransformBlockCompact;
 pub use transform_cast_schema::TransformCastSchema;
@@ -70,6 +78,7 @@ pub use transform_compact::Compactor;
 pub use transform_compact::TransformCompact;
 pub use transform_create_sets::SubqueryReceiver;
 pub use transform_create_sets::TransformCreateSets;
+pub use transform_dict_get::TransformDictGet;
 pub use transform_hash_join::TransformHashJoinBuild;
 pub use transform_hash_join::TransformHashJoinProbe;
 pub use transform_ie_join::TransformRangeJoinLeft;
@@ -78,11 +87,20 @@ pub use transform_limit::TransformLimit;
 pub use transform_mark_join::MarkJoinCompactor;
 pub use transform_mark_join::TransformMarkJoin;
 pub use transform_merge_block::TransformMergeBlock;
+pub use transform_recursive_cte::RecursiveCteScanSource;
+pub use transform_recursive_cte::RecursiveCteSource;
+pub use transform_recursive_cte::RecursiveCteWorkingTable;
 pub use transform_resort_addon::TransformResortAddOn;
 pub use transform_runtime_cast_schema::TransformRuntimeCastSchema;
 pub use transform_runtime_filter::SinkRuntimeFilterSource;
 pub use transform_runtime_filter::TransformRuntimeFilter;
+pub use transform_sample::TransformSample;
 pub use transform_sort_partial::TransformSortPartial;
+pub use transform_udf::TransformUdf;
+pub use transform_udf::UdfFlightClient;
+pub use transform_udf::UdfRequestOptions;
+pub use wasm_udf::WasmUdfLimits;
+pub use wasm_udf::WasmUdfModule;
 pub use window::FrameBound;
 pub use window::TransformWindow;
 pub use window::WindowFunctionInfo;
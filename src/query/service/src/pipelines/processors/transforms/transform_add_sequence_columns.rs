@@ -0,0 +1,137 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::type_check::check_cast;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::UInt64Type;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::DataField;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FromData;
+use common_expression::FunctionContext;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::Processor;
+use common_pipeline_transforms::processors::transforms::AsyncTransform;
+use common_pipeline_transforms::processors::transforms::AsyncTransformer;
+use common_sql::parse_sequence_default_expr;
+use common_users::UserApiProvider;
+
+/// Min number of values allocated from a sequence in one request to the meta service.
+const SEQUENCE_BATCH_SIZE: u64 = 1000;
+
+struct SequenceColumn {
+    sequence: String,
+    data_type: DataType,
+    /// The values allocated but not used yet: `next`, `next + increment`, ...
+    next: u64,
+    increment: u64,
+    remaining: u64,
+}
+
+/// Appends the columns whose default is `nextval('<sequence>')` to the blocks.
+///
+/// The values are allocated from the meta service in batches, the values left in
+/// a batch are used by the next blocks, and are lost when the insert finishes.
+pub struct TransformAddSequenceColumns {
+    tenant: String,
+    func_ctx: FunctionContext,
+    columns: Vec<SequenceColumn>,
+}
+
+impl TransformAddSequenceColumns {
+    /// `fields` are the fields to append, each with a sequence default.
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        tenant: String,
+        func_ctx: FunctionContext,
+        fields: &[DataField],
+    ) -> Box<dyn Processor> {
+        let columns = fields
+            .iter()
+            .filter_map(|f| {
+                let sequence = parse_sequence_default_expr(f.default_expr()?)?;
+                Some(SequenceColumn {
+                    sequence,
+                    data_type: f.data_type().clone(),
+                    next: 0,
+                    increment: 1,
+                    remaining: 0,
+                })
+            })
+            .collect();
+        AsyncTransformer::create(input, output, TransformAddSequenceColumns {
+            tenant,
+            func_ctx,
+            columns,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncTransform for TransformAddSequenceColumns {
+    const NAME: &'static str = "AddSequenceColumnsTransform";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, mut data: DataBlock) -> Result<DataBlock> {
+        let num_rows = data.num_rows();
+        for column in self.columns.iter_mut() {
+            let mut values = Vec::with_capacity(num_rows);
+            while values.len() < num_rows {
+                if column.remaining == 0 {
+                    let count =
+                        std::cmp::max((num_rows - values.len()) as u64, SEQUENCE_BATCH_SIZE);
+                    let (first, increment) = UserApiProvider::instance()
+                        .next_sequence_values(&self.tenant, &column.sequence, count)
+                        .await?;
+                    column.next = first;
+                    column.increment = increment;
+                    column.remaining = count;
+                }
+                values.push(column.next);
+                column.next = column.next.wrapping_add(column.increment);
+                column.remaining -= 1;
+            }
+
+            let values = DataBlock::new_from_columns(vec![UInt64Type::from_data(values)]);
+            let cast = check_cast(
+                None,
+                false,
+                Expr::ColumnRef {
+                    span: None,
+                    id: 0,
+                    data_type: DataType::Number(NumberDataType::UInt64),
+                    display_name: "".to_string(),
+                },
+                &column.data_type,
+                &BUILTIN_FUNCTIONS,
+            )?;
+            let evaluator = Evaluator::new(&values, &self.func_ctx, &BUILTIN_FUNCTIONS);
+            let value = evaluator.run(&cast)?;
+            data.add_column(BlockEntry {
+                data_type: column.data_type.clone(),
+                value,
+            });
+        }
+        Ok(data)
+    }
+}
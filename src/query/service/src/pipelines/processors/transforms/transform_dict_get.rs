@@ -0,0 +1,324 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_cache::Cache;
+use common_cache::LruCache;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::type_check::check_cast;
+use common_expression::types::DataType;
+use common_expression::types::StringType;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FromOptData;
+use common_expression::FunctionContext;
+use common_expression::ScalarRef;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::principal::DictionarySource;
+use common_meta_app::principal::MysqlDictionarySource;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::Processor;
+use common_pipeline_transforms::processors::transforms::AsyncTransform;
+use common_pipeline_transforms::processors::transforms::AsyncTransformer;
+use common_sql::executor::DictGetDesc;
+use mysql_async::prelude::Queryable;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+
+/// Max number of keys sent to the source in one request.
+const LOOKUP_BATCH_SIZE: usize = 1000;
+
+const DICTIONARY_CACHE_CAPACITY: u64 = 1_000_000;
+
+/// Values looked up by `dict_get`, shared by all the queries of the process.
+///
+/// Entries are keyed by the lookup (source, primary key and attribute) and the key, and
+/// expire after `dictionary_cache_ttl_secs`. Missing keys are cached too, as `None`.
+static DICTIONARY_CACHE: Lazy<Mutex<LruCache<(String, String), CachedValue>>> =
+    Lazy::new(|| Mutex::new(LruCache::new(DICTIONARY_CACHE_CAPACITY)));
+
+#[derive(Clone)]
+struct CachedValue {
+    value: Option<Vec<u8>>,
+    loaded_at: Instant,
+}
+
+enum SourceClient {
+    Mysql(mysql_async::Pool),
+    Redis(redis::aio::Connection),
+}
+
+impl SourceClient {
+    #[async_backtrace::framed]
+    async fn connect(source: &DictionarySource) -> Result<SourceClient> {
+        match source {
+            DictionarySource::Mysql(s) => {
+                let opts = mysql_async::OptsBuilder::default()
+                    .ip_or_hostname(s.host.clone())
+                    .tcp_port(s.port)
+                    .user(Some(s.username.clone()))
+                    .pass(Some(s.password.clone()))
+                    .db_name(Some(s.database.clone()));
+                Ok(SourceClient::Mysql(mysql_async::Pool::new(opts)))
+            }
+            DictionarySource::Redis(s) => {
+                let info = redis::ConnectionInfo {
+                    addr: redis::ConnectionAddr::Tcp(s.host.clone(), s.port),
+                    redis: redis::RedisConnectionInfo {
+                        db: s.db_index,
+                        username: s.username.clone(),
+                        password: s.password.clone(),
+                    },
+                };
+                let client = redis::Client::open(info).map_err(redis_error)?;
+                let conn = client.get_async_connection().await.map_err(redis_error)?;
+                Ok(SourceClient::Redis(conn))
+            }
+        }
+    }
+
+    /// Look up the values of `keys`, the keys that are not found are left out.
+    #[async_backtrace::framed]
+    async fn lookup(
+        &mut self,
+        source: &DictionarySource,
+        primary_key: &str,
+        attribute: &str,
+        keys: &[String],
+    ) -> Result<HashMap<String, Option<Vec<u8>>>> {
+        let mut values = HashMap::with_capacity(keys.len());
+        match (self, source) {
+            (SourceClient::Mysql(pool), DictionarySource::Mysql(s)) => {
+                let mut conn = pool.get_conn().await.map_err(mysql_error)?;
+                for keys in keys.chunks(LOOKUP_BATCH_SIZE) {
+                    let query = mysql_lookup_query(s, primary_key, attribute, keys.len());
+                    let rows: Vec<(String, Option<Vec<u8>>)> =
+                        conn.exec(query, keys.to_vec()).await.map_err(mysql_error)?;
+                    values.extend(rows);
+                }
+            }
+            (SourceClient::Redis(conn), DictionarySource::Redis(_)) => {
+                for keys in keys.chunks(LOOKUP_BATCH_SIZE) {
+                    let rows: Vec<Option<Vec<u8>>> = redis::cmd("MGET")
+                        .arg(keys)
+                        .query_async(conn)
+                        .await
+                        .map_err(redis_error)?;
+                    for (key, value) in keys.iter().zip(rows.into_iter()) {
+                        if value.is_some() {
+                            values.insert(key.clone(), value);
+                        }
+                    }
+                }
+            }
+            _ => unreachable!("the client is created from the source"),
+        }
+        Ok(values)
+    }
+
+    #[async_backtrace::framed]
+    async fn close(self) -> Result<()> {
+        if let SourceClient::Mysql(pool) = self {
+            pool.disconnect().await.map_err(mysql_error)?;
+        }
+        Ok(())
+    }
+}
+
+fn mysql_lookup_query(
+    source: &MysqlDictionarySource,
+    primary_key: &str,
+    attribute: &str,
+    num_keys: usize,
+) -> String {
+    fn quote(ident: &str) -> String {
+        format!("`{}`", ident.replace('`', "``"))
+    }
+    format!(
+        "SELECT CAST({pk} AS CHAR), CAST({attr} AS BINARY) FROM {db}.{table} WHERE {pk} IN ({params})",
+        pk = quote(primary_key),
+        attr = quote(attribute),
+        db = quote(&source.database),
+        table = quote(&source.table),
+        params = vec!["?"; num_keys].join(", ")
+    )
+}
+
+fn mysql_error(e: mysql_async::Error) -> ErrorCode {
+    ErrorCode::DictionarySourceError(format!("failed to look up mysql dictionary: {e}"))
+}
+
+fn redis_error(e: redis::RedisError) -> ErrorCode {
+    ErrorCode::DictionarySourceError(format!("failed to look up redis dictionary: {e}"))
+}
+
+/// The string a key is looked up by, which is how the source displays it.
+fn key_string(key: ScalarRef) -> Option<String> {
+    match key {
+        ScalarRef::Null => None,
+        ScalarRef::String(s) => Some(String::from_utf8_lossy(s).into_owned()),
+        other => Some(other.to_string()),
+    }
+}
+
+/// Appends the results of the `dict_get` calls to the blocks.
+///
+/// The keys of a block are deduplicated, and only the ones that are not cached are
+/// sent to the source, in batches. The values are read as strings and cast to the
+/// types of the attributes, invalid ones becoming NULL.
+pub struct TransformDictGet {
+    func_ctx: FunctionContext,
+    items: Vec<DictGetDesc>,
+    cache_ttl: Duration,
+    /// Connected on the first block, one per item.
+    clients: Vec<Option<SourceClient>>,
+}
+
+impl TransformDictGet {
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        func_ctx: FunctionContext,
+        items: Vec<DictGetDesc>,
+        cache_ttl: Duration,
+    ) -> Box<dyn Processor> {
+        let clients = items.iter().map(|_| None).collect();
+        AsyncTransformer::create(input, output, TransformDictGet {
+            func_ctx,
+            items,
+            cache_ttl,
+            clients,
+        })
+    }
+
+    #[async_backtrace::framed]
+    async fn lookup(
+        &mut self,
+        index: usize,
+        keys: &[Option<String>],
+    ) -> Result<Vec<Option<Vec<u8>>>> {
+        let item = &self.items[index];
+        let lookup_id = format!("{:?}/{}/{}", item.source, item.primary_key, item.attribute);
+
+        let mut found = HashMap::new();
+        let mut missing = HashSet::new();
+        {
+            let mut cache = DICTIONARY_CACHE.lock();
+            for key in keys.iter().flatten() {
+                if found.contains_key(key) || missing.contains(key) {
+                    continue;
+                }
+                let cache_key = (lookup_id.clone(), key.clone());
+                match cache.get(&cache_key) {
+                    Some(cached) if cached.loaded_at.elapsed() < self.cache_ttl => {
+                        found.insert(key.clone(), cached.value.clone());
+                    }
+                    _ => {
+                        missing.insert(key.clone());
+                    }
+                }
+            }
+        }
+
+        if !missing.is_empty() {
+            let missing = missing.into_iter().collect::<Vec<_>>();
+            if self.clients[index].is_none() {
+                self.clients[index] = Some(SourceClient::connect(&item.source).await?);
+            }
+            let client = self.clients[index].as_mut().unwrap();
+            let mut values = client
+                .lookup(&item.source, &item.primary_key, &item.attribute, &missing)
+                .await?;
+
+            let loaded_at = Instant::now();
+            let mut cache = DICTIONARY_CACHE.lock();
+            for key in missing {
+                let value = values.remove(&key).flatten();
+                if !self.cache_ttl.is_zero() {
+                    cache.put((lookup_id.clone(), key.clone()), CachedValue {
+                        value: value.clone(),
+                        loaded_at,
+                    });
+                }
+                found.insert(key, value);
+            }
+        }
+
+        Ok(keys
+            .iter()
+            .map(|key| {
+                key.as_ref()
+                    .and_then(|key| found.get(key).cloned().flatten())
+            })
+            .collect())
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncTransform for TransformDictGet {
+    const NAME: &'static str = "DictGetTransform";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, mut data: DataBlock) -> Result<DataBlock> {
+        let num_rows = data.num_rows();
+        for index in 0..self.items.len() {
+            let key_expr = self.items[index].key.as_expr(&BUILTIN_FUNCTIONS);
+            let keys = {
+                let evaluator = Evaluator::new(&data, &self.func_ctx, &BUILTIN_FUNCTIONS);
+                let value = evaluator.run(&key_expr)?;
+                let column = value.convert_to_full_column(key_expr.data_type(), num_rows);
+                column.iter().map(key_string).collect::<Vec<_>>()
+            };
+
+            let values = self.lookup(index, &keys).await?;
+            let values = DataBlock::new_from_columns(vec![StringType::from_opt_data(values)]);
+
+            let data_type = self.items[index].data_type.clone();
+            let cast = check_cast(
+                None,
+                true,
+                Expr::ColumnRef {
+                    span: None,
+                    id: 0,
+                    data_type: DataType::String.wrap_nullable(),
+                    display_name: "".to_string(),
+                },
+                &data_type.remove_nullable(),
+                &BUILTIN_FUNCTIONS,
+            )?;
+            let evaluator = Evaluator::new(&values, &self.func_ctx, &BUILTIN_FUNCTIONS);
+            let value = evaluator.run(&cast)?;
+            data.add_column(BlockEntry { data_type, value });
+        }
+        Ok(data)
+    }
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        for client in self.clients.iter_mut() {
+            if let Some(client) = client.take() {
+                client.close().await?;
+            }
+        }
+        Ok(())
+    }
+}
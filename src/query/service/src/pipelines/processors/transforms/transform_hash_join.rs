@@ -34,6 +34,7 @@ enum HashJoinStep {
     Finalize,
     Probe,
     OuterScan,
+    Restore,
 }
 
 pub struct TransformHashJoinProbe {
@@ -47,6 +48,9 @@ pub struct TransformHashJoinProbe {
     probe_state: ProbeState,
     block_size: u64,
     outer_scan_finished: bool,
+    /// The spilled partition being restored and its in-memory hash join state.
+    restore_partition: Option<(usize, Arc<dyn HashJoinState>)>,
+    restore_finished: bool,
 }
 
 impl TransformHashJoinProbe {
@@ -69,6 +73,8 @@ impl TransformHashJoinProbe {
             probe_state: ProbeState::create(join_type, with_conjunct, ctx.get_function_context()?),
             block_size: default_block_size,
             outer_scan_finished: false,
+            restore_partition: None,
+            restore_finished: false,
         }))
     }
 
@@ -89,6 +95,44 @@ impl TransformHashJoinProbe {
             .extend(self.join_state.outer_scan(task, &mut self.probe_state)?);
         Ok(())
     }
+
+    // Join the spilled partitions one by one.
+    fn restore(&mut self) -> Result<()> {
+        let (partition, state) = match &self.restore_partition {
+            Some((partition, state)) => (*partition, state.clone()),
+            None => {
+                match self.join_state.restore_task() {
+                    Some(partition) => {
+                        let state = self.join_state.restore_build(partition)?;
+                        self.restore_partition = Some((partition, state));
+                    }
+                    None => self.restore_finished = true,
+                }
+                return Ok(());
+            }
+        };
+
+        if let Some(data) = self.join_state.restore_probe(partition)? {
+            let (sub_blocks, remain_block) = data.split_by_rows(self.block_size as usize);
+            for block in sub_blocks.iter().chain(remain_block.iter()) {
+                self.probe_state.clear();
+                self.output_data_blocks
+                    .extend(state.probe(block, &mut self.probe_state)?);
+            }
+            return Ok(());
+        }
+
+        // All the probe blocks of the partition are joined, scan the unmatched build rows.
+        state.probe_done()?;
+        if state.need_outer_scan() {
+            while let Some(task) = state.outer_scan_task() {
+                self.output_data_blocks
+                    .extend(state.outer_scan(task, &mut self.probe_state)?);
+            }
+        }
+        self.restore_partition = None;
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -138,7 +182,7 @@ impl Processor for TransformHashJoinProbe {
                 }
 
                 if self.input_port.is_finished() {
-                    if self.join_state.need_outer_scan() {
+                    if self.join_state.need_outer_scan() || self.join_state.is_spilled() {
                         self.join_state.probe_done()?;
                         return Ok(Event::Async);
                     } else {
@@ -150,7 +194,7 @@ impl Processor for TransformHashJoinProbe {
                 self.input_port.set_need_data();
                 Ok(Event::NeedData)
             }
-            HashJoinStep::OuterScan => {
+            HashJoinStep::OuterScan | HashJoinStep::Restore => {
                 if self.output_port.is_finished() {
                     return Ok(Event::Finished);
                 }
@@ -165,7 +209,11 @@ impl Processor for TransformHashJoinProbe {
                     return Ok(Event::NeedConsume);
                 }
 
-                match self.outer_scan_finished {
+                let finished = match self.step {
+                    HashJoinStep::Restore => self.restore_finished,
+                    _ => self.outer_scan_finished,
+                };
+                match finished {
                     false => Ok(Event::Sync),
                     true => {
                         self.output_port.finish();
@@ -199,6 +247,7 @@ impl Processor for TransformHashJoinProbe {
                 }
                 Ok(())
             }
+            HashJoinStep::Restore => self.restore(),
         }
     }
 
@@ -212,9 +261,12 @@ impl Processor for TransformHashJoinProbe {
             HashJoinStep::Finalize => unreachable!(),
             HashJoinStep::Probe => {
                 self.join_state.wait_probe_finish().await?;
-                self.step = HashJoinStep::OuterScan;
+                self.step = match self.join_state.is_spilled() {
+                    true => HashJoinStep::Restore,
+                    false => HashJoinStep::OuterScan,
+                };
             }
-            HashJoinStep::OuterScan => unreachable!(),
+            HashJoinStep::OuterScan | HashJoinStep::Restore => unreachable!(),
         };
         Ok(())
     }
@@ -288,6 +340,7 @@ impl Processor for TransformHashJoinBuild {
             },
             HashJoinStep::Probe => unreachable!(),
             HashJoinStep::OuterScan => unreachable!(),
+            HashJoinStep::Restore => unreachable!(),
         }
     }
 
@@ -313,6 +366,7 @@ impl Processor for TransformHashJoinBuild {
             }
             HashJoinStep::Probe => unreachable!(),
             HashJoinStep::OuterScan => unreachable!(),
+            HashJoinStep::Restore => unreachable!(),
         }
     }
 
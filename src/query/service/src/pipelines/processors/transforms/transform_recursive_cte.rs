@@ -0,0 +1,244 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::Scalar;
+use common_pipeline_sources::SyncSource;
+use common_pipeline_sources::SyncSourcer;
+use common_profile::SharedProcessorProfiles;
+use common_sql::executor::PhysicalPlan;
+use common_sql::executor::RecursiveCte;
+use common_sql::IndexType;
+use parking_lot::RwLock;
+
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelinePullingExecutor;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::processor::ProcessorPtr;
+use crate::pipelines::PipelineBuilder;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// The rows produced by the last iteration of a recursive CTE, which are read
+/// by the `RecursiveCteScan`s of the next iteration.
+pub struct RecursiveCteWorkingTable {
+    blocks: RwLock<Vec<DataBlock>>,
+}
+
+impl RecursiveCteWorkingTable {
+    pub fn create() -> Arc<RecursiveCteWorkingTable> {
+        Arc::new(RecursiveCteWorkingTable {
+            blocks: RwLock::new(vec![]),
+        })
+    }
+
+    pub fn replace(&self, blocks: Vec<DataBlock>) {
+        *self.blocks.write() = blocks;
+    }
+
+    pub fn blocks(&self) -> Vec<DataBlock> {
+        self.blocks.read().clone()
+    }
+}
+
+pub struct RecursiveCteScanSource {
+    working_table: Arc<RecursiveCteWorkingTable>,
+    blocks: Option<VecDeque<DataBlock>>,
+}
+
+impl RecursiveCteScanSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        working_table: Arc<RecursiveCteWorkingTable>,
+    ) -> Result<ProcessorPtr> {
+        SyncSourcer::create(ctx, output, RecursiveCteScanSource {
+            working_table,
+            blocks: None,
+        })
+    }
+}
+
+impl SyncSource for RecursiveCteScanSource {
+    const NAME: &'static str = "RecursiveCteScanSource";
+
+    fn generate(&mut self) -> Result<Option<DataBlock>> {
+        let blocks = self
+            .blocks
+            .get_or_insert_with(|| self.working_table.blocks().into());
+        Ok(blocks.pop_front())
+    }
+}
+
+/// Evaluates a recursive CTE: the anchor is executed once, then the recursive
+/// term is executed against the rows of the previous iteration until an
+/// iteration produces no rows.
+///
+/// Each iteration runs in its own pipeline, because the recursive term has to
+/// be restarted with a new working table.
+pub struct RecursiveCteSource {
+    ctx: Arc<QueryContext>,
+    plan: RecursiveCte,
+    working_tables: HashMap<IndexType, Arc<RecursiveCteWorkingTable>>,
+    working_table: Arc<RecursiveCteWorkingTable>,
+    max_depth: u64,
+
+    /// Number of iterations of the recursive term started so far, the anchor
+    /// is running while it is zero.
+    iterations: u64,
+    anchor_executed: bool,
+    executor: Option<PipelinePullingExecutor>,
+    /// Rows produced by the running iteration.
+    next_blocks: Vec<DataBlock>,
+    /// Rows produced so far, only used by `UNION`.
+    seen: HashSet<Vec<Scalar>>,
+}
+
+impl RecursiveCteSource {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        output: Arc<OutputPort>,
+        plan: RecursiveCte,
+        working_tables: HashMap<IndexType, Arc<RecursiveCteWorkingTable>>,
+    ) -> Result<ProcessorPtr> {
+        let working_table = working_tables
+            .get(&plan.cte_index)
+            .cloned()
+            .ok_or_else(|| ErrorCode::Internal("Working table of recursive cte not found"))?;
+        let max_depth = ctx.get_settings().get_max_cte_recursive_depth()?;
+
+        SyncSourcer::create(ctx.clone(), output, RecursiveCteSource {
+            ctx,
+            plan,
+            working_tables,
+            working_table,
+            max_depth,
+            iterations: 0,
+            anchor_executed: false,
+            executor: None,
+            next_blocks: vec![],
+            seen: HashSet::new(),
+        })
+    }
+
+    /// Starts the next iteration, returns false once the recursion is done.
+    fn next_iteration(&mut self) -> Result<bool> {
+        let plan = if !self.anchor_executed {
+            self.anchor_executed = true;
+            self.plan.anchor.clone()
+        } else {
+            if self.next_blocks.is_empty() {
+                return Ok(false);
+            }
+
+            self.iterations += 1;
+            if self.iterations > self.max_depth {
+                return Err(ErrorCode::RecursiveCteDepthExceeded(format!(
+                    "Recursive cte exceeded the maximum number of iterations {}, it can be changed by setting max_cte_recursive_depth",
+                    self.max_depth
+                )));
+            }
+            self.working_table
+                .replace(std::mem::take(&mut self.next_blocks));
+            self.plan.recursive.clone()
+        };
+
+        self.executor = Some(self.create_executor(&plan)?);
+        Ok(true)
+    }
+
+    fn create_executor(&self, plan: &PhysicalPlan) -> Result<PipelinePullingExecutor> {
+        let ctx = QueryContext::create_from(self.ctx.clone());
+        let mut pipeline_builder =
+            PipelineBuilder::create(ctx, false, SharedProcessorProfiles::default());
+        pipeline_builder.recursive_cte_working_tables = self.working_tables.clone();
+        let mut build_res = pipeline_builder.finalize(plan)?;
+
+        let settings = self.ctx.get_settings();
+        build_res.set_max_threads(settings.get_max_threads()? as usize);
+        let settings = ExecutorSettings::try_create(&settings, self.ctx.get_id())?;
+
+        let mut executor = PipelinePullingExecutor::from_pipelines(build_res, settings)?;
+        executor.start();
+        Ok(executor)
+    }
+
+    fn project(&self, block: DataBlock) -> DataBlock {
+        let projection = match self.iterations {
+            0 => &self.plan.anchor_projection,
+            _ => &self.plan.recursive_projection,
+        };
+        let num_rows = block.num_rows();
+        let columns = projection
+            .iter()
+            .map(|offset| block.get_by_offset(*offset).clone())
+            .collect();
+        DataBlock::new(columns, num_rows)
+    }
+
+    /// Filters out the rows that have been produced already.
+    fn distinct(&mut self, block: DataBlock) -> Result<DataBlock> {
+        let mut bitmap = MutableBitmap::with_capacity(block.num_rows());
+        for row in 0..block.num_rows() {
+            let values = block
+                .columns()
+                .iter()
+                .map(|entry| entry.value.index(row).unwrap().to_owned())
+                .collect::<Vec<_>>();
+            bitmap.push(self.seen.insert(values));
+        }
+        block.filter_with_bitmap(&bitmap.into())
+    }
+}
+
+impl SyncSource for RecursiveCteSource {
+    const NAME: &'static str = "RecursiveCteSource";
+
+    fn generate(&mut self) -> Result<Option<DataBlock>> {
+        loop {
+            let executor = match self.executor.as_mut() {
+                Some(executor) => executor,
+                None => {
+                    if !self.next_iteration()? {
+                        return Ok(None);
+                    }
+                    continue;
+                }
+            };
+
+            match executor.pull_data()? {
+                Some(block) => {
+                    let mut block = self.project(block);
+                    if self.plan.distinct {
+                        block = self.distinct(block)?;
+                    }
+                    if block.is_empty() {
+                        continue;
+                    }
+                    self.next_blocks.push(block.clone());
+                    return Ok(Some(block));
+                }
+                None => self.executor = None,
+            }
+        }
+    }
+}
@@ -0,0 +1,65 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::Result;
+use common_expression::DataBlock;
+use rand::rngs::StdRng;
+use rand::Rng;
+use rand::SeedableRng;
+
+use crate::pipelines::processors::port::InputPort;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::processor::ProcessorPtr;
+use crate::pipelines::processors::transforms::transform::Transform;
+use crate::pipelines::processors::transforms::transform::Transformer;
+
+/// Bernoulli sampling of the rows for `SELECT ... FROM t SAMPLE ROW (p)`,
+/// each row is kept with the probability `p` independently of the others.
+pub struct TransformSample {
+    probability: f64,
+    rng: StdRng,
+}
+
+impl TransformSample {
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        probability: f64,
+    ) -> ProcessorPtr {
+        ProcessorPtr::create(Transformer::create(input, output, TransformSample {
+            probability,
+            rng: StdRng::from_entropy(),
+        }))
+    }
+}
+
+impl Transform for TransformSample {
+    const NAME: &'static str = "TransformSample";
+
+    fn transform(&mut self, block: DataBlock) -> Result<DataBlock> {
+        if self.probability >= 1.0 || block.is_empty() {
+            return Ok(block);
+        }
+
+        let probability = self.probability;
+        let rng = &mut self.rng;
+        let selection = (0..block.num_rows())
+            .map(|_| rng.gen::<f64>() < probability)
+            .collect::<Bitmap>();
+        block.filter_with_bitmap(&selection)
+    }
+}
@@ -0,0 +1,340 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use arrow_flight::flight_service_client::FlightServiceClient;
+use arrow_flight::utils::flight_data_to_batches;
+use arrow_flight::FlightData;
+use arrow_flight::FlightDescriptor;
+use arrow_flight::SchemaAsIpc;
+use arrow_ipc::writer;
+use arrow_ipc::writer::IpcWriteOptions;
+use arrow_schema::Schema as ArrowSchema;
+use common_base::base::tokio::task::spawn_blocking;
+use common_base::base::tokio::time::sleep;
+use common_base::runtime::match_join_handle;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::type_check::check_cast;
+use common_expression::BlockEntry;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::DataField;
+use common_expression::DataSchema;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Value;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::Processor;
+use common_pipeline_transforms::processors::transforms::AsyncTransform;
+use common_pipeline_transforms::processors::transforms::AsyncTransformer;
+use common_sql::binder::parse_stage_location;
+use common_sql::executor::UdfFunctionDesc;
+use common_storage::init_stage_operator;
+use futures::TryStreamExt;
+use tonic::transport::Channel;
+use tonic::transport::Endpoint;
+
+use crate::pipelines::processors::transforms::WasmUdfLimits;
+use crate::pipelines::processors::transforms::WasmUdfModule;
+
+/// The controls of the requests to the UDF servers.
+#[derive(Clone, Debug)]
+pub struct UdfRequestOptions {
+    pub connect_timeout: Duration,
+    pub request_timeout: Duration,
+    /// Max number of rows sent to a server in one request.
+    pub batch_rows: usize,
+    /// Number of times a failed request is retried.
+    pub max_retries: u64,
+}
+
+/// A client of an external UDF server, which evaluates a function over the
+/// rows of a batch with the `DoExchange` call of Arrow Flight.
+///
+/// The path of the flight descriptor is the name of the handler, the server
+/// returns a single column with a row per input row.
+pub struct UdfFlightClient {
+    inner: FlightServiceClient<Channel>,
+}
+
+impl UdfFlightClient {
+    #[async_backtrace::framed]
+    pub async fn connect(addr: &str, options: &UdfRequestOptions) -> Result<UdfFlightClient> {
+        // tonic only knows the schemes of HTTP/2.
+        let addr = match addr.strip_prefix("grpc://") {
+            Some(rest) => format!("http://{rest}"),
+            None => addr.to_string(),
+        };
+        let endpoint = Endpoint::from_shared(addr.clone())
+            .map_err(|e| {
+                ErrorCode::UDFServerError(format!("invalid address of UDF server {addr}: {e}"))
+            })?
+            .connect_timeout(options.connect_timeout)
+            .timeout(options.request_timeout);
+        let channel = endpoint.connect().await.map_err(|e| {
+            ErrorCode::UDFServerError(format!("failed to connect to UDF server {addr}: {e}"))
+        })?;
+        Ok(UdfFlightClient {
+            inner: FlightServiceClient::new(channel),
+        })
+    }
+
+    /// Sends `block` to the server, and returns the result of the function.
+    #[async_backtrace::framed]
+    pub async fn do_exchange(&mut self, func_name: &str, block: DataBlock) -> Result<DataBlock> {
+        let num_rows = block.num_rows();
+        let result = self
+            .exchange(vec![func_name.to_string()], func_name, block)
+            .await?;
+        if result.num_columns() != 1 || result.num_rows() != num_rows {
+            return Err(ErrorCode::UDFServerError(format!(
+                "function {func_name} should return 1 column and {num_rows} rows, but got {} columns and {} rows",
+                result.num_columns(),
+                result.num_rows()
+            )));
+        }
+        Ok(result)
+    }
+
+    /// Sends `block` to the server with the descriptor `path`, and returns
+    /// the blocks sent back by the server.
+    #[async_backtrace::framed]
+    pub async fn exchange(
+        &mut self,
+        path: Vec<String>,
+        func_name: &str,
+        block: DataBlock,
+    ) -> Result<DataBlock> {
+        let fields = block
+            .columns()
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| DataField::new(&format!("arg{i}"), entry.data_type.clone()))
+            .collect::<Vec<_>>();
+        let data_schema = DataSchema::new(fields);
+        let batch = block
+            .to_record_batch(&data_schema)
+            .map_err(|e| ErrorCode::Internal(format!("{e:?}")))?;
+
+        let options = IpcWriteOptions::default();
+        let mut schema_data: FlightData =
+            SchemaAsIpc::new(&ArrowSchema::from(&data_schema), &options).into();
+        schema_data.flight_descriptor = Some(FlightDescriptor::new_path(path));
+        let data_gen = writer::IpcDataGenerator::default();
+        let mut dictionary_tracker = writer::DictionaryTracker::new(false);
+        let (_encoded_dictionaries, encoded_batch) = data_gen
+            .encoded_batch(&batch, &mut dictionary_tracker, &options)
+            .map_err(|e| ErrorCode::Internal(format!("{e:?}")))?;
+        let request = futures::stream::iter(vec![schema_data, encoded_batch.into()]);
+
+        let response = self.inner.do_exchange(request).await.map_err(|status| {
+            ErrorCode::UDFServerError(format!(
+                "failed to call function {func_name} on UDF server: {}",
+                status.message()
+            ))
+        })?;
+        let flight_data: Vec<FlightData> =
+            response
+                .into_inner()
+                .try_collect()
+                .await
+                .map_err(|status| {
+                    ErrorCode::UDFServerError(format!(
+                        "failed to receive the result of function {func_name}: {}",
+                        status.message()
+                    ))
+                })?;
+        let batches = flight_data_to_batches(&flight_data).map_err(|e| {
+            ErrorCode::UDFServerError(format!(
+                "invalid result of function {func_name} from UDF server: {e}"
+            ))
+        })?;
+
+        let blocks = batches
+            .iter()
+            .map(|batch| {
+                DataBlock::from_record_batch(batch)
+                    .map(|(block, _)| block)
+                    .map_err(|e| ErrorCode::Internal(format!("{e:?}")))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        DataBlock::concat(&blocks)
+    }
+}
+
+/// Appends the results of the external UDF calls to the blocks.
+///
+/// The arguments of a call are evaluated and sent to the server in batches
+/// of `batch_rows` rows, a failed request is retried with a new connection.
+/// The calls of WebAssembly UDFs are evaluated in-process by the modules
+/// loaded from the stages, with the same batches on the blocking threads.
+/// The results are cast to the declared return types.
+pub struct TransformUdf {
+    ctx: Arc<dyn TableContext>,
+    func_ctx: FunctionContext,
+    funcs: Vec<UdfFunctionDesc>,
+    options: UdfRequestOptions,
+    wasm_limits: WasmUdfLimits,
+    /// Connected on the first use, keyed by the address of the server.
+    clients: HashMap<String, UdfFlightClient>,
+    /// Loaded on the first use, keyed by the stage location of the module.
+    wasm_modules: HashMap<String, Arc<WasmUdfModule>>,
+}
+
+impl TransformUdf {
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        ctx: Arc<dyn TableContext>,
+        funcs: Vec<UdfFunctionDesc>,
+        options: UdfRequestOptions,
+        wasm_limits: WasmUdfLimits,
+    ) -> Result<Box<dyn Processor>> {
+        Ok(AsyncTransformer::create(input, output, TransformUdf {
+            func_ctx: ctx.get_function_context()?,
+            ctx,
+            funcs,
+            options,
+            wasm_limits,
+            clients: HashMap::new(),
+            wasm_modules: HashMap::new(),
+        }))
+    }
+
+    #[async_backtrace::framed]
+    async fn wasm_module(&mut self, location: &str) -> Result<Arc<WasmUdfModule>> {
+        if let Some(module) = self.wasm_modules.get(location) {
+            return Ok(module.clone());
+        }
+        let (stage_info, path) = parse_stage_location(&self.ctx, location).await?;
+        let operator = init_stage_operator(&stage_info)?;
+        let bytes = operator.read(&path).await?;
+        let module = Arc::new(WasmUdfModule::create(&bytes, self.wasm_limits.clone())?);
+        self.wasm_modules
+            .insert(location.to_string(), module.clone());
+        Ok(module)
+    }
+
+    #[async_backtrace::framed]
+    async fn call(&mut self, index: usize, block: DataBlock) -> Result<DataBlock> {
+        if let Some(location) = self.funcs[index].wasm_module.clone() {
+            let module = self.wasm_module(&location).await?;
+            let handler = self.funcs[index].func_name.clone();
+            // the call runs until the fuel is used up, keep it off the async workers.
+            return match_join_handle(spawn_blocking(move || module.call(&handler, block))).await;
+        }
+
+        let func = &self.funcs[index];
+        let mut retries = 0;
+        loop {
+            if !self.clients.contains_key(&func.server_addr) {
+                match UdfFlightClient::connect(&func.server_addr, &self.options).await {
+                    Ok(client) => {
+                        self.clients.insert(func.server_addr.clone(), client);
+                    }
+                    Err(e) if retries < self.options.max_retries => {
+                        retries += 1;
+                        tracing::warn!("{e}, retry {retries}");
+                        sleep(Duration::from_millis(100 * retries)).await;
+                        continue;
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+            let client = self.clients.get_mut(&func.server_addr).unwrap();
+            match client.do_exchange(&func.func_name, block.clone()).await {
+                Ok(result) => return Ok(result),
+                Err(e) if retries < self.options.max_retries => {
+                    retries += 1;
+                    tracing::warn!("{e}, retry {retries}");
+                    // The connection may be broken.
+                    self.clients.remove(&func.server_addr);
+                    sleep(Duration::from_millis(100 * retries)).await;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncTransform for TransformUdf {
+    const NAME: &'static str = "UdfTransform";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, mut data: DataBlock) -> Result<DataBlock> {
+        let num_rows = data.num_rows();
+        for index in 0..self.funcs.len() {
+            let data_type = self.funcs[index].data_type.clone();
+            if num_rows == 0 {
+                let column = ColumnBuilder::with_capacity(&data_type, 0).build();
+                data.add_column(BlockEntry {
+                    data_type,
+                    value: Value::Column(column),
+                });
+                continue;
+            }
+
+            let arguments = {
+                let evaluator = Evaluator::new(&data, &self.func_ctx, &BUILTIN_FUNCTIONS);
+                self.funcs[index]
+                    .arguments
+                    .iter()
+                    .map(|argument| {
+                        let expr = argument.as_expr(&BUILTIN_FUNCTIONS);
+                        let value = evaluator.run(&expr)?;
+                        let column = value.convert_to_full_column(expr.data_type(), num_rows);
+                        Ok(BlockEntry {
+                            data_type: expr.data_type().clone(),
+                            value: Value::Column(column),
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?
+            };
+            let arguments = DataBlock::new(arguments, num_rows);
+
+            let batch_rows = self.options.batch_rows.max(1);
+            let mut results = Vec::with_capacity(num_rows / batch_rows + 1);
+            for start in (0..num_rows).step_by(batch_rows) {
+                let end = (start + batch_rows).min(num_rows);
+                results.push(self.call(index, arguments.slice(start..end)).await?);
+            }
+            let result = DataBlock::concat(&results)?;
+
+            let cast = check_cast(
+                None,
+                false,
+                Expr::ColumnRef {
+                    span: None,
+                    id: 0,
+                    data_type: result.get_by_offset(0).data_type.clone(),
+                    display_name: "".to_string(),
+                },
+                &data_type,
+                &BUILTIN_FUNCTIONS,
+            )?;
+            let evaluator = Evaluator::new(&result, &self.func_ctx, &BUILTIN_FUNCTIONS);
+            let value = evaluator.run(&cast)?;
+            data.add_column(BlockEntry { data_type, value });
+        }
+        Ok(data)
+    }
+}
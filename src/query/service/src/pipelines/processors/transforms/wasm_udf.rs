@@ -0,0 +1,208 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::io::Cursor;
+
+use arrow_ipc::reader::StreamReader;
+use arrow_ipc::writer::StreamWriter;
+use arrow_schema::Schema as ArrowSchema;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataField;
+use common_expression::DataSchema;
+use wasmi::Config;
+use wasmi::Engine;
+use wasmi::Linker;
+use wasmi::Module;
+use wasmi::Store;
+use wasmi::StoreLimits;
+use wasmi::StoreLimitsBuilder;
+
+/// The limits of an invocation of a WebAssembly UDF.
+#[derive(Clone, Debug)]
+pub struct WasmUdfLimits {
+    /// Max units of fuel consumed by an invocation, about the number of
+    /// executed instructions.
+    pub max_fuel: u64,
+    /// Max bytes of the linear memory of the instance of an invocation.
+    pub max_memory_bytes: usize,
+}
+
+struct InstanceState {
+    limits: StoreLimits,
+}
+
+/// A WebAssembly module which evaluates a UDF in-process.
+///
+/// The arguments and the result are passed as Arrow IPC streams through the
+/// linear memory of the module, which must export:
+///
+/// - `memory`, the linear memory.
+/// - `alloc(len: i32) -> i32`, which allocates `len` bytes for the arguments
+///   and returns their offset.
+/// - `<handler>(offset: i32, len: i32) -> i64`, which evaluates the function
+///   over the record batch of the arguments, and returns the offset and the
+///   length of the record batch of the result, packed as `offset << 32 | len`.
+///   The result has a single column with a row per input row.
+///
+/// Every invocation runs in a new instance with its own fuel and memory limits,
+/// so no state is kept between the invocations.
+pub struct WasmUdfModule {
+    engine: Engine,
+    module: Module,
+    limits: WasmUdfLimits,
+}
+
+impl WasmUdfModule {
+    pub fn create(bytes: &[u8], limits: WasmUdfLimits) -> Result<WasmUdfModule> {
+        let mut config = Config::default();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config);
+        let module = Module::new(&engine, bytes).map_err(|e| {
+            ErrorCode::UDFRuntimeError(format!("invalid WebAssembly module of UDF: {e}"))
+        })?;
+        Ok(WasmUdfModule {
+            engine,
+            module,
+            limits,
+        })
+    }
+
+    /// Evaluates the function `handler` over the rows of `block`.
+    pub fn call(&self, handler: &str, block: DataBlock) -> Result<DataBlock> {
+        let num_rows = block.num_rows();
+        let input = write_ipc(block)?;
+        let input_len = i32::try_from(input.len()).map_err(|_| {
+            ErrorCode::UDFRuntimeError(format!(
+                "the arguments of function {handler} are too large: {} bytes",
+                input.len()
+            ))
+        })?;
+
+        let limits = StoreLimitsBuilder::new()
+            .memory_size(self.limits.max_memory_bytes)
+            .build();
+        let mut store = Store::new(&self.engine, InstanceState { limits });
+        store.limiter(|state| &mut state.limits);
+        store
+            .add_fuel(self.limits.max_fuel)
+            .map_err(|e| runtime_error(handler, e))?;
+
+        let linker = <Linker<InstanceState>>::new(&self.engine);
+        let instance = linker
+            .instantiate(&mut store, &self.module)
+            .map_err(|e| runtime_error(handler, e))?
+            .start(&mut store)
+            .map_err(|e| runtime_error(handler, e))?;
+        let memory = instance.get_memory(&store, "memory").ok_or_else(|| {
+            ErrorCode::UDFRuntimeError("WebAssembly module of UDF must export memory")
+        })?;
+        let alloc = instance
+            .get_typed_func::<i32, i32>(&store, "alloc")
+            .map_err(|e| runtime_error("alloc", e))?;
+        let func = instance
+            .get_typed_func::<(i32, i32), i64>(&store, handler)
+            .map_err(|e| runtime_error(handler, e))?;
+
+        let offset = alloc
+            .call(&mut store, input_len)
+            .map_err(|e| runtime_error("alloc", e))?;
+        memory
+            .write(&mut store, offset as u32 as usize, &input)
+            .map_err(|e| runtime_error(handler, e))?;
+        let packed = func
+            .call(&mut store, (offset, input_len))
+            .map_err(|e| runtime_error(handler, e))? as u64;
+
+        // the range comes from the module, check it before allocating the buffer.
+        let (output_offset, output_len) =
+            ((packed >> 32) as usize, (packed & 0xffff_ffff) as usize);
+        let in_memory = output_offset
+            .checked_add(output_len)
+            .map_or(false, |end| end <= memory.data(&store).len());
+        if !in_memory || output_len > self.limits.max_memory_bytes {
+            return Err(ErrorCode::UDFRuntimeError(format!(
+                "function {handler} returned an invalid result range, offset {output_offset}, length {output_len}"
+            )));
+        }
+        let mut output = vec![0; output_len];
+        memory
+            .read(&store, output_offset, &mut output)
+            .map_err(|e| runtime_error(handler, e))?;
+        let result = read_ipc(handler, &output)?;
+        if result.num_columns() != 1 || result.num_rows() != num_rows {
+            return Err(ErrorCode::UDFRuntimeError(format!(
+                "function {handler} should return 1 column and {num_rows} rows, but got {} columns and {} rows",
+                result.num_columns(),
+                result.num_rows()
+            )));
+        }
+        Ok(result)
+    }
+}
+
+fn runtime_error(func_name: &str, e: impl Display) -> ErrorCode {
+    ErrorCode::UDFRuntimeError(format!(
+        "failed to call function {func_name} of WebAssembly module: {e}"
+    ))
+}
+
+fn write_ipc(block: DataBlock) -> Result<Vec<u8>> {
+    let fields = block
+        .columns()
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| DataField::new(&format!("arg{i}"), entry.data_type.clone()))
+        .collect::<Vec<_>>();
+    let data_schema = DataSchema::new(fields);
+    let batch = block
+        .to_record_batch(&data_schema)
+        .map_err(|e| ErrorCode::Internal(format!("{e:?}")))?;
+
+    let mut writer = StreamWriter::try_new(vec![], &ArrowSchema::from(&data_schema))
+        .map_err(|e| ErrorCode::Internal(format!("{e:?}")))?;
+    writer
+        .write(&batch)
+        .map_err(|e| ErrorCode::Internal(format!("{e:?}")))?;
+    writer
+        .finish()
+        .map_err(|e| ErrorCode::Internal(format!("{e:?}")))?;
+    writer
+        .into_inner()
+        .map_err(|e| ErrorCode::Internal(format!("{e:?}")))
+}
+
+fn read_ipc(func_name: &str, bytes: &[u8]) -> Result<DataBlock> {
+    let reader = StreamReader::try_new(Cursor::new(bytes), None).map_err(|e| {
+        ErrorCode::UDFRuntimeError(format!("invalid result of function {func_name}: {e}"))
+    })?;
+    let blocks = reader
+        .map(|batch| {
+            let batch = batch.map_err(|e| {
+                ErrorCode::UDFRuntimeError(format!("invalid result of function {func_name}: {e}"))
+            })?;
+            DataBlock::from_record_batch(&batch)
+                .map(|(block, _)| block)
+                .map_err(|e| ErrorCode::Internal(format!("{e:?}")))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if blocks.is_empty() {
+        return Err(ErrorCode::UDFRuntimeError(format!(
+            "function {func_name} returned no record batch"
+        )));
+    }
+    DataBlock::concat(&blocks)
+}
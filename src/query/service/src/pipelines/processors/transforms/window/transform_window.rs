@@ -99,10 +99,15 @@ pub struct TransformWindow<T: Number> {
     start_bound: FrameBound<T>,
     end_bound: FrameBound<T>,
 
-    // Only used for ROWS frame, default value: 0. (when not used)
+    // Only used for ROWS and GROUPS frame, default value: 0. (when not used)
     rows_start_bound: usize,
     rows_end_bound: usize,
 
+    // Only used for GROUPS frame.
+    // The index (within the partition) of the peer group starting at `frame_start` and `frame_end`.
+    frame_start_group: usize,
+    frame_end_group: usize,
+
     // NULL frame is a special RANGE frame, we need to check if the frame is a null frame.
     need_check_null_frame: bool,
     // If current frame is a null frame. This is only used when `need_check_null_frame` is true.
@@ -121,7 +126,7 @@ pub struct TransformWindow<T: Number> {
 
     /// The start of current peer group.
     /// For ROWS frame, it is the same as `current_row`.
-    /// For RANGE frame, `peer_group_start` <= `current_row`
+    /// For RANGE and GROUPS frame, `peer_group_start` <= `current_row`
     peer_group_start: RowPtr,
 
     // Used for row_number
@@ -290,7 +295,63 @@ impl<T: Number> TransformWindow<T> {
         .min(self.partition_end);
     }
 
-    /// This function is used for both `ROWS` and `RANGE`.
+    /// Find the start of the peer group next to the one `row` belongs to.
+    ///
+    /// Returns `None` if it cannot be determined until more data of the partition arrives.
+    fn next_peer_group_start(&self, row: RowPtr) -> Option<RowPtr> {
+        let mut next = self.advance_row(row);
+        while next < self.partition_end {
+            if !self.are_peers(&row, &next, false) {
+                return Some(next);
+            }
+            next = self.advance_row(next);
+        }
+        if self.partition_ended {
+            Some(self.partition_end)
+        } else {
+            None
+        }
+    }
+
+    /// Move `frame_start` to the start of the `target` peer group of the partition.
+    fn advance_frame_start_groups(&mut self, target: usize) {
+        while self.frame_start_group < target && self.frame_start < self.partition_end {
+            match self.next_peer_group_start(self.frame_start) {
+                Some(next) => {
+                    self.frame_start = next;
+                    self.frame_start_group += 1;
+                }
+                None => return,
+            }
+        }
+        self.frame_started = true;
+    }
+
+    /// Move `frame_end` to the start of the `target` peer group of the partition (`frame_end` is excluded).
+    fn advance_frame_end_groups(&mut self, target: usize) {
+        while self.frame_end_group < target {
+            if self.frame_end == self.partition_end {
+                self.frame_ended = self.partition_ended;
+                return;
+            }
+            match self.next_peer_group_start(self.frame_end) {
+                Some(next) => {
+                    self.frame_end = next;
+                    self.frame_end_group += 1;
+                }
+                None => return,
+            }
+        }
+        self.frame_ended = true;
+    }
+
+    /// The index of the current peer group in the partition, counting from 0.
+    #[inline(always)]
+    fn current_group(&self) -> usize {
+        self.current_dense_rank - 1
+    }
+
+    /// This function is used for `ROWS`, `RANGE` and `GROUPS`.
     fn advance_frame_end_current_row(&mut self) {
         // Every frame must be processed to the end of the input block if the its partition is started.
         debug_assert!(
@@ -617,6 +678,8 @@ impl TransformWindow<u64> {
             end_bound,
             rows_start_bound,
             rows_end_bound,
+            frame_start_group: 0,
+            frame_end_group: 0,
             need_check_null_frame: false,
             is_null_frame: false,
             frame_start: RowPtr::default(),
@@ -637,6 +700,24 @@ impl TransformWindow<u64> {
     }
 }
 
+// For GROUPS frame
+impl TransformWindow<u64> {
+    /// Cannot be cloned because every [`TransformWindow`] has one independent `place`.
+    pub fn try_create_groups(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        func: WindowFunctionInfo,
+        partition_indices: Vec<usize>,
+        order_by: Vec<SortColumnDescription>,
+        bounds: (FrameBound<u64>, FrameBound<u64>),
+    ) -> Result<Self> {
+        let mut transform =
+            Self::try_create_rows(input, output, func, partition_indices, order_by, bounds)?;
+        transform.frame_unit = WindowFuncFrameUnits::Groups;
+        Ok(transform)
+    }
+}
+
 // For RANGE frame
 impl<T> TransformWindow<T>
 where T: Number + ResultTypeOfUnary
@@ -682,6 +763,8 @@ where T: Number + ResultTypeOfUnary
             end_bound,
             rows_start_bound: 0,
             rows_end_bound: 0,
+            frame_start_group: 0,
+            frame_end_group: 0,
             need_check_null_frame,
             is_null_frame: false,
             frame_start: RowPtr::default(),
@@ -733,20 +816,24 @@ where T: Number + ResultTypeOfUnary
                 debug_assert!(self.peer_group_start <= self.current_row);
 
                 self.frame_started = true;
-                if self.frame_unit.is_range() {
-                    self.frame_start = self.peer_group_start;
-                } else {
+                if self.frame_unit.is_rows() {
                     self.frame_start = self.current_row;
+                } else {
+                    self.frame_start = self.peer_group_start;
+                    self.frame_start_group = self.current_group();
                 }
             }
             FrameBound::Preceding(Some(n)) => {
-                debug_assert!(self.frame_unit.is_rows() || self.order_by.len() == 1);
+                debug_assert!(!self.frame_unit.is_range() || self.order_by.len() == 1);
 
                 if self.is_null_frame {
                     self.frame_started = true;
                     self.frame_start = self.peer_group_start;
                 } else if self.frame_unit.is_rows() {
                     self.advance_frame_start_rows_preceding(self.rows_start_bound);
+                } else if self.frame_unit.is_groups() {
+                    let target = self.current_group().saturating_sub(self.rows_start_bound);
+                    self.advance_frame_start_groups(target);
                 } else if self.order_by[0].is_nullable {
                     self.advance_frame_start_nullable_range(*n, true);
                 } else {
@@ -757,13 +844,15 @@ where T: Number + ResultTypeOfUnary
                 self.frame_started = true;
             }
             FrameBound::Following(Some(n)) => {
-                debug_assert!(self.frame_unit.is_rows() || self.order_by.len() == 1);
+                debug_assert!(!self.frame_unit.is_range() || self.order_by.len() == 1);
 
                 if self.is_null_frame {
                     self.frame_started = true;
                     self.frame_start = self.peer_group_start;
                 } else if self.frame_unit.is_rows() {
                     self.advance_frame_start_rows_following(self.rows_start_bound);
+                } else if self.frame_unit.is_groups() {
+                    self.advance_frame_start_groups(self.current_group() + self.rows_start_bound);
                 } else if self.order_by[0].is_nullable {
                     self.advance_frame_start_nullable_range(*n, false);
                 } else {
@@ -784,12 +873,15 @@ where T: Number + ResultTypeOfUnary
                 self.advance_frame_end_current_row();
             }
             FrameBound::Preceding(Some(n)) => {
-                debug_assert!(self.frame_unit.is_rows() || self.order_by.len() == 1);
+                debug_assert!(!self.frame_unit.is_range() || self.order_by.len() == 1);
 
                 if self.is_null_frame {
                     self.advance_frame_end_current_row();
                 } else if self.frame_unit.is_rows() {
                     self.advance_frame_end_rows_preceding(self.rows_end_bound);
+                } else if self.frame_unit.is_groups() {
+                    let target = (self.current_group() + 1).saturating_sub(self.rows_end_bound);
+                    self.advance_frame_end_groups(target);
                 } else if self.order_by[0].is_nullable {
                     self.advance_frame_end_nullable_range(*n, true);
                 } else {
@@ -800,12 +892,14 @@ where T: Number + ResultTypeOfUnary
                 unreachable!()
             }
             FrameBound::Following(Some(n)) => {
-                debug_assert!(self.frame_unit.is_rows() || self.order_by.len() == 1);
+                debug_assert!(!self.frame_unit.is_range() || self.order_by.len() == 1);
 
                 if self.is_null_frame {
                     self.advance_frame_end_current_row();
                 } else if self.frame_unit.is_rows() {
                     self.advance_frame_end_rows_following(self.rows_end_bound);
+                } else if self.frame_unit.is_groups() {
+                    self.advance_frame_end_groups(self.current_group() + self.rows_end_bound + 1);
                 } else if self.order_by[0].is_nullable {
                     self.advance_frame_end_nullable_range(*n, false);
                 } else {
@@ -895,6 +989,7 @@ where T: Number + ResultTypeOfUnary
 
                     if self.frame_end < self.frame_start {
                         self.frame_end = self.frame_start;
+                        self.frame_end_group = self.frame_start_group;
                     }
 
                     self.advance_frame_end();
@@ -945,6 +1040,8 @@ where T: Number + ResultTypeOfUnary
                 self.is_null_frame = false;
                 self.frame_start = self.partition_start;
                 self.frame_end = self.partition_start;
+                self.frame_start_group = 0;
+                self.frame_end_group = 0;
                 self.prev_frame_start = self.frame_start;
                 self.prev_frame_end = self.frame_end;
 
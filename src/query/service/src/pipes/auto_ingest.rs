@@ -0,0 +1,305 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::PipeInfo;
+use common_meta_app::principal::PipeSource;
+use common_meta_app::principal::StageInfo;
+use common_meta_app::storage::StorageParams;
+use common_meta_app::storage::StorageS3Config;
+use common_users::UserApiProvider;
+use regex::Regex;
+use serde::Deserialize;
+use serde_json::json;
+use serde_json::Value;
+use tracing::warn;
+
+use crate::change_notification::sqs_signed_request;
+
+const SQS_CONTENT_TYPE: &str = "application/x-amz-json-1.0";
+const SQS_MAX_MESSAGES: u64 = 10;
+/// The most messages received from the queue by a round of the runner.
+const SQS_MAX_RECEIVES: usize = 10;
+
+/// An object created in the bucket of a stage, from an S3 event notification.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ObjectCreated {
+    pub bucket: String,
+    pub key: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct S3EventNotification {
+    #[serde(default)]
+    records: Vec<S3EventRecord>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct S3EventRecord {
+    event_name: String,
+    s3: S3Entity,
+}
+
+#[derive(Deserialize)]
+struct S3Entity {
+    bucket: S3Bucket,
+    object: S3Object,
+}
+
+#[derive(Deserialize)]
+struct S3Bucket {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct S3Object {
+    key: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct SqsReceiveResult {
+    #[serde(default)]
+    messages: Vec<SqsMessage>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct SqsMessage {
+    receipt_handle: String,
+    body: String,
+}
+
+/// Parse the object-created events of an S3 event notification, as sent to SQS or
+/// to a webhook, either directly or wrapped in an SNS notification. The other events,
+/// e.g. the test event sent when the notification is configured, are ignored.
+pub fn parse_object_created_events(body: &str) -> Result<Vec<ObjectCreated>> {
+    let invalid = |e: serde_json::Error| {
+        ErrorCode::BadArguments(format!("Invalid S3 event notification, {}", e))
+    };
+    let mut value = serde_json::from_str::<Value>(body).map_err(invalid)?;
+    if value["Type"] == "Notification" {
+        if let Some(message) = value["Message"].as_str() {
+            value = serde_json::from_str(message).map_err(invalid)?;
+        }
+    }
+
+    let notification = serde_json::from_value::<S3EventNotification>(value).map_err(invalid)?;
+    Ok(notification
+        .records
+        .into_iter()
+        .filter(|record| record.event_name.starts_with("ObjectCreated:"))
+        .map(|record| ObjectCreated {
+            bucket: record.s3.bucket.name,
+            key: decode_object_key(&record.s3.object.key),
+        })
+        .collect())
+}
+
+/// The keys of the events are URL encoded, with '+' for the spaces.
+fn decode_object_key(key: &str) -> String {
+    serde_urlencoded::from_str::<Vec<(String, String)>>(&format!("key={}", key))
+        .ok()
+        .and_then(|pairs| pairs.into_iter().next())
+        .map_or_else(|| key.to_string(), |(_, key)| key)
+}
+
+/// The files of the objects created under the location of a pipe, as the paths in
+/// the stage. The objects of other buckets or out of the location are ignored, as
+/// well as the ones not matching the pattern of the pipe.
+pub fn pipe_files(
+    stage: &StageInfo,
+    path: &str,
+    pattern: &str,
+    events: &[ObjectCreated],
+) -> Result<Vec<String>> {
+    let config = match &stage.stage_params.storage {
+        StorageParams::S3(config) => config,
+        _ => {
+            return Err(ErrorCode::IllegalPipeFormat(format!(
+                "Stage {} is not on S3",
+                stage.stage_name
+            )));
+        }
+    };
+    let pattern = Regex::new(pattern)
+        .map_err(|e| ErrorCode::IllegalPipeFormat(format!("Invalid pattern {}, {}", pattern, e)))?;
+
+    let root = config.root.trim_matches('/');
+    let location = path.trim_start_matches('/');
+    let mut files = vec![];
+    for event in events {
+        if event.bucket != config.bucket || event.key.ends_with('/') {
+            continue;
+        }
+        let file = match root.is_empty() {
+            true => Some(event.key.as_str()),
+            false => event
+                .key
+                .strip_prefix(root)
+                .and_then(|key| key.strip_prefix('/')),
+        };
+        match file {
+            Some(file) if file.starts_with(location) && pattern.is_match(file) => {
+                files.push(file.to_string());
+            }
+            _ => {}
+        }
+    }
+    files.sort();
+    files.dedup();
+    Ok(files)
+}
+
+/// Add the files of the object-created events to the files of a pipe from a stage,
+/// to be copied by the runner. Returns the files added.
+#[async_backtrace::framed]
+pub async fn add_pipe_events(tenant: &str, pipe: &PipeInfo, body: &str) -> Result<Vec<String>> {
+    let (stage_name, path, pattern) = match &pipe.source {
+        PipeSource::Stage {
+            stage_name,
+            path,
+            pattern,
+            ..
+        } => (stage_name, path, pattern),
+        _ => {
+            return Err(ErrorCode::IllegalPipeFormat(format!(
+                "Pipe {} doesn't ingest from a stage",
+                pipe.name
+            )));
+        }
+    };
+
+    let events = parse_object_created_events(body)?;
+    let user_api = UserApiProvider::instance();
+    let stage = user_api.get_stage(tenant, stage_name).await?;
+    let files = pipe_files(&stage, path, pattern, &events)?;
+    user_api.add_pipe_files(tenant, &pipe.name, &files).await?;
+    Ok(files)
+}
+
+/// Receive the events of a pipe from its SQS queue by the JSON protocol, signed with
+/// the credentials of the stage. A message is deleted from the queue after its files
+/// are added to the pipe, a message that can't be parsed is dropped.
+#[async_backtrace::framed]
+pub async fn receive_sqs_events(
+    client: &reqwest::Client,
+    tenant: &str,
+    pipe: &PipeInfo,
+    stage: &StageInfo,
+    queue_url: &str,
+) -> Result<usize> {
+    let config = match &stage.stage_params.storage {
+        StorageParams::S3(config) => config,
+        _ => {
+            return Err(ErrorCode::IllegalPipeFormat(format!(
+                "Stage {} is not on S3",
+                stage.stage_name
+            )));
+        }
+    };
+    let mut received = 0;
+    for _ in 0..SQS_MAX_RECEIVES {
+        let text = sqs_action(
+            client,
+            config,
+            queue_url,
+            "ReceiveMessage",
+            json!({
+                "QueueUrl": queue_url,
+                "MaxNumberOfMessages": SQS_MAX_MESSAGES,
+                "WaitTimeSeconds": 0,
+            }),
+        )
+        .await?;
+        let result = serde_json::from_str::<SqsReceiveResult>(&text).map_err(|e| {
+            ErrorCode::PipeSourceFailure(format!("invalid response of {}, {}", queue_url, e))
+        })?;
+        if result.messages.is_empty() {
+            break;
+        }
+
+        for message in result.messages {
+            match add_pipe_events(tenant, pipe, &message.body).await {
+                Ok(_) => received += 1,
+                Err(cause) if cause.code() == ErrorCode::BAD_ARGUMENTS => {
+                    warn!(
+                        "pipe {}: drop message of {}, {}",
+                        pipe.name, queue_url, cause
+                    );
+                }
+                Err(cause) => return Err(cause),
+            }
+            sqs_action(
+                client,
+                config,
+                queue_url,
+                "DeleteMessage",
+                json!({
+                    "QueueUrl": queue_url,
+                    "ReceiptHandle": message.receipt_handle,
+                }),
+            )
+            .await?;
+        }
+    }
+    Ok(received)
+}
+
+#[async_backtrace::framed]
+async fn sqs_action(
+    client: &reqwest::Client,
+    config: &StorageS3Config,
+    queue_url: &str,
+    action: &str,
+    body: Value,
+) -> Result<String> {
+    let region = match config.region.is_empty() {
+        true => "us-east-1",
+        false => config.region.as_str(),
+    };
+    // the actions of the JSON protocol are posted to the root of the endpoint.
+    let url = reqwest::Url::parse(queue_url)
+        .map_err(|e| ErrorCode::PipeSourceFailure(format!("invalid queue url, {}", e)))?;
+    let endpoint = format!("{}/", url.origin().ascii_serialization());
+    let target = format!("AmazonSQS.{}", action);
+    let request = sqs_signed_request(
+        client,
+        &endpoint,
+        region,
+        &config.access_key_id,
+        &config.secret_access_key,
+        &[
+            ("content-type", SQS_CONTENT_TYPE),
+            ("x-amz-target", &target),
+        ],
+        body.to_string(),
+    )?;
+
+    let response = request.send().await.map_err(|e| {
+        ErrorCode::PipeSourceFailure(format!("failed to {} from {}, {}", action, queue_url, e))
+    })?;
+    let status = response.status();
+    let text = response.text().await.unwrap_or_default();
+    if !status.is_success() {
+        return Err(ErrorCode::PipeSourceFailure(format!(
+            "{} responded {} to {}, {}",
+            queue_url, status, action, text
+        )));
+    }
+    Ok(text)
+}
@@ -0,0 +1,354 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::TableDataType;
+use common_expression::TableSchema;
+use common_meta_app::principal::GtidSet;
+use common_meta_app::principal::PipeInfo;
+use common_meta_app::principal::PipeSource;
+use common_users::UserApiProvider;
+use serde::Deserialize;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::pipes::runner::execute_plan;
+use crate::pipes::runner::failed_load;
+use crate::pipes::runner::owner_session;
+use crate::pipes::runner::record_error;
+use crate::pipes::runner::record_load;
+use crate::pipes::runner::succeeded_load;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::Planner;
+
+/// The most rows merged by a statement, the rows of a post are merged by several
+/// statements if there are more.
+const MAX_MERGE_ROWS: usize = 1000;
+const OPERATION_COLUMN: &str = "__binlog_op";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BinlogEventType {
+    Insert,
+    Update,
+    Delete,
+}
+
+/// A row event of the binlog of MySQL, as posted by the coordinator tailing it. `data`
+/// is the row inserted, deleted, or after the update, `old` has the columns changed by
+/// the update with their values before it.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct BinlogRowEvent {
+    /// `<server_uuid>:<transaction_id>` of the transaction of the event.
+    pub gtid: String,
+    pub database: String,
+    pub table: String,
+    #[serde(rename = "type")]
+    pub event_type: BinlogEventType,
+    pub data: Map<String, Value>,
+    #[serde(default)]
+    pub old: Map<String, Value>,
+}
+
+/// The last change of a row of a post, found by the primary key.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MergeRow {
+    pub delete: bool,
+    pub values: Map<String, Value>,
+}
+
+/// How a post of row events is merged into the table of a pipe.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BinlogMerged {
+    pub merged: u64,
+    pub skipped: u64,
+    pub gtid_executed: GtidSet,
+}
+
+/// Parse the row events posted to a pipe, a JSON array of events, or an event per line.
+pub fn parse_binlog_events(body: &str) -> Result<Vec<BinlogRowEvent>> {
+    let invalid =
+        |e: serde_json::Error| ErrorCode::BadArguments(format!("Invalid binlog row event, {}", e));
+    let body = body.trim();
+    if body.starts_with('[') {
+        return serde_json::from_str(body).map_err(invalid);
+    }
+    body.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str(line).map_err(invalid))
+        .collect()
+}
+
+/// Collapse the events into the last change of each row, in the order the rows are
+/// first changed. An update of the primary key deletes the row of the old key.
+pub fn merge_rows(primary_key: &[String], events: &[BinlogRowEvent]) -> Result<Vec<MergeRow>> {
+    let mut rows: Vec<MergeRow> = vec![];
+    let mut positions: HashMap<Vec<String>, usize> = HashMap::new();
+    let mut put = |key: Vec<String>, row: MergeRow| match positions.get(&key) {
+        Some(position) => rows[*position] = row,
+        None => {
+            positions.insert(key, rows.len());
+            rows.push(row);
+        }
+    };
+
+    for event in events {
+        let key = row_key(primary_key, event, &event.data)?;
+        if event.event_type == BinlogEventType::Update {
+            let mut before = event.data.clone();
+            before.extend(event.old.clone());
+            let old_key = row_key(primary_key, event, &before)?;
+            if old_key != key {
+                put(old_key, MergeRow {
+                    delete: true,
+                    values: before,
+                });
+            }
+        }
+        put(key, MergeRow {
+            delete: event.event_type == BinlogEventType::Delete,
+            values: event.data.clone(),
+        });
+    }
+    Ok(rows)
+}
+
+fn row_key(
+    primary_key: &[String],
+    event: &BinlogRowEvent,
+    row: &Map<String, Value>,
+) -> Result<Vec<String>> {
+    primary_key
+        .iter()
+        .map(|column| match column_value(row, column) {
+            Some(value) if !value.is_null() => Ok(value.to_string()),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "Row event of {} has no value of primary key column {}",
+                event.gtid, column
+            ))),
+        })
+        .collect()
+}
+
+fn column_value<'a>(row: &'a Map<String, Value>, column: &str) -> Option<&'a Value> {
+    row.iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(column))
+        .map(|(_, value)| value)
+}
+
+/// The statements merging the rows into the table, by the primary key. Only the columns
+/// of the table in the rows are updated, the others keep their values, or get their
+/// defaults when the rows are inserted.
+pub fn merge_sql(
+    pipe: &PipeInfo,
+    schema: &TableSchema,
+    primary_key: &[String],
+    rows: &[MergeRow],
+) -> Vec<String> {
+    let quote_ident = |name: &str| format!("`{}`", name.replace('`', "``"));
+    let quote = |s: &str| format!("'{}'", s.replace('\\', "\\\\").replace('\'', "\\'"));
+    let is_key = |name: &str| primary_key.iter().any(|key| key.eq_ignore_ascii_case(name));
+    let fields = schema
+        .fields()
+        .iter()
+        .filter(|field| {
+            is_key(field.name())
+                || rows
+                    .iter()
+                    .any(|row| column_value(&row.values, field.name()).is_some())
+        })
+        .collect::<Vec<_>>();
+
+    let mut on = vec![];
+    let mut updates = vec![];
+    let mut columns = vec![];
+    let mut values = vec![];
+    for field in fields.iter() {
+        let column = quote_ident(field.name());
+        match is_key(field.name()) {
+            true => on.push(format!("t.{} = s.{}", column, column)),
+            false => updates.push(format!("{} = s.{}", column, column)),
+        }
+        values.push(format!("s.{}", column));
+        columns.push(column);
+    }
+
+    let select_row = |row: &MergeRow| {
+        let mut select_list = fields
+            .iter()
+            .map(|field| {
+                // only the primary key of a deleted row is needed.
+                let value = match row.delete && !is_key(field.name()) {
+                    true => None,
+                    false => column_value(&row.values, field.name()),
+                };
+                // the values of the VARIANT columns are kept as JSON.
+                let is_variant =
+                    matches!(field.data_type().remove_nullable(), TableDataType::Variant);
+                let literal = match value {
+                    None | Some(Value::Null) => "NULL".to_string(),
+                    Some(Value::String(s)) if !is_variant => quote(s),
+                    Some(value) => quote(&value.to_string()),
+                };
+                format!(
+                    "CAST({} AS {}) AS {}",
+                    literal,
+                    field.data_type().wrap_nullable().sql_name(),
+                    quote_ident(field.name())
+                )
+            })
+            .collect::<Vec<_>>();
+        let operation = match row.delete {
+            true => "delete",
+            false => "upsert",
+        };
+        select_list.push(format!("'{}' AS {}", operation, OPERATION_COLUMN));
+        format!("SELECT {}", select_list.join(", "))
+    };
+
+    rows.chunks(MAX_MERGE_ROWS)
+        .map(|chunk| {
+            let source = chunk.iter().map(select_row).collect::<Vec<_>>();
+            let mut sql = format!(
+                "MERGE INTO {}.{} AS t USING ({}) AS s ON {} WHEN MATCHED AND s.{} = 'delete' THEN DELETE",
+                quote_ident(&pipe.database),
+                quote_ident(&pipe.table),
+                source.join(" UNION ALL "),
+                on.join(" AND "),
+                OPERATION_COLUMN,
+            );
+            if !updates.is_empty() {
+                sql.push_str(&format!(
+                    " WHEN MATCHED THEN UPDATE SET {}",
+                    updates.join(", ")
+                ));
+            }
+            sql.push_str(&format!(
+                " WHEN NOT MATCHED AND s.{} = 'upsert' THEN INSERT ({}) VALUES ({})",
+                OPERATION_COLUMN,
+                columns.join(", "),
+                values.join(", ")
+            ));
+            sql
+        })
+        .collect()
+}
+
+/// Merge the row events posted to a pipe from MySQL into its table, as the owner of the
+/// pipe, then add their transactions to the ones merged.
+///
+/// The coordinator posts the events in the order of the binlog, and posts them again
+/// if the post fails. The events of the transactions merged already are skipped, except
+/// the ones of the last transaction, which may go on in this post: merging the last
+/// change of the rows again leaves them the same. The events of other tables are
+/// skipped too.
+#[async_backtrace::framed]
+pub async fn merge_binlog_events(tenant: &str, name: &str, body: &str) -> Result<BinlogMerged> {
+    let user_api = UserApiProvider::instance();
+    let seq_pipe = user_api.get_pipe(tenant, name).await?;
+    let (mut pipe, seq) = (seq_pipe.data, seq_pipe.seq);
+    let (database, table, primary_key) = match &pipe.source {
+        PipeSource::MySql {
+            database,
+            table,
+            primary_key,
+        } => (database.clone(), table.clone(), primary_key.clone()),
+        _ => {
+            return Err(ErrorCode::IllegalPipeFormat(format!(
+                "Pipe {} doesn't replicate MySQL",
+                pipe.name
+            )));
+        }
+    };
+
+    let started_at = Utc::now();
+    let mut events = vec![];
+    let mut gtids = vec![];
+    let mut skipped = 0;
+    for event in parse_binlog_events(body)? {
+        let (uuid, id) = GtidSet::parse_gtid(&event.gtid)?;
+        let gtid = format!("{}:{}", uuid, id);
+        if !event.database.eq_ignore_ascii_case(&database)
+            || !event.table.eq_ignore_ascii_case(&table)
+            || (pipe.gtid_executed.contains(&uuid, id) && gtid != pipe.last_gtid)
+        {
+            skipped += 1;
+            continue;
+        }
+        gtids.push((uuid, id, gtid));
+        events.push(event);
+    }
+    let last_gtid = match gtids.last() {
+        Some((_, _, gtid)) => gtid.clone(),
+        None => {
+            return Ok(BinlogMerged {
+                merged: 0,
+                skipped,
+                gtid_executed: pipe.gtid_executed,
+            });
+        }
+    };
+
+    let merged = events.len() as u64;
+    let rows = merge_rows(&primary_key, &events)?;
+    if let Err(cause) = merge_into_table(tenant, &pipe, &primary_key, &rows).await {
+        let load = failed_load(&pipe, started_at, merged, vec![], &cause);
+        return Err(record_error(tenant, pipe, seq, load, cause).await);
+    }
+
+    for (uuid, id, _) in gtids {
+        pipe.gtid_executed.add(&uuid, id);
+    }
+    pipe.last_gtid = last_gtid;
+    pipe.loaded_messages += merged;
+    pipe.last_loaded_at = Some(Utc::now());
+    pipe.last_error.clear();
+    let load = succeeded_load(&pipe, started_at, merged, vec![]);
+    let gtid_executed = pipe.gtid_executed.clone();
+    user_api.update_pipe(tenant, pipe, seq).await?;
+    record_load(tenant, load).await;
+    Ok(BinlogMerged {
+        merged,
+        skipped,
+        gtid_executed,
+    })
+}
+
+#[async_backtrace::framed]
+async fn merge_into_table(
+    tenant: &str,
+    pipe: &PipeInfo,
+    primary_key: &[String],
+    rows: &[MergeRow],
+) -> Result<()> {
+    let session = owner_session(tenant, pipe).await?;
+    let ctx: Arc<QueryContext> = session.create_query_context().await?;
+    let to_table = ctx
+        .get_table(CATALOG_DEFAULT, &pipe.database, &pipe.table)
+        .await?;
+    for sql in merge_sql(pipe, &to_table.schema(), primary_key, rows) {
+        let ctx: Arc<QueryContext> = session.create_query_context().await?;
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, _) = planner.plan_sql(&sql).await?;
+        execute_plan(ctx, plan).await?;
+    }
+    Ok(())
+}
@@ -0,0 +1,310 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use reqwest::header::ACCEPT;
+use reqwest::header::AUTHORIZATION;
+use reqwest::header::CONTENT_TYPE;
+use reqwest::RequestBuilder;
+use serde::Deserialize;
+use serde_json::json;
+
+const KAFKA_CONTENT_TYPE: &str = "application/vnd.kafka.v2+json";
+const KAFKA_BINARY_ACCEPT: &str = "application/vnd.kafka.binary.v2+json";
+const POLL_TIMEOUT: Duration = Duration::from_secs(1);
+const POLL_MAX_BYTES: u64 = 16 * 1024 * 1024;
+/// A pending batch is given up if its messages stop coming, e.g. they are deleted
+/// by the retention of the topic.
+const MAX_EMPTY_POLLS: usize = 10;
+
+/// A message of the topic, the value is loaded as a row of the table.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KafkaMessage {
+    pub partition: u32,
+    pub offset: u64,
+    pub value: Vec<u8>,
+}
+
+#[derive(Deserialize)]
+struct ConsumerInstance {
+    base_uri: String,
+}
+
+#[derive(Deserialize)]
+struct TopicPartition {
+    partition: u32,
+}
+
+#[derive(Deserialize)]
+struct BinaryRecord {
+    partition: u32,
+    offset: u64,
+    value: Option<String>,
+}
+
+/// Reads the messages of a topic through the consumer API v2 of a Kafka REST proxy.
+///
+/// The offsets are never committed to Kafka, the consumer is positioned explicitly on
+/// each read, and the instance is deleted by `close` after the read.
+pub struct KafkaRestConsumer {
+    client: reqwest::Client,
+    topic: String,
+    authorization_header: String,
+    base_uri: String,
+    partitions: Vec<u32>,
+}
+
+impl KafkaRestConsumer {
+    /// Creates a consumer instance in `group` reading the records as binary, and looks
+    /// up the partitions of the topic.
+    #[async_backtrace::framed]
+    pub async fn create(
+        client: reqwest::Client,
+        rest_proxy_url: &str,
+        topic: &str,
+        authorization_header: &str,
+        group: &str,
+    ) -> Result<Self> {
+        let mut consumer = KafkaRestConsumer {
+            client,
+            topic: topic.to_string(),
+            authorization_header: authorization_header.to_string(),
+            base_uri: String::new(),
+            partitions: vec![],
+        };
+        let url = format!("{}/consumers/{}", rest_proxy_url, group);
+        let body = json!({
+            "format": "binary",
+            "auto.offset.reset": "earliest",
+            "auto.commit.enable": "false",
+        });
+        let instance: ConsumerInstance = consumer
+            .send_json(consumer.client.post(&url).body(body.to_string()))
+            .await?;
+        consumer.base_uri = instance.base_uri;
+
+        let url = format!("{}/topics/{}/partitions", rest_proxy_url, topic);
+        let partitions: Vec<TopicPartition> = consumer.send_json(consumer.client.get(&url)).await?;
+        consumer.partitions = partitions.into_iter().map(|p| p.partition).collect();
+        Ok(consumer)
+    }
+
+    /// Reads up to `max_messages` messages from `offsets`, the partitions not in it are
+    /// read from their earliest messages. The messages of each partition are in order.
+    #[async_backtrace::framed]
+    pub async fn fetch_from(
+        &self,
+        offsets: &BTreeMap<u32, u64>,
+        max_messages: usize,
+    ) -> Result<Vec<KafkaMessage>> {
+        self.assign(&self.partitions).await?;
+        let (positioned, beginning): (Vec<u32>, Vec<u32>) = self
+            .partitions
+            .iter()
+            .partition(|p| offsets.contains_key(p));
+        self.seek(&positioned, offsets).await?;
+        self.seek_to_beginning(&beginning).await?;
+
+        let mut messages = vec![];
+        let mut next_offsets = offsets.clone();
+        while messages.len() < max_messages {
+            let fetched = messages.len();
+            for message in self.poll().await? {
+                // the records read already may be returned again, e.g. after a rebalance.
+                let next = next_offsets.entry(message.partition).or_insert(0);
+                if message.offset < *next || messages.len() >= max_messages {
+                    continue;
+                }
+                *next = message.offset + 1;
+                messages.push(message);
+            }
+            if messages.len() == fetched {
+                break;
+            }
+        }
+        Ok(messages)
+    }
+
+    /// Reads the messages of the offsets `[start, end)` of each partition again.
+    #[async_backtrace::framed]
+    pub async fn fetch_range(
+        &self,
+        ranges: &BTreeMap<u32, (u64, u64)>,
+    ) -> Result<Vec<KafkaMessage>> {
+        let partitions = ranges.keys().copied().collect::<Vec<_>>();
+        let offsets = ranges
+            .iter()
+            .map(|(partition, (start, _))| (*partition, *start))
+            .collect::<BTreeMap<_, _>>();
+        self.assign(&partitions).await?;
+        self.seek(&partitions, &offsets).await?;
+
+        let mut messages = vec![];
+        let mut remaining = ranges.clone();
+        let mut empty_polls = 0;
+        while !remaining.is_empty() {
+            let records = self.poll().await?;
+            if records.is_empty() {
+                empty_polls += 1;
+                if empty_polls >= MAX_EMPTY_POLLS {
+                    return Err(ErrorCode::PipeSourceFailure(format!(
+                        "messages of partitions {:?} of topic {} are no longer available",
+                        remaining, self.topic
+                    )));
+                }
+                continue;
+            }
+            empty_polls = 0;
+            for message in records {
+                let (start, end) = match ranges.get(&message.partition) {
+                    Some(range) => *range,
+                    None => continue,
+                };
+                // the offsets may have gaps, e.g. the markers of transactions.
+                if message.offset + 1 >= end {
+                    remaining.remove(&message.partition);
+                }
+                if message.offset >= start && message.offset < end {
+                    messages.push(message);
+                }
+            }
+        }
+        Ok(messages)
+    }
+
+    /// Deletes the consumer instance, the proxy also deletes it after it's idle a while.
+    #[async_backtrace::framed]
+    pub async fn close(self) -> Result<()> {
+        self.send(self.client.delete(&self.base_uri)).await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn assign(&self, partitions: &[u32]) -> Result<()> {
+        let body = json!({
+            "partitions": partitions
+                .iter()
+                .map(|p| json!({ "topic": self.topic, "partition": p }))
+                .collect::<Vec<_>>(),
+        });
+        let url = format!("{}/assignments", self.base_uri);
+        self.send(self.client.post(&url).body(body.to_string()))
+            .await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn seek(&self, partitions: &[u32], offsets: &BTreeMap<u32, u64>) -> Result<()> {
+        if partitions.is_empty() {
+            return Ok(());
+        }
+        let body = json!({
+            "offsets": partitions
+                .iter()
+                .map(|p| json!({ "topic": self.topic, "partition": p, "offset": offsets[p] }))
+                .collect::<Vec<_>>(),
+        });
+        let url = format!("{}/positions", self.base_uri);
+        self.send(self.client.post(&url).body(body.to_string()))
+            .await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn seek_to_beginning(&self, partitions: &[u32]) -> Result<()> {
+        if partitions.is_empty() {
+            return Ok(());
+        }
+        let body = json!({
+            "partitions": partitions
+                .iter()
+                .map(|p| json!({ "topic": self.topic, "partition": p }))
+                .collect::<Vec<_>>(),
+        });
+        let url = format!("{}/positions/beginning", self.base_uri);
+        self.send(self.client.post(&url).body(body.to_string()))
+            .await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn poll(&self) -> Result<Vec<KafkaMessage>> {
+        let url = format!(
+            "{}/records?timeout={}&max_bytes={}",
+            self.base_uri,
+            POLL_TIMEOUT.as_millis(),
+            POLL_MAX_BYTES
+        );
+        let records: Vec<BinaryRecord> = self
+            .send_json(self.client.get(&url).header(ACCEPT, KAFKA_BINARY_ACCEPT))
+            .await?;
+
+        let mut messages = Vec::with_capacity(records.len());
+        for record in records {
+            let value = match record.value {
+                Some(value) => BASE64_STANDARD.decode(value).map_err(|e| {
+                    ErrorCode::PipeSourceFailure(format!(
+                        "invalid record of topic {} at {}/{}, {}",
+                        self.topic, record.partition, record.offset, e
+                    ))
+                })?,
+                // the tombstones have no value to load.
+                None => continue,
+            };
+            messages.push(KafkaMessage {
+                partition: record.partition,
+                offset: record.offset,
+                value,
+            });
+        }
+        Ok(messages)
+    }
+
+    #[async_backtrace::framed]
+    async fn send_json<T: for<'de> Deserialize<'de>>(&self, request: RequestBuilder) -> Result<T> {
+        let body = self.send(request).await?;
+        serde_json::from_slice(&body).map_err(|e| {
+            ErrorCode::PipeSourceFailure(format!("invalid response of the Kafka REST proxy, {}", e))
+        })
+    }
+
+    #[async_backtrace::framed]
+    async fn send(&self, request: RequestBuilder) -> Result<Vec<u8>> {
+        let mut request = request.header(CONTENT_TYPE, KAFKA_CONTENT_TYPE);
+        if !self.authorization_header.is_empty() {
+            request = request.header(AUTHORIZATION, &self.authorization_header);
+        }
+        let response = request.send().await.map_err(|e| {
+            ErrorCode::PipeSourceFailure(format!("failed to reach the Kafka REST proxy, {}", e))
+        })?;
+        let status = response.status();
+        let body = response.bytes().await.map_err(|e| {
+            ErrorCode::PipeSourceFailure(format!("failed to read the Kafka REST proxy, {}", e))
+        })?;
+        if !status.is_success() {
+            return Err(ErrorCode::PipeSourceFailure(format!(
+                "Kafka REST proxy responded {}, {}",
+                status,
+                String::from_utf8_lossy(&body)
+            )));
+        }
+        Ok(body.to_vec())
+    }
+}
@@ -0,0 +1,37 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod auto_ingest;
+mod binlog;
+mod kafka;
+mod runner;
+
+pub use auto_ingest::add_pipe_events;
+pub use auto_ingest::parse_object_created_events;
+pub use auto_ingest::pipe_files;
+pub use auto_ingest::receive_sqs_events;
+pub use auto_ingest::ObjectCreated;
+pub use binlog::merge_binlog_events;
+pub use binlog::merge_rows;
+pub use binlog::merge_sql;
+pub use binlog::parse_binlog_events;
+pub use binlog::BinlogEventType;
+pub use binlog::BinlogMerged;
+pub use binlog::BinlogRowEvent;
+pub use binlog::MergeRow;
+pub use kafka::KafkaMessage;
+pub use kafka::KafkaRestConsumer;
+pub use runner::run_pipe_batch;
+pub use runner::PipeRunner;
+pub use runner::MAX_BATCH_MESSAGES;
@@ -0,0 +1,651 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_base::base::tokio;
+use common_base::base::tokio::time::sleep;
+use common_base::base::GlobalUniqName;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_config::InnerConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::infer_table_schema;
+use common_meta_app::principal::FileFormatParams;
+use common_meta_app::principal::PipeBatch;
+use common_meta_app::principal::PipeInfo;
+use common_meta_app::principal::PipeLoad;
+use common_meta_app::principal::PipeSource;
+use common_meta_app::principal::StageInfo;
+use common_pipeline_sources::input_formats::InputContext;
+use common_pipeline_sources::input_formats::StreamingReadBatch;
+use common_sql::plans::InsertInputSource;
+use common_sql::plans::Plan;
+use common_storages_stage::StageTable;
+use common_users::UserApiProvider;
+use futures_util::TryStreamExt;
+use parking_lot::Mutex;
+use tracing::info;
+use tracing::warn;
+
+use crate::interpreters::InterpreterFactory;
+use crate::pipes::receive_sqs_events;
+use crate::pipes::KafkaMessage;
+use crate::pipes::KafkaRestConsumer;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+use crate::sql::Planner;
+
+const RUNNER_TICK: Duration = Duration::from_secs(1);
+/// The leader is replaced if it doesn't renew the lease in time, e.g. it's down.
+const RUNNER_LEASE_TTL: Duration = Duration::from_secs(10);
+const SOURCE_TIMEOUT: Duration = Duration::from_secs(30);
+/// The most messages loaded by a batch, i.e. by a snapshot of the table.
+pub const MAX_BATCH_MESSAGES: usize = 10000;
+/// The most files of a stage copied at a time.
+const MAX_COPY_FILES: usize = 1000;
+
+/// Loads the messages and the files of the pipes of the tenant into their tables.
+///
+/// Every query node runs a runner, but only the one holding the lease in meta loads
+/// the pipes, each pipe loads one batch at a time. The offsets and the pending batch
+/// of a pipe are updated with the seq read, so a runner that lost the lease can't
+/// move them, and the deduplicate label of a batch makes its load idempotent.
+pub struct PipeRunner {
+    tenant: String,
+    holder: String,
+    client: reqwest::Client,
+    running: Mutex<HashSet<String>>,
+}
+
+impl PipeRunner {
+    pub fn init(config: &InnerConfig) -> Result<()> {
+        // the pipes can't load the tables on a read-only replica or in management mode.
+        if config.query.read_only_replica || config.query.management_mode {
+            return Ok(());
+        }
+
+        let client = reqwest::Client::builder()
+            .timeout(SOURCE_TIMEOUT)
+            .build()
+            .map_err(|e| ErrorCode::PipeSourceFailure(e.to_string()))?;
+        let runner = Arc::new(PipeRunner {
+            tenant: config.query.tenant_id.clone(),
+            holder: GlobalUniqName::unique(),
+            client,
+            running: Mutex::new(HashSet::new()),
+        });
+        GlobalIORuntime::instance().try_spawn(async move { runner.run().await })?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn run(self: Arc<Self>) {
+        let mut leading = false;
+        loop {
+            sleep(RUNNER_TICK).await;
+
+            let user_api = UserApiProvider::instance();
+            match user_api
+                .renew_pipe_runner_lease(&self.tenant, &self.holder, RUNNER_LEASE_TTL)
+                .await
+            {
+                Ok(true) => {
+                    if !leading {
+                        info!("pipe runner: {} takes the lead", self.holder);
+                    }
+                    leading = true;
+                }
+                Ok(false) => {
+                    leading = false;
+                    continue;
+                }
+                Err(cause) => {
+                    warn!("pipe runner: renew lease failed, {}", cause);
+                    continue;
+                }
+            }
+
+            match user_api.get_pipes(&self.tenant).await {
+                Ok(pipes) => {
+                    for pipe in pipes {
+                        if let Err(cause) = self.spawn_batch(pipe.name) {
+                            warn!("pipe runner: spawn batch failed, {}", cause);
+                        }
+                    }
+                }
+                Err(cause) => warn!("pipe runner: round failed, {}", cause),
+            }
+        }
+    }
+
+    fn spawn_batch(self: &Arc<Self>, name: String) -> Result<()> {
+        if !self.running.lock().insert(name.clone()) {
+            return Ok(());
+        }
+        let runner = self.clone();
+        GlobalIORuntime::instance().try_spawn(async move {
+            if let Err(cause) = run_pipe_batch(&runner.tenant, &name, &runner.client).await {
+                warn!("pipe runner: pipe {} failed, {}", name, cause);
+            }
+            runner.running.lock().remove(&name);
+        })?;
+        Ok(())
+    }
+}
+
+/// Load the next messages or files of a pipe. Returns the number of messages or
+/// files loaded.
+#[async_backtrace::framed]
+pub async fn run_pipe_batch(tenant: &str, name: &str, client: &reqwest::Client) -> Result<u64> {
+    let seq_pipe = UserApiProvider::instance().get_pipe(tenant, name).await?;
+    match &seq_pipe.data.source {
+        PipeSource::Kafka { .. } => {
+            load_kafka_batch(tenant, seq_pipe.data, seq_pipe.seq, client).await
+        }
+        PipeSource::Stage { .. } => {
+            copy_stage_files(tenant, seq_pipe.data, seq_pipe.seq, client).await
+        }
+        // the row events are merged as they are posted.
+        PipeSource::MySql { .. } => Ok(0),
+    }
+}
+
+/// Load a batch of messages of a pipe from Kafka, the pending batch if there is one,
+/// or else the next messages of the topic.
+#[async_backtrace::framed]
+async fn load_kafka_batch(
+    tenant: &str,
+    mut pipe: PipeInfo,
+    mut seq: u64,
+    client: &reqwest::Client,
+) -> Result<u64> {
+    let user_api = UserApiProvider::instance();
+    let started_at = Utc::now();
+    let consumer = match &pipe.source {
+        PipeSource::Kafka {
+            rest_proxy_url,
+            topic,
+            authorization_header,
+        } => {
+            let group = format!("databend_pipe_{}_{}", tenant, pipe.name);
+            KafkaRestConsumer::create(
+                client.clone(),
+                rest_proxy_url,
+                topic,
+                authorization_header,
+                &group,
+            )
+            .await?
+        }
+        PipeSource::Stage { .. } | PipeSource::MySql { .. } => {
+            return Err(ErrorCode::Internal("pipe doesn't consume Kafka"));
+        }
+    };
+    let fetched = match pipe.pending_batch.clone() {
+        Some(batch) => consumer
+            .fetch_range(&batch.ranges)
+            .await
+            .map(|messages| Some((batch, messages))),
+        None => consumer
+            .fetch_from(&pipe.offsets, MAX_BATCH_MESSAGES)
+            .await
+            .map(|messages| new_batch(&pipe, messages)),
+    };
+    if let Err(cause) = consumer.close().await {
+        warn!("pipe {}: close consumer failed, {}", pipe.name, cause);
+    }
+    let (batch, messages) = match fetched {
+        Ok(Some(fetched)) => fetched,
+        Ok(None) => return Ok(0),
+        Err(cause) => {
+            let load = failed_load(&pipe, started_at, 0, vec![], &cause);
+            return Err(record_error(tenant, pipe, seq, load, cause).await);
+        }
+    };
+
+    // the batch is recorded before it's loaded, to be loaded again if the load fails.
+    if pipe.pending_batch.is_none() {
+        pipe.pending_batch = Some(batch.clone());
+        pipe.next_batch_id = batch.id + 1;
+        seq = user_api.update_pipe(tenant, pipe.clone(), seq).await?;
+    }
+
+    let loaded = messages.len() as u64;
+    if let Err(cause) = load_batch(tenant, &pipe, &batch, &messages).await {
+        let load = failed_load(&pipe, started_at, loaded, vec![], &cause);
+        return Err(record_error(tenant, pipe, seq, load, cause).await);
+    }
+
+    for (partition, (_, end)) in batch.ranges.iter() {
+        pipe.offsets.insert(*partition, *end);
+    }
+    pipe.pending_batch = None;
+    pipe.loaded_messages += loaded;
+    pipe.last_loaded_at = Some(Utc::now());
+    pipe.last_error.clear();
+    let load = succeeded_load(&pipe, started_at, loaded, vec![]);
+    user_api.update_pipe(tenant, pipe.clone(), seq).await?;
+    // the batch is never loaded again once the offsets advance past it, a label left
+    // by a failure here is removed when the pipe is dropped.
+    if let Err(cause) = user_api
+        .remove_pipe_batch_label(tenant, &pipe, &batch)
+        .await
+    {
+        warn!("pipe {}: remove batch label failed, {}", pipe.name, cause);
+    }
+    record_load(tenant, load).await;
+    Ok(loaded)
+}
+
+/// Copy the files of a pipe from a stage, the ones added by the events of the webhook
+/// or received from the SQS queue of the pipe.
+///
+/// The files are removed from the pipe after the copy, the files copied already are
+/// skipped by the copy, so a file is loaded once even if the files are copied again
+/// after a failure. A file that fails to load is skipped and logged as a failed load,
+/// like the files that don't exist anymore.
+#[async_backtrace::framed]
+async fn copy_stage_files(
+    tenant: &str,
+    mut pipe: PipeInfo,
+    seq: u64,
+    client: &reqwest::Client,
+) -> Result<u64> {
+    let user_api = UserApiProvider::instance();
+    let started_at = Utc::now();
+    let (stage_name, queue_url) = match &pipe.source {
+        PipeSource::Stage {
+            stage_name,
+            aws_sqs_queue_url,
+            ..
+        } => (stage_name.clone(), aws_sqs_queue_url.clone()),
+        PipeSource::Kafka { .. } | PipeSource::MySql { .. } => {
+            return Err(ErrorCode::Internal("pipe doesn't ingest from a stage"));
+        }
+    };
+
+    let stage = user_api.get_stage(tenant, &stage_name).await?;
+    if !queue_url.is_empty() {
+        if let Err(cause) = receive_sqs_events(client, tenant, &pipe, &stage, &queue_url).await {
+            let load = failed_load(&pipe, started_at, 0, vec![], &cause);
+            return Err(record_error(tenant, pipe, seq, load, cause).await);
+        }
+    }
+
+    let mut files = user_api.get_pipe_files(tenant, &pipe.name).await?;
+    files.truncate(MAX_COPY_FILES);
+    if files.is_empty() {
+        return Ok(0);
+    }
+
+    let (existing, missing) = match split_missing_files(&stage, &files).await {
+        Ok(split) => split,
+        Err(cause) => {
+            let load = failed_load(&pipe, started_at, 0, files, &cause);
+            return Err(record_error(tenant, pipe, seq, load, cause).await);
+        }
+    };
+    let mut failures = missing
+        .into_iter()
+        .map(|file| {
+            let cause = ErrorCode::BadArguments(format!("file {} not found in the stage", file));
+            (file, cause)
+        })
+        .collect::<Vec<_>>();
+    if !existing.is_empty() {
+        match copy_files(tenant, &pipe, &existing).await {
+            Ok(failed) => failures.extend(failed),
+            Err(cause) => {
+                let load = failed_load(&pipe, started_at, 0, existing, &cause);
+                return Err(record_error(tenant, pipe, seq, load, cause).await);
+            }
+        }
+    }
+    user_api
+        .remove_pipe_files(tenant, &pipe.name, &files)
+        .await?;
+
+    let copied = existing
+        .into_iter()
+        .filter(|file| !failures.iter().any(|(failed, _)| failed == file))
+        .collect::<Vec<_>>();
+    let mut loads = failures
+        .iter()
+        .map(|(file, cause)| failed_load(&pipe, started_at, 0, vec![file.clone()], cause))
+        .collect::<Vec<_>>();
+    pipe.loaded_files += copied.len() as u64;
+    pipe.last_error = match failures.last() {
+        Some((_, cause)) => cause.message(),
+        None => String::new(),
+    };
+    if !copied.is_empty() {
+        pipe.last_loaded_at = Some(Utc::now());
+        loads.push(succeeded_load(&pipe, started_at, 0, copied.clone()));
+    }
+    user_api.update_pipe(tenant, pipe, seq).await?;
+    for load in loads {
+        record_load(tenant, load).await;
+    }
+    Ok(copied.len() as u64)
+}
+
+/// Split the files into the ones that exist in the stage and the missing ones.
+#[async_backtrace::framed]
+async fn split_missing_files(
+    stage: &StageInfo,
+    files: &[String],
+) -> Result<(Vec<String>, Vec<String>)> {
+    let op = StageTable::get_op(stage)?;
+    let (mut existing, mut missing) = (vec![], vec![]);
+    for file in files {
+        match op.stat(file).await {
+            Ok(_) => existing.push(file.clone()),
+            Err(e) if e.kind() == opendal::ErrorKind::NotFound => missing.push(file.clone()),
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok((existing, missing))
+}
+
+/// The batch of the messages fetched, `None` if there are no messages.
+fn new_batch(
+    pipe: &PipeInfo,
+    mut messages: Vec<KafkaMessage>,
+) -> Option<(PipeBatch, Vec<KafkaMessage>)> {
+    messages.sort_by_key(|m| (m.partition, m.offset));
+    messages.dedup_by_key(|m| (m.partition, m.offset));
+    if messages.is_empty() {
+        return None;
+    }
+
+    let mut ranges = BTreeMap::new();
+    for message in messages.iter() {
+        let start = pipe.offsets.get(&message.partition).copied();
+        let range = ranges
+            .entry(message.partition)
+            .or_insert((start.unwrap_or(message.offset), message.offset));
+        range.1 = message.offset + 1;
+    }
+    let batch = PipeBatch {
+        id: pipe.next_batch_id,
+        ranges,
+    };
+    Some((batch, messages))
+}
+
+/// Keep the error of the load in the pipe, for `system.pipes`, and in the loads of
+/// `system.pipe_history` if it's not the same as the last error, not to flood the
+/// history while the source is down.
+#[async_backtrace::framed]
+pub(super) async fn record_error(
+    tenant: &str,
+    mut pipe: PipeInfo,
+    seq: u64,
+    load: PipeLoad,
+    cause: ErrorCode,
+) -> ErrorCode {
+    let repeated = pipe.last_error == cause.message();
+    pipe.last_error = cause.message();
+    if let Err(e) = UserApiProvider::instance()
+        .update_pipe(tenant, pipe, seq)
+        .await
+    {
+        warn!("pipe runner: record error failed, {}", e);
+    }
+    if !repeated {
+        record_load(tenant, load).await;
+    }
+    cause
+}
+
+#[async_backtrace::framed]
+pub(super) async fn record_load(tenant: &str, load: PipeLoad) {
+    if let Err(e) = UserApiProvider::instance()
+        .add_pipe_load(tenant, load)
+        .await
+    {
+        warn!("pipe runner: record load failed, {}", e);
+    }
+}
+
+pub(super) fn succeeded_load(
+    pipe: &PipeInfo,
+    started_at: DateTime<Utc>,
+    messages: u64,
+    files: Vec<String>,
+) -> PipeLoad {
+    PipeLoad {
+        pipe_name: pipe.name.clone(),
+        started_at,
+        finished_at: Utc::now(),
+        messages,
+        files,
+        succeeded: true,
+        error_code: 0,
+        error_message: String::new(),
+    }
+}
+
+pub(super) fn failed_load(
+    pipe: &PipeInfo,
+    started_at: DateTime<Utc>,
+    messages: u64,
+    files: Vec<String>,
+    cause: &ErrorCode,
+) -> PipeLoad {
+    PipeLoad {
+        error_code: cause.code(),
+        error_message: cause.message(),
+        succeeded: false,
+        ..succeeded_load(pipe, started_at, messages, files)
+    }
+}
+
+/// A session of the owner of the pipe, in the database of the table.
+#[async_backtrace::framed]
+pub(super) async fn owner_session(tenant: &str, pipe: &PipeInfo) -> Result<Arc<Session>> {
+    let user = UserApiProvider::instance()
+        .get_user(tenant, pipe.owner.clone())
+        .await?;
+    let session = SessionManager::instance()
+        .create_session(SessionType::Local)
+        .await?;
+    session.set_authed_user(user, None).await?;
+    session.set_current_database(pipe.database.clone());
+    Ok(session)
+}
+
+/// Load the messages as the owner of the pipe, the snapshot committed by the load
+/// carries the deduplicate label of the batch.
+#[async_backtrace::framed]
+async fn load_batch(
+    tenant: &str,
+    pipe: &PipeInfo,
+    batch: &PipeBatch,
+    messages: &[KafkaMessage],
+) -> Result<()> {
+    let session = owner_session(tenant, pipe).await?;
+    session.get_settings().set_setting(
+        "deduplicate_label".to_string(),
+        pipe.batch_label(tenant, batch),
+    )?;
+
+    let ctx: Arc<QueryContext> = session.create_query_context().await?;
+    let sql = format!(
+        "INSERT INTO `{}`.`{}` FILE_FORMAT = (type = NDJSON)",
+        pipe.database, pipe.table
+    );
+    let mut planner = Planner::new(ctx.clone());
+    let (mut plan, _) = planner.plan_sql(&sql).await?;
+    let schema = plan.schema();
+    let (tx, rx) = tokio::sync::mpsc::channel(2);
+    let read_batch_size = match &mut plan {
+        Plan::Insert(insert) => match &mut insert.source {
+            InsertInputSource::StreamingWithFileFormat(params, _, input_context_ref) => {
+                *params = pipe.file_format.clone();
+                let to_table = ctx
+                    .get_table(&insert.catalog, &insert.database, &insert.table)
+                    .await?;
+                let input_context = Arc::new(
+                    InputContext::try_create_from_insert_file_format(
+                        rx,
+                        ctx.get_settings(),
+                        pipe.file_format.clone(),
+                        infer_table_schema(&schema)?,
+                        ctx.get_scan_progress(),
+                        true,
+                        to_table.get_block_thresholds(),
+                    )
+                    .await?,
+                );
+                *input_context_ref = Some(input_context.clone());
+                input_context.read_batch_size
+            }
+            _ => return Err(ErrorCode::Internal("pipe load must stream the messages")),
+        },
+        _ => return Err(ErrorCode::Internal("pipe load must be an insert")),
+    };
+
+    let handler = ctx.try_spawn(execute_plan(ctx.clone(), plan))?;
+    'send: for (path, data) in message_files(pipe, batch, messages) {
+        for (i, chunk) in data.chunks(read_batch_size.max(1)).enumerate() {
+            let read_batch = StreamingReadBatch {
+                data: chunk.to_vec(),
+                path: path.clone(),
+                is_start: i == 0,
+                compression: None,
+            };
+            // the insert is done already if the batch was loaded before.
+            if tx.send(Ok(read_batch)).await.is_err() {
+                break 'send;
+            }
+        }
+    }
+    drop(tx);
+
+    match handler.await {
+        Ok(result) => result,
+        Err(cause) => Err(ErrorCode::PipeSourceFailure(format!(
+            "load of pipe {} panicked, {}",
+            pipe.name, cause
+        ))),
+    }
+}
+
+#[async_backtrace::framed]
+pub(super) async fn execute_plan(ctx: Arc<QueryContext>, plan: Plan) -> Result<()> {
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    interpreter
+        .execute(ctx)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    Ok(())
+}
+
+/// Copy the files as the owner of the pipe, the files that fail to load are skipped.
+/// Returns the files skipped and their first errors.
+#[async_backtrace::framed]
+async fn copy_files(
+    tenant: &str,
+    pipe: &PipeInfo,
+    files: &[String],
+) -> Result<Vec<(String, ErrorCode)>> {
+    let (stage_name, file_format_options) = match &pipe.source {
+        PipeSource::Stage {
+            stage_name,
+            file_format_options,
+            ..
+        } => (stage_name, file_format_options),
+        PipeSource::Kafka { .. } | PipeSource::MySql { .. } => {
+            return Err(ErrorCode::Internal("pipe doesn't ingest from a stage"));
+        }
+    };
+
+    let session = owner_session(tenant, pipe).await?;
+    let ctx: Arc<QueryContext> = session.create_query_context().await?;
+    let quote = |s: &str| format!("'{}'", s.replace('\\', "\\\\").replace('\'', "\\'"));
+    let mut sql = format!(
+        "COPY INTO `{}`.`{}` FROM @{} FILES = ({})",
+        pipe.database,
+        pipe.table,
+        stage_name,
+        files
+            .iter()
+            .map(|f| quote(f))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    if !file_format_options.is_empty() {
+        let options = file_format_options
+            .iter()
+            .map(|(k, v)| format!("{} = {}", k, quote(v)))
+            .collect::<Vec<_>>();
+        sql.push_str(&format!(" FILE_FORMAT = ({})", options.join(" ")));
+    }
+    sql.push_str(" ON_ERROR = skip_file");
+
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(&sql).await?;
+    execute_plan(ctx.clone(), plan).await?;
+
+    let mut failed = vec![];
+    if let Some(on_error_map) = ctx.get_on_error_map() {
+        for entry in on_error_map.iter() {
+            if let Some(error) = entry.value().values().next() {
+                failed.push((entry.key().clone(), error.err.clone()));
+            }
+        }
+    }
+    Ok(failed)
+}
+
+/// The messages as the files of the load: the messages of NDJSON are the lines of one
+/// file, and every message of AVRO is an object container file of its own.
+fn message_files(
+    pipe: &PipeInfo,
+    batch: &PipeBatch,
+    messages: &[KafkaMessage],
+) -> Vec<(String, Vec<u8>)> {
+    let endpoint = pipe.source.endpoint();
+    match &pipe.file_format {
+        FileFormatParams::Avro(_) => messages
+            .iter()
+            .map(|m| {
+                let path = format!("{}/{}/{}", endpoint, m.partition, m.offset);
+                (path, m.value.clone())
+            })
+            .collect(),
+        _ => {
+            let mut data = vec![];
+            for message in messages {
+                data.extend_from_slice(&message.value);
+                if !message.value.ends_with(b"\n") {
+                    data.push(b'\n');
+                }
+            }
+            vec![(format!("{}/batch_{}", endpoint, batch.id), data)]
+        }
+    }
+}
@@ -71,7 +71,6 @@ impl OneBlockProcedure for SearchTablesProcedure {
                 *bind_context,
                 *s_expr,
                 metadata,
-                None,
                 false,
             )?;
             interpreter.execute(ctx.clone()).await
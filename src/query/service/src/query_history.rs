@@ -0,0 +1,267 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use chrono::NaiveDateTime;
+use common_base::base::tokio::time::sleep;
+use common_base::base::GlobalInstance;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_config::InnerConfig;
+use common_exception::Result;
+use common_io::escape_string;
+use common_meta_app::principal::UserInfo;
+use common_storages_system::QueryLogElement;
+use futures_util::TryStreamExt;
+use parking_lot::Mutex;
+use tracing::info;
+use tracing::warn;
+
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+use crate::sql::Planner;
+
+pub const QUERY_HISTORY_DATABASE: &str = "system_history";
+pub const QUERY_HISTORY_TABLE: &str = "query_history";
+
+/// How often the history older than the retention is deleted.
+const RETENTION_CHECK_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// Persists the finished queries to the fuse table `system_history.query_history`,
+/// so that they survive restarts, unlike the in-memory `system.query_log`.
+///
+/// The queries are buffered in memory and inserted in one batch every flush interval,
+/// the rows older than the retention are deleted in the background as well.
+pub struct QueryHistoryService {
+    flush_interval: Duration,
+    retention_days: u64,
+    max_buffer_rows: usize,
+    buffer: Mutex<VecDeque<QueryLogElement>>,
+}
+
+impl QueryHistoryService {
+    pub fn init(config: &InnerConfig) -> Result<()> {
+        // a read-only replica never commits to the tables, its history is not persisted.
+        let flush_interval = match config.query.read_only_replica {
+            true => Duration::ZERO,
+            false => Duration::from_secs(config.query.query_history_flush_interval_secs),
+        };
+        let service = Arc::new(QueryHistoryService {
+            flush_interval,
+            retention_days: config.query.query_history_retention_days,
+            max_buffer_rows: std::cmp::max(config.query.max_query_log_size, 1),
+            buffer: Mutex::new(VecDeque::new()),
+        });
+        GlobalInstance::set(service.clone());
+
+        if service.enabled() {
+            GlobalIORuntime::instance().try_spawn(async move { service.run().await })?;
+        }
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<QueryHistoryService> {
+        GlobalInstance::get()
+    }
+
+    fn enabled(&self) -> bool {
+        !self.flush_interval.is_zero()
+    }
+
+    /// Buffer a finished query, if the `query_history_level` of its session persists it.
+    pub fn append(&self, ctx: &QueryContext, event: &QueryLogElement) -> Result<()> {
+        if !self.enabled() {
+            return Ok(());
+        }
+
+        let persist = match ctx.get_settings().get_query_history_level()?.as_str() {
+            "all" => true,
+            "error" => event.exception_code != 0,
+            _ => false,
+        };
+        if persist {
+            let mut buffer = self.buffer.lock();
+            // the storage is not available for a long time, keep the latest ones.
+            if buffer.len() >= self.max_buffer_rows {
+                buffer.pop_front();
+            }
+            buffer.push_back(event.clone());
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn run(self: Arc<Self>) {
+        let mut last_cleanup: Option<Instant> = None;
+        loop {
+            sleep(self.flush_interval).await;
+
+            if let Err(cause) = self.flush().await {
+                warn!("query history: flush failed, {}", cause);
+            }
+
+            let cleanup_due =
+                last_cleanup.map_or(true, |t| t.elapsed() >= RETENTION_CHECK_INTERVAL);
+            if self.retention_days > 0 && cleanup_due {
+                last_cleanup = Some(Instant::now());
+                if let Err(cause) = self.cleanup().await {
+                    warn!("query history: cleanup failed, {}", cause);
+                }
+            }
+        }
+    }
+
+    /// Insert the buffered queries into the history table, the table is created if not exists.
+    #[async_backtrace::framed]
+    pub async fn flush(&self) -> Result<()> {
+        let events: Vec<QueryLogElement> = self.buffer.lock().drain(..).collect();
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        let session = Self::create_session().await?;
+        execute_sql(
+            &session,
+            &format!("CREATE DATABASE IF NOT EXISTS {}", QUERY_HISTORY_DATABASE),
+        )
+        .await?;
+        execute_sql(
+            &session,
+            &format!(
+                "CREATE TABLE IF NOT EXISTS {}.{} (\
+                    query_id VARCHAR, \
+                    query_kind VARCHAR, \
+                    sql_user VARCHAR, \
+                    current_database VARCHAR, \
+                    query_text VARCHAR, \
+                    query_start_time TIMESTAMP, \
+                    query_duration_ms BIGINT, \
+                    scan_rows BIGINT UNSIGNED, \
+                    scan_bytes BIGINT UNSIGNED, \
+                    result_rows BIGINT UNSIGNED, \
+                    result_bytes BIGINT UNSIGNED, \
+                    exception_code INT, \
+                    exception_text VARCHAR\
+                )",
+                QUERY_HISTORY_DATABASE, QUERY_HISTORY_TABLE
+            ),
+        )
+        .await?;
+
+        let values = events.iter().map(history_values).collect::<Vec<_>>();
+        execute_sql(
+            &session,
+            &format!(
+                "INSERT INTO {}.{} VALUES {}",
+                QUERY_HISTORY_DATABASE,
+                QUERY_HISTORY_TABLE,
+                values.join(", ")
+            ),
+        )
+        .await?;
+
+        info!("query history: flushed {} queries", events.len());
+        Ok(())
+    }
+
+    /// Delete the history older than the retention.
+    #[async_backtrace::framed]
+    async fn cleanup(&self) -> Result<()> {
+        let session = Self::create_session().await?;
+        let ctx = session.create_query_context().await?;
+        let catalog = ctx.get_catalog("default")?;
+        if catalog
+            .get_table(
+                &ctx.get_tenant(),
+                QUERY_HISTORY_DATABASE,
+                QUERY_HISTORY_TABLE,
+            )
+            .await
+            .is_err()
+        {
+            // nothing flushed yet.
+            return Ok(());
+        }
+
+        execute_sql(
+            &session,
+            &format!(
+                "DELETE FROM {}.{} WHERE query_start_time < date_sub(DAY, {}, now())",
+                QUERY_HISTORY_DATABASE, QUERY_HISTORY_TABLE, self.retention_days
+            ),
+        )
+        .await
+    }
+
+    async fn create_session() -> Result<Arc<Session>> {
+        let session = SessionManager::instance()
+            .create_session(SessionType::Local)
+            .await?;
+        session
+            .set_authed_user(UserInfo::new_no_auth("root", "%"), None)
+            .await?;
+        // the statements of the service itself are not part of the history.
+        session
+            .get_settings()
+            .set_setting("query_history_level".to_string(), "none".to_string())?;
+        Ok(session)
+    }
+}
+
+#[async_backtrace::framed]
+async fn execute_sql(session: &Arc<Session>, sql: &str) -> Result<()> {
+    let ctx: Arc<QueryContext> = session.create_query_context().await?;
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    interpreter
+        .execute(ctx)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    Ok(())
+}
+
+fn history_values(event: &QueryLogElement) -> String {
+    let start_time = NaiveDateTime::from_timestamp_opt(
+        event.query_start_time.div_euclid(1_000_000),
+        (event.query_start_time.rem_euclid(1_000_000) * 1000) as u32,
+    )
+    .map(|t| t.format("%Y-%m-%d %H:%M:%S%.6f").to_string())
+    .unwrap_or_default();
+    format!(
+        "('{}', '{}', '{}', '{}', '{}', '{}', {}, {}, {}, {}, {}, {}, '{}')",
+        escape_string(&event.query_id),
+        escape_string(&event.query_kind),
+        escape_string(&event.sql_user),
+        escape_string(&event.current_database),
+        escape_string(&event.query_text),
+        start_time,
+        event.query_duration_ms,
+        event.scan_rows,
+        event.scan_bytes,
+        event.result_rows,
+        event.result_bytes,
+        event.exception_code,
+        escape_string(&event.exception_text),
+    )
+}
@@ -0,0 +1,164 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use common_base::base::tokio::time::sleep;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_catalog::table::Table;
+use common_config::InnerConfig;
+use common_exception::Result;
+use common_storage::init_stage_operator;
+use common_storages_fuse::operations::ReplicationResult;
+use common_storages_fuse::FuseTable;
+use common_storages_fuse::FUSE_OPT_KEY_REPLICATION_STAGE;
+use common_storages_system::ReplicationHistoryLogElement;
+use common_storages_system::ReplicationHistoryQueue;
+use common_users::UserApiProvider;
+use tracing::warn;
+
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+
+/// Replicates the fuse tables with the `replication_stage` option to their stages
+/// periodically, each round of a table is logged to `system.replication_history`.
+///
+/// The replicas keep the layout of the tables, they can be attached read-only by
+/// `ATTACH TABLE` in the deployments of the secondary storage.
+pub struct ReplicationService {
+    tenant: String,
+    interval: Duration,
+}
+
+impl ReplicationService {
+    pub fn init(config: &InnerConfig) -> Result<()> {
+        if config.query.replication_interval_secs == 0 {
+            return Ok(());
+        }
+
+        let service = ReplicationService {
+            tenant: config.query.tenant_id.clone(),
+            interval: Duration::from_secs(config.query.replication_interval_secs),
+        };
+        GlobalIORuntime::instance().try_spawn(async move { service.run().await })?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn run(self) {
+        loop {
+            sleep(self.interval).await;
+
+            if let Err(cause) = self.replicate_round().await {
+                warn!("replication: round failed, {}", cause);
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn replicate_round(&self) -> Result<()> {
+        let session = SessionManager::instance()
+            .create_session(SessionType::Dummy)
+            .await?;
+
+        let ctx = session.create_query_context().await?;
+        let catalog = ctx.get_catalog("default")?;
+        for database in catalog.list_databases(&self.tenant).await? {
+            for table in database.list_tables().await? {
+                let stage = match table.options().get(FUSE_OPT_KEY_REPLICATION_STAGE) {
+                    Some(stage) if !stage.is_empty() => stage.clone(),
+                    _ => continue,
+                };
+
+                let ctx = session.create_query_context().await?;
+                let replicated = self.replicate_table(ctx, table.clone(), &stage).await;
+                let mut element = ReplicationHistoryLogElement {
+                    event_time: now_micros(),
+                    database: database.name().to_string(),
+                    table: table.name().to_string(),
+                    stage,
+                    snapshot_id: None,
+                    snapshot_time: None,
+                    lag_seconds: None,
+                    copied_files: 0,
+                    copied_bytes: 0,
+                    status: String::new(),
+                    error_message: String::new(),
+                };
+                match replicated {
+                    // nothing committed yet.
+                    Ok(None) => continue,
+                    Ok(Some(result)) => {
+                        element.snapshot_id = Some(result.snapshot_id.as_simple().to_string());
+                        element.snapshot_time =
+                            result.snapshot_timestamp.map(|t| t.timestamp_micros());
+                        element.lag_seconds = Some(match element.snapshot_time {
+                            Some(t) if result.copied_files > 0 => {
+                                (element.event_time - t).max(0) as u64 / 1_000_000
+                            }
+                            _ => 0,
+                        });
+                        element.copied_files = result.copied_files;
+                        element.copied_bytes = result.copied_bytes;
+                        element.status = if result.copied_files > 0 {
+                            "replicated".to_string()
+                        } else {
+                            "up_to_date".to_string()
+                        };
+                    }
+                    Err(cause) => {
+                        warn!(
+                            "replication: failed to replicate table {}.{}, {}",
+                            database.name(),
+                            table.name(),
+                            cause
+                        );
+                        element.status = "failed".to_string();
+                        element.error_message = cause.to_string();
+                    }
+                }
+                ReplicationHistoryQueue::instance()?.append_data(element)?;
+            }
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn replicate_table(
+        &self,
+        ctx: Arc<QueryContext>,
+        table: Arc<dyn Table>,
+        stage: &str,
+    ) -> Result<Option<ReplicationResult>> {
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let stage_info = UserApiProvider::instance()
+            .get_stage(&self.tenant, stage)
+            .await?;
+        let target = init_stage_operator(&stage_info)?;
+        fuse_table.do_replicate(ctx, &target).await
+    }
+}
+
+fn now_micros() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_micros() as i64
+}
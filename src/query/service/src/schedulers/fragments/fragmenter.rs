@@ -146,6 +146,7 @@ impl PhysicalPlanReplacer for Fragmenter {
             probe: Box::new(probe_input),
             build_keys: plan.build_keys.clone(),
             probe_keys: plan.probe_keys.clone(),
+            probe_keys_rt: plan.probe_keys_rt.clone(),
             non_equi_conditions: plan.non_equi_conditions.clone(),
             join_type: plan.join_type.clone(),
             marker_index: plan.marker_index,
@@ -17,6 +17,7 @@ use std::sync::Arc;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_profile::SharedProcessorProfiles;
+use tracing::info;
 
 use crate::pipelines::PipelineBuildResult;
 use crate::pipelines::PipelineBuilder;
@@ -75,10 +76,56 @@ pub async fn build_local_pipeline(
     let mut build_res = pipeline.finalize(plan)?;
 
     let settings = ctx.get_settings();
-    build_res.set_max_threads(settings.get_max_threads()? as usize);
+    let max_threads = settings.get_max_threads()? as usize;
+    if settings.get_enable_adaptive_parallelism()? {
+        build_res.max_threads_limit = adaptive_max_threads(plan, max_threads);
+        if let Some(limit) = build_res.max_threads_limit {
+            info!("Adaptive parallelism limits max threads to {}", limit);
+        }
+    }
+    build_res.set_max_threads(max_threads);
     Ok(build_res)
 }
 
+/// The bytes to read of each thread when choosing the parallelism adaptively.
+const ADAPTIVE_PARALLELISM_BYTES_PER_THREAD: usize = 16 * 1024 * 1024;
+
+/// Choose a smaller number of threads for the local queries that read little data, so
+/// that they don't pay the overhead of scheduling `max_threads` workers.
+///
+/// This is a heuristic on the input size only: the cost of the operators above the scans
+/// isn't estimated, so a query joining or sorting little data heavily is limited as well,
+/// which is why it's disabled by default. The limit is chosen once, when the pipeline is
+/// built, and the fragments of a distributed query are never limited.
+///
+/// The bytes to read are estimated by the partitions left after pruning, they are not exact
+/// if the filters are pushed down to the scans, or if the partitions are pruned lazily.
+/// Returns `None` if the bytes to read of any table scan are unknown.
+fn adaptive_max_threads(plan: &PhysicalPlan, max_threads: usize) -> Option<usize> {
+    let mut read_bytes = 0;
+    let mut plans = vec![plan];
+    let mut has_table_scan = false;
+    while let Some(plan) = plans.pop() {
+        if let PhysicalPlan::TableScan(scan) = plan {
+            let statistics = &scan.source.statistics;
+            if statistics.read_bytes == 0 {
+                return None;
+            }
+            has_table_scan = true;
+            read_bytes += statistics.read_bytes;
+        }
+        plans.extend(plan.children());
+    }
+
+    if !has_table_scan {
+        return None;
+    }
+
+    let threads = (read_bytes + ADAPTIVE_PARALLELISM_BYTES_PER_THREAD - 1)
+        / ADAPTIVE_PARALLELISM_BYTES_PER_THREAD;
+    Some(threads.clamp(1, max_threads.max(1)))
+}
+
 /// Build distributed pipeline via fragment and actions.
 #[async_backtrace::framed]
 pub async fn build_distributed_pipeline(
@@ -98,6 +145,8 @@ pub async fn build_distributed_pipeline(
         .commit_actions(ctx.clone(), enable_profiling, fragments_actions)
         .await?;
 
+    // the fragments run on other nodes, with their own inputs, so they are not limited
+    // by `enable_adaptive_parallelism`.
     let settings = ctx.get_settings();
     build_res.set_max_threads(settings.get_max_threads()? as usize);
     Ok(build_res)
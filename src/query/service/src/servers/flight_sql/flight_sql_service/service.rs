@@ -53,6 +53,7 @@ use arrow_flight::PutResult;
 use arrow_flight::SchemaAsIpc;
 use arrow_flight::Ticket;
 use arrow_ipc::writer::IpcWriteOptions;
+use arrow_schema::Schema as ArrowSchema;
 use common_base::base::uuid::Uuid;
 use common_exception::Result;
 use common_expression::DataSchema;
@@ -109,6 +110,31 @@ fn simple_flight_info<T: ProstMessageExt>(message: T) -> Response<FlightInfo> {
     Response::new(info)
 }
 
+// The endpoint has no location, the client fetches the ticket from the server it is connected to.
+fn schema_flight_info(schema: &ArrowSchema, ticket: Ticket) -> Result<FlightInfo, Status> {
+    let message = SchemaAsIpc::new(schema, &IpcWriteOptions::default())
+        .try_into()
+        .map_err(|e| status!("Unable to serialize schema", e))?;
+    let IpcMessage(schema_bytes) = message;
+
+    let endpoint = FlightEndpoint {
+        ticket: Some(ticket),
+        location: vec![],
+    };
+    let flight_desc = FlightDescriptor {
+        r#type: DescriptorType::Cmd.into(),
+        cmd: Default::default(),
+        path: vec![],
+    };
+    Ok(FlightInfo {
+        schema: schema_bytes,
+        flight_descriptor: Some(flight_desc),
+        endpoint: vec![endpoint],
+        total_records: -1,
+        total_bytes: -1,
+    })
+}
+
 impl NamedService for FlightSqlServiceImpl {
     const NAME: &'static str = "FlightSqlService";
 }
@@ -188,15 +214,41 @@ impl FlightSqlService for FlightSqlServiceImpl {
         Ok(resp)
     }
 
+    // called by ADBC and other clients that execute statements without preparing them.
+    // The query is only planned here, it's executed when the ticket is fetched by do_get.
     #[async_backtrace::framed]
     async fn get_flight_info_statement(
         &self,
         query: CommandStatementQuery,
         request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        tracing::info!("get_flight_info_sql_info(query={})", query.query);
-        let _session = self.get_session(&request)?;
-        Ok(simple_flight_info(query))
+        let session = self.get_session(&request)?;
+        let handle = Uuid::new_v4();
+        tracing::info!(
+            "get_flight_info_statement with handle={handle} query={:?}",
+            query.query
+        );
+
+        let plan = self
+            .plan_sql(&session, &query.query)
+            .await
+            .map_err(|e| status!("Error getting result schema", e))?;
+        let data_schema = if plan.0.has_result_set() {
+            plan.0.schema()
+        } else {
+            Arc::new(DataSchema::empty())
+        };
+        let schema: ArrowSchema = (&*data_schema).into();
+        self.statements.insert(handle, plan);
+
+        let ticket = TicketStatementQuery {
+            statement_handle: handle.as_bytes().to_vec().into(),
+        };
+        let ticket = Ticket {
+            ticket: ticket.as_any().encode_to_vec().into(),
+        };
+        let info = schema_flight_info(&schema, ticket)?;
+        Ok(Response::new(info))
     }
 
     #[async_backtrace::framed]
@@ -356,22 +408,48 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_statement(
         &self,
         ticket: TicketStatementQuery,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        tracing::info!("do_get_statement({ticket:?}");
-        Err(Status::unimplemented("do_get_statement not implemented"))
+        let session = self.get_session(&request)?;
+        let handle = Uuid::from_slice(ticket.statement_handle.as_ref())
+            .map_err(|e| Status::internal(format!("Error decoding handle: {e}")))?;
+
+        tracing::info!("do_get_statement with handle={handle}");
+
+        // the ticket of a statement can only be fetched once
+        let (_, (plan, plan_extras)) = self
+            .statements
+            .remove(&handle)
+            .ok_or_else(|| Status::not_found(format!("statement {handle} not found")))?;
+        let stream = self
+            .execute_query(session, &plan, &plan_extras)
+            .await
+            .map_err(|e| status!("fail to execute", e))?;
+        Ok(Response::new(stream))
     }
 
     #[async_backtrace::framed]
     async fn do_get_prepared_statement(
         &self,
         query: CommandPreparedStatementQuery,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        tracing::info!("do_get_prepared_statement({query:?}");
-        Err(Status::unimplemented(
-            "do_get_prepared_statement not implemented",
-        ))
+        let session = self.get_session(&request)?;
+        let handle = Uuid::from_slice(query.prepared_statement_handle.as_ref())
+            .map_err(|e| Status::internal(format!("Error decoding handle: {e}")))?;
+
+        tracing::info!("do_get_prepared_statement with handle={handle}");
+
+        let (plan, plan_extras) = self
+            .statements
+            .get(&handle)
+            .map(|handle_plan| handle_plan.value().clone())
+            .ok_or_else(|| Status::not_found(format!("prepared statement {handle} not found")))?;
+        let stream = self
+            .execute_query(session, &plan, &plan_extras)
+            .await
+            .map_err(|e| status!("fail to execute", e))?;
+        Ok(Response::new(stream))
     }
 
     #[async_backtrace::framed]
@@ -89,17 +89,16 @@ impl FlightSqlServiceImpl {
         password: String,
         remote_addr: Option<SocketAddr>,
     ) -> Result<Arc<Session>, Status> {
-        let client_ip = remote_addr
-            .map(|a| a.ip().to_string())
-            .unwrap_or("%".to_string());
+        let client_ip = remote_addr.map(|a| a.ip().to_string());
         let session = SessionManager::instance()
             .create_session(SessionType::FlightSQL)
             .await
             .map_err(|e| status!("Could not create session", e))?;
         let tenant = session.get_current_tenant();
 
-        let user = UserApiProvider::instance()
-            .get_user_with_client_ip(&tenant, &user, &client_ip)
+        let user_api = UserApiProvider::instance();
+        let user = user_api
+            .get_user_with_client_ip(&tenant, &user, client_ip.as_deref().unwrap_or("%"))
             .await
             .map_err(|e| status!("get_user_with_client_ip fail {}", e))?;
         let password = password.as_bytes().to_vec();
@@ -122,6 +121,10 @@ impl FlightSqlServiceImpl {
             },
             _ => return Err(Status::unauthenticated("wrong auth type")),
         };
+        user_api
+            .enforce_network_policy(&tenant, &user, client_ip.as_deref())
+            .await
+            .map_err(|e| Status::unauthenticated(e.message()))?;
         session
             .set_authed_user(user, None)
             .await
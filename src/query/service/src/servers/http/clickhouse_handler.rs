@@ -25,6 +25,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::ToErrorCode;
 use common_expression::infer_table_schema;
+use common_formats::ClickhouseBinaryFormat;
 use common_formats::ClickhouseFormatType;
 use common_formats::FileFormatOptionsExt;
 use common_formats::FileFormatTypeExt;
@@ -36,6 +37,7 @@ use common_sql::Planner;
 use futures::StreamExt;
 use http::HeaderMap;
 use naive_cityhash::cityhash128;
+use once_cell::sync::Lazy;
 use poem::error::BadRequest;
 use poem::error::InternalServerError;
 use poem::error::Result as PoemResult;
@@ -48,6 +50,7 @@ use poem::Endpoint;
 use poem::EndpointExt;
 use poem::IntoResponse;
 use poem::Route;
+use regex::Regex;
 use serde::Deserialize;
 use serde::Serialize;
 use tracing::info;
@@ -282,11 +285,18 @@ pub async fn clickhouse_handler_post(
         .map_err(BadRequest)?;
 
     let default_format = get_default_format(&params, headers).map_err(BadRequest)?;
-    let mut sql = params.query();
-    if !sql.is_empty() {
-        sql.push(' ');
-    }
-    sql.push_str(body.into_string().await?.as_str());
+    let body = body.into_vec().await?;
+    let (sql, mut binary_data) = match split_binary_insert(&params.query(), &body) {
+        Some((sql, start)) => (sql, Some(body[start..].to_vec())),
+        None => {
+            let mut sql = params.query();
+            if !sql.is_empty() {
+                sql.push(' ');
+            }
+            sql.push_str(std::str::from_utf8(&body).map_err(BadRequest)?);
+            (sql, None)
+        }
+    };
     let n = 64;
     // other parts of the request already logged in middleware
     let len = sql.len();
@@ -295,7 +305,14 @@ pub async fn clickhouse_handler_post(
     } else {
         sql.to_string()
     };
-    info!("receive clickhouse http post, (query + body) = {}", &msg);
+    match &binary_data {
+        Some(data) => info!(
+            "receive clickhouse http post, query = {}, {} bytes of binary data",
+            &msg,
+            data.len()
+        ),
+        None => info!("receive clickhouse http post, (query + body) = {}", &msg),
+    }
 
     let mut planner = Planner::new(ctx.clone());
     let (mut plan, extras) = planner
@@ -340,17 +357,12 @@ pub async fn clickhouse_handler_post(
                 .get_compression_alg("")
                 .map_err(|err| err.display_with_sql(&sql))
                 .map_err(BadRequest)?;
-            let start = *start;
-            let sql_cloned = sql.clone();
+            let data = match binary_data.take() {
+                Some(data) => data,
+                None => sql.trim_start().as_bytes()[*start..].to_vec(),
+            };
             handle = Some(ctx.spawn(async move {
-                gen_batches(
-                    sql_cloned,
-                    start,
-                    input_context.read_batch_size,
-                    tx,
-                    compression_alg,
-                )
-                .await
+                gen_batches(data, input_context.read_batch_size, tx, compression_alg).await
             }));
         } else if let InsertInputSource::StreamingWithFileFormat(
             option_settings,
@@ -389,17 +401,9 @@ pub async fn clickhouse_handler_post(
                 .get_compression_alg("")
                 .map_err(|err| err.display_with_sql(&sql))
                 .map_err(BadRequest)?;
-            let start = *start;
-            let sql_cloned = sql.clone();
+            let data = sql.trim_start().as_bytes()[*start..].to_vec();
             handle = Some(ctx.spawn(async move {
-                gen_batches(
-                    sql_cloned,
-                    start,
-                    input_context.read_batch_size,
-                    tx,
-                    compression_alg,
-                )
-                .await
+                gen_batches(data, input_context.read_batch_size, tx, compression_alg).await
             }));
         }
     };
@@ -465,6 +469,43 @@ fn compress_block(input: Vec<u8>) -> Result<Vec<u8>> {
     }
 }
 
+/// Split an insert in a binary format of ClickHouse into the statement and the start of
+/// its data in the body, the data is not text and can't be appended to the query.
+///
+/// The statement is the query of the url if any, otherwise it's followed by the data in
+/// the body. Like ClickHouse, the data starts after the spaces and at most one line
+/// break after the name of the format.
+fn split_binary_insert(query: &str, body: &[u8]) -> Option<(String, usize)> {
+    if !query.trim().is_empty() {
+        return binary_insert_end(query).map(|_| (query.to_string(), 0));
+    }
+
+    let text = match std::str::from_utf8(body) {
+        Ok(text) => text,
+        Err(e) => std::str::from_utf8(&body[..e.valid_up_to()]).ok()?,
+    };
+    let end = binary_insert_end(text)?;
+    let mut start = end;
+    while start < body.len() && matches!(body[start], b' ' | b'\t' | b'\x0c') {
+        start += 1;
+    }
+    if body.get(start) == Some(&b'\r') {
+        start += 1;
+    }
+    if body.get(start) == Some(&b'\n') {
+        start += 1;
+    }
+    Some((text[..end].to_string(), start))
+}
+
+/// The end of the name of the format, if the statement is an insert in a binary format.
+fn binary_insert_end(sql: &str) -> Option<usize> {
+    static INSERT_FORMAT: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"(?is)^\s*INSERT\s.*?\sFORMAT\s+(\w+)").unwrap());
+    let format = INSERT_FORMAT.captures(sql)?.get(1)?;
+    ClickhouseBinaryFormat::parse(format.as_str()).map(|_| format.end())
+}
+
 fn get_default_format(
     params: &StatementHandlerParams,
     headers: &HeaderMap,
@@ -493,13 +534,11 @@ fn get_format_with_default(
 }
 
 async fn gen_batches(
-    data: String,
-    start: usize,
+    buf: Vec<u8>,
     batch_size: usize,
     tx: Sender<Result<StreamingReadBatch>>,
     compression: Option<CompressAlgorithm>,
 ) {
-    let buf = &data.trim_start().as_bytes()[start..];
     let buf_size = buf.len();
     let mut is_start = true;
     let mut start = 0;
@@ -28,12 +28,16 @@ use poem::listener::RustlsConfig;
 use poem::middleware::CatchPanic;
 use poem::middleware::NormalizePath;
 use poem::middleware::TrailingSlash;
+use poem::post;
 use poem::put;
 use poem::Endpoint;
 use poem::EndpointExt;
 use poem::Route;
 use tracing::info;
 
+use super::v1::pipe_binlog_events;
+use super::v1::pipe_binlog_position;
+use super::v1::pipe_events;
 use super::v1::upload_to_stage;
 use crate::auth::AuthMgr;
 use crate::servers::http::middleware::HTTPSessionMiddleware;
@@ -94,7 +98,12 @@ impl HttpHandler {
         let ep_v1 = Route::new()
             .nest("/query", query_route())
             .at("/streaming_load", put(streaming_load))
-            .at("/upload_to_stage", put(upload_to_stage));
+            .at("/upload_to_stage", put(upload_to_stage))
+            .at("/pipes/:name/events", post(pipe_events))
+            .at(
+                "/pipes/:name/binlog",
+                get(pipe_binlog_position).post(pipe_binlog_events),
+            );
         let ep_v1 = self.wrap_auth(ep_v1);
 
         let ep_clickhouse = Route::new().nest("/", clickhouse_router());
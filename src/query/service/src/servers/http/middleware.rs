@@ -100,6 +100,7 @@ fn auth_by_header(
         match Bearer::decode(value) {
             Some(bearer) => Ok(Credential::Jwt {
                 token: bearer.token().to_string(),
+                client_ip,
             }),
             None => Err(ErrorCode::AuthenticateFailure("bad Bearer auth header")),
         }
@@ -171,7 +172,16 @@ impl<E> HTTPSessionEndpoint<E> {
             .auth(ctx.get_current_session(), &credential)
             .await?;
 
-        Ok(HttpQueryContext::new(session))
+        let header = |name: &str| {
+            req.headers()
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(|v| v.to_string())
+        };
+        let mut http_ctx = HttpQueryContext::new(session);
+        http_ctx.trace_parent = header("traceparent");
+        http_ctx.trace_state = header("tracestate");
+        Ok(http_ctx)
     }
 }
 #[poem::async_trait]
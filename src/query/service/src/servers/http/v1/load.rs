@@ -36,7 +36,10 @@ use poem::error::Result as PoemResult;
 use poem::http::StatusCode;
 use poem::web::Json;
 use poem::web::Multipart;
+use poem::Body;
+use poem::FromRequest;
 use poem::Request;
+use poem::RequestBody;
 use serde::Deserialize;
 use serde::Serialize;
 use tokio::sync::mpsc::Sender;
@@ -82,12 +85,38 @@ fn remove_quote(s: &[u8]) -> &[u8] {
     r
 }
 
+const DEFAULT_FILE_NAME: &str = "file_with_no_name";
+
+/// The data to load, uploaded as multipart files, or sent as the raw (usually chunked) request body.
+pub enum LoadBody {
+    Multipart(Multipart),
+    Raw(Body),
+}
+
+#[async_trait::async_trait]
+impl<'a> FromRequest<'a> for LoadBody {
+    #[async_backtrace::framed]
+    async fn from_request(req: &'a Request, body: &mut RequestBody) -> PoemResult<Self> {
+        let is_multipart = req
+            .content_type()
+            .map(|content_type| content_type.starts_with("multipart/"))
+            .unwrap_or(false);
+        if is_multipart {
+            Ok(LoadBody::Multipart(
+                Multipart::from_request(req, body).await?,
+            ))
+        } else {
+            Ok(LoadBody::Raw(body.take()?))
+        }
+    }
+}
+
 #[poem::handler]
 #[async_backtrace::framed]
 pub async fn streaming_load(
     ctx: &HttpQueryContext,
     req: &Request,
-    mut multipart: Multipart,
+    body: LoadBody,
 ) -> PoemResult<Json<LoadResponse>> {
     let session = ctx.get_session(SessionType::HTTPStreamingLoad);
     let context = session
@@ -165,7 +194,20 @@ pub async fn streaming_load(
                 tracing::info!("streaming load with file_format {:?}", input_context);
 
                 let handler = context.spawn(execute_query(context.clone(), plan));
-                let files = read_multi_part(multipart, tx, &input_context).await?;
+                let files = match body {
+                    LoadBody::Multipart(multipart) => {
+                        read_multi_part(multipart, tx, &input_context).await?
+                    }
+                    LoadBody::Raw(body) => {
+                        let filename = req
+                            .headers()
+                            .get("file_name")
+                            .and_then(|v| v.to_str().ok())
+                            .unwrap_or(DEFAULT_FILE_NAME)
+                            .to_string();
+                        read_body(body, filename, tx, &input_context).await?
+                    }
+                };
 
                 match handler.await {
                     Ok(Ok(_)) => Ok(Json(LoadResponse {
@@ -235,37 +277,12 @@ async fn read_multi_part(
                 break;
             }
             Ok(Some(field)) => {
-                let filename = field.file_name().unwrap_or("file_with_no_name").to_string();
-                let compression = input_context
-                    .get_compression_alg(&filename)
-                    .map_err(BadRequest)?;
+                let filename = field.file_name().unwrap_or(DEFAULT_FILE_NAME).to_string();
                 tracing::debug!("Multipart start read {}", &filename);
                 files.push(filename.clone());
-                let mut async_reader = field.into_async_read();
-                let mut is_start = true;
-                loop {
-                    let mut batch = vec![0u8; input_context.read_batch_size];
-                    let n = read_full(&mut async_reader, &mut batch[0..])
-                        .await
-                        .map_err(InternalServerError)?;
-                    if n == 0 {
-                        break;
-                    } else {
-                        batch.truncate(n);
-                        tracing::debug!("Multipart read {} bytes", n);
-                        if let Err(e) = tx
-                            .send(Ok(StreamingReadBatch {
-                                data: batch,
-                                path: filename.clone(),
-                                is_start,
-                                compression,
-                            }))
-                            .await
-                        {
-                            tracing::warn!(" Multipart fail to send ReadBatch: {}", e);
-                        }
-                        is_start = false;
-                    }
+                let async_reader = field.into_async_read();
+                if !read_stream(async_reader, filename, &tx, input_context).await? {
+                    break;
                 }
             }
         }
@@ -273,6 +290,59 @@ async fn read_multi_part(
     Ok(files)
 }
 
+async fn read_body(
+    body: Body,
+    filename: String,
+    tx: Sender<Result<StreamingReadBatch>>,
+    input_context: &Arc<InputContext>,
+) -> poem::Result<Vec<String>> {
+    tracing::debug!("Request body start read {}", &filename);
+    read_stream(body.into_async_read(), filename.clone(), &tx, input_context).await?;
+    Ok(vec![filename])
+}
+
+/// Reads the data in batches and sends them to the load pipeline. The channel is bounded, the
+/// reading waits until the pipeline has consumed the previous batches, so the client is slowed
+/// down by the TCP window instead of the whole file being buffered in memory.
+///
+/// Returns false if the pipeline is gone and the remaining data should not be read.
+async fn read_stream<R: AsyncRead + Unpin>(
+    mut reader: R,
+    filename: String,
+    tx: &Sender<Result<StreamingReadBatch>>,
+    input_context: &Arc<InputContext>,
+) -> poem::Result<bool> {
+    let compression = input_context
+        .get_compression_alg(&filename)
+        .map_err(BadRequest)?;
+    let mut is_start = true;
+    loop {
+        let mut batch = vec![0u8; input_context.read_batch_size];
+        let n = read_full(&mut reader, &mut batch[0..])
+            .await
+            .map_err(InternalServerError)?;
+        if n == 0 {
+            return Ok(true);
+        }
+        batch.truncate(n);
+        tracing::debug!("Streaming load read {} bytes of {}", n, filename);
+        if let Err(e) = tx
+            .send(Ok(StreamingReadBatch {
+                data: batch,
+                path: filename.clone(),
+                is_start,
+                compression,
+            }))
+            .await
+        {
+            // the error of the pipeline is returned by the query
+            tracing::warn!("Streaming load fail to send ReadBatch: {}", e);
+            return Ok(false);
+        }
+        is_start = false;
+    }
+}
+
 #[async_backtrace::framed]
 pub async fn read_full<R: AsyncRead + Unpin>(reader: &mut R, buf: &mut [u8]) -> Result<usize> {
     let mut buf = &mut buf[0..];
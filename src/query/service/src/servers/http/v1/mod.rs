@@ -15,6 +15,7 @@
 mod http_query_handlers;
 pub mod json_block;
 mod load;
+mod pipe;
 mod query;
 mod stage;
 
@@ -27,6 +28,11 @@ pub use http_query_handlers::QueryStats;
 pub(crate) use json_block::JsonBlock;
 pub use load::streaming_load;
 pub use load::LoadResponse;
+pub use pipe::pipe_binlog_events;
+pub use pipe::pipe_binlog_position;
+pub use pipe::pipe_events;
+pub use pipe::PipeBinlogResponse;
+pub use pipe::PipeEventsResponse;
 pub use query::ExecuteStateKind;
 pub use query::ExpiringMap;
 pub use query::ExpiringState;
@@ -0,0 +1,145 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_meta_app::principal::PipeInfo;
+use common_meta_app::principal::PipeSource;
+use common_users::UserApiProvider;
+use poem::error::InternalServerError;
+use poem::error::Result as PoemResult;
+use poem::http::StatusCode;
+use poem::web::Json;
+use poem::web::Path;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::HttpQueryContext;
+use crate::pipes::add_pipe_events;
+use crate::pipes::merge_binlog_events;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PipeEventsResponse {
+    pub pipe: String,
+    pub files: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PipeBinlogResponse {
+    pub pipe: String,
+    /// The row events merged into the table, and the ones skipped.
+    pub merged: u64,
+    pub skipped: u64,
+    /// The transactions merged so far, where the coordinator resumes the binlog from.
+    pub gtid_executed: String,
+}
+
+/// The tenant and the pipe, if the current user owns it.
+#[async_backtrace::framed]
+async fn owned_pipe(
+    ctx: &HttpQueryContext,
+    name: &str,
+    handler: &str,
+) -> PoemResult<(String, PipeInfo)> {
+    let session = ctx.get_session(SessionType::HTTPAPI(handler.to_string()));
+    let context = session
+        .create_query_context()
+        .await
+        .map_err(InternalServerError)?;
+    let tenant = context.get_tenant();
+    let user = context.get_current_user().map_err(InternalServerError)?;
+
+    match UserApiProvider::instance().get_pipe(&tenant, name).await {
+        Ok(pipe) if pipe.data.owner == user.identity() => Ok((tenant, pipe.data)),
+        Ok(_) => Err(poem::Error::from_string(
+            format!("Only the owner can post the events of pipe {}", name),
+            StatusCode::FORBIDDEN,
+        )),
+        Err(e) if e.code() == ErrorCode::UNKNOWN_PIPE => {
+            Err(poem::Error::from_string(e.message(), StatusCode::NOT_FOUND))
+        }
+        Err(e) => Err(InternalServerError(e)),
+    }
+}
+
+fn event_error(e: ErrorCode) -> poem::Error {
+    if e.code() == ErrorCode::BAD_ARGUMENTS || e.code() == ErrorCode::ILLEGAL_PIPE_FORMAT {
+        poem::Error::from_string(e.message(), StatusCode::BAD_REQUEST)
+    } else {
+        InternalServerError(e)
+    }
+}
+
+/// Receives the S3 event notifications of an `AUTO_INGEST` pipe, posted directly or
+/// by an SNS subscription, the files created are copied by the pipe runner. Only
+/// the owner of the pipe can post its events.
+#[poem::handler]
+#[async_backtrace::framed]
+pub async fn pipe_events(
+    ctx: &HttpQueryContext,
+    Path(name): Path<String>,
+    body: String,
+) -> PoemResult<Json<PipeEventsResponse>> {
+    let (tenant, pipe) = owned_pipe(ctx, &name, "PipeEvents").await?;
+    match add_pipe_events(&tenant, &pipe, &body).await {
+        Ok(files) => Ok(Json(PipeEventsResponse { pipe: name, files })),
+        Err(e) => Err(event_error(e)),
+    }
+}
+
+/// Receives the binlog row events of a pipe from MySQL, posted by the coordinator
+/// tailing the binlog, and merges them into the table before responding. Only the
+/// owner of the pipe can post its events.
+#[poem::handler]
+#[async_backtrace::framed]
+pub async fn pipe_binlog_events(
+    ctx: &HttpQueryContext,
+    Path(name): Path<String>,
+    body: String,
+) -> PoemResult<Json<PipeBinlogResponse>> {
+    let (tenant, _) = owned_pipe(ctx, &name, "PipeBinlog").await?;
+    match merge_binlog_events(&tenant, &name, &body).await {
+        Ok(merged) => Ok(Json(PipeBinlogResponse {
+            pipe: name,
+            merged: merged.merged,
+            skipped: merged.skipped,
+            gtid_executed: merged.gtid_executed.to_string(),
+        })),
+        Err(e) => Err(event_error(e)),
+    }
+}
+
+/// The transactions merged by a pipe from MySQL, for the coordinator to resume the
+/// binlog after them.
+#[poem::handler]
+#[async_backtrace::framed]
+pub async fn pipe_binlog_position(
+    ctx: &HttpQueryContext,
+    Path(name): Path<String>,
+) -> PoemResult<Json<PipeBinlogResponse>> {
+    let (_, pipe) = owned_pipe(ctx, &name, "PipeBinlog").await?;
+    if !matches!(pipe.source, PipeSource::MySql { .. }) {
+        return Err(poem::Error::from_string(
+            format!("Pipe {} doesn't replicate MySQL", name),
+            StatusCode::BAD_REQUEST,
+        ));
+    }
+    Ok(Json(PipeBinlogResponse {
+        pipe: name,
+        merged: 0,
+        skipped: 0,
+        gtid_executed: pipe.gtid_executed.to_string(),
+    }))
+}
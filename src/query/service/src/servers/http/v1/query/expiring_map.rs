@@ -111,7 +111,12 @@ where
             None => None,
         };
         let i = MaybeExpiring { task, value: v };
-        self.map.insert(k, i);
+        // the checker of the replaced value would remove the new one.
+        if let Some(mut old) = self.map.insert(k, i) {
+            if let Some(t) = old.task.take() {
+                t.abort()
+            }
+        }
     }
 
     pub fn get<Q: ?Sized>(&self, k: &Q) -> Option<V>
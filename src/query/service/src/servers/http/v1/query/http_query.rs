@@ -24,10 +24,14 @@ use common_base::base::tokio::sync::RwLock;
 use common_base::runtime::GlobalQueryRuntime;
 use common_base::runtime::TrySpawn;
 use common_catalog::table_context::StageAttachment;
+use common_catalog::txn::TxnManagerRef;
+use common_catalog::txn::TxnState;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_sql::plans::Plan;
 use serde::Deserialize;
 use serde::Serialize;
+use tracing::Instrument;
 
 use super::HttpQueryContext;
 use crate::interpreters::InterpreterFactory;
@@ -83,6 +87,9 @@ impl Debug for HttpQueryRequest {
 const DEFAULT_MAX_ROWS_IN_BUFFER: usize = 5 * 1000 * 1000;
 const DEFAULT_MAX_ROWS_PER_PAGE: usize = 10000;
 const DEFAULT_WAIT_TIME_SECS: u32 = 1;
+/// How long a session is kept on the server after `BEGIN` if the client did
+/// not ask for `keep_server_session_secs`, until `COMMIT` or `ROLLBACK`.
+const DEFAULT_TXN_SESSION_SECS: u64 = 3600;
 
 fn default_max_rows_in_buffer() -> usize {
     DEFAULT_MAX_ROWS_IN_BUFFER
@@ -135,6 +142,10 @@ pub struct HttpSessionConf {
     pub keep_server_session_secs: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub settings: Option<BTreeMap<String, String>>,
+    /// State of the explicit transaction of the server side session, the client
+    /// should send it back together with the `session_id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub txn_state: Option<TxnState>,
 }
 
 impl HttpSessionConf {
@@ -205,6 +216,7 @@ pub struct HttpQuery {
     page_manager: Arc<TokioMutex<PageManager>>,
     config: HttpQueryConfig,
     expire_state: Arc<TokioMutex<ExpireState>>,
+    txn_mgr: TxnManagerRef,
 }
 
 impl HttpQuery {
@@ -215,11 +227,45 @@ impl HttpQuery {
         config: HttpQueryConfig,
     ) -> Result<Arc<HttpQuery>> {
         let http_query_manager = HttpQueryManager::instance();
+        let trace_parent = ctx.trace_parent.clone();
+        let trace_state = ctx.trace_state.clone();
+
+        let in_txn = matches!(
+            request.session.as_ref().and_then(|s| s.txn_state),
+            Some(TxnState::Active)
+        );
+        if in_txn && request.session_id.is_none() {
+            return Err(ErrorCode::BadArguments(
+                "transaction is active but session_id is missing, the transaction is lost",
+            ));
+        }
 
         let session = if let Some(id) = &request.session_id {
             let session = http_query_manager.get_session(id).await.ok_or_else(|| {
-                ErrorCode::UnknownSession(format!("unknown session-id {}, maybe expired", id))
+                if in_txn {
+                    ErrorCode::UnknownSession(format!(
+                        "unknown session-id {}, maybe expired, the transaction is lost",
+                        id
+                    ))
+                } else {
+                    ErrorCode::UnknownSession(format!("unknown session-id {}, maybe expired", id))
+                }
             })?;
+            // a session is only resumed by the user who authenticated it.
+            let authed_user = ctx.get_session(SessionType::HTTPQuery).get_current_user()?;
+            if session.get_current_user()?.identity() != authed_user.identity() {
+                return Err(ErrorCode::PermissionDenied(format!(
+                    "session-id {} does not belong to user {}",
+                    id,
+                    authed_user.identity()
+                )));
+            }
+            if in_txn && !session.txn_mgr().lock().is_active() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "no active transaction in session-id {}, the transaction is lost",
+                    id
+                )));
+            }
             let mut n = 1;
             while let ExpiringState::InUse(query_id) = session.expire_state() {
                 if let Some(last_query) = &http_query_manager.get_query(&query_id).await {
@@ -244,6 +290,11 @@ impl HttpQuery {
             ctx.get_session(SessionType::HTTPQuery)
         };
 
+        let keep_session = matches!(
+            request.session.as_ref().and_then(|s| s.keep_server_session_secs),
+            Some(secs) if secs > 0
+        );
+        let mut kept = request.session_id.is_some();
         if let Some(session_conf) = &request.session {
             if let Some(db) = &session_conf.database {
                 session.set_current_database(db.clone());
@@ -268,17 +319,25 @@ impl HttpQuery {
                     http_query_manager
                         .add_session(session.clone(), Duration::from_secs(secs))
                         .await;
+                    kept = true;
                 }
             }
         };
 
         let session_id = session.get_id().clone();
+        let txn_mgr = session.txn_mgr();
 
         let ctx = session.create_query_context().await?;
         let id = ctx.get_id();
         let sql = &request.sql;
         tracing::info!("run query_id={id} in session_id={session_id}, sql='{sql}'");
 
+        let span = tracing::info_span!("http_query", query_id = %id);
+        if let Some(trace_parent) = &trace_parent {
+            common_tracing::set_w3c_span_parent(&span, trace_parent, trace_state.as_deref());
+        }
+        ctx.set_query_span(span.clone());
+
         match &request.stage_attachment {
             Some(attachment) => ctx.attach_stage(StageAttachment {
                 location: attachment.location.clone(),
@@ -305,10 +364,33 @@ impl HttpQuery {
         let (plan, plan_extras) = ExecuteState::plan_sql(&sql, ctx.clone()).await?;
         let schema = InterpreterFactory::get(ctx.clone(), &plan).await?.schema();
 
+        match plan {
+            // the statements after `BEGIN` must run in the same session,
+            // so keep it on the server for the following requests.
+            Plan::Begin if !kept => {
+                http_query_manager
+                    .add_session(
+                        session.clone(),
+                        Duration::from_secs(DEFAULT_TXN_SESSION_SECS),
+                    )
+                    .await;
+            }
+            // the transaction ends even if `COMMIT` fails. If the session was only kept
+            // for the transaction, it expires like the results of the query from now on.
+            Plan::Commit | Plan::Abort if request.session_id.is_some() && !keep_session => {
+                http_query_manager
+                    .add_session(
+                        session.clone(),
+                        Duration::from_secs(config.result_timeout_secs),
+                    )
+                    .await;
+            }
+            _ => {}
+        }
+
         let http_query_runtime_instance = GlobalQueryRuntime::instance();
-        http_query_runtime_instance
-            .runtime()
-            .try_spawn(async move {
+        http_query_runtime_instance.runtime().try_spawn(
+            async move {
                 let state = state_clone.clone();
                 if let Err(e) = ExecuteState::try_start_query(
                     state,
@@ -336,7 +418,9 @@ impl HttpQuery {
                         .await;
                     block_sender_closer.close();
                 }
-            })?;
+            }
+            .instrument(span),
+        )?;
 
         let format_settings = ctx.get_format_settings()?;
         let data = Arc::new(TokioMutex::new(PageManager::new(
@@ -354,6 +438,7 @@ impl HttpQuery {
             page_manager: data,
             config,
             expire_state: Arc::new(TokioMutex::new(ExpireState::Working)),
+            txn_mgr,
         };
 
         Ok(Arc::new(query))
@@ -364,16 +449,17 @@ impl HttpQuery {
         let data = Some(self.get_page(page_no).await?);
         let state = self.get_state().await;
         let session_conf = self.request.session.clone().unwrap_or_default();
-        let session_conf = if let Some(affect) = &state.affect {
-            Some(session_conf.apply_affect(affect))
+        let mut session_conf = if let Some(affect) = &state.affect {
+            session_conf.apply_affect(affect)
         } else {
-            Some(session_conf)
+            session_conf
         };
+        session_conf.txn_state = self.get_txn_state(session_conf.txn_state);
 
         Ok(HttpQueryResponseInternal {
             data,
             state,
-            session: session_conf,
+            session: Some(session_conf),
             session_id: self.session_id.clone(),
         })
     }
@@ -388,6 +474,16 @@ impl HttpQuery {
         }
     }
 
+    /// Only reported once a transaction was started, so that the clients
+    /// not using transactions see no change.
+    fn get_txn_state(&self, client_state: Option<TxnState>) -> Option<TxnState> {
+        let state = self.txn_mgr.lock().state();
+        match (state, client_state) {
+            (TxnState::AutoCommit, None) => None,
+            (state, _) => Some(state),
+        }
+    }
+
     #[async_backtrace::framed]
     async fn get_state(&self) -> ResponseState {
         let state = self.state.read().await;
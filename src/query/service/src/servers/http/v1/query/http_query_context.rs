@@ -24,11 +24,19 @@ use crate::sessions::SessionType;
 
 pub struct HttpQueryContext {
     session: Arc<Session>,
+    /// W3C trace context of the client, from the `traceparent` header.
+    pub trace_parent: Option<String>,
+    /// From the `tracestate` header.
+    pub trace_state: Option<String>,
 }
 
 impl HttpQueryContext {
     pub fn new(session: Arc<Session>) -> Self {
-        HttpQueryContext { session }
+        HttpQueryContext {
+            session,
+            trace_parent: None,
+            trace_state: None,
+        }
     }
 
     pub fn get_session(&self, session_type: SessionType) -> Arc<Session> {
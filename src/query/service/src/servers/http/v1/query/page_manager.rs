@@ -90,6 +90,11 @@ impl PageManager {
         }
     }
 
+    /// Returns the page `page_no`, fetching a new page acknowledges the previous
+    /// ones, so they are dropped.
+    ///
+    /// Fetching the last returned page again gets the same rows, so the clients
+    /// can retry a request whose response is lost.
     #[async_backtrace::framed]
     pub async fn get_a_page(&mut self, page_no: usize, tp: &Wait) -> Result<Page> {
         let next_no = self.total_pages;
@@ -107,9 +112,18 @@ impl PageManager {
             }
             self.end = end;
             Ok(page)
-        } else if page_no == next_no - 1 {
+        } else if page_no == next_no {
+            // the last page returned was empty, and the end was reached.
+            Ok(Page {
+                data: JsonBlock {
+                    schema: self.schema.clone(),
+                    data: vec![],
+                },
+                total_rows: self.total_rows,
+            })
+        } else if page_no + 1 == next_no {
             // later, there may be other ways to ack and drop the last page except collect_new_page.
-            // but for now, last_page always exists in this branch, since page_no is unsigned.
+            // but for now, last_page always exists in this branch, since next_no > 0.
             Ok(self
                 .last_page
                 .as_ref()
@@ -23,9 +23,11 @@ pub use self::http::HttpHandlerKind;
 pub use self::mysql::MySQLConnection;
 pub use self::mysql::MySQLFederated;
 pub use self::mysql::MySQLHandler;
+pub use self::pgwire::PGWireHandler;
 
 pub(crate) mod federated_helper;
 pub mod flight_sql;
 pub mod http;
 mod mysql;
+mod pgwire;
 pub(crate) mod server;
@@ -29,6 +29,7 @@ use common_expression::DataSchemaRef;
 use common_expression::SendableDataBlockStream;
 use common_io::prelude::FormatSettings;
 use common_sql::Planner;
+use common_users::parse_client_ip;
 use common_users::CertifiedInfo;
 use common_users::UserApiProvider;
 use futures_util::StreamExt;
@@ -255,15 +256,22 @@ impl<W: AsyncWrite + Send + Unpin> InteractiveWorkerBase<W> {
     #[async_backtrace::framed]
     async fn authenticate(&self, salt: &[u8], info: CertifiedInfo) -> Result<bool> {
         let user_name = &info.user_name;
-        let client_ip = info.user_client_address.split(':').collect::<Vec<_>>()[0];
+        let client_ip = match parse_client_ip(&info.user_client_address) {
+            Some(ip) => ip.to_string(),
+            None => info.user_client_address.clone(),
+        };
 
         let ctx = self.session.create_query_context().await?;
-        let user_info = UserApiProvider::instance()
-            .get_user_with_client_ip(&ctx.get_tenant(), user_name, client_ip)
+        let user_api = UserApiProvider::instance();
+        let user_info = user_api
+            .get_user_with_client_ip(&ctx.get_tenant(), user_name, &client_ip)
             .await?;
 
         let authed = user_info.auth_info.auth_mysql(&info.user_password, salt)?;
         if authed {
+            user_api
+                .enforce_network_policy(&ctx.get_tenant(), &user_info, Some(&client_ip))
+                .await?;
             self.session.set_authed_user(user_info, None).await?;
         }
         Ok(authed)
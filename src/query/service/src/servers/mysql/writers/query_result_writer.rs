@@ -176,6 +176,8 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
                 DataType::Bitmap => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 DataType::Tuple(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 DataType::Variant => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+                DataType::Geometry => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+                DataType::Binary => Ok(ColumnType::MYSQL_TYPE_BLOB),
                 DataType::Decimal(_) => Ok(ColumnType::MYSQL_TYPE_DECIMAL),
                 _ => Err(ErrorCode::Unimplemented(format!(
                     "Unsupported column type:{:?}",
@@ -279,6 +281,9 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
                                     let bitmap_result = "<bitmap binary>".as_bytes();
                                     row_writer.write_col(bitmap_result)?;
                                 }
+                                ScalarRef::Binary(v) => {
+                                    row_writer.write_col(v)?;
+                                }
                                 _ => write_field(
                                     &mut row_writer,
                                     column,
@@ -0,0 +1,776 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::net::Shutdown;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use chrono_tz::Tz;
+use common_ast::parser::token::TokenKind;
+use common_ast::parser::tokenize_sql;
+use common_base::base::tokio::io::AsyncWriteExt;
+use common_base::base::tokio::io::BufReader;
+use common_base::base::tokio::net::tcp::OwnedReadHalf;
+use common_base::base::tokio::net::tcp::OwnedWriteHalf;
+use common_base::base::tokio::net::TcpStream;
+use common_base::runtime::Runtime;
+use common_base::runtime::Thread;
+use common_base::runtime::TrySpawn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::ToErrorCode;
+use common_expression::Column;
+use common_expression::SendableDataBlockStream;
+use common_formats::field_encoder::FieldEncoderValues;
+use common_sql::plans::Plan;
+use common_sql::Planner;
+use futures::StreamExt;
+use once_cell::sync::Lazy;
+use rand::Rng;
+use regex::Regex;
+use tracing::error;
+use tracing::info;
+use tracing::Instrument;
+
+use crate::auth::AuthMgr;
+use crate::auth::Credential;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::interpreters::InterpreterQueryLog;
+use crate::servers::pgwire::pgwire_messages::read_message;
+use crate::servers::pgwire::pgwire_messages::read_startup_message;
+use crate::servers::pgwire::pgwire_messages::FieldDescription;
+use crate::servers::pgwire::pgwire_messages::FrontendMessage;
+use crate::servers::pgwire::pgwire_messages::MessageWriter;
+use crate::servers::pgwire::pgwire_messages::StartupMessage;
+use crate::servers::pgwire::pgwire_messages::PROTOCOL_VERSION_3;
+use crate::servers::pgwire::pgwire_types::bind_params;
+use crate::servers::pgwire::pgwire_types::column_format;
+use crate::servers::pgwire::pgwire_types::encode_value;
+use crate::servers::pgwire::pgwire_types::field_descriptions;
+use crate::servers::pgwire::pgwire_types::param_literal;
+use crate::servers::pgwire::pgwire_types::placeholders;
+use crate::servers::pgwire::pgwire_types::sqlstate;
+use crate::servers::pgwire::pgwire_types::OID_TEXT;
+use crate::servers::pgwire::pgwire_types::OID_UNSPECIFIED;
+use crate::servers::pgwire::PG_SERVER_VERSION;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::TableContext;
+
+// flush the buffered rows to the client once they exceed 64KB
+const FLUSH_THRESHOLD: usize = 64 * 1024;
+
+// Session variables postgres drivers set on their own, they mean nothing to
+// databend and are acknowledged without doing anything.
+static IGNORED_SET_STATEMENT: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)^\s*SET\s+(SESSION\s+|LOCAL\s+)?(application_name|extra_float_digits|client_encoding|datestyle|intervalstyle|standard_conforming_strings|search_path|client_min_messages|statement_timeout|lock_timeout|idle_in_transaction_session_timeout)\s*(=|TO\s)",
+    )
+    .unwrap()
+});
+
+struct PreparedStatement {
+    query: String,
+    param_types: Vec<u32>,
+}
+
+/// A prepared statement with its parameters bound, the query starts on the first
+/// `Execute` and may be fetched by several `Execute`s.
+struct Portal {
+    query: String,
+    result_formats: Vec<i16>,
+    running: Option<RunningQuery>,
+}
+
+enum CommandTag {
+    Select,
+    Insert,
+    Update,
+    Delete,
+    Other(String),
+}
+
+impl CommandTag {
+    fn create(plan: &Plan) -> CommandTag {
+        if plan.has_result_set() {
+            return CommandTag::Select;
+        }
+        match plan {
+            Plan::Insert(_) | Plan::Replace(_) => CommandTag::Insert,
+            Plan::Update(_) => CommandTag::Update,
+            Plan::Delete(_) => CommandTag::Delete,
+            Plan::Begin => CommandTag::Other("BEGIN".to_string()),
+            Plan::Commit => CommandTag::Other("COMMIT".to_string()),
+            Plan::Abort => CommandTag::Other("ROLLBACK".to_string()),
+            // `CreateTable` => `CREATE TABLE`
+            other => {
+                let name = other.to_string();
+                let mut tag = String::with_capacity(name.len() + 4);
+                for (i, c) in name.chars().enumerate() {
+                    if i > 0 && c.is_ascii_uppercase() {
+                        tag.push(' ');
+                    }
+                    tag.push(c.to_ascii_uppercase());
+                }
+                CommandTag::Other(tag)
+            }
+        }
+    }
+}
+
+struct CurrentBlock {
+    columns: Vec<Column>,
+    num_rows: usize,
+    next_row: usize,
+}
+
+struct RunningQuery {
+    context: Option<Arc<QueryContext>>,
+    tag: CommandTag,
+    /// `None` if the statement returns no rows.
+    fields: Option<Vec<FieldDescription>>,
+    blocks: Option<SendableDataBlockStream>,
+    current: Option<CurrentBlock>,
+    timezone: Tz,
+    sent_rows: usize,
+}
+
+impl RunningQuery {
+    fn completion_tag(&self) -> String {
+        let affected_rows = self
+            .context
+            .as_ref()
+            .map(|ctx| ctx.get_write_progress_value().rows)
+            .unwrap_or_default();
+        match &self.tag {
+            CommandTag::Select => format!("SELECT {}", self.sent_rows),
+            CommandTag::Insert => format!("INSERT 0 {}", affected_rows),
+            CommandTag::Update => format!("UPDATE {}", affected_rows),
+            CommandTag::Delete => format!("DELETE {}", affected_rows),
+            CommandTag::Other(tag) => tag.clone(),
+        }
+    }
+}
+
+pub struct PGWireConnection {
+    session: Arc<Session>,
+    client_addr: Option<SocketAddr>,
+    reader: BufReader<OwnedReadHalf>,
+    socket: OwnedWriteHalf,
+    writer: MessageWriter,
+    statements: HashMap<String, PreparedStatement>,
+    portals: HashMap<String, Portal>,
+    // after an error of the extended query protocol, messages are discarded until `Sync`
+    skip_till_sync: bool,
+}
+
+impl PGWireConnection {
+    pub fn run_on_stream(session: Arc<Session>, stream: TcpStream) -> Result<()> {
+        let blocking_stream = stream.into_std().map_err_to_code(
+            ErrorCode::TokioError,
+            || "Cannot to convert Tokio TcpStream to Std TcpStream",
+        )?;
+        let host = blocking_stream.peer_addr().ok();
+        let blocking_stream_ref = blocking_stream.try_clone()?;
+        session.attach(host, move || {
+            if let Err(error) = blocking_stream_ref.shutdown(Shutdown::Both) {
+                error!("Cannot shutdown PostgreSQL session io {}", error);
+            }
+        });
+
+        let non_blocking_stream = TcpStream::from_std(blocking_stream)?;
+        let query_executor =
+            Runtime::with_worker_threads(1, Some("pgwire-query-executor".to_string()))?;
+        Thread::spawn(move || {
+            let join_handle = query_executor.spawn(async move {
+                let connection = PGWireConnection::create(session, non_blocking_stream);
+                if let Err(error) = connection.run().await {
+                    error!("Unexpected error occurred during query: {:?}", error);
+                }
+            });
+            let _ = futures::executor::block_on(join_handle);
+        });
+        Ok(())
+    }
+
+    fn create(session: Arc<Session>, stream: TcpStream) -> PGWireConnection {
+        let client_addr = stream.peer_addr().ok();
+        let (reader, socket) = stream.into_split();
+        PGWireConnection {
+            session,
+            client_addr,
+            reader: BufReader::new(reader),
+            socket,
+            writer: MessageWriter::default(),
+            statements: HashMap::new(),
+            portals: HashMap::new(),
+            skip_till_sync: false,
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn run(mut self) -> Result<()> {
+        if !self.startup().await? {
+            return Ok(());
+        }
+
+        loop {
+            let message = match read_message(&mut self.reader).await? {
+                Some(message) => message,
+                None => return Ok(()),
+            };
+
+            if self.session.is_aborting() {
+                let error = ErrorCode::AbortedSession(
+                    "Aborting this connection. because we are try aborting server.",
+                );
+                self.send_error(&error);
+                self.flush().await?;
+                return Err(error);
+            }
+
+            match message {
+                FrontendMessage::Terminate => return Ok(()),
+                FrontendMessage::Sync => {
+                    self.skip_till_sync = false;
+                    self.ready_for_query();
+                    self.flush().await?;
+                }
+                FrontendMessage::Flush => self.flush().await?,
+                _ if self.skip_till_sync => {}
+                FrontendMessage::Query(sql) => {
+                    self.simple_query(&sql).await?;
+                    self.ready_for_query();
+                    self.flush().await?;
+                }
+                message => {
+                    if let Err(error) = self.extended_query(message).await {
+                        self.send_error(&error);
+                        self.skip_till_sync = true;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Negotiates the protocol and authenticates the user, returns false if the
+    /// connection should be closed.
+    #[async_backtrace::framed]
+    async fn startup(&mut self) -> Result<bool> {
+        let parameters = loop {
+            match read_startup_message(&mut self.reader).await? {
+                // TLS is not supported, the client goes on with the plain connection
+                StartupMessage::SslRequest | StartupMessage::GssEncRequest => {
+                    self.writer.reject_encryption();
+                    self.flush().await?;
+                }
+                // cancelling queries by a separate connection is not supported
+                StartupMessage::CancelRequest => return Ok(false),
+                StartupMessage::Startup {
+                    protocol_version,
+                    parameters,
+                } => {
+                    if protocol_version != PROTOCOL_VERSION_3 {
+                        self.writer.error_response(
+                            "0A000",
+                            &format!(
+                                "unsupported frontend protocol {}.{}",
+                                protocol_version >> 16,
+                                protocol_version & 0xffff
+                            ),
+                        );
+                        self.flush().await?;
+                        return Ok(false);
+                    }
+                    break parameters;
+                }
+            }
+        };
+
+        let user = match parameters.get("user") {
+            Some(user) => user.clone(),
+            None => {
+                self.writer
+                    .error_response("28000", "no user name specified in startup packet");
+                self.flush().await?;
+                return Ok(false);
+            }
+        };
+        if let Err(error) = self.authenticate(&user).await {
+            info!(
+                "PostgreSQL handler authenticate failed, user_name: {}, client_address: {:?}, failure_cause: {}",
+                user, self.client_addr, error
+            );
+            // the cause is only logged, the client can not tell an unknown user from a wrong password
+            self.writer.error_response(
+                "28P01",
+                &format!("password authentication failed for user \"{}\"", user),
+            );
+            self.flush().await?;
+            return Ok(false);
+        }
+        self.writer.authentication_ok();
+
+        if let Some(database) = parameters.get("database") {
+            if let Err(error) = self.use_database(database, &user).await {
+                self.send_error(&error);
+                self.flush().await?;
+                return Ok(false);
+            }
+        }
+
+        let timezone = self.session.get_settings().get_timezone()?;
+        for (name, value) in [
+            ("server_version", PG_SERVER_VERSION),
+            ("server_encoding", "UTF8"),
+            ("client_encoding", "UTF8"),
+            ("DateStyle", "ISO, MDY"),
+            ("integer_datetimes", "on"),
+            ("standard_conforming_strings", "on"),
+            ("TimeZone", timezone.as_str()),
+        ] {
+            self.writer.parameter_status(name, value);
+        }
+        if let Some(application_name) = parameters.get("application_name") {
+            self.writer
+                .parameter_status("application_name", application_name);
+        }
+        let (process_id, secret_key) = {
+            let mut rng = rand::thread_rng();
+            (rng.gen::<i32>(), rng.gen::<i32>())
+        };
+        self.writer.backend_key_data(process_id, secret_key);
+        self.ready_for_query();
+        self.flush().await?;
+        Ok(true)
+    }
+
+    #[async_backtrace::framed]
+    async fn authenticate(&mut self, user: &str) -> Result<()> {
+        // the password is asked before the user is looked up, so that the response
+        // does not tell whether the user exists.
+        self.writer.authentication_cleartext_password();
+        self.flush().await?;
+        let password = match read_message(&mut self.reader).await? {
+            Some(FrontendMessage::Password(password)) => password.into_bytes(),
+            _ => {
+                return Err(ErrorCode::AuthenticateFailure("expected password response"));
+            }
+        };
+
+        let credential = Credential::Password {
+            name: user.to_string(),
+            password: Some(password),
+            hostname: self.client_addr.map(|addr| addr.ip().to_string()),
+        };
+        AuthMgr::instance()
+            .auth(self.session.clone(), &credential)
+            .await
+    }
+
+    #[async_backtrace::framed]
+    async fn use_database(&self, database: &str, user: &str) -> Result<()> {
+        let ctx = self.session.create_query_context().await?;
+        match ctx.set_current_database(database.to_string()).await {
+            Ok(_) => Ok(()),
+            // postgres clients take the user name as the database if not given
+            Err(error) if error.code() == ErrorCode::UNKNOWN_DATABASE && database == user => Ok(()),
+            Err(error) => Err(error),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn simple_query(&mut self, sql: &str) -> Result<()> {
+        // the unnamed statement and portal are dropped by a simple query
+        self.statements.remove("");
+        self.portals.remove("");
+
+        let statements = split_statements(sql);
+        if statements.is_empty() {
+            self.writer.empty_query_response();
+            return Ok(());
+        }
+
+        for statement in statements {
+            let mut query = match self.start_query(statement, &[]).await {
+                Ok(query) => query,
+                Err(error) => {
+                    self.send_error(&error.display_with_sql(statement));
+                    break;
+                }
+            };
+            if let Some(fields) = &query.fields {
+                self.writer.row_description(fields);
+            }
+            if let Err(error) = self.send_rows(&mut query, 0).await {
+                self.send_error(&error.display_with_sql(statement));
+                break;
+            }
+            self.writer.command_complete(&query.completion_tag());
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn extended_query(&mut self, message: FrontendMessage) -> Result<()> {
+        match message {
+            FrontendMessage::Parse {
+                name,
+                query,
+                param_types,
+            } => {
+                if !name.is_empty() && self.statements.contains_key(&name) {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "prepared statement \"{}\" already exists",
+                        name
+                    )));
+                }
+                self.statements
+                    .insert(name, PreparedStatement { query, param_types });
+                self.writer.parse_complete();
+            }
+            FrontendMessage::Bind {
+                portal,
+                statement,
+                param_formats,
+                params,
+                result_formats,
+            } => {
+                let prepared = self.get_statement(&statement)?;
+                let query = if params.is_empty() {
+                    prepared.query.clone()
+                } else {
+                    let literals = params
+                        .iter()
+                        .enumerate()
+                        .map(|(i, param)| {
+                            let type_oid = prepared
+                                .param_types
+                                .get(i)
+                                .copied()
+                                .unwrap_or(OID_UNSPECIFIED);
+                            param_literal(
+                                param.as_deref(),
+                                column_format(&param_formats, i)?,
+                                type_oid,
+                            )
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+                    bind_params(&prepared.query, &literals)?
+                };
+                self.portals.insert(portal, Portal {
+                    query,
+                    result_formats,
+                    running: None,
+                });
+                self.writer.bind_complete();
+            }
+            FrontendMessage::Describe { kind: b'S', name } => {
+                let prepared = self.get_statement(&name)?;
+                let num_params = placeholders(&prepared.query)
+                    .iter()
+                    .map(|(_, index)| *index)
+                    .max()
+                    .unwrap_or_default()
+                    .max(prepared.param_types.len());
+                // parameters of unspecified types are taken as text
+                let param_types = (0..num_params)
+                    .map(|i| match prepared.param_types.get(i) {
+                        Some(oid) if *oid != OID_UNSPECIFIED => *oid,
+                        _ => OID_TEXT,
+                    })
+                    .collect::<Vec<_>>();
+                let literals = vec!["NULL".to_string(); num_params];
+                let query = bind_params(&prepared.query, &literals)?;
+                self.writer.parameter_description(&param_types);
+                self.describe(&query, &[]).await?;
+            }
+            FrontendMessage::Describe { kind: b'P', name } => {
+                let portal = self.get_portal(&name)?;
+                let (query, result_formats) = (portal.query.clone(), portal.result_formats.clone());
+                self.describe(&query, &result_formats).await?;
+            }
+            FrontendMessage::Execute { portal, max_rows } => {
+                let mut bound = match self.portals.remove(&portal) {
+                    Some(bound) => bound,
+                    None => return Err(portal_not_exists(&portal)),
+                };
+                if split_statements(&bound.query).is_empty() {
+                    self.writer.empty_query_response();
+                    self.portals.insert(portal, bound);
+                    return Ok(());
+                }
+                let mut query = match bound.running.take() {
+                    Some(query) => query,
+                    None => self
+                        .start_query(&bound.query, &bound.result_formats)
+                        .await
+                        .map_err(|e| e.display_with_sql(&bound.query))?,
+                };
+                if self.send_rows(&mut query, max_rows.max(0) as usize).await? {
+                    self.writer.portal_suspended();
+                    bound.running = Some(query);
+                } else {
+                    self.writer.command_complete(&query.completion_tag());
+                }
+                self.portals.insert(portal, bound);
+            }
+            FrontendMessage::Close { kind, name } => {
+                if kind == b'S' {
+                    self.statements.remove(&name);
+                } else {
+                    self.portals.remove(&name);
+                }
+                self.writer.close_complete();
+            }
+            FrontendMessage::Describe { kind, .. } => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "invalid describe kind: {}",
+                    kind as char
+                )));
+            }
+            FrontendMessage::Password(_) => {
+                return Err(ErrorCode::BadBytes("unexpected password message"));
+            }
+            FrontendMessage::Unsupported(tag) => {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "unsupported message type: {}",
+                    tag as char
+                )));
+            }
+            FrontendMessage::Query(_)
+            | FrontendMessage::Sync
+            | FrontendMessage::Flush
+            | FrontendMessage::Terminate => unreachable!("handled by the caller"),
+        }
+        Ok(())
+    }
+
+    fn get_statement(&self, name: &str) -> Result<&PreparedStatement> {
+        self.statements.get(name).ok_or_else(|| {
+            ErrorCode::BadArguments(format!("prepared statement \"{}\" does not exist", name))
+        })
+    }
+
+    fn get_portal(&self, name: &str) -> Result<&Portal> {
+        self.portals
+            .get(name)
+            .ok_or_else(|| portal_not_exists(name))
+    }
+
+    /// Sends the description of the rows the query returns, the query is planned
+    /// but not executed.
+    #[async_backtrace::framed]
+    async fn describe(&mut self, sql: &str, result_formats: &[i16]) -> Result<()> {
+        if split_statements(sql).is_empty() || IGNORED_SET_STATEMENT.is_match(sql) {
+            self.writer.no_data();
+            return Ok(());
+        }
+
+        let context = self.session.create_query_context().await?;
+        let mut planner = Planner::new(context.clone());
+        let (plan, _) = planner.plan_sql(sql).await?;
+        if !plan.has_result_set() {
+            self.writer.no_data();
+            return Ok(());
+        }
+        let interpreter = InterpreterFactory::get(context, &plan).await?;
+        let fields = field_descriptions(interpreter.schema().fields(), result_formats)?;
+        self.writer.row_description(&fields);
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn start_query(&self, sql: &str, result_formats: &[i16]) -> Result<RunningQuery> {
+        if IGNORED_SET_STATEMENT.is_match(sql) {
+            info!("Federated query: {}", sql);
+            return Ok(RunningQuery {
+                context: None,
+                tag: CommandTag::Other("SET".to_string()),
+                fields: None,
+                blocks: None,
+                current: None,
+                timezone: Tz::UTC,
+                sent_rows: 0,
+            });
+        }
+
+        info!("Normal query: {}", sql);
+        let context = self.session.create_query_context().await?;
+        let mut planner = Planner::new(context.clone());
+        let (plan, extras) = planner.plan_sql(sql).await?;
+        context.attach_query_str(plan.to_string(), extras.statement.to_mask_sql());
+
+        let interpreter = match InterpreterFactory::get(context.clone(), &plan).await {
+            Ok(interpreter) => interpreter,
+            Err(error) => {
+                InterpreterQueryLog::fail_to_start(context, error.clone());
+                return Err(error);
+            }
+        };
+        let fields = match plan.has_result_set() {
+            true => Some(field_descriptions(
+                interpreter.schema().fields(),
+                result_formats,
+            )?),
+            false => None,
+        };
+
+        let blocks = context.try_spawn({
+            let ctx = context.clone();
+            async move { interpreter.execute(ctx).await }.in_current_span()
+        })?;
+        let blocks = blocks.await.map_err_to_code(
+            ErrorCode::TokioError,
+            || "Cannot join handle from context's runtime",
+        )??;
+
+        Ok(RunningQuery {
+            tag: CommandTag::create(&plan),
+            timezone: context.get_format_settings()?.timezone,
+            context: Some(context),
+            fields,
+            blocks: Some(blocks),
+            current: None,
+            sent_rows: 0,
+        })
+    }
+
+    /// Sends the rows of the query, at most `max_rows` rows if it is not zero.
+    /// Returns true if the query has more rows to send.
+    #[async_backtrace::framed]
+    async fn send_rows(&mut self, query: &mut RunningQuery, max_rows: usize) -> Result<bool> {
+        let blocks = match &mut query.blocks {
+            Some(blocks) => blocks,
+            None => return Ok(false),
+        };
+        let fields = match &query.fields {
+            Some(fields) => fields,
+            None => {
+                // statements without rows still need to be pulled to the end,
+                // errors may occur in the stream.
+                while let Some(block) = blocks.next().await {
+                    block?;
+                }
+                return Ok(false);
+            }
+        };
+
+        let encoder = FieldEncoderValues::create_for_postgres_handler(query.timezone);
+        let mut sent_rows = 0;
+        loop {
+            if let Some(current) = &mut query.current {
+                while current.next_row < current.num_rows {
+                    if max_rows > 0 && sent_rows == max_rows {
+                        return Ok(true);
+                    }
+                    let values = current
+                        .columns
+                        .iter()
+                        .zip(fields)
+                        .map(|(column, field)| {
+                            encode_value(column, current.next_row, field, &encoder)
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+                    self.writer.data_row(&values);
+                    current.next_row += 1;
+                    sent_rows += 1;
+                    query.sent_rows += 1;
+
+                    if self.writer.buffer().len() >= FLUSH_THRESHOLD {
+                        self.flush().await?;
+                    }
+                }
+            }
+
+            match blocks.next().await {
+                None => {
+                    query.current = None;
+                    return Ok(false);
+                }
+                Some(block) => {
+                    let block = block?;
+                    let num_rows = block.num_rows();
+                    let columns = block
+                        .convert_to_full()
+                        .columns()
+                        .iter()
+                        .map(|column| column.value.clone().into_column().unwrap())
+                        .collect();
+                    query.current = Some(CurrentBlock {
+                        columns,
+                        num_rows,
+                        next_row: 0,
+                    });
+                }
+            }
+        }
+    }
+
+    fn ready_for_query(&mut self) {
+        let status = match self.session.txn_mgr().lock().is_active() {
+            true => b'T',
+            false => b'I',
+        };
+        self.writer.ready_for_query(status);
+    }
+
+    fn send_error(&mut self, error: &ErrorCode) {
+        self.writer
+            .error_response(sqlstate(error), &error.message());
+    }
+
+    #[async_backtrace::framed]
+    async fn flush(&mut self) -> Result<()> {
+        self.socket.write_all(self.writer.buffer()).await?;
+        self.socket.flush().await?;
+        self.writer.clear();
+        Ok(())
+    }
+}
+
+fn portal_not_exists(name: &str) -> ErrorCode {
+    ErrorCode::BadArguments(format!("portal \"{}\" does not exist", name))
+}
+
+/// Splits the statements of a simple query by `;`, the statements can't be
+/// told apart if the query fails to tokenize, it's taken as a single statement then.
+fn split_statements(sql: &str) -> Vec<&str> {
+    let tokens = match tokenize_sql(sql) {
+        Ok(tokens) => tokens,
+        Err(_) => {
+            let sql = sql.trim().trim_end_matches(';').trim();
+            return if sql.is_empty() { vec![] } else { vec![sql] };
+        }
+    };
+
+    let mut statements = vec![];
+    let mut range: Option<(usize, usize)> = None;
+    for token in tokens {
+        match token.kind {
+            TokenKind::SemiColon | TokenKind::EOI => {
+                if let Some((start, end)) = range.take() {
+                    statements.push(&sql[start..end]);
+                }
+            }
+            _ => {
+                let start = range.map_or(token.span.start, |(start, _)| start);
+                range = Some((start, token.span.end));
+            }
+        }
+    }
+    statements
+}
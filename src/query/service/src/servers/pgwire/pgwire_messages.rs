@@ -0,0 +1,407 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Messages of the PostgreSQL frontend/backend protocol (version 3.0).
+//!
+//! https://www.postgresql.org/docs/current/protocol-message-formats.html
+
+use std::collections::HashMap;
+
+use common_base::base::tokio::io::AsyncRead;
+use common_base::base::tokio::io::AsyncReadExt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+pub const PROTOCOL_VERSION_3: i32 = 196608;
+const SSL_REQUEST_CODE: i32 = 80877103;
+const GSSENC_REQUEST_CODE: i32 = 80877104;
+const CANCEL_REQUEST_CODE: i32 = 80877102;
+
+// Large enough for any sane statement, guards against garbage length prefixes.
+const MAX_MESSAGE_LENGTH: usize = 256 * 1024 * 1024;
+
+/// Format code of parameters and result columns.
+pub const FORMAT_TEXT: i16 = 0;
+pub const FORMAT_BINARY: i16 = 1;
+
+/// The first message sent by the client, it is not prefixed with a message type.
+pub enum StartupMessage {
+    SslRequest,
+    GssEncRequest,
+    CancelRequest,
+    Startup {
+        protocol_version: i32,
+        parameters: HashMap<String, String>,
+    },
+}
+
+pub enum FrontendMessage {
+    Query(String),
+    Parse {
+        name: String,
+        query: String,
+        param_types: Vec<u32>,
+    },
+    Bind {
+        portal: String,
+        statement: String,
+        param_formats: Vec<i16>,
+        params: Vec<Option<Vec<u8>>>,
+        result_formats: Vec<i16>,
+    },
+    /// `b'S'` describes a prepared statement, `b'P'` describes a portal.
+    Describe {
+        kind: u8,
+        name: String,
+    },
+    Execute {
+        portal: String,
+        max_rows: i32,
+    },
+    Close {
+        kind: u8,
+        name: String,
+    },
+    Sync,
+    Flush,
+    Terminate,
+    Password(String),
+    Unsupported(u8),
+}
+
+#[async_backtrace::framed]
+pub async fn read_startup_message<R: AsyncRead + Unpin>(reader: &mut R) -> Result<StartupMessage> {
+    let len = reader.read_i32().await? as usize;
+    if !(8..=MAX_MESSAGE_LENGTH).contains(&len) {
+        return Err(ErrorCode::BadBytes(format!(
+            "invalid length of startup message: {}",
+            len
+        )));
+    }
+    let mut body = vec![0; len - 4];
+    reader.read_exact(&mut body).await?;
+
+    let mut body = MessageBody::new(&body);
+    let code = body.get_i32()?;
+    match code {
+        SSL_REQUEST_CODE => Ok(StartupMessage::SslRequest),
+        GSSENC_REQUEST_CODE => Ok(StartupMessage::GssEncRequest),
+        CANCEL_REQUEST_CODE => Ok(StartupMessage::CancelRequest),
+        protocol_version => {
+            let mut parameters = HashMap::new();
+            loop {
+                let name = body.get_cstr()?;
+                if name.is_empty() {
+                    break;
+                }
+                let value = body.get_cstr()?;
+                parameters.insert(name, value);
+            }
+            Ok(StartupMessage::Startup {
+                protocol_version,
+                parameters,
+            })
+        }
+    }
+}
+
+/// Reads a message of the client, returns `None` if the client closed the connection.
+#[async_backtrace::framed]
+pub async fn read_message<R: AsyncRead + Unpin>(reader: &mut R) -> Result<Option<FrontendMessage>> {
+    let tag = match reader.read_u8().await {
+        Ok(tag) => tag,
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let len = reader.read_i32().await? as usize;
+    if !(4..=MAX_MESSAGE_LENGTH).contains(&len) {
+        return Err(ErrorCode::BadBytes(format!(
+            "invalid length of message '{}': {}",
+            tag as char, len
+        )));
+    }
+    let mut body = vec![0; len - 4];
+    reader.read_exact(&mut body).await?;
+
+    let mut body = MessageBody::new(&body);
+    let message = match tag {
+        b'Q' => FrontendMessage::Query(body.get_cstr()?),
+        b'P' => {
+            let name = body.get_cstr()?;
+            let query = body.get_cstr()?;
+            let num_params = body.get_i16()?;
+            let mut param_types = Vec::with_capacity(num_params.max(0) as usize);
+            for _ in 0..num_params {
+                param_types.push(body.get_i32()? as u32);
+            }
+            FrontendMessage::Parse {
+                name,
+                query,
+                param_types,
+            }
+        }
+        b'B' => {
+            let portal = body.get_cstr()?;
+            let statement = body.get_cstr()?;
+            let param_formats = body.get_i16_list()?;
+            let num_params = body.get_i16()?;
+            let mut params = Vec::with_capacity(num_params.max(0) as usize);
+            for _ in 0..num_params {
+                let len = body.get_i32()?;
+                if len < 0 {
+                    params.push(None);
+                } else {
+                    params.push(Some(body.get_bytes(len as usize)?.to_vec()));
+                }
+            }
+            let result_formats = body.get_i16_list()?;
+            FrontendMessage::Bind {
+                portal,
+                statement,
+                param_formats,
+                params,
+                result_formats,
+            }
+        }
+        b'D' => FrontendMessage::Describe {
+            kind: body.get_u8()?,
+            name: body.get_cstr()?,
+        },
+        b'E' => FrontendMessage::Execute {
+            portal: body.get_cstr()?,
+            max_rows: body.get_i32()?,
+        },
+        b'C' => FrontendMessage::Close {
+            kind: body.get_u8()?,
+            name: body.get_cstr()?,
+        },
+        b'S' => FrontendMessage::Sync,
+        b'H' => FrontendMessage::Flush,
+        b'X' => FrontendMessage::Terminate,
+        b'p' => FrontendMessage::Password(body.get_cstr()?),
+        other => FrontendMessage::Unsupported(other),
+    };
+    Ok(Some(message))
+}
+
+struct MessageBody<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> MessageBody<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        MessageBody { buf, pos: 0 }
+    }
+
+    fn get_bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.pos + len > self.buf.len() {
+            return Err(ErrorCode::BadBytes("message is shorter than expected"));
+        }
+        let bytes = &self.buf[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn get_u8(&mut self) -> Result<u8> {
+        Ok(self.get_bytes(1)?[0])
+    }
+
+    fn get_i16(&mut self) -> Result<i16> {
+        let bytes = self.get_bytes(2)?;
+        Ok(i16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn get_i32(&mut self) -> Result<i32> {
+        let bytes = self.get_bytes(4)?;
+        Ok(i32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn get_i16_list(&mut self) -> Result<Vec<i16>> {
+        let len = self.get_i16()?;
+        let mut list = Vec::with_capacity(len.max(0) as usize);
+        for _ in 0..len {
+            list.push(self.get_i16()?);
+        }
+        Ok(list)
+    }
+
+    fn get_cstr(&mut self) -> Result<String> {
+        let rest = &self.buf[self.pos..];
+        match rest.iter().position(|b| *b == 0) {
+            Some(end) => {
+                self.pos += end + 1;
+                String::from_utf8(rest[..end].to_vec())
+                    .map_err(|e| ErrorCode::BadBytes(format!("invalid utf-8 string: {}", e)))
+            }
+            None => Err(ErrorCode::BadBytes("string is not null terminated")),
+        }
+    }
+}
+
+/// Buffers the messages sent to the client, they are written out to the
+/// socket when the connection flushes.
+#[derive(Default)]
+pub struct MessageWriter {
+    buf: Vec<u8>,
+}
+
+impl MessageWriter {
+    pub fn buffer(&self) -> &[u8] {
+        &self.buf
+    }
+
+    pub fn clear(&mut self) {
+        self.buf.clear();
+    }
+
+    fn message(&mut self, tag: u8, body: impl FnOnce(&mut Vec<u8>)) {
+        self.buf.push(tag);
+        let start = self.buf.len();
+        self.buf.extend_from_slice(&[0; 4]);
+        body(&mut self.buf);
+        let len = (self.buf.len() - start) as i32;
+        self.buf[start..start + 4].copy_from_slice(&len.to_be_bytes());
+    }
+
+    /// The answer to `SSLRequest` and `GSSENCRequest`, the only message without a header.
+    pub fn reject_encryption(&mut self) {
+        self.buf.push(b'N');
+    }
+
+    pub fn authentication_ok(&mut self) {
+        self.message(b'R', |buf| buf.extend_from_slice(&0i32.to_be_bytes()));
+    }
+
+    pub fn authentication_cleartext_password(&mut self) {
+        self.message(b'R', |buf| buf.extend_from_slice(&3i32.to_be_bytes()));
+    }
+
+    pub fn parameter_status(&mut self, name: &str, value: &str) {
+        self.message(b'S', |buf| {
+            put_cstr(buf, name);
+            put_cstr(buf, value);
+        });
+    }
+
+    pub fn backend_key_data(&mut self, process_id: i32, secret_key: i32) {
+        self.message(b'K', |buf| {
+            buf.extend_from_slice(&process_id.to_be_bytes());
+            buf.extend_from_slice(&secret_key.to_be_bytes());
+        });
+    }
+
+    /// `status` is `b'I'` if idle, `b'T'` if in a transaction block.
+    pub fn ready_for_query(&mut self, status: u8) {
+        self.message(b'Z', |buf| buf.push(status));
+    }
+
+    pub fn row_description(&mut self, fields: &[FieldDescription]) {
+        self.message(b'T', |buf| {
+            buf.extend_from_slice(&(fields.len() as i16).to_be_bytes());
+            for field in fields {
+                put_cstr(buf, &field.name);
+                // table oid and column attribute number
+                buf.extend_from_slice(&0i32.to_be_bytes());
+                buf.extend_from_slice(&0i16.to_be_bytes());
+                buf.extend_from_slice(&field.type_oid.to_be_bytes());
+                buf.extend_from_slice(&field.type_size.to_be_bytes());
+                // type modifier
+                buf.extend_from_slice(&(-1i32).to_be_bytes());
+                buf.extend_from_slice(&field.format.to_be_bytes());
+            }
+        });
+    }
+
+    pub fn parameter_description(&mut self, type_oids: &[u32]) {
+        self.message(b't', |buf| {
+            buf.extend_from_slice(&(type_oids.len() as i16).to_be_bytes());
+            for oid in type_oids {
+                buf.extend_from_slice(&oid.to_be_bytes());
+            }
+        });
+    }
+
+    /// Values are already encoded in the format of their columns, `None` is NULL.
+    pub fn data_row(&mut self, values: &[Option<Vec<u8>>]) {
+        self.message(b'D', |buf| {
+            buf.extend_from_slice(&(values.len() as i16).to_be_bytes());
+            for value in values {
+                match value {
+                    Some(value) => {
+                        buf.extend_from_slice(&(value.len() as i32).to_be_bytes());
+                        buf.extend_from_slice(value);
+                    }
+                    None => buf.extend_from_slice(&(-1i32).to_be_bytes()),
+                }
+            }
+        });
+    }
+
+    pub fn command_complete(&mut self, tag: &str) {
+        self.message(b'C', |buf| put_cstr(buf, tag));
+    }
+
+    pub fn empty_query_response(&mut self) {
+        self.message(b'I', |_| {});
+    }
+
+    pub fn parse_complete(&mut self) {
+        self.message(b'1', |_| {});
+    }
+
+    pub fn bind_complete(&mut self) {
+        self.message(b'2', |_| {});
+    }
+
+    pub fn close_complete(&mut self) {
+        self.message(b'3', |_| {});
+    }
+
+    pub fn no_data(&mut self) {
+        self.message(b'n', |_| {});
+    }
+
+    pub fn portal_suspended(&mut self) {
+        self.message(b's', |_| {});
+    }
+
+    pub fn error_response(&mut self, sqlstate: &str, message: &str) {
+        self.message(b'E', |buf| {
+            for (field, value) in [
+                (b'S', "ERROR"),
+                (b'V', "ERROR"),
+                (b'C', sqlstate),
+                (b'M', message),
+            ] {
+                buf.push(field);
+                put_cstr(buf, value);
+            }
+            buf.push(0);
+        });
+    }
+}
+
+pub struct FieldDescription {
+    pub name: String,
+    pub type_oid: u32,
+    pub type_size: i16,
+    pub format: i16,
+}
+
+fn put_cstr(buf: &mut Vec<u8>, s: &str) {
+    // null bytes can't be sent inside of a string
+    buf.extend(s.bytes().filter(|b| *b != 0));
+    buf.push(0);
+}
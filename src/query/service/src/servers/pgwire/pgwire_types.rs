@@ -0,0 +1,369 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::infer_schema_type;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::NumberDataType;
+use common_expression::Column;
+use common_expression::DataField;
+use common_expression::ScalarRef;
+use common_expression::TableDataType;
+use common_formats::field_encoder::FieldEncoderRowBased;
+use common_formats::field_encoder::FieldEncoderValues;
+
+use crate::servers::pgwire::pgwire_messages::FieldDescription;
+use crate::servers::pgwire::pgwire_messages::FORMAT_BINARY;
+use crate::servers::pgwire::pgwire_messages::FORMAT_TEXT;
+
+// Type OIDs of postgres, see `pg_type.dat` of postgres.
+pub const OID_UNSPECIFIED: u32 = 0;
+pub const OID_BOOL: u32 = 16;
+pub const OID_INT8: u32 = 20;
+pub const OID_INT2: u32 = 21;
+pub const OID_INT4: u32 = 23;
+pub const OID_TEXT: u32 = 25;
+pub const OID_JSON: u32 = 114;
+pub const OID_FLOAT4: u32 = 700;
+pub const OID_FLOAT8: u32 = 701;
+pub const OID_UNKNOWN: u32 = 705;
+pub const OID_VARCHAR: u32 = 1043;
+pub const OID_DATE: u32 = 1082;
+pub const OID_TIMESTAMP: u32 = 1114;
+pub const OID_NUMERIC: u32 = 1700;
+
+// postgres counts dates and timestamps from 2000-01-01.
+const PG_EPOCH_DAYS: i32 = 10957;
+const PG_EPOCH_MICROS: i64 = 946_684_800_000_000;
+
+/// Maps the type to the postgres type whose value range covers it, the unsigned
+/// integers are widened to the next signed integer type.
+pub fn type_oid(data_type: &TableDataType) -> u32 {
+    match data_type.remove_nullable() {
+        TableDataType::Null => OID_UNKNOWN,
+        TableDataType::Boolean => OID_BOOL,
+        TableDataType::String => OID_VARCHAR,
+        TableDataType::Number(num_ty) => match num_ty {
+            NumberDataType::Int8 | NumberDataType::UInt8 | NumberDataType::Int16 => OID_INT2,
+            NumberDataType::UInt16 | NumberDataType::Int32 => OID_INT4,
+            NumberDataType::UInt32 | NumberDataType::Int64 => OID_INT8,
+            NumberDataType::UInt64 => OID_NUMERIC,
+            NumberDataType::Float32 => OID_FLOAT4,
+            NumberDataType::Float64 => OID_FLOAT8,
+        },
+        TableDataType::Decimal(_) => OID_NUMERIC,
+        TableDataType::Date => OID_DATE,
+        TableDataType::Timestamp => OID_TIMESTAMP,
+        TableDataType::Variant => OID_JSON,
+        // arrays, maps, tuples and the others are sent as their text form.
+        _ => OID_TEXT,
+    }
+}
+
+fn type_size(type_oid: u32) -> i16 {
+    match type_oid {
+        OID_BOOL => 1,
+        OID_INT2 => 2,
+        OID_INT4 | OID_FLOAT4 | OID_DATE => 4,
+        OID_INT8 | OID_FLOAT8 | OID_TIMESTAMP => 8,
+        _ => -1,
+    }
+}
+
+/// `formats` follows the rule of the `Bind` message: empty means all text, a
+/// single one applies to all the columns.
+pub fn field_descriptions(fields: &[DataField], formats: &[i16]) -> Result<Vec<FieldDescription>> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let type_oid = type_oid(&infer_schema_type(field.data_type())?);
+            Ok(FieldDescription {
+                name: field.name().to_string(),
+                type_oid,
+                type_size: type_size(type_oid),
+                format: column_format(formats, i)?,
+            })
+        })
+        .collect()
+}
+
+pub fn column_format(formats: &[i16], index: usize) -> Result<i16> {
+    let format = match formats.len() {
+        0 => FORMAT_TEXT,
+        1 => formats[0],
+        _ => *formats.get(index).ok_or_else(|| {
+            ErrorCode::BadArguments(format!("format code at {} is missing", index))
+        })?,
+    };
+    match format {
+        FORMAT_TEXT | FORMAT_BINARY => Ok(format),
+        other => Err(ErrorCode::BadArguments(format!(
+            "unknown format code: {}",
+            other
+        ))),
+    }
+}
+
+/// Encodes the value at `row_index`, returns `None` for NULL.
+pub fn encode_value(
+    column: &Column,
+    row_index: usize,
+    field: &FieldDescription,
+    encoder: &FieldEncoderValues,
+) -> Result<Option<Vec<u8>>> {
+    let value = unsafe { column.index_unchecked(row_index) };
+    if let ScalarRef::Null = value {
+        return Ok(None);
+    }
+    if field.format == FORMAT_BINARY {
+        return encode_binary(value, field.type_oid).map(Some);
+    }
+    match value {
+        ScalarRef::Bitmap(_) => Ok(Some(b"<bitmap binary>".to_vec())),
+        _ => {
+            let mut buf = Vec::new();
+            encoder.write_field(column, row_index, &mut buf, true);
+            Ok(Some(buf))
+        }
+    }
+}
+
+fn encode_binary(value: ScalarRef, type_oid: u32) -> Result<Vec<u8>> {
+    let bytes = match (value, type_oid) {
+        (ScalarRef::Boolean(v), OID_BOOL) => vec![v as u8],
+        (ScalarRef::Number(num), OID_INT2 | OID_INT4 | OID_INT8) => {
+            let v = match num {
+                NumberScalar::Int8(v) => v as i64,
+                NumberScalar::Int16(v) => v as i64,
+                NumberScalar::Int32(v) => v as i64,
+                NumberScalar::Int64(v) => v,
+                NumberScalar::UInt8(v) => v as i64,
+                NumberScalar::UInt16(v) => v as i64,
+                NumberScalar::UInt32(v) => v as i64,
+                _ => return Err(binary_unsupported(type_oid)),
+            };
+            match type_oid {
+                OID_INT2 => (v as i16).to_be_bytes().to_vec(),
+                OID_INT4 => (v as i32).to_be_bytes().to_vec(),
+                _ => v.to_be_bytes().to_vec(),
+            }
+        }
+        (ScalarRef::Number(NumberScalar::Float32(v)), OID_FLOAT4) => v.0.to_be_bytes().to_vec(),
+        (ScalarRef::Number(NumberScalar::Float64(v)), OID_FLOAT8) => v.0.to_be_bytes().to_vec(),
+        (ScalarRef::Date(v), OID_DATE) => (v - PG_EPOCH_DAYS).to_be_bytes().to_vec(),
+        (ScalarRef::Timestamp(v), OID_TIMESTAMP) => (v - PG_EPOCH_MICROS).to_be_bytes().to_vec(),
+        // the binary format of text is the text itself.
+        (ScalarRef::String(v), OID_VARCHAR | OID_TEXT) => v.to_vec(),
+        _ => return Err(binary_unsupported(type_oid)),
+    };
+    Ok(bytes)
+}
+
+fn binary_unsupported(type_oid: u32) -> ErrorCode {
+    ErrorCode::Unimplemented(format!(
+        "binary format of type oid {} is not supported, use text format instead",
+        type_oid
+    ))
+}
+
+/// Converts the value of a bound parameter into a SQL literal.
+pub fn param_literal(value: Option<&[u8]>, format: i16, type_oid: u32) -> Result<String> {
+    let value = match value {
+        None => return Ok("NULL".to_string()),
+        Some(value) => value,
+    };
+
+    if format == FORMAT_BINARY {
+        let literal = match (type_oid, value.len()) {
+            (OID_BOOL, 1) => (value[0] != 0).to_string().to_uppercase(),
+            (OID_INT2, 2) => i16::from_be_bytes([value[0], value[1]]).to_string(),
+            (OID_INT4, 4) => i32::from_be_bytes(value.try_into().unwrap()).to_string(),
+            (OID_INT8, 8) => i64::from_be_bytes(value.try_into().unwrap()).to_string(),
+            (OID_FLOAT4, 4) => float_literal(f32::from_be_bytes(value.try_into().unwrap()) as f64),
+            (OID_FLOAT8, 8) => float_literal(f64::from_be_bytes(value.try_into().unwrap())),
+            (OID_TEXT | OID_VARCHAR | OID_UNKNOWN | OID_UNSPECIFIED, _) => {
+                quote_string(&text_param(value)?)
+            }
+            _ => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "invalid binary parameter of type oid {}",
+                    type_oid
+                )));
+            }
+        };
+        return Ok(literal);
+    }
+
+    let text = text_param(value)?;
+    match type_oid {
+        OID_INT2 | OID_INT4 | OID_INT8 | OID_FLOAT4 | OID_FLOAT8 | OID_NUMERIC => {
+            // numbers are inlined as they are, make sure nothing else sneaks in.
+            let trimmed = text.trim();
+            if trimmed.parse::<f64>().is_err() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "invalid number parameter: {}",
+                    text
+                )));
+            }
+            if trimmed
+                .chars()
+                .all(|c| c.is_ascii_digit() || "+-.eE".contains(c))
+            {
+                Ok(trimmed.to_string())
+            } else {
+                // NaN and Infinity
+                Ok(format!("{}::DOUBLE", quote_string(trimmed)))
+            }
+        }
+        OID_BOOL => match text.trim().to_lowercase().as_str() {
+            "t" | "true" | "y" | "yes" | "on" | "1" => Ok("TRUE".to_string()),
+            "f" | "false" | "n" | "no" | "off" | "0" => Ok("FALSE".to_string()),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "invalid boolean parameter: {}",
+                text
+            ))),
+        },
+        OID_DATE => Ok(format!("{}::DATE", quote_string(&text))),
+        OID_TIMESTAMP => Ok(format!("{}::TIMESTAMP", quote_string(&text))),
+        _ => Ok(quote_string(&text)),
+    }
+}
+
+fn text_param(value: &[u8]) -> Result<String> {
+    String::from_utf8(value.to_vec())
+        .map_err(|e| ErrorCode::BadArguments(format!("parameter is not valid utf-8: {}", e)))
+}
+
+fn float_literal(v: f64) -> String {
+    if v.is_finite() {
+        v.to_string()
+    } else {
+        format!("'{}'::DOUBLE", v)
+    }
+}
+
+fn quote_string(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('\'');
+    for c in s.chars() {
+        match c {
+            '\'' => quoted.push_str("''"),
+            '\\' => quoted.push_str("\\\\"),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('\'');
+    quoted
+}
+
+/// Finds the `$<n>` placeholders outside of literals, identifiers and comments,
+/// returns the byte range and the 1-based index of each.
+pub fn placeholders(sql: &str) -> Vec<(std::ops::Range<usize>, usize)> {
+    let bytes = sql.as_bytes();
+    let mut found = vec![];
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            quote @ (b'\'' | b'"' | b'`') => {
+                i += 1;
+                while i < bytes.len() {
+                    if bytes[i] == b'\\' && quote == b'\'' {
+                        i += 2;
+                        continue;
+                    }
+                    if bytes[i] == quote {
+                        // doubled quote is an escaped quote
+                        if bytes.get(i + 1) == Some(&quote) {
+                            i += 2;
+                            continue;
+                        }
+                        break;
+                    }
+                    i += 1;
+                }
+                i += 1;
+            }
+            b'-' if bytes.get(i + 1) == Some(&b'-') => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i += 2;
+                while i < bytes.len() && !(bytes[i] == b'*' && bytes.get(i + 1) == Some(&b'/')) {
+                    i += 1;
+                }
+                i += 2;
+            }
+            b'$' => {
+                let start = i;
+                i += 1;
+                while i < bytes.len() && bytes[i].is_ascii_digit() {
+                    i += 1;
+                }
+                if i > start + 1 {
+                    if let Ok(index) = sql[start + 1..i].parse::<usize>() {
+                        found.push((start..i, index));
+                    }
+                }
+            }
+            _ => i += 1,
+        }
+    }
+    found
+}
+
+/// Replaces the `$<n>` placeholders with the literals of the parameters.
+pub fn bind_params(sql: &str, literals: &[String]) -> Result<String> {
+    let mut bound = String::with_capacity(sql.len());
+    let mut last = 0;
+    for (range, index) in placeholders(sql) {
+        let literal = literals.get(index.wrapping_sub(1)).ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "parameter ${} is not bound, {} parameters are given",
+                index,
+                literals.len()
+            ))
+        })?;
+        bound.push_str(&sql[last..range.start]);
+        bound.push_str(literal);
+        last = range.end;
+    }
+    bound.push_str(&sql[last..]);
+    Ok(bound)
+}
+
+/// Maps the error to the SQLSTATE code postgres clients understand.
+pub fn sqlstate(error: &ErrorCode) -> &'static str {
+    match error.code() {
+        ErrorCode::SYNTAX_EXCEPTION => "42601",
+        ErrorCode::SEMANTIC_ERROR => "42000",
+        ErrorCode::UNKNOWN_TABLE => "42P01",
+        ErrorCode::UNKNOWN_COLUMN => "42703",
+        ErrorCode::UNKNOWN_FUNCTION => "42883",
+        ErrorCode::UNKNOWN_DATABASE => "3D000",
+        ErrorCode::TABLE_ALREADY_EXISTS => "42P07",
+        ErrorCode::DATABASE_ALREADY_EXISTS => "42P04",
+        ErrorCode::AUTHENTICATE_FAILURE | ErrorCode::UNKNOWN_USER => "28P01",
+        ErrorCode::PERMISSION_DENIED => "42501",
+        ErrorCode::BAD_ARGUMENTS => "22023",
+        ErrorCode::UNIMPLEMENTED => "0A000",
+        ErrorCode::ABORTED_QUERY => "57014",
+        ErrorCode::ABORTED_SESSION => "57P01",
+        ErrorCode::BAD_BYTES => "08P01",
+        _ => "XX000",
+    }
+}
@@ -22,6 +22,7 @@ mod session_mgr;
 mod session_mgr_status;
 mod session_status;
 mod session_type;
+mod workload_mgr;
 
 pub use common_catalog::table_context::TableContext;
 pub use query_affect::QueryAffect;
@@ -35,3 +36,5 @@ pub use session_mgr::SessionManager;
 pub use session_mgr_status::SessionManagerStatus;
 pub use session_status::SessionStatus;
 pub use session_type::SessionType;
+pub use workload_mgr::WorkloadManager;
+pub use workload_mgr::WorkloadPermit;
@@ -35,12 +35,16 @@ use common_catalog::plan::PartInfoPtr;
 use common_catalog::plan::Partitions;
 use common_catalog::plan::StageTableInfo;
 use common_catalog::table_args::TableArgs;
+use common_catalog::table_context::NodeProcessInfo;
 use common_catalog::table_context::StageAttachment;
+use common_catalog::table_context::WorkloadQueryInfo;
+use common_catalog::txn::TxnManagerRef;
 use common_config::GlobalConfig;
 use common_config::DATABEND_COMMIT_VERSION;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::date_helper::TzFactory;
+use common_expression::Expr;
 use common_expression::FunctionContext;
 use common_io::prelude::FormatSettings;
 use common_meta_app::principal::FileFormatParams;
@@ -66,10 +70,14 @@ use dashmap::mapref::multiple::RefMulti;
 use dashmap::DashMap;
 use parking_lot::RwLock;
 use tracing::debug;
+use tracing::warn;
+use tracing::Instrument;
+use tracing::Span;
 
 use crate::api::DataExchangeManager;
 use crate::catalogs::Catalog;
 use crate::clusters::Cluster;
+use crate::clusters::ClusterHelper;
 use crate::pipelines::executor::PipelineExecutor;
 use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::ProcessInfo;
@@ -77,6 +85,7 @@ use crate::sessions::QueryContextShared;
 use crate::sessions::Session;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionType;
+use crate::sessions::WorkloadManager;
 use crate::storages::Table;
 
 const MYSQL_VERSION: &str = "8.0.26";
@@ -191,6 +200,57 @@ impl QueryContext {
         SessionManager::instance().processes_info()
     }
 
+    /// Wait until the workload group of the current user admits the query.
+    ///
+    /// The group is taken from the settings of the user in meta, then the ones of the
+    /// current role, never from the session which the user can change at will.
+    ///
+    /// Returns false if no permit is taken, that is the user has no workload group or
+    /// the query holds the permit already (e.g. an interpreter run by another one).
+    #[async_backtrace::framed]
+    pub async fn acquire_workload_permit(&self) -> Result<bool> {
+        if self.shared.workload_permit.lock().is_some() {
+            return Ok(false);
+        }
+        if !self.get_current_session().get_type().is_user_session() {
+            return Ok(false);
+        }
+        let user = self.get_current_user()?;
+        let group = match user.option.settings().get(WORKLOAD_GROUP_SETTING) {
+            Some(group) => group.clone(),
+            None => self
+                .get_current_role()
+                .and_then(|role| role.settings.get(WORKLOAD_GROUP_SETTING).cloned())
+                .unwrap_or_default(),
+        };
+        if group.is_empty() {
+            return Ok(false);
+        }
+        let permit = WorkloadManager::instance()
+            .acquire(
+                &group,
+                &self.shared,
+                &self.get_id(),
+                user.identity().to_string(),
+            )
+            .await?;
+        *self.shared.workload_permit.lock() = Some(permit);
+        Ok(true)
+    }
+
+    pub fn release_workload_permit(&self) {
+        self.shared.workload_permit.lock().take();
+    }
+
+    pub fn get_query_span(&self) -> Span {
+        self.shared.query_span.read().clone()
+    }
+
+    /// Set the span of the query, its parent may come from the client or the coordinator node.
+    pub fn set_query_span(&self, span: Span) {
+        *self.shared.query_span.write() = span;
+    }
+
     /// Get the client socket address.
     pub fn get_client_address(&self) -> Option<SocketAddr> {
         self.shared.session.session_ctx.get_client_host()
@@ -331,6 +391,22 @@ impl TableContext for QueryContext {
         sha
     }
 
+    fn set_runtime_filter(&self, filters: (usize, Vec<Expr<String>>)) {
+        let mut runtime_filters = self.shared.runtime_filters.write();
+        runtime_filters
+            .entry(filters.0)
+            .or_default()
+            .extend(filters.1);
+    }
+
+    fn get_runtime_filters(&self, table_index: usize) -> Vec<Expr<String>> {
+        let runtime_filters = self.shared.runtime_filters.read();
+        runtime_filters
+            .get(&table_index)
+            .cloned()
+            .unwrap_or_default()
+    }
+
     fn get_cacheable(&self) -> bool {
         self.shared.cacheable.load(Ordering::Acquire)
     }
@@ -386,6 +462,11 @@ impl TableContext for QueryContext {
         self.shared.get_current_role()
     }
 
+    #[async_backtrace::framed]
+    async fn get_all_effective_roles(&self) -> Result<Vec<RoleInfo>> {
+        self.get_current_session().get_all_effective_roles().await
+    }
+
     fn get_fuse_version(&self) -> String {
         let session = self.get_current_session();
         match session.get_type() {
@@ -457,6 +538,10 @@ impl TableContext for QueryContext {
         SessionManager::instance().processes_info()
     }
 
+    fn get_workload_queries_info(&self) -> Vec<WorkloadQueryInfo> {
+        WorkloadManager::instance().queries_info()
+    }
+
     // Get Stage Attachment.
     fn get_stage_attachment(&self) -> Option<StageAttachment> {
         self.shared.get_stage_attachment()
@@ -531,6 +616,10 @@ impl TableContext for QueryContext {
         self.query_settings.get_changes()
     }
 
+    fn txn_mgr(&self) -> TxnManagerRef {
+        self.shared.session.txn_mgr()
+    }
+
     fn get_query_profile_manager(&self) -> Arc<QueryProfileManager> {
         self.shared.get_query_profile_manager()
     }
@@ -540,6 +629,40 @@ impl TableContext for QueryContext {
         Ok(self.shared.data_operator.clone())
     }
 
+    #[async_backtrace::framed]
+    async fn get_cluster_processes_info(&self) -> Result<Vec<NodeProcessInfo>> {
+        let cluster = self.get_cluster();
+        let config = GlobalConfig::instance();
+        let timeout = self.get_settings().get_flight_client_timeout()?;
+        let remote_nodes = cluster
+            .get_nodes()
+            .into_iter()
+            .filter(|node| !cluster.is_local(node))
+            .collect::<Vec<_>>();
+        let remote_processes_info = remote_nodes.iter().map(|node| {
+            let (cluster, config) = (cluster.clone(), config.clone());
+            async move {
+                let mut conn = cluster.create_node_conn(&node.id, &config).await?;
+                conn.get_processes_info(&node.id, timeout).await
+            }
+        });
+
+        let mut processes_info =
+            SessionManager::instance().node_processes_info(&cluster.local_id());
+        let remote_processes_info = futures::future::join_all(remote_processes_info).await;
+        for (node, node_processes_info) in remote_nodes.iter().zip(remote_processes_info) {
+            // the node may be leaving the cluster, list the processes of the others.
+            match node_processes_info {
+                Ok(node_processes_info) => processes_info.extend(node_processes_info),
+                Err(cause) => warn!(
+                    "Cannot get the processes of node {}, cause {:?}",
+                    node.id, cause
+                ),
+            }
+        }
+        Ok(processes_info)
+    }
+
     #[async_backtrace::framed]
     async fn get_file_format(&self, name: &str) -> Result<FileFormatParams> {
         match StageFileFormatType::from_str(name) {
@@ -643,6 +766,7 @@ impl TrySpawn for QueryContext {
         T: Future + Send + 'static,
         T::Output: Send + 'static,
     {
+        let task = task.instrument(self.get_query_span());
         Ok(self.shared.try_get_runtime()?.spawn(task))
     }
 }
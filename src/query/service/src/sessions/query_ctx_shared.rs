@@ -25,6 +25,7 @@ use common_base::runtime::Runtime;
 use common_catalog::table_context::StageAttachment;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::Expr;
 use common_meta_app::principal::OnErrorMode;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
@@ -37,6 +38,7 @@ use common_storage::StorageMetrics;
 use dashmap::DashMap;
 use parking_lot::Mutex;
 use parking_lot::RwLock;
+use tracing::Span;
 use uuid::Uuid;
 
 use crate::catalogs::CatalogManager;
@@ -44,6 +46,7 @@ use crate::clusters::Cluster;
 use crate::pipelines::executor::PipelineExecutor;
 use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::Session;
+use crate::sessions::WorkloadPermit;
 use crate::storages::Table;
 
 type DatabaseAndTable = (String, String, String);
@@ -81,11 +84,17 @@ pub struct QueryContextShared {
     pub(in crate::sessions) on_error_mode: Arc<RwLock<Option<OnErrorMode>>>,
     /// partitions_sha for each table in the query. Not empty only when enabling query result cache.
     pub(in crate::sessions) partitions_shas: Arc<RwLock<Vec<String>>>,
+    /// Runtime filters pushed down from hash joins, keyed by the table index of the probe side scan.
+    pub(in crate::sessions) runtime_filters: Arc<RwLock<HashMap<usize, Vec<Expr<String>>>>>,
     pub(in crate::sessions) cacheable: Arc<AtomicBool>,
     // Status info.
     pub(in crate::sessions) status: Arc<RwLock<String>>,
     /// Query profile manager
     pub(in crate::sessions) profile_mgr: Arc<QueryProfileManager>,
+    /// The slot of the query in the workload group of the session.
+    pub(in crate::sessions) workload_permit: Arc<Mutex<Option<WorkloadPermit>>>,
+    /// The span of the query, the tasks spawned by the query run in it.
+    pub(in crate::sessions) query_span: Arc<RwLock<Span>>,
 }
 
 impl QueryContextShared {
@@ -116,9 +125,12 @@ impl QueryContextShared {
             on_error_map: Arc::new(RwLock::new(None)),
             on_error_mode: Arc::new(RwLock::new(None)),
             partitions_shas: Arc::new(RwLock::new(vec![])),
+            runtime_filters: Arc::new(RwLock::new(HashMap::new())),
             cacheable: Arc::new(AtomicBool::new(true)),
             status: Arc::new(RwLock::new("null".to_string())),
             profile_mgr: QueryProfileManager::instance(),
+            workload_permit: Arc::new(Mutex::new(None)),
+            query_span: Arc::new(RwLock::new(Span::none())),
         }))
     }
 
@@ -266,7 +278,16 @@ impl QueryContextShared {
         let tenant = self.get_tenant();
         let table_meta_key = (catalog.to_string(), database.to_string(), table.to_string());
         let catalog = self.catalog_manager.get_catalog(catalog)?;
-        let cache_table = catalog.get_table(tenant.as_str(), database, table).await?;
+        let mut cache_table = catalog.get_table(tenant.as_str(), database, table).await?;
+        // the table mutated inside the transaction is read with the mutations applied.
+        let buffered = self
+            .session
+            .txn_mgr()
+            .lock()
+            .get_table_from_buffer(cache_table.get_id());
+        if let Some(table_info) = buffered {
+            cache_table = catalog.get_table_by_info(&table_info)?;
+        }
 
         let mut tables_refs = self.tables_refs.lock();
 
@@ -16,6 +16,7 @@ use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::sync::Arc;
 
+use common_catalog::txn::TxnManagerRef;
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -25,6 +26,7 @@ use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
 use common_meta_app::principal::UserPrivilegeType;
 use common_settings::ChangeValue;
+use common_settings::ScopeLevel;
 use common_settings::Settings;
 use common_users::RoleCacheManager;
 use common_users::BUILTIN_ROLE_PUBLIC;
@@ -193,7 +195,17 @@ impl Session {
         self.session_ctx.set_current_user(user);
         self.session_ctx.set_auth_role(auth_role);
         self.ensure_current_role().await?;
-        Ok(())
+        self.apply_profile_settings()
+    }
+
+    // The settings attached to the current role and the user, the user's take precedence.
+    fn apply_profile_settings(self: &Arc<Self>) -> Result<()> {
+        let settings = self.get_settings();
+        if let Some(role) = self.get_current_role() {
+            settings.apply_profile_settings(&role.settings, ScopeLevel::Role)?;
+        }
+        let user = self.get_current_user()?;
+        settings.apply_profile_settings(user.option.settings(), ScopeLevel::User)
     }
 
     // ensure_current_role() is called after authentication and before any privilege checks
@@ -296,6 +308,25 @@ impl Session {
         Ok(related_roles)
     }
 
+    // Returns the current role and all the roles granted to it, only the column privileges
+    // of the roles granted to the current role are available to the current session.
+    #[async_backtrace::framed]
+    pub async fn get_all_effective_roles(self: &Arc<Self>) -> Result<Vec<RoleInfo>> {
+        self.ensure_current_role().await?;
+        let current_role = match self.get_current_role() {
+            Some(role) => role,
+            None => return Ok(vec![]),
+        };
+        let tenant = self.get_current_tenant();
+        let mut roles = RoleCacheManager::instance()
+            .find_related_roles(&tenant, &[current_role.name.clone()])
+            .await?;
+        if !roles.iter().any(|r| r.name == current_role.name) {
+            roles.push(current_role);
+        }
+        Ok(roles)
+    }
+
     #[async_backtrace::framed]
     pub async fn validate_privilege(
         self: &Arc<Self>,
@@ -344,6 +375,10 @@ impl Session {
         self.session_ctx.get_changed_settings()
     }
 
+    pub fn txn_mgr(&self) -> TxnManagerRef {
+        self.session_ctx.txn_mgr()
+    }
+
     pub fn apply_changed_settings(&self, changes: HashMap<String, ChangeValue>) -> Result<()> {
         self.session_ctx.apply_changed_settings(changes)
     }
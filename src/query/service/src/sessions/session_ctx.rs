@@ -20,6 +20,8 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Weak;
 
+use common_catalog::txn::TxnManager;
+use common_catalog::txn::TxnManagerRef;
 use common_config::GlobalConfig;
 use common_exception::Result;
 use common_meta_app::principal::RoleInfo;
@@ -58,6 +60,8 @@ pub struct SessionContext {
     // query result through previous query_id easily.
     query_ids_results: RwLock<Vec<(String, Option<String>)>>,
     typ: SessionType,
+    // The explicit transaction of the session, started by `BEGIN`.
+    txn_mgr: TxnManagerRef,
 }
 
 impl SessionContext {
@@ -76,6 +80,7 @@ impl SessionContext {
             query_context_shared: Default::default(),
             query_ids_results: Default::default(),
             typ,
+            txn_mgr: TxnManager::init(),
         }))
     }
 
@@ -97,6 +102,10 @@ impl SessionContext {
         self.settings.get_changes()
     }
 
+    pub fn txn_mgr(&self) -> TxnManagerRef {
+        self.txn_mgr.clone()
+    }
+
     pub fn apply_changed_settings(&self, changes: HashMap<String, ChangeValue>) -> Result<()> {
         unsafe {
             self.settings.unchecked_apply_changes(changes);
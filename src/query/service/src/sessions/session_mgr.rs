@@ -24,6 +24,7 @@ use std::time::Duration;
 use common_base::base::tokio;
 use common_base::base::GlobalInstance;
 use common_base::base::SignalStream;
+use common_catalog::table_context::NodeProcessInfo;
 use common_config::GlobalConfig;
 use common_config::InnerConfig;
 use common_exception::ErrorCode;
@@ -164,6 +165,24 @@ impl SessionManager {
         sessions.get(id).and_then(|weak_ptr| weak_ptr.upgrade())
     }
 
+    /// Kill the running query of the session, or the session itself with `kill_connection`.
+    /// Returns false if the session is not on this node.
+    pub fn kill_session(&self, id: &str, kill_connection: bool) -> bool {
+        match self.get_session_by_id(id) {
+            None => false,
+            Some(kill_session) if kill_connection => {
+                kill_session.force_kill_session();
+                true
+            }
+            Some(kill_session) => {
+                kill_session.force_kill_query(ErrorCode::AbortedQuery(
+                    "Aborted query, because the server is shutting down or the query was killed",
+                ));
+                true
+            }
+        }
+    }
+
     pub fn get_id_by_mysql_conn_id(&self, mysql_conn_id: &Option<u32>) -> Option<String> {
         let sessions = self.mysql_conn_map.read();
         sessions.get(mysql_conn_id).cloned()
@@ -257,6 +276,43 @@ impl SessionManager {
             .collect::<Vec<_>>()
     }
 
+    /// The number of the sessions running a query, or the fragments of a query of
+    /// another node.
+    pub fn running_queries_count(&self) -> usize {
+        self.running_query_sessions().len()
+    }
+
+    /// Kill the running queries, the node is leaving the cluster.
+    pub fn kill_running_queries(&self) {
+        for session in self.running_query_sessions() {
+            session.force_kill_query(ErrorCode::AbortedQuery(
+                "Aborted query, because the node is drained from the cluster",
+            ));
+        }
+    }
+
+    fn running_query_sessions(&self) -> Vec<Arc<Session>> {
+        // Drop the read lock before upgrading the sessions, the same as `processes_info`.
+        let active_sessions = {
+            let active_sessions_guard = self.active_sessions.read();
+            active_sessions_guard.values().cloned().collect::<Vec<_>>()
+        };
+
+        active_sessions
+            .into_iter()
+            .filter_map(|weak_ptr| weak_ptr.upgrade())
+            .filter(|session| session.session_ctx.get_query_context_shared().is_some())
+            .collect()
+    }
+
+    /// The processes of this node, as listed in the processes of the cluster.
+    pub fn node_processes_info(&self, node: &str) -> Vec<NodeProcessInfo> {
+        self.processes_info()
+            .iter()
+            .map(|info| NodeProcessInfo::create(node, info))
+            .collect()
+    }
+
     fn destroy_idle_sessions(sessions: &Arc<RwLock<HashMap<String, Weak<Session>>>>) -> bool {
         // Read lock does not support reentrant
         // https://github.com/Amanieu/parking_lot::/blob/lock_api-0.4.4/lock_api/src/rwlock.rs#L422
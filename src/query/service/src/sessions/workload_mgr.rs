@@ -0,0 +1,238 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Weak;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_base::base::tokio;
+use common_base::base::tokio::sync::Notify;
+use common_base::base::GlobalInstance;
+use common_catalog::table_context::WorkloadQueryInfo;
+use common_config::InnerConfig;
+use common_config::WorkloadGroup;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use parking_lot::Mutex;
+
+use crate::sessions::QueryContextShared;
+
+/// How often a queued query checks if it's killed or the memory of the group is released.
+const QUEUE_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum WorkloadQueryState {
+    Queued,
+    Running,
+}
+
+struct WorkloadQuery {
+    query_id: String,
+    user: String,
+    query_text: String,
+    state: WorkloadQueryState,
+    enqueue_time: Instant,
+    admit_time: Option<Instant>,
+    shared: Weak<QueryContextShared>,
+}
+
+/// Must not be called with the queries of the group locked: the upgraded context may be
+/// the last reference, dropping it releases the permit of the query.
+fn memory_usage(shared: &Weak<QueryContextShared>) -> i64 {
+    match shared.upgrade().and_then(|shared| shared.get_runtime()) {
+        Some(runtime) => runtime.get_tracker().get_memory_usage(),
+        None => 0,
+    }
+}
+
+struct WorkloadGroupState {
+    conf: WorkloadGroup,
+    /// Queries in the order they arrived, queued queries are admitted first in first out.
+    queries: Mutex<Vec<WorkloadQuery>>,
+    notify: Notify,
+}
+
+impl WorkloadGroupState {
+    /// Admit the query if it's the oldest queued one and the group has room for it.
+    fn try_admit(&self, query_id: &str) -> bool {
+        if self.conf.memory_quota > 0 {
+            let running: Vec<Weak<QueryContextShared>> = {
+                let queries = self.queries.lock();
+                queries
+                    .iter()
+                    .filter(|q| q.state == WorkloadQueryState::Running)
+                    .map(|q| q.shared.clone())
+                    .collect()
+            };
+            let used: i64 = running.iter().map(memory_usage).sum();
+            if used >= self.conf.memory_quota as i64 {
+                return false;
+            }
+        }
+
+        let mut queries = self.queries.lock();
+        let running = queries
+            .iter()
+            .filter(|q| q.state == WorkloadQueryState::Running)
+            .count() as u64;
+        if running >= self.conf.max_concurrency {
+            return false;
+        }
+        match queries
+            .iter_mut()
+            .find(|q| q.state == WorkloadQueryState::Queued)
+        {
+            Some(first) if first.query_id == query_id => {
+                first.state = WorkloadQueryState::Running;
+                first.admit_time = Some(Instant::now());
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn remove(&self, query_id: &str) {
+        self.queries.lock().retain(|q| q.query_id != query_id);
+        self.notify.notify_waiters();
+    }
+}
+
+/// Releases the slot of the query in its workload group when dropped.
+pub struct WorkloadPermit {
+    group: Arc<WorkloadGroupState>,
+    query_id: String,
+}
+
+impl Drop for WorkloadPermit {
+    fn drop(&mut self) {
+        self.group.remove(&self.query_id);
+    }
+}
+
+/// Admission control of the queries by workload groups.
+///
+/// A group runs at most `max_concurrency` queries at the same time, and stops admitting
+/// queries while its running queries use more memory than `memory_quota`. The other
+/// queries wait in the queue of the group instead of failing.
+pub struct WorkloadManager {
+    groups: HashMap<String, Arc<WorkloadGroupState>>,
+}
+
+impl WorkloadManager {
+    pub fn init(conf: &InnerConfig) -> Result<()> {
+        let groups = conf
+            .query
+            .workload_groups
+            .iter()
+            .map(|group| {
+                (
+                    group.name.clone(),
+                    Arc::new(WorkloadGroupState {
+                        conf: group.clone(),
+                        queries: Mutex::new(vec![]),
+                        notify: Notify::new(),
+                    }),
+                )
+            })
+            .collect();
+        GlobalInstance::set(Arc::new(WorkloadManager { groups }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<WorkloadManager> {
+        GlobalInstance::get()
+    }
+
+    /// Wait until the query is admitted by the workload group.
+    #[async_backtrace::framed]
+    pub(in crate::sessions) async fn acquire(
+        &self,
+        group_name: &str,
+        shared: &Arc<QueryContextShared>,
+        query_id: &str,
+        user: String,
+    ) -> Result<WorkloadPermit> {
+        let group = self.groups.get(group_name).cloned().ok_or_else(|| {
+            ErrorCode::BadArguments(format!("unknown workload group '{}'", group_name))
+        })?;
+
+        let enqueue_time = Instant::now();
+        group.queries.lock().push(WorkloadQuery {
+            query_id: query_id.to_string(),
+            user,
+            query_text: shared.get_query_str(),
+            state: WorkloadQueryState::Queued,
+            enqueue_time,
+            admit_time: None,
+            shared: Arc::downgrade(shared),
+        });
+        let permit = WorkloadPermit {
+            group: group.clone(),
+            query_id: query_id.to_string(),
+        };
+
+        loop {
+            // register before checking, not to miss the notification in between.
+            let notified = group.notify.notified();
+            if group.try_admit(query_id) {
+                return Ok(permit);
+            }
+            shared.check_aborting()?;
+            let timeout = group.conf.queue_timeout_secs;
+            if timeout > 0 && enqueue_time.elapsed() >= Duration::from_secs(timeout) {
+                return Err(ErrorCode::AbortedQuery(format!(
+                    "query {} waited in the queue of workload group '{}' for more than {} seconds",
+                    query_id, group_name, timeout
+                )));
+            }
+            let _ = tokio::time::timeout(QUEUE_CHECK_INTERVAL, notified).await;
+        }
+    }
+
+    pub fn queries_info(&self) -> Vec<WorkloadQueryInfo> {
+        let mut infos = vec![];
+        let mut running = vec![];
+        for (name, group) in self.groups.iter() {
+            let queries = group.queries.lock();
+            for query in queries.iter() {
+                let (state, queued_time) = match query.state {
+                    WorkloadQueryState::Queued => ("Queued", query.enqueue_time.elapsed()),
+                    WorkloadQueryState::Running => {
+                        running.push((infos.len(), query.shared.clone()));
+                        let queued_time = query
+                            .admit_time
+                            .map(|t| t - query.enqueue_time)
+                            .unwrap_or_default();
+                        ("Running", queued_time)
+                    }
+                };
+                infos.push(WorkloadQueryInfo {
+                    workload_group: name.clone(),
+                    query_id: query.query_id.clone(),
+                    user: query.user.clone(),
+                    state: state.to_string(),
+                    queued_time,
+                    memory_usage: 0,
+                    query_text: query.query_text.clone(),
+                });
+            }
+        }
+        for (i, shared) in running {
+            infos[i].memory_usage = memory_usage(&shared);
+        }
+        infos
+    }
+}
@@ -0,0 +1,250 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_args::TableArgs;
+use common_catalog::table_context::TableContext;
+use common_catalog::table_function::TableFunction;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::types::UInt64Type;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::FromOptData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::ListTableCopiedFileReq;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::Pipeline;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+use common_storages_fuse::table_functions::string_literal;
+use common_storages_fuse::table_functions::string_value;
+
+const COPY_HISTORY: &str = "copy_history";
+
+/// `copy_history('db', 'table')` lists the files loaded into the table by `COPY INTO`,
+/// these files are skipped by the following `COPY INTO` unless `FORCE = TRUE`.
+pub struct CopyHistoryTable {
+    table_info: TableInfo,
+    arg_database_name: String,
+    arg_table_name: String,
+}
+
+impl CopyHistoryTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let args = table_args.expect_all_positioned(COPY_HISTORY, Some(2))?;
+        let arg_database_name = string_value(&args[0])?;
+        let arg_table_name = string_value(&args[1])?;
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: Self::schema(),
+                engine: COPY_HISTORY.to_owned(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        Ok(Arc::new(Self {
+            table_info,
+            arg_database_name,
+            arg_table_name,
+        }))
+    }
+
+    fn schema() -> Arc<TableSchema> {
+        TableSchemaRefExt::create(vec![
+            TableField::new("file_name", TableDataType::String),
+            TableField::new(
+                "content_length",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "etag",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new(
+                "last_modified",
+                TableDataType::Nullable(Box::new(TableDataType::Timestamp)),
+            ),
+            TableField::new(
+                "load_time",
+                TableDataType::Nullable(Box::new(TableDataType::Timestamp)),
+            ),
+        ])
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for CopyHistoryTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        Ok((PartStatistics::default(), Partitions::default()))
+    }
+
+    fn table_args(&self) -> Option<TableArgs> {
+        Some(TableArgs::new_positioned(vec![
+            string_literal(self.arg_database_name.as_str()),
+            string_literal(self.arg_table_name.as_str()),
+        ]))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        pipeline.add_source(
+            |output| {
+                CopyHistorySource::create(
+                    ctx.clone(),
+                    output,
+                    self.arg_database_name.to_owned(),
+                    self.arg_table_name.to_owned(),
+                )
+            },
+            1,
+        )?;
+        Ok(())
+    }
+}
+
+impl TableFunction for CopyHistoryTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
+
+struct CopyHistorySource {
+    is_finished: bool,
+    ctx: Arc<dyn TableContext>,
+    arg_database_name: String,
+    arg_table_name: String,
+}
+
+impl CopyHistorySource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        arg_database_name: String,
+        arg_table_name: String,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, CopyHistorySource {
+            is_finished: false,
+            ctx,
+            arg_database_name,
+            arg_table_name,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for CopyHistorySource {
+    const NAME: &'static str = COPY_HISTORY;
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.is_finished {
+            return Ok(None);
+        }
+
+        self.is_finished = true;
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(CATALOG_DEFAULT)?;
+        let table = catalog
+            .get_table(
+                tenant.as_str(),
+                self.arg_database_name.as_str(),
+                self.arg_table_name.as_str(),
+            )
+            .await?;
+        let req = ListTableCopiedFileReq {
+            table_id: table.get_id(),
+        };
+        let files = catalog
+            .list_table_copied_file_info(tenant.as_str(), self.arg_database_name.as_str(), req)
+            .await?
+            .file_info;
+
+        let names: Vec<Vec<u8>> = files.keys().map(|name| name.clone().into_bytes()).collect();
+        let sizes: Vec<u64> = files.values().map(|file| file.content_length).collect();
+        let etags: Vec<Option<Vec<u8>>> = files
+            .values()
+            .map(|file| file.etag.as_ref().map(|etag| etag.clone().into_bytes()))
+            .collect();
+        let last_modifieds: Vec<Option<i64>> = files
+            .values()
+            .map(|file| file.last_modified.map(|t| t.timestamp_micros()))
+            .collect();
+        let load_times: Vec<Option<i64>> = files
+            .values()
+            .map(|file| file.load_time.map(|t| t.timestamp_micros()))
+            .collect();
+
+        let block = DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            UInt64Type::from_data(sizes),
+            StringType::from_opt_data(etags),
+            TimestampType::from_opt_data(last_modifieds),
+            TimestampType::from_opt_data(load_times),
+        ]);
+
+        Ok(Some(block))
+    }
+}
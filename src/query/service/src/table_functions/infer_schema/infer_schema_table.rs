@@ -33,11 +33,17 @@ use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchema;
 use common_expression::TableSchemaRefExt;
+use common_meta_app::principal::CsvFileFormatParams;
+use common_meta_app::principal::FileFormatParams;
 use common_meta_app::principal::StageFileFormatType;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
 use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sources::input_formats::detect_file_format;
+use common_pipeline_sources::input_formats::InputContext;
+use common_pipeline_sources::input_formats::InputFormat;
+use common_pipeline_sources::input_formats::InputFormatCSV;
 use common_pipeline_sources::AsyncSource;
 use common_pipeline_sources::AsyncSourcer;
 use common_sql::binder::parse_stage_location;
@@ -187,6 +193,10 @@ impl AsyncSource for InferSchemaSource {
         let first_file = files_info.first_file(&operator).await?;
         let file_format_params = match &self.args_parsed.file_format {
             Some(f) => self.ctx.get_file_format(f).await?,
+            // the stage has the default format, detect it from the file.
+            None if matches!(stage_info.file_format_params, FileFormatParams::Parquet(_)) => {
+                detect_file_format(&operator, &first_file.path, first_file.size).await?
+            }
             None => stage_info.file_format_params.clone(),
         };
         let schema = match file_format_params.get_type() {
@@ -194,9 +204,26 @@ impl AsyncSource for InferSchemaSource {
                 let arrow_schema = read_parquet_schema_async(&operator, &first_file.path).await?;
                 TableSchema::from(&arrow_schema)
             }
+            StageFileFormatType::Avro | StageFileFormatType::Orc => {
+                let schema = InputContext::get_input_format(&file_format_params)?
+                    .infer_schema(&first_file.path, &operator)
+                    .await?;
+                schema.as_ref().clone()
+            }
+            StageFileFormatType::Csv => {
+                let params = CsvFileFormatParams::downcast_unchecked(&file_format_params);
+                let schema = InputFormatCSV::infer_schema_by_sample(
+                    params,
+                    &first_file.path,
+                    first_file.size,
+                    &operator,
+                )
+                .await?;
+                schema.as_ref().clone()
+            }
             _ => {
                 return Err(ErrorCode::BadArguments(
-                    "infer_schema is currently limited to format Parquet",
+                    "infer_schema is currently limited to format Parquet, CSV, Avro and ORC",
                 ));
             }
         };
@@ -35,6 +35,7 @@ impl InferSchemaArgsParsed {
             path: "".to_string(),
             files: None,
             pattern: None,
+            filter: Default::default(),
         };
 
         for (k, v) in &args {
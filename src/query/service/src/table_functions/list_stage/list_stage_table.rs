@@ -189,6 +189,7 @@ impl AsyncSource for ListStagesSource {
             path,
             files: self.args_parsed.files_info.files.clone(),
             pattern: self.args_parsed.files_info.pattern.clone(),
+            filter: self.args_parsed.files_info.filter.clone(),
         };
 
         let files = files_info.list(&op, false, None).await?;
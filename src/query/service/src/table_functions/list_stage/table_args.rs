@@ -33,6 +33,7 @@ impl ListStageArgsParsed {
             path: "".to_string(),
             files: None,
             pattern: None,
+            filter: Default::default(),
         };
 
         for (k, v) in &args {
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod async_crash_me;
+mod copy_history;
 mod infer_schema;
 mod list_stage;
 mod numbers;
@@ -30,6 +30,7 @@ use crate::storages::fuse::table_functions::FuseSegmentTable;
 use crate::storages::fuse::table_functions::FuseSnapshotTable;
 use crate::storages::fuse::table_functions::FuseStatisticTable;
 use crate::table_functions::async_crash_me::AsyncCrashMeTable;
+use crate::table_functions::copy_history::CopyHistoryTable;
 use crate::table_functions::infer_schema::InferSchemaTable;
 use crate::table_functions::list_stage::ListStageTable;
 use crate::table_functions::numbers::NumbersTable;
@@ -145,6 +146,11 @@ impl TableFunctionFactory {
             (next_id(), Arc::new(ListStageTable::create)),
         );
 
+        creators.insert(
+            "copy_history".to_string(),
+            (next_id(), Arc::new(CopyHistoryTable::create)),
+        );
+
         creators.insert(
             "generate_series".to_string(),
             (next_id(), Arc::new(RangeTable::create)),
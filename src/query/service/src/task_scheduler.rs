@@ -0,0 +1,254 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_base::base::tokio::time::sleep;
+use common_base::base::GlobalUniqName;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_config::InnerConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::TaskMeta;
+use common_meta_app::principal::TaskRun;
+use common_meta_app::principal::TaskRunTrigger;
+use common_meta_app::principal::TaskState;
+use common_users::UserApiProvider;
+use futures_util::TryStreamExt;
+use parking_lot::Mutex;
+use tracing::info;
+use tracing::warn;
+
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+use crate::sql::Planner;
+
+const SCHEDULER_TICK: Duration = Duration::from_secs(1);
+/// The leader is replaced if it doesn't renew the lease in time, e.g. it's down.
+const SCHEDULER_LEASE_TTL: Duration = Duration::from_secs(10);
+const RETRY_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Starts the runs of the tasks of the tenant on schedule.
+///
+/// Every query node runs a scheduler, but only the one holding the lease in meta
+/// starts runs. A due task is claimed by advancing its `next_scheduled_at` with
+/// the seq read, so a run is started once even if the lease moves meanwhile.
+/// Runs missed while no scheduler was leading are skipped, not caught up.
+pub struct TaskScheduler {
+    tenant: String,
+    holder: String,
+    running: Mutex<HashSet<String>>,
+}
+
+impl TaskScheduler {
+    pub fn init(config: &InnerConfig) -> Result<()> {
+        // the tasks can't run their statements on a read-only replica or in management mode.
+        if config.query.read_only_replica || config.query.management_mode {
+            return Ok(());
+        }
+
+        let scheduler = Arc::new(TaskScheduler {
+            tenant: config.query.tenant_id.clone(),
+            holder: GlobalUniqName::unique(),
+            running: Mutex::new(HashSet::new()),
+        });
+        GlobalIORuntime::instance().try_spawn(async move { scheduler.run().await })?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn run(self: Arc<Self>) {
+        let mut leading = false;
+        loop {
+            sleep(SCHEDULER_TICK).await;
+
+            let user_api = UserApiProvider::instance();
+            match user_api
+                .renew_task_scheduler_lease(&self.tenant, &self.holder, SCHEDULER_LEASE_TTL)
+                .await
+            {
+                Ok(true) => {
+                    if !leading {
+                        info!("task scheduler: {} takes the lead", self.holder);
+                    }
+                    leading = true;
+                }
+                Ok(false) => {
+                    leading = false;
+                    continue;
+                }
+                Err(cause) => {
+                    warn!("task scheduler: renew lease failed, {}", cause);
+                    continue;
+                }
+            }
+
+            if let Err(cause) = self.schedule_round().await {
+                warn!("task scheduler: round failed, {}", cause);
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn schedule_round(self: &Arc<Self>) -> Result<()> {
+        let now = Utc::now();
+        let tasks = UserApiProvider::instance().get_tasks(&self.tenant).await?;
+        for task in tasks {
+            if task.state != TaskState::Started || self.running.lock().contains(&task.name) {
+                continue;
+            }
+            if matches!(task.next_scheduled_at, Some(at) if at > now) {
+                continue;
+            }
+
+            match self.claim(&task.name, now).await {
+                Ok(Some((task, scheduled_at))) => self.spawn_run(task, scheduled_at)?,
+                Ok(None) => {}
+                Err(cause) => warn!("task scheduler: claim task {} failed, {}", task.name, cause),
+            }
+        }
+        Ok(())
+    }
+
+    /// Advance `next_scheduled_at` of a due task, returns the task and the time it
+    /// was due at if it's to run now.
+    #[async_backtrace::framed]
+    async fn claim(
+        &self,
+        name: &str,
+        now: DateTime<Utc>,
+    ) -> Result<Option<(TaskMeta, DateTime<Utc>)>> {
+        let user_api = UserApiProvider::instance();
+        let seq_task = user_api.get_task(&self.tenant, name).await?;
+        let mut task = seq_task.data;
+        if task.state != TaskState::Started {
+            return Ok(None);
+        }
+        let due = match task.next_scheduled_at {
+            Some(at) if at > now => return Ok(None),
+            due => due,
+        };
+
+        task.next_scheduled_at = Some(task.schedule.next_after(now)?);
+        match user_api
+            .update_task(&self.tenant, task.clone(), seq_task.seq)
+            .await
+        {
+            // the task is altered or dropped since read, it's checked again next round.
+            Err(cause) if cause.code() == ErrorCode::UNKNOWN_TASK => Ok(None),
+            Err(cause) => Err(cause),
+            // a task without `next_scheduled_at` is only scheduled, not run.
+            Ok(_) => Ok(due.map(|due| (task, due))),
+        }
+    }
+
+    fn spawn_run(self: &Arc<Self>, task: TaskMeta, scheduled_at: DateTime<Utc>) -> Result<()> {
+        self.running.lock().insert(task.name.clone());
+        let scheduler = self.clone();
+        GlobalIORuntime::instance().try_spawn(async move {
+            if let Err(cause) = run_task(
+                &scheduler.tenant,
+                &task,
+                scheduled_at,
+                TaskRunTrigger::Schedule,
+            )
+            .await
+            {
+                warn!("task scheduler: task {} failed, {}", task.name, cause);
+            }
+            scheduler.running.lock().remove(&task.name);
+        })?;
+        Ok(())
+    }
+}
+
+/// Run the statement of a task, retried up to `retry_attempts` times, and record
+/// the run in the history of the task.
+#[async_backtrace::framed]
+pub async fn run_task(
+    tenant: &str,
+    task: &TaskMeta,
+    scheduled_at: DateTime<Utc>,
+    trigger: TaskRunTrigger,
+) -> Result<()> {
+    let started_at = Utc::now();
+    let mut attempts = 0;
+    let mut query_id = String::new();
+    let result = loop {
+        attempts += 1;
+        match execute_task_sql(tenant, task, &mut query_id).await {
+            Err(cause) if attempts <= task.retry_attempts => {
+                warn!(
+                    "task {}: attempt {} failed, {}, retrying",
+                    task.name, attempts, cause
+                );
+                sleep(RETRY_BACKOFF * attempts as u32).await;
+            }
+            result => break result,
+        }
+    };
+
+    let (error_code, error_message) = match &result {
+        Ok(_) => (0, String::new()),
+        Err(cause) => (cause.code(), cause.message()),
+    };
+    let run = TaskRun {
+        task_name: task.name.clone(),
+        trigger,
+        query_id,
+        scheduled_at,
+        started_at,
+        finished_at: Utc::now(),
+        attempts,
+        succeeded: result.is_ok(),
+        error_code,
+        error_message,
+    };
+    if let Err(cause) = UserApiProvider::instance().add_task_run(tenant, run).await {
+        warn!("task {}: record run failed, {}", task.name, cause);
+    }
+    result
+}
+
+#[async_backtrace::framed]
+async fn execute_task_sql(tenant: &str, task: &TaskMeta, query_id: &mut String) -> Result<()> {
+    let user = UserApiProvider::instance()
+        .get_user(tenant, task.owner.clone())
+        .await?;
+    let session = SessionManager::instance()
+        .create_session(SessionType::Local)
+        .await?;
+    session.set_authed_user(user, None).await?;
+    session.set_current_database(task.database.clone());
+
+    let ctx: Arc<QueryContext> = session.create_query_context().await?;
+    *query_id = ctx.get_id();
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(&task.sql).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    interpreter
+        .execute(ctx)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    Ok(())
+}
@@ -187,6 +187,8 @@ impl TestFixture {
             .into(),
             field_comments: vec![],
             as_select: None,
+            template: None,
+            identity_sequences: vec![],
             cluster_key: Some("(id)".to_string()),
         }
     }
@@ -210,6 +212,8 @@ impl TestFixture {
             .into(),
             field_comments: vec![],
             as_select: None,
+            template: None,
+            identity_sequences: vec![],
             cluster_key: None,
         }
     }
@@ -244,6 +248,8 @@ impl TestFixture {
             .into(),
             field_comments: vec![],
             as_select: None,
+            template: None,
+            identity_sequences: vec![],
             cluster_key: None,
         }
     }
@@ -287,6 +293,8 @@ impl TestFixture {
             .into(),
             field_comments: vec![],
             as_select: None,
+            template: None,
+            identity_sequences: vec![],
             cluster_key: None,
         }
     }
@@ -12,16 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::time::Duration;
+
 use common_base::base::tokio;
 use common_exception::Result;
 use common_meta_types::NodeInfo;
 use databend_query::api::http::v1::cluster::*;
+use databend_query::api::http::v1::instance_status::InstanceStatus;
 use databend_query::test_kits::TestGlobalServices;
 use poem::get;
 use poem::http::header;
 use poem::http::Method;
 use poem::http::StatusCode;
 use poem::http::Uri;
+use poem::post;
 use poem::Endpoint;
 use poem::Request;
 use poem::Route;
@@ -55,3 +59,57 @@ async fn test_cluster() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_cluster_drain() -> Result<()> {
+    let _guard =
+        TestGlobalServices::setup(databend_query::test_kits::ConfigBuilder::create().build())
+            .await?;
+    let cluster_router = Route::new()
+        .at("/v1/cluster/list", get(cluster_list_handler))
+        .at("/v1/cluster/drain", post(cluster_drain_handler));
+
+    // Drain Node
+    {
+        let response = cluster_router
+            .call(
+                Request::builder()
+                    .uri(Uri::from_static("/v1/cluster/drain?timeout_secs=1"))
+                    .method(Method::POST)
+                    .finish(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response.into_body().into_vec().await.unwrap();
+        let status = serde_json::from_str::<InstanceStatus>(&String::from_utf8_lossy(&body))?;
+        assert!(status.draining);
+    }
+
+    // The node leaves the cluster without running queries
+    let mut nodes_count = 1;
+    for _ in 0..20 {
+        let response = cluster_router
+            .call(
+                Request::builder()
+                    .uri(Uri::from_static("/v1/cluster/list"))
+                    .method(Method::GET)
+                    .finish(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response.into_body().into_vec().await.unwrap();
+        let nodes = serde_json::from_str::<Vec<NodeInfo>>(&String::from_utf8_lossy(&body))?;
+        nodes_count = nodes.len();
+        if nodes_count == 0 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    }
+    assert_eq!(nodes_count, 0);
+
+    Ok(())
+}
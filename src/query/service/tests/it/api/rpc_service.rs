@@ -24,7 +24,9 @@ use common_exception::Result;
 use common_grpc::ConnectionFactory;
 use common_grpc::GrpcConnectionError;
 use common_grpc::RpcClientTlsConfig;
+use databend_query::api::FlightClient;
 use databend_query::api::RpcService;
+use databend_query::test_kits::create_query_context;
 use databend_query::test_kits::ConfigBuilder;
 
 use crate::tests::tls_constants::TEST_CA_CERT;
@@ -65,6 +67,28 @@ async fn test_tls_rpc_server() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_rpc_processes_info_and_kill_session() -> Result<()> {
+    let (_guard, ctx) = create_query_context().await?;
+    let mut rpc_service = RpcService::create(ConfigBuilder::create().build())?;
+    let listener_address = SocketAddr::from_str("127.0.0.1:9992")?;
+    let listener_address = rpc_service.start(listener_address).await?;
+
+    let channel = ConnectionFactory::create_rpc_channel(listener_address, None, None).await?;
+    let mut client = FlightClient::new(FlightServiceClient::new(channel));
+
+    // the processes are listed as the ones of the node asked for.
+    let session_id = ctx.get_current_session().get_id();
+    let processes_info = client.get_processes_info("node-1", 10).await?;
+    let process_info = processes_info.iter().find(|info| info.id == session_id);
+    assert_eq!(process_info.map(|info| info.node.as_str()), Some("node-1"));
+
+    assert!(!client.kill_session("unknown-session", false, 10).await?);
+    assert!(client.kill_session(&session_id, false, 10).await?);
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_tls_rpc_server_invalid_server_config() -> Result<()> {
     // setup, invalid cert locations
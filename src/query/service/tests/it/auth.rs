@@ -14,9 +14,12 @@
 
 use base64::engine::general_purpose;
 use base64::prelude::*;
+use chrono::Utc;
 use common_base::base::tokio;
 use common_exception::Result;
 use common_meta_app::principal::AuthInfo;
+use common_meta_app::principal::NetworkPolicy;
+use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
 use common_users::CustomClaims;
 use common_users::EnsureUser;
@@ -38,6 +41,11 @@ struct NonCustomClaims {
     user_country: String,
 }
 
+#[derive(Serialize, Deserialize)]
+struct GroupsClaims {
+    groups: Vec<String>,
+}
+
 fn get_jwks_file_rs256(kid: &str) -> (RS256KeyPair, String) {
     let key_pair = RS256KeyPair::generate(2048).unwrap().with_key_id(kid);
     let rsa_components = key_pair.public_key().to_components();
@@ -97,6 +105,7 @@ async fn test_auth_mgr_with_jwt_multi_sources() -> Result<()> {
         let res = auth_mgr
             .auth(ctx.get_current_session(), &Credential::Jwt {
                 token: token1,
+                client_ip: None,
             })
             .await;
         assert!(res.is_ok());
@@ -122,6 +131,7 @@ async fn test_auth_mgr_with_jwt_multi_sources() -> Result<()> {
         let res = auth_mgr
             .auth(ctx.get_current_session(), &Credential::Jwt {
                 token: token2,
+                client_ip: None,
             })
             .await;
         assert!(res.is_ok());
@@ -152,6 +162,7 @@ async fn test_auth_mgr_with_jwt_multi_sources() -> Result<()> {
         let res2 = auth_mgr
             .auth(ctx.get_current_session(), &Credential::Jwt {
                 token: token2,
+                client_ip: None,
             })
             .await;
         assert!(res2.is_ok());
@@ -173,6 +184,7 @@ async fn test_auth_mgr_with_jwt_multi_sources() -> Result<()> {
         let res3 = auth_mgr
             .auth(ctx.get_current_session(), &Credential::Jwt {
                 token: token3,
+                client_ip: None,
             })
             .await;
         assert!(res3.is_err());
@@ -222,7 +234,10 @@ async fn test_auth_mgr_with_jwt() -> Result<()> {
         let token = key_pair.sign(claims)?;
 
         let res = auth_mgr
-            .auth(ctx.get_current_session(), &Credential::Jwt { token })
+            .auth(ctx.get_current_session(), &Credential::Jwt {
+                token,
+                client_ip: None,
+            })
             .await;
         assert!(res.is_err());
 
@@ -240,7 +255,10 @@ async fn test_auth_mgr_with_jwt() -> Result<()> {
         let token = key_pair.sign(claims)?;
 
         let res = auth_mgr
-            .auth(ctx.get_current_session(), &Credential::Jwt { token })
+            .auth(ctx.get_current_session(), &Credential::Jwt {
+                token,
+                client_ip: None,
+            })
             .await;
         assert!(res.is_err());
         assert!(
@@ -259,7 +277,10 @@ async fn test_auth_mgr_with_jwt() -> Result<()> {
         let token = key_pair.sign(claims)?;
 
         let res = auth_mgr
-            .auth(ctx.get_current_session(), &Credential::Jwt { token })
+            .auth(ctx.get_current_session(), &Credential::Jwt {
+                token,
+                client_ip: None,
+            })
             .await;
         assert!(res.is_err());
         assert!(
@@ -278,7 +299,10 @@ async fn test_auth_mgr_with_jwt() -> Result<()> {
         let token = key_pair.sign(claims)?;
 
         auth_mgr
-            .auth(ctx.get_current_session(), &Credential::Jwt { token })
+            .auth(ctx.get_current_session(), &Credential::Jwt {
+                token,
+                client_ip: None,
+            })
             .await?;
         let user_info = ctx.get_current_user()?;
         assert_eq!(user_info.grants.roles().len(), 0);
@@ -294,7 +318,10 @@ async fn test_auth_mgr_with_jwt() -> Result<()> {
         let token = key_pair.sign(claims)?;
 
         auth_mgr
-            .auth(ctx.get_current_session(), &Credential::Jwt { token })
+            .auth(ctx.get_current_session(), &Credential::Jwt {
+                token,
+                client_ip: None,
+            })
             .await?;
         let user_info = ctx.get_current_user()?;
         assert!(user_info.grants.roles().is_empty());
@@ -312,7 +339,10 @@ async fn test_auth_mgr_with_jwt() -> Result<()> {
         let token = key_pair.sign(claims)?;
 
         auth_mgr
-            .auth(ctx.get_current_session(), &Credential::Jwt { token })
+            .auth(ctx.get_current_session(), &Credential::Jwt {
+                token,
+                client_ip: None,
+            })
             .await?;
 
         let user_info = ctx.get_current_user()?;
@@ -334,7 +364,10 @@ async fn test_auth_mgr_with_jwt() -> Result<()> {
         let token = key_pair.sign(claims)?;
 
         let res = auth_mgr
-            .auth(ctx.get_current_session(), &Credential::Jwt { token })
+            .auth(ctx.get_current_session(), &Credential::Jwt {
+                token,
+                client_ip: None,
+            })
             .await;
         assert!(res.is_ok());
 
@@ -355,7 +388,10 @@ async fn test_auth_mgr_with_jwt() -> Result<()> {
         let token = key_pair.sign(claims)?;
 
         let res = auth_mgr
-            .auth(ctx.get_current_session(), &Credential::Jwt { token })
+            .auth(ctx.get_current_session(), &Credential::Jwt {
+                token,
+                client_ip: None,
+            })
             .await;
         assert!(res.is_err());
     }
@@ -401,7 +437,10 @@ async fn test_auth_mgr_with_jwt_es256() -> Result<()> {
         let token = key_pair.sign(claims)?;
 
         let res = auth_mgr
-            .auth(ctx.get_current_session(), &Credential::Jwt { token })
+            .auth(ctx.get_current_session(), &Credential::Jwt {
+                token,
+                client_ip: None,
+            })
             .await;
         assert!(res.is_err());
         assert!(
@@ -418,7 +457,10 @@ async fn test_auth_mgr_with_jwt_es256() -> Result<()> {
         let token = key_pair.sign(claims)?;
 
         let res = auth_mgr
-            .auth(ctx.get_current_session(), &Credential::Jwt { token })
+            .auth(ctx.get_current_session(), &Credential::Jwt {
+                token,
+                client_ip: None,
+            })
             .await;
         assert!(res.is_err());
         assert!(
@@ -437,7 +479,10 @@ async fn test_auth_mgr_with_jwt_es256() -> Result<()> {
         let token = key_pair.sign(claims)?;
 
         let res = auth_mgr
-            .auth(ctx.get_current_session(), &Credential::Jwt { token })
+            .auth(ctx.get_current_session(), &Credential::Jwt {
+                token,
+                client_ip: None,
+            })
             .await;
         assert!(res.is_err());
         assert!(
@@ -456,7 +501,10 @@ async fn test_auth_mgr_with_jwt_es256() -> Result<()> {
         let token = key_pair.sign(claims)?;
 
         auth_mgr
-            .auth(ctx.get_current_session(), &Credential::Jwt { token })
+            .auth(ctx.get_current_session(), &Credential::Jwt {
+                token,
+                client_ip: None,
+            })
             .await?;
         let user_info = ctx.get_current_user()?;
         assert_eq!(user_info.grants.roles().len(), 0);
@@ -472,7 +520,10 @@ async fn test_auth_mgr_with_jwt_es256() -> Result<()> {
         let token = key_pair.sign(claims)?;
 
         auth_mgr
-            .auth(ctx.get_current_session(), &Credential::Jwt { token })
+            .auth(ctx.get_current_session(), &Credential::Jwt {
+                token,
+                client_ip: None,
+            })
             .await?;
         let user_info = ctx.get_current_user()?;
         assert!(user_info.grants.roles().is_empty());
@@ -490,7 +541,10 @@ async fn test_auth_mgr_with_jwt_es256() -> Result<()> {
         let token = key_pair.sign(claims)?;
 
         auth_mgr
-            .auth(ctx.get_current_session(), &Credential::Jwt { token })
+            .auth(ctx.get_current_session(), &Credential::Jwt {
+                token,
+                client_ip: None,
+            })
             .await?;
         let user_info = ctx.get_current_user()?;
         assert_eq!(user_info.name, user_name);
@@ -511,7 +565,10 @@ async fn test_auth_mgr_with_jwt_es256() -> Result<()> {
         let token = key_pair.sign(claims)?;
 
         let res = auth_mgr
-            .auth(ctx.get_current_session(), &Credential::Jwt { token })
+            .auth(ctx.get_current_session(), &Credential::Jwt {
+                token,
+                client_ip: None,
+            })
             .await;
         assert!(res.is_ok());
 
@@ -532,7 +589,10 @@ async fn test_auth_mgr_with_jwt_es256() -> Result<()> {
         let token = key_pair.sign(claims)?;
 
         let res = auth_mgr
-            .auth(ctx.get_current_session(), &Credential::Jwt { token })
+            .auth(ctx.get_current_session(), &Credential::Jwt {
+                token,
+                client_ip: None,
+            })
             .await;
         assert!(res.is_err());
     }
@@ -582,7 +642,10 @@ async fn test_jwt_auth_mgr_with_management() -> Result<()> {
         let token = key_pair.sign(claims)?;
 
         auth_mgr
-            .auth(ctx.get_current_session(), &Credential::Jwt { token })
+            .auth(ctx.get_current_session(), &Credential::Jwt {
+                token,
+                client_ip: None,
+            })
             .await?;
         let user_info = ctx.get_current_user()?;
         let current_tenant = ctx.get_tenant();
@@ -592,3 +655,243 @@ async fn test_jwt_auth_mgr_with_management() -> Result<()> {
         Ok(())
     }
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_auth_mgr_with_oidc_role_mapping() -> Result<()> {
+    let (key_pair, jwks) = get_jwks_file_rs256("test_kid");
+
+    let server = MockServer::start().await;
+    let issuer = format!("http://{}", server.address());
+    let jwks_path = "/jwks.json";
+    let openid_configuration = serde_json::json!({
+        "issuer": issuer,
+        "jwks_uri": format!("{}{}", issuer, jwks_path),
+    })
+    .to_string();
+    Mock::given(method("GET"))
+        .and(path("/.well-known/openid-configuration"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_raw(openid_configuration, "application/json"),
+        )
+        .expect(1..)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(jwks_path))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(jwks, "application/json"))
+        .expect(1..)
+        .mount(&server)
+        .await;
+
+    let mut conf = databend_query::test_kits::ConfigBuilder::create().config();
+    conf.query.jwt_issuer = issuer.clone();
+    conf.query.jwt_audience = "databend".to_string();
+    conf.query.jwt_role_claim = "groups".to_string();
+    conf.query.jwt_role_mapping = vec!["analysts=analyst".to_string()];
+    let (_guard, ctx) =
+        databend_query::test_kits::create_query_context_with_config(conf, None).await?;
+    let auth_mgr = AuthMgr::instance();
+    let user_name = "oidc_user";
+
+    let tenant = ctx.get_current_session().get_current_tenant();
+    UserApiProvider::instance()
+        .add_role(&tenant, RoleInfo::new("analyst"), false)
+        .await?;
+
+    // without issuer
+    {
+        let groups = GroupsClaims {
+            groups: vec!["analysts".to_string()],
+        };
+        let claims = Claims::with_custom_claims(groups, Duration::from_hours(2))
+            .with_subject(user_name.to_string());
+        let token = key_pair.sign(claims)?;
+
+        let res = auth_mgr
+            .auth(ctx.get_current_session(), &Credential::Jwt {
+                token,
+                client_ip: None,
+            })
+            .await;
+        assert!(res.is_err());
+    }
+
+    // with other issuer
+    {
+        let groups = GroupsClaims {
+            groups: vec!["analysts".to_string()],
+        };
+        let claims = Claims::with_custom_claims(groups, Duration::from_hours(2))
+            .with_subject(user_name.to_string())
+            .with_issuer("http://other.issuer")
+            .with_audience("databend");
+        let token = key_pair.sign(claims)?;
+
+        let res = auth_mgr
+            .auth(ctx.get_current_session(), &Credential::Jwt {
+                token,
+                client_ip: None,
+            })
+            .await;
+        assert!(res.is_err());
+    }
+
+    // without audience
+    {
+        let groups = GroupsClaims {
+            groups: vec!["analysts".to_string()],
+        };
+        let claims = Claims::with_custom_claims(groups, Duration::from_hours(2))
+            .with_subject(user_name.to_string())
+            .with_issuer(&issuer);
+        let token = key_pair.sign(claims)?;
+
+        let res = auth_mgr
+            .auth(ctx.get_current_session(), &Credential::Jwt {
+                token,
+                client_ip: None,
+            })
+            .await;
+        assert!(res.is_err());
+    }
+
+    // the user is not created unless the token asks for it
+    {
+        let groups = GroupsClaims {
+            groups: vec!["analysts".to_string()],
+        };
+        let claims = Claims::with_custom_claims(groups, Duration::from_hours(2))
+            .with_subject(user_name.to_string())
+            .with_issuer(&issuer)
+            .with_audience("databend");
+        let token = key_pair.sign(claims)?;
+
+        let res = auth_mgr
+            .auth(ctx.get_current_session(), &Credential::Jwt {
+                token,
+                client_ip: None,
+            })
+            .await;
+        assert!(res.is_err());
+    }
+
+    // the mapped roles are granted in the session only, the tenant and the roles
+    // in the other claims are ignored
+    {
+        let claims = serde_json::json!({
+            "groups": ["analysts", "others", "account_admin"],
+            "tenant_id": "other_tenant",
+            "role": "account_admin",
+            "ensure_user": { "roles": ["account_admin"] },
+        });
+        let claims = Claims::with_custom_claims(claims, Duration::from_hours(2))
+            .with_subject(user_name.to_string())
+            .with_issuer(&issuer)
+            .with_audience("databend");
+        let token = key_pair.sign(claims)?;
+
+        auth_mgr
+            .auth(ctx.get_current_session(), &Credential::Jwt {
+                token,
+                client_ip: None,
+            })
+            .await?;
+        let user_info = ctx.get_current_user()?;
+        assert_eq!(user_info.auth_info, AuthInfo::JWT);
+        assert_eq!(user_info.grants.roles(), vec!["analyst".to_string()]);
+        assert_eq!(ctx.get_current_session().get_current_tenant(), tenant);
+        assert_ne!(
+            ctx.get_current_role().map(|r| r.name),
+            Some("account_admin".to_string())
+        );
+
+        let user_info = UserApiProvider::instance()
+            .get_user(&tenant, user_info.identity())
+            .await?;
+        assert!(user_info.grants.roles().is_empty());
+    }
+
+    // the role is gone once the claim is removed
+    {
+        let groups = GroupsClaims { groups: vec![] };
+        let claims = Claims::with_custom_claims(groups, Duration::from_hours(2))
+            .with_subject(user_name.to_string())
+            .with_issuer(&issuer)
+            .with_audience("databend");
+        let token = key_pair.sign(claims)?;
+
+        auth_mgr
+            .auth(ctx.get_current_session(), &Credential::Jwt {
+                token,
+                client_ip: None,
+            })
+            .await?;
+        let user_info = ctx.get_current_user()?;
+        assert!(user_info.grants.roles().is_empty());
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_jwt_auth_mgr_with_network_policy() -> Result<()> {
+    let (key_pair, jwks) = get_jwks_file_rs256("test_kid");
+
+    let server = MockServer::start().await;
+    let json_path = "/jwks.json";
+    Mock::given(method("GET"))
+        .and(path(json_path))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(jwks, "application/json"))
+        .expect(1..)
+        .mount(&server)
+        .await;
+
+    let mut conf = databend_query::test_kits::ConfigBuilder::create().config();
+    conf.query.jwt_key_file = format!("http://{}{}", server.address(), json_path);
+    let (_guard, ctx) =
+        databend_query::test_kits::create_query_context_with_config(conf, None).await?;
+    let auth_mgr = AuthMgr::instance();
+    let user_name = "policy_user";
+
+    let tenant = ctx.get_current_session().get_current_tenant();
+    let user_api = UserApiProvider::instance();
+    let policy = NetworkPolicy {
+        name: "office".to_string(),
+        allowed_ip_list: vec!["10.0.0.0/8".to_string()],
+        blocked_ip_list: vec!["10.0.0.99".to_string()],
+        comment: "".to_string(),
+        created_on: Utc::now(),
+    };
+    user_api.add_network_policy(&tenant, policy, false).await?;
+    let mut user_info = UserInfo::new(user_name, "%", AuthInfo::JWT);
+    user_info
+        .option
+        .set_network_policy(Some("office".to_string()));
+    user_api.add_user(&tenant, user_info, false).await?;
+
+    let claims = Claims::with_custom_claims(CustomClaims::new(), Duration::from_hours(2))
+        .with_subject(user_name.to_string());
+    let token = key_pair.sign(claims)?;
+
+    // allowed by the network policy
+    auth_mgr
+        .auth(ctx.get_current_session(), &Credential::Jwt {
+            token: token.clone(),
+            client_ip: Some("10.0.0.1".to_string()),
+        })
+        .await?;
+    assert_eq!(ctx.get_current_user()?.name, user_name);
+
+    // blocked, not allowed, or unknown client address
+    for client_ip in [Some("10.0.0.99"), Some("192.168.1.1"), None] {
+        let res = auth_mgr
+            .auth(ctx.get_current_session(), &Credential::Jwt {
+                token: token.clone(),
+                client_ip: client_ip.map(|ip| ip.to_string()),
+            })
+            .await;
+        assert_eq!(res.unwrap_err().code(), 1051);
+    }
+
+    Ok(())
+}
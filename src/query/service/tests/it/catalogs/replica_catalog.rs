@@ -0,0 +1,67 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::DropTableByIdReq;
+use databend_query::catalogs::Catalog;
+use databend_query::catalogs::ReplicaCatalog;
+use databend_query::test_kits::table_test_fixture::append_sample_data;
+use databend_query::test_kits::table_test_fixture::TestFixture;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_replica_catalog_pinned_tables() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    fixture.create_default_table().await?;
+    append_sample_data(1, &fixture).await?;
+
+    let tenant = fixture.default_tenant();
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let catalog = ReplicaCatalog::create(fixture.ctx().get_catalog("default")?);
+
+    // pinned when first read
+    let pinned = catalog.get_table(&tenant, &db, &tbl).await?;
+    let pinned_ident = pinned.get_table_info().ident;
+
+    // the new snapshot is not seen until the refresh
+    append_sample_data(1, &fixture).await?;
+    let latest_ident = fixture.latest_default_table().await?.get_table_info().ident;
+    assert_ne!(pinned_ident, latest_ident);
+
+    let table = catalog.get_table(&tenant, &db, &tbl).await?;
+    assert_eq!(table.get_table_info().ident, pinned_ident);
+    let tables = catalog.list_tables(&tenant, &db).await?;
+    assert_eq!(tables[0].get_table_info().ident, pinned_ident);
+
+    catalog.refresh().await?;
+    let table = catalog.get_table(&tenant, &db, &tbl).await?;
+    assert_eq!(table.get_table_info().ident, latest_ident);
+
+    // the writes are rejected
+    let res = catalog
+        .drop_table_by_id(DropTableByIdReq {
+            if_exists: false,
+            tb_id: latest_ident.table_id,
+        })
+        .await;
+    assert_eq!(
+        res.unwrap_err().code(),
+        ErrorCode::READ_ONLY_REPLICA_PERMISSION_DENIED
+    );
+
+    Ok(())
+}
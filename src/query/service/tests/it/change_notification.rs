@@ -0,0 +1,71 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_base::base::tokio::time::sleep;
+use common_exception::Result;
+use databend_query::sessions::TableContext;
+use databend_query::test_kits::create_query_context;
+use databend_query::test_kits::table_test_fixture::execute_command;
+use wiremock::matchers::method;
+use wiremock::matchers::path;
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::ResponseTemplate;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_change_notification_webhook() -> Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/hook"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+
+    let (_guard, ctx) = create_query_context().await?;
+    let session = ctx.get_current_session();
+    for sql in [
+        format!(
+            "CREATE NOTIFICATION INTEGRATION hook TYPE = WEBHOOK CONNECTION = (url = '{}/hook')",
+            server.uri()
+        ),
+        "CREATE TABLE default.t(a INT) notification_integration = 'hook'".to_string(),
+        "INSERT INTO default.t VALUES (1), (2)".to_string(),
+    ] {
+        execute_command(session.create_query_context().await?, &sql).await?;
+    }
+
+    // the notifications are delivered in the background.
+    let mut requests = vec![];
+    for _ in 0..50 {
+        requests = server.received_requests().await.unwrap_or_default();
+        if !requests.is_empty() {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+    assert_eq!(requests.len(), 1);
+
+    let payload: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!(payload["integration"], "hook");
+    assert_eq!(payload["database"], "default");
+    assert_eq!(payload["table"], "t");
+    assert_eq!(payload["row_count"], 2);
+    assert_eq!(payload["row_delta"], 2);
+    assert!(payload["snapshot_id"].is_string());
+    assert!(payload["previous_snapshot_id"].is_null());
+    Ok(())
+}
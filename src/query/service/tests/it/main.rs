@@ -21,12 +21,15 @@ extern crate core;
 mod api;
 mod auth;
 mod catalogs;
+mod change_notification;
 mod clusters;
 mod configs;
 mod databases;
 mod frame;
 mod metrics;
 mod pipelines;
+mod pipes;
+mod query_history;
 mod servers;
 mod sessions;
 mod sql;
@@ -13,3 +13,4 @@
 // limitations under the License.
 
 mod executor;
+mod wasm_udf;
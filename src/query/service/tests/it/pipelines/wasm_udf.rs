@@ -0,0 +1,130 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::Int32Type;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use databend_query::pipelines::processors::WasmUdfLimits;
+use databend_query::pipelines::processors::WasmUdfModule;
+
+// (module
+//   (memory (export "memory") 1)
+//   (global $top (mut i32) (i32.const 1024))
+//   ;; A bump allocator, which grows the memory if needed.
+//   (func (export "alloc") (param $len i32) (result i32) (local $ptr i32) (local $pages i32)
+//     (local.set $ptr (global.get $top))
+//     (global.set $top (i32.add (local.get $ptr) (local.get $len)))
+//     (local.set $pages (i32.sub
+//       (i32.shr_u (i32.add (global.get $top) (i32.const 65535)) (i32.const 16))
+//       (memory.size)))
+//     (if (i32.gt_s (local.get $pages) (i32.const 0))
+//       (then (drop (memory.grow (local.get $pages)))))
+//     (local.get $ptr))
+//   ;; Returns the record batch of the arguments.
+//   (func (export "echo") (param i32 i32) (result i64)
+//     (i64.or
+//       (i64.shl (i64.extend_i32_u (local.get 0)) (i64.const 32))
+//       (i64.extend_i32_u (local.get 1))))
+//   ;; Never returns.
+//   (func (export "spin") (param i32 i32) (result i64)
+//     (loop (br 0))
+//     (unreachable))
+//   ;; Returns a result out of the memory.
+//   (func (export "oob") (param i32 i32) (result i64)
+//     (i64.const -1)))
+const TEST_MODULE: &[u8] = &[
+    0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x0c, 0x02, 0x60, //
+    0x01, 0x7f, 0x01, 0x7f, 0x60, 0x02, 0x7f, 0x7f, 0x01, 0x7e, 0x03, 0x05, //
+    0x04, 0x00, 0x01, 0x01, 0x01, 0x05, 0x03, 0x01, 0x00, 0x01, 0x06, 0x07, //
+    0x01, 0x7f, 0x01, 0x41, 0x80, 0x08, 0x0b, 0x07, 0x26, 0x05, 0x06, 0x6d, //
+    0x65, 0x6d, 0x6f, 0x72, 0x79, 0x02, 0x00, 0x05, 0x61, 0x6c, 0x6c, 0x6f, //
+    0x63, 0x00, 0x00, 0x04, 0x65, 0x63, 0x68, 0x6f, 0x00, 0x01, 0x04, 0x73, //
+    0x70, 0x69, 0x6e, 0x00, 0x02, 0x03, 0x6f, 0x6f, 0x62, 0x00, 0x03, 0x0a, //
+    0x4a, 0x04, 0x2d, 0x01, 0x02, 0x7f, 0x23, 0x00, 0x21, 0x01, 0x20, 0x01, //
+    0x20, 0x00, 0x6a, 0x24, 0x00, 0x23, 0x00, 0x41, 0xff, 0xff, 0x03, 0x6a, //
+    0x41, 0x10, 0x76, 0x3f, 0x00, 0x6b, 0x21, 0x02, 0x20, 0x02, 0x41, 0x00, //
+    0x4a, 0x04, 0x40, 0x20, 0x02, 0x40, 0x00, 0x1a, 0x0b, 0x20, 0x01, 0x0b, //
+    0x0c, 0x00, 0x20, 0x00, 0xad, 0x42, 0x20, 0x86, 0x20, 0x01, 0xad, 0x84, //
+    0x0b, 0x08, 0x00, 0x03, 0x40, 0x0c, 0x00, 0x0b, 0x00, 0x0b, 0x04, 0x00, //
+    0x42, 0x7f, 0x0b,
+];
+
+fn test_limits() -> WasmUdfLimits {
+    WasmUdfLimits {
+        max_fuel: 1_000_000,
+        max_memory_bytes: 16 * 1024 * 1024,
+    }
+}
+
+#[test]
+fn test_wasm_udf_call() -> Result<()> {
+    let module = WasmUdfModule::create(TEST_MODULE, test_limits())?;
+    let column = Int32Type::from_data(vec![1, 2, 3]);
+    let block = DataBlock::new_from_columns(vec![column.clone()]);
+
+    // Every call runs in a new instance.
+    for _ in 0..2 {
+        let result = module.call("echo", block.clone())?;
+        assert_eq!(result.num_rows(), 3);
+        assert_eq!(result.num_columns(), 1);
+        let value = &result.get_by_offset(0).value;
+        assert_eq!(value.as_column(), Some(&column));
+    }
+
+    // The result must have a single column.
+    let block = DataBlock::new_from_columns(vec![column.clone(), column]);
+    let err = module.call("echo", block).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::UDFRuntimeError("").code());
+
+    // The handler must be exported.
+    let block = DataBlock::new_from_columns(vec![Int32Type::from_data(vec![1])]);
+    let err = module.call("missing", block).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::UDFRuntimeError("").code());
+
+    Ok(())
+}
+
+#[test]
+fn test_wasm_udf_limits() -> Result<()> {
+    // Runs out of fuel.
+    let module = WasmUdfModule::create(TEST_MODULE, test_limits())?;
+    let block = DataBlock::new_from_columns(vec![Int32Type::from_data(vec![1, 2, 3])]);
+    let err = module.call("spin", block).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::UDFRuntimeError("").code());
+
+    // The memory can't grow to hold the arguments.
+    let module = WasmUdfModule::create(TEST_MODULE, WasmUdfLimits {
+        max_memory_bytes: 64 * 1024,
+        ..test_limits()
+    })?;
+    let block = DataBlock::new_from_columns(vec![Int32Type::from_data(vec![1; 100_000])]);
+    let err = module.call("echo", block).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::UDFRuntimeError("").code());
+
+    // The result is out of the memory.
+    let module = WasmUdfModule::create(TEST_MODULE, test_limits())?;
+    let block = DataBlock::new_from_columns(vec![Int32Type::from_data(vec![1])]);
+    let err = module.call("oob", block).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::UDFRuntimeError("").code());
+
+    // Not a WebAssembly module.
+    let err = WasmUdfModule::create(b"not wasm", test_limits())
+        .err()
+        .unwrap();
+    assert_eq!(err.code(), ErrorCode::UDFRuntimeError("").code());
+
+    Ok(())
+}
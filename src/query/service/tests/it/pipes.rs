@@ -0,0 +1,346 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use common_base::base::tokio;
+use common_base::base::tokio::time::sleep;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::StageInfo;
+use common_meta_app::storage::StorageParams;
+use common_meta_app::storage::StorageS3Config;
+use common_users::UserApiProvider;
+use databend_query::pipes::merge_binlog_events;
+use databend_query::pipes::merge_rows;
+use databend_query::pipes::parse_binlog_events;
+use databend_query::pipes::parse_object_created_events;
+use databend_query::pipes::pipe_files;
+use databend_query::pipes::MergeRow;
+use databend_query::pipes::ObjectCreated;
+use databend_query::sessions::TableContext;
+use databend_query::test_kits::create_query_context;
+use databend_query::test_kits::table_test_fixture::execute_command;
+use databend_query::test_kits::table_test_fixture::execute_query;
+use databend_query::test_kits::table_test_fixture::expects_ok;
+use serde_json::json;
+use wiremock::matchers::method;
+use wiremock::matchers::path;
+use wiremock::matchers::path_regex;
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::ResponseTemplate;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_pipe_kafka_rest_proxy() -> Result<()> {
+    let server = MockServer::start().await;
+    let base_uri = format!("{}/consumers/pipe/instances/i1", server.uri());
+    Mock::given(method("POST"))
+        .and(path_regex("^/consumers/[^/]+$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "instance_id": "i1",
+            "base_uri": base_uri,
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/topics/orders/partitions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            { "partition": 0, "leader": 1, "replicas": [] },
+        ])))
+        .mount(&server)
+        .await;
+    // the proxy returns the same records on every poll, they must be loaded once.
+    let record = |offset: u64, value: &str| {
+        json!({
+            "topic": "orders",
+            "key": null,
+            "value": BASE64_STANDARD.encode(value),
+            "partition": 0,
+            "offset": offset,
+        })
+    };
+    Mock::given(method("GET"))
+        .and(path("/consumers/pipe/instances/i1/records"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            record(0, r#"{"a": 1, "b": "x"}"#),
+            record(1, r#"{"a": 2, "b": "y"}"#),
+        ])))
+        .mount(&server)
+        .await;
+    Mock::given(path_regex("^/consumers/pipe/instances/i1"))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&server)
+        .await;
+
+    let (_guard, ctx) = create_query_context().await?;
+    let session = ctx.get_current_session();
+    for sql in [
+        "CREATE TABLE default.t(a INT, b STRING)".to_string(),
+        format!(
+            "CREATE PIPE orders_pipe AS COPY INTO default.t FROM KAFKA (rest_proxy_url = '{}' topic = 'orders') FILE_FORMAT = (type = NDJSON)",
+            server.uri()
+        ),
+    ] {
+        execute_command(session.create_query_context().await?, &sql).await?;
+    }
+
+    // the pipes are loaded in the background.
+    let mut pipe = None;
+    for _ in 0..100 {
+        let got = UserApiProvider::instance()
+            .get_pipe(&ctx.get_tenant(), "orders_pipe")
+            .await?
+            .data;
+        if got.loaded_messages > 0 {
+            pipe = Some(got);
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+    let pipe = pipe.expect("the pipe must load the messages");
+    assert_eq!(pipe.loaded_messages, 2);
+    assert_eq!(pipe.offsets.get(&0), Some(&2));
+    assert!(pipe.pending_batch.is_none());
+    assert_eq!(pipe.last_error, "");
+
+    // the next rounds find no new messages.
+    sleep(Duration::from_secs(3)).await;
+    let expected = vec![
+        "+----------+----------+",
+        "| Column 0 | Column 1 |",
+        "+----------+----------+",
+        "| 1        | 'x'      |",
+        "| 2        | 'y'      |",
+        "+----------+----------+",
+    ];
+    expects_ok(
+        "pipe_loaded_rows",
+        execute_query(
+            session.create_query_context().await?,
+            "SELECT a, b FROM default.t ORDER BY a",
+        )
+        .await,
+        expected,
+    )
+    .await?;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_pipe_mysql_binlog() -> Result<()> {
+    let (_guard, ctx) = create_query_context().await?;
+    let session = ctx.get_current_session();
+    let tenant = ctx.get_tenant();
+    for sql in [
+        "CREATE TABLE default.orders(id INT, item STRING, amount INT NULL)",
+        "INSERT INTO default.orders VALUES (1, 'old', 1)",
+        "CREATE PIPE orders_cdc AS COPY INTO default.orders FROM MYSQL (database = 'shop' table = 'orders' primary_key = 'id')",
+    ] {
+        execute_command(session.create_query_context().await?, sql).await?;
+    }
+
+    let uuid = "3e11fa47-71ca-11e1-9e33-c80aa9429562";
+    let event = |id: u64, event_type: &str, data: serde_json::Value| {
+        json!({
+            "gtid": format!("{}:{}", uuid, id),
+            "database": "shop",
+            "table": "orders",
+            "type": event_type,
+            "data": data,
+        })
+        .to_string()
+    };
+    let body = [
+        event(1, "insert", json!({"id": 2, "item": "apple", "amount": 3})),
+        event(
+            1,
+            "insert",
+            json!({"id": 3, "item": "pear", "amount": null}),
+        ),
+        event(2, "update", json!({"id": 1, "item": "new", "amount": 1})),
+        event(
+            3,
+            "delete",
+            json!({"id": 3, "item": "pear", "amount": null}),
+        ),
+    ]
+    .join("\n");
+    let merged = merge_binlog_events(&tenant, "orders_cdc", &body).await?;
+    assert_eq!(merged.merged, 4);
+    assert_eq!(merged.skipped, 0);
+    assert_eq!(merged.gtid_executed.to_string(), format!("{}:1-3", uuid));
+
+    // posted again after a failure, only the events of the last transaction are merged.
+    let merged = merge_binlog_events(&tenant, "orders_cdc", &body).await?;
+    assert_eq!(merged.merged, 1);
+    assert_eq!(merged.skipped, 3);
+
+    let other = json!({
+        "gtid": format!("{}:4", uuid),
+        "database": "shop",
+        "table": "customers",
+        "type": "insert",
+        "data": {"id": 1},
+    });
+    let merged = merge_binlog_events(&tenant, "orders_cdc", &other.to_string()).await?;
+    assert_eq!(merged.merged, 0);
+    assert_eq!(merged.skipped, 1);
+
+    let expected = vec![
+        "+----------+----------+----------+",
+        "| Column 0 | Column 1 | Column 2 |",
+        "+----------+----------+----------+",
+        "| 1        | 'new'    | 1        |",
+        "| 2        | 'apple'  | 3        |",
+        "+----------+----------+----------+",
+    ];
+    expects_ok(
+        "pipe_merged_rows",
+        execute_query(
+            session.create_query_context().await?,
+            "SELECT id, item, amount FROM default.orders ORDER BY id",
+        )
+        .await,
+        expected,
+    )
+    .await?;
+
+    let pipe = UserApiProvider::instance()
+        .get_pipe(&tenant, "orders_cdc")
+        .await?
+        .data;
+    assert_eq!(pipe.loaded_messages, 5);
+    assert_eq!(pipe.last_gtid, format!("{}:3", uuid));
+
+    let invalid = merge_binlog_events(&tenant, "orders_cdc", "{").await;
+    assert_eq!(invalid.unwrap_err().code(), ErrorCode::BAD_ARGUMENTS);
+    Ok(())
+}
+
+#[test]
+fn test_pipe_binlog_merge_rows() -> Result<()> {
+    let events = parse_binlog_events(
+        r#"[
+            {"gtid": "u:1", "database": "shop", "table": "t", "type": "insert", "data": {"id": 1, "v": "a"}},
+            {"gtid": "u:2", "database": "shop", "table": "t", "type": "update", "data": {"id": 2, "v": "b"}, "old": {"id": 1}},
+            {"gtid": "u:3", "database": "shop", "table": "t", "type": "insert", "data": {"id": 3, "v": "c"}},
+            {"gtid": "u:4", "database": "shop", "table": "t", "type": "delete", "data": {"id": 3, "v": "c"}}
+        ]"#,
+    )?;
+    let primary_key = vec!["ID".to_string()];
+    let rows = merge_rows(&primary_key, &events)?;
+    let row = |delete: bool, value: serde_json::Value| MergeRow {
+        delete,
+        values: value.as_object().unwrap().clone(),
+    };
+    assert_eq!(rows, vec![
+        row(true, json!({"id": 1, "v": "b"})),
+        row(false, json!({"id": 2, "v": "b"})),
+        row(true, json!({"id": 3, "v": "c"})),
+    ]);
+
+    let events = parse_binlog_events(
+        r#"{"gtid": "u:1", "database": "shop", "table": "t", "type": "insert", "data": {"v": "a"}}"#,
+    )?;
+    assert_eq!(
+        merge_rows(&primary_key, &events).unwrap_err().code(),
+        ErrorCode::BAD_ARGUMENTS
+    );
+    assert_eq!(
+        parse_binlog_events(r#"{"gtid": "u:1", "type": "upsert"}"#)
+            .unwrap_err()
+            .code(),
+        ErrorCode::BAD_ARGUMENTS
+    );
+    Ok(())
+}
+
+#[test]
+fn test_pipe_s3_events() -> Result<()> {
+    let created = |bucket: &str, key: &str| {
+        json!({
+            "eventName": "ObjectCreated:Put",
+            "s3": { "bucket": { "name": bucket }, "object": { "key": key, "size": 1 } },
+        })
+    };
+    let removed = json!({
+        "eventName": "ObjectRemoved:Delete",
+        "s3": { "bucket": { "name": "b1" }, "object": { "key": "data/orders/x.csv" } },
+    });
+    let notification = json!({
+        "Records": [
+            created("b1", "data/orders/2023/a+b.csv"),
+            created("b1", "data/orders/c%3D1.csv"),
+            created("b1", "data/orders/c.json"),
+            created("b1", "data/orders/"),
+            created("b1", "data/customers/d.csv"),
+            created("b1", "other/orders/e.csv"),
+            created("b2", "data/orders/f.csv"),
+            created("b1", "data/orders/c%3D1.csv"),
+            removed,
+        ]
+    });
+
+    let events = parse_object_created_events(&notification.to_string())?;
+    assert_eq!(events.len(), 8);
+    assert_eq!(events[0], ObjectCreated {
+        bucket: "b1".to_string(),
+        key: "data/orders/2023/a b.csv".to_string(),
+    });
+    assert_eq!(events[1].key, "data/orders/c=1.csv");
+
+    // wrapped in an SNS notification.
+    let sns = json!({
+        "Type": "Notification",
+        "Message": notification.to_string(),
+    });
+    assert_eq!(parse_object_created_events(&sns.to_string())?, events);
+
+    // the test event sent when the notification is configured.
+    let test_event = json!({
+        "Service": "Amazon S3",
+        "Event": "s3:TestEvent",
+        "Bucket": "b1",
+    });
+    assert!(parse_object_created_events(&test_event.to_string())?.is_empty());
+    let err = parse_object_created_events("not json").unwrap_err();
+    assert_eq!(err.code(), ErrorCode::BAD_ARGUMENTS);
+
+    let stage = StageInfo::new_external_stage(
+        StorageParams::S3(StorageS3Config {
+            bucket: "b1".to_string(),
+            root: "/data/".to_string(),
+            ..Default::default()
+        }),
+        "/",
+    )
+    .with_stage_name("s1");
+    assert_eq!(pipe_files(&stage, "/orders/", ".*[.]csv", &events)?, vec![
+        "orders/2023/a b.csv".to_string(),
+        "orders/c=1.csv".to_string(),
+    ]);
+    assert_eq!(pipe_files(&stage, "/", "", &events)?, vec![
+        "customers/d.csv".to_string(),
+        "orders/2023/a b.csv".to_string(),
+        "orders/c.json".to_string(),
+        "orders/c=1.csv".to_string(),
+    ]);
+
+    let err = pipe_files(&stage, "/", "(", &events).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::ILLEGAL_PIPE_FORMAT);
+    Ok(())
+}
@@ -0,0 +1,66 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use databend_query::query_history::QueryHistoryService;
+use databend_query::sessions::Session;
+use databend_query::sessions::TableContext;
+use databend_query::test_kits::create_query_context_with_config;
+use databend_query::test_kits::table_test_fixture::execute_command;
+use databend_query::test_kits::table_test_fixture::execute_query;
+use databend_query::test_kits::table_test_fixture::expects_ok;
+use databend_query::test_kits::ConfigBuilder;
+
+async fn run(session: &Arc<Session>, sql: &str) -> Result<()> {
+    let ctx = session.create_query_context().await?;
+    ctx.attach_query_str("Query".to_string(), sql.to_string());
+    execute_command(ctx, sql).await
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_query_history_flush() -> Result<()> {
+    let mut conf = ConfigBuilder::create().config();
+    // flushed by the test, not the background task.
+    conf.query.query_history_flush_interval_secs = 3600;
+    let (_guard, ctx) = create_query_context_with_config(conf, None).await?;
+    let session = ctx.get_current_session();
+
+    run(&session, "SELECT 1 AS persisted").await?;
+    session
+        .get_settings()
+        .set_setting("query_history_level".to_string(), "error".to_string())?;
+    run(&session, "SELECT 2 AS skipped").await?;
+    QueryHistoryService::instance().flush().await?;
+
+    let expected = vec![
+        "+-------------------------+----------+",
+        "| Column 0                | Column 1 |",
+        "+-------------------------+----------+",
+        "| 'SELECT 1 AS persisted' | 0        |",
+        "+-------------------------+----------+",
+    ];
+    expects_ok(
+        "query_history",
+        execute_query(
+            session.create_query_context().await?,
+            "SELECT query_text, exception_code FROM system_history.query_history",
+        )
+        .await,
+        expected,
+    )
+    .await
+}
@@ -23,11 +23,15 @@ use arrow_flight::sql::client::FlightSqlServiceClient;
 use arrow_flight::utils::flight_data_to_batches;
 use arrow_flight::FlightData;
 use arrow_schema::ArrowError;
+use chrono::Utc;
 use common_base::base::tokio;
 use common_config::InnerConfig;
 use common_exception::Result;
 use common_meta_app::principal::AuthInfo;
+use common_meta_app::principal::NetworkPolicy;
 use common_meta_app::principal::PasswordHashMethod;
+use common_meta_app::principal::UserInfo;
+use common_users::UserApiProvider;
 use databend_query::servers::flight_sql::flight_sql_service::FlightSqlServiceImpl;
 use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestGlobalServices;
@@ -75,6 +79,18 @@ async fn run_query(
     Ok(res)
 }
 
+async fn run_statement(
+    client: &mut FlightSqlServiceClient<Channel>,
+    sql: &str,
+) -> std::result::Result<String, ArrowError> {
+    let flight_info = client.execute(sql.to_string()).await?;
+    let ticket = flight_info.endpoint[0].ticket.as_ref().unwrap().clone();
+    let flight_data = client.do_get(ticket).await?;
+    let flight_data: Vec<FlightData> = flight_data.try_collect().await.unwrap();
+    let batches = flight_data_to_batches(&flight_data)?;
+    Ok(pretty_format_batches(batches.as_slice())?.to_string())
+}
+
 fn prepare_config() -> InnerConfig {
     let hash_method = PasswordHashMethod::DoubleSha1;
     let hash_value = hash_method.hash(TEST_PASSWORD.as_bytes());
@@ -133,6 +149,22 @@ async fn test_query() -> Result<()> {
             };
             writeln!(file, "{}", res).unwrap();
         }
+
+        let mut file = mint.new_goldenfile("statement.txt").unwrap();
+        let cases = [
+            "select number as a, number * 2 as b from numbers(3) order by a",
+            "select a from test1 order by a",
+        ];
+        for case in cases {
+            writeln!(file, "---------- Input ----------").unwrap();
+            writeln!(file, "{}", case).unwrap();
+            writeln!(file, "---------- Output ---------").unwrap();
+            let res = match run_statement(&mut client, case).await {
+                Ok(s) => s,
+                Err(e) => format!("{e:?}"),
+            };
+            writeln!(file, "{}", res).unwrap();
+        }
     };
     tokio::pin!(serve_future);
 
@@ -148,3 +180,66 @@ async fn test_query() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_network_policy() -> Result<()> {
+    let config = prepare_config();
+    let tenant = config.query.tenant_id.clone();
+    let _guard = TestGlobalServices::setup(config).await?;
+
+    // the user can only connect from the office, the address of the client of a unix
+    // domain socket is unknown.
+    let user_api = UserApiProvider::instance();
+    let policy = NetworkPolicy {
+        name: "office".to_string(),
+        allowed_ip_list: vec!["10.0.0.0/8".to_string()],
+        blocked_ip_list: vec![],
+        comment: "".to_string(),
+        created_on: Utc::now(),
+    };
+    user_api.add_network_policy(&tenant, policy, false).await?;
+    let hash_method = PasswordHashMethod::DoubleSha1;
+    let mut user_info = UserInfo::new("policy_user", "%", AuthInfo::Password {
+        hash_value: hash_method.hash(TEST_PASSWORD.as_bytes()),
+        hash_method,
+    });
+    user_info
+        .option
+        .set_network_policy(Some("office".to_string()));
+    user_api.add_user(&tenant, user_info, false).await?;
+
+    let file = NamedTempFile::new().unwrap();
+    let path = file.into_temp_path().to_str().unwrap().to_string();
+    let _ = fs::remove_file(path.clone());
+
+    let uds = UnixListener::bind(path.clone()).unwrap();
+    let stream = UnixListenerStream::new(uds);
+
+    let service = FlightSqlServiceImpl::create();
+    let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+    let serve_future = Server::builder()
+        .add_service(FlightServiceServer::new(service))
+        .serve_with_incoming_shutdown(stream, async { shutdown_rx.await.unwrap() });
+
+    let request_future = async {
+        let mut client = client_with_uds(path.clone()).await;
+        let res = client.handshake("policy_user", TEST_PASSWORD).await;
+        assert!(res.is_err());
+
+        let mut client = client_with_uds(path).await;
+        let res = client.handshake(TEST_USER, TEST_PASSWORD).await;
+        assert!(res.is_ok());
+    };
+    tokio::pin!(serve_future);
+
+    tokio::select! {
+        _ = &mut serve_future => panic!("server returned first"),
+        _ = request_future => {
+            debug!("Client finished!");
+        }
+    }
+    shutdown_tx.send(()).unwrap();
+    serve_future.await.unwrap();
+
+    Ok(())
+}
@@ -268,6 +268,90 @@ async fn test_insert_format_ndjson() -> PoemResult<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_insert_format_row_binary_and_native() -> PoemResult<()> {
+    let config = ConfigBuilder::create().build();
+    let _guard = TestGlobalServices::setup(config.clone()).await.unwrap();
+
+    let server = Server::new().await;
+    {
+        let (status, body) = server
+            .post("create table t1(a int, b string null)", "")
+            .await;
+        assert_ok!(status, body);
+    }
+
+    fn string(buf: &mut Vec<u8>, s: &str) {
+        buf.push(s.len() as u8);
+        buf.extend_from_slice(s.as_bytes());
+    }
+
+    {
+        // the query in the url and the data in the body.
+        let mut data = vec![];
+        data.extend_from_slice(&0i32.to_le_bytes());
+        data.push(0);
+        string(&mut data, "a");
+        data.extend_from_slice(&1i32.to_le_bytes());
+        data.push(1);
+        let req = QueryBuilder::new("insert into table t1 format RowBinary")
+            .body(data)
+            .build();
+        let (status, body) = server.get_response(req).await;
+        assert_ok!(status, body);
+    }
+
+    {
+        // the query followed by the data in the body.
+        let mut data = b"insert into t1 format RowBinaryWithNamesAndTypes\n".to_vec();
+        data.push(2);
+        string(&mut data, "b");
+        string(&mut data, "a");
+        string(&mut data, "String");
+        string(&mut data, "UInt8");
+        string(&mut data, "c");
+        data.push(2);
+        let req = QueryBuilder::new("").body(data).build();
+        let (status, body) = server.get_response(req).await;
+        assert_ok!(status, body);
+    }
+
+    {
+        let mut data = vec![2, 2];
+        string(&mut data, "a");
+        string(&mut data, "Int64");
+        data.extend_from_slice(&3i64.to_le_bytes());
+        data.extend_from_slice(&4i64.to_le_bytes());
+        string(&mut data, "b");
+        string(&mut data, "Nullable(String)");
+        data.extend_from_slice(&[1, 0]);
+        string(&mut data, "");
+        string(&mut data, "d");
+        let req = QueryBuilder::new("insert into table t1 format Native")
+            .body(data)
+            .build();
+        let (status, body) = server.get_response(req).await;
+        assert_ok!(status, body);
+    }
+
+    {
+        let (status, body) = server.get(r#"select * from t1 order by a"#).await;
+        assert_ok!(status, body);
+        assert_eq!(&body, "0\ta\n1\t\\N\n2\tc\n3\t\\N\n4\td\n");
+    }
+
+    {
+        let data = 5i32.to_le_bytes().to_vec();
+        let req = QueryBuilder::new("insert into table t1 format RowBinary")
+            .body(data)
+            .build();
+        let (status, body) = server.get_response(req).await;
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_error!(body, "RowBinary");
+    }
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_settings() -> PoemResult<()> {
     let config = ConfigBuilder::create().build();
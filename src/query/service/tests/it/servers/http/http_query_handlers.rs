@@ -21,6 +21,7 @@ use base64::engine::general_purpose;
 use base64::prelude::*;
 use common_base::base::get_free_tcp_port;
 use common_base::base::tokio;
+use common_catalog::txn::TxnState;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::principal::AuthInfo;
@@ -372,6 +373,13 @@ async fn test_pagination() -> Result<()> {
         r#"{"error":{"code":"404","message":"wrong page number 6"}}"#
     );
 
+    // retry the first page
+    let uri = make_page_uri(query_id, 0);
+    let (status, retry) = get_uri_checked(&ep, &uri).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", retry);
+    assert_eq!(retry.data, result.data, "{:?}", retry);
+    assert_eq!(retry.next_uri, result.next_uri, "{:?}", retry);
+
     let mut next_uri = result.next_uri.clone().unwrap();
 
     for page in 1..5 {
@@ -380,6 +388,15 @@ async fn test_pagination() -> Result<()> {
         assert_eq!(status, StatusCode::OK, "{:?}", msg());
         assert!(result.error.is_none(), "{:?}", msg());
         assert!(!result.schema.is_empty(), "{:?}", result);
+
+        // a retry gets the same page, and the previous page is dropped
+        let (status, retry) = get_uri_checked(&ep, &next_uri).await?;
+        assert_eq!(status, StatusCode::OK, "{:?}", msg());
+        assert_eq!(retry.data, result.data, "{:?}", msg());
+        assert_eq!(retry.next_uri, result.next_uri, "{:?}", msg());
+        let response = get_uri(&ep, &make_page_uri(query_id, page - 1)).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND, "{:?}", msg());
+
         if page == 5 {
             // get state
             let uri = make_state_uri(query_id);
@@ -433,6 +450,126 @@ async fn test_http_session() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_http_session_of_other_user() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let ep = create_endpoint().await?;
+    let json = serde_json::json!({"sql": "create user u_other identified by 'p1'"});
+    let (status, result) = post_json_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+
+    let json =
+        serde_json::json!({"sql":  "use system", "session": {"keep_server_session_secs": 10}});
+    let (status, result) = post_json_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    let session_id = &result.session_id.unwrap();
+
+    // the session of root can not be resumed by another user.
+    let json = serde_json::json!({"sql": "select database()", "session_id": session_id});
+    let (status, result) = post_json_to_endpoint_as(&ep, &json, "u_other", "p1").await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_some(), "{:?}", result);
+    assert_eq!(result.data.len(), 0, "{:?}", result);
+
+    let (status, result) = post_json_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+    assert_eq!(result.data[0][0], "system", "{:?}", result);
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_http_session_txn() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let ep = create_endpoint().await?;
+
+    // BEGIN keeps the session on the server without keep_server_session_secs.
+    let json = serde_json::json!({"sql": "begin"});
+    let (status, result) = post_json_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+    assert_eq!(result.state, ExecuteStateKind::Succeeded, "{:?}", result);
+    let session_id = result.session_id.clone().unwrap();
+    let session = result.session.unwrap();
+    assert_eq!(session.txn_state, Some(TxnState::Active), "{:?}", session);
+
+    let json = serde_json::json!({"sql": "select 1", "session_id": session_id, "session": session});
+    let (status, result) = post_json_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+    assert_eq!(result.data.len(), 1, "{:?}", result);
+    let session = result.session.unwrap();
+    assert_eq!(session.txn_state, Some(TxnState::Active), "{:?}", session);
+
+    let json = serde_json::json!({"sql": "commit", "session_id": session_id, "session": session});
+    let (status, result) = post_json_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+    let session = result.session.unwrap();
+    assert_eq!(
+        session.txn_state,
+        Some(TxnState::AutoCommit),
+        "{:?}",
+        session
+    );
+
+    // the transaction can not silently fall back to auto commit.
+    let json = serde_json::json!({"sql": "select 1", "session": {"txn_state": "Active"}});
+    let (status, result) = post_json_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_some(), "{:?}", result);
+
+    let json = serde_json::json!({"sql": "select 1", "session_id": session_id, "session": {"txn_state": "Active"}});
+    let (status, result) = post_json_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_some(), "{:?}", result);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_http_session_txn_expire() -> Result<()> {
+    let config = ConfigBuilder::create()
+        .http_handler_result_timeout(1u64)
+        .build();
+    let _guard = TestGlobalServices::setup(config).await?;
+
+    let ep = create_endpoint().await?;
+
+    // the session is kept until the end of the transaction.
+    let json = serde_json::json!({"sql": "begin"});
+    let (status, result) = post_json_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+    let session_id = result.session_id.clone().unwrap();
+    let session = result.session.unwrap();
+    check_final(&ep, &make_final_uri(&result.id)).await?;
+    sleep(std::time::Duration::from_secs(3)).await;
+
+    let json = serde_json::json!({"sql": "commit", "session_id": session_id, "session": session});
+    let (status, result) = post_json_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+    let session = result.session.unwrap();
+    assert_eq!(
+        session.txn_state,
+        Some(TxnState::AutoCommit),
+        "{:?}",
+        session
+    );
+    check_final(&ep, &make_final_uri(&result.id)).await?;
+
+    // then it expires like the results of a query.
+    sleep(std::time::Duration::from_secs(3)).await;
+    let json = serde_json::json!({"sql": "select 1", "session_id": session_id, "session": session});
+    let (status, result) = post_json_to_endpoint(&ep, &json).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_some(), "{:?}", result);
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_result_timeout() -> Result<()> {
     let config = ConfigBuilder::create()
@@ -715,11 +852,20 @@ async fn post_sql_to_endpoint_new_session(
 async fn post_json_to_endpoint(
     ep: &EndpointType,
     json: &serde_json::Value,
+) -> Result<(StatusCode, QueryResponse)> {
+    post_json_to_endpoint_as(ep, json, "root", "").await
+}
+
+async fn post_json_to_endpoint_as(
+    ep: &EndpointType,
+    json: &serde_json::Value,
+    user: &str,
+    password: &str,
 ) -> Result<(StatusCode, QueryResponse)> {
     let uri = "/v1/query";
     let content_type = "application/json";
     let body = serde_json::to_vec(&json)?;
-    let basic = headers::Authorization::basic("root", "");
+    let basic = headers::Authorization::basic(user, password);
 
     let req = Request::builder()
         .uri(uri.parse().unwrap())
@@ -1184,6 +1330,7 @@ async fn test_affect() -> Result<()> {
                     ("max_threads".to_string(), "1".to_string()),
                     ("timezone".to_string(), "Asia/Shanghai".to_string()),
                 ])),
+                txn_state: None,
             }),
         ),
         (
@@ -1196,6 +1343,7 @@ async fn test_affect() -> Result<()> {
                     "max_threads".to_string(),
                     "6".to_string(),
                 )])),
+                txn_state: None,
             }),
         ),
         (
@@ -1210,6 +1358,7 @@ async fn test_affect() -> Result<()> {
                     "max_threads".to_string(),
                     "6".to_string(),
                 )])),
+                txn_state: None,
             }),
         ),
     ];
@@ -12,8 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use common_base::base::tokio;
 use common_exception::Result;
+use common_meta_app::principal::AuthInfo;
+use common_meta_app::principal::RoleInfo;
+use common_meta_app::principal::UserInfo;
+use common_settings::ScopeLevel;
+use common_users::RoleCacheManager;
+use common_users::UserApiProvider;
 use databend_query::sessions::SessionManager;
 use databend_query::sessions::SessionType;
 use databend_query::test_kits::TestGlobalServices;
@@ -69,3 +77,58 @@ async fn test_session_setting_override() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_session_setting_profiles() -> Result<()> {
+    let _guard =
+        TestGlobalServices::setup(databend_query::test_kits::ConfigBuilder::create().build())
+            .await?;
+    let session = SessionManager::instance()
+        .create_session(SessionType::Dummy)
+        .await?;
+    let tenant = session.get_current_tenant();
+
+    let mut role = RoleInfo::new("r_profile");
+    role.settings = BTreeMap::from([
+        ("max_threads".to_string(), "2".to_string()),
+        ("max_storage_io_requests".to_string(), "77".to_string()),
+    ]);
+    UserApiProvider::instance()
+        .add_role(&tenant, role, false)
+        .await?;
+    RoleCacheManager::instance().force_reload(&tenant).await?;
+
+    let mut user = UserInfo::new("u_profile", "%", AuthInfo::None);
+    user.grants.grant_role("r_profile".to_string());
+    user.option = user
+        .option
+        .with_default_role(Some("r_profile".to_string()))
+        .with_settings(BTreeMap::from([
+            ("max_threads".to_string(), "5".to_string()),
+            ("max_block_size".to_string(), "1000".to_string()),
+        ]));
+
+    // set in the session before login, kept.
+    let settings = session.get_settings();
+    settings.set_setting("max_block_size".to_string(), "2000".to_string())?;
+    session.set_authed_user(user, None).await?;
+
+    // the user's take precedence over the role's.
+    assert_eq!(settings.get_max_threads()?, 5);
+    assert!(matches!(
+        settings.get_setting_level("max_threads")?,
+        ScopeLevel::User
+    ));
+    assert_eq!(settings.get_max_storage_io_requests()?, 77);
+    assert!(matches!(
+        settings.get_setting_level("max_storage_io_requests")?,
+        ScopeLevel::Role
+    ));
+    assert_eq!(settings.get_max_block_size()?, 2000);
+    assert!(matches!(
+        settings.get_setting_level("max_block_size")?,
+        ScopeLevel::Session
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,130 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_config::WorkloadGroup;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::UserInfo;
+use databend_query::sessions::QueryContext;
+use databend_query::sessions::SessionManager;
+use databend_query::sessions::SessionType;
+use databend_query::sessions::TableContext;
+use databend_query::sessions::WorkloadManager;
+use databend_query::test_kits::ConfigBuilder;
+use databend_query::test_kits::TestGlobalServices;
+
+async fn create_ctx(group: &str) -> Result<Arc<QueryContext>> {
+    let session = SessionManager::instance()
+        .create_session(SessionType::HTTPQuery)
+        .await?;
+    let mut user = UserInfo::new_no_auth("root", "%");
+    if !group.is_empty() {
+        user.option
+            .set_setting("workload_group".to_string(), group.to_string());
+    }
+    session.set_authed_user(user, None).await?;
+    session.create_query_context().await
+}
+
+fn queued_queries() -> usize {
+    WorkloadManager::instance()
+        .queries_info()
+        .iter()
+        .filter(|q| q.state == "Queued")
+        .count()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_workload_group_queue() -> Result<()> {
+    let mut conf = ConfigBuilder::create().config();
+    conf.query.workload_groups = vec![
+        WorkloadGroup {
+            name: "g1".to_string(),
+            max_concurrency: 1,
+            memory_quota: 0,
+            queue_timeout_secs: 0,
+        },
+        WorkloadGroup {
+            name: "g2".to_string(),
+            max_concurrency: 1,
+            memory_quota: 0,
+            queue_timeout_secs: 1,
+        },
+    ];
+    let _guard = TestGlobalServices::setup(conf).await?;
+
+    let ctx1 = create_ctx("g1").await?;
+    assert!(ctx1.acquire_workload_permit().await?);
+    // the permit is held by the query already.
+    assert!(!ctx1.acquire_workload_permit().await?);
+
+    let ctx2 = create_ctx("g1").await?;
+    let ctx2_clone = ctx2.clone();
+    let handle = tokio::spawn(async move { ctx2_clone.acquire_workload_permit().await });
+    while queued_queries() == 0 {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert!(!handle.is_finished());
+
+    ctx1.release_workload_permit();
+    assert!(handle.await.unwrap()?);
+    assert_eq!(queued_queries(), 0);
+    let info = WorkloadManager::instance().queries_info();
+    assert_eq!(info.len(), 1);
+    assert_eq!(info[0].query_id, ctx2.get_id());
+    assert_eq!(info[0].state, "Running");
+    ctx2.release_workload_permit();
+
+    // waits no longer than queue_timeout_secs.
+    let ctx3 = create_ctx("g2").await?;
+    assert!(ctx3.acquire_workload_permit().await?);
+    let ctx4 = create_ctx("g2").await?;
+    assert!(ctx4.acquire_workload_permit().await.is_err());
+    assert_eq!(queued_queries(), 0);
+
+    let ctx5 = create_ctx("unknown").await?;
+    assert!(ctx5.acquire_workload_permit().await.is_err());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_workload_group_of_user() -> Result<()> {
+    let mut conf = ConfigBuilder::create().config();
+    conf.query.workload_groups = vec![WorkloadGroup {
+        name: "g1".to_string(),
+        max_concurrency: 1,
+        memory_quota: 0,
+        queue_timeout_secs: 0,
+    }];
+    let _guard = TestGlobalServices::setup(conf).await?;
+
+    // the session can not leave or change the group of the user.
+    let ctx = create_ctx("g1").await?;
+    let settings = ctx.get_settings();
+    let err = settings
+        .set_setting("workload_group".to_string(), "".to_string())
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::PERMISSION_DENIED);
+    assert_eq!(settings.get_workload_group()?, "g1");
+    assert!(ctx.acquire_workload_permit().await?);
+    ctx.release_workload_permit();
+
+    let ctx = create_ctx("").await?;
+    assert!(!ctx.acquire_workload_permit().await?);
+    Ok(())
+}
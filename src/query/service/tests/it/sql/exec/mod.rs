@@ -12,6 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_base::base::tokio;
+use common_exception::Result;
+use common_sql::Planner;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::test_kits::table_test_fixture::execute_command;
+use databend_query::test_kits::table_test_fixture::TestFixture;
+
 #[test]
 pub fn test_format_field_name() {
     use databend_query::sql::executor::decode_field_name;
@@ -22,3 +29,43 @@ pub fn test_format_field_name() {
     let (decoded_name, decoded_index) = decode_field_name(field_name.as_str()).unwrap();
     assert!(decoded_name == display_name && decoded_index == index);
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_adaptive_parallelism() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let db = fixture.default_db_name();
+    ctx.get_settings().set_max_threads(8)?;
+    ctx.get_settings()
+        .set_setting("max_block_size".to_string(), "100".to_string())?;
+
+    execute_command(ctx.clone(), &format!("create table {db}.t(a int)")).await?;
+    execute_command(
+        ctx.clone(),
+        &format!("insert into {db}.t values (1), (2), (3)"),
+    )
+    .await?;
+
+    let numbers_query = "select * from numbers_mt(10000) where number > 1".to_string();
+    // The statistics of the scan are not exact because the filter is pushed down to it,
+    // the bytes to read are estimated by the blocks left after pruning.
+    let fuse_query = format!("select * from {db}.t where a > 1");
+    let cases = [
+        (&numbers_query, "0", 8),
+        (&numbers_query, "1", 1),
+        (&fuse_query, "1", 1),
+    ];
+    for (query, enable, max_threads) in cases {
+        ctx.get_settings().set_setting(
+            "enable_adaptive_parallelism".to_string(),
+            enable.to_string(),
+        )?;
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, _) = planner.plan_sql(query).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        let build_res = interpreter.execute2().await?;
+        assert_eq!(build_res.main_pipeline.get_max_threads(), max_threads);
+    }
+
+    Ok(())
+}
@@ -160,6 +160,7 @@ fn test_format() {
                 marker_index: None,
                 from_correlated_subquery: false,
                 contain_runtime_filter: false,
+                is_lateral: false,
             }
             .into(),
         ),
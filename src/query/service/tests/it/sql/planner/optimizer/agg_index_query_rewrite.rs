@@ -77,6 +77,8 @@ fn create_table_plan(fixture: &TestFixture) -> CreateTablePlan {
         .into(),
         field_comments: vec![],
         as_select: None,
+        template: None,
+        identity_sequences: vec![],
         cluster_key: None,
     }
 }
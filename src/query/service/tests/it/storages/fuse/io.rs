@@ -12,8 +12,16 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_catalog::table_context::TableContext;
 use common_exception::Result;
+use databend_query::storages::fuse::io::write_data_multipart;
 use databend_query::storages::fuse::io::TableMetaLocationGenerator;
+use databend_query::storages::fuse::io::UploadSettings;
+use databend_query::test_kits::table_test_fixture::TestFixture;
+use opendal::Operator;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::Versioned;
 use uuid::Uuid;
@@ -31,3 +39,44 @@ fn test_meta_locations() -> Result<()> {
     assert!(snapshot_loc.starts_with(test_prefix));
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_write_data_multipart() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let mut builder = opendal::services::Fs::default();
+    builder.root(dir.path().to_str().unwrap());
+    let operator = Operator::new(builder)?.finish();
+
+    let settings = UploadSettings {
+        multipart_threshold: 0,
+        multipart_part_size: 5 * 1024 * 1024,
+        multipart_max_retries: 3,
+    };
+    // two full parts and a smaller last one.
+    let data: Vec<u8> = (0..11 * 1024 * 1024).map(|i| (i % 251) as u8).collect();
+    write_data_multipart(data.clone().into(), &operator, "block", &settings).await?;
+    assert_eq!(operator.read("block").await?, data);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_upload_settings() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx: Arc<dyn TableContext> = fixture.ctx();
+
+    let settings = UploadSettings::from_ctx(&ctx)?;
+    assert_eq!(settings.multipart_threshold, 32 * 1024 * 1024);
+    assert_eq!(settings.multipart_part_size, 8 * 1024 * 1024);
+
+    ctx.get_settings()
+        .set_storage_multipart_upload_threshold(64 * 1024 * 1024)?;
+    // the parts except the last one are at least 5MiB.
+    ctx.get_settings()
+        .set_storage_multipart_upload_part_size(1024 * 1024)?;
+    let settings = UploadSettings::from_ctx(&ctx)?;
+    assert_eq!(settings.multipart_threshold, 64 * 1024 * 1024);
+    assert_eq!(settings.multipart_part_size, 5 * 1024 * 1024);
+
+    Ok(())
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_ast::ast::ClusterType;
 use common_ast::ast::Engine;
 use common_base::base::tokio;
 use common_sql::plans::AlterTableClusterKeyPlan;
@@ -52,6 +53,8 @@ async fn test_fuse_alter_table_cluster_key() -> common_exception::Result<()> {
         .into(),
         field_comments: vec![],
         as_select: None,
+        template: None,
+        identity_sequences: vec![],
         cluster_key: None,
     };
 
@@ -65,6 +68,7 @@ async fn test_fuse_alter_table_cluster_key() -> common_exception::Result<()> {
         catalog: fixture.default_catalog_name(),
         database: fixture.default_db_name(),
         table: fixture.default_table_name(),
+        cluster_type: ClusterType::Linear,
         cluster_keys: vec!["id".to_string()],
     };
     let interpreter =
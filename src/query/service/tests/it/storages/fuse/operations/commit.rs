@@ -26,12 +26,16 @@ use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartInfoPtr;
 use common_catalog::plan::Partitions;
 use common_catalog::table::Table;
+use common_catalog::table_context::NodeProcessInfo;
 use common_catalog::table_context::ProcessInfo;
 use common_catalog::table_context::StageAttachment;
 use common_catalog::table_context::TableContext;
+use common_catalog::table_context::WorkloadQueryInfo;
+use common_catalog::txn::TxnManagerRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
+use common_expression::Expr;
 use common_expression::FunctionContext;
 use common_io::prelude::FormatSettings;
 use common_meta_app::principal::FileFormatParams;
@@ -61,6 +65,8 @@ use common_meta_app::schema::GetTableCopiedFileReply;
 use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::IndexMeta;
 use common_meta_app::schema::ListIndexesReq;
+use common_meta_app::schema::ListTableCopiedFileReply;
+use common_meta_app::schema::ListTableCopiedFileReq;
 use common_meta_app::schema::ListVirtualColumnsReq;
 use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
@@ -75,6 +81,8 @@ use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpdateVirtualColumnReply;
@@ -389,6 +397,14 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn set_runtime_filter(&self, _filters: (usize, Vec<Expr<String>>)) {
+        todo!()
+    }
+
+    fn get_runtime_filters(&self, _table_index: usize) -> Vec<Expr<String>> {
+        todo!()
+    }
+
     fn get_cacheable(&self) -> bool {
         todo!()
     }
@@ -441,6 +457,10 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    async fn get_all_effective_roles(&self) -> Result<Vec<RoleInfo>> {
+        todo!()
+    }
+
     fn get_fuse_version(&self) -> String {
         todo!()
     }
@@ -481,6 +501,14 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn get_workload_queries_info(&self) -> Vec<WorkloadQueryInfo> {
+        todo!()
+    }
+
+    async fn get_cluster_processes_info(&self) -> Result<Vec<NodeProcessInfo>> {
+        todo!()
+    }
+
     fn get_stage_attachment(&self) -> Option<StageAttachment> {
         todo!()
     }
@@ -522,6 +550,10 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn txn_mgr(&self) -> TxnManagerRef {
+        self.ctx.txn_mgr()
+    }
+
     fn get_query_profile_manager(&self) -> Arc<QueryProfileManager> {
         todo!()
     }
@@ -653,6 +685,17 @@ impl Catalog for FakedCatalog {
         }
     }
 
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply> {
+        if let Some(e) = &self.error_injection {
+            Err(e.clone())
+        } else {
+            self.cat.update_multi_table_meta(req).await
+        }
+    }
+
     async fn count_tables(&self, _req: CountTablesReq) -> Result<CountTablesReply> {
         todo!()
     }
@@ -666,6 +709,15 @@ impl Catalog for FakedCatalog {
         todo!()
     }
 
+    async fn list_table_copied_file_info(
+        &self,
+        _tenant: &str,
+        _db_name: &str,
+        _req: ListTableCopiedFileReq,
+    ) -> Result<ListTableCopiedFileReply> {
+        todo!()
+    }
+
     async fn truncate_table(
         &self,
         _table_info: &TableInfo,
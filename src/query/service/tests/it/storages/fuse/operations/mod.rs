@@ -26,5 +26,6 @@ mod purge_drop;
 mod purge_truncate;
 mod read_plan;
 mod replace_into;
+mod replicate;
 mod table_analyze;
 mod truncate;
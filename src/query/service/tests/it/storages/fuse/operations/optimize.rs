@@ -13,10 +13,13 @@
 //  limitations under the License.
 
 use common_base::base::tokio;
+use common_catalog::table::CompactTarget;
 use common_exception::Result;
 use common_sql::Planner;
+use common_storages_fuse::FuseTable;
 use common_storages_fuse::TableContext;
 use databend_query::interpreters::InterpreterFactory;
+use databend_query::test_kits::table_test_fixture::execute_command;
 use databend_query::test_kits::table_test_fixture::TestFixture;
 use futures_util::TryStreamExt;
 
@@ -84,3 +87,59 @@ async fn test_fuse_table_optimize() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_table_auto_compact_target() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let tbl_name = fixture.default_table_name();
+    let db_name = fixture.default_db_name();
+
+    fixture.create_normal_table().await?;
+
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    assert!(!fuse_table.auto_compact_enabled());
+    // no snapshot, nothing to compact.
+    assert!(fuse_table.auto_compact_target().await?.is_none());
+
+    let query = format!("alter table {db_name}.{tbl_name} set options(auto_compact = 'true')");
+    execute_command(ctx.clone(), &query).await?;
+
+    // insert 5 times, there will be 5 segments with 1 tiny block each.
+    let n = 5;
+    for _ in 0..n {
+        let table = fixture.latest_default_table().await?;
+        let stream = TestFixture::gen_sample_blocks_stream(1, 1);
+        let blocks = stream.try_collect().await?;
+        fixture
+            .append_commit_blocks(table.clone(), blocks, false, true)
+            .await?;
+    }
+
+    // the segments are compacted first.
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    assert!(fuse_table.auto_compact_enabled());
+    let target = fuse_table.auto_compact_target().await?;
+    assert!(matches!(target, Some(CompactTarget::Segments)));
+
+    ctx.get_settings().set_max_threads(1)?;
+    let query = format!("optimize table {db_name}.{tbl_name} compact segment");
+    execute_command(ctx.clone(), &query).await?;
+
+    // then the blocks.
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let target = fuse_table.auto_compact_target().await?;
+    assert!(matches!(target, Some(CompactTarget::Blocks)));
+
+    let query = format!("optimize table {db_name}.{tbl_name} compact");
+    execute_command(ctx.clone(), &query).await?;
+
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    assert!(fuse_table.auto_compact_target().await?.is_none());
+
+    Ok(())
+}
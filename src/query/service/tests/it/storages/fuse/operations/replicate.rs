@@ -0,0 +1,68 @@
+//  Copyright 2023 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_storages_fuse::FuseTable;
+use common_storages_fuse::TableContext;
+use databend_query::test_kits::table_test_fixture::append_sample_data;
+use databend_query::test_kits::table_test_fixture::TestFixture;
+use opendal::Operator;
+
+use crate::storages::fuse::utils::do_insertions;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_replicate() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx: Arc<dyn TableContext> = fixture.ctx();
+    let target = Operator::new(opendal::services::Memory::default())?.finish();
+
+    // the latest snapshot: 1 segment, 1 block, 1 bloom index
+    do_insertions(&fixture).await?;
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let result = fuse_table
+        .do_replicate(ctx.clone(), &target)
+        .await?
+        .unwrap();
+    assert_eq!(result.copied_files, 4);
+    assert!(result.copied_bytes > 0);
+
+    let snapshot_loc = fuse_table.snapshot_loc().await?;
+    let replicated =
+        FuseTable::read_attached_snapshot_location(&target, fuse_table.meta_location_generator())
+            .await?;
+    assert_eq!(replicated, snapshot_loc);
+
+    // up to date, nothing is copied
+    let result = fuse_table
+        .do_replicate(ctx.clone(), &target)
+        .await?
+        .unwrap();
+    assert_eq!(result.copied_files, 0);
+
+    // only the new segment, block, bloom index and snapshot are copied
+    append_sample_data(1, &fixture).await?;
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let result = fuse_table
+        .do_replicate(ctx.clone(), &target)
+        .await?
+        .unwrap();
+    assert_eq!(result.copied_files, 4);
+
+    Ok(())
+}
@@ -92,6 +92,31 @@ async fn test_table_modify_column_ndv_statistics() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_table_ndv_statistics_collected_on_write() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    // setup
+    let create_tbl_command = "create table t(c int)";
+    execute_command(ctx.clone(), create_tbl_command).await?;
+
+    let catalog = ctx.get_catalog("default")?;
+
+    // append the same values twice, without analyze.
+    let num_inserts = 3;
+    append_rows(ctx.clone(), num_inserts).await?;
+    append_rows(ctx.clone(), num_inserts).await?;
+
+    let table = catalog
+        .get_table(ctx.get_tenant().as_str(), "default", "t")
+        .await?;
+    let expected = HashMap::from([(0, num_inserts as u64)]);
+    check_column_ndv_statistics(table, expected).await?;
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_table_update_analyze_statistics() -> Result<()> {
     let fixture = TestFixture::new().await;
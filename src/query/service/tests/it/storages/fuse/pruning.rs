@@ -100,6 +100,8 @@ async fn test_block_pruner() -> Result<()> {
         .into(),
         field_comments: vec![],
         as_select: None,
+        template: None,
+        identity_sequences: vec![],
         cluster_key: None,
     };
 
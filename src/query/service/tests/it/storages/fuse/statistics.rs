@@ -43,6 +43,7 @@ use common_storages_fuse::statistics::STATS_REPLACEMENT_CHAR;
 use common_storages_fuse::statistics::STATS_STRING_PREFIX_LEN;
 use common_storages_fuse::FuseStorageFormat;
 use databend_query::storages::fuse::io::TableMetaLocationGenerator;
+use databend_query::storages::fuse::statistics::gen_columns_distinct_hll;
 use databend_query::storages::fuse::statistics::gen_columns_statistics;
 use databend_query::storages::fuse::statistics::reducers;
 use databend_query::storages::fuse::statistics::ClusterStatsGenerator;
@@ -51,6 +52,7 @@ use databend_query::test_kits::block_writer::BlockWriter;
 use databend_query::test_kits::table_test_fixture::TestFixture;
 use opendal::Operator;
 use rand::Rng;
+use storages_common_table_meta::meta::merge_column_hlls;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterStatistics;
 use storages_common_table_meta::meta::ColumnStatistics;
@@ -108,6 +110,50 @@ fn test_ft_stats_block_stats_with_column_distinct_count() -> common_exception::R
     Ok(())
 }
 
+#[test]
+fn test_ft_stats_block_distinct_hll() -> common_exception::Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![
+        TableField::new("a", TableDataType::Number(NumberDataType::Int32)),
+        TableField::new("b", TableDataType::String),
+    ]));
+    let func_ctx = FunctionContext::default();
+
+    let block = DataBlock::new_from_columns(vec![
+        Int32Type::from_data(vec![1, 2, 3]),
+        StringType::from_data(vec!["aa", "aa", "bb"]),
+    ]);
+    let mut r = gen_columns_distinct_hll(&func_ctx, &block, &schema)?;
+    assert_eq!(2, r.len());
+    assert_eq!(r.get(&0).unwrap().count(), 3);
+    assert_eq!(r.get(&1).unwrap().count(), 2);
+
+    // merge the sketches of another block, the overlapping values are not counted twice.
+    let block = DataBlock::new_from_columns(vec![
+        Int32Type::from_data(vec![3, 4, 5]),
+        StringType::from_data(vec!["bb", "cc", "cc"]),
+    ]);
+    let other = gen_columns_distinct_hll(&func_ctx, &block, &schema)?;
+    merge_column_hlls(&mut r, &other);
+    assert_eq!(r.get(&0).unwrap().count(), 5);
+    assert_eq!(r.get(&1).unwrap().count(), 3);
+
+    // large number of distinct values, the error should be small.
+    let num_rows = 100000;
+    let block = DataBlock::new_from_columns(vec![
+        Int32Type::from_data((0..num_rows).collect::<Vec<_>>()),
+        StringType::from_data(
+            (0..num_rows)
+                .map(|v| (v % 10).to_string())
+                .collect::<Vec<_>>(),
+        ),
+    ]);
+    let r = gen_columns_distinct_hll(&func_ctx, &block, &schema)?;
+    let ndv = r.get(&0).unwrap().count() as f64;
+    assert!((ndv - num_rows as f64).abs() / (num_rows as f64) < 0.1);
+    assert_eq!(r.get(&1).unwrap().count(), 10);
+    Ok(())
+}
+
 #[test]
 fn test_ft_tuple_stats_block_stats() -> common_exception::Result<()> {
     let schema = Arc::new(TableSchema::new(vec![TableField::new(
@@ -0,0 +1,113 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_storages_fuse::FuseTable;
+use databend_query::sessions::QueryContext;
+use databend_query::sessions::TableContext;
+use databend_query::test_kits::table_test_fixture::*;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_changes_table() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let ctx = fixture.ctx();
+
+    execute_command(ctx.clone(), &format!("create table {}.t(a int)", db)).await?;
+    execute_command(ctx.clone(), &format!("insert into {}.t values(1),(2)", db)).await?;
+    let first = latest_snapshot_id(ctx.clone(), &db).await?;
+    execute_command(ctx.clone(), &format!("insert into {}.t values(3)", db)).await?;
+    let second = latest_snapshot_id(ctx.clone(), &db).await?;
+    execute_command(ctx.clone(), &format!("delete from {}.t where a = 1", db)).await?;
+
+    {
+        // the block of (1, 2) is rewritten by the deletion
+        let expected = vec![
+            "+----------+----------+",
+            "| Column 0 | Column 1 |",
+            "+----------+----------+",
+            "| 1        | DELETE   |",
+            "| 2        | DELETE   |",
+            "| 2        | INSERT   |",
+            "| 3        | INSERT   |",
+            "+----------+----------+",
+        ];
+        let qry = format!(
+            "select a, \"change$action\" from changes('{}', 't', '{}')",
+            db, first
+        );
+        expects_ok(
+            "changes_since_first_snapshot",
+            execute_query(ctx.clone(), qry.as_str()).await,
+            expected,
+        )
+        .await?;
+    }
+
+    {
+        let expected = vec![
+            "+----------+----------+",
+            "| Column 0 | Column 1 |",
+            "+----------+----------+",
+            "| 3        | INSERT   |",
+            "+----------+----------+",
+        ];
+        let qry = format!(
+            "select a, \"change$action\" from changes('{}', 't', '{}', '{}')",
+            db, first, second
+        );
+        expects_ok(
+            "changes_between_snapshots",
+            execute_query(ctx.clone(), qry.as_str()).await,
+            expected,
+        )
+        .await?;
+    }
+
+    {
+        // the row ids of the deleted rows are distinct
+        let expected = vec![
+            "+----------+",
+            "| Column 0 |",
+            "+----------+",
+            "| 2        |",
+            "+----------+",
+        ];
+        let qry = format!(
+            "select count(distinct \"change$row_id\") from changes('{}', 't', '{}') where \"change$action\" = 'DELETE'",
+            db, first
+        );
+        expects_ok(
+            "distinct_row_ids",
+            execute_query(ctx.clone(), qry.as_str()).await,
+            expected,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+async fn latest_snapshot_id(ctx: Arc<QueryContext>, db: &str) -> Result<String> {
+    let table = ctx
+        .get_catalog("default")?
+        .get_table(ctx.get_tenant().as_str(), db, "t")
+        .await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let snapshot = fuse_table.read_table_snapshot().await?.unwrap();
+    Ok(snapshot.snapshot_id.simple().to_string())
+}
@@ -46,11 +46,11 @@ async fn test_clustering_information_table_read() -> Result<()> {
 
     {
         let expected = vec![
-            "+----------+----------+----------+----------+----------+----------+",
-            "| Column 0 | Column 1 | Column 2 | Column 3 | Column 4 | Column 5 |",
-            "+----------+----------+----------+----------+----------+----------+",
-            "| '(id)'   | 0        | 0        | 0        | 0        | {}       |",
-            "+----------+----------+----------+----------+----------+----------+",
+            "+----------+----------+----------+----------+----------+----------+----------+----------+",
+            "| Column 0 | Column 1 | Column 2 | Column 3 | Column 4 | Column 5 | Column 6 | Column 7 |",
+            "+----------+----------+----------+----------+----------+----------+----------+----------+",
+            "| '(id)'   | 0        | 0        | 0        | 0        | {}       | 0        | {}       |",
+            "+----------+----------+----------+----------+----------+----------+----------+----------+",
         ];
 
         expects_ok(
@@ -69,11 +69,11 @@ async fn test_clustering_information_table_read() -> Result<()> {
         let qry = format!("insert into {}.{} values(1, (2, 3)),(2, (4, 6))", db, tbl);
         execute_query(ctx.clone(), qry.as_str()).await?;
         let expected = vec![
-            "+----------+----------+----------+----------+----------+-------------+",
-            "| Column 0 | Column 1 | Column 2 | Column 3 | Column 4 | Column 5    |",
-            "+----------+----------+----------+----------+----------+-------------+",
-            "| '(id)'   | 1        | 0        | 0        | 1        | {\"00001\":1} |",
-            "+----------+----------+----------+----------+----------+-------------+",
+            "+----------+----------+----------+----------+----------+-------------+----------+-------------+",
+            "| Column 0 | Column 1 | Column 2 | Column 3 | Column 4 | Column 5    | Column 6 | Column 7    |",
+            "+----------+----------+----------+----------+----------+-------------+----------+-------------+",
+            "| '(id)'   | 1        | 0        | 0        | 1        | {\"00001\":1} | 1        | {\"00000\":1} |",
+            "+----------+----------+----------+----------+----------+-------------+----------+-------------+",
         ];
 
         let qry = format!("select * from clustering_information('{}', '{}')", db, tbl);
@@ -12,5 +12,6 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+mod changes_table;
 mod clustering_information_table;
 mod fuse_block_table;
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::fmt::Formatter;
@@ -30,6 +31,10 @@ use crate::settings_default::DefaultSettings;
 pub enum ScopeLevel {
     Global,
     Session,
+    /// From the settings of the user of the session.
+    User,
+    /// From the settings of the current role of the session.
+    Role,
 }
 
 impl Debug for ScopeLevel {
@@ -41,6 +46,12 @@ impl Debug for ScopeLevel {
             ScopeLevel::Session => {
                 write!(f, "SESSION")
             }
+            ScopeLevel::User => {
+                write!(f, "USER")
+            }
+            ScopeLevel::Role => {
+                write!(f, "ROLE")
+            }
         }
     }
 }
@@ -94,6 +105,7 @@ impl Settings {
     }
 
     pub fn set_setting(&self, k: String, v: String) -> Result<()> {
+        DefaultSettings::check_writable(&k)?;
         if let (key, Some(value)) = DefaultSettings::convert_value(k.clone(), v)? {
             self.changes.insert(key, ChangeValue {
                 value,
@@ -109,6 +121,55 @@ impl Settings {
         )))
     }
 
+    /// Check the name and the value of a setting, without changing anything.
+    pub fn check_setting_value(k: &str, v: &str) -> Result<()> {
+        match DefaultSettings::convert_value(k.to_string(), v.to_string())? {
+            (_, Some(_)) => Ok(()),
+            (_, None) => Err(ErrorCode::UnknownVariable(format!(
+                "Unknown variable: {:?}",
+                k
+            ))),
+        }
+    }
+
+    /// Apply the settings attached to the user or the role of the session.
+    ///
+    /// The precedence is session > user > role > global > default, so the settings
+    /// set in the session are kept, and the ones of the user override the role's.
+    pub fn apply_profile_settings(
+        &self,
+        settings: &BTreeMap<String, String>,
+        level: ScopeLevel,
+    ) -> Result<()> {
+        for (k, v) in settings {
+            if let Some(entry) = self.changes.get(k) {
+                let keep = match (&entry.level, &level) {
+                    (ScopeLevel::Session, _) => true,
+                    (ScopeLevel::User, ScopeLevel::Role) => true,
+                    _ => false,
+                };
+                if keep {
+                    continue;
+                }
+            }
+
+            match DefaultSettings::convert_value(k.clone(), v.clone())? {
+                (key, Some(value)) => {
+                    self.changes.insert(key, ChangeValue {
+                        value,
+                        level: level.clone(),
+                    });
+                }
+                // the settings may be deprecated
+                (key, None) => {
+                    tracing::warn!("Ignore unknown {:?} setting {} = {}", level, key, v);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn set_batch_settings(&self, settings: &HashMap<String, String>) -> Result<()> {
         for (k, v) in settings.iter() {
             if self.has_setting(k.as_str())? {
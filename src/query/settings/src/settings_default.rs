@@ -88,6 +88,18 @@ impl DefaultSettings {
                     possible_values: None,
                     display_in_show_settings: true,
                 }),
+                ("storage_multipart_upload_threshold", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(32 * 1024 * 1024),
+                    desc: "Sets the minimum byte size of blocks that are uploaded to S3 in multiple parts, 0 disables the multipart upload.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
+                ("storage_multipart_upload_part_size", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(8 * 1024 * 1024),
+                    desc: "Sets the byte size of the parts of a multipart upload, at least 5MiB.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
                 ("flight_client_timeout", DefaultSettingValue {
                     value: UserSettingValue::UInt64(60),
                     desc: "Sets the maximum time in seconds that a flight client request can be processed.",
@@ -250,6 +262,12 @@ impl DefaultSettings {
                     desc: "Determines whether Databend will return cached query results that are inconsistent with the underlying data.",
                     possible_values: None,
                     display_in_show_settings: true,
+                }),
+                ("query_result_cache_storage", DefaultSettingValue {
+                    value: UserSettingValue::String("storage".to_owned()),
+                    desc: "Sets where the cached query results are stored. Available values include \"storage\" and \"memory\", results in memory are only visible to the same node.",
+                    possible_values: Some(vec!["storage", "memory"]),
+                    display_in_show_settings: true,
                 }),
                     #[cfg(feature = "hive")]
                 ("enable_hive_parquet_predict_pushdown", DefaultSettingValue {
@@ -271,6 +289,18 @@ impl DefaultSettings {
                     possible_values: None,
                     display_in_show_settings: true,
                 }),
+                ("spilling_memory_ratio", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the percentage of max_memory_usage that the process can use before aggregators spill data to storage during query execution, 0 means disabled.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
+                ("join_spilling_threshold", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum amount of memory in bytes that the build side of a hash join can use before spilling partitions to storage, 0 means disabled.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
                 ("group_by_shuffle_mode", DefaultSettingValue {
                     value: UserSettingValue::String(String::from("before_merge")),
                     desc: "Group by shuffle mode, 'before_partial' is more balanced, but more data needs to exchange.",
@@ -310,12 +340,96 @@ impl DefaultSettings {
                     possible_values: None,
                     display_in_show_settings: true,
                 }),
+                ("enable_materialized_view_rewrite", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables rewriting queries to read from fresh materialized views of the queried table.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
+                ("materialized_view_max_staleness", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum staleness in seconds of a materialized view that can be used to answer queries, 0 means only up-to-date ones are used.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
+                ("enable_deletion_vector", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables DELETE to mark the deleted rows in deletion vectors instead of rewriting the blocks, only for tables of the parquet storage format.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
+                ("dictionary_cache_ttl_secs", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(60),
+                    desc: "Sets the number of seconds the values looked up by dict_get are cached, 0 disables the cache.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
+                ("max_cte_recursive_depth", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1000),
+                    desc: "Sets the maximum number of iterations of a recursive common table expression, the query fails once it is exceeded.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
+                ("external_server_connect_timeout_secs", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(10),
+                    desc: "Sets the timeout in seconds of connecting to an external UDF server.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
+                ("external_server_request_timeout_secs", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(180),
+                    desc: "Sets the timeout in seconds of a request to an external UDF server.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
+                ("external_server_request_batch_rows", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(65536),
+                    desc: "Sets the maximum number of rows sent to an external UDF server in one request.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
+                ("external_server_request_max_retries", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(3),
+                    desc: "Sets the number of times a failed request to an external UDF server is retried.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
+                ("wasm_udf_max_fuel", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1_000_000_000),
+                    desc: "Sets the maximum units of fuel, about the number of executed instructions, consumed by a WebAssembly UDF over a batch of rows.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
+                ("wasm_udf_max_memory_bytes", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(256 * 1024 * 1024),
+                    desc: "Sets the maximum bytes of memory used by a WebAssembly UDF over a batch of rows.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
+                ("enable_adaptive_parallelism", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables choosing fewer threads for a local query by the estimated bytes its table scans read, distributed queries are not limited.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
                 ("deduplicate_label", DefaultSettingValue {
                     value: UserSettingValue::String("".to_owned()),
                     desc: "Sql duplicate label for deduplication.",
                     possible_values: None,
                     display_in_show_settings: false,
                 }),
+                ("query_history_level", DefaultSettingValue {
+                    value: UserSettingValue::String("all".to_owned()),
+                    desc: "Sets which queries of the session are persisted to system_history.query_history. Available values include \"all\", \"error\" and \"none\".",
+                    possible_values: Some(vec!["all", "error", "none"]),
+                    display_in_show_settings: true,
+                }),
+                ("workload_group", DefaultSettingValue {
+                    value: UserSettingValue::String("".to_owned()),
+                    desc: "The workload group that the queries of the user run in, set for the user or the role. Empty means no admission control.",
+                    possible_values: None,
+                    display_in_show_settings: true,
+                }),
             ]);
 
             Ok(Arc::new(DefaultSettings {
@@ -381,6 +495,18 @@ impl DefaultSettings {
         Ok(Self::instance()?.settings.contains_key(key))
     }
 
+    /// Some settings are only set for users and roles in meta, e.g. by
+    /// `ALTER USER u1 WITH SET workload_group = 'etl'`, but never by `SET`.
+    pub fn check_writable(k: &str) -> Result<()> {
+        match k {
+            "workload_group" => Err(ErrorCode::PermissionDenied(format!(
+                "Variable {:?} can only be set for a user or a role",
+                k
+            ))),
+            _ => Ok(()),
+        }
+    }
+
     pub fn convert_value(k: String, v: String) -> Result<(String, Option<UserSettingValue>)> {
         let default_settings = DefaultSettings::instance()?;
 
@@ -80,6 +80,10 @@ impl Settings {
         self.try_set_u64("max_threads", val)
     }
 
+    pub fn get_enable_adaptive_parallelism(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_adaptive_parallelism")? != 0)
+    }
+
     // Get storage_fetch_part_num.
     pub fn get_storage_fetch_part_num(&self) -> Result<u64> {
         match self.try_get_u64("storage_fetch_part_num")? {
@@ -149,6 +153,22 @@ impl Settings {
         self.try_set_u64("storage_io_max_page_bytes_for_read", val)
     }
 
+    pub fn get_storage_multipart_upload_threshold(&self) -> Result<u64> {
+        self.try_get_u64("storage_multipart_upload_threshold")
+    }
+
+    pub fn set_storage_multipart_upload_threshold(&self, val: u64) -> Result<()> {
+        self.try_set_u64("storage_multipart_upload_threshold", val)
+    }
+
+    pub fn get_storage_multipart_upload_part_size(&self) -> Result<u64> {
+        self.try_get_u64("storage_multipart_upload_part_size")
+    }
+
+    pub fn set_storage_multipart_upload_part_size(&self, val: u64) -> Result<()> {
+        self.try_set_u64("storage_multipart_upload_part_size", val)
+    }
+
     // Get max_execute_time.
     pub fn get_max_execute_time(&self) -> Result<u64> {
         self.try_get_u64("max_execute_time")
@@ -310,6 +330,12 @@ impl Settings {
         Ok(self.try_get_u64("query_result_cache_allow_inconsistent")? != 0)
     }
 
+    pub fn get_query_result_cache_in_memory(&self) -> Result<bool> {
+        Ok(self
+            .try_get_string("query_result_cache_storage")?
+            .eq_ignore_ascii_case("memory"))
+    }
+
     pub fn get_spilling_bytes_threshold_per_proc(&self) -> Result<usize> {
         Ok(self.try_get_u64("spilling_bytes_threshold_per_proc")? as usize)
     }
@@ -318,6 +344,22 @@ impl Settings {
         self.try_set_u64("spilling_bytes_threshold_per_proc", value as u64)
     }
 
+    pub fn get_spilling_memory_ratio(&self) -> Result<usize> {
+        Ok(self.try_get_u64("spilling_memory_ratio")? as usize)
+    }
+
+    pub fn set_spilling_memory_ratio(&self, value: usize) -> Result<()> {
+        self.try_set_u64("spilling_memory_ratio", value as u64)
+    }
+
+    pub fn get_join_spilling_threshold(&self) -> Result<usize> {
+        Ok(self.try_get_u64("join_spilling_threshold")? as usize)
+    }
+
+    pub fn set_join_spilling_threshold(&self, value: usize) -> Result<()> {
+        self.try_set_u64("join_spilling_threshold", value as u64)
+    }
+
     pub fn get_group_by_shuffle_mode(&self) -> Result<String> {
         self.try_get_string("group_by_shuffle_mode")
     }
@@ -350,6 +392,58 @@ impl Settings {
         self.try_get_u64("table_lock_expire_secs")
     }
 
+    pub fn get_enable_materialized_view_rewrite(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_materialized_view_rewrite")? != 0)
+    }
+
+    pub fn set_enable_materialized_view_rewrite(&self, val: bool) -> Result<()> {
+        self.try_set_u64("enable_materialized_view_rewrite", u64::from(val))
+    }
+
+    pub fn get_materialized_view_max_staleness(&self) -> Result<u64> {
+        self.try_get_u64("materialized_view_max_staleness")
+    }
+
+    pub fn get_enable_deletion_vector(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_deletion_vector")? != 0)
+    }
+
+    pub fn set_enable_deletion_vector(&self, val: bool) -> Result<()> {
+        self.try_set_u64("enable_deletion_vector", u64::from(val))
+    }
+
+    pub fn get_dictionary_cache_ttl_secs(&self) -> Result<u64> {
+        self.try_get_u64("dictionary_cache_ttl_secs")
+    }
+
+    pub fn get_max_cte_recursive_depth(&self) -> Result<u64> {
+        self.try_get_u64("max_cte_recursive_depth")
+    }
+
+    pub fn get_external_server_connect_timeout_secs(&self) -> Result<u64> {
+        self.try_get_u64("external_server_connect_timeout_secs")
+    }
+
+    pub fn get_external_server_request_timeout_secs(&self) -> Result<u64> {
+        self.try_get_u64("external_server_request_timeout_secs")
+    }
+
+    pub fn get_external_server_request_batch_rows(&self) -> Result<u64> {
+        self.try_get_u64("external_server_request_batch_rows")
+    }
+
+    pub fn get_external_server_request_max_retries(&self) -> Result<u64> {
+        self.try_get_u64("external_server_request_max_retries")
+    }
+
+    pub fn get_wasm_udf_max_fuel(&self) -> Result<u64> {
+        self.try_get_u64("wasm_udf_max_fuel")
+    }
+
+    pub fn get_wasm_udf_max_memory_bytes(&self) -> Result<u64> {
+        self.try_get_u64("wasm_udf_max_memory_bytes")
+    }
+
     pub fn get_enterprise_license(&self) -> Result<String> {
         self.try_get_string("enterprise_license")
     }
@@ -366,4 +460,12 @@ impl Settings {
             Ok(Some(deduplicate_label))
         }
     }
+
+    pub fn get_workload_group(&self) -> Result<String> {
+        self.try_get_string("workload_group")
+    }
+
+    pub fn get_query_history_level(&self) -> Result<String> {
+        self.try_get_string("query_history_level")
+    }
 }
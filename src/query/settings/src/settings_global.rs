@@ -57,6 +57,7 @@ impl Settings {
 
     #[async_backtrace::framed]
     pub async fn set_global_setting(&self, k: String, v: String) -> Result<()> {
+        DefaultSettings::check_writable(&k)?;
         if let (key, Some(value)) = DefaultSettings::convert_value(k.clone(), v)? {
             self.changes.insert(key.clone(), ChangeValue {
                 value: value.clone(),
@@ -13,7 +13,11 @@
 // limitations under the License.
 
 use common_ast::ast::FormatTreeNode;
+use common_base::base::convert_byte_size;
+use common_catalog::plan::DataSourceInfo;
 use common_catalog::plan::PartStatistics;
+use common_catalog::plan::SampleLevel;
+use common_catalog::plan::VectorDistance;
 use common_exception::Result;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_profile::SharedProcessorProfiles;
@@ -23,6 +27,7 @@ use super::AggregateExpand;
 use super::AggregateFinal;
 use super::AggregateFunctionDesc;
 use super::AggregatePartial;
+use super::DictGet;
 use super::EvalScalar;
 use super::Exchange;
 use super::Filter;
@@ -31,9 +36,12 @@ use super::Limit;
 use super::PhysicalPlan;
 use super::Project;
 use super::ProjectSet;
+use super::RecursiveCte;
+use super::RecursiveCteScan;
 use super::RowFetch;
 use super::Sort;
 use super::TableScan;
+use super::Udf;
 use super::UnionAll;
 use super::WindowFunction;
 use crate::executor::explain::PlanStatsInfo;
@@ -137,6 +145,10 @@ fn to_format_tree(
         PhysicalPlan::HashJoin(plan) => hash_join_to_format_tree(plan, metadata, prof_span_set),
         PhysicalPlan::Exchange(plan) => exchange_to_format_tree(plan, metadata, prof_span_set),
         PhysicalPlan::UnionAll(plan) => union_all_to_format_tree(plan, metadata, prof_span_set),
+        PhysicalPlan::RecursiveCte(plan) => {
+            recursive_cte_to_format_tree(plan, metadata, prof_span_set)
+        }
+        PhysicalPlan::RecursiveCteScan(plan) => recursive_cte_scan_to_format_tree(plan),
         PhysicalPlan::ExchangeSource(plan) => exchange_source_to_format_tree(plan),
         PhysicalPlan::ExchangeSink(plan) => {
             exchange_sink_to_format_tree(plan, metadata, prof_span_set)
@@ -145,6 +157,8 @@ fn to_format_tree(
             distributed_insert_to_format_tree(plan.as_ref(), metadata, prof_span_set)
         }
         PhysicalPlan::ProjectSet(plan) => project_set_to_format_tree(plan, metadata, prof_span_set),
+        PhysicalPlan::DictGet(plan) => dict_get_to_format_tree(plan, metadata, prof_span_set),
+        PhysicalPlan::Udf(plan) => udf_to_format_tree(plan, metadata, prof_span_set),
         PhysicalPlan::RuntimeFilterSource(plan) => {
             runtime_filter_source_to_format_tree(plan, metadata, prof_span_set)
         }
@@ -163,6 +177,28 @@ fn append_profile_info(
             "total cpu time: {}ms",
             prof.cpu_time.as_secs_f64() * 1000.0
         )));
+        children.push(FormatTreeNode::new(format!(
+            "total wait time: {}ms",
+            prof.wait_time.as_secs_f64() * 1000.0
+        )));
+        children.push(FormatTreeNode::new(format!(
+            "output rows: {}",
+            prof.output_rows
+        )));
+        children.push(FormatTreeNode::new(format!(
+            "output bytes: {}",
+            convert_byte_size(prof.output_bytes as f64)
+        )));
+        children.push(FormatTreeNode::new(format!(
+            "peak memory usage: {}",
+            convert_byte_size(prof.peak_memory_usage.max(0) as f64)
+        )));
+        if prof.spill_bytes > 0 {
+            children.push(FormatTreeNode::new(format!(
+                "spilled bytes: {}",
+                convert_byte_size(prof.spill_bytes as f64)
+            )));
+        }
     }
 }
 
@@ -212,6 +248,15 @@ fn table_scan_to_format_tree(
         .and_then(|extras| extras.agg_index.as_ref());
 
     let mut children = vec![FormatTreeNode::new(format!("table: {table_name}"))];
+    // File format of stage files, given by the user or detected from the files.
+    let file_format = match &plan.source.source_info {
+        DataSourceInfo::StageSource(info) => Some(&info.stage_info.file_format_params),
+        DataSourceInfo::ParquetSource(info) => Some(&info.stage_info.file_format_params),
+        _ => None,
+    };
+    if let Some(file_format) = file_format {
+        children.push(FormatTreeNode::new(format!("file format: {file_format}")));
+    }
 
     // Part stats.
     children.extend(part_stats_info_to_format_tree(&plan.source.statistics));
@@ -227,6 +272,38 @@ fn table_scan_to_format_tree(
         }
     };
     children.push(FormatTreeNode::new(push_downs));
+    // Table sample
+    if let Some(sample) = plan
+        .source
+        .push_downs
+        .as_ref()
+        .and_then(|extras| extras.sample)
+    {
+        let level = match sample.level {
+            SampleLevel::Row => "ROW",
+            SampleLevel::Block => "BLOCK",
+        };
+        children.push(FormatTreeNode::new(format!(
+            "sample: [level: {level}, probability: {}]",
+            sample.probability
+        )));
+    }
+    // Nearest rows by vector distance
+    if let Some(vector_topn) = plan
+        .source
+        .push_downs
+        .as_ref()
+        .and_then(|extras| extras.vector_topn.as_ref())
+    {
+        let distance = match vector_topn.distance {
+            VectorDistance::Cosine => "cosine_distance",
+            VectorDistance::L2 => "l2_distance",
+        };
+        children.push(FormatTreeNode::new(format!(
+            "vector top-n: [{distance}({}), limit: {}]",
+            vector_topn.column, vector_topn.limit
+        )));
+    }
     // Aggregating index
     if let Some(agg_index) = agg_index {
         let metadata = metadata.read();
@@ -801,6 +878,45 @@ fn union_all_to_format_tree(
     ))
 }
 
+fn recursive_cte_to_format_tree(
+    plan: &RecursiveCte,
+    metadata: &MetadataRef,
+    prof_span_set: &SharedProcessorProfiles,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![FormatTreeNode::new(format!("distinct: {}", plan.distinct))];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, prof_span_set, plan.plan_id);
+
+    children.extend(vec![
+        to_format_tree(&plan.anchor, metadata, prof_span_set)?,
+        to_format_tree(&plan.recursive, metadata, prof_span_set)?,
+    ]);
+
+    Ok(FormatTreeNode::with_children(
+        "RecursiveCte".to_string(),
+        children,
+    ))
+}
+
+fn recursive_cte_scan_to_format_tree(plan: &RecursiveCteScan) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    Ok(FormatTreeNode::with_children(
+        "RecursiveCteScan".to_string(),
+        children,
+    ))
+}
+
 fn part_stats_info_to_format_tree(info: &PartStatistics) -> Vec<FormatTreeNode<String>> {
     let mut items = vec![
         FormatTreeNode::new(format!("read rows: {}", info.read_rows)),
@@ -909,6 +1025,69 @@ fn project_set_to_format_tree(
     ))
 }
 
+fn dict_get_to_format_tree(
+    plan: &DictGet,
+    metadata: &MetadataRef,
+    prof_span_set: &SharedProcessorProfiles,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, prof_span_set, plan.plan_id);
+
+    children.extend(vec![FormatTreeNode::new(format!(
+        "lookups: {}",
+        plan.items
+            .iter()
+            .map(|item| format!(
+                "{}[{}]",
+                item.attribute,
+                item.key.as_expr(&BUILTIN_FUNCTIONS).sql_display()
+            ))
+            .collect::<Vec<_>>()
+            .join(", ")
+    ))]);
+
+    children.extend(vec![to_format_tree(&plan.input, metadata, prof_span_set)?]);
+
+    Ok(FormatTreeNode::with_children(
+        "DictGet".to_string(),
+        children,
+    ))
+}
+
+fn udf_to_format_tree(
+    plan: &Udf,
+    metadata: &MetadataRef,
+    prof_span_set: &SharedProcessorProfiles,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, prof_span_set, plan.plan_id);
+
+    children.extend(vec![FormatTreeNode::new(format!(
+        "udf functions: {}",
+        plan.udf_funcs
+            .iter()
+            .map(|func| func.display_name.clone())
+            .collect::<Vec<_>>()
+            .join(", ")
+    ))]);
+
+    children.extend(vec![to_format_tree(&plan.input, metadata, prof_span_set)?]);
+
+    Ok(FormatTreeNode::with_children("Udf".to_string(), children))
+}
+
 fn runtime_filter_source_to_format_tree(
     plan: &RuntimeFilterSource,
     metadata: &MetadataRef,
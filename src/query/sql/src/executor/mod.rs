@@ -29,6 +29,7 @@ pub use physical_join::range_join;
 pub use physical_join::PhysicalJoinType;
 pub use physical_plan::Exchange;
 pub use physical_plan::*;
+pub use physical_plan_builder::build_agg_index_aggregation;
 pub use physical_plan_builder::PhysicalPlanBuilder;
 pub use physical_plan_builder::RangeJoinCondition;
 pub use physical_plan_visitor::PhysicalPlanReplacer;
@@ -16,9 +16,12 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::type_check::check_cast;
 use common_expression::type_check::common_super_type;
+use common_expression::types::DataType;
 use common_expression::ConstantFolder;
 use common_expression::DataField;
 use common_expression::DataSchemaRefExt;
+use common_expression::Expr;
+use common_expression::RemoteExpr;
 use common_functions::BUILTIN_FUNCTIONS;
 
 use crate::executor::explain::PlanStatsInfo;
@@ -26,9 +29,12 @@ use crate::executor::Exchange;
 use crate::executor::HashJoin;
 use crate::executor::PhysicalPlan;
 use crate::executor::PhysicalPlanBuilder;
+use crate::executor::TableScan;
 use crate::optimizer::SExpr;
 use crate::plans::Join;
 use crate::plans::JoinType;
+use crate::IndexType;
+use crate::ScalarExpr;
 use crate::TypeCheck;
 
 impl PhysicalPlanBuilder {
@@ -122,9 +128,21 @@ impl PhysicalPlanBuilder {
             _ => probe_side.output_schema()?,
         };
 
+        // The rows of probe side which have no match in build side can be filtered out
+        // by the runtime filters only if they are not needed by the join result.
+        let support_runtime_filter = matches!(
+            join.join_type,
+            JoinType::Inner
+                | JoinType::LeftSemi
+                | JoinType::Right
+                | JoinType::RightSemi
+                | JoinType::RightAnti
+        );
+
         assert_eq!(join.left_conditions.len(), join.right_conditions.len());
         let mut left_join_conditions = Vec::new();
         let mut right_join_conditions = Vec::new();
+        let mut left_join_conditions_rt = Vec::new();
         for (left_condition, right_condition) in join
             .left_conditions
             .iter()
@@ -171,8 +189,16 @@ impl PhysicalPlanBuilder {
             let (right_expr, _) =
                 ConstantFolder::fold(&right_expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
 
+            let left_expr_rt = match (support_runtime_filter, left_condition) {
+                (true, ScalarExpr::BoundColumnRef(column)) => {
+                    runtime_filter_probe_key(&probe_side, column.column.index, &common_ty)?
+                }
+                _ => None,
+            };
+
             left_join_conditions.push(left_expr.as_remote_expr());
             right_join_conditions.push(right_expr.as_remote_expr());
+            left_join_conditions_rt.push(left_expr_rt);
         }
 
         let merged_schema = DataSchemaRefExt::create(
@@ -191,6 +217,7 @@ impl PhysicalPlanBuilder {
             join_type: join.join_type.clone(),
             build_keys: right_join_conditions,
             probe_keys: left_join_conditions,
+            probe_keys_rt: left_join_conditions_rt,
             non_equi_conditions: join
                 .non_equi_conditions
                 .iter()
@@ -212,3 +239,54 @@ impl PhysicalPlanBuilder {
         }))
     }
 }
+
+// Find the table scan which outputs the column, the operators between the join and
+// the scan must not change the rows of the scan except filtering them.
+fn find_scan_of_column(plan: &PhysicalPlan, column: IndexType) -> Option<&TableScan> {
+    match plan {
+        PhysicalPlan::TableScan(scan) => scan
+            .name_mapping
+            .values()
+            .any(|index| *index == column)
+            .then_some(scan),
+        PhysicalPlan::Filter(plan) => find_scan_of_column(&plan.input, column),
+        PhysicalPlan::Project(plan) => find_scan_of_column(&plan.input, column),
+        PhysicalPlan::EvalScalar(plan) => find_scan_of_column(&plan.input, column),
+        PhysicalPlan::HashJoin(plan) => find_scan_of_column(&plan.probe, column),
+        _ => None,
+    }
+}
+
+// Build the probe key over the table column of the scan, so the runtime filter
+// generated from the build keys can be used to prune the blocks of the scan.
+fn runtime_filter_probe_key(
+    probe_side: &PhysicalPlan,
+    column: IndexType,
+    common_ty: &DataType,
+) -> Result<Option<(RemoteExpr<String>, IndexType)>> {
+    let table_column = find_scan_of_column(probe_side, column).and_then(|scan| {
+        let (name, _) = scan
+            .name_mapping
+            .iter()
+            .find(|(_, index)| **index == column)?;
+        let field = scan.source.schema().field_with_name(name).ok()?;
+        Some((
+            scan.table_index,
+            name.clone(),
+            DataType::from(field.data_type()),
+        ))
+    });
+    let (table_index, name, data_type) = match table_column {
+        Some(table_column) => table_column,
+        None => return Ok(None),
+    };
+
+    let column_expr = Expr::ColumnRef {
+        span: None,
+        id: name.clone(),
+        data_type,
+        display_name: name,
+    };
+    let probe_key = check_cast(None, false, column_expr, common_ty, &BUILTIN_FUNCTIONS)?;
+    Ok(Some((probe_key.as_remote_expr(), table_index)))
+}
@@ -30,6 +30,7 @@ use common_expression::RemoteExpr;
 use common_expression::Scalar;
 use common_functions::aggregates::AggregateFunctionFactory;
 use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::principal::DictionarySource;
 use common_meta_app::schema::TableInfo;
 
 use crate::executor::explain::PlanStatsInfo;
@@ -52,7 +53,7 @@ pub struct TableScan {
     pub name_mapping: BTreeMap<String, IndexType>,
     pub source: Box<DataSourcePlan>,
 
-    /// Only used for display
+    /// Used for display, and to find the scan that runtime filters are pushed down to.
     pub table_index: IndexType,
     pub stat_info: Option<PlanStatsInfo>,
 
@@ -177,6 +178,85 @@ impl ProjectSet {
     }
 }
 
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct DictGetDesc {
+    pub key: RemoteExpr,
+    pub source: DictionarySource,
+    pub primary_key: String,
+    pub attribute: String,
+    pub data_type: DataType,
+    pub index: IndexType,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct DictGet {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    pub input: Box<PhysicalPlan>,
+
+    pub items: Vec<DictGetDesc>,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl DictGet {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let mut fields = input_schema.fields().clone();
+        fields.extend(
+            self.items
+                .iter()
+                .map(|item| DataField::new(&item.index.to_string(), item.data_type.clone())),
+        );
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct UdfFunctionDesc {
+    /// The name of the handler on the server, or of the function exported by
+    /// the WebAssembly module.
+    pub func_name: String,
+    pub display_name: String,
+    pub server_addr: String,
+    /// The stage location of the WebAssembly module, the call is sent to
+    /// `server_addr` if it's `None`.
+    pub wasm_module: Option<String>,
+    pub arguments: Vec<RemoteExpr>,
+    pub data_type: DataType,
+    pub index: IndexType,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct Udf {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    pub input: Box<PhysicalPlan>,
+
+    pub udf_funcs: Vec<UdfFunctionDesc>,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl Udf {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let mut fields = input_schema.fields().clone();
+        fields.extend(
+            self.udf_funcs
+                .iter()
+                .map(|func| DataField::new(&func.index.to_string(), func.data_type.clone())),
+        );
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct AggregateExpand {
     /// A unique id of operator in a `PhysicalPlan` tree.
@@ -440,6 +520,9 @@ pub struct HashJoin {
     pub probe: Box<PhysicalPlan>,
     pub build_keys: Vec<RemoteExpr>,
     pub probe_keys: Vec<RemoteExpr>,
+    /// The probe keys over the columns of probe side table scans, the min/max
+    /// of the build keys are pushed down to the scans to prune blocks.
+    pub probe_keys_rt: Vec<Option<(RemoteExpr<String>, IndexType)>>,
     pub non_equi_conditions: Vec<RemoteExpr>,
     pub join_type: JoinType,
     pub marker_index: Option<IndexType>,
@@ -658,6 +741,51 @@ impl UnionAll {
     }
 }
 
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RecursiveCte {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    pub anchor: Box<PhysicalPlan>,
+    pub recursive: Box<PhysicalPlan>,
+    pub cte_index: IndexType,
+    /// Offsets of the output columns in the output of the anchor.
+    pub anchor_projection: Vec<usize>,
+    /// Offsets of the output columns in the output of the recursive term.
+    pub recursive_projection: Vec<usize>,
+    pub distinct: bool,
+    pub schema: DataSchemaRef,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl RecursiveCte {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RecursiveCteScan {
+    /// A unique id of operator in a `PhysicalPlan` tree.
+    /// Only used for display.
+    pub plan_id: u32,
+
+    pub cte_index: IndexType,
+    pub schema: DataSchemaRef,
+
+    /// Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl RecursiveCteScan {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct DistributedInsertSelect {
     /// A unique id of operator in a `PhysicalPlan` tree.
@@ -706,6 +834,8 @@ pub enum PhysicalPlan {
     Project(Project),
     EvalScalar(EvalScalar),
     ProjectSet(ProjectSet),
+    DictGet(DictGet),
+    Udf(Udf),
     AggregateExpand(AggregateExpand),
     AggregatePartial(AggregatePartial),
     AggregateFinal(AggregateFinal),
@@ -717,6 +847,8 @@ pub enum PhysicalPlan {
     RangeJoin(RangeJoin),
     Exchange(Exchange),
     UnionAll(UnionAll),
+    RecursiveCte(RecursiveCte),
+    RecursiveCteScan(RecursiveCteScan),
     RuntimeFilterSource(RuntimeFilterSource),
 
     /// For insert into ... select ... in cluster
@@ -744,6 +876,8 @@ impl PhysicalPlan {
             PhysicalPlan::Project(v) => v.plan_id,
             PhysicalPlan::EvalScalar(v) => v.plan_id,
             PhysicalPlan::ProjectSet(v) => v.plan_id,
+            PhysicalPlan::DictGet(v) => v.plan_id,
+            PhysicalPlan::Udf(v) => v.plan_id,
             PhysicalPlan::AggregateExpand(v) => v.plan_id,
             PhysicalPlan::AggregatePartial(v) => v.plan_id,
             PhysicalPlan::AggregateFinal(v) => v.plan_id,
@@ -755,6 +889,8 @@ impl PhysicalPlan {
             PhysicalPlan::RangeJoin(v) => v.plan_id,
             PhysicalPlan::Exchange(v) => v.plan_id,
             PhysicalPlan::UnionAll(v) => v.plan_id,
+            PhysicalPlan::RecursiveCte(v) => v.plan_id,
+            PhysicalPlan::RecursiveCteScan(v) => v.plan_id,
             PhysicalPlan::RuntimeFilterSource(v) => v.plan_id,
             PhysicalPlan::DistributedInsertSelect(v) => v.plan_id,
             PhysicalPlan::ExchangeSource(v) => v.plan_id,
@@ -780,8 +916,12 @@ impl PhysicalPlan {
             PhysicalPlan::ExchangeSource(plan) => plan.output_schema(),
             PhysicalPlan::ExchangeSink(plan) => plan.output_schema(),
             PhysicalPlan::UnionAll(plan) => plan.output_schema(),
+            PhysicalPlan::RecursiveCte(plan) => plan.output_schema(),
+            PhysicalPlan::RecursiveCteScan(plan) => plan.output_schema(),
             PhysicalPlan::DistributedInsertSelect(plan) => plan.output_schema(),
             PhysicalPlan::ProjectSet(plan) => plan.output_schema(),
+            PhysicalPlan::DictGet(plan) => plan.output_schema(),
+            PhysicalPlan::Udf(plan) => plan.output_schema(),
             PhysicalPlan::RuntimeFilterSource(plan) => plan.output_schema(),
             PhysicalPlan::RangeJoin(plan) => plan.output_schema(),
         }
@@ -803,10 +943,14 @@ impl PhysicalPlan {
             PhysicalPlan::HashJoin(_) => "HashJoin".to_string(),
             PhysicalPlan::Exchange(_) => "Exchange".to_string(),
             PhysicalPlan::UnionAll(_) => "UnionAll".to_string(),
+            PhysicalPlan::RecursiveCte(_) => "RecursiveCte".to_string(),
+            PhysicalPlan::RecursiveCteScan(_) => "RecursiveCteScan".to_string(),
             PhysicalPlan::DistributedInsertSelect(_) => "DistributedInsertSelect".to_string(),
             PhysicalPlan::ExchangeSource(_) => "Exchange Source".to_string(),
             PhysicalPlan::ExchangeSink(_) => "Exchange Sink".to_string(),
             PhysicalPlan::ProjectSet(_) => "Unnest".to_string(),
+            PhysicalPlan::DictGet(_) => "DictGet".to_string(),
+            PhysicalPlan::Udf(_) => "Udf".to_string(),
             PhysicalPlan::RuntimeFilterSource(_) => "RuntimeFilterSource".to_string(),
             PhysicalPlan::RangeJoin(_) => "RangeJoin".to_string(),
         }
@@ -834,10 +978,17 @@ impl PhysicalPlan {
             PhysicalPlan::UnionAll(plan) => Box::new(
                 std::iter::once(plan.left.as_ref()).chain(std::iter::once(plan.right.as_ref())),
             ),
+            PhysicalPlan::RecursiveCte(plan) => Box::new(
+                std::iter::once(plan.anchor.as_ref())
+                    .chain(std::iter::once(plan.recursive.as_ref())),
+            ),
+            PhysicalPlan::RecursiveCteScan(_) => Box::new(std::iter::empty()),
             PhysicalPlan::DistributedInsertSelect(plan) => {
                 Box::new(std::iter::once(plan.input.as_ref()))
             }
             PhysicalPlan::ProjectSet(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::DictGet(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::Udf(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::RuntimeFilterSource(plan) => Box::new(
                 std::iter::once(plan.left_side.as_ref())
                     .chain(std::iter::once(plan.right_side.as_ref())),
@@ -862,9 +1013,13 @@ impl PhysicalPlan {
             PhysicalPlan::ExchangeSink(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::DistributedInsertSelect(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::ProjectSet(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::DictGet(plan) => plan.input.try_find_single_data_source(),
+            PhysicalPlan::Udf(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::RowFetch(plan) => plan.input.try_find_single_data_source(),
             PhysicalPlan::RuntimeFilterSource(_)
             | PhysicalPlan::UnionAll(_)
+            | PhysicalPlan::RecursiveCte(_)
+            | PhysicalPlan::RecursiveCteScan(_)
             | PhysicalPlan::ExchangeSource(_)
             | PhysicalPlan::HashJoin(_)
             | PhysicalPlan::RangeJoin(_)
@@ -910,6 +1065,17 @@ pub struct AggregateFunctionSignature {
     pub name: String,
     pub params: Vec<Scalar>,
     pub args: Vec<DataType>,
+    /// Set if the function is an aggregate UDF evaluated by a UDF server.
+    pub udaf: Option<UdafDesc>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct UdafDesc {
+    pub server_addr: String,
+    pub handler: String,
+    /// The fields of the intermediate state, as pairs of name and type.
+    pub state_fields: Vec<(String, DataType)>,
+    pub return_type: DataType,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -921,6 +1087,9 @@ pub struct SortDesc {
 
 impl AggregateFunctionSignature {
     pub fn return_type(&self) -> Result<DataType> {
+        if let Some(udaf) = &self.udaf {
+            return Ok(udaf.return_type.clone());
+        }
         AggregateFunctionFactory::instance()
             .get(&self.name, self.params.clone(), self.args.clone())?
             .return_type()
@@ -19,7 +19,10 @@ use std::sync::Arc;
 
 use common_catalog::catalog::CatalogManager;
 use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_catalog::plan::AggIndexAggregation;
+use common_catalog::plan::AggIndexFunction;
 use common_catalog::plan::AggIndexInfo;
+use common_catalog::plan::AggIndexMerge;
 use common_catalog::plan::PrewhereInfo;
 use common_catalog::plan::Projection;
 use common_catalog::plan::PushDownInfo;
@@ -50,15 +53,22 @@ use super::AggregateFinal;
 use super::AggregateFunctionDesc;
 use super::AggregateFunctionSignature;
 use super::AggregatePartial;
+use super::DictGet;
+use super::DictGetDesc;
 use super::EvalScalar;
 use super::Exchange as PhysicalExchange;
 use super::Filter;
 use super::Limit;
 use super::NthValueFunctionDesc;
 use super::ProjectSet;
+use super::RecursiveCte;
+use super::RecursiveCteScan;
 use super::RowFetch;
 use super::Sort;
 use super::TableScan;
+use super::UdafDesc;
+use super::Udf;
+use super::UdfFunctionDesc;
 use super::WindowFunction;
 use crate::binder::wrap_cast;
 use crate::binder::INTERNAL_COLUMN_FACTORY;
@@ -78,6 +88,9 @@ use crate::optimizer::ColumnSet;
 use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
 use crate::planner;
+use crate::plans::AggIndexAggregation as PlanAggIndexAggregation;
+use crate::plans::AggIndexMerge as PlanAggIndexMerge;
+use crate::plans::AggregateFunction;
 use crate::plans::AggregateMode;
 use crate::plans::BoundColumnRef;
 use crate::plans::Exchange;
@@ -122,7 +135,7 @@ impl PhysicalPlanBuilder {
         }
     }
 
-    pub(crate) fn next_plan_id(&mut self) -> u32 {
+    pub fn next_plan_id(&mut self) -> u32 {
         let id = self.next_plan_id;
         self.next_plan_id += 1;
         id
@@ -389,7 +402,7 @@ impl PhysicalPlanBuilder {
         let push_downs =
             self.push_downs(scan, &table_schema, has_inner_column, has_virtual_column)?;
 
-        let source = table
+        let mut source = table
             .read_plan_with_catalog(
                 self.ctx.clone(),
                 table_entry.catalog().to_string(),
@@ -401,6 +414,7 @@ impl PhysicalPlanBuilder {
                 },
             )
             .await?;
+        source.table_index = Some(scan.table_index);
 
         let internal_column = if project_internal_columns.is_empty() {
             None
@@ -514,6 +528,7 @@ impl PhysicalPlanBuilder {
                                             }
                                         }).collect::<Result<_>>()?,
                                         params: agg.params.clone(),
+                                        udaf: udaf_desc(agg),
                                     },
                                     output_column: v.index,
                                     args: agg.args.iter().map(|arg| {
@@ -545,7 +560,8 @@ impl PhysicalPlanBuilder {
 
                         match input {
                             PhysicalPlan::Exchange(PhysicalExchange { input, kind, .. })
-                                if group_by_shuffle_mode == "before_merge" =>
+                                if group_by_shuffle_mode == "before_merge"
+                                    || !agg.grouping_sets.is_empty() =>
                             {
                                 let aggregate_partial = if !agg.grouping_sets.is_empty() {
                                     let expand = AggregateExpand {
@@ -579,11 +595,21 @@ impl PhysicalPlanBuilder {
 
                                 let group_by_key_index =
                                     aggregate_partial.output_schema()?.num_fields() - 1;
+                                // The hash method is chosen by the input of the partial aggregation,
+                                // whose group by columns are nullable after expanding grouping sets.
+                                let partial_input_schema =
+                                    aggregate_partial.input.output_schema()?;
                                 let group_by_key_data_type =
                                     DataBlock::choose_hash_method_with_types(
-                                        &agg.group_items
+                                        &aggregate_partial
+                                            .group_by
                                             .iter()
-                                            .map(|v| v.scalar.data_type())
+                                            .map(|index| {
+                                                Ok(partial_input_schema
+                                                    .field_with_name(&index.to_string())?
+                                                    .data_type()
+                                                    .clone())
+                                            })
                                             .collect::<Result<Vec<_>>>()?,
                                         efficiently_memory,
                                     )?
@@ -621,6 +647,10 @@ impl PhysicalPlanBuilder {
                                         stat_info: Some(stat_info),
                                     })
                                 } else {
+                                    let mut input = input;
+                                    if let Some(merge) = find_agg_index_merge(s_expr.child(0)?) {
+                                        push_down_agg_index_merge(&mut input, merge, &agg_funcs)?;
+                                    }
                                     PhysicalPlan::AggregatePartial(AggregatePartial {
                                         plan_id: self.next_plan_id(),
                                         agg_funcs,
@@ -666,6 +696,7 @@ impl PhysicalPlanBuilder {
                                             }
                                         }).collect::<Result<_>>()?,
                                         params: agg.params.clone(),
+                                        udaf: udaf_desc(agg),
                                     },
                                     output_column: v.index,
                                     args: agg.args.iter().map(|arg| {
@@ -1004,6 +1035,141 @@ impl PhysicalPlanBuilder {
                 }))
             }
 
+            RelOperator::DictGet(dict_get) => {
+                let input = self.build(s_expr.child(0)?).await?;
+                let input_schema = input.output_schema()?;
+                let items = dict_get
+                    .items
+                    .iter()
+                    .map(|item| {
+                        let key = item
+                            .key
+                            .resolve_and_check(input_schema.as_ref())?
+                            .project_column_ref(|index| {
+                                input_schema.index_of(&index.to_string()).unwrap()
+                            });
+                        let (key, _) =
+                            ConstantFolder::fold(&key, &self.func_ctx, &BUILTIN_FUNCTIONS);
+                        Ok(DictGetDesc {
+                            key: key.as_remote_expr(),
+                            source: item.source.clone(),
+                            primary_key: item.primary_key.clone(),
+                            attribute: item.attribute.clone(),
+                            data_type: item.data_type.clone(),
+                            index: item.index,
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(PhysicalPlan::DictGet(DictGet {
+                    plan_id: self.next_plan_id(),
+                    input: Box::new(input),
+                    items,
+                    stat_info: Some(stat_info),
+                }))
+            }
+
+            RelOperator::Udf(udf) => {
+                let input = self.build(s_expr.child(0)?).await?;
+                let input_schema = input.output_schema()?;
+                let udf_funcs = udf
+                    .items
+                    .iter()
+                    .map(|item| {
+                        let arguments = item
+                            .arguments
+                            .iter()
+                            .map(|argument| {
+                                let argument = argument
+                                    .resolve_and_check(input_schema.as_ref())?
+                                    .project_column_ref(|index| {
+                                        input_schema.index_of(&index.to_string()).unwrap()
+                                    });
+                                let (argument, _) = ConstantFolder::fold(
+                                    &argument,
+                                    &self.func_ctx,
+                                    &BUILTIN_FUNCTIONS,
+                                );
+                                Ok(argument.as_remote_expr())
+                            })
+                            .collect::<Result<Vec<_>>>()?;
+                        Ok(UdfFunctionDesc {
+                            func_name: item.func_name.clone(),
+                            display_name: item.display_name.clone(),
+                            server_addr: item.server_addr.clone(),
+                            wasm_module: item.wasm_module.clone(),
+                            arguments,
+                            data_type: item.data_type.clone(),
+                            index: item.index,
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(PhysicalPlan::Udf(Udf {
+                    plan_id: self.next_plan_id(),
+                    input: Box::new(input),
+                    udf_funcs,
+                    stat_info: Some(stat_info),
+                }))
+            }
+
+            RelOperator::RecursiveCte(op) => {
+                let anchor = self.build(s_expr.child(0)?).await?;
+                let recursive = self.build(s_expr.child(1)?).await?;
+                let anchor_schema = anchor.output_schema()?;
+                let recursive_schema = recursive.output_schema()?;
+                let anchor_projection = op
+                    .anchor_columns
+                    .iter()
+                    .map(|index| anchor_schema.index_of(&index.to_string()))
+                    .collect::<Result<Vec<_>>>()?;
+                let recursive_projection = op
+                    .recursive_columns
+                    .iter()
+                    .map(|index| recursive_schema.index_of(&index.to_string()))
+                    .collect::<Result<Vec<_>>>()?;
+                let fields = op
+                    .anchor_columns
+                    .iter()
+                    .zip(anchor_projection.iter())
+                    .map(|(index, offset)| {
+                        DataField::new(
+                            &index.to_string(),
+                            anchor_schema.field(*offset).data_type().clone(),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+
+                Ok(PhysicalPlan::RecursiveCte(RecursiveCte {
+                    plan_id: self.next_plan_id(),
+                    anchor: Box::new(anchor),
+                    recursive: Box::new(recursive),
+                    cte_index: op.cte_index,
+                    anchor_projection,
+                    recursive_projection,
+                    distinct: op.distinct,
+                    schema: DataSchemaRefExt::create(fields),
+                    stat_info: Some(stat_info),
+                }))
+            }
+
+            RelOperator::RecursiveCteScan(scan) => {
+                let fields = {
+                    let metadata = self.metadata.read();
+                    scan.columns
+                        .iter()
+                        .map(|index| {
+                            DataField::new(&index.to_string(), metadata.column(*index).data_type())
+                        })
+                        .collect::<Vec<_>>()
+                };
+
+                Ok(PhysicalPlan::RecursiveCteScan(RecursiveCteScan {
+                    plan_id: self.next_plan_id(),
+                    cte_index: scan.cte_index,
+                    schema: DataSchemaRefExt::create(fields),
+                    stat_info: Some(stat_info),
+                }))
+            }
+
             _ => Err(ErrorCode::Internal(format!(
                 "Unsupported physical plan: {:?}",
                 s_expr.plan()
@@ -1132,6 +1298,7 @@ impl PhysicalPlanBuilder {
                         .map(|s| s.data_type())
                         .collect::<Result<_>>()?,
                     params: agg.params.clone(),
+                    udaf: udaf_desc(agg),
                 },
                 output_column: w.index,
                 args: agg
@@ -1489,6 +1656,8 @@ impl PhysicalPlanBuilder {
                     index_id: agg.index_id,
                     filter,
                     selection,
+                    // Set by the partial aggregation reading the scan, see `push_down_agg_index_merge`.
+                    merge: None,
                 })
             })
             .transpose()?;
@@ -1504,6 +1673,8 @@ impl PhysicalPlanBuilder {
             virtual_columns,
             lazy_materialization: !metadata.lazy_columns().is_empty(),
             agg_index,
+            sample: scan.sample,
+            vector_topn: scan.vector_topn.clone(),
         })
     }
 
@@ -1517,6 +1688,137 @@ impl PhysicalPlanBuilder {
     }
 }
 
+fn udaf_desc(agg: &AggregateFunction) -> Option<UdafDesc> {
+    agg.udaf.as_ref().map(|udaf| UdafDesc {
+        server_addr: udaf.address.clone(),
+        handler: udaf.handler.clone(),
+        state_fields: udaf.state_fields.clone(),
+        return_type: (*agg.return_type).clone(),
+    })
+}
+
+/// Build the partial aggregation of an aggregating index, which is evaluated by the storage
+/// on the columns of the table.
+pub fn build_agg_index_aggregation(
+    aggregation: &PlanAggIndexAggregation,
+) -> Result<AggIndexAggregation> {
+    let build_expr = |scalar: &ScalarExpr| -> Result<RemoteExpr<String>> {
+        Ok(scalar
+            .as_expr()?
+            .project_column_ref(|col| col.column_name.clone())
+            .as_remote_expr())
+    };
+
+    let predicate = aggregation.predicates.iter().cloned().reduce(|lhs, rhs| {
+        ScalarExpr::FunctionCall(FunctionCall {
+            span: None,
+            func_name: "and".to_string(),
+            params: vec![],
+            arguments: vec![lhs, rhs],
+        })
+    });
+    let filter = predicate
+        .map(|pred| -> Result<_> {
+            Ok(cast_expr_to_non_null_boolean(
+                pred.as_expr()?
+                    .project_column_ref(|col| col.column_name.clone()),
+            )?
+            .as_remote_expr())
+        })
+        .transpose()?;
+    let group_items = aggregation
+        .group_items
+        .iter()
+        .map(build_expr)
+        .collect::<Result<Vec<_>>>()?;
+    let aggregate_functions = aggregation
+        .aggregate_functions
+        .iter()
+        .map(|func| {
+            Ok(AggIndexFunction {
+                name: func.func_name.clone(),
+                params: func.params.clone(),
+                args: func.args.iter().map(build_expr).collect::<Result<_>>()?,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(AggIndexAggregation {
+        filter,
+        group_items,
+        aggregate_functions,
+        output: aggregation.output.clone(),
+    })
+}
+
+/// Find the aggregating index of the scan below the partial aggregation,
+/// if the query can merge the partial aggregate states in the index.
+fn find_agg_index_merge(s_expr: &SExpr) -> Option<&PlanAggIndexMerge> {
+    match s_expr.plan() {
+        RelOperator::EvalScalar(_) | RelOperator::Filter(_) => {
+            find_agg_index_merge(s_expr.child(0).ok()?)
+        }
+        RelOperator::Scan(scan) => scan.agg_index.as_ref()?.merge.as_ref(),
+        _ => None,
+    }
+}
+
+/// Let the table scan below the partial aggregation output the partial aggregate states,
+/// which are merged by the partial aggregation instead of the raw data.
+fn push_down_agg_index_merge(
+    input: &mut PhysicalPlan,
+    merge: &PlanAggIndexMerge,
+    agg_funcs: &[AggregateFunctionDesc],
+) -> Result<()> {
+    let scan = match input {
+        PhysicalPlan::EvalScalar(EvalScalar { input, .. })
+        | PhysicalPlan::Filter(Filter { input, .. }) => {
+            return push_down_agg_index_merge(input, merge, agg_funcs);
+        }
+        PhysicalPlan::TableScan(scan) => scan,
+        _ => return Ok(()),
+    };
+    // The masked columns can not be aggregated by the storage.
+    if scan.source.data_mask_policy.is_some() {
+        return Ok(());
+    }
+
+    // The states should be created by the same aggregate functions.
+    let aggregation = build_agg_index_aggregation(&merge.aggregation)?;
+    let matched = agg_funcs.len() == aggregation.aggregate_functions.len()
+        && agg_funcs
+            .iter()
+            .zip(aggregation.aggregate_functions.iter())
+            .all(|(desc, func)| {
+                desc.sig.udaf.is_none()
+                    && desc.sig.name == func.name
+                    && desc.sig.params == func.params
+                    && desc.sig.args.len() == func.args.len()
+                    && desc
+                        .sig
+                        .args
+                        .iter()
+                        .zip(func.args.iter())
+                        .all(|(ty, arg)| ty == arg.as_expr(&BUILTIN_FUNCTIONS).data_type())
+            });
+    if !matched {
+        return Ok(());
+    }
+
+    if let Some(agg_index) = scan
+        .source
+        .push_downs
+        .as_mut()
+        .and_then(|push_downs| push_downs.agg_index.as_mut())
+    {
+        agg_index.merge = Some(AggIndexMerge {
+            projection: merge.projection.clone(),
+            aggregation,
+        });
+    }
+    Ok(())
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct RangeJoinCondition {
     pub left_expr: RemoteExpr,
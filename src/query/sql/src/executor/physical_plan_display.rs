@@ -19,9 +19,11 @@ use common_functions::BUILTIN_FUNCTIONS;
 use itertools::Itertools;
 
 use super::AggregateExpand;
+use super::DictGet;
 use super::DistributedInsertSelect;
 use super::ProjectSet;
 use super::RowFetch;
+use super::Udf;
 use crate::executor::AggregateFinal;
 use crate::executor::AggregatePartial;
 use crate::executor::EvalScalar;
@@ -34,6 +36,8 @@ use crate::executor::Limit;
 use crate::executor::PhysicalPlan;
 use crate::executor::Project;
 use crate::executor::RangeJoin;
+use crate::executor::RecursiveCte;
+use crate::executor::RecursiveCteScan;
 use crate::executor::RuntimeFilterSource;
 use crate::executor::Sort;
 use crate::executor::TableScan;
@@ -73,8 +77,12 @@ impl<'a> Display for PhysicalPlanIndentFormatDisplay<'a> {
             PhysicalPlan::ExchangeSource(source) => write!(f, "{}", source)?,
             PhysicalPlan::ExchangeSink(sink) => write!(f, "{}", sink)?,
             PhysicalPlan::UnionAll(union_all) => write!(f, "{}", union_all)?,
+            PhysicalPlan::RecursiveCte(plan) => write!(f, "{}", plan)?,
+            PhysicalPlan::RecursiveCteScan(plan) => write!(f, "{}", plan)?,
             PhysicalPlan::DistributedInsertSelect(insert_select) => write!(f, "{}", insert_select)?,
             PhysicalPlan::ProjectSet(unnest) => write!(f, "{}", unnest)?,
+            PhysicalPlan::DictGet(dict_get) => write!(f, "{}", dict_get)?,
+            PhysicalPlan::Udf(udf) => write!(f, "{}", udf)?,
             PhysicalPlan::RuntimeFilterSource(plan) => write!(f, "{}", plan)?,
             PhysicalPlan::RangeJoin(plan) => write!(f, "{}", plan)?,
         }
@@ -340,6 +348,22 @@ impl Display for UnionAll {
     }
 }
 
+impl Display for RecursiveCte {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.distinct {
+            write!(f, "RecursiveCte: union")
+        } else {
+            write!(f, "RecursiveCte: union all")
+        }
+    }
+}
+
+impl Display for RecursiveCteScan {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RecursiveCteScan")
+    }
+}
+
 impl Display for DistributedInsertSelect {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "DistributedInsertSelect")
@@ -367,3 +391,33 @@ impl Display for ProjectSet {
         )
     }
 }
+
+impl Display for DictGet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let lookups = self
+            .items
+            .iter()
+            .map(|item| {
+                format!(
+                    "{}[{}]",
+                    item.attribute,
+                    item.key.as_expr(&BUILTIN_FUNCTIONS)
+                )
+            })
+            .collect::<Vec<String>>();
+
+        write!(f, "DictGet: lookups : {}", lookups.join(", "))
+    }
+}
+
+impl Display for Udf {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let funcs = self
+            .udf_funcs
+            .iter()
+            .map(|func| func.display_name.clone())
+            .collect::<Vec<String>>();
+
+        write!(f, "Udf: udf functions : {}", funcs.join(", "))
+    }
+}
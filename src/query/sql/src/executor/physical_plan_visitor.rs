@@ -17,6 +17,7 @@ use common_exception::Result;
 use super::AggregateExpand;
 use super::AggregateFinal;
 use super::AggregatePartial;
+use super::DictGet;
 use super::DistributedInsertSelect;
 use super::EvalScalar;
 use super::Exchange;
@@ -28,9 +29,12 @@ use super::Limit;
 use super::PhysicalPlan;
 use super::Project;
 use super::ProjectSet;
+use super::RecursiveCte;
+use super::RecursiveCteScan;
 use super::RowFetch;
 use super::Sort;
 use super::TableScan;
+use super::Udf;
 use crate::executor::RangeJoin;
 use crate::executor::RuntimeFilterSource;
 use crate::executor::UnionAll;
@@ -55,8 +59,12 @@ pub trait PhysicalPlanReplacer {
             PhysicalPlan::ExchangeSource(plan) => self.replace_exchange_source(plan),
             PhysicalPlan::ExchangeSink(plan) => self.replace_exchange_sink(plan),
             PhysicalPlan::UnionAll(plan) => self.replace_union(plan),
+            PhysicalPlan::RecursiveCte(plan) => self.replace_recursive_cte(plan),
+            PhysicalPlan::RecursiveCteScan(plan) => self.replace_recursive_cte_scan(plan),
             PhysicalPlan::DistributedInsertSelect(plan) => self.replace_insert_select(plan),
             PhysicalPlan::ProjectSet(plan) => self.replace_project_set(plan),
+            PhysicalPlan::DictGet(plan) => self.replace_dict_get(plan),
+            PhysicalPlan::Udf(plan) => self.replace_udf(plan),
             PhysicalPlan::RuntimeFilterSource(plan) => self.replace_runtime_filter_source(plan),
             PhysicalPlan::RangeJoin(plan) => self.replace_range_join(plan),
         }
@@ -163,6 +171,7 @@ pub trait PhysicalPlanReplacer {
             probe: Box::new(probe),
             build_keys: plan.build_keys.clone(),
             probe_keys: plan.probe_keys.clone(),
+            probe_keys_rt: plan.probe_keys_rt.clone(),
             non_equi_conditions: plan.non_equi_conditions.clone(),
             join_type: plan.join_type.clone(),
             marker_index: plan.marker_index,
@@ -273,6 +282,26 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_recursive_cte(&mut self, plan: &RecursiveCte) -> Result<PhysicalPlan> {
+        let anchor = self.replace(&plan.anchor)?;
+        let recursive = self.replace(&plan.recursive)?;
+        Ok(PhysicalPlan::RecursiveCte(RecursiveCte {
+            plan_id: plan.plan_id,
+            anchor: Box::new(anchor),
+            recursive: Box::new(recursive),
+            cte_index: plan.cte_index,
+            anchor_projection: plan.anchor_projection.clone(),
+            recursive_projection: plan.recursive_projection.clone(),
+            distinct: plan.distinct,
+            schema: plan.schema.clone(),
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
+
+    fn replace_recursive_cte_scan(&mut self, plan: &RecursiveCteScan) -> Result<PhysicalPlan> {
+        Ok(PhysicalPlan::RecursiveCteScan(plan.clone()))
+    }
+
     fn replace_insert_select(&mut self, plan: &DistributedInsertSelect) -> Result<PhysicalPlan> {
         let input = self.replace(&plan.input)?;
 
@@ -300,6 +329,26 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_dict_get(&mut self, plan: &DictGet) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::DictGet(DictGet {
+            plan_id: plan.plan_id,
+            input: Box::new(input),
+            items: plan.items.clone(),
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
+
+    fn replace_udf(&mut self, plan: &Udf) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+        Ok(PhysicalPlan::Udf(Udf {
+            plan_id: plan.plan_id,
+            input: Box::new(input),
+            udf_funcs: plan.udf_funcs.clone(),
+            stat_info: plan.stat_info.clone(),
+        }))
+    }
+
     fn replace_runtime_filter_source(
         &mut self,
         plan: &RuntimeFilterSource,
@@ -372,12 +421,23 @@ impl PhysicalPlan {
                     Self::traverse(&plan.left, pre_visit, visit, post_visit);
                     Self::traverse(&plan.right, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::RecursiveCte(plan) => {
+                    Self::traverse(&plan.anchor, pre_visit, visit, post_visit);
+                    Self::traverse(&plan.recursive, pre_visit, visit, post_visit);
+                }
+                PhysicalPlan::RecursiveCteScan(_) => {}
                 PhysicalPlan::DistributedInsertSelect(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
                 PhysicalPlan::ProjectSet(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit)
                 }
+                PhysicalPlan::DictGet(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit)
+                }
+                PhysicalPlan::Udf(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit)
+                }
                 PhysicalPlan::RuntimeFilterSource(plan) => {
                     Self::traverse(&plan.left_side, pre_visit, visit, post_visit);
                     Self::traverse(&plan.right_side, pre_visit, visit, post_visit);
@@ -48,6 +48,12 @@ fn flatten_plan_node_profile(
                 description: "".to_string(),
                 // We don't record the time spent on table scan for now
                 cpu_time: Default::default(),
+                memory_usage: 0,
+                peak_memory_usage: 0,
+                wait_time: Default::default(),
+                output_rows: 0,
+                output_bytes: 0,
+                spill_bytes: 0,
             };
             plan_node_profs.push(prof);
         }
@@ -61,6 +67,12 @@ fn flatten_plan_node_profile(
                 plan_node_name: "Filter".to_string(),
                 description: "".to_string(),
                 cpu_time: proc_prof.cpu_time,
+                memory_usage: proc_prof.memory_usage,
+                peak_memory_usage: proc_prof.peak_memory_usage,
+                wait_time: proc_prof.wait_time,
+                output_rows: proc_prof.output_rows,
+                output_bytes: proc_prof.output_bytes,
+                spill_bytes: proc_prof.spill_bytes,
             };
             plan_node_profs.push(prof);
         }
@@ -74,6 +86,12 @@ fn flatten_plan_node_profile(
                 plan_node_name: "Project".to_string(),
                 description: "".to_string(),
                 cpu_time: proc_prof.cpu_time,
+                memory_usage: proc_prof.memory_usage,
+                peak_memory_usage: proc_prof.peak_memory_usage,
+                wait_time: proc_prof.wait_time,
+                output_rows: proc_prof.output_rows,
+                output_bytes: proc_prof.output_bytes,
+                spill_bytes: proc_prof.spill_bytes,
             };
             plan_node_profs.push(prof);
         }
@@ -87,6 +105,12 @@ fn flatten_plan_node_profile(
                 plan_node_name: "EvalScalar".to_string(),
                 description: "".to_string(),
                 cpu_time: proc_prof.cpu_time,
+                memory_usage: proc_prof.memory_usage,
+                peak_memory_usage: proc_prof.peak_memory_usage,
+                wait_time: proc_prof.wait_time,
+                output_rows: proc_prof.output_rows,
+                output_bytes: proc_prof.output_bytes,
+                spill_bytes: proc_prof.spill_bytes,
             };
             plan_node_profs.push(prof);
         }
@@ -100,6 +124,50 @@ fn flatten_plan_node_profile(
                 plan_node_name: "ProjectSet".to_string(),
                 description: "".to_string(),
                 cpu_time: proc_prof.cpu_time,
+                memory_usage: proc_prof.memory_usage,
+                peak_memory_usage: proc_prof.peak_memory_usage,
+                wait_time: proc_prof.wait_time,
+                output_rows: proc_prof.output_rows,
+                output_bytes: proc_prof.output_bytes,
+                spill_bytes: proc_prof.spill_bytes,
+            };
+            plan_node_profs.push(prof);
+        }
+        PhysicalPlan::DictGet(dict_get) => {
+            flatten_plan_node_profile(&dict_get.input, profs, plan_node_profs)?;
+            let proc_prof = profs
+                .get(&dict_get.plan_id)
+                .ok_or_else(|| ErrorCode::Internal("Plan node profile not found"))?;
+            let prof = PlanNodeProfile {
+                id: dict_get.plan_id,
+                plan_node_name: "DictGet".to_string(),
+                description: "".to_string(),
+                cpu_time: proc_prof.cpu_time,
+                memory_usage: proc_prof.memory_usage,
+                peak_memory_usage: proc_prof.peak_memory_usage,
+                wait_time: proc_prof.wait_time,
+                output_rows: proc_prof.output_rows,
+                output_bytes: proc_prof.output_bytes,
+                spill_bytes: proc_prof.spill_bytes,
+            };
+            plan_node_profs.push(prof);
+        }
+        PhysicalPlan::Udf(udf) => {
+            flatten_plan_node_profile(&udf.input, profs, plan_node_profs)?;
+            let proc_prof = profs
+                .get(&udf.plan_id)
+                .ok_or_else(|| ErrorCode::Internal("Plan node profile not found"))?;
+            let prof = PlanNodeProfile {
+                id: udf.plan_id,
+                plan_node_name: "Udf".to_string(),
+                description: "".to_string(),
+                cpu_time: proc_prof.cpu_time,
+                memory_usage: proc_prof.memory_usage,
+                peak_memory_usage: proc_prof.peak_memory_usage,
+                wait_time: proc_prof.wait_time,
+                output_rows: proc_prof.output_rows,
+                output_bytes: proc_prof.output_bytes,
+                spill_bytes: proc_prof.spill_bytes,
             };
             plan_node_profs.push(prof);
         }
@@ -113,6 +181,12 @@ fn flatten_plan_node_profile(
                 plan_node_name: "AggregateExpand".to_string(),
                 description: "".to_string(),
                 cpu_time: proc_prof.cpu_time,
+                memory_usage: proc_prof.memory_usage,
+                peak_memory_usage: proc_prof.peak_memory_usage,
+                wait_time: proc_prof.wait_time,
+                output_rows: proc_prof.output_rows,
+                output_bytes: proc_prof.output_bytes,
+                spill_bytes: proc_prof.spill_bytes,
             };
             plan_node_profs.push(prof);
         }
@@ -126,6 +200,12 @@ fn flatten_plan_node_profile(
                 plan_node_name: "AggregatePartial".to_string(),
                 description: "".to_string(),
                 cpu_time: proc_prof.cpu_time,
+                memory_usage: proc_prof.memory_usage,
+                peak_memory_usage: proc_prof.peak_memory_usage,
+                wait_time: proc_prof.wait_time,
+                output_rows: proc_prof.output_rows,
+                output_bytes: proc_prof.output_bytes,
+                spill_bytes: proc_prof.spill_bytes,
             };
             plan_node_profs.push(prof);
         }
@@ -139,6 +219,12 @@ fn flatten_plan_node_profile(
                 plan_node_name: "AggregateFinal".to_string(),
                 description: "".to_string(),
                 cpu_time: proc_prof.cpu_time,
+                memory_usage: proc_prof.memory_usage,
+                peak_memory_usage: proc_prof.peak_memory_usage,
+                wait_time: proc_prof.wait_time,
+                output_rows: proc_prof.output_rows,
+                output_bytes: proc_prof.output_bytes,
+                spill_bytes: proc_prof.spill_bytes,
             };
             plan_node_profs.push(prof);
         }
@@ -152,6 +238,12 @@ fn flatten_plan_node_profile(
                 plan_node_name: "Window".to_string(),
                 description: "".to_string(),
                 cpu_time: proc_prof.cpu_time,
+                memory_usage: proc_prof.memory_usage,
+                peak_memory_usage: proc_prof.peak_memory_usage,
+                wait_time: proc_prof.wait_time,
+                output_rows: proc_prof.output_rows,
+                output_bytes: proc_prof.output_bytes,
+                spill_bytes: proc_prof.spill_bytes,
             };
             plan_node_profs.push(prof);
         }
@@ -165,6 +257,12 @@ fn flatten_plan_node_profile(
                 plan_node_name: "Sort".to_string(),
                 description: "".to_string(),
                 cpu_time: proc_prof.cpu_time,
+                memory_usage: proc_prof.memory_usage,
+                peak_memory_usage: proc_prof.peak_memory_usage,
+                wait_time: proc_prof.wait_time,
+                output_rows: proc_prof.output_rows,
+                output_bytes: proc_prof.output_bytes,
+                spill_bytes: proc_prof.spill_bytes,
             };
             plan_node_profs.push(prof);
         }
@@ -178,6 +276,12 @@ fn flatten_plan_node_profile(
                 plan_node_name: "Limit".to_string(),
                 description: "".to_string(),
                 cpu_time: proc_prof.cpu_time,
+                memory_usage: proc_prof.memory_usage,
+                peak_memory_usage: proc_prof.peak_memory_usage,
+                wait_time: proc_prof.wait_time,
+                output_rows: proc_prof.output_rows,
+                output_bytes: proc_prof.output_bytes,
+                spill_bytes: proc_prof.spill_bytes,
             };
             plan_node_profs.push(prof);
         }
@@ -191,6 +295,12 @@ fn flatten_plan_node_profile(
                 plan_node_name: "RowFetch".to_string(),
                 description: "".to_string(),
                 cpu_time: proc_prof.cpu_time,
+                memory_usage: proc_prof.memory_usage,
+                peak_memory_usage: proc_prof.peak_memory_usage,
+                wait_time: proc_prof.wait_time,
+                output_rows: proc_prof.output_rows,
+                output_bytes: proc_prof.output_bytes,
+                spill_bytes: proc_prof.spill_bytes,
             };
             plan_node_profs.push(prof);
         }
@@ -205,6 +315,12 @@ fn flatten_plan_node_profile(
                 plan_node_name: "HashJoin".to_string(),
                 description: "".to_string(),
                 cpu_time: proc_prof.cpu_time,
+                memory_usage: proc_prof.memory_usage,
+                peak_memory_usage: proc_prof.peak_memory_usage,
+                wait_time: proc_prof.wait_time,
+                output_rows: proc_prof.output_rows,
+                output_bytes: proc_prof.output_bytes,
+                spill_bytes: proc_prof.spill_bytes,
             };
             plan_node_profs.push(prof);
         }
@@ -219,6 +335,12 @@ fn flatten_plan_node_profile(
                 plan_node_name: "RangeJoin".to_string(),
                 description: "".to_string(),
                 cpu_time: proc_prof.cpu_time,
+                memory_usage: proc_prof.memory_usage,
+                peak_memory_usage: proc_prof.peak_memory_usage,
+                wait_time: proc_prof.wait_time,
+                output_rows: proc_prof.output_rows,
+                output_bytes: proc_prof.output_bytes,
+                spill_bytes: proc_prof.spill_bytes,
             };
             plan_node_profs.push(prof);
         }
@@ -232,6 +354,12 @@ fn flatten_plan_node_profile(
                 plan_node_name: "Exchange".to_string(),
                 description: "".to_string(),
                 cpu_time: proc_prof.cpu_time,
+                memory_usage: proc_prof.memory_usage,
+                peak_memory_usage: proc_prof.peak_memory_usage,
+                wait_time: proc_prof.wait_time,
+                output_rows: proc_prof.output_rows,
+                output_bytes: proc_prof.output_bytes,
+                spill_bytes: proc_prof.spill_bytes,
             };
             plan_node_profs.push(prof);
         }
@@ -246,6 +374,44 @@ fn flatten_plan_node_profile(
                 plan_node_name: "UnionAll".to_string(),
                 description: "".to_string(),
                 cpu_time: proc_prof.cpu_time,
+                memory_usage: proc_prof.memory_usage,
+                peak_memory_usage: proc_prof.peak_memory_usage,
+                wait_time: proc_prof.wait_time,
+                output_rows: proc_prof.output_rows,
+                output_bytes: proc_prof.output_bytes,
+                spill_bytes: proc_prof.spill_bytes,
+            };
+            plan_node_profs.push(prof);
+        }
+        PhysicalPlan::RecursiveCte(recursive_cte) => {
+            // The anchor and the recursive term are executed by the pipelines
+            // of the iterations, which are not profiled for now.
+            let prof = PlanNodeProfile {
+                id: recursive_cte.plan_id,
+                plan_node_name: "RecursiveCte".to_string(),
+                description: "".to_string(),
+                cpu_time: Default::default(),
+                memory_usage: 0,
+                peak_memory_usage: 0,
+                wait_time: Default::default(),
+                output_rows: 0,
+                output_bytes: 0,
+                spill_bytes: 0,
+            };
+            plan_node_profs.push(prof);
+        }
+        PhysicalPlan::RecursiveCteScan(scan) => {
+            let prof = PlanNodeProfile {
+                id: scan.plan_id,
+                plan_node_name: "RecursiveCteScan".to_string(),
+                description: "".to_string(),
+                cpu_time: Default::default(),
+                memory_usage: 0,
+                peak_memory_usage: 0,
+                wait_time: Default::default(),
+                output_rows: 0,
+                output_bytes: 0,
+                spill_bytes: 0,
             };
             plan_node_profs.push(prof);
         }
@@ -258,6 +424,12 @@ fn flatten_plan_node_profile(
                 plan_node_name: "RuntimeFilterSource".to_string(),
                 description: "".to_string(),
                 cpu_time: proc_prof.cpu_time,
+                memory_usage: proc_prof.memory_usage,
+                peak_memory_usage: proc_prof.peak_memory_usage,
+                wait_time: proc_prof.wait_time,
+                output_rows: proc_prof.output_rows,
+                output_bytes: proc_prof.output_bytes,
+                spill_bytes: proc_prof.spill_bytes,
             };
             plan_node_profs.push(prof);
         }
@@ -271,6 +443,12 @@ fn flatten_plan_node_profile(
                 plan_node_name: "DistributedInsertSelect".to_string(),
                 description: "".to_string(),
                 cpu_time: proc_prof.cpu_time,
+                memory_usage: proc_prof.memory_usage,
+                peak_memory_usage: proc_prof.peak_memory_usage,
+                wait_time: proc_prof.wait_time,
+                output_rows: proc_prof.output_rows,
+                output_bytes: proc_prof.output_bytes,
+                spill_bytes: proc_prof.spill_bytes,
             };
             plan_node_profs.push(prof);
         }
@@ -283,6 +461,12 @@ fn flatten_plan_node_profile(
                 plan_node_name: "ExchangeSource".to_string(),
                 description: "".to_string(),
                 cpu_time: proc_prof.cpu_time,
+                memory_usage: proc_prof.memory_usage,
+                peak_memory_usage: proc_prof.peak_memory_usage,
+                wait_time: proc_prof.wait_time,
+                output_rows: proc_prof.output_rows,
+                output_bytes: proc_prof.output_bytes,
+                spill_bytes: proc_prof.spill_bytes,
             };
             plan_node_profs.push(prof);
         }
@@ -296,6 +480,12 @@ fn flatten_plan_node_profile(
                 plan_node_name: "ExchangeSink".to_string(),
                 description: "".to_string(),
                 cpu_time: proc_prof.cpu_time,
+                memory_usage: proc_prof.memory_usage,
+                peak_memory_usage: proc_prof.peak_memory_usage,
+                wait_time: proc_prof.wait_time,
+                output_rows: proc_prof.output_rows,
+                output_bytes: proc_prof.output_bytes,
+                spill_bytes: proc_prof.spill_bytes,
             };
             plan_node_profs.push(prof);
         }
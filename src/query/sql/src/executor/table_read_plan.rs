@@ -242,6 +242,7 @@ impl ToReadDataSourcePlan for dyn Table {
             push_downs,
             query_internal_columns: internal_columns.is_some(),
             data_mask_policy,
+            table_index: None,
         })
     }
 }
@@ -43,6 +43,7 @@ use crate::plans::CastExpr;
 use crate::plans::EvalScalar;
 use crate::plans::FunctionCall;
 use crate::plans::LagLeadFunction;
+use crate::plans::LambdaFunc;
 use crate::plans::NthValueFunction;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
@@ -122,6 +123,21 @@ impl<'a> AggregateRewriter<'a> {
                 }
                 .into())
             }
+            ScalarExpr::LambdaFunction(lambda_func) => {
+                let new_args = lambda_func
+                    .args
+                    .iter()
+                    .map(|arg| self.visit(arg))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(LambdaFunc {
+                    span: lambda_func.span,
+                    func_name: lambda_func.func_name.clone(),
+                    args: new_args,
+                    lambda_expr: lambda_func.lambda_expr.clone(),
+                    lambda_display: lambda_func.lambda_display.clone(),
+                }
+                .into())
+            }
             ScalarExpr::CastExpr(cast) => Ok(CastExpr {
                 span: cast.span,
                 is_try: cast.is_try,
@@ -168,6 +184,7 @@ impl<'a> AggregateRewriter<'a> {
                             distinct: agg.distinct,
                             params: agg.params.clone(),
                             return_type: agg.return_type.clone(),
+                            udaf: agg.udaf.clone(),
                         })
                     }
                     WindowFuncType::LagLead(ll) => {
@@ -272,6 +289,7 @@ impl<'a> AggregateRewriter<'a> {
             params: aggregate.params.clone(),
             args: replaced_args,
             return_type: aggregate.return_type.clone(),
+            udaf: aggregate.udaf.clone(),
         };
 
         agg_info.aggregate_functions.push(ScalarItem {
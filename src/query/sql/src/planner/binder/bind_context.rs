@@ -146,16 +146,33 @@ pub struct BindContext {
     /// It's used to check if the view has a loop dependency.
     pub view_info: Option<(String, String)>,
 
+    /// Names of the SQL UDFs being inlined in current context.
+    ///
+    /// It's used to check if a UDF calls itself.
+    pub udf_calls: Vec<String>,
+
     /// Set-returning functions in current context.
     /// The key is the `Expr::to_string` of the function.
     pub srfs: DashMap<String, ScalarExpr>,
 
+    /// `dict_get` calls in current context.
+    /// The key is the `Expr::to_string` of the call.
+    pub dict_gets: DashMap<String, ScalarExpr>,
+
+    /// External UDF calls in current context.
+    /// The key is the `Expr::to_string` of the call.
+    pub udf_server_calls: DashMap<String, ScalarExpr>,
+
     pub expr_context: ExprContext,
 
     /// If true, the query is planning for aggregate index.
     /// It's used to avoid infinite loop.
     pub planning_agg_index: bool,
 
+    /// If true, the query is planning for materialized view.
+    /// It's used to avoid rewriting the query of a materialized view by itself.
+    pub planning_materialized_view: bool,
+
     pub window_definitions: DashMap<String, WindowSpec>,
 }
 
@@ -163,6 +180,19 @@ pub struct BindContext {
 pub struct CteInfo {
     pub columns_alias: Vec<String>,
     pub query: Query,
+    /// Declared with `WITH RECURSIVE`, the query may reference the CTE itself.
+    pub recursive: bool,
+    /// Set while binding the recursive term of a recursive CTE, the references
+    /// to the CTE read the rows of the previous iteration.
+    pub working_table: Option<WorkingTableInfo>,
+}
+
+/// The working table of a recursive CTE.
+#[derive(Clone, Debug)]
+pub struct WorkingTableInfo {
+    pub cte_index: IndexType,
+    pub column_names: Vec<String>,
+    pub column_types: Vec<DataType>,
 }
 
 impl BindContext {
@@ -176,9 +206,13 @@ impl BindContext {
             in_grouping: false,
             ctes_map: Box::new(DashMap::new()),
             view_info: None,
+            udf_calls: vec![],
             srfs: DashMap::new(),
+            dict_gets: DashMap::new(),
+            udf_server_calls: DashMap::new(),
             expr_context: ExprContext::default(),
             planning_agg_index: false,
+            planning_materialized_view: false,
             window_definitions: DashMap::new(),
         }
     }
@@ -193,9 +227,13 @@ impl BindContext {
             in_grouping: false,
             ctes_map: parent.ctes_map.clone(),
             view_info: None,
+            udf_calls: parent.udf_calls.clone(),
             srfs: DashMap::new(),
+            dict_gets: DashMap::new(),
+            udf_server_calls: DashMap::new(),
             expr_context: ExprContext::default(),
             planning_agg_index: false,
+            planning_materialized_view: false,
             window_definitions: DashMap::new(),
         }
     }
@@ -205,6 +243,7 @@ impl BindContext {
         let mut bind_context = BindContext::new();
         bind_context.parent = self.parent.clone();
         bind_context.ctes_map = self.ctes_map.clone();
+        bind_context.udf_calls = self.udf_calls.clone();
         bind_context
     }
 
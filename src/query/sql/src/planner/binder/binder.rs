@@ -17,7 +17,6 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 use chrono_tz::Tz;
-use common_ast::ast::format_statement;
 use common_ast::ast::ExplainKind;
 use common_ast::ast::Hint;
 use common_ast::ast::Identifier;
@@ -34,12 +33,10 @@ use common_expression::ConstantFolder;
 use common_expression::Expr;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_meta_app::principal::StageFileFormatType;
-use common_meta_app::principal::UserDefinedFunction;
 use tracing::warn;
 
 use crate::binder::wrap_cast;
 use crate::normalize_identifier;
-use crate::planner::udf_validator::UDFValidator;
 use crate::plans::AlterUDFPlan;
 use crate::plans::CallPlan;
 use crate::plans::CreateFileFormatPlan;
@@ -152,18 +149,12 @@ impl<'a> Binder {
         let plan = match stmt {
             Statement::Query(query) => {
                 let (s_expr, bind_context) = self.bind_query(bind_context, query).await?;
-                let formatted_ast = if self.ctx.get_settings().get_enable_query_result_cache()? {
-                    Some(format_statement(stmt.clone())?)
-                } else {
-                    None
-                };
                 Plan::Query {
                     s_expr: Box::new(s_expr),
                     metadata: self.metadata.clone(),
                     bind_context: Box::new(bind_context),
                     rewrite_kind: None,
                     ignore_result: query.ignore_result,
-                    formatted_ast,
                 }
             }
 
@@ -230,6 +221,8 @@ impl<'a> Binder {
             Statement::CreateDatabase(stmt) => self.bind_create_database(stmt).await?,
             Statement::DropDatabase(stmt) => self.bind_drop_database(stmt).await?,
             Statement::UndropDatabase(stmt) => self.bind_undrop_database(stmt).await?,
+            Statement::BackupDatabase(stmt) => self.bind_backup_database(stmt).await?,
+            Statement::RestoreDatabase(stmt) => self.bind_restore_database(stmt).await?,
             Statement::AlterDatabase(stmt) => self.bind_alter_database(stmt).await?,
             Statement::UseDatabase { database } => {
                 let database = normalize_identifier(database, &self.name_resolution_ctx).name;
@@ -241,12 +234,19 @@ impl<'a> Binder {
             Statement::ShowColumns(stmt) => self.bind_show_columns(bind_context, stmt).await?,
             // Tables
             Statement::ShowTables(stmt) => self.bind_show_tables(bind_context, stmt).await?,
+            Statement::ShowDropTables(stmt) => {
+                self.bind_show_drop_tables(bind_context, stmt).await?
+            }
             Statement::ShowCreateTable(stmt) => self.bind_show_create_table(stmt).await?,
             Statement::DescribeTable(stmt) => self.bind_describe_table(stmt).await?,
             Statement::ShowTablesStatus(stmt) => {
                 self.bind_show_tables_status(bind_context, stmt).await?
             }
             Statement::CreateTable(stmt) => self.bind_create_table(stmt).await?,
+            Statement::AttachTable(stmt) => self.bind_attach_table(stmt).await?,
+            Statement::CreateExternalTable(stmt) => {
+                self.bind_create_external_table(stmt).await?
+            }
             Statement::DropTable(stmt) => self.bind_drop_table(stmt).await?,
             Statement::UndropTable(stmt) => self.bind_undrop_table(stmt).await?,
             Statement::AlterTable(stmt) => self.bind_alter_table(bind_context, stmt).await?,
@@ -262,6 +262,18 @@ impl<'a> Binder {
             Statement::AlterView(stmt) => self.bind_alter_view(stmt).await?,
             Statement::DropView(stmt) => self.bind_drop_view(stmt).await?,
 
+            // Materialized views
+            Statement::CreateMaterializedView(stmt) => {
+                self.bind_create_materialized_view(bind_context, stmt)
+                    .await?
+            }
+            Statement::DropMaterializedView(stmt) => {
+                self.bind_drop_materialized_view(stmt).await?
+            }
+            Statement::RefreshMaterializedView(stmt) => {
+                self.bind_refresh_materialized_view(stmt).await?
+            }
+
             // Indexes
             Statement::CreateIndex(stmt) => self.bind_create_index(bind_context, stmt).await?,
             Statement::DropIndex(stmt) => self.bind_drop_index(stmt).await?,
@@ -297,6 +309,7 @@ impl<'a> Binder {
                 if_exists: *if_exists,
                 role_name: role_name.to_string(),
             })),
+            Statement::AlterRole(stmt) => self.bind_alter_role(stmt).await?,
 
             // Stages
             Statement::ShowStages => self.bind_rewrite_to_query(bind_context, "SELECT name, stage_type, number_of_files, creator, comment FROM system.stages ORDER BY name", RewriteKind::ShowStages).await?,
@@ -327,6 +340,12 @@ impl<'a> Binder {
                     }
                 }
                 self.bind_replace(bind_context, stmt).await?},
+            Statement::MergeInto(stmt) => self.bind_merge_into(bind_context, stmt).await?,
+
+            // Transactions
+            Statement::Begin => Plan::Begin,
+            Statement::Commit => Plan::Commit,
+            Statement::Abort => Plan::Abort,
             Statement::Delete {
                 hints,
                 table_reference,
@@ -383,51 +402,19 @@ impl<'a> Binder {
             Statement::CreateUDF {
                 if_not_exists,
                 udf_name,
-                parameters,
                 definition,
                 description,
-            } => {
-                let mut validator = UDFValidator {
-                    name: udf_name.to_string(),
-                    parameters: parameters.iter().map(|v| v.to_string()).collect(),
-                    ..Default::default()
-                };
-                validator.verify_definition_expr(definition)?;
-                let udf = UserDefinedFunction {
-                    name: validator.name,
-                    parameters: validator.parameters,
-                    definition: definition.to_string(),
-                    description: description.clone().unwrap_or_default(),
-                };
-
-                Plan::CreateUDF(Box::new(CreateUDFPlan {
-                    if_not_exists: *if_not_exists,
-                    udf,
-                }))
-            }
+            } => Plan::CreateUDF(Box::new(CreateUDFPlan {
+                if_not_exists: *if_not_exists,
+                udf: self.bind_udf_definition(udf_name, definition, description)?,
+            })),
             Statement::AlterUDF {
                 udf_name,
-                parameters,
                 definition,
                 description,
-            } => {
-                let mut validator = UDFValidator {
-                    name: udf_name.to_string(),
-                    parameters: parameters.iter().map(|v| v.to_string()).collect(),
-                    ..Default::default()
-                };
-                validator.verify_definition_expr(definition)?;
-                let udf = UserDefinedFunction {
-                    name: validator.name,
-                    parameters: validator.parameters,
-                    definition: definition.to_string(),
-                    description: description.clone().unwrap_or_default(),
-                };
-
-                Plan::AlterUDF(Box::new(AlterUDFPlan {
-                    udf,
-                }))
-            }
+            } => Plan::AlterUDF(Box::new(AlterUDFPlan {
+                udf: self.bind_udf_definition(udf_name, definition, description)?,
+            })),
             Statement::DropUDF {
                 if_exists,
                 udf_name,
@@ -435,6 +422,43 @@ impl<'a> Binder {
                 if_exists: *if_exists,
                 name: udf_name.to_string(),
             })),
+
+            // Row access policies
+            Statement::CreateRowAccessPolicy(stmt) => {
+                self.bind_create_row_access_policy(stmt).await?
+            }
+            Statement::DropRowAccessPolicy(stmt) => self.bind_drop_row_access_policy(stmt).await?,
+
+            // Network policies
+            Statement::CreateNetworkPolicy(stmt) => self.bind_create_network_policy(stmt).await?,
+            Statement::DropNetworkPolicy(stmt) => self.bind_drop_network_policy(stmt).await?,
+
+            // Dictionaries
+            Statement::CreateDictionary(stmt) => self.bind_create_dictionary(stmt).await?,
+            Statement::DropDictionary(stmt) => self.bind_drop_dictionary(stmt).await?,
+
+            // Sequences
+            Statement::CreateSequence(stmt) => self.bind_create_sequence(stmt).await?,
+            Statement::DropSequence(stmt) => self.bind_drop_sequence(stmt).await?,
+
+            // Tasks
+            Statement::CreateTask(stmt) => self.bind_create_task(stmt).await?,
+            Statement::DropTask(stmt) => self.bind_drop_task(stmt).await?,
+            Statement::AlterTask(stmt) => self.bind_alter_task(stmt).await?,
+            Statement::ExecuteTask(stmt) => self.bind_execute_task(stmt).await?,
+
+            // Notification integrations
+            Statement::CreateNotificationIntegration(stmt) => {
+                self.bind_create_notification_integration(stmt).await?
+            }
+            Statement::DropNotificationIntegration(stmt) => {
+                self.bind_drop_notification_integration(stmt).await?
+            }
+
+            // Pipes
+            Statement::CreatePipe(stmt) => self.bind_create_pipe(stmt).await?,
+            Statement::DropPipe(stmt) => self.bind_drop_pipe(stmt).await?,
+
             Statement::Call(stmt) => Plan::Call(Box::new(CallPlan {
                 name: stmt.name.clone(),
                 args: stmt.args.clone(),
@@ -468,6 +492,9 @@ impl<'a> Binder {
                     .await?
             }
 
+            // cluster statements
+            Statement::AlterClusterNode(stmt) => self.bind_alter_cluster_node(stmt).await?,
+
             // share statements
             Statement::CreateShareEndpoint(stmt) => {
                 self.bind_create_share_endpoint(stmt).await?
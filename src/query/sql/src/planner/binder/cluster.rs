@@ -0,0 +1,39 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::AlterClusterNodeAction;
+use common_ast::ast::AlterClusterNodeStmt;
+use common_exception::Result;
+
+use crate::planner::binder::Binder;
+use crate::plans::DrainClusterNodePlan;
+use crate::plans::Plan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(super) async fn bind_alter_cluster_node(
+        &mut self,
+        stmt: &AlterClusterNodeStmt,
+    ) -> Result<Plan> {
+        let AlterClusterNodeStmt { node_id, action } = stmt;
+        match action {
+            AlterClusterNodeAction::Drain { timeout_secs } => {
+                Ok(Plan::DrainClusterNode(Box::new(DrainClusterNodePlan {
+                    node_id: node_id.clone(),
+                    timeout_secs: *timeout_secs,
+                })))
+            }
+        }
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::GrantObject;
+use common_meta_app::principal::UserPrivilegeType;
+
+use crate::Binder;
+use crate::ColumnBinding;
+use crate::MetadataRef;
+
+impl Binder {
+    /// Returns the columns of a table the current user is allowed to select, if SELECT is only
+    /// granted on some of its columns. `None` means the column privileges need not be checked:
+    /// either SELECT is granted on the whole table, or it is not granted at all and the query
+    /// is rejected by the privilege checker. Like the privilege checker, SELECT on the whole
+    /// table is only taken from the user and the current role, while the column grants are
+    /// also taken from the roles granted to the current role.
+    pub(in crate::planner::binder) async fn granted_columns(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+    ) -> Result<Option<HashSet<String>>> {
+        let user = match self.ctx.get_current_user() {
+            Ok(user) => user,
+            Err(_) => return Ok(None),
+        };
+        let mut entries = user.grants.entries();
+        if let Some(role) = self.ctx.get_current_role() {
+            entries.extend(role.grants.entries());
+        }
+
+        let table_object =
+            GrantObject::Table(catalog.to_string(), database.to_string(), table.to_string());
+        if entries
+            .iter()
+            .any(|e| e.verify_privilege(&table_object, vec![UserPrivilegeType::Select]))
+        {
+            return Ok(None);
+        }
+
+        for role in self.ctx.get_all_effective_roles().await? {
+            entries.extend(role.grants.entries());
+        }
+
+        let columns = entries
+            .iter()
+            .filter_map(|e| match e.object() {
+                GrantObject::Column(cat, db, tbl, column)
+                    if cat == catalog
+                        && db == database
+                        && tbl == table
+                        && e.privileges().contains(UserPrivilegeType::Select) =>
+                {
+                    Some(column.clone())
+                }
+                _ => None,
+            })
+            .collect::<HashSet<_>>();
+        if columns.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(columns))
+        }
+    }
+}
+
+/// Checks that the current user is allowed to select a resolved column, the granted columns
+/// of the tables are recorded in the metadata when they are bound.
+pub fn check_column_privilege(metadata: &MetadataRef, column: &ColumnBinding) -> Result<()> {
+    let table_index = match column.table_index {
+        Some(table_index) => table_index,
+        None => return Ok(()),
+    };
+    let metadata = metadata.read();
+    match metadata.granted_columns(table_index) {
+        Some(columns) if !columns.contains(&column.column_name) => {
+            let table = metadata.table(table_index);
+            let object = GrantObject::Column(
+                table.catalog().to_string(),
+                table.database().to_string(),
+                table.name().to_string(),
+                column.column_name.clone(),
+            );
+            Err(ErrorCode::PermissionDenied(format!(
+                "Permission denied, privilege [Select] is required on {object}"
+            )))
+        }
+        _ => Ok(()),
+    }
+}
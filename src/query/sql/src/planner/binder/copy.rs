@@ -22,7 +22,9 @@ use common_ast::ast::CopyUnit;
 use common_ast::ast::Expr;
 use common_ast::ast::FileLocation;
 use common_ast::ast::Identifier;
+use common_ast::ast::Indirection;
 use common_ast::ast::Query;
+use common_ast::ast::SelectStmt;
 use common_ast::ast::SelectTarget;
 use common_ast::ast::SetExpr;
 use common_ast::ast::Statement;
@@ -34,6 +36,7 @@ use common_ast::parser::parser_values_with_placeholder;
 use common_ast::parser::tokenize_sql;
 use common_ast::Dialect;
 use common_ast::Visitor;
+use common_catalog::plan::ParquetReadOptions;
 use common_catalog::plan::StageTableInfo;
 use common_catalog::table_context::StageAttachment;
 use common_catalog::table_context::TableContext;
@@ -49,6 +52,7 @@ use common_meta_app::principal::FileFormatParams;
 use common_meta_app::principal::OnErrorMode;
 use common_meta_app::principal::StageInfo;
 use common_storage::StageFilesInfo;
+use common_storages_parquet::ParquetTable;
 use common_users::UserApiProvider;
 use parking_lot::RwLock;
 use tracing::debug;
@@ -56,6 +60,8 @@ use tracing::debug;
 use crate::binder::location::parse_uri_location;
 use crate::binder::select::MaxColumnPosition;
 use crate::binder::Binder;
+use crate::normalize_identifier;
+use crate::plans::ColumnMatchMode;
 use crate::plans::CopyIntoTableMode;
 use crate::plans::CopyIntoTablePlan;
 use crate::plans::CopyPlan;
@@ -72,6 +78,19 @@ impl<'a> Binder {
         bind_context: &mut BindContext,
         stmt: &CopyStmt,
     ) -> Result<Plan> {
+        if stmt.partition_by.is_some() {
+            if matches!(stmt.dst, CopyUnit::Table { .. }) {
+                return Err(ErrorCode::SemanticError(
+                    "PARTITION BY is only supported by COPY INTO <location>",
+                ));
+            }
+            if stmt.single {
+                return Err(ErrorCode::SemanticError(
+                    "PARTITION BY can not be used together with SINGLE = TRUE",
+                ));
+            }
+        }
+
         match (&stmt.src, &stmt.dst) {
             (
                 CopyUnit::StageLocation(location),
@@ -92,6 +111,7 @@ impl<'a> Binder {
                     path,
                     files: stmt.files.clone(),
                     pattern: stmt.pattern.clone(),
+                    filter: Default::default(),
                 };
 
                 let table = self
@@ -111,6 +131,10 @@ impl<'a> Binder {
                     .map_err(ErrorCode::SyntaxException)?;
 
                 let stage_schema = infer_table_schema(&required_values_schema)?;
+                if stmt.file_format.is_empty() {
+                    self.detect_stage_file_format(&mut stage_info, &files_info)
+                        .await?;
+                }
 
                 let plan = CopyIntoTablePlan {
                     catalog_name,
@@ -118,12 +142,15 @@ impl<'a> Binder {
                     table_name,
                     validation_mode,
                     force: stmt.force,
+                    column_match_mode: column_match_mode(stmt)?,
+                    error_on_column_count_mismatch: stmt.error_on_column_count_mismatch,
                     stage_table_info: StageTableInfo {
                         schema: stage_schema,
                         files_info,
                         stage_info,
                         files_to_copy: None,
                         is_select: false,
+                        partition_by: vec![],
                     },
                     values_consts: vec![],
                     required_source_schema: required_values_schema.clone(),
@@ -169,6 +196,7 @@ impl<'a> Binder {
                     path,
                     files: stmt.files.clone(),
                     pattern: stmt.pattern.clone(),
+                    filter: Default::default(),
                 };
                 let validation_mode = ValidationMode::from_str(stmt.validation_mode.as_str())
                     .map_err(ErrorCode::SyntaxException)?;
@@ -186,6 +214,10 @@ impl<'a> Binder {
                     .into(),
                 );
                 let stage_schema = infer_table_schema(&required_values_schema)?;
+                if stmt.file_format.is_empty() {
+                    self.detect_stage_file_format(&mut stage_info, &files_info)
+                        .await?;
+                }
 
                 let plan = CopyIntoTablePlan {
                     catalog_name,
@@ -193,12 +225,15 @@ impl<'a> Binder {
                     table_name,
                     validation_mode,
                     force: stmt.force,
+                    column_match_mode: column_match_mode(stmt)?,
+                    error_on_column_count_mismatch: stmt.error_on_column_count_mismatch,
                     stage_table_info: StageTableInfo {
                         schema: stage_schema,
                         files_info,
                         stage_info,
                         files_to_copy: None,
                         is_select: false,
+                        partition_by: vec![],
                     },
                     values_consts: vec![],
                     required_source_schema: required_values_schema.clone(),
@@ -313,6 +348,12 @@ impl<'a> Binder {
                     .write()
                     .set_max_column_position(max_column_position.max_pos);
 
+                if stmt.match_by_column_name.is_some() {
+                    return Err(ErrorCode::SyntaxException(
+                        "MATCH_BY_COLUMN_NAME is not supported when copying from a query",
+                    ));
+                }
+
                 let (select_list, location, alias) = check_transform_query(query)?;
                 let (mut stage_info, path) =
                     parse_file_location(&self.ctx, location, BTreeMap::new()).await?;
@@ -321,7 +362,12 @@ impl<'a> Binder {
                     path,
                     pattern: stmt.pattern.clone(),
                     files: stmt.files.clone(),
+                    filter: Default::default(),
                 };
+                if stmt.file_format.is_empty() {
+                    self.detect_stage_file_format(&mut stage_info, &files_info)
+                        .await?;
+                }
 
                 let table = self
                     .ctx
@@ -344,12 +390,15 @@ impl<'a> Binder {
                     required_values_schema: required_values_schema.clone(),
                     values_consts: vec![],
                     force: stmt.force,
+                    column_match_mode: None,
+                    error_on_column_count_mismatch: false,
                     stage_table_info: StageTableInfo {
                         schema: infer_table_schema(&required_values_schema)?,
                         files_info,
                         stage_info,
                         files_to_copy: None,
                         is_select: false,
+                        partition_by: vec![],
                     },
                     write_mode: CopyIntoTableMode::Copy,
                     query: None,
@@ -372,38 +421,100 @@ impl<'a> Binder {
     async fn bind_copy_into_table_from_location(
         &mut self,
         bind_ctx: &BindContext,
-        plan: CopyIntoTablePlan,
+        mut plan: CopyIntoTablePlan,
     ) -> Result<Plan> {
         if matches!(
             plan.stage_table_info.stage_info.file_format_params,
             FileFormatParams::Parquet(_)
         ) {
-            let select_list = plan
-                .required_source_schema
-                .fields()
-                .iter()
-                .map(|f| SelectTarget::AliasedExpr {
-                    expr: Box::new(Expr::ColumnRef {
-                        span: None,
-                        database: None,
-                        table: None,
-                        column: AstColumnID::Name(Identifier {
-                            name: f.name().to_string(),
-                            quote: None,
-                            span: None,
-                        }),
-                    }),
-                    alias: None,
-                })
-                .collect::<Vec<_>>();
+            let select_list = match plan.column_match_mode {
+                Some(mode) => self.match_parquet_columns(&mut plan, mode).await?,
+                None => plan
+                    .required_source_schema
+                    .fields()
+                    .iter()
+                    .map(|f| column_ref(f.name(), None))
+                    .collect::<Vec<_>>(),
+            };
 
             self.bind_copy_from_query_into_table(bind_ctx, plan, &select_list, &None)
                 .await
+        } else if plan.column_match_mode.is_some() {
+            Err(ErrorCode::SemanticError(
+                "MATCH_BY_COLUMN_NAME is only supported for PARQUET files",
+            ))
         } else {
             Ok(Plan::Copy(Box::new(CopyPlan::IntoTable(plan))))
         }
     }
 
+    /// Matches the columns of the table to the columns of the parquet files by name. The
+    /// table columns missing in the files are removed from the plan, so they are filled
+    /// with their default values.
+    #[async_backtrace::framed]
+    async fn match_parquet_columns(
+        &mut self,
+        plan: &mut CopyIntoTablePlan,
+        mode: ColumnMatchMode,
+    ) -> Result<Vec<SelectTarget>> {
+        let parquet_table = ParquetTable::create(
+            plan.stage_table_info.stage_info.clone(),
+            plan.stage_table_info.files_info.clone(),
+            ParquetReadOptions::default(),
+            None,
+        )
+        .await?;
+        let file_schema = parquet_table.schema();
+
+        let mut fields = vec![];
+        let mut select_list = vec![];
+        let mut matched = vec![false; file_schema.num_fields()];
+        for field in plan.required_source_schema.fields() {
+            let mut file_columns = file_schema
+                .fields()
+                .iter()
+                .enumerate()
+                .filter(|(_, f)| mode.matches(f.name(), field.name()));
+            match (file_columns.next(), file_columns.next()) {
+                (Some((i, file_column)), None) => {
+                    matched[i] = true;
+                    fields.push(field.clone());
+                    // quoted to keep the case of the file column.
+                    select_list.push(column_ref(file_column.name(), Some('"')));
+                }
+                (Some(_), Some(_)) => {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "column `{}` matches more than one column of the parquet files",
+                        field.name()
+                    )));
+                }
+                (None, _) => {}
+            }
+        }
+
+        if plan.error_on_column_count_mismatch {
+            if let Some(i) = matched.iter().position(|m| !m) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "column `{}` of the parquet files does not exist in table `{}`",
+                    file_schema.field(i).name(),
+                    plan.table_name
+                )));
+            }
+        }
+        if fields.is_empty() {
+            return Err(ErrorCode::BadArguments(format!(
+                "no column of the parquet files matches the columns of table `{}`",
+                plan.table_name
+            )));
+        }
+
+        let schema: DataSchemaRef = Arc::new(DataSchema::new(fields));
+        plan.stage_table_info.schema = infer_table_schema(&schema)?;
+        plan.required_source_schema = schema.clone();
+        plan.required_values_schema = schema;
+        Ok(select_list)
+    }
+
     #[async_backtrace::framed]
     pub(crate) async fn bind_attachment(
         &mut self,
@@ -425,6 +536,7 @@ impl<'a> Binder {
             path,
             files: None,
             pattern: None,
+            filter: Default::default(),
         };
         Ok((stage_info, files_info))
     }
@@ -443,8 +555,12 @@ impl<'a> Binder {
         values_str: &str,
         write_mode: CopyIntoTableMode,
     ) -> Result<Plan> {
-        let (data_schema, const_columns) = if values_str.is_empty() {
-            (required_values_schema.clone(), vec![])
+        let (required_values_schema, data_schema, const_columns) = if values_str.is_empty() {
+            (
+                required_values_schema.clone(),
+                required_values_schema,
+                vec![],
+            )
         } else {
             self.prepared_values(values_str, &required_values_schema)
                 .await?
@@ -463,12 +579,15 @@ impl<'a> Binder {
             required_values_schema,
             values_consts: const_columns,
             force: true,
+            column_match_mode: None,
+            error_on_column_count_mismatch: false,
             stage_table_info: StageTableInfo {
                 schema: stage_schema,
                 files_info,
                 stage_info,
                 files_to_copy: None,
                 is_select: false,
+                partition_by: vec![],
             },
             write_mode,
             query: None,
@@ -497,11 +616,8 @@ impl<'a> Binder {
         let tokens = tokenize_sql(&subquery)?;
         let sub_stmt_msg = parse_sql(&tokens, Dialect::PostgreSQL)?;
         let sub_stmt = sub_stmt_msg.0;
-        let query = match &sub_stmt {
-            Statement::Query(query) => {
-                self.bind_statement(bind_context, &Statement::Query(query.clone()))
-                    .await?
-            }
+        let (query, partition_by) = match sub_stmt {
+            Statement::Query(query) => self.bind_unload_query(bind_context, stmt, *query).await?,
             _ => {
                 return Err(ErrorCode::SyntaxException(
                     "COPY INTO <location> FROM <non-query> is invalid",
@@ -521,6 +637,7 @@ impl<'a> Binder {
             stage: Box::new(stage_info),
             path,
             validation_mode,
+            partition_by,
             from: Box::new(query),
         })))
     }
@@ -542,11 +659,8 @@ impl<'a> Binder {
         let tokens = tokenize_sql(&subquery)?;
         let sub_stmt_msg = parse_sql(&tokens, Dialect::PostgreSQL)?;
         let sub_stmt = sub_stmt_msg.0;
-        let query = match &sub_stmt {
-            Statement::Query(query) => {
-                self.bind_statement(bind_context, &Statement::Query(query.clone()))
-                    .await?
-            }
+        let (query, partition_by) = match sub_stmt {
+            Statement::Query(query) => self.bind_unload_query(bind_context, stmt, *query).await?,
             _ => {
                 return Err(ErrorCode::SyntaxException(
                     "COPY INTO <location> FROM <non-query> is invalid",
@@ -572,6 +686,7 @@ impl<'a> Binder {
             stage: Box::new(stage_info),
             path,
             validation_mode,
+            partition_by,
             from: Box::new(query),
         })))
     }
@@ -586,8 +701,8 @@ impl<'a> Binder {
         dst_stage: &str,
         dst_path: &str,
     ) -> Result<Plan> {
-        let query = self
-            .bind_statement(bind_context, &Statement::Query(Box::new(src_query.clone())))
+        let (query, partition_by) = self
+            .bind_unload_query(bind_context, stmt, src_query.clone())
             .await?;
 
         // Validation mode.
@@ -602,6 +717,7 @@ impl<'a> Binder {
             stage: Box::new(stage_info),
             path,
             validation_mode,
+            partition_by,
             from: Box::new(query),
         })))
     }
@@ -616,8 +732,8 @@ impl<'a> Binder {
         src_query: &Query,
         dst_uri_location: &mut UriLocation,
     ) -> Result<Plan> {
-        let query = self
-            .bind_statement(bind_context, &Statement::Query(Box::new(src_query.clone())))
+        let (query, partition_by) = self
+            .bind_unload_query(bind_context, stmt, src_query.clone())
             .await?;
 
         // Validation mode.
@@ -638,10 +754,101 @@ impl<'a> Binder {
             stage: Box::new(stage_info),
             path,
             validation_mode,
+            partition_by,
             from: Box::new(query),
         })))
     }
 
+    /// Bind the source query of COPY INTO <location>.
+    ///
+    /// With `PARTITION BY`, the query is bound as `SELECT *, <keys> FROM (<query>)`, so the
+    /// partition values arrive as the trailing columns of every block and the sink can
+    /// route rows to their directories without evaluating expressions itself.
+    #[async_backtrace::framed]
+    async fn bind_unload_query(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &CopyStmt,
+        query: Query,
+    ) -> Result<(Plan, Vec<String>)> {
+        let partition_by = match &stmt.partition_by {
+            None => {
+                let plan = self
+                    .bind_statement(bind_context, &Statement::Query(Box::new(query)))
+                    .await?;
+                return Ok((plan, vec![]));
+            }
+            Some(partition_by) => partition_by,
+        };
+
+        let mut select_list = vec![SelectTarget::QualifiedName {
+            qualified: vec![Indirection::Star(None)],
+            exclude: None,
+        }];
+        let mut names = Vec::with_capacity(partition_by.len());
+        for target in partition_by {
+            let name = match target {
+                SelectTarget::AliasedExpr {
+                    alias: Some(alias), ..
+                } => normalize_identifier(alias, &self.name_resolution_ctx).name,
+                SelectTarget::AliasedExpr { expr, alias: None } => match expr.as_ref() {
+                    Expr::ColumnRef {
+                        column: AstColumnID::Name(column),
+                        ..
+                    } => normalize_identifier(column, &self.name_resolution_ctx).name,
+                    _ => {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "PARTITION BY expression `{expr}` must be a column or have an alias"
+                        )));
+                    }
+                },
+                SelectTarget::QualifiedName { .. } => {
+                    return Err(ErrorCode::SemanticError(
+                        "PARTITION BY does not accept wildcards",
+                    ));
+                }
+            };
+            if names.contains(&name) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "duplicate partition key `{name}` in PARTITION BY"
+                )));
+            }
+            names.push(name);
+            select_list.push(target.clone());
+        }
+
+        let wrapped = Query {
+            span: None,
+            with: None,
+            body: SetExpr::Select(Box::new(SelectStmt {
+                span: None,
+                hints: None,
+                distinct: false,
+                select_list,
+                from: vec![TableReference::Subquery {
+                    span: None,
+                    lateral: false,
+                    subquery: Box::new(query),
+                    alias: None,
+                    pivot: None,
+                    unpivot: None,
+                }],
+                selection: None,
+                group_by: None,
+                having: None,
+                window_list: None,
+            })),
+            order_by: vec![],
+            limit: vec![],
+            offset: None,
+            ignore_result: false,
+        };
+        let plan = self
+            .bind_statement(bind_context, &Statement::Query(Box::new(wrapped)))
+            .await?;
+        Ok((plan, names))
+    }
+
     /// Bind COPY INTO <table> FROM <query>
     #[async_backtrace::framed]
     #[allow(clippy::too_many_arguments)]
@@ -687,7 +894,6 @@ impl<'a> Binder {
             bind_context: Box::new(output_context),
             rewrite_kind: None,
             ignore_result: false,
-            formatted_ast: None,
         }));
         Ok(Plan::Copy(Box::new(CopyPlan::IntoTable(plan))))
     }
@@ -725,12 +931,13 @@ impl<'a> Binder {
         Ok(())
     }
 
+    /// Returns the schema of the values, the schema of the attachment and the constant values.
     #[async_backtrace::framed]
     pub(crate) async fn prepared_values(
         &self,
         values_str: &str,
         source_schema: &DataSchemaRef,
-    ) -> Result<(DataSchemaRef, Vec<Scalar>)> {
+    ) -> Result<(DataSchemaRef, DataSchemaRef, Vec<Scalar>)> {
         let settings = self.ctx.get_settings();
         let sql_dialect = settings.get_sql_dialect()?;
         let tokens = tokenize_sql(values_str)?;
@@ -744,17 +951,27 @@ impl<'a> Binder {
             )));
         }
 
+        let mut values_fields = vec![];
         let mut attachment_fields = vec![];
         let mut const_fields = vec![];
         let mut exprs = vec![];
         for (i, eo) in expr_or_placeholders.into_iter().enumerate() {
+            let field = source_schema.fields()[i].clone();
             match eo {
+                // `DEFAULT` columns are left out of the values and filled as missing columns,
+                // so that non-deterministic defaults like `uuid()` are evaluated per row.
+                Some(Expr::ColumnRef { column, .. })
+                    if column.name().eq_ignore_ascii_case("default") =>
+                {
+                    continue;
+                }
                 Some(e) => {
                     exprs.push(e);
-                    const_fields.push(source_schema.fields()[i].clone());
+                    const_fields.push(field.clone());
                 }
-                None => attachment_fields.push(source_schema.fields()[i].clone()),
+                None => attachment_fields.push(field.clone()),
             }
+            values_fields.push(field);
         }
         let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
         let mut bind_context = BindContext::new();
@@ -769,10 +986,37 @@ impl<'a> Binder {
                 metadata,
             )
             .await?;
-        Ok((Arc::new(DataSchema::new(attachment_fields)), const_values))
+        Ok((
+            Arc::new(DataSchema::new(values_fields)),
+            Arc::new(DataSchema::new(attachment_fields)),
+            const_values,
+        ))
+    }
+}
+
+fn column_ref(name: &str, quote: Option<char>) -> SelectTarget {
+    SelectTarget::AliasedExpr {
+        expr: Box::new(Expr::ColumnRef {
+            span: None,
+            database: None,
+            table: None,
+            column: AstColumnID::Name(Identifier {
+                name: name.to_string(),
+                quote,
+                span: None,
+            }),
+        }),
+        alias: None,
     }
 }
 
+fn column_match_mode(stmt: &CopyStmt) -> Result<Option<ColumnMatchMode>> {
+    stmt.match_by_column_name
+        .as_ref()
+        .map(|v| ColumnMatchMode::from_str(v).map_err(ErrorCode::SyntaxException))
+        .transpose()
+}
+
 // we can avoid this by specializing the parser.
 // make parse a little more complex, now it is COPY ~ INTO ~ #copy_unit ~ FROM ~ #copy_unit
 // also check_query here may give a more friendly error msg.
@@ -12,19 +12,28 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use common_ast::ast::AccountMgrLevel;
 use common_ast::ast::AccountMgrSource;
+use common_ast::ast::AlterRoleAction;
+use common_ast::ast::AlterRoleStmt;
 use common_ast::ast::AlterUserStmt;
 use common_ast::ast::CreateUserStmt;
 use common_ast::ast::GrantStmt;
+use common_ast::ast::Identifier;
 use common_ast::ast::RevokeStmt;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::principal::AuthInfo;
 use common_meta_app::principal::GrantObject;
 use common_meta_app::principal::UserOption;
 use common_meta_app::principal::UserPrivilegeSet;
+use common_settings::Settings;
 use common_users::UserApiProvider;
 
+use crate::normalize_identifier;
+use crate::plans::AlterRolePlan;
 use crate::plans::AlterUserPlan;
 use crate::plans::CreateUserPlan;
 use crate::plans::GrantPrivilegePlan;
@@ -57,7 +66,7 @@ impl Binder {
                 let priv_types = grant_object.available_privileges();
                 let plan = GrantPrivilegePlan {
                     principal: principal.clone(),
-                    on: grant_object,
+                    on: vec![grant_object],
                     priv_types,
                 };
                 Ok(Plan::GrantPriv(Box::new(plan)))
@@ -70,11 +79,24 @@ impl Binder {
                 }
                 let plan = GrantPrivilegePlan {
                     principal: principal.clone(),
-                    on: grant_object,
+                    on: vec![grant_object],
                     priv_types,
                 };
                 Ok(Plan::GrantPriv(Box::new(plan)))
             }
+            AccountMgrSource::ColumnPrivs {
+                privilege,
+                columns,
+                level,
+            } => {
+                let on = self.convert_to_column_grant_objects(columns, level)?;
+                let plan = GrantPrivilegePlan {
+                    principal: principal.clone(),
+                    on,
+                    priv_types: (*privilege).into(),
+                };
+                Ok(Plan::GrantPriv(Box::new(plan)))
+            }
         }
     }
 
@@ -100,7 +122,7 @@ impl Binder {
                 let priv_types = grant_object.available_privileges();
                 let plan = RevokePrivilegePlan {
                     principal: principal.clone(),
-                    on: grant_object,
+                    on: vec![grant_object],
                     priv_types,
                 };
                 Ok(Plan::RevokePriv(Box::new(plan)))
@@ -113,11 +135,24 @@ impl Binder {
                 }
                 let plan = RevokePrivilegePlan {
                     principal: principal.clone(),
-                    on: grant_object,
+                    on: vec![grant_object],
                     priv_types,
                 };
                 Ok(Plan::RevokePriv(Box::new(plan)))
             }
+            AccountMgrSource::ColumnPrivs {
+                privilege,
+                columns,
+                level,
+            } => {
+                let on = self.convert_to_column_grant_objects(columns, level)?;
+                let plan = RevokePrivilegePlan {
+                    principal: principal.clone(),
+                    on,
+                    priv_types: (*privilege).into(),
+                };
+                Ok(Plan::RevokePriv(Box::new(plan)))
+            }
         }
     }
 
@@ -144,6 +179,29 @@ impl Binder {
         }
     }
 
+    fn convert_to_column_grant_objects(
+        &self,
+        columns: &[Identifier],
+        level: &AccountMgrLevel,
+    ) -> Result<Vec<GrantObject>> {
+        match self.convert_to_grant_object(level) {
+            GrantObject::Table(catalog_name, database_name, table_name) => Ok(columns
+                .iter()
+                .map(|column| {
+                    GrantObject::Column(
+                        catalog_name.clone(),
+                        database_name.clone(),
+                        table_name.clone(),
+                        normalize_identifier(column, &self.name_resolution_ctx).name,
+                    )
+                })
+                .collect()),
+            _ => Err(ErrorCode::SemanticError(
+                "column privileges can only be granted on a table",
+            )),
+        }
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_create_user(
         &mut self,
@@ -159,6 +217,8 @@ impl Binder {
         for option in user_options {
             option.apply(&mut user_option);
         }
+        self.verify_network_policy(&user_option).await?;
+        verify_settings(user_option.settings())?;
         let plan = CreateUserPlan {
             user: user.clone(),
             auth_info: AuthInfo::create2(&auth_option.auth_type, &auth_option.password)?,
@@ -213,6 +273,8 @@ impl Binder {
         for option in user_options {
             option.apply(&mut user_option);
         }
+        self.verify_network_policy(&user_option).await?;
+        verify_settings(user_option.settings())?;
         let new_user_option = if user_option == user_info.option {
             None
         } else {
@@ -226,4 +288,43 @@ impl Binder {
 
         Ok(Plan::AlterUser(Box::new(plan)))
     }
+
+    #[async_backtrace::framed]
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_role(
+        &mut self,
+        stmt: &AlterRoleStmt,
+    ) -> Result<Plan> {
+        let AlterRoleStmt { role_name, action } = stmt;
+        let (set_settings, unset_settings) = match action {
+            AlterRoleAction::SetSettings(settings) => {
+                verify_settings(settings)?;
+                (settings.clone(), vec![])
+            }
+            AlterRoleAction::UnsetSettings(names) => (BTreeMap::new(), names.clone()),
+        };
+        Ok(Plan::AlterRole(Box::new(AlterRolePlan {
+            role_name: role_name.clone(),
+            set_settings,
+            unset_settings,
+        })))
+    }
+
+    async fn verify_network_policy(&self, user_option: &UserOption) -> Result<()> {
+        if let Some(name) = user_option.network_policy() {
+            // A user can only be attached to an existing network policy.
+            UserApiProvider::instance()
+                .get_network_policy(&self.ctx.get_tenant(), name)
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+/// The settings attached to a user or a role must be valid session settings.
+fn verify_settings(settings: &BTreeMap<String, String>) -> Result<()> {
+    for (name, value) in settings {
+        Settings::check_setting_value(name, value)?;
+    }
+    Ok(())
 }
@@ -16,9 +16,11 @@ use std::collections::BTreeMap;
 
 use common_ast::ast::AlterDatabaseAction;
 use common_ast::ast::AlterDatabaseStmt;
+use common_ast::ast::BackupDatabaseStmt;
 use common_ast::ast::CreateDatabaseStmt;
 use common_ast::ast::DatabaseEngine;
 use common_ast::ast::DropDatabaseStmt;
+use common_ast::ast::RestoreDatabaseStmt;
 use common_ast::ast::SQLProperty;
 use common_ast::ast::ShowCreateDatabaseStmt;
 use common_ast::ast::ShowDatabasesStmt;
@@ -32,13 +34,16 @@ use common_meta_app::schema::DatabaseMeta;
 use common_meta_app::share::ShareNameIdent;
 use tracing::debug;
 
+use crate::binder::parse_stage_location;
 use crate::binder::Binder;
 use crate::planner::semantic::normalize_identifier;
+use crate::plans::BackupDatabasePlan;
 use crate::plans::CreateDatabasePlan;
 use crate::plans::DropDatabasePlan;
 use crate::plans::Plan;
 use crate::plans::RenameDatabaseEntity;
 use crate::plans::RenameDatabasePlan;
+use crate::plans::RestoreDatabasePlan;
 use crate::plans::RewriteKind;
 use crate::plans::ShowCreateDatabasePlan;
 use crate::plans::UndropDatabasePlan;
@@ -199,6 +204,64 @@ impl Binder {
         })))
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_backup_database(
+        &self,
+        stmt: &BackupDatabaseStmt,
+    ) -> Result<Plan> {
+        let BackupDatabaseStmt {
+            catalog,
+            database,
+            location,
+            with_data,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = catalog
+            .as_ref()
+            .map(|catalog| normalize_identifier(catalog, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_catalog());
+        let database = normalize_identifier(database, &self.name_resolution_ctx).name;
+        let (stage, path) = parse_stage_location(&self.ctx, &format!("@{location}")).await?;
+
+        Ok(Plan::BackupDatabase(Box::new(BackupDatabasePlan {
+            tenant,
+            catalog,
+            database,
+            stage,
+            path: backup_directory(&path),
+            with_data: *with_data,
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_restore_database(
+        &self,
+        stmt: &RestoreDatabaseStmt,
+    ) -> Result<Plan> {
+        let RestoreDatabaseStmt {
+            catalog,
+            database,
+            location,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = catalog
+            .as_ref()
+            .map(|catalog| normalize_identifier(catalog, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_catalog());
+        let database = normalize_identifier(database, &self.name_resolution_ctx).name;
+        let (stage, path) = parse_stage_location(&self.ctx, &format!("@{location}")).await?;
+
+        Ok(Plan::RestoreDatabase(Box::new(RestoreDatabasePlan {
+            tenant,
+            catalog,
+            database,
+            stage,
+            path: backup_directory(&path),
+        })))
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_create_database(
         &self,
@@ -263,3 +326,11 @@ impl Binder {
         })
     }
 }
+
+/// The files of a backup are put under the directory `path` of the stage.
+fn backup_directory(path: &str) -> String {
+    match path.trim_matches('/') {
+        "" => String::new(),
+        path => format!("{path}/"),
+    }
+}
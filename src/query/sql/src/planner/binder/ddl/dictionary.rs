@@ -0,0 +1,166 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::BTreeMap;
+
+use chrono::Utc;
+use common_ast::ast::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_meta_app::principal::DictionaryMeta;
+use common_meta_app::principal::DictionarySource;
+use common_meta_app::principal::MysqlDictionarySource;
+use common_meta_app::principal::RedisDictionarySource;
+
+use crate::binder::Binder;
+use crate::planner::semantic::normalize_identifier;
+use crate::planner::semantic::resolve_type_name;
+use crate::plans::CreateDictionaryPlan;
+use crate::plans::DropDictionaryPlan;
+use crate::plans::Plan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_dictionary(
+        &mut self,
+        stmt: &CreateDictionaryStmt,
+    ) -> Result<Plan> {
+        let CreateDictionaryStmt {
+            if_not_exists,
+            name,
+            columns,
+            primary_key,
+            source_type,
+            source_options,
+            comment,
+        } = stmt;
+
+        let mut fields = Vec::with_capacity(columns.len());
+        for column in columns {
+            let name = normalize_identifier(&column.name, &self.name_resolution_ctx).name;
+            if fields.iter().any(|f: &TableField| f.name() == &name) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "duplicated column {name} in dictionary"
+                )));
+            }
+            let data_type = resolve_type_name(&column.data_type)?;
+            fields.push(TableField::new(&name, data_type));
+        }
+
+        let primary_key = normalize_identifier(primary_key, &self.name_resolution_ctx).name;
+        let key_field = fields
+            .iter()
+            .find(|f| f.name() == &primary_key)
+            .ok_or_else(|| {
+                ErrorCode::SemanticError(format!(
+                    "primary key {primary_key} is not a column of the dictionary"
+                ))
+            })?;
+        if !matches!(
+            key_field.data_type().remove_nullable(),
+            TableDataType::String | TableDataType::Number(_)
+        ) {
+            return Err(ErrorCode::SemanticError(format!(
+                "primary key {primary_key} of dictionary must be a string or a number, but got {}",
+                key_field.data_type()
+            )));
+        }
+        if fields.len() < 2 {
+            return Err(ErrorCode::SemanticError(
+                "dictionary must have at least one column besides the primary key",
+            ));
+        }
+
+        let source = match source_type.name.to_lowercase().as_str() {
+            "mysql" => DictionarySource::Mysql(MysqlDictionarySource {
+                host: required_option(source_options, "host")?,
+                port: port_option(source_options, 3306)?,
+                username: required_option(source_options, "username")?,
+                password: source_options.get("password").cloned().unwrap_or_default(),
+                database: required_option(source_options, "db")?,
+                table: required_option(source_options, "table")?,
+            }),
+            "redis" => {
+                if fields.len() != 2 {
+                    return Err(ErrorCode::SemanticError(
+                        "redis dictionary must have exactly one column besides the primary key",
+                    ));
+                }
+                let db_index = match source_options.get("db_index") {
+                    None => 0,
+                    Some(v) => v.parse::<i64>().map_err(|_| {
+                        ErrorCode::SemanticError(format!("invalid redis db_index '{v}'"))
+                    })?,
+                };
+                DictionarySource::Redis(RedisDictionarySource {
+                    host: required_option(source_options, "host")?,
+                    port: port_option(source_options, 6379)?,
+                    username: source_options.get("username").cloned(),
+                    password: source_options.get("password").cloned(),
+                    db_index,
+                })
+            }
+            other => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "unsupported dictionary source {other}, expected MYSQL or REDIS"
+                )));
+            }
+        };
+
+        let meta = DictionaryMeta {
+            name: normalize_identifier(name, &self.name_resolution_ctx).name,
+            source,
+            schema: TableSchema::new(fields),
+            primary_key,
+            comment: comment.clone().unwrap_or_default(),
+            created_on: Utc::now(),
+        };
+        Ok(Plan::CreateDictionary(Box::new(CreateDictionaryPlan {
+            if_not_exists: *if_not_exists,
+            tenant: self.ctx.get_tenant(),
+            meta,
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_dictionary(
+        &mut self,
+        stmt: &DropDictionaryStmt,
+    ) -> Result<Plan> {
+        let DropDictionaryStmt { if_exists, name } = stmt;
+
+        Ok(Plan::DropDictionary(Box::new(DropDictionaryPlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            name: normalize_identifier(name, &self.name_resolution_ctx).name,
+        })))
+    }
+}
+
+fn required_option(options: &BTreeMap<String, String>, key: &str) -> Result<String> {
+    options.get(key).cloned().ok_or_else(|| {
+        ErrorCode::SemanticError(format!("dictionary source option '{key}' is required"))
+    })
+}
+
+fn port_option(options: &BTreeMap<String, String>, default: u16) -> Result<u16> {
+    match options.get("port") {
+        None => Ok(default),
+        Some(v) => v
+            .parse::<u16>()
+            .map_err(|_| ErrorCode::SemanticError(format!("invalid port '{v}'"))),
+    }
+}
@@ -18,11 +18,19 @@ use common_ast::ast::GroupBy;
 use common_ast::ast::Identifier;
 use common_ast::ast::Query;
 use common_ast::ast::SetExpr;
+use common_ast::ast::Statement;
 use common_ast::ast::TableReference;
+use common_ast::parser::parse_sql;
+use common_ast::parser::tokenize_sql;
+use common_ast::Dialect;
+use common_catalog::plan::AggIndexAggregation;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
 use crate::binder::Binder;
+use crate::executor::build_agg_index_aggregation;
+use crate::optimizer::agg_index::index_aggregation;
+use crate::optimizer::SExpr;
 use crate::plans::CreateIndexPlan;
 use crate::plans::DropIndexPlan;
 use crate::plans::Plan;
@@ -48,7 +56,7 @@ impl Binder {
         let index_name = self.normalize_object_identifier(index_name);
 
         bind_context.planning_agg_index = true;
-        self.bind_query(bind_context, query).await?;
+        let (s_expr, _) = self.bind_query(bind_context, query).await?;
         bind_context.planning_agg_index = false;
 
         let tables = self.metadata.read().tables().to_vec();
@@ -69,6 +77,9 @@ impl Binder {
             )));
         }
 
+        // The partial aggregate states of each block are stored as the index data.
+        let aggregation = self.index_aggregation(table_entry.index(), &s_expr)?;
+
         let table_id = table.get_id();
         let mut query = *query.clone();
         Self::rewrite_query_with_database(&mut query, table_entry.database());
@@ -79,10 +90,51 @@ impl Binder {
             index_name,
             query: query.to_string(),
             table_id,
+            database: table_entry.database().to_string(),
+            table: table_entry.name().to_string(),
+            aggregation,
         };
         Ok(Plan::CreateIndex(Box::new(plan)))
     }
 
+    /// Bind the partial aggregation computing the data of aggregating index `query`
+    /// for each block of its table.
+    ///
+    /// Returns [None] if the index is not an aggregation.
+    #[async_backtrace::framed]
+    pub async fn bind_agg_index_aggregation(
+        mut self,
+        query: &str,
+    ) -> Result<Option<AggIndexAggregation>> {
+        let tokens = tokenize_sql(query)?;
+        let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL)?;
+        let query = match &stmt {
+            Statement::Query(query) => query,
+            _ => return Ok(None),
+        };
+
+        let mut bind_context = BindContext::new();
+        bind_context.planning_agg_index = true;
+        let (s_expr, _) = self.bind_query(&mut bind_context, query).await?;
+
+        let table_index = match self.metadata.read().tables() {
+            [table_entry] => table_entry.index(),
+            _ => return Ok(None),
+        };
+        self.index_aggregation(table_index, &s_expr)
+    }
+
+    fn index_aggregation(
+        &self,
+        table_index: usize,
+        s_expr: &SExpr,
+    ) -> Result<Option<AggIndexAggregation>> {
+        let base_columns = self.metadata.read().columns_by_table_index(table_index);
+        index_aggregation(&base_columns, s_expr)?
+            .map(|aggregation| build_agg_index_aggregation(&aggregation))
+            .transpose()
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_drop_index(
         &mut self,
@@ -132,7 +184,7 @@ impl Binder {
         Ok(())
     }
 
-    fn rewrite_query_with_database(query: &mut Query, name: &str) {
+    pub(in crate::planner::binder) fn rewrite_query_with_database(query: &mut Query, name: &str) {
         if let SetExpr::Select(stmt) = &mut query.body {
             if let TableReference::Table { database, .. } = &mut stmt.from[0] {
                 if database.is_none() {
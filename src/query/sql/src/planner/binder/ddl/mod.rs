@@ -17,10 +17,17 @@ mod catalog;
 mod column;
 mod data_mask;
 mod database;
+mod dictionary;
 mod index;
+mod network_policy;
+mod notification;
+mod pipe;
 mod role;
+mod row_access_policy;
+mod sequence;
 mod share;
 mod stage;
 mod table;
+mod task;
 mod view;
 mod virtual_column;
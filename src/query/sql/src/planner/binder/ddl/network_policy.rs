@@ -0,0 +1,73 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use chrono::Utc;
+use common_ast::ast::*;
+use common_exception::Result;
+use common_meta_app::principal::NetworkPolicy;
+use common_users::parse_network_policy_ip;
+
+use crate::binder::Binder;
+use crate::planner::semantic::normalize_identifier;
+use crate::plans::CreateNetworkPolicyPlan;
+use crate::plans::DropNetworkPolicyPlan;
+use crate::plans::Plan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_network_policy(
+        &mut self,
+        stmt: &CreateNetworkPolicyStmt,
+    ) -> Result<Plan> {
+        let CreateNetworkPolicyStmt {
+            if_not_exists,
+            name,
+            allowed_ip_list,
+            blocked_ip_list,
+            comment,
+        } = stmt;
+
+        for ip in allowed_ip_list.iter().chain(blocked_ip_list.iter()) {
+            parse_network_policy_ip(ip)?;
+        }
+
+        let policy = NetworkPolicy {
+            name: normalize_identifier(name, &self.name_resolution_ctx).name,
+            allowed_ip_list: allowed_ip_list.clone(),
+            blocked_ip_list: blocked_ip_list.clone(),
+            comment: comment.clone().unwrap_or_default(),
+            created_on: Utc::now(),
+        };
+        Ok(Plan::CreateNetworkPolicy(Box::new(
+            CreateNetworkPolicyPlan {
+                if_not_exists: *if_not_exists,
+                tenant: self.ctx.get_tenant(),
+                policy,
+            },
+        )))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_network_policy(
+        &mut self,
+        stmt: &DropNetworkPolicyStmt,
+    ) -> Result<Plan> {
+        let DropNetworkPolicyStmt { if_exists, name } = stmt;
+
+        Ok(Plan::DropNetworkPolicy(Box::new(DropNetworkPolicyPlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            name: normalize_identifier(name, &self.name_resolution_ctx).name,
+        })))
+    }
+}
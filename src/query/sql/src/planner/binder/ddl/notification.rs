@@ -0,0 +1,71 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Utc;
+use common_ast::ast::*;
+use common_exception::Result;
+use common_meta_app::principal::NotificationIntegration;
+use common_meta_app::principal::NotificationTarget;
+
+use crate::binder::Binder;
+use crate::planner::semantic::normalize_identifier;
+use crate::plans::CreateNotificationIntegrationPlan;
+use crate::plans::DropNotificationIntegrationPlan;
+use crate::plans::Plan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_notification_integration(
+        &mut self,
+        stmt: &CreateNotificationIntegrationStmt,
+    ) -> Result<Plan> {
+        let CreateNotificationIntegrationStmt {
+            if_not_exists,
+            name,
+            integration_type,
+            connection,
+            comment,
+        } = stmt;
+
+        let integration = NotificationIntegration {
+            name: normalize_identifier(name, &self.name_resolution_ctx).name,
+            target: NotificationTarget::from_options(&integration_type.name, connection)?,
+            comment: comment.clone().unwrap_or_default(),
+            created_on: Utc::now(),
+        };
+        Ok(Plan::CreateNotificationIntegration(Box::new(
+            CreateNotificationIntegrationPlan {
+                if_not_exists: *if_not_exists,
+                tenant: self.ctx.get_tenant(),
+                integration,
+            },
+        )))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_notification_integration(
+        &mut self,
+        stmt: &DropNotificationIntegrationStmt,
+    ) -> Result<Plan> {
+        let DropNotificationIntegrationStmt { if_exists, name } = stmt;
+
+        Ok(Plan::DropNotificationIntegration(Box::new(
+            DropNotificationIntegrationPlan {
+                if_exists: *if_exists,
+                tenant: self.ctx.get_tenant(),
+                name: normalize_identifier(name, &self.name_resolution_ctx).name,
+            },
+        )))
+    }
+}
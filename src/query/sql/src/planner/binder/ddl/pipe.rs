@@ -0,0 +1,193 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::*;
+use common_catalog::catalog::CATALOG_DEFAULT;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::FileFormatParams;
+use common_meta_app::principal::PipeInfo;
+use common_meta_app::principal::PipeSource;
+use common_meta_app::principal::StageType;
+use common_meta_app::storage::StorageParams;
+use regex::Regex;
+
+use crate::binder::copy::parse_stage_location_v2;
+use crate::binder::Binder;
+use crate::planner::semantic::normalize_identifier;
+use crate::plans::CreatePipePlan;
+use crate::plans::DropPipePlan;
+use crate::plans::Plan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_pipe(
+        &mut self,
+        stmt: &CreatePipeStmt,
+    ) -> Result<Plan> {
+        let CreatePipeStmt {
+            if_not_exists,
+            name,
+            auto_ingest,
+            aws_sqs_queue_url,
+            comment,
+            catalog,
+            database,
+            table,
+            source,
+            file_format,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        if catalog != CATALOG_DEFAULT {
+            return Err(ErrorCode::IllegalPipeFormat(format!(
+                "Pipe only loads tables of the {} catalog, but got {}",
+                CATALOG_DEFAULT, catalog
+            )));
+        }
+        // the batches are deduplicated by the snapshots of the table.
+        let to_table = self.ctx.get_table(&catalog, &database, &table).await?;
+        if to_table.engine() != "FUSE" {
+            return Err(ErrorCode::IllegalPipeFormat(format!(
+                "Pipe only loads tables of FUSE engine, but got {}",
+                to_table.engine()
+            )));
+        }
+
+        let (source, file_format) = match source {
+            PipeSourceLocation::Kafka(options) => {
+                if *auto_ingest || aws_sqs_queue_url.is_some() {
+                    return Err(ErrorCode::IllegalPipeFormat(
+                        "AUTO_INGEST and AWS_SQS_QUEUE_URL only apply to pipes from a stage",
+                    ));
+                }
+                let file_format = self.try_resolve_file_format(file_format).await?;
+                if !matches!(
+                    file_format,
+                    FileFormatParams::NdJson(_) | FileFormatParams::Avro(_)
+                ) {
+                    return Err(ErrorCode::IllegalPipeFormat(format!(
+                        "Pipe only loads messages of NDJSON or AVRO format, but got {:?}",
+                        file_format.get_type()
+                    )));
+                }
+                (PipeSource::from_kafka_options(options)?, file_format)
+            }
+            PipeSourceLocation::MySql(options) => {
+                if *auto_ingest || aws_sqs_queue_url.is_some() {
+                    return Err(ErrorCode::IllegalPipeFormat(
+                        "AUTO_INGEST and AWS_SQS_QUEUE_URL only apply to pipes from a stage",
+                    ));
+                }
+                // the row events are merged by the primary key, not loaded by a format.
+                let source = PipeSource::from_mysql_options(options)?;
+                if let PipeSource::MySql { primary_key, .. } = &source {
+                    let schema = to_table.schema();
+                    for column in primary_key {
+                        if !schema
+                            .fields()
+                            .iter()
+                            .any(|f| f.name().eq_ignore_ascii_case(column))
+                        {
+                            return Err(ErrorCode::IllegalPipeFormat(format!(
+                                "Primary key column {} not found in table {}.{}",
+                                column, database, table
+                            )));
+                        }
+                    }
+                }
+                (source, FileFormatParams::default())
+            }
+            PipeSourceLocation::Stage { location, pattern } => {
+                if !*auto_ingest {
+                    return Err(ErrorCode::IllegalPipeFormat(
+                        "Pipe from a stage must be AUTO_INGEST = TRUE",
+                    ));
+                }
+                // the keys of the object-created events are matched against the bucket.
+                let (stage_info, _) =
+                    parse_stage_location_v2(&self.ctx, &location.name, &location.path).await?;
+                if stage_info.stage_type != StageType::External
+                    || !matches!(stage_info.stage_params.storage, StorageParams::S3(_))
+                {
+                    return Err(ErrorCode::IllegalPipeFormat(format!(
+                        "Pipe only ingests from external stages on S3, but got stage {}",
+                        location.name
+                    )));
+                }
+                let pattern = pattern.clone().unwrap_or_default();
+                if let Err(e) = Regex::new(&pattern) {
+                    return Err(ErrorCode::IllegalPipeFormat(format!(
+                        "Invalid pattern {}, {}",
+                        pattern, e
+                    )));
+                }
+                let aws_sqs_queue_url = aws_sqs_queue_url.clone().unwrap_or_default();
+                if !aws_sqs_queue_url.is_empty()
+                    && !aws_sqs_queue_url.starts_with("http://")
+                    && !aws_sqs_queue_url.starts_with("https://")
+                {
+                    return Err(ErrorCode::IllegalPipeFormat(format!(
+                        "Invalid AWS_SQS_QUEUE_URL {}, expect http:// or https://",
+                        aws_sqs_queue_url
+                    )));
+                }
+
+                let file_format_params = match file_format.is_empty() {
+                    true => stage_info.file_format_params.clone(),
+                    false => self.try_resolve_file_format(file_format).await?,
+                };
+                let source = PipeSource::Stage {
+                    stage_name: location.name.clone(),
+                    path: location.path.clone(),
+                    pattern,
+                    file_format_options: file_format.clone(),
+                    aws_sqs_queue_url,
+                };
+                (source, file_format_params)
+            }
+        };
+
+        // the messages are loaded as the current user.
+        let pipe = PipeInfo::new(
+            &normalize_identifier(name, &self.name_resolution_ctx).name,
+            self.ctx.get_current_user()?.identity(),
+            database,
+            table,
+            source,
+            file_format,
+            comment.clone().unwrap_or_default(),
+        );
+        Ok(Plan::CreatePipe(Box::new(CreatePipePlan {
+            if_not_exists: *if_not_exists,
+            tenant: self.ctx.get_tenant(),
+            pipe,
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_pipe(
+        &mut self,
+        stmt: &DropPipeStmt,
+    ) -> Result<Plan> {
+        let DropPipeStmt { if_exists, name } = stmt;
+
+        Ok(Plan::DropPipe(Box::new(DropPipePlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            name: normalize_identifier(name, &self.name_resolution_ctx).name,
+        })))
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use chrono::Utc;
+use common_ast::ast::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_meta_app::principal::RowAccessPolicyMeta;
+
+use crate::binder::Binder;
+use crate::planner::semantic::normalize_identifier;
+use crate::planner::semantic::resolve_type_name;
+use crate::plans::BoundColumnRef;
+use crate::plans::CreateRowAccessPolicyPlan;
+use crate::plans::DropRowAccessPolicyPlan;
+use crate::plans::Plan;
+use crate::ColumnBinding;
+use crate::ScalarExpr;
+use crate::Visibility;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_row_access_policy(
+        &mut self,
+        stmt: &CreateRowAccessPolicyStmt,
+    ) -> Result<Plan> {
+        let CreateRowAccessPolicyStmt {
+            if_not_exists,
+            name,
+            args,
+            body,
+            exempt_roles,
+            comment,
+        } = stmt;
+
+        let mut policy_args = Vec::with_capacity(args.len());
+        let mut arg_scalars = Vec::with_capacity(args.len());
+        for (i, arg) in args.iter().enumerate() {
+            let arg_name = normalize_identifier(&arg.name, &self.name_resolution_ctx).name;
+            if policy_args
+                .iter()
+                .any(|(name, _): &(String, String)| name == &arg_name)
+            {
+                return Err(ErrorCode::SemanticError(format!(
+                    "duplicated argument {arg_name} in row access policy"
+                )));
+            }
+            let data_type: DataType = (&resolve_type_name(&arg.data_type)?).into();
+            let column = ColumnBinding {
+                database_name: None,
+                table_name: None,
+                column_position: None,
+                table_index: None,
+                column_name: arg_name.clone(),
+                index: i,
+                data_type: Box::new(data_type),
+                visibility: Visibility::Visible,
+                virtual_computed_expr: None,
+            };
+            arg_scalars.push((
+                arg_name.clone(),
+                ScalarExpr::BoundColumnRef(BoundColumnRef { span: None, column }),
+            ));
+            policy_args.push((arg_name, arg.data_type.to_string()));
+        }
+
+        // Check the body against the arguments, it's bound again to the
+        // columns of a table when the table is scanned.
+        self.bind_row_access_policy_body(body, &arg_scalars).await?;
+
+        let meta = RowAccessPolicyMeta {
+            name: normalize_identifier(name, &self.name_resolution_ctx).name,
+            args: policy_args,
+            body: body.to_string(),
+            exempt_roles: exempt_roles.clone(),
+            comment: comment.clone().unwrap_or_default(),
+            created_on: Utc::now(),
+        };
+        Ok(Plan::CreateRowAccessPolicy(Box::new(
+            CreateRowAccessPolicyPlan {
+                if_not_exists: *if_not_exists,
+                tenant: self.ctx.get_tenant(),
+                meta,
+            },
+        )))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_row_access_policy(
+        &mut self,
+        stmt: &DropRowAccessPolicyStmt,
+    ) -> Result<Plan> {
+        let DropRowAccessPolicyStmt { if_exists, name } = stmt;
+
+        Ok(Plan::DropRowAccessPolicy(Box::new(
+            DropRowAccessPolicyPlan {
+                if_exists: *if_exists,
+                tenant: self.ctx.get_tenant(),
+                name: normalize_identifier(name, &self.name_resolution_ctx).name,
+            },
+        )))
+    }
+}
@@ -19,9 +19,12 @@ use std::sync::Arc;
 use common_ast::ast::AlterTableAction;
 use common_ast::ast::AlterTableStmt;
 use common_ast::ast::AnalyzeTableStmt;
+use common_ast::ast::AttachTableStmt;
+use common_ast::ast::ClusterType;
 use common_ast::ast::ColumnDefinition;
 use common_ast::ast::ColumnExpr;
 use common_ast::ast::CompactTarget;
+use common_ast::ast::CreateExternalTableStmt;
 use common_ast::ast::CreateTableSource;
 use common_ast::ast::CreateTableStmt;
 use common_ast::ast::DescribeTableStmt;
@@ -35,11 +38,13 @@ use common_ast::ast::OptimizeTableAction as AstOptimizeTableAction;
 use common_ast::ast::OptimizeTableStmt;
 use common_ast::ast::RenameTableStmt;
 use common_ast::ast::ShowCreateTableStmt;
+use common_ast::ast::ShowDropTablesStmt;
 use common_ast::ast::ShowLimit;
 use common_ast::ast::ShowTablesStatusStmt;
 use common_ast::ast::ShowTablesStmt;
 use common_ast::ast::Statement;
 use common_ast::ast::TableReference;
+use common_ast::ast::TimeTravelPoint;
 use common_ast::ast::TruncateTableStmt;
 use common_ast::ast::UndropTableStmt;
 use common_ast::ast::UriLocation;
@@ -57,17 +62,25 @@ use common_expression::types::DataType;
 use common_expression::ComputedExpr;
 use common_expression::DataField;
 use common_expression::DataSchemaRefExt;
+use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchemaRef;
 use common_expression::TableSchemaRefExt;
 use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::principal::SequenceMeta;
 use common_meta_app::storage::StorageParams;
 use common_storage::DataOperator;
 use common_storages_view::view_table::QUERY;
 use common_storages_view::view_table::VIEW_ENGINE;
 use storages_common_table_meta::table::is_reserved_opt_key;
+use storages_common_table_meta::table::split_table_data_uri;
+use storages_common_table_meta::table::OPT_KEY_CLONE_SOURCE_TABLE_ID;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
+use storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
 use tracing::debug;
 
@@ -84,6 +97,7 @@ use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::resolve_type_name;
 use crate::planner::semantic::IdentifierNormalizer;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTableRowAccessPolicyPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AnalyzeTablePlan;
 use crate::plans::CreateTablePlan;
@@ -91,21 +105,25 @@ use crate::plans::DescribeTablePlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTableRowAccessPolicyPlan;
 use crate::plans::ExistsTablePlan;
 use crate::plans::ModifyTableColumnPlan;
 use crate::plans::OptimizeTableAction;
 use crate::plans::OptimizeTablePlan;
 use crate::plans::Plan;
 use crate::plans::ReclusterTablePlan;
+use crate::plans::RenameTableColumnPlan;
 use crate::plans::RenameTablePlan;
 use crate::plans::RevertTablePlan;
 use crate::plans::RewriteKind;
+use crate::plans::ScalarExpr;
 use crate::plans::SetOptionsPlan;
 use crate::plans::ShowCreateTablePlan;
 use crate::plans::TruncateTablePlan;
 use crate::plans::UndropTablePlan;
 use crate::plans::VacuumTableOption;
 use crate::plans::VacuumTablePlan;
+use crate::sequence_default_expr;
 use crate::BindContext;
 use crate::ColumnBinding;
 use crate::Planner;
@@ -186,6 +204,60 @@ impl Binder {
             .await
     }
 
+    /// The dropped tables can be restored by `UNDROP TABLE` until their data is removed
+    /// after the retention period.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_show_drop_tables(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &ShowDropTablesStmt,
+    ) -> Result<Plan> {
+        let ShowDropTablesStmt {
+            catalog,
+            database,
+            limit,
+        } = stmt;
+
+        let database = self.check_database_exist(catalog, database).await?;
+
+        let mut select_builder = SelectBuilder::from("system.tables_with_history");
+        select_builder
+            .with_column(format!("name AS `Tables_in_{database}`"))
+            .with_column("dropped_on AS drop_time")
+            .with_column("engine")
+            .with_column("num_rows")
+            .with_column("data_size");
+
+        select_builder
+            .with_order_by("catalog")
+            .with_order_by("database")
+            .with_order_by("name")
+            .with_order_by("dropped_on");
+
+        select_builder.with_filter(format!("database = '{database}'"));
+        select_builder.with_filter("dropped_on <> 'NULL'");
+
+        if let Some(catalog) = catalog {
+            let catalog = normalize_identifier(catalog, &self.name_resolution_ctx).name;
+            select_builder.with_filter(format!("catalog = '{catalog}'"));
+        }
+
+        let query = match limit {
+            None => select_builder.build(),
+            Some(ShowLimit::Like { pattern }) => {
+                select_builder.with_filter(format!("name LIKE '{pattern}'"));
+                select_builder.build()
+            }
+            Some(ShowLimit::Where { selection }) => {
+                select_builder.with_filter(format!("({selection})"));
+                select_builder.build()
+            }
+        };
+        debug!("show drop tables rewrite to: {:?}", query);
+        self.bind_rewrite_to_query(bind_context, query.as_str(), RewriteKind::ShowDropTables)
+            .await
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_show_create_table(
         &mut self,
@@ -321,6 +393,7 @@ impl Binder {
             table,
             source,
             table_options,
+            cluster_type,
             cluster_by,
             as_query,
             transient,
@@ -373,11 +446,54 @@ impl Binder {
             options.insert("TRANSIENT".to_owned(), "T".to_owned());
         }
 
+        // `CREATE TABLE ... USING TEMPLATE (...)`, the schema is built by the interpreter
+        // from the result of the template query.
+        let template = match source {
+            Some(CreateTableSource::Template(query)) => {
+                if as_query.is_some() || !cluster_by.is_empty() {
+                    return Err(ErrorCode::SyntaxException(
+                        "USING TEMPLATE can not be used with AS SELECT or CLUSTER BY",
+                    ));
+                }
+                let mut bind_context = BindContext::new();
+                let stmt = Statement::Query(query.clone());
+                let template_plan = self.bind_statement(&mut bind_context, &stmt).await?;
+                let opt_ctx = Arc::new(OptimizerContext::new(OptimizerConfig::default()));
+                let optimized_plan = optimize(self.ctx.clone(), opt_ctx, template_plan)?;
+                Some(Box::new(optimized_plan))
+            }
+            _ => None,
+        };
+
         // Build table schema
         let (schema, field_comments) = match (&source, &as_query) {
+            (
+                Some(CreateTableSource::Clone {
+                    catalog: source_catalog,
+                    database: source_database,
+                    table: source_table,
+                    travel_point,
+                }),
+                None,
+            ) => {
+                if engine != Engine::Fuse || storage_params.is_some() {
+                    return Err(ErrorCode::SyntaxException(
+                        "CLONE can only create a FUSE table at the default storage location",
+                    ));
+                }
+                self.bind_clone_source(
+                    &mut options,
+                    source_catalog,
+                    source_database,
+                    source_table,
+                    travel_point,
+                )
+                .await?
+            }
             (Some(source), None) => {
                 // `CREATE TABLE` without `AS SELECT ...`
-                self.analyze_create_table_schema(source).await?
+                self.analyze_create_table_schema(&database, &table, source)
+                    .await?
             }
             (None, Some(query)) => {
                 // `CREATE TABLE AS SELECT ...` without column definitions
@@ -399,8 +515,9 @@ impl Binder {
             }
             (Some(source), Some(query)) => {
                 // e.g. `CREATE TABLE t (i INT) AS SELECT * from old_t` with columns specified
-                let (source_schema, source_comments) =
-                    self.analyze_create_table_schema(source).await?;
+                let (source_schema, source_comments) = self
+                    .analyze_create_table_schema(&database, &table, source)
+                    .await?;
                 let mut init_bind_context = BindContext::new();
                 let (_, bind_context) = self.bind_query(&mut init_bind_context, query).await?;
                 let query_fields: Vec<TableField> = bind_context
@@ -440,59 +557,41 @@ impl Binder {
             let db_id = db.get_db_info().ident.db_id;
             options.insert(OPT_KEY_DATABASE_ID.to_owned(), db_id.to_string());
 
-            let config = GlobalConfig::instance();
-            let is_blocking_fs = matches!(
-                storage_params.as_ref().unwrap_or(&config.storage.params),
-                StorageParams::Fs(_)
-            );
-
-            // we should persist the storage format and compression type instead of using the default value in fuse table
-            if !options.contains_key(OPT_KEY_STORAGE_FORMAT) {
-                let default_storage_format = match config.query.default_storage_format.as_str() {
-                    "" | "auto" => {
-                        if is_blocking_fs {
-                            "native"
-                        } else {
-                            "parquet"
-                        }
-                    }
-                    _ => config.query.default_storage_format.as_str(),
-                };
-                options.insert(
-                    OPT_KEY_STORAGE_FORMAT.to_owned(),
-                    default_storage_format.to_owned(),
-                );
-            }
-
-            if !options.contains_key(OPT_KEY_TABLE_COMPRESSION) {
-                let default_compression = match config.query.default_compression.as_str() {
-                    "" | "auto" => {
-                        if is_blocking_fs {
-                            "lz4"
-                        } else {
-                            "zstd"
-                        }
-                    }
-                    _ => config.query.default_compression.as_str(),
-                };
-                options.insert(
-                    OPT_KEY_TABLE_COMPRESSION.to_owned(),
-                    default_compression.to_owned(),
-                );
-            }
+            insert_default_fuse_options(&mut options, storage_params.as_ref());
         }
 
         let cluster_key = {
             let keys = self
-                .analyze_cluster_keys(cluster_by, schema.clone())
+                .analyze_cluster_keys(*cluster_type, cluster_by, schema.clone())
                 .await?;
             if keys.is_empty() {
                 None
             } else {
-                Some(format!("({})", keys.join(", ")))
+                Some(format!("{}({})", cluster_type, keys.join(", ")))
             }
         };
 
+        // The identity columns take their values from the sequences created with the table.
+        let mut identity_sequences = vec![];
+        if let Some(CreateTableSource::Columns(columns)) = source {
+            for column in columns {
+                if let Some(ColumnExpr::Identity { start, increment }) = &column.expr {
+                    if *increment == 0 {
+                        return Err(ErrorCode::SemanticError(
+                            "INCREMENT of an identity column must be greater than 0".to_string(),
+                        ));
+                    }
+                    let name = normalize_identifier(&column.name, &self.name_resolution_ctx).name;
+                    identity_sequences.push(SequenceMeta::new(
+                        &identity_sequence_name(&database, &table, &name),
+                        *start,
+                        *increment,
+                        format!("identity of column {database}.{table}.{name}"),
+                    ));
+                }
+            }
+        }
+
         let plan = CreateTablePlan {
             if_not_exists: *if_not_exists,
             tenant: self.ctx.get_tenant(),
@@ -517,10 +616,180 @@ impl Binder {
             } else {
                 None
             },
+            template,
+            identity_sequences,
         };
         Ok(Plan::CreateTable(Box::new(plan)))
     }
 
+    /// `ATTACH TABLE` is bound to a fuse table created with the data uri, its schema is
+    /// read from the latest snapshot of the attached table when the table is created.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_attach_table(
+        &mut self,
+        stmt: &AttachTableStmt,
+    ) -> Result<Plan> {
+        let AttachTableStmt {
+            catalog,
+            database,
+            table,
+            uri_location,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+
+        // The locations kept in the snapshots start with `<database_id>/<table_id>`, the
+        // storage root is the directory above them.
+        let data_uri = format!(
+            "{}://{}{}",
+            uri_location.protocol, uri_location.name, uri_location.path
+        );
+        let (root, _) = split_table_data_uri(&uri_location.path).ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "invalid data uri {data_uri} of ATTACH TABLE, expect the location of a fuse table: '<protocol>://<bucket>/<path>/<database_id>/<table_id>/'"
+            ))
+        })?;
+        let mut uri = UriLocation {
+            protocol: uri_location.protocol.clone(),
+            name: uri_location.name.clone(),
+            path: root.to_string(),
+            part_prefix: "".to_string(),
+            connection: uri_location.connection.clone(),
+        };
+        let (storage_params, _) = parse_uri_location(&mut uri)?;
+
+        // create a temporary op to check if params is correct
+        DataOperator::try_create(&storage_params).await?;
+
+        let mut options = BTreeMap::new();
+        options.insert(OPT_KEY_TABLE_ATTACHED_DATA_URI.to_owned(), data_uri);
+        insert_default_fuse_options(&mut options, Some(&storage_params));
+
+        Ok(Plan::CreateTable(Box::new(CreateTablePlan {
+            if_not_exists: false,
+            tenant: self.ctx.get_tenant(),
+            catalog,
+            database,
+            table,
+            schema: TableSchemaRefExt::create(vec![]),
+            engine: Engine::Fuse,
+            storage_params: Some(storage_params),
+            part_prefix: "".to_string(),
+            options,
+            field_comments: vec![],
+            cluster_key: None,
+            as_select: None,
+            template: None,
+            identity_sequences: vec![],
+        })))
+    }
+
+    /// `CREATE EXTERNAL TABLE` is bound to a table of the `EXTERNAL` engine, reading the
+    /// parquet files under the location. The partition columns are appended after the
+    /// columns of the files, their values are taken from the `<column>=<value>` directories.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_external_table(
+        &mut self,
+        stmt: &CreateExternalTableStmt,
+    ) -> Result<Plan> {
+        let CreateExternalTableStmt {
+            if_not_exists,
+            catalog,
+            database,
+            table,
+            columns,
+            uri_location,
+            partitioned_by,
+            table_options,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+
+        let mut options: BTreeMap<String, String> = BTreeMap::new();
+        for table_option in table_options.iter() {
+            self.insert_table_option_with_validation(
+                &mut options,
+                table_option.0.to_lowercase(),
+                table_option.1.to_string(),
+            )?;
+        }
+
+        if !uri_location.part_prefix.is_empty() {
+            return Err(ErrorCode::SyntaxException(
+                "LOCATION_PREFIX can not be used with CREATE EXTERNAL TABLE",
+            ));
+        }
+        // The location is always a directory, the partitions and files are listed under it.
+        let mut uri = UriLocation {
+            protocol: uri_location.protocol.clone(),
+            name: uri_location.name.clone(),
+            path: if uri_location.path.ends_with('/') {
+                uri_location.path.clone()
+            } else {
+                format!("{}/", uri_location.path)
+            },
+            part_prefix: "".to_string(),
+            connection: uri_location.connection.clone(),
+        };
+        let (storage_params, _) = parse_uri_location(&mut uri)?;
+
+        // create a temporary op to check if params is correct
+        DataOperator::try_create(&storage_params).await?;
+
+        let all_columns = columns
+            .iter()
+            .chain(partitioned_by.iter())
+            .cloned()
+            .collect::<Vec<_>>();
+        let (schema, field_comments) = self
+            .analyze_create_table_schema_by_columns(&database, &table, &all_columns)
+            .await?;
+
+        let mut partition_columns = Vec::with_capacity(partitioned_by.len());
+        for column in partitioned_by {
+            let name = normalize_identifier(&column.name, &self.name_resolution_ctx).name;
+            let field = schema.field_with_name(&name)?;
+            if column.expr.is_some() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "partition column {name} can not have a default or computed expression"
+                )));
+            }
+            if !matches!(
+                field.data_type().remove_nullable(),
+                TableDataType::String | TableDataType::Number(_)
+            ) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "partition column {name} must be of a string or number type, but got {}",
+                    field.data_type()
+                )));
+            }
+            partition_columns.push(name);
+        }
+        if !partition_columns.is_empty() {
+            options.insert(OPT_KEY_PARTITION_BY.to_owned(), partition_columns.join(","));
+        }
+
+        Ok(Plan::CreateTable(Box::new(CreateTablePlan {
+            if_not_exists: *if_not_exists,
+            tenant: self.ctx.get_tenant(),
+            catalog,
+            database,
+            table,
+            schema,
+            engine: Engine::External,
+            storage_params: Some(storage_params),
+            part_prefix: "".to_string(),
+            options,
+            field_comments,
+            cluster_key: None,
+            as_select: None,
+            template: None,
+            identity_sequences: vec![],
+        })))
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_drop_table(
         &mut self,
@@ -611,6 +880,16 @@ impl Binder {
                     table,
                 })))
             }
+            AlterTableAction::RenameColumn {
+                old_column,
+                new_column,
+            } => Ok(Plan::RenameTableColumn(Box::new(RenameTableColumnPlan {
+                catalog,
+                database,
+                table,
+                old_column: normalize_identifier(old_column, &self.name_resolution_ctx).name,
+                new_column: normalize_identifier(new_column, &self.name_resolution_ctx).name,
+            }))),
             AlterTableAction::AddColumn { column } => {
                 let schema = self
                     .ctx
@@ -644,13 +923,18 @@ impl Binder {
                     column: column.to_string(),
                 })))
             }
-            AlterTableAction::AlterTableClusterKey { cluster_by } => {
+            AlterTableAction::AlterTableClusterKey {
+                cluster_type,
+                cluster_by,
+            } => {
                 let schema = self
                     .ctx
                     .get_table(&catalog, &database, &table)
                     .await?
                     .schema();
-                let cluster_keys = self.analyze_cluster_keys(cluster_by, schema).await?;
+                let cluster_keys = self
+                    .analyze_cluster_keys(*cluster_type, cluster_by, schema)
+                    .await?;
 
                 Ok(Plan::AlterTableClusterKey(Box::new(
                     AlterTableClusterKeyPlan {
@@ -658,6 +942,7 @@ impl Binder {
                         catalog,
                         database,
                         table,
+                        cluster_type: *cluster_type,
                         cluster_keys,
                     },
                 )))
@@ -674,31 +959,17 @@ impl Binder {
                 is_final,
                 selection,
             } => {
-                let (_, mut context) = self
-                    .bind_table_reference(bind_context, table_reference)
+                let push_downs = self
+                    .bind_recluster_selection(bind_context, table_reference, selection)
                     .await?;
 
-                let mut scalar_binder = ScalarBinder::new(
-                    &mut context,
-                    self.ctx.clone(),
-                    &self.name_resolution_ctx,
-                    self.metadata.clone(),
-                    &[],
-                );
-
-                let push_downs = if let Some(expr) = selection {
-                    let (scalar, _) = scalar_binder.bind(expr).await?;
-                    Some(scalar)
-                } else {
-                    None
-                };
-
                 Ok(Plan::ReclusterTable(Box::new(ReclusterTablePlan {
                     tenant,
                     catalog,
                     database,
                     table,
                     is_final: *is_final,
+                    limit: None,
                     metadata: self.metadata.clone(),
                     push_downs,
                 })))
@@ -721,6 +992,44 @@ impl Binder {
                     table,
                 })))
             }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                let schema = self
+                    .ctx
+                    .get_table(&catalog, &database, &table)
+                    .await?
+                    .schema();
+                let mut column_names = Vec::with_capacity(columns.len());
+                for column in columns {
+                    let column = normalize_identifier(column, &self.name_resolution_ctx).name;
+                    schema.field_with_name(&column)?;
+                    // The bound columns are stored comma separated in the table options.
+                    if column.contains(',') {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "column `{column}` can not be bound to a row access policy"
+                        )));
+                    }
+                    column_names.push(column);
+                }
+                Ok(Plan::AddTableRowAccessPolicy(Box::new(
+                    AddTableRowAccessPolicyPlan {
+                        tenant,
+                        catalog,
+                        database,
+                        table,
+                        policy: normalize_identifier(policy, &self.name_resolution_ctx).name,
+                        columns: column_names,
+                    },
+                )))
+            }
+            AlterTableAction::DropRowAccessPolicy { policy } => Ok(Plan::DropTableRowAccessPolicy(
+                Box::new(DropTableRowAccessPolicyPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    policy: normalize_identifier(policy, &self.name_resolution_ctx).name,
+                }),
+            )),
         }
     }
 
@@ -799,6 +1108,48 @@ impl Binder {
             action: ast_action,
         } = stmt;
 
+        if let AstOptimizeTableAction::Recluster { selection, limit } = ast_action {
+            // `OPTIMIZE TABLE ... RECLUSTER` runs the recluster iteratively like
+            // `ALTER TABLE ... RECLUSTER FINAL`, optionally bounded by the iterations.
+            let table_reference = TableReference::Table {
+                span: None,
+                catalog: catalog.clone(),
+                database: database.clone(),
+                table: table.clone(),
+                alias: None,
+                travel_point: None,
+                pivot: None,
+                unpivot: None,
+                sample: None,
+            };
+            let push_downs = self
+                .bind_recluster_selection(bind_context, &table_reference, selection)
+                .await?;
+            let limit = match limit {
+                Some(Expr::Literal {
+                    lit: Literal::UInt64(uint),
+                    ..
+                }) => Some(*uint as usize),
+                Some(_) => {
+                    return Err(ErrorCode::IllegalDataType("Unsupported limit type"));
+                }
+                _ => None,
+            };
+
+            let (catalog, database, table) =
+                self.normalize_object_identifier_triple(catalog, database, table);
+            return Ok(Plan::ReclusterTable(Box::new(ReclusterTablePlan {
+                tenant: self.ctx.get_tenant(),
+                catalog,
+                database,
+                table,
+                is_final: true,
+                limit,
+                metadata: self.metadata.clone(),
+                push_downs,
+            })));
+        }
+
         let (catalog, database, table) =
             self.normalize_object_identifier_triple(catalog, database, table);
         let action = match ast_action {
@@ -828,6 +1179,7 @@ impl Binder {
                     CompactTarget::Segment => OptimizeTableAction::CompactSegments(limit_cnt),
                 }
             }
+            AstOptimizeTableAction::Recluster { .. } => unreachable!(),
         };
 
         Ok(Plan::OptimizeTable(Box::new(OptimizeTablePlan {
@@ -838,6 +1190,33 @@ impl Binder {
         })))
     }
 
+    #[async_backtrace::framed]
+    async fn bind_recluster_selection(
+        &mut self,
+        bind_context: &mut BindContext,
+        table_reference: &TableReference,
+        selection: &Option<Expr>,
+    ) -> Result<Option<ScalarExpr>> {
+        let (_, mut context) = self
+            .bind_table_reference(bind_context, table_reference)
+            .await?;
+
+        let mut scalar_binder = ScalarBinder::new(
+            &mut context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+
+        if let Some(expr) = selection {
+            let (scalar, _) = scalar_binder.bind(expr).await?;
+            Ok(Some(scalar))
+        } else {
+            Ok(None)
+        }
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_vacuum_table(
         &mut self,
@@ -952,6 +1331,12 @@ impl Binder {
                         "can't add a stored computed column".to_string(),
                     ));
                 }
+                ColumnExpr::Identity { .. } => {
+                    // The existing rows have no values from the sequence.
+                    return Err(ErrorCode::SemanticError(
+                        "can't add an identity column".to_string(),
+                    ));
+                }
             }
         }
         let comment = column.comment.clone().unwrap_or_default();
@@ -961,6 +1346,8 @@ impl Binder {
     #[async_backtrace::framed]
     async fn analyze_create_table_schema_by_columns(
         &self,
+        database: &str,
+        table: &str,
         columns: &[ColumnDefinition],
     ) -> Result<(TableSchemaRef, Vec<String>)> {
         let mut has_computed = false;
@@ -983,6 +1370,18 @@ impl Binder {
                         )?;
                         field = field.with_default_expr(Some(expr));
                     }
+                    ColumnExpr::Identity { .. } => {
+                        if !DataType::from(&schema_data_type)
+                            .remove_nullable()
+                            .is_integer()
+                        {
+                            return Err(ErrorCode::SemanticError(format!(
+                                "identity column `{name}` must be an integer column"
+                            )));
+                        }
+                        let sequence = identity_sequence_name(database, table, &name);
+                        field = field.with_default_expr(Some(sequence_default_expr(&sequence)));
+                    }
                     _ => has_computed = true,
                 }
             }
@@ -1041,11 +1440,14 @@ impl Binder {
     #[async_backtrace::framed]
     async fn analyze_create_table_schema(
         &self,
+        database: &str,
+        table: &str,
         source: &CreateTableSource,
     ) -> Result<(TableSchemaRef, Vec<String>)> {
         match source {
             CreateTableSource::Columns(columns) => {
-                self.analyze_create_table_schema_by_columns(columns).await
+                self.analyze_create_table_schema_by_columns(database, table, columns)
+                    .await
             }
             CreateTableSource::Like {
                 catalog,
@@ -1070,7 +1472,63 @@ impl Binder {
                     Ok((table.schema(), table.field_comments().clone()))
                 }
             }
+            // Resolved when the table is created, see `CreateTablePlan::template`.
+            CreateTableSource::Template(_) => Ok((TableSchemaRefExt::create(vec![]), vec![])),
+            CreateTableSource::Clone { .. } => Err(ErrorCode::SyntaxException(
+                "CLONE can not be used with AS SELECT",
+            )),
+        }
+    }
+
+    /// `CREATE TABLE ... CLONE <source>`, the new table starts from the snapshot of the
+    /// source table at the travel point, the segments and blocks of the snapshot are
+    /// shared with the source instead of copied.
+    #[async_backtrace::framed]
+    async fn bind_clone_source(
+        &mut self,
+        options: &mut BTreeMap<String, String>,
+        catalog: &Option<Identifier>,
+        database: &Option<Identifier>,
+        table: &Identifier,
+        travel_point: &Option<TimeTravelPoint>,
+    ) -> Result<(TableSchemaRef, Vec<String>)> {
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let source = self.ctx.get_table(&catalog, &database, &table).await?;
+        let source_info = source.get_table_info();
+        if source.engine() != "FUSE"
+            || source_info.meta.storage_params.is_some()
+            || source_info
+                .options()
+                .contains_key(OPT_KEY_TABLE_ATTACHED_DATA_URI)
+        {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "table {database}.{table} can not be cloned, only the FUSE tables at the default storage location can be cloned",
+            )));
+        }
+        let source = match travel_point {
+            Some(travel_point) => {
+                let mut bind_context = BindContext::new();
+                let point = self
+                    .resolve_data_travel_point(&mut bind_context, travel_point)
+                    .await?;
+                source.navigate_to(&point).await?
+            }
+            None => source,
+        };
+
+        let source_options = source.get_table_info().options();
+        if let Some(location) = source_options
+            .get(OPT_KEY_SNAPSHOT_LOCATION)
+            .or_else(|| source_options.get(OPT_KEY_LEGACY_SNAPSHOT_LOC))
+        {
+            options.insert(OPT_KEY_SNAPSHOT_LOCATION.to_string(), location.clone());
         }
+        options.insert(
+            OPT_KEY_CLONE_SOURCE_TABLE_ID.to_string(),
+            source.get_id().to_string(),
+        );
+        Ok((source.schema(), source.field_comments().clone()))
     }
 
     /// Validate the schema of the table to be created.
@@ -1111,6 +1569,7 @@ impl Binder {
     #[async_backtrace::framed]
     async fn analyze_cluster_keys(
         &mut self,
+        cluster_type: ClusterType,
         cluster_by: &[Expr],
         schema: TableSchemaRef,
     ) -> Result<Vec<String>> {
@@ -1158,6 +1617,78 @@ impl Binder {
             cluster_keys.push(format!("{:#}", &cluster_by));
         }
 
+        // The rows are sorted by `hilbert_key(...)` of the keys, check if the types
+        // of the keys are supported.
+        if cluster_type == ClusterType::Hilbert {
+            let hilbert_key = Expr::FunctionCall {
+                span: None,
+                distinct: false,
+                name: Identifier::from_name("hilbert_key"),
+                args: cluster_by.to_vec(),
+                params: vec![],
+                window: None,
+                lambda: None,
+            };
+            scalar_binder.bind(&hilbert_key).await.map_err(|e| {
+                ErrorCode::InvalidClusterKeys(format!(
+                    "Unsupported HILBERT cluster keys: {}",
+                    e.message()
+                ))
+            })?;
+        }
+
         Ok(cluster_keys)
     }
 }
+
+/// The name of the sequence that generates the values of an identity column.
+fn identity_sequence_name(database: &str, table: &str, column: &str) -> String {
+    format!("{database}_{table}_{column}_seq")
+}
+
+/// Persist the storage format and compression type instead of using the default value
+/// in fuse table.
+fn insert_default_fuse_options(
+    options: &mut BTreeMap<String, String>,
+    storage_params: Option<&StorageParams>,
+) {
+    let config = GlobalConfig::instance();
+    let is_blocking_fs = matches!(
+        storage_params.unwrap_or(&config.storage.params),
+        StorageParams::Fs(_)
+    );
+
+    if !options.contains_key(OPT_KEY_STORAGE_FORMAT) {
+        let default_storage_format = match config.query.default_storage_format.as_str() {
+            "" | "auto" => {
+                if is_blocking_fs {
+                    "native"
+                } else {
+                    "parquet"
+                }
+            }
+            _ => config.query.default_storage_format.as_str(),
+        };
+        options.insert(
+            OPT_KEY_STORAGE_FORMAT.to_owned(),
+            default_storage_format.to_owned(),
+        );
+    }
+
+    if !options.contains_key(OPT_KEY_TABLE_COMPRESSION) {
+        let default_compression = match config.query.default_compression.as_str() {
+            "" | "auto" => {
+                if is_blocking_fs {
+                    "lz4"
+                } else {
+                    "zstd"
+                }
+            }
+            _ => config.query.default_compression.as_str(),
+        };
+        options.insert(
+            OPT_KEY_TABLE_COMPRESSION.to_owned(),
+            default_compression.to_owned(),
+        );
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::TaskMeta;
+use common_meta_app::principal::TaskSchedule;
+use common_meta_app::principal::TaskState;
+
+use crate::binder::Binder;
+use crate::planner::semantic::normalize_identifier;
+use crate::plans::AlterTaskPlan;
+use crate::plans::CreateTaskPlan;
+use crate::plans::DropTaskPlan;
+use crate::plans::ExecuteTaskPlan;
+use crate::plans::Plan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_task(
+        &mut self,
+        stmt: &CreateTaskStmt,
+    ) -> Result<Plan> {
+        let CreateTaskStmt {
+            if_not_exists,
+            name,
+            schedule,
+            retry_attempts,
+            comment,
+            sql,
+        } = stmt;
+
+        if matches!(
+            sql.as_ref(),
+            Statement::CreateTask(_)
+                | Statement::DropTask(_)
+                | Statement::AlterTask(_)
+                | Statement::ExecuteTask(_)
+        ) {
+            return Err(ErrorCode::SemanticError(
+                "The statement of a task can't manage the tasks".to_string(),
+            ));
+        }
+
+        // the statement runs as the current user, in the current database.
+        let meta = TaskMeta::new(
+            &normalize_identifier(name, &self.name_resolution_ctx).name,
+            self.ctx.get_current_user()?.identity(),
+            self.ctx.get_current_database(),
+            TaskSchedule::parse(schedule)?,
+            sql.to_string(),
+            retry_attempts.unwrap_or(0),
+            comment.clone().unwrap_or_default(),
+        );
+        Ok(Plan::CreateTask(Box::new(CreateTaskPlan {
+            if_not_exists: *if_not_exists,
+            tenant: self.ctx.get_tenant(),
+            meta,
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_task(
+        &mut self,
+        stmt: &DropTaskStmt,
+    ) -> Result<Plan> {
+        let DropTaskStmt { if_exists, name } = stmt;
+
+        Ok(Plan::DropTask(Box::new(DropTaskPlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            name: normalize_identifier(name, &self.name_resolution_ctx).name,
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_task(
+        &mut self,
+        stmt: &AlterTaskStmt,
+    ) -> Result<Plan> {
+        let AlterTaskStmt { name, action } = stmt;
+
+        let state = match action {
+            AlterTaskAction::Suspend => TaskState::Suspended,
+            AlterTaskAction::Resume => TaskState::Started,
+        };
+        Ok(Plan::AlterTask(Box::new(AlterTaskPlan {
+            tenant: self.ctx.get_tenant(),
+            name: normalize_identifier(name, &self.name_resolution_ctx).name,
+            state,
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_execute_task(
+        &mut self,
+        stmt: &ExecuteTaskStmt,
+    ) -> Result<Plan> {
+        let ExecuteTaskStmt { name } = stmt;
+
+        Ok(Plan::ExecuteTask(Box::new(ExecuteTaskPlan {
+            tenant: self.ctx.get_tenant(),
+            name: normalize_identifier(name, &self.name_resolution_ctx).name,
+        })))
+    }
+}
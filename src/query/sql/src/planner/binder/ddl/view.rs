@@ -13,16 +13,33 @@
 // limitations under the License.
 
 use common_ast::ast::AlterViewStmt;
+use common_ast::ast::ClusterType;
+use common_ast::ast::CreateMaterializedViewStmt;
+use common_ast::ast::CreateTableStmt;
 use common_ast::ast::CreateViewStmt;
+use common_ast::ast::DropMaterializedViewStmt;
+use common_ast::ast::DropTableStmt;
 use common_ast::ast::DropViewStmt;
+use common_ast::ast::Engine;
+use common_ast::ast::Query;
+use common_ast::ast::RefreshMaterializedViewStmt;
+use common_ast::ast::SetExpr;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_REFRESHED_ON;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SNAPSHOT;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCE;
 
 use crate::binder::Binder;
 use crate::planner::semantic::normalize_identifier;
 use crate::plans::AlterViewPlan;
+use crate::plans::CreateMaterializedViewPlan;
 use crate::plans::CreateViewPlan;
 use crate::plans::DropViewPlan;
 use crate::plans::Plan;
+use crate::plans::RefreshMaterializedViewPlan;
+use crate::BindContext;
 
 impl Binder {
     #[async_backtrace::framed]
@@ -117,4 +134,211 @@ impl Binder {
         };
         Ok(Plan::DropView(Box::new(plan)))
     }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_materialized_view(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &CreateMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let CreateMaterializedViewStmt {
+            if_not_exists,
+            catalog,
+            database,
+            view,
+            query,
+        } = stmt;
+
+        Self::check_materialized_view_support(query)?;
+
+        let (catalog_name, database_name, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+
+        bind_context.planning_materialized_view = true;
+        self.bind_query(bind_context, query).await?;
+        bind_context.planning_materialized_view = false;
+
+        let tables = self.metadata.read().tables().to_vec();
+        if tables.len() != 1 {
+            return Err(ErrorCode::SemanticError(
+                "Materialized view currently only support single table",
+            ));
+        }
+
+        let table_entry = &tables[0];
+        let table = table_entry.table();
+        if table.engine() != "FUSE" {
+            return Err(ErrorCode::SemanticError(format!(
+                "Materialized view only support FUSE engine, but got {}",
+                table.engine()
+            )));
+        }
+        if table_entry.catalog() != catalog_name || table_entry.database() != database_name {
+            return Err(ErrorCode::SemanticError(
+                "Materialized view must be created in the same database as its source table",
+            ));
+        }
+
+        let mut query = *query.clone();
+        Self::rewrite_query_with_database(&mut query, table_entry.database());
+
+        // The materialized view is stored as a fuse table, reuse the binding of
+        // `CREATE TABLE ... AS SELECT ...` to build its schema and default options.
+        let create_table_stmt = CreateTableStmt {
+            if_not_exists: *if_not_exists,
+            catalog: catalog.clone(),
+            database: database.clone(),
+            table: view.clone(),
+            source: None,
+            engine: Some(Engine::Fuse),
+            uri_location: None,
+            cluster_type: ClusterType::Linear,
+            cluster_by: vec![],
+            table_options: Default::default(),
+            as_query: Some(Box::new(query.clone())),
+            transient: false,
+        };
+        let create_table_plan = match self.bind_create_table(&create_table_stmt).await? {
+            Plan::CreateTable(plan) => plan,
+            _ => unreachable!(),
+        };
+
+        let mut options = create_table_plan.options;
+        options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_QUERY.to_string(),
+            query.to_string(),
+        );
+        options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_SOURCE.to_string(),
+            table.get_id().to_string(),
+        );
+        options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_SNAPSHOT.to_string(),
+            "".to_string(),
+        );
+        options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_REFRESHED_ON.to_string(),
+            "0".to_string(),
+        );
+
+        let plan = CreateMaterializedViewPlan {
+            if_not_exists: *if_not_exists,
+            tenant: self.ctx.get_tenant(),
+            catalog: catalog_name,
+            database: database_name,
+            view_name,
+            schema: create_table_plan.schema,
+            options,
+        };
+        Ok(Plan::CreateMaterializedView(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_materialized_view(
+        &mut self,
+        stmt: &DropMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let DropMaterializedViewStmt {
+            if_exists,
+            catalog,
+            database,
+            view,
+        } = stmt;
+
+        let (catalog_name, database_name, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+        match self
+            .ctx
+            .get_table(&catalog_name, &database_name, &view_name)
+            .await
+        {
+            Ok(table) => {
+                if !table
+                    .options()
+                    .contains_key(OPT_KEY_MATERIALIZED_VIEW_QUERY)
+                {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "{}.{} is not MATERIALIZED VIEW, please use `DROP TABLE {}.{}`",
+                        database_name, view_name, database_name, view_name
+                    )));
+                }
+            }
+            Err(e) => {
+                if !*if_exists || e.code() != ErrorCode::UNKNOWN_TABLE {
+                    return Err(e);
+                }
+            }
+        }
+
+        // The materialized view is a fuse table, drop it as a table.
+        let drop_table_stmt = DropTableStmt {
+            if_exists: *if_exists,
+            catalog: catalog.clone(),
+            database: database.clone(),
+            table: view.clone(),
+            all: false,
+        };
+        self.bind_drop_table(&drop_table_stmt).await
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_refresh_materialized_view(
+        &mut self,
+        stmt: &RefreshMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let RefreshMaterializedViewStmt {
+            catalog,
+            database,
+            view,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let (catalog, database, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+
+        let table = self.ctx.get_table(&catalog, &database, &view_name).await?;
+        if !table
+            .options()
+            .contains_key(OPT_KEY_MATERIALIZED_VIEW_QUERY)
+        {
+            return Err(ErrorCode::SemanticError(format!(
+                "{}.{} is not MATERIALIZED VIEW",
+                database, view_name
+            )));
+        }
+
+        let plan = RefreshMaterializedViewPlan {
+            tenant,
+            catalog,
+            database,
+            view_name,
+        };
+        Ok(Plan::RefreshMaterializedView(Box::new(plan)))
+    }
+
+    fn check_materialized_view_support(query: &Query) -> Result<()> {
+        let err = Err(ErrorCode::SemanticError(format!(
+            "Currently materialized view just support simple query, like: {}",
+            "SELECT ... FROM ... WHERE ... GROUP BY ..."
+        )));
+
+        if query.with.is_some() || !query.order_by.is_empty() || !query.limit.is_empty() {
+            return err;
+        }
+
+        if let SetExpr::Select(stmt) = &query.body {
+            if stmt.from.len() != 1 || stmt.window_list.is_some() {
+                return err;
+            }
+            for target in &stmt.select_list {
+                if target.has_window() {
+                    return err;
+                }
+            }
+        } else {
+            return err;
+        }
+
+        Ok(())
+    }
 }
@@ -28,9 +28,11 @@ use crate::optimizer::SExpr;
 use crate::optimizer::SubqueryRewriter;
 use crate::plans::DeletePlan;
 use crate::plans::Filter;
+use crate::plans::FunctionCall;
 use crate::plans::Operator;
 use crate::plans::Plan;
 use crate::plans::RelOp;
+use crate::plans::RelOperator;
 use crate::plans::RelOperator::Scan;
 use crate::plans::SubqueryDesc;
 use crate::plans::SubqueryExpr;
@@ -44,7 +46,17 @@ impl<'a> Binder {
         table_expr: SExpr,
         scalar_binder: &mut ScalarBinder<'_>,
     ) -> Result<(Option<ScalarExpr>, Vec<SubqueryDesc>)> {
-        Ok(if let Some(expr) = filter {
+        // The scan is filtered by the row access policy of the table, only
+        // the rows visible to the current role can be modified.
+        let (table_expr, policy_predicate) = match table_expr.plan() {
+            RelOperator::Filter(filter) => (
+                table_expr.child(0)?.clone(),
+                filter.predicates.first().cloned(),
+            ),
+            _ => (table_expr, None),
+        };
+
+        let (selection, subquery_desc) = if let Some(expr) = filter {
             let (scalar, _) = scalar_binder.bind(expr).await?;
             let mut subquery_desc = vec![];
             self.subquery_desc(&scalar, table_expr, &mut subquery_desc)
@@ -52,7 +64,18 @@ impl<'a> Binder {
             (Some(scalar), subquery_desc)
         } else {
             (None, vec![])
-        })
+        };
+
+        let selection = match (selection, policy_predicate) {
+            (Some(selection), Some(predicate)) => Some(ScalarExpr::FunctionCall(FunctionCall {
+                span: None,
+                func_name: "and".to_string(),
+                params: vec![],
+                arguments: vec![predicate, selection],
+            })),
+            (selection, predicate) => selection.or(predicate),
+        };
+        Ok((selection, subquery_desc))
     }
 
     #[async_backtrace::framed]
@@ -187,6 +210,12 @@ impl Binder {
                         .await?;
                 }
             }
+            ScalarExpr::LambdaFunction(scalar) => {
+                for arg in scalar.args.iter() {
+                    self.subquery_desc(arg, table_expr.clone(), subquery_desc)
+                        .await?;
+                }
+            }
             ScalarExpr::CastExpr(scalar) => {
                 self.subquery_desc(scalar.argument.as_ref(), table_expr, subquery_desc)
                     .await?;
@@ -0,0 +1,261 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_ast::ast::ColumnID;
+use common_ast::ast::Expr;
+use common_ast::ast::Identifier;
+use common_ast::ast::Lambda;
+use common_ast::ast::Literal;
+use common_ast::ast::Query;
+use common_ast::ast::SubqueryModifier;
+use common_ast::ast::Window;
+use common_ast::Visitor;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::Span;
+use common_expression::types::DataType;
+use common_users::UserApiProvider;
+
+use crate::binder::ExprContext;
+use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::CastExpr;
+use crate::plans::DictGet;
+use crate::plans::DictGetItem;
+use crate::BindContext;
+use crate::Binder;
+use crate::ColumnBinding;
+use crate::ScalarBinder;
+use crate::ScalarExpr;
+use crate::Visibility;
+
+/// Collects the `dict_get` calls of the select list.
+pub struct DictGetCollector {
+    calls: Vec<Expr>,
+}
+
+impl<'a> Visitor<'a> for DictGetCollector {
+    fn visit_function_call(
+        &mut self,
+        span: Span,
+        distinct: bool,
+        name: &'a Identifier,
+        args: &'a [Expr],
+        params: &'a [Literal],
+        over: &'a Option<Window>,
+        lambda: &'a Option<Lambda>,
+    ) {
+        if name.name.eq_ignore_ascii_case("dict_get") {
+            self.calls.push(Expr::FunctionCall {
+                span,
+                distinct,
+                name: name.clone(),
+                args: args.to_vec(),
+                params: params.to_vec(),
+                window: over.clone(),
+                lambda: lambda.clone(),
+            });
+        } else {
+            for arg in args.iter() {
+                self.visit_expr(arg);
+            }
+        }
+    }
+
+    fn visit_subquery(
+        &mut self,
+        _span: Span,
+        _modifier: &'a Option<SubqueryModifier>,
+        _subquery: &'a Query,
+    ) {
+        // The lookups of a subquery are bound with the subquery.
+    }
+
+    fn visit_query(&mut self, _query: &'a Query) {}
+}
+
+impl DictGetCollector {
+    pub fn new() -> Self {
+        DictGetCollector { calls: vec![] }
+    }
+
+    pub fn visit(&mut self, expr: &Expr) {
+        self.visit_expr(expr);
+    }
+
+    pub fn into_calls(self) -> Vec<Expr> {
+        self.calls
+    }
+}
+
+impl Binder {
+    /// Bind `dict_get(<dictionary>, [<attribute>,] <key>)` calls to a `DictGet` operator
+    /// on top of `s_expr`. The attribute can be omitted if the dictionary has only one.
+    #[async_backtrace::framed]
+    pub async fn bind_dict_get(
+        &mut self,
+        bind_context: &mut BindContext,
+        calls: &[Expr],
+        s_expr: SExpr,
+    ) -> Result<SExpr> {
+        if calls.is_empty() {
+            return Ok(s_expr);
+        }
+
+        let tenant = self.ctx.get_tenant();
+        let mut items: Vec<DictGetItem> = Vec::with_capacity(calls.len());
+        for call in calls {
+            if bind_context.dict_gets.contains_key(&call.to_string()) {
+                continue;
+            }
+            let args = match call {
+                Expr::FunctionCall { args, .. } => args,
+                // Should have been checked by DictGetCollector
+                _ => unreachable!(),
+            };
+            let (dictionary, attribute, key) = match args.as_slice() {
+                [dictionary, key] => (dictionary, None, key),
+                [dictionary, attribute, key] => (dictionary, Some(attribute), key),
+                _ => {
+                    return Err(ErrorCode::SemanticError(
+                        "dict_get expects 2 or 3 arguments: dict_get(<dictionary>, [<attribute>,] <key>)",
+                    )
+                    .set_span(call.span()));
+                }
+            };
+
+            let dictionary_name = match dictionary {
+                Expr::Literal {
+                    lit: Literal::String(name),
+                    ..
+                } => name.clone(),
+                Expr::ColumnRef {
+                    database: None,
+                    table: None,
+                    column: ColumnID::Name(ident),
+                    ..
+                } => ident.name.clone(),
+                _ => {
+                    return Err(ErrorCode::SemanticError(
+                        "the dictionary of dict_get must be a name or a string literal",
+                    )
+                    .set_span(dictionary.span()));
+                }
+            };
+            let meta = UserApiProvider::instance()
+                .get_dictionary(&tenant, &dictionary_name)
+                .await
+                .map_err(|e| e.set_span(dictionary.span()))?;
+
+            let attribute = match attribute {
+                Some(Expr::Literal {
+                    lit: Literal::String(attribute),
+                    ..
+                }) => attribute.clone(),
+                Some(other) => {
+                    return Err(ErrorCode::SemanticError(
+                        "the attribute of dict_get must be a string literal",
+                    )
+                    .set_span(other.span()));
+                }
+                None => {
+                    let attributes = meta.attributes().collect::<Vec<_>>();
+                    if attributes.len() != 1 {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "dictionary {} has {} attributes, the attribute must be specified",
+                            dictionary_name,
+                            attributes.len()
+                        ))
+                        .set_span(call.span()));
+                    }
+                    attributes[0].to_string()
+                }
+            };
+            let field = meta
+                .schema
+                .field_with_name(&attribute)
+                .ok()
+                .filter(|f| f.name() != &meta.primary_key)
+                .ok_or_else(|| {
+                    ErrorCode::SemanticError(format!(
+                        "dictionary {} has no attribute {}",
+                        dictionary_name, attribute
+                    ))
+                    .set_span(call.span())
+                })?;
+            let data_type = DataType::from(field.data_type()).wrap_nullable();
+            let key_type =
+                DataType::from(meta.schema.field_with_name(&meta.primary_key)?.data_type())
+                    .wrap_nullable();
+
+            let original_context = bind_context.expr_context.clone();
+            bind_context.set_expr_context(ExprContext::SelectClause);
+            let mut scalar_binder = ScalarBinder::new(
+                bind_context,
+                self.ctx.clone(),
+                &self.name_resolution_ctx,
+                self.metadata.clone(),
+                &[],
+            );
+            let (key, _) = scalar_binder.bind(key).await?;
+            bind_context.set_expr_context(original_context);
+            let key = ScalarExpr::CastExpr(CastExpr {
+                span: key.span(),
+                is_try: false,
+                argument: Box::new(key),
+                target_type: Box::new(key_type),
+            });
+
+            let name = call.to_string();
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(name.clone(), data_type.clone());
+            let column = ColumnBinding {
+                database_name: None,
+                table_name: None,
+                column_position: None,
+                table_index: None,
+                column_name: name.clone(),
+                index,
+                data_type: Box::new(data_type.clone()),
+                visibility: Visibility::InVisible,
+                virtual_computed_expr: None,
+            };
+            items.push(DictGetItem {
+                key,
+                source: meta.source.clone(),
+                primary_key: meta.primary_key.clone(),
+                attribute,
+                data_type,
+                index,
+            });
+
+            // Add the lookup to bind context, so we can replace the calls later.
+            bind_context.dict_gets.insert(
+                name,
+                ScalarExpr::BoundColumnRef(BoundColumnRef {
+                    span: call.span(),
+                    column,
+                }),
+            );
+        }
+
+        Ok(SExpr::create_unary(
+            Arc::new(DictGet { items }.into()),
+            Arc::new(s_expr),
+        ))
+    }
+}
@@ -14,15 +14,25 @@
 
 use std::sync::Arc;
 
+use common_ast::ast::Expr;
 use common_ast::ast::Identifier;
 use common_ast::ast::InsertSource;
 use common_ast::ast::InsertStmt;
+use common_ast::ast::OnConflict;
+use common_ast::ast::OnConflictAction;
 use common_ast::ast::Statement;
+use common_ast::parser::parse_comma_separated_exprs;
+use common_ast::parser::parse_sql;
+use common_ast::parser::quote::quote_ident;
+use common_ast::parser::tokenize_sql;
+use common_ast::Dialect;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::TableSchema;
 use common_expression::TableSchemaRefExt;
 use common_meta_app::principal::FileFormatOptionsAst;
+use storages_common_table_meta::table::parse_unique_keys;
+use storages_common_table_meta::table::OPT_KEY_UNIQUE_KEYS;
 
 use crate::binder::Binder;
 use crate::normalize_identifier;
@@ -81,8 +91,14 @@ impl Binder {
             columns,
             source,
             overwrite,
+            on_conflict,
             ..
         } = stmt;
+        if let Some(on_conflict) = on_conflict {
+            return self
+                .bind_insert_on_conflict(bind_context, stmt, on_conflict)
+                .await;
+        }
         let (catalog_name, database_name, table_name) =
             self.normalize_object_identifier_triple(catalog, database, table);
         let table = self
@@ -156,4 +172,123 @@ impl Binder {
 
         Ok(Plan::Insert(Box::new(plan)))
     }
+
+    /// `INSERT ... ON CONFLICT` is bound as a `MERGE INTO` the table, using the inserted
+    /// rows as the source named `excluded`, joined with the table on the conflict columns.
+    #[async_backtrace::framed]
+    async fn bind_insert_on_conflict(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &InsertStmt,
+        on_conflict: &OnConflict,
+    ) -> Result<Plan> {
+        let (catalog_name, database_name, table_name) =
+            self.normalize_object_identifier_triple(&stmt.catalog, &stmt.database, &stmt.table);
+        let table = self
+            .ctx
+            .get_table(&catalog_name, &database_name, &table_name)
+            .await?;
+
+        // The conflicts are only looked up by a declared unique key, like PostgreSQL.
+        let conflict_columns = on_conflict
+            .columns
+            .iter()
+            .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+            .collect::<Vec<_>>();
+        let unique_keys = table
+            .options()
+            .get(OPT_KEY_UNIQUE_KEYS)
+            .map(|value| parse_unique_keys(value))
+            .unwrap_or_default();
+        if !unique_keys.iter().any(|key| {
+            key.len() == conflict_columns.len()
+                && key.iter().all(|column| conflict_columns.contains(column))
+        }) {
+            return Err(ErrorCode::SemanticError(format!(
+                "there is no unique key of table {table_name} matching the ON CONFLICT columns ({})",
+                conflict_columns.join(", ")
+            )));
+        }
+
+        let schema = self.schema_project(&table.schema(), &stmt.columns)?;
+        let columns = schema
+            .fields()
+            .iter()
+            .map(|field| quote_ident(field.name(), '"', true))
+            .collect::<Vec<_>>();
+        let source = match &stmt.source {
+            InsertSource::Values { rest_str } => {
+                let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+                let tokens = tokenize_sql(rest_str.trim_end_matches(';'))?;
+                let rows = parse_comma_separated_exprs(&tokens, sql_dialect)?;
+                let mut selects = Vec::with_capacity(rows.len());
+                for row in rows {
+                    let values = match row {
+                        Expr::Tuple { exprs, .. } => exprs,
+                        expr => vec![expr],
+                    };
+                    if values.len() != columns.len() {
+                        return Err(ErrorCode::TableSchemaMismatch(format!(
+                            "Table columns count is not match, expect {}, input: {}",
+                            columns.len(),
+                            values.len()
+                        )));
+                    }
+                    let values = values.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+                    selects.push(format!("SELECT {}", values.join(", ")));
+                }
+                selects.join(" UNION ALL ")
+            }
+            InsertSource::Select { query } => query.to_string(),
+            _ => {
+                return Err(ErrorCode::SemanticError(
+                    "ON CONFLICT only supports VALUES or a query as the source of INSERT",
+                ));
+            }
+        };
+
+        let target = quote_ident(&table_name, '"', true);
+        let join_expr = conflict_columns
+            .iter()
+            .map(|column| {
+                let column = quote_ident(column, '"', true);
+                format!("{target}.{column} = excluded.{column}")
+            })
+            .collect::<Vec<_>>();
+        let mut sql = format!(
+            "MERGE INTO {}.{}.{target} USING ({source}) AS excluded({}) ON {}",
+            quote_ident(&catalog_name, '"', true),
+            quote_ident(&database_name, '"', true),
+            columns.join(", "),
+            join_expr.join(" AND ")
+        );
+        if let OnConflictAction::Update {
+            update_list,
+            selection,
+        } = &on_conflict.action
+        {
+            sql.push_str(" WHEN MATCHED");
+            if let Some(selection) = selection {
+                sql.push_str(&format!(" AND {selection}"));
+            }
+            let update_list = update_list
+                .iter()
+                .map(|update_expr| update_expr.to_string())
+                .collect::<Vec<_>>();
+            sql.push_str(&format!(" THEN UPDATE SET {}", update_list.join(", ")));
+        }
+        let values = columns
+            .iter()
+            .map(|column| format!("excluded.{column}"))
+            .collect::<Vec<_>>();
+        sql.push_str(&format!(
+            " WHEN NOT MATCHED THEN INSERT ({}) VALUES ({})",
+            columns.join(", "),
+            values.join(", ")
+        ));
+
+        let tokens = tokenize_sql(&sql)?;
+        let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL)?;
+        self.bind_statement(bind_context, &stmt).await
+    }
 }
@@ -18,12 +18,18 @@ use async_recursion::async_recursion;
 use common_ast::ast::split_conjunctions_expr;
 use common_ast::ast::split_equivalent_predicate_expr;
 use common_ast::ast::Expr;
+use common_ast::ast::Identifier;
 use common_ast::ast::JoinCondition;
 use common_ast::ast::JoinOperator;
+use common_ast::ast::Literal;
+use common_ast::ast::TableAlias;
+use common_ast::ast::TableReference;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::Span;
+use common_expression::FunctionKind;
+use common_functions::BUILTIN_FUNCTIONS;
 
 use crate::binder::JoinPredicate;
 use crate::binder::Visibility;
@@ -38,6 +44,7 @@ use crate::plans::BoundColumnRef;
 use crate::plans::Filter;
 use crate::plans::Join;
 use crate::plans::JoinType;
+use crate::plans::RelOperator;
 use crate::plans::ScalarExpr;
 use crate::BindContext;
 use crate::MetadataRef;
@@ -167,6 +174,234 @@ impl Binder {
         Ok((s_expr, bind_context))
     }
 
+    /// Bind a join whose right side is marked as `LATERAL`, so it can reference
+    /// the columns of the left side.
+    ///
+    /// A lateral set-returning function is evaluated by a `ProjectSet` over the
+    /// left side directly. A lateral derived table is bound with the left side as
+    /// its outer context, the join is decorrelated by `SubqueryRewriter` later.
+    #[async_recursion]
+    #[async_backtrace::framed]
+    pub(crate) async fn bind_lateral_join(
+        &mut self,
+        bind_context: &mut BindContext,
+        left_context: BindContext,
+        left_child: SExpr,
+        join: &common_ast::ast::Join,
+    ) -> Result<(SExpr, BindContext)> {
+        if !matches!(
+            join.op,
+            JoinOperator::Inner | JoinOperator::CrossJoin | JoinOperator::LeftOuter
+        ) {
+            return Err(ErrorCode::SemanticError(
+                "LATERAL is only supported with INNER, CROSS and LEFT OUTER JOIN".to_string(),
+            )
+            .set_span(join.right.span()));
+        }
+
+        if let TableReference::TableFunction {
+            span,
+            name,
+            params,
+            named_params,
+            alias,
+            ..
+        } = join.right.as_ref()
+        {
+            let func_name = normalize_identifier(name, &self.name_resolution_ctx).name;
+            if BUILTIN_FUNCTIONS
+                .get_property(&func_name)
+                .map(|p| p.kind == FunctionKind::SRF)
+                .unwrap_or(false)
+            {
+                if join.op == JoinOperator::LeftOuter {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "LATERAL {func_name} is only supported with INNER and CROSS JOIN"
+                    ))
+                    .set_span(*span));
+                }
+                let args = self.srf_table_args(*span, &func_name, params, named_params)?;
+                return self
+                    .bind_lateral_srf(
+                        left_context,
+                        left_child,
+                        *span,
+                        func_name,
+                        args,
+                        alias,
+                        join,
+                    )
+                    .await;
+            }
+        }
+
+        let mut lateral_context = left_context.clone();
+        let (right_child, right_context) = self
+            .bind_single_table(&mut lateral_context, &join.right)
+            .await?;
+
+        let left_prop = RelExpr::with_s_expr(&left_child).derive_relational_prop()?;
+        let right_prop = RelExpr::with_s_expr(&right_child).derive_relational_prop()?;
+        let is_lateral = right_prop
+            .outer_columns
+            .iter()
+            .any(|column| left_prop.output_columns.contains(column));
+        if !is_lateral {
+            return self
+                .bind_join(
+                    bind_context,
+                    left_context,
+                    right_context,
+                    left_child,
+                    right_child,
+                    join,
+                )
+                .await;
+        }
+
+        check_lateral_subquery(&right_child)?;
+
+        // A correlated aggregation without GROUP BY produces exactly one row for each
+        // row of the left side, but the decorrelated plan only produces the groups that
+        // are not empty, so the join is bound as a LEFT OUTER JOIN and the join
+        // conditions are applied after it.
+        let mut join = join.clone();
+        let mut post_conditions = None;
+        if join.op != JoinOperator::LeftOuter && find_correlated_scalar_aggregate(&right_child)? {
+            post_conditions = match &join.condition {
+                JoinCondition::On(expr) => Some(expr.clone()),
+                JoinCondition::None => None,
+                _ => {
+                    return Err(ErrorCode::SemanticError(
+                        "only ON conditions are supported with a LATERAL aggregation".to_string(),
+                    )
+                    .set_span(join.right.span()));
+                }
+            };
+            join.op = JoinOperator::LeftOuter;
+            join.condition = JoinCondition::On(Box::new(Expr::Literal {
+                span: None,
+                lit: Literal::Boolean(true),
+            }));
+        }
+
+        let (mut s_expr, mut bind_context) = self
+            .bind_join(
+                bind_context,
+                left_context,
+                right_context,
+                left_child,
+                right_child,
+                &join,
+            )
+            .await?;
+        let mut logical_join: Join = s_expr.plan().clone().try_into()?;
+        logical_join.is_lateral = true;
+        s_expr = SExpr::create_binary(
+            Arc::new(logical_join.into()),
+            Arc::new(s_expr.child(0)?.clone()),
+            Arc::new(s_expr.child(1)?.clone()),
+        );
+
+        if let Some(expr) = post_conditions {
+            let mut scalar_binder = ScalarBinder::new(
+                &mut bind_context,
+                self.ctx.clone(),
+                &self.name_resolution_ctx,
+                self.metadata.clone(),
+                &[],
+            );
+            let mut predicates = vec![];
+            for expr in split_conjunctions_expr(&expr) {
+                predicates.push(scalar_binder.bind(&expr).await?.0);
+            }
+            s_expr = SExpr::create_unary(
+                Arc::new(
+                    Filter {
+                        predicates,
+                        is_having: false,
+                    }
+                    .into(),
+                ),
+                Arc::new(s_expr),
+            );
+        }
+        Ok((s_expr, bind_context))
+    }
+
+    /// Bind `LATERAL srf(args)`, the set-returning function is evaluated against
+    /// each row of the left side, which is repeated for each row it returns.
+    #[allow(clippy::too_many_arguments)]
+    #[async_backtrace::framed]
+    async fn bind_lateral_srf(
+        &mut self,
+        left_context: BindContext,
+        left_child: SExpr,
+        span: Span,
+        func_name: String,
+        args: Vec<Expr>,
+        alias: &Option<TableAlias>,
+        join: &common_ast::ast::Join,
+    ) -> Result<(SExpr, BindContext)> {
+        let srf = Expr::FunctionCall {
+            span,
+            distinct: false,
+            name: Identifier {
+                span,
+                name: func_name.clone(),
+                quote: None,
+            },
+            args,
+            params: vec![],
+            window: None,
+            lambda: None,
+        };
+
+        let mut bind_context = left_context;
+        let s_expr = self
+            .bind_project_set(&mut bind_context, &[srf.clone()], left_child)
+            .await?;
+        // The result of the srf is only referenced by the new columns.
+        let (_, result) = bind_context.srfs.remove(&srf.to_string()).unwrap();
+        let mut s_expr =
+            self.bind_srf_table_columns(&mut bind_context, s_expr, &func_name, result, alias)?;
+
+        match &join.condition {
+            JoinCondition::None => {}
+            JoinCondition::On(expr) => {
+                let mut scalar_binder = ScalarBinder::new(
+                    &mut bind_context,
+                    self.ctx.clone(),
+                    &self.name_resolution_ctx,
+                    self.metadata.clone(),
+                    &[],
+                );
+                let mut predicates = vec![];
+                for expr in split_conjunctions_expr(expr) {
+                    predicates.push(scalar_binder.bind(&expr).await?.0);
+                }
+                s_expr = SExpr::create_unary(
+                    Arc::new(
+                        Filter {
+                            predicates,
+                            is_having: false,
+                        }
+                        .into(),
+                    ),
+                    Arc::new(s_expr),
+                );
+            }
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "only ON conditions are supported with LATERAL {func_name}"
+                ))
+                .set_span(span));
+            }
+        }
+
+        Ok((s_expr, bind_context))
+    }
+
     pub fn bind_join_with_type(
         &mut self,
         join_type: JoinType,
@@ -199,6 +434,7 @@ impl Binder {
             marker_index: None,
             from_correlated_subquery: false,
             contain_runtime_filter: false,
+            is_lateral: false,
         };
         Ok(SExpr::create_binary(
             Arc::new(logical_join.into()),
@@ -275,6 +511,54 @@ impl Binder {
     }
 }
 
+/// The decorrelation of a lateral join evaluates the right side once for all the
+/// rows of the left side, which can't limit the rows for each of them.
+fn check_lateral_subquery(s_expr: &SExpr) -> Result<()> {
+    if let RelOperator::Limit(_) = s_expr.plan() {
+        let prop = RelExpr::with_s_expr(s_expr).derive_relational_prop()?;
+        if !prop.outer_columns.is_empty() {
+            return Err(ErrorCode::SemanticError(
+                "LIMIT is not supported in a LATERAL subquery that references preceding tables"
+                    .to_string(),
+            ));
+        }
+    }
+    for child in s_expr.children() {
+        check_lateral_subquery(child)?;
+    }
+    Ok(())
+}
+
+/// Check if there is an aggregation without GROUP BY that references the outer
+/// query, which must produce a row even if its input is empty.
+fn find_correlated_scalar_aggregate(s_expr: &SExpr) -> Result<bool> {
+    if let RelOperator::Aggregate(aggregate) = s_expr.plan() {
+        let prop = RelExpr::with_s_expr(s_expr).derive_relational_prop()?;
+        if aggregate.group_items.is_empty() && !prop.outer_columns.is_empty() {
+            // `COUNT` of an empty input is 0 rather than NULL.
+            let has_count = aggregate.aggregate_functions.iter().any(|item| {
+                matches!(
+                    &item.scalar,
+                    ScalarExpr::AggregateFunction(func) if func.func_name.eq_ignore_ascii_case("count")
+                )
+            });
+            if has_count {
+                return Err(ErrorCode::SemanticError(
+                    "COUNT without GROUP BY is not supported in a LATERAL subquery that references preceding tables"
+                        .to_string(),
+                ));
+            }
+            return Ok(true);
+        }
+    }
+    for child in s_expr.children() {
+        if find_correlated_scalar_aggregate(child)? {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
 // Wrap nullable for column binding depending on join type.
 fn wrap_nullable_for_column(
     join_type: &JoinOperator,
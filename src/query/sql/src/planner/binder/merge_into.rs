@@ -0,0 +1,262 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_ast::ast::Expr;
+use common_ast::ast::MatchOperation;
+use common_ast::ast::MergeIntoStmt;
+use common_ast::parser::parse_sql;
+use common_ast::parser::quote::quote_ident;
+use common_ast::parser::tokenize_sql;
+use common_ast::Dialect;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::ROW_ID_COL_NAME;
+
+use crate::binder::Binder;
+use crate::normalize_identifier;
+use crate::optimizer::optimize;
+use crate::optimizer::OptimizerConfig;
+use crate::optimizer::OptimizerContext;
+use crate::plans::MergeInto;
+use crate::plans::Plan;
+use crate::plans::MERGE_INTO_ACTION_COL_NAME;
+use crate::plans::MERGE_INTO_ACTION_DELETE;
+use crate::plans::MERGE_INTO_ACTION_INSERT;
+use crate::plans::MERGE_INTO_ACTION_UPDATE;
+use crate::plans::MERGE_INTO_ROW_ID_COL_NAME;
+use crate::BindContext;
+
+impl Binder {
+    /// Bind `MERGE INTO` by rewriting it into a left outer join of the source and the
+    /// target table, which outputs the row id of the matched target row, the action to
+    /// be taken and the new values of the target columns. The clauses are evaluated in
+    /// order, the first clause whose condition holds takes effect.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_merge_into(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &MergeIntoStmt,
+    ) -> Result<Plan> {
+        let MergeIntoStmt {
+            catalog,
+            database,
+            table,
+            target_alias,
+            source,
+            join_expr,
+            matched_clauses,
+            unmatched_clauses,
+        } = stmt;
+
+        let catalog_name = catalog.as_ref().map_or_else(
+            || self.ctx.get_current_catalog(),
+            |ident| normalize_identifier(ident, &self.name_resolution_ctx).name,
+        );
+        let database_name = database.as_ref().map_or_else(
+            || self.ctx.get_current_database(),
+            |ident| normalize_identifier(ident, &self.name_resolution_ctx).name,
+        );
+        let table_name = normalize_identifier(table, &self.name_resolution_ctx).name;
+        let table = self
+            .ctx
+            .get_table(&catalog_name, &database_name, &table_name)
+            .await?;
+        let table_id = table.get_id();
+
+        if !table.support_row_id_column() {
+            return Err(ErrorCode::Unimplemented(format!(
+                "MERGE INTO is not supported for table {}.{}, engine: {}",
+                database_name,
+                table_name,
+                table.engine()
+            )));
+        }
+        let schema = table.schema();
+        if schema
+            .fields()
+            .iter()
+            .any(|field| field.computed_expr().is_some())
+        {
+            return Err(ErrorCode::Unimplemented(
+                "MERGE INTO table with computed columns is not supported",
+            ));
+        }
+
+        let alias_name = match target_alias {
+            Some(alias) => {
+                if !alias.columns.is_empty() {
+                    return Err(ErrorCode::SemanticError(
+                        "column aliases of the MERGE INTO target table are not supported",
+                    ));
+                }
+                normalize_identifier(&alias.name, &self.name_resolution_ctx).name
+            }
+            None => table_name.clone(),
+        };
+        let target_column = |name: &str| {
+            format!(
+                "{}.{}",
+                quote_ident(&alias_name, '"', true),
+                quote_ident(name, '"', true)
+            )
+        };
+        let condition = |selection: &Option<Expr>| {
+            selection
+                .as_ref()
+                .map_or_else(|| "TRUE".to_string(), |expr| format!("({expr})"))
+        };
+        let fields = schema.fields();
+
+        // `WHEN <condition> THEN <value>` branches of the action and the new column values.
+        let mut matched_actions = Vec::with_capacity(matched_clauses.len());
+        let mut matched_values = vec![Vec::with_capacity(matched_clauses.len()); fields.len()];
+        for clause in matched_clauses {
+            let condition = condition(&clause.selection);
+            match &clause.operation {
+                MatchOperation::Update { update_list } => {
+                    let mut values = HashMap::with_capacity(update_list.len());
+                    for update_expr in update_list {
+                        let name =
+                            normalize_identifier(&update_expr.name, &self.name_resolution_ctx).name;
+                        schema.index_of(&name)?;
+                        if values
+                            .insert(name.clone(), update_expr.expr.to_string())
+                            .is_some()
+                        {
+                            return Err(ErrorCode::SemanticError(format!(
+                                "column {name} is assigned more than once in MERGE INTO"
+                            )));
+                        }
+                    }
+                    matched_actions
+                        .push(format!("WHEN {condition} THEN {MERGE_INTO_ACTION_UPDATE}"));
+                    for (field, branches) in fields.iter().zip(matched_values.iter_mut()) {
+                        let value = match values.get(field.name()) {
+                            Some(value) => value.clone(),
+                            None => target_column(field.name()),
+                        };
+                        branches.push(format!("WHEN {condition} THEN {value}"));
+                    }
+                }
+                MatchOperation::Delete => {
+                    matched_actions
+                        .push(format!("WHEN {condition} THEN {MERGE_INTO_ACTION_DELETE}"));
+                    for (field, branches) in fields.iter().zip(matched_values.iter_mut()) {
+                        branches.push(format!(
+                            "WHEN {condition} THEN {}",
+                            target_column(field.name())
+                        ));
+                    }
+                }
+            }
+        }
+
+        let mut unmatched_actions = Vec::with_capacity(unmatched_clauses.len());
+        let mut unmatched_values = vec![Vec::with_capacity(unmatched_clauses.len()); fields.len()];
+        for clause in unmatched_clauses {
+            let condition = condition(&clause.selection);
+            let columns = match &clause.columns {
+                Some(columns) => columns
+                    .iter()
+                    .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+                    .collect::<Vec<_>>(),
+                None => fields.iter().map(|field| field.name().clone()).collect(),
+            };
+            if columns.len() != clause.values.len() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "MERGE INTO has {} columns but {} values to insert",
+                    columns.len(),
+                    clause.values.len()
+                )));
+            }
+            let mut values = HashMap::with_capacity(columns.len());
+            for (name, value) in columns.into_iter().zip(clause.values.iter()) {
+                schema.index_of(&name)?;
+                if values.insert(name.clone(), value.to_string()).is_some() {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "column {name} is specified more than once in MERGE INTO"
+                    )));
+                }
+            }
+            unmatched_actions.push(format!("WHEN {condition} THEN {MERGE_INTO_ACTION_INSERT}"));
+            for (field, branches) in fields.iter().zip(unmatched_values.iter_mut()) {
+                let value = match values.get(field.name()) {
+                    Some(value) => value.clone(),
+                    None => field
+                        .default_expr()
+                        .cloned()
+                        .unwrap_or_else(|| "NULL".to_string()),
+                };
+                branches.push(format!("WHEN {condition} THEN {value}"));
+            }
+        }
+
+        let row_id = target_column(MERGE_INTO_ROW_ID_COL_NAME);
+        let case_when = |branches: &[String], else_result: &str| {
+            if branches.is_empty() {
+                else_result.to_string()
+            } else {
+                format!("CASE {} ELSE {else_result} END", branches.join(" "))
+            }
+        };
+        let mut select_list = Vec::with_capacity(fields.len() + 2);
+        select_list.push(row_id.clone());
+        select_list.push(format!(
+            "CASE WHEN {row_id} IS NULL THEN {} ELSE {} END AS {}",
+            case_when(&unmatched_actions, "0"),
+            case_when(&matched_actions, "0"),
+            quote_ident(MERGE_INTO_ACTION_COL_NAME, '"', true)
+        ));
+        for (i, field) in fields.iter().enumerate() {
+            select_list.push(format!(
+                "CASE WHEN {row_id} IS NULL THEN {} ELSE {} END AS {}",
+                case_when(&unmatched_values[i], "NULL"),
+                case_when(&matched_values[i], &target_column(field.name())),
+                quote_ident(field.name(), '"', true)
+            ));
+        }
+        let target = format!(
+            "(SELECT *, {ROW_ID_COL_NAME} AS {} FROM {}.{}.{}) AS {}",
+            quote_ident(MERGE_INTO_ROW_ID_COL_NAME, '"', true),
+            quote_ident(&catalog_name, '"', true),
+            quote_ident(&database_name, '"', true),
+            quote_ident(&table_name, '"', true),
+            quote_ident(&alias_name, '"', true)
+        );
+        let query = format!(
+            "SELECT * FROM (SELECT {} FROM {source} LEFT OUTER JOIN {target} ON {join_expr}) WHERE {} > 0",
+            select_list.join(", "),
+            quote_ident(MERGE_INTO_ACTION_COL_NAME, '"', true)
+        );
+
+        let tokens = tokenize_sql(&query)?;
+        let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL)?;
+        let input = self.bind_statement(bind_context, &stmt).await?;
+        let opt_ctx = Arc::new(OptimizerContext::new(OptimizerConfig {
+            enable_distributed_optimization: !self.ctx.get_cluster().is_empty(),
+        }));
+        let input = optimize(self.ctx.clone(), opt_ctx, input)?;
+
+        Ok(Plan::MergeInto(Box::new(MergeInto {
+            catalog: catalog_name,
+            database: database_name,
+            table: table_name,
+            table_id,
+            input: Box::new(input),
+        })))
+    }
+}
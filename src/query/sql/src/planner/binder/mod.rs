@@ -18,9 +18,12 @@ mod bind_context;
 mod binder;
 /// SQL builders;
 mod builders;
+mod cluster;
+mod column_privilege;
 mod copy;
 mod ddl;
 mod delete;
+mod dict_get;
 mod distinct;
 mod having;
 mod insert;
@@ -29,10 +32,13 @@ mod join;
 mod kill;
 mod limit;
 mod location;
+mod merge_into;
 mod presign;
 mod project;
 mod project_set;
+mod recursive_cte;
 mod replace;
+mod row_access_policy;
 mod scalar;
 mod scalar_common;
 mod scalar_visitor;
@@ -43,6 +49,8 @@ mod sort;
 mod stage;
 mod table;
 mod table_args;
+mod udf;
+mod udf_server;
 mod update;
 mod window;
 
@@ -50,6 +58,7 @@ pub use aggregate::AggregateInfo;
 pub use bind_context::*;
 pub use binder::Binder;
 pub use builders::*;
+pub use column_privilege::check_column_privilege;
 pub use copy::parse_stage_location;
 pub use internal_column_factory::INTERNAL_COLUMN_FACTORY;
 pub use location::parse_uri_location;
@@ -28,6 +28,7 @@ use common_exception::Result;
 use common_exception::Span;
 
 use super::AggregateInfo;
+use crate::binder::check_column_privilege;
 use crate::binder::select::SelectItem;
 use crate::binder::select::SelectList;
 use crate::binder::ExprContext;
@@ -277,6 +278,7 @@ impl Binder {
         select_target: &'a SelectTarget,
         column_binding: ColumnBinding,
     ) -> Result<SelectItem<'a>> {
+        check_column_privilege(&self.metadata, &column_binding)?;
         let scalar = match column_binding.virtual_computed_expr {
             Some(virtual_computed_expr) => {
                 let mut input_context = input_context.clone();
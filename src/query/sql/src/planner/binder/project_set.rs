@@ -16,21 +16,27 @@ use std::sync::Arc;
 
 use common_ast::ast::Expr;
 use common_ast::ast::Identifier;
+use common_ast::ast::Lambda;
 use common_ast::ast::Literal;
+use common_ast::ast::TableAlias;
 use common_ast::ast::Window;
 use common_ast::Visitor;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::Span;
 use common_expression::FunctionKind;
+use common_functions::srfs::FLATTEN_ARGS;
+use common_functions::srfs::FLATTEN_COLUMNS;
 use common_functions::BUILTIN_FUNCTIONS;
 
 use crate::binder::ExprContext;
 use crate::normalize_identifier;
 use crate::optimizer::SExpr;
 use crate::plans::BoundColumnRef;
+use crate::plans::EvalScalar;
 use crate::plans::FunctionCall;
 use crate::plans::ProjectSet;
+use crate::plans::ScalarItem;
 use crate::plans::SrfItem;
 use crate::BindContext;
 use crate::Binder;
@@ -52,6 +58,7 @@ impl<'a> Visitor<'a> for SrfCollector {
         args: &'a [Expr],
         params: &'a [Literal],
         over: &'a Option<Window>,
+        lambda: &'a Option<Lambda>,
     ) {
         if BUILTIN_FUNCTIONS
             .get_property(&name.name)
@@ -66,6 +73,7 @@ impl<'a> Visitor<'a> for SrfCollector {
                 args: args.to_vec(),
                 params: params.to_vec(),
                 window: over.clone(),
+                lambda: lambda.clone(),
             });
         } else {
             for arg in args.iter() {
@@ -195,4 +203,150 @@ impl Binder {
             Arc::new(s_expr),
         ))
     }
+
+    /// Arrange the arguments of a set-returning function in the `FROM` clause
+    /// in positional order, only `flatten` accepts named arguments.
+    pub(crate) fn srf_table_args(
+        &self,
+        span: Span,
+        func_name: &str,
+        params: &[Expr],
+        named_params: &[(String, Expr)],
+    ) -> Result<Vec<Expr>> {
+        if named_params.is_empty() {
+            return Ok(params.to_vec());
+        }
+        if func_name != "flatten" {
+            return Err(ErrorCode::SemanticError(format!(
+                "{func_name} doesn't support named arguments"
+            ))
+            .set_span(span));
+        }
+        if params.len() > FLATTEN_ARGS.len() {
+            return Err(ErrorCode::SemanticError(format!(
+                "flatten accepts at most {} arguments",
+                FLATTEN_ARGS.len()
+            ))
+            .set_span(span));
+        }
+
+        let mut args = params.iter().cloned().map(Some).collect::<Vec<_>>();
+        args.resize(FLATTEN_ARGS.len(), None);
+        for (name, expr) in named_params {
+            let position = FLATTEN_ARGS
+                .iter()
+                .position(|arg| arg.eq_ignore_ascii_case(name))
+                .ok_or_else(|| {
+                    ErrorCode::SemanticError(format!(
+                        "unknown argument {name} of flatten, expect one of {}",
+                        FLATTEN_ARGS.join(", ")
+                    ))
+                    .set_span(span)
+                })?;
+            if args[position].is_some() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "argument {name} of flatten is specified more than once"
+                ))
+                .set_span(span));
+            }
+            args[position] = Some(expr.clone());
+        }
+
+        // The arguments after the last given one are left out, the ones in
+        // between get their default values.
+        let len = args.iter().rposition(Option::is_some).map_or(0, |i| i + 1);
+        args.truncate(len);
+        args.into_iter()
+            .enumerate()
+            .map(|(i, arg)| match arg {
+                Some(arg) => Ok(arg),
+                None if i == 0 => Err(ErrorCode::SemanticError(
+                    "argument input of flatten is required",
+                )
+                .set_span(span)),
+                None => {
+                    let lit = match FLATTEN_ARGS[i] {
+                        "path" => Literal::String("".to_string()),
+                        "mode" => Literal::String("BOTH".to_string()),
+                        _ => Literal::Boolean(false),
+                    };
+                    Ok(Expr::Literal { span, lit })
+                }
+            })
+            .collect()
+    }
+
+    /// Add the columns of a set-returning function in the `FROM` clause, the
+    /// fields of the result of `flatten` become separate columns.
+    pub(crate) fn bind_srf_table_columns(
+        &mut self,
+        bind_context: &mut BindContext,
+        s_expr: SExpr,
+        func_name: &str,
+        result: ScalarExpr,
+        alias: &Option<TableAlias>,
+    ) -> Result<SExpr> {
+        let column_names = if func_name == "flatten" {
+            FLATTEN_COLUMNS
+                .iter()
+                .map(|name| name.to_string())
+                .collect::<Vec<_>>()
+        } else {
+            vec![func_name.to_string()]
+        };
+        let table_name = alias
+            .as_ref()
+            .map(|alias| normalize_identifier(&alias.name, &self.name_resolution_ctx).name);
+        if let Some(alias) = alias {
+            if alias.columns.len() > column_names.len() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "table has {} columns available but {} columns specified",
+                    column_names.len(),
+                    alias.columns.len()
+                ))
+                .set_span(alias.name.span));
+            }
+        }
+
+        let mut items = Vec::with_capacity(column_names.len());
+        for (i, column_name) in column_names.into_iter().enumerate() {
+            let scalar = if func_name == "flatten" {
+                ScalarExpr::FunctionCall(FunctionCall {
+                    span: None,
+                    func_name: "get".to_string(),
+                    params: vec![i + 1],
+                    arguments: vec![result.clone()],
+                })
+            } else {
+                result.clone()
+            };
+            let column_name = alias
+                .as_ref()
+                .and_then(|alias| alias.columns.get(i))
+                .map(|column| normalize_identifier(column, &self.name_resolution_ctx).name)
+                .unwrap_or(column_name);
+            let data_type = scalar.data_type()?;
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(column_name.clone(), data_type.clone());
+            bind_context.add_column_binding(ColumnBinding {
+                database_name: None,
+                table_name: table_name.clone(),
+                column_position: None,
+                table_index: None,
+                column_name,
+                index,
+                data_type: Box::new(data_type),
+                visibility: Visibility::Visible,
+                virtual_computed_expr: None,
+            });
+            items.push(ScalarItem { scalar, index });
+        }
+
+        Ok(SExpr::create_unary(
+            Arc::new(EvalScalar { items }.into()),
+            Arc::new(s_expr),
+        ))
+    }
 }
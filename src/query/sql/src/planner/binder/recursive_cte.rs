@@ -0,0 +1,330 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_ast::ast::SetExpr;
+use common_ast::ast::SetOperator;
+use common_ast::ast::TableReference;
+use common_ast::walk_table_reference;
+use common_ast::Visitor;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::Span;
+use common_expression::type_check::common_super_type;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_functions::BUILTIN_FUNCTIONS;
+
+use crate::binder::wrap_cast;
+use crate::binder::Binder;
+use crate::binder::CteInfo;
+use crate::binder::WorkingTableInfo;
+use crate::normalize_identifier;
+use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::EvalScalar;
+use crate::plans::RecursiveCte;
+use crate::plans::RecursiveCteScan;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
+use crate::BindContext;
+use crate::ColumnBinding;
+use crate::NameResolutionContext;
+use crate::Visibility;
+
+/// The types of the working table are resolved by binding the recursive term
+/// until they are stable, each round can only widen them.
+const MAX_TYPE_RESOLUTION_ROUNDS: usize = 4;
+
+impl Binder {
+    /// Bind a CTE declared with `WITH RECURSIVE`.
+    ///
+    /// A CTE that references itself must be of the form
+    /// `anchor UNION [ALL] recursive_term`, where only the recursive term
+    /// references the CTE.
+    #[async_backtrace::framed]
+    pub(crate) async fn bind_recursive_cte(
+        &mut self,
+        span: Span,
+        bind_context: &mut BindContext,
+        cte_name: &str,
+        cte_info: &CteInfo,
+    ) -> Result<(SExpr, BindContext)> {
+        let query = &cte_info.query;
+        let (anchor, recursive, distinct) = match &query.body {
+            SetExpr::SetOperation(op)
+                if op.op == SetOperator::Union && self.references_cte(&op.right, cte_name) =>
+            {
+                (op.left.as_ref(), op.right.as_ref(), !op.all)
+            }
+            body => {
+                if self.references_cte(body, cte_name) {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "recursive cte {cte_name} must be of the form `anchor UNION [ALL] recursive term`"
+                    ))
+                    .set_span(span));
+                }
+                return self.bind_query(bind_context, query).await;
+            }
+        };
+        if self.references_cte(anchor, cte_name) {
+            return Err(ErrorCode::SemanticError(format!(
+                "the anchor of recursive cte {cte_name} cannot reference itself"
+            ))
+            .set_span(anchor.span()));
+        }
+        if !query.order_by.is_empty() || !query.limit.is_empty() || query.offset.is_some() {
+            return Err(ErrorCode::SemanticError(format!(
+                "ORDER BY, LIMIT and OFFSET are not supported in recursive cte {cte_name}"
+            ))
+            .set_span(span));
+        }
+        if let Some(with) = &query.with {
+            Self::add_ctes(bind_context, with)?;
+        }
+
+        let (anchor_expr, anchor_context) =
+            self.bind_set_expr(bind_context, anchor, &[], 0).await?;
+        let column_names = anchor_context
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, column)| {
+                cte_info
+                    .columns_alias
+                    .get(i)
+                    .cloned()
+                    .unwrap_or_else(|| column.column_name.clone())
+            })
+            .collect::<Vec<_>>();
+        let mut column_types = anchor_context
+            .columns
+            .iter()
+            .map(|column| working_table_type(&column.data_type))
+            .collect::<Vec<_>>();
+
+        let cte_index = self.metadata.write().add_recursive_cte();
+        let mut rounds = 0;
+        let (recursive_expr, recursive_context) = loop {
+            let mut recursive_bind_context = bind_context.clone();
+            recursive_bind_context
+                .ctes_map
+                .insert(cte_name.to_string(), CteInfo {
+                    working_table: Some(WorkingTableInfo {
+                        cte_index,
+                        column_names: column_names.clone(),
+                        column_types: column_types.clone(),
+                    }),
+                    ..cte_info.clone()
+                });
+            let (recursive_expr, recursive_context) = self
+                .bind_set_expr(&mut recursive_bind_context, recursive, &[], 0)
+                .await?;
+            if recursive_context.columns.len() != column_types.len() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "the anchor and the recursive term of recursive cte {cte_name} must have the same number of columns"
+                ))
+                .set_span(recursive.span()));
+            }
+
+            let common_types = column_types
+                .iter()
+                .zip(recursive_context.columns.iter())
+                .zip(column_names.iter())
+                .map(|((ty, column), name)| {
+                    common_super_type(
+                        ty.clone(),
+                        *column.data_type.clone(),
+                        &BUILTIN_FUNCTIONS.default_cast_rules,
+                    )
+                    .ok_or_else(|| {
+                        ErrorCode::SemanticError(format!(
+                            "column {name} of recursive cte {cte_name} has type {ty} in the anchor but {} in the recursive term",
+                            column.data_type
+                        ))
+                        .set_span(recursive.span())
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            if common_types == column_types {
+                break (recursive_expr, recursive_context);
+            }
+
+            rounds += 1;
+            if rounds >= MAX_TYPE_RESOLUTION_ROUNDS {
+                return Err(ErrorCode::SemanticError(format!(
+                    "cannot resolve the column types of recursive cte {cte_name}"
+                ))
+                .set_span(span));
+            }
+            column_types = common_types;
+        };
+
+        let (anchor_expr, anchor_columns) = self.cast_recursive_cte_columns(
+            anchor_expr,
+            &anchor_context.columns,
+            &column_names,
+            &column_types,
+        );
+        let (recursive_expr, recursive_columns) = self.cast_recursive_cte_columns(
+            recursive_expr,
+            &recursive_context.columns,
+            &column_names,
+            &column_types,
+        );
+
+        let recursive_cte = RecursiveCte {
+            cte_index,
+            anchor_columns: anchor_columns.iter().map(|c| c.index).collect(),
+            recursive_columns: recursive_columns.iter().map(|c| c.index).collect(),
+            distinct,
+        };
+        let s_expr = SExpr::create_binary(
+            Arc::new(recursive_cte.into()),
+            Arc::new(anchor_expr),
+            Arc::new(recursive_expr),
+        );
+
+        let mut output_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        output_context.columns = anchor_columns;
+        Ok((s_expr, output_context))
+    }
+
+    /// Bind a reference to a recursive CTE inside its recursive term, which
+    /// reads the rows produced by the previous iteration.
+    pub(crate) fn bind_working_table(
+        &mut self,
+        bind_context: &BindContext,
+        working_table: &WorkingTableInfo,
+    ) -> Result<(SExpr, BindContext)> {
+        let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        for (name, ty) in working_table
+            .column_names
+            .iter()
+            .zip(working_table.column_types.iter())
+        {
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(name.clone(), ty.clone());
+            new_bind_context.add_column_binding(ColumnBinding {
+                database_name: None,
+                table_name: None,
+                column_position: None,
+                table_index: None,
+                column_name: name.clone(),
+                index,
+                data_type: Box::new(ty.clone()),
+                visibility: Visibility::Visible,
+                virtual_computed_expr: None,
+            });
+        }
+
+        let scan = RecursiveCteScan {
+            cte_index: working_table.cte_index,
+            columns: new_bind_context.columns.iter().map(|c| c.index).collect(),
+        };
+        Ok((SExpr::create_leaf(Arc::new(scan.into())), new_bind_context))
+    }
+
+    /// Project the output of the anchor or the recursive term to new columns
+    /// of the resolved types.
+    fn cast_recursive_cte_columns(
+        &mut self,
+        s_expr: SExpr,
+        columns: &[ColumnBinding],
+        names: &[String],
+        types: &[DataType],
+    ) -> (SExpr, Vec<ColumnBinding>) {
+        let mut items = Vec::with_capacity(columns.len());
+        let mut new_columns = Vec::with_capacity(columns.len());
+        for ((column, name), ty) in columns.iter().zip(names.iter()).zip(types.iter()) {
+            let column_ref = ScalarExpr::BoundColumnRef(BoundColumnRef {
+                span: None,
+                column: column.clone(),
+            });
+            let scalar = if column.data_type.as_ref() == ty {
+                column_ref
+            } else {
+                wrap_cast(&column_ref, ty)
+            };
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(name.clone(), ty.clone());
+            items.push(ScalarItem { scalar, index });
+            new_columns.push(ColumnBinding {
+                database_name: None,
+                table_name: None,
+                column_position: None,
+                table_index: None,
+                column_name: name.clone(),
+                index,
+                data_type: Box::new(ty.clone()),
+                visibility: Visibility::Visible,
+                virtual_computed_expr: None,
+            });
+        }
+
+        let s_expr = SExpr::create_unary(Arc::new(EvalScalar { items }.into()), Arc::new(s_expr));
+        (s_expr, new_columns)
+    }
+
+    fn references_cte(&self, set_expr: &SetExpr, cte_name: &str) -> bool {
+        let mut finder = CteReferenceFinder {
+            cte_name,
+            name_resolution_ctx: &self.name_resolution_ctx,
+            found: false,
+        };
+        finder.visit_set_expr(set_expr);
+        finder.found
+    }
+}
+
+/// Integers and floats of the working table are widened to 64 bits, so that
+/// arithmetic like `n + 1` in the recursive term fits without rebinding.
+fn working_table_type(data_type: &DataType) -> DataType {
+    match data_type {
+        DataType::Nullable(ty) => DataType::Nullable(Box::new(working_table_type(ty))),
+        DataType::Number(ty) => {
+            DataType::Number(NumberDataType::new(64, ty.is_signed(), ty.is_float()))
+        }
+        ty => ty.clone(),
+    }
+}
+
+/// Checks whether a query references a common table expression by name.
+struct CteReferenceFinder<'a> {
+    cte_name: &'a str,
+    name_resolution_ctx: &'a NameResolutionContext,
+    found: bool,
+}
+
+impl<'a, 'ast> Visitor<'ast> for CteReferenceFinder<'a> {
+    fn visit_table_reference(&mut self, table_ref: &'ast TableReference) {
+        if let TableReference::Table {
+            catalog: None,
+            database: None,
+            table,
+            ..
+        } = table_ref
+        {
+            if normalize_identifier(table, self.name_resolution_ctx).name == self.cte_name {
+                self.found = true;
+            }
+        }
+        walk_table_reference(self, table_ref);
+    }
+}
@@ -0,0 +1,150 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_ast::ast::Expr;
+use common_ast::parser::parse_expr;
+use common_ast::parser::tokenize_sql;
+use common_ast::Dialect;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_users::UserApiProvider;
+use storages_common_table_meta::table::OPT_KEY_ROW_ACCESS_POLICY;
+use storages_common_table_meta::table::OPT_KEY_ROW_ACCESS_POLICY_COLUMNS;
+
+use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::CastExpr;
+use crate::plans::Filter;
+use crate::resolve_type_name_by_str;
+use crate::BindContext;
+use crate::Binder;
+use crate::ScalarBinder;
+use crate::ScalarExpr;
+
+impl Binder {
+    /// Binds the body of a row access policy, the arguments of the policy
+    /// are resolved to the scalars of `args`.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_row_access_policy_body(
+        &mut self,
+        body: &Expr,
+        args: &[(String, ScalarExpr)],
+    ) -> Result<ScalarExpr> {
+        // The body can only see the arguments of the policy.
+        let mut bind_context = BindContext::new();
+        let mut scalar_binder = ScalarBinder::new(
+            &mut bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            args,
+        );
+        let (predicate, data_type) = scalar_binder.bind(body).await?;
+        if data_type.remove_nullable() != DataType::Boolean {
+            return Err(ErrorCode::SemanticError(format!(
+                "row access policy must return BOOLEAN, but got {data_type}"
+            ))
+            .set_span(body.span()));
+        }
+        Ok(predicate)
+    }
+
+    /// Filters the scan of a table by the row access policy attached to it,
+    /// unless the current role is exempt from the policy.
+    ///
+    /// `bind_context` is the context of the table scan `s_expr`.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn apply_row_access_policy(
+        &mut self,
+        bind_context: &BindContext,
+        table_options: &BTreeMap<String, String>,
+        s_expr: SExpr,
+    ) -> Result<SExpr> {
+        let policy_name = match table_options.get(OPT_KEY_ROW_ACCESS_POLICY) {
+            Some(policy_name) => policy_name,
+            None => return Ok(s_expr),
+        };
+
+        // A dropped policy fails the query instead of exposing all the rows.
+        let policy = UserApiProvider::instance()
+            .get_row_access_policy(&self.ctx.get_tenant(), policy_name)
+            .await?;
+        if let Some(role) = self.ctx.get_current_role() {
+            if policy.is_exempt(&role.name) {
+                return Ok(s_expr);
+            }
+        }
+
+        let columns = table_options
+            .get(OPT_KEY_ROW_ACCESS_POLICY_COLUMNS)
+            .map(|columns| columns.split(',').collect::<Vec<_>>())
+            .unwrap_or_default();
+        if columns.len() != policy.args.len() {
+            return Err(ErrorCode::SemanticError(format!(
+                "row access policy {} has {} arguments, but {} columns are bound to it",
+                policy.name,
+                policy.args.len(),
+                columns.len()
+            )));
+        }
+
+        let mut args = Vec::with_capacity(columns.len());
+        for ((arg_name, arg_type), column) in policy.args.iter().zip(columns) {
+            let column = bind_context
+                .columns
+                .iter()
+                .find(|c| c.column_name == column)
+                .ok_or_else(|| {
+                    ErrorCode::SemanticError(format!(
+                        "column {column} bound to row access policy {} doesn't exist",
+                        policy.name
+                    ))
+                })?;
+            let mut arg_type: DataType = (&resolve_type_name_by_str(arg_type)?).into();
+            if column.data_type.is_nullable_or_null() {
+                arg_type = arg_type.wrap_nullable();
+            }
+            let mut arg = ScalarExpr::BoundColumnRef(BoundColumnRef {
+                span: None,
+                column: column.clone(),
+            });
+            if *column.data_type != arg_type {
+                arg = ScalarExpr::CastExpr(CastExpr {
+                    span: None,
+                    is_try: false,
+                    argument: Box::new(arg),
+                    target_type: Box::new(arg_type),
+                });
+            }
+            args.push((arg_name.clone(), arg));
+        }
+
+        let tokens = tokenize_sql(&policy.body)?;
+        let body = parse_expr(&tokens, Dialect::PostgreSQL)?;
+        let predicate = self.bind_row_access_policy_body(&body, &args).await?;
+
+        let filter = Filter {
+            predicates: vec![predicate],
+            is_having: false,
+        };
+        Ok(SExpr::create_unary(
+            Arc::new(filter.into()),
+            Arc::new(s_expr),
+        ))
+    }
+}
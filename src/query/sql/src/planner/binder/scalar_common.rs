@@ -158,6 +158,7 @@ pub fn contain_subquery(scalar: &ScalarExpr) -> bool {
             column.column_name == format!("scalar_subquery_{}", column.index)
         }
         ScalarExpr::FunctionCall(func) => func.arguments.iter().any(contain_subquery),
+        ScalarExpr::LambdaFunction(func) => func.args.iter().any(contain_subquery),
         ScalarExpr::CastExpr(CastExpr { argument, .. }) => contain_subquery(argument),
         _ => false,
     }
@@ -204,6 +205,10 @@ pub fn prune_by_children(scalar: &ScalarExpr, columns: &HashSet<ScalarExpr>) ->
             .arguments
             .iter()
             .all(|arg| prune_by_children(arg, columns)),
+        ScalarExpr::LambdaFunction(scalar) => scalar
+            .args
+            .iter()
+            .all(|arg| prune_by_children(arg, columns)),
         ScalarExpr::CastExpr(expr) => prune_by_children(expr.argument.as_ref(), columns),
         ScalarExpr::SubqueryExpr(_) => false,
     }
@@ -223,6 +228,8 @@ pub fn wrap_cast_scalar(
             | DataType::Timestamp
             | DataType::Date
             | DataType::Bitmap
+            | DataType::Geometry
+            | DataType::Binary
             | DataType::Variant => wrap_cast(scalar, target_type),
             DataType::String => {
                 // parse string to JSON value
@@ -88,6 +88,11 @@ pub trait ScalarVisitor: Sized {
                                         stack.push(RecursionProcessing::Call(arg));
                                     }
                                 }
+                                ScalarExpr::LambdaFunction(func) => {
+                                    for arg in func.args.iter() {
+                                        stack.push(RecursionProcessing::Call(arg));
+                                    }
+                                }
                                 ScalarExpr::BoundColumnRef(_) | ScalarExpr::ConstantExpr(_) => {}
                                 ScalarExpr::CastExpr(cast) => {
                                     stack.push(RecursionProcessing::Call(&cast.argument))
@@ -35,6 +35,7 @@ use common_ast::ast::SelectTarget;
 use common_ast::ast::SetExpr;
 use common_ast::ast::SetOperator;
 use common_ast::ast::TableReference;
+use common_ast::ast::With;
 use common_ast::Visitor;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -42,9 +43,11 @@ use common_exception::Span;
 use tracing::warn;
 
 use super::sort::OrderItem;
+use crate::binder::dict_get::DictGetCollector;
 use crate::binder::join::JoinConditions;
 use crate::binder::project_set::SrfCollector;
 use crate::binder::scalar_common::split_conjunctions;
+use crate::binder::udf_server::UdfServerCallCollector;
 use crate::binder::CteInfo;
 use crate::binder::ExprContext;
 use crate::optimizer::SExpr;
@@ -142,6 +145,34 @@ impl Binder {
             .bind_project_set(&mut from_context, &set_returning_functions, s_expr)
             .await?;
 
+        // Collect and bind the dictionary lookups
+        let dict_get_calls = {
+            let mut collector = DictGetCollector::new();
+            stmt.select_list.iter().for_each(|item| {
+                if let SelectTarget::AliasedExpr { expr, .. } = item {
+                    collector.visit(expr);
+                }
+            });
+            collector.into_calls()
+        };
+        s_expr = self
+            .bind_dict_get(&mut from_context, &dict_get_calls, s_expr)
+            .await?;
+
+        // Collect and bind the external UDF calls
+        let udf_server_calls = {
+            let mut collector = UdfServerCallCollector::new();
+            stmt.select_list.iter().for_each(|item| {
+                if let SelectTarget::AliasedExpr { expr, .. } = item {
+                    collector.visit(expr);
+                }
+            });
+            collector.into_calls()
+        };
+        s_expr = self
+            .bind_udf_server_calls(&mut from_context, &udf_server_calls, s_expr)
+            .await?;
+
         // Try put window definitions into bind context.
         // This operation should be before `normalize_select_list` because window functions can be used in select list.
         self.analyze_window_definition(&mut from_context, &stmt.window_list)?;
@@ -308,19 +339,7 @@ impl Binder {
         query: &Query,
     ) -> Result<(SExpr, BindContext)> {
         if let Some(with) = &query.with {
-            for cte in with.ctes.iter() {
-                let table_name = cte.alias.name.name.clone();
-                if bind_context.ctes_map.contains_key(&table_name) {
-                    return Err(ErrorCode::SemanticError(format!(
-                        "duplicate cte {table_name}"
-                    )));
-                }
-                let cte_info = CteInfo {
-                    columns_alias: cte.alias.columns.iter().map(|c| c.name.clone()).collect(),
-                    query: cte.query.clone(),
-                };
-                bind_context.ctes_map.insert(table_name, cte_info);
-            }
+            Self::add_ctes(bind_context, with)?;
         }
 
         let (limit, offset) = if !query.limit.is_empty() {
@@ -365,6 +384,25 @@ impl Binder {
         Ok((s_expr, bind_context))
     }
 
+    pub(crate) fn add_ctes(bind_context: &mut BindContext, with: &With) -> Result<()> {
+        for cte in with.ctes.iter() {
+            let table_name = cte.alias.name.name.clone();
+            if bind_context.ctes_map.contains_key(&table_name) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "duplicate cte {table_name}"
+                )));
+            }
+            let cte_info = CteInfo {
+                columns_alias: cte.alias.columns.iter().map(|c| c.name.clone()).collect(),
+                query: cte.query.clone(),
+                recursive: with.recursive,
+                working_table: None,
+            };
+            bind_context.ctes_map.insert(table_name, cte_info);
+        }
+        Ok(())
+    }
+
     #[async_backtrace::framed]
     pub async fn bind_where(
         &mut self,
@@ -744,6 +782,7 @@ impl<'a> SelectRewriter<'a> {
                 args,
                 params: vec![],
                 window: None,
+                lambda: None,
             }),
             alias,
         }
@@ -799,11 +838,34 @@ impl<'a> SelectRewriter<'a> {
     }
 
     fn rewrite(&mut self, stmt: &SelectStmt) -> Result<Option<SelectStmt>> {
+        Self::check_pivot_position(stmt)?;
         self.rewrite_pivot(stmt)?;
         self.rewrite_unpivot(stmt)?;
         Ok(self.new_stmt.take())
     }
 
+    /// PIVOT and UNPIVOT are rewritten over the whole select statement, so they
+    /// can only be applied to the only table in the FROM clause.
+    fn check_pivot_position(stmt: &SelectStmt) -> Result<()> {
+        fn has_pivot(table: &TableReference) -> bool {
+            match table {
+                TableReference::Join { join, .. } => {
+                    has_pivot(&join.left) || has_pivot(&join.right)
+                }
+                _ => table.pivot().is_some() || table.unpivot().is_some(),
+            }
+        }
+
+        let single_table =
+            stmt.from.len() == 1 && !matches!(stmt.from[0], TableReference::Join { .. });
+        if !single_table && stmt.from.iter().any(has_pivot) {
+            return Err(ErrorCode::SemanticError(
+                "PIVOT and UNPIVOT can only be applied to the only table in the FROM clause",
+            ));
+        }
+        Ok(())
+    }
+
     fn rewrite_pivot(&mut self, stmt: &SelectStmt) -> Result<()> {
         if stmt.from.len() != 1 || stmt.from[0].pivot().is_none() {
             return Ok(());
@@ -833,9 +895,17 @@ impl<'a> SelectRewriter<'a> {
                                 .iter()
                                 .any(|col| self.compare_unquoted_ident(col, &col_bind.column_name))
                     })
-                    .map(|col| Expr::Literal {
-                        span: Span::default(),
-                        lit: Literal::UInt64(col.index as u64 + 1),
+                    // The remaining columns are referenced by name, their positions
+                    // in the select list depend on the excluded columns.
+                    .map(|col| Expr::ColumnRef {
+                        span: None,
+                        database: None,
+                        table: None,
+                        column: ColumnID::Name(Identifier {
+                            name: col.column_name.clone(),
+                            quote: Some('"'),
+                            span: None,
+                        }),
                     })
                     .collect(),
             )
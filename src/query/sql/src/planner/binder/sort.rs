@@ -326,6 +326,7 @@ impl Binder {
                     params,
                     args,
                     return_type,
+                    udaf,
                 }) => {
                     let args = args
                         .iter()
@@ -340,6 +341,7 @@ impl Binder {
                         params: params.clone(),
                         args,
                         return_type: return_type.clone(),
+                        udaf: udaf.clone(),
                     }))
                 }
                 window @ ScalarExpr::WindowFunction(_) => {
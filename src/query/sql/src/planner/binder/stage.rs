@@ -33,11 +33,13 @@ use common_expression::Expr;
 use common_expression::Scalar;
 use common_expression::Value;
 use common_pipeline_transforms::processors::transforms::Transform;
+use common_users::UserApiProvider;
 
 use crate::binder::wrap_cast;
 use crate::binder::wrap_cast_scalar;
 use crate::evaluator::BlockOperator;
 use crate::evaluator::CompoundBlockOperator;
+use crate::parse_sequence_default_expr;
 use crate::BindContext;
 use crate::MetadataRef;
 use crate::NameResolutionContext;
@@ -70,7 +72,8 @@ impl BindContext {
             if let AExpr::ColumnRef { column, .. } = expr {
                 if column.name().eq_ignore_ascii_case("default") {
                     let field = schema.field(i);
-                    fill_default_value(&mut scalar_binder, &mut map_exprs, field, schema).await?;
+                    fill_default_value(&mut scalar_binder, &ctx, &mut map_exprs, field, schema)
+                        .await?;
                     continue;
                 }
             }
@@ -112,11 +115,30 @@ impl BindContext {
 
 async fn fill_default_value(
     binder: &mut ScalarBinder<'_>,
+    ctx: &Arc<dyn TableContext>,
     map_exprs: &mut Vec<Expr>,
     field: &DataField,
     schema: &DataSchema,
 ) -> Result<()> {
-    if let Some(default_expr) = field.default_expr() {
+    if let Some(sequence) = field
+        .default_expr()
+        .and_then(|expr| parse_sequence_default_expr(expr))
+    {
+        let (value, _) = UserApiProvider::instance()
+            .next_sequence_values(&ctx.get_tenant(), &sequence, 1)
+            .await?;
+        let expr = Expr::Cast {
+            span: None,
+            is_try: false,
+            expr: Box::new(Expr::Constant {
+                span: None,
+                scalar: Scalar::Number(NumberScalar::UInt64(value)),
+                data_type: DataType::Number(NumberDataType::UInt64),
+            }),
+            dest_type: field.data_type().clone(),
+        };
+        map_exprs.push(expr);
+    } else if let Some(default_expr) = field.default_expr() {
         let tokens = tokenize_sql(default_expr)?;
         let ast = parse_expr(&tokens, Dialect::PostgreSQL)?;
         let (mut scalar, _) = binder.bind(&ast).await?;
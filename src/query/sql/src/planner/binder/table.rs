@@ -19,21 +19,28 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 use async_recursion::async_recursion;
+use chrono::DateTime;
 use chrono::TimeZone;
 use chrono::Utc;
+use chrono_tz::Tz;
 use common_ast::ast::Indirection;
 use common_ast::ast::Join;
+use common_ast::ast::Literal;
 use common_ast::ast::SelectStmt;
 use common_ast::ast::SelectTarget;
 use common_ast::ast::Statement;
 use common_ast::ast::TableAlias;
 use common_ast::ast::TableReference;
+use common_ast::ast::TableSample;
 use common_ast::ast::TimeTravelPoint;
 use common_ast::parser::parse_sql;
+use common_ast::parser::quote::quote_ident;
 use common_ast::parser::tokenize_sql;
 use common_ast::Dialect;
 use common_catalog::catalog_kind::CATALOG_DEFAULT;
 use common_catalog::plan::ParquetReadOptions;
+use common_catalog::plan::SampleConfig;
+use common_catalog::plan::SampleLevel;
 use common_catalog::plan::StageTableInfo;
 use common_catalog::table::ColumnStatistics;
 use common_catalog::table::NavigationPoint;
@@ -43,7 +50,9 @@ use common_catalog::table_function::TableFunction;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::Span;
+use common_expression::types::timestamp::string_to_timestamp;
 use common_expression::types::DataType;
+use common_expression::types::F64;
 use common_expression::ColumnId;
 use common_expression::ConstantFolder;
 use common_expression::FunctionKind;
@@ -59,9 +68,14 @@ use common_meta_app::principal::StageInfo;
 use common_meta_app::schema::IndexMeta;
 use common_meta_app::schema::ListIndexesReq;
 use common_meta_types::MetaId;
+use common_pipeline_sources::input_formats::detect_file_format;
+use common_pipeline_sources::input_formats::InputContext;
+use common_pipeline_sources::input_formats::InputFormat;
 use common_storage::DataOperator;
+use common_storage::StageFileFilter;
 use common_storage::StageFileInfo;
 use common_storage::StageFilesInfo;
+use common_storage::StageMetadataColumn;
 use common_storages_parquet::ParquetTable;
 use common_storages_result_cache::ResultCacheMetaManager;
 use common_storages_result_cache::ResultCacheReader;
@@ -70,6 +84,12 @@ use common_storages_stage::StageTable;
 use common_storages_view::view_table::QUERY;
 use common_users::UserApiProvider;
 use dashmap::DashMap;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_REFRESHED_ON;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SNAPSHOT;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCE;
+use storages_common_table_meta::table::OPT_KEY_ROW_ACCESS_POLICY;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 
 use crate::binder::copy::parse_file_location;
 use crate::binder::scalar::ScalarBinder;
@@ -82,6 +102,8 @@ use crate::binder::Visibility;
 use crate::optimizer::SExpr;
 use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::TypeChecker;
+use crate::plans::BoundColumnRef;
+use crate::plans::ScalarExpr;
 use crate::plans::Scan;
 use crate::plans::Statistics;
 use crate::BaseTableColumn;
@@ -89,6 +111,7 @@ use crate::BindContext;
 use crate::ColumnEntry;
 use crate::DerivedColumn;
 use crate::IndexType;
+use crate::MaterializedViewEntry;
 use crate::TableInternalColumn;
 use crate::VirtualColumn;
 
@@ -169,6 +192,7 @@ impl Binder {
                 travel_point,
                 pivot: _,
                 unpivot: _,
+                sample,
             } => {
                 let (catalog, database, table_name) =
                     self.normalize_object_identifier_triple(catalog, database, table);
@@ -177,8 +201,13 @@ impl Binder {
                 } else {
                     None
                 };
+                let sample = match sample {
+                    Some(sample) => Some(bind_table_sample(*span, sample)?),
+                    None => None,
+                };
                 // Check and bind common table expression
                 if let Some(cte_info) = bind_context.ctes_map.get(&table_name) {
+                    check_sample_on_table(*span, &sample, "common table expression")?;
                     return self
                         .bind_cte(*span, bind_context, &table_name, alias, &cte_info)
                         .await;
@@ -214,6 +243,7 @@ impl Binder {
                                 break;
                             }
                             if let Some(cte_info) = parent.unwrap().ctes_map.get(&table_name) {
+                                check_sample_on_table(*span, &sample, "common table expression")?;
                                 return self
                                     .bind_cte(*span, bind_context, &table_name, alias, &cte_info)
                                     .await;
@@ -268,8 +298,33 @@ impl Binder {
                     }
                 }
 
+                // The rows of a materialized view are not filtered by the row access
+                // policy of its source table, so it can not answer the query.
+                let mut materialized_views = vec![];
+                if !bind_context.planning_agg_index
+                    && !bind_context.planning_materialized_view
+                    && navigation_point.is_none()
+                    && table_meta.engine() == "FUSE"
+                    && !table_meta.options().contains_key(OPT_KEY_ROW_ACCESS_POLICY)
+                    && self
+                        .ctx
+                        .get_settings()
+                        .get_enable_materialized_view_rewrite()?
+                {
+                    materialized_views = self
+                        .resolve_materialized_views(
+                            bind_context,
+                            tenant.as_str(),
+                            catalog.as_str(),
+                            database.as_str(),
+                            &table_meta,
+                        )
+                        .await?;
+                }
+
                 match table_meta.engine() {
                     "VIEW" => {
+                        check_sample_on_table(*span, &sample, "view")?;
                         Self::check_view_dep(bind_context, &database, &table_name)?;
                         let query = table_meta
                             .options()
@@ -316,15 +371,40 @@ impl Binder {
                         }
                     }
                     _ => {
+                        if matches!(
+                            sample,
+                            Some(SampleConfig {
+                                level: SampleLevel::Block,
+                                ..
+                            })
+                        ) && table_meta.engine() != "FUSE"
+                        {
+                            return Err(ErrorCode::SemanticError(format!(
+                                "SAMPLE BLOCK is not supported by table engine {}, use SAMPLE ROW instead",
+                                table_meta.engine()
+                            ))
+                            .set_span(*span));
+                        }
                         let table_name = table_meta.name();
                         let full_table_name = format!("{catalog}.{database}.{table_name}");
+                        let table_options = table_meta.options().clone();
+                        let planning_index = bind_context.planning_agg_index
+                            || bind_context.planning_materialized_view;
+                        // The privileges of the tables inside a view are not checked.
+                        let granted_columns = if bind_context.view_info.is_none() && !planning_index
+                        {
+                            self.granted_columns(&catalog, &database, table_name)
+                                .await?
+                        } else {
+                            None
+                        };
                         let table_index = self.metadata.write().add_table(
                             catalog,
                             database.clone(),
                             table_meta,
                             table_alias_name,
                             bind_context.view_info.is_some(),
-                            bind_context.planning_agg_index,
+                            planning_index,
                         );
 
                         if !agg_indexes.is_empty() {
@@ -334,9 +414,31 @@ impl Binder {
                                 .add_agg_indexes(full_table_name, agg_indexes);
                         }
 
-                        let (s_expr, mut bind_context) = self
+                        if !materialized_views.is_empty() {
+                            self.metadata
+                                .write()
+                                .add_materialized_views(table_index, materialized_views);
+                        }
+
+                        if let Some(granted_columns) = granted_columns {
+                            self.metadata
+                                .write()
+                                .set_granted_columns(table_index, granted_columns);
+                        }
+
+                        let (mut s_expr, mut bind_context) = self
                             .bind_base_table(bind_context, database.as_str(), table_index)
                             .await?;
+                        if let Some(sample) = sample {
+                            let mut scan: Scan = s_expr.plan().clone().try_into()?;
+                            scan.sample = Some(sample);
+                            s_expr = SExpr::create_leaf(Arc::new(scan.into()));
+                        }
+                        if !planning_index {
+                            s_expr = self
+                                .apply_row_access_policy(&bind_context, &table_options, s_expr)
+                                .await?;
+                        }
                         if let Some(alias) = alias {
                             bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
                         }
@@ -346,11 +448,83 @@ impl Binder {
             }
             TableReference::TableFunction {
                 span,
+                lateral: _,
                 name,
                 params,
                 named_params,
                 alias,
             } => {
+                let func_name = normalize_identifier(name, &self.name_resolution_ctx);
+
+                if BUILTIN_FUNCTIONS
+                    .get_property(&func_name.name)
+                    .map(|p| p.kind == FunctionKind::SRF)
+                    .unwrap_or(false)
+                {
+                    // If it is a set-returning function, we bind it as a subquery.
+                    let args = self.srf_table_args(*span, &func_name.name, params, named_params)?;
+                    let mut bind_context = BindContext::new();
+                    let stmt = SelectStmt {
+                        span: *span,
+                        hints: None,
+                        distinct: false,
+                        select_list: vec![SelectTarget::AliasedExpr {
+                            expr: Box::new(common_ast::ast::Expr::FunctionCall {
+                                span: *span,
+                                distinct: false,
+                                name: common_ast::ast::Identifier {
+                                    span: *span,
+                                    name: func_name.name.clone(),
+                                    quote: None,
+                                },
+                                params: vec![],
+                                args,
+                                window: None,
+                                lambda: None,
+                            }),
+                            alias: None,
+                        }],
+                        from: vec![],
+                        selection: None,
+                        group_by: None,
+                        having: None,
+                        window_list: None,
+                    };
+                    let (s_expr, bind_context) = self
+                        .bind_select_stmt(&mut bind_context, &stmt, &[], 0)
+                        .await?;
+                    if func_name.name != "flatten" {
+                        return Ok((s_expr, bind_context));
+                    }
+
+                    let result = ScalarExpr::BoundColumnRef(BoundColumnRef {
+                        span: *span,
+                        column: bind_context.columns[0].clone(),
+                    });
+                    let mut new_bind_context = BindContext::new();
+                    let s_expr = self.bind_srf_table_columns(
+                        &mut new_bind_context,
+                        s_expr,
+                        &func_name.name,
+                        result,
+                        alias,
+                    )?;
+                    return Ok((s_expr, new_bind_context));
+                }
+
+                if let Some(udf) = self.get_table_udf(&func_name.name).await? {
+                    if !named_params.is_empty() {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "Table function {} doesn't accept named arguments",
+                            func_name.name
+                        ))
+                        .set_span(*span));
+                    }
+                    return self
+                        .bind_table_udf(bind_context, *span, udf, params, alias)
+                        .await;
+                }
+
                 let mut scalar_binder = ScalarBinder::new(
                     bind_context,
                     self.ctx.clone(),
@@ -360,8 +534,6 @@ impl Binder {
                 );
                 let table_args = bind_table_args(&mut scalar_binder, params, named_params).await?;
 
-                let func_name = normalize_identifier(name, &self.name_resolution_ctx);
-
                 if func_name.name.eq_ignore_ascii_case("result_scan") {
                     let query_id = parse_result_scan_args(&table_args)?;
                     if query_id.is_empty() {
@@ -423,76 +595,58 @@ impl Binder {
                     return Ok((s_expr, bind_context));
                 }
 
-                if BUILTIN_FUNCTIONS
-                    .get_property(&func_name.name)
-                    .map(|p| p.kind == FunctionKind::SRF)
-                    .unwrap_or(false)
-                {
-                    // If it is a set-returning function, we bind it as a subquery.
-                    let mut bind_context = BindContext::new();
-                    let stmt = SelectStmt {
-                        span: *span,
-                        hints: None,
-                        distinct: false,
-                        select_list: vec![SelectTarget::AliasedExpr {
-                            expr: Box::new(common_ast::ast::Expr::FunctionCall {
-                                span: *span,
-                                distinct: false,
-                                name: common_ast::ast::Identifier {
-                                    span: *span,
-                                    name: func_name.name.clone(),
-                                    quote: None,
-                                },
-                                params: vec![],
-                                args: params.clone(),
-                                window: None,
-                            }),
-                            alias: None,
-                        }],
-                        from: vec![],
-                        selection: None,
-                        group_by: None,
-                        having: None,
-                        window_list: None,
-                    };
-                    self.bind_select_stmt(&mut bind_context, &stmt, &[], 0)
-                        .await
+                if func_name.name.eq_ignore_ascii_case("changes") {
+                    let query = changes_query(&parse_changes_args(&table_args)?);
+                    let tokens = tokenize_sql(&query)?;
+                    let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL)?;
+                    let mut new_bind_context =
+                        BindContext::with_parent(Box::new(bind_context.clone()));
+                    if let Statement::Query(query) = &stmt {
+                        let (s_expr, mut new_bind_context) =
+                            self.bind_query(&mut new_bind_context, query).await?;
+                        if let Some(alias) = alias {
+                            new_bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+                        }
+                        return Ok((s_expr, new_bind_context));
+                    }
+                    return Err(ErrorCode::Internal("Invalid CHANGES query").set_span(*span));
+                }
+
+                // Other table functions always reside is default catalog
+                let table_meta: Arc<dyn TableFunction> = self
+                    .catalogs
+                    .get_catalog(CATALOG_DEFAULT)?
+                    .get_table_function(&func_name.name, table_args)?;
+                let table = table_meta.as_table();
+                let table_alias_name = if let Some(table_alias) = alias {
+                    Some(normalize_identifier(&table_alias.name, &self.name_resolution_ctx).name)
                 } else {
-                    // Other table functions always reside is default catalog
-                    let table_meta: Arc<dyn TableFunction> = self
-                        .catalogs
-                        .get_catalog(CATALOG_DEFAULT)?
-                        .get_table_function(&func_name.name, table_args)?;
-                    let table = table_meta.as_table();
-                    let table_alias_name = if let Some(table_alias) = alias {
-                        Some(
-                            normalize_identifier(&table_alias.name, &self.name_resolution_ctx).name,
-                        )
-                    } else {
-                        None
-                    };
-                    let table_index = self.metadata.write().add_table(
-                        CATALOG_DEFAULT.to_string(),
-                        "system".to_string(),
-                        table.clone(),
-                        table_alias_name,
-                        false,
-                        false,
-                    );
+                    None
+                };
+                let table_index = self.metadata.write().add_table(
+                    CATALOG_DEFAULT.to_string(),
+                    "system".to_string(),
+                    table.clone(),
+                    table_alias_name,
+                    false,
+                    false,
+                );
 
-                    let (s_expr, mut bind_context) = self
-                        .bind_base_table(bind_context, "system", table_index)
-                        .await?;
-                    if let Some(alias) = alias {
-                        bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
-                    }
-                    Ok((s_expr, bind_context))
+                let (s_expr, mut bind_context) = self
+                    .bind_base_table(bind_context, "system", table_index)
+                    .await?;
+                if let Some(alias) = alias {
+                    bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
                 }
+                Ok((s_expr, bind_context))
             }
             TableReference::Subquery {
                 span: _,
+                lateral: _,
                 subquery,
                 alias,
+                pivot: _,
+                unpivot: _,
             } => {
                 // For subquery, we need use a new context to bind it.
                 let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
@@ -517,11 +671,25 @@ impl Binder {
                         _ => self.ctx.get_file_format(f).await?,
                     }
                 }
+                let tz = self.ctx.get_function_context()?.tz.tz;
                 let files_info = StageFilesInfo {
                     path,
                     pattern: options.pattern.clone(),
                     files: options.files.clone(),
+                    filter: StageFileFilter {
+                        last_modified_after: parse_last_modified(&options.last_modified_after, tz)?,
+                        last_modified_before: parse_last_modified(
+                            &options.last_modified_before,
+                            tz,
+                        )?,
+                        min_file_size: options.min_file_size,
+                        max_file_size: options.max_file_size,
+                    },
                 };
+                if options.file_format.is_none() {
+                    self.detect_stage_file_format(&mut stage_info, &files_info)
+                        .await?;
+                }
                 self.bind_stage_table(bind_context, stage_info, files_info, alias, None)
                     .await
             }
@@ -529,6 +697,26 @@ impl Binder {
         }
     }
 
+    /// Detects the format of the stage files from the content of the first file, if the
+    /// stage has the default file format and no format is given by the statement.
+    #[async_backtrace::framed]
+    pub(crate) async fn detect_stage_file_format(
+        &self,
+        stage_info: &mut StageInfo,
+        files_info: &StageFilesInfo,
+    ) -> Result<()> {
+        if !matches!(stage_info.file_format_params, FileFormatParams::Parquet(_)) {
+            return Ok(());
+        }
+        let operator = StageTable::get_op(stage_info)?;
+        // keep the stage format if there is no file, the error is reported later.
+        if let Some(file) = files_info.list(&operator, true, None).await?.pop() {
+            stage_info.file_format_params =
+                detect_file_format(&operator, &file.path, file.size).await?;
+        }
+        Ok(())
+    }
+
     #[async_backtrace::framed]
     pub(crate) async fn bind_stage_table(
         &mut self,
@@ -546,16 +734,19 @@ impl Binder {
                     .await?
             }
             FileFormatParams::NdJson(..) => {
-                let schema = Arc::new(TableSchema::new(vec![TableField::new(
+                let mut fields = vec![TableField::new(
                     "_$1", // TODO: this name should be in visible
                     TableDataType::Variant,
-                )]));
+                )];
+                fields.extend(StageMetadataColumn::table_fields());
+                let schema = Arc::new(TableSchema::new(fields));
                 let info = StageTableInfo {
                     schema,
                     stage_info,
                     files_info,
                     files_to_copy: None,
                     is_select: true,
+                    partition_by: vec![],
                 };
                 StageTable::try_create(info)?
             }
@@ -571,6 +762,7 @@ impl Binder {
                 for i in 1..(max_column_position + 1) {
                     fields.push(TableField::new(&format!("_${}", i), TableDataType::String));
                 }
+                fields.extend(StageMetadataColumn::table_fields());
 
                 let schema = Arc::new(TableSchema::new(fields));
                 let info = StageTableInfo {
@@ -579,12 +771,28 @@ impl Binder {
                     files_info,
                     files_to_copy: None,
                     is_select: true,
+                    partition_by: vec![],
+                };
+                StageTable::try_create(info)?
+            }
+            FileFormatParams::Avro(..) | FileFormatParams::Orc(..) => {
+                let operator = StageTable::get_op(&stage_info)?;
+                let first_file = files_info.first_file(&operator).await?;
+                let format = InputContext::get_input_format(&stage_info.file_format_params)?;
+                let schema = format.infer_schema(&first_file.path, &operator).await?;
+                let info = StageTableInfo {
+                    schema,
+                    stage_info,
+                    files_info,
+                    files_to_copy,
+                    is_select: true,
+                    partition_by: vec![],
                 };
                 StageTable::try_create(info)?
             }
             _ => {
                 return Err(ErrorCode::Unimplemented(
-                    "stage table function only support parquet/NDJson/CSV format for now",
+                    "stage table function only support parquet/NDJson/CSV/Avro/ORC format for now",
                 ));
             }
         };
@@ -665,6 +873,14 @@ impl Binder {
                     result_expr = join_expr;
                     result_ctx = ctx;
                 }
+                TableReference::TableFunction { lateral: true, .. }
+                | TableReference::Subquery { lateral: true, .. } => {
+                    let (join_expr, ctx) = self
+                        .bind_lateral_join(current_ctx, result_ctx, result_expr, join)
+                        .await?;
+                    result_expr = join_expr;
+                    result_ctx = ctx;
+                }
                 _ => {
                     let (right_expr, right_ctx) =
                         self.bind_single_table(current_ctx, &join.right).await?;
@@ -705,14 +921,24 @@ impl Binder {
             in_grouping: false,
             ctes_map: Box::new(DashMap::new()),
             view_info: None,
+            udf_calls: bind_context.udf_calls.clone(),
             srfs: Default::default(),
+            dict_gets: Default::default(),
+            udf_server_calls: Default::default(),
             expr_context: ExprContext::default(),
             planning_agg_index: false,
+            planning_materialized_view: false,
             window_definitions: DashMap::new(),
         };
-        let (s_expr, mut new_bind_context) = self
-            .bind_query(&mut new_bind_context, &cte_info.query)
-            .await?;
+        let (s_expr, mut new_bind_context) = if let Some(working_table) = &cte_info.working_table {
+            self.bind_working_table(&new_bind_context, working_table)?
+        } else if cte_info.recursive {
+            self.bind_recursive_cte(span, &mut new_bind_context, table_name, cte_info)
+                .await?
+        } else {
+            self.bind_query(&mut new_bind_context, &cte_info.query)
+                .await?
+        };
         let mut cols_alias = cte_info.columns_alias.clone();
         if let Some(alias) = alias {
             for (idx, col_alias) in alias.columns.iter().enumerate() {
@@ -915,6 +1141,111 @@ impl Binder {
 
         Ok(index_metas)
     }
+
+    /// Resolve the materialized views of `table` which are fresh enough to answer queries.
+    ///
+    /// A materialized view is fresh if it has consumed the current snapshot of its source
+    /// table, or it was refreshed within `materialized_view_max_staleness` seconds.
+    #[async_backtrace::framed]
+    async fn resolve_materialized_views(
+        &mut self,
+        bind_context: &BindContext,
+        tenant: &str,
+        catalog_name: &str,
+        database_name: &str,
+        table: &Arc<dyn Table>,
+    ) -> Result<Vec<MaterializedViewEntry>> {
+        let max_staleness = self
+            .ctx
+            .get_settings()
+            .get_materialized_view_max_staleness()?;
+        let table_id = table.get_id().to_string();
+        let snapshot_location = table
+            .options()
+            .get(OPT_KEY_SNAPSHOT_LOCATION)
+            .cloned()
+            .unwrap_or_default();
+        let now = Utc::now().timestamp() as u64;
+
+        let catalog = self.catalogs.get_catalog(catalog_name)?;
+        let mut materialized_views = vec![];
+        for view in catalog.list_tables(tenant, database_name).await? {
+            let options = view.options();
+            let query = match options.get(OPT_KEY_MATERIALIZED_VIEW_QUERY) {
+                Some(query) => query.clone(),
+                None => continue,
+            };
+            if options.get(OPT_KEY_MATERIALIZED_VIEW_SOURCE) != Some(&table_id) {
+                continue;
+            }
+
+            let up_to_date =
+                options.get(OPT_KEY_MATERIALIZED_VIEW_SNAPSHOT) == Some(&snapshot_location);
+            let refreshed_on = options
+                .get(OPT_KEY_MATERIALIZED_VIEW_REFRESHED_ON)
+                .and_then(|v| v.parse::<u64>().ok())
+                .unwrap_or_default();
+            let within_staleness =
+                max_staleness > 0 && now.saturating_sub(refreshed_on) <= max_staleness;
+            if !up_to_date && !within_staleness {
+                continue;
+            }
+
+            let tokens = tokenize_sql(&query)?;
+            let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL)?;
+            if let Statement::Query(query) = &stmt {
+                let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
+                new_bind_context.planning_materialized_view = true;
+                // The source table may have been altered since the view was created,
+                // such a view can not be used to answer queries.
+                if let Ok((s_expr, view_bind_context)) =
+                    self.bind_query(&mut new_bind_context, query).await
+                {
+                    materialized_views.push(MaterializedViewEntry {
+                        catalog: catalog_name.to_string(),
+                        database: database_name.to_string(),
+                        table: view,
+                        output_columns: view_bind_context
+                            .columns
+                            .iter()
+                            .map(|column| column.index)
+                            .collect(),
+                        plan: s_expr,
+                    });
+                }
+            }
+        }
+
+        Ok(materialized_views)
+    }
+}
+
+fn bind_table_sample(span: Span, sample: &TableSample) -> Result<SampleConfig> {
+    if !(sample.percent > 0.0 && sample.percent <= 100.0) {
+        return Err(ErrorCode::SemanticError(format!(
+            "SAMPLE probability must be in (0, 100], but got {}",
+            sample.percent
+        ))
+        .set_span(span));
+    }
+    let level = match sample.level {
+        common_ast::ast::SampleLevel::Row => SampleLevel::Row,
+        common_ast::ast::SampleLevel::Block => SampleLevel::Block,
+    };
+    Ok(SampleConfig {
+        level,
+        probability: F64::from(sample.percent / 100.0),
+    })
+}
+
+fn check_sample_on_table(span: Span, sample: &Option<SampleConfig>, kind: &str) -> Result<()> {
+    match sample {
+        Some(_) => Err(ErrorCode::SemanticError(format!(
+            "SAMPLE is only supported on tables, not on a {kind}"
+        ))
+        .set_span(span)),
+        None => Ok(()),
+    }
 }
 
 // copy from common-storages-fuse to avoid cyclic dependency.
@@ -931,3 +1262,87 @@ pub fn parse_result_scan_args(table_args: &TableArgs) -> Result<String> {
     let args = table_args.expect_all_positioned("RESULT_SCAN", Some(1))?;
     string_value(&args[0])
 }
+
+struct ChangesArgs {
+    database: String,
+    table: String,
+    from_snapshot_id: String,
+    to_snapshot_id: Option<String>,
+}
+
+fn parse_changes_args(table_args: &TableArgs) -> Result<ChangesArgs> {
+    let args = table_args.expect_all_positioned("CHANGES", None)?;
+    if args.len() != 3 && args.len() != 4 {
+        return Err(ErrorCode::BadArguments(format!(
+            "expecting <database>, <table_name>, <from_snapshot_id> and optional <to_snapshot_id> (as string literals), but got {:?}",
+            args
+        )));
+    }
+
+    Ok(ChangesArgs {
+        database: string_value(&args[0])?,
+        table: string_value(&args[1])?,
+        from_snapshot_id: string_value(&args[2])?,
+        to_snapshot_id: args.get(3).map(string_value).transpose()?,
+    })
+}
+
+/// Rewrites `CHANGES` into a query over the two snapshots of the fuse table.
+///
+/// Blocks are immutable, so the blocks only referenced by the newer snapshot hold the
+/// inserted rows, and the blocks only referenced by the older snapshot hold the deleted
+/// rows. The block lists are taken from the snapshot metadata by `fuse_block`.
+///
+/// A row updated in between is reported as a `DELETE` of the old row and an `INSERT` of
+/// the new one, so are the untouched rows of a rewritten block.
+fn changes_query(args: &ChangesArgs) -> String {
+    let table = format!(
+        "{}.{}",
+        quote_ident(&args.database, '"', true),
+        quote_ident(&args.table, '"', true)
+    );
+    let literal = |s: &str| Literal::String(s.to_string()).to_string();
+    let from_point = format!(" AT (SNAPSHOT => {})", literal(&args.from_snapshot_id));
+    let to_point = args
+        .to_snapshot_id
+        .as_ref()
+        .map(|id| format!(" AT (SNAPSHOT => {})", literal(id)))
+        .unwrap_or_default();
+    let blocks_of = |snapshot_id: Option<&String>| {
+        let mut func_args = vec![literal(&args.database), literal(&args.table)];
+        func_args.extend(snapshot_id.map(|id| literal(id)));
+        format!(
+            "SELECT block_location FROM fuse_block({})",
+            func_args.join(", ")
+        )
+    };
+    let select = |action: &str, point: &str, other_blocks: String| {
+        format!(
+            "SELECT *, '{action}' AS \"change$action\", \
+             concat(_block_name, '#', to_string(_row_id % 4294967296)) AS \"change$row_id\" \
+             FROM {table}{point} WHERE _block_name NOT IN ({other_blocks})"
+        )
+    };
+
+    format!(
+        "{} UNION ALL {}",
+        select("INSERT", &to_point, blocks_of(Some(&args.from_snapshot_id))),
+        select(
+            "DELETE",
+            &from_point,
+            blocks_of(args.to_snapshot_id.as_ref())
+        ),
+    )
+}
+
+fn parse_last_modified(value: &Option<String>, tz: Tz) -> Result<Option<DateTime<Utc>>> {
+    match value {
+        Some(v) => match string_to_timestamp(v, tz) {
+            Some(ts) => Ok(Some(ts.with_timezone(&Utc))),
+            None => Err(ErrorCode::BadArguments(format!(
+                "invalid last modified time: '{v}'"
+            ))),
+        },
+        None => Ok(None),
+    }
+}
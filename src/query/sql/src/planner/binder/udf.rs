@@ -0,0 +1,439 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_ast::ast::Expr;
+use common_ast::ast::Identifier;
+use common_ast::ast::Statement;
+use common_ast::ast::TableAlias;
+use common_ast::ast::UDFArgument;
+use common_ast::ast::UDFDefinition;
+use common_ast::parser::parse_sql;
+use common_ast::parser::tokenize_sql;
+use common_ast::walk_expr_mut;
+use common_ast::VisitorMut;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::Span;
+use common_expression::type_check;
+use common_expression::types::DataType;
+use common_expression::RawExpr;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::principal::UDFServer;
+use common_meta_app::principal::UDFWasmModule;
+use common_meta_app::principal::UserDefinedFunction;
+use common_users::UserApiProvider;
+
+use crate::normalize_identifier;
+use crate::optimizer::SExpr;
+use crate::planner::semantic::parse_type_name;
+use crate::planner::semantic::resolve_type_name;
+use crate::planner::udf_validator::UDFValidator;
+use crate::plans::BoundColumnRef;
+use crate::plans::CastExpr;
+use crate::plans::EvalScalar;
+use crate::plans::ScalarItem;
+use crate::BindContext;
+use crate::Binder;
+use crate::ColumnBinding;
+use crate::ScalarExpr;
+use crate::Visibility;
+
+impl Binder {
+    pub(in crate::planner::binder) fn bind_udf_definition(
+        &self,
+        udf_name: &Identifier,
+        definition: &UDFDefinition,
+        description: &Option<String>,
+    ) -> Result<UserDefinedFunction> {
+        let name = udf_name.to_string();
+        let description = description.clone().unwrap_or_default();
+        match definition {
+            UDFDefinition::LambdaUDF {
+                parameters,
+                definition,
+            } => {
+                let mut validator = UDFValidator {
+                    name,
+                    parameters: parameters.iter().map(|v| v.to_string()).collect(),
+                    ..Default::default()
+                };
+                validator.verify_definition_expr(definition)?;
+                Ok(UserDefinedFunction::new(
+                    &validator.name,
+                    validator.parameters,
+                    &definition.to_string(),
+                    &description,
+                ))
+            }
+            UDFDefinition::ScalarUDF {
+                arguments,
+                return_type,
+                definition,
+            } => {
+                let arguments = Self::check_udf_arguments(arguments)?;
+                resolve_type_name(return_type)?;
+                let mut validator = UDFValidator {
+                    name,
+                    parameters: arguments.iter().map(|(name, _)| name.clone()).collect(),
+                    ..Default::default()
+                };
+                validator.verify_definition_expr(definition)?;
+                Ok(UserDefinedFunction::create_sql_udf(
+                    &validator.name,
+                    arguments,
+                    &return_type.to_string(),
+                    &definition.to_string(),
+                    &description,
+                ))
+            }
+            UDFDefinition::TableUDF {
+                arguments,
+                return_columns,
+                definition,
+            } => {
+                let arguments = Self::check_udf_arguments(arguments)?;
+                let return_columns = Self::check_udf_arguments(return_columns)?;
+                let mut validator = UDFValidator {
+                    name,
+                    ..Default::default()
+                };
+                validator.verify_definition_query(definition)?;
+                Ok(UserDefinedFunction::create_table_udf(
+                    &validator.name,
+                    arguments,
+                    return_columns,
+                    &definition.to_string(),
+                    &description,
+                ))
+            }
+            UDFDefinition::UDFServer {
+                arg_types,
+                return_type,
+                address,
+                handler,
+                language,
+            } => {
+                let arg_types = arg_types
+                    .iter()
+                    .map(|arg_type| {
+                        resolve_type_name(arg_type)?;
+                        Ok(arg_type.to_string())
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                resolve_type_name(return_type)?;
+                Self::check_udf_server_address(address)?;
+                Ok(UserDefinedFunction::create_udf_server(
+                    &name,
+                    arg_types,
+                    &return_type.to_string(),
+                    UDFServer {
+                        address: address.clone(),
+                        handler: handler.clone(),
+                        language: language.to_lowercase(),
+                    },
+                    &description,
+                ))
+            }
+            UDFDefinition::UDAFServer {
+                arg_types,
+                state_fields,
+                return_type,
+                address,
+                handler,
+                language,
+            } => {
+                let arg_types = arg_types
+                    .iter()
+                    .map(|arg_type| {
+                        resolve_type_name(arg_type)?;
+                        Ok(arg_type.to_string())
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let state_fields = Self::check_udf_arguments(state_fields)?;
+                resolve_type_name(return_type)?;
+                Self::check_udf_server_address(address)?;
+                Ok(UserDefinedFunction::create_udaf_server(
+                    &name,
+                    arg_types,
+                    state_fields,
+                    &return_type.to_string(),
+                    UDFServer {
+                        address: address.clone(),
+                        handler: handler.clone(),
+                        language: language.to_lowercase(),
+                    },
+                    &description,
+                ))
+            }
+            UDFDefinition::WasmUDF {
+                arg_types,
+                return_type,
+                handler,
+                location,
+            } => {
+                let arg_types = arg_types
+                    .iter()
+                    .map(|arg_type| {
+                        resolve_type_name(arg_type)?;
+                        Ok(arg_type.to_string())
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                resolve_type_name(return_type)?;
+                if !location.starts_with('@') {
+                    return Err(ErrorCode::InvalidArgument(format!(
+                        "Invalid location of WebAssembly module: {location}, it must be a stage location like @<stage>/<path>"
+                    )));
+                }
+                Ok(UserDefinedFunction::create_wasm_udf(
+                    &name,
+                    arg_types,
+                    &return_type.to_string(),
+                    UDFWasmModule {
+                        location: location.clone(),
+                        handler: handler.clone(),
+                    },
+                    &description,
+                ))
+            }
+        }
+    }
+
+    fn check_udf_server_address(address: &str) -> Result<()> {
+        if !["grpc://", "http://", "https://"]
+            .iter()
+            .any(|scheme| address.starts_with(scheme))
+        {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Invalid address of UDF server: {address}, it must start with grpc://, http:// or https://"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Checks the names and the types of the arguments of a SQL UDF, and
+    /// returns them as pairs of name and SQL type.
+    fn check_udf_arguments(arguments: &[UDFArgument]) -> Result<Vec<(String, String)>> {
+        let mut names = HashSet::with_capacity(arguments.len());
+        arguments
+            .iter()
+            .map(|argument| {
+                let name = argument.name.to_string();
+                if !names.insert(name.clone()) {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "Duplicated argument name: {name}"
+                    ))
+                    .set_span(argument.name.span));
+                }
+                resolve_type_name(&argument.data_type)?;
+                Ok((name, argument.data_type.to_string()))
+            })
+            .collect()
+    }
+
+    /// Returns the table UDF named `func_name`, if it's not the name of a
+    /// built-in table function.
+    pub(in crate::planner::binder) async fn get_table_udf(
+        &self,
+        func_name: &str,
+    ) -> Result<Option<UserDefinedFunction>> {
+        if func_name.eq_ignore_ascii_case("result_scan")
+            || func_name.eq_ignore_ascii_case("changes")
+            || self
+                .catalogs
+                .get_catalog(CATALOG_DEFAULT)?
+                .list_table_functions()
+                .iter()
+                .any(|name| name == func_name)
+        {
+            return Ok(None);
+        }
+        let tenant = self.ctx.get_tenant();
+        match UserApiProvider::instance()
+            .get_udf(&tenant, func_name)
+            .await
+        {
+            Ok(udf) if udf.is_table_function() => Ok(Some(udf)),
+            _ => Ok(None),
+        }
+    }
+
+    /// Binds a call of a table UDF by inlining its definition as a subquery,
+    /// with the parameters replaced by the arguments.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_table_udf(
+        &mut self,
+        bind_context: &BindContext,
+        span: Span,
+        udf: UserDefinedFunction,
+        arguments: &[Expr],
+        alias: &Option<TableAlias>,
+    ) -> Result<(SExpr, BindContext)> {
+        if bind_context.udf_calls.iter().any(|name| name == &udf.name) {
+            return Err(ErrorCode::SemanticError(format!(
+                "Recursive call of function {} is not allowed",
+                udf.name
+            ))
+            .set_span(span));
+        }
+        if udf.parameters.len() != arguments.len() {
+            return Err(ErrorCode::SyntaxException(format!(
+                "Require {} parameters, but got: {}",
+                udf.parameters.len(),
+                arguments.len()
+            ))
+            .set_span(span));
+        }
+
+        let mut replacer = UDFArgumentReplacer {
+            arguments: HashMap::with_capacity(arguments.len()),
+        };
+        for ((parameter, arg_type), argument) in udf
+            .parameters
+            .iter()
+            .zip(udf.arg_types.iter())
+            .zip(arguments.iter())
+        {
+            let argument = Expr::Cast {
+                span,
+                expr: Box::new(argument.clone()),
+                target_type: parse_type_name(arg_type)?.wrap_nullable(),
+                pg_style: false,
+            };
+            replacer.arguments.insert(parameter.clone(), argument);
+        }
+        let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+        let tokens = tokenize_sql(&udf.definition)?;
+        let (stmt, _) = parse_sql(&tokens, sql_dialect)?;
+        let mut query = match stmt {
+            Statement::Query(query) => query,
+            _ => {
+                return Err(ErrorCode::Internal(format!(
+                    "Invalid definition of table function {}",
+                    udf.name
+                ))
+                .set_span(span));
+            }
+        };
+        replacer.visit_query(&mut query);
+
+        let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        new_bind_context.udf_calls.push(udf.name.clone());
+        let (s_expr, query_context) = self
+            .bind_query(&mut new_bind_context, &query)
+            .await
+            .map_err(|e| e.set_span(span))?;
+
+        let columns = query_context
+            .columns
+            .iter()
+            .filter(|column| column.visibility == Visibility::Visible)
+            .collect::<Vec<_>>();
+        let return_columns = udf.return_columns.unwrap_or_default();
+        if columns.len() != return_columns.len() {
+            return Err(ErrorCode::SemanticError(format!(
+                "Table function {} returns {} columns, but its definition returns {} columns",
+                udf.name,
+                return_columns.len(),
+                columns.len()
+            ))
+            .set_span(span));
+        }
+
+        // Cast the columns of the definition to the declared return columns.
+        let table_name = match alias {
+            Some(alias) => normalize_identifier(&alias.name, &self.name_resolution_ctx).name,
+            None => udf.name.clone(),
+        };
+        let mut output_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        let mut items = Vec::with_capacity(return_columns.len());
+        for (column, (column_name, column_type)) in
+            columns.into_iter().zip(return_columns.into_iter())
+        {
+            let target_type = parse_type_name(&column_type)?.wrap_nullable();
+            let target_type = DataType::from(&resolve_type_name(&target_type)?);
+            let argument = ScalarExpr::BoundColumnRef(BoundColumnRef {
+                span,
+                column: column.clone(),
+            });
+            type_check::check(
+                &RawExpr::Cast {
+                    span,
+                    is_try: false,
+                    expr: Box::new(argument.as_raw_expr()),
+                    dest_type: target_type.clone(),
+                },
+                &BUILTIN_FUNCTIONS,
+            )?;
+            let scalar = ScalarExpr::CastExpr(CastExpr {
+                span,
+                is_try: false,
+                argument: Box::new(argument),
+                target_type: Box::new(target_type.clone()),
+            });
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(column_name.clone(), target_type.clone());
+            output_context.add_column_binding(ColumnBinding {
+                database_name: None,
+                table_name: Some(table_name.clone()),
+                column_position: None,
+                table_index: None,
+                column_name,
+                index,
+                data_type: Box::new(target_type),
+                visibility: Visibility::Visible,
+                virtual_computed_expr: None,
+            });
+            items.push(ScalarItem { scalar, index });
+        }
+        if let Some(alias) = alias {
+            output_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+        }
+
+        Ok((
+            SExpr::create_unary(Arc::new(EvalScalar { items }.into()), Arc::new(s_expr)),
+            output_context,
+        ))
+    }
+}
+
+/// Replaces the references to the parameters of a table UDF with the
+/// arguments of the call.
+struct UDFArgumentReplacer {
+    arguments: HashMap<String, Expr>,
+}
+
+impl VisitorMut for UDFArgumentReplacer {
+    fn visit_expr(&mut self, expr: &mut Expr) {
+        if let Expr::ColumnRef {
+            database: None,
+            table: None,
+            column,
+            ..
+        } = expr
+        {
+            if let Some(argument) = self.arguments.get(&column.name().to_string()) {
+                *expr = argument.clone();
+                return;
+            }
+        }
+        walk_expr_mut(self, expr);
+    }
+}
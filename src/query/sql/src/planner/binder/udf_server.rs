@@ -0,0 +1,257 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_ast::ast::Expr;
+use common_ast::ast::Identifier;
+use common_ast::ast::Lambda;
+use common_ast::ast::Literal;
+use common_ast::ast::Query;
+use common_ast::ast::SubqueryModifier;
+use common_ast::ast::Window;
+use common_ast::Visitor;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::Span;
+use common_expression::types::DataType;
+use common_functions::is_builtin_function;
+use common_users::UserApiProvider;
+
+use crate::binder::ExprContext;
+use crate::normalize_identifier;
+use crate::optimizer::ColumnSet;
+use crate::optimizer::SExpr;
+use crate::planner::semantic::resolve_type_name_by_str;
+use crate::plans::BoundColumnRef;
+use crate::plans::CastExpr;
+use crate::plans::Udf;
+use crate::plans::UdfItem;
+use crate::BindContext;
+use crate::Binder;
+use crate::ColumnBinding;
+use crate::ScalarBinder;
+use crate::ScalarExpr;
+use crate::TypeChecker;
+use crate::Visibility;
+
+/// Collects the calls of the select list that may be external UDF calls,
+/// the arguments of a call are collected before the call.
+pub struct UdfServerCallCollector {
+    calls: Vec<Expr>,
+}
+
+impl<'a> Visitor<'a> for UdfServerCallCollector {
+    fn visit_function_call(
+        &mut self,
+        span: Span,
+        distinct: bool,
+        name: &'a Identifier,
+        args: &'a [Expr],
+        params: &'a [Literal],
+        over: &'a Option<Window>,
+        lambda: &'a Option<Lambda>,
+    ) {
+        for arg in args.iter() {
+            self.visit_expr(arg);
+        }
+        let func_name = name.name.to_lowercase();
+        if over.is_none()
+            && lambda.is_none()
+            && func_name != "dict_get"
+            && !is_builtin_function(&func_name)
+            && !TypeChecker::all_rewritable_scalar_function().contains(&func_name.as_str())
+        {
+            self.calls.push(Expr::FunctionCall {
+                span,
+                distinct,
+                name: name.clone(),
+                args: args.to_vec(),
+                params: params.to_vec(),
+                window: over.clone(),
+                lambda: lambda.clone(),
+            });
+        }
+    }
+
+    fn visit_subquery(
+        &mut self,
+        _span: Span,
+        _modifier: &'a Option<SubqueryModifier>,
+        _subquery: &'a Query,
+    ) {
+        // The calls of a subquery are bound with the subquery.
+    }
+
+    fn visit_query(&mut self, _query: &'a Query) {}
+}
+
+impl UdfServerCallCollector {
+    pub fn new() -> Self {
+        UdfServerCallCollector { calls: vec![] }
+    }
+
+    pub fn visit(&mut self, expr: &Expr) {
+        self.visit_expr(expr);
+    }
+
+    pub fn into_calls(self) -> Vec<Expr> {
+        self.calls
+    }
+}
+
+impl Binder {
+    /// Bind the calls of external UDFs and WebAssembly UDFs to `Udf` operators
+    /// on top of `s_expr`.
+    /// The other calls are left to the type checker.
+    ///
+    /// A call taking the result of another call as argument is evaluated by
+    /// a `Udf` operator above the one of the inner call.
+    #[async_backtrace::framed]
+    pub async fn bind_udf_server_calls(
+        &mut self,
+        bind_context: &mut BindContext,
+        calls: &[Expr],
+        mut s_expr: SExpr,
+    ) -> Result<SExpr> {
+        let tenant = self.ctx.get_tenant();
+        let mut items: Vec<UdfItem> = vec![];
+        let mut outputs = ColumnSet::new();
+        for call in calls {
+            let display_name = call.to_string();
+            if bind_context.udf_server_calls.contains_key(&display_name) {
+                continue;
+            }
+            let (name, args) = match call {
+                Expr::FunctionCall { name, args, .. } => (name, args),
+                // Should have been checked by UdfServerCallCollector
+                _ => unreachable!(),
+            };
+            let func_name = normalize_identifier(name, &self.name_resolution_ctx).to_string();
+            let udf = match UserApiProvider::instance()
+                .get_udf(&tenant, &func_name)
+                .await
+            {
+                Ok(udf) => udf,
+                Err(_) => continue,
+            };
+            // Aggregate UDFs are resolved by the type checker.
+            let is_aggregate = udf.is_aggregate_function();
+            let (handler, server_addr, wasm_module) = match (udf.udf_server, udf.wasm_module) {
+                (Some(udf_server), _) if !is_aggregate => {
+                    (udf_server.handler, udf_server.address, None)
+                }
+                (_, Some(wasm_module)) => (
+                    wasm_module.handler,
+                    String::new(),
+                    Some(wasm_module.location),
+                ),
+                _ => continue,
+            };
+            if udf.arg_types.len() != args.len() {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "Require {} parameters, but got: {}",
+                    udf.arg_types.len(),
+                    args.len()
+                ))
+                .set_span(call.span()));
+            }
+
+            let mut arguments = Vec::with_capacity(args.len());
+            for (arg, arg_type) in args.iter().zip(udf.arg_types.iter()) {
+                let original_context = bind_context.expr_context.clone();
+                bind_context.set_expr_context(ExprContext::SelectClause);
+                let mut scalar_binder = ScalarBinder::new(
+                    bind_context,
+                    self.ctx.clone(),
+                    &self.name_resolution_ctx,
+                    self.metadata.clone(),
+                    &[],
+                );
+                let (argument, _) = scalar_binder.bind(arg).await?;
+                bind_context.set_expr_context(original_context);
+                let target_type = resolve_type_name_by_str(arg_type)?;
+                arguments.push(ScalarExpr::CastExpr(CastExpr {
+                    span: argument.span(),
+                    is_try: false,
+                    argument: Box::new(argument),
+                    target_type: Box::new(DataType::from(&target_type).wrap_nullable()),
+                }));
+            }
+
+            // The arguments use the results of calls in `items`, which must be
+            // evaluated first.
+            if arguments
+                .iter()
+                .any(|argument| !argument.used_columns().is_disjoint(&outputs))
+            {
+                s_expr = SExpr::create_unary(
+                    Arc::new(
+                        Udf {
+                            items: std::mem::take(&mut items),
+                        }
+                        .into(),
+                    ),
+                    Arc::new(s_expr),
+                );
+                outputs.clear();
+            }
+
+            let return_type = udf.return_type.unwrap_or_default();
+            let data_type =
+                DataType::from(&resolve_type_name_by_str(&return_type)?).wrap_nullable();
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(display_name.clone(), data_type.clone());
+            let column = ColumnBinding {
+                database_name: None,
+                table_name: None,
+                column_position: None,
+                table_index: None,
+                column_name: display_name.clone(),
+                index,
+                data_type: Box::new(data_type.clone()),
+                visibility: Visibility::InVisible,
+                virtual_computed_expr: None,
+            };
+            items.push(UdfItem {
+                func_name: handler,
+                display_name: display_name.clone(),
+                server_addr,
+                wasm_module,
+                arguments,
+                data_type,
+                index,
+            });
+            outputs.insert(index);
+
+            // Add the call to bind context, so we can replace the calls later.
+            bind_context.udf_server_calls.insert(
+                display_name,
+                ScalarExpr::BoundColumnRef(BoundColumnRef {
+                    span: call.span(),
+                    column,
+                }),
+            );
+        }
+
+        if items.is_empty() {
+            return Ok(s_expr);
+        }
+        Ok(SExpr::create_unary(
+            Arc::new(Udf { items }.into()),
+            Arc::new(s_expr),
+        ))
+    }
+}
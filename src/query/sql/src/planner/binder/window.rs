@@ -28,6 +28,7 @@ use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::FunctionCall;
 use crate::plans::LagLeadFunction;
+use crate::plans::LambdaFunc;
 use crate::plans::NthValueFunction;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
@@ -246,6 +247,21 @@ impl<'a> WindowRewriter<'a> {
                 }
                 .into())
             }
+            ScalarExpr::LambdaFunction(lambda_func) => {
+                let new_args = lambda_func
+                    .args
+                    .iter()
+                    .map(|arg| self.visit(arg))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(LambdaFunc {
+                    span: lambda_func.span,
+                    func_name: lambda_func.func_name.clone(),
+                    args: new_args,
+                    lambda_expr: lambda_func.lambda_expr.clone(),
+                    lambda_display: lambda_func.lambda_display.clone(),
+                }
+                .into())
+            }
             ScalarExpr::CastExpr(cast) => Ok(CastExpr {
                 span: cast.span,
                 is_try: cast.is_try,
@@ -297,6 +313,7 @@ impl<'a> WindowRewriter<'a> {
                         params: agg_func.params.clone(),
                         args: new_args,
                         return_type: agg_func.return_type.clone(),
+                        udaf: agg_func.udaf.clone(),
                         display_name: agg_func.display_name.clone(),
                     }
                     .into())
@@ -341,6 +358,7 @@ impl<'a> WindowRewriter<'a> {
                     params: agg.params.clone(),
                     args: replaced_args,
                     return_type: agg.return_type.clone(),
+                    udaf: agg.udaf.clone(),
                 })
             }
             WindowFuncType::LagLead(ll) => {
@@ -67,6 +67,7 @@ impl Dataframe {
             travel_point: None,
             pivot: None,
             unpivot: None,
+            sample: None,
         };
 
         let settings = query_ctx.get_settings();
@@ -406,6 +407,7 @@ impl Dataframe {
                 travel_point: None,
                 pivot: None,
                 unpivot: None,
+                sample: None,
             };
             table_ref.push(table);
         }
@@ -476,7 +478,6 @@ impl Dataframe {
             bind_context: Box::new(self.bind_context),
             rewrite_kind: None,
             ignore_result: false,
-            formatted_ast: None,
         };
         let opt_ctx = Arc::new(OptimizerContext::new(OptimizerConfig {
             enable_distributed_optimization,
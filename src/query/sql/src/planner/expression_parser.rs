@@ -15,7 +15,9 @@
 use std::sync::Arc;
 
 use common_ast::ast::Expr as AExpr;
+use common_ast::ast::Literal;
 use common_ast::parser::parse_comma_separated_exprs;
+use common_ast::parser::parse_expr;
 use common_ast::parser::tokenize_sql;
 use common_ast::walk_expr_mut;
 use common_ast::Dialect;
@@ -220,6 +222,26 @@ pub fn parse_default_expr_to_string(
     ast: &AExpr,
     is_add_column: bool,
 ) -> Result<String> {
+    if let Some(sequence) = sequence_of_default_expr(ast) {
+        // The existing rows can't take their values from the sequence.
+        if is_add_column {
+            return Err(ErrorCode::SemanticError(format!(
+                "default expression `{}` is not a valid constant. Please provide a valid constant expression as the default value.",
+                ast
+            )));
+        }
+        if !DataType::from(field.data_type())
+            .remove_nullable()
+            .is_integer()
+        {
+            return Err(ErrorCode::SemanticError(format!(
+                "column `{}` takes its values from a sequence, it must be an integer column",
+                field.name()
+            )));
+        }
+        return Ok(sequence_default_expr(&sequence));
+    }
+
     let settings = Settings::create("".to_string());
     let mut bind_context = BindContext::new();
     let metadata = Metadata::default();
@@ -349,10 +371,55 @@ impl Table for DummyTable {
     }
 }
 
+/// The default expression of a column that takes its values from a sequence.
+pub fn sequence_default_expr(sequence: &str) -> String {
+    format!("nextval({})", Literal::String(sequence.to_string()))
+}
+
+/// Returns the name of the sequence if the default expression is `nextval('<sequence>')`.
+///
+/// The values of such a column are allocated from the sequence by the insert
+/// pipeline, the expression itself is never evaluated.
+pub fn parse_sequence_default_expr(default_expr: &str) -> Option<String> {
+    let tokens = tokenize_sql(default_expr).ok()?;
+    let ast = parse_expr(&tokens, Dialect::PostgreSQL).ok()?;
+    sequence_of_default_expr(&ast)
+}
+
+fn sequence_of_default_expr(ast: &AExpr) -> Option<String> {
+    match ast {
+        AExpr::FunctionCall {
+            distinct: false,
+            name,
+            args,
+            params,
+            window: None,
+            lambda: None,
+            ..
+        } if name.name.eq_ignore_ascii_case("nextval") && params.is_empty() => match &args[..] {
+            [
+                AExpr::Literal {
+                    lit: Literal::String(sequence),
+                    ..
+                },
+            ] => Some(sequence.clone()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 pub fn field_default_value(ctx: Arc<dyn TableContext>, field: &TableField) -> Result<Scalar> {
     let data_type = field.data_type();
     let data_type = DataType::from(data_type);
 
+    // The column always has values in the blocks, as it can't be added to an existing table.
+    if let Some(default_expr) = field.default_expr() {
+        if parse_sequence_default_expr(default_expr).is_some() {
+            return Ok(Scalar::default_value(&data_type));
+        }
+    }
+
     match field.default_expr() {
         Some(default_expr) => {
             let table: Arc<dyn Table> = Arc::new(DummyTable::default());
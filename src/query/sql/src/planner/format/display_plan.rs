@@ -64,6 +64,8 @@ impl Plan {
             Plan::CreateDatabase(create_database) => Ok(format!("{:?}", create_database)),
             Plan::DropDatabase(drop_database) => Ok(format!("{:?}", drop_database)),
             Plan::UndropDatabase(undrop_database) => Ok(format!("{:?}", undrop_database)),
+            Plan::BackupDatabase(backup_database) => Ok(format!("{:?}", backup_database)),
+            Plan::RestoreDatabase(restore_database) => Ok(format!("{:?}", restore_database)),
             Plan::RenameDatabase(rename_database) => Ok(format!("{:?}", rename_database)),
 
             // Tables
@@ -74,10 +76,15 @@ impl Plan {
             Plan::DescribeTable(describe_table) => Ok(format!("{:?}", describe_table)),
             Plan::RenameTable(rename_table) => Ok(format!("{:?}", rename_table)),
             Plan::SetOptions(set_options) => Ok(format!("{:?}", set_options)),
+            Plan::AddTableRowAccessPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DropTableRowAccessPolicy(p) => Ok(format!("{:?}", p)),
             Plan::AddTableColumn(add_table_column) => Ok(format!("{:?}", add_table_column)),
             Plan::ModifyTableColumn(modify_table_column) => {
                 Ok(format!("{:?}", modify_table_column))
             }
+            Plan::RenameTableColumn(rename_table_column) => {
+                Ok(format!("{:?}", rename_table_column))
+            }
             Plan::DropTableColumn(drop_table_column) => Ok(format!("{:?}", drop_table_column)),
             Plan::AlterTableClusterKey(alter_table_cluster_key) => {
                 Ok(format!("{:?}", alter_table_cluster_key))
@@ -97,6 +104,10 @@ impl Plan {
             Plan::AlterView(alter_view) => Ok(format!("{:?}", alter_view)),
             Plan::DropView(drop_view) => Ok(format!("{:?}", drop_view)),
 
+            // Materialized views
+            Plan::CreateMaterializedView(create_view) => Ok(format!("{:?}", create_view)),
+            Plan::RefreshMaterializedView(refresh_view) => Ok(format!("{:?}", refresh_view)),
+
             // Indexes
             Plan::CreateIndex(index) => Ok(format!("{:?}", index)),
             Plan::DropIndex(index) => Ok(format!("{:?}", index)),
@@ -118,9 +129,13 @@ impl Plan {
             // Insert
             Plan::Insert(insert) => Ok(format!("{:?}", insert)),
             Plan::Replace(replace) => Ok(format!("{:?}", replace)),
+            Plan::MergeInto(merge_into) => Ok(format!("{:?}", merge_into)),
             Plan::Delete(delete) => format_delete(delete),
             Plan::Update(update) => Ok(format!("{:?}", update)),
 
+            // Transactions
+            Plan::Begin | Plan::Commit | Plan::Abort => Ok(format!("{:?}", self)),
+
             // Stages
             Plan::CreateStage(create_stage) => Ok(format!("{:?}", create_stage)),
             Plan::DropStage(s) => Ok(format!("{:?}", s)),
@@ -142,9 +157,26 @@ impl Plan {
             Plan::CreateUDF(create_user_udf) => Ok(format!("{:?}", create_user_udf)),
             Plan::AlterUDF(alter_user_udf) => Ok(format!("{alter_user_udf:?}")),
             Plan::DropUDF(drop_udf) => Ok(format!("{drop_udf:?}")),
+            Plan::CreateRowAccessPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DropRowAccessPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::CreateNetworkPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DropNetworkPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::CreateDictionary(p) => Ok(format!("{:?}", p)),
+            Plan::DropDictionary(p) => Ok(format!("{:?}", p)),
+            Plan::CreateSequence(p) => Ok(format!("{:?}", p)),
+            Plan::DropSequence(p) => Ok(format!("{:?}", p)),
+            Plan::CreateTask(p) => Ok(format!("{:?}", p)),
+            Plan::DropTask(p) => Ok(format!("{:?}", p)),
+            Plan::AlterTask(p) => Ok(format!("{:?}", p)),
+            Plan::ExecuteTask(p) => Ok(format!("{:?}", p)),
+            Plan::CreateNotificationIntegration(p) => Ok(format!("{:?}", p)),
+            Plan::DropNotificationIntegration(p) => Ok(format!("{:?}", p)),
+            Plan::CreatePipe(p) => Ok(format!("{:?}", p)),
+            Plan::DropPipe(p) => Ok(format!("{:?}", p)),
             Plan::AlterUser(alter_user) => Ok(format!("{:?}", alter_user)),
             Plan::CreateRole(create_role) => Ok(format!("{:?}", create_role)),
             Plan::DropRole(drop_role) => Ok(format!("{:?}", drop_role)),
+            Plan::AlterRole(alter_role) => Ok(format!("{:?}", alter_role)),
 
             Plan::Presign(presign) => Ok(format!("{:?}", presign)),
 
@@ -153,6 +185,7 @@ impl Plan {
             Plan::SetRole(p) => Ok(format!("{:?}", p)),
             Plan::UseDatabase(p) => Ok(format!("{:?}", p)),
             Plan::Kill(p) => Ok(format!("{:?}", p)),
+            Plan::DrainClusterNode(p) => Ok(format!("{:?}", p)),
 
             Plan::CreateShareEndpoint(p) => Ok(format!("{:?}", p)),
             Plan::ShowShareEndpoint(p) => Ok(format!("{:?}", p)),
@@ -219,6 +252,8 @@ fn format_delete(delete: &DeletePlan) -> Result<String> {
             order_by: None,
             prewhere: None,
             agg_index: None,
+            sample: None,
+            vector_topn: None,
             statistics: Default::default(),
         });
         let scan_expr = SExpr::create_leaf(Arc::new(scan));
@@ -76,6 +76,10 @@ impl Display for FormatContext {
                 RelOperator::RuntimeFilterSource(_) => write!(f, "RuntimeFilterSource"),
                 RelOperator::Window(_) => write!(f, "WindowFunc"),
                 RelOperator::ProjectSet(_) => write!(f, "ProjectSet"),
+                RelOperator::DictGet(_) => write!(f, "DictGet"),
+                RelOperator::Udf(_) => write!(f, "Udf"),
+                RelOperator::RecursiveCte(_) => write!(f, "RecursiveCte"),
+                RelOperator::RecursiveCteScan(_) => write!(f, "RecursiveCteScan"),
             },
             Self::Text(text) => write!(f, "{}", text),
         }
@@ -111,6 +115,15 @@ pub fn format_scalar(scalar: &ScalarExpr) -> String {
                     .join(", ")
             )
         }
+        ScalarExpr::LambdaFunction(lambda) => {
+            let mut args = lambda
+                .args
+                .iter()
+                .map(format_scalar)
+                .collect::<Vec<String>>();
+            args.push(lambda.lambda_display.clone());
+            format!("{}({})", &lambda.func_name, args.join(", "))
+        }
         ScalarExpr::CastExpr(cast) => {
             format!(
                 "CAST({} AS {})",
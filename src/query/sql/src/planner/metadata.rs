@@ -59,6 +59,13 @@ pub struct Metadata {
     //// Columns that are lazy materialized.
     lazy_columns: HashSet<usize>,
     agg_indexes: HashMap<String, Vec<(u64, String, SExpr)>>,
+    /// Fresh materialized views of the tables, keyed by the table index.
+    materialized_views: HashMap<IndexType, Vec<MaterializedViewEntry>>,
+    /// Columns the current user can select of the tables on which SELECT is only granted
+    /// on some columns, keyed by the table index.
+    granted_columns: HashMap<IndexType, HashSet<String>>,
+    /// Number of the bound recursive CTEs, used to identify their working tables.
+    recursive_ctes: usize,
     max_column_position: usize, // for CSV
 }
 
@@ -183,6 +190,12 @@ impl Metadata {
         column_index
     }
 
+    pub fn add_recursive_cte(&mut self) -> IndexType {
+        let cte_index = self.recursive_ctes;
+        self.recursive_ctes += 1;
+        cte_index
+    }
+
     pub fn add_derived_column(&mut self, alias: String, data_type: DataType) -> IndexType {
         let column_index = self.columns.len();
         let column_entry = ColumnEntry::DerivedColumn(DerivedColumn {
@@ -242,6 +255,34 @@ impl Metadata {
         self.agg_indexes.get(table).map(|v| v.as_slice())
     }
 
+    pub fn add_materialized_views(
+        &mut self,
+        table_index: IndexType,
+        materialized_views: Vec<MaterializedViewEntry>,
+    ) {
+        self.materialized_views
+            .entry(table_index)
+            .or_default()
+            .extend(materialized_views);
+    }
+
+    pub fn set_granted_columns(&mut self, table_index: IndexType, columns: HashSet<String>) {
+        self.granted_columns.insert(table_index, columns);
+    }
+
+    pub fn granted_columns(&self, table_index: IndexType) -> Option<&HashSet<String>> {
+        self.granted_columns.get(&table_index)
+    }
+
+    pub fn get_materialized_views(
+        &self,
+        table_index: IndexType,
+    ) -> Option<&[MaterializedViewEntry]> {
+        self.materialized_views
+            .get(&table_index)
+            .map(|v| v.as_slice())
+    }
+
     pub fn add_table(
         &mut self,
         catalog: String,
@@ -361,6 +402,28 @@ impl Metadata {
     }
 }
 
+/// A materialized view which is fresh enough to answer queries on its source table.
+#[derive(Clone)]
+pub struct MaterializedViewEntry {
+    pub catalog: String,
+    pub database: String,
+    pub table: Arc<dyn Table>,
+    /// Output columns of the bound view query, in the order of the view schema.
+    pub output_columns: Vec<IndexType>,
+    /// Bound plan of the view query.
+    pub plan: SExpr,
+}
+
+impl Debug for MaterializedViewEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MaterializedViewEntry")
+            .field("catalog", &self.catalog)
+            .field("database", &self.database)
+            .field("name", &self.table.name())
+            .finish_non_exhaustive()
+    }
+}
+
 #[derive(Clone)]
 pub struct TableEntry {
     catalog: String,
@@ -48,6 +48,10 @@ pub fn display_rel_op(rel_op: &RelOperator) -> String {
         RelOperator::DummyTableScan(_) => "DummyTableScan".to_string(),
         RelOperator::RuntimeFilterSource(_) => "RuntimeFilterSource".to_string(),
         RelOperator::ProjectSet(_) => "ProjectSet".to_string(),
+        RelOperator::DictGet(_) => "DictGet".to_string(),
+        RelOperator::Udf(_) => "Udf".to_string(),
+        RelOperator::RecursiveCte(_) => "RecursiveCte".to_string(),
+        RelOperator::RecursiveCteScan(_) => "RecursiveCteScan".to_string(),
         RelOperator::Window(_) => "WindowFunc".to_string(),
     }
 }
@@ -39,6 +39,7 @@ use crate::plans::Filter;
 use crate::plans::FunctionCall;
 use crate::plans::Join;
 use crate::plans::JoinType;
+use crate::plans::LambdaFunc;
 use crate::plans::PatternPlan;
 use crate::plans::RelOp;
 use crate::plans::RelOperator;
@@ -189,6 +190,7 @@ impl SubqueryRewriter {
             marker_index: None,
             from_correlated_subquery: true,
             contain_runtime_filter: false,
+            is_lateral: false,
         };
 
         // Rewrite plan to semi-join.
@@ -267,6 +269,7 @@ impl SubqueryRewriter {
                     marker_index: None,
                     from_correlated_subquery: true,
                     contain_runtime_filter: false,
+                    is_lateral: false,
                 };
                 let s_expr = SExpr::create_binary(
                     Arc::new(join_plan.into()),
@@ -310,6 +313,7 @@ impl SubqueryRewriter {
                     marker_index: Some(marker_index),
                     from_correlated_subquery: true,
                     contain_runtime_filter: false,
+                    is_lateral: false,
                 };
                 let s_expr = SExpr::create_binary(
                     Arc::new(join_plan.into()),
@@ -372,6 +376,7 @@ impl SubqueryRewriter {
                     marker_index: Some(marker_index),
                     from_correlated_subquery: true,
                     contain_runtime_filter: false,
+                    is_lateral: false,
                 }
                 .into();
                 Ok((
@@ -387,6 +392,146 @@ impl SubqueryRewriter {
         }
     }
 
+    /// Decorrelate a lateral join, the right side is flattened like a correlated
+    /// subquery and joined with the left side on the correlated columns.
+    ///
+    /// If the correlated columns can't be replaced by the columns they are compared
+    /// with, the right side is evaluated for each distinct value of them, which is
+    /// a nested loop join with the left side.
+    pub fn decorrelate_lateral_join(
+        &mut self,
+        join: &Join,
+        left: SExpr,
+        right: SExpr,
+    ) -> Result<SExpr> {
+        let left_prop = RelExpr::with_s_expr(&left).derive_relational_prop()?;
+        let right_prop = RelExpr::with_s_expr(&right).derive_relational_prop()?;
+        let correlated_columns: ColumnSet = right_prop
+            .outer_columns
+            .intersection(&left_prop.output_columns)
+            .cloned()
+            .collect();
+
+        let mut join = join.clone();
+        join.is_lateral = false;
+        if correlated_columns.is_empty() {
+            return Ok(SExpr::create_binary(
+                Arc::new(join.into()),
+                Arc::new(left),
+                Arc::new(right),
+            ));
+        }
+
+        self.derived_columns.clear();
+        self.lateral_outer = Some(left.clone());
+        let mut flatten_info = FlattenInfo {
+            from_count_func: false,
+        };
+        let flatten_plan = self.flatten(&right, &correlated_columns, &mut flatten_info, false);
+        self.lateral_outer = None;
+        let flatten_plan = flatten_plan?;
+
+        self.add_equi_conditions(
+            None,
+            &correlated_columns,
+            &mut join.right_conditions,
+            &mut join.left_conditions,
+        )?;
+        if join.join_type == JoinType::Cross {
+            join.join_type = JoinType::Inner;
+        }
+        Ok(SExpr::create_binary(
+            Arc::new(join.into()),
+            Arc::new(left),
+            Arc::new(flatten_plan),
+        ))
+    }
+
+    // Evaluate `plan` for each distinct value of the correlated columns in the left
+    // side of the lateral join being decorrelated.
+    fn cross_join_lateral_outer(
+        &mut self,
+        outer: &SExpr,
+        plan: &SExpr,
+        correlated_columns: &ColumnSet,
+    ) -> Result<SExpr> {
+        let mut items = Vec::with_capacity(correlated_columns.len());
+        let mut group_items = Vec::with_capacity(correlated_columns.len());
+        for correlated_column in correlated_columns.iter() {
+            let (name, data_type) = {
+                let metadata = self.metadata.read();
+                let column_entry = metadata.column(*correlated_column);
+                (column_entry.name(), column_entry.data_type())
+            };
+            let derived_column = self
+                .metadata
+                .write()
+                .add_derived_column(name.clone(), data_type.clone());
+            self.derived_columns
+                .insert(*correlated_column, derived_column);
+
+            let column_ref = |index| {
+                ScalarExpr::BoundColumnRef(BoundColumnRef {
+                    span: None,
+                    column: ColumnBinding {
+                        database_name: None,
+                        table_name: None,
+                        column_position: None,
+                        table_index: None,
+                        column_name: name.clone(),
+                        index,
+                        data_type: Box::new(data_type.clone()),
+                        visibility: Visibility::Visible,
+                        virtual_computed_expr: None,
+                    },
+                })
+            };
+            items.push(ScalarItem {
+                scalar: column_ref(*correlated_column),
+                index: derived_column,
+            });
+            group_items.push(ScalarItem {
+                scalar: column_ref(derived_column),
+                index: derived_column,
+            });
+        }
+
+        let distinct_outer = SExpr::create_unary(
+            Arc::new(
+                Aggregate {
+                    mode: AggregateMode::Initial,
+                    group_items,
+                    aggregate_functions: vec![],
+                    from_distinct: true,
+                    limit: None,
+                    grouping_id_index: 0,
+                    grouping_sets: vec![],
+                }
+                .into(),
+            ),
+            Arc::new(SExpr::create_unary(
+                Arc::new(EvalScalar { items }.into()),
+                Arc::new(outer.clone()),
+            )),
+        );
+        let cross_join = Join {
+            left_conditions: vec![],
+            right_conditions: vec![],
+            non_equi_conditions: vec![],
+            join_type: JoinType::Cross,
+            marker_index: None,
+            from_correlated_subquery: false,
+            contain_runtime_filter: false,
+            is_lateral: false,
+        }
+        .into();
+        Ok(SExpr::create_binary(
+            Arc::new(cross_join),
+            Arc::new(distinct_outer),
+            Arc::new(plan.clone()),
+        ))
+    }
+
     fn flatten(
         &mut self,
         plan: &SExpr,
@@ -400,6 +545,9 @@ impl SubqueryRewriter {
             if !need_cross_join {
                 return Ok(plan.clone());
             }
+            if let Some(outer) = self.lateral_outer.clone() {
+                return self.cross_join_lateral_outer(&outer, plan, correlated_columns);
+            }
             // Construct a LogicalGet plan by correlated columns.
             // Finally generate a cross join, so we finish flattening the subquery.
             let mut metadata = self.metadata.write();
@@ -434,6 +582,7 @@ impl SubqueryRewriter {
                 marker_index: None,
                 from_correlated_subquery: false,
                 contain_runtime_filter: false,
+                is_lateral: false,
             }
             .into();
             return Ok(SExpr::create_binary(
@@ -552,6 +701,7 @@ impl SubqueryRewriter {
                             marker_index: join.marker_index,
                             from_correlated_subquery: false,
                             contain_runtime_filter: false,
+                            is_lateral: false,
                         }
                         .into(),
                     ),
@@ -755,6 +905,7 @@ impl SubqueryRewriter {
                     params: agg.params.clone(),
                     args,
                     return_type: agg.return_type.clone(),
+                    udaf: agg.udaf.clone(),
                 }))
             }
             ScalarExpr::FunctionCall(func) => {
@@ -770,6 +921,20 @@ impl SubqueryRewriter {
                     arguments,
                 }))
             }
+            ScalarExpr::LambdaFunction(lambda_func) => {
+                let args = lambda_func
+                    .args
+                    .iter()
+                    .map(|arg| self.flatten_scalar(arg, correlated_columns))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(ScalarExpr::LambdaFunction(LambdaFunc {
+                    span: lambda_func.span,
+                    func_name: lambda_func.func_name.clone(),
+                    args,
+                    lambda_expr: lambda_func.lambda_expr.clone(),
+                    lambda_display: lambda_func.lambda_display.clone(),
+                }))
+            }
             ScalarExpr::CastExpr(cast_expr) => {
                 let scalar = self.flatten_scalar(&cast_expr.argument, correlated_columns)?;
                 Ok(ScalarExpr::CastExpr(CastExpr {
@@ -21,9 +21,11 @@ use crate::optimizer::util::contains_project_set;
 use crate::optimizer::ColumnSet;
 use crate::optimizer::SExpr;
 use crate::plans::Aggregate;
+use crate::plans::DictGet;
 use crate::plans::DummyTableScan;
 use crate::plans::EvalScalar;
 use crate::plans::RelOperator;
+use crate::plans::Udf;
 use crate::ColumnEntry;
 use crate::MetadataRef;
 
@@ -254,7 +256,62 @@ impl UnusedColumnPruner {
                 ))
             }
 
-            RelOperator::DummyTableScan(_) => Ok(expr.clone()),
+            RelOperator::DictGet(op) => {
+                // Lookups whose results are not used are dropped.
+                let items = op
+                    .items
+                    .iter()
+                    .filter(|item| required.contains(&item.index))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                if items.is_empty() {
+                    return self.keep_required_columns(expr.child(0)?, required);
+                }
+                for item in items.iter() {
+                    required.extend(item.key.used_columns().iter().copied());
+                }
+
+                Ok(SExpr::create_unary(
+                    Arc::new(RelOperator::DictGet(DictGet { items })),
+                    Arc::new(self.keep_required_columns(expr.child(0)?, required)?),
+                ))
+            }
+
+            RelOperator::Udf(op) => {
+                // Calls whose results are not used are dropped.
+                let items = op
+                    .items
+                    .iter()
+                    .filter(|item| required.contains(&item.index))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                if items.is_empty() {
+                    return self.keep_required_columns(expr.child(0)?, required);
+                }
+                for item in items.iter() {
+                    for argument in item.arguments.iter() {
+                        required.extend(argument.used_columns().iter().copied());
+                    }
+                }
+
+                Ok(SExpr::create_unary(
+                    Arc::new(RelOperator::Udf(Udf { items })),
+                    Arc::new(self.keep_required_columns(expr.child(0)?, required)?),
+                ))
+            }
+
+            RelOperator::RecursiveCte(p) => {
+                // All the columns are kept, they fill the working table of the next iteration.
+                let anchor_required = p.anchor_columns.iter().copied().collect();
+                let recursive_required = p.recursive_columns.iter().copied().collect();
+                Ok(SExpr::create_binary(
+                    Arc::new(RelOperator::RecursiveCte(p.clone())),
+                    Arc::new(self.keep_required_columns(expr.child(0)?, anchor_required)?),
+                    Arc::new(self.keep_required_columns(expr.child(1)?, recursive_required)?),
+                ))
+            }
+
+            RelOperator::DummyTableScan(_) | RelOperator::RecursiveCteScan(_) => Ok(expr.clone()),
 
             _ => Err(ErrorCode::Internal(
                 "Attempting to prune columns of a physical plan is not allowed",
@@ -39,6 +39,7 @@ use crate::plans::Filter;
 use crate::plans::FunctionCall;
 use crate::plans::Join;
 use crate::plans::JoinType;
+use crate::plans::LambdaFunc;
 use crate::plans::Limit;
 use crate::plans::RelOperator;
 use crate::plans::ScalarExpr;
@@ -65,6 +66,9 @@ pub struct FlattenInfo {
 pub struct SubqueryRewriter {
     pub(crate) metadata: MetadataRef,
     pub(crate) derived_columns: HashMap<IndexType, IndexType>,
+    // The left side of the lateral join being decorrelated, the values of the
+    // correlated columns are taken from it.
+    pub(crate) lateral_outer: Option<SExpr>,
 }
 
 impl SubqueryRewriter {
@@ -72,6 +76,7 @@ impl SubqueryRewriter {
         Self {
             metadata,
             derived_columns: Default::default(),
+            lateral_outer: None,
         }
     }
 
@@ -116,6 +121,18 @@ impl SubqueryRewriter {
                 Ok(SExpr::create_unary(Arc::new(plan.into()), Arc::new(input)))
             }
 
+            RelOperator::ProjectSet(mut plan) => {
+                let mut input = self.rewrite(s_expr.child(0)?)?;
+
+                for item in plan.srfs.iter_mut() {
+                    let res = self.try_rewrite_subquery(&item.scalar, &input, false)?;
+                    input = res.1;
+                    item.scalar = res.0;
+                }
+
+                Ok(SExpr::create_unary(Arc::new(plan.into()), Arc::new(input)))
+            }
+
             RelOperator::Window(mut plan) => {
                 let mut input = self.rewrite(s_expr.child(0)?)?;
 
@@ -143,18 +160,31 @@ impl SubqueryRewriter {
                 Ok(SExpr::create_unary(Arc::new(plan.into()), Arc::new(input)))
             }
 
-            RelOperator::Join(_) | RelOperator::UnionAll(_) => Ok(SExpr::create_binary(
-                Arc::new(s_expr.plan().clone()),
-                Arc::new(self.rewrite(s_expr.child(0)?)?),
-                Arc::new(self.rewrite(s_expr.child(1)?)?),
-            )),
+            RelOperator::Join(join) if join.is_lateral => {
+                let left = self.rewrite(s_expr.child(0)?)?;
+                let right = self.rewrite(s_expr.child(1)?)?;
+                self.decorrelate_lateral_join(&join, left, right)
+            }
 
-            RelOperator::Limit(_) | RelOperator::Sort(_) => Ok(SExpr::create_unary(
+            RelOperator::Join(_) | RelOperator::UnionAll(_) | RelOperator::RecursiveCte(_) => {
+                Ok(SExpr::create_binary(
+                    Arc::new(s_expr.plan().clone()),
+                    Arc::new(self.rewrite(s_expr.child(0)?)?),
+                    Arc::new(self.rewrite(s_expr.child(1)?)?),
+                ))
+            }
+
+            RelOperator::Limit(_)
+            | RelOperator::Sort(_)
+            | RelOperator::DictGet(_)
+            | RelOperator::Udf(_) => Ok(SExpr::create_unary(
                 Arc::new(s_expr.plan().clone()),
                 Arc::new(self.rewrite(s_expr.child(0)?)?),
             )),
 
-            RelOperator::DummyTableScan(_) | RelOperator::Scan(_) => Ok(s_expr.clone()),
+            RelOperator::DummyTableScan(_)
+            | RelOperator::Scan(_)
+            | RelOperator::RecursiveCteScan(_) => Ok(s_expr.clone()),
 
             _ => Err(ErrorCode::Internal("Invalid plan type")),
         }
@@ -192,6 +222,26 @@ impl SubqueryRewriter {
 
                 Ok((expr, s_expr))
             }
+            ScalarExpr::LambdaFunction(lambda_func) => {
+                let mut args = vec![];
+                let mut s_expr = s_expr.clone();
+                for arg in lambda_func.args.iter() {
+                    let res = self.try_rewrite_subquery(arg, &s_expr, false)?;
+                    s_expr = res.1;
+                    args.push(res.0);
+                }
+
+                let expr: ScalarExpr = LambdaFunc {
+                    span: lambda_func.span,
+                    func_name: lambda_func.func_name.clone(),
+                    args,
+                    lambda_expr: lambda_func.lambda_expr.clone(),
+                    lambda_display: lambda_func.lambda_display.clone(),
+                }
+                .into();
+
+                Ok((expr, s_expr))
+            }
             ScalarExpr::CastExpr(cast) => {
                 let (scalar, s_expr) = self.try_rewrite_subquery(&cast.argument, s_expr, false)?;
                 Ok((
@@ -343,6 +393,7 @@ impl SubqueryRewriter {
                     marker_index: None,
                     from_correlated_subquery: false,
                     contain_runtime_filter: false,
+                    is_lateral: false,
                 }
                 .into();
                 let s_expr = SExpr::create_binary(
@@ -381,6 +432,7 @@ impl SubqueryRewriter {
                             params: vec![],
                             args: vec![],
                             return_type: Box::new(agg_func.return_type()?),
+                            udaf: None,
                         }
                         .into(),
                         index: agg_func_index,
@@ -445,6 +497,7 @@ impl SubqueryRewriter {
                     marker_index: None,
                     from_correlated_subquery: false,
                     contain_runtime_filter: false,
+                    is_lateral: false,
                 }
                 .into();
                 Ok((
@@ -516,6 +569,7 @@ impl SubqueryRewriter {
                     marker_index: Some(marker_index),
                     from_correlated_subquery: false,
                     contain_runtime_filter: false,
+                    is_lateral: false,
                 }
                 .into();
                 let s_expr = SExpr::create_binary(
@@ -197,6 +197,8 @@ impl DPhpy {
             }
 
             RelOperator::ProjectSet(_)
+            | RelOperator::DictGet(_)
+            | RelOperator::Udf(_)
             | RelOperator::EvalScalar(_)
             | RelOperator::Filter(_)
             | RelOperator::Aggregate(_)
@@ -231,6 +233,8 @@ impl DPhpy {
             RelOperator::Exchange(_) | RelOperator::Pattern(_) => unreachable!(),
             RelOperator::Window(_)
             | RelOperator::UnionAll(_)
+            | RelOperator::RecursiveCte(_)
+            | RelOperator::RecursiveCteScan(_)
             | RelOperator::DummyTableScan(_)
             | RelOperator::RuntimeFilterSource(_) => Ok((s_expr, false)),
         }
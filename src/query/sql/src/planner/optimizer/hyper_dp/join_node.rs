@@ -87,6 +87,7 @@ impl JoinNode {
             marker_index: None,
             from_correlated_subquery: false,
             contain_runtime_filter: false,
+            is_lateral: false,
         });
         let children = self
             .children
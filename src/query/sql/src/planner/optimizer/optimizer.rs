@@ -28,6 +28,7 @@ use crate::optimizer::distributed::optimize_distributed_query;
 use crate::optimizer::hyper_dp::DPhpy;
 use crate::optimizer::runtime_filter::try_add_runtime_filter_nodes;
 use crate::optimizer::util::contains_local_table_scan;
+use crate::optimizer::util::contains_recursive_cte;
 use crate::optimizer::HeuristicOptimizer;
 use crate::optimizer::SExpr;
 use crate::plans::CopyPlan;
@@ -63,7 +64,6 @@ pub fn optimize(
             bind_context,
             metadata,
             rewrite_kind,
-            formatted_ast,
             ignore_result,
         } => Ok(Plan::Query {
             s_expr: Box::new(optimize_query(
@@ -76,7 +76,6 @@ pub fn optimize(
             bind_context,
             metadata,
             rewrite_kind,
-            formatted_ast,
             ignore_result,
         }),
         Plan::Explain { kind, plan } => match kind {
@@ -121,12 +120,14 @@ pub fn optimize(
                     stage,
                     path,
                     validation_mode,
+                    partition_by,
                     from,
                 } => {
                     CopyPlan::IntoStage {
                         stage,
                         path,
                         validation_mode,
+                        partition_by,
                         // Make sure the subquery has been optimized.
                         from: Box::new(optimize(ctx, opt_ctx, *from)?),
                     }
@@ -147,6 +148,7 @@ pub fn optimize_query(
     s_expr: SExpr,
 ) -> Result<SExpr> {
     let contains_local_table_scan = contains_local_table_scan(&s_expr, &metadata);
+    let contains_recursive_cte = contains_recursive_cte(&s_expr);
 
     let heuristic =
         HeuristicOptimizer::new(ctx.get_function_context()?, bind_context, metadata.clone());
@@ -162,9 +164,11 @@ pub fn optimize_query(
         result = cascades.optimize(result)?;
     }
     // So far, we don't have ability to execute distributed query
-    // with reading data from local tales(e.g. system tables).
-    let enable_distributed_query =
-        opt_ctx.config.enable_distributed_optimization && !contains_local_table_scan;
+    // with reading data from local tales(e.g. system tables),
+    // nor iterate a recursive CTE across the cluster.
+    let enable_distributed_query = opt_ctx.config.enable_distributed_optimization
+        && !contains_local_table_scan
+        && !contains_recursive_cte;
     // Add runtime filter related nodes after cbo
     // Because cbo may change join order and we don't want to
     // break optimizer due to new added nodes by runtime filter.
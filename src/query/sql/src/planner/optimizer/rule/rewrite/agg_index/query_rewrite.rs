@@ -15,20 +15,26 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::DataType;
 use common_expression::Scalar;
 
 use crate::binder::split_conjunctions;
 use crate::optimizer::SExpr;
+use crate::plans::AggIndexAggregation;
 use crate::plans::AggIndexInfo;
+use crate::plans::AggIndexMerge;
 use crate::plans::Aggregate;
+use crate::plans::AggregateFunction;
 use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::ConstantExpr;
 use crate::plans::EvalScalar;
 use crate::plans::FunctionCall;
+use crate::plans::LambdaFunc;
 use crate::plans::RelOperator;
+use crate::BaseTableColumn;
 use crate::ColumnBinding;
 use crate::ColumnEntry;
 use crate::ColumnSet;
@@ -54,6 +60,7 @@ pub fn try_rewrite(
         .iter()
         .map(|col| (col.name(), col.index()))
         .collect::<HashMap<_, _>>();
+    let stored_columns = stored_columns(base_columns);
 
     let query_predicates = query_info.predicates.map(distinguish_predicates);
     let query_group_items = query_info.formatted_group_items();
@@ -135,10 +142,27 @@ pub fn try_rewrite(
             (None, None) => { /* Matched */ }
         }
 
+        // 4. If the query is an aggregation, check if it can merge the partial aggregate
+        // states in the index.
+        let mut merge = None;
+        if query_info.aggregation.is_some() {
+            if let (Some((qe, _, qo)), Some((ie, _, io))) = (&query_predicates, &index_predicates) {
+                // The partial states can not be filtered by the predicates not in the index.
+                if !check_predicates_equal(ie, qe) || !check_predicates_other(io, qo) {
+                    continue;
+                }
+            }
+            match query_info.merge_index_states(&stored_columns, &index_selection) {
+                Some(m) => merge = Some(m),
+                None => continue,
+            }
+        }
+
         let result = push_down_index_scan(s_expr, AggIndexInfo {
             index_id: *index_id,
             selection: new_selection,
             predicates: new_predicates,
+            merge,
         })?;
         return Ok(Some(result));
     }
@@ -146,6 +170,74 @@ pub fn try_rewrite(
     Ok(None)
 }
 
+/// Get the partial aggregation of the aggregating index `s_expr`,
+/// which computes the index data of each block.
+///
+/// Returns [None] if the index is not an aggregation.
+pub fn index_aggregation(
+    base_columns: &[ColumnEntry],
+    s_expr: &SExpr,
+) -> Result<Option<AggIndexAggregation>> {
+    let index_info = collect_information(s_expr)?;
+    if index_info.aggregation.is_none() {
+        return Ok(None);
+    }
+    let mut aggregation = match index_info.partial_aggregation(&stored_columns(base_columns)) {
+        Some(aggregation) => aggregation,
+        None => {
+            return Err(ErrorCode::UnsupportedIndex(
+                "Aggregating index only supports aggregate functions on the columns of the table",
+            ));
+        }
+    };
+
+    // The index data are the selection of the index, each should be a group item
+    // or an aggregate function.
+    let (agg, _) = index_info.aggregation.unwrap();
+    let group_items = agg
+        .group_items
+        .iter()
+        .map(|item| index_info.format_scalar(&item.scalar))
+        .collect::<Vec<_>>();
+    let aggregate_functions = agg
+        .aggregate_functions
+        .iter()
+        .map(|item| index_info.format_scalar(&item.scalar))
+        .collect::<Vec<_>>();
+    let mut output = Vec::with_capacity(index_info.selection.items.len());
+    for item in index_info.selection.items.iter() {
+        let key = index_info.format_scalar(&item.scalar);
+        if let Some(i) = group_items.iter().position(|g| *g == key) {
+            output.push(i);
+        } else if let Some(i) = aggregate_functions.iter().position(|f| *f == key) {
+            output.push(group_items.len() + i);
+        } else {
+            return Err(ErrorCode::UnsupportedIndex(
+                "Aggregating index only supports selecting group by items and aggregate functions",
+            ));
+        }
+    }
+    aggregation.output = output;
+
+    Ok(Some(aggregation))
+}
+
+/// Collect the columns stored in the blocks, which can be read by the partial aggregation.
+fn stored_columns(base_columns: &[ColumnEntry]) -> ColumnSet {
+    base_columns
+        .iter()
+        .filter_map(|col| match col {
+            ColumnEntry::BaseTableColumn(BaseTableColumn {
+                column_index,
+                path_indices: None,
+                virtual_computed_expr: None,
+                ..
+            }) => Some(*column_index),
+            _ => None,
+        })
+        .collect()
+}
+
 /// Rewrite base column index in the original index plan by `columns`.
 fn rewrite_index_plan(columns: &HashMap<String, IndexType>, s_expr: &SExpr) -> SExpr {
     match s_expr.plan() {
@@ -194,6 +286,11 @@ fn rewrite_scalar_index(columns: &HashMap<String, IndexType>, scalar: &mut Scala
                 .iter_mut()
                 .for_each(|arg| rewrite_scalar_index(columns, arg));
         }
+        ScalarExpr::LambdaFunction(func) => {
+            func.args
+                .iter_mut()
+                .for_each(|arg| rewrite_scalar_index(columns, arg));
+        }
         ScalarExpr::CastExpr(cast) => {
             rewrite_scalar_index(columns, &mut cast.argument);
         }
@@ -475,6 +572,123 @@ impl RewriteInfomartion<'_> {
         vec![]
     }
 
+    /// Get the partial aggregation of the query on the base columns,
+    /// the output are the group items followed by the aggregate functions.
+    ///
+    /// Returns [None] if the aggregation is not on the `stored_columns`,
+    /// or has aggregate UDFs which can not be evaluated inside the storage.
+    fn partial_aggregation(&self, stored_columns: &ColumnSet) -> Option<AggIndexAggregation> {
+        let (agg, _) = self.aggregation?;
+        let predicates = self.predicates.map(|p| p.to_vec()).unwrap_or_default();
+        let group_items = agg
+            .group_items
+            .iter()
+            .map(|item| self.resolve_scalar(&item.scalar))
+            .collect::<Vec<_>>();
+        let mut aggregate_functions = Vec::with_capacity(agg.aggregate_functions.len());
+        for item in agg.aggregate_functions.iter() {
+            match self.resolve_scalar(&item.scalar) {
+                ScalarExpr::AggregateFunction(func) if func.udaf.is_none() => {
+                    aggregate_functions.push(func)
+                }
+                _ => return None,
+            }
+        }
+
+        let mut used_columns = ColumnSet::new();
+        for scalar in predicates.iter().chain(group_items.iter()) {
+            used_columns.extend(scalar.used_columns());
+        }
+        for func in aggregate_functions.iter() {
+            for arg in func.args.iter() {
+                used_columns.extend(arg.used_columns());
+            }
+        }
+        if !used_columns.is_subset(stored_columns) {
+            return None;
+        }
+
+        let output = (0..group_items.len() + aggregate_functions.len()).collect();
+        Some(AggIndexAggregation {
+            predicates,
+            group_items,
+            aggregate_functions,
+            output,
+        })
+    }
+
+    /// Check if the query can merge the partial aggregate states in the index,
+    /// the group items and the aggregate functions should be in the index selection.
+    fn merge_index_states(
+        &self,
+        stored_columns: &ColumnSet,
+        index_selection: &SelectionMap<'_>,
+    ) -> Option<AggIndexMerge> {
+        let (agg, _) = self.aggregation?;
+        let mut projection =
+            Vec::with_capacity(agg.group_items.len() + agg.aggregate_functions.len());
+        for item in agg.group_items.iter().chain(agg.aggregate_functions.iter()) {
+            let (index, _) = index_selection.get(&self.format_scalar(&item.scalar))?;
+            projection.push(*index);
+        }
+
+        Some(AggIndexMerge {
+            projection,
+            aggregation: self.partial_aggregation(stored_columns)?,
+        })
+    }
+
+    /// Replace the columns evaluated below the aggregation with their expressions.
+    fn resolve_scalar(&self, scalar: &ScalarExpr) -> ScalarExpr {
+        match scalar {
+            ScalarExpr::BoundColumnRef(col) => {
+                if let Some((_, args)) = &self.aggregation {
+                    if let Some(arg) = args.get(&col.column.index) {
+                        match arg {
+                            ScalarExpr::BoundColumnRef(c) if c.column.index == col.column.index => {
+                            }
+                            _ => return self.resolve_scalar(arg),
+                        }
+                    }
+                }
+                scalar.clone()
+            }
+            ScalarExpr::FunctionCall(func) => FunctionCall {
+                arguments: func
+                    .arguments
+                    .iter()
+                    .map(|arg| self.resolve_scalar(arg))
+                    .collect(),
+                ..func.clone()
+            }
+            .into(),
+            ScalarExpr::LambdaFunction(func) => LambdaFunc {
+                args: func
+                    .args
+                    .iter()
+                    .map(|arg| self.resolve_scalar(arg))
+                    .collect(),
+                ..func.clone()
+            }
+            .into(),
+            ScalarExpr::CastExpr(cast) => CastExpr {
+                argument: Box::new(self.resolve_scalar(&cast.argument)),
+                ..cast.clone()
+            }
+            .into(),
+            ScalarExpr::AggregateFunction(agg) => AggregateFunction {
+                args: agg
+                    .args
+                    .iter()
+                    .map(|arg| self.resolve_scalar(arg))
+                    .collect(),
+                ..agg.clone()
+            }
+            .into(),
+            _ => scalar.clone(),
+        }
+    }
+
     fn formatted_selection(&self) -> SelectionMap<'_> {
         let mut outputs = HashMap::with_capacity(self.selection.items.len());
         for (index, item) in self.selection.items.iter().enumerate() {
@@ -521,6 +735,16 @@ impl RewriteInfomartion<'_> {
                     .collect::<Vec<String>>()
                     .join(", ")
             ),
+            ScalarExpr::LambdaFunction(func) => format!(
+                "{}({}, {})",
+                &func.func_name,
+                func.args
+                    .iter()
+                    .map(|arg| { self.format_scalar(arg) })
+                    .collect::<Vec<String>>()
+                    .join(", "),
+                func.lambda_display
+            ),
             ScalarExpr::CastExpr(cast) => format!(
                 "CAST({} AS {})",
                 self.format_scalar(&cast.argument),
@@ -834,6 +1058,23 @@ fn rewrite_query_item(
                 .into(),
             )
         }
+        ScalarExpr::LambdaFunction(func) => {
+            let mut new_args = Vec::with_capacity(func.args.len());
+            for arg in func.args.iter() {
+                let new_arg = rewrite_by_selection(query_info, arg, index_selection)?;
+                new_args.push(new_arg);
+            }
+            Some(
+                LambdaFunc {
+                    span: None,
+                    func_name: func.func_name.clone(),
+                    args: new_args,
+                    lambda_expr: func.lambda_expr.clone(),
+                    lambda_display: func.lambda_display.clone(),
+                }
+                .into(),
+            )
+        }
         ScalarExpr::AggregateFunction(_) => None, /* Aggregate function must appear in index selection. */
         _ => unreachable!(), // Window function and subquery will not appear in index.
     }
@@ -37,6 +37,7 @@ mod rule_push_down_prewhere;
 mod rule_push_down_sort_scan;
 mod rule_split_aggregate;
 mod rule_try_apply_agg_index;
+mod rule_try_apply_materialized_view;
 
 pub use rule_eliminate_eval_scalar::RuleEliminateEvalScalar;
 pub use rule_eliminate_filter::RuleEliminateFilter;
@@ -62,3 +63,4 @@ pub use rule_push_down_prewhere::RulePushDownPrewhere;
 pub use rule_push_down_sort_scan::RulePushDownSortScan;
 pub use rule_split_aggregate::RuleSplitAggregate;
 pub use rule_try_apply_agg_index::RuleTryApplyAggIndex;
+pub use rule_try_apply_materialized_view::RuleTryApplyMaterializedView;
@@ -29,6 +29,7 @@ use crate::plans::EvalScalar;
 use crate::plans::Filter;
 use crate::plans::FunctionCall;
 use crate::plans::LagLeadFunction;
+use crate::plans::LambdaFunc;
 use crate::plans::NthValueFunction;
 use crate::plans::PatternPlan;
 use crate::plans::RelOp;
@@ -109,6 +110,7 @@ impl RulePushDownFilterEvalScalar {
                             params: agg.params.clone(),
                             args,
                             return_type: agg.return_type.clone(),
+                            udaf: agg.udaf.clone(),
                             display_name: agg.display_name.clone(),
                         })
                     }
@@ -181,6 +183,7 @@ impl RulePushDownFilterEvalScalar {
                     params: agg_func.params.clone(),
                     args,
                     return_type: agg_func.return_type.clone(),
+                    udaf: agg_func.udaf.clone(),
                     display_name: agg_func.display_name.clone(),
                 }))
             }
@@ -198,6 +201,21 @@ impl RulePushDownFilterEvalScalar {
                     func_name: func.func_name.clone(),
                 }))
             }
+            ScalarExpr::LambdaFunction(func) => {
+                let args = func
+                    .args
+                    .iter()
+                    .map(|arg| Self::replace_predicate(arg, items))
+                    .collect::<Result<Vec<ScalarExpr>>>()?;
+
+                Ok(ScalarExpr::LambdaFunction(LambdaFunc {
+                    span: func.span,
+                    func_name: func.func_name.clone(),
+                    args,
+                    lambda_expr: func.lambda_expr.clone(),
+                    lambda_display: func.lambda_display.clone(),
+                }))
+            }
             ScalarExpr::CastExpr(cast) => {
                 let arg = Self::replace_predicate(&cast.argument, items)?;
                 Ok(ScalarExpr::CastExpr(CastExpr {
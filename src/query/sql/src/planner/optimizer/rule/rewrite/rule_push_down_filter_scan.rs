@@ -26,6 +26,7 @@ use crate::plans::CastExpr;
 use crate::plans::Filter;
 use crate::plans::FunctionCall;
 use crate::plans::LagLeadFunction;
+use crate::plans::LambdaFunc;
 use crate::plans::NthValueFunction;
 use crate::plans::PatternPlan;
 use crate::plans::RelOp;
@@ -132,6 +133,7 @@ impl RulePushDownFilterScan {
                             params: agg.params.clone(),
                             args,
                             return_type: agg.return_type.clone(),
+                            udaf: agg.udaf.clone(),
                             display_name: agg.display_name.clone(),
                         })
                     }
@@ -207,6 +209,7 @@ impl RulePushDownFilterScan {
                     params: agg_func.params.clone(),
                     args,
                     return_type: agg_func.return_type.clone(),
+                    udaf: agg_func.udaf.clone(),
                     display_name: agg_func.display_name.clone(),
                 }))
             }
@@ -224,6 +227,21 @@ impl RulePushDownFilterScan {
                     func_name: func.func_name.clone(),
                 }))
             }
+            ScalarExpr::LambdaFunction(func) => {
+                let args = func
+                    .args
+                    .iter()
+                    .map(|arg| Self::replace_view_column(arg, table_entries, column_entries))
+                    .collect::<Result<Vec<ScalarExpr>>>()?;
+
+                Ok(ScalarExpr::LambdaFunction(LambdaFunc {
+                    span: func.span,
+                    func_name: func.func_name.clone(),
+                    args,
+                    lambda_expr: func.lambda_expr.clone(),
+                    lambda_display: func.lambda_display.clone(),
+                }))
+            }
             ScalarExpr::CastExpr(cast) => {
                 let arg = Self::replace_view_column(&cast.argument, table_entries, column_entries)?;
                 Ok(ScalarExpr::CastExpr(CastExpr {
@@ -28,6 +28,7 @@ use crate::plans::CastExpr;
 use crate::plans::Filter;
 use crate::plans::FunctionCall;
 use crate::plans::LagLeadFunction;
+use crate::plans::LambdaFunc;
 use crate::plans::NthValueFunction;
 use crate::plans::PatternPlan;
 use crate::plans::RelOp;
@@ -180,6 +181,7 @@ fn replace_column_binding(
                         .map(|arg| replace_column_binding(index_pairs, arg))
                         .collect::<Result<Vec<_>>>()?,
                     return_type: arg.return_type,
+                    udaf: arg.udaf,
                 }),
                 WindowFuncType::LagLead(ll) => {
                     let new_arg = replace_column_binding(index_pairs, *ll.arg)?;
@@ -235,6 +237,7 @@ fn replace_column_binding(
                     .map(|arg| replace_column_binding(index_pairs, arg))
                     .collect::<Result<Vec<_>>>()?,
                 return_type: expr.return_type,
+                udaf: expr.udaf,
             }))
         }
         ScalarExpr::FunctionCall(expr) => Ok(ScalarExpr::FunctionCall(FunctionCall {
@@ -247,6 +250,17 @@ fn replace_column_binding(
                 .map(|arg| replace_column_binding(index_pairs, arg))
                 .collect::<Result<Vec<_>>>()?,
         })),
+        ScalarExpr::LambdaFunction(expr) => Ok(ScalarExpr::LambdaFunction(LambdaFunc {
+            span: expr.span,
+            func_name: expr.func_name,
+            args: expr
+                .args
+                .into_iter()
+                .map(|arg| replace_column_binding(index_pairs, arg))
+                .collect::<Result<Vec<_>>>()?,
+            lambda_expr: expr.lambda_expr,
+            lambda_display: expr.lambda_display,
+        })),
         ScalarExpr::CastExpr(expr) => Ok(ScalarExpr::CastExpr(CastExpr {
             span: expr.span,
             is_try: expr.is_try,
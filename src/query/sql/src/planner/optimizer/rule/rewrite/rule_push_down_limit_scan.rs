@@ -73,6 +73,10 @@ impl Rule for RulePushDownLimitScan {
         if let Some(mut count) = limit.limit {
             let child = s_expr.child(0)?;
             let mut get: Scan = child.plan().clone().try_into()?;
+            if get.sample.is_some() {
+                // The limit applies to the sampled rows, not to the rows read.
+                return Ok(());
+            }
             count += limit.offset;
             get.limit = Some(get.limit.map_or(count, |c| cmp::max(c, count)));
             let get = SExpr::create_leaf(Arc::new(RelOperator::Scan(get)));
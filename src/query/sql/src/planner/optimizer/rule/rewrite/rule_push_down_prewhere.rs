@@ -85,6 +85,11 @@ impl RulePushDownPrewhere {
                     Self::collect_columns_impl(table_index, schema, arg, columns)?;
                 }
             }
+            ScalarExpr::LambdaFunction(func) => {
+                for arg in func.args.iter() {
+                    Self::collect_columns_impl(table_index, schema, arg, columns)?;
+                }
+            }
             ScalarExpr::CastExpr(cast) => {
                 Self::collect_columns_impl(table_index, schema, cast.argument.as_ref(), columns)?;
             }
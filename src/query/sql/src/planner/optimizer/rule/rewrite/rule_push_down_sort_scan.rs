@@ -15,17 +15,37 @@
 use std::cmp;
 use std::sync::Arc;
 
+use common_catalog::plan::VectorDistance;
+use common_catalog::plan::VectorTopN;
 use common_exception::Result;
+use common_expression::type_check::check_cast;
+use common_expression::types::DataType;
+use common_expression::types::Float32Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::ValueType;
+use common_expression::ConstantFolder;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_functions::BUILTIN_FUNCTIONS;
 
 use crate::optimizer::rule::Rule;
 use crate::optimizer::rule::TransformResult;
 use crate::optimizer::RuleID;
 use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::ConstantExpr;
+use crate::plans::EvalScalar;
+use crate::plans::FunctionCall;
+use crate::plans::Operator;
 use crate::plans::PatternPlan;
 use crate::plans::RelOp;
 use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
 use crate::plans::Scan;
 use crate::plans::Sort;
+use crate::ColumnEntry;
+use crate::MetadataRef;
 
 /// Input:  Sort
 ///           \
@@ -35,31 +55,176 @@ use crate::plans::Sort;
 ///         Sort
 ///           \
 ///           LogicalGet(padding order_by and limit)
-
+///
+/// Input:  Sort(limit)
+///           \
+///          EvalScalar
+///             \
+///            LogicalGet
+///
+/// Output:
+///         Sort(limit)
+///           \
+///          EvalScalar
+///             \
+///            LogicalGet(padding vector_topn)
+///
+/// if the sort key is `cosine_distance(col, <constant>)` or `l2_distance(col, <constant>)`.
 pub struct RulePushDownSortScan {
     id: RuleID,
     patterns: Vec<SExpr>,
+    metadata: MetadataRef,
+    func_ctx: FunctionContext,
 }
 
 impl RulePushDownSortScan {
-    pub fn new() -> Self {
+    pub fn new(metadata: MetadataRef, func_ctx: FunctionContext) -> Self {
         Self {
             id: RuleID::PushDownSortScan,
-            patterns: vec![SExpr::create_unary(
-                Arc::new(
-                    PatternPlan {
-                        plan_type: RelOp::Sort,
-                    }
-                    .into(),
+            patterns: vec![
+                SExpr::create_unary(
+                    Arc::new(
+                        PatternPlan {
+                            plan_type: RelOp::Sort,
+                        }
+                        .into(),
+                    ),
+                    Arc::new(SExpr::create_leaf(Arc::new(
+                        PatternPlan {
+                            plan_type: RelOp::Scan,
+                        }
+                        .into(),
+                    ))),
                 ),
-                Arc::new(SExpr::create_leaf(Arc::new(
-                    PatternPlan {
-                        plan_type: RelOp::Scan,
-                    }
-                    .into(),
-                ))),
-            )],
+                SExpr::create_unary(
+                    Arc::new(
+                        PatternPlan {
+                            plan_type: RelOp::Sort,
+                        }
+                        .into(),
+                    ),
+                    Arc::new(SExpr::create_unary(
+                        Arc::new(
+                            PatternPlan {
+                                plan_type: RelOp::EvalScalar,
+                            }
+                            .into(),
+                        ),
+                        Arc::new(SExpr::create_leaf(Arc::new(
+                            PatternPlan {
+                                plan_type: RelOp::Scan,
+                            }
+                            .into(),
+                        ))),
+                    )),
+                ),
+            ],
+            metadata,
+            func_ctx,
+        }
+    }
+
+    fn push_down_vector_topn(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let sort: Sort = s_expr.plan().clone().try_into()?;
+        let eval_scalar_expr = s_expr.child(0)?;
+        let eval_scalar: EvalScalar = eval_scalar_expr.plan().clone().try_into()?;
+        let mut get: Scan = eval_scalar_expr.child(0)?.plan().clone().try_into()?;
+
+        // Only the nearest rows first, the rows of NULL or NaN distance are the last ones.
+        let (limit, item) = match (sort.limit, sort.items.as_slice()) {
+            (Some(limit), [item]) if item.asc && !item.nulls_first => (limit, item),
+            _ => return Ok(()),
+        };
+        if get.vector_topn.is_some()
+            || get.sample.is_some()
+            || get.push_down_predicates.is_some()
+            || get.prewhere.is_some()
+        {
+            return Ok(());
+        }
+        let scalar = match eval_scalar.items.iter().find(|i| i.index == item.index) {
+            Some(scalar_item) => &scalar_item.scalar,
+            None => return Ok(()),
+        };
+        let vector_topn = match self.vector_topn(scalar, &get, limit) {
+            Some(vector_topn) => vector_topn,
+            None => return Ok(()),
+        };
+        get.vector_topn = Some(vector_topn);
+
+        let get = SExpr::create_leaf(Arc::new(RelOperator::Scan(get)));
+        let eval_scalar_expr = eval_scalar_expr.replace_children(vec![Arc::new(get)]);
+        let mut result = s_expr.replace_children(vec![Arc::new(eval_scalar_expr)]);
+        result.set_applied_rule(&self.id);
+        state.add_result(result);
+        Ok(())
+    }
+
+    /// Matches `cosine_distance(col, <constant>)` and `l2_distance(col, <constant>)`,
+    /// `col` is a column of the table.
+    fn vector_topn(&self, scalar: &ScalarExpr, get: &Scan, limit: usize) -> Option<VectorTopN> {
+        let (distance, arguments) = match scalar {
+            ScalarExpr::FunctionCall(FunctionCall {
+                func_name,
+                arguments,
+                ..
+            }) => match func_name.as_str() {
+                "cosine_distance" => (VectorDistance::Cosine, arguments),
+                "l2_distance" => (VectorDistance::L2, arguments),
+                _ => return None,
+            },
+            _ => return None,
+        };
+        let (column, query) = match arguments.as_slice() {
+            [
+                ScalarExpr::BoundColumnRef(BoundColumnRef { column, .. }),
+                ScalarExpr::ConstantExpr(ConstantExpr { value, .. }),
+            ]
+            | [
+                ScalarExpr::ConstantExpr(ConstantExpr { value, .. }),
+                ScalarExpr::BoundColumnRef(BoundColumnRef { column, .. }),
+            ] => (column, value),
+            _ => return None,
+        };
+        if !get.columns.contains(&column.index) {
+            return None;
         }
+        let column_name = match self.metadata.read().column(column.index) {
+            ColumnEntry::BaseTableColumn(base)
+                if base.table_index == get.table_index
+                    && base.path_indices.is_none()
+                    && base.virtual_computed_expr.is_none() =>
+            {
+                base.column_name.clone()
+            }
+            _ => return None,
+        };
+
+        // cast the constant to the array of Float32 as the distance functions do.
+        let constant: Expr = Expr::Constant {
+            span: None,
+            scalar: query.clone(),
+            data_type: query.as_ref().infer_data_type(),
+        };
+        let target_type = DataType::Array(Box::new(DataType::Number(NumberDataType::Float32)));
+        let cast = check_cast(None, false, constant, &target_type, &BUILTIN_FUNCTIONS).ok()?;
+        let query = match ConstantFolder::fold(&cast, &self.func_ctx, &BUILTIN_FUNCTIONS) {
+            (
+                Expr::Constant {
+                    scalar: Scalar::Array(column),
+                    ..
+                },
+                _,
+            ) => Float32Type::try_downcast_column(&column)?.to_vec(),
+            _ => return None,
+        };
+
+        Some(VectorTopN {
+            column: column_name,
+            distance,
+            query,
+            limit,
+        })
     }
 }
 
@@ -69,9 +234,17 @@ impl Rule for RulePushDownSortScan {
     }
 
     fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
-        let sort: Sort = s_expr.plan().clone().try_into()?;
         let child = s_expr.child(0)?;
+        if child.plan().rel_op() == RelOp::EvalScalar {
+            return self.push_down_vector_topn(s_expr, state);
+        }
+
+        let sort: Sort = s_expr.plan().clone().try_into()?;
         let mut get: Scan = child.plan().clone().try_into()?;
+        if get.sample.is_some() {
+            // The top-n rows of a sample are not the top-n rows of the table.
+            return Ok(());
+        }
         if get.order_by.is_none() {
             get.order_by = Some(sort.items);
         }
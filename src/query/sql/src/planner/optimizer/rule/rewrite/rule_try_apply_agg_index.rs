@@ -202,6 +202,10 @@ impl Rule for RuleTryApplyAggIndex {
         s_expr: &SExpr,
         state: &mut crate::optimizer::rule::TransformResult,
     ) -> Result<()> {
+        if Self::is_sampled(s_expr) {
+            // The index is built from all the rows of the table.
+            return Ok(());
+        }
         let (table_inedx, table_name) = self.get_table(s_expr);
         let metadata = self.metadata.read();
         let index_plans = metadata.get_agg_indexes(&table_name);
@@ -240,4 +244,11 @@ impl RuleTryApplyAggIndex {
             _ => self.get_table(s_expr.child(0).unwrap()),
         }
     }
+
+    fn is_sampled(s_expr: &SExpr) -> bool {
+        match s_expr.plan() {
+            RelOperator::Scan(scan) => scan.sample.is_some(),
+            _ => s_expr.child(0).map_or(false, Self::is_sampled),
+        }
+    }
 }
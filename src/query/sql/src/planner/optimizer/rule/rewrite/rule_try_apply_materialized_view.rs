@@ -0,0 +1,327 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::TableDataType;
+
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::RuleID;
+use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::EvalScalar;
+use crate::plans::PatternPlan;
+use crate::plans::RelOp;
+use crate::plans::RelOperator;
+use crate::plans::ScalarItem;
+use crate::plans::Scan;
+use crate::BaseTableColumn;
+use crate::ColumnBinding;
+use crate::ColumnEntry;
+use crate::ColumnSet;
+use crate::IndexType;
+use crate::MaterializedViewEntry;
+use crate::Metadata;
+use crate::MetadataRef;
+use crate::ScalarExpr;
+use crate::Visibility;
+
+/// Answer a scan of a table from one of its fresh materialized views.
+///
+/// A materialized view can be used if it is a plain projection (with optional filter)
+/// of the table, outputs every column required by the scan, and its predicates are
+/// a subset of the query predicates. The scan is replaced by a scan of the view, and
+/// the view columns are projected back to the indexes of the table columns, so the
+/// operators above are left untouched.
+pub struct RuleTryApplyMaterializedView {
+    id: RuleID,
+    metadata: MetadataRef,
+
+    patterns: Vec<SExpr>,
+}
+
+impl RuleTryApplyMaterializedView {
+    pub fn new(metadata: MetadataRef) -> Self {
+        Self {
+            id: RuleID::TryApplyMaterializedView,
+            metadata,
+            patterns: vec![
+                //  Filter
+                //     |
+                //    Scan
+                SExpr::create_unary(
+                    Arc::new(
+                        PatternPlan {
+                            plan_type: RelOp::Filter,
+                        }
+                        .into(),
+                    ),
+                    Arc::new(SExpr::create_leaf(Arc::new(
+                        PatternPlan {
+                            plan_type: RelOp::Scan,
+                        }
+                        .into(),
+                    ))),
+                ),
+                // Scan
+                SExpr::create_leaf(Arc::new(
+                    PatternPlan {
+                        plan_type: RelOp::Scan,
+                    }
+                    .into(),
+                )),
+            ],
+        }
+    }
+}
+
+impl Rule for RuleTryApplyMaterializedView {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn patterns(&self) -> &Vec<SExpr> {
+        &self.patterns
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let (scan, predicates): (Scan, &[ScalarExpr]) = match s_expr.plan() {
+            RelOperator::Filter(filter) => (
+                s_expr.child(0)?.plan().clone().try_into()?,
+                &filter.predicates,
+            ),
+            _ => (s_expr.plan().clone().try_into()?, &[]),
+        };
+        if scan.push_down_predicates.is_some()
+            || scan.limit.is_some()
+            || scan.order_by.is_some()
+            || scan.prewhere.is_some()
+            || scan.agg_index.is_some()
+            || scan.sample.is_some()
+        {
+            return Ok(());
+        }
+
+        let matched = {
+            let metadata = self.metadata.read();
+            match metadata.get_materialized_views(scan.table_index) {
+                Some(views) => views
+                    .iter()
+                    .find_map(|view| try_match(&metadata, &scan, predicates, view)),
+                None => None,
+            }
+        };
+        let (view, columns) = match matched {
+            Some(matched) => matched,
+            None => return Ok(()),
+        };
+
+        let mut metadata = self.metadata.write();
+        // The privileges of the source table have been checked, so treat
+        // the view as the source of a view.
+        let view_index = metadata.add_table(
+            view.catalog.clone(),
+            view.database.clone(),
+            view.table.clone(),
+            None,
+            true,
+            false,
+        );
+        let view_columns = metadata
+            .columns_by_table_index(view_index)
+            .into_iter()
+            .filter_map(|column| match column {
+                ColumnEntry::BaseTableColumn(BaseTableColumn {
+                    column_name,
+                    column_index,
+                    data_type,
+                    path_indices: None,
+                    ..
+                }) => Some((column_name, (column_index, data_type))),
+                _ => None,
+            })
+            .collect::<HashMap<_, _>>();
+
+        let mut items = Vec::with_capacity(columns.len());
+        let mut scan_columns = ColumnSet::new();
+        for (index, field_name) in columns {
+            let (column_index, data_type) = &view_columns[&field_name];
+            scan_columns.insert(*column_index);
+            items.push(ScalarItem {
+                scalar: BoundColumnRef {
+                    span: None,
+                    column: ColumnBinding {
+                        database_name: None,
+                        table_name: None,
+                        column_position: None,
+                        table_index: Some(view_index),
+                        column_name: field_name,
+                        index: *column_index,
+                        data_type: Box::new(DataType::from(data_type)),
+                        visibility: Visibility::Visible,
+                        virtual_computed_expr: None,
+                    },
+                }
+                .into(),
+                index,
+            });
+        }
+
+        let view_scan = SExpr::create_leaf(Arc::new(
+            Scan {
+                table_index: view_index,
+                columns: scan_columns,
+                ..Default::default()
+            }
+            .into(),
+        ));
+        let mut result =
+            SExpr::create_unary(Arc::new(EvalScalar { items }.into()), Arc::new(view_scan));
+        if let RelOperator::Filter(filter) = s_expr.plan() {
+            // Rows of the view already satisfy the view predicates,
+            // evaluating them again is harmless.
+            result = SExpr::create_unary(Arc::new(filter.clone().into()), Arc::new(result));
+        }
+
+        result.set_applied_rule(&self.id);
+        state.add_result(result);
+        Ok(())
+    }
+}
+
+/// Check if the query can be answered by `view`, return the view and
+/// the pairs of (column index in query, field name in view).
+fn try_match(
+    metadata: &Metadata,
+    scan: &Scan,
+    predicates: &[ScalarExpr],
+    view: &MaterializedViewEntry,
+) -> Option<(MaterializedViewEntry, Vec<(IndexType, String)>)> {
+    // The view plan should be `[EvalScalar] -> [Filter] -> Scan`.
+    let mut view_items = HashMap::new();
+    let mut view_predicates: &[ScalarExpr] = &[];
+    let mut s_expr = &view.plan;
+    let view_table_index = loop {
+        match s_expr.plan() {
+            RelOperator::EvalScalar(eval) => {
+                for item in eval.items.iter() {
+                    view_items.insert(item.index, &item.scalar);
+                }
+            }
+            RelOperator::Filter(filter) => {
+                view_predicates = &filter.predicates;
+            }
+            RelOperator::Scan(view_scan) => break view_scan.table_index,
+            _ => return None,
+        }
+        s_expr = s_expr.child(0).ok()?;
+    };
+
+    // Map the source table columns output by the view to the view fields.
+    let view_schema = view.table.schema();
+    let mut outputs: HashMap<String, (String, &TableDataType)> = HashMap::new();
+    for (field, index) in view_schema.fields().iter().zip(view.output_columns.iter()) {
+        let index = match view_items.get(index) {
+            Some(ScalarExpr::BoundColumnRef(column)) => column.column.index,
+            Some(_) => continue,
+            None => *index,
+        };
+        if let ColumnEntry::BaseTableColumn(BaseTableColumn {
+            table_index,
+            column_name,
+            data_type,
+            path_indices: None,
+            ..
+        }) = metadata.column(index)
+        {
+            if *table_index == view_table_index && data_type == field.data_type() {
+                outputs
+                    .entry(column_name.clone())
+                    .or_insert((field.name().clone(), data_type));
+            }
+        }
+    }
+
+    // Every column used by the query should be output by the view.
+    let mut used_columns = scan.columns.clone();
+    for predicate in predicates {
+        used_columns.extend(predicate.used_columns());
+    }
+    let mut columns = Vec::with_capacity(used_columns.len());
+    for index in used_columns {
+        match metadata.column(index) {
+            ColumnEntry::BaseTableColumn(BaseTableColumn {
+                column_name,
+                data_type,
+                path_indices: None,
+                ..
+            }) => match outputs.get(column_name) {
+                Some((field_name, field_type)) if *field_type == data_type => {
+                    columns.push((index, field_name.clone()));
+                }
+                _ => return None,
+            },
+            _ => return None,
+        }
+    }
+
+    // The view predicates should be a subset of the query predicates.
+    let query_predicates = predicates
+        .iter()
+        .map(|predicate| format_scalar(metadata, predicate))
+        .collect::<Option<HashSet<_>>>()?;
+    for predicate in view_predicates {
+        if !query_predicates.contains(&format_scalar(metadata, predicate)?) {
+            return None;
+        }
+    }
+
+    Some((view.clone(), columns))
+}
+
+/// Format the scalar with column names, so that the predicates of
+/// the query and the view can be compared.
+fn format_scalar(metadata: &Metadata, scalar: &ScalarExpr) -> Option<String> {
+    match scalar {
+        ScalarExpr::BoundColumnRef(column) => match metadata.column(column.column.index) {
+            ColumnEntry::BaseTableColumn(BaseTableColumn {
+                column_name,
+                path_indices: None,
+                ..
+            }) => Some(column_name.clone()),
+            _ => None,
+        },
+        ScalarExpr::ConstantExpr(constant) => Some(format!("{}", constant.value)),
+        ScalarExpr::FunctionCall(func) => Some(format!(
+            "{}({})",
+            &func.func_name,
+            func.arguments
+                .iter()
+                .map(|arg| format_scalar(metadata, arg))
+                .collect::<Option<Vec<_>>>()?
+                .join(", ")
+        )),
+        ScalarExpr::CastExpr(cast) => Some(format!(
+            "CAST({} AS {})",
+            format_scalar(metadata, &cast.argument)?,
+            cast.target_type
+        )),
+        _ => None,
+    }
+}
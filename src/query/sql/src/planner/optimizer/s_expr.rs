@@ -248,12 +248,16 @@ fn find_subquery(rel_op: &RelOperator) -> bool {
         | RelOperator::Limit(_)
         | RelOperator::Exchange(_)
         | RelOperator::UnionAll(_)
+        | RelOperator::RecursiveCte(_)
+        | RelOperator::RecursiveCteScan(_)
         | RelOperator::Sort(_)
         | RelOperator::DummyTableScan(_)
         | RelOperator::RuntimeFilterSource(_)
         | RelOperator::Pattern(_) => false,
         RelOperator::Join(op) => {
-            op.left_conditions.iter().any(find_subquery_in_expr)
+            // A lateral join is decorrelated together with the subqueries.
+            op.is_lateral
+                || op.left_conditions.iter().any(find_subquery_in_expr)
                 || op.right_conditions.iter().any(find_subquery_in_expr)
                 || op.non_equi_conditions.iter().any(find_subquery_in_expr)
         }
@@ -288,6 +292,11 @@ fn find_subquery(rel_op: &RelOperator) -> bool {
             .srfs
             .iter()
             .any(|expr| find_subquery_in_expr(&expr.scalar)),
+        RelOperator::DictGet(op) => op.items.iter().any(|item| find_subquery_in_expr(&item.key)),
+        RelOperator::Udf(op) => op
+            .items
+            .iter()
+            .any(|item| item.arguments.iter().any(find_subquery_in_expr)),
     }
 }
 
@@ -304,6 +313,7 @@ fn find_subquery_in_expr(expr: &ScalarExpr) -> bool {
         }
         ScalarExpr::AggregateFunction(expr) => expr.args.iter().any(find_subquery_in_expr),
         ScalarExpr::FunctionCall(expr) => expr.arguments.iter().any(find_subquery_in_expr),
+        ScalarExpr::LambdaFunction(expr) => expr.args.iter().any(find_subquery_in_expr),
         ScalarExpr::CastExpr(expr) => find_subquery_in_expr(&expr.argument),
         ScalarExpr::SubqueryExpr(_) => true,
     }
@@ -111,9 +111,17 @@ impl Operator for Aggregate {
                     required.distribution = Distribution::Any;
                 } else {
                     let settings = ctx.get_settings();
+                    let shuffle_mode = if self.grouping_sets.is_empty() {
+                        settings.get_group_by_shuffle_mode()?
+                    } else {
+                        // A row is aggregated into every grouping set, so it can't be
+                        // shuffled by its group items before being expanded. Shuffle the
+                        // partial results instead, whose keys contain the `_grouping_id`.
+                        "before_merge".to_string()
+                    };
 
                     // Group aggregation, enforce `Hash` distribution
-                    required.distribution = match settings.get_group_by_shuffle_mode()?.as_str() {
+                    required.distribution = match shuffle_mode.as_str() {
                         "before_partial" => Ok(Distribution::Hash(
                             self.group_items
                                 .iter()
@@ -21,8 +21,14 @@ use std::time::Instant;
 use common_catalog::plan::StageTableInfo;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::DataField;
+use common_expression::DataSchema;
 use common_expression::DataSchemaRef;
+use common_expression::DataSchemaRefExt;
 use common_expression::Scalar;
+use common_meta_app::principal::OnErrorMode;
 use common_meta_app::principal::StageInfo;
 use common_storage::init_stage_operator;
 use common_storage::StageFileInfo;
@@ -59,6 +65,36 @@ impl FromStr for ValidationMode {
     }
 }
 
+/// How the columns of the files are matched to the columns of the table, by
+/// `MATCH_BY_COLUMN_NAME`.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum ColumnMatchMode {
+    CaseSensitive,
+    CaseInsensitive,
+}
+
+impl ColumnMatchMode {
+    pub fn matches(&self, file_column: &str, table_column: &str) -> bool {
+        match self {
+            ColumnMatchMode::CaseSensitive => file_column == table_column,
+            ColumnMatchMode::CaseInsensitive => file_column.eq_ignore_ascii_case(table_column),
+        }
+    }
+}
+
+impl FromStr for ColumnMatchMode {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, String> {
+        match s.to_uppercase().as_str() {
+            "CASE_SENSITIVE" => Ok(ColumnMatchMode::CaseSensitive),
+            "CASE_INSENSITIVE" => Ok(ColumnMatchMode::CaseInsensitive),
+            v => Err(format!(
+                "Unknown MATCH_BY_COLUMN_NAME mode:{v:?}, must one of {{ CASE_SENSITIVE | CASE_INSENSITIVE }}"
+            )),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Eq, PartialEq)]
 pub enum CopyIntoTableMode {
     Insert { overwrite: bool },
@@ -79,6 +115,10 @@ pub struct CopyIntoTablePlan {
     pub write_mode: CopyIntoTableMode,
     pub validation_mode: ValidationMode,
     pub force: bool,
+    /// Load the parquet columns into the table columns with the same name instead of
+    /// the same position.
+    pub column_match_mode: Option<ColumnMatchMode>,
+    pub error_on_column_count_mismatch: bool,
 
     pub stage_table_info: StageTableInfo,
     pub query: Option<Box<Plan>>,
@@ -172,6 +212,7 @@ impl Debug for CopyIntoTablePlan {
             table_name,
             validation_mode,
             force,
+            column_match_mode,
             stage_table_info,
             query,
             ..
@@ -183,6 +224,9 @@ impl Debug for CopyIntoTablePlan {
         write!(f, ", validation_mode: {validation_mode:?}")?;
         write!(f, ", from: {stage_table_info:?}")?;
         write!(f, " force: {force}")?;
+        if let Some(column_match_mode) = column_match_mode {
+            write!(f, " column_match_mode: {column_match_mode:?}")?;
+        }
         write!(f, " query: {query:?}")?;
         Ok(())
     }
@@ -197,10 +241,43 @@ pub enum CopyPlan {
         stage: Box<StageInfo>,
         path: String,
         validation_mode: ValidationMode,
+        /// Names of the hive-style partition keys, their values are the trailing
+        /// columns of `from`.
+        partition_by: Vec<String>,
         from: Box<Plan>,
     },
 }
 
+impl CopyPlan {
+    /// `COPY INTO <table>` with `ON_ERROR = CONTINUE` returns the number of the loaded and
+    /// the rejected rows, and where the rejected records are quarantined.
+    pub fn has_result_set(&self) -> bool {
+        match self {
+            CopyPlan::IntoTable(plan) => {
+                plan.write_mode == CopyIntoTableMode::Copy
+                    && plan.stage_table_info.stage_info.copy_options.on_error
+                        == OnErrorMode::Continue
+            }
+            _ => false,
+        }
+    }
+
+    pub fn schema(&self) -> DataSchemaRef {
+        if self.has_result_set() {
+            DataSchemaRefExt::create(vec![
+                DataField::new("rows_loaded", DataType::Number(NumberDataType::UInt64)),
+                DataField::new("rows_rejected", DataType::Number(NumberDataType::UInt64)),
+                DataField::new(
+                    "rejected_file",
+                    DataType::Nullable(Box::new(DataType::String)),
+                ),
+            ])
+        } else {
+            Arc::new(DataSchema::empty())
+        }
+    }
+}
+
 impl Debug for CopyPlan {
     // Ignore the schema.
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -212,11 +289,15 @@ impl Debug for CopyPlan {
                 stage,
                 path,
                 validation_mode,
+                partition_by,
                 ..
             } => {
                 write!(f, "Copy into {stage:?}")?;
                 write!(f, ", path: {path:?}")?;
                 write!(f, ", validation_mode: {validation_mode:?}")?;
+                if !partition_by.is_empty() {
+                    write!(f, ", partition_by: {partition_by:?}")?;
+                }
             }
             CopyPlan::NoFileToCopy => {
                 write!(f, "No file to copy")?;
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use common_expression::types::DataType;
 use common_expression::types::NumberDataType;
 use common_expression::DataField;
@@ -58,6 +60,13 @@ pub struct DropRolePlan {
     pub role_name: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterRolePlan {
+    pub role_name: String,
+    pub set_settings: BTreeMap<String, String>,
+    pub unset_settings: Vec<String>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct GrantRolePlan {
     pub principal: PrincipalIdentity,
@@ -105,12 +114,14 @@ impl ShowRolesPlan {
 pub struct GrantPrivilegePlan {
     pub principal: PrincipalIdentity,
     pub priv_types: UserPrivilegeSet,
-    pub on: GrantObject,
+    /// A column level grant `SELECT(c1, c2) ON t` has one object per column.
+    pub on: Vec<GrantObject>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct RevokePrivilegePlan {
     pub principal: PrincipalIdentity,
     pub priv_types: UserPrivilegeSet,
-    pub on: GrantObject,
+    /// A column level grant `SELECT(c1, c2) ON t` has one object per column.
+    pub on: Vec<GrantObject>,
 }
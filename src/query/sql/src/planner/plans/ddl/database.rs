@@ -12,7 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::DataField;
 use common_expression::DataSchemaRef;
+use common_expression::DataSchemaRefExt;
+use common_meta_app::principal::StageInfo;
 use common_meta_app::schema::CreateDatabaseReq;
 use common_meta_app::schema::DatabaseMeta;
 use common_meta_app::schema::DatabaseNameIdent;
@@ -122,6 +127,41 @@ impl From<UndropDatabasePlan> for UndropDatabaseReq {
     }
 }
 
+/// Backup.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BackupDatabasePlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub stage: StageInfo,
+    /// The directory of the backup in the stage, empty or ends with `/`.
+    pub path: String,
+    pub with_data: bool,
+}
+
+impl BackupDatabasePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("table", DataType::String),
+            DataField::new("engine", DataType::String),
+            DataField::new("files", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("copied_files", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("copied_bytes", DataType::Number(NumberDataType::UInt64)),
+        ])
+    }
+}
+
+/// Restore.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RestoreDatabasePlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub stage: StageInfo,
+    /// The directory of the backup in the stage, empty or ends with `/`.
+    pub path: String,
+}
+
 /// Use.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct UseDatabasePlan {
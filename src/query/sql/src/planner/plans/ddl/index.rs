@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use common_ast::ast::TableIndexType;
+use common_catalog::plan::AggIndexAggregation;
 use common_meta_types::MetaId;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -22,6 +23,11 @@ pub struct CreateIndexPlan {
     pub index_name: String,
     pub query: String,
     pub table_id: MetaId,
+    pub database: String,
+    pub table: String,
+    /// The partial aggregation computing the index data of each block,
+    /// [None] if the index is not an aggregation.
+    pub aggregation: Option<AggIndexAggregation>,
 }
 
 /// Drop.
@@ -15,10 +15,17 @@
 mod account;
 mod catalog;
 mod database;
+mod dictionary;
 mod file_format;
 mod index;
+mod network_policy;
+mod notification;
+mod pipe;
+mod row_access_policy;
+mod sequence;
 mod stage;
 mod table;
+mod task;
 mod udf;
 mod view;
 mod virtual_column;
@@ -26,10 +33,17 @@ mod virtual_column;
 pub use account::*;
 pub use catalog::*;
 pub use database::*;
+pub use dictionary::*;
 pub use file_format::*;
 pub use index::*;
+pub use network_policy::*;
+pub use notification::*;
+pub use pipe::*;
+pub use row_access_policy::*;
+pub use sequence::*;
 pub use stage::*;
 pub use table::*;
+pub use task::*;
 pub use udf::*;
 pub use view::*;
 pub use virtual_column::*;
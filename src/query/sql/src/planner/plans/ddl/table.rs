@@ -15,6 +15,7 @@
 use std::collections::BTreeMap;
 use std::sync::Arc;
 
+use common_ast::ast::ClusterType;
 use common_ast::ast::Engine;
 use common_ast::ast::ModifyColumnAction;
 use common_catalog::table::NavigationPoint;
@@ -26,6 +27,7 @@ use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
 use common_expression::TableField;
 use common_expression::TableSchemaRef;
+use common_meta_app::principal::SequenceMeta;
 use common_meta_app::schema::TableNameIdent;
 use common_meta_app::schema::UndropTableReq;
 use common_meta_app::storage::StorageParams;
@@ -50,6 +52,10 @@ pub struct CreateTablePlan {
     pub field_comments: Vec<String>,
     pub cluster_key: Option<String>,
     pub as_select: Option<Box<Plan>>,
+    /// `USING TEMPLATE` query, the schema is built from its result when the table is created.
+    pub template: Option<Box<Plan>>,
+    /// Sequences of the identity columns, created with the table.
+    pub identity_sequences: Vec<SequenceMeta>,
 }
 
 impl CreateTablePlan {
@@ -215,6 +221,22 @@ impl DropTableColumnPlan {
     }
 }
 
+// Table rename column
+#[derive(Clone, Debug, PartialEq)]
+pub struct RenameTableColumnPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub old_column: String,
+    pub new_column: String,
+}
+
+impl RenameTableColumnPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
 // Table modify column
 #[derive(Clone, Debug, PartialEq)]
 pub struct ModifyTableColumnPlan {
@@ -318,6 +340,7 @@ pub struct AlterTableClusterKeyPlan {
     pub catalog: String,
     pub database: String,
     pub table: String,
+    pub cluster_type: ClusterType,
     pub cluster_keys: Vec<String>,
 }
 
@@ -340,3 +363,35 @@ impl DropTableClusterKeyPlan {
         Arc::new(DataSchema::empty())
     }
 }
+
+/// Row access policy.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AddTableRowAccessPolicyPlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub policy: String,
+    pub columns: Vec<String>,
+}
+
+impl AddTableRowAccessPolicyPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropTableRowAccessPolicyPlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub policy: String,
+}
+
+impl DropTableRowAccessPolicyPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
@@ -0,0 +1,44 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_meta_app::principal::TaskMeta;
+use common_meta_app::principal::TaskState;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateTaskPlan {
+    pub if_not_exists: bool,
+    pub tenant: String,
+    pub meta: TaskMeta,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropTaskPlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub name: String,
+}
+
+/// `ALTER TASK ... SUSPEND | RESUME`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterTaskPlan {
+    pub tenant: String,
+    pub name: String,
+    pub state: TaskState,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecuteTaskPlan {
+    pub tenant: String,
+    pub name: String,
+}
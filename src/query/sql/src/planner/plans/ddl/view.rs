@@ -12,6 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_expression::TableSchemaRef;
+
+use crate::plans::TableOptions;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CreateViewPlan {
     pub if_not_exists: bool,
@@ -41,3 +45,23 @@ pub struct DropViewPlan {
     pub database: String,
     pub view_name: String,
 }
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateMaterializedViewPlan {
+    pub if_not_exists: bool,
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub view_name: String,
+    pub schema: TableSchemaRef,
+    /// Options of the underlying fuse table, including the materialized view options.
+    pub options: TableOptions,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RefreshMaterializedViewPlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub view_name: String,
+}
@@ -0,0 +1,93 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_meta_app::principal::DictionarySource;
+
+use crate::optimizer::ColumnSet;
+use crate::optimizer::PhysicalProperty;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::RequiredProperty;
+use crate::optimizer::StatInfo;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::IndexType;
+use crate::ScalarExpr;
+
+/// A `dict_get` call, which looks up `attribute` of the dictionary by `key`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DictGetItem {
+    pub key: ScalarExpr,
+    pub source: DictionarySource,
+    pub primary_key: String,
+    pub attribute: String,
+    /// the type of the attribute, always nullable since keys may be missing.
+    pub data_type: DataType,
+    pub index: IndexType,
+}
+
+/// `DictGet` evaluates the `dict_get` calls of the select list against
+/// the external sources of the dictionaries, and appends the results to
+/// its input relation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DictGet {
+    pub items: Vec<DictGetItem>,
+}
+
+impl DictGet {
+    pub fn used_columns(&self) -> Result<ColumnSet> {
+        let mut used_columns = ColumnSet::new();
+        for item in self.items.iter() {
+            used_columns.extend(item.key.used_columns());
+        }
+        Ok(used_columns)
+    }
+}
+
+impl Operator for DictGet {
+    fn rel_op(&self) -> RelOp {
+        RelOp::DictGet
+    }
+
+    fn derive_relational_prop(&self, rel_expr: &RelExpr) -> Result<Arc<RelationalProperty>> {
+        let mut child_prop = rel_expr.derive_relational_prop_child(0)?.as_ref().clone();
+        for item in &self.items {
+            child_prop.output_columns.insert(item.index);
+            child_prop.used_columns.extend(item.key.used_columns());
+        }
+        Ok(Arc::new(child_prop))
+    }
+
+    fn derive_physical_prop(&self, rel_expr: &RelExpr) -> Result<PhysicalProperty> {
+        rel_expr.derive_physical_prop_child(0)
+    }
+
+    fn derive_cardinality(&self, rel_expr: &RelExpr) -> Result<Arc<StatInfo>> {
+        rel_expr.derive_cardinality_child(0)
+    }
+
+    fn compute_required_prop_child(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _child_index: usize,
+        required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        Ok(required.clone())
+    }
+}
@@ -146,6 +146,9 @@ pub struct Join {
     pub from_correlated_subquery: bool,
     // It means that join has a corresponding runtime filter
     pub contain_runtime_filter: bool,
+    // The right child references columns of the left child, it's a `LATERAL` join
+    // which will be decorrelated by `SubqueryRewriter`.
+    pub is_lateral: bool,
 }
 
 impl Default for Join {
@@ -158,6 +161,7 @@ impl Default for Join {
             marker_index: Default::default(),
             from_correlated_subquery: Default::default(),
             contain_runtime_filter: false,
+            is_lateral: false,
         }
     }
 }
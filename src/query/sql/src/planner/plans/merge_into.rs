@@ -0,0 +1,59 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_meta_types::MetaId;
+
+use crate::plans::Plan;
+
+/// The matched target row is replaced by the new values.
+pub const MERGE_INTO_ACTION_UPDATE: u8 = 1;
+/// The matched target row is deleted.
+pub const MERGE_INTO_ACTION_DELETE: u8 = 2;
+/// The new values are inserted into the target table.
+pub const MERGE_INTO_ACTION_INSERT: u8 = 3;
+
+pub const MERGE_INTO_ROW_ID_COL_NAME: &str = "__merge_row_id";
+pub const MERGE_INTO_ACTION_COL_NAME: &str = "__merge_action";
+
+#[derive(Clone)]
+pub struct MergeInto {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub table_id: MetaId,
+    /// The join of the source and the target table. Each row of it holds the row id
+    /// of the matched target row (NULL if not matched), the action to be taken, and
+    /// the new values of the target columns, only rows with actions are output.
+    pub input: Box<Plan>,
+}
+
+impl PartialEq for MergeInto {
+    fn eq(&self, other: &Self) -> bool {
+        self.catalog == other.catalog
+            && self.database == other.database
+            && self.table == other.table
+            && self.table_id == other.table_id
+    }
+}
+
+impl std::fmt::Debug for MergeInto {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MergeInto")
+            .field("catalog", &self.catalog)
+            .field("database", &self.database)
+            .field("table", &self.table)
+            .field("table_id", &self.table_id)
+            .finish()
+    }
+}
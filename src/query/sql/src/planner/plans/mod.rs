@@ -14,10 +14,12 @@
 
 mod aggregate;
 mod call;
+mod cluster;
 mod copy;
 pub mod data_mask;
 mod ddl;
 mod delete;
+mod dict_get;
 mod dummy_table_scan;
 mod eval_scalar;
 mod exchange;
@@ -26,12 +28,14 @@ pub mod insert;
 mod join;
 mod kill;
 mod limit;
+mod merge_into;
 mod operator;
 mod pattern;
 mod plan;
 mod presign;
 mod project_set;
 mod recluster_table;
+mod recursive_cte;
 mod replace;
 mod revert_table;
 mod runtime_filter_source;
@@ -40,17 +44,20 @@ mod scan;
 mod setting;
 pub mod share;
 mod sort;
+mod udf;
 mod union_all;
 mod update;
 mod window;
 
 pub use aggregate::*;
 pub use call::CallPlan;
+pub use cluster::DrainClusterNodePlan;
 pub use copy::*;
 pub use data_mask::*;
 pub use ddl::*;
 pub use delete::DeletePlan;
 pub use delete::SubqueryDesc;
+pub use dict_get::*;
 pub use dummy_table_scan::DummyTableScan;
 pub use eval_scalar::*;
 pub use exchange::*;
@@ -60,6 +67,7 @@ pub use insert::InsertInputSource;
 pub use join::*;
 pub use kill::KillPlan;
 pub use limit::*;
+pub use merge_into::*;
 pub use operator::*;
 pub use pattern::PatternPlan;
 pub use plan::Plan::*;
@@ -68,6 +76,7 @@ pub use plan::*;
 pub use presign::*;
 pub use project_set::*;
 pub use recluster_table::ReclusterTablePlan;
+pub use recursive_cte::*;
 pub use replace::Replace;
 pub use revert_table::RevertTablePlan;
 pub use runtime_filter_source::RuntimeFilterId;
@@ -77,6 +86,7 @@ pub use scan::*;
 pub use setting::*;
 pub use share::*;
 pub use sort::*;
+pub use udf::*;
 pub use union_all::UnionAll;
 pub use update::UpdatePlan;
 pub use window::*;
@@ -34,8 +34,12 @@ use crate::optimizer::RelationalProperty;
 use crate::optimizer::RequiredProperty;
 use crate::optimizer::StatInfo;
 use crate::plans::runtime_filter_source::RuntimeFilterSource;
+use crate::plans::DictGet;
 use crate::plans::Exchange;
 use crate::plans::ProjectSet;
+use crate::plans::RecursiveCte;
+use crate::plans::RecursiveCteScan;
+use crate::plans::Udf;
 use crate::plans::Window;
 
 pub trait Operator {
@@ -76,6 +80,10 @@ pub enum RelOp {
     RuntimeFilterSource,
     Window,
     ProjectSet,
+    DictGet,
+    Udf,
+    RecursiveCte,
+    RecursiveCteScan,
 
     // Pattern
     Pattern,
@@ -97,6 +105,10 @@ pub enum RelOperator {
     RuntimeFilterSource(RuntimeFilterSource),
     Window(Window),
     ProjectSet(ProjectSet),
+    DictGet(DictGet),
+    Udf(Udf),
+    RecursiveCte(RecursiveCte),
+    RecursiveCteScan(RecursiveCteScan),
 
     Pattern(PatternPlan),
 }
@@ -117,6 +129,10 @@ impl Operator for RelOperator {
             RelOperator::DummyTableScan(rel_op) => rel_op.rel_op(),
             RelOperator::RuntimeFilterSource(rel_op) => rel_op.rel_op(),
             RelOperator::ProjectSet(rel_op) => rel_op.rel_op(),
+            RelOperator::DictGet(rel_op) => rel_op.rel_op(),
+            RelOperator::Udf(rel_op) => rel_op.rel_op(),
+            RelOperator::RecursiveCte(rel_op) => rel_op.rel_op(),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.rel_op(),
             RelOperator::Window(rel_op) => rel_op.rel_op(),
         }
     }
@@ -136,6 +152,10 @@ impl Operator for RelOperator {
             RelOperator::DummyTableScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::RuntimeFilterSource(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::ProjectSet(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::DictGet(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::Udf(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::RecursiveCte(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::Window(rel_op) => rel_op.derive_relational_prop(rel_expr),
         }
     }
@@ -155,6 +175,10 @@ impl Operator for RelOperator {
             RelOperator::DummyTableScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::RuntimeFilterSource(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::ProjectSet(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::DictGet(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::Udf(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::RecursiveCte(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::Window(rel_op) => rel_op.derive_physical_prop(rel_expr),
         }
     }
@@ -174,6 +198,10 @@ impl Operator for RelOperator {
             RelOperator::DummyTableScan(rel_op) => rel_op.derive_cardinality(rel_expr),
             RelOperator::RuntimeFilterSource(rel_op) => rel_op.derive_cardinality(rel_expr),
             RelOperator::ProjectSet(rel_op) => rel_op.derive_cardinality(rel_expr),
+            RelOperator::DictGet(rel_op) => rel_op.derive_cardinality(rel_expr),
+            RelOperator::Udf(rel_op) => rel_op.derive_cardinality(rel_expr),
+            RelOperator::RecursiveCte(rel_op) => rel_op.derive_cardinality(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_cardinality(rel_expr),
             RelOperator::Window(rel_op) => rel_op.derive_cardinality(rel_expr),
         }
     }
@@ -228,6 +256,18 @@ impl Operator for RelOperator {
             RelOperator::ProjectSet(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
+            RelOperator::DictGet(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
+            RelOperator::Udf(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
+            RelOperator::RecursiveCte(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
+            RelOperator::RecursiveCteScan(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
         }
     }
 }
@@ -492,3 +532,81 @@ impl TryFrom<RelOperator> for ProjectSet {
         }
     }
 }
+
+impl From<DictGet> for RelOperator {
+    fn from(value: DictGet) -> Self {
+        Self::DictGet(value)
+    }
+}
+
+impl TryFrom<RelOperator> for DictGet {
+    type Error = ErrorCode;
+
+    fn try_from(value: RelOperator) -> std::result::Result<Self, Self::Error> {
+        if let RelOperator::DictGet(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to DictGet",
+            ))
+        }
+    }
+}
+
+impl From<RecursiveCte> for RelOperator {
+    fn from(value: RecursiveCte) -> Self {
+        Self::RecursiveCte(value)
+    }
+}
+
+impl TryFrom<RelOperator> for RecursiveCte {
+    type Error = ErrorCode;
+
+    fn try_from(value: RelOperator) -> std::result::Result<Self, Self::Error> {
+        if let RelOperator::RecursiveCte(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to RecursiveCte",
+            ))
+        }
+    }
+}
+
+impl From<RecursiveCteScan> for RelOperator {
+    fn from(value: RecursiveCteScan) -> Self {
+        Self::RecursiveCteScan(value)
+    }
+}
+
+impl TryFrom<RelOperator> for RecursiveCteScan {
+    type Error = ErrorCode;
+
+    fn try_from(value: RelOperator) -> std::result::Result<Self, Self::Error> {
+        if let RelOperator::RecursiveCteScan(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to RecursiveCteScan",
+            ))
+        }
+    }
+}
+
+impl From<Udf> for RelOperator {
+    fn from(value: Udf) -> Self {
+        Self::Udf(value)
+    }
+}
+
+impl TryFrom<RelOperator> for Udf {
+    type Error = ErrorCode;
+
+    fn try_from(value: RelOperator) -> std::result::Result<Self, Self::Error> {
+        if let RelOperator::Udf(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal("Cannot downcast RelOperator to Udf"))
+        }
+    }
+}
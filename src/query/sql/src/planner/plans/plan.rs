@@ -49,47 +49,72 @@ use crate::plans::share::ShowGrantTenantsOfSharePlan;
 use crate::plans::share::ShowObjectGrantPrivilegesPlan;
 use crate::plans::share::ShowSharesPlan;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTableRowAccessPolicyPlan;
+use crate::plans::AlterRolePlan;
 use crate::plans::AlterTableClusterKeyPlan;
+use crate::plans::AlterTaskPlan;
 use crate::plans::AlterUDFPlan;
 use crate::plans::AlterUserPlan;
 use crate::plans::AlterViewPlan;
 use crate::plans::AlterVirtualColumnsPlan;
 use crate::plans::AnalyzeTablePlan;
+use crate::plans::BackupDatabasePlan;
 use crate::plans::CallPlan;
 use crate::plans::CreateCatalogPlan;
 use crate::plans::CreateDatabasePlan;
+use crate::plans::CreateDictionaryPlan;
 use crate::plans::CreateFileFormatPlan;
+use crate::plans::CreateMaterializedViewPlan;
+use crate::plans::CreateNetworkPolicyPlan;
+use crate::plans::CreateNotificationIntegrationPlan;
+use crate::plans::CreatePipePlan;
 use crate::plans::CreateRolePlan;
+use crate::plans::CreateRowAccessPolicyPlan;
+use crate::plans::CreateSequencePlan;
 use crate::plans::CreateStagePlan;
 use crate::plans::CreateTablePlan;
+use crate::plans::CreateTaskPlan;
 use crate::plans::CreateUDFPlan;
 use crate::plans::CreateUserPlan;
 use crate::plans::CreateViewPlan;
 use crate::plans::CreateVirtualColumnsPlan;
 use crate::plans::DeletePlan;
 use crate::plans::DescribeTablePlan;
+use crate::plans::DrainClusterNodePlan;
 use crate::plans::DropCatalogPlan;
 use crate::plans::DropDatabasePlan;
+use crate::plans::DropDictionaryPlan;
 use crate::plans::DropFileFormatPlan;
+use crate::plans::DropNetworkPolicyPlan;
+use crate::plans::DropNotificationIntegrationPlan;
+use crate::plans::DropPipePlan;
 use crate::plans::DropRolePlan;
+use crate::plans::DropRowAccessPolicyPlan;
+use crate::plans::DropSequencePlan;
 use crate::plans::DropStagePlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTaskPlan;
 use crate::plans::DropUDFPlan;
 use crate::plans::DropUserPlan;
 use crate::plans::DropViewPlan;
 use crate::plans::DropVirtualColumnsPlan;
+use crate::plans::ExecuteTaskPlan;
 use crate::plans::ExistsTablePlan;
 use crate::plans::GenerateVirtualColumnsPlan;
 use crate::plans::GrantPrivilegePlan;
 use crate::plans::GrantRolePlan;
 use crate::plans::KillPlan;
+use crate::plans::MergeInto;
 use crate::plans::OptimizeTablePlan;
+use crate::plans::RefreshMaterializedViewPlan;
 use crate::plans::RemoveStagePlan;
 use crate::plans::RenameDatabasePlan;
+use crate::plans::RenameTableColumnPlan;
 use crate::plans::RenameTablePlan;
 use crate::plans::Replace;
+use crate::plans::RestoreDatabasePlan;
 use crate::plans::RevertTablePlan;
 use crate::plans::RevokePrivilegePlan;
 use crate::plans::RevokeRolePlan;
@@ -119,8 +144,6 @@ pub enum Plan {
         metadata: MetadataRef,
         bind_context: Box<BindContext>,
         rewrite_kind: Option<RewriteKind>,
-        // Use for generate query result cache key.
-        formatted_ast: Option<String>,
         ignore_result: bool,
     },
 
@@ -154,6 +177,8 @@ pub enum Plan {
     CreateDatabase(Box<CreateDatabasePlan>),
     DropDatabase(Box<DropDatabasePlan>),
     UndropDatabase(Box<UndropDatabasePlan>),
+    BackupDatabase(Box<BackupDatabasePlan>),
+    RestoreDatabase(Box<RestoreDatabasePlan>),
     RenameDatabase(Box<RenameDatabasePlan>),
     UseDatabase(Box<UseDatabasePlan>),
 
@@ -165,6 +190,7 @@ pub enum Plan {
     UndropTable(Box<UndropTablePlan>),
     RenameTable(Box<RenameTablePlan>),
     AddTableColumn(Box<AddTableColumnPlan>),
+    RenameTableColumn(Box<RenameTableColumnPlan>),
     DropTableColumn(Box<DropTableColumnPlan>),
     ModifyTableColumn(Box<ModifyTableColumnPlan>),
     AlterTableClusterKey(Box<AlterTableClusterKeyPlan>),
@@ -177,18 +203,30 @@ pub enum Plan {
     AnalyzeTable(Box<AnalyzeTablePlan>),
     ExistsTable(Box<ExistsTablePlan>),
     SetOptions(Box<SetOptionsPlan>),
+    AddTableRowAccessPolicy(Box<AddTableRowAccessPolicyPlan>),
+    DropTableRowAccessPolicy(Box<DropTableRowAccessPolicyPlan>),
 
     // Insert
     Insert(Box<Insert>),
     Replace(Box<Replace>),
+    MergeInto(Box<MergeInto>),
     Delete(Box<DeletePlan>),
     Update(Box<UpdatePlan>),
 
+    // Transactions
+    Begin,
+    Commit,
+    Abort,
+
     // Views
     CreateView(Box<CreateViewPlan>),
     AlterView(Box<AlterViewPlan>),
     DropView(Box<DropViewPlan>),
 
+    // Materialized views
+    CreateMaterializedView(Box<CreateMaterializedViewPlan>),
+    RefreshMaterializedView(Box<RefreshMaterializedViewPlan>),
+
     // Indexes
     CreateIndex(Box<CreateIndexPlan>),
     DropIndex(Box<DropIndexPlan>),
@@ -209,10 +247,41 @@ pub enum Plan {
     AlterUDF(Box<AlterUDFPlan>),
     DropUDF(Box<DropUDFPlan>),
 
+    // Row access policy
+    CreateRowAccessPolicy(Box<CreateRowAccessPolicyPlan>),
+    DropRowAccessPolicy(Box<DropRowAccessPolicyPlan>),
+
+    // Network policy
+    CreateNetworkPolicy(Box<CreateNetworkPolicyPlan>),
+    DropNetworkPolicy(Box<DropNetworkPolicyPlan>),
+
+    // Dictionary
+    CreateDictionary(Box<CreateDictionaryPlan>),
+    DropDictionary(Box<DropDictionaryPlan>),
+
+    // Sequence
+    CreateSequence(Box<CreateSequencePlan>),
+    DropSequence(Box<DropSequencePlan>),
+
+    // Task
+    CreateTask(Box<CreateTaskPlan>),
+    DropTask(Box<DropTaskPlan>),
+    AlterTask(Box<AlterTaskPlan>),
+    ExecuteTask(Box<ExecuteTaskPlan>),
+
+    // Notification integration
+    CreateNotificationIntegration(Box<CreateNotificationIntegrationPlan>),
+    DropNotificationIntegration(Box<DropNotificationIntegrationPlan>),
+
+    // Pipe
+    CreatePipe(Box<CreatePipePlan>),
+    DropPipe(Box<DropPipePlan>),
+
     // Role
     ShowRoles(Box<ShowRolesPlan>),
     CreateRole(Box<CreateRolePlan>),
     DropRole(Box<DropRolePlan>),
+    AlterRole(Box<AlterRolePlan>),
     GrantRole(Box<GrantRolePlan>),
     GrantPriv(Box<GrantPrivilegePlan>),
     ShowGrants(Box<ShowGrantsPlan>),
@@ -238,6 +307,9 @@ pub enum Plan {
     UnSetVariable(Box<UnSettingPlan>),
     Kill(Box<KillPlan>),
 
+    // Cluster
+    DrainClusterNode(Box<DrainClusterNodePlan>),
+
     // Share
     CreateShareEndpoint(Box<CreateShareEndpointPlan>),
     ShowShareEndpoint(Box<ShowShareEndpointPlan>),
@@ -269,6 +341,7 @@ pub enum RewriteKind {
     ShowCatalogs,
     ShowDatabases,
     ShowTables,
+    ShowDropTables,
     ShowColumns,
     ShowTablesStatus,
 
@@ -302,6 +375,8 @@ impl Display for Plan {
             Plan::CreateDatabase(_) => write!(f, "CreateDatabase"),
             Plan::DropDatabase(_) => write!(f, "DropDatabase"),
             Plan::UndropDatabase(_) => write!(f, "UndropDatabase"),
+            Plan::BackupDatabase(_) => write!(f, "BackupDatabase"),
+            Plan::RestoreDatabase(_) => write!(f, "RestoreDatabase"),
             Plan::UseDatabase(_) => write!(f, "UseDatabase"),
             Plan::RenameDatabase(_) => write!(f, "RenameDatabase"),
             Plan::ShowCreateTable(_) => write!(f, "ShowCreateTable"),
@@ -311,6 +386,7 @@ impl Display for Plan {
             Plan::UndropTable(_) => write!(f, "UndropTable"),
             Plan::RenameTable(_) => write!(f, "RenameTable"),
             Plan::AddTableColumn(_) => write!(f, "AddTableColumn"),
+            Plan::RenameTableColumn(_) => write!(f, "RenameTableColumn"),
             Plan::ModifyTableColumn(_) => write!(f, "ModifyTableColumn"),
             Plan::DropTableColumn(_) => write!(f, "DropTableColumn"),
             Plan::AlterTableClusterKey(_) => write!(f, "AlterTableClusterKey"),
@@ -324,6 +400,8 @@ impl Display for Plan {
             Plan::CreateView(_) => write!(f, "CreateView"),
             Plan::AlterView(_) => write!(f, "AlterView"),
             Plan::DropView(_) => write!(f, "DropView"),
+            Plan::CreateMaterializedView(_) => write!(f, "CreateMaterializedView"),
+            Plan::RefreshMaterializedView(_) => write!(f, "RefreshMaterializedView"),
             Plan::CreateIndex(_) => write!(f, "CreateIndex"),
             Plan::DropIndex(_) => write!(f, "DropIndex"),
             Plan::CreateVirtualColumns(_) => write!(f, "CreateVirtualColumns"),
@@ -335,6 +413,7 @@ impl Display for Plan {
             Plan::DropUser(_) => write!(f, "DropUser"),
             Plan::CreateRole(_) => write!(f, "CreateRole"),
             Plan::DropRole(_) => write!(f, "DropRole"),
+            Plan::AlterRole(_) => write!(f, "AlterRole"),
             Plan::CreateStage(_) => write!(f, "CreateStage"),
             Plan::DropStage(_) => write!(f, "DropStage"),
             Plan::CreateFileFormat(_) => write!(f, "CreateFileFormat"),
@@ -350,16 +429,37 @@ impl Display for Plan {
             Plan::CreateUDF(_) => write!(f, "CreateUDF"),
             Plan::AlterUDF(_) => write!(f, "AlterUDF"),
             Plan::DropUDF(_) => write!(f, "DropUDF"),
+            Plan::CreateRowAccessPolicy(_) => write!(f, "CreateRowAccessPolicy"),
+            Plan::DropRowAccessPolicy(_) => write!(f, "DropRowAccessPolicy"),
+            Plan::CreateNetworkPolicy(_) => write!(f, "CreateNetworkPolicy"),
+            Plan::DropNetworkPolicy(_) => write!(f, "DropNetworkPolicy"),
+            Plan::CreateDictionary(_) => write!(f, "CreateDictionary"),
+            Plan::DropDictionary(_) => write!(f, "DropDictionary"),
+            Plan::CreateSequence(_) => write!(f, "CreateSequence"),
+            Plan::DropSequence(_) => write!(f, "DropSequence"),
+            Plan::CreateTask(_) => write!(f, "CreateTask"),
+            Plan::DropTask(_) => write!(f, "DropTask"),
+            Plan::AlterTask(_) => write!(f, "AlterTask"),
+            Plan::ExecuteTask(_) => write!(f, "ExecuteTask"),
+            Plan::CreateNotificationIntegration(_) => write!(f, "CreateNotificationIntegration"),
+            Plan::DropNotificationIntegration(_) => write!(f, "DropNotificationIntegration"),
+            Plan::CreatePipe(_) => write!(f, "CreatePipe"),
+            Plan::DropPipe(_) => write!(f, "DropPipe"),
             Plan::Insert(_) => write!(f, "Insert"),
             Plan::Replace(_) => write!(f, "Replace"),
+            Plan::MergeInto(_) => write!(f, "MergeInto"),
             Plan::Delete(_) => write!(f, "Delete"),
             Plan::Update(_) => write!(f, "Update"),
+            Plan::Begin => write!(f, "Begin"),
+            Plan::Commit => write!(f, "Commit"),
+            Plan::Abort => write!(f, "Abort"),
             Plan::Call(_) => write!(f, "Call"),
             Plan::Presign(_) => write!(f, "Presign"),
             Plan::SetVariable(_) => write!(f, "SetVariable"),
             Plan::UnSetVariable(_) => write!(f, "UnSetVariable"),
             Plan::SetRole(_) => write!(f, "SetRole"),
             Plan::Kill(_) => write!(f, "Kill"),
+            Plan::DrainClusterNode(_) => write!(f, "DrainClusterNode"),
             Plan::CreateShareEndpoint(_) => write!(f, "CreateShareEndpoint"),
             Plan::ShowShareEndpoint(_) => write!(f, "ShowShareEndpoint"),
             Plan::DropShareEndpoint(_) => write!(f, "DropShareEndpoint"),
@@ -384,6 +484,8 @@ impl Display for Plan {
             Plan::DescDatamaskPolicy(..) => {
                 write!(f, "Desc Data Mask Policy")
             }
+            Plan::AddTableRowAccessPolicy(_) => write!(f, "AddTableRowAccessPolicy"),
+            Plan::DropTableRowAccessPolicy(_) => write!(f, "DropTableRowAccessPolicy"),
             Plan::SetOptions(..) => {
                 write!(f, "SetOptions")
             }
@@ -410,6 +512,7 @@ impl Plan {
             }
             Plan::ShowCreateCatalog(plan) => plan.schema(),
             Plan::ShowCreateDatabase(plan) => plan.schema(),
+            Plan::BackupDatabase(plan) => plan.schema(),
             Plan::ShowCreateTable(plan) => plan.schema(),
             Plan::DescribeTable(plan) => plan.schema(),
             Plan::VacuumTable(plan) => plan.schema(),
@@ -422,6 +525,7 @@ impl Plan {
             Plan::Replace(plan) => plan.schema(),
 
             Plan::Call(_) => Arc::new(DataSchema::empty()),
+            Plan::Copy(plan) => plan.schema(),
             Plan::Presign(plan) => plan.schema(),
             Plan::ShowShareEndpoint(plan) => plan.schema(),
             Plan::DescShare(plan) => plan.schema(),
@@ -438,6 +542,9 @@ impl Plan {
     }
 
     pub fn has_result_set(&self) -> bool {
+        if let Plan::Copy(plan) = self {
+            return plan.has_result_set();
+        }
         matches!(
             self,
             Plan::Query { .. }
@@ -447,6 +554,7 @@ impl Plan {
                 | Plan::ExplainAnalyze { .. }
                 | Plan::Call(_)
                 | Plan::ShowCreateDatabase(_)
+                | Plan::BackupDatabase(_)
                 | Plan::ShowCreateTable(_)
                 | Plan::ShowFileFormats(_)
                 | Plan::ShowRoles(_)
@@ -22,6 +22,8 @@ pub struct ReclusterTablePlan {
     pub database: String,
     pub table: String,
     pub is_final: bool,
+    /// The max number of iterations of a final recluster, unlimited if None.
+    pub limit: Option<usize>,
     pub metadata: MetadataRef,
     pub push_downs: Option<ScalarExpr>,
 }
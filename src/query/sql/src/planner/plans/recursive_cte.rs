@@ -0,0 +1,169 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+
+use crate::optimizer::ColumnSet;
+use crate::optimizer::Distribution;
+use crate::optimizer::PhysicalProperty;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::RequiredProperty;
+use crate::optimizer::StatInfo;
+use crate::optimizer::Statistics;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::IndexType;
+
+/// `RecursiveCte` evaluates a `WITH RECURSIVE` common table expression.
+///
+/// The anchor (the left child) is evaluated once, then the recursive term
+/// (the right child) is evaluated repeatedly, each iteration reading the rows
+/// produced by the previous one through a `RecursiveCteScan` of the same
+/// `cte_index`, until an iteration produces no rows.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RecursiveCte {
+    pub cte_index: IndexType,
+    /// Output columns of the anchor, which are also the output of the CTE.
+    pub anchor_columns: Vec<IndexType>,
+    /// Output columns of the recursive term, in the same order.
+    pub recursive_columns: Vec<IndexType>,
+    /// `UNION` instead of `UNION ALL`, rows that have been produced already are
+    /// discarded, which also stops the iteration on cyclic data.
+    pub distinct: bool,
+}
+
+impl RecursiveCte {
+    pub fn used_columns(&self) -> Result<ColumnSet> {
+        let mut used_columns = ColumnSet::new();
+        used_columns.extend(self.anchor_columns.iter().copied());
+        used_columns.extend(self.recursive_columns.iter().copied());
+        Ok(used_columns)
+    }
+}
+
+impl Operator for RecursiveCte {
+    fn rel_op(&self) -> RelOp {
+        RelOp::RecursiveCte
+    }
+
+    fn derive_relational_prop(&self, rel_expr: &RelExpr) -> Result<Arc<RelationalProperty>> {
+        let anchor_prop = rel_expr.derive_relational_prop_child(0)?;
+        let recursive_prop = rel_expr.derive_relational_prop_child(1)?;
+
+        let output_columns = self.anchor_columns.iter().copied().collect();
+
+        let mut outer_columns = anchor_prop.outer_columns.clone();
+        outer_columns.extend(recursive_prop.outer_columns.iter().copied());
+
+        let mut used_columns = self.used_columns()?;
+        used_columns.extend(anchor_prop.used_columns.iter().copied());
+        used_columns.extend(recursive_prop.used_columns.iter().copied());
+
+        Ok(Arc::new(RelationalProperty {
+            output_columns,
+            outer_columns,
+            used_columns,
+        }))
+    }
+
+    fn derive_physical_prop(&self, _rel_expr: &RelExpr) -> Result<PhysicalProperty> {
+        Ok(PhysicalProperty {
+            distribution: Distribution::Serial,
+        })
+    }
+
+    fn derive_cardinality(&self, rel_expr: &RelExpr) -> Result<Arc<StatInfo>> {
+        let anchor_stat_info = rel_expr.derive_cardinality_child(0)?;
+        let recursive_stat_info = rel_expr.derive_cardinality_child(1)?;
+
+        // The number of iterations is unknown before execution.
+        Ok(Arc::new(StatInfo {
+            cardinality: anchor_stat_info.cardinality + recursive_stat_info.cardinality,
+            statistics: Statistics {
+                precise_cardinality: None,
+                column_stats: Default::default(),
+            },
+        }))
+    }
+
+    fn compute_required_prop_child(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _child_index: usize,
+        _required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        Ok(RequiredProperty {
+            distribution: Distribution::Serial,
+        })
+    }
+}
+
+/// `RecursiveCteScan` reads the working table of the `RecursiveCte` with the
+/// same `cte_index`, that is the rows produced by the previous iteration.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RecursiveCteScan {
+    pub cte_index: IndexType,
+    pub columns: Vec<IndexType>,
+}
+
+impl RecursiveCteScan {
+    pub fn used_columns(&self) -> Result<ColumnSet> {
+        Ok(self.columns.iter().copied().collect())
+    }
+}
+
+impl Operator for RecursiveCteScan {
+    fn rel_op(&self) -> RelOp {
+        RelOp::RecursiveCteScan
+    }
+
+    fn derive_relational_prop(&self, _rel_expr: &RelExpr) -> Result<Arc<RelationalProperty>> {
+        Ok(Arc::new(RelationalProperty {
+            output_columns: self.used_columns()?,
+            outer_columns: ColumnSet::new(),
+            used_columns: self.used_columns()?,
+        }))
+    }
+
+    fn derive_physical_prop(&self, _rel_expr: &RelExpr) -> Result<PhysicalProperty> {
+        Ok(PhysicalProperty {
+            distribution: Distribution::Serial,
+        })
+    }
+
+    fn derive_cardinality(&self, _rel_expr: &RelExpr) -> Result<Arc<StatInfo>> {
+        Ok(Arc::new(StatInfo {
+            cardinality: 1.0,
+            statistics: Statistics {
+                precise_cardinality: None,
+                column_stats: Default::default(),
+            },
+        }))
+    }
+
+    fn compute_required_prop_child(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _child_index: usize,
+        required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        Ok(required.clone())
+    }
+}
@@ -21,6 +21,7 @@ use common_exception::Range;
 use common_exception::Result;
 use common_exception::Span;
 use common_expression::types::DataType;
+use common_expression::RemoteExpr;
 use common_expression::Scalar;
 use educe::Educe;
 use itertools::Itertools;
@@ -40,6 +41,7 @@ pub enum ScalarExpr {
     WindowFunction(WindowFunc),
     AggregateFunction(AggregateFunction),
     FunctionCall(FunctionCall),
+    LambdaFunction(LambdaFunc),
     CastExpr(CastExpr),
     SubqueryExpr(SubqueryExpr),
 }
@@ -77,6 +79,13 @@ impl ScalarExpr {
                 }
                 result
             }
+            ScalarExpr::LambdaFunction(scalar) => {
+                let mut result = ColumnSet::new();
+                for scalar in &scalar.args {
+                    result = result.union(&scalar.used_columns()).cloned().collect();
+                }
+                result
+            }
             ScalarExpr::CastExpr(scalar) => scalar.argument.used_columns(),
             ScalarExpr::SubqueryExpr(scalar) => scalar.outer_columns.clone(),
         }
@@ -107,6 +116,13 @@ impl ScalarExpr {
                 }
                 Ok(result)
             }
+            ScalarExpr::LambdaFunction(scalar) => {
+                let mut result = vec![];
+                for scalar in &scalar.args {
+                    result.append(&mut scalar.used_tables(metadata.clone())?);
+                }
+                Ok(result)
+            }
             ScalarExpr::CastExpr(scalar) => scalar.argument.used_tables(metadata),
             ScalarExpr::WindowFunction(_) | ScalarExpr::SubqueryExpr(_) => {
                 Err(ErrorCode::Unimplemented(
@@ -130,6 +146,7 @@ impl ScalarExpr {
                     .into_option()?;
                 Some(Range { start, end })
             }),
+            ScalarExpr::LambdaFunction(expr) => expr.span,
             ScalarExpr::CastExpr(expr) => expr.span.or(expr.argument.span()),
             ScalarExpr::SubqueryExpr(expr) => expr.span,
             _ => None,
@@ -231,6 +248,23 @@ impl TryFrom<ScalarExpr> for FunctionCall {
     }
 }
 
+impl From<LambdaFunc> for ScalarExpr {
+    fn from(v: LambdaFunc) -> Self {
+        Self::LambdaFunction(v)
+    }
+}
+
+impl TryFrom<ScalarExpr> for LambdaFunc {
+    type Error = ErrorCode;
+    fn try_from(value: ScalarExpr) -> Result<Self> {
+        if let ScalarExpr::LambdaFunction(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal("Cannot downcast Scalar to LambdaFunc"))
+        }
+    }
+}
+
 impl From<CastExpr> for ScalarExpr {
     fn from(v: CastExpr) -> Self {
         Self::CastExpr(v)
@@ -347,10 +381,21 @@ pub struct AggregateFunction {
     pub params: Vec<Scalar>,
     pub args: Vec<ScalarExpr>,
     pub return_type: Box<DataType>,
+    /// Set if the function is an aggregate UDF evaluated by a UDF server.
+    pub udaf: Option<UdafServer>,
 
     pub display_name: String,
 }
 
+/// The UDF server that evaluates an aggregate UDF.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct UdafServer {
+    pub address: String,
+    pub handler: String,
+    /// The fields of the intermediate state, as pairs of name and type.
+    pub state_fields: Vec<(String, DataType)>,
+}
+
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub struct LagLeadFunction {
     /// Is `lag` or `lead`.
@@ -404,6 +449,19 @@ pub struct FunctionCall {
     pub arguments: Vec<ScalarExpr>,
 }
 
+#[derive(Clone, Debug, Educe)]
+#[educe(PartialEq, Eq, Hash)]
+pub struct LambdaFunc {
+    #[educe(Hash(ignore), PartialEq(ignore), Eq(ignore))]
+    pub span: Span,
+    pub func_name: String,
+    pub args: Vec<ScalarExpr>,
+    /// The lambda body, whose column references are the positions of the lambda params.
+    #[educe(Hash(ignore), PartialEq(ignore), Eq(ignore))]
+    pub lambda_expr: Box<RemoteExpr>,
+    pub lambda_display: String,
+}
+
 #[derive(Clone, Debug, Educe)]
 #[educe(PartialEq, Eq, Hash)]
 pub struct CastExpr {
@@ -16,6 +16,8 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 
+use common_catalog::plan::SampleConfig;
+use common_catalog::plan::VectorTopN;
 use common_catalog::table::ColumnStatistics;
 use common_catalog::table::TableStatistics;
 use common_catalog::table_context::TableContext;
@@ -37,6 +39,7 @@ use crate::optimizer::StatInfo;
 use crate::optimizer::Statistics as OpStatistics;
 use crate::optimizer::DEFAULT_HISTOGRAM_BUCKETS;
 use crate::optimizer::MAX_SELECTIVITY;
+use crate::plans::AggregateFunction;
 use crate::plans::Operator;
 use crate::plans::RelOp;
 use crate::plans::ScalarExpr;
@@ -58,6 +61,29 @@ pub struct AggIndexInfo {
     pub index_id: u64,
     pub selection: Vec<ScalarExpr>,
     pub predicates: Vec<ScalarExpr>,
+    /// Set if the query is an aggregation answered by merging the partial aggregate
+    /// states stored in the index.
+    pub merge: Option<AggIndexMerge>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AggIndexMerge {
+    /// Offsets in the index selection of the group items, followed by the ones of the
+    /// aggregate functions of the query.
+    pub projection: Vec<IndexType>,
+    /// The partial aggregation of the query on the base columns,
+    /// used for the blocks not indexed yet.
+    pub aggregation: AggIndexAggregation,
+}
+
+/// The partial aggregation of each block of an aggregating index, on the base columns.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AggIndexAggregation {
+    pub predicates: Vec<ScalarExpr>,
+    pub group_items: Vec<ScalarExpr>,
+    pub aggregate_functions: Vec<AggregateFunction>,
+    /// Offsets of the output columns in the group items followed by the aggregate functions.
+    pub output: Vec<IndexType>,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -77,6 +103,8 @@ pub struct Scan {
     pub order_by: Option<Vec<SortItem>>,
     pub prewhere: Option<Prewhere>,
     pub agg_index: Option<AggIndexInfo>,
+    pub sample: Option<SampleConfig>,
+    pub vector_topn: Option<VectorTopN>,
 
     pub statistics: Statistics,
 }
@@ -103,6 +131,8 @@ impl Scan {
             },
             prewhere,
             agg_index: self.agg_index.clone(),
+            sample: self.sample,
+            vector_topn: self.vector_topn.clone(),
         }
     }
 
@@ -127,6 +157,7 @@ impl PartialEq for Scan {
         self.table_index == other.table_index
             && self.columns == other.columns
             && self.push_down_predicates == other.push_down_predicates
+            && self.sample == other.sample
     }
 }
 
@@ -139,6 +170,7 @@ impl std::hash::Hash for Scan {
             column.hash(state);
         }
         self.push_down_predicates.hash(state);
+        self.sample.hash(state);
     }
 }
 
@@ -208,7 +240,7 @@ impl Operator for Scan {
             .as_ref()
             .and_then(|stat| stat.num_rows);
 
-        let cardinality = match (precise_cardinality, &self.prewhere) {
+        let mut cardinality = match (precise_cardinality, &self.prewhere) {
             (Some(precise_cardinality), Some(ref prewhere)) => {
                 let mut statistics = OpStatistics {
                     precise_cardinality: Some(precise_cardinality),
@@ -232,8 +264,12 @@ impl Operator for Scan {
             (_, _) => 0.0,
         };
 
-        // If prewhere is not none, we can't get precise cardinality
-        let precise_cardinality = if self.prewhere.is_none() {
+        if let Some(sample) = &self.sample {
+            cardinality *= sample.probability.into_inner();
+        }
+
+        // If prewhere or sample is not none, we can't get precise cardinality
+        let precise_cardinality = if self.prewhere.is_none() && self.sample.is_none() {
             precise_cardinality
         } else {
             None
@@ -0,0 +1,104 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::DataType;
+
+use crate::optimizer::ColumnSet;
+use crate::optimizer::PhysicalProperty;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::RequiredProperty;
+use crate::optimizer::StatInfo;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::IndexType;
+use crate::ScalarExpr;
+
+/// A call of a UDF evaluated by an external UDF server, or by a WebAssembly
+/// module in-process.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UdfItem {
+    /// The name of the handler on the server, or of the function exported by
+    /// the WebAssembly module.
+    pub func_name: String,
+    pub display_name: String,
+    pub server_addr: String,
+    /// The stage location of the WebAssembly module, the call is sent to
+    /// `server_addr` if it's `None`.
+    pub wasm_module: Option<String>,
+    /// The arguments, cast to the declared types of the UDF.
+    pub arguments: Vec<ScalarExpr>,
+    /// The declared return type of the UDF, always nullable.
+    pub data_type: DataType,
+    pub index: IndexType,
+}
+
+/// `Udf` evaluates the external UDF calls of the select list on the UDF
+/// servers or by the WebAssembly modules, and appends the results to its
+/// input relation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Udf {
+    pub items: Vec<UdfItem>,
+}
+
+impl Udf {
+    pub fn used_columns(&self) -> Result<ColumnSet> {
+        let mut used_columns = ColumnSet::new();
+        for item in self.items.iter() {
+            for argument in item.arguments.iter() {
+                used_columns.extend(argument.used_columns());
+            }
+        }
+        Ok(used_columns)
+    }
+}
+
+impl Operator for Udf {
+    fn rel_op(&self) -> RelOp {
+        RelOp::Udf
+    }
+
+    fn derive_relational_prop(&self, rel_expr: &RelExpr) -> Result<Arc<RelationalProperty>> {
+        let mut child_prop = rel_expr.derive_relational_prop_child(0)?.as_ref().clone();
+        for item in &self.items {
+            child_prop.output_columns.insert(item.index);
+            for argument in item.arguments.iter() {
+                child_prop.used_columns.extend(argument.used_columns());
+            }
+        }
+        Ok(Arc::new(child_prop))
+    }
+
+    fn derive_physical_prop(&self, rel_expr: &RelExpr) -> Result<PhysicalProperty> {
+        rel_expr.derive_physical_prop_child(0)
+    }
+
+    fn derive_cardinality(&self, rel_expr: &RelExpr) -> Result<Arc<StatInfo>> {
+        rel_expr.derive_cardinality_child(0)
+    }
+
+    fn compute_required_prop_child(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _child_index: usize,
+        required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        Ok(required.clone())
+    }
+}
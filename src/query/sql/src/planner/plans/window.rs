@@ -200,6 +200,7 @@ pub enum WindowFuncFrameUnits {
     #[default]
     Rows,
     Range,
+    Groups,
 }
 
 #[derive(Default, Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
@@ -89,13 +89,17 @@ impl VisitorMut for DistinctToGroupBy {
                                 args: vec![],
                                 params: vec![],
                                 window: None,
+                                lambda: None,
                             }),
                             alias: alias.clone(),
                         }],
                         from: vec![TableReference::Subquery {
                             span: None,
+                            lateral: false,
                             subquery: Box::new(subquery),
                             alias: None,
+                            pivot: None,
+                            unpivot: None,
                         }],
                         selection: None,
                         group_by: None,
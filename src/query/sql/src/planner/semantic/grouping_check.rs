@@ -21,6 +21,7 @@ use crate::binder::Visibility;
 use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::FunctionCall;
+use crate::plans::LambdaFunc;
 use crate::plans::ScalarExpr;
 use crate::BindContext;
 
@@ -103,6 +104,21 @@ impl<'a> GroupingChecker<'a> {
                 }
                 .into())
             }
+            ScalarExpr::LambdaFunction(func) => {
+                let args = func
+                    .args
+                    .iter()
+                    .map(|arg| self.resolve(arg, span))
+                    .collect::<Result<Vec<ScalarExpr>>>()?;
+                Ok(LambdaFunc {
+                    span: func.span,
+                    func_name: func.func_name.clone(),
+                    args,
+                    lambda_expr: func.lambda_expr.clone(),
+                    lambda_display: func.lambda_display.clone(),
+                }
+                .into())
+            }
             ScalarExpr::CastExpr(cast) => Ok(CastExpr {
                 span: cast.span,
                 is_try: cast.is_try,
@@ -122,6 +122,25 @@ fn resolve_column_type<C: LoweringContext>(
                 args,
             })
         }
+        RawExpr::LambdaFunctionCall {
+            span,
+            name,
+            args,
+            lambda_expr,
+            lambda_display,
+        } => {
+            let args = args
+                .iter()
+                .map(|arg| resolve_column_type(arg, context))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(RawExpr::LambdaFunctionCall {
+                span: *span,
+                name: name.clone(),
+                args,
+                lambda_expr: lambda_expr.clone(),
+                lambda_display: lambda_display.clone(),
+            })
+        }
         RawExpr::Constant { .. } => Ok(raw_expr.clone()),
     }
 }
@@ -228,6 +247,13 @@ impl ScalarExpr {
                 params: func.params.clone(),
                 args: func.arguments.iter().map(ScalarExpr::as_raw_expr).collect(),
             },
+            ScalarExpr::LambdaFunction(func) => RawExpr::LambdaFunctionCall {
+                span: func.span,
+                name: func.func_name.clone(),
+                args: func.args.iter().map(ScalarExpr::as_raw_expr).collect(),
+                lambda_expr: func.lambda_expr.clone(),
+                lambda_display: func.lambda_display.clone(),
+            },
             ScalarExpr::CastExpr(cast) => RawExpr::Cast {
                 span: cast.span,
                 is_try: cast.is_try,
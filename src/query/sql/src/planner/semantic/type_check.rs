@@ -22,6 +22,7 @@ use common_ast::ast::ColumnID;
 use common_ast::ast::Expr;
 use common_ast::ast::Identifier;
 use common_ast::ast::IntervalKind as ASTIntervalKind;
+use common_ast::ast::Lambda;
 use common_ast::ast::Literal;
 use common_ast::ast::MapAccessor;
 use common_ast::ast::Query;
@@ -62,12 +63,15 @@ use common_functions::aggregates::AggregateCountFunction;
 use common_functions::aggregates::AggregateFunctionFactory;
 use common_functions::is_builtin_function;
 use common_functions::BUILTIN_FUNCTIONS;
+use common_functions::GENERAL_LAMBDA_FUNCTIONS;
 use common_functions::GENERAL_WINDOW_FUNCTIONS;
+use common_meta_app::principal::UserDefinedFunction;
 use common_users::UserApiProvider;
 use simsearch::SimSearch;
 
 use super::name_resolution::NameResolutionContext;
 use super::normalize_identifier;
+use crate::binder::check_column_privilege;
 use crate::binder::wrap_cast;
 use crate::binder::Binder;
 use crate::binder::ExprContext;
@@ -81,10 +85,12 @@ use crate::plans::ComparisonOp;
 use crate::plans::ConstantExpr;
 use crate::plans::FunctionCall;
 use crate::plans::LagLeadFunction;
+use crate::plans::LambdaFunc;
 use crate::plans::NthValueFunction;
 use crate::plans::ScalarExpr;
 use crate::plans::SubqueryExpr;
 use crate::plans::SubqueryType;
+use crate::plans::UdafServer;
 use crate::plans::WindowFunc;
 use crate::plans::WindowFuncFrame;
 use crate::plans::WindowFuncFrameBound;
@@ -174,6 +180,25 @@ impl<'a> TypeChecker<'a> {
             return Ok(Box::new((scalar.clone(), scalar.data_type()?)));
         }
 
+        if let Some(scalar) = self.bind_context.dict_gets.get(&expr.to_string()) {
+            // Found a dictionary lookup, return it directly.
+            // See `Binder::bind_dict_get` for more details.
+            return Ok(Box::new((scalar.clone(), scalar.data_type()?)));
+        }
+        if let Some(scalar) = self.bind_context.udf_server_calls.get(&expr.to_string()) {
+            // Found an external UDF call, return it directly.
+            // See `Binder::bind_udf_server_calls` for more details.
+            return Ok(Box::new((scalar.clone(), scalar.data_type()?)));
+        }
+        if let Expr::FunctionCall { name, .. } = expr {
+            if name.name.eq_ignore_ascii_case("dict_get") {
+                return Err(
+                    ErrorCode::SemanticError("dict_get is only allowed in SELECT clause")
+                        .set_span(expr.span()),
+                );
+            }
+        }
+
         let box (scalar, data_type): Box<(ScalarExpr, DataType)> = match expr {
             Expr::ColumnRef {
                 span,
@@ -208,6 +233,7 @@ impl<'a> TypeChecker<'a> {
 
                 let (scalar, data_type) = match result {
                     NameResolutionResult::Column(column) => {
+                        check_column_privilege(&self.metadata, &column)?;
                         if let Some(virtual_computed_expr) = column.virtual_computed_expr {
                             let sql_tokens = tokenize_sql(virtual_computed_expr.as_str())?;
                             let expr = parse_expr(&sql_tokens, Dialect::PostgreSQL)?;
@@ -345,6 +371,7 @@ impl<'a> TypeChecker<'a> {
                             args: args.iter().copied().cloned().collect(),
                             params: vec![],
                             window: None,
+                            lambda: None,
                         })
                         .await?
                     } else {
@@ -570,6 +597,7 @@ impl<'a> TypeChecker<'a> {
                                 args: vec![*operand.clone(), c.clone()],
                                 params: vec![],
                                 window: None,
+                                lambda: None,
                             };
                             arguments.push(equal_expr)
                         }
@@ -620,13 +648,22 @@ impl<'a> TypeChecker<'a> {
                 args,
                 params,
                 window,
+                lambda,
             } => {
                 let func_name = normalize_identifier(name, self.name_resolution_ctx).to_string();
                 let func_name = func_name.as_str();
+                if let Some(lambda) = lambda {
+                    return self
+                        .resolve_lambda_function(*span, func_name, args, lambda)
+                        .await;
+                }
                 if !is_builtin_function(func_name)
                     && !Self::all_rewritable_scalar_function().contains(&func_name)
                 {
-                    if let Some(udf) = self.resolve_udf(*span, func_name, args).await? {
+                    if let Some(udf) = self
+                        .resolve_udf(*span, func_name, expr, *distinct, window, args)
+                        .await?
+                    {
                         return Ok(udf);
                     } else {
                         // Function not found, try to find and suggest similar function name.
@@ -752,6 +789,7 @@ impl<'a> TypeChecker<'a> {
                         params: vec![],
                         args: vec![],
                         return_type: Box::new(agg_func.return_type()?),
+                        udaf: None,
                     },
                     agg_func.return_type()?,
                 );
@@ -1011,7 +1049,7 @@ impl<'a> TypeChecker<'a> {
         }
 
         Err(ErrorCode::SemanticError(
-            "Only unsigned numbers are allowed in ROWS offset".to_string(),
+            "Only unsigned numbers are allowed in ROWS or GROUPS offset".to_string(),
         )
         .set_span(expr.span()))
     }
@@ -1020,6 +1058,7 @@ impl<'a> TypeChecker<'a> {
         let units = match frame.units {
             WindowFrameUnits::Rows => WindowFuncFrameUnits::Rows,
             WindowFrameUnits::Range => WindowFuncFrameUnits::Range,
+            WindowFrameUnits::Groups => WindowFuncFrameUnits::Groups,
         };
         let start = match frame.start_bound {
             WindowFrameBound::CurrentRow => WindowFuncFrameBound::CurrentRow,
@@ -1092,6 +1131,7 @@ impl<'a> TypeChecker<'a> {
         let units = match frame.units {
             WindowFrameUnits::Rows => WindowFuncFrameUnits::Rows,
             WindowFrameUnits::Range => WindowFuncFrameUnits::Range,
+            WindowFrameUnits::Groups => WindowFuncFrameUnits::Groups,
         };
         let start = match frame.start_bound {
             WindowFrameBound::CurrentRow => WindowFuncFrameBound::CurrentRow,
@@ -1160,6 +1200,14 @@ impl<'a> TypeChecker<'a> {
                     )).set_span(span));
                 }
                 self.resolve_window_range_frame(frame).await
+            } else if frame.units.is_groups() {
+                if order_by.is_empty() {
+                    return Err(ErrorCode::SemanticError(
+                        "The GROUPS window frame requires an ORDER BY clause.".to_string(),
+                    )
+                    .set_span(span));
+                }
+                self.resolve_window_rows_frame(frame)
             } else {
                 self.resolve_window_rows_frame(frame)
             }
@@ -1437,6 +1485,7 @@ impl<'a> TypeChecker<'a> {
             params,
             args,
             return_type: Box::new(agg_func.return_type()?),
+            udaf: None,
         };
 
         let data_type = agg_func.return_type()?;
@@ -1513,6 +1562,148 @@ impl<'a> TypeChecker<'a> {
             .await
     }
 
+    #[async_backtrace::framed]
+    async fn resolve_lambda_function(
+        &mut self,
+        span: Span,
+        func_name: &str,
+        args: &[Expr],
+        lambda: &Lambda,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let func_name = func_name.to_lowercase();
+        if !GENERAL_LAMBDA_FUNCTIONS.contains(&func_name.as_str()) {
+            return Err(ErrorCode::SemanticError(format!(
+                "function {func_name} does not support lambda expression"
+            ))
+            .set_span(span));
+        }
+        // `array_map` is an alias of `array_transform`.
+        let func_name = if func_name == "array_map" {
+            "array_transform"
+        } else {
+            func_name.as_str()
+        };
+        if args.len() != 1 {
+            return Err(ErrorCode::SemanticError(format!(
+                "function {func_name} expects 1 argument and a lambda expression, but got {} arguments",
+                args.len()
+            ))
+            .set_span(span));
+        }
+
+        let box (arg, arg_type) = self.resolve(&args[0]).await?;
+        let inner_type = match arg_type.remove_nullable() {
+            DataType::Array(box inner_type) => inner_type,
+            DataType::EmptyArray | DataType::Null => DataType::Null,
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "function {func_name} expects an array argument, but got {arg_type}"
+                ))
+                .set_span(span));
+            }
+        };
+
+        // `array_reduce` takes the accumulated value and the current element.
+        let params_len = if func_name == "array_reduce" { 2 } else { 1 };
+        if lambda.params.len() != params_len {
+            return Err(ErrorCode::SemanticError(format!(
+                "the lambda expression of {func_name} expects {params_len} parameters, but got {}",
+                lambda.params.len()
+            ))
+            .set_span(span));
+        }
+
+        // The lambda body can only reference the lambda parameters, which are bound
+        // as columns whose indexes are the positions of the parameters.
+        let mut lambda_context = BindContext::new();
+        for (index, param) in lambda.params.iter().enumerate() {
+            let column_name = normalize_identifier(param, self.name_resolution_ctx).name;
+            if lambda_context
+                .columns
+                .iter()
+                .any(|column| column.column_name == column_name)
+            {
+                return Err(ErrorCode::SemanticError(format!(
+                    "duplicate lambda parameter name {column_name}"
+                ))
+                .set_span(param.span));
+            }
+            lambda_context.add_column_binding(ColumnBinding {
+                database_name: None,
+                table_name: None,
+                column_position: None,
+                table_index: None,
+                column_name,
+                index,
+                data_type: Box::new(inner_type.clone()),
+                visibility: Visibility::Visible,
+                virtual_computed_expr: None,
+            });
+        }
+
+        let box (lambda_scalar, _) = {
+            let mut lambda_checker = TypeChecker::new(
+                &mut lambda_context,
+                self.ctx.clone(),
+                self.name_resolution_ctx,
+                self.metadata.clone(),
+                &[],
+                false,
+            );
+            lambda_checker.resolve(&lambda.expr).await?
+        };
+        let lambda_expr = lambda_scalar.as_expr()?;
+        if lambda_expr
+            .column_refs()
+            .keys()
+            .any(|column| column.index >= params_len)
+        {
+            return Err(ErrorCode::SemanticError(format!(
+                "the lambda expression of {func_name} can only reference the lambda parameters"
+            ))
+            .set_span(lambda.expr.span()));
+        }
+        let mut lambda_expr = lambda_expr.project_column_ref(|column| column.index);
+        if func_name == "array_reduce" {
+            lambda_expr = type_check::check_cast(
+                lambda_expr.span(),
+                false,
+                lambda_expr,
+                &inner_type,
+                &BUILTIN_FUNCTIONS,
+            )?;
+        }
+
+        let lambda_display = lambda.to_string();
+        let expr = type_check::check_lambda_function(
+            span,
+            func_name,
+            vec![arg.as_expr()?],
+            lambda_expr.clone(),
+            &lambda_display,
+        )?;
+
+        if !expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+            self.ctx.set_cacheable(false);
+        }
+
+        if let Some(constant) = self.try_fold_constant(&expr) {
+            return Ok(constant);
+        }
+
+        Ok(Box::new((
+            LambdaFunc {
+                span,
+                func_name: func_name.to_string(),
+                args: vec![arg],
+                lambda_expr: Box::new(lambda_expr.as_remote_expr()),
+                lambda_display,
+            }
+            .into(),
+            expr.data_type().clone(),
+        )))
+    }
+
     #[async_backtrace::framed]
     pub async fn resolve_scalar_function_call(
         &mut self,
@@ -1563,6 +1754,38 @@ impl<'a> TypeChecker<'a> {
         left: &Expr,
         right: &Expr,
     ) -> Result<Box<(ScalarExpr, DataType)>> {
+        if matches!(
+            op,
+            BinaryOperator::Eq
+                | BinaryOperator::NotEq
+                | BinaryOperator::Gt
+                | BinaryOperator::Gte
+                | BinaryOperator::Lt
+                | BinaryOperator::Lte
+                | BinaryOperator::Like
+                | BinaryOperator::NotLike
+        ) {
+            // The collation of one side applies to the comparison, e.g.
+            // `a COLLATE 'utf8_unicode_ci' = 'ABC'` compares both sides case-insensitively.
+            match (collation_of(left), collation_of(right)) {
+                (Some(collation), None) => {
+                    let right = with_collation(right, collation);
+                    return self.resolve_binary_op(span, op, left, &right).await;
+                }
+                (None, Some(collation)) => {
+                    let left = with_collation(left, collation);
+                    return self.resolve_binary_op(span, op, &left, right).await;
+                }
+                (Some(l), Some(r)) if !l.eq_ignore_ascii_case(r) => {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "Illegal mix of collations '{l}' and '{r}'"
+                    ))
+                    .set_span(span));
+                }
+                _ => {}
+            }
+        }
+
         match op {
             BinaryOperator::NotLike | BinaryOperator::NotRegexp | BinaryOperator::NotRLike => {
                 let positive_op = match op {
@@ -1857,6 +2080,8 @@ impl<'a> TypeChecker<'a> {
             "last_query_id",
             "array_sort",
             "array_aggregate",
+            "query",
+            "collate",
         ]
     }
 
@@ -1970,6 +2195,7 @@ impl<'a> TypeChecker<'a> {
                         args: vec![arg_x.clone()],
                         params: vec![],
                         window: None,
+                        lambda: None,
                     })
                     .await,
                 )
@@ -2006,6 +2232,7 @@ impl<'a> TypeChecker<'a> {
                         args: vec![(*arg).clone()],
                         params: vec![],
                         window: None,
+                        lambda: None,
                     };
 
                     new_args.push(is_not_null_expr);
@@ -2049,6 +2276,106 @@ impl<'a> TypeChecker<'a> {
                     Err(e) => Err(e),
                 })
             }
+            (
+                "query",
+                &[
+                    Expr::Literal {
+                        lit: Literal::String(query),
+                        ..
+                    },
+                ],
+            ) => {
+                // Rewrite query('col1:term1 col2:term2') to match(col1, 'term1') AND match(col2, 'term2')
+                let mut column_terms: Vec<(&str, Vec<&str>)> = Vec::new();
+                for item in query.split_whitespace() {
+                    let (column, term) = match item.split_once(':') {
+                        Some((column, term)) if !column.is_empty() && !term.is_empty() => {
+                            (column, term)
+                        }
+                        _ => {
+                            return Some(Err(ErrorCode::SemanticError(format!(
+                                "invalid query item '{item}', must be in the form of 'column:term'"
+                            ))
+                            .set_span(span)));
+                        }
+                    };
+                    match column_terms.iter_mut().find(|(c, _)| *c == column) {
+                        Some((_, terms)) => terms.push(term),
+                        None => column_terms.push((column, vec![term])),
+                    }
+                }
+
+                let mut predicate: Option<Expr> = None;
+                for (column, terms) in column_terms {
+                    let match_expr = Expr::FunctionCall {
+                        span,
+                        distinct: false,
+                        name: Identifier {
+                            name: "match".to_string(),
+                            quote: None,
+                            span,
+                        },
+                        args: vec![
+                            Expr::ColumnRef {
+                                span,
+                                database: None,
+                                table: None,
+                                column: ColumnID::Name(Identifier {
+                                    name: column.to_string(),
+                                    quote: None,
+                                    span,
+                                }),
+                            },
+                            Expr::Literal {
+                                span,
+                                lit: Literal::String(terms.join(" ")),
+                            },
+                        ],
+                        params: vec![],
+                        window: None,
+                        lambda: None,
+                    };
+                    predicate = Some(match predicate {
+                        Some(left) => Expr::BinaryOp {
+                            span,
+                            op: BinaryOperator::And,
+                            left: Box::new(left),
+                            right: Box::new(match_expr),
+                        },
+                        None => match_expr,
+                    });
+                }
+                match predicate {
+                    Some(predicate) => Some(self.resolve(&predicate).await),
+                    None => Some(Err(ErrorCode::SemanticError(
+                        "query function needs at least one 'column:term' item",
+                    )
+                    .set_span(span))),
+                }
+            }
+            ("collate", &[arg, collation]) => {
+                let collation = match collation {
+                    Expr::Literal {
+                        lit: Literal::String(collation),
+                        ..
+                    } => collation.to_lowercase(),
+                    _ => {
+                        return Some(Err(ErrorCode::SemanticError(
+                            "Collation must be a constant string",
+                        )
+                        .set_span(span)));
+                    }
+                };
+                match collation.as_str() {
+                    // The strings are compared by bytes by default.
+                    "utf8" | "utf8_bin" | "binary" => Some(self.resolve(arg).await),
+                    "utf8_unicode_ci" | "utf8_general_ci" => None,
+                    _ => Some(Err(ErrorCode::SemanticError(format!(
+                        "Unknown collation '{collation}', supported collations are 'utf8' and 'utf8_unicode_ci'"
+                    ))
+                    .set_span(span))),
+                }
+            }
             // Try convert get function of Variant data type into a virtual column
             ("get", args) => {
                 if !self.allow_pushdown {
@@ -2238,6 +2565,7 @@ impl<'a> TypeChecker<'a> {
             })),
             Literal::Float64(float) => Scalar::Number(NumberScalar::Float64((*float).into())),
             Literal::String(string) => Scalar::String(string.as_bytes().to_vec()),
+            Literal::Binary(bytes) => Scalar::Binary(bytes.clone()),
             Literal::Boolean(boolean) => Scalar::Boolean(*boolean),
             Literal::Null => Scalar::Null,
             Literal::CurrentTimestamp => Err(ErrorCode::SemanticError(format!(
@@ -2360,6 +2688,9 @@ impl<'a> TypeChecker<'a> {
         &mut self,
         span: Span,
         func_name: &str,
+        expr: &Expr,
+        distinct: bool,
+        window: &Option<Window>,
         arguments: &[Expr],
     ) -> Result<Option<Box<(ScalarExpr, DataType)>>> {
         let udf = UserApiProvider::instance()
@@ -2372,6 +2703,40 @@ impl<'a> TypeChecker<'a> {
             return Ok(None);
         };
 
+        if udf.is_table_function() {
+            return Err(ErrorCode::SemanticError(format!(
+                "Table function {func_name} can only be used in the FROM clause"
+            ))
+            .set_span(span));
+        }
+        if udf.is_aggregate_function() {
+            if window.is_some() || distinct {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Aggregate function {func_name} evaluated by UDF server can't be used with DISTINCT or OVER"
+                ))
+                .set_span(span));
+            }
+            let (agg_func, data_type) = self.resolve_udaf(span, expr, udf, arguments).await?;
+            return Ok(Some(Box::new((agg_func.into(), data_type))));
+        }
+        if udf.udf_server.is_some() || udf.wasm_module.is_some() {
+            return Err(ErrorCode::SemanticError(format!(
+                "External function {func_name} is only allowed in SELECT clause"
+            ))
+            .set_span(span));
+        }
+        if self
+            .bind_context
+            .udf_calls
+            .iter()
+            .any(|name| name == func_name)
+        {
+            return Err(ErrorCode::SemanticError(format!(
+                "Recursive call of function {func_name} is not allowed"
+            ))
+            .set_span(span));
+        }
+
         let parameters = udf.parameters;
         if parameters.len() != arguments.len() {
             return Err(ErrorCode::SyntaxException(format!(
@@ -2384,13 +2749,29 @@ impl<'a> TypeChecker<'a> {
         let settings = self.ctx.get_settings();
         let sql_dialect = settings.get_sql_dialect()?;
         let sql_tokens = tokenize_sql(udf.definition.as_str())?;
-        let expr = parse_expr(&sql_tokens, sql_dialect)?;
+        let mut expr = parse_expr(&sql_tokens, sql_dialect)?;
         let mut args_map = HashMap::new();
-        arguments.iter().enumerate().for_each(|(idx, argument)| {
-            if let Some(parameter) = parameters.get(idx) {
-                args_map.insert(parameter, (*argument).clone());
+        for (idx, argument) in arguments.iter().enumerate() {
+            let mut argument = argument.clone();
+            // The arguments of a SQL UDF are cast to the declared types.
+            if let Some(arg_type) = udf.arg_types.get(idx) {
+                argument = Expr::Cast {
+                    span,
+                    expr: Box::new(argument),
+                    target_type: parse_type_name(arg_type)?.wrap_nullable(),
+                    pg_style: false,
+                };
             }
-        });
+            args_map.insert(&parameters[idx], argument);
+        }
+        if let Some(return_type) = &udf.return_type {
+            expr = Expr::Cast {
+                span,
+                expr: Box::new(expr),
+                target_type: parse_type_name(return_type)?.wrap_nullable(),
+                pg_style: false,
+            };
+        }
         let udf_expr = self
             .clone_expr_with_replacement(&expr, &|nest_expr| {
                 if let Expr::ColumnRef { column, .. } = nest_expr {
@@ -2402,7 +2783,88 @@ impl<'a> TypeChecker<'a> {
             })
             .map_err(|e| e.set_span(span))?;
 
-        Ok(Some(self.resolve(&udf_expr).await?))
+        // The UDFs called by the definition are inlined recursively, record
+        // the current one to detect cycles.
+        self.bind_context.udf_calls.push(func_name.to_string());
+        let result = self.resolve(&udf_expr).await;
+        self.bind_context.udf_calls.pop();
+        Ok(Some(result?))
+    }
+
+    /// Resolve a call of an aggregate UDF evaluated by a UDF server, the
+    /// arguments are cast to the declared types.
+    #[async_recursion::async_recursion]
+    #[async_backtrace::framed]
+    async fn resolve_udaf(
+        &mut self,
+        span: Span,
+        expr: &Expr,
+        udf: UserDefinedFunction,
+        arguments: &[Expr],
+    ) -> Result<(AggregateFunction, DataType)> {
+        if self.in_aggregate_function {
+            self.in_aggregate_function = false;
+            return Err(ErrorCode::SemanticError(
+                "aggregate function calls cannot be nested".to_string(),
+            )
+            .set_span(span));
+        }
+        if udf.arg_types.len() != arguments.len() {
+            return Err(ErrorCode::SyntaxException(format!(
+                "Require {} parameters, but got: {}",
+                udf.arg_types.len(),
+                arguments.len()
+            ))
+            .set_span(span));
+        }
+
+        self.in_aggregate_function = true;
+        let mut args = Vec::with_capacity(arguments.len());
+        for (argument, arg_type) in arguments.iter().zip(udf.arg_types.iter()) {
+            let argument = Expr::Cast {
+                span,
+                expr: Box::new(argument.clone()),
+                target_type: parse_type_name(arg_type)?.wrap_nullable(),
+                pg_style: false,
+            };
+            let result = self.resolve(&argument).await;
+            match result {
+                Ok(box (arg, _)) => args.push(arg),
+                Err(err) => {
+                    self.in_aggregate_function = false;
+                    return Err(err);
+                }
+            }
+        }
+        self.in_aggregate_function = false;
+
+        let state_fields = udf
+            .state_fields
+            .unwrap_or_default()
+            .iter()
+            .map(|(name, field_type)| {
+                let field_type = resolve_type_name_by_str(field_type)?;
+                Ok((name.clone(), DataType::from(&field_type).wrap_nullable()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let return_type = resolve_type_name_by_str(&udf.return_type.unwrap_or_default())?;
+        let return_type = DataType::from(&return_type).wrap_nullable();
+        let udf_server = udf.udf_server.unwrap_or_default();
+
+        let agg_func = AggregateFunction {
+            display_name: format!("{:#}", expr),
+            func_name: udf.name,
+            distinct: false,
+            params: vec![],
+            args,
+            return_type: Box::new(return_type.clone()),
+            udaf: Some(UdafServer {
+                address: udf_server.address,
+                handler: udf_server.handler,
+                state_fields,
+            }),
+        };
+        Ok((agg_func, return_type))
     }
 
     #[async_recursion::async_recursion]
@@ -2889,6 +3351,7 @@ impl<'a> TypeChecker<'a> {
                     args,
                     params,
                     window,
+                    lambda,
                 } => Ok(Expr::FunctionCall {
                     span: *span,
                     distinct: *distinct,
@@ -2899,6 +3362,7 @@ impl<'a> TypeChecker<'a> {
                         .collect::<Result<Vec<Expr>>>()?,
                     params: params.clone(),
                     window: window.clone(),
+                    lambda: lambda.clone(),
                 }),
                 Expr::Case {
                     span,
@@ -3012,7 +3476,7 @@ impl<'a> TypeChecker<'a> {
     }
 }
 
-pub fn resolve_type_name_by_str(name: &str) -> Result<TableDataType> {
+pub fn parse_type_name(name: &str) -> Result<TypeName> {
     let sql_tokens = common_ast::parser::tokenize_sql(name)?;
     let backtrace = common_ast::Backtrace::new();
     match common_ast::parser::expr::type_name(common_ast::Input(
@@ -3020,7 +3484,7 @@ pub fn resolve_type_name_by_str(name: &str) -> Result<TableDataType> {
         common_ast::Dialect::default(),
         &backtrace,
     )) {
-        Ok((_, typename)) => resolve_type_name(&typename),
+        Ok((_, typename)) => Ok(typename),
         Err(err) => Err(ErrorCode::SyntaxException(format!(
             "Unsupported type name: {}, error: {}",
             name, err
@@ -3028,6 +3492,10 @@ pub fn resolve_type_name_by_str(name: &str) -> Result<TableDataType> {
     }
 }
 
+pub fn resolve_type_name_by_str(name: &str) -> Result<TableDataType> {
+    resolve_type_name(&parse_type_name(name)?)
+}
+
 pub fn resolve_type_name(type_name: &TypeName) -> Result<TableDataType> {
     let data_type = match type_name {
         TypeName::Boolean => TableDataType::Boolean,
@@ -3076,6 +3544,8 @@ pub fn resolve_type_name(type_name: &TypeName) -> Result<TableDataType> {
             }
         }
         TypeName::Bitmap => TableDataType::Bitmap,
+        TypeName::Geometry => TableDataType::Geometry,
+        TypeName::Binary => TableDataType::Binary,
         TypeName::Tuple {
             fields_type,
             fields_name,
@@ -3171,3 +3641,37 @@ fn check_prefix(like_str: &str) -> bool {
     }
     true
 }
+
+/// Returns the collation of `<expr> COLLATE <collation>`.
+fn collation_of(expr: &Expr) -> Option<&str> {
+    match expr {
+        Expr::FunctionCall { name, args, .. } if name.name.eq_ignore_ascii_case("collate") => {
+            match args.as_slice() {
+                [
+                    _,
+                    Expr::Literal {
+                        lit: Literal::String(collation),
+                        ..
+                    },
+                ] => Some(collation.as_str()),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn with_collation(expr: &Expr, collation: &str) -> Expr {
+    Expr::FunctionCall {
+        span: expr.span(),
+        distinct: false,
+        name: Identifier::from_name("collate"),
+        args: vec![expr.clone(), Expr::Literal {
+            span: None,
+            lit: Literal::String(collation.to_string()),
+        }],
+        params: vec![],
+        window: None,
+        lambda: None,
+    }
+}
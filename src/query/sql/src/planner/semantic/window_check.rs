@@ -18,6 +18,7 @@ use common_exception::Result;
 use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::FunctionCall;
+use crate::plans::LambdaFunc;
 use crate::BindContext;
 use crate::ColumnBinding;
 use crate::ScalarExpr;
@@ -49,6 +50,21 @@ impl<'a> WindowChecker<'a> {
                 }
                 .into())
             }
+            ScalarExpr::LambdaFunction(func) => {
+                let args = func
+                    .args
+                    .iter()
+                    .map(|arg| self.resolve(arg))
+                    .collect::<Result<Vec<ScalarExpr>>>()?;
+                Ok(LambdaFunc {
+                    span: func.span,
+                    func_name: func.func_name.clone(),
+                    args,
+                    lambda_expr: func.lambda_expr.clone(),
+                    lambda_display: func.lambda_display.clone(),
+                }
+                .into())
+            }
             ScalarExpr::CastExpr(cast) => Ok(CastExpr {
                 span: cast.span,
                 is_try: cast.is_try,
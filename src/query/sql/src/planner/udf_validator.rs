@@ -17,9 +17,14 @@ use std::collections::HashSet;
 use common_ast::ast::ColumnID;
 use common_ast::ast::Expr;
 use common_ast::ast::Identifier;
+use common_ast::ast::Lambda;
 use common_ast::ast::Literal;
+use common_ast::ast::Query;
+use common_ast::ast::TableReference;
 use common_ast::ast::Window;
 use common_ast::walk_expr;
+use common_ast::walk_query;
+use common_ast::walk_table_reference;
 use common_ast::Visitor;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -70,7 +75,30 @@ impl UDFValidator {
     }
 }
 
+impl UDFValidator {
+    /// Verifies the definition of a table UDF. The parameters are not checked,
+    /// as they can't be told from the columns of the tables it reads.
+    pub fn verify_definition_query(&mut self, definition_query: &Query) -> Result<()> {
+        walk_query(self, definition_query);
+
+        if self.has_recursive {
+            return Err(ErrorCode::SyntaxException("Recursive UDF is not supported"));
+        }
+        Ok(())
+    }
+}
+
 impl<'ast> Visitor<'ast> for UDFValidator {
+    fn visit_table_reference(&mut self, table: &'ast TableReference) {
+        if let TableReference::TableFunction { name, .. } = table {
+            if self.name.eq_ignore_ascii_case(&name.to_string()) {
+                self.has_recursive = true;
+                return;
+            }
+        }
+        walk_table_reference(self, table);
+    }
+
     fn visit_column_ref(
         &mut self,
         _span: Span,
@@ -89,6 +117,7 @@ impl<'ast> Visitor<'ast> for UDFValidator {
         args: &'ast [Expr],
         _params: &'ast [Literal],
         over: &'ast Option<Window>,
+        lambda: &'ast Option<Lambda>,
     ) {
         let name = name.to_string();
         if !is_builtin_function(&name) && self.name.eq_ignore_ascii_case(&name) {
@@ -120,5 +149,17 @@ impl<'ast> Visitor<'ast> for UDFValidator {
                 }
             }
         }
+
+        if let Some(lambda) = lambda {
+            let expr_params = self.expr_params.clone();
+            walk_expr(self, &lambda.expr);
+            // The parameters of the lambda are not the parameters of the UDF.
+            for param in &lambda.params {
+                let param = param.to_string();
+                if !expr_params.contains(&param) {
+                    self.expr_params.remove(&param);
+                }
+            }
+        }
     }
 }
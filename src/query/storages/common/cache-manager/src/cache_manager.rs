@@ -14,6 +14,7 @@
 
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_base::base::GlobalInstance;
 use common_cache::CountableMeter;
@@ -95,15 +96,20 @@ impl CacheManager {
                 table_column_array_cache,
             }));
         } else {
-            let table_snapshot_cache =
+            let mut table_snapshot_cache =
                 Self::new_item_cache(config.table_meta_snapshot_count, "table_snapshot");
             let table_statistic_cache =
                 Self::new_item_cache(config.table_meta_statistic_count, "table_statistics");
-            let segment_info_cache = Self::new_in_memory_cache(
+            let mut segment_info_cache = Self::new_in_memory_cache(
                 config.table_meta_segment_bytes,
                 CompactSegmentInfoMeter {},
                 "segment_info",
             );
+            if config.table_meta_ttl_secs > 0 {
+                let ttl = Duration::from_secs(config.table_meta_ttl_secs);
+                table_snapshot_cache = table_snapshot_cache.map(|c| c.with_ttl(ttl));
+                segment_info_cache = segment_info_cache.map(|c| c.with_ttl(ttl));
+            }
             let bloom_index_filter_cache =
                 Self::new_item_cache(config.table_bloom_index_filter_count, "bloom_index_filter");
             let bloom_index_meta_cache = Self::new_item_cache(
@@ -12,13 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::hash::BuildHasher;
 use std::hash::Hash;
 use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
 
 use common_cache::Count;
 use common_cache::CountableMeter;
 use common_cache::DefaultHashBuilder;
+use parking_lot::Mutex;
 
 use crate::metrics_inc_cache_access_count;
 use crate::metrics_inc_cache_hit_count;
@@ -50,6 +54,7 @@ where Self: Sized
         NamedCache {
             name: name.into(),
             cache: self,
+            ttl: None,
         }
     }
 }
@@ -61,6 +66,7 @@ impl<T> Named for T where T: Sized + Clone {}
 pub struct NamedCache<C> {
     name: String,
     cache: C,
+    ttl: Option<ItemTtl>,
 }
 
 impl<C> NamedCache<C> {
@@ -68,17 +74,44 @@ impl<C> NamedCache<C> {
     pub fn name(&self) -> &str {
         &self.name
     }
+
+    /// Items that have been cached longer than `ttl` are treated as missing, and evicted on access.
+    ///
+    /// The capacity (and the LRU eviction) is still managed by the underlying cache, the ttl
+    /// only lets the items of cold tables be released before the cache is full.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ItemTtl {
+            ttl,
+            inserted_at: Arc::new(Mutex::new(HashMap::new())),
+        });
+        self
+    }
 }
 
-impl<K, V, S, M, C> CacheAccessor<K, V, S, M> for NamedCache<C>
+#[derive(Clone)]
+struct ItemTtl {
+    ttl: Duration,
+    inserted_at: Arc<Mutex<HashMap<String, Instant>>>,
+}
+
+impl<V, S, M, C> CacheAccessor<String, V, S, M> for NamedCache<C>
 where
-    C: CacheAccessor<K, V, S, M>,
-    K: Eq + Hash,
+    C: CacheAccessor<String, V, S, M>,
     S: BuildHasher,
-    M: CountableMeter<K, Arc<V>>,
+    M: CountableMeter<String, Arc<V>>,
 {
     fn get<Q: AsRef<str>>(&self, k: Q) -> Option<Arc<V>> {
         metrics_inc_cache_access_count(1, &self.name);
+        if let Some(ItemTtl { ttl, inserted_at }) = &self.ttl {
+            let mut inserted_at = inserted_at.lock();
+            let expired = matches!(inserted_at.get(k.as_ref()), Some(t) if t.elapsed() > *ttl);
+            if expired {
+                inserted_at.remove(k.as_ref());
+                self.cache.evict(k.as_ref());
+                metrics_inc_cache_miss_count(1, &self.name);
+                return None;
+            }
+        }
         match self.cache.get(k) {
             None => {
                 metrics_inc_cache_miss_count(1, &self.name);
@@ -91,11 +124,27 @@ where
         }
     }
 
-    fn put(&self, key: K, value: Arc<V>) {
-        self.cache.put(key, value)
+    fn put(&self, key: String, value: Arc<V>) {
+        match &self.ttl {
+            None => self.cache.put(key, value),
+            Some(ItemTtl { inserted_at, .. }) => {
+                let mut inserted_at = inserted_at.lock();
+                self.cache.put(key.clone(), value);
+                inserted_at.insert(key, Instant::now());
+
+                // items evicted by the underlying cache leave their timestamps behind,
+                // drop them once they outnumber the cached items.
+                if inserted_at.len() > 2 * self.cache.len() + 1 {
+                    inserted_at.retain(|k, _| self.cache.contains_key(k));
+                }
+            }
+        }
     }
 
     fn evict(&self, k: &str) -> bool {
+        if let Some(ItemTtl { inserted_at, .. }) = &self.ttl {
+            inserted_at.lock().remove(k);
+        }
         self.cache.evict(k)
     }
 
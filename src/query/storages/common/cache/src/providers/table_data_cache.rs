@@ -38,15 +38,17 @@ struct CacheItem {
     value: Arc<Vec<u8>>,
 }
 
+/// Key of the cached raw column chunk, consists of the block location, the column id,
+/// and the byte range of the column chunk inside the block.
 #[derive(Clone)]
 pub struct TableDataCacheKey {
     cache_key: String,
 }
 
 impl TableDataCacheKey {
-    pub fn new(block_path: &str, column_id: u32) -> Self {
+    pub fn new(block_path: &str, column_id: u32, offset: u64, len: u64) -> Self {
         Self {
-            cache_key: format!("{block_path}-{column_id}"),
+            cache_key: format!("{block_path}-{column_id}-{offset}-{len}"),
         }
     }
 }
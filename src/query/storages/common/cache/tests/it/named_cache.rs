@@ -0,0 +1,53 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use storages_common_cache::CacheAccessor;
+use storages_common_cache::InMemoryCacheBuilder;
+use storages_common_cache::Named;
+
+#[test]
+fn test_named_cache_ttl() {
+    let cache = InMemoryCacheBuilder::new_item_cache::<u64>(10)
+        .name_with("test")
+        .with_ttl(Duration::from_millis(100));
+
+    cache.put("a".to_string(), Arc::new(1));
+    assert_eq!(cache.get("a").as_deref(), Some(&1));
+
+    std::thread::sleep(Duration::from_millis(200));
+    cache.put("b".to_string(), Arc::new(2));
+
+    // expired item is evicted on access
+    assert!(cache.get("a").is_none());
+    assert!(!cache.contains_key("a"));
+    assert_eq!(cache.get("b").as_deref(), Some(&2));
+    assert_eq!(cache.len(), 1);
+}
+
+#[test]
+fn test_named_cache_without_ttl() {
+    let cache = InMemoryCacheBuilder::new_item_cache::<u64>(2).name_with("test");
+
+    cache.put("a".to_string(), Arc::new(1));
+    cache.put("b".to_string(), Arc::new(2));
+    cache.put("c".to_string(), Arc::new(3));
+
+    // capacity is still managed by the lru cache
+    assert!(cache.get("a").is_none());
+    assert_eq!(cache.get("b").as_deref(), Some(&2));
+    assert_eq!(cache.get("c").as_deref(), Some(&3));
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::ops::Deref;
 use std::sync::Arc;
 
@@ -22,6 +23,7 @@ use common_exception::Result;
 use common_exception::Span;
 use common_expression::converts::scalar_to_datavalue;
 use common_expression::eval_function;
+use common_expression::types::string::StringColumnBuilder;
 use common_expression::types::AnyType;
 use common_expression::types::DataType;
 use common_expression::types::MapType;
@@ -38,6 +40,7 @@ use common_expression::Expr;
 use common_expression::FieldIndex;
 use common_expression::FunctionContext;
 use common_expression::Scalar;
+use common_expression::ScalarRef;
 use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchema;
@@ -55,6 +58,9 @@ use crate::filters::Xor8Builder;
 use crate::filters::Xor8Filter;
 use crate::Index;
 
+/// The prefixes of string values are indexed up to this length (in bytes).
+pub const PREFIX_FILTER_MAX_LEN: usize = 8;
+
 #[derive(Clone)]
 pub struct BloomIndexMeta {
     pub columns: Vec<(String, SingleColumnMeta)>,
@@ -116,10 +122,11 @@ impl TryFrom<ThriftFileMetaData> for BloomIndexMeta {
 /// ```
 /// We will create table of filters as follows:
 /// ```
-///         +---Bloom(name)--+--Bloom(age)--+
-///         |  123456789abcd |  ac2345bcd   |
-///         +----------------+--------------+
+///         +---Bloom(name)--+--Bloom(age)--+--PrefixBloom(name)--+
+///         |  123456789abcd |  ac2345bcd   |  b7c6d5e4f3a2b1c0   |
+///         +----------------+--------------+---------------------+
 /// ```
+/// The prefix filter of string column is used by predicates like `name LIKE 'Al%'`.
 pub struct BloomIndex {
     pub func_ctx: FunctionContext,
 
@@ -294,6 +301,14 @@ impl BloomIndex {
             let filter_name = Self::build_filter_column_name(version, field)?;
             filter_fields.push(TableField::new(&filter_name, TableDataType::String));
             filters.push(Arc::new(filter));
+
+            if field.data_type().remove_nullable() == TableDataType::String {
+                if let Some(filter) = Self::build_prefix_filter(&func_ctx, column)? {
+                    let filter_name = Self::build_prefix_filter_column_name(version, field)?;
+                    filter_fields.push(TableField::new(&filter_name, TableDataType::String));
+                    filters.push(Arc::new(filter));
+                }
+            }
         }
 
         let filter_schema = Arc::new(TableSchema::new(filter_fields));
@@ -346,6 +361,22 @@ impl BloomIndex {
             },
         )?;
 
+        visit_expr_column_prefix_constant(
+            &mut expr,
+            &mut |span, col_name, prefix, return_type| {
+                // If no value of the column starts with the prefix, we rewrite the expression to `false`.
+                if self.find_prefix(col_name, prefix, scalar_map)? == FilterEvalResult::MustFalse {
+                    Ok(Some(Expr::Constant {
+                        span,
+                        scalar: Scalar::Boolean(false),
+                        data_type: return_type.clone(),
+                    }))
+                } else {
+                    Ok(None)
+                }
+            },
+        )?;
+
         let (new_expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
 
         match new_expr {
@@ -407,6 +438,20 @@ impl BloomIndex {
         Ok(cols)
     }
 
+    /// Find all string columns that match the pattern of `col LIKE 'prefix%'` or
+    /// `starts_with(col, 'prefix')` in the expression.
+    ///
+    /// The returned scalar is the prefix truncated to `PREFIX_FILTER_MAX_LEN`, which is
+    /// the key to be looked up in the prefix filter.
+    pub fn find_prefix_columns(expr: &Expr<String>) -> Result<Vec<(String, Scalar)>> {
+        let mut cols = Vec::new();
+        visit_expr_column_prefix_constant(&mut expr.clone(), &mut |_, col_name, prefix, _| {
+            cols.push((col_name.to_string(), prefix.clone()));
+            Ok(None)
+        })?;
+        Ok(cols)
+    }
+
     /// For every applicable column, we will create a filter.
     /// The filter will be stored with field name 'Bloom(column_name)'
     pub fn build_filter_column_name(version: u64, field: &TableField) -> Result<String> {
@@ -422,6 +467,94 @@ impl BloomIndex {
         }
     }
 
+    /// For every string column, we will create a prefix filter besides the bloom filter.
+    /// The filter will be stored with field name 'PrefixBloom(column_name)'
+    pub fn build_prefix_filter_column_name(version: u64, field: &TableField) -> Result<String> {
+        let index_version = BlockBloomFilterIndexVersion::try_from(version)?;
+        match index_version {
+            BlockBloomFilterIndexVersion::V0(_) => Err(ErrorCode::DeprecatedIndexFormat(
+                "bloom filter index version(v0) is deprecated",
+            )),
+            BlockBloomFilterIndexVersion::V2(_) | BlockBloomFilterIndexVersion::V3(_) => {
+                Ok(format!("PrefixBloom({})", field.name()))
+            }
+            BlockBloomFilterIndexVersion::V4(_) => {
+                Ok(format!("PrefixBloom({})", field.column_id()))
+            }
+        }
+    }
+
+    /// Build a filter of the prefixes (up to `PREFIX_FILTER_MAX_LEN` bytes) of the string values.
+    fn build_prefix_filter(
+        func_ctx: &FunctionContext,
+        column: &Column,
+    ) -> Result<Option<Xor8Filter>> {
+        let mut prefixes = HashSet::new();
+        for value in column.iter() {
+            if let ScalarRef::String(s) = value {
+                for len in 1..=s.len().min(PREFIX_FILTER_MAX_LEN) {
+                    prefixes.insert(&s[..len]);
+                }
+            }
+        }
+        if prefixes.is_empty() {
+            return Ok(None);
+        }
+
+        let mut builder = StringColumnBuilder::with_capacity(
+            prefixes.len(),
+            prefixes.len() * PREFIX_FILTER_MAX_LEN,
+        );
+        for prefix in prefixes {
+            builder.put_slice(prefix);
+            builder.commit_row();
+        }
+        let prefix_column = Column::String(builder.build());
+
+        let col = Self::calculate_column_digest(
+            func_ctx,
+            &prefix_column,
+            &DataType::String,
+            &DataType::Number(NumberDataType::UInt64),
+        )?;
+        let digests = UInt64Type::try_downcast_column(&col).unwrap();
+
+        let mut filter_builder = Xor8Builder::create();
+        filter_builder.add_digests(digests.deref());
+        Ok(Some(filter_builder.build()?))
+    }
+
+    fn find_prefix(
+        &self,
+        column_name: &str,
+        prefix: &Scalar,
+        scalar_map: &HashMap<Scalar, u64>,
+    ) -> Result<FilterEvalResult> {
+        let filter_column = &Self::build_prefix_filter_column_name(
+            self.version,
+            self.source_schema.field_with_name(column_name)?,
+        )?;
+
+        if !self.filter_schema.has_field(filter_column) {
+            // The column doesn't have a prefix filter.
+            return Ok(FilterEvalResult::Uncertain);
+        }
+
+        let idx = self.filter_schema.index_of(filter_column)?;
+        let filter = &self.filters[idx];
+
+        let contains = match scalar_map.get(prefix) {
+            Some(digest) => filter.contains_digest(*digest),
+            None => true,
+        };
+
+        if contains {
+            Ok(FilterEvalResult::Uncertain)
+        } else {
+            Ok(FilterEvalResult::MustFalse)
+        }
+    }
+
     fn find(
         &self,
         column_name: &str,
@@ -554,6 +687,138 @@ fn visit_expr_column_eq_constant(
     Ok(())
 }
 
+fn visit_expr_column_prefix_constant(
+    expr: &mut Expr<String>,
+    visitor: &mut impl FnMut(Span, &str, &Scalar, &DataType) -> Result<Option<Expr<String>>>,
+) -> Result<()> {
+    // Find patterns like `Column LIKE 'prefix%'` or `starts_with(Column, 'prefix')`
+    if let Expr::FunctionCall {
+        span,
+        function,
+        args,
+        return_type,
+        ..
+    } = expr
+    {
+        let name = function.signature.name.as_str();
+        if name == "like" || name == "starts_with" {
+            if let [
+                Expr::ColumnRef {
+                    id,
+                    data_type: column_type,
+                    ..
+                },
+                Expr::Constant {
+                    scalar: Scalar::String(pattern),
+                    ..
+                },
+            ] = args.as_slice()
+            {
+                let prefix = if name == "like" {
+                    like_pattern_prefix(pattern)
+                } else {
+                    pattern.as_slice()
+                };
+                if column_type.remove_nullable() == DataType::String && !prefix.is_empty() {
+                    let len = prefix.len().min(PREFIX_FILTER_MAX_LEN);
+                    let prefix = Scalar::String(prefix[..len].to_vec());
+                    // If the visitor returns a new expression, then replace with the current expression.
+                    if let Some(new_expr) = visitor(*span, id, &prefix, return_type)? {
+                        *expr = new_expr;
+                        return Ok(());
+                    }
+                }
+            }
+        }
+    }
+
+    // The planner rewrites `Column LIKE 'prefix%'` into the range
+    // `Column >= 'prefix' AND Column < 'prefiy'`, recognize it as a prefix too.
+    if let Expr::FunctionCall {
+        span,
+        function,
+        args,
+        return_type,
+        ..
+    } = expr
+    {
+        let name = function.signature.name.as_str();
+        if (name == "and" || name == "and_filters") && args.len() == 2 {
+            if let (Some(("gte", lo_id, lo)), Some(("lt", hi_id, hi))) =
+                (range_bound(&args[0]), range_bound(&args[1]))
+            {
+                if lo_id == hi_id && is_prefix_range(lo, hi) {
+                    let id = lo_id.to_string();
+                    let len = lo.len().min(PREFIX_FILTER_MAX_LEN);
+                    let prefix = Scalar::String(lo[..len].to_vec());
+                    if let Some(new_expr) = visitor(*span, &id, &prefix, return_type)? {
+                        *expr = new_expr;
+                        return Ok(());
+                    }
+                }
+            }
+        }
+    }
+
+    // Otherwise, rewrite sub expressions.
+    match expr {
+        Expr::Cast { expr, .. } => {
+            visit_expr_column_prefix_constant(expr, visitor)?;
+        }
+        Expr::FunctionCall { args, .. } => {
+            for arg in args.iter_mut() {
+                visit_expr_column_prefix_constant(arg, visitor)?;
+            }
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
+
+/// Returns the comparison name, column id and bound of `Column <op> 'constant'` on a string column.
+fn range_bound(expr: &Expr<String>) -> Option<(&str, &str, &[u8])> {
+    let expr = match expr {
+        Expr::Cast { expr, .. } => expr.as_ref(),
+        _ => expr,
+    };
+    if let Expr::FunctionCall { function, args, .. } = expr {
+        if let [
+            Expr::ColumnRef { id, data_type, .. },
+            Expr::Constant {
+                scalar: Scalar::String(bound),
+                ..
+            },
+        ] = args.as_slice()
+        {
+            if data_type.remove_nullable() == DataType::String {
+                return Some((function.signature.name.as_str(), id.as_str(), bound));
+            }
+        }
+    }
+    None
+}
+
+/// Checks whether `[lo, hi)` is the range of strings starting with `lo`,
+/// i.e. `hi` is `lo` with the last byte incremented by one.
+fn is_prefix_range(lo: &[u8], hi: &[u8]) -> bool {
+    match (lo.split_last(), hi.split_last()) {
+        (Some((lo_last, lo_init)), Some((hi_last, hi_init))) => {
+            lo_init == hi_init && *lo_last < 0x7f && *hi_last == *lo_last + 1
+        }
+        _ => false,
+    }
+}
+
+/// Returns the literal prefix of a like pattern, which all the matched values start with.
+fn like_pattern_prefix(pattern: &[u8]) -> &[u8] {
+    let end = pattern
+        .iter()
+        .position(|c| matches!(*c, b'%' | b'_' | b'\\'))
+        .unwrap_or(pattern.len());
+    &pattern[..end]
+}
+
 fn visit_map_column(
     span: Span,
     args: &[Expr<String>],
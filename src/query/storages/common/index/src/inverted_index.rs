@@ -0,0 +1,253 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::Span;
+use common_expression::types::array::ArrayColumn;
+use common_expression::types::ArrayType;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::UInt32Type;
+use common_expression::types::ValueType;
+use common_expression::ConstantFolder;
+use common_expression::DataBlock;
+use common_expression::Expr;
+use common_expression::FromData;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_expression::TableSchemaRefExt;
+use common_functions::scalars::tokenize;
+use common_functions::BUILTIN_FUNCTIONS;
+
+use crate::FilterEvalResult;
+
+/// InvertedIndex represents the posting lists of the text columns of a block.
+///
+/// The text of each row is split into terms by [`tokenize`], the posting list of a term
+/// is the ascending offsets of the rows containing it. The index is stored as a block of
+/// one row per term:
+/// ```
+///         +--column--+--term--+--postings--+
+///         |  "body"  | "bob"  |  [0, 3]    |
+///         |  "body"  | "data" |  [1]       |
+///         +----------+--------+------------+
+/// ```
+/// It is used to prune the blocks by predicates like `match(body, 'bob')`.
+pub struct InvertedIndex {
+    /// The posting lists of each indexed column, keyed by column name and term.
+    pub postings: BTreeMap<String, BTreeMap<String, Vec<u32>>>,
+}
+
+impl InvertedIndex {
+    /// Create the index of the string columns `columns` of the block.
+    ///
+    /// Returns [None] if none of the columns is in the block.
+    pub fn try_create(
+        source_schema: &TableSchemaRef,
+        block: &DataBlock,
+        columns: &[String],
+    ) -> Result<Option<Self>> {
+        let schema = source_schema.remove_virtual_computed_fields();
+        let mut postings = BTreeMap::new();
+        for name in columns {
+            let index = match schema.index_of(name) {
+                Ok(index) => index,
+                Err(_) => continue,
+            };
+            let entry = block.get_by_offset(index);
+            if entry.data_type.remove_nullable() != DataType::String {
+                continue;
+            }
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, block.num_rows());
+
+            let mut column_postings: BTreeMap<String, Vec<u32>> = BTreeMap::new();
+            for (row, value) in column.iter().enumerate() {
+                if let ScalarRef::String(text) = value {
+                    for term in tokenize(text) {
+                        let rows = column_postings.entry(term).or_default();
+                        if rows.last() != Some(&(row as u32)) {
+                            rows.push(row as u32);
+                        }
+                    }
+                }
+            }
+            postings.insert(name.clone(), column_postings);
+        }
+
+        if postings.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Self { postings }))
+    }
+
+    /// The schema of the serialized index.
+    pub fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("column", TableDataType::String),
+            TableField::new("term", TableDataType::String),
+            TableField::new(
+                "postings",
+                TableDataType::Array(Box::new(TableDataType::Number(NumberDataType::UInt32))),
+            ),
+        ])
+    }
+
+    pub fn serialize_to_data_block(&self) -> Result<DataBlock> {
+        let mut columns = Vec::new();
+        let mut terms = Vec::new();
+        let mut values = Vec::new();
+        let mut offsets = vec![0u64];
+        for (column, column_postings) in self.postings.iter() {
+            for (term, rows) in column_postings.iter() {
+                columns.push(column.as_str());
+                terms.push(term.as_str());
+                values.extend_from_slice(rows);
+                offsets.push(values.len() as u64);
+            }
+        }
+
+        let postings = ArrayType::<UInt32Type>::upcast_column(ArrayColumn {
+            values: values.into(),
+            offsets: offsets.into(),
+        });
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(columns),
+            StringType::from_data(terms),
+            postings,
+        ]))
+    }
+
+    /// Load the index from the block serialized by [`Self::serialize_to_data_block`].
+    pub fn from_data_block(block: &DataBlock) -> Result<Self> {
+        let downcast_error = || ErrorCode::StorageOther("invalid inverted index block");
+        let num_rows = block.num_rows();
+        let column_at = |index: usize| {
+            let entry = block.get_by_offset(index);
+            entry
+                .value
+                .convert_to_full_column(&entry.data_type, num_rows)
+        };
+        let columns = column_at(0);
+        let terms = column_at(1);
+        let postings = column_at(2);
+        let columns = StringType::try_downcast_column(&columns).ok_or_else(downcast_error)?;
+        let terms = StringType::try_downcast_column(&terms).ok_or_else(downcast_error)?;
+        let postings =
+            ArrayType::<UInt32Type>::try_downcast_column(&postings).ok_or_else(downcast_error)?;
+
+        let mut index: BTreeMap<String, BTreeMap<String, Vec<u32>>> = BTreeMap::new();
+        for ((column, term), rows) in columns.iter().zip(terms.iter()).zip(postings.iter()) {
+            index
+                .entry(String::from_utf8_lossy(column).into_owned())
+                .or_default()
+                .insert(String::from_utf8_lossy(term).into_owned(), rows.to_vec());
+        }
+        Ok(Self { postings: index })
+    }
+
+    /// Apply the predicate expression, return `MustFalse` if no row of the block can
+    /// match the expression.
+    ///
+    /// The predicates `match(col, 'query')` on the indexed columns are rewritten to `false`
+    /// if some terms of the query are not in the column.
+    pub fn apply(
+        &self,
+        mut expr: Expr<String>,
+        func_ctx: &FunctionContext,
+    ) -> Result<FilterEvalResult> {
+        visit_expr_column_match(&mut expr, &mut |span, col_name, query, return_type| {
+            let column_postings = match self.postings.get(col_name) {
+                Some(column_postings) => column_postings,
+                None => return Ok(None),
+            };
+            let terms = tokenize(query);
+            if terms.iter().all(|term| column_postings.contains_key(term)) {
+                return Ok(None);
+            }
+            Ok(Some(Expr::Constant {
+                span,
+                scalar: Scalar::Boolean(false),
+                data_type: return_type.clone(),
+            }))
+        })?;
+
+        let (new_expr, _) = ConstantFolder::fold(&expr, func_ctx, &BUILTIN_FUNCTIONS);
+        match new_expr {
+            Expr::Constant {
+                scalar: Scalar::Boolean(false),
+                ..
+            } => Ok(FilterEvalResult::MustFalse),
+            _ => Ok(FilterEvalResult::Uncertain),
+        }
+    }
+
+    /// Find all the columns that match the pattern of `match(col, 'query')` in the expression.
+    pub fn find_match_columns(expr: &Expr<String>) -> Result<Vec<(String, Vec<u8>)>> {
+        let mut cols = Vec::new();
+        visit_expr_column_match(&mut expr.clone(), &mut |_, col_name, query, _| {
+            cols.push((col_name.to_string(), query.to_vec()));
+            Ok(None)
+        })?;
+        Ok(cols)
+    }
+}
+
+fn visit_expr_column_match(
+    expr: &mut Expr<String>,
+    visitor: &mut impl FnMut(Span, &str, &[u8], &DataType) -> Result<Option<Expr<String>>>,
+) -> Result<()> {
+    match expr {
+        Expr::FunctionCall {
+            span,
+            function,
+            args,
+            return_type,
+            ..
+        } if function.signature.name == "match" => {
+            if let [
+                Expr::ColumnRef { id, .. },
+                Expr::Constant {
+                    scalar: Scalar::String(query),
+                    ..
+                },
+            ] = args.as_slice()
+            {
+                // If the visitor returns a new expression, then replace with the current expression.
+                if let Some(new_expr) = visitor(*span, id, query, return_type)? {
+                    *expr = new_expr;
+                }
+            }
+        }
+        Expr::Cast { expr, .. } => {
+            visit_expr_column_match(expr, visitor)?;
+        }
+        Expr::FunctionCall { args, .. } => {
+            for arg in args.iter_mut() {
+                visit_expr_column_match(arg, visitor)?;
+            }
+        }
+        _ => (),
+    }
+    Ok(())
+}
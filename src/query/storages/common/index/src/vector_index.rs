@@ -0,0 +1,403 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use common_arrow::arrow::buffer::Buffer;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::array::ArrayColumn;
+use common_expression::types::ArrayType;
+use common_expression::types::DataType;
+use common_expression::types::Float32Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::UInt64Type;
+use common_expression::types::ValueType;
+use common_expression::types::F32;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::ScalarRef;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_expression::TableSchemaRefExt;
+
+/// Max number of clusters of the vectors of a column in a block.
+const MAX_CLUSTERS: usize = 16;
+/// Max number of vectors used to train the centroids of the clusters.
+const MAX_TRAINING_VECTORS: usize = 4096;
+const TRAINING_ITERATIONS: usize = 5;
+
+/// VectorIndex partitions the vectors of the `Array(Float32)` columns of a block into
+/// clusters, like an IVF index, but at the block level.
+///
+/// A cluster keeps its centroid and the max distance from its vectors to the centroid,
+/// so the distance from a query vector to any vector of the cluster is bounded by the
+/// triangle inequality. It is used to prune the blocks of
+/// `ORDER BY cosine_distance(col, [..]) LIMIT k` that can't contain the k nearest rows.
+///
+/// The cosine distance is bounded by the clusters of the normalized vectors, as the
+/// cosine distance of two vectors is half of the squared euclidean distance of the
+/// normalized ones.
+pub struct VectorIndex {
+    /// The clusters of each indexed column, keyed by column name.
+    pub clusters: BTreeMap<String, Vec<VectorCluster>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct VectorCluster {
+    pub centroid: Vec<f32>,
+    /// Max euclidean distance from the vectors of the cluster to the centroid.
+    pub radius: f32,
+    pub rows: u64,
+    /// The centroid normalized, zero if the centroid is zero.
+    pub normalized_centroid: Vec<f32>,
+    /// Max euclidean distance from the normalized vectors to the normalized centroid.
+    pub normalized_radius: f32,
+    /// Number of vectors not zero, the cosine distance of zero vectors is NaN.
+    pub normalized_rows: u64,
+}
+
+/// The bounds of the distances from a query vector to the vectors of a block.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VectorDistanceBounds {
+    /// No vector of the block is nearer than it.
+    pub min: f32,
+    /// The max distance of the vectors of each cluster, with the number of the vectors.
+    pub clusters: Vec<(f32, u64)>,
+}
+
+impl VectorIndex {
+    /// Create the index of the `Array(Float32)` columns `columns` of the block.
+    ///
+    /// Returns [None] if none of the columns is in the block. The column is not indexed if
+    /// its vectors are not of the same length.
+    pub fn try_create(
+        source_schema: &TableSchemaRef,
+        block: &DataBlock,
+        columns: &[String],
+    ) -> Result<Option<Self>> {
+        let schema = source_schema.remove_virtual_computed_fields();
+        let vector_type = DataType::Array(Box::new(DataType::Number(NumberDataType::Float32)));
+        let mut clusters = BTreeMap::new();
+        for name in columns {
+            let index = match schema.index_of(name) {
+                Ok(index) => index,
+                Err(_) => continue,
+            };
+            let entry = block.get_by_offset(index);
+            if entry.data_type.remove_nullable() != vector_type {
+                continue;
+            }
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, block.num_rows());
+
+            let mut dim = None;
+            let mut vectors: Vec<f32> = Vec::new();
+            let mut same_length = true;
+            for value in column.iter() {
+                if let ScalarRef::Array(array) = value {
+                    let values = match Float32Type::try_downcast_column(&array) {
+                        Some(values) => values,
+                        None => continue,
+                    };
+                    if *dim.get_or_insert(values.len()) != values.len() {
+                        same_length = false;
+                        break;
+                    }
+                    vectors.extend(values.iter().map(|v| v.0));
+                }
+            }
+            // not indexed if all the vectors are NULL.
+            if let Some(dim) = dim.filter(|dim| *dim > 0 && same_length) {
+                clusters.insert(name.clone(), build_clusters(&vectors, dim));
+            }
+        }
+
+        if clusters.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Self { clusters }))
+    }
+
+    /// The schema of the serialized index, one row per cluster.
+    pub fn schema() -> TableSchemaRef {
+        let vector_type =
+            TableDataType::Array(Box::new(TableDataType::Number(NumberDataType::Float32)));
+        TableSchemaRefExt::create(vec![
+            TableField::new("column", TableDataType::String),
+            TableField::new("centroid", vector_type.clone()),
+            TableField::new("radius", TableDataType::Number(NumberDataType::Float32)),
+            TableField::new("rows", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("normalized_centroid", vector_type),
+            TableField::new(
+                "normalized_radius",
+                TableDataType::Number(NumberDataType::Float32),
+            ),
+            TableField::new(
+                "normalized_rows",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+        ])
+    }
+
+    pub fn serialize_to_data_block(&self) -> Result<DataBlock> {
+        let mut columns = Vec::new();
+        let mut centroids = VectorsBuilder::default();
+        let mut radiuses = Vec::new();
+        let mut rows = Vec::new();
+        let mut normalized_centroids = VectorsBuilder::default();
+        let mut normalized_radiuses = Vec::new();
+        let mut normalized_rows = Vec::new();
+        for (column, column_clusters) in self.clusters.iter() {
+            for cluster in column_clusters.iter() {
+                columns.push(column.as_str());
+                centroids.push(&cluster.centroid);
+                radiuses.push(cluster.radius);
+                rows.push(cluster.rows);
+                normalized_centroids.push(&cluster.normalized_centroid);
+                normalized_radiuses.push(cluster.normalized_radius);
+                normalized_rows.push(cluster.normalized_rows);
+            }
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(columns),
+            centroids.build(),
+            Float32Type::from_data(radiuses),
+            UInt64Type::from_data(rows),
+            normalized_centroids.build(),
+            Float32Type::from_data(normalized_radiuses),
+            UInt64Type::from_data(normalized_rows),
+        ]))
+    }
+
+    /// Load the index from the block serialized by [`Self::serialize_to_data_block`].
+    pub fn from_data_block(block: &DataBlock) -> Result<Self> {
+        let downcast_error = || ErrorCode::StorageOther("invalid vector index block");
+        let num_rows = block.num_rows();
+        let column_at = |index: usize| {
+            let entry = block.get_by_offset(index);
+            entry
+                .value
+                .convert_to_full_column(&entry.data_type, num_rows)
+        };
+        let (columns, centroids, radiuses, rows) =
+            (column_at(0), column_at(1), column_at(2), column_at(3));
+        let (normalized_centroids, normalized_radiuses, normalized_rows) =
+            (column_at(4), column_at(5), column_at(6));
+        let columns = StringType::try_downcast_column(&columns).ok_or_else(downcast_error)?;
+        let centroids =
+            ArrayType::<Float32Type>::try_downcast_column(&centroids).ok_or_else(downcast_error)?;
+        let radiuses = Float32Type::try_downcast_column(&radiuses).ok_or_else(downcast_error)?;
+        let rows = UInt64Type::try_downcast_column(&rows).ok_or_else(downcast_error)?;
+        let normalized_centroids =
+            ArrayType::<Float32Type>::try_downcast_column(&normalized_centroids)
+                .ok_or_else(downcast_error)?;
+        let normalized_radiuses =
+            Float32Type::try_downcast_column(&normalized_radiuses).ok_or_else(downcast_error)?;
+        let normalized_rows =
+            UInt64Type::try_downcast_column(&normalized_rows).ok_or_else(downcast_error)?;
+
+        let mut clusters: BTreeMap<String, Vec<VectorCluster>> = BTreeMap::new();
+        for (i, column) in columns.iter().enumerate() {
+            let to_vec = |v: Buffer<F32>| v.iter().map(|v| v.0).collect::<Vec<_>>();
+            clusters
+                .entry(String::from_utf8_lossy(column).into_owned())
+                .or_default()
+                .push(VectorCluster {
+                    centroid: to_vec(centroids.index(i).ok_or_else(downcast_error)?),
+                    radius: radiuses[i].0,
+                    rows: rows[i],
+                    normalized_centroid: to_vec(
+                        normalized_centroids.index(i).ok_or_else(downcast_error)?,
+                    ),
+                    normalized_radius: normalized_radiuses[i].0,
+                    normalized_rows: normalized_rows[i],
+                });
+        }
+        Ok(Self { clusters })
+    }
+
+    /// The bounds of the distances from `query` to the vectors of the column, by the
+    /// cosine distance if `cosine` is true, otherwise by the euclidean distance.
+    ///
+    /// Returns [None] if the column is not indexed, or the bounds are unknown.
+    pub fn distance_bounds(
+        &self,
+        column: &str,
+        query: &[f32],
+        cosine: bool,
+    ) -> Option<VectorDistanceBounds> {
+        let clusters = self.clusters.get(column)?;
+        let mut bounds = VectorDistanceBounds {
+            min: f32::INFINITY,
+            clusters: Vec::with_capacity(clusters.len()),
+        };
+        if cosine {
+            // the cosine distances to a zero vector are NaN.
+            let query = normalize(query)?;
+            for cluster in clusters.iter().filter(|c| c.normalized_rows > 0) {
+                if cluster.normalized_centroid.len() != query.len() {
+                    return None;
+                }
+                let distance = l2_distance(&query, &cluster.normalized_centroid);
+                let lower = (distance - cluster.normalized_radius).max(0.0);
+                let upper = (distance + cluster.normalized_radius).min(2.0);
+                bounds.min = bounds.min.min(lower * lower / 2.0);
+                bounds
+                    .clusters
+                    .push((upper * upper / 2.0, cluster.normalized_rows));
+            }
+        } else {
+            for cluster in clusters.iter() {
+                if cluster.centroid.len() != query.len() {
+                    return None;
+                }
+                let distance = l2_distance(query, &cluster.centroid);
+                bounds.min = bounds.min.min((distance - cluster.radius).max(0.0));
+                bounds
+                    .clusters
+                    .push((distance + cluster.radius, cluster.rows));
+            }
+        }
+        Some(bounds)
+    }
+}
+
+#[derive(Default)]
+struct VectorsBuilder {
+    values: Vec<F32>,
+    offsets: Vec<u64>,
+}
+
+impl VectorsBuilder {
+    fn push(&mut self, vector: &[f32]) {
+        if self.offsets.is_empty() {
+            self.offsets.push(0);
+        }
+        self.values.extend(vector.iter().map(|v| F32::from(*v)));
+        self.offsets.push(self.values.len() as u64);
+    }
+
+    fn build(mut self) -> Column {
+        if self.offsets.is_empty() {
+            self.offsets.push(0);
+        }
+        ArrayType::<Float32Type>::upcast_column(ArrayColumn {
+            values: self.values.into(),
+            offsets: self.offsets.into(),
+        })
+    }
+}
+
+/// Cluster the vectors by k-means, the centroids are trained by a sample of the vectors.
+fn build_clusters(vectors: &[f32], dim: usize) -> Vec<VectorCluster> {
+    let num_vectors = vectors.len() / dim;
+    let vector_at = |i: usize| &vectors[i * dim..(i + 1) * dim];
+
+    let step = std::cmp::max(1, num_vectors / MAX_TRAINING_VECTORS);
+    let training: Vec<usize> = (0..num_vectors).step_by(step).collect();
+    let num_clusters = ((num_vectors as f64).sqrt().ceil() as usize).clamp(1, MAX_CLUSTERS);
+    let mut centroids: Vec<Vec<f32>> = (0..num_clusters)
+        .map(|i| vector_at(training[i * training.len() / num_clusters]).to_vec())
+        .collect();
+
+    for _ in 0..TRAINING_ITERATIONS {
+        let mut sums = vec![vec![0f64; dim]; num_clusters];
+        let mut counts = vec![0usize; num_clusters];
+        for i in training.iter() {
+            let vector = vector_at(*i);
+            let nearest = nearest_centroid(&centroids, vector);
+            counts[nearest] += 1;
+            for (sum, v) in sums[nearest].iter_mut().zip(vector) {
+                *sum += *v as f64;
+            }
+        }
+        for ((centroid, sum), count) in centroids.iter_mut().zip(sums).zip(counts) {
+            // an empty cluster keeps its centroid.
+            if count > 0 {
+                for (c, s) in centroid.iter_mut().zip(sum) {
+                    *c = (s / count as f64) as f32;
+                }
+            }
+        }
+    }
+
+    let mut clusters: Vec<VectorCluster> = centroids
+        .into_iter()
+        .map(|centroid| VectorCluster {
+            normalized_centroid: normalize(&centroid).unwrap_or_else(|| vec![0.0; dim]),
+            centroid,
+            radius: 0.0,
+            rows: 0,
+            normalized_radius: 0.0,
+            normalized_rows: 0,
+        })
+        .collect();
+    let centroids: Vec<Vec<f32>> = clusters.iter().map(|c| c.centroid.clone()).collect();
+    for i in 0..num_vectors {
+        let vector = vector_at(i);
+        let cluster = &mut clusters[nearest_centroid(&centroids, vector)];
+        cluster.rows += 1;
+        cluster.radius = cluster.radius.max(l2_distance(vector, &cluster.centroid));
+        if let Some(normalized) = normalize(vector) {
+            cluster.normalized_rows += 1;
+            cluster.normalized_radius = cluster
+                .normalized_radius
+                .max(l2_distance(&normalized, &cluster.normalized_centroid));
+        }
+    }
+    clusters.retain(|c| c.rows > 0);
+    clusters
+}
+
+fn nearest_centroid(centroids: &[Vec<f32>], vector: &[f32]) -> usize {
+    let mut nearest = 0;
+    let mut nearest_distance = f32::INFINITY;
+    for (i, centroid) in centroids.iter().enumerate() {
+        let distance = squared_l2_distance(vector, centroid);
+        if distance < nearest_distance {
+            nearest = i;
+            nearest_distance = distance;
+        }
+    }
+    nearest
+}
+
+fn squared_l2_distance(a: &[f32], b: &[f32]) -> f32 {
+    a.iter()
+        .zip(b)
+        .map(|(x, y)| {
+            let diff = x - y;
+            diff * diff
+        })
+        .sum()
+}
+
+fn l2_distance(a: &[f32], b: &[f32]) -> f32 {
+    squared_l2_distance(a, b).sqrt()
+}
+
+/// Returns [None] for zero vectors.
+fn normalize(vector: &[f32]) -> Option<Vec<f32>> {
+    let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm == 0.0 || !norm.is_finite() {
+        return None;
+    }
+    Some(vector.iter().map(|v| v / norm).collect())
+}
@@ -190,6 +190,28 @@ fn test_bloom_filter() -> Result<()> {
             DataType::String
         )
     );
+
+    assert_eq!(
+        FilterEvalResult::Uncertain,
+        eval_prefix_index(&index, "1", "like", b"a%")
+    );
+    assert_eq!(
+        FilterEvalResult::Uncertain,
+        eval_prefix_index(&index, "1", "starts_with", b"c")
+    );
+    assert_eq!(
+        FilterEvalResult::MustFalse,
+        eval_prefix_index(&index, "1", "like", b"d%")
+    );
+    assert_eq!(
+        FilterEvalResult::MustFalse,
+        eval_prefix_index(&index, "1", "starts_with", b"ab")
+    );
+    // The pattern has no literal prefix, the prefix filter can not be used.
+    assert_eq!(
+        FilterEvalResult::Uncertain,
+        eval_prefix_index(&index, "1", "like", b"%d")
+    );
     Ok(())
 }
 
@@ -285,3 +307,45 @@ fn eval_map_index(
 
     index.apply(expr, &scalar_map).unwrap()
 }
+
+fn eval_prefix_index(
+    index: &BloomIndex,
+    col_name: &str,
+    func_name: &str,
+    pattern: &[u8],
+) -> FilterEvalResult {
+    let expr = check_function(
+        None,
+        func_name,
+        &[],
+        &[
+            Expr::ColumnRef {
+                span: None,
+                id: col_name.to_string(),
+                data_type: DataType::String,
+                display_name: col_name.to_string(),
+            },
+            Expr::Constant {
+                span: None,
+                scalar: Scalar::String(pattern.to_vec()),
+                data_type: DataType::String,
+            },
+        ],
+        &BUILTIN_FUNCTIONS,
+    )
+    .unwrap();
+
+    let prefix_query_cols = BloomIndex::find_prefix_columns(&expr).unwrap();
+
+    let mut scalar_map = HashMap::<Scalar, u64>::new();
+    let func_ctx = FunctionContext::default();
+    for (_, prefix) in prefix_query_cols.iter() {
+        if !scalar_map.contains_key(prefix) {
+            let digest =
+                BloomIndex::calculate_scalar_digest(&func_ctx, prefix, &DataType::String).unwrap();
+            scalar_map.insert(prefix.clone(), digest);
+        }
+    }
+
+    index.apply(expr, &scalar_map).unwrap()
+}
@@ -191,6 +191,8 @@ fn build_test_segment_info(num_blocks_per_seg: usize) -> common_exception::Resul
         bloom_filter_index_location: Some(location_gen.block_bloom_index_location(&block_uuid)),
         bloom_filter_index_size: 0,
         compression: Compression::Lz4,
+        deletion_vector_location: None,
+        deleted_row_count: 0,
     };
 
     let block_metas = (0..num_blocks_per_seg)
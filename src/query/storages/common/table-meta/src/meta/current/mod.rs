@@ -16,12 +16,13 @@ pub use v0::ColumnMeta as SingleColumnMeta;
 pub use v1::TableSnapshotStatistics;
 pub use v2::BlockMeta;
 pub use v2::ColumnMeta;
-pub use v4::CompactSegmentInfo;
-pub use v4::SegmentInfo;
 pub use v4::TableSnapshot;
 pub use v4::TableSnapshotLite;
+pub use v5::CompactSegmentInfo;
+pub use v5::SegmentInfo;
 
 use super::v0;
 use super::v1;
 use super::v2;
 use super::v4;
+use super::v5;
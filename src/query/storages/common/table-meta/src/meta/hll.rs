@@ -0,0 +1,98 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_expression::ColumnId;
+
+pub type DistinctHLLOfColumns = HashMap<ColumnId, ColumnDistinctHLL>;
+
+// 2^10 registers, the standard error of the estimation is about 1.04 / sqrt(2^10) = 3.25%
+const HLL_PRECISION: u32 = 10;
+const HLL_REGISTERS: usize = 1 << HLL_PRECISION;
+
+/// HyperLogLog sketch of the hashes of a column's values.
+///
+/// Sketches of different blocks can be merged, so the number of distinct values of a
+/// column could be maintained incrementally while the table is being written.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ColumnDistinctHLL {
+    registers: Vec<u8>,
+}
+
+impl Default for ColumnDistinctHLL {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ColumnDistinctHLL {
+    pub fn new() -> Self {
+        Self {
+            registers: vec![0; HLL_REGISTERS],
+        }
+    }
+
+    pub fn add_hash(&mut self, hash: u64) {
+        let index = (hash >> (64 - HLL_PRECISION)) as usize;
+        // the remaining bits, with a sentinel bit to bound the rank
+        let w = (hash << HLL_PRECISION) | (1 << (HLL_PRECISION - 1));
+        let rank = w.leading_zeros() as u8 + 1;
+        if self.registers[index] < rank {
+            self.registers[index] = rank;
+        }
+    }
+
+    pub fn merge(&mut self, other: &Self) {
+        for (l, r) in self.registers.iter_mut().zip(other.registers.iter()) {
+            if *l < *r {
+                *l = *r;
+            }
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.registers.iter().all(|r| *r == 0)
+    }
+
+    /// Estimated number of distinct values.
+    pub fn count(&self) -> u64 {
+        let m = HLL_REGISTERS as f64;
+        let mut sum = 0.0;
+        let mut zeros = 0;
+        for r in &self.registers {
+            sum += 1.0 / (1u64 << r) as f64;
+            if *r == 0 {
+                zeros += 1;
+            }
+        }
+
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let estimate = alpha * m * m / sum;
+        if estimate <= 2.5 * m && zeros > 0 {
+            // small range correction, use linear counting.
+            (m * (m / zeros as f64).ln()).round() as u64
+        } else {
+            estimate.round() as u64
+        }
+    }
+}
+
+pub fn merge_column_hlls(l: &mut DistinctHLLOfColumns, r: &DistinctHLLOfColumns) {
+    for (column_id, hll) in r {
+        l.entry(*column_id)
+            .and_modify(|v| v.merge(hll))
+            .or_insert_with(|| hll.clone());
+    }
+}
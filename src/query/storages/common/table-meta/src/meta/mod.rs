@@ -18,12 +18,14 @@ mod statistics;
 mod compression;
 mod current;
 mod format;
+mod hll;
 mod utils;
 mod v0;
 mod v1;
 mod v2;
 mod v3;
 mod v4;
+mod v5;
 mod versions;
 
 pub use compression::Compression;
@@ -32,6 +34,9 @@ pub use current::*;
 pub(crate) use format::load_json;
 pub(crate) use format::MetaCompression;
 pub(crate) use format::MetaEncoding;
+pub use hll::merge_column_hlls;
+pub use hll::ColumnDistinctHLL;
+pub use hll::DistinctHLLOfColumns;
 pub use statistics::ClusterKey;
 pub use statistics::ClusterStatistics;
 pub use statistics::ColumnStatistics;
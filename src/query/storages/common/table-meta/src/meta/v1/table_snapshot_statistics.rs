@@ -19,6 +19,7 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use crate::meta::statistics::FormatVersion;
+use crate::meta::DistinctHLLOfColumns;
 use crate::meta::SnapshotId;
 use crate::meta::Versioned;
 
@@ -31,6 +32,10 @@ pub struct TableSnapshotStatistics {
     pub snapshot_id: SnapshotId,
 
     pub column_distinct_values: HashMap<ColumnId, u64>,
+
+    /// sketches of the columns whose distinct values are maintained incrementally while writing.
+    #[serde(default)]
+    pub column_hlls: DistinctHLLOfColumns,
 }
 
 impl TableSnapshotStatistics {
@@ -39,6 +44,47 @@ impl TableSnapshotStatistics {
             format_version: TableSnapshotStatistics::VERSION,
             snapshot_id: SnapshotId::new_v4(),
             column_distinct_values,
+            column_hlls: HashMap::new(),
+        }
+    }
+
+    pub fn from_column_hlls(column_hlls: DistinctHLLOfColumns) -> Self {
+        let column_distinct_values = column_hlls
+            .iter()
+            .map(|(column_id, hll)| (*column_id, hll.count()))
+            .collect();
+        Self {
+            format_version: TableSnapshotStatistics::VERSION,
+            snapshot_id: SnapshotId::new_v4(),
+            column_distinct_values,
+            column_hlls,
+        }
+    }
+
+    /// Merges the sketches of newly written data, returns the statistics of a new snapshot.
+    ///
+    /// The columns whose distinct values are not backed by a sketch (e.g. estimated by ANALYZE)
+    /// can not be merged, they are kept as they are.
+    pub fn merge_column_hlls(&self, column_hlls: &DistinctHLLOfColumns) -> Self {
+        let mut merged = self.column_hlls.clone();
+        let mut column_distinct_values = self.column_distinct_values.clone();
+        for (column_id, hll) in column_hlls {
+            if !self.column_hlls.contains_key(column_id)
+                && self.column_distinct_values.contains_key(column_id)
+            {
+                continue;
+            }
+            let merged_hll = merged
+                .entry(*column_id)
+                .and_modify(|v| v.merge(hll))
+                .or_insert_with(|| hll.clone());
+            column_distinct_values.insert(*column_id, merged_hll.count());
+        }
+        Self {
+            format_version: TableSnapshotStatistics::VERSION,
+            snapshot_id: SnapshotId::new_v4(),
+            column_distinct_values,
+            column_hlls: merged,
         }
     }
 
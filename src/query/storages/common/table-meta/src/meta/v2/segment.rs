@@ -73,6 +73,12 @@ pub struct BlockMeta {
     #[serde(default)]
     pub bloom_filter_index_size: u64,
     pub compression: Compression,
+    /// location of deletion vector, which marks the rows deleted from the data block
+    #[serde(default)]
+    pub deletion_vector_location: Option<Location>,
+    /// number of rows marked in the deletion vector
+    #[serde(default)]
+    pub deleted_row_count: u64,
 }
 
 impl BlockMeta {
@@ -100,6 +106,8 @@ impl BlockMeta {
             bloom_filter_index_location,
             bloom_filter_index_size,
             compression,
+            deletion_vector_location: None,
+            deleted_row_count: 0,
         }
     }
 
@@ -107,6 +115,11 @@ impl BlockMeta {
         self.compression
     }
 
+    /// Number of rows not marked as deleted.
+    pub fn live_row_count(&self) -> u64 {
+        self.row_count - self.deleted_row_count
+    }
+
     /// Get the page size of the block.
     /// - If the format is parquet, its page size is its row count.
     /// - If the format is native, its page size is the row count of each page.
@@ -233,6 +246,8 @@ impl BlockMeta {
             bloom_filter_index_location: None,
             bloom_filter_index_size: 0,
             compression: Compression::Lz4,
+            deletion_vector_location: None,
+            deleted_row_count: 0,
         }
     }
 
@@ -263,6 +278,8 @@ impl BlockMeta {
             bloom_filter_index_location: s.bloom_filter_index_location.clone(),
             bloom_filter_index_size: s.bloom_filter_index_size,
             compression: s.compression,
+            deletion_vector_location: None,
+            deleted_row_count: 0,
         }
     }
 }
@@ -61,6 +61,8 @@ impl From<BlockMeta> for crate::meta::BlockMeta {
             bloom_filter_index_location: value.bloom_filter_index_location,
             bloom_filter_index_size: value.bloom_filter_index_size,
             compression: value.compression.into(),
+            deletion_vector_location: None,
+            deleted_row_count: 0,
         }
     }
 }
@@ -23,6 +23,60 @@ pub const OPT_KEY_COMMENT: &str = "comment";
 pub const OPT_KEY_EXTERNAL_LOCATION: &str = "external_location";
 pub const OPT_KEY_ENGINE: &str = "engine";
 
+/// Transient table option key
+///
+/// Only the latest snapshot of a transient table is retained, the historical data is
+/// purged on each commit. `CREATE TRANSIENT TABLE` and the `transient = true` option
+/// both set it to `T`.
+pub const OPT_KEY_TRANSIENT: &str = "TRANSIENT";
+
+/// Materialized view option keys
+///
+/// A materialized view is stored as a fuse table carrying these options:
+/// - the defining query, with the database of the source table qualified
+/// - the id of the source table
+/// - the source table snapshot location consumed by the last refresh
+/// - the unix timestamp (in seconds) of the last refresh
+pub const OPT_KEY_MATERIALIZED_VIEW_QUERY: &str = "materialized_view_query";
+pub const OPT_KEY_MATERIALIZED_VIEW_SOURCE: &str = "materialized_view_source";
+pub const OPT_KEY_MATERIALIZED_VIEW_SNAPSHOT: &str = "materialized_view_snapshot";
+pub const OPT_KEY_MATERIALIZED_VIEW_REFRESHED_ON: &str = "materialized_view_refreshed_on";
+
+/// Row access policy option keys
+///
+/// - the name of the row access policy attached to the table
+/// - the comma separated columns bound to the arguments of the policy
+pub const OPT_KEY_ROW_ACCESS_POLICY: &str = "row_access_policy";
+pub const OPT_KEY_ROW_ACCESS_POLICY_COLUMNS: &str = "row_access_policy_columns";
+
+/// Unique keys of the table, separated by `;`, each a comma separated list of columns.
+///
+/// The keys are declarative, inserts don't check them, `INSERT ... ON CONFLICT`
+/// requires its conflict columns to be one of them.
+pub const OPT_KEY_UNIQUE_KEYS: &str = "unique_keys";
+
+/// The data uri of an attached table, `ATTACH TABLE <table> '<uri>'`.
+///
+/// The fuse table at the uri is written by another deployment, the attached table is
+/// read-only, its latest snapshot is resolved from the snapshot hint on each query.
+pub const OPT_KEY_TABLE_ATTACHED_DATA_URI: &str = "table_data_uri";
+
+/// Table clone option keys, `CREATE TABLE <table> CLONE <source>`.
+///
+/// - the id of the source table, kept by the clone
+/// - the comma separated ids of the clones, kept by the source table
+///
+/// A clone starts from a snapshot of the source and shares its segments and blocks, the
+/// source keeps the files referenced by the latest snapshots of its clones when purged.
+pub const OPT_KEY_CLONE_SOURCE_TABLE_ID: &str = "clone_source_table_id";
+pub const OPT_KEY_TABLE_CLONES: &str = "table_clones";
+
+/// The comma separated partition columns of an external table, in the order of the
+/// directory levels, `CREATE EXTERNAL TABLE ... PARTITIONED BY (<columns>)`.
+///
+/// The files of a partition are under `<column>=<value>/...` of the table location.
+pub const OPT_KEY_PARTITION_BY: &str = "partition_by";
+
 /// Legacy table snapshot location key
 ///
 /// # Deprecated
@@ -40,6 +94,16 @@ pub static RESERVED_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SOURCE);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SNAPSHOT);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_REFRESHED_ON);
+    r.insert(OPT_KEY_ROW_ACCESS_POLICY);
+    r.insert(OPT_KEY_ROW_ACCESS_POLICY_COLUMNS);
+    r.insert(OPT_KEY_TABLE_ATTACHED_DATA_URI);
+    r.insert(OPT_KEY_CLONE_SOURCE_TABLE_ID);
+    r.insert(OPT_KEY_TABLE_CLONES);
+    r.insert(OPT_KEY_PARTITION_BY);
     r
 });
 
@@ -48,6 +112,16 @@ pub static INTERNAL_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
     r.insert(OPT_KEY_DATABASE_ID);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SOURCE);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SNAPSHOT);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_REFRESHED_ON);
+    r.insert(OPT_KEY_ROW_ACCESS_POLICY);
+    r.insert(OPT_KEY_ROW_ACCESS_POLICY_COLUMNS);
+    r.insert(OPT_KEY_TABLE_ATTACHED_DATA_URI);
+    r.insert(OPT_KEY_CLONE_SOURCE_TABLE_ID);
+    r.insert(OPT_KEY_TABLE_CLONES);
+    r.insert(OPT_KEY_PARTITION_BY);
     r
 });
 
@@ -58,3 +132,17 @@ pub fn is_reserved_opt_key<S: AsRef<str>>(opt_key: S) -> bool {
 pub fn is_internal_opt_key<S: AsRef<str>>(opt_key: S) -> bool {
     INTERNAL_TABLE_OPTION_KEYS.contains(opt_key.as_ref().to_lowercase().as_str())
 }
+
+/// Parse the value of table option `unique_keys`, a list of keys separated by `;`.
+pub fn parse_unique_keys(value: &str) -> Vec<Vec<String>> {
+    value
+        .split(';')
+        .map(|key| {
+            key.split(',')
+                .map(|column| column.trim().to_string())
+                .filter(|column| !column.is_empty())
+                .collect::<Vec<_>>()
+        })
+        .filter(|key| !key.is_empty())
+        .collect()
+}
@@ -19,6 +19,20 @@ pub fn table_storage_prefix(database_id: impl Display, table_id: impl Display) -
     format!("{}/{}", database_id, table_id)
 }
 
+/// Splits the data uri of a fuse table, `<root>/<database_id>/<table_id>/`, into the root
+/// and the storage prefix of the table, returns [None] if the uri doesn't end with the ids.
+pub fn split_table_data_uri(uri: &str) -> Option<(&str, String)> {
+    let path = uri.trim_end_matches('/');
+    let (rest, table_id) = path.rsplit_once('/')?;
+    let (root, database_id) = rest.rsplit_once('/')?;
+    let table_id = table_id.parse::<u64>().ok()?;
+    let database_id = database_id.parse::<u64>().ok()?;
+    Some((
+        &uri[..root.len() + 1],
+        table_storage_prefix(database_id, table_id),
+    ))
+}
+
 /// Constructs the prefix path which covers all the data of of a give table identity
 pub fn database_storage_prefix(database_id: impl Display) -> String {
     format!("{}", database_id)
@@ -21,6 +21,7 @@ use common_exception::Result;
 use common_meta_app::schema::TableInfo;
 use common_storages_memory::MemoryTable;
 use common_storages_null::NullTable;
+use common_storages_parquet::ExternalTable;
 use common_storages_random::RandomTable;
 use common_storages_view::view_table::ViewTable;
 use dashmap::DashMap;
@@ -102,6 +103,12 @@ impl StorageFactory {
             descriptor: Arc::new(RandomTable::description),
         });
 
+        // Register EXTERNAL table engine
+        creators.insert("EXTERNAL".to_string(), Storage {
+            creator: Arc::new(ExternalTable::try_create),
+            descriptor: Arc::new(ExternalTable::description),
+        });
+
         StorageFactory { storages: creators }
     }
 
@@ -17,6 +17,15 @@ pub const FUSE_OPT_KEY_BLOCK_PER_SEGMENT: &str = "block_per_segment";
 pub const FUSE_OPT_KEY_ROW_PER_BLOCK: &str = "row_per_block";
 pub const FUSE_OPT_KEY_ROW_PER_PAGE: &str = "row_per_page";
 pub const FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD: &str = "row_avg_depth_threshold";
+pub const FUSE_OPT_KEY_MAX_PREFETCH_BLOCKS: &str = "max_prefetch_blocks";
+pub const FUSE_OPT_KEY_SEGMENT_MAX_BLOCKS: &str = "segment_max_blocks";
+pub const FUSE_OPT_KEY_AUTO_COMPACT: &str = "auto_compact";
+pub const FUSE_OPT_KEY_VARIANT_SHREDDING: &str = "variant_shredding";
+pub const FUSE_OPT_KEY_INVERTED_INDEX_COLUMNS: &str = "inverted_index_columns";
+pub const FUSE_OPT_KEY_VECTOR_INDEX_COLUMNS: &str = "vector_index_columns";
+pub const FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS: &str = "data_retention_period_in_hours";
+pub const FUSE_OPT_KEY_REPLICATION_STAGE: &str = "replication_stage";
+pub const FUSE_OPT_KEY_NOTIFICATION_INTEGRATION: &str = "notification_integration";
 
 pub const FUSE_TBL_BLOCK_PREFIX: &str = "_b";
 pub const FUSE_TBL_BLOCK_INDEX_PREFIX: &str = "_i";
@@ -26,9 +35,21 @@ pub const FUSE_TBL_SNAPSHOT_PREFIX: &str = "_ss";
 pub const FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX: &str = "_ts";
 pub const FUSE_TBL_LAST_SNAPSHOT_HINT: &str = "last_snapshot_location_hint";
 pub const FUSE_TBL_VIRTUAL_BLOCK_PREFIX: &str = "_vb";
+pub const FUSE_TBL_DELETION_VECTOR_PREFIX: &str = "_dv";
+pub const FUSE_TBL_AGG_INDEX_PREFIX: &str = "_i_a";
+pub const FUSE_TBL_INVERTED_INDEX_PREFIX: &str = "_i_i";
+pub const FUSE_TBL_VECTOR_INDEX_PREFIX: &str = "_i_v";
 
 pub const DEFAULT_BLOCK_PER_SEGMENT: usize = 1000;
 pub const DEFAULT_ROW_PER_PAGE: usize = 8192;
 pub const DEFAULT_ROW_PER_PAGE_FOR_BLOCKING: usize = 2048;
 
 pub const DEFAULT_AVG_DEPTH_THRESHOLD: f64 = 0.01;
+
+pub const DEFAULT_MAX_PREFETCH_BLOCKS: usize = 4;
+
+// 0 means the appended blocks are not batched into consolidated segments.
+pub const DEFAULT_SEGMENT_MAX_BLOCKS: usize = 0;
+
+// 90 days, the historical data is not kept longer for time travel.
+pub const MAX_DATA_RETENTION_PERIOD_IN_HOURS: u64 = 90 * 24;
@@ -58,17 +58,22 @@ use storages_common_table_meta::meta::Statistics as FuseStatistics;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::TableSnapshotStatistics;
 use storages_common_table_meta::meta::Versioned;
+use storages_common_table_meta::table::split_table_data_uri;
 use storages_common_table_meta::table::table_storage_prefix;
 use storages_common_table_meta::table::TableCompression;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
+use storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+use storages_common_table_meta::table::OPT_KEY_TRANSIENT;
 use tracing::error;
 use tracing::warn;
 use uuid::Uuid;
 
+use crate::io::parse_inverted_index_columns;
+use crate::io::parse_vector_index_columns;
 use crate::io::MetaReaders;
 use crate::io::TableMetaLocationGenerator;
 use crate::io::WriteSettings;
@@ -80,11 +85,18 @@ use crate::TableStatistics;
 use crate::DEFAULT_BLOCK_PER_SEGMENT;
 use crate::DEFAULT_ROW_PER_PAGE;
 use crate::DEFAULT_ROW_PER_PAGE_FOR_BLOCKING;
+use crate::DEFAULT_SEGMENT_MAX_BLOCKS;
 use crate::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
+use crate::FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS;
+use crate::FUSE_OPT_KEY_INVERTED_INDEX_COLUMNS;
 use crate::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use crate::FUSE_OPT_KEY_ROW_PER_PAGE;
+use crate::FUSE_OPT_KEY_SEGMENT_MAX_BLOCKS;
+use crate::FUSE_OPT_KEY_VARIANT_SHREDDING;
+use crate::FUSE_OPT_KEY_VECTOR_INDEX_COLUMNS;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
+use crate::MAX_DATA_RETENTION_PERIOD_IN_HOURS;
 
 #[derive(Clone)]
 pub struct FuseTable {
@@ -181,12 +193,33 @@ impl FuseTable {
         let max_page_size = self.get_option(FUSE_OPT_KEY_ROW_PER_PAGE, default_rows_per_page);
         let block_per_seg =
             self.get_option(FUSE_OPT_KEY_BLOCK_PER_SEGMENT, DEFAULT_BLOCK_PER_SEGMENT);
+        let segment_max_blocks =
+            self.get_option(FUSE_OPT_KEY_SEGMENT_MAX_BLOCKS, DEFAULT_SEGMENT_MAX_BLOCKS);
+        // virtual columns can only be read from native format.
+        let variant_shredding = matches!(self.storage_format, FuseStorageFormat::Native)
+            && self.get_option(FUSE_OPT_KEY_VARIANT_SHREDDING, false);
+        let inverted_index_columns = self
+            .table_info
+            .options()
+            .get(FUSE_OPT_KEY_INVERTED_INDEX_COLUMNS)
+            .map(|value| parse_inverted_index_columns(value))
+            .unwrap_or_default();
+        let vector_index_columns = self
+            .table_info
+            .options()
+            .get(FUSE_OPT_KEY_VECTOR_INDEX_COLUMNS)
+            .map(|value| parse_vector_index_columns(value))
+            .unwrap_or_default();
 
         WriteSettings {
             storage_format: self.storage_format,
             table_compression: self.table_compression,
             max_page_size,
             block_per_seg,
+            segment_max_blocks,
+            variant_shredding,
+            inverted_index_columns,
+            vector_index_columns,
         }
     }
 
@@ -200,6 +233,16 @@ impl FuseTable {
     }
 
     pub fn parse_storage_prefix(table_info: &TableInfo) -> Result<String> {
+        // the data of an attached table is at the storage prefix of the attached table.
+        if let Some(uri) = table_info.options().get(OPT_KEY_TABLE_ATTACHED_DATA_URI) {
+            return split_table_data_uri(uri)
+                .map(|(_, prefix)| prefix)
+                .ok_or_else(|| {
+                    ErrorCode::Internal(format!(
+                        "Invalid attached fuse table, invalid data uri {uri}"
+                    ))
+                });
+        }
         let table_id = table_info.ident.table_id;
         let db_id = table_info
             .options()
@@ -296,6 +339,10 @@ impl FuseTable {
                     }
                 }
             }
+            DatabaseType::NormalDB if self.is_attached() => {
+                Self::read_attached_snapshot_location(&self.operator, &self.meta_location_generator)
+                    .await
+            }
             DatabaseType::NormalDB => {
                 let options = self.table_info.options();
                 Ok(options
@@ -324,8 +371,82 @@ impl FuseTable {
         })
     }
 
+    /// Reads the location of the latest snapshot of an attached table from the snapshot
+    /// hint left by its last commit, [None] if nothing is committed yet.
+    #[async_backtrace::framed]
+    pub async fn read_attached_snapshot_location(
+        operator: &Operator,
+        location_generator: &TableMetaLocationGenerator,
+    ) -> Result<Option<String>> {
+        let hint_path = location_generator.gen_last_snapshot_hint_location();
+        let data = match operator.read(&hint_path).await {
+            Ok(data) => data,
+            Err(e) if e.kind() == opendal::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        // the hint is prefixed with the storage root of the writer, which may be spelled
+        // differently from the root of the attached table, the location starts at the
+        // storage prefix of the table.
+        let hint = str::from_utf8(&data)?.trim();
+        let prefix = format!("{}/", location_generator.prefix());
+        if hint.starts_with(&prefix) {
+            return Ok(Some(hint.to_string()));
+        }
+        match hint.rfind(&format!("/{prefix}")) {
+            Some(pos) => Ok(Some(hint[pos + 1..].to_string())),
+            None => Err(ErrorCode::StorageOther(format!(
+                "Invalid snapshot hint of the attached table: {hint}"
+            ))),
+        }
+    }
+
+    /// The data of attached tables is written by another deployment, they are read-only.
+    pub fn is_attached(&self) -> bool {
+        self.table_info
+            .options()
+            .contains_key(OPT_KEY_TABLE_ATTACHED_DATA_URI)
+    }
+
+    pub fn check_mutable(&self) -> Result<()> {
+        if self.is_attached() {
+            return Err(ErrorCode::TableNotWritable(format!(
+                "table {} is attached, it is read-only",
+                self.table_info.name
+            )));
+        }
+        Ok(())
+    }
+
     pub fn transient(&self) -> bool {
-        self.table_info.meta.options.contains_key("TRANSIENT")
+        self.table_info.meta.options.contains_key(OPT_KEY_TRANSIENT)
+    }
+
+    /// The hours the historical data is kept for time travel, the table option
+    /// `data_retention_period_in_hours` overrides the setting `retention_period`.
+    ///
+    /// The result is at most `MAX_DATA_RETENTION_PERIOD_IN_HOURS`, so it can be
+    /// converted to a duration safely.
+    pub fn get_data_retention_period(&self, ctx: &dyn TableContext) -> Result<u64> {
+        let hours = match self
+            .table_info
+            .options()
+            .get(FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS)
+        {
+            Some(hours) => hours.parse::<u64>().map_err(|e| {
+                ErrorCode::TableOptionInvalid(format!(
+                    "invalid data_retention_period_in_hours option {hours}, {e}"
+                ))
+            })?,
+            None => ctx.get_settings().get_retention_period()?,
+        };
+
+        if hours > MAX_DATA_RETENTION_PERIOD_IN_HOURS {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "data retention period {hours} hours is larger than the maximum {} hours",
+                MAX_DATA_RETENTION_PERIOD_IN_HOURS
+            )));
+        }
+        Ok(hours)
     }
 
     pub fn cluster_key_str(&self) -> Option<&String> {
@@ -356,7 +477,8 @@ impl Table for FuseTable {
     }
 
     fn has_exact_total_row_count(&self) -> bool {
-        true
+        // the statistics of attached tables are not updated by the commits of the writer.
+        !self.is_attached()
     }
 
     fn cluster_keys(&self, ctx: Arc<dyn TableContext>) -> Vec<RemoteExpr<String>> {
@@ -388,6 +510,7 @@ impl Table for FuseTable {
         ctx: Arc<dyn TableContext>,
         cluster_key_str: String,
     ) -> Result<()> {
+        self.check_mutable()?;
         let mut new_table_meta = self.get_table_info().meta.clone();
         new_table_meta = new_table_meta.push_cluster_key(cluster_key_str);
         let cluster_key_meta = new_table_meta.cluster_key();
@@ -434,6 +557,7 @@ impl Table for FuseTable {
 
     #[async_backtrace::framed]
     async fn drop_table_cluster_keys(&self, ctx: Arc<dyn TableContext>) -> Result<()> {
+        self.check_mutable()?;
         if self.cluster_key_meta.is_none() {
             return Ok(());
         }
@@ -509,6 +633,7 @@ impl Table for FuseTable {
         pipeline: &mut Pipeline,
         append_mode: AppendMode,
     ) -> Result<()> {
+        self.check_mutable()?;
         self.do_append_data(ctx, pipeline, append_mode)
     }
 
@@ -519,10 +644,17 @@ impl Table for FuseTable {
         pipeline: &mut Pipeline,
         on_conflict_fields: Vec<TableField>,
     ) -> Result<()> {
+        self.check_mutable()?;
         self.build_replace_pipeline(ctx, on_conflict_fields, pipeline)
             .await
     }
 
+    #[async_backtrace::framed]
+    async fn merge_into(&self, ctx: Arc<dyn TableContext>, pipeline: &mut Pipeline) -> Result<()> {
+        self.check_mutable()?;
+        self.build_merge_into_pipeline(ctx, pipeline).await
+    }
+
     fn commit_insertion(
         &self,
         ctx: Arc<dyn TableContext>,
@@ -530,12 +662,14 @@ impl Table for FuseTable {
         copied_files: Option<UpsertTableCopiedFileReq>,
         overwrite: bool,
     ) -> Result<()> {
+        self.check_mutable()?;
         self.do_commit(ctx, pipeline, copied_files, overwrite)
     }
 
     #[tracing::instrument(level = "debug", name = "fuse_table_truncate", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
     #[async_backtrace::framed]
     async fn truncate(&self, ctx: Arc<dyn TableContext>, purge: bool) -> Result<()> {
+        self.check_mutable()?;
         self.do_truncate(ctx, purge).await
     }
 
@@ -548,6 +682,7 @@ impl Table for FuseTable {
         keep_last_snapshot: bool,
         dry_run_limit: Option<usize>,
     ) -> Result<Option<Vec<String>>> {
+        self.check_mutable()?;
         match self.navigate_for_purge(&ctx, instant).await {
             Ok((table, files)) => {
                 table
@@ -569,10 +704,14 @@ impl Table for FuseTable {
     #[tracing::instrument(level = "debug", name = "analyze", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
     #[async_backtrace::framed]
     async fn analyze(&self, ctx: Arc<dyn TableContext>) -> Result<()> {
+        self.check_mutable()?;
         self.do_analyze(&ctx).await
     }
 
     fn table_statistics(&self) -> Result<Option<TableStatistics>> {
+        if self.is_attached() {
+            return Ok(None);
+        }
         let s = &self.table_info.meta.statistics;
         Ok(Some(TableStatistics {
             num_rows: Some(s.number_of_rows),
@@ -640,6 +779,7 @@ impl Table for FuseTable {
         query_row_id_col: bool,
         pipeline: &mut Pipeline,
     ) -> Result<()> {
+        self.check_mutable()?;
         self.do_delete(ctx, filters, col_indices, query_row_id_col, pipeline)
             .await
     }
@@ -655,6 +795,7 @@ impl Table for FuseTable {
         query_row_id_col: bool,
         pipeline: &mut Pipeline,
     ) -> Result<()> {
+        self.check_mutable()?;
         self.do_update(
             ctx,
             filter,
@@ -686,6 +827,7 @@ impl Table for FuseTable {
         limit: Option<usize>,
         pipeline: &mut Pipeline,
     ) -> Result<()> {
+        self.check_mutable()?;
         self.do_compact(ctx, target, limit, pipeline).await
     }
 
@@ -696,6 +838,7 @@ impl Table for FuseTable {
         pipeline: &mut Pipeline,
         push_downs: Option<PushDownInfo>,
     ) -> Result<()> {
+        self.check_mutable()?;
         self.do_recluster(ctx, pipeline, push_downs).await
     }
 
@@ -705,6 +848,7 @@ impl Table for FuseTable {
         ctx: Arc<dyn TableContext>,
         point: NavigationDescriptor,
     ) -> Result<()> {
+        self.check_mutable()?;
         self.do_revert_to(ctx.as_ref(), point).await
     }
 
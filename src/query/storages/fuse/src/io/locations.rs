@@ -23,12 +23,17 @@ use storages_common_table_meta::meta::TableSnapshotStatisticsVersion;
 use storages_common_table_meta::meta::Versioned;
 use uuid::Uuid;
 
+use crate::constants::FUSE_TBL_AGG_INDEX_PREFIX;
 use crate::constants::FUSE_TBL_BLOCK_PREFIX;
+use crate::constants::FUSE_TBL_DELETION_VECTOR_PREFIX;
+use crate::constants::FUSE_TBL_INVERTED_INDEX_PREFIX;
 use crate::constants::FUSE_TBL_SEGMENT_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX;
+use crate::constants::FUSE_TBL_VECTOR_INDEX_PREFIX;
 use crate::constants::FUSE_TBL_VIRTUAL_BLOCK_PREFIX;
 use crate::index::filters::BlockFilter;
+use crate::io::DELETION_VECTOR_VERSION;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
 use crate::FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
 
@@ -95,6 +100,20 @@ impl TableMetaLocationGenerator {
         )
     }
 
+    pub fn gen_deletion_vector_location(&self) -> Location {
+        let deletion_vector_uuid = Uuid::new_v4();
+        (
+            format!(
+                "{}/{}/{}_v{}.bin",
+                &self.prefix,
+                FUSE_TBL_DELETION_VECTOR_PREFIX,
+                deletion_vector_uuid.as_simple(),
+                DELETION_VECTOR_VERSION,
+            ),
+            DELETION_VECTOR_VERSION,
+        )
+    }
+
     pub fn gen_segment_info_location(&self) -> String {
         let segment_uuid = Uuid::new_v4().simple().to_string();
         format!(
@@ -145,6 +164,33 @@ impl TableMetaLocationGenerator {
     pub fn gen_virtual_block_location(location: &str) -> String {
         location.replace(FUSE_TBL_BLOCK_PREFIX, FUSE_TBL_VIRTUAL_BLOCK_PREFIX)
     }
+
+    /// The data of aggregating index `index_id` computed from the block at `location`.
+    pub fn gen_agg_index_location_from_block_location(location: &str, index_id: u64) -> String {
+        location.replacen(
+            &format!("{}/", FUSE_TBL_BLOCK_PREFIX),
+            &format!("{}/{}/", FUSE_TBL_AGG_INDEX_PREFIX, index_id),
+            1,
+        )
+    }
+
+    /// The inverted index of the text columns of the block at `location`.
+    pub fn gen_inverted_index_location_from_block_location(location: &str) -> String {
+        location.replacen(
+            &format!("{}/", FUSE_TBL_BLOCK_PREFIX),
+            &format!("{}/", FUSE_TBL_INVERTED_INDEX_PREFIX),
+            1,
+        )
+    }
+
+    /// The vector index of the vector columns of the block at `location`.
+    pub fn gen_vector_index_location_from_block_location(location: &str) -> String {
+        location.replacen(
+            &format!("{}/", FUSE_TBL_BLOCK_PREFIX),
+            &format!("{}/", FUSE_TBL_VECTOR_INDEX_PREFIX),
+            1,
+        )
+    }
 }
 
 trait SnapshotLocationCreator {
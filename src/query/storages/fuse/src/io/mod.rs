@@ -21,6 +21,7 @@ mod write;
 
 pub use files::Files;
 pub use locations::TableMetaLocationGenerator;
+pub use read::BlockPrefetcher;
 pub use read::BlockReader;
 pub use read::BloomBlockFilterReader;
 pub use read::CompactSegmentInfoReader;
@@ -35,11 +36,20 @@ pub use segments::SegmentsIO;
 pub use segments::SerializedSegment;
 pub use snapshots::SnapshotLiteExtended;
 pub use snapshots::SnapshotsIO;
+pub use write::parse_inverted_index_columns;
+pub use write::parse_vector_index_columns;
 pub use write::serialize_block;
+pub use write::write_block_data;
 pub use write::write_data;
+pub use write::write_data_multipart;
 pub use write::BlockBuilder;
 pub use write::BlockSerialization;
 pub use write::CachedMetaWriter;
+pub use write::DeletionVectorWriter;
+pub use write::InvertedIndexState;
 pub use write::MetaWriter;
 pub use write::SegmentWriter;
+pub use write::UploadSettings;
+pub use write::VectorIndexState;
 pub use write::WriteSettings;
+pub use write::DELETION_VECTOR_VERSION;
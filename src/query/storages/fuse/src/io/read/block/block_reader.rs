@@ -13,10 +13,13 @@
 // limitations under the License.
 
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_arrow::arrow::datatypes::Field;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
 use common_arrow::arrow::io::parquet::write::to_parquet_schema;
+use common_arrow::parquet::metadata::ColumnDescriptor;
 use common_arrow::parquet::metadata::SchemaDescriptor;
 use common_catalog::plan::Projection;
 use common_catalog::table_context::TableContext;
@@ -27,13 +30,19 @@ use common_expression::ColumnId;
 use common_expression::DataField;
 use common_expression::DataSchema;
 use common_expression::FieldIndex;
+use common_expression::FunctionContext;
 use common_expression::Scalar;
 use common_expression::TableField;
+use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
 use common_sql::field_default_value;
 use common_storage::ColumnNode;
 use common_storage::ColumnNodes;
 use opendal::Operator;
+use storages_common_table_meta::meta::ColumnMeta;
+
+use crate::fuse_part::FusePartInfo;
+use crate::pruning::RuntimeFilterPruner;
 
 // TODO: make BlockReader as a trait.
 #[derive(Clone)]
@@ -46,6 +55,21 @@ pub struct BlockReader {
     pub(crate) parquet_schema_descriptor: SchemaDescriptor,
     pub(crate) default_vals: Vec<Scalar>,
     pub query_internal_columns: bool,
+    // Min/max filters of the join keys pushed down from the build side of hash joins.
+    pub(crate) runtime_filter: Option<Arc<RuntimeFilterPruner>>,
+    // The origin columns of the fields whose data type was modified, keyed by the current column id.
+    pub(crate) origin_columns: HashMap<ColumnId, Vec<OriginColumn>>,
+    pub(crate) func_ctx: FunctionContext,
+}
+
+/// A column which stores the data of a field before its data type was modified,
+/// the data read from it is cast to the current data type of the field.
+#[derive(Clone)]
+pub(crate) struct OriginColumn {
+    pub(crate) column_id: ColumnId,
+    pub(crate) field: Field,
+    pub(crate) data_type: DataType,
+    pub(crate) column_descriptor: ColumnDescriptor,
 }
 
 fn inner_project_field_default_values(default_vals: &[Scalar], paths: &[usize]) -> Result<Scalar> {
@@ -125,6 +149,7 @@ impl BlockReader {
             .map(|c| (*c).clone())
             .collect();
         let project_indices = Self::build_projection_indices(&project_column_nodes);
+        let origin_columns = Self::build_origin_columns(&schema)?;
 
         Ok(Arc::new(BlockReader {
             operator,
@@ -135,9 +160,84 @@ impl BlockReader {
             parquet_schema_descriptor,
             default_vals,
             query_internal_columns,
+            runtime_filter: None,
+            origin_columns,
+            func_ctx: ctx.get_function_context()?,
         }))
     }
 
+    fn build_origin_columns(schema: &TableSchema) -> Result<HashMap<ColumnId, Vec<OriginColumn>>> {
+        let mut origin_columns = HashMap::new();
+        for field in schema.fields() {
+            if field.origins().is_empty() {
+                continue;
+            }
+            let mut columns = Vec::with_capacity(field.origins().len());
+            for origin in field.origins() {
+                let origin_field = TableField::new(field.name(), origin.data_type.clone());
+                let arrow_field = Field::from(&origin_field);
+                let parquet_schema =
+                    to_parquet_schema(&ArrowSchema::from(vec![arrow_field.clone()]))?;
+                columns.push(OriginColumn {
+                    column_id: origin.column_id,
+                    field: arrow_field,
+                    data_type: origin_field.data_type().into(),
+                    column_descriptor: parquet_schema.columns()[0].clone(),
+                });
+            }
+            origin_columns.insert(field.column_id(), columns);
+        }
+        Ok(origin_columns)
+    }
+
+    /// Returns the origin column which stores the data of the leaf column `column_id`,
+    /// if the block is written before the data type of the column was modified.
+    pub(crate) fn origin_column(
+        &self,
+        column_id: ColumnId,
+        columns_meta: &HashMap<ColumnId, ColumnMeta>,
+    ) -> Option<&OriginColumn> {
+        if columns_meta.contains_key(&column_id) {
+            return None;
+        }
+        self.origin_columns
+            .get(&column_id)?
+            .iter()
+            .find(|origin| columns_meta.contains_key(&origin.column_id))
+    }
+
+    /// Returns the id of the column which the data of the leaf column `column_id` is read from.
+    pub(crate) fn stored_column_id(
+        &self,
+        column_id: ColumnId,
+        columns_meta: &HashMap<ColumnId, ColumnMeta>,
+    ) -> ColumnId {
+        match self.origin_column(column_id, columns_meta) {
+            Some(origin) => origin.column_id,
+            None => column_id,
+        }
+    }
+
+    /// Returns a copy of this reader which skips the parts that can not match
+    /// the runtime filters pushed down from hash joins.
+    pub fn with_runtime_filter(
+        &self,
+        runtime_filter: Arc<RuntimeFilterPruner>,
+    ) -> Arc<BlockReader> {
+        let mut reader = self.clone();
+        reader.runtime_filter = Some(runtime_filter);
+        Arc::new(reader)
+    }
+
+    /// Returns false if the min/max statistics of the part prove that no row matches
+    /// the runtime filters.
+    pub fn should_read_part(&self, part: &FusePartInfo) -> bool {
+        match &self.runtime_filter {
+            None => true,
+            Some(runtime_filter) => runtime_filter.should_keep(part),
+        }
+    }
+
     pub fn support_blocking_api(&self) -> bool {
         self.operator.info().can_blocking()
     }
@@ -0,0 +1,60 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_exception::Result;
+use common_expression::DataBlock;
+use roaring::RoaringBitmap;
+use storages_common_table_meta::meta::Location;
+
+use super::BlockReader;
+
+impl BlockReader {
+    #[async_backtrace::framed]
+    pub async fn read_deletion_vector(&self, location: &Location) -> Result<RoaringBitmap> {
+        let data = self.operator.read(&location.0).await?;
+        Ok(RoaringBitmap::deserialize_from(data.as_slice())?)
+    }
+
+    pub fn sync_read_deletion_vector(&self, location: &Location) -> Result<RoaringBitmap> {
+        let data = self.operator.blocking().read(&location.0)?;
+        Ok(RoaringBitmap::deserialize_from(data.as_slice())?)
+    }
+
+    /// Removes the rows marked in the deletion vector from the data block.
+    ///
+    /// Returns the remaining rows, and their offsets in the data block, which are
+    /// needed to generate the row ids of the remaining rows.
+    pub fn apply_deletion_vector(
+        data_block: DataBlock,
+        deletion_vector: &RoaringBitmap,
+    ) -> Result<(DataBlock, Vec<usize>)> {
+        let num_rows = data_block.num_rows();
+        let mut bitmap = MutableBitmap::from_len_set(num_rows);
+        let mut offsets = Vec::with_capacity(num_rows);
+        for offset in 0..num_rows {
+            if deletion_vector.contains(offset as u32) {
+                bitmap.set(offset, false);
+            } else {
+                offsets.push(offset);
+            }
+        }
+
+        if offsets.len() == num_rows {
+            return Ok((data_block, offsets));
+        }
+        let data_block = data_block.filter_with_bitmap(&bitmap.into())?;
+        Ok((data_block, offsets))
+    }
+}
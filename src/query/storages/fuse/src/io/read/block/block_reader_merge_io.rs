@@ -19,6 +19,7 @@ use std::sync::Arc;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::ColumnId;
+use roaring::RoaringBitmap;
 use storages_common_cache::CacheAccessor;
 use storages_common_cache::TableDataCache;
 use storages_common_cache::TableDataCacheKey;
@@ -54,6 +55,10 @@ pub struct MergeIOReadResult {
     pub cached_column_data: CachedColumnData,
     pub cached_column_array: CachedColumnArray,
     table_data_cache: Option<TableDataCache>,
+    /// The deleted rows of the block, read only if the block has a deletion vector.
+    pub deletion_vector: Option<RoaringBitmap>,
+    /// Bytes read from the storage, including the gaps of the merged ranges.
+    pub remote_read_bytes: u64,
 }
 
 pub enum DataItem<'a> {
@@ -75,6 +80,8 @@ impl MergeIOReadResult {
             cached_column_data: vec![],
             cached_column_array: vec![],
             table_data_cache,
+            deletion_vector: None,
+            remote_read_bytes: 0,
         }
     }
 
@@ -108,12 +115,18 @@ impl MergeIOReadResult {
         &mut self,
         chunk_index: usize,
         column_id: ColumnId,
+        column_range: Range<u64>,
         range: Range<usize>,
     ) {
         if let Some(table_data_cache) = &self.table_data_cache {
             // populate raw column data cache (compressed raw bytes)
             if let Ok(chunk_data) = self.get_chunk(chunk_index, &self.block_path) {
-                let cache_key = TableDataCacheKey::new(&self.block_path, column_id);
+                let cache_key = TableDataCacheKey::new(
+                    &self.block_path,
+                    column_id,
+                    column_range.start,
+                    column_range.end - column_range.start,
+                );
                 let data = &chunk_data[range.clone()];
                 table_data_cache.put(cache_key.as_ref().to_owned(), Arc::new(data.to_vec()));
             }
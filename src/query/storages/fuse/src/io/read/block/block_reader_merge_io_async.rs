@@ -73,7 +73,9 @@ impl BlockReader {
 
         // Read merged range data.
         let mut read_handlers = Vec::with_capacity(merged_ranges.len());
+        let mut remote_read_bytes = 0;
         for (idx, range) in merged_ranges.iter().enumerate() {
+            remote_read_bytes += range.end - range.start;
             // Perf.
             {
                 metrics_inc_remote_io_seeks_after_merged(1);
@@ -98,6 +100,7 @@ impl BlockReader {
             location.to_string(),
             table_data_cache,
         );
+        read_res.remote_read_bytes = remote_read_bytes;
 
         // Perf.
         {
@@ -120,7 +123,7 @@ impl BlockReader {
             let start = (column_range.start - merged_range.start) as usize;
             let end = (column_range.end - merged_range.start) as usize;
             let column_id = *raw_idx as ColumnId;
-            read_res.add_column_chunk(merged_range_idx, column_id, start..end);
+            read_res.add_column_chunk(merged_range_idx, column_id, column_range, start..end);
         }
 
         Ok(read_res)
@@ -145,30 +148,37 @@ impl BlockReader {
         let mut cached_column_data = vec![];
         let mut cached_column_array = vec![];
         for (_index, (column_id, ..)) in self.project_indices.iter() {
-            let column_cache_key = TableDataCacheKey::new(location, *column_id);
+            // blocks written before the data type of the column was modified
+            // store the data in an origin column.
+            let column_id = self.stored_column_id(*column_id, columns_meta);
+            let column_meta = match columns_meta.get(&column_id) {
+                Some(column_meta) => column_meta,
+                None => continue,
+            };
+            let (offset, len) = column_meta.offset_length();
+            let column_cache_key = TableDataCacheKey::new(location, column_id, offset, len);
 
             // first, check column array object cache
             if let Some(cache_array) = column_array_cache.get(&column_cache_key) {
-                cached_column_array.push((*column_id, cache_array));
+                metrics_inc_remote_io_read_bytes_from_cache(len);
+                cached_column_array.push((column_id, cache_array));
                 continue;
             }
 
             // and then, check column data cache
             if let Some(cached_column_raw_data) = column_data_cache.get(&column_cache_key) {
-                cached_column_data.push((*column_id, cached_column_raw_data));
+                metrics_inc_remote_io_read_bytes_from_cache(len);
+                cached_column_data.push((column_id, cached_column_raw_data));
                 continue;
             }
 
             // if all cache missed, prepare the ranges to be read
-            if let Some(column_meta) = columns_meta.get(column_id) {
-                let (offset, len) = column_meta.offset_length();
-                ranges.push((*column_id, offset..(offset + len)));
-
-                // Perf
-                {
-                    metrics_inc_remote_io_seeks(1);
-                    metrics_inc_remote_io_read_bytes(len);
-                }
+            ranges.push((column_id, offset..(offset + len)));
+
+            // Perf
+            {
+                metrics_inc_remote_io_seeks(1);
+                metrics_inc_remote_io_read_bytes(len);
             }
         }
 
@@ -90,7 +90,7 @@ impl BlockReader {
             // Fetch the raw data for the raw range.
             let start = (column_range.start - merged_range.start) as usize;
             let end = (column_range.end - merged_range.start) as usize;
-            read_res.add_column_chunk(merged_range_idx, column_id, start..end);
+            read_res.add_column_chunk(merged_range_idx, column_id, column_range, start..end);
         }
 
         Ok(read_res)
@@ -107,22 +107,28 @@ impl BlockReader {
         let mut ranges = vec![];
         let mut cached_column_array = vec![];
         for (_index, (column_id, ..)) in self.project_indices.iter() {
+            let column_id = self.stored_column_id(*column_id, &part.columns_meta);
+            let column_meta = match part.columns_meta.get(&column_id) {
+                Some(column_meta) => column_meta,
+                None => continue,
+            };
+            let (offset, len) = column_meta.offset_length();
             // first, check column array object cache
             let block_path = &part.location;
-            let column_cache_key = TableDataCacheKey::new(block_path, *column_id);
+            let column_cache_key = TableDataCacheKey::new(block_path, column_id, offset, len);
             if let Some(cache_array) = column_array_cache.get(&column_cache_key) {
-                cached_column_array.push((*column_id, cache_array));
+                cached_column_array.push((column_id, cache_array));
                 continue;
             }
-            if let Some(column_meta) = part.columns_meta.get(column_id) {
-                let (offset, len) = column_meta.offset_length();
-                ranges.push((*column_id, offset..(offset + len)));
-            }
+            ranges.push((column_id, offset..(offset + len)));
         }
 
         let mut merge_io_result =
             Self::sync_merge_io_read(settings, self.operator.clone(), &part.location, ranges)?;
         merge_io_result.cached_column_array = cached_column_array;
+        if let Some(location) = &part.deletion_vector_location {
+            merge_io_result.deletion_vector = Some(self.sync_read_deletion_vector(location)?);
+        }
         Ok(merge_io_result)
     }
 
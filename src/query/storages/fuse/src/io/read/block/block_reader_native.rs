@@ -96,6 +96,28 @@ impl BlockReader {
                 metrics_inc_remote_io_read_bytes(total_len);
             }
         }
+
+        // If virtual column file exists, read the data from the virtual columns directly.
+        let virtual_loc = TableMetaLocationGenerator::gen_virtual_block_location(&part.location);
+        if let Some(ref virtual_columns_meta) = part.virtual_columns_meta {
+            for (_, virtual_column_meta) in virtual_columns_meta.iter() {
+                let virtual_index = virtual_column_meta.index + self.project_column_nodes.len();
+                join_handlers.push(Self::read_native_columns_data(
+                    self.operator.clone(),
+                    &virtual_loc,
+                    virtual_index,
+                    vec![virtual_column_meta.meta.clone()],
+                    part.range(),
+                ));
+
+                // Perf
+                {
+                    let (_, len) = virtual_column_meta.meta.offset_length();
+                    metrics_inc_remote_io_seeks(1);
+                    metrics_inc_remote_io_read_bytes(len);
+                }
+            }
+        }
         let start = Instant::now();
         let readers = futures::future::try_join_all(join_handlers).await?;
         let results: BTreeMap<usize, Vec<NativeReader<Reader>>> = readers.into_iter().collect();
@@ -225,7 +247,8 @@ impl BlockReader {
         chunks: Vec<(usize, Box<dyn Array>)>,
         default_val_indices: Option<HashSet<usize>>,
     ) -> Result<DataBlock> {
-        let mut rows = 0;
+        // The chunks may only contain virtual columns if the source columns are not read.
+        let rows = chunks.first().map(|(_, array)| array.len()).unwrap_or(0);
         let mut entries = Vec::with_capacity(chunks.len());
         for (index, _) in self.project_column_nodes.iter().enumerate() {
             if let Some(array) = chunks.iter().find(|c| c.0 == index).map(|c| c.1.clone()) {
@@ -234,7 +257,6 @@ impl BlockReader {
                     data_type.clone(),
                     Value::Column(Column::from_arrow(array.as_ref(), &data_type)),
                 ));
-                rows = array.len();
             } else if let Some(ref default_val_indices) = default_val_indices {
                 if default_val_indices.contains(&index) {
                     let data_type: DataType = self.projected_schema.field(index).data_type().into();
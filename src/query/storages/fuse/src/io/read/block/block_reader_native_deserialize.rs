@@ -153,8 +153,11 @@ impl BlockReader {
             // populate array cache items
             for item in deserialized_column_arrays.into_iter() {
                 if let DeserializedArray::Deserialized((column_id, array, size)) = item {
-                    let key = TableDataCacheKey::new(block_path, column_id);
-                    cache.put(key.into(), Arc::new((array, size)))
+                    if let Some(column_meta) = column_metas.get(&column_id) {
+                        let (offset, len) = column_meta.offset_length();
+                        let key = TableDataCacheKey::new(block_path, column_id, offset, len);
+                        cache.put(key.into(), Arc::new((array, size)))
+                    }
                 }
             }
         }
@@ -16,6 +16,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
 
+use common_arrow::arrow::array::Array;
 use common_arrow::arrow::chunk::Chunk;
 use common_arrow::arrow::datatypes::Field;
 use common_arrow::arrow::io::parquet::read::column_iter_to_arrays;
@@ -26,8 +27,13 @@ use common_arrow::parquet::read::PageMetaData;
 use common_arrow::parquet::read::PageReader;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::Column;
 use common_expression::ColumnId;
 use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_functions::BUILTIN_FUNCTIONS;
 use common_storage::ColumnNode;
 use storages_common_cache::CacheAccessor;
 use storages_common_cache::TableDataCacheKey;
@@ -37,6 +43,7 @@ use storages_common_table_meta::meta::Compression;
 
 use super::block_reader_deserialize::DeserializedArray;
 use super::block_reader_deserialize::FieldDeserializationContext;
+use crate::io::read::block::block_reader::OriginColumn;
 use crate::io::read::block::block_reader_merge_io::DataItem;
 use crate::io::read::block::decompressor::BuffedBasicDecompressor;
 use crate::io::BlockReader;
@@ -162,8 +169,11 @@ impl BlockReader {
             // populate array cache items
             for item in deserialized_column_arrays.into_iter() {
                 if let DeserializedArray::Deserialized((column_id, array, size)) = item {
-                    let key = TableDataCacheKey::new(block_path, column_id);
-                    cache.put(key.into(), Arc::new((array, size)))
+                    if let Some(column_meta) = column_metas.get(&column_id) {
+                        let (offset, len) = column_meta.offset_length();
+                        let key = TableDataCacheKey::new(block_path, column_id, offset, len);
+                        cache.put(key.into(), Arc::new((array, size)))
+                    }
                 }
             }
         }
@@ -232,6 +242,15 @@ impl BlockReader {
         // column passed in may be a compound field (with sub leaves),
         // or a leaf column of compound field
         let is_nested = column.has_children();
+        if !is_nested {
+            if let Some(origin) = self.origin_column(
+                column.leaf_column_ids[0],
+                deserialization_context.column_metas,
+            ) {
+                return self.deserialize_origin_field(deserialization_context, column, origin);
+            }
+        }
+
         let estimated_cap = indices.len();
         let mut field_column_metas = Vec::with_capacity(estimated_cap);
         let mut field_column_data = Vec::with_capacity(estimated_cap);
@@ -312,6 +331,73 @@ impl BlockReader {
         }
     }
 
+    /// Deserialize a field of a block written before its data type was modified,
+    /// the data is read from the origin column and cast to the current data type.
+    fn deserialize_origin_field<'a>(
+        &self,
+        deserialization_context: &'a FieldDeserializationContext,
+        column: &ColumnNode,
+        origin: &OriginColumn,
+    ) -> Result<Option<DeserializedArray<'a>>> {
+        let column_meta = &deserialization_context.column_metas[&origin.column_id];
+        let array = match deserialization_context.column_chunks.get(&origin.column_id) {
+            Some(DataItem::RawData(data)) => {
+                let mut array_iter = Self::chunks_to_parquet_array_iter(
+                    vec![column_meta],
+                    vec![*data],
+                    deserialization_context.num_rows,
+                    vec![&origin.column_descriptor],
+                    origin.field.clone(),
+                    deserialization_context.compression,
+                    deserialization_context
+                        .uncompressed_buffer
+                        .clone()
+                        .unwrap_or_else(|| UncompressedBuffer::new(0)),
+                )?;
+                array_iter.next().transpose()?.ok_or_else(|| {
+                    ErrorCode::StorageOther(format!(
+                        "unexpected deserialization error, no array found for field {} ",
+                        column.field.name
+                    ))
+                })?
+            }
+            Some(DataItem::ColumnArray(column_array)) => column_array.0.clone(),
+            None => {
+                return Err(ErrorCode::StorageOther("unexpected: column data not found"));
+            }
+        };
+
+        let (_, _, data_type) = &self.project_indices[&column.leaf_indices[0]];
+        let array = self.cast_origin_array(array.as_ref(), &origin.data_type, data_type)?;
+        // the array is not the data stored with the column id, it should not be cached.
+        Ok(Some(DeserializedArray::NoNeedToCache(array)))
+    }
+
+    fn cast_origin_array(
+        &self,
+        array: &dyn Array,
+        origin_type: &DataType,
+        data_type: &DataType,
+    ) -> Result<Box<dyn Array>> {
+        let column = Column::from_arrow(array, origin_type);
+        let num_rows = column.len();
+        let block = DataBlock::new_from_columns(vec![column]);
+        let expr = Expr::Cast {
+            span: None,
+            is_try: false,
+            expr: Box::new(Expr::ColumnRef {
+                span: None,
+                id: 0,
+                data_type: origin_type.clone(),
+                display_name: String::new(),
+            }),
+            dest_type: data_type.clone(),
+        };
+        let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let value = evaluator.run(&expr)?;
+        Ok(value.convert_to_full_column(data_type, num_rows).as_arrow())
+    }
+
     fn to_parquet_compression(meta_compression: &Compression) -> Result<ParquetCompression> {
         match meta_compression {
             Compression::Lz4 => {
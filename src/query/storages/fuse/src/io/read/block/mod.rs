@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod block_reader;
+mod block_reader_deletion_vector;
 mod block_reader_deserialize;
 mod block_reader_merge_io;
 mod block_reader_merge_io_async;
@@ -0,0 +1,107 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_base::base::tokio::task::JoinHandle;
+use common_catalog::plan::PartInfoPtr;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::fuse_part::FusePartInfo;
+use crate::io::BlockReader;
+use crate::io::ReadSettings;
+use crate::MergeIOReadResult;
+
+/// Reads the column data of the parts ahead of their deserialization.
+///
+/// Up to `max_prefetch_blocks` parts are fetched concurrently in the background, so that
+/// the IO of the next blocks overlaps with the deserialization of the current ones.
+/// The prefetched parts are returned in the order they are submitted.
+pub struct BlockPrefetcher {
+    block_reader: Arc<BlockReader>,
+    settings: ReadSettings,
+    max_prefetch_blocks: usize,
+    in_flight: VecDeque<(PartInfoPtr, JoinHandle<Result<MergeIOReadResult>>)>,
+}
+
+impl BlockPrefetcher {
+    pub fn create(
+        block_reader: Arc<BlockReader>,
+        settings: ReadSettings,
+        max_prefetch_blocks: usize,
+    ) -> Self {
+        let max_prefetch_blocks = std::cmp::max(max_prefetch_blocks, 1);
+        BlockPrefetcher {
+            block_reader,
+            settings,
+            max_prefetch_blocks,
+            in_flight: VecDeque::with_capacity(max_prefetch_blocks),
+        }
+    }
+
+    /// The number of parts that can be submitted before the prefetch window is full.
+    pub fn remaining_capacity(&self) -> usize {
+        self.max_prefetch_blocks
+            .saturating_sub(self.in_flight.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.in_flight.is_empty()
+    }
+
+    /// Starts fetching the column data of the part in the background.
+    pub fn prefetch(&mut self, part: PartInfoPtr) {
+        let block_reader = self.block_reader.clone();
+        let settings = self.settings.clone();
+        let fetch_part = part.clone();
+
+        let handle = tokio::spawn(async_backtrace::location!().frame(async move {
+            let part = FusePartInfo::from_part(&fetch_part)?;
+            let mut read_res = block_reader
+                .read_columns_data_by_merge_io(&settings, &part.location, &part.columns_meta)
+                .await?;
+            if let Some(location) = &part.deletion_vector_location {
+                read_res.deletion_vector = Some(block_reader.read_deletion_vector(location).await?);
+            }
+            Ok(read_res)
+        }));
+        self.in_flight.push_back((part, handle));
+    }
+
+    /// Waits for the earliest submitted part, returns None if no part is in flight.
+    #[async_backtrace::framed]
+    pub async fn next(&mut self) -> Result<Option<(PartInfoPtr, MergeIOReadResult)>> {
+        match self.in_flight.pop_front() {
+            None => Ok(None),
+            Some((part, handle)) => {
+                let data = handle.await.map_err(|e| {
+                    ErrorCode::TokioError(format!("prefetch block join error. {}", e))
+                })??;
+                Ok(Some((part, data)))
+            }
+        }
+    }
+}
+
+impl Drop for BlockPrefetcher {
+    fn drop(&mut self) {
+        // The query may be aborted, no need to keep fetching the remaining parts.
+        for (_, handle) in &self.in_flight {
+            handle.abort();
+        }
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod block;
+mod block_prefetcher;
 mod bloom;
 pub mod meta;
 mod read_settings;
@@ -22,6 +23,7 @@ pub use block::BlockReader;
 pub use block::MergeIOReadResult;
 pub use block::NativeReaderExt;
 pub use block::UncompressedBuffer;
+pub use block_prefetcher::BlockPrefetcher;
 pub use bloom::BloomBlockFilterReader;
 pub use meta::CompactSegmentInfoReader;
 pub use meta::MetaReaders;
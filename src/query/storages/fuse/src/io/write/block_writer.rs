@@ -15,8 +15,12 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use backoff::backoff::Backoff;
+use backoff::ExponentialBackoff;
+use bytes::Bytes;
 use common_arrow::arrow::chunk::Chunk as ArrowChunk;
 use common_arrow::native::write::NativeWriter;
+use common_base::base::tokio;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_expression::ColumnId;
@@ -26,6 +30,8 @@ use common_expression::TableSchemaRef;
 use common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use common_io::constants::DEFAULT_BLOCK_INDEX_BUFFER_SIZE;
 use opendal::Operator;
+use opendal::Scheme;
+use opendal::Writer;
 use storages_common_blocks::blocks_to_parquet;
 use storages_common_index::BloomIndex;
 use storages_common_table_meta::meta::BlockMeta;
@@ -33,8 +39,13 @@ use storages_common_table_meta::meta::ClusterStatistics;
 use storages_common_table_meta::meta::ColumnMeta;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::table::TableCompression;
+use tracing::warn;
 
 use crate::fuse_table::FuseStorageFormat;
+use crate::io::write::InvertedIndexState;
+use crate::io::write::UploadSettings;
+use crate::io::write::VectorIndexState;
+use crate::io::write::VirtualColumnState;
 use crate::io::write::WriteSettings;
 use crate::io::TableMetaLocationGenerator;
 use crate::operations::util;
@@ -94,6 +105,78 @@ pub async fn write_data(data: Vec<u8>, data_accessor: &Operator, location: &str)
     Ok(())
 }
 
+/// Writes the data of a block, the large ones are uploaded to S3 by multipart upload.
+#[async_backtrace::framed]
+pub async fn write_block_data(
+    data: Vec<u8>,
+    data_accessor: &Operator,
+    location: &str,
+    settings: &UploadSettings,
+) -> Result<()> {
+    if settings.multipart_threshold > 0
+        && data.len() > settings.multipart_threshold
+        && data_accessor.info().scheme() == Scheme::S3
+    {
+        return write_data_multipart(Bytes::from(data), data_accessor, location, settings).await;
+    }
+
+    write_data(data, data_accessor, location).await
+}
+
+/// Uploads the data by multipart upload, each write of the writer uploads one part.
+///
+/// A part which fails with a temporary error is uploaded again, instead of the whole data.
+/// The writer of the operator uploads the parts one after another in order, so they are
+/// not uploaded concurrently; the blocks themselves are written by parallel processors.
+#[async_backtrace::framed]
+pub async fn write_data_multipart(
+    data: Bytes,
+    data_accessor: &Operator,
+    location: &str,
+    settings: &UploadSettings,
+) -> Result<()> {
+    let mut writer = data_accessor.writer(location).await?;
+
+    let mut offset = 0;
+    while offset < data.len() {
+        let end = std::cmp::min(offset + settings.multipart_part_size, data.len());
+        let part = data.slice(offset..end);
+        if let Err(e) = write_part(&mut writer, part, settings.multipart_max_retries).await {
+            // do not leave the uploaded parts behind.
+            let _ = writer.abort().await;
+            return Err(e);
+        }
+        offset = end;
+    }
+
+    if let Err(e) = writer.close().await {
+        let _ = writer.abort().await;
+        return Err(e.into());
+    }
+
+    Ok(())
+}
+
+async fn write_part(writer: &mut Writer, part: Bytes, max_retries: usize) -> Result<()> {
+    let mut backoff = ExponentialBackoff::default();
+    let mut retries = 0;
+    loop {
+        match writer.write(part.clone()).await {
+            Ok(_) => return Ok(()),
+            Err(e) if e.is_temporary() && retries < max_retries => {
+                retries += 1;
+                let duration = backoff.next_backoff().unwrap_or(backoff.max_interval);
+                warn!(
+                    "upload part failed, retry {} of {} in {:?}: {}",
+                    retries, max_retries, duration, e
+                );
+                tokio::time::sleep(duration).await;
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
+
 pub struct BloomIndexState {
     pub(crate) data: Vec<u8>,
     pub(crate) size: u64,
@@ -142,6 +225,9 @@ pub struct BlockSerialization {
     pub size: u64, // TODO redundancy
     pub block_meta: BlockMeta,
     pub bloom_index_state: Option<BloomIndexState>,
+    pub virtual_column_state: Option<VirtualColumnState>,
+    pub inverted_index_state: Option<InvertedIndexState>,
+    pub vector_index_state: Option<VectorIndexState>,
 }
 
 #[derive(Clone)]
@@ -171,6 +257,39 @@ impl BlockBuilder {
             .as_ref()
             .map(|i| i.column_distinct_count.clone());
 
+        let virtual_column_state = if self.write_settings.variant_shredding {
+            VirtualColumnState::try_create(
+                &self.write_settings,
+                &self.source_schema,
+                &data_block,
+                &block_location.0,
+            )?
+        } else {
+            None
+        };
+
+        let inverted_index_state = if !self.write_settings.inverted_index_columns.is_empty() {
+            InvertedIndexState::try_create(
+                &self.write_settings,
+                &self.source_schema,
+                &data_block,
+                &block_location.0,
+            )?
+        } else {
+            None
+        };
+
+        let vector_index_state = if !self.write_settings.vector_index_columns.is_empty() {
+            VectorIndexState::try_create(
+                &self.write_settings,
+                &self.source_schema,
+                &data_block,
+                &block_location.0,
+            )?
+        } else {
+            None
+        };
+
         let row_count = data_block.num_rows() as u64;
         let block_size = data_block.memory_size() as u64;
         let col_stats =
@@ -198,6 +317,8 @@ impl BlockBuilder {
                 .map(|v| v.size)
                 .unwrap_or_default(),
             compression: self.write_settings.table_compression.try_into()?,
+            deletion_vector_location: None,
+            deleted_row_count: 0,
         };
 
         let serialized = BlockSerialization {
@@ -205,6 +326,9 @@ impl BlockBuilder {
             size: file_size,
             block_meta,
             bloom_index_state,
+            virtual_column_state,
+            inverted_index_state,
+            vector_index_state,
         };
         Ok(serialized)
     }
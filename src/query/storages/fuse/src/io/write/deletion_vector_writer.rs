@@ -0,0 +1,54 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use opendal::Operator;
+use roaring::RoaringBitmap;
+use storages_common_table_meta::meta::Location;
+
+use crate::io::write_data;
+use crate::io::TableMetaLocationGenerator;
+
+/// Version of the deletion vector file format.
+///
+/// A deletion vector file holds the offsets of the deleted rows of a data block,
+/// serialized as a roaring bitmap in its portable format.
+pub const DELETION_VECTOR_VERSION: u64 = 0;
+
+/// Writes the deletion vectors of data blocks as sidecar files, instead of
+/// rewriting the blocks whose rows are deleted.
+#[derive(Clone)]
+pub struct DeletionVectorWriter {
+    location_generator: TableMetaLocationGenerator,
+    data_accessor: Operator,
+}
+
+impl DeletionVectorWriter {
+    pub fn create(data_accessor: Operator, location_generator: TableMetaLocationGenerator) -> Self {
+        Self {
+            location_generator,
+            data_accessor,
+        }
+    }
+
+    /// Writes down the deletion vector, returns its location.
+    #[async_backtrace::framed]
+    pub async fn write(&self, deletion_vector: &RoaringBitmap) -> Result<Location> {
+        let location = self.location_generator.gen_deletion_vector_location();
+        let mut data = Vec::with_capacity(deletion_vector.serialized_size());
+        deletion_vector.serialize_into(&mut data)?;
+        write_data(data, &self.data_accessor, &location.0).await?;
+        Ok(location)
+    }
+}
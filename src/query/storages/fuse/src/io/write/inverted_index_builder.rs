@@ -0,0 +1,74 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::TableSchemaRef;
+use common_io::constants::DEFAULT_BLOCK_INDEX_BUFFER_SIZE;
+use storages_common_blocks::blocks_to_parquet;
+use storages_common_index::InvertedIndex;
+use storages_common_table_meta::table::TableCompression;
+
+use crate::io::write::WriteSettings;
+use crate::io::TableMetaLocationGenerator;
+
+/// The inverted index of the text columns of a block, stored next to the block.
+pub struct InvertedIndexState {
+    pub(crate) data: Vec<u8>,
+    pub(crate) location: String,
+}
+
+impl InvertedIndexState {
+    pub fn try_create(
+        write_settings: &WriteSettings,
+        source_schema: &TableSchemaRef,
+        block: &DataBlock,
+        block_location: &str,
+    ) -> Result<Option<Self>> {
+        let inverted_index = match InvertedIndex::try_create(
+            source_schema,
+            block,
+            &write_settings.inverted_index_columns,
+        )? {
+            Some(inverted_index) => inverted_index,
+            None => return Ok(None),
+        };
+
+        let index_block = inverted_index.serialize_to_data_block()?;
+        let mut data = Vec::with_capacity(DEFAULT_BLOCK_INDEX_BUFFER_SIZE);
+        let _ = blocks_to_parquet(
+            &InvertedIndex::schema(),
+            vec![index_block],
+            &mut data,
+            TableCompression::LZ4,
+        )?;
+
+        Ok(Some(Self {
+            data,
+            location: TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+                block_location,
+            ),
+        }))
+    }
+}
+
+/// Parse the value of table option `inverted_index_columns`, a comma separated list of
+/// column names.
+pub fn parse_inverted_index_columns(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .collect()
+}
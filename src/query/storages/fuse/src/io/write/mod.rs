@@ -13,16 +13,30 @@
 // limitations under the License.
 
 mod block_writer;
+mod deletion_vector_writer;
+mod inverted_index_builder;
 mod meta_writer;
 mod segment_writer;
+mod vector_index_builder;
+mod virtual_column_builder;
 mod write_settings;
 
 pub use block_writer::serialize_block;
+pub use block_writer::write_block_data;
 pub use block_writer::write_data;
+pub use block_writer::write_data_multipart;
 pub use block_writer::BlockBuilder;
 pub use block_writer::BlockSerialization;
 pub use block_writer::BloomIndexState;
+pub use deletion_vector_writer::DeletionVectorWriter;
+pub use deletion_vector_writer::DELETION_VECTOR_VERSION;
+pub use inverted_index_builder::parse_inverted_index_columns;
+pub use inverted_index_builder::InvertedIndexState;
 pub use meta_writer::CachedMetaWriter;
 pub use meta_writer::MetaWriter;
 pub use segment_writer::SegmentWriter;
+pub use vector_index_builder::parse_vector_index_columns;
+pub use vector_index_builder::VectorIndexState;
+pub use virtual_column_builder::VirtualColumnState;
+pub use write_settings::UploadSettings;
 pub use write_settings::WriteSettings;
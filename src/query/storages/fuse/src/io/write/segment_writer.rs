@@ -12,15 +12,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_exception::Result;
+use common_expression::BlockThresholds;
 use opendal::Operator;
+use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::SegmentInfo;
+use storages_common_table_meta::meta::Statistics;
 use storages_common_table_meta::meta::Versioned;
 
 use super::meta_writer::MetaWriter;
 use crate::io::CachedMetaWriter;
 use crate::io::TableMetaLocationGenerator;
+use crate::statistics::reducers::reduce_block_metas;
 
 #[derive(Clone)]
 pub struct SegmentWriter<'a> {
@@ -57,6 +63,28 @@ impl<'a> SegmentWriter<'a> {
         Ok(location)
     }
 
+    /// Batches the blocks into consolidated segments, each of which holds at most
+    /// `segment_max_blocks` blocks, and writes them down.
+    ///
+    /// Returns the locations and the summaries of the written segments.
+    #[async_backtrace::framed]
+    pub async fn write_segments_batched(
+        &self,
+        blocks: &[Arc<BlockMeta>],
+        segment_max_blocks: usize,
+        thresholds: BlockThresholds,
+    ) -> Result<Vec<(Location, Statistics)>> {
+        let segment_max_blocks = std::cmp::max(segment_max_blocks, 1);
+        let mut segments = Vec::new();
+        for chunk in blocks.chunks(segment_max_blocks) {
+            let summary = reduce_block_metas(chunk, thresholds);
+            let segment = SegmentInfo::new(chunk.to_vec(), summary.clone());
+            let location = self.write_segment(segment).await?;
+            segments.push((location, summary));
+        }
+        Ok(segments)
+    }
+
     fn generate_location(&self) -> Location {
         let path = self.location_generator.gen_segment_info_location();
         (path, SegmentInfo::VERSION)
@@ -0,0 +1,216 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_exception::Result;
+use common_expression::types::nullable::NullableColumn;
+use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::DataType;
+use common_expression::types::VariantType;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::ScalarRef;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_expression::TableSchemaRefExt;
+use common_expression::Value;
+use common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
+use jsonb::get_by_index;
+use jsonb::get_by_name;
+
+use crate::io::write::block_writer::serialize_block;
+use crate::io::write::WriteSettings;
+use crate::io::TableMetaLocationGenerator;
+
+// The max number of paths shredded from a variant column in one block,
+// values of other paths are still extracted from the source column on read.
+const MAX_SHREDDED_PATHS: usize = 64;
+// Paths nested deeper than this are not shredded.
+const MAX_SHREDDED_DEPTH: usize = 4;
+// Only the leading elements of an array are shredded.
+const MAX_SHREDDED_ARRAY_ELEMENTS: usize = 4;
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum PathElement {
+    Name(String),
+    Index(u64),
+}
+
+/// The shredded variant columns of a block.
+///
+/// Each leaf path found in the values of a variant column is stored as a virtual
+/// column named like `col:a:b[0]`, the name of the virtual column generated by the
+/// planner for the same path, so that reading it does not need the source column.
+pub struct VirtualColumnState {
+    pub(crate) data: Vec<u8>,
+    pub(crate) location: String,
+}
+
+impl VirtualColumnState {
+    pub fn try_create(
+        write_settings: &WriteSettings,
+        source_schema: &TableSchemaRef,
+        block: &DataBlock,
+        block_location: &str,
+    ) -> Result<Option<Self>> {
+        let num_rows = block.num_rows();
+        let schema = source_schema.remove_virtual_computed_fields();
+
+        let mut virtual_fields = Vec::new();
+        let mut virtual_columns = Vec::new();
+        for (index, field) in schema.fields().iter().enumerate() {
+            if field.data_type().remove_nullable() != TableDataType::Variant {
+                continue;
+            }
+            let entry = block.get_by_offset(index);
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, num_rows);
+
+            for path in collect_leaf_paths(&column) {
+                virtual_fields.push(TableField::new(
+                    &virtual_column_name(field.name(), &path),
+                    TableDataType::Nullable(Box::new(TableDataType::Variant)),
+                ));
+                virtual_columns.push(BlockEntry::new(
+                    DataType::Nullable(Box::new(DataType::Variant)),
+                    Value::Column(shred_path(&column, &path)),
+                ));
+            }
+        }
+        if virtual_columns.is_empty() {
+            return Ok(None);
+        }
+
+        let virtual_schema = TableSchemaRefExt::create(virtual_fields);
+        let virtual_block = DataBlock::new(virtual_columns, num_rows);
+        let mut data = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
+        let _ = serialize_block(write_settings, &virtual_schema, virtual_block, &mut data)?;
+
+        Ok(Some(Self {
+            data,
+            location: TableMetaLocationGenerator::gen_virtual_block_location(block_location),
+        }))
+    }
+}
+
+fn collect_leaf_paths(column: &Column) -> BTreeSet<Vec<PathElement>> {
+    let mut paths = BTreeSet::new();
+    for row in 0..column.len() {
+        if paths.len() >= MAX_SHREDDED_PATHS {
+            break;
+        }
+        if let Some(ScalarRef::Variant(val)) = column.index(row) {
+            if let Ok(value) = jsonb::from_slice(val) {
+                collect_value_paths(&value, &mut vec![], &mut paths);
+            }
+        }
+    }
+    paths
+}
+
+fn collect_value_paths(
+    value: &jsonb::Value,
+    path: &mut Vec<PathElement>,
+    paths: &mut BTreeSet<Vec<PathElement>>,
+) {
+    match value {
+        jsonb::Value::Object(obj) if path.len() < MAX_SHREDDED_DEPTH => {
+            for (key, val) in obj.iter() {
+                // Keys like `a:b` can't be distinguished from nested paths by the column name.
+                if key.is_empty() || key.contains(':') || key.contains('[') {
+                    continue;
+                }
+                path.push(PathElement::Name(key.clone()));
+                collect_value_paths(val, path, paths);
+                path.pop();
+            }
+        }
+        jsonb::Value::Array(vals) if path.len() < MAX_SHREDDED_DEPTH => {
+            for (idx, val) in vals.iter().take(MAX_SHREDDED_ARRAY_ELEMENTS).enumerate() {
+                path.push(PathElement::Index(idx as u64));
+                collect_value_paths(val, path, paths);
+                path.pop();
+            }
+        }
+        jsonb::Value::Object(_) | jsonb::Value::Array(_) => {}
+        _ => {
+            if !path.is_empty() && paths.len() < MAX_SHREDDED_PATHS {
+                paths.insert(path.clone());
+            }
+        }
+    }
+}
+
+fn virtual_column_name(source_name: &str, path: &[PathElement]) -> String {
+    let mut name = source_name.to_string();
+    for element in path {
+        match element {
+            PathElement::Name(key) => {
+                name.push(':');
+                name.push_str(key);
+            }
+            PathElement::Index(idx) => {
+                name.push('[');
+                name.push_str(&idx.to_string());
+                name.push(']');
+            }
+        }
+    }
+    name
+}
+
+fn shred_path(column: &Column, path: &[PathElement]) -> Column {
+    let len = column.len();
+    let mut validity = MutableBitmap::with_capacity(len);
+    let mut builder = StringColumnBuilder::with_capacity(len, len * 10);
+    for row in 0..len {
+        let value = match column.index(row) {
+            Some(ScalarRef::Variant(val)) => get_by_path(val, path),
+            _ => None,
+        };
+        match value {
+            Some(value) => {
+                validity.push(true);
+                builder.put_slice(&value);
+            }
+            None => validity.push(false),
+        }
+        builder.commit_row();
+    }
+    Column::Nullable(Box::new(
+        NullableColumn::<VariantType> {
+            column: builder.build(),
+            validity: validity.into(),
+        }
+        .upcast(),
+    ))
+}
+
+// Extracts the value in the same way as the `get` function,
+// which generates the virtual column from the source column.
+fn get_by_path(value: &[u8], path: &[PathElement]) -> Option<Vec<u8>> {
+    let mut value = value.to_vec();
+    for element in path {
+        value = match element {
+            PathElement::Name(name) => get_by_name(&value, name)?,
+            PathElement::Index(idx) => get_by_index(&value, *idx as i32)?,
+        };
+    }
+    Some(value)
+}
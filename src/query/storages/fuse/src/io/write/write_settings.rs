@@ -12,11 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
 use storages_common_table_meta::table::TableCompression;
 
 use crate::FuseStorageFormat;
 use crate::DEFAULT_BLOCK_PER_SEGMENT;
 use crate::DEFAULT_ROW_PER_PAGE;
+use crate::DEFAULT_SEGMENT_MAX_BLOCKS;
 
 #[derive(Clone, Debug)]
 pub struct WriteSettings {
@@ -26,6 +31,16 @@ pub struct WriteSettings {
     pub max_page_size: usize,
 
     pub block_per_seg: usize,
+    // if not 0, the blocks appended within one commit are batched
+    // into segments of at most `segment_max_blocks` blocks.
+    pub segment_max_blocks: usize,
+    // if true, the variant columns are shredded into virtual columns on write,
+    // current only work in native format
+    pub variant_shredding: bool,
+    // the string columns indexed by the inverted index on write.
+    pub inverted_index_columns: Vec<String>,
+    // the vector columns indexed by the vector index on write.
+    pub vector_index_columns: Vec<String>,
 }
 
 impl Default for WriteSettings {
@@ -35,6 +50,37 @@ impl Default for WriteSettings {
             table_compression: TableCompression::default(),
             max_page_size: DEFAULT_ROW_PER_PAGE,
             block_per_seg: DEFAULT_BLOCK_PER_SEGMENT,
+            segment_max_blocks: DEFAULT_SEGMENT_MAX_BLOCKS,
+            variant_shredding: false,
+            inverted_index_columns: vec![],
+            vector_index_columns: vec![],
         }
     }
 }
+
+// S3 requires the parts of a multipart upload, except the last one, to be at least 5MiB.
+const MIN_MULTIPART_UPLOAD_PART_SIZE: usize = 5 * 1024 * 1024;
+// Times a failed part is retried before the multipart upload is aborted.
+const MULTIPART_UPLOAD_MAX_RETRIES: usize = 3;
+
+#[derive(Clone, Debug)]
+pub struct UploadSettings {
+    // blocks larger than this are uploaded in multiple parts, 0 disables the multipart upload.
+    pub multipart_threshold: usize,
+    pub multipart_part_size: usize,
+    pub multipart_max_retries: usize,
+}
+
+impl UploadSettings {
+    pub fn from_ctx(ctx: &Arc<dyn TableContext>) -> Result<UploadSettings> {
+        let settings = ctx.get_settings();
+        Ok(UploadSettings {
+            multipart_threshold: settings.get_storage_multipart_upload_threshold()? as usize,
+            multipart_part_size: std::cmp::max(
+                settings.get_storage_multipart_upload_part_size()? as usize,
+                MIN_MULTIPART_UPLOAD_PART_SIZE,
+            ),
+            multipart_max_retries: MULTIPART_UPLOAD_MAX_RETRIES,
+        })
+    }
+}
@@ -14,6 +14,7 @@
 
 use metrics::counter;
 use metrics::gauge;
+use metrics::histogram;
 use metrics::increment_gauge;
 
 macro_rules! key {
@@ -70,6 +71,25 @@ pub fn metrics_inc_remote_io_deserialize_milliseconds(c: u64) {
     increment_gauge!(key!("remote_io_deserialize_milliseconds"), c as f64);
 }
 
+/// Bytes of the column chunks served by the table data caches instead of the storage.
+pub fn metrics_inc_remote_io_read_bytes_from_cache(c: u64) {
+    increment_gauge!(key!("remote_io_read_bytes_from_cache"), c as f64);
+}
+
+/// Scan metrics, what the block readers return to the pipeline.
+pub fn metrics_inc_block_read_output_rows(c: u64) {
+    increment_gauge!(key!("block_read_output_rows"), c as f64);
+}
+
+pub fn metrics_inc_block_read_output_bytes(c: u64) {
+    increment_gauge!(key!("block_read_output_bytes"), c as f64);
+}
+
+/// Bytes read from the storage for a block divided by the bytes of the block returned.
+pub fn metrics_observe_read_amplification(v: f64) {
+    histogram!(key!("read_amplification"), v);
+}
+
 /// Block metrics.
 pub fn metrics_inc_block_write_nums(c: u64) {
     increment_gauge!(key!("block_write_nums"), c as f64);
@@ -165,10 +185,24 @@ pub fn metrics_inc_pruning_prewhere_nums(c: u64) {
     increment_gauge!(key!("pruning_prewhere_nums"), c as f64);
 }
 
+pub fn metrics_inc_pruning_runtime_filter_nums(c: u64) {
+    increment_gauge!(key!("pruning_runtime_filter_nums"), c as f64);
+}
+
 pub fn metrics_inc_pruning_milliseconds(c: u64) {
     increment_gauge!(key!("pruning_milliseconds"), c as f64);
 }
 
+/// Ratio of the blocks kept by the pruning of a scan.
+pub fn metrics_observe_pruning_blocks_ratio(v: f64) {
+    histogram!(key!("pruning_blocks_ratio"), v);
+}
+
+/// Ratio of the blocks that the bloom filters of a scan can not prune.
+pub fn metrics_observe_bloom_pruning_hit_ratio(v: f64) {
+    histogram!(key!("bloom_pruning_hit_ratio"), v);
+}
+
 pub fn metrics_inc_deletion_block_range_pruned_nums(c: u64) {
     increment_gauge!(key!("deletion_block_range_pruned_nums"), c as f64);
 }
@@ -191,6 +225,11 @@ pub fn metrics_reset() {
     gauge!(key!("remote_io_read_parts"), c);
     gauge!(key!("remote_io_read_milliseconds"), c);
     gauge!(key!("remote_io_deserialize_milliseconds"), c);
+    gauge!(key!("remote_io_read_bytes_from_cache"), c);
+
+    // Scan metrics.
+    gauge!(key!("block_read_output_rows"), c);
+    gauge!(key!("block_read_output_bytes"), c);
 
     // Block metrics.
     gauge!(key!("block_write_nums"), c);
@@ -210,6 +249,7 @@ pub fn metrics_reset() {
 
     // Pruning metrics.
     gauge!(key!("pruning_prewhere_nums"), c);
+    gauge!(key!("pruning_runtime_filter_nums"), c);
     gauge!(key!("pruning_milliseconds"), c);
 
     gauge!(key!("segments_range_pruning_before"), c);
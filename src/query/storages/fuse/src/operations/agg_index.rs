@@ -0,0 +1,338 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::collections::HashMap;
+use std::io::Cursor;
+
+use bumpalo::Bump;
+use common_arrow::arrow::io::parquet::read as pread;
+use common_catalog::plan::AggIndexAggregation;
+use common_catalog::plan::Projection;
+use common_exception::Result;
+use common_expression::infer_schema_type;
+use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::BooleanType;
+use common_expression::types::DataType;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::RemoteExpr;
+use common_expression::ScalarRef;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::Value;
+use common_functions::aggregates::get_layout_offsets;
+use common_functions::aggregates::AggregateFunctionFactory;
+use common_functions::aggregates::AggregateFunctionRef;
+use common_functions::aggregates::StateAddr;
+use common_functions::BUILTIN_FUNCTIONS;
+use opendal::Operator;
+use storages_common_blocks::blocks_to_parquet;
+use storages_common_table_meta::table::TableCompression;
+
+use crate::io::write_data;
+
+/// Computes the partial aggregation of aggregating index on the blocks of the table.
+///
+/// The output of a block are the group items and the serialized aggregate states of
+/// each group, ordered by [`AggIndexAggregation::output`].
+pub struct AggIndexAggregator {
+    func_ctx: FunctionContext,
+    filter: Option<Expr>,
+    group_items: Vec<Expr>,
+    functions: Vec<AggregateFunctionRef>,
+    args: Vec<Vec<Expr>>,
+    layout: Option<Layout>,
+    offsets: Vec<usize>,
+    output: Vec<usize>,
+}
+
+impl AggIndexAggregator {
+    /// The columns of the table read by the aggregation.
+    pub fn projection(
+        schema: &TableSchema,
+        aggregation: &AggIndexAggregation,
+    ) -> Result<Projection> {
+        let mut names = aggregation
+            .filter
+            .iter()
+            .chain(aggregation.group_items.iter())
+            .chain(
+                aggregation
+                    .aggregate_functions
+                    .iter()
+                    .flat_map(|f| f.args.iter()),
+            )
+            .flat_map(|expr| expr.as_expr(&BUILTIN_FUNCTIONS).column_refs().into_keys())
+            .collect::<Vec<_>>();
+        names.sort();
+        names.dedup();
+
+        let mut indices = names
+            .iter()
+            .map(|name| schema.index_of(name))
+            .collect::<Result<Vec<_>>>()?;
+        indices.sort();
+        Ok(Projection::Columns(indices))
+    }
+
+    /// `schema` is the schema of the blocks read by the projection of [`Self::projection`].
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        schema: &DataSchema,
+        aggregation: &AggIndexAggregation,
+    ) -> Result<Self> {
+        let build_expr = |expr: &RemoteExpr<String>| -> Result<Expr> {
+            let expr = expr.as_expr(&BUILTIN_FUNCTIONS);
+            let mut offsets = HashMap::new();
+            for name in expr.column_refs().into_keys() {
+                let offset = schema.index_of(&name)?;
+                offsets.insert(name, offset);
+            }
+            Ok(expr.project_column_ref(|name| offsets[name]))
+        };
+
+        let filter = aggregation.filter.as_ref().map(build_expr).transpose()?;
+        let group_items = aggregation
+            .group_items
+            .iter()
+            .map(build_expr)
+            .collect::<Result<Vec<_>>>()?;
+        let mut functions = Vec::with_capacity(aggregation.aggregate_functions.len());
+        let mut args = Vec::with_capacity(aggregation.aggregate_functions.len());
+        for func in aggregation.aggregate_functions.iter() {
+            let func_args = func
+                .args
+                .iter()
+                .map(build_expr)
+                .collect::<Result<Vec<_>>>()?;
+            let arg_types = func_args
+                .iter()
+                .map(|arg| arg.data_type().clone())
+                .collect::<Vec<_>>();
+            functions.push(AggregateFunctionFactory::instance().get(
+                &func.name,
+                func.params.clone(),
+                arg_types,
+            )?);
+            args.push(func_args);
+        }
+
+        let mut offsets = Vec::with_capacity(functions.len());
+        let layout = if functions.is_empty() {
+            None
+        } else {
+            Some(get_layout_offsets(&functions, &mut offsets)?)
+        };
+
+        Ok(Self {
+            func_ctx,
+            filter,
+            group_items,
+            functions,
+            args,
+            layout,
+            offsets,
+            output: aggregation.output.clone(),
+        })
+    }
+
+    /// Data types of the output columns.
+    pub fn output_types(&self) -> Vec<DataType> {
+        self.output
+            .iter()
+            .map(|i| match self.group_items.get(*i) {
+                Some(item) => item.data_type().clone(),
+                None => DataType::String,
+            })
+            .collect()
+    }
+
+    /// Schema of the index data, the columns are named by their offsets.
+    pub fn output_schema(&self) -> Result<TableSchema> {
+        let fields = self
+            .output_types()
+            .iter()
+            .enumerate()
+            .map(|(i, ty)| Ok(TableField::new(&i.to_string(), infer_schema_type(ty)?)))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(TableSchema::new(fields))
+    }
+
+    pub fn aggregate(&self, block: DataBlock) -> Result<DataBlock> {
+        let block = match &self.filter {
+            Some(filter) => {
+                let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+                let predicate = evaluator
+                    .run(filter)?
+                    .try_downcast::<BooleanType>()
+                    .unwrap();
+                block.filter_boolean_value(&predicate)?
+            }
+            None => block,
+        };
+        let num_rows = block.num_rows();
+
+        let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let eval = |expr: &Expr| -> Result<Column> {
+            Ok(evaluator
+                .run(expr)?
+                .convert_to_full_column(expr.data_type(), num_rows))
+        };
+        let group_columns = self
+            .group_items
+            .iter()
+            .map(eval)
+            .collect::<Result<Vec<_>>>()?;
+        let arg_columns = self
+            .args
+            .iter()
+            .map(|args| args.iter().map(eval).collect::<Result<Vec<_>>>())
+            .collect::<Result<Vec<_>>>()?;
+
+        // Group the rows, the first row of each group is kept to output the group items.
+        let mut groups: HashMap<Vec<ScalarRef>, usize> = HashMap::new();
+        let mut first_rows = Vec::new();
+        let mut row_groups = Vec::with_capacity(num_rows);
+        for row in 0..num_rows {
+            let key = group_columns
+                .iter()
+                .map(|c| unsafe { c.index_unchecked(row) })
+                .collect::<Vec<_>>();
+            let group = *groups.entry(key).or_insert_with(|| {
+                first_rows.push(row);
+                first_rows.len() - 1
+            });
+            row_groups.push(group);
+        }
+        // The aggregation without group items has one group even if there are no rows.
+        if self.group_items.is_empty() && first_rows.is_empty() {
+            first_rows.push(0);
+        }
+        let num_groups = first_rows.len();
+
+        let mut columns = Vec::with_capacity(self.group_items.len() + self.functions.len());
+        for (item, column) in self.group_items.iter().zip(group_columns.iter()) {
+            let mut builder = ColumnBuilder::with_capacity(item.data_type(), num_groups);
+            for row in first_rows.iter() {
+                builder.push(unsafe { column.index_unchecked(*row) });
+            }
+            columns.push(builder.build());
+        }
+
+        if let Some(layout) = self.layout {
+            let arena = Bump::new();
+            let places = (0..num_groups)
+                .map(|_| {
+                    let place: StateAddr = arena.alloc_layout(layout).into();
+                    for (func, offset) in self.functions.iter().zip(self.offsets.iter()) {
+                        func.init_state(place.next(*offset));
+                    }
+                    place
+                })
+                .collect::<Vec<_>>();
+            let row_places = row_groups
+                .iter()
+                .map(|group| places[*group])
+                .collect::<Vec<_>>();
+
+            let res = self.accumulate_and_serialize(&places, &row_places, &arg_columns);
+
+            for (func, offset) in self.functions.iter().zip(self.offsets.iter()) {
+                if func.need_manual_drop_state() {
+                    for place in places.iter() {
+                        unsafe { func.drop_state(place.next(*offset)) }
+                    }
+                }
+            }
+            columns.extend(res?);
+        }
+
+        let output_types = self.output_types();
+        let columns = self
+            .output
+            .iter()
+            .zip(output_types)
+            .map(|(i, ty)| BlockEntry::new(ty, Value::Column(columns[*i].clone())))
+            .collect();
+        Ok(DataBlock::new(columns, num_groups))
+    }
+
+    fn accumulate_and_serialize(
+        &self,
+        places: &[StateAddr],
+        row_places: &[StateAddr],
+        arg_columns: &[Vec<Column>],
+    ) -> Result<Vec<Column>> {
+        let mut columns = Vec::with_capacity(self.functions.len());
+        for ((func, offset), args) in self
+            .functions
+            .iter()
+            .zip(self.offsets.iter())
+            .zip(arg_columns.iter())
+        {
+            func.accumulate_keys(row_places, *offset, args, row_places.len())?;
+
+            let mut builder = StringColumnBuilder::with_capacity(places.len(), places.len() * 4);
+            for place in places.iter() {
+                func.serialize(place.next(*offset), &mut builder.data)?;
+                builder.commit_row();
+            }
+            columns.push(Column::String(builder.build()));
+        }
+        Ok(columns)
+    }
+}
+
+/// Write the index data of a block, see [`AggIndexAggregator`].
+pub async fn write_agg_index_data(
+    operator: &Operator,
+    location: &str,
+    schema: &TableSchema,
+    block: DataBlock,
+) -> Result<()> {
+    let mut data = Vec::with_capacity(block.memory_size());
+    blocks_to_parquet(schema, vec![block], &mut data, TableCompression::LZ4)?;
+    write_data(data, operator, location).await
+}
+
+/// Read the index data of a block, returns [None] if the block is not indexed yet.
+pub async fn read_agg_index_data(operator: &Operator, location: &str) -> Result<Option<DataBlock>> {
+    let data = match operator.read(location).await {
+        Ok(data) => data,
+        Err(e) if e.kind() == opendal::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut reader = Cursor::new(data);
+    let meta = pread::read_metadata(&mut reader)?;
+    let arrow_schema = pread::infer_schema(&meta)?;
+    let schema = DataSchema::from(&TableSchema::from(&arrow_schema));
+    let chunks = pread::FileReader::new(reader, meta.row_groups, arrow_schema, None, None, None);
+
+    let mut blocks = Vec::new();
+    for chunk in chunks {
+        blocks.push(DataBlock::from_arrow_chunk(&chunk?, &schema)?);
+    }
+    if blocks.is_empty() {
+        return Ok(Some(DataBlock::empty_with_schema(schema.into())));
+    }
+    Ok(Some(DataBlock::concat(&blocks)?))
+}
@@ -122,7 +122,21 @@ impl FuseTable {
             }
 
             // 3. Generate new table statistics
-            let table_statistics = TableSnapshotStatistics::new(ndv_map);
+            let mut table_statistics = TableSnapshotStatistics::new(ndv_map);
+            // the sketches maintained while writing are kept, they do not shrink on deletion, and
+            // the estimation does not know the overlapping of blocks, take the lesser of the two.
+            if let Some(prev) = self.read_table_snapshot_statistics(Some(&snapshot)).await? {
+                for (column_id, hll) in &prev.column_hlls {
+                    let ndv = hll.count();
+                    table_statistics
+                        .column_distinct_values
+                        .entry(*column_id)
+                        .and_modify(|v| *v = (*v).min(ndv))
+                        .or_insert(ndv);
+                }
+                table_statistics.column_hlls = prev.column_hlls.clone();
+            }
+
             let table_statistics_location = self
                 .meta_location_generator
                 .snapshot_statistics_location_from_uuid(
@@ -0,0 +1,72 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_catalog::table::CompactTarget;
+use common_exception::Result;
+use storages_common_table_meta::meta::TableSnapshot;
+
+use crate::FuseTable;
+use crate::DEFAULT_BLOCK_PER_SEGMENT;
+use crate::FUSE_OPT_KEY_AUTO_COMPACT;
+use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
+
+impl FuseTable {
+    /// Whether the table is compacted by the background compaction, disabled by default.
+    pub fn auto_compact_enabled(&self) -> bool {
+        self.get_option(FUSE_OPT_KEY_AUTO_COMPACT, false)
+    }
+
+    /// Evaluates the latest snapshot of the table, returns the compaction that is
+    /// worth doing without user-issued `OPTIMIZE TABLE`, if any.
+    ///
+    /// Only the summary of the snapshot is checked, the segments are not read.
+    #[async_backtrace::framed]
+    pub async fn auto_compact_target(&self) -> Result<Option<CompactTarget>> {
+        let snapshot = match self.read_table_snapshot().await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(None),
+        };
+
+        let block_per_seg =
+            self.get_option(FUSE_OPT_KEY_BLOCK_PER_SEGMENT, DEFAULT_BLOCK_PER_SEGMENT);
+        Ok(Self::check_auto_compact(&snapshot, block_per_seg))
+    }
+
+    fn check_auto_compact(snapshot: &TableSnapshot, block_per_seg: usize) -> Option<CompactTarget> {
+        let summary = &snapshot.summary;
+        if summary.block_count <= 1 {
+            return None;
+        }
+
+        // The compaction merges the undersized blocks of each group of segments, and at most
+        // one undersized block is left in a group. So the blocks are worth compacting only if
+        // there are more undersized blocks than segments.
+        let undersized_blocks = summary
+            .block_count
+            .saturating_sub(summary.perfect_block_count);
+        if undersized_blocks > snapshot.segments.len() as u64 {
+            return Some(CompactTarget::Blocks);
+        }
+
+        // After the compaction, every segment except the last one has at least
+        // `block_per_seg` blocks.
+        let block_per_seg = std::cmp::max(block_per_seg, 1) as u64;
+        let expected_segments = summary.block_count / block_per_seg + 1;
+        if snapshot.segments.len() as u64 > expected_segments {
+            return Some(CompactTarget::Segments);
+        }
+
+        None
+    }
+}
@@ -0,0 +1,193 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::Duration;
+use chrono::Utc;
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableInfo;
+use storages_common_cache::LoadParams;
+use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::table::OPT_KEY_TABLE_CLONES;
+use tracing::warn;
+
+use crate::io::MetaReaders;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+use crate::FUSE_TBL_SNAPSHOT_PREFIX;
+
+impl FuseTable {
+    /// The files of a table are under its storage prefix, a clone also references the
+    /// files of its source table, which are never purged by the clone.
+    pub fn owns_location(&self, location: &str) -> bool {
+        location
+            .strip_prefix(self.meta_location_generator.prefix())
+            .is_some_and(|rest| rest.starts_with('/'))
+    }
+
+    /// The ids of the tables cloned from this table.
+    pub fn table_clones(&self) -> Vec<u64> {
+        parse_table_clones(self.table_info.options().get(OPT_KEY_TABLE_CLONES))
+    }
+
+    /// Collects the files of this table referenced by the snapshots of its clones that
+    /// are still in the retention period of the clones, they can be read by time travel,
+    /// so they are kept when the table is purged. The clones dropped and then garbage
+    /// collected from meta are skipped.
+    #[async_backtrace::framed]
+    pub async fn get_clone_referenced_locations(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+    ) -> Result<HashSet<String>> {
+        let mut locations = HashSet::new();
+        let clones = self.table_clones();
+        if clones.is_empty() {
+            return Ok(locations);
+        }
+
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT)?;
+        for table_id in clones {
+            let (ident, meta) = match catalog.get_table_meta_by_id(table_id).await {
+                Ok(v) => v,
+                Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE_ID => {
+                    warn!(
+                        "clone {} of table {} no longer exists, ignore it",
+                        table_id, self.table_info.desc
+                    );
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
+            let table_info = TableInfo {
+                ident,
+                desc: format!("clone {} of {}", table_id, self.table_info.desc),
+                meta: meta.as_ref().clone(),
+                ..Default::default()
+            };
+            let table = FuseTable::do_create(table_info)?;
+            for (snapshot_loc, snapshot) in self.retained_clone_snapshots(ctx, &table).await? {
+                let files = table
+                    .list_snapshot_referenced_files(ctx.clone(), snapshot_loc, &snapshot)
+                    .await?;
+                locations.extend(
+                    files
+                        .data
+                        .into_iter()
+                        .chain(files.optional)
+                        .chain(files.meta)
+                        .chain(Some(files.snapshot))
+                        .filter(|location| self.owns_location(location)),
+                );
+            }
+        }
+        Ok(locations)
+    }
+
+    /// The snapshots of the clone that time travel can read: its latest snapshot, the
+    /// snapshots it committed in its retention period, and the snapshot of this table
+    /// the oldest of them was committed on.
+    #[async_backtrace::framed]
+    async fn retained_clone_snapshots(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        clone: &FuseTable,
+    ) -> Result<Vec<(String, Arc<TableSnapshot>)>> {
+        let root_loc = match clone.snapshot_loc().await? {
+            Some(root_loc) => root_loc,
+            None => return Ok(vec![]),
+        };
+        let root_snapshot = match read_snapshot(clone, &root_loc).await? {
+            Some(root_snapshot) => root_snapshot,
+            None => return Ok(vec![]),
+        };
+
+        let retention = Duration::hours(clone.get_data_retention_period(ctx.as_ref())? as i64);
+        let time_point = root_snapshot.timestamp.unwrap_or_else(Utc::now) - retention;
+        let prefix = format!(
+            "{}/{}/",
+            clone.meta_location_generator().prefix(),
+            FUSE_TBL_SNAPSHOT_PREFIX,
+        );
+        let retained = clone
+            .list_files(prefix, |_, modified| modified >= time_point)
+            .await?;
+
+        let mut snapshots = vec![(root_loc.clone(), root_snapshot)];
+        for location in retained {
+            if location == root_loc {
+                continue;
+            }
+            if let Some(snapshot) = read_snapshot(clone, &location).await? {
+                snapshots.push((location, snapshot));
+            }
+        }
+
+        // The first snapshot committed by the clone follows the snapshot of this table
+        // the clone was created from.
+        let mut prev_snapshots = vec![];
+        for (_, snapshot) in &snapshots {
+            let (id, ver) = match snapshot.prev_snapshot_id {
+                Some(prev) => prev,
+                None => continue,
+            };
+            let location = self
+                .meta_location_generator()
+                .snapshot_location_from_uuid(&id, ver)?;
+            if snapshots.iter().any(|(loc, _)| loc == &location)
+                || prev_snapshots.iter().any(|(loc, _)| loc == &location)
+            {
+                continue;
+            }
+            if let Some(prev) = read_snapshot(self, &location).await? {
+                prev_snapshots.push((location, prev));
+            }
+        }
+        snapshots.extend(prev_snapshots);
+        Ok(snapshots)
+    }
+}
+
+/// Reads the snapshot at `location` with the operator of `table`, [None] if it has been
+/// purged already.
+#[async_backtrace::framed]
+async fn read_snapshot(table: &FuseTable, location: &str) -> Result<Option<Arc<TableSnapshot>>> {
+    let reader = MetaReaders::table_snapshot_reader(table.get_operator());
+    let params = LoadParams {
+        location: location.to_string(),
+        len_hint: None,
+        ver: TableMetaLocationGenerator::snapshot_version(location),
+        put_cache: true,
+    };
+    match reader.read(&params).await {
+        Ok(snapshot) => Ok(Some(snapshot)),
+        Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Parses the comma separated ids of the `table_clones` option.
+pub fn parse_table_clones(option: Option<&String>) -> Vec<u64> {
+    option
+        .map(|v| {
+            v.split(',')
+                .filter_map(|id| id.trim().parse::<u64>().ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
@@ -27,6 +27,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::TableSchemaRef;
 use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::TableStatistics;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
@@ -59,6 +60,8 @@ use crate::operations::common::AbortOperation;
 use crate::operations::common::AppendGenerator;
 use crate::operations::common::CommitSink;
 use crate::operations::common::TableMutationAggregator;
+use crate::operations::table_change::notify_table_change;
+use crate::operations::TableChangeEvent;
 use crate::statistics::merge_statistics;
 use crate::FuseTable;
 
@@ -86,6 +89,7 @@ impl FuseTable {
                 self.meta_location_generator().clone(),
                 self.schema(),
                 self.get_operator(),
+                self.get_write_settings().segment_max_blocks,
             );
             Ok(ProcessorPtr::create(AsyncAccumulatingTransformer::create(
                 input, output, aggregator,
@@ -175,26 +179,7 @@ impl FuseTable {
         operator: &Operator,
     ) -> Result<()> {
         // 1. prepare table meta
-        let mut new_table_meta = table_info.meta.clone();
-        // 1.1 set new snapshot location
-        new_table_meta.options.insert(
-            OPT_KEY_SNAPSHOT_LOCATION.to_owned(),
-            snapshot_location.clone(),
-        );
-        // remove legacy options
-        Self::remove_legacy_options(&mut new_table_meta.options);
-
-        // 1.2 setup table statistics
-        let stats = &snapshot.summary;
-        // update statistics
-        new_table_meta.statistics = TableStatistics {
-            number_of_rows: stats.row_count,
-            data_bytes: stats.uncompressed_byte_size,
-            compressed_data_bytes: stats.compressed_byte_size,
-            index_data_bytes: stats.index_size,
-            number_of_segments: Some(snapshot.segments.len() as u64),
-            number_of_blocks: Some(stats.block_count),
-        };
+        let new_table_meta = Self::new_table_meta(table_info, &snapshot, snapshot_location.clone());
 
         // 2. prepare the request
         let catalog = ctx.get_catalog(table_info.catalog())?;
@@ -209,10 +194,23 @@ impl FuseTable {
             deduplicated_label: ctx.get_settings().get_deduplicate_label()?,
         };
 
+        // inside a transaction, the table meta is updated while committing the transaction.
+        {
+            let txn_mgr = ctx.txn_mgr();
+            let mut txn_mgr = txn_mgr.lock();
+            if txn_mgr.is_active() {
+                txn_mgr.update_table_meta(table_info, req)?;
+                TableSnapshot::cache().put(snapshot_location, Arc::new(snapshot));
+                return Ok(());
+            }
+        }
+
         // 3. let's roll
+        let change = TableChangeEvent::try_create(table_info, &req.new_table_meta);
         let reply = catalog.update_table_meta(table_info, req).await;
         match reply {
             Ok(_) => {
+                notify_table_change(change);
                 TableSnapshot::cache().put(snapshot_location.clone(), Arc::new(snapshot));
                 // try keep a hit file of last snapshot
                 Self::write_last_snapshot_hint(operator, location_generator, snapshot_location)
@@ -236,6 +234,33 @@ impl FuseTable {
         }
     }
 
+    /// Builds the table meta which points to the new snapshot.
+    pub fn new_table_meta(
+        table_info: &TableInfo,
+        snapshot: &TableSnapshot,
+        snapshot_location: String,
+    ) -> TableMeta {
+        let mut new_table_meta = table_info.meta.clone();
+        // set new snapshot location
+        new_table_meta
+            .options
+            .insert(OPT_KEY_SNAPSHOT_LOCATION.to_owned(), snapshot_location);
+        // remove legacy options
+        Self::remove_legacy_options(&mut new_table_meta.options);
+
+        // update statistics
+        let stats = &snapshot.summary;
+        new_table_meta.statistics = TableStatistics {
+            number_of_rows: stats.row_count,
+            data_bytes: stats.uncompressed_byte_size,
+            compressed_data_bytes: stats.compressed_byte_size,
+            index_data_bytes: stats.index_size,
+            number_of_segments: Some(snapshot.segments.len() as u64),
+            number_of_blocks: Some(stats.block_count),
+        };
+        new_table_meta
+    }
+
     // Left a hint file which indicates the location of the latest snapshot
     #[async_backtrace::framed]
     pub async fn write_last_snapshot_hint(
@@ -440,6 +465,187 @@ impl FuseTable {
         }
     }
 
+    /// Commits the transaction of the session, the tables mutated inside the transaction
+    /// are updated in a single meta service transaction, either all of them or none.
+    ///
+    /// If some of the tables are changed concurrently, the transaction is rebased on the
+    /// latest versions of them and retried, as long as the concurrent changes only append
+    /// data. The transaction is ended no matter it is committed or not.
+    #[async_backtrace::framed]
+    pub async fn commit_txn(ctx: Arc<dyn TableContext>) -> Result<()> {
+        let txn_mgr = ctx.txn_mgr();
+        let res = Self::try_commit_txn(&ctx).await;
+        txn_mgr.lock().clear();
+        res
+    }
+
+    #[async_backtrace::framed]
+    async fn try_commit_txn(ctx: &Arc<dyn TableContext>) -> Result<()> {
+        let txn_mgr = ctx.txn_mgr();
+        let tables = txn_mgr.lock().tables();
+        let catalog = match tables.first() {
+            Some(table) => ctx.get_catalog(table.base.catalog())?,
+            // nothing is mutated inside the transaction.
+            None => return Ok(()),
+        };
+
+        let mut retries = 0;
+        let mut backoff = Self::set_backoff(None);
+        loop {
+            let req = txn_mgr.lock().req();
+            match catalog.update_multi_table_meta(req).await {
+                Ok(_) => {
+                    metrics_inc_commit_mutation_success();
+                    let tables = txn_mgr.lock().tables();
+                    info!("transaction committed, {} tables updated", tables.len());
+                    // try keep hint files of last snapshots
+                    for table in tables {
+                        notify_table_change(TableChangeEvent::try_create(
+                            &table.base,
+                            &table.pending.meta,
+                        ));
+                        if let Some(location) =
+                            table.pending.meta.options.get(OPT_KEY_SNAPSHOT_LOCATION)
+                        {
+                            let table = catalog.get_table_by_info(&table.pending)?;
+                            let table = FuseTable::try_from_table(table.as_ref())?;
+                            Self::write_last_snapshot_hint(
+                                &table.operator,
+                                &table.meta_location_generator,
+                                location.clone(),
+                            )
+                            .await;
+                        }
+                    }
+                    return Ok(());
+                }
+                Err(e) if e.code() == ErrorCode::TABLE_VERSION_MISMATCHED => {
+                    let d = match backoff.next_backoff() {
+                        Some(d) => d,
+                        None => {
+                            return Err(ErrorCode::OCCRetryFailure(format!(
+                                "can not fulfill the transaction after retries({} times), aborted",
+                                retries
+                            )));
+                        }
+                    };
+                    tracing::debug!(
+                        "got error TableVersionMismatched, transaction will be retried {} ms later",
+                        d.as_millis()
+                    );
+                    common_base::base::tokio::time::sleep(d).await;
+                    retries += 1;
+                    metrics_inc_commit_mutation_retry();
+
+                    let tables = txn_mgr.lock().tables();
+                    for table in tables {
+                        let (ident, meta) = catalog
+                            .get_table_meta_by_id(table.base.ident.table_id)
+                            .await?;
+                        if ident.seq == table.base.ident.seq {
+                            continue;
+                        }
+                        let latest = TableInfo {
+                            ident,
+                            meta: meta.as_ref().clone(),
+                            ..table.base.clone()
+                        };
+                        let pending =
+                            Self::rebase_txn_table(ctx, &table.base, &table.pending, &latest)
+                                .await?;
+                        txn_mgr.lock().rebase_table(latest, pending);
+                    }
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Rebases the changes the transaction made to a table on the latest version of the
+    /// table, returns the table with the changes applied.
+    #[async_backtrace::framed]
+    async fn rebase_txn_table(
+        ctx: &Arc<dyn TableContext>,
+        base: &TableInfo,
+        pending: &TableInfo,
+        latest: &TableInfo,
+    ) -> Result<TableInfo> {
+        let conflict = || {
+            metrics_inc_commit_mutation_unresolvable_conflict();
+            ErrorCode::StorageOther(format!(
+                "transaction conflicts, concurrent mutation detected on table {}",
+                latest.desc
+            ))
+        };
+        if base.meta.schema != latest.meta.schema {
+            return Err(conflict());
+        }
+
+        let catalog = ctx.get_catalog(latest.catalog())?;
+        let base_table = catalog.get_table_by_info(base)?;
+        let base_snapshot = FuseTable::try_from_table(base_table.as_ref())?
+            .read_table_snapshot()
+            .await?;
+        let pending_table = catalog.get_table_by_info(pending)?;
+        let pending_snapshot = FuseTable::try_from_table(pending_table.as_ref())?
+            .read_table_snapshot()
+            .await?
+            .ok_or_else(|| ErrorCode::Internal("transaction meets empty snapshot"))?;
+        let latest_table = catalog.get_table_by_info(latest)?;
+        let latest_fuse_table = FuseTable::try_from_table(latest_table.as_ref())?;
+        let latest_snapshot = latest_fuse_table
+            .read_table_snapshot()
+            .await?
+            .ok_or_else(|| ErrorCode::Internal("transaction meets empty snapshot"))?;
+
+        // Check if there is only insertion since the transaction mutated the table.
+        let concurrently_appended_segment_locations = match &base_snapshot {
+            Some(base_snapshot) => {
+                match MutatorConflictDetector::detect_conflicts(
+                    base_snapshot.as_ref(),
+                    latest_snapshot.as_ref(),
+                ) {
+                    Conflict::Unresolvable => return Err(conflict()),
+                    Conflict::ResolvableAppend(range_of_newly_append) => {
+                        &latest_snapshot.segments[range_of_newly_append]
+                    }
+                }
+            }
+            None => &latest_snapshot.segments[..],
+        };
+        info!(
+            "resolvable conflicts detected in transaction, table {}",
+            latest.desc
+        );
+        metrics_inc_commit_mutation_resolvable_conflict();
+
+        let (segments, summary) = Self::merge_with_base(
+            ctx.clone(),
+            latest_fuse_table.operator.clone(),
+            &pending_snapshot.segments,
+            &pending_snapshot.summary,
+            concurrently_appended_segment_locations,
+            latest_fuse_table.schema(),
+        )
+        .await?;
+        let mut snapshot = TableSnapshot::from_previous(latest_snapshot.as_ref());
+        snapshot.segments = segments;
+        snapshot.summary = summary;
+
+        let location_generator = &latest_fuse_table.meta_location_generator;
+        let snapshot_location = location_generator
+            .snapshot_location_from_uuid(&snapshot.snapshot_id, TableSnapshot::VERSION)?;
+        snapshot
+            .write_meta(&latest_fuse_table.operator, &snapshot_location)
+            .await?;
+
+        let meta = Self::new_table_meta(latest, &snapshot, snapshot_location);
+        Ok(TableInfo {
+            meta,
+            ..latest.clone()
+        })
+    }
+
     #[inline]
     pub fn is_error_recoverable(e: &ErrorCode, is_table_transient: bool) -> bool {
         let code = e.code();
@@ -23,7 +23,9 @@ use common_exception::Result;
 use common_expression::BlockThresholds;
 use common_expression::TableSchemaRef;
 use opendal::Operator;
+use storages_common_table_meta::meta::merge_column_hlls;
 use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::DistinctHLLOfColumns;
 use storages_common_table_meta::meta::FormatVersion;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::SegmentInfo;
@@ -31,10 +33,12 @@ use storages_common_table_meta::meta::Statistics;
 use storages_common_table_meta::meta::Versioned;
 use tracing::info;
 
+use crate::io::SegmentWriter;
 use crate::io::SegmentsIO;
 use crate::io::SerializedSegment;
 use crate::io::TableMetaLocationGenerator;
 use crate::operations::common::AbortOperation;
+use crate::operations::common::AppendBlocksLogEntry;
 use crate::operations::common::AppendOperationLogEntry;
 use crate::operations::common::CommitMeta;
 use crate::operations::common::MutationLogEntry;
@@ -86,6 +90,11 @@ pub struct MutationAccumulator {
     mutations: HashMap<SegmentIndex, BlockMutations>,
     // (path, segment_info)
     appended_segments: Vec<(String, Arc<SegmentInfo>, FormatVersion)>,
+    // blocks appended without segment, batched into segments of at most `segment_max_blocks` blocks.
+    appended_blocks: Vec<Arc<BlockMeta>>,
+    segment_max_blocks: usize,
+    // distinct value sketches of the appended data.
+    column_hlls: DistinctHLLOfColumns,
     base_segments: Vec<Location>,
 
     abort_operation: AbortOperation,
@@ -101,6 +110,7 @@ impl MutationAccumulator {
         thresholds: BlockThresholds,
         base_segments: Vec<Location>,
         summary: Statistics,
+        segment_max_blocks: usize,
     ) -> Self {
         MutationAccumulator {
             ctx,
@@ -110,6 +120,9 @@ impl MutationAccumulator {
             thresholds,
             mutations: HashMap::new(),
             appended_segments: vec![],
+            appended_blocks: vec![],
+            segment_max_blocks,
+            column_hlls: DistinctHLLOfColumns::new(),
             base_segments,
             abort_operation: AbortOperation::default(),
             summary,
@@ -120,6 +133,7 @@ impl MutationAccumulator {
         match log_entry {
             MutationLogEntry::Replacement(mutation) => self.accumulate_mutation(mutation),
             MutationLogEntry::Append(append) => self.accumulate_append(append),
+            MutationLogEntry::AppendBlocks(append) => self.accumulate_append_blocks(append),
         }
     }
 
@@ -157,7 +171,17 @@ impl MutationAccumulator {
             append_log_entry.segment_location.clone(),
             append_log_entry.segment_info.clone(),
             append_log_entry.format_version,
-        ))
+        ));
+        merge_column_hlls(&mut self.column_hlls, &append_log_entry.column_hlls);
+    }
+
+    fn accumulate_append_blocks(&mut self, append_log_entry: &AppendBlocksLogEntry) {
+        for block_meta in &append_log_entry.block_metas {
+            self.abort_operation.add_block(block_meta);
+        }
+        self.appended_blocks
+            .extend(append_log_entry.block_metas.iter().cloned());
+        merge_column_hlls(&mut self.column_hlls, &append_log_entry.column_hlls);
     }
 }
 
@@ -215,6 +239,27 @@ impl MutationAccumulator {
             merge_statistics_mut(&mut self.summary, &new_segment.summary);
         }
 
+        // consolidate the appended blocks into as few segments as possible.
+        let batched_segments = if self.appended_blocks.is_empty() {
+            vec![]
+        } else {
+            let blocks = std::mem::take(&mut self.appended_blocks);
+            let segment_writer = SegmentWriter::new(&self.dal, &self.location_gen);
+            let segments = segment_writer
+                .write_segments_batched(&blocks, self.segment_max_blocks, self.thresholds)
+                .await?;
+            info!(
+                "batched {} appended blocks into {} segments",
+                blocks.len(),
+                segments.len()
+            );
+            segments
+        };
+        for (location, summary) in &batched_segments {
+            self.abort_operation.add_segment(location.0.clone());
+            merge_statistics_mut(&mut self.summary, summary);
+        }
+
         let updated_segments = segments_editor.into_values();
 
         // with newly appended segments
@@ -222,6 +267,7 @@ impl MutationAccumulator {
             .appended_segments
             .iter()
             .map(|(path, _segment, format_version)| (path.clone(), *format_version))
+            .chain(batched_segments.into_iter().map(|(location, _)| location))
             .chain(updated_segments)
             .collect();
 
@@ -230,6 +276,7 @@ impl MutationAccumulator {
             self.summary.clone(),
             self.abort_operation.clone(),
             false,
+            std::mem::take(&mut self.column_hlls),
         );
         Ok(meta)
     }
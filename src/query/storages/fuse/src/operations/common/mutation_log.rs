@@ -20,6 +20,7 @@ use common_expression::BlockMetaInfo;
 use common_expression::BlockMetaInfoDowncast;
 use common_expression::DataBlock;
 use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::DistinctHLLOfColumns;
 use storages_common_table_meta::meta::FormatVersion;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::SegmentInfo;
@@ -36,6 +37,7 @@ pub struct MutationLogs {
 pub enum MutationLogEntry {
     Replacement(ReplacementLogEntry),
     Append(AppendOperationLogEntry),
+    AppendBlocks(AppendBlocksLogEntry),
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
@@ -64,6 +66,7 @@ pub struct AppendOperationLogEntry {
     pub segment_location: String,
     pub segment_info: Arc<SegmentInfo>,
     pub format_version: FormatVersion,
+    pub column_hlls: DistinctHLLOfColumns,
 }
 
 impl AppendOperationLogEntry {
@@ -71,11 +74,30 @@ impl AppendOperationLogEntry {
         segment_location: String,
         segment_info: Arc<SegmentInfo>,
         format_version: FormatVersion,
+        column_hlls: DistinctHLLOfColumns,
     ) -> Self {
         Self {
             segment_location,
             segment_info,
             format_version,
+            column_hlls,
+        }
+    }
+}
+
+// Blocks that are appended without a segment, they are batched
+// into consolidated segments while committing.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AppendBlocksLogEntry {
+    pub block_metas: Vec<Arc<BlockMeta>>,
+    pub column_hlls: DistinctHLLOfColumns,
+}
+
+impl AppendBlocksLogEntry {
+    pub fn new(block_metas: Vec<Arc<BlockMeta>>, column_hlls: DistinctHLLOfColumns) -> Self {
+        Self {
+            block_metas,
+            column_hlls,
         }
     }
 }
@@ -131,6 +153,8 @@ pub struct CommitMeta {
     pub summary: Statistics,
     pub abort_operation: AbortOperation,
     pub need_lock: bool,
+    // distinct value sketches of the appended data.
+    pub column_hlls: DistinctHLLOfColumns,
 }
 
 impl CommitMeta {
@@ -139,12 +163,14 @@ impl CommitMeta {
         summary: Statistics,
         abort_operation: AbortOperation,
         need_lock: bool,
+        column_hlls: DistinctHLLOfColumns,
     ) -> Self {
         CommitMeta {
             segments,
             summary,
             abort_operation,
             need_lock,
+            column_hlls,
         }
     }
 }
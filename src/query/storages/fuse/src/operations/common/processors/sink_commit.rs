@@ -27,12 +27,16 @@ use common_exception::Result;
 use common_expression::BlockMetaInfoDowncast;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
 use opendal::Operator;
+use storages_common_cache::CacheAccessor;
+use storages_common_cache_manager::CachedObject;
 use storages_common_table_meta::meta::ClusterKey;
 use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::meta::TableSnapshotStatistics;
 use storages_common_table_meta::meta::Versioned;
 use table_lock::TableLockHandlerWrapper;
 use table_lock::TableLockHeartbeat;
 
+use crate::io::MetaWriter;
 use crate::io::TableMetaLocationGenerator;
 use crate::metrics::metrics_inc_commit_aborts;
 use crate::metrics::metrics_inc_commit_mutation_success;
@@ -52,11 +56,13 @@ enum State {
     RefreshTable,
     GenerateSnapshot {
         previous: Option<Arc<TableSnapshot>>,
+        table_statistics: Option<Arc<TableSnapshotStatistics>>,
         cluster_key_meta: Option<ClusterKey>,
     },
     TryCommit {
         data: Vec<u8>,
         snapshot: TableSnapshot,
+        table_statistics: Option<TableSnapshotStatistics>,
     },
     AbortOperation,
     Finish,
@@ -135,6 +141,8 @@ where F: SnapshotGenerator + Send + 'static
 
         self.snapshot_gen.set_merged_segments(meta.segments.clone());
         self.snapshot_gen.set_merged_summary(meta.summary.clone());
+        self.snapshot_gen
+            .set_merged_column_hlls(meta.column_hlls.clone());
         self.abort_operation = meta.abort_operation.clone();
 
         self.backoff = FuseTable::set_backoff(self.max_retry_elapsed);
@@ -196,17 +204,29 @@ where F: SnapshotGenerator + Send + 'static
         match std::mem::replace(&mut self.state, State::None) {
             State::GenerateSnapshot {
                 previous,
+                table_statistics,
                 cluster_key_meta,
             } => {
                 let schema = self.table.schema().as_ref().clone();
+                let new_table_statistics = self
+                    .snapshot_gen
+                    .generate_table_statistics(&previous, table_statistics);
                 match self
                     .snapshot_gen
                     .generate_new_snapshot(schema, cluster_key_meta, previous)
                 {
-                    Ok(snapshot) => {
+                    Ok(mut snapshot) => {
+                        if let Some(table_statistics) = &new_table_statistics {
+                            snapshot.table_statistics_location =
+                                Some(self.location_gen.snapshot_statistics_location_from_uuid(
+                                    &table_statistics.snapshot_id,
+                                    table_statistics.format_version(),
+                                )?);
+                        }
                         self.state = State::TryCommit {
                             data: snapshot.to_bytes()?,
                             snapshot,
+                            table_statistics: new_table_statistics,
                         };
                     }
                     Err(e) => {
@@ -232,6 +252,9 @@ where F: SnapshotGenerator + Send + 'static
 
                 let fuse_table = FuseTable::try_from_table(self.table.as_ref())?.to_owned();
                 let previous = fuse_table.read_table_snapshot().await?;
+                let table_statistics = fuse_table
+                    .read_table_snapshot_statistics(previous.as_ref())
+                    .await?;
 
                 self.snapshot_gen
                     .fill_default_values(schema, &previous)
@@ -239,6 +262,7 @@ where F: SnapshotGenerator + Send + 'static
 
                 self.state = State::GenerateSnapshot {
                     previous,
+                    table_statistics,
                     cluster_key_meta: fuse_table.cluster_key_meta.clone(),
                 };
             }
@@ -259,11 +283,24 @@ where F: SnapshotGenerator + Send + 'static
                     }
                 }
             }
-            State::TryCommit { data, snapshot } => {
+            State::TryCommit {
+                data,
+                snapshot,
+                table_statistics,
+            } => {
                 let location = self
                     .location_gen
                     .snapshot_location_from_uuid(&snapshot.snapshot_id, TableSnapshot::VERSION)?;
 
+                let table_statistics_location = snapshot.table_statistics_location.clone();
+                if let (Some(table_statistics), Some(table_statistics_location)) =
+                    (&table_statistics, &table_statistics_location)
+                {
+                    table_statistics
+                        .write_meta(&self.dal, table_statistics_location)
+                        .await?;
+                }
+
                 self.dal.write(&location, data).await?;
 
                 match FuseTable::update_table_meta(
@@ -278,7 +315,16 @@ where F: SnapshotGenerator + Send + 'static
                 .await
                 {
                     Ok(_) => {
-                        if self.transient {
+                        if let (Some(table_statistics), Some(table_statistics_location)) =
+                            (table_statistics, table_statistics_location)
+                        {
+                            if let Some(cache) = TableSnapshotStatistics::cache() {
+                                cache.put(table_statistics_location, Arc::new(table_statistics));
+                            }
+                        }
+                        // Inside a transaction, the table meta is not updated until commit,
+                        // there is nothing to purge yet.
+                        if self.transient && !self.ctx.txn_mgr().lock().is_active() {
                             // Removes historical data, if table is transient
                             let latest = self.table.refresh(self.ctx.as_ref()).await?;
                             let tbl = FuseTable::try_from_table(latest.as_ref())?;
@@ -308,6 +354,14 @@ where F: SnapshotGenerator + Send + 'static
                         self.state = State::Finish;
                     }
                     Err(e) if FuseTable::is_error_recoverable(&e, self.transient) => {
+                        // the table statistics will be generated again while retrying.
+                        if table_statistics.is_some()
+                            && FuseTable::no_side_effects_in_meta_store(&e)
+                        {
+                            if let Some(table_statistics_location) = &table_statistics_location {
+                                let _ = self.dal.delete(table_statistics_location).await;
+                            }
+                        }
                         let table_info = self.table.get_table_info();
                         match self.backoff.next_backoff() {
                             Some(d) => {
@@ -354,9 +408,13 @@ where F: SnapshotGenerator + Send + 'static
                 self.table = self.table.refresh(self.ctx.as_ref()).await?;
                 let fuse_table = FuseTable::try_from_table(self.table.as_ref())?.to_owned();
                 let previous = fuse_table.read_table_snapshot().await?;
+                let table_statistics = fuse_table
+                    .read_table_snapshot_statistics(previous.as_ref())
+                    .await?;
                 let cluster_key_meta = fuse_table.cluster_key_meta.clone();
                 self.state = State::GenerateSnapshot {
                     previous,
+                    table_statistics,
                     cluster_key_meta,
                 };
             }
@@ -28,20 +28,25 @@ use common_pipeline_core::processors::port::OutputPort;
 use opendal::Operator;
 use storages_common_cache::CacheAccessor;
 use storages_common_cache_manager::CachedObject;
+use storages_common_table_meta::meta::merge_column_hlls;
+use storages_common_table_meta::meta::DistinctHLLOfColumns;
 use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::Statistics;
 use storages_common_table_meta::meta::Versioned;
 use tracing::info;
 
+use crate::io::write_block_data;
 use crate::io::write_data;
 use crate::io::BlockBuilder;
 use crate::io::BlockSerialization;
+use crate::io::UploadSettings;
 use crate::metrics::metrics_inc_block_index_write_bytes;
 use crate::metrics::metrics_inc_block_index_write_milliseconds;
 use crate::metrics::metrics_inc_block_index_write_nums;
 use crate::metrics::metrics_inc_block_write_bytes;
 use crate::metrics::metrics_inc_block_write_milliseconds;
 use crate::metrics::metrics_inc_block_write_nums;
+use crate::operations::common::AppendBlocksLogEntry;
 use crate::operations::common::AppendOperationLogEntry;
 use crate::operations::common::MutationLogEntry;
 use crate::operations::common::MutationLogs;
@@ -49,6 +54,7 @@ use crate::pipelines::processors::port::InputPort;
 use crate::pipelines::processors::processor::Event;
 use crate::pipelines::processors::processor::ProcessorPtr;
 use crate::pipelines::processors::Processor;
+use crate::statistics::gen_columns_distinct_hll;
 use crate::statistics::ClusterStatsGenerator;
 use crate::statistics::StatisticsAccumulator;
 use crate::FuseTable;
@@ -73,6 +79,8 @@ enum State {
 pub struct AppendTransform {
     data_accessor: Operator,
     accumulator: StatisticsAccumulator,
+    // distinct value sketches of the blocks that are not emitted yet.
+    column_hlls: DistinctHLLOfColumns,
     block_builder: BlockBuilder,
     state: State,
     input: Arc<InputPort>,
@@ -106,6 +114,7 @@ impl AppendTransform {
             block_builder,
             state: State::None,
             accumulator: StatisticsAccumulator::new(thresholds),
+            column_hlls: DistinctHLLOfColumns::new(),
         }
     }
 
@@ -193,6 +202,14 @@ impl Processor for AppendTransform {
     fn process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::None) {
             State::NeedSerialize(data_block) => {
+                let func_ctx = self.block_builder.ctx.get_function_context()?;
+                let column_hlls = gen_columns_distinct_hll(
+                    &func_ctx,
+                    &data_block,
+                    &self.block_builder.source_schema,
+                )?;
+                merge_column_hlls(&mut self.column_hlls, &column_hlls);
+
                 let serialized = self.block_builder.build(data_block, |block, generator| {
                     generator.gen_stats_for_append(block)
                 })?;
@@ -210,6 +227,19 @@ impl Processor for AppendTransform {
             }
             State::GenerateSegment => {
                 let acc = std::mem::take(&mut self.accumulator);
+
+                // batching mode, the segment is generated while committing
+                // together with the blocks appended by the other processors.
+                if self.block_builder.write_settings.segment_max_blocks > 0 {
+                    let column_hlls = std::mem::take(&mut self.column_hlls);
+                    let log_entry = AppendBlocksLogEntry::new(acc.blocks_metas, column_hlls);
+                    let meta = MutationLogs {
+                        entries: vec![MutationLogEntry::AppendBlocks(log_entry)],
+                    };
+                    self.output_data = Some(DataBlock::empty_with_meta(Box::new(meta)));
+                    return Ok(());
+                }
+
                 let col_stats = acc.summary();
 
                 let segment_info = SegmentInfo::new(acc.blocks_metas, Statistics {
@@ -238,8 +268,13 @@ impl Processor for AppendTransform {
 
                 // emit log entry.
                 // for newly created segment, always use the latest version
-                let log_entry =
-                    AppendOperationLogEntry::new(location, segment, SegmentInfo::VERSION);
+                let column_hlls = std::mem::take(&mut self.column_hlls);
+                let log_entry = AppendOperationLogEntry::new(
+                    location,
+                    segment,
+                    SegmentInfo::VERSION,
+                    column_hlls,
+                );
                 let meta = MutationLogs {
                     entries: vec![MutationLogEntry::Append(log_entry)],
                 };
@@ -263,7 +298,9 @@ impl Processor for AppendTransform {
                 let raw_block_data = serialized.block_raw_data;
                 let data_size = raw_block_data.len();
                 let path = serialized.block_meta.location.0.as_str();
-                write_data(raw_block_data, &self.data_accessor, path).await?;
+                let upload_settings = UploadSettings::from_ctx(&self.block_builder.ctx)?;
+                write_block_data(raw_block_data, &self.data_accessor, path, &upload_settings)
+                    .await?;
 
                 // Perf.
                 {
@@ -293,6 +330,34 @@ impl Processor for AppendTransform {
                     }
                 }
 
+                // write shredded variant columns.
+                if let Some(virtual_column_state) = serialized.virtual_column_state {
+                    write_data(
+                        virtual_column_state.data,
+                        &self.data_accessor,
+                        &virtual_column_state.location,
+                    )
+                    .await?;
+                }
+                // write inverted index.
+                if let Some(inverted_index_state) = serialized.inverted_index_state {
+                    write_data(
+                        inverted_index_state.data,
+                        &self.data_accessor,
+                        &inverted_index_state.location,
+                    )
+                    .await?;
+                }
+                // write vector index.
+                if let Some(vector_index_state) = serialized.vector_index_state {
+                    write_data(
+                        vector_index_state.data,
+                        &self.data_accessor,
+                        &vector_index_state.location,
+                    )
+                    .await?;
+                }
+
                 self.accumulator.add_with_block_meta(serialized.block_meta);
 
                 if self.accumulator.summary_block_count
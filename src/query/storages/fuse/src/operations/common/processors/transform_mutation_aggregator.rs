@@ -51,6 +51,7 @@ impl TableMutationAggregator {
         location_gen: TableMetaLocationGenerator,
         schema: TableSchemaRef,
         dal: Operator,
+        segment_max_blocks: usize,
     ) -> Self {
         let mutation_accumulator = MutationAccumulator::new(
             ctx.clone(),
@@ -60,6 +61,7 @@ impl TableMutationAggregator {
             thresholds,
             base_segments,
             base_summary,
+            segment_max_blocks,
         );
 
         TableMutationAggregator {
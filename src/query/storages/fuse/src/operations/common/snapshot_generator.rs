@@ -24,9 +24,11 @@ use common_expression::TableSchema;
 use common_sql::field_default_value;
 use storages_common_table_meta::meta::ClusterKey;
 use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::meta::DistinctHLLOfColumns;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::Statistics;
 use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::meta::TableSnapshotStatistics;
 use uuid::Uuid;
 
 use crate::metrics::metrics_inc_commit_mutation_resolvable_conflict;
@@ -43,6 +45,8 @@ pub trait SnapshotGenerator {
 
     fn set_merged_summary(&mut self, summary: Statistics);
 
+    fn set_merged_column_hlls(&mut self, _column_hlls: DistinctHLLOfColumns) {}
+
     async fn fill_default_values(
         &mut self,
         _schema: TableSchema,
@@ -57,6 +61,15 @@ pub trait SnapshotGenerator {
         cluster_key_meta: Option<ClusterKey>,
         previous: Option<Arc<TableSnapshot>>,
     ) -> Result<TableSnapshot>;
+
+    /// Generates the table statistics of the new snapshot, if they are changed.
+    fn generate_table_statistics(
+        &self,
+        _previous: &Option<Arc<TableSnapshot>>,
+        _table_statistics: Option<Arc<TableSnapshotStatistics>>,
+    ) -> Option<TableSnapshotStatistics> {
+        None
+    }
 }
 
 #[derive(Clone)]
@@ -138,6 +151,7 @@ pub struct AppendGenerator {
     ctx: Arc<dyn TableContext>,
     merged_segments: Vec<Location>,
     merged_statistics: Statistics,
+    merged_column_hlls: DistinctHLLOfColumns,
     leaf_default_values: HashMap<ColumnId, Scalar>,
 
     overwrite: bool,
@@ -149,6 +163,7 @@ impl AppendGenerator {
             ctx,
             merged_segments: vec![],
             merged_statistics: Statistics::default(),
+            merged_column_hlls: DistinctHLLOfColumns::new(),
             leaf_default_values: HashMap::new(),
             overwrite,
         }
@@ -177,6 +192,10 @@ impl SnapshotGenerator for AppendGenerator {
         self.merged_statistics = summary;
     }
 
+    fn set_merged_column_hlls(&mut self, column_hlls: DistinctHLLOfColumns) {
+        self.merged_column_hlls = column_hlls;
+    }
+
     async fn fill_default_values(
         &mut self,
         schema: TableSchema,
@@ -256,4 +275,25 @@ impl SnapshotGenerator for AppendGenerator {
             table_statistics_location,
         ))
     }
+
+    fn generate_table_statistics(
+        &self,
+        previous: &Option<Arc<TableSnapshot>>,
+        table_statistics: Option<Arc<TableSnapshotStatistics>>,
+    ) -> Option<TableSnapshotStatistics> {
+        if self.merged_column_hlls.is_empty() {
+            return None;
+        }
+
+        match previous {
+            Some(snapshot) if !self.overwrite && snapshot.summary.row_count != 0 => {
+                // the sketches can only be merged into the existing statistics, otherwise
+                // they do not cover the data of the previous snapshot.
+                table_statistics.map(|v| v.merge_column_hlls(&self.merged_column_hlls))
+            }
+            _ => Some(TableSnapshotStatistics::from_column_hlls(
+                self.merged_column_hlls.clone(),
+            )),
+        }
+    }
 }
@@ -57,6 +57,7 @@ use crate::operations::mutation::SerializeDataTransform;
 use crate::pipelines::Pipeline;
 use crate::pruning::create_segment_location_vector;
 use crate::pruning::FusePruner;
+use crate::FuseStorageFormat;
 use crate::FuseTable;
 
 pub struct MutationTaskInfo {
@@ -284,6 +285,10 @@ impl FuseTable {
         projection.sort_by_key(|&i| source_col_indices[i]);
         let ops = vec![BlockOperator::Project { projection }];
 
+        // deletion vectors are only supported by the parquet storage format.
+        let enable_deletion_vector = ctx.get_settings().get_enable_deletion_vector()?
+            && matches!(self.storage_format, FuseStorageFormat::Parquet);
+
         let max_threads =
             std::cmp::min(ctx.get_settings().get_max_threads()? as usize, total_tasks);
         // Add source pipe.
@@ -299,6 +304,7 @@ impl FuseTable {
                     ops.clone(),
                     self.storage_format,
                     query_row_id_col,
+                    enable_deletion_vector,
                 )
             },
             max_threads,
@@ -402,6 +408,7 @@ impl FuseTable {
                         cluster_stats,
                         c,
                         whole_block_deletion,
+                        block_meta,
                     )
                 })
                 .collect(),
@@ -88,9 +88,22 @@ impl FuseTable {
             Ok(v) => v,
         };
 
-        let locations_referenced_by_root = self
+        let mut locations_referenced_by_root = self
             .get_block_locations(ctx.clone(), &root_snapshot.segments, keep_last_snapshot)
             .await?;
+        // 2. The files shared with the clones of the table are kept.
+        let referenced_by_clones = self.get_clone_referenced_locations(ctx).await?;
+        for loc in &referenced_by_clones {
+            locations_referenced_by_root
+                .block_location
+                .insert(loc.clone());
+            locations_referenced_by_root
+                .bloom_location
+                .insert(loc.clone());
+            locations_referenced_by_root
+                .deletion_vector_location
+                .insert(loc.clone());
+        }
         let root_snapshot_lite = Arc::new(SnapshotLiteExtended {
             format_version: ver,
             snapshot_id: root_snapshot.snapshot_id,
@@ -150,6 +163,9 @@ impl FuseTable {
                     ts_to_be_purged.insert(s.table_statistics_location.unwrap());
                 }
             }
+            segments_to_be_purged.retain(|loc| !referenced_by_clones.contains(&loc.0));
+            snapshots_to_be_purged.retain(|loc| !referenced_by_clones.contains(loc));
+            ts_to_be_purged.retain(|loc| !referenced_by_clones.contains(loc));
 
             // Refresh status.
             {
@@ -211,6 +227,9 @@ impl FuseTable {
                     ts_to_be_purged.insert(s.table_statistics_location.unwrap());
                 }
             }
+            segments_to_be_purged.retain(|loc| !referenced_by_clones.contains(&loc.0));
+            snapshots_to_be_purged.retain(|loc| !referenced_by_clones.contains(loc));
+            ts_to_be_purged.retain(|loc| !referenced_by_clones.contains(loc));
             if let Some(dry_run_limit) = dry_run_limit {
                 if self
                     .dry_run_purge(
@@ -242,6 +261,7 @@ impl FuseTable {
         // 4. purge root snapshots.
 
         if dry_run_limit.is_some() {
+            dry_run_purge_files.retain(|loc| self.owns_location(loc));
             Ok(Some(dry_run_purge_files))
         } else {
             if !keep_last_snapshot {
@@ -251,6 +271,7 @@ impl FuseTable {
                     root_snapshot_lite,
                     locations_referenced_by_root,
                     root_snapshot_location,
+                    &referenced_by_clones,
                 )
                 .await?;
             }
@@ -296,6 +317,19 @@ impl FuseTable {
                 return Ok(true);
             }
 
+            for loc in &locations.deletion_vector_location {
+                if locations_referenced_by_root
+                    .deletion_vector_location
+                    .contains(loc)
+                {
+                    continue;
+                }
+                purge_files.push(loc.to_string())
+            }
+            if purge_files.len() >= dry_run_limit {
+                return Ok(true);
+            }
+
             purge_files.extend(chunk.iter().map(|loc| loc.0.clone()));
             if purge_files.len() >= dry_run_limit {
                 return Ok(true);
@@ -342,6 +376,17 @@ impl FuseTable {
                 blooms_to_be_purged.insert(loc.to_string());
             }
 
+            let mut deletion_vectors_to_be_purged = HashSet::new();
+            for loc in &locations.deletion_vector_location {
+                if locations_referenced_by_root
+                    .deletion_vector_location
+                    .contains(loc)
+                {
+                    continue;
+                }
+                deletion_vectors_to_be_purged.insert(loc.to_string());
+            }
+
             let segment_locations_to_be_purged = HashSet::from_iter(
                 chunk
                     .iter()
@@ -367,6 +412,7 @@ impl FuseTable {
                 counter,
                 blocks_to_be_purged,
                 blooms_to_be_purged,
+                deletion_vectors_to_be_purged,
                 segment_locations_to_be_purged,
             )
             .await?;
@@ -381,21 +427,33 @@ impl FuseTable {
         ctx: &Arc<dyn TableContext>,
         counter: &mut PurgeCounter,
         root_snapshot: Arc<SnapshotLiteExtended>,
-        root_location_tuple: LocationTuple,
+        mut root_location_tuple: LocationTuple,
         root_snapshot_location: String,
+        referenced_by_clones: &HashSet<String>,
     ) -> Result<()> {
         let segment_locations_to_be_purged = HashSet::from_iter(
             root_snapshot
                 .segments
                 .iter()
                 .map(|loc| loc.0.clone())
+                .filter(|loc| !referenced_by_clones.contains(loc))
                 .collect::<Vec<_>>(),
         );
+        root_location_tuple
+            .block_location
+            .retain(|loc| !referenced_by_clones.contains(loc));
+        root_location_tuple
+            .bloom_location
+            .retain(|loc| !referenced_by_clones.contains(loc));
+        root_location_tuple
+            .deletion_vector_location
+            .retain(|loc| !referenced_by_clones.contains(loc));
         self.purge_block_segments(
             ctx,
             counter,
             root_location_tuple.block_location,
             root_location_tuple.bloom_location,
+            root_location_tuple.deletion_vector_location,
             segment_locations_to_be_purged,
         )
         .await?;
@@ -404,13 +462,11 @@ impl FuseTable {
         if let Some(ts) = root_snapshot.table_statistics_location.clone() {
             ts_to_be_purged.insert(ts);
         }
-        self.purge_ts_snapshots(
-            ctx,
-            counter,
-            ts_to_be_purged,
-            HashSet::from([root_snapshot_location]),
-        )
-        .await
+        ts_to_be_purged.retain(|loc| !referenced_by_clones.contains(loc));
+        let mut snapshots_to_be_purged = HashSet::from([root_snapshot_location]);
+        snapshots_to_be_purged.retain(|loc| !referenced_by_clones.contains(loc));
+        self.purge_ts_snapshots(ctx, counter, ts_to_be_purged, snapshots_to_be_purged)
+            .await
     }
 
     async fn purge_block_segments(
@@ -419,6 +475,7 @@ impl FuseTable {
         counter: &mut PurgeCounter,
         blocks_to_be_purged: HashSet<String>,
         blooms_to_be_purged: HashSet<String>,
+        deletion_vectors_to_be_purged: HashSet<String>,
         segments_to_be_purged: HashSet<String>,
     ) -> Result<()> {
         // 1. Try to purge block file chunks.
@@ -440,7 +497,15 @@ impl FuseTable {
             .await?;
         }
 
-        // 3. Try to purge segment file chunks.
+        // 3. Try to purge deletion vector file chunks.
+        let deletion_vectors_count = deletion_vectors_to_be_purged.len();
+        if deletion_vectors_count > 0 {
+            counter.deletion_vectors += deletion_vectors_count;
+            self.try_purge_location_files(ctx.clone(), deletion_vectors_to_be_purged)
+                .await?;
+        }
+
+        // 4. Try to purge segment file chunks.
         let segments_count = segments_to_be_purged.len();
         if segments_count > 0 {
             counter.segments += segments_count;
@@ -485,9 +550,10 @@ impl FuseTable {
         // 5. Refresh status.
         {
             let status = format!(
-                "gc: block files purged:{}, bloom files purged:{}, segment files purged:{}, table statistic files purged:{}, snapshots purged:{}, take:{} sec",
+                "gc: block files purged:{}, bloom files purged:{}, deletion vector files purged:{}, segment files purged:{}, table statistic files purged:{}, snapshots purged:{}, take:{} sec",
                 counter.blocks,
                 counter.blooms,
+                counter.deletion_vectors,
                 counter.segments,
                 counter.table_statistics,
                 counter.snapshots,
@@ -507,7 +573,11 @@ impl FuseTable {
         locations_to_be_purged: HashSet<String>,
     ) -> Result<()> {
         let fuse_file = Files::create(ctx.clone(), self.operator.clone());
-        let locations = Vec::from_iter(locations_to_be_purged);
+        // the files of the source table referenced by a clone are not purged by the clone.
+        let locations = locations_to_be_purged
+            .into_iter()
+            .filter(|loc| self.owns_location(loc))
+            .collect::<Vec<_>>();
         fuse_file.remove_file_in_batch(&locations).await
     }
 
@@ -541,6 +611,7 @@ impl FuseTable {
     ) -> Result<LocationTuple> {
         let mut blocks = HashSet::new();
         let mut blooms = HashSet::new();
+        let mut deletion_vectors = HashSet::new();
 
         let fuse_segments = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
         let results = fuse_segments
@@ -562,11 +633,13 @@ impl FuseTable {
             };
             blocks.extend(location_tuple.block_location.into_iter());
             blooms.extend(location_tuple.bloom_location.into_iter());
+            deletion_vectors.extend(location_tuple.deletion_vector_location.into_iter());
         }
 
         Ok(LocationTuple {
             block_location: blocks,
             bloom_location: blooms,
+            deletion_vector_location: deletion_vectors,
         })
     }
 
@@ -584,21 +657,27 @@ impl FuseTable {
 pub struct LocationTuple {
     pub block_location: HashSet<String>,
     pub bloom_location: HashSet<String>,
+    pub deletion_vector_location: HashSet<String>,
 }
 
 impl From<SegmentInfo> for LocationTuple {
     fn from(value: SegmentInfo) -> Self {
         let mut block_location = HashSet::new();
         let mut bloom_location = HashSet::new();
+        let mut deletion_vector_location = HashSet::new();
         for block_meta in &value.blocks {
             block_location.insert(block_meta.location.0.clone());
             if let Some(bloom_loc) = &block_meta.bloom_filter_index_location {
                 bloom_location.insert(bloom_loc.0.clone());
             }
+            if let Some(deletion_vector_loc) = &block_meta.deletion_vector_location {
+                deletion_vector_location.insert(deletion_vector_loc.0.clone());
+            }
         }
         Self {
             block_location,
             bloom_location,
+            deletion_vector_location,
         }
     }
 }
@@ -607,6 +686,7 @@ struct PurgeCounter {
     start: Instant,
     blocks: usize,
     blooms: usize,
+    deletion_vectors: usize,
     segments: usize,
     table_statistics: usize,
     snapshots: usize,
@@ -618,6 +698,7 @@ impl PurgeCounter {
             start: Instant::now(),
             blocks: 0,
             blooms: 0,
+            deletion_vectors: 0,
             segments: 0,
             table_statistics: 0,
             snapshots: 0,
@@ -0,0 +1,101 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_pipeline_core::pipe::Pipe;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use storages_common_table_meta::meta::TableSnapshot;
+
+use crate::operations::common::AppendTransform;
+use crate::operations::merge_into::MergeIntoRowDeletionAggregator;
+use crate::operations::merge_into::MergeIntoSplitProcessor;
+use crate::pipelines::Pipeline;
+use crate::statistics::ClusterStatsGenerator;
+use crate::FuseTable;
+
+impl FuseTable {
+    // The pipeline going to be constructed
+    //
+    //                       ┌──────────────────┐            ┌─────────────────────────────┐
+    //                       │                  ├───────────►│       AppendTransform       ├──────┐
+    // ┌──────────────┐      │                  │            └─────────────────────────────┘      │
+    // │ MergeIntoJoin├─────►│MergeIntoSplitter │                                                 ├───────┐
+    // └──────────────┘      │                  │            ┌─────────────────────────────┐      │       │
+    //                       │                  ├───────────►│MergeIntoRowDeletionAggregator├─────┘       │
+    //                       └──────────────────┘            └─────────────────────────────┘             │
+    //                                                                                                    │
+    //                 ┌──────────────────────────────────────────────────────────────────────────────────┘
+    //                 │
+    //                 │      ┌───────────────────┐       ┌───────────────────────┐         ┌───────────────────┐
+    //                 └─────►│ResizeProcessor(1) ├──────►│TableMutationAggregator├────────►│     CommitSink    │
+    //                        └───────────────────┘       └───────────────────────┘         └───────────────────┘
+    //
+    // The input of the pipeline is supposed to be of the schema
+    // `[row_id Nullable(UInt64), action UInt8, <table columns>...]`.
+    #[async_backtrace::framed]
+    pub async fn build_merge_into_pipeline(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let schema = self.table_info.schema();
+        let base_snapshot = self.read_table_snapshot().await?.unwrap_or_else(|| {
+            Arc::new(TableSnapshot::new_empty_snapshot(schema.as_ref().clone()))
+        });
+
+        // `cluster_gen_for_append` can not be used here, the cluster key expressions it
+        // adds to the pipeline do not take the row id and action columns into account.
+        // Cluster statistics of the new blocks are left empty if the cluster keys are
+        // not plain columns, a later recluster will take care of them.
+        let block_thresholds = self.get_block_thresholds();
+        let cluster_stats_gen =
+            match self.get_cluster_stats_gen(ctx.clone(), 0, block_thresholds)? {
+                cluster_stats_gen if cluster_stats_gen.operators.is_empty() => cluster_stats_gen,
+                _ => ClusterStatsGenerator::default(),
+            };
+
+        // 1. split the rows to be appended and the row ids to be deleted
+        pipeline.resize(1)?;
+        pipeline.add_pipe(MergeIntoSplitProcessor::create().into_pipe());
+
+        // 2. connect with AppendTransform and MergeIntoRowDeletionAggregator, order matters!
+        let append_transform = AppendTransform::new(
+            ctx.clone(),
+            InputPort::create(),
+            OutputPort::create(),
+            self,
+            cluster_stats_gen,
+            block_thresholds,
+        );
+        let row_deletion_aggregator = MergeIntoRowDeletionAggregator::try_create(
+            ctx.clone(),
+            self,
+            base_snapshot.segments.clone(),
+            append_transform.get_block_builder(),
+        )?;
+        pipeline.add_pipe(Pipe::create(2, 2, vec![
+            append_transform.into_pipe_item(),
+            row_deletion_aggregator.into_pipe_item(),
+        ]));
+
+        // 3. connect with mutation pipes, the TableMutationAggregator, then CommitSink
+        self.chain_mutation_pipes(&ctx, pipeline, base_snapshot)
+            .await
+    }
+}
@@ -0,0 +1,189 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::DataBlock;
+use common_pipeline_core::pipe::Pipe;
+use common_pipeline_core::pipe::PipeItem;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::Event;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::processors::Processor;
+use common_sql::plans::MERGE_INTO_ACTION_DELETE;
+use common_sql::plans::MERGE_INTO_ACTION_INSERT;
+use common_sql::plans::MERGE_INTO_ACTION_UPDATE;
+
+/// Splits the output of the `MERGE INTO` join into
+/// - the rows to be appended (inserted rows and the new version of updated rows), and
+/// - the row ids of the target rows to be removed (deleted rows and the old version of updated rows).
+pub struct MergeIntoSplitProcessor {
+    input_port: Arc<InputPort>,
+    output_port_append_data: Arc<OutputPort>,
+    output_port_row_ids: Arc<OutputPort>,
+
+    input_data: Option<DataBlock>,
+    output_data_append: Option<DataBlock>,
+    output_data_row_ids: Option<DataBlock>,
+}
+
+impl MergeIntoSplitProcessor {
+    pub fn create() -> Self {
+        Self {
+            input_port: InputPort::create(),
+            output_port_append_data: OutputPort::create(),
+            output_port_row_ids: OutputPort::create(),
+            input_data: None,
+            output_data_append: None,
+            output_data_row_ids: None,
+        }
+    }
+
+    pub fn into_pipe(self) -> Pipe {
+        let pipe_item = self.into_pipe_item();
+        Pipe::create(1, 2, vec![pipe_item])
+    }
+
+    pub fn into_pipe_item(self) -> PipeItem {
+        let input = self.input_port.clone();
+        let output_port_append_data = self.output_port_append_data.clone();
+        let output_port_row_ids = self.output_port_row_ids.clone();
+        let processor_ptr = ProcessorPtr::create(Box::new(self));
+        PipeItem::create(processor_ptr, vec![input], vec![
+            output_port_append_data,
+            output_port_row_ids,
+        ])
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for MergeIntoSplitProcessor {
+    fn name(&self) -> String {
+        "MergeIntoSplit".to_owned()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        let finished = self.input_port.is_finished()
+            && self.output_data_append.is_none()
+            && self.output_data_row_ids.is_none();
+
+        if finished {
+            self.output_port_append_data.finish();
+            self.output_port_row_ids.finish();
+            return Ok(Event::Finished);
+        }
+
+        let mut pushed_something = false;
+        if self.output_port_append_data.can_push() {
+            if let Some(data) = self.output_data_append.take() {
+                self.output_port_append_data.push_data(Ok(data));
+                pushed_something = true;
+            }
+        }
+
+        if self.output_port_row_ids.can_push() {
+            if let Some(data) = self.output_data_row_ids.take() {
+                self.output_port_row_ids.push_data(Ok(data));
+                pushed_something = true;
+            }
+        }
+
+        if pushed_something {
+            Ok(Event::NeedConsume)
+        } else {
+            if self.input_data.is_some() {
+                return Ok(Event::Sync);
+            }
+
+            if self.input_port.has_data() {
+                if self.output_data_append.is_none() && self.output_data_row_ids.is_none() {
+                    // no pending data (being sent to down streams)
+                    self.input_data = Some(self.input_port.pull_data().unwrap()?);
+                    Ok(Event::Sync)
+                } else {
+                    // data pending
+                    Ok(Event::NeedConsume)
+                }
+            } else {
+                self.input_port.set_need_data();
+                Ok(Event::NeedData)
+            }
+        }
+    }
+
+    fn process(&mut self) -> Result<()> {
+        if let Some(data_block) = self.input_data.take() {
+            let num_rows = data_block.num_rows();
+            if num_rows == 0 {
+                return Ok(());
+            }
+
+            // columns: row id, action, values of the table columns.
+            let actions = data_block
+                .get_by_offset(1)
+                .value
+                .convert_to_full_column(&DataType::Number(NumberDataType::UInt8), num_rows)
+                .into_number()
+                .unwrap()
+                .into_u_int8()
+                .unwrap();
+
+            let mut append_bitmap = MutableBitmap::with_capacity(num_rows);
+            let mut removal_bitmap = MutableBitmap::with_capacity(num_rows);
+            for action in actions.iter() {
+                append_bitmap.push(
+                    *action == MERGE_INTO_ACTION_UPDATE || *action == MERGE_INTO_ACTION_INSERT,
+                );
+                removal_bitmap.push(
+                    *action == MERGE_INTO_ACTION_UPDATE || *action == MERGE_INTO_ACTION_DELETE,
+                );
+            }
+
+            if append_bitmap.unset_bits() != num_rows {
+                let num_columns = data_block.num_columns();
+                let values =
+                    DataBlock::new(data_block.columns()[2..num_columns].to_vec(), num_rows);
+                self.output_data_append = Some(values.filter_with_bitmap(&append_bitmap.into())?);
+            }
+
+            if removal_bitmap.unset_bits() != num_rows {
+                let row_ids = data_block.get_by_offset(0).value.convert_to_full_column(
+                    &DataType::Nullable(Box::new(DataType::Number(NumberDataType::UInt64))),
+                    num_rows,
+                );
+                let row_ids = DataBlock::new_from_columns(vec![row_ids])
+                    .filter_with_bitmap(&removal_bitmap.into())?;
+                let row_ids = row_ids
+                    .get_by_offset(0)
+                    .value
+                    .as_column()
+                    .unwrap()
+                    .remove_nullable();
+                self.output_data_row_ids = Some(DataBlock::new_from_columns(vec![row_ids]));
+            }
+        }
+
+        Ok(())
+    }
+}
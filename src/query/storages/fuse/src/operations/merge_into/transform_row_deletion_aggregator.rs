@@ -0,0 +1,391 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_base::base::tokio::sync::Semaphore;
+use common_base::base::ProgressValues;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::TrySpawn;
+use common_catalog::plan::block_idx_in_segment;
+use common_catalog::plan::split_prefix;
+use common_catalog::plan::split_row_id;
+use common_catalog::plan::Projection;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::DataBlock;
+use common_pipeline_core::pipe::PipeItem;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_transforms::processors::transforms::transform_accumulating_async::AsyncAccumulatingTransform;
+use common_pipeline_transforms::processors::transforms::AsyncAccumulatingTransformer;
+use opendal::Operator;
+use storages_common_cache::LoadParams;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::Location;
+use tracing::info;
+
+use crate::io::write_block_data;
+use crate::io::write_data;
+use crate::io::BlockBuilder;
+use crate::io::BlockReader;
+use crate::io::CompactSegmentInfoReader;
+use crate::io::MetaReaders;
+use crate::io::ReadSettings;
+use crate::io::UploadSettings;
+use crate::io::WriteSettings;
+use crate::operations::common::BlockMetaIndex;
+use crate::operations::common::MutationLogEntry;
+use crate::operations::common::MutationLogs;
+use crate::operations::common::Replacement;
+use crate::operations::common::ReplacementLogEntry;
+use crate::operations::mutation::SegmentIndex;
+use crate::FuseTable;
+
+struct DeletionContext {
+    segment_locations: Vec<Location>,
+    block_reader: Arc<BlockReader>,
+    data_accessor: Operator,
+    write_settings: WriteSettings,
+    read_settings: ReadSettings,
+    segment_reader: CompactSegmentInfoReader,
+    block_builder: BlockBuilder,
+    io_request_semaphore: Arc<Semaphore>,
+}
+
+/// Collects the row ids of the target rows removed by `MERGE INTO`, and rewrites the
+/// blocks they belong to in the `final` stage. The deleted rows of a block are kept
+/// in a bitmap, so that each affected block is read and rewritten only once.
+///
+/// Outputs [MutationLogs] logs(to be committed).
+pub struct MergeIntoRowDeletionAggregator {
+    // (segment index, block id in row id) -> offsets of deleted rows
+    deletions: HashMap<(SegmentIndex, usize), HashSet<u64>>,
+    deletion_ctx: Arc<DeletionContext>,
+}
+
+impl MergeIntoRowDeletionAggregator {
+    pub fn try_create(
+        ctx: Arc<dyn TableContext>,
+        table: &FuseTable,
+        segment_locations: Vec<Location>,
+        block_builder: BlockBuilder,
+    ) -> Result<Self> {
+        let schema = table.table_info.schema();
+        let block_reader = BlockReader::create(
+            table.get_operator(),
+            schema.clone(),
+            Projection::Columns(table.all_column_indices()),
+            ctx.clone(),
+            false,
+        )?;
+        let max_io_request = ctx.get_settings().get_max_storage_io_requests()?;
+        Ok(Self {
+            deletions: HashMap::new(),
+            deletion_ctx: Arc::new(DeletionContext {
+                segment_locations,
+                block_reader,
+                data_accessor: table.get_operator(),
+                write_settings: table.get_write_settings(),
+                read_settings: ReadSettings::from_ctx(&ctx)?,
+                segment_reader: MetaReaders::segment_info_reader(table.get_operator(), schema),
+                block_builder,
+                io_request_semaphore: Arc::new(Semaphore::new(max_io_request as usize)),
+            }),
+        })
+    }
+
+    pub fn into_pipe_item(self) -> PipeItem {
+        let input = InputPort::create();
+        let output = OutputPort::create();
+        let processor_ptr =
+            AsyncAccumulatingTransformer::create(input.clone(), output.clone(), self);
+        PipeItem::create(ProcessorPtr::create(processor_ptr), vec![input], vec![
+            output,
+        ])
+    }
+
+    fn accumulate(&mut self, data_block: &DataBlock) -> Result<()> {
+        let num_rows = data_block.num_rows();
+        if num_rows == 0 {
+            return Ok(());
+        }
+
+        let row_ids = data_block
+            .get_by_offset(0)
+            .value
+            .convert_to_full_column(&DataType::Number(NumberDataType::UInt64), num_rows)
+            .into_number()
+            .unwrap()
+            .into_u_int64()
+            .unwrap();
+        for row_id in row_ids.iter() {
+            let (prefix, offset) = split_row_id(*row_id);
+            let (segment_idx, block_id) = split_prefix(prefix);
+            let inserted = self
+                .deletions
+                .entry((segment_idx as usize, block_id as usize))
+                .or_default()
+                .insert(offset);
+            if !inserted {
+                return Err(ErrorCode::BadArguments(
+                    "MERGE INTO: a target row is matched by multiple source rows",
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn apply(&mut self) -> Result<MutationLogs> {
+        let deletion_ctx = &self.deletion_ctx;
+        let io_runtime = GlobalIORuntime::instance();
+        let mut mutation_log_handlers = Vec::with_capacity(self.deletions.len());
+        let mut segment_blocks = HashMap::new();
+        for ((segment_idx, block_id), offsets) in self.deletions.drain() {
+            if !segment_blocks.contains_key(&segment_idx) {
+                let (path, ver) =
+                    deletion_ctx
+                        .segment_locations
+                        .get(segment_idx)
+                        .ok_or_else(|| {
+                            ErrorCode::Internal(format!(
+                                "unexpected, segment (idx {}) not found, during applying deletion",
+                                segment_idx
+                            ))
+                        })?;
+                let load_param = LoadParams {
+                    location: path.clone(),
+                    len_hint: None,
+                    ver: *ver,
+                    put_cache: true,
+                };
+                let compact_segment_info = deletion_ctx.segment_reader.read(&load_param).await?;
+                segment_blocks.insert(segment_idx, compact_segment_info.block_metas()?);
+            }
+            let blocks = &segment_blocks[&segment_idx];
+            let block_idx = block_idx_in_segment(blocks.len(), block_id);
+            let block_meta = blocks[block_idx].clone();
+
+            let permit = deletion_ctx
+                .io_request_semaphore
+                .clone()
+                .acquire_owned()
+                .await
+                .map_err(|e| {
+                    ErrorCode::Internal("unexpected, io request semaphore is closed.")
+                        .add_message_back(e.to_string())
+                })?;
+            let deletion_ctx = deletion_ctx.clone();
+            let handle = io_runtime.spawn(async_backtrace::location!().frame({
+                async move {
+                    let mutation_log_entry = deletion_ctx
+                        .apply_deletion_to_data_block(segment_idx, block_idx, &block_meta, &offsets)
+                        .await?;
+                    drop(permit);
+                    Ok::<_, ErrorCode>(mutation_log_entry)
+                }
+            }));
+            mutation_log_handlers.push(handle);
+        }
+
+        let log_entries = futures::future::try_join_all(mutation_log_handlers)
+            .await
+            .map_err(|e| {
+                ErrorCode::Internal("unexpected, failed to join apply-deletion tasks.")
+                    .add_message_back(e.to_string())
+            })?;
+
+        let mut entries = Vec::with_capacity(log_entries.len());
+        for log_entry in log_entries {
+            entries.push(MutationLogEntry::Replacement(log_entry?));
+        }
+        Ok(MutationLogs { entries })
+    }
+}
+
+impl DeletionContext {
+    #[async_backtrace::framed]
+    async fn apply_deletion_to_data_block(
+        &self,
+        segment_index: SegmentIndex,
+        block_index: usize,
+        block_meta: &BlockMeta,
+        deleted_offsets: &HashSet<u64>,
+    ) -> Result<ReplacementLogEntry> {
+        info!(
+            "merge into: delete {} rows of segment idx {}, block idx {}",
+            deleted_offsets.len(),
+            segment_index,
+            block_index,
+        );
+
+        let index = BlockMetaIndex {
+            segment_idx: segment_index,
+            block_idx: block_index,
+        };
+
+        let num_rows = block_meta.row_count as usize;
+        self.block_builder
+            .ctx
+            .get_write_progress()
+            .incr(&ProgressValues {
+                rows: deleted_offsets.len(),
+                // ignore bytes.
+                bytes: 0,
+            });
+
+        let mut bitmap = MutableBitmap::from_len_set(num_rows);
+        for offset in deleted_offsets {
+            bitmap.set(*offset as usize, false);
+        }
+        // rows already marked in the deletion vector are dropped by the rewrite as well
+        if let Some(location) = &block_meta.deletion_vector_location {
+            let deletion_vector = self.block_reader.read_deletion_vector(location).await?;
+            for offset in deletion_vector.iter() {
+                bitmap.set(offset as usize, false);
+            }
+        }
+        let bitmap: Bitmap = bitmap.into();
+
+        if bitmap.unset_bits() == num_rows {
+            // whole block deletion
+            return Ok(ReplacementLogEntry {
+                index,
+                op: Replacement::Deleted,
+            });
+        }
+
+        let data_block = self.read_block(block_meta).await?;
+        let new_block = data_block.filter_with_bitmap(&bitmap)?;
+
+        // serialization and compression is cpu intensive, send them to dedicated thread pool
+        // and wait (asyncly, which will NOT block the executor thread)
+        let block_builder = self.block_builder.clone();
+        let origin_stats = block_meta.cluster_stats.clone();
+        let serialized = GlobalIORuntime::instance()
+            .spawn_blocking(move || {
+                block_builder.build(new_block, |block, generator| {
+                    let cluster_stats =
+                        generator.gen_with_origin_stats(&block, origin_stats.clone())?;
+                    Ok((cluster_stats, block))
+                })
+            })
+            .await?;
+
+        // persistent data
+        let new_block_meta = serialized.block_meta;
+        let upload_settings = UploadSettings::from_ctx(&self.block_builder.ctx)?;
+        write_block_data(
+            serialized.block_raw_data,
+            &self.data_accessor,
+            &new_block_meta.location.0,
+            &upload_settings,
+        )
+        .await?;
+        if let Some(index_state) = serialized.bloom_index_state {
+            write_data(
+                index_state.data,
+                &self.data_accessor,
+                &index_state.location.0,
+            )
+            .await?;
+        }
+        if let Some(virtual_column_state) = serialized.virtual_column_state {
+            write_data(
+                virtual_column_state.data,
+                &self.data_accessor,
+                &virtual_column_state.location,
+            )
+            .await?;
+        }
+        if let Some(inverted_index_state) = serialized.inverted_index_state {
+            write_data(
+                inverted_index_state.data,
+                &self.data_accessor,
+                &inverted_index_state.location,
+            )
+            .await?;
+        }
+        if let Some(vector_index_state) = serialized.vector_index_state {
+            write_data(
+                vector_index_state.data,
+                &self.data_accessor,
+                &vector_index_state.location,
+            )
+            .await?;
+        }
+
+        Ok(ReplacementLogEntry {
+            index,
+            op: Replacement::Replaced(Arc::new(new_block_meta)),
+        })
+    }
+
+    async fn read_block(&self, block_meta: &BlockMeta) -> Result<DataBlock> {
+        let merged_io_read_result = self
+            .block_reader
+            .read_columns_data_by_merge_io(
+                &self.read_settings,
+                &block_meta.location.0,
+                &block_meta.col_metas,
+            )
+            .await?;
+
+        // deserialize block data
+        // cpu intensive task, send them to dedicated thread pool
+        let storage_format = self.write_settings.storage_format;
+        let block_meta_ptr = block_meta.clone();
+        let reader = self.block_reader.clone();
+        GlobalIORuntime::instance()
+            .spawn_blocking(move || {
+                let column_chunks = merged_io_read_result.columns_chunks()?;
+                reader.deserialize_chunks(
+                    block_meta_ptr.location.0.as_str(),
+                    block_meta_ptr.row_count as usize,
+                    &block_meta_ptr.compression,
+                    &block_meta_ptr.col_metas,
+                    column_chunks,
+                    &storage_format,
+                )
+            })
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncAccumulatingTransform for MergeIntoRowDeletionAggregator {
+    const NAME: &'static str = "MergeIntoRowDeletionAggregator";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, data: DataBlock) -> Result<Option<DataBlock>> {
+        self.accumulate(&data)?;
+        // no partial output
+        Ok(None)
+    }
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self, _output: bool) -> Result<Option<DataBlock>> {
+        let mutation_logs = self.apply().await?;
+        Ok(Some(mutation_logs.into()))
+    }
+}
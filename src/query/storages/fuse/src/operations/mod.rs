@@ -12,13 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod agg_index;
 mod analyze;
 mod append;
+mod auto_compact;
+mod clone;
 mod commit;
 mod common;
 mod compact;
 mod delete;
 mod gc;
+mod merge;
+mod merge_into;
 mod mutation;
 mod navigate;
 mod read;
@@ -27,11 +32,17 @@ mod read_partitions;
 mod recluster;
 mod replace;
 mod replace_into;
+mod replicate;
 mod revert;
+mod table_change;
 mod truncate;
 mod update;
 pub mod util;
 
+pub use agg_index::read_agg_index_data;
+pub use agg_index::write_agg_index_data;
+pub use agg_index::AggIndexAggregator;
+pub use clone::parse_table_clones;
 pub use common::BlockMetaIndex;
 pub use common::FillInternalColumnProcessor;
 pub use compact::CompactOptions;
@@ -42,4 +53,10 @@ pub use mutation::SegmentCompactMutator;
 pub use mutation::SegmentCompactionState;
 pub use mutation::SegmentCompactor;
 pub use read::build_row_fetcher_pipeline;
+pub use replicate::copy_snapshot_files;
+pub use replicate::ReplicationResult;
+pub use replicate::SnapshotFiles;
+pub use table_change::set_table_change_notifier;
+pub use table_change::TableChangeEvent;
+pub use table_change::TableChangeNotifier;
 pub use util::column_parquet_metas;
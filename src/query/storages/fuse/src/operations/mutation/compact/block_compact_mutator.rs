@@ -282,8 +282,13 @@ impl SegmentCompactChecker {
 
     fn check_for_compact(segments: &Vec<(Location, Arc<SegmentInfo>)>) -> bool {
         segments.len() != 1
-            || (segments[0].1.summary.block_count > 1
-                && segments[0].1.summary.perfect_block_count != segments[0].1.summary.block_count)
+            || (segments[0].1.summary.perfect_block_count != segments[0].1.summary.block_count
+                && (segments[0].1.summary.block_count > 1
+                    || segments[0]
+                        .1
+                        .blocks
+                        .iter()
+                        .any(|block| block.deletion_vector_location.is_some())))
     }
 
     fn add(
@@ -352,7 +357,7 @@ impl CompactTaskBuilder {
             }
         }
 
-        let total_rows = self.total_rows + block.row_count as usize;
+        let total_rows = self.total_rows + block.live_row_count() as usize;
         let total_size = self.total_size + block.block_size as usize;
         if !thresholds.check_large_enough(total_rows, total_size) {
             // blocks < N
@@ -388,6 +393,11 @@ impl CompactTaskBuilder {
     }
 
     fn check_compact(&self, block: &Arc<BlockMeta>) -> bool {
+        // The deleted rows are removed by compaction.
+        if block.deletion_vector_location.is_some() {
+            return true;
+        }
+
         let column_ids: HashSet<ColumnId> = block.col_metas.keys().cloned().collect();
         if self.column_ids == column_ids {
             // Check if the block needs to be resort.
@@ -27,6 +27,7 @@ use common_expression::TableSchemaRefExt;
 use common_pipeline_transforms::processors::transforms::AsyncAccumulatingTransform;
 use opendal::Operator;
 use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::DistinctHLLOfColumns;
 use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::Statistics;
@@ -186,6 +187,7 @@ impl AsyncAccumulatingTransform for CompactAggregator {
             std::mem::take(&mut self.merged_statistics),
             std::mem::take(&mut self.abort_operation),
             true,
+            DistinctHLLOfColumns::new(),
         );
         Ok(Some(DataBlock::empty_with_meta(Box::new(meta))))
     }
@@ -168,9 +168,22 @@ impl Processor for CompactSource {
                             metrics_inc_compact_block_read_bytes(block.block_size);
                         }
 
-                        block_reader
+                        let data_block = block_reader
                             .read_by_meta(&settings, block.as_ref(), &storage_format)
-                            .await
+                            .await?;
+                        // remove the deleted rows.
+                        match &block.deletion_vector_location {
+                            Some(location) => {
+                                let deletion_vector =
+                                    block_reader.read_deletion_vector(location).await?;
+                                let (data_block, _) = BlockReader::apply_deletion_vector(
+                                    data_block,
+                                    &deletion_vector,
+                                )?;
+                                Ok::<_, ErrorCode>(data_block)
+                            }
+                            None => Ok(data_block),
+                        }
                     });
                 }
 
@@ -13,10 +13,13 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::sync::Arc;
 
 use common_expression::BlockMetaInfo;
 use common_expression::BlockMetaInfoDowncast;
 use common_expression::BlockMetaInfoPtr;
+use roaring::RoaringBitmap;
+use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterStatistics;
 
 use crate::operations::common::BlockMetaIndex;
@@ -31,6 +34,16 @@ pub enum ClusterStatsGenType {
 pub struct SerializeDataMeta {
     pub index: BlockMetaIndex,
     pub stats_type: ClusterStatsGenType,
+    pub deletion: Option<BlockDeletion>,
+}
+
+/// The rows of a block to be marked in its deletion vector, the data of the block
+/// is kept unchanged.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct BlockDeletion {
+    pub block_meta: Arc<BlockMeta>,
+    /// Offsets of all the deleted rows of the block, including the ones marked before.
+    pub deletion_vector: RoaringBitmap,
 }
 
 #[typetag::serde(name = "serialize_data_meta")]
@@ -53,6 +66,21 @@ impl BlockMetaInfo for SerializeDataMeta {
 
 impl SerializeDataMeta {
     pub fn create(index: BlockMetaIndex, stats_type: ClusterStatsGenType) -> BlockMetaInfoPtr {
-        Box::new(SerializeDataMeta { index, stats_type })
+        Box::new(SerializeDataMeta {
+            index,
+            stats_type,
+            deletion: None,
+        })
+    }
+
+    pub fn create_with_deletion(
+        index: BlockMetaIndex,
+        deletion: BlockDeletion,
+    ) -> BlockMetaInfoPtr {
+        Box::new(SerializeDataMeta {
+            index,
+            stats_type: ClusterStatsGenType::Generally,
+            deletion: Some(deletion),
+        })
     }
 }
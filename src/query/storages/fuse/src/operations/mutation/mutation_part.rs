@@ -20,6 +20,7 @@ use common_catalog::plan::PartInfoPtr;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use storages_common_pruner::BlockMetaIndex;
+use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterStatistics;
 
 #[derive(serde::Serialize, serde::Deserialize, PartialEq)]
@@ -28,6 +29,7 @@ pub struct MutationPartInfo {
     pub cluster_stats: Option<ClusterStatistics>,
     pub inner_part: PartInfoPtr,
     pub whole_block_mutation: bool,
+    pub block_meta: Arc<BlockMeta>,
 }
 
 #[typetag::serde(name = "mutation")]
@@ -54,12 +56,14 @@ impl MutationPartInfo {
         cluster_stats: Option<ClusterStatistics>,
         inner_part: PartInfoPtr,
         whole_block_mutation: bool,
+        block_meta: Arc<BlockMeta>,
     ) -> PartInfoPtr {
         Arc::new(Box::new(MutationPartInfo {
             index,
             cluster_stats,
             inner_part,
             whole_block_mutation,
+            block_meta,
         }))
     }
 
@@ -34,11 +34,14 @@ use common_expression::Value;
 use common_expression::ROW_ID_COL_NAME;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_sql::evaluator::BlockOperator;
+use roaring::RoaringBitmap;
+use storages_common_table_meta::meta::BlockMeta;
 
 use crate::fuse_part::FusePartInfo;
 use crate::io::BlockReader;
 use crate::io::ReadSettings;
 use crate::operations::common::BlockMetaIndex;
+use crate::operations::mutation::mutation_meta::BlockDeletion;
 use crate::operations::mutation::mutation_meta::ClusterStatsGenType;
 use crate::operations::mutation::MutationPartInfo;
 use crate::operations::mutation::SerializeDataMeta;
@@ -85,9 +88,14 @@ pub struct MutationSource {
     storage_format: FuseStorageFormat,
     action: MutationAction,
     query_row_id_col: bool,
+    // mark the deleted rows in the deletion vector instead of rewriting the block.
+    enable_deletion_vector: bool,
 
     index: BlockMetaIndex,
     stats_type: ClusterStatsGenType,
+    block_meta: Option<Arc<BlockMeta>>,
+    // the deletion vector of the block being mutated, if any.
+    deletion_vector: Option<RoaringBitmap>,
 }
 
 impl MutationSource {
@@ -102,6 +110,7 @@ impl MutationSource {
         operators: Vec<BlockOperator>,
         storage_format: FuseStorageFormat,
         query_row_id_col: bool,
+        enable_deletion_vector: bool,
     ) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(Box::new(MutationSource {
             state: State::ReadData(None),
@@ -114,8 +123,11 @@ impl MutationSource {
             storage_format,
             action,
             query_row_id_col,
+            enable_deletion_vector,
             index: BlockMetaIndex::default(),
             stats_type: ClusterStatsGenType::Generally,
+            block_meta: None,
+            deletion_vector: None,
         })))
     }
 }
@@ -174,13 +186,22 @@ impl Processor for MutationSource {
 
     fn process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::Finish) {
-            State::FilterData(part, read_res) => {
+            State::FilterData(part, mut read_res) => {
+                self.deletion_vector = read_res.deletion_vector.take();
                 let chunks = read_res.columns_chunks()?;
                 let mut data_block = self.block_reader.deserialize_chunks_with_part_info(
                     part.clone(),
                     chunks,
                     &self.storage_format,
                 )?;
+                // offsets of the remaining rows in the block, if some rows are marked deleted.
+                let mut offsets = None;
+                if let Some(deletion_vector) = &self.deletion_vector {
+                    let (block, remain_offsets) =
+                        BlockReader::apply_deletion_vector(data_block, deletion_vector)?;
+                    data_block = block;
+                    offsets = Some(remain_offsets);
+                }
                 let num_rows = data_block.num_rows();
 
                 if let Some(filter) = self.filter.as_ref() {
@@ -194,7 +215,7 @@ impl Processor for MutationSource {
                             block_location: block_meta.block_location.clone(),
                             segment_location: block_meta.segment_location.clone(),
                             snapshot_location: "".to_string(),
-                            offsets: None,
+                            offsets: offsets.clone(),
                         };
                         let internal_col = InternalColumn {
                             column_name: ROW_ID_COL_NAME.to_string(),
@@ -245,6 +266,32 @@ impl Processor for MutationSource {
                                         self.ctx.get_partition(),
                                         DataBlock::empty_with_meta(meta),
                                     );
+                                } else if self.enable_deletion_vector {
+                                    // mark the deleted rows, the block is kept unchanged.
+                                    let predicate_col = predicates.into_column().unwrap();
+                                    let mut deletion_vector =
+                                        self.deletion_vector.take().unwrap_or_default();
+                                    for (idx, deleted) in predicate_col.iter().enumerate() {
+                                        if deleted {
+                                            let offset = match &offsets {
+                                                Some(offsets) => offsets[idx],
+                                                None => idx,
+                                            };
+                                            deletion_vector.insert(offset as u32);
+                                        }
+                                    }
+                                    let deletion = BlockDeletion {
+                                        block_meta: self.block_meta.clone().unwrap(),
+                                        deletion_vector,
+                                    };
+                                    let meta = SerializeDataMeta::create_with_deletion(
+                                        self.index.clone(),
+                                        deletion,
+                                    );
+                                    self.state = State::Output(
+                                        self.ctx.get_partition(),
+                                        DataBlock::empty_with_meta(meta),
+                                    );
                                 } else {
                                     let predicate_col = predicates.into_column().unwrap();
                                     let filter = predicate_col.not();
@@ -302,11 +349,16 @@ impl Processor for MutationSource {
             } => {
                 if let Some(remain_reader) = self.remain_reader.as_ref() {
                     let chunks = merged_io_read_result.columns_chunks()?;
-                    let remain_block = remain_reader.deserialize_chunks_with_part_info(
+                    let mut remain_block = remain_reader.deserialize_chunks_with_part_info(
                         part,
                         chunks,
                         &self.storage_format,
                     )?;
+                    if let Some(deletion_vector) = self.deletion_vector.take() {
+                        let (block, _) =
+                            BlockReader::apply_deletion_vector(remain_block, &deletion_vector)?;
+                        remain_block = block;
+                    }
 
                     match self.action {
                         MutationAction::Deletion => {
@@ -359,6 +411,7 @@ impl Processor for MutationSource {
                 if matches!(self.action, MutationAction::Deletion) {
                     self.stats_type = ClusterStatsGenType::WithOrigin(part.cluster_stats.clone());
                 }
+                self.block_meta = Some(part.block_meta.clone());
 
                 let inner_part = part.inner_part.clone();
                 let fuse_part = FusePartInfo::from_part(&inner_part)?;
@@ -366,7 +419,7 @@ impl Processor for MutationSource {
                 if part.whole_block_mutation && matches!(self.action, MutationAction::Deletion) {
                     // whole block deletion.
                     let progress_values = ProgressValues {
-                        rows: fuse_part.nums_rows,
+                        rows: part.block_meta.live_row_count() as usize,
                         bytes: 0,
                     };
                     self.ctx.get_write_progress().incr(&progress_values);
@@ -375,7 +428,7 @@ impl Processor for MutationSource {
                     self.state =
                         State::Output(self.ctx.get_partition(), DataBlock::empty_with_meta(meta));
                 } else {
-                    let read_res = self
+                    let mut read_res = self
                         .block_reader
                         .read_columns_data_by_merge_io(
                             &settings,
@@ -383,6 +436,10 @@ impl Processor for MutationSource {
                             &fuse_part.columns_meta,
                         )
                         .await?;
+                    if let Some(location) = &fuse_part.deletion_vector_location {
+                        read_res.deletion_vector =
+                            Some(self.block_reader.read_deletion_vector(location).await?);
+                    }
                     self.state = State::FilterData(inner_part, read_res);
                 }
             }
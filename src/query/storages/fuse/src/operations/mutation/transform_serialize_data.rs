@@ -24,10 +24,14 @@ use common_expression::DataBlock;
 use common_pipeline_core::processors::port::InputPort;
 use common_pipeline_core::processors::processor::ProcessorPtr;
 use opendal::Operator;
+use storages_common_table_meta::meta::BlockMeta;
 
+use crate::io::write_block_data;
 use crate::io::write_data;
 use crate::io::BlockBuilder;
 use crate::io::BlockSerialization;
+use crate::io::DeletionVectorWriter;
+use crate::io::UploadSettings;
 use crate::metrics::metrics_inc_block_index_write_bytes;
 use crate::metrics::metrics_inc_block_index_write_milliseconds;
 use crate::metrics::metrics_inc_block_index_write_nums;
@@ -39,6 +43,7 @@ use crate::operations::common::MutationLogEntry;
 use crate::operations::common::MutationLogs;
 use crate::operations::common::Replacement;
 use crate::operations::common::ReplacementLogEntry;
+use crate::operations::mutation::mutation_meta::BlockDeletion;
 use crate::operations::mutation::mutation_meta::ClusterStatsGenType;
 use crate::operations::mutation::SerializeDataMeta;
 use crate::pipelines::processors::port::OutputPort;
@@ -51,6 +56,7 @@ enum State {
     Consume,
     NeedSerialize(DataBlock, ClusterStatsGenType),
     Serialized(BlockSerialization),
+    WriteDeletionVector(BlockDeletion),
     Output(Replacement),
 }
 
@@ -63,6 +69,7 @@ pub struct SerializeDataTransform {
     block_builder: BlockBuilder,
 
     dal: Operator,
+    deletion_vector_writer: DeletionVectorWriter,
 
     index: BlockMetaIndex,
 }
@@ -90,6 +97,10 @@ impl SerializeDataTransform {
             output_data: None,
             block_builder,
             dal: table.get_operator(),
+            deletion_vector_writer: DeletionVectorWriter::create(
+                table.get_operator(),
+                table.meta_location_generator().clone(),
+            ),
             index: BlockMetaIndex::default(),
         })))
     }
@@ -110,7 +121,10 @@ impl Processor for SerializeDataTransform {
             return Ok(Event::Sync);
         }
 
-        if matches!(self.state, State::Serialized(_)) {
+        if matches!(
+            self.state,
+            State::Serialized(_) | State::WriteDeletionVector(_)
+        ) {
             return Ok(Event::Async);
         }
 
@@ -142,6 +156,10 @@ impl Processor for SerializeDataTransform {
         if let Some(meta) = meta {
             let meta = SerializeDataMeta::downcast_ref_from(&meta).unwrap();
             self.index = meta.index.clone();
+            if let Some(deletion) = &meta.deletion {
+                self.state = State::WriteDeletionVector(deletion.clone());
+                return Ok(Event::Async);
+            }
             if input_data.is_empty() {
                 self.state = State::Output(Replacement::Deleted);
             } else {
@@ -193,7 +211,8 @@ impl Processor for SerializeDataTransform {
                 let raw_block_data = serialized.block_raw_data;
                 let data_size = raw_block_data.len();
                 let path = serialized.block_meta.location.0.as_str();
-                write_data(raw_block_data, &self.dal, path).await?;
+                let upload_settings = UploadSettings::from_ctx(&self.block_builder.ctx)?;
+                write_block_data(raw_block_data, &self.dal, path, &upload_settings).await?;
 
                 // Perf.
                 {
@@ -221,9 +240,48 @@ impl Processor for SerializeDataTransform {
                         );
                     }
                 }
+                // write shredded variant columns.
+                if let Some(virtual_column_state) = serialized.virtual_column_state {
+                    write_data(
+                        virtual_column_state.data,
+                        &self.dal,
+                        &virtual_column_state.location,
+                    )
+                    .await?;
+                }
+                // write inverted index.
+                if let Some(inverted_index_state) = serialized.inverted_index_state {
+                    write_data(
+                        inverted_index_state.data,
+                        &self.dal,
+                        &inverted_index_state.location,
+                    )
+                    .await?;
+                }
+                // write vector index.
+                if let Some(vector_index_state) = serialized.vector_index_state {
+                    write_data(
+                        vector_index_state.data,
+                        &self.dal,
+                        &vector_index_state.location,
+                    )
+                    .await?;
+                }
                 let block_meta = Arc::new(serialized.block_meta);
                 self.state = State::Output(Replacement::Replaced(block_meta));
             }
+            State::WriteDeletionVector(deletion) => {
+                let location = self
+                    .deletion_vector_writer
+                    .write(&deletion.deletion_vector)
+                    .await?;
+                let block_meta = BlockMeta {
+                    deletion_vector_location: Some(location),
+                    deleted_row_count: deletion.deletion_vector.len(),
+                    ..deletion.block_meta.as_ref().clone()
+                };
+                self.state = State::Output(Replacement::Replaced(Arc::new(block_meta)));
+            }
             _ => return Err(ErrorCode::Internal("It's a bug.")),
         }
         Ok(())
@@ -143,7 +143,7 @@ impl FuseTable {
         ctx: &Arc<dyn TableContext>,
         instant: Option<NavigationPoint>,
     ) -> Result<(Arc<FuseTable>, Vec<String>)> {
-        let retention = Duration::hours(ctx.get_settings().get_retention_period()? as i64);
+        let retention = Duration::hours(self.get_data_retention_period(ctx.as_ref())? as i64);
         let root_snapshot = if let Some(snapshot) = self.read_table_snapshot().await? {
             snapshot
         } else {
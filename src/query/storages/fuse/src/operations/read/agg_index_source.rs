@@ -0,0 +1,212 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::AggIndexInfo;
+use common_catalog::plan::AggIndexMerge;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartInfoPtr;
+use common_catalog::plan::StealablePartitions;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::BooleanType;
+use common_expression::types::DataType;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::Pipeline;
+use common_pipeline_core::SourcePipeBuilder;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+use opendal::Operator;
+
+use crate::fuse_part::FusePartInfo;
+use crate::io::BlockReader;
+use crate::io::ReadSettings;
+use crate::io::TableMetaLocationGenerator;
+use crate::metrics::metrics_inc_pruning_runtime_filter_nums;
+use crate::operations::agg_index::read_agg_index_data;
+use crate::operations::agg_index::AggIndexAggregator;
+use crate::operations::read::fuse_source::dispatch_partitions;
+use crate::FuseStorageFormat;
+
+/// Build the pipeline reading the partial aggregate states of aggregating index,
+/// the output are the group items followed by the states of the query.
+#[allow(clippy::too_many_arguments)]
+pub fn build_fuse_agg_index_source_pipeline(
+    ctx: Arc<dyn TableContext>,
+    pipeline: &mut Pipeline,
+    operator: Operator,
+    storage_format: FuseStorageFormat,
+    block_reader: Arc<BlockReader>,
+    plan: &DataSourcePlan,
+    agg_index: &AggIndexInfo,
+    merge: &AggIndexMerge,
+    max_io_requests: usize,
+) -> Result<()> {
+    let max_threads = ctx.get_settings().get_max_threads()? as usize;
+    let func_ctx = ctx.get_function_context()?;
+    let filter = agg_index
+        .filter
+        .as_ref()
+        .map(|filter| filter.as_expr(&BUILTIN_FUNCTIONS));
+
+    let partitions = dispatch_partitions(ctx.clone(), plan, max_io_requests);
+    let partitions = StealablePartitions::new(partitions, ctx.clone());
+
+    let mut source_builder = SourcePipeBuilder::create();
+    for i in 0..max_io_requests {
+        let aggregator = AggIndexAggregator::try_create(
+            func_ctx.clone(),
+            &block_reader.data_schema(),
+            &merge.aggregation,
+        )?;
+        let output = OutputPort::create();
+        source_builder.add_source(
+            output.clone(),
+            AsyncSourcer::create(ctx.clone(), output, AggIndexSource {
+                id: i,
+                ctx: ctx.clone(),
+                func_ctx: func_ctx.clone(),
+                partitions: partitions.clone(),
+                operator: operator.clone(),
+                storage_format,
+                block_reader: block_reader.clone(),
+                index_id: agg_index.index_id,
+                filter: filter.clone(),
+                projection: merge.projection.clone(),
+                output_types: aggregator.output_types(),
+                aggregator,
+            })?,
+        );
+    }
+    pipeline.add_pipe(source_builder.finalize());
+    pipeline.resize(std::cmp::min(max_threads, max_io_requests))
+}
+
+/// Reads the index data of each part, the parts not indexed yet are aggregated from
+/// their raw data.
+pub struct AggIndexSource {
+    id: usize,
+    ctx: Arc<dyn TableContext>,
+    func_ctx: FunctionContext,
+    partitions: StealablePartitions,
+    operator: Operator,
+    storage_format: FuseStorageFormat,
+    // Reads the columns of the partial aggregation.
+    block_reader: Arc<BlockReader>,
+    index_id: u64,
+    // Filter of the index data, the columns are the offsets in the index data.
+    filter: Option<Expr>,
+    // Offsets of the output columns in the index data.
+    projection: Vec<usize>,
+    output_types: Vec<DataType>,
+    aggregator: AggIndexAggregator,
+}
+
+impl AggIndexSource {
+    #[async_backtrace::framed]
+    async fn read_index(&self, part: &FusePartInfo) -> Result<Option<DataBlock>> {
+        // The index data are computed from all the rows of the block.
+        if part.deletion_vector_location.is_some() || part.range().is_some() {
+            return Ok(None);
+        }
+
+        let location = TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
+            &part.location,
+            self.index_id,
+        );
+        let block = match read_agg_index_data(&self.operator, &location).await? {
+            Some(block) => block,
+            None => return Ok(None),
+        };
+        let matched = self
+            .projection
+            .iter()
+            .zip(self.output_types.iter())
+            .all(|(i, ty)| *i < block.num_columns() && block.get_by_offset(*i).data_type == *ty);
+        if !matched {
+            return Ok(None);
+        }
+
+        let block = match &self.filter {
+            Some(filter) => {
+                let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+                let predicate = evaluator
+                    .run(filter)?
+                    .try_downcast::<BooleanType>()
+                    .unwrap();
+                block.filter_boolean_value(&predicate)?
+            }
+            None => block,
+        };
+        let columns = self
+            .projection
+            .iter()
+            .map(|i| block.get_by_offset(*i).clone())
+            .collect();
+        Ok(Some(DataBlock::new(columns, block.num_rows())))
+    }
+
+    #[async_backtrace::framed]
+    async fn aggregate_part(&self, part: PartInfoPtr) -> Result<DataBlock> {
+        let fuse_part = FusePartInfo::from_part(&part)?;
+        let settings = ReadSettings::from_ctx(&self.ctx)?;
+        let merge_io_result = self
+            .block_reader
+            .read_columns_data_by_merge_io(&settings, &fuse_part.location, &fuse_part.columns_meta)
+            .await?;
+        let chunks = merge_io_result.columns_chunks()?;
+        let mut block = self.block_reader.deserialize_chunks_with_part_info(
+            part.clone(),
+            chunks,
+            &self.storage_format,
+        )?;
+        if let Some(location) = &fuse_part.deletion_vector_location {
+            let deletion_vector = self.block_reader.read_deletion_vector(location).await?;
+            (block, _) = BlockReader::apply_deletion_vector(block, &deletion_vector)?;
+        }
+        self.aggregator.aggregate(block)
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for AggIndexSource {
+    const NAME: &'static str = "AggIndexSource";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        while let Some(part) = self.partitions.steal_one(self.id) {
+            let fuse_part = FusePartInfo::from_part(&part)?;
+            if !self.block_reader.should_read_part(fuse_part) {
+                metrics_inc_pruning_runtime_filter_nums(1);
+                continue;
+            }
+
+            let block = match self.read_index(fuse_part).await? {
+                Some(block) => block,
+                None => self.aggregate_part(part.clone()).await?,
+            };
+            if block.num_rows() > 0 {
+                return Ok(Some(block));
+            }
+        }
+        Ok(None)
+    }
+}
@@ -126,6 +126,7 @@ pub fn build_fuse_parquet_source_pipeline(
     plan: &DataSourcePlan,
     mut max_threads: usize,
     mut max_io_requests: usize,
+    max_prefetch_blocks: usize,
 ) -> Result<()> {
     (max_threads, max_io_requests) =
         adjust_threads_and_request(false, max_threads, max_io_requests, plan);
@@ -147,6 +148,7 @@ pub fn build_fuse_parquet_source_pipeline(
                         output,
                         block_reader.clone(),
                         partitions.clone(),
+                        max_prefetch_blocks,
                     )?,
                 );
             }
@@ -168,6 +170,7 @@ pub fn build_fuse_parquet_source_pipeline(
                         output,
                         block_reader.clone(),
                         partitions.clone(),
+                        max_prefetch_blocks,
                     )?,
                 );
             }
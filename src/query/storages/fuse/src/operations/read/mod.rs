@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod agg_index_source;
 mod fuse_rows_fetcher;
 pub mod fuse_source;
 mod native_data_source;
@@ -23,6 +24,7 @@ mod parquet_data_source_deserializer;
 mod parquet_data_source_reader;
 mod parquet_rows_fetcher;
 
+pub use agg_index_source::build_fuse_agg_index_source_pipeline;
 pub use fuse_rows_fetcher::build_row_fetcher_pipeline;
 pub use fuse_source::build_fuse_parquet_source_pipeline;
 pub use parquet_data_source::DataSourceMeta;
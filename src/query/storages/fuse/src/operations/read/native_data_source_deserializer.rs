@@ -65,6 +65,8 @@ use super::fuse_source::fill_internal_column_meta;
 use crate::fuse_part::FusePartInfo;
 use crate::io::BlockReader;
 use crate::io::NativeReaderExt;
+use crate::metrics::metrics_inc_block_read_output_bytes;
+use crate::metrics::metrics_inc_block_read_output_rows;
 use crate::metrics::metrics_inc_pruning_prewhere_nums;
 use crate::operations::read::native_data_source::DataChunks;
 use crate::operations::read::native_data_source::NativeDataSourceMeta;
@@ -262,6 +264,8 @@ impl NativeDeserializeDataTransform {
             bytes: data_block.memory_size(),
         };
         self.scan_progress.incr(&progress_values);
+        metrics_inc_block_read_output_rows(rows as u64);
+        metrics_inc_block_read_output_bytes(progress_values.bytes as u64);
         self.output_data = Some(data_block);
         Ok(())
     }
@@ -782,11 +786,6 @@ impl Processor for NativeDeserializeDataTransform {
 
             let block = self.block_reader.build_block(arrays.clone(), None)?;
             let origin_num_rows = block.num_rows();
-            let block = if let Some(filter) = &filter {
-                block.filter_boolean_value(filter)?
-            } else {
-                block
-            };
 
             // Step 6: fill missing field default value if need
             let mut block = if need_to_fill_data {
@@ -796,8 +795,14 @@ impl Processor for NativeDeserializeDataTransform {
                 block
             };
 
-            // Step 7: Add optional virtual columns
+            // Step 7: Add optional virtual columns before filtering,
+            // the arrays of the virtual columns read from storage are not filtered.
             self.add_virtual_columns(arrays, &self.src_schema, &self.virtual_columns, &mut block)?;
+            let block = if let Some(filter) = &filter {
+                block.filter_boolean_value(filter)?
+            } else {
+                block
+            };
 
             // Step 8: Fill `InternalColumnMeta` as `DataBlock.meta` if query internal columns,
             // `FillInternalColumnProcessor` will generate internal columns using `InternalColumnMeta` in next pipeline.
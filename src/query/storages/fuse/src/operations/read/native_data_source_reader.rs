@@ -28,7 +28,9 @@ use common_pipeline_core::processors::Processor;
 use common_pipeline_sources::SyncSource;
 use common_pipeline_sources::SyncSourcer;
 
+use crate::fuse_part::FusePartInfo;
 use crate::io::BlockReader;
+use crate::metrics::metrics_inc_pruning_runtime_filter_nums;
 use crate::operations::read::native_data_source::DataChunks;
 use crate::operations::read::native_data_source::NativeDataSourceMeta;
 
@@ -87,18 +89,45 @@ impl ReadNativeDataSource<false> {
     }
 }
 
+impl<const BLOCKING_IO: bool> ReadNativeDataSource<BLOCKING_IO> {
+    fn prune_parts_by_runtime_filter(&self, parts: Vec<PartInfoPtr>) -> Result<Vec<PartInfoPtr>> {
+        let num_parts = parts.len();
+        let mut kept = Vec::with_capacity(num_parts);
+        for part in parts {
+            if self
+                .block_reader
+                .should_read_part(FusePartInfo::from_part(&part)?)
+            {
+                kept.push(part);
+            }
+        }
+
+        metrics_inc_pruning_runtime_filter_nums((num_parts - kept.len()) as u64);
+        Ok(kept)
+    }
+}
+
 impl SyncSource for ReadNativeDataSource<true> {
     const NAME: &'static str = "SyncReadNativeDataSource";
 
     fn generate(&mut self) -> Result<Option<DataBlock>> {
-        match self.partitions.steal_one(self.id) {
-            None => Ok(None),
-            Some(part) => Ok(Some(DataBlock::empty_with_meta(
+        while let Some(part) = self.partitions.steal_one(self.id) {
+            if !self
+                .block_reader
+                .should_read_part(FusePartInfo::from_part(&part)?)
+            {
+                metrics_inc_pruning_runtime_filter_nums(1);
+                continue;
+            }
+
+            return Ok(Some(DataBlock::empty_with_meta(
                 NativeDataSourceMeta::create(vec![part.clone()], vec![
                     self.block_reader.sync_read_native_columns_data(part)?,
                 ]),
-            ))),
+            )));
         }
+
+        Ok(None)
     }
 }
 
@@ -140,6 +169,12 @@ impl Processor for ReadNativeDataSource<false> {
         let parts = self.partitions.steal(self.id, self.batch_size);
 
         if !parts.is_empty() {
+            let parts = self.prune_parts_by_runtime_filter(parts)?;
+            if parts.is_empty() {
+                // All the stolen parts are pruned, try to steal more in the next round.
+                return Ok(());
+            }
+
             let mut chunks = Vec::with_capacity(parts.len());
             for part in &parts {
                 let part = part.clone();
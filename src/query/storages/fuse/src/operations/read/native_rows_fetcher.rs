@@ -162,6 +162,7 @@ impl<const BLOCKING_IO: bool> NativeRowsFetcher<BLOCKING_IO> {
                 None,
                 None,
                 &self.projection,
+                None,
             );
 
             self.part_map.insert(prefix, (part_info, page_size));
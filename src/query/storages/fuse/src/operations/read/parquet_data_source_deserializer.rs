@@ -34,7 +34,10 @@ use crate::fuse_part::FusePartInfo;
 use crate::io::BlockReader;
 use crate::io::MergeIOReadResult;
 use crate::io::UncompressedBuffer;
+use crate::metrics::metrics_inc_block_read_output_bytes;
+use crate::metrics::metrics_inc_block_read_output_rows;
 use crate::metrics::metrics_inc_remote_io_deserialize_milliseconds;
+use crate::metrics::metrics_observe_read_amplification;
 use crate::operations::read::parquet_data_source::DataSourceMeta;
 
 pub struct DeserializeDataTransform {
@@ -149,9 +152,28 @@ impl Processor for DeserializeDataTransform {
                 Some(self.uncompressed_buffer.clone()),
             )?;
 
+            // Remove the rows marked as deleted, the offsets of the remaining rows
+            // are kept to generate the internal columns.
+            let (data_block, offsets) = match &read_res.deletion_vector {
+                Some(deletion_vector) => {
+                    let (data_block, offsets) =
+                        BlockReader::apply_deletion_vector(data_block, deletion_vector)?;
+                    (data_block, Some(offsets))
+                }
+                None => (data_block, None),
+            };
+
             // Perf.
             {
                 metrics_inc_remote_io_deserialize_milliseconds(start.elapsed().as_millis() as u64);
+                let output_bytes = data_block.memory_size() as u64;
+                metrics_inc_block_read_output_rows(data_block.num_rows() as u64);
+                metrics_inc_block_read_output_bytes(output_bytes);
+                if read_res.remote_read_bytes > 0 && output_bytes > 0 {
+                    metrics_observe_read_amplification(
+                        read_res.remote_read_bytes as f64 / output_bytes as f64,
+                    );
+                }
             }
 
             let progress_values = ProgressValues {
@@ -163,7 +185,7 @@ impl Processor for DeserializeDataTransform {
             // Fill `BlockMetaIndex` as `DataBlock.meta` if query internal columns,
             // `FillInternalColumnProcessor` will generate internal columns using `BlockMetaIndex` in next pipeline.
             if self.block_reader.query_internal_columns() {
-                let data_block = fill_internal_column_meta(data_block, part, None)?;
+                let data_block = fill_internal_column_meta(data_block, part, offsets)?;
                 self.output_data = Some(data_block);
             } else {
                 self.output_data = Some(data_block);
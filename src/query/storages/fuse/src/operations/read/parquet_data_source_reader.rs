@@ -15,7 +15,6 @@
 use std::any::Any;
 use std::sync::Arc;
 
-use common_base::base::tokio;
 use common_catalog::plan::PartInfoPtr;
 use common_catalog::plan::StealablePartitions;
 use common_catalog::table_context::TableContext;
@@ -29,8 +28,10 @@ use common_pipeline_sources::SyncSource;
 use common_pipeline_sources::SyncSourcer;
 
 use crate::fuse_part::FusePartInfo;
+use crate::io::BlockPrefetcher;
 use crate::io::BlockReader;
 use crate::io::ReadSettings;
+use crate::metrics::metrics_inc_pruning_runtime_filter_nums;
 use crate::operations::read::parquet_data_source::DataSourceMeta;
 use crate::MergeIOReadResult;
 
@@ -43,6 +44,8 @@ pub struct ReadParquetDataSource<const BLOCKING_IO: bool> {
     output: Arc<OutputPort>,
     output_data: Option<(Vec<PartInfoPtr>, Vec<MergeIOReadResult>)>,
     partitions: StealablePartitions,
+    // Only used by the async source, the next parts are read while the current ones are deserialized.
+    prefetcher: Option<BlockPrefetcher>,
 }
 
 impl<const BLOCKING_IO: bool> ReadParquetDataSource<BLOCKING_IO> {
@@ -52,6 +55,7 @@ impl<const BLOCKING_IO: bool> ReadParquetDataSource<BLOCKING_IO> {
         output: Arc<OutputPort>,
         block_reader: Arc<BlockReader>,
         partitions: StealablePartitions,
+        max_prefetch_blocks: usize,
     ) -> Result<ProcessorPtr> {
         let batch_size = ctx.get_settings().get_storage_fetch_part_num()? as usize;
 
@@ -64,8 +68,16 @@ impl<const BLOCKING_IO: bool> ReadParquetDataSource<BLOCKING_IO> {
                 finished: false,
                 output_data: None,
                 partitions,
+                prefetcher: None,
             })
         } else {
+            // The prefetch window holds at least one batch.
+            let max_prefetch_blocks = std::cmp::max(max_prefetch_blocks, batch_size);
+            let prefetcher = BlockPrefetcher::create(
+                block_reader.clone(),
+                ReadSettings::from_ctx(&ctx)?,
+                max_prefetch_blocks,
+            );
             Ok(ProcessorPtr::create(Box::new(ReadParquetDataSource::<
                 false,
             > {
@@ -76,25 +88,51 @@ impl<const BLOCKING_IO: bool> ReadParquetDataSource<BLOCKING_IO> {
                 finished: false,
                 output_data: None,
                 partitions,
+                prefetcher: Some(prefetcher),
             })))
         }
     }
+
+    fn prune_parts_by_runtime_filter(&self, parts: Vec<PartInfoPtr>) -> Result<Vec<PartInfoPtr>> {
+        let num_parts = parts.len();
+        let mut kept = Vec::with_capacity(num_parts);
+        for part in parts {
+            if self
+                .block_reader
+                .should_read_part(FusePartInfo::from_part(&part)?)
+            {
+                kept.push(part);
+            }
+        }
+
+        metrics_inc_pruning_runtime_filter_nums((num_parts - kept.len()) as u64);
+        Ok(kept)
+    }
 }
 
 impl SyncSource for ReadParquetDataSource<true> {
     const NAME: &'static str = "SyncReadParquetDataSource";
 
     fn generate(&mut self) -> Result<Option<DataBlock>> {
-        match self.partitions.steal_one(self.id) {
-            None => Ok(None),
-            Some(part) => Ok(Some(DataBlock::empty_with_meta(DataSourceMeta::create(
+        while let Some(part) = self.partitions.steal_one(self.id) {
+            if !self
+                .block_reader
+                .should_read_part(FusePartInfo::from_part(&part)?)
+            {
+                metrics_inc_pruning_runtime_filter_nums(1);
+                continue;
+            }
+
+            return Ok(Some(DataBlock::empty_with_meta(DataSourceMeta::create(
                 vec![part.clone()],
                 vec![self.block_reader.sync_read_columns_data_by_merge_io(
                     &ReadSettings::from_ctx(&self.partitions.ctx)?,
                     part,
                 )?],
-            )))),
+            ))));
         }
+
+        Ok(None)
     }
 }
 
@@ -134,37 +172,52 @@ impl Processor for ReadParquetDataSource<false> {
 
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
-        let parts = self.partitions.steal(self.id, self.batch_size);
-
-        if !parts.is_empty() {
-            let mut chunks = Vec::with_capacity(parts.len());
-            for part in &parts {
-                let part = part.clone();
-                let block_reader = self.block_reader.clone();
-                let settings = ReadSettings::from_ctx(&self.partitions.ctx)?;
-
-                chunks.push(async move {
-                    tokio::spawn(async_backtrace::location!().frame(async move {
-                        let part = FusePartInfo::from_part(&part)?;
-
-                        block_reader
-                            .read_columns_data_by_merge_io(
-                                &settings,
-                                &part.location,
-                                &part.columns_meta,
-                            )
-                            .await
-                    }))
-                    .await
-                    .unwrap()
-                });
+        self.fill_prefetch_window()?;
+
+        let prefetcher = self.prefetcher.as_mut().unwrap();
+        let mut parts = Vec::with_capacity(self.batch_size);
+        let mut chunks = Vec::with_capacity(self.batch_size);
+        while parts.len() < self.batch_size {
+            match prefetcher.next().await? {
+                None => break,
+                Some((part, chunk)) => {
+                    parts.push(part);
+                    chunks.push(chunk);
+                }
             }
+        }
 
-            self.output_data = Some((parts, futures::future::try_join_all(chunks).await?));
+        if parts.is_empty() {
+            self.finished = true;
             return Ok(());
         }
 
-        self.finished = true;
+        // Keep fetching the next parts while the current ones are deserialized.
+        self.fill_prefetch_window()?;
+        self.output_data = Some((parts, chunks));
         Ok(())
     }
 }
+
+impl ReadParquetDataSource<false> {
+    fn fill_prefetch_window(&mut self) -> Result<()> {
+        loop {
+            let capacity = self.prefetcher.as_ref().unwrap().remaining_capacity();
+            if capacity == 0 {
+                return Ok(());
+            }
+
+            let parts = self.partitions.steal(self.id, capacity);
+            if parts.is_empty() {
+                return Ok(());
+            }
+
+            // The pruned parts are skipped, try to steal more.
+            let parts = self.prune_parts_by_runtime_filter(parts)?;
+            let prefetcher = self.prefetcher.as_mut().unwrap();
+            for part in parts {
+                prefetcher.prefetch(part);
+            }
+        }
+    }
+}
@@ -147,6 +147,7 @@ impl<const BLOCKING_IO: bool> ParquetRowsFetcher<BLOCKING_IO> {
                 None,
                 None,
                 &self.projection,
+                None,
             );
 
             self.part_map.insert(prefix, part_info);
@@ -32,11 +32,16 @@ use storages_common_index::RangeIndex;
 
 use crate::fuse_lazy_part::FuseLazyPartInfo;
 use crate::io::BlockReader;
+use crate::operations::read::build_fuse_agg_index_source_pipeline;
 use crate::operations::read::build_fuse_parquet_source_pipeline;
 use crate::operations::read::fuse_source::build_fuse_native_source_pipeline;
+use crate::operations::AggIndexAggregator;
+use crate::pruning::RuntimeFilterPruner;
 use crate::pruning::SegmentLocation;
 use crate::FuseStorageFormat;
 use crate::FuseTable;
+use crate::DEFAULT_MAX_PREFETCH_BLOCKS;
+use crate::FUSE_OPT_KEY_MAX_PREFETCH_BLOCKS;
 
 impl FuseTable {
     pub fn create_block_reader(
@@ -59,13 +64,24 @@ impl FuseTable {
     fn build_block_reader(
         &self,
         plan: &DataSourcePlan,
+        projection: Projection,
         ctx: Arc<dyn TableContext>,
     ) -> Result<Arc<BlockReader>> {
-        self.create_block_reader(
-            PushDownInfo::projection_of_push_downs(&self.table_info.schema(), &plan.push_downs),
-            plan.query_internal_columns,
-            ctx,
-        )
+        let block_reader =
+            self.create_block_reader(projection, plan.query_internal_columns, ctx.clone())?;
+
+        match plan.table_index {
+            None => Ok(block_reader),
+            Some(table_index) => {
+                // Runtime filters, skip the parts which can not match the build side of hash joins.
+                let runtime_filter = RuntimeFilterPruner::try_create(
+                    ctx.clone(),
+                    table_index,
+                    self.table_info.schema(),
+                )?;
+                Ok(block_reader.with_runtime_filter(Arc::new(runtime_filter)))
+            }
+        }
     }
 
     fn adjust_io_request(&self, ctx: &Arc<dyn TableContext>) -> Result<usize> {
@@ -180,9 +196,41 @@ impl FuseTable {
             });
         }
 
-        let block_reader = self.build_block_reader(plan, ctx.clone())?;
         let max_io_requests = self.adjust_io_request(&ctx)?;
 
+        // Merge the partial aggregate states of aggregating index.
+        if let Some((agg_index, merge)) = plan
+            .push_downs
+            .as_ref()
+            .and_then(|p| p.agg_index.as_ref())
+            .and_then(|agg_index| Some((agg_index, agg_index.merge.as_ref()?)))
+        {
+            let projection =
+                AggIndexAggregator::projection(&self.table_info.schema(), &merge.aggregation)?;
+            let block_reader = self.build_block_reader(plan, projection, ctx.clone())?;
+            return build_fuse_agg_index_source_pipeline(
+                ctx,
+                pipeline,
+                self.operator.clone(),
+                self.storage_format,
+                block_reader,
+                plan,
+                agg_index,
+                merge,
+                max_io_requests,
+            );
+        }
+
+        let block_reader = self.build_block_reader(
+            plan,
+            PushDownInfo::projection_of_push_downs(&self.table_info.schema(), &plan.push_downs),
+            ctx.clone(),
+        )?;
+        let max_prefetch_blocks = self.get_option(
+            FUSE_OPT_KEY_MAX_PREFETCH_BLOCKS,
+            DEFAULT_MAX_PREFETCH_BLOCKS,
+        );
+
         let topk = plan.push_downs.as_ref().and_then(|x| {
             x.top_k(
                 plan.schema().as_ref(),
@@ -199,6 +247,7 @@ impl FuseTable {
             plan,
             topk,
             max_io_requests,
+            max_prefetch_blocks,
         )?;
 
         // replace the column which has data mask if needed
@@ -215,6 +264,7 @@ impl FuseTable {
         plan: &DataSourcePlan,
         top_k: Option<TopK>,
         max_io_requests: usize,
+        max_prefetch_blocks: usize,
     ) -> Result<()> {
         let max_threads = ctx.get_settings().get_max_threads()? as usize;
 
@@ -235,6 +285,7 @@ impl FuseTable {
                 plan,
                 max_threads,
                 max_io_requests,
+                max_prefetch_blocks,
             ),
         }
     }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -32,6 +33,7 @@ use common_catalog::plan::VirtualColumnInfo;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
+use common_expression::ColumnId;
 use common_expression::TableSchemaRef;
 use common_meta_app::schema::TableInfo;
 use common_storage::ColumnNodes;
@@ -52,6 +54,8 @@ use crate::fuse_lazy_part::FuseLazyPartInfo;
 use crate::fuse_part::FusePartInfo;
 use crate::fuse_part::VirtualColumnMeta;
 use crate::io::TableMetaLocationGenerator;
+use crate::metrics::metrics_observe_bloom_pruning_hit_ratio;
+use crate::metrics::metrics_observe_pruning_blocks_ratio;
 use crate::pruning::FusePruner;
 use crate::pruning::SegmentLocation;
 use crate::FuseTable;
@@ -185,6 +189,21 @@ impl FuseTable {
         let block_metas = pruner.pruning(segments_location).await?;
         let pruning_stats = pruner.pruning_stats();
 
+        // Perf.
+        {
+            if pruning_stats.blocks_range_pruning_before > 0 {
+                metrics_observe_pruning_blocks_ratio(
+                    block_metas.len() as f64 / pruning_stats.blocks_range_pruning_before as f64,
+                );
+            }
+            if pruning_stats.blocks_bloom_pruning_before > 0 {
+                metrics_observe_bloom_pruning_hit_ratio(
+                    pruning_stats.blocks_bloom_pruning_after as f64
+                        / pruning_stats.blocks_bloom_pruning_before as f64,
+                );
+            }
+        }
+
         info!(
             "prune snapshot block end, final block numbers:{}, cost:{}",
             block_metas.len(),
@@ -395,14 +414,21 @@ impl FuseTable {
                     top_k.clone(),
                     limit,
                 ),
-                Some(projection) => Self::projection_partitions(
-                    &block_metas,
-                    virtual_columns_metas,
-                    column_nodes,
-                    projection,
-                    top_k.clone(),
-                    limit,
-                ),
+                Some(projection) => {
+                    let virtual_source_columns = match schema {
+                        Some(schema) => Self::virtual_source_columns(schema, extras),
+                        None => vec![],
+                    };
+                    Self::projection_partitions(
+                        &block_metas,
+                        virtual_columns_metas,
+                        &virtual_source_columns,
+                        column_nodes,
+                        projection,
+                        top_k.clone(),
+                        limit,
+                    )
+                }
             },
         };
 
@@ -414,10 +440,48 @@ impl FuseTable {
         (statistics, partitions)
     }
 
+    /// Returns the leaf column ids of the source columns which are only used to generate
+    /// virtual columns, together with the names of the virtual columns generated from them.
+    fn virtual_source_columns(
+        schema: &TableSchemaRef,
+        push_downs: &PushDownInfo,
+    ) -> Vec<(Vec<ColumnId>, Vec<String>)> {
+        let (projection, output_columns) =
+            match (&push_downs.projection, &push_downs.output_columns) {
+                (
+                    Some(Projection::Columns(projection)),
+                    Some(Projection::Columns(output_columns)),
+                ) => (projection, output_columns),
+                _ => return vec![],
+            };
+        let virtual_columns =
+            match PushDownInfo::virtual_columns_of_push_downs(&Some(push_downs.clone())) {
+                Some(virtual_columns) => virtual_columns,
+                None => return vec![],
+            };
+
+        let mut virtual_source_columns = Vec::new();
+        for index in projection {
+            if output_columns.contains(index) {
+                continue;
+            }
+            let field = schema.field(*index);
+            let virtual_names = virtual_columns
+                .iter()
+                .filter(|c| &c.source_name == field.name())
+                .map(|c| c.name.clone())
+                .collect::<Vec<_>>();
+            if !virtual_names.is_empty() {
+                virtual_source_columns.push((field.leaf_column_ids(), virtual_names));
+            }
+        }
+        virtual_source_columns
+    }
+
     fn is_exact(push_downs: &Option<PushDownInfo>) -> bool {
         match push_downs {
             None => true,
-            Some(extra) => extra.filter.is_none(),
+            Some(extra) => extra.filter.is_none() && extra.sample.is_none(),
         }
     }
 
@@ -443,7 +507,7 @@ impl FuseTable {
                 } else {
                     None
                 };
-            let rows = block_meta.row_count as usize;
+            let rows = block_meta.live_row_count() as usize;
             partitions.partitions.push(Self::all_columns_part(
                 schema,
                 block_meta_index,
@@ -471,6 +535,7 @@ impl FuseTable {
     fn projection_partitions(
         block_metas: &[(Option<BlockMetaIndex>, Arc<BlockMeta>)],
         virtual_columns_metas: Option<Vec<Option<HashMap<String, VirtualColumnMeta>>>>,
+        virtual_source_columns: &[(Vec<ColumnId>, Vec<String>)],
         column_nodes: &ColumnNodes,
         projection: &Projection,
         top_k: Option<TopK>,
@@ -493,6 +558,8 @@ impl FuseTable {
                 } else {
                     None
                 };
+            let skipped_column_ids =
+                Self::skipped_virtual_source_columns(virtual_source_columns, &virtual_columns_meta);
             partitions.partitions.push(Self::projection_part(
                 block_meta,
                 block_meta_index,
@@ -500,13 +567,23 @@ impl FuseTable {
                 virtual_columns_meta,
                 top_k.clone(),
                 projection,
+                skipped_column_ids.as_ref(),
             ));
 
             let rows = block_meta.row_count as usize;
 
             statistics.read_rows += rows;
             for column in &columns {
-                for column_id in &column.leaf_column_ids {
+                for column_id in column
+                    .leaf_column_ids
+                    .iter()
+                    .chain(column.origin_column_ids.iter())
+                {
+                    if let Some(skipped_column_ids) = &skipped_column_ids {
+                        if skipped_column_ids.contains(column_id) {
+                            continue;
+                        }
+                    }
                     // ignore all deleted field
                     if let Some(col_metas) = block_meta.col_metas.get(column_id) {
                         let (_, len) = col_metas.offset_length();
@@ -528,6 +605,29 @@ impl FuseTable {
         (statistics, partitions)
     }
 
+    /// If all the virtual columns generated from a source column have been shredded
+    /// in the block, the source column does not need to be read.
+    fn skipped_virtual_source_columns(
+        virtual_source_columns: &[(Vec<ColumnId>, Vec<String>)],
+        virtual_columns_meta: &Option<HashMap<String, VirtualColumnMeta>>,
+    ) -> Option<HashSet<ColumnId>> {
+        let virtual_columns_meta = virtual_columns_meta.as_ref()?;
+        let skipped_column_ids = virtual_source_columns
+            .iter()
+            .filter(|(_, virtual_names)| {
+                virtual_names
+                    .iter()
+                    .all(|name| virtual_columns_meta.contains_key(name))
+            })
+            .flat_map(|(column_ids, _)| column_ids.iter().cloned())
+            .collect::<HashSet<_>>();
+        if skipped_column_ids.is_empty() {
+            None
+        } else {
+            Some(skipped_column_ids)
+        }
+    }
+
     fn all_columns_part(
         schema: Option<&TableSchemaRef>,
         block_meta_index: &Option<BlockMetaIndex>,
@@ -536,6 +636,7 @@ impl FuseTable {
         virtual_columns_meta: Option<HashMap<String, VirtualColumnMeta>>,
     ) -> PartInfoPtr {
         let mut columns_meta = HashMap::with_capacity(meta.col_metas.len());
+        let mut columns_stat = HashMap::with_capacity(meta.col_stats.len());
 
         for column_id in meta.col_metas.keys() {
             // ignore all deleted field
@@ -546,8 +647,12 @@ impl FuseTable {
             }
 
             // ignore column this block dose not exist
-            if let Some(meta) = meta.col_metas.get(column_id) {
-                columns_meta.insert(*column_id, meta.clone());
+            if let Some(column_meta) = meta.col_metas.get(column_id) {
+                columns_meta.insert(*column_id, column_meta.clone());
+            }
+
+            if let Some(column_stat) = meta.col_stats.get(column_id) {
+                columns_stat.insert(*column_id, column_stat.clone());
             }
         }
 
@@ -565,10 +670,12 @@ impl FuseTable {
             format_version,
             rows_count,
             columns_meta,
+            columns_stat,
             virtual_columns_meta,
             meta.compression(),
             sort_min_max,
             block_meta_index.to_owned(),
+            meta.deletion_vector_location.clone(),
         )
     }
 
@@ -579,16 +686,35 @@ impl FuseTable {
         virtual_columns_meta: Option<HashMap<String, VirtualColumnMeta>>,
         top_k: Option<TopK>,
         projection: &Projection,
+        skipped_column_ids: Option<&HashSet<ColumnId>>,
     ) -> PartInfoPtr {
         let mut columns_meta = HashMap::with_capacity(projection.len());
+        let mut columns_stat = HashMap::with_capacity(projection.len());
 
         let columns = projection.project_column_nodes(column_nodes).unwrap();
         for column in &columns {
             for column_id in &column.leaf_column_ids {
+                // ignore source column only used to generate the shredded virtual columns
+                if let Some(skipped_column_ids) = skipped_column_ids {
+                    if skipped_column_ids.contains(column_id) {
+                        continue;
+                    }
+                }
                 // ignore column this block dose not exist
                 if let Some(column_meta) = meta.col_metas.get(column_id) {
                     columns_meta.insert(*column_id, column_meta.clone());
                 }
+
+                if let Some(column_stat) = meta.col_stats.get(column_id) {
+                    columns_stat.insert(*column_id, column_stat.clone());
+                }
+            }
+
+            // the block may be written before the data type of the column was modified
+            for column_id in &column.origin_column_ids {
+                if let Some(column_meta) = meta.col_metas.get(column_id) {
+                    columns_meta.insert(*column_id, column_meta.clone());
+                }
             }
         }
 
@@ -609,10 +735,12 @@ impl FuseTable {
             format_version,
             rows_count,
             columns_meta,
+            columns_stat,
             virtual_columns_meta,
             meta.compression(),
             sort_min_max,
             block_meta_index.to_owned(),
+            meta.deletion_vector_location.clone(),
         )
     }
 }
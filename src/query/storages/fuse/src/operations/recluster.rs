@@ -133,6 +133,7 @@ impl FuseTable {
             push_downs: None,
             query_internal_columns: false,
             data_mask_policy: None,
+            table_index: None,
         };
 
         ctx.set_partitions(plan.parts.clone())?;
@@ -212,6 +213,7 @@ impl FuseTable {
                 self.meta_location_generator().clone(),
                 self.schema(),
                 self.get_operator(),
+                self.get_write_settings().segment_max_blocks,
             );
             aggregator.accumulate_log_entry(mutator.mutation_logs());
             Ok(ProcessorPtr::create(AsyncAccumulatingTransformer::create(
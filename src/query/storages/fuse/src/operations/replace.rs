@@ -341,6 +341,7 @@ impl FuseTable {
             let location_gen = self.meta_location_generator.clone();
             let schema = self.table_info.schema();
             let dal = self.operator.clone();
+            let segment_max_blocks = self.get_write_settings().segment_max_blocks;
             let mutation_aggregator = TableMutationAggregator::create(
                 ctx.clone(),
                 base_segments,
@@ -349,6 +350,7 @@ impl FuseTable {
                 location_gen,
                 schema,
                 dal,
+                segment_max_blocks,
             );
             Ok(ProcessorPtr::create(AsyncAccumulatingTransformer::create(
                 input,
@@ -35,6 +35,7 @@ use common_expression::Scalar;
 use common_expression::TableSchema;
 use common_sql::evaluator::BlockOperator;
 use opendal::Operator;
+use roaring::RoaringBitmap;
 use siphasher::sip128;
 use siphasher::sip128::Hasher128;
 use storages_common_cache::LoadParams;
@@ -44,12 +45,14 @@ use storages_common_table_meta::meta::Location;
 use storages_common_table_meta::meta::SegmentInfo;
 use tracing::info;
 
+use crate::io::write_block_data;
 use crate::io::write_data;
 use crate::io::BlockBuilder;
 use crate::io::BlockReader;
 use crate::io::CompactSegmentInfoReader;
 use crate::io::MetaReaders;
 use crate::io::ReadSettings;
+use crate::io::UploadSettings;
 use crate::io::WriteSettings;
 use crate::operations::common::BlockMetaIndex;
 use crate::operations::common::MutationLogEntry;
@@ -303,7 +306,19 @@ impl AggregationContext {
             return Ok(None);
         }
 
-        let key_columns_data = self.read_block(&self.key_column_reader, block_meta).await?;
+        // the rows marked as deleted are removed while reading, the offsets of rows in
+        // the data read do not matter here.
+        let deletion_vector = match &block_meta.deletion_vector_location {
+            Some(location) => Some(
+                self.key_column_reader
+                    .read_deletion_vector(location)
+                    .await?,
+            ),
+            None => None,
+        };
+        let key_columns_data = self
+            .read_block(&self.key_column_reader, block_meta, &deletion_vector)
+            .await?;
 
         let num_rows = key_columns_data.num_rows();
 
@@ -365,10 +380,9 @@ impl AggregationContext {
             .incr(&progress_values);
 
         // shortcut: nothing to be deleted
-        if delete_nums == block_meta.row_count as usize {
+        if delete_nums == num_rows {
             info!("whole block deletion");
             // whole block deletion
-            // NOTE that if deletion marker is enabled, check the real meaning of `row_count`
             let mutation = ReplacementLogEntry {
                 index: BlockMetaIndex {
                     segment_idx: segment_index,
@@ -387,8 +401,9 @@ impl AggregationContext {
             None => key_columns_data_after_deletion,
             Some(remain_columns_reader) => {
                 // read the remaining columns
-                let remain_columns_data =
-                    self.read_block(remain_columns_reader, block_meta).await?;
+                let remain_columns_data = self
+                    .read_block(remain_columns_reader, block_meta, &deletion_vector)
+                    .await?;
 
                 // remove the deleted rows
                 let remain_columns_data_after_deletion =
@@ -433,10 +448,41 @@ impl AggregationContext {
         let new_block_location = new_block_meta.location.0.clone();
         let new_block_raw_data = serialized.block_raw_data;
         let data_accessor = self.data_accessor.clone();
-        write_data(new_block_raw_data, &data_accessor, &new_block_location).await?;
+        let upload_settings = UploadSettings::from_ctx(&self.block_builder.ctx)?;
+        write_block_data(
+            new_block_raw_data,
+            &data_accessor,
+            &new_block_location,
+            &upload_settings,
+        )
+        .await?;
         if let Some(index_state) = serialized.bloom_index_state {
             write_data(index_state.data, &data_accessor, &index_state.location.0).await?;
         }
+        if let Some(virtual_column_state) = serialized.virtual_column_state {
+            write_data(
+                virtual_column_state.data,
+                &data_accessor,
+                &virtual_column_state.location,
+            )
+            .await?;
+        }
+        if let Some(inverted_index_state) = serialized.inverted_index_state {
+            write_data(
+                inverted_index_state.data,
+                &data_accessor,
+                &inverted_index_state.location,
+            )
+            .await?;
+        }
+        if let Some(vector_index_state) = serialized.vector_index_state {
+            write_data(
+                vector_index_state.data,
+                &data_accessor,
+                &vector_index_state.location,
+            )
+            .await?;
+        }
 
         // generate log
         let mutation = ReplacementLogEntry {
@@ -503,7 +549,12 @@ impl AggregationContext {
         }
     }
 
-    async fn read_block(&self, reader: &BlockReader, block_meta: &BlockMeta) -> Result<DataBlock> {
+    async fn read_block(
+        &self,
+        reader: &BlockReader,
+        block_meta: &BlockMeta,
+        deletion_vector: &Option<RoaringBitmap>,
+    ) -> Result<DataBlock> {
         let merged_io_read_result = reader
             .read_columns_data_by_merge_io(
                 &self.read_settings,
@@ -517,7 +568,7 @@ impl AggregationContext {
         let storage_format = self.write_settings.storage_format;
         let block_meta_ptr = block_meta.clone();
         let reader = reader.clone();
-        GlobalIORuntime::instance()
+        let data_block = GlobalIORuntime::instance()
             .spawn_blocking(move || {
                 let column_chunks = merged_io_read_result.columns_chunks()?;
                 reader.deserialize_chunks(
@@ -529,7 +580,16 @@ impl AggregationContext {
                     &storage_format,
                 )
             })
-            .await
+            .await?;
+
+        match deletion_vector {
+            Some(deletion_vector) => {
+                let (data_block, _) =
+                    BlockReader::apply_deletion_vector(data_block, deletion_vector)?;
+                Ok(data_block)
+            }
+            None => Ok(data_block),
+        }
     }
 }
 
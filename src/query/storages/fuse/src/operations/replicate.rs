@@ -0,0 +1,248 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use futures::StreamExt;
+use futures::TryStreamExt;
+use opendal::Operator;
+use serde::Deserialize;
+use serde::Serialize;
+use storages_common_table_meta::meta::SnapshotId;
+use storages_common_table_meta::meta::TableSnapshot;
+use tracing::info;
+
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+use crate::FUSE_OPT_KEY_INVERTED_INDEX_COLUMNS;
+use crate::FUSE_OPT_KEY_VECTOR_INDEX_COLUMNS;
+
+/// The files referenced by a snapshot of a fuse table.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotFiles {
+    /// The blocks, the bloom indexes and the deletion vectors.
+    pub data: Vec<String>,
+    /// The inverted and vector indexes, a block may have no index of them.
+    pub optional: Vec<String>,
+    /// The segments and the table statistics.
+    pub meta: Vec<String>,
+    pub snapshot: String,
+}
+
+/// What a replication of a table shipped to the secondary storage.
+#[derive(Clone, Debug)]
+pub struct ReplicationResult {
+    pub snapshot_id: SnapshotId,
+    pub snapshot_timestamp: Option<DateTime<Utc>>,
+    /// Number of the files copied, the files already replicated are not counted.
+    pub copied_files: u64,
+    pub copied_bytes: u64,
+}
+
+impl FuseTable {
+    /// Copies the current snapshot of the table, and the segments, blocks and indexes
+    /// referenced by it, to `target`, under the same storage prefix of the table.
+    ///
+    /// The files of fuse tables are immutable, the files already existing in `target`
+    /// are skipped. The snapshot is copied at last and then the last snapshot hint is
+    /// written, so that a table attached on `target` never sees a partial snapshot.
+    ///
+    /// Returns [None] if the table has no snapshot.
+    #[async_backtrace::framed]
+    pub async fn do_replicate(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        target: &Operator,
+    ) -> Result<Option<ReplicationResult>> {
+        let snapshot_loc = match self.snapshot_loc().await? {
+            Some(snapshot_loc) => snapshot_loc,
+            None => return Ok(None),
+        };
+        let location_generator = &self.meta_location_generator;
+        let replicated =
+            FuseTable::read_attached_snapshot_location(target, location_generator).await?;
+        if replicated.as_deref() == Some(snapshot_loc.as_str()) {
+            let snapshot = match self.read_table_snapshot().await? {
+                Some(snapshot) => snapshot,
+                None => return Ok(None),
+            };
+            return Ok(Some(ReplicationResult {
+                snapshot_id: snapshot.snapshot_id,
+                snapshot_timestamp: snapshot.timestamp,
+                copied_files: 0,
+                copied_bytes: 0,
+            }));
+        }
+
+        let (snapshot, files) = match self.list_referenced_files(ctx.clone()).await? {
+            Some(listed) => listed,
+            None => return Ok(None),
+        };
+        let max_io_requests = ctx.get_settings().get_max_storage_io_requests()? as usize;
+        let (copied_files, copied_bytes) =
+            copy_snapshot_files(&self.operator, "", target, "", &files, max_io_requests).await?;
+        FuseTable::write_last_snapshot_hint(target, location_generator, files.snapshot).await;
+
+        let result = ReplicationResult {
+            snapshot_id: snapshot.snapshot_id,
+            snapshot_timestamp: snapshot.timestamp,
+            copied_files,
+            copied_bytes,
+        };
+        info!(
+            "replicated snapshot {} of table {}, {} files, {} bytes copied",
+            result.snapshot_id, self.table_info.desc, result.copied_files, result.copied_bytes
+        );
+        Ok(Some(result))
+    }
+
+    /// Lists the files referenced by the current snapshot of the table, the paths are
+    /// relative to the root of the operator of the table.
+    ///
+    /// Returns [None] if the table has no snapshot.
+    #[async_backtrace::framed]
+    pub async fn list_referenced_files(
+        &self,
+        ctx: Arc<dyn TableContext>,
+    ) -> Result<Option<(Arc<TableSnapshot>, SnapshotFiles)>> {
+        let snapshot_loc = match self.snapshot_loc().await? {
+            Some(snapshot_loc) => snapshot_loc,
+            None => return Ok(None),
+        };
+        let snapshot = match self.read_table_snapshot().await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(None),
+        };
+
+        let files = self
+            .list_snapshot_referenced_files(ctx, snapshot_loc, &snapshot)
+            .await?;
+        Ok(Some((snapshot, files)))
+    }
+
+    /// Lists the files referenced by the snapshot at `snapshot_loc` of the table, the paths
+    /// are relative to the root of the operator of the table.
+    #[async_backtrace::framed]
+    pub async fn list_snapshot_referenced_files(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        snapshot_loc: String,
+        snapshot: &TableSnapshot,
+    ) -> Result<SnapshotFiles> {
+        let blocks = self
+            .get_block_locations(ctx, &snapshot.segments, false)
+            .await?;
+        let data = blocks
+            .block_location
+            .iter()
+            .chain(blocks.bloom_location.iter())
+            .chain(blocks.deletion_vector_location.iter())
+            .cloned()
+            .collect();
+        let mut optional = vec![];
+        let options = self.table_info.options();
+        if options.contains_key(FUSE_OPT_KEY_INVERTED_INDEX_COLUMNS) {
+            optional.extend(blocks.block_location.iter().map(|location| {
+                TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+                    location,
+                )
+            }));
+        }
+        if options.contains_key(FUSE_OPT_KEY_VECTOR_INDEX_COLUMNS) {
+            optional.extend(blocks.block_location.iter().map(|location| {
+                TableMetaLocationGenerator::gen_vector_index_location_from_block_location(location)
+            }));
+        }
+        let meta = snapshot
+            .segments
+            .iter()
+            .map(|(location, _)| location.clone())
+            .chain(snapshot.table_statistics_location.iter().cloned())
+            .collect();
+
+        Ok(SnapshotFiles {
+            data,
+            optional,
+            meta,
+            snapshot: snapshot_loc,
+        })
+    }
+}
+
+/// Copies the files of a snapshot from `source_prefix` of `source` to `target_prefix` of
+/// `target`, the blocks and their indexes before the segments referencing them, and the
+/// snapshot at last. The files already existing in `target` are skipped.
+///
+/// Returns the number and the total size of the files copied.
+#[async_backtrace::framed]
+pub async fn copy_snapshot_files(
+    source: &Operator,
+    source_prefix: &str,
+    target: &Operator,
+    target_prefix: &str,
+    files: &SnapshotFiles,
+    max_io_requests: usize,
+) -> Result<(u64, u64)> {
+    let groups = [
+        (&files.data, false),
+        (&files.optional, true),
+        (&files.meta, false),
+    ];
+    let snapshot = vec![files.snapshot.clone()];
+    let mut copied_files = 0;
+    let mut copied_bytes = 0;
+    for (group, optional) in groups.into_iter().chain(Some((&snapshot, false))) {
+        let copied: Vec<Option<u64>> = futures::stream::iter(group)
+            .map(|file| {
+                let from = format!("{source_prefix}{file}");
+                let to = format!("{target_prefix}{file}");
+                copy_file(source, from, target, to, optional)
+            })
+            .buffer_unordered(std::cmp::max(max_io_requests, 1))
+            .try_collect()
+            .await?;
+
+        for len in copied.into_iter().flatten() {
+            copied_files += 1;
+            copied_bytes += len;
+        }
+    }
+    Ok((copied_files, copied_bytes))
+}
+
+/// Returns the size of the file copied, [None] if it's not copied.
+#[async_backtrace::framed]
+async fn copy_file(
+    source: &Operator,
+    from: String,
+    target: &Operator,
+    to: String,
+    optional: bool,
+) -> Result<Option<u64>> {
+    if target.is_exist(&to).await? {
+        return Ok(None);
+    }
+    let data = match source.read(&from).await {
+        Ok(data) => data,
+        Err(e) if optional && e.kind() == opendal::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let len = data.len() as u64;
+    target.write(&to, data).await?;
+    Ok(Some(len))
+}
@@ -0,0 +1,112 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Utc;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use once_cell::sync::OnceCell;
+use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+
+use crate::FUSE_OPT_KEY_NOTIFICATION_INTEGRATION;
+
+static TABLE_CHANGE_NOTIFIER: OnceCell<Arc<dyn TableChangeNotifier>> = OnceCell::new();
+
+/// A committed snapshot of a table which subscribes to a notification integration by
+/// the `notification_integration` option.
+#[derive(Clone, Debug)]
+pub struct TableChangeEvent {
+    pub tenant: String,
+    pub integration: String,
+    pub database: String,
+    pub table: String,
+    pub table_id: u64,
+    pub snapshot_id: String,
+    pub previous_snapshot_id: Option<String>,
+    pub row_count: u64,
+    pub previous_row_count: u64,
+    pub committed_at: DateTime<Utc>,
+}
+
+impl TableChangeEvent {
+    /// Builds the event of committing `committed` over `base`, returns None if the table
+    /// doesn't subscribe to a notification integration.
+    pub fn try_create(base: &TableInfo, committed: &TableMeta) -> Option<Self> {
+        let integration = match committed.options.get(FUSE_OPT_KEY_NOTIFICATION_INTEGRATION) {
+            Some(integration) if !integration.is_empty() => integration.clone(),
+            _ => return None,
+        };
+        let snapshot_id = snapshot_id_of(committed.options.get(OPT_KEY_SNAPSHOT_LOCATION)?);
+        let previous_snapshot_id = base
+            .meta
+            .options
+            .get(OPT_KEY_SNAPSHOT_LOCATION)
+            .map(|location| snapshot_id_of(location));
+        // the table meta is updated without a new snapshot.
+        if previous_snapshot_id.as_ref() == Some(&snapshot_id) {
+            return None;
+        }
+
+        // the desc of a table ends with `'<database>'.'<table>'`.
+        let parts = base.desc.split("'.'").collect::<Vec<_>>();
+        let database = match parts.len() {
+            n if n >= 2 => parts[n - 2].trim_start_matches('\'').to_string(),
+            _ => String::new(),
+        };
+        Some(TableChangeEvent {
+            tenant: base.tenant.clone(),
+            integration,
+            database,
+            table: base.name.clone(),
+            table_id: base.ident.table_id,
+            snapshot_id,
+            previous_snapshot_id,
+            row_count: committed.statistics.number_of_rows,
+            previous_row_count: base.meta.statistics.number_of_rows,
+            committed_at: Utc::now(),
+        })
+    }
+
+    pub fn row_delta(&self) -> i64 {
+        self.row_count as i64 - self.previous_row_count as i64
+    }
+}
+
+/// Delivers the events of the committed snapshots, the commits never wait for it.
+pub trait TableChangeNotifier: Send + Sync {
+    fn notify(&self, event: TableChangeEvent);
+}
+
+/// Registers the notifier of the process, the commits are not notified until then.
+pub fn set_table_change_notifier(notifier: Arc<dyn TableChangeNotifier>) {
+    let _ = TABLE_CHANGE_NOTIFIER.set(notifier);
+}
+
+pub(crate) fn notify_table_change(event: Option<TableChangeEvent>) {
+    if let (Some(mut event), Some(notifier)) = (event, TABLE_CHANGE_NOTIFIER.get()) {
+        event.committed_at = Utc::now();
+        notifier.notify(event);
+    }
+}
+
+// the name of a snapshot file is `<snapshot id>_v<version>.mpk`.
+fn snapshot_id_of(location: &str) -> String {
+    let name = location.rsplit('/').next().unwrap_or(location);
+    match name.split_once('_') {
+        Some((id, _)) => id.to_string(),
+        None => name.to_string(),
+    }
+}
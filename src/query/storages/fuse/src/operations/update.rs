@@ -263,6 +263,7 @@ impl FuseTable {
                         ops.clone(),
                         self.storage_format,
                         true,
+                        false,
                     )
                 },
                 max_threads,
@@ -17,6 +17,7 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::DataType;
 use common_expression::ColumnId;
 use common_expression::Expr;
 use common_expression::FunctionContext;
@@ -47,6 +48,9 @@ pub struct BloomPrunerCreator {
     /// indices that should be loaded from filter block
     index_fields: Vec<TableField>,
 
+    /// prefix indices that should be loaded from filter block
+    prefix_index_fields: Vec<TableField>,
+
     /// the expression that would be evaluate
     filter_expression: Expr<String>,
 
@@ -69,8 +73,9 @@ impl BloomPrunerCreator {
     ) -> Result<Option<Arc<dyn BloomPruner + Send + Sync>>> {
         if let Some(expr) = filter_expr {
             let point_query_cols = BloomIndex::find_eq_columns(expr)?;
+            let prefix_query_cols = BloomIndex::find_prefix_columns(expr)?;
 
-            if !point_query_cols.is_empty() {
+            if !point_query_cols.is_empty() || !prefix_query_cols.is_empty() {
                 // convert to filter column names
                 let mut filter_fields = Vec::with_capacity(point_query_cols.len());
                 let mut scalar_map = HashMap::<Scalar, u64>::new();
@@ -85,9 +90,25 @@ impl BloomPrunerCreator {
                     }
                 }
 
+                let mut prefix_filter_fields = Vec::with_capacity(prefix_query_cols.len());
+                for (col_name, prefix) in prefix_query_cols.iter() {
+                    if let Ok(field) = schema.field_with_name(col_name) {
+                        prefix_filter_fields.push(field.clone());
+                        if !scalar_map.contains_key(prefix) {
+                            let digest = BloomIndex::calculate_scalar_digest(
+                                &func_ctx,
+                                prefix,
+                                &DataType::String,
+                            )?;
+                            scalar_map.insert(prefix.clone(), digest);
+                        }
+                    }
+                }
+
                 let creator = BloomPrunerCreator {
                     func_ctx,
                     index_fields: filter_fields,
+                    prefix_index_fields: prefix_filter_fields,
                     filter_expression: expr.clone(),
                     scalar_map,
                     dal,
@@ -110,8 +131,8 @@ impl BloomPrunerCreator {
         let version = index_location.1;
 
         // filter out columns that no longer exist in the indexed block
-        let index_columns = self.index_fields.iter().try_fold(
-            Vec::with_capacity(self.index_fields.len()),
+        let mut index_columns = self.index_fields.iter().try_fold(
+            Vec::with_capacity(self.index_fields.len() + self.prefix_index_fields.len()),
             |mut acc, field| {
                 if column_ids_of_indexed_block.contains(&field.column_id()) {
                     acc.push(BloomIndex::build_filter_column_name(version, field)?);
@@ -119,6 +140,11 @@ impl BloomPrunerCreator {
                 Ok::<_, ErrorCode>(acc)
             },
         )?;
+        for field in &self.prefix_index_fields {
+            if column_ids_of_indexed_block.contains(&field.column_id()) {
+                index_columns.push(BloomIndex::build_prefix_filter_column_name(version, field)?);
+            }
+        }
         // load the relevant index columns
         let maybe_filter = index_location
             .read_block_filter(self.dal.clone(), &index_columns, index_length)
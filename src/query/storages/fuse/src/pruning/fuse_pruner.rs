@@ -18,6 +18,8 @@ use common_base::base::tokio::sync::Semaphore;
 use common_base::runtime::Runtime;
 use common_base::runtime::TrySpawn;
 use common_catalog::plan::PushDownInfo;
+use common_catalog::plan::SampleConfig;
+use common_catalog::plan::SampleLevel;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -27,6 +29,8 @@ use common_expression::SEGMENT_NAME_COL_NAME;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_sql::field_default_value;
 use opendal::Operator;
+use rand::thread_rng;
+use rand::Rng;
 use storages_common_pruner::BlockMetaIndex;
 use storages_common_pruner::InternalColumnPruner;
 use storages_common_pruner::Limiter;
@@ -47,7 +51,9 @@ use crate::pruning::BlockPruner;
 use crate::pruning::BloomPruner;
 use crate::pruning::BloomPrunerCreator;
 use crate::pruning::FusePruningStatistics;
+use crate::pruning::InvertedIndexPruner;
 use crate::pruning::SegmentLocation;
+use crate::pruning::VectorIndexPruner;
 
 pub struct PruningContext {
     pub ctx: Arc<dyn TableContext>,
@@ -58,6 +64,7 @@ pub struct PruningContext {
     pub limit_pruner: Arc<dyn Limiter + Send + Sync>,
     pub range_pruner: Arc<dyn RangePruner + Send + Sync>,
     pub bloom_pruner: Option<Arc<dyn BloomPruner + Send + Sync>>,
+    pub inverted_index_pruner: Option<Arc<InvertedIndexPruner>>,
     pub page_pruner: Arc<dyn PagePruner + Send + Sync>,
     pub internal_column_pruner: Option<Arc<InternalColumnPruner>>,
 
@@ -144,6 +151,11 @@ impl FusePruner {
             filter_expr.as_ref(),
         )?;
 
+        // Inverted index pruner.
+        // None will be returned, if there are no `match` predicates in the filter expression.
+        let inverted_index_pruner =
+            InvertedIndexPruner::create(func_ctx.clone(), dal.clone(), filter_expr.as_ref())?;
+
         // Page pruner, used in native format
         let page_pruner = PagePrunerCreator::try_create(
             func_ctx.clone(),
@@ -189,6 +201,7 @@ impl FusePruner {
             limit_pruner,
             range_pruner,
             bloom_pruner,
+            inverted_index_pruner,
             page_pruner,
             internal_column_pruner,
             pruning_stats,
@@ -203,7 +216,7 @@ impl FusePruner {
     }
 
     // Pruning chain:
-    // segment pruner -> block pruner -> topn pruner
+    // segment pruner -> block pruner -> topn pruner -> vector index pruner -> sample pruner
     #[async_backtrace::framed]
     pub async fn pruning(
         &self,
@@ -265,7 +278,13 @@ impl FusePruner {
                 }
 
                 // TopN pruner.
-                self.topn_pruning(metas)
+                let metas = self.topn_pruning(metas)?;
+
+                // Vector index pruner.
+                let metas = self.vector_topn_pruning(metas).await?;
+
+                // Sample pruner.
+                Ok(self.sample_pruning(metas))
             }
         }
     }
@@ -292,6 +311,54 @@ impl FusePruner {
         Ok(metas)
     }
 
+    // vector index pruner:
+    // if there are `ORDER BY cosine_distance(col, [..]) LIMIT k` and no filters, prune the blocks
+    // which can't contain the k nearest rows
+    #[async_backtrace::framed]
+    async fn vector_topn_pruning(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        let vector_topn = self
+            .push_down
+            .as_ref()
+            .filter(|p| p.filter.is_none() && p.prewhere.is_none())
+            .and_then(|p| p.vector_topn.clone());
+        match vector_topn {
+            Some(vector_topn) => {
+                let vector_index_pruner = VectorIndexPruner::create(
+                    self.pruning_ctx.dal.clone(),
+                    vector_topn,
+                    self.max_concurrency,
+                );
+                vector_index_pruner.prune(metas).await
+            }
+            None => Ok(metas),
+        }
+    }
+
+    // sample pruner:
+    // if there is a `SAMPLE BLOCK (p)` clause, keep each block with the probability p,
+    // the others are never read
+    fn sample_pruning(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Vec<(BlockMetaIndex, Arc<BlockMeta>)> {
+        match self.push_down.as_ref().and_then(|p| p.sample) {
+            Some(SampleConfig {
+                level: SampleLevel::Block,
+                probability,
+            }) => {
+                let mut rng = thread_rng();
+                metas
+                    .into_iter()
+                    .filter(|_| rng.gen_bool(probability.into_inner()))
+                    .collect()
+            }
+            _ => metas,
+        }
+    }
+
     // Pruning stats.
     pub fn pruning_stats(&self) -> common_catalog::plan::PruningStatistics {
         let stats = self.pruning_ctx.pruning_stats.clone();
@@ -0,0 +1,116 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+use std::sync::Arc;
+
+use common_arrow::arrow::io::parquet::read as pread;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::TableSchema;
+use opendal::Operator;
+use storages_common_index::FilterEvalResult;
+use storages_common_index::InvertedIndex;
+
+use crate::io::TableMetaLocationGenerator;
+
+/// Prunes the blocks by the `match(col, 'query')` predicates, using the inverted
+/// index stored next to each block.
+pub struct InvertedIndexPruner {
+    func_ctx: FunctionContext,
+
+    /// the expression that would be evaluate
+    filter_expression: Expr<String>,
+
+    /// the data accessor
+    dal: Operator,
+}
+
+impl InvertedIndexPruner {
+    pub fn create(
+        func_ctx: FunctionContext,
+        dal: Operator,
+        filter_expr: Option<&Expr<String>>,
+    ) -> Result<Option<Arc<InvertedIndexPruner>>> {
+        if let Some(expr) = filter_expr {
+            if !InvertedIndex::find_match_columns(expr)?.is_empty() {
+                return Ok(Some(Arc::new(InvertedIndexPruner {
+                    func_ctx,
+                    filter_expression: expr.clone(),
+                    dal,
+                })));
+            }
+        }
+        Ok(None)
+    }
+
+    // returns true, if the block should NOT be pruned.
+    #[async_backtrace::framed]
+    pub async fn should_keep(&self, block_location: &str) -> bool {
+        match self.apply(block_location).await {
+            Ok(v) => v,
+            Err(e) => {
+                // swallow exceptions intentionally, corrupted index should not prevent execution
+                tracing::warn!(
+                    "failed to apply inverted index pruner, returning true. {}",
+                    e
+                );
+                true
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn apply(&self, block_location: &str) -> Result<bool> {
+        let location = TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+            block_location,
+        );
+        let index_block = match read_block_index(&self.dal, &location).await? {
+            Some(index_block) => index_block,
+            None => return Ok(true),
+        };
+
+        let index = InvertedIndex::from_data_block(&index_block)?;
+        Ok(index.apply(self.filter_expression.clone(), &self.func_ctx)?
+            != FilterEvalResult::MustFalse)
+    }
+}
+
+/// Read the index written in parquet next to a block, returns [None] if the block
+/// is written without the index.
+#[async_backtrace::framed]
+pub(crate) async fn read_block_index(dal: &Operator, location: &str) -> Result<Option<DataBlock>> {
+    let data = match dal.read(location).await {
+        Ok(data) => data,
+        Err(e) if e.kind() == opendal::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut reader = Cursor::new(data);
+    let meta = pread::read_metadata(&mut reader)?;
+    let arrow_schema = pread::infer_schema(&meta)?;
+    let schema = DataSchema::from(&TableSchema::from(&arrow_schema));
+    let chunks = pread::FileReader::new(reader, meta.row_groups, arrow_schema, None, None, None);
+    let mut blocks = Vec::new();
+    for chunk in chunks {
+        blocks.push(DataBlock::from_arrow_chunk(&chunk?, &schema)?);
+    }
+    if blocks.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(DataBlock::concat(&blocks)?))
+}
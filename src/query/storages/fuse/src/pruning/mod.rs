@@ -15,15 +15,21 @@
 mod block_pruner;
 mod bloom_pruner;
 mod fuse_pruner;
+mod inverted_index_pruner;
 mod pruner_location;
 mod pruning_statistics;
+mod runtime_filter_pruner;
 mod segment_pruner;
+mod vector_index_pruner;
 
 pub use block_pruner::BlockPruner;
 pub use bloom_pruner::BloomPruner;
 pub use bloom_pruner::BloomPrunerCreator;
 pub use fuse_pruner::FusePruner;
 pub use fuse_pruner::PruningContext;
+pub use inverted_index_pruner::InvertedIndexPruner;
 pub use pruner_location::create_segment_location_vector;
 pub use pruner_location::SegmentLocation;
 pub use pruning_statistics::FusePruningStatistics;
+pub use runtime_filter_pruner::RuntimeFilterPruner;
+pub use vector_index_pruner::VectorIndexPruner;
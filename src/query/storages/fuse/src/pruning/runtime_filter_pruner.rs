@@ -0,0 +1,84 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::FunctionContext;
+use common_expression::TableSchemaRef;
+use parking_lot::RwLock;
+use storages_common_pruner::RangePruner;
+use storages_common_pruner::RangePrunerCreator;
+use tracing::warn;
+
+use crate::fuse_part::FusePartInfo;
+
+/// Prunes the parts of a table scan by the runtime filters pushed down from hash joins.
+///
+/// The filters are only available after the build side of the join is finished,
+/// so they are fetched from the context every time before a part is read.
+pub struct RuntimeFilterPruner {
+    ctx: Arc<dyn TableContext>,
+    table_index: usize,
+    schema: TableSchemaRef,
+    func_ctx: FunctionContext,
+    pruners: RwLock<Vec<Arc<dyn RangePruner + Send + Sync>>>,
+}
+
+impl RuntimeFilterPruner {
+    pub fn try_create(
+        ctx: Arc<dyn TableContext>,
+        table_index: usize,
+        schema: TableSchemaRef,
+    ) -> Result<Self> {
+        Ok(RuntimeFilterPruner {
+            func_ctx: ctx.get_function_context()?,
+            ctx,
+            table_index,
+            schema,
+            pruners: RwLock::new(vec![]),
+        })
+    }
+
+    pub fn should_keep(&self, part: &FusePartInfo) -> bool {
+        self.refresh();
+
+        let pruners = self.pruners.read();
+        pruners
+            .iter()
+            .all(|pruner| pruner.should_keep(&part.columns_stat, Some(&part.columns_meta)))
+    }
+
+    // Create the pruners of the filters which are pushed down after the last refresh.
+    fn refresh(&self) {
+        let filters = self.ctx.get_runtime_filters(self.table_index);
+        if filters.len() == self.pruners.read().len() {
+            return;
+        }
+
+        let mut pruners = self.pruners.write();
+        for filter in filters.iter().skip(pruners.len()) {
+            let pruner =
+                RangePrunerCreator::try_create(self.func_ctx.clone(), &self.schema, Some(filter))
+                    .unwrap_or_else(|cause| {
+                        warn!("Cannot create pruner of runtime filter, cause: {:?}", cause);
+                        // Keep all the parts.
+                        RangePrunerCreator::try_create(self.func_ctx.clone(), &self.schema, None)
+                            .unwrap()
+                    });
+            pruners.push(pruner);
+        }
+    }
+}
@@ -0,0 +1,127 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::VectorDistance;
+use common_catalog::plan::VectorTopN;
+use common_exception::Result;
+use futures::StreamExt;
+use opendal::Operator;
+use storages_common_index::VectorDistanceBounds;
+use storages_common_index::VectorIndex;
+use storages_common_pruner::BlockMetaIndex;
+use storages_common_table_meta::meta::BlockMeta;
+
+use crate::io::TableMetaLocationGenerator;
+use crate::pruning::inverted_index_pruner::read_block_index;
+
+/// Prunes the blocks of `ORDER BY cosine_distance(col, [..]) LIMIT k`, using the vector
+/// index stored next to each block.
+///
+/// The k nearest rows are not farther than the threshold found by the upper bounds of
+/// the clusters, the blocks whose rows are all farther than it are pruned.
+pub struct VectorIndexPruner {
+    dal: Operator,
+    vector_topn: VectorTopN,
+    max_concurrency: usize,
+}
+
+impl VectorIndexPruner {
+    pub fn create(dal: Operator, vector_topn: VectorTopN, max_concurrency: usize) -> Self {
+        Self {
+            dal,
+            vector_topn,
+            max_concurrency,
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn prune(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        let total_rows: u64 = metas.iter().map(|(_, meta)| meta.row_count).sum();
+        if metas.len() <= 1 || total_rows <= self.vector_topn.limit as u64 {
+            return Ok(metas);
+        }
+
+        let bounds: Vec<Option<VectorDistanceBounds>> = futures::stream::iter(metas.iter())
+            .map(|(_, meta)| self.block_bounds(meta))
+            .buffered(self.max_concurrency)
+            .collect()
+            .await;
+
+        // the rows of the blocks with deleted rows are not counted.
+        let mut clusters: Vec<(f32, u64)> = metas
+            .iter()
+            .zip(bounds.iter())
+            .filter(|((_, meta), _)| meta.deletion_vector_location.is_none())
+            .filter_map(|(_, bounds)| bounds.as_ref())
+            .flat_map(|bounds| bounds.clusters.iter().copied())
+            .collect();
+        clusters.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let mut rows = 0;
+        let mut threshold = None;
+        for (upper, cluster_rows) in clusters {
+            rows += cluster_rows;
+            if rows >= self.vector_topn.limit as u64 {
+                threshold = Some(upper);
+                break;
+            }
+        }
+        let threshold = match threshold {
+            // leave room for the rounding errors of the distances.
+            Some(threshold) => threshold + threshold.abs() * 1e-3 + 1e-5,
+            None => return Ok(metas),
+        };
+
+        Ok(metas
+            .into_iter()
+            .zip(bounds)
+            .filter(|(_, bounds)| match bounds {
+                Some(bounds) => bounds.min <= threshold,
+                None => true,
+            })
+            .map(|(meta, _)| meta)
+            .collect())
+    }
+
+    /// Returns [None] if the bounds are unknown, the block can't be pruned.
+    #[async_backtrace::framed]
+    async fn block_bounds(&self, meta: &BlockMeta) -> Option<VectorDistanceBounds> {
+        let location = TableMetaLocationGenerator::gen_vector_index_location_from_block_location(
+            &meta.location.0,
+        );
+        let index_block = match read_block_index(&self.dal, &location).await {
+            Ok(index_block) => index_block?,
+            Err(e) => {
+                // swallow exceptions intentionally, corrupted index should not prevent execution
+                tracing::warn!("failed to read vector index, {}", e);
+                return None;
+            }
+        };
+        let index = match VectorIndex::from_data_block(&index_block) {
+            Ok(index) => index,
+            Err(e) => {
+                tracing::warn!("failed to load vector index, {}", e);
+                return None;
+            }
+        };
+
+        let query: Vec<f32> = self.vector_topn.query.iter().map(|v| v.0).collect();
+        let cosine = self.vector_topn.distance == VectorDistance::Cosine;
+        index.distance_bounds(&self.vector_topn.column, &query, cosine)
+    }
+}
@@ -97,17 +97,19 @@ impl StatisticsAccumulator {
     }
 
     pub fn add_with_block_meta(&mut self, block_meta: BlockMeta) {
-        self.summary_row_count += block_meta.row_count;
+        self.summary_row_count += block_meta.live_row_count();
         self.summary_block_count += 1;
         self.in_memory_size += block_meta.block_size;
         self.file_size += block_meta.file_size;
         self.index_size += block_meta.bloom_filter_index_size;
         self.blocks_statistics.push(block_meta.col_stats.clone());
 
-        if self.thresholds.check_large_enough(
-            block_meta.row_count as usize,
-            block_meta.block_size as usize,
-        ) {
+        if block_meta.deletion_vector_location.is_none()
+            && self.thresholds.check_large_enough(
+                block_meta.row_count as usize,
+                block_meta.block_size as usize,
+            )
+        {
             self.perfect_block_count += 1;
         }
 
@@ -15,17 +15,24 @@
 use std::collections::HashMap;
 
 use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NullableType;
+use common_expression::types::NumberDataType;
 use common_expression::types::NumberType;
 use common_expression::types::ValueType;
 use common_expression::Column;
 use common_expression::DataBlock;
 use common_expression::FieldIndex;
+use common_expression::FunctionContext;
 use common_expression::Scalar;
 use common_expression::TableSchemaRef;
 use common_functions::aggregates::eval_aggr;
+use storages_common_index::BloomIndex;
 use storages_common_index::Index;
 use storages_common_index::RangeIndex;
+use storages_common_table_meta::meta::ColumnDistinctHLL;
 use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::meta::DistinctHLLOfColumns;
 use storages_common_table_meta::meta::StatisticsOfColumns;
 
 pub fn calc_column_distinct_of_values(column: &Column, rows: usize) -> Result<u64> {
@@ -124,6 +131,54 @@ pub fn gen_columns_statistics(
     Ok(statistics)
 }
 
+/// Builds the distinct value sketches of the columns that have column statistics.
+pub fn gen_columns_distinct_hll(
+    func_ctx: &FunctionContext,
+    data_block: &DataBlock,
+    schema: &TableSchemaRef,
+) -> Result<DistinctHLLOfColumns> {
+    let mut column_hlls = DistinctHLLOfColumns::new();
+    let data_block = data_block.convert_to_full();
+
+    let leaves = get_traverse_columns_dfs(&data_block)?;
+    let leaf_column_ids = schema.to_leaf_column_ids();
+    for ((_, col, data_type), column_id) in leaves.iter().zip(leaf_column_ids) {
+        if !RangeIndex::supported_type(data_type) {
+            continue;
+        }
+
+        let mut hll = ColumnDistinctHLL::new();
+        if data_type.is_nullable() {
+            let digests = BloomIndex::calculate_column_digest(
+                func_ctx,
+                col,
+                data_type,
+                &DataType::Nullable(Box::new(DataType::Number(NumberDataType::UInt64))),
+            )?;
+            let digests = NullableType::<NumberType<u64>>::try_downcast_column(&digests).unwrap();
+            // nulls are not counted as distinct values.
+            for (digest, valid) in digests.column.iter().zip(digests.validity.iter()) {
+                if valid {
+                    hll.add_hash(*digest);
+                }
+            }
+        } else {
+            let digests = BloomIndex::calculate_column_digest(
+                func_ctx,
+                col,
+                data_type,
+                &DataType::Number(NumberDataType::UInt64),
+            )?;
+            let digests = NumberType::<u64>::try_downcast_column(&digests).unwrap();
+            for digest in digests.iter() {
+                hll.add_hash(*digest);
+            }
+        }
+        column_hlls.insert(column_id, hll);
+    }
+    Ok(column_hlls)
+}
+
 pub mod traverse {
     use common_expression::types::map::KvPair;
     use common_expression::types::AnyType;
@@ -22,6 +22,7 @@ pub use accumulator::StatisticsAccumulator;
 pub use block_statistics::BlockStatistics;
 pub use cluster_statistics::ClusterStatsGenerator;
 pub use column_statistic::calc_column_distinct_of_values;
+pub use column_statistic::gen_columns_distinct_hll;
 pub use column_statistic::gen_columns_statistics;
 pub use column_statistic::get_traverse_columns_dfs;
 pub use column_statistic::traverse;
@@ -55,6 +55,8 @@ struct ClusteringStatistics {
     average_overlaps: f64,
     average_depth: f64,
     block_depth_histogram: JsonValue,
+    max_depth: u64,
+    block_overlap_histogram: JsonValue,
 }
 
 impl Default for ClusteringStatistics {
@@ -65,6 +67,8 @@ impl Default for ClusteringStatistics {
             average_overlaps: 0.0,
             average_depth: 0.0,
             block_depth_histogram: json!({}),
+            max_depth: 0,
+            block_overlap_histogram: json!({}),
         }
     }
 }
@@ -86,27 +90,7 @@ impl<'a> ClusteringInformation<'a> {
 
     #[async_backtrace::framed]
     pub async fn get_clustering_info(&self) -> Result<DataBlock> {
-        let snapshot = self.table.read_table_snapshot().await?;
-
-        let mut info = ClusteringStatistics::default();
-        if let Some(snapshot) = snapshot {
-            let segment_locations = &snapshot.segments;
-            let segments_io = SegmentsIO::create(
-                self.ctx.clone(),
-                self.table.operator.clone(),
-                self.table.schema(),
-            );
-            let segments = segments_io
-                .read_segments::<Arc<SegmentInfo>>(segment_locations, true)
-                .await?
-                .into_iter()
-                .collect::<Result<Vec<_>>>()?;
-            if !segments.is_empty() {
-                let blocks = segments.iter().flat_map(|s| s.blocks.iter());
-                info = self.get_clustering_stats(blocks)?
-            }
-        };
-
+        let info = self.read_clustering_stats().await?;
         let cluster_by_keys = self.plain_cluster_keys.clone();
 
         Ok(DataBlock::new(
@@ -143,11 +127,53 @@ impl<'a> ClusteringInformation<'a> {
                         JsonbValue::from(&info.block_depth_histogram).to_vec(),
                     )),
                 ),
+                BlockEntry::new(
+                    DataType::Number(NumberDataType::UInt64),
+                    Value::Scalar(Scalar::Number(NumberScalar::UInt64(info.max_depth))),
+                ),
+                BlockEntry::new(
+                    DataType::Variant,
+                    Value::Scalar(Scalar::Variant(
+                        JsonbValue::from(&info.block_overlap_histogram).to_vec(),
+                    )),
+                ),
             ],
             1,
         ))
     }
 
+    /// Returns the average overlaps and the average depth of the blocks.
+    #[async_backtrace::framed]
+    pub async fn get_average_overlaps_and_depth(&self) -> Result<(f64, f64)> {
+        let info = self.read_clustering_stats().await?;
+        Ok((info.average_overlaps, info.average_depth))
+    }
+
+    #[async_backtrace::framed]
+    async fn read_clustering_stats(&self) -> Result<ClusteringStatistics> {
+        let snapshot = self.table.read_table_snapshot().await?;
+
+        let mut info = ClusteringStatistics::default();
+        if let Some(snapshot) = snapshot {
+            let segment_locations = &snapshot.segments;
+            let segments_io = SegmentsIO::create(
+                self.ctx.clone(),
+                self.table.operator.clone(),
+                self.table.schema(),
+            );
+            let segments = segments_io
+                .read_segments::<Arc<SegmentInfo>>(segment_locations, true)
+                .await?
+                .into_iter()
+                .collect::<Result<Vec<_>>>()?;
+            if !segments.is_empty() {
+                let blocks = segments.iter().flat_map(|s| s.blocks.iter());
+                info = self.get_clustering_stats(blocks)?
+            }
+        };
+        Ok(info)
+    }
+
     fn get_min_max_stats(&self, block: &BlockMeta) -> Result<(Vec<Scalar>, Vec<Scalar>)> {
         if self.table.cluster_keys(self.ctx.clone()) != self.cluster_keys
             || block.cluster_stats.is_none()
@@ -222,29 +248,31 @@ impl<'a> ClusteringInformation<'a> {
 
         let mut sum_overlap = 0;
         let mut sum_depth = 0;
+        let mut max_depth = 0;
         let length = stats.len();
+        let mut overlap_mp = BTreeMap::new();
         let mp = stats
             .into_iter()
             .fold(BTreeMap::new(), |mut acc, (overlap, depth)| {
                 sum_overlap += overlap;
                 sum_depth += depth;
+                max_depth = cmp::max(max_depth, depth);
 
                 let bucket = get_buckets(depth);
                 acc.entry(bucket).and_modify(|v| *v += 1).or_insert(1u32);
+                overlap_mp
+                    .entry(get_buckets(overlap))
+                    .and_modify(|v| *v += 1)
+                    .or_insert(1u32);
                 acc
             });
         // round the float to 4 decimal places.
         let average_depth = (10000.0 * sum_depth as f64 / length as f64).round() / 10000.0;
         let average_overlaps = (10000.0 * sum_overlap as f64 / length as f64).round() / 10000.0;
 
-        let objects = mp.iter().fold(
-            serde_json::Map::with_capacity(mp.len()),
-            |mut acc, (bucket, count)| {
-                acc.insert(format!("{:05}", bucket), json!(count));
-                acc
-            },
-        );
-        let block_depth_histogram = JsonValue::Object(objects);
+        let block_depth_histogram = build_histogram(&mp);
+        // The histogram of the number of blocks overlapping with each block.
+        let block_overlap_histogram = build_histogram(&overlap_mp);
 
         Ok(ClusteringStatistics {
             total_block_count,
@@ -252,6 +280,8 @@ impl<'a> ClusteringInformation<'a> {
             average_overlaps,
             average_depth,
             block_depth_histogram,
+            max_depth: max_depth as u64,
+            block_overlap_histogram,
         })
     }
 
@@ -275,10 +305,23 @@ impl<'a> ClusteringInformation<'a> {
                 TableDataType::Number(NumberDataType::Float64),
             ),
             TableField::new("block_depth_histogram", TableDataType::Variant),
+            TableField::new("max_depth", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("block_overlap_histogram", TableDataType::Variant),
         ])
     }
 }
 
+fn build_histogram(mp: &BTreeMap<u32, u32>) -> JsonValue {
+    let objects = mp.iter().fold(
+        serde_json::Map::with_capacity(mp.len()),
+        |mut acc, (bucket, count)| {
+            acc.insert(format!("{:05}", bucket), json!(count));
+            acc
+        },
+    );
+    JsonValue::Object(objects)
+}
+
 // The histogram contains buckets with widths:
 // 1 to 16 with increments of 1.
 // For buckets larger than 16, increments of twice the width of the previous bucket (e.g. 32, 64, 128, …).
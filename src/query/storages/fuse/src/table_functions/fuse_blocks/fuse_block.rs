@@ -139,6 +139,8 @@ impl<'a> FuseBlock<'a> {
                 bloom_filter_size.push(NumberScalar::UInt64(block.bloom_filter_index_size));
             });
         }
+        // one row per block, a segment may contain many blocks.
+        let num_rows = bloom_filter_location.len();
 
         Ok(DataBlock::new(
             vec![
@@ -175,7 +177,7 @@ impl<'a> FuseBlock<'a> {
                     Value::Column(Column::Number(bloom_filter_size.build())),
                 ),
             ],
-            len,
+            num_rows,
         ))
     }
 
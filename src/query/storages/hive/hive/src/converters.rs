@@ -15,9 +15,11 @@
 use std::sync::Arc;
 
 use chrono::Utc;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::decimal::DecimalSize;
 use common_expression::types::DecimalDataType;
+use common_expression::types::NumberDataType;
 use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchema;
@@ -31,7 +33,6 @@ use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
 use common_sql::resolve_type_name_by_str;
 
-use crate::hive_catalog::HIVE_CATALOG;
 use crate::hive_database::HiveDatabase;
 use crate::hive_database::HIVE_DATABASE_ENGINE;
 use crate::hive_table::HIVE_TABLE_ENGINE;
@@ -58,6 +59,7 @@ impl From<hms::Database> for HiveDatabase {
 }
 
 pub fn try_into_table_info(
+    catalog: &str,
     hms_table: hms::Table,
     fields: Vec<hms::FieldSchema>,
 ) -> Result<TableInfo> {
@@ -88,7 +90,7 @@ pub fn try_into_table_info(
 
     let meta = TableMeta {
         schema,
-        catalog: HIVE_CATALOG.to_string(),
+        catalog: catalog.to_string(),
         engine: HIVE_TABLE_ENGINE.to_owned(),
         engine_options: table_options.into(),
         created_on: Utc::now(),
@@ -129,26 +131,174 @@ fn try_into_schema(hive_fields: Vec<hms::FieldSchema>) -> Result<TableSchema> {
     Ok(TableSchema::new(fields))
 }
 
+/// Map a hive column type, like `map<string,array<struct<a:int,b:decimal(10,2)>>>`,
+/// to the table data type.
+///
+/// https://cwiki.apache.org/confluence/display/Hive/LanguageManual+Types
 fn try_from_filed_type_name(type_name: impl AsRef<str>) -> Result<TableDataType> {
-    let name = type_name.as_ref().to_uppercase();
-    // TODO more mappings goes here
-    // https://cwiki.apache.org/confluence/display/Hive/LanguageManual+Types
-    // Hive string data type could be varchar(n), where n is the maximum number of characters
-    if name.starts_with("VARCHAR") {
-        Ok(TableDataType::String)
-    } else if name.starts_with("ARRAY<") {
-        let sub_type = &name["ARRAY<".len()..name.len() - 1];
-        let sub_type = try_from_filed_type_name(sub_type)?;
-        Ok(TableDataType::Array(Box::new(sub_type.wrap_nullable())))
-    } else {
-        match name.as_str() {
-            "DECIMAL" | "NUMERIC" => Ok(TableDataType::Decimal(DecimalDataType::Decimal128(
-                DecimalSize {
-                    precision: 10,
-                    scale: 0,
+    let type_name = type_name.as_ref().trim();
+    let (name, args) = match type_name.find(['<', '(']) {
+        Some(pos) => {
+            let args = type_name[pos + 1..]
+                .strip_suffix(['>', ')'])
+                .ok_or_else(|| invalid_type_name(type_name))?;
+            (type_name[..pos].trim().to_uppercase(), Some(args))
+        }
+        None => (type_name.to_uppercase(), None),
+    };
+
+    match (name.as_str(), args) {
+        ("BOOLEAN", None) => Ok(TableDataType::Boolean),
+        ("TINYINT", None) => Ok(TableDataType::Number(NumberDataType::Int8)),
+        ("SMALLINT", None) => Ok(TableDataType::Number(NumberDataType::Int16)),
+        ("INT" | "INTEGER", None) => Ok(TableDataType::Number(NumberDataType::Int32)),
+        ("BIGINT", None) => Ok(TableDataType::Number(NumberDataType::Int64)),
+        ("FLOAT", None) => Ok(TableDataType::Number(NumberDataType::Float32)),
+        ("DOUBLE" | "DOUBLE PRECISION", None) => Ok(TableDataType::Number(NumberDataType::Float64)),
+        // the length of varchar(n) and char(n) is not enforced while reading
+        ("STRING" | "BINARY", None) | ("VARCHAR" | "CHAR", Some(_)) => Ok(TableDataType::String),
+        ("DATE", None) => Ok(TableDataType::Date),
+        ("TIMESTAMP", None) => Ok(TableDataType::Timestamp),
+        ("DECIMAL" | "NUMERIC", args) => {
+            // hive defaults to decimal(10, 0)
+            let (precision, scale) = match args.map(split_type_args) {
+                None => (10, 0),
+                Some(args) => match args.as_slice() {
+                    [p] => (parse_decimal_arg(p, type_name)?, 0),
+                    [p, s] => (
+                        parse_decimal_arg(p, type_name)?,
+                        parse_decimal_arg(s, type_name)?,
+                    ),
+                    _ => return Err(invalid_type_name(type_name)),
                 },
-            ))),
-            _ => resolve_type_name_by_str(name.as_str()),
+            };
+            Ok(TableDataType::Decimal(DecimalDataType::from_size(
+                DecimalSize { precision, scale },
+            )?))
+        }
+        ("ARRAY", Some(args)) => {
+            let inner_type = try_from_filed_type_name(args)?;
+            Ok(TableDataType::Array(Box::new(inner_type.wrap_nullable())))
+        }
+        ("MAP", Some(args)) => match split_type_args(args).as_slice() {
+            [key, value] => {
+                let key_type = try_from_filed_type_name(key)?;
+                let value_type = try_from_filed_type_name(value)?.wrap_nullable();
+                Ok(TableDataType::Map(Box::new(TableDataType::Tuple {
+                    fields_name: vec!["key".to_string(), "value".to_string()],
+                    fields_type: vec![key_type, value_type],
+                })))
+            }
+            _ => Err(invalid_type_name(type_name)),
+        },
+        ("STRUCT", Some(args)) => {
+            let mut fields_name = vec![];
+            let mut fields_type = vec![];
+            for field in split_type_args(args) {
+                let (field_name, field_type) = field
+                    .split_once(':')
+                    .ok_or_else(|| invalid_type_name(type_name))?;
+                fields_name.push(field_name.trim().to_string());
+                fields_type.push(try_from_filed_type_name(field_type)?.wrap_nullable());
+            }
+            Ok(TableDataType::Tuple {
+                fields_name,
+                fields_type,
+            })
+        }
+        _ => resolve_type_name_by_str(type_name),
+    }
+}
+
+/// Split the arguments of a type on the top level commas,
+/// `int,map<string,int>` gives `["int", "map<string,int>"]`.
+fn split_type_args(args: &str) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in args.char_indices() {
+        match c {
+            '<' | '(' => depth += 1,
+            '>' | ')' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(args[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(args[start..].trim());
+    parts
+}
+
+fn parse_decimal_arg(arg: &str, type_name: &str) -> Result<u8> {
+    arg.trim()
+        .parse::<u8>()
+        .map_err(|_| invalid_type_name(type_name))
+}
+
+fn invalid_type_name(type_name: &str) -> ErrorCode {
+    ErrorCode::TableInfoError(format!("unsupported hive column type {type_name}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use common_expression::types::decimal::DecimalSize;
+    use common_expression::types::DecimalDataType;
+    use common_expression::types::NumberDataType;
+    use common_expression::TableDataType;
+
+    use super::try_from_filed_type_name;
+
+    #[test]
+    fn test_hive_type_names() {
+        let int32 = TableDataType::Number(NumberDataType::Int32);
+        let cases = vec![
+            ("int", int32.clone()),
+            ("varchar(10)", TableDataType::String),
+            (
+                "decimal(38,4)",
+                TableDataType::Decimal(DecimalDataType::Decimal128(DecimalSize {
+                    precision: 38,
+                    scale: 4,
+                })),
+            ),
+            (
+                "array<int>",
+                TableDataType::Array(Box::new(int32.wrap_nullable())),
+            ),
+            (
+                "map<string,array<int>>",
+                TableDataType::Map(Box::new(TableDataType::Tuple {
+                    fields_name: vec!["key".to_string(), "value".to_string()],
+                    fields_type: vec![
+                        TableDataType::String,
+                        TableDataType::Array(Box::new(int32.wrap_nullable())).wrap_nullable(),
+                    ],
+                })),
+            ),
+            (
+                "struct<Id:int,tags:map<string,string>>",
+                TableDataType::Tuple {
+                    fields_name: vec!["Id".to_string(), "tags".to_string()],
+                    fields_type: vec![
+                        int32.wrap_nullable(),
+                        TableDataType::Map(Box::new(TableDataType::Tuple {
+                            fields_name: vec!["key".to_string(), "value".to_string()],
+                            fields_type: vec![
+                                TableDataType::String,
+                                TableDataType::String.wrap_nullable(),
+                            ],
+                        }))
+                        .wrap_nullable(),
+                    ],
+                },
+            ),
+        ];
+
+        for (name, expected) in cases {
+            assert_eq!(try_from_filed_type_name(name).unwrap(), expected, "{name}");
         }
+        assert!(try_from_filed_type_name("map<string>").is_err());
     }
 }
@@ -50,6 +50,8 @@ use common_meta_app::schema::GetTableCopiedFileReply;
 use common_meta_app::schema::GetTableCopiedFileReq;
 use common_meta_app::schema::IndexMeta;
 use common_meta_app::schema::ListIndexesReq;
+use common_meta_app::schema::ListTableCopiedFileReply;
+use common_meta_app::schema::ListTableCopiedFileReq;
 use common_meta_app::schema::ListVirtualColumnsReq;
 use common_meta_app::schema::RenameDatabaseReply;
 use common_meta_app::schema::RenameDatabaseReq;
@@ -64,6 +66,8 @@ use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpdateVirtualColumnReply;
@@ -78,17 +82,21 @@ use thrift::transport::*;
 use super::hive_database::HiveDatabase;
 use crate::hive_table::HiveTable;
 
-pub const HIVE_CATALOG: &str = "hive";
-
 #[derive(Clone)]
 pub struct HiveCatalog {
+    /// name of the catalog, recorded in the meta of its tables
+    name: String,
     /// address of hive meta store service
     client_address: String,
 }
 
 impl HiveCatalog {
-    pub fn try_create(hms_address: impl Into<String>) -> Result<HiveCatalog> {
+    pub fn try_create(
+        name: impl Into<String>,
+        hms_address: impl Into<String>,
+    ) -> Result<HiveCatalog> {
         Ok(HiveCatalog {
+            name: name.into(),
             client_address: hms_address.into(),
         })
     }
@@ -141,6 +149,27 @@ impl HiveCatalog {
         Ok(partitions)
     }
 
+    /// Get the partitions matching `filter`, evaluated by the metastore.
+    ///
+    /// The filter is in the syntax of the metastore, like `year = 2023 and region = "eu"`.
+    #[tracing::instrument(level = "info", skip(self))]
+    #[async_backtrace::framed]
+    pub async fn get_partitions_by_filter(
+        &self,
+        db: String,
+        table: String,
+        filter: String,
+    ) -> Result<Vec<Partition>> {
+        let mut client = self.get_client()?;
+        tokio::task::spawn_blocking(move || {
+            client
+                .get_partitions_by_filter(db, table, filter, -1)
+                .map_err(from_thrift_error)
+        })
+        .await
+        .unwrap()
+    }
+
     #[tracing::instrument(level = "info", skip(self))]
     #[async_backtrace::framed]
     pub async fn get_partition_names(
@@ -170,11 +199,11 @@ impl HiveCatalog {
     }
 
     fn do_get_table(
-        client: impl TThriftHiveMetastoreSyncClient,
+        client: &mut impl TThriftHiveMetastoreSyncClient,
+        catalog: &str,
         db_name: String,
         table_name: String,
     ) -> Result<Arc<dyn Table>> {
-        let mut client = client;
         let table = client.get_table(db_name.clone(), table_name.clone());
         let table_meta = match table {
             Ok(table_meta) => table_meta,
@@ -212,16 +241,40 @@ impl HiveCatalog {
         let fields = client
             .get_schema(db_name, table_name)
             .map_err(from_thrift_error)?;
-        let table_info: TableInfo = super::converters::try_into_table_info(table_meta, fields)?;
+        let table_info: TableInfo =
+            super::converters::try_into_table_info(catalog, table_meta, fields)?;
         let res: Arc<dyn Table> = Arc::new(HiveTable::try_create(table_info)?);
         Ok(res)
     }
 
-    fn do_get_database(
+    fn do_list_tables(
         client: impl TThriftHiveMetastoreSyncClient,
+        catalog: &str,
         db_name: String,
-    ) -> Result<Arc<dyn Database>> {
+    ) -> Result<Vec<Arc<dyn Table>>> {
         let mut client = client;
+        let table_names = client
+            .get_all_tables(db_name.clone())
+            .map_err(from_thrift_error)?;
+
+        let mut tables = Vec::with_capacity(table_names.len());
+        for table_name in table_names {
+            match Self::do_get_table(&mut client, catalog, db_name.clone(), table_name.clone()) {
+                Ok(table) => tables.push(table),
+                // views and tables in formats not supported yet are left out
+                Err(e) if e.code() == ErrorCode::UNIMPLEMENTED => {
+                    tracing::debug!("skip hive table {}.{}: {}", db_name, table_name, e);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(tables)
+    }
+
+    fn do_get_database(
+        client: &mut impl TThriftHiveMetastoreSyncClient,
+        db_name: String,
+    ) -> Result<Arc<dyn Database>> {
         let thrift_db_meta = client.get_database(db_name).map_err(from_thrift_error)?;
         let hive_database: HiveDatabase = thrift_db_meta.into();
         let res: Arc<dyn Database> = Arc::new(hive_database);
@@ -243,10 +296,10 @@ impl Catalog for HiveCatalog {
     #[tracing::instrument(level = "info", skip(self))]
     #[async_backtrace::framed]
     async fn get_database(&self, _tenant: &str, db_name: &str) -> Result<Arc<dyn Database>> {
-        let client = self.get_client()?;
+        let mut client = self.get_client()?;
         let _tenant = _tenant.to_string();
         let db_name = db_name.to_string();
-        tokio::task::spawn_blocking(move || Self::do_get_database(client, db_name))
+        tokio::task::spawn_blocking(move || Self::do_get_database(&mut client, db_name))
             .await
             .unwrap()
     }
@@ -254,7 +307,16 @@ impl Catalog for HiveCatalog {
     // Get all the databases.
     #[async_backtrace::framed]
     async fn list_databases(&self, _tenant: &str) -> Result<Vec<Arc<dyn Database>>> {
-        todo!()
+        let mut client = self.get_client()?;
+        tokio::task::spawn_blocking(move || {
+            let db_names = client.get_all_databases().map_err(from_thrift_error)?;
+            db_names
+                .into_iter()
+                .map(|db_name| Self::do_get_database(&mut client, db_name))
+                .collect()
+        })
+        .await
+        .unwrap()
     }
 
     // Operation with database.
@@ -310,17 +372,25 @@ impl Catalog for HiveCatalog {
         db_name: &str,
         table_name: &str,
     ) -> Result<Arc<dyn Table>> {
-        let client = self.get_client()?;
+        let mut client = self.get_client()?;
         let db_name = db_name.to_string();
         let table_name = table_name.to_string();
-        tokio::task::spawn_blocking(move || Self::do_get_table(client, db_name, table_name))
-            .await
-            .unwrap()
+        let catalog = self.name.clone();
+        tokio::task::spawn_blocking(move || {
+            Self::do_get_table(&mut client, &catalog, db_name, table_name)
+        })
+        .await
+        .unwrap()
     }
 
     #[async_backtrace::framed]
-    async fn list_tables(&self, _tenant: &str, _db_name: &str) -> Result<Vec<Arc<dyn Table>>> {
-        todo!()
+    async fn list_tables(&self, _tenant: &str, db_name: &str) -> Result<Vec<Arc<dyn Table>>> {
+        let client = self.get_client()?;
+        let catalog = self.name.clone();
+        let db_name = db_name.to_string();
+        tokio::task::spawn_blocking(move || Self::do_list_tables(client, &catalog, db_name))
+            .await
+            .unwrap()
     }
 
     #[async_backtrace::framed]
@@ -401,6 +471,16 @@ impl Catalog for HiveCatalog {
         ))
     }
 
+    #[async_backtrace::framed]
+    async fn update_multi_table_meta(
+        &self,
+        _req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply> {
+        Err(ErrorCode::Unimplemented(
+            "Cannot update table meta in HIVE catalog",
+        ))
+    }
+
     #[async_backtrace::framed]
     async fn get_table_copied_file_info(
         &self,
@@ -411,6 +491,16 @@ impl Catalog for HiveCatalog {
         unimplemented!()
     }
 
+    #[async_backtrace::framed]
+    async fn list_table_copied_file_info(
+        &self,
+        _tenant: &str,
+        _db_name: &str,
+        _req: ListTableCopiedFileReq,
+    ) -> Result<ListTableCopiedFileReply> {
+        unimplemented!()
+    }
+
     #[async_backtrace::framed]
     async fn truncate_table(
         &self,
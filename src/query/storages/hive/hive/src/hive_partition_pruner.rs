@@ -18,7 +18,10 @@ use std::vec;
 
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::DataType;
 use common_expression::Expr;
+use common_expression::Scalar;
 use common_expression::TableSchema;
 use storages_common_index::RangeIndex;
 use storages_common_table_meta::meta::ColumnStatistics;
@@ -101,3 +104,116 @@ impl HivePartitionPruner {
         Ok(filtered_partitions)
     }
 }
+
+/// Translate the part of `filter` on the partition keys to a filter of the hive metastore,
+/// which is evaluated by the metastore to list only the matching partitions.
+///
+/// Conjuncts that can not be translated are dropped, so the partitions listed are a superset
+/// of the ones matching `filter`, `None` is returned if nothing can be translated.
+pub fn metastore_filter(filter: &Expr<String>, partition_schema: &TableSchema) -> Option<String> {
+    match filter {
+        Expr::FunctionCall { function, args, .. } => {
+            let name = function.signature.name.as_str();
+            match name {
+                "and" | "and_filters" => {
+                    let conjuncts = args
+                        .iter()
+                        .filter_map(|arg| metastore_filter(arg, partition_schema))
+                        .collect::<Vec<_>>();
+                    match conjuncts.len() {
+                        0 => None,
+                        1 => conjuncts.into_iter().next(),
+                        _ => Some(format!("({})", conjuncts.join(" and "))),
+                    }
+                }
+                "or" => {
+                    let disjuncts = args
+                        .iter()
+                        .map(|arg| metastore_filter(arg, partition_schema))
+                        .collect::<Option<Vec<_>>>()?;
+                    Some(format!("({})", disjuncts.join(" or ")))
+                }
+                "eq" | "noteq" | "lt" | "lte" | "gt" | "gte" if args.len() == 2 => {
+                    let (op, flipped_op) = match name {
+                        "eq" => ("=", "="),
+                        "noteq" => ("<>", "<>"),
+                        "lt" => ("<", ">"),
+                        "lte" => ("<=", ">="),
+                        "gt" => (">", "<"),
+                        _ => (">=", "<="),
+                    };
+                    if let Some(key) = partition_key(&args[0], partition_schema) {
+                        let value = literal(&args[1], key.1)?;
+                        Some(format!("{} {} {}", key.0, op, value))
+                    } else {
+                        let key = partition_key(&args[1], partition_schema)?;
+                        let value = literal(&args[0], key.1)?;
+                        Some(format!("{} {} {}", key.0, flipped_op, value))
+                    }
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// The partition key referred by `expr`, with whether it is a string key.
+fn partition_key<'a>(
+    expr: &'a Expr<String>,
+    partition_schema: &TableSchema,
+) -> Option<(&'a str, bool)> {
+    let (id, data_type) = match expr {
+        Expr::ColumnRef { id, data_type, .. } => (id, data_type),
+        // integers are compared as integers by the metastore, widening them is fine
+        Expr::Cast {
+            expr: inner,
+            dest_type,
+            ..
+        } if is_integer(dest_type) => match inner.as_ref() {
+            Expr::ColumnRef { id, data_type, .. } if is_integer(data_type) => (id, data_type),
+            _ => return None,
+        },
+        _ => return None,
+    };
+    partition_schema.field_with_name(id).ok()?;
+    match data_type.remove_nullable() {
+        DataType::String => Some((id.as_str(), true)),
+        ty if is_integer(&ty) => Some((id.as_str(), false)),
+        _ => None,
+    }
+}
+
+fn is_integer(data_type: &DataType) -> bool {
+    matches!(data_type.remove_nullable(), DataType::Number(n) if !n.is_float())
+}
+
+fn literal(expr: &Expr<String>, is_string_key: bool) -> Option<String> {
+    match expr {
+        Expr::Constant {
+            scalar: Scalar::String(s),
+            ..
+        } if is_string_key => {
+            let s = std::str::from_utf8(s).ok()?;
+            Some(format!(
+                "\"{}\"",
+                s.replace('\\', "\\\\").replace('"', "\\\"")
+            ))
+        }
+        Expr::Constant {
+            scalar: Scalar::Number(n),
+            ..
+        } if !is_string_key => match n {
+            NumberScalar::UInt8(v) => Some(v.to_string()),
+            NumberScalar::UInt16(v) => Some(v.to_string()),
+            NumberScalar::UInt32(v) => Some(v.to_string()),
+            NumberScalar::UInt64(v) => Some(v.to_string()),
+            NumberScalar::Int8(v) => Some(v.to_string()),
+            NumberScalar::Int16(v) => Some(v.to_string()),
+            NumberScalar::Int32(v) => Some(v.to_string()),
+            NumberScalar::Int64(v) => Some(v.to_string()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Instant;
@@ -19,7 +20,6 @@ use std::time::Instant;
 use async_recursion::async_recursion;
 use common_base::base::tokio;
 use common_base::base::tokio::sync::Semaphore;
-use common_catalog::catalog_kind::CATALOG_HIVE;
 use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartStatistics;
 use common_catalog::plan::Partitions;
@@ -59,11 +59,13 @@ use storages_common_index::RangeIndex;
 use storages_common_table_meta::meta::StatisticsOfColumns;
 
 use super::hive_catalog::HiveCatalog;
+use super::hive_partition_pruner::metastore_filter;
 use super::hive_partition_pruner::HivePartitionPruner;
 use super::hive_table_options::HiveTableOptions;
 use crate::filter_hive_partition_from_partition_keys;
 use crate::hive_parquet_block_reader::HiveBlockReader;
 use crate::hive_table_source::HiveTableSource;
+use crate::utils::make_partition_name;
 use crate::HiveBlockFilter;
 use crate::HiveFileSplitter;
 
@@ -389,14 +391,57 @@ impl HiveTable {
         partition_keys: Vec<String>,
         filter_expression: Option<Expr<String>>,
     ) -> Result<Vec<(String, Option<String>)>> {
-        let hive_catalog = ctx.get_catalog(CATALOG_HIVE)?;
+        let hive_catalog = ctx.get_catalog(&self.table_info.meta.catalog)?;
         let hive_catalog = hive_catalog.as_any().downcast_ref::<HiveCatalog>().unwrap();
 
-        // todo may use get_partition_names_ps to filter
         let table_info = self.table_info.desc.split('.').collect::<Vec<&str>>();
-        let mut partition_names = hive_catalog
-            .get_partition_names(table_info[0].to_string(), table_info[1].to_string(), -1)
-            .await?;
+        let partition_schemas = self.get_column_schemas(partition_keys.clone())?;
+
+        // let the metastore filter the partitions by the keys first, which saves
+        // listing all the partitions of tables with lots of them.
+        let mut listed = None;
+        if let Some(filter) = filter_expression
+            .as_ref()
+            .and_then(|expr| metastore_filter(expr, &partition_schemas))
+        {
+            match hive_catalog
+                .get_partitions_by_filter(
+                    table_info[0].to_string(),
+                    table_info[1].to_string(),
+                    filter.clone(),
+                )
+                .await
+            {
+                Ok(partitions) => {
+                    let names = partitions
+                        .iter()
+                        .map(|p| {
+                            make_partition_name(
+                                &partition_keys,
+                                p.values.as_deref().unwrap_or_default(),
+                            )
+                        })
+                        .collect::<Vec<_>>();
+                    listed = Some((names, partitions));
+                }
+                // e.g. the metastore may not support filtering on integral keys
+                Err(e) => tracing::warn!(
+                    "hive metastore failed to filter partitions by `{}`, list all of them instead: {}",
+                    filter,
+                    e
+                ),
+            }
+        }
+
+        let (mut partition_names, partitions) = match listed {
+            Some((names, partitions)) => (names, Some(partitions)),
+            None => {
+                let names = hive_catalog
+                    .get_partition_names(table_info[0].to_string(), table_info[1].to_string(), -1)
+                    .await?;
+                (names, None)
+            }
+        };
 
         if tracing::enabled!(tracing::Level::TRACE) {
             let partition_num = partition_names.len();
@@ -412,7 +457,6 @@ impl HiveTable {
         }
 
         if let Some(expr) = filter_expression {
-            let partition_schemas = self.get_column_schemas(partition_keys.clone())?;
             let partition_pruner =
                 HivePartitionPruner::create(ctx, expr, partition_schemas, self.table_info.schema());
             partition_names = partition_pruner.prune(partition_names)?;
@@ -426,13 +470,33 @@ impl HiveTable {
             )
         }
 
-        let partitions = hive_catalog
-            .get_partitions(
-                table_info[0].to_string(),
-                table_info[1].to_string(),
-                partition_names.clone(),
-            )
-            .await?;
+        let partitions = match partitions {
+            Some(partitions) => {
+                let mut partitions = partitions
+                    .into_iter()
+                    .map(|p| {
+                        let name = make_partition_name(
+                            &partition_keys,
+                            p.values.as_deref().unwrap_or_default(),
+                        );
+                        (name, p)
+                    })
+                    .collect::<HashMap<_, _>>();
+                partition_names
+                    .iter()
+                    .filter_map(|name| partitions.remove(name))
+                    .collect()
+            }
+            None => {
+                hive_catalog
+                    .get_partitions(
+                        table_info[0].to_string(),
+                        table_info[1].to_string(),
+                        partition_names.clone(),
+                    )
+                    .await?
+            }
+        };
         let res = partitions
             .into_iter()
             .map(|p| convert_hdfs_path(&p.sd.unwrap().location.unwrap(), true))
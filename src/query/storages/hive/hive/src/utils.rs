@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt::Write;
+
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::number::NumberScalar;
@@ -80,3 +82,53 @@ pub(crate) fn str_field_to_scalar(value: &str, data_type: &DataType) -> Result<S
         ))),
     }
 }
+
+/// Name of the partition with `values` of the partition keys, like `c_region=Asia/c_nation=China`,
+/// escaped the same way as the partition names listed by the hive metastore.
+pub(crate) fn make_partition_name(keys: &[String], values: &[String]) -> String {
+    let mut name = String::new();
+    for (key, value) in keys.iter().zip(values.iter()) {
+        if !name.is_empty() {
+            name.push('/');
+        }
+        let value = if value.is_empty() {
+            HIVE_DEFAULT_PARTITION
+        } else {
+            value.as_str()
+        };
+        write!(
+            name,
+            "{}={}",
+            escape_path_name(key),
+            escape_path_name(value)
+        )
+        .unwrap();
+    }
+    name
+}
+
+fn escape_path_name(name: &str) -> String {
+    let mut escaped = String::with_capacity(name.len());
+    for c in name.chars() {
+        match c {
+            '\u{00}'..='\u{1F}'
+            | '"'
+            | '#'
+            | '%'
+            | '\''
+            | '*'
+            | '/'
+            | ':'
+            | '='
+            | '?'
+            | '\\'
+            | '\u{7F}'
+            | '{'
+            | '['
+            | ']'
+            | '^' => write!(escaped, "%{:02X}", c as u32).unwrap(),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -15,26 +15,33 @@
 //! this module is used for converting iceberg data types, schemas and other metadata
 //! to databend
 
+use std::collections::BTreeMap;
+
 use chrono::Utc;
 use common_expression::types::decimal::DecimalSize;
 use common_expression::types::DecimalDataType;
 use common_expression::types::NumberDataType;
+use common_expression::ColumnId;
 use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchema;
 use common_meta_app::schema::TableMeta;
 use common_meta_app::storage::StorageParams;
 use icelake::types;
-use itertools::Itertools;
 
-/// generate TableMeta from Iceberg table meta
+/// generate TableMeta from Iceberg table meta, with the current schema of the table
 pub(crate) fn meta_iceberg_to_databend(
     catalog: &str,
     storage_params: &StorageParams,
     meta: &types::TableMetadata,
 ) -> TableMeta {
-    let schema = match meta.schemas.last() {
-        Some(scm) => schema_iceberg_to_databend(scm),
+    let current = meta
+        .schemas
+        .iter()
+        .find(|scm| scm.schema_id == meta.current_schema_id)
+        .or_else(|| meta.schemas.last());
+    let schema = match current {
+        Some(scm) => schema_iceberg_to_databend(scm, meta.last_column_id),
         // empty schema
         None => TableSchema::empty(),
     }
@@ -51,14 +58,20 @@ pub(crate) fn meta_iceberg_to_databend(
 }
 
 /// generate databend DataSchema from Iceberg
-pub(super) fn schema_iceberg_to_databend(schema: &types::Schema) -> TableSchema {
+///
+/// Fields keep the order they are declared in, and the iceberg field ids are
+/// used as column ids, so columns can be matched against data files by id
+/// after they have been renamed or reordered.
+pub(super) fn schema_iceberg_to_databend(
+    schema: &types::Schema,
+    last_column_id: i32,
+) -> TableSchema {
     let fields = schema
         .fields
         .iter()
-        .sorted_by_key(|f| f.id)
         .map(struct_field_iceberg_to_databend)
         .collect();
-    TableSchema::new(fields)
+    TableSchema::new_from_column_ids(fields, BTreeMap::new(), last_column_id as ColumnId + 1)
 }
 
 fn struct_field_iceberg_to_databend(sf: &types::Field) -> TableField {
@@ -66,18 +79,17 @@ fn struct_field_iceberg_to_databend(sf: &types::Field) -> TableField {
     let ty = primitive_iceberg_to_databend(&sf.field_type);
 
     if sf.required {
-        TableField::new(name, ty)
+        TableField::new_from_column_id(name, ty, sf.id as ColumnId)
     } else {
-        TableField::new(name, ty.wrap_nullable())
+        TableField::new_from_column_id(name, ty.wrap_nullable(), sf.id as ColumnId)
     }
 }
 
-// TODO: reject nested Struct
 fn primitive_iceberg_to_databend(prim: &types::Any) -> TableDataType {
     match prim {
         types::Any::Primitive(p) => match p {
             types::Primitive::Boolean => TableDataType::Boolean,
-            types::Primitive::Int => TableDataType::Number(NumberDataType::UInt32),
+            types::Primitive::Int => TableDataType::Number(NumberDataType::Int32),
             types::Primitive::Long => TableDataType::Number(NumberDataType::Int64),
             types::Primitive::Float => TableDataType::Number(NumberDataType::Float32),
             types::Primitive::Double => TableDataType::Number(NumberDataType::Float64),
@@ -93,8 +105,8 @@ fn primitive_iceberg_to_databend(prim: &types::Any) -> TableDataType {
                 TableDataType::Date
             }
             types::Primitive::Time => {
-                // not supported, time without date
-                unimplemented!()
+                // time without date, microseconds since midnight
+                TableDataType::Number(NumberDataType::Int64)
             }
             types::Primitive::Timestamp => TableDataType::Timestamp,
             types::Primitive::Timestampz => TableDataType::Timestamp,
@@ -107,13 +119,14 @@ fn primitive_iceberg_to_databend(prim: &types::Any) -> TableDataType {
             let (names, fields): (Vec<String>, Vec<TableDataType>) = s
                 .fields
                 .iter()
-                // reading as is?
-                .sorted_by_key(|f| f.id)
                 .map(|field| {
-                    (
-                        field.name.clone(),
-                        primitive_iceberg_to_databend(&field.field_type),
-                    )
+                    let ty = primitive_iceberg_to_databend(&field.field_type);
+                    let ty = if field.required {
+                        ty
+                    } else {
+                        ty.wrap_nullable()
+                    };
+                    (field.name.clone(), ty)
                 })
                 .unzip();
 
@@ -134,9 +147,23 @@ fn primitive_iceberg_to_databend(prim: &types::Any) -> TableDataType {
                 TableDataType::Array(Box::new(TableDataType::Nullable(Box::new(element_type))))
             }
         }
-        types::Any::Map(_) => {
-            // wait for new expression support to complete
-            unimplemented!()
+        types::Any::Map(types::Map {
+            key_type,
+            value_required,
+            value_type,
+            ..
+        }) => {
+            let key_type = primitive_iceberg_to_databend(key_type);
+            let value_type = primitive_iceberg_to_databend(value_type);
+            let value_type = if *value_required {
+                value_type
+            } else {
+                value_type.wrap_nullable()
+            };
+            TableDataType::Map(Box::new(TableDataType::Tuple {
+                fields_name: vec!["key".to_string(), "value".to_string()],
+                fields_type: vec![key_type, value_type],
+            }))
         }
     }
 }
@@ -217,5 +244,10 @@ mod convert_test {
 
         assert_eq!(converted.engine, "iceberg");
         assert_eq!(converted.catalog, "ctl");
+
+        // iceberg field ids are kept as column ids.
+        let field = converted.schema.field_with_name("struct_name").unwrap();
+        assert_eq!(field.column_id(), 1);
+        assert_eq!(converted.schema.next_column_id(), 2);
     }
 }
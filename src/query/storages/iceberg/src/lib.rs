@@ -49,6 +49,16 @@
 //! ```sql
 //! SELECT * FROM icb_ctl.db0.tbl1;
 //! ```
+//!
+//! ## Time Travel
+//!
+//! The current snapshot of a table is read by default, position and equality delete files
+//! of iceberg v2 tables are applied while reading. Older snapshots can be read by their
+//! snapshot id or by a time point, with the schema the snapshot was written with:
+//! ```sql
+//! SELECT * FROM icb_ctl.db0.tbl1 AT (SNAPSHOT => '5179288593441340232');
+//! SELECT * FROM icb_ctl.db0.tbl1 AT (TIMESTAMP => '2023-06-01 00:00:00'::TIMESTAMP);
+//! ```
 //! ## Flatten Catalogs
 //!
 //! There may also some iceberg storages barely storing tables in the root directory,
@@ -94,10 +104,13 @@ mod converters;
 /// database implementation
 mod database;
 /// table metadata reader
-#[allow(unused)]
 mod meta_reader;
+/// partition information of data files
+mod partition;
 /// table implementation
 mod table;
+/// data file reader
+mod table_source;
 
 pub use catalog::IcebergCatalog;
 pub use catalog::ICEBERG_CATALOG;
@@ -13,3 +13,151 @@
 // limitations under the License.
 
 //! this module contains metadata reader utilities for table metadata
+
+use std::collections::HashSet;
+
+use common_catalog::plan::PartInfoPtr;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use futures::future::try_join_all;
+use icelake::types;
+use opendal::Operator;
+
+use crate::partition::IcebergDeleteFile;
+use crate::partition::IcebergPartInfo;
+
+/// A live file of a snapshot, with the data sequence number it inherits from its manifest.
+struct ScannedFile {
+    file: types::DataFile,
+    sequence_number: i64,
+    spec_id: i32,
+    /// the partition tuple, rendered for comparison.
+    partition: String,
+}
+
+impl ScannedFile {
+    fn same_partition(&self, other: &ScannedFile) -> bool {
+        self.spec_id == other.spec_id && self.partition == other.partition
+    }
+}
+
+/// Paths in iceberg metadata are absolute, like `s3://bkt/wh/db/tbl/data/00000.parquet`,
+/// while the table operator is rooted at the table location.
+///
+/// Schemes are ignored in the comparison, since writers mix up `s3://` and `s3a://`.
+pub(crate) fn relative_path(table_location: &str, path: &str) -> Result<String> {
+    fn strip_scheme(s: &str) -> &str {
+        s.split_once("://").map_or(s, |(_, rest)| rest)
+    }
+
+    let location = strip_scheme(table_location).trim_end_matches('/');
+    match strip_scheme(path).strip_prefix(location) {
+        Some(rel) => Ok(rel.trim_start_matches('/').to_string()),
+        None => Err(ErrorCode::ReadTableDataError(format!(
+            "file {path} is not under the table location {table_location}"
+        ))),
+    }
+}
+
+/// Read the manifest list of `snapshot` and all its manifests, and turn the live data
+/// files into partitions, each one carrying the delete files that apply to it.
+///
+/// A position delete applies to the data files of the same partition whose data sequence
+/// number is not greater than its own, an equality delete to the ones whose data sequence
+/// number is strictly smaller, globally if it was written with an unpartitioned spec.
+#[async_backtrace::framed]
+pub(crate) async fn plan_snapshot_files(
+    op: &Operator,
+    metadata: &types::TableMetadata,
+    snapshot: &types::Snapshot,
+) -> Result<Vec<PartInfoPtr>> {
+    let manifest_list_path = relative_path(&metadata.location, &snapshot.manifest_list)?;
+    let bs = op.read(&manifest_list_path).await?;
+    let manifest_list = types::parse_manifest_list(&bs).map_err(|e| {
+        ErrorCode::ReadTableDataError(format!(
+            "invalid manifest list {}: {:?}",
+            &manifest_list_path, e
+        ))
+    })?;
+
+    let manifests = try_join_all(manifest_list.entries.iter().map(|manifest| async move {
+        let path = relative_path(&metadata.location, &manifest.manifest_path)?;
+        let bs = op.read(&path).await?;
+        let file = types::parse_manifest_file(&bs).map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("invalid manifest {}: {:?}", &path, e))
+        })?;
+        Ok::<_, ErrorCode>((manifest, file))
+    }))
+    .await?;
+
+    let mut data_files = vec![];
+    let mut delete_files = vec![];
+    for (manifest, manifest_file) in manifests {
+        for entry in manifest_file.entries {
+            if matches!(entry.status, types::ManifestStatus::Deleted) {
+                continue;
+            }
+            let scanned = ScannedFile {
+                sequence_number: entry.sequence_number.unwrap_or(manifest.sequence_number),
+                spec_id: manifest.partition_spec_id,
+                partition: format!("{:?}", entry.data_file.partition),
+                file: entry.data_file,
+            };
+            match scanned.file.content {
+                types::DataContentType::Data => data_files.push(scanned),
+                _ => delete_files.push(scanned),
+            }
+        }
+    }
+
+    let unpartitioned_specs = metadata
+        .partition_specs
+        .iter()
+        .filter(|spec| spec.fields.is_empty())
+        .map(|spec| spec.spec_id)
+        .collect::<HashSet<_>>();
+
+    let mut parts = Vec::with_capacity(data_files.len());
+    for data in data_files {
+        if !matches!(data.file.file_format, types::DataFileFormat::Parquet) {
+            return Err(ErrorCode::Unimplemented(format!(
+                "iceberg data file {} is not parquet, only parquet data files are supported",
+                data.file.file_path
+            )));
+        }
+
+        let mut deletes = vec![];
+        for delete in delete_files.iter() {
+            let path = relative_path(&metadata.location, &delete.file.file_path)?;
+            match delete.file.content {
+                types::DataContentType::EqualityDeletes => {
+                    let global = unpartitioned_specs.contains(&delete.spec_id);
+                    if delete.sequence_number > data.sequence_number
+                        && (global || delete.same_partition(&data))
+                    {
+                        deletes.push(IcebergDeleteFile::Equality {
+                            path,
+                            equality_ids: delete.file.equality_ids.clone().unwrap_or_default(),
+                        });
+                    }
+                }
+                _ => {
+                    if delete.sequence_number >= data.sequence_number
+                        && delete.same_partition(&data)
+                    {
+                        deletes.push(IcebergDeleteFile::Position { path });
+                    }
+                }
+            }
+        }
+
+        parts.push(IcebergPartInfo::create(
+            relative_path(&metadata.location, &data.file.file_path)?,
+            data.file.file_path.clone(),
+            data.file.record_count as u64,
+            data.file.file_size_in_bytes as u64,
+            deletes,
+        ));
+    }
+    Ok(parts)
+}
@@ -0,0 +1,97 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::Arc;
+
+use common_catalog::plan::PartInfo;
+use common_catalog::plan::PartInfoPtr;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+/// A delete file that applies to the data file of an [`IcebergPartInfo`].
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
+pub enum IcebergDeleteFile {
+    /// rows are deleted by `(file_path, pos)`, `file_path` being the full location
+    /// of the data file as recorded in the manifest.
+    Position { path: String },
+    /// rows are deleted when the columns with `equality_ids` equal a row of the file.
+    Equality {
+        path: String,
+        equality_ids: Vec<i32>,
+    },
+}
+
+/// One data file of the snapshot being read, along with the delete files to apply.
+///
+/// Paths are relative to the table root.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct IcebergPartInfo {
+    pub path: String,
+    /// location of the data file as recorded in the manifest, position deletes refer to it.
+    pub location: String,
+    pub record_count: u64,
+    pub file_size: u64,
+    pub deletes: Vec<IcebergDeleteFile>,
+}
+
+#[typetag::serde(name = "iceberg")]
+impl PartInfo for IcebergPartInfo {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn PartInfo>) -> bool {
+        match info.as_any().downcast_ref::<IcebergPartInfo>() {
+            None => false,
+            Some(other) => self == other,
+        }
+    }
+
+    fn hash(&self) -> u64 {
+        let mut s = DefaultHasher::new();
+        self.path.hash(&mut s);
+        s.finish()
+    }
+}
+
+impl IcebergPartInfo {
+    pub fn create(
+        path: String,
+        location: String,
+        record_count: u64,
+        file_size: u64,
+        deletes: Vec<IcebergDeleteFile>,
+    ) -> PartInfoPtr {
+        Arc::new(Box::new(IcebergPartInfo {
+            path,
+            location,
+            record_count,
+            file_size,
+            deletes,
+        }))
+    }
+
+    pub fn from_part(info: &PartInfoPtr) -> Result<&IcebergPartInfo> {
+        match info.as_any().downcast_ref::<IcebergPartInfo>() {
+            Some(part_ref) => Ok(part_ref),
+            None => Err(ErrorCode::Internal(
+                "Cannot downcast from PartInfo to IcebergPartInfo.",
+            )),
+        }
+    }
+}
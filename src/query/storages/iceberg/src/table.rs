@@ -22,21 +22,33 @@ use std::any::Any;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::Utc;
+use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartStatistics;
 use common_catalog::plan::Partitions;
+use common_catalog::plan::PartitionsShuffleKind;
+use common_catalog::plan::Projection;
 use common_catalog::plan::PushDownInfo;
+use common_catalog::table::NavigationPoint;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
+use common_pipeline_core::Pipeline;
+use common_storage::init_operator;
 use common_storage::DataOperator;
 use futures::StreamExt;
 use icelake::types;
 use opendal::Operator;
 
 use crate::converters::meta_iceberg_to_databend;
+use crate::converters::schema_iceberg_to_databend;
+use crate::meta_reader::plan_snapshot_files;
+use crate::partition::IcebergPartInfo;
+use crate::table_source::IcebergTableSource;
 
 /// file marking the current version of metadata file
 const META_PTR: &str = "metadata/version_hint.text";
@@ -44,16 +56,19 @@ const META_PTR: &str = "metadata/version_hint.text";
 /// accessor wrapper as a table
 ///
 /// TODO: we should use icelake Table instead.
-#[allow(unused)]
+#[derive(Clone)]
 pub struct IcebergTable {
     /// database that belongs to
     database: String,
     /// name of the current table
     name: String,
     /// root of the table
-    tbl_root: DataOperator,
-    /// table metadata
-    manifests: types::TableMetadata,
+    op: Operator,
+    /// table metadata, only available on the node the table was loaded,
+    /// tables rebuilt from [`TableInfo`] are only able to read data.
+    metadata: Option<Arc<types::TableMetadata>>,
+    /// the snapshot navigated to, the current snapshot is read if not set
+    snapshot_id: Option<i64>,
     /// table information
     info: TableInfo,
 }
@@ -102,12 +117,82 @@ impl IcebergTable {
         Ok(Self {
             database: database.to_string(),
             name: table_name.to_string(),
-            tbl_root,
-            manifests: metadata,
+            op,
+            metadata: Some(Arc::new(metadata)),
+            snapshot_id: None,
             info,
         })
     }
 
+    /// rebuild the table from its [`TableInfo`], e.g. on the executors of a distributed query.
+    pub fn try_create(info: TableInfo) -> Result<IcebergTable> {
+        let sp = info.meta.storage_params.as_ref().ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "storage params of iceberg table {} are missing",
+                info.name
+            ))
+        })?;
+        let op = init_operator(sp)?;
+
+        Ok(Self {
+            database: String::new(),
+            name: info.name.clone(),
+            op,
+            metadata: None,
+            snapshot_id: None,
+            info,
+        })
+    }
+
+    fn metadata(&self) -> Result<&types::TableMetadata> {
+        self.metadata.as_deref().ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "metadata of iceberg table {} is not loaded",
+                self.name
+            ))
+        })
+    }
+
+    /// the snapshot to read, `None` if the table has no snapshot yet
+    fn snapshot(&self) -> Result<Option<&types::Snapshot>> {
+        let metadata = self.metadata()?;
+        let snapshot_id = match self.snapshot_id.or(metadata.current_snapshot_id) {
+            // -1 stands for no snapshot in metadata written by some writers
+            Some(id) if id >= 0 => id,
+            _ => return Ok(None),
+        };
+        let snapshot = metadata
+            .snapshots
+            .iter()
+            .flatten()
+            .find(|s| s.snapshot_id == snapshot_id);
+        match snapshot {
+            Some(snapshot) => Ok(Some(snapshot)),
+            None => Err(ErrorCode::ReadTableDataError(format!(
+                "snapshot {} of iceberg table {} not found",
+                snapshot_id, self.name
+            ))),
+        }
+    }
+
+    /// the same table, reading `snapshot` with the schema it was written with
+    fn with_snapshot(&self, snapshot: &types::Snapshot) -> Result<Arc<dyn Table>> {
+        let metadata = self.metadata()?;
+        let mut table = self.clone();
+        table.snapshot_id = Some(snapshot.snapshot_id);
+        if let Some(schema_id) = snapshot.schema_id {
+            if let Some(schema) = metadata
+                .schemas
+                .iter()
+                .find(|s| s.schema_id as i64 == schema_id)
+            {
+                table.info.meta.schema =
+                    Arc::new(schema_iceberg_to_databend(schema, metadata.last_column_id));
+            }
+        }
+        Ok(Arc::new(table))
+    }
+
     /// version_detect figures out the manifest list version of the table
     /// and gives the relative path from table root directory
     /// to latest metadata json file
@@ -169,12 +254,107 @@ impl Table for IcebergTable {
         &self.get_table_info().name
     }
 
+    fn benefit_column_prune(&self) -> bool {
+        true
+    }
+
     #[async_backtrace::framed]
     async fn read_partitions(
         &self,
         _ctx: Arc<dyn TableContext>,
-        _push_downs: Option<PushDownInfo>,
+        push_downs: Option<PushDownInfo>,
     ) -> Result<(PartStatistics, Partitions)> {
-        todo!()
+        if let Some(Projection::InnerColumns(_)) =
+            push_downs.as_ref().and_then(|p| p.projection.as_ref())
+        {
+            return Err(ErrorCode::Unimplemented(
+                "inner columns projection of iceberg tables is not supported",
+            ));
+        }
+
+        let snapshot = match self.snapshot()? {
+            Some(snapshot) => snapshot,
+            None => return Ok((PartStatistics::default(), Partitions::default())),
+        };
+        let parts = plan_snapshot_files(&self.op, self.metadata()?, snapshot).await?;
+
+        let mut read_rows = 0;
+        let mut read_bytes = 0;
+        let mut has_deletes = false;
+        for part in parts.iter() {
+            let part = IcebergPartInfo::from_part(part)?;
+            read_rows += part.record_count as usize;
+            read_bytes += part.file_size as usize;
+            has_deletes |= !part.deletes.is_empty();
+        }
+
+        // rows removed by delete files are only known after reading them.
+        let statistics = if has_deletes {
+            PartStatistics::new_estimated(None, read_rows, read_bytes, parts.len(), parts.len())
+        } else {
+            PartStatistics::new_exact(read_rows, read_bytes, parts.len(), parts.len())
+        };
+        Ok((
+            statistics,
+            Partitions::create_nolazy(PartitionsShuffleKind::Seq, parts),
+        ))
     }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let max_threads = std::cmp::max(1, std::cmp::min(plan.parts.len(), max_threads));
+
+        let table_schema = self.info.schema();
+        let output_schema = plan.schema();
+        pipeline.add_source(
+            |output| {
+                IcebergTableSource::create(
+                    ctx.clone(),
+                    output,
+                    self.op.clone(),
+                    table_schema.clone(),
+                    output_schema.clone(),
+                )
+            },
+            max_threads,
+        )
+    }
+
+    #[async_backtrace::framed]
+    async fn navigate_to(&self, point: &NavigationPoint) -> Result<Arc<dyn Table>> {
+        let metadata = self.metadata()?;
+        let snapshots = metadata.snapshots.as_deref().unwrap_or_default();
+        let snapshot = match point {
+            NavigationPoint::SnapshotID(snapshot_id) => {
+                let snapshot_id = snapshot_id.trim().parse::<i64>().map_err(|_| {
+                    ErrorCode::TableHistoricalDataNotFound(format!(
+                        "invalid iceberg snapshot id {snapshot_id}"
+                    ))
+                })?;
+                snapshots.iter().find(|s| s.snapshot_id == snapshot_id)
+            }
+            NavigationPoint::TimePoint(time_point) => snapshots
+                .iter()
+                .filter(|s| snapshot_time(s).map_or(false, |t| t <= *time_point))
+                .max_by_key(|s| s.timestamp_ms),
+        };
+
+        match snapshot {
+            Some(snapshot) => self.with_snapshot(snapshot),
+            None => Err(ErrorCode::TableHistoricalDataNotFound(format!(
+                "No historical data found of iceberg table {} at {:?}",
+                self.name, point
+            ))),
+        }
+    }
+}
+
+fn snapshot_time(snapshot: &types::Snapshot) -> Option<DateTime<Utc>> {
+    chrono::NaiveDateTime::from_timestamp_millis(snapshot.timestamp_ms)
+        .map(|t| DateTime::<Utc>::from_utc(t, Utc))
 }
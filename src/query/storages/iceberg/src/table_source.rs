@@ -0,0 +1,376 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reading the data files of an iceberg snapshot, with their delete files applied.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::fmt::Write;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use async_trait::unboxed_simple;
+use common_arrow::arrow::array::new_null_array;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_arrow::arrow::chunk::Chunk as ArrowChunk;
+use common_arrow::arrow::compute::cast::cast;
+use common_arrow::arrow::compute::cast::CastOptions;
+use common_arrow::arrow::datatypes::Field as ArrowField;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::io::parquet::read as pread;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::NumberDataType;
+use common_expression::ColumnId;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::ScalarRef;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+use opendal::Operator;
+
+use crate::partition::IcebergDeleteFile;
+use crate::partition::IcebergPartInfo;
+
+/// reserved field ids of the columns of position delete files.
+const DELETE_FILE_PATH_ID: ColumnId = 2147483546;
+const DELETE_POS_ID: ColumnId = 2147483545;
+
+/// row keys of an equality delete file.
+type EqualityDeletes = Arc<HashSet<String>>;
+/// deleted row positions of a position delete file, by data file location.
+type PositionDeletes = Arc<HashMap<String, Vec<u64>>>;
+
+pub struct IcebergTableSource {
+    ctx: Arc<dyn TableContext>,
+    op: Operator,
+    /// schema of the table, column ids being iceberg field ids.
+    table_schema: TableSchemaRef,
+    /// the projected columns to output.
+    output_schema: TableSchemaRef,
+
+    pending: VecDeque<DataBlock>,
+    // delete files are usually shared by many data files, keep them once read.
+    position_deletes: HashMap<String, PositionDeletes>,
+    equality_deletes: HashMap<String, EqualityDeletes>,
+}
+
+impl IcebergTableSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        op: Operator,
+        table_schema: TableSchemaRef,
+        output_schema: TableSchemaRef,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, IcebergTableSource {
+            ctx,
+            op,
+            table_schema,
+            output_schema,
+            pending: VecDeque::new(),
+            position_deletes: HashMap::new(),
+            equality_deletes: HashMap::new(),
+        })
+    }
+
+    #[async_backtrace::framed]
+    async fn read_part(&mut self, part: &IcebergPartInfo) -> Result<Vec<DataBlock>> {
+        // deleted positions in this data file.
+        let mut deleted_positions = HashSet::new();
+        // columns of the equality deletes, and the row keys they delete.
+        let mut equality_deletes = vec![];
+        for delete in part.deletes.iter() {
+            match delete {
+                IcebergDeleteFile::Position { path } => {
+                    let deletes = self.read_position_deletes(path).await?;
+                    if let Some(positions) = deletes.get(&part.location) {
+                        deleted_positions.extend(positions.iter().copied());
+                    }
+                }
+                IcebergDeleteFile::Equality { path, equality_ids } => {
+                    let fields = self.fields_of_ids(equality_ids)?;
+                    let keys = self.read_equality_deletes(path, &fields).await?;
+                    equality_deletes.push((fields, keys));
+                }
+            }
+        }
+
+        // read the equality delete columns along with the output ones, they
+        // are appended at the end and removed after the rows are filtered.
+        let mut read_fields = self.output_schema.fields().clone();
+        let num_output_fields = read_fields.len();
+        let mut equality_offsets = Vec::with_capacity(equality_deletes.len());
+        for (fields, _) in equality_deletes.iter() {
+            let mut offsets = Vec::with_capacity(fields.len());
+            for field in fields {
+                let offset = match read_fields
+                    .iter()
+                    .position(|f| f.column_id() == field.column_id())
+                {
+                    Some(offset) => offset,
+                    None => {
+                        read_fields.push(field.clone());
+                        read_fields.len() - 1
+                    }
+                };
+                offsets.push(offset);
+            }
+            equality_offsets.push(offsets);
+        }
+
+        let data = self.op.read(&part.path).await?;
+        let blocks = read_parquet_blocks(data, &part.path, &read_fields)?;
+        if deleted_positions.is_empty() && equality_deletes.is_empty() {
+            return Ok(blocks);
+        }
+
+        let mut position = 0_u64;
+        let mut outputs = Vec::with_capacity(blocks.len());
+        for block in blocks {
+            let num_rows = block.num_rows();
+            let mut keep = MutableBitmap::from_len_set(num_rows);
+            for row in 0..num_rows {
+                if deleted_positions.contains(&(position + row as u64)) {
+                    keep.set(row, false);
+                    continue;
+                }
+                for ((_, keys), offsets) in equality_deletes.iter().zip(equality_offsets.iter()) {
+                    if keys.contains(&row_key(&block, offsets, row)) {
+                        keep.set(row, false);
+                        break;
+                    }
+                }
+            }
+            position += num_rows as u64;
+
+            let block = block.filter_with_bitmap(&keep.into())?;
+            let num_extra_fields = read_fields.len() - num_output_fields;
+            outputs.push(block.pop_columns(num_extra_fields)?);
+        }
+        Ok(outputs)
+    }
+
+    fn fields_of_ids(&self, ids: &[i32]) -> Result<Vec<TableField>> {
+        ids.iter()
+            .map(|id| {
+                self.table_schema
+                    .fields()
+                    .iter()
+                    .find(|f| f.column_id() == *id as ColumnId)
+                    .cloned()
+                    .ok_or_else(|| {
+                        ErrorCode::ReadTableDataError(format!(
+                            "equality delete field id {id} is not a column of the table"
+                        ))
+                    })
+            })
+            .collect()
+    }
+
+    #[async_backtrace::framed]
+    async fn read_position_deletes(&mut self, path: &str) -> Result<PositionDeletes> {
+        if let Some(deletes) = self.position_deletes.get(path) {
+            return Ok(deletes.clone());
+        }
+
+        let fields = vec![
+            TableField::new_from_column_id("file_path", TableDataType::String, DELETE_FILE_PATH_ID),
+            TableField::new_from_column_id(
+                "pos",
+                TableDataType::Number(NumberDataType::Int64),
+                DELETE_POS_ID,
+            ),
+        ];
+        let data = self.op.read(path).await?;
+        let mut deletes: HashMap<String, Vec<u64>> = HashMap::new();
+        for block in read_parquet_blocks(data, path, &fields)? {
+            let file_paths = &block.get_by_offset(0).value;
+            let positions = &block.get_by_offset(1).value;
+            for row in 0..block.num_rows() {
+                if let (Some(ScalarRef::String(file_path)), Some(ScalarRef::Number(pos))) =
+                    (file_paths.index(row), positions.index(row))
+                {
+                    let pos = match pos {
+                        NumberScalar::Int64(v) => v as u64,
+                        _ => continue,
+                    };
+                    deletes
+                        .entry(String::from_utf8_lossy(file_path).into_owned())
+                        .or_default()
+                        .push(pos);
+                }
+            }
+        }
+
+        let deletes = Arc::new(deletes);
+        self.position_deletes
+            .insert(path.to_string(), deletes.clone());
+        Ok(deletes)
+    }
+
+    #[async_backtrace::framed]
+    async fn read_equality_deletes(
+        &mut self,
+        path: &str,
+        fields: &[TableField],
+    ) -> Result<EqualityDeletes> {
+        if let Some(keys) = self.equality_deletes.get(path) {
+            return Ok(keys.clone());
+        }
+
+        let data = self.op.read(path).await?;
+        let offsets = (0..fields.len()).collect::<Vec<_>>();
+        let mut keys = HashSet::new();
+        for block in read_parquet_blocks(data, path, fields)? {
+            for row in 0..block.num_rows() {
+                keys.insert(row_key(&block, &offsets, row));
+            }
+        }
+
+        let keys = Arc::new(keys);
+        self.equality_deletes.insert(path.to_string(), keys.clone());
+        Ok(keys)
+    }
+}
+
+#[async_trait]
+impl AsyncSource for IcebergTableSource {
+    const NAME: &'static str = "IcebergTableSource";
+
+    #[unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        loop {
+            if let Some(block) = self.pending.pop_front() {
+                return Ok(Some(block));
+            }
+            match self.ctx.get_partition() {
+                None => return Ok(None),
+                Some(part) => {
+                    let part = IcebergPartInfo::from_part(&part)?.clone();
+                    let blocks = self.read_part(&part).await?;
+                    self.pending.extend(blocks);
+                }
+            }
+        }
+    }
+}
+
+fn row_key(block: &DataBlock, offsets: &[usize], row: usize) -> String {
+    let mut key = String::new();
+    for offset in offsets {
+        let value = block.get_by_offset(*offset).value.index(row).unwrap();
+        write!(key, "{value}\u{1f}").unwrap();
+    }
+    key
+}
+
+/// Read a parquet file into blocks of `fields`.
+///
+/// Columns are matched by the parquet field ids, which iceberg writers set to the
+/// iceberg field ids, falling back to the names for files without them. Columns
+/// missing in the file, e.g. added after it was written, are filled with NULLs.
+fn read_parquet_blocks(data: Vec<u8>, path: &str, fields: &[TableField]) -> Result<Vec<DataBlock>> {
+    let mut reader = Cursor::new(data);
+    let meta = pread::read_metadata(&mut reader).map_err(|e| {
+        ErrorCode::ReadTableDataError(format!("invalid parquet file {}: {}", path, e))
+    })?;
+    let arrow_schema = pread::infer_schema(&meta)?;
+    let field_ids = meta
+        .schema()
+        .fields()
+        .iter()
+        .map(|f| (f.get_field_info().name.clone(), f.get_field_info().id))
+        .collect::<HashMap<_, _>>();
+
+    // position of each wanted field among the projected file fields.
+    let mut projected: Vec<ArrowField> = vec![];
+    let mut positions = Vec::with_capacity(fields.len());
+    for field in fields {
+        let found =
+            arrow_schema
+                .fields
+                .iter()
+                .find(|f| match field_ids.get(&f.name).copied().flatten() {
+                    Some(id) => id as ColumnId == field.column_id(),
+                    None => f.name == field.name,
+                });
+        positions.push(found.map(|f| {
+            projected.push(f.clone());
+            projected.len() - 1
+        }));
+    }
+
+    let target_fields = fields.iter().map(ArrowField::from).collect::<Vec<_>>();
+    let data_schema = DataSchema::from(&TableSchema::new_from_column_ids(
+        fields.to_vec(),
+        Default::default(),
+        0,
+    ));
+
+    let mut blocks = vec![];
+    if projected.is_empty() {
+        // none of the columns are in the file, but the rows still count.
+        let num_rows = meta.num_rows;
+        let arrays = target_fields
+            .iter()
+            .map(|f| new_null_array(f.data_type.clone(), num_rows))
+            .collect::<Vec<_>>();
+        if num_rows > 0 {
+            let chunk = ArrowChunk::try_new(arrays)?;
+            blocks.push(DataBlock::from_arrow_chunk(&chunk, &data_schema)?);
+        }
+        return Ok(blocks);
+    }
+
+    let chunks = pread::FileReader::new(
+        reader,
+        meta.row_groups,
+        ArrowSchema::from(projected),
+        None,
+        None,
+        None,
+    );
+    for chunk in chunks {
+        let chunk = chunk?;
+        let num_rows = chunk.len();
+        let arrays = chunk.into_arrays();
+        let mut columns = Vec::with_capacity(target_fields.len());
+        for (target, position) in target_fields.iter().zip(positions.iter()) {
+            let array = match position {
+                Some(pos) => cast(
+                    arrays[*pos].as_ref(),
+                    &target.data_type,
+                    CastOptions::default(),
+                )?,
+                None => new_null_array(target.data_type.clone(), num_rows),
+            };
+            columns.push(array);
+        }
+        let chunk = ArrowChunk::try_new(columns)?;
+        blocks.push(DataBlock::from_arrow_chunk(&chunk, &data_schema)?);
+    }
+    Ok(blocks)
+}
@@ -0,0 +1,75 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::Arc;
+
+use common_catalog::plan::PartInfo;
+use common_catalog::plan::PartInfoPtr;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::Scalar;
+
+/// A file of an external table, along with the values of the partition it is in.
+///
+/// The path is relative to the table location.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct ExternalPartInfo {
+    pub path: String,
+    pub size: u64,
+    /// values of the partition columns, in the order of the directory levels.
+    pub partition_values: Vec<Scalar>,
+}
+
+#[typetag::serde(name = "external")]
+impl PartInfo for ExternalPartInfo {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn PartInfo>) -> bool {
+        match info.as_any().downcast_ref::<ExternalPartInfo>() {
+            None => false,
+            Some(other) => self == other,
+        }
+    }
+
+    fn hash(&self) -> u64 {
+        let mut s = DefaultHasher::new();
+        self.path.hash(&mut s);
+        s.finish()
+    }
+}
+
+impl ExternalPartInfo {
+    pub fn create(path: String, size: u64, partition_values: Vec<Scalar>) -> PartInfoPtr {
+        Arc::new(Box::new(ExternalPartInfo {
+            path,
+            size,
+            partition_values,
+        }))
+    }
+
+    pub fn from_part(info: &PartInfoPtr) -> Result<&ExternalPartInfo> {
+        match info.as_any().downcast_ref::<ExternalPartInfo>() {
+            Some(part_ref) => Ok(part_ref),
+            None => Err(ErrorCode::Internal(
+                "Cannot downcast from PartInfo to ExternalPartInfo.",
+            )),
+        }
+    }
+}
@@ -0,0 +1,317 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Discovery of the hive style partitions of an external table.
+//!
+//! The partitions are directories named `<column>=<value>`, nested in the order of the
+//! partition columns. They are listed level by level, the directories whose values can't
+//! match the filter of the query are pruned before their sub directories are listed.
+//!
+//! The directory listings are kept in memory and shared by the queries on the table, a
+//! listing older than the refresh interval is still used by the query, while it's listed
+//! again in the background.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberType;
+use common_expression::types::ValueType;
+use common_expression::with_number_mapped_type;
+use common_expression::Scalar;
+use common_expression::TableField;
+use futures::TryStreamExt;
+use once_cell::sync::Lazy;
+use opendal::Metakey;
+use opendal::Operator;
+use parking_lot::Mutex;
+use storages_common_index::RangeIndex;
+use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::meta::StatisticsOfColumns;
+use tracing::warn;
+
+/// The directory name of the partition whose value is NULL, as written by hive and spark.
+pub const HIVE_DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+struct DirListing {
+    dirs: Arc<Vec<String>>,
+    listed_at: Instant,
+    refreshing: bool,
+}
+
+/// Sub directories of the listed directories, by table id and directory.
+static DIR_LISTINGS: Lazy<Mutex<HashMap<(u64, String), DirListing>>> = Lazy::new(Default::default);
+
+/// A partition directory of the table, with the values of the partition columns.
+pub struct Partition {
+    /// the directory relative to the table location, ends with `/`.
+    pub dir: String,
+    pub values: Vec<Scalar>,
+}
+
+pub struct PartitionDiscovery {
+    table_id: u64,
+    operator: Operator,
+    partition_fields: Vec<TableField>,
+    refresh_interval: Duration,
+}
+
+impl PartitionDiscovery {
+    pub fn create(
+        table_id: u64,
+        operator: Operator,
+        partition_fields: Vec<TableField>,
+        refresh_interval: Duration,
+    ) -> Self {
+        PartitionDiscovery {
+            table_id,
+            operator,
+            partition_fields,
+            refresh_interval,
+        }
+    }
+
+    /// Lists the partitions that may contain rows matching `pruner`, a table without
+    /// partition columns has a single partition, the table location.
+    #[async_backtrace::framed]
+    pub async fn discover(&self, pruner: Option<&RangeIndex>) -> Result<Vec<Partition>> {
+        let mut partitions = vec![Partition {
+            dir: "".to_string(),
+            values: vec![],
+        }];
+        for field in self.partition_fields.iter() {
+            let data_type = DataType::from(field.data_type());
+            let listings = futures::future::try_join_all(
+                partitions
+                    .iter()
+                    .map(|partition| self.list_dirs(&partition.dir)),
+            )
+            .await?;
+
+            let mut next_level = vec![];
+            for (partition, dirs) in partitions.iter().zip(listings) {
+                for name in dirs.iter() {
+                    let value = match name.split_once('=') {
+                        Some((column, value)) if column.eq_ignore_ascii_case(field.name()) => value,
+                        _ => continue,
+                    };
+                    let scalar = match partition_value_to_scalar(value, &data_type) {
+                        Ok(scalar) => scalar,
+                        Err(e) => {
+                            warn!("skip partition {}{}: {}", partition.dir, name, e);
+                            continue;
+                        }
+                    };
+                    let mut values = partition.values.clone();
+                    values.push(scalar);
+                    if let Some(pruner) = pruner {
+                        if !pruner.apply(&self.partition_stats(&values), |_| false)? {
+                            continue;
+                        }
+                    }
+                    next_level.push(Partition {
+                        dir: format!("{}{}/", partition.dir, name),
+                        values,
+                    });
+                }
+            }
+            partitions = next_level;
+        }
+        Ok(partitions)
+    }
+
+    /// Statistics of the partition columns with known values, the others are unknown to
+    /// the pruner and don't prune anything.
+    fn partition_stats(&self, values: &[Scalar]) -> StatisticsOfColumns {
+        self.partition_fields
+            .iter()
+            .zip(values)
+            .map(|(field, value)| {
+                let stats = ColumnStatistics {
+                    min: value.clone(),
+                    max: value.clone(),
+                    null_count: u64::from(value.is_null()),
+                    in_memory_size: 0,
+                    distinct_of_values: Some(1),
+                };
+                (field.column_id(), stats)
+            })
+            .collect()
+    }
+
+    /// The names of the sub directories of `dir`, from the cached listing if there is one.
+    #[async_backtrace::framed]
+    async fn list_dirs(&self, dir: &str) -> Result<Arc<Vec<String>>> {
+        if self.refresh_interval.is_zero() {
+            return Ok(Arc::new(list_sub_dirs(&self.operator, dir).await?));
+        }
+
+        let key = (self.table_id, dir.to_string());
+        if let Some(listing) = DIR_LISTINGS.lock().get_mut(&key) {
+            if !listing.refreshing && listing.listed_at.elapsed() >= self.refresh_interval {
+                listing.refreshing = true;
+                let operator = self.operator.clone();
+                let key = key.clone();
+                tokio::spawn(async_backtrace::location!().frame(async move {
+                    let listed = list_sub_dirs(&operator, &key.1).await;
+                    let mut listings = DIR_LISTINGS.lock();
+                    match listed {
+                        Ok(dirs) => {
+                            listings.insert(key, DirListing {
+                                dirs: Arc::new(dirs),
+                                listed_at: Instant::now(),
+                                refreshing: false,
+                            });
+                        }
+                        Err(e) => {
+                            warn!("refresh partitions under {} failed: {}", key.1, e);
+                            if let Some(listing) = listings.get_mut(&key) {
+                                listing.refreshing = false;
+                            }
+                        }
+                    }
+                }));
+            }
+            return Ok(listing.dirs.clone());
+        }
+
+        // the directory is listed for the first time, it's listed lazily when a
+        // query reaches it, i.e. its parent is not pruned.
+        let dirs = Arc::new(list_sub_dirs(&self.operator, dir).await?);
+        DIR_LISTINGS.lock().insert(key, DirListing {
+            dirs: dirs.clone(),
+            listed_at: Instant::now(),
+            refreshing: false,
+        });
+        Ok(dirs)
+    }
+}
+
+/// Forgets the cached partitions of a table, they are listed again by the next query.
+pub fn clear_cached_partitions(table_id: u64) {
+    DIR_LISTINGS.lock().retain(|(id, _), _| *id != table_id);
+}
+
+#[async_backtrace::framed]
+async fn list_sub_dirs(operator: &Operator, dir: &str) -> Result<Vec<String>> {
+    let mut lister = match operator.list(root_or(dir)).await {
+        Ok(lister) => lister,
+        Err(e) if e.kind() == opendal::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e.into()),
+    };
+    let mut dirs = vec![];
+    while let Some(entry) = lister.try_next().await? {
+        let meta = operator.metadata(&entry, Metakey::Mode).await?;
+        if !meta.mode().is_dir() {
+            continue;
+        }
+        let name = entry.path().trim_end_matches('/');
+        let name = name.rsplit('/').next().unwrap_or(name);
+        if !name.starts_with('.') && !name.starts_with('_') {
+            dirs.push(name.to_string());
+        }
+    }
+    Ok(dirs)
+}
+
+/// Lists the data files under the directory of a partition, with their sizes.
+///
+/// The files and directories starting with `.` or `_` are skipped, e.g. `_SUCCESS`.
+#[async_backtrace::framed]
+pub async fn list_partition_files(operator: &Operator, dir: &str) -> Result<Vec<(String, u64)>> {
+    let mut lister = match operator.scan(root_or(dir)).await {
+        Ok(lister) => lister,
+        Err(e) if e.kind() == opendal::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e.into()),
+    };
+    let mut files = vec![];
+    while let Some(entry) = lister.try_next().await? {
+        let path = entry.path();
+        let hidden = path[dir.len().min(path.len())..]
+            .split('/')
+            .any(|name| name.starts_with('.') || name.starts_with('_'));
+        if hidden {
+            continue;
+        }
+        let meta = operator
+            .metadata(&entry, Metakey::Mode | Metakey::ContentLength)
+            .await?;
+        if meta.mode().is_file() {
+            files.push((path.to_string(), meta.content_length()));
+        }
+    }
+    Ok(files)
+}
+
+/// The directories are relative to the table location, which is the root of the operator.
+fn root_or(dir: &str) -> &str {
+    if dir.is_empty() { "/" } else { dir }
+}
+
+/// Parses the value of a partition directory, escaped as hive does with `%XX`.
+pub fn partition_value_to_scalar(value: &str, data_type: &DataType) -> Result<Scalar> {
+    let invalid = || {
+        ErrorCode::BadBytes(format!(
+            "invalid partition value '{value}' of type {data_type}"
+        ))
+    };
+    match data_type {
+        DataType::Nullable(inner) => {
+            if value == HIVE_DEFAULT_PARTITION {
+                Ok(Scalar::Null)
+            } else {
+                partition_value_to_scalar(value, inner)
+            }
+        }
+        DataType::String => Ok(Scalar::String(unescape_partition_value(value).into_bytes())),
+        DataType::Number(num_ty) => {
+            with_number_mapped_type!(|NUM_TYPE| match num_ty {
+                NumberDataType::NUM_TYPE => {
+                    let num = unescape_partition_value(value)
+                        .parse::<NUM_TYPE>()
+                        .map_err(|_| invalid())?;
+                    Ok(NumberType::<NUM_TYPE>::upcast_scalar(num))
+                }
+            })
+        }
+        _ => Err(invalid()),
+    }
+}
+
+fn unescape_partition_value(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut unescaped = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            let byte = std::str::from_utf8(&bytes[i + 1..i + 3])
+                .ok()
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+            if let Some(byte) = byte {
+                unescaped.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        unescaped.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&unescaped).into_owned()
+}
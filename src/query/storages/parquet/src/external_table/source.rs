@@ -0,0 +1,231 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use async_trait::unboxed_simple;
+use common_arrow::arrow::array::new_null_array;
+use common_arrow::arrow::chunk::Chunk as ArrowChunk;
+use common_arrow::arrow::compute::cast::cast;
+use common_arrow::arrow::compute::cast::CastOptions;
+use common_arrow::arrow::datatypes::Field as ArrowField;
+use common_arrow::arrow::datatypes::Schema as ArrowSchema;
+use common_arrow::arrow::io::parquet::read as pread;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_expression::Value;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+use opendal::Operator;
+
+use super::part::ExternalPartInfo;
+
+/// Where a column of the output comes from.
+#[derive(Clone)]
+enum ColumnSource {
+    /// the offset of the column among the columns read from the file.
+    File(usize),
+    /// the offset of the partition column, its value is the same for the whole file.
+    Partition(usize),
+}
+
+pub struct ExternalTableSource {
+    ctx: Arc<dyn TableContext>,
+    op: Operator,
+    /// the columns to read from the files.
+    file_fields: Vec<TableField>,
+    /// the source of each output column.
+    columns: Vec<(ColumnSource, DataType)>,
+
+    pending: VecDeque<DataBlock>,
+}
+
+impl ExternalTableSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        op: Operator,
+        output_schema: TableSchemaRef,
+        partition_fields: &[TableField],
+    ) -> Result<ProcessorPtr> {
+        let mut file_fields = vec![];
+        let mut columns = Vec::with_capacity(output_schema.num_fields());
+        for field in output_schema.fields() {
+            let source = match partition_fields
+                .iter()
+                .position(|f| f.name() == field.name())
+            {
+                Some(offset) => ColumnSource::Partition(offset),
+                None => {
+                    file_fields.push(field.clone());
+                    ColumnSource::File(file_fields.len() - 1)
+                }
+            };
+            columns.push((source, DataType::from(field.data_type())));
+        }
+
+        AsyncSourcer::create(ctx.clone(), output, ExternalTableSource {
+            ctx,
+            op,
+            file_fields,
+            columns,
+            pending: VecDeque::new(),
+        })
+    }
+
+    #[async_backtrace::framed]
+    async fn read_part(&self, part: &ExternalPartInfo) -> Result<Vec<DataBlock>> {
+        let data = self.op.read(&part.path).await?;
+        let blocks = read_parquet_blocks(data, &part.path, &self.file_fields)?;
+        blocks
+            .into_iter()
+            .map(|block| {
+                let num_rows = block.num_rows();
+                let entries = self
+                    .columns
+                    .iter()
+                    .map(|(source, data_type)| match source {
+                        ColumnSource::File(offset) => block.get_by_offset(*offset).clone(),
+                        ColumnSource::Partition(offset) => BlockEntry::new(
+                            data_type.clone(),
+                            Value::Scalar(part.partition_values[*offset].clone()),
+                        ),
+                    })
+                    .collect();
+                Ok(DataBlock::new(entries, num_rows))
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl AsyncSource for ExternalTableSource {
+    const NAME: &'static str = "ExternalTableSource";
+
+    #[unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        loop {
+            if let Some(block) = self.pending.pop_front() {
+                return Ok(Some(block));
+            }
+            match self.ctx.get_partition() {
+                None => return Ok(None),
+                Some(part) => {
+                    let part = ExternalPartInfo::from_part(&part)?;
+                    let blocks = self.read_part(part).await?;
+                    self.pending.extend(blocks);
+                }
+            }
+        }
+    }
+}
+
+/// Read a parquet file into blocks of `fields`.
+///
+/// Columns are matched by their names, case insensitively, and cast to the types of
+/// the table. Nullable columns missing in the file, e.g. added after it was written,
+/// are filled with NULLs.
+fn read_parquet_blocks(data: Vec<u8>, path: &str, fields: &[TableField]) -> Result<Vec<DataBlock>> {
+    let mut reader = Cursor::new(data);
+    let meta = pread::read_metadata(&mut reader).map_err(|e| {
+        ErrorCode::ReadTableDataError(format!("invalid parquet file {}: {}", path, e))
+    })?;
+    let arrow_schema = pread::infer_schema(&meta)?;
+
+    // position of each wanted field among the projected file fields.
+    let mut projected: Vec<ArrowField> = vec![];
+    let mut positions = Vec::with_capacity(fields.len());
+    for field in fields {
+        let found = arrow_schema
+            .fields
+            .iter()
+            .find(|f| f.name.eq_ignore_ascii_case(field.name()));
+        if found.is_none() && !field.is_nullable() {
+            return Err(ErrorCode::ReadTableDataError(format!(
+                "column {} is not found in parquet file {}",
+                field.name(),
+                path
+            )));
+        }
+        positions.push(found.map(|f| {
+            projected.push(f.clone());
+            projected.len() - 1
+        }));
+    }
+
+    let target_fields = fields.iter().map(ArrowField::from).collect::<Vec<_>>();
+    let data_schema = DataSchema::from(&TableSchema::new(fields.to_vec()));
+
+    let mut blocks = vec![];
+    if projected.is_empty() {
+        // none of the columns are read from the file, but the rows still count.
+        let num_rows = meta.num_rows;
+        let arrays = target_fields
+            .iter()
+            .map(|f| new_null_array(f.data_type.clone(), num_rows))
+            .collect::<Vec<_>>();
+        if num_rows > 0 {
+            if arrays.is_empty() {
+                blocks.push(DataBlock::new(vec![], num_rows));
+            } else {
+                let chunk = ArrowChunk::try_new(arrays)?;
+                blocks.push(DataBlock::from_arrow_chunk(&chunk, &data_schema)?);
+            }
+        }
+        return Ok(blocks);
+    }
+
+    let chunks = pread::FileReader::new(
+        reader,
+        meta.row_groups,
+        ArrowSchema::from(projected),
+        None,
+        None,
+        None,
+    );
+    for chunk in chunks {
+        let chunk = chunk?;
+        let num_rows = chunk.len();
+        let arrays = chunk.into_arrays();
+        let mut columns = Vec::with_capacity(target_fields.len());
+        for (target, position) in target_fields.iter().zip(positions.iter()) {
+            let array = match position {
+                Some(pos) => cast(
+                    arrays[*pos].as_ref(),
+                    &target.data_type,
+                    CastOptions::default(),
+                )?,
+                None => new_null_array(target.data_type.clone(), num_rows),
+            };
+            columns.push(array);
+        }
+        let chunk = ArrowChunk::try_new(columns)?;
+        blocks.push(DataBlock::from_arrow_chunk(&chunk, &data_schema)?);
+    }
+    Ok(blocks)
+}
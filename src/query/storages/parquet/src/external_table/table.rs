@@ -0,0 +1,229 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_catalog::catalog::StorageDescription;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PartitionsShuffleKind;
+use common_catalog::plan::Projection;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::TableField;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::schema::TableInfo;
+use common_pipeline_core::Pipeline;
+use common_storage::init_operator;
+use opendal::Operator;
+use storages_common_index::RangeIndex;
+use storages_common_table_meta::meta::StatisticsOfColumns;
+use storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
+
+use super::part::ExternalPartInfo;
+use super::partition::clear_cached_partitions;
+use super::partition::list_partition_files;
+use super::partition::PartitionDiscovery;
+use super::source::ExternalTableSource;
+
+/// How long the listed partitions of an external table are used before listed again,
+/// in seconds. `0` lists the partitions on each query.
+pub const EXTERNAL_OPT_KEY_PARTITION_REFRESH_INTERVAL: &str = "partition_refresh_interval_in_secs";
+
+const DEFAULT_PARTITION_REFRESH_INTERVAL_IN_SECS: u64 = 60;
+
+/// A table of the parquet files under a location of the object storage, created by
+/// `CREATE EXTERNAL TABLE`.
+///
+/// The files may be partitioned into hive style directories, `<column>=<value>`, the
+/// partition columns are the last columns of the table.
+pub struct ExternalTable {
+    table_info: TableInfo,
+    operator: Operator,
+    /// the partition columns, in the order of the directory levels.
+    partition_fields: Vec<TableField>,
+    refresh_interval: Duration,
+}
+
+impl ExternalTable {
+    pub fn try_create(table_info: TableInfo) -> Result<Box<dyn Table>> {
+        let storage_params = table_info.meta.storage_params.as_ref().ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "external table {} should have storage params",
+                table_info.desc
+            ))
+        })?;
+        let operator = init_operator(storage_params)?;
+
+        let schema = table_info.schema();
+        let partition_fields = match table_info.options().get(OPT_KEY_PARTITION_BY) {
+            Some(columns) => columns
+                .split(',')
+                .map(|name| schema.field_with_name(name.trim()).cloned())
+                .collect::<Result<Vec<_>>>()?,
+            None => vec![],
+        };
+        let refresh_interval = table_info
+            .options()
+            .get(EXTERNAL_OPT_KEY_PARTITION_REFRESH_INTERVAL)
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(DEFAULT_PARTITION_REFRESH_INTERVAL_IN_SECS);
+
+        Ok(Box::new(ExternalTable {
+            table_info,
+            operator,
+            partition_fields,
+            refresh_interval: Duration::from_secs(refresh_interval),
+        }))
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: "EXTERNAL".to_string(),
+            comment: "EXTERNAL Storage Engine".to_string(),
+            support_cluster_key: false,
+        }
+    }
+
+    fn create_partition_pruner(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        push_downs: &Option<PushDownInfo>,
+    ) -> Result<Option<RangeIndex>> {
+        let filter = match push_downs.as_ref().and_then(|p| p.filter.as_ref()) {
+            Some(filter) if !self.partition_fields.is_empty() => filter,
+            _ => return Ok(None),
+        };
+        let pruner = RangeIndex::try_create(
+            ctx.get_function_context()?,
+            &filter.as_expr(&BUILTIN_FUNCTIONS),
+            self.table_info.schema(),
+            StatisticsOfColumns::default(),
+        )?;
+        Ok(Some(pruner))
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for ExternalTable {
+    fn is_local(&self) -> bool {
+        false
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn benefit_column_prune(&self) -> bool {
+        true
+    }
+
+    /// The partitions are discovered and pruned by the filter before their files are
+    /// listed, each file is a part.
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        if let Some(Projection::InnerColumns(_)) =
+            push_downs.as_ref().and_then(|p| p.projection.as_ref())
+        {
+            return Err(ErrorCode::Unimplemented(
+                "inner columns projection of external tables is not supported",
+            ));
+        }
+
+        let pruner = self.create_partition_pruner(&ctx, &push_downs)?;
+        let discovery = PartitionDiscovery::create(
+            self.table_info.ident.table_id,
+            self.operator.clone(),
+            self.partition_fields.clone(),
+            self.refresh_interval,
+        );
+        let partitions = discovery.discover(pruner.as_ref()).await?;
+        tracing::info!(
+            "{} partitions of external table {} to read",
+            partitions.len(),
+            self.table_info.desc
+        );
+
+        let files = futures::future::try_join_all(
+            partitions
+                .iter()
+                .map(|partition| list_partition_files(&self.operator, &partition.dir)),
+        )
+        .await?;
+
+        let mut parts = vec![];
+        let mut read_bytes = 0;
+        for (partition, files) in partitions.into_iter().zip(files) {
+            for (path, size) in files {
+                read_bytes += size as usize;
+                parts.push(ExternalPartInfo::create(
+                    path,
+                    size,
+                    partition.values.clone(),
+                ));
+            }
+        }
+
+        Ok((
+            PartStatistics::new_estimated(None, 0, read_bytes, parts.len(), parts.len()),
+            Partitions::create_nolazy(PartitionsShuffleKind::Mod, parts),
+        ))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let max_threads = std::cmp::max(1, std::cmp::min(plan.parts.len(), max_threads));
+
+        let output_schema = plan.schema();
+        pipeline.add_source(
+            |output| {
+                ExternalTableSource::create(
+                    ctx.clone(),
+                    output,
+                    self.operator.clone(),
+                    output_schema.clone(),
+                    &self.partition_fields,
+                )
+            },
+            max_threads,
+        )
+    }
+
+    /// `ANALYZE TABLE` drops the listed partitions of the table, the partitions added
+    /// or removed since are seen by the next query without waiting for the refresh.
+    #[async_backtrace::framed]
+    async fn analyze(&self, _ctx: Arc<dyn TableContext>) -> Result<()> {
+        clear_cached_partitions(self.table_info.ident.table_id);
+        Ok(())
+    }
+}
@@ -16,6 +16,7 @@
 #![deny(unused_crate_dependencies)]
 
 mod deserialize_transform;
+mod external_table;
 mod parquet_part;
 mod parquet_reader;
 mod parquet_source;
@@ -23,4 +24,6 @@ mod parquet_table;
 mod pruning;
 mod statistics;
 
+pub use external_table::ExternalTable;
+pub use external_table::EXTERNAL_OPT_KEY_PARTITION_REFRESH_INTERVAL;
 pub use parquet_table::ParquetTable;
@@ -137,7 +137,8 @@ fn lower_field_name(field: &mut ArrowField) {
     match &mut field.data_type {
         ArrowDataType::List(f)
         | ArrowDataType::LargeList(f)
-        | ArrowDataType::FixedSizeList(f, _) => {
+        | ArrowDataType::FixedSizeList(f, _)
+        | ArrowDataType::Map(f, _) => {
             lower_field_name(f.as_mut());
         }
         ArrowDataType::Struct(ref mut fields) => {
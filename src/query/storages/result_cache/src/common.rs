@@ -53,6 +53,9 @@ pub struct ResultCacheValue {
     pub num_rows: usize,
     /// The sha256 of the partitions for each table in the query.
     pub partitions_shas: Vec<String>,
+    /// The snapshot of each table in the query.
+    #[serde(default)]
+    pub table_snapshots: Vec<String>,
     /// The location of the result cache file.
     pub location: String,
 }
@@ -16,6 +16,7 @@
 #![deny(unused_crate_dependencies)]
 
 mod common;
+mod memory_store;
 mod meta_manager;
 mod read;
 mod table_function;
@@ -24,6 +25,7 @@ mod write;
 pub use common::gen_result_cache_key;
 pub use common::gen_result_cache_meta_key;
 pub use common::gen_result_cache_prefix;
+pub use memory_store::ResultCacheMemoryStore;
 pub use meta_manager::ResultCacheMetaManager;
 pub use read::ResultCacheReader;
 pub use table_function::ResultScan;
@@ -0,0 +1,82 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_exception::Result;
+use common_meta_types::SeqV;
+use once_cell::sync::Lazy;
+use opendal::Operator;
+use parking_lot::Mutex;
+
+const RESULT_CACHE_MEMORY_SCHEME: &str = "memory://";
+
+static MEMORY_STORE: Lazy<ResultCacheMemoryStore> = Lazy::new(|| ResultCacheMemoryStore {
+    results: Mutex::new(HashMap::new()),
+});
+
+/// Serialized query results cached in the memory of the current node.
+///
+/// The meta of them is still stored in the meta service, so the ttl and
+/// `system.query_cache` work the same as the results cached in the storage.
+/// A result is not found if the query runs on another node, and is cached
+/// again by it.
+pub struct ResultCacheMemoryStore {
+    /// location -> (expire_at, serialized result)
+    results: Mutex<HashMap<String, (u64, Vec<u8>)>>,
+}
+
+impl ResultCacheMemoryStore {
+    pub fn instance() -> &'static ResultCacheMemoryStore {
+        &MEMORY_STORE
+    }
+
+    pub fn is_memory_location(location: &str) -> bool {
+        location.starts_with(RESULT_CACHE_MEMORY_SCHEME)
+    }
+
+    pub(crate) fn gen_location(path: &str) -> String {
+        format!("{RESULT_CACHE_MEMORY_SCHEME}{path}")
+    }
+
+    pub(crate) fn put(&self, location: String, data: Vec<u8>, expire_at: u64) {
+        let now = SeqV::<()>::now_ms() / 1000;
+        let mut results = self.results.lock();
+        results.retain(|_, (expire_at, _)| *expire_at > now);
+        results.insert(location, (expire_at, data));
+    }
+
+    pub(crate) fn get(&self, location: &str) -> Option<Vec<u8>> {
+        let now = SeqV::<()>::now_ms() / 1000;
+        let results = self.results.lock();
+        match results.get(location) {
+            Some((expire_at, data)) if *expire_at > now => Some(data.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// Read the serialized result from the memory or the storage, `None` if the
+/// result is cached in the memory of another node.
+#[async_backtrace::framed]
+pub(crate) async fn read_result_data(
+    operator: &Operator,
+    location: &str,
+) -> Result<Option<Vec<u8>>> {
+    if ResultCacheMemoryStore::is_memory_location(location) {
+        Ok(ResultCacheMemoryStore::instance().get(location))
+    } else {
+        Ok(Some(operator.read(location).await?))
+    }
+}
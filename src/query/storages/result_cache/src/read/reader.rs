@@ -19,6 +19,7 @@ use common_arrow::arrow::io::parquet::read::infer_schema;
 use common_arrow::arrow::io::parquet::read::{self as pread};
 use common_arrow::parquet::read::read_metadata;
 use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
 use common_expression::DataSchema;
@@ -28,6 +29,7 @@ use common_storage::DataOperator;
 use opendal::Operator;
 
 use crate::common::gen_result_cache_meta_key;
+use crate::memory_store::read_result_data;
 use crate::meta_manager::ResultCacheMetaManager;
 
 pub struct ResultCacheReader {
@@ -37,9 +39,11 @@ pub struct ResultCacheReader {
     operator: Operator,
     /// To ensure the cache is valid.
     partitions_shas: Vec<String>,
+    /// The snapshot of each table in the query.
+    table_snapshots: Vec<String>,
 
     /// If true, the cache will be used even if it is inconsistent.
-    /// In another word, `partitions_sha` will not be checked.
+    /// In another word, `partitions_sha` and `table_snapshots` will not be checked.
     tolerate_inconsistent: bool,
 }
 
@@ -47,6 +51,7 @@ impl ResultCacheReader {
     pub fn create(
         ctx: Arc<dyn TableContext>,
         key: &str,
+        table_snapshots: Vec<String>,
         kv_store: Arc<MetaStore>,
         tolerate_inconsistent: bool,
     ) -> Self {
@@ -58,6 +63,7 @@ impl ResultCacheReader {
             meta_mgr: ResultCacheMetaManager::create(kv_store, 0),
             meta_key,
             partitions_shas,
+            table_snapshots,
             operator: DataOperator::instance().operator(),
             tolerate_inconsistent,
         }
@@ -80,11 +86,14 @@ impl ResultCacheReader {
     ) -> Result<Option<Vec<DataBlock>>> {
         match self.meta_mgr.get(meta_key).await? {
             Some(value) => {
-                if self.tolerate_inconsistent || value.partitions_shas == self.partitions_shas {
+                if self.tolerate_inconsistent
+                    || (value.partitions_shas == self.partitions_shas
+                        && value.table_snapshots == self.table_snapshots)
+                {
                     if value.num_rows == 0 {
                         Ok(Some(vec![DataBlock::empty()]))
                     } else {
-                        Ok(Some(self.read_result_from_cache(&value.location).await?))
+                        self.read_result_from_cache(&value.location).await
                     }
                 } else {
                     // The cache is invalid (due to data update or other reasons).
@@ -96,8 +105,12 @@ impl ResultCacheReader {
     }
 
     #[async_backtrace::framed]
-    async fn read_result_from_cache(&self, location: &str) -> Result<Vec<DataBlock>> {
-        let data = self.operator.read(location).await?;
+    async fn read_result_from_cache(&self, location: &str) -> Result<Option<Vec<DataBlock>>> {
+        let data = match read_result_data(&self.operator, location).await? {
+            Some(data) => data,
+            // The result is cached in the memory of another node.
+            None => return Ok(None),
+        };
         let mut reader = Cursor::new(data);
         let meta = read_metadata(&mut reader)?;
         let arrow_schema = infer_schema(&meta)?;
@@ -113,7 +126,7 @@ impl ResultCacheReader {
             blocks.push(block);
         }
 
-        Ok(blocks)
+        Ok(Some(blocks))
     }
 
     #[async_backtrace::framed]
@@ -121,7 +134,15 @@ impl ResultCacheReader {
         operator: Operator,
         location: &str,
     ) -> Result<(TableSchema, Vec<u8>)> {
-        let data = operator.read(location).await?;
+        let data = match read_result_data(&operator, location).await? {
+            Some(data) => data,
+            None => {
+                return Err(ErrorCode::EmptyData(format!(
+                    "the query result {} is not cached in the memory of the current node",
+                    location
+                )));
+            }
+        };
         let mut reader = Cursor::new(data.clone());
         let meta = read_metadata(&mut reader)?;
         let arrow_schema = infer_schema(&meta)?;
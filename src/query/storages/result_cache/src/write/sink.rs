@@ -37,6 +37,9 @@ pub struct WriteResultCacheSink {
     ctx: Arc<dyn TableContext>,
     sql: String,
     partitions_shas: Vec<String>,
+    table_snapshots: Vec<String>,
+    /// Cache the result in the memory of the current node instead of the storage.
+    in_memory: bool,
 
     meta_mgr: ResultCacheMetaManager,
     meta_key: String,
@@ -65,20 +68,26 @@ impl AsyncMpscSink for WriteResultCacheSink {
             return Ok(());
         }
 
-        // 1. Write the result cache to the storage.
-        let location = self.cache_writer.write_to_storage().await?;
-
-        // 2. Set result cache key-value pair to meta.
         let now = SeqV::<()>::now_ms() / 1000;
         let ttl = self.meta_mgr.get_ttl();
         let expire_at = now + ttl;
 
+        // 1. Write the result cache to the storage or the memory.
+        let location = if self.in_memory {
+            self.cache_writer.write_to_memory(expire_at)?
+        } else {
+            self.cache_writer.write_to_storage().await?
+        };
+
+        // 2. Set result cache key-value pair to meta.
+
         let value = ResultCacheValue {
             sql: self.sql.clone(),
             query_id: self.ctx.get_id(),
             query_time: now,
             ttl,
             partitions_shas: self.partitions_shas.clone(),
+            table_snapshots: self.table_snapshots.clone(),
             result_size: self.cache_writer.current_bytes(),
             num_rows: self.cache_writer.num_rows(),
             location,
@@ -97,12 +106,14 @@ impl WriteResultCacheSink {
         ctx: Arc<dyn TableContext>,
         key: &str,
         schema: TableSchemaRef,
+        table_snapshots: Vec<String>,
         inputs: Vec<Arc<InputPort>>,
         kv_store: Arc<MetaStore>,
     ) -> Result<ProcessorPtr> {
         let settings = ctx.get_settings();
         let max_bytes = settings.get_query_result_cache_max_bytes()?;
         let ttl = settings.get_query_result_cache_ttl_secs()?;
+        let in_memory = settings.get_query_result_cache_in_memory()?;
         let tenant = ctx.get_tenant();
         let sql = ctx.get_query_str();
         let partitions_shas = ctx.get_partitions_shas();
@@ -119,6 +130,8 @@ impl WriteResultCacheSink {
                 ctx,
                 sql,
                 partitions_shas,
+                table_snapshots,
+                in_memory,
                 meta_mgr: ResultCacheMetaManager::create(kv_store, ttl),
                 meta_key,
                 cache_writer,
@@ -20,6 +20,8 @@ use storages_common_blocks::blocks_to_parquet;
 use storages_common_table_meta::table::TableCompression;
 use uuid::Uuid;
 
+use crate::memory_store::ResultCacheMemoryStore;
+
 pub(super) struct ResultCacheWriter {
     operator: Operator,
     location: String,
@@ -60,9 +62,7 @@ impl ResultCacheWriter {
         self.current_bytes > self.max_bytes
     }
 
-    /// Write the result cache to the storage and return the location.
-    #[async_backtrace::framed]
-    pub async fn write_to_storage(&self) -> Result<String> {
+    fn serialize(&self) -> Result<Vec<u8>> {
         let mut buf = Vec::with_capacity(self.current_bytes);
         let _ = blocks_to_parquet(
             &self.schema,
@@ -70,13 +70,32 @@ impl ResultCacheWriter {
             &mut buf,
             TableCompression::None,
         )?;
+        Ok(buf)
+    }
 
-        let file_location = format!("{}/{}.parquet", self.location, Uuid::new_v4().as_simple());
+    fn gen_file_path(&self) -> String {
+        format!("{}/{}.parquet", self.location, Uuid::new_v4().as_simple())
+    }
+
+    /// Write the result cache to the storage and return the location.
+    #[async_backtrace::framed]
+    pub async fn write_to_storage(&self) -> Result<String> {
+        let buf = self.serialize()?;
+        let file_location = self.gen_file_path();
 
         self.operator.write(&file_location, buf).await?;
         Ok(file_location)
     }
 
+    /// Write the result cache to the memory of the current node and return the location.
+    pub fn write_to_memory(&self, expire_at: u64) -> Result<String> {
+        let buf = self.serialize()?;
+        let location = ResultCacheMemoryStore::gen_location(&self.gen_file_path());
+
+        ResultCacheMemoryStore::instance().put(location.clone(), buf, expire_at);
+        Ok(location)
+    }
+
     pub fn current_bytes(&self) -> usize {
         self.current_bytes
     }
@@ -0,0 +1,296 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use async_trait::unboxed_simple;
+use common_catalog::plan::StageTableInfo;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::ScalarRef;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_formats::output_format::OutputFormat;
+use common_formats::FileFormatOptionsExt;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sinks::AsyncSink;
+use common_pipeline_sinks::AsyncSinker;
+use opendal::Operator;
+
+use crate::stage_table_sink::StageTableSink;
+
+/// Directory name used by hive for NULL and empty partition values.
+const HIVE_DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+struct PartitionWriter {
+    output_format: Box<dyn OutputFormat>,
+    buffer: Vec<u8>,
+    has_prefix: bool,
+    batch_id: usize,
+}
+
+impl PartitionWriter {
+    fn size(&mut self) -> usize {
+        self.buffer.len() + self.output_format.buffer_size()
+    }
+}
+
+/// Unloads blocks into hive-style partition directories, `<path>/<key>=<value>/.../data_*`.
+///
+/// The values of the partition keys are the trailing columns of the input blocks, they are
+/// only used to route the rows and are not written into the files. Every pipeline lane gets
+/// its own sink, so the writers run in parallel and name their files by `group_id`. A file
+/// is flushed once it reaches `max_file_size`, and the largest pending file is flushed
+/// early whenever all the partitions together buffer more than that.
+pub struct PartitionedStageTableSink {
+    ctx: Arc<dyn TableContext>,
+    table_info: StageTableInfo,
+    data_accessor: Operator,
+    file_schema: TableSchemaRef,
+
+    uuid: String,
+    group_id: usize,
+    max_file_size: usize,
+
+    partitions: HashMap<String, PartitionWriter>,
+}
+
+impl PartitionedStageTableSink {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        ctx: Arc<dyn TableContext>,
+        table_info: StageTableInfo,
+        data_accessor: Operator,
+        uuid: String,
+        group_id: usize,
+    ) -> Result<ProcessorPtr> {
+        let fields = table_info.schema.fields();
+        let num_file_fields = fields.len() - table_info.partition_by.len();
+        let file_schema = Arc::new(TableSchema::new(fields[..num_file_fields].to_vec()));
+        let max_file_size = StageTableSink::adjust_max_file_size(&ctx, &table_info)?;
+
+        Ok(ProcessorPtr::create(AsyncSinker::create(
+            input,
+            PartitionedStageTableSink {
+                ctx,
+                table_info,
+                data_accessor,
+                file_schema,
+                uuid,
+                group_id,
+                max_file_size,
+                partitions: HashMap::new(),
+            },
+        )))
+    }
+
+    fn unload_path(&self, dir: &str, batch_id: usize) -> String {
+        let format_name = format!(
+            "{:?}",
+            self.table_info.stage_info.file_format_params.get_type()
+        );
+        let base = self.table_info.files_info.path.trim_end_matches('/');
+        let file = format!(
+            "data_{}_{:0>4}_{:0>8}.{}",
+            self.uuid,
+            self.group_id,
+            batch_id,
+            format_name.to_ascii_lowercase()
+        );
+        if base.is_empty() {
+            format!("{}/{}", dir, file)
+        } else {
+            format!("{}/{}/{}", base, dir, file)
+        }
+    }
+
+    fn partition_dirs(&self, block: &DataBlock) -> (Vec<String>, Vec<u32>) {
+        let num_columns = block.num_columns();
+        let keys_offset = num_columns - self.table_info.partition_by.len();
+
+        let mut dirs = vec![];
+        let mut dir_ids = HashMap::new();
+        let mut indices = Vec::with_capacity(block.num_rows());
+        for row in 0..block.num_rows() {
+            let mut dir = String::new();
+            for (i, name) in (keys_offset..num_columns).zip(self.table_info.partition_by.iter()) {
+                let value = block.get_by_offset(i).value.index(row).unwrap();
+                if !dir.is_empty() {
+                    dir.push('/');
+                }
+                write!(
+                    dir,
+                    "{}={}",
+                    escape_path_name(name),
+                    escape_path_name(&partition_value(value))
+                )
+                .unwrap();
+            }
+            let next_id = dirs.len() as u32;
+            let id = *dir_ids.entry(dir.clone()).or_insert_with(|| {
+                dirs.push(dir);
+                next_id
+            });
+            indices.push(id);
+        }
+        (dirs, indices)
+    }
+
+    fn write_block(&mut self, dir: &str, block: DataBlock) -> Result<()> {
+        if !self.partitions.contains_key(dir) {
+            let mut options_ext =
+                FileFormatOptionsExt::create_from_settings(&self.ctx.get_settings(), false)?;
+            let output_format = options_ext.get_output_format(
+                self.file_schema.clone(),
+                self.table_info.stage_info.file_format_params.clone(),
+            )?;
+            self.partitions.insert(dir.to_string(), PartitionWriter {
+                output_format,
+                buffer: Vec::with_capacity(4096),
+                has_prefix: false,
+                batch_id: 0,
+            });
+        }
+
+        let writer = self.partitions.get_mut(dir).unwrap();
+        if !writer.has_prefix {
+            let prefix = writer.output_format.serialize_prefix()?;
+            writer.buffer.extend_from_slice(&prefix);
+            writer.has_prefix = true;
+        }
+        let bs = writer.output_format.serialize_block(&block)?;
+        writer.buffer.extend_from_slice(&bs);
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn flush(&mut self, dir: &str) -> Result<()> {
+        let (data, batch_id) = match self.partitions.get_mut(dir) {
+            Some(writer) if writer.has_prefix => {
+                let bs = writer.output_format.finalize()?;
+                writer.buffer.extend_from_slice(&bs);
+                writer.has_prefix = false;
+                writer.batch_id += 1;
+                (std::mem::take(&mut writer.buffer), writer.batch_id - 1)
+            }
+            _ => return Ok(()),
+        };
+
+        let path = self.unload_path(dir, batch_id);
+        self.data_accessor.write(&path, data).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AsyncSink for PartitionedStageTableSink {
+    const NAME: &'static str = "PartitionedStageSink";
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        let dirs = self.partitions.keys().cloned().collect::<Vec<_>>();
+        for dir in dirs {
+            self.flush(&dir).await?;
+        }
+        Ok(())
+    }
+
+    #[unboxed_simple]
+    #[async_backtrace::framed]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<bool> {
+        if data_block.num_rows() == 0 {
+            return Ok(false);
+        }
+
+        let (dirs, indices) = self.partition_dirs(&data_block);
+        let data_block = data_block.pop_columns(self.table_info.partition_by.len())?;
+        let blocks = data_block.scatter(&indices, dirs.len())?;
+
+        for (dir, block) in dirs.iter().zip(blocks.into_iter()) {
+            for i in (0..block.num_rows()).step_by(1024) {
+                let end = (i + 1024).min(block.num_rows());
+                self.write_block(dir, block.slice(i..end))?;
+
+                let writer = self.partitions.get_mut(dir).unwrap();
+                if writer.size() >= self.max_file_size {
+                    self.flush(dir).await?;
+                }
+            }
+        }
+
+        // bound the memory held by the pending files of all the partitions.
+        loop {
+            let mut total = 0;
+            let mut largest: Option<(usize, &String)> = None;
+            for (dir, writer) in self.partitions.iter_mut() {
+                let size = writer.size();
+                total += size;
+                if largest.map_or(true, |(s, _)| size > s) {
+                    largest = Some((size, dir));
+                }
+            }
+            match largest {
+                Some((_, dir)) if total > self.max_file_size => {
+                    let dir = dir.clone();
+                    self.flush(&dir).await?;
+                }
+                _ => break,
+            }
+        }
+
+        Ok(false)
+    }
+}
+
+fn partition_value(value: ScalarRef) -> String {
+    match value {
+        ScalarRef::Null => HIVE_DEFAULT_PARTITION.to_string(),
+        ScalarRef::String(s) if s.is_empty() => HIVE_DEFAULT_PARTITION.to_string(),
+        ScalarRef::String(s) => String::from_utf8_lossy(s).into_owned(),
+        // dates and timestamps are displayed quoted.
+        other => other.to_string().trim_matches('\'').to_string(),
+    }
+}
+
+/// Percent-encode the characters hive does not allow in partition directory names.
+fn escape_path_name(name: &str) -> String {
+    let mut escaped = String::with_capacity(name.len());
+    for c in name.chars() {
+        match c {
+            '\u{00}'..='\u{1F}'
+            | '"'
+            | '#'
+            | '%'
+            | '\''
+            | '*'
+            | '/'
+            | ':'
+            | '='
+            | '?'
+            | '\\'
+            | '\u{7F}'
+            | '{'
+            | '['
+            | ']'
+            | '^' => write!(escaped, "%{:02X}", c as u32).unwrap(),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
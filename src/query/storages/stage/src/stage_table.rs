@@ -34,17 +34,22 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::BlockThresholds;
 use common_expression::TableSchemaRefExt;
+use common_functions::BUILTIN_FUNCTIONS;
 use common_meta_app::principal::StageInfo;
 use common_meta_app::schema::TableInfo;
 use common_pipeline_core::Pipeline;
 use common_pipeline_sources::input_formats::InputContext;
+use common_pipeline_sources::input_formats::InputFormat;
 use common_pipeline_sources::input_formats::SplitInfo;
 use common_storage::init_stage_operator;
 use common_storage::StageFileInfo;
+use common_storage::StageMetadataColumn;
 use dashmap::DashMap;
 use opendal::Operator;
 use parking_lot::Mutex;
+use storages_common_pruner::RangePrunerCreator;
 
+use crate::partitioned_stage_table_sink::PartitionedStageTableSink;
 use crate::stage_table_sink::StageTableSink;
 
 /// TODO: we need to track the data metrics in stage table.
@@ -88,6 +93,36 @@ impl StageTable {
             Some(t) => *t,
         }
     }
+
+    /// Skip the splits whose statistics show that no row can match the filter,
+    /// e.g. ORC stripes.
+    fn prune_splits(
+        ctx: &Arc<dyn TableContext>,
+        stage_info: &StageTableInfo,
+        format: &dyn InputFormat,
+        splits: Vec<Arc<SplitInfo>>,
+        push_downs: &Option<PushDownInfo>,
+    ) -> Result<Vec<Arc<SplitInfo>>> {
+        let filter = match push_downs.as_ref().and_then(|p| p.filter.as_ref()) {
+            Some(filter) => filter.as_expr(&BUILTIN_FUNCTIONS),
+            None => return Ok(splits),
+        };
+        let schema = &stage_info.schema;
+        let pruner =
+            RangePrunerCreator::try_create(ctx.get_function_context()?, schema, Some(&filter))?;
+        let total = splits.len();
+        let splits = splits
+            .into_iter()
+            .filter(|split| match format.split_statistics(split, schema) {
+                Some(stats) => pruner.should_keep(&stats, None),
+                None => true,
+            })
+            .collect::<Vec<_>>();
+        if splits.len() < total {
+            tracing::info!("pruned {} of {} stage splits", total - splits.len(), total);
+        }
+        Ok(splits)
+    }
 }
 
 #[async_trait::async_trait]
@@ -109,7 +144,7 @@ impl Table for StageTable {
     async fn read_partitions(
         &self,
         ctx: Arc<dyn TableContext>,
-        _push_downs: Option<PushDownInfo>,
+        push_downs: Option<PushDownInfo>,
     ) -> Result<(PartStatistics, Partitions)> {
         let stage_info = &self.table_info;
         // User set the files.
@@ -128,6 +163,7 @@ impl Table for StageTable {
                 &ctx.get_settings(),
             )
             .await?;
+        let splits = Self::prune_splits(&ctx, stage_info, format.as_ref(), splits, &push_downs)?;
 
         let partitions = splits
             .into_iter()
@@ -174,11 +210,20 @@ impl Table for StageTable {
 
         //  Build copy pipeline.
         let settings = ctx.get_settings();
+        let mut metadata_columns = vec![];
         let fields = stage_table_info
             .schema
             .fields()
             .iter()
             .filter(|f| f.computed_expr().is_none())
+            .filter(|f| match StageMetadataColumn::from_name(f.name()) {
+                // the metadata columns are only added when selecting from the stage.
+                Some(column) if stage_table_info.is_select => {
+                    metadata_columns.push(column);
+                    false
+                }
+                _ => true,
+            })
             .cloned()
             .collect::<Vec<_>>();
         let schema = TableSchemaRefExt::create(fields);
@@ -194,7 +239,7 @@ impl Table for StageTable {
             }
         };
         // let projection = self.projection.lock().clone();
-        let input_ctx = Arc::new(InputContext::try_create_from_copy(
+        let mut input_ctx = InputContext::try_create_from_copy(
             operator,
             settings,
             schema,
@@ -205,7 +250,9 @@ impl Table for StageTable {
             on_error_map,
             self.table_info.is_select,
             projection,
-        )?);
+        )?;
+        input_ctx.metadata_columns = metadata_columns;
+        let input_ctx = Arc::new(input_ctx);
 
         input_ctx.format.exec_copy(input_ctx.clone(), pipeline)?;
         Ok(())
@@ -223,6 +270,21 @@ impl Table for StageTable {
         let uuid = uuid::Uuid::new_v4().to_string();
         let group_id = AtomicUsize::new(0);
 
+        // partitioned unload, every lane writes its own files into the partition directories.
+        if !self.table_info.partition_by.is_empty() {
+            return pipeline.add_sink(|input| {
+                let gid = group_id.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                PartitionedStageTableSink::try_create(
+                    input,
+                    ctx.clone(),
+                    self.table_info.clone(),
+                    op.clone(),
+                    uuid.clone(),
+                    gid,
+                )
+            });
+        }
+
         // parallel compact unload, the partial block will flush into next operator
         if !single && pipeline.output_len() > 1 {
             pipeline.add_transform(|input, output| {
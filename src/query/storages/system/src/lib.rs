@@ -34,17 +34,26 @@ mod log_queue;
 mod malloc_stats_table;
 mod malloc_stats_totals_table;
 mod metrics_table;
+mod notification_history_table;
+mod notification_integrations_table;
 mod one_table;
+mod pipe_history_table;
+mod pipes_table;
 mod processes_table;
 mod query_cache_table;
 mod query_log_table;
 mod query_profile_table;
+mod query_queue_table;
+mod recluster_progress_table;
+mod replication_history_table;
 mod roles_table;
 mod settings_table;
 mod stages_table;
 mod table;
 mod table_functions_table;
 mod tables_table;
+mod task_history_table;
+mod tasks_table;
 mod tracing_table;
 mod users_table;
 mod util;
@@ -70,7 +79,13 @@ pub use log_queue::SystemLogTable;
 pub use malloc_stats_table::MallocStatsTable;
 pub use malloc_stats_totals_table::MallocStatsTotalsTable;
 pub use metrics_table::MetricsTable;
+pub use notification_history_table::NotificationHistoryLogElement;
+pub use notification_history_table::NotificationHistoryQueue;
+pub use notification_history_table::NotificationHistoryTable;
+pub use notification_integrations_table::NotificationIntegrationsTable;
 pub use one_table::OneTable;
+pub use pipe_history_table::PipeHistoryTable;
+pub use pipes_table::PipesTable;
 pub use processes_table::ProcessesTable;
 pub use query_cache_table::QueryCacheTable;
 pub use query_log_table::LogType;
@@ -78,6 +93,13 @@ pub use query_log_table::QueryLogElement;
 pub use query_log_table::QueryLogQueue;
 pub use query_log_table::QueryLogTable;
 pub use query_profile_table::QueryProfileTable;
+pub use query_queue_table::QueryQueueTable;
+pub use recluster_progress_table::ReclusterProgressLogElement;
+pub use recluster_progress_table::ReclusterProgressQueue;
+pub use recluster_progress_table::ReclusterProgressTable;
+pub use replication_history_table::ReplicationHistoryLogElement;
+pub use replication_history_table::ReplicationHistoryQueue;
+pub use replication_history_table::ReplicationHistoryTable;
 pub use roles_table::RolesTable;
 pub use settings_table::SettingsTable;
 pub use stages_table::StagesTable;
@@ -87,5 +109,7 @@ pub use table_functions_table::TableFunctionsTable;
 pub use tables_table::TablesTable;
 pub use tables_table::TablesTableWithHistory;
 pub use tables_table::TablesTableWithoutHistory;
+pub use task_history_table::TaskHistoryTable;
+pub use tasks_table::TasksTable;
 pub use tracing_table::TracingTable;
 pub use users_table::UsersTable;
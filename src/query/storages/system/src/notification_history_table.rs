@@ -0,0 +1,104 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::NumberDataType;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_expression::TableSchemaRefExt;
+
+use crate::SystemLogElement;
+use crate::SystemLogQueue;
+use crate::SystemLogTable;
+
+/// A notification of a committed snapshot sent to a notification integration,
+/// `attempts` counts the retries as well.
+#[derive(Clone)]
+pub struct NotificationHistoryLogElement {
+    pub event_time: i64,
+    pub integration: String,
+    pub database: String,
+    pub table: String,
+    pub snapshot_id: String,
+    pub row_delta: i64,
+    pub attempts: u64,
+    pub status: String,
+    pub error_message: String,
+}
+
+impl SystemLogElement for NotificationHistoryLogElement {
+    const TABLE_NAME: &'static str = "notification_history";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("event_time", TableDataType::Timestamp),
+            TableField::new("integration", TableDataType::String),
+            TableField::new("database", TableDataType::String),
+            TableField::new("table", TableDataType::String),
+            TableField::new("snapshot_id", TableDataType::String),
+            TableField::new("row_delta", TableDataType::Number(NumberDataType::Int64)),
+            TableField::new("attempts", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("status", TableDataType::String),
+            TableField::new("error_message", TableDataType::String),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let mut columns = columns.iter_mut();
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.event_time).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.integration.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.database.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.table.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.snapshot_id.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::Int64(self.row_delta)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.attempts)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.status.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.error_message.as_bytes().to_vec()).as_ref());
+        Ok(())
+    }
+}
+
+pub type NotificationHistoryQueue = SystemLogQueue<NotificationHistoryLogElement>;
+pub type NotificationHistoryTable = SystemLogTable<NotificationHistoryLogElement>;
@@ -0,0 +1,107 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+/// The notification integrations of the tenant, the credentials are not shown.
+pub struct NotificationIntegrationsTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for NotificationIntegrationsTable {
+    const NAME: &'static str = "system.notification_integrations";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let integrations = UserApiProvider::instance()
+            .get_notification_integrations(&tenant)
+            .await?;
+
+        let mut names = Vec::with_capacity(integrations.len());
+        let mut types = Vec::with_capacity(integrations.len());
+        let mut endpoints = Vec::with_capacity(integrations.len());
+        let mut comments = Vec::with_capacity(integrations.len());
+        let mut created_on = Vec::with_capacity(integrations.len());
+        for integration in integrations {
+            names.push(integration.name.into_bytes());
+            types.push(integration.target.type_name().as_bytes().to_vec());
+            endpoints.push(integration.target.endpoint().into_bytes());
+            comments.push(integration.comment.into_bytes());
+            created_on.push(integration.created_on.timestamp_micros());
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(types),
+            StringType::from_data(endpoints),
+            StringType::from_data(comments),
+            TimestampType::from_data(created_on),
+        ]))
+    }
+}
+
+impl NotificationIntegrationsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("type", TableDataType::String),
+            TableField::new("endpoint", TableDataType::String),
+            TableField::new("comment", TableDataType::String),
+            TableField::new("created_on", TableDataType::Timestamp),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'notification_integrations'".to_string(),
+            name: "notification_integrations".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemNotificationIntegrations".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(NotificationIntegrationsTable { table_info })
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::types::UInt16Type;
+use common_expression::types::UInt64Type;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+/// The loads of the pipes and their errors, the latest loads of each pipe are kept
+/// in meta.
+pub struct PipeHistoryTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for PipeHistoryTable {
+    const NAME: &'static str = "system.pipe_history";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let loads = UserApiProvider::instance()
+            .get_pipe_loads(&tenant, None)
+            .await?;
+
+        let mut names = Vec::with_capacity(loads.len());
+        let mut states = Vec::with_capacity(loads.len());
+        let mut started_at = Vec::with_capacity(loads.len());
+        let mut finished_at = Vec::with_capacity(loads.len());
+        let mut messages = Vec::with_capacity(loads.len());
+        let mut files = Vec::with_capacity(loads.len());
+        let mut error_codes = Vec::with_capacity(loads.len());
+        let mut error_messages = Vec::with_capacity(loads.len());
+        for load in loads {
+            let state = if load.succeeded {
+                "SUCCEEDED"
+            } else {
+                "FAILED"
+            };
+            names.push(load.pipe_name.into_bytes());
+            states.push(state.as_bytes().to_vec());
+            started_at.push(load.started_at.timestamp_micros());
+            finished_at.push(load.finished_at.timestamp_micros());
+            messages.push(load.messages);
+            files.push(load.files.join(",").into_bytes());
+            error_codes.push(load.error_code);
+            error_messages.push(load.error_message.into_bytes());
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(states),
+            TimestampType::from_data(started_at),
+            TimestampType::from_data(finished_at),
+            UInt64Type::from_data(messages),
+            StringType::from_data(files),
+            UInt16Type::from_data(error_codes),
+            StringType::from_data(error_messages),
+        ]))
+    }
+}
+
+impl PipeHistoryTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("state", TableDataType::String),
+            TableField::new("started_at", TableDataType::Timestamp),
+            TableField::new("finished_at", TableDataType::Timestamp),
+            // the messages loaded from Kafka
+            TableField::new("messages", TableDataType::Number(NumberDataType::UInt64)),
+            // the files copied from a stage
+            TableField::new("files", TableDataType::String),
+            TableField::new("error_code", TableDataType::Number(NumberDataType::UInt16)),
+            TableField::new("error_message", TableDataType::String),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'pipe_history'".to_string(),
+            name: "pipe_history".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemPipeHistory".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(PipeHistoryTable { table_info })
+    }
+}
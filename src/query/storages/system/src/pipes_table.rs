@@ -0,0 +1,187 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::types::UInt64Type;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::FromOptData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::principal::PipeSource;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+/// The pipes of the tenant and how far they have loaded, the credentials of the
+/// sources are not shown.
+pub struct PipesTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for PipesTable {
+    const NAME: &'static str = "system.pipes";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let user_api = UserApiProvider::instance();
+        let pipes = user_api.get_pipes(&tenant).await?;
+
+        let mut names = Vec::with_capacity(pipes.len());
+        let mut owners = Vec::with_capacity(pipes.len());
+        let mut databases = Vec::with_capacity(pipes.len());
+        let mut tables = Vec::with_capacity(pipes.len());
+        let mut sources = Vec::with_capacity(pipes.len());
+        let mut endpoints = Vec::with_capacity(pipes.len());
+        let mut file_formats = Vec::with_capacity(pipes.len());
+        let mut offsets = Vec::with_capacity(pipes.len());
+        let mut gtid_executed = Vec::with_capacity(pipes.len());
+        let mut pending_batches = Vec::with_capacity(pipes.len());
+        let mut pending_files = Vec::with_capacity(pipes.len());
+        let mut loaded_messages = Vec::with_capacity(pipes.len());
+        let mut loaded_files = Vec::with_capacity(pipes.len());
+        let mut last_loaded_at = Vec::with_capacity(pipes.len());
+        let mut last_errors = Vec::with_capacity(pipes.len());
+        let mut comments = Vec::with_capacity(pipes.len());
+        let mut created_on = Vec::with_capacity(pipes.len());
+        for pipe in pipes {
+            let pending = match &pipe.source {
+                PipeSource::Stage { .. } => user_api.get_pipe_files(&tenant, &pipe.name).await?,
+                PipeSource::Kafka { .. } | PipeSource::MySql { .. } => vec![],
+            };
+            names.push(pipe.name.into_bytes());
+            owners.push(pipe.owner.to_string().into_bytes());
+            databases.push(pipe.database.into_bytes());
+            tables.push(pipe.table.into_bytes());
+            sources.push(pipe.source.type_name().as_bytes().to_vec());
+            endpoints.push(pipe.source.endpoint().into_bytes());
+            file_formats.push(pipe.file_format.to_string().into_bytes());
+            // `<partition>:<offset>` of the next message to load of each partition.
+            let partition_offsets = pipe
+                .offsets
+                .iter()
+                .map(|(partition, offset)| format!("{}:{}", partition, offset))
+                .collect::<Vec<_>>();
+            offsets.push(partition_offsets.join(",").into_bytes());
+            gtid_executed.push(pipe.gtid_executed.to_string().into_bytes());
+            pending_batches.push(pipe.pending_batch.map(|batch| batch.id));
+            pending_files.push(pending.len() as u64);
+            loaded_messages.push(pipe.loaded_messages);
+            loaded_files.push(pipe.loaded_files);
+            last_loaded_at.push(pipe.last_loaded_at.map(|at| at.timestamp_micros()));
+            last_errors.push(pipe.last_error.into_bytes());
+            comments.push(pipe.comment.into_bytes());
+            created_on.push(pipe.created_on.timestamp_micros());
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(owners),
+            StringType::from_data(databases),
+            StringType::from_data(tables),
+            StringType::from_data(sources),
+            StringType::from_data(endpoints),
+            StringType::from_data(file_formats),
+            StringType::from_data(offsets),
+            StringType::from_data(gtid_executed),
+            UInt64Type::from_opt_data(pending_batches),
+            UInt64Type::from_data(pending_files),
+            UInt64Type::from_data(loaded_messages),
+            UInt64Type::from_data(loaded_files),
+            TimestampType::from_opt_data(last_loaded_at),
+            StringType::from_data(last_errors),
+            StringType::from_data(comments),
+            TimestampType::from_data(created_on),
+        ]))
+    }
+}
+
+impl PipesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("owner", TableDataType::String),
+            TableField::new("database", TableDataType::String),
+            TableField::new("table", TableDataType::String),
+            TableField::new("source", TableDataType::String),
+            TableField::new("endpoint", TableDataType::String),
+            TableField::new("file_format", TableDataType::String),
+            TableField::new("offsets", TableDataType::String),
+            // the transactions merged, of a pipe from MySQL
+            TableField::new("gtid_executed", TableDataType::String),
+            // NULL unless a batch is being loaded, or is to be loaded again
+            TableField::new(
+                "pending_batch",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64))),
+            ),
+            // the files of the events not copied yet, of a pipe from a stage
+            TableField::new(
+                "pending_files",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "loaded_messages",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "loaded_files",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "last_loaded_at",
+                TableDataType::Nullable(Box::new(TableDataType::Timestamp)),
+            ),
+            TableField::new("last_error", TableDataType::String),
+            TableField::new("comment", TableDataType::String),
+            TableField::new("created_on", TableDataType::Timestamp),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'pipes'".to_string(),
+            name: "pipes".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemPipes".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(PipesTable { table_info })
+    }
+}
@@ -12,10 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::net::SocketAddr;
 use std::sync::Arc;
-use std::time::Duration;
 
+use common_catalog::plan::PushDownInfo;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
@@ -34,23 +33,29 @@ use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
 
-use crate::SyncOneBlockSystemTable;
-use crate::SyncSystemTable;
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
 
+/// The processes of all the nodes of the cluster.
 pub struct ProcessesTable {
     table_info: TableInfo,
 }
 
 #[async_trait::async_trait]
-impl SyncSystemTable for ProcessesTable {
+impl AsyncSystemTable for ProcessesTable {
     const NAME: &'static str = "system.processes";
 
     fn get_table_info(&self) -> &TableInfo {
         &self.table_info
     }
 
-    fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
-        let processes_info = ctx.get_processes_info();
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let processes_info = ctx.get_cluster_processes_info().await?;
 
         let mut processes_id = Vec::with_capacity(processes_info.len());
         let mut processes_type = Vec::with_capacity(processes_info.len());
@@ -67,52 +72,25 @@ impl SyncSystemTable for ProcessesTable {
         let mut processes_mysql_connection_id = Vec::with_capacity(processes_info.len());
         let mut processes_time = Vec::with_capacity(processes_info.len());
         let mut processes_status = Vec::with_capacity(processes_info.len());
-
-        for process_info in &processes_info {
-            let data_metrics = &process_info.data_metrics;
-            let scan_progress = process_info.scan_progress_value.clone().unwrap_or_default();
-            let time = process_info
-                .created_time
-                .elapsed()
-                .unwrap_or(Duration::from_secs(0))
-                .as_secs();
-
-            processes_id.push(process_info.id.clone().into_bytes());
-            processes_type.push(process_info.typ.clone().into_bytes());
-            processes_state.push(process_info.state.clone().into_bytes());
-            processes_database.push(process_info.database.clone().into_bytes());
-            processes_host.push(ProcessesTable::process_host(&process_info.client_address));
-            processes_user.push(
-                ProcessesTable::process_option_value(process_info.user.clone())
-                    .name
-                    .into_bytes(),
-            );
-            processes_extra_info.push(
-                ProcessesTable::process_option_value(process_info.session_extra_info.clone())
-                    .into_bytes(),
-            );
+        let mut processes_node = Vec::with_capacity(processes_info.len());
+
+        for process_info in processes_info {
+            processes_id.push(process_info.id.into_bytes());
+            processes_type.push(process_info.typ.into_bytes());
+            processes_host.push(process_info.host.map(String::into_bytes));
+            processes_user.push(process_info.user.into_bytes());
+            processes_state.push(process_info.state.into_bytes());
+            processes_database.push(process_info.database.into_bytes());
+            processes_extra_info.push(process_info.extra_info.into_bytes());
             processes_memory_usage.push(process_info.memory_usage);
-            processes_scan_progress_read_rows.push(scan_progress.rows as u64);
-            processes_scan_progress_read_bytes.push(scan_progress.bytes as u64);
+            processes_data_read_bytes.push(process_info.data_read_bytes);
+            processes_data_write_bytes.push(process_info.data_write_bytes);
+            processes_scan_progress_read_rows.push(process_info.scan_progress_read_rows);
+            processes_scan_progress_read_bytes.push(process_info.scan_progress_read_bytes);
             processes_mysql_connection_id.push(process_info.mysql_connection_id);
-            processes_time.push(time);
-
-            if let Some(data_metrics) = data_metrics {
-                processes_data_read_bytes.push(data_metrics.get_read_bytes() as u64);
-                processes_data_write_bytes.push(data_metrics.get_write_bytes() as u64);
-            } else {
-                processes_data_read_bytes.push(0);
-                processes_data_write_bytes.push(0);
-            }
-
-            // Status info.
-            processes_status.push(
-                process_info
-                    .status_info
-                    .clone()
-                    .unwrap_or("".to_owned())
-                    .into_bytes(),
-            );
+            processes_time.push(process_info.time);
+            processes_status.push(process_info.status.into_bytes());
+            processes_node.push(process_info.node.into_bytes());
         }
 
         Ok(DataBlock::new_from_columns(vec![
@@ -131,6 +109,7 @@ impl SyncSystemTable for ProcessesTable {
             UInt32Type::from_opt_data(processes_mysql_connection_id),
             UInt64Type::from_data(processes_time),
             StringType::from_data(processes_status),
+            StringType::from_data(processes_node),
         ]))
     }
 }
@@ -171,6 +150,7 @@ impl ProcessesTable {
             ),
             TableField::new("time", TableDataType::Number(NumberDataType::UInt64)),
             TableField::new("status", TableDataType::String),
+            TableField::new("node", TableDataType::String),
         ]);
 
         let table_info = TableInfo {
@@ -186,15 +166,6 @@ impl ProcessesTable {
             ..Default::default()
         };
 
-        SyncOneBlockSystemTable::create(ProcessesTable { table_info })
-    }
-
-    fn process_host(client_address: &Option<SocketAddr>) -> Option<Vec<u8>> {
-        client_address.as_ref().map(|s| s.to_string().into_bytes())
-    }
-
-    fn process_option_value<T>(opt: Option<T>) -> T
-    where T: Default {
-        opt.unwrap_or_default()
+        AsyncOneBlockSystemTable::create(ProcessesTable { table_info })
     }
 }
@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
+use common_expression::types::Int64Type;
 use common_expression::types::NumberDataType;
 use common_expression::types::StringType;
 use common_expression::types::UInt32Type;
@@ -44,6 +45,18 @@ impl QueryProfileTable {
             TableField::new("plan_name", TableDataType::String),
             TableField::new("description", TableDataType::String),
             TableField::new("cpu_time", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("memory_usage", TableDataType::Number(NumberDataType::Int64)),
+            TableField::new(
+                "peak_memory_usage",
+                TableDataType::Number(NumberDataType::Int64),
+            ),
+            TableField::new("wait_time", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("output_rows", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "output_bytes",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("spill_bytes", TableDataType::Number(NumberDataType::UInt64)),
         ]);
 
         let table_info = TableInfo {
@@ -78,6 +91,12 @@ impl SyncSystemTable for QueryProfileTable {
         let mut plan_names: Vec<Vec<u8>> = Vec::with_capacity(query_profs.len());
         let mut descriptions: Vec<Vec<u8>> = Vec::with_capacity(query_profs.len());
         let mut cpu_times: Vec<u64> = Vec::with_capacity(query_profs.len());
+        let mut memory_usages: Vec<i64> = Vec::with_capacity(query_profs.len());
+        let mut peak_memory_usages: Vec<i64> = Vec::with_capacity(query_profs.len());
+        let mut wait_times: Vec<u64> = Vec::with_capacity(query_profs.len());
+        let mut output_rows: Vec<u64> = Vec::with_capacity(query_profs.len());
+        let mut output_bytes: Vec<u64> = Vec::with_capacity(query_profs.len());
+        let mut spill_bytes: Vec<u64> = Vec::with_capacity(query_profs.len());
 
         for prof in query_profs.iter() {
             for plan_prof in prof.plan_node_profs.iter() {
@@ -86,6 +105,12 @@ impl SyncSystemTable for QueryProfileTable {
                 plan_names.push(plan_prof.plan_node_name.clone().into_bytes());
                 descriptions.push(plan_prof.description.clone().into_bytes());
                 cpu_times.push(plan_prof.cpu_time.as_nanos() as u64);
+                memory_usages.push(plan_prof.memory_usage);
+                peak_memory_usages.push(plan_prof.peak_memory_usage);
+                wait_times.push(plan_prof.wait_time.as_nanos() as u64);
+                output_rows.push(plan_prof.output_rows as u64);
+                output_bytes.push(plan_prof.output_bytes as u64);
+                spill_bytes.push(plan_prof.spill_bytes as u64);
             }
         }
 
@@ -95,6 +120,12 @@ impl SyncSystemTable for QueryProfileTable {
             StringType::from_data(plan_names),
             StringType::from_data(descriptions),
             UInt64Type::from_data(cpu_times),
+            Int64Type::from_data(memory_usages),
+            Int64Type::from_data(peak_memory_usages),
+            UInt64Type::from_data(wait_times),
+            UInt64Type::from_data(output_rows),
+            UInt64Type::from_data(output_bytes),
+            UInt64Type::from_data(spill_bytes),
         ]);
 
         Ok(block)
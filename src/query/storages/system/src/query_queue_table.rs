@@ -0,0 +1,109 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_expression::types::Int64Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::UInt64Type;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+
+use crate::SyncOneBlockSystemTable;
+use crate::SyncSystemTable;
+
+/// The queries queued in or admitted by the workload groups.
+pub struct QueryQueueTable {
+    table_info: TableInfo,
+}
+
+impl QueryQueueTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("workload_group", TableDataType::String),
+            TableField::new("query_id", TableDataType::String),
+            TableField::new("user", TableDataType::String),
+            TableField::new("state", TableDataType::String),
+            TableField::new(
+                "queued_time_ms",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("memory_usage", TableDataType::Number(NumberDataType::Int64)),
+            TableField::new("query_text", TableDataType::String),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'query_queue'".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            name: "query_queue".to_string(),
+            meta: TableMeta {
+                schema,
+                engine: "SystemQueryQueue".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        SyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
+
+impl SyncSystemTable for QueryQueueTable {
+    const NAME: &'static str = "system.query_queue";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> common_exception::Result<DataBlock> {
+        let queries = ctx.get_workload_queries_info();
+
+        let mut groups: Vec<Vec<u8>> = Vec::with_capacity(queries.len());
+        let mut query_ids: Vec<Vec<u8>> = Vec::with_capacity(queries.len());
+        let mut users: Vec<Vec<u8>> = Vec::with_capacity(queries.len());
+        let mut states: Vec<Vec<u8>> = Vec::with_capacity(queries.len());
+        let mut queued_times: Vec<u64> = Vec::with_capacity(queries.len());
+        let mut memory_usages: Vec<i64> = Vec::with_capacity(queries.len());
+        let mut query_texts: Vec<Vec<u8>> = Vec::with_capacity(queries.len());
+
+        for query in queries.into_iter() {
+            groups.push(query.workload_group.into_bytes());
+            query_ids.push(query.query_id.into_bytes());
+            users.push(query.user.into_bytes());
+            states.push(query.state.into_bytes());
+            queued_times.push(query.queued_time.as_millis() as u64);
+            memory_usages.push(query.memory_usage);
+            query_texts.push(query.query_text.into_bytes());
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(groups),
+            StringType::from_data(query_ids),
+            StringType::from_data(users),
+            StringType::from_data(states),
+            UInt64Type::from_data(queued_times),
+            Int64Type::from_data(memory_usages),
+            StringType::from_data(query_texts),
+        ]))
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::NumberDataType;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_expression::TableSchemaRefExt;
+
+use crate::SystemLogElement;
+use crate::SystemLogQueue;
+use crate::SystemLogTable;
+
+/// The progress of a recluster job, logged after each of its iterations.
+///
+/// Every iteration commits its own snapshot, so an interrupted job can be resumed
+/// by running the recluster again, which continues from the last committed iteration.
+#[derive(Clone)]
+pub struct ReclusterProgressLogElement {
+    pub query_id: String,
+    pub event_time: i64,
+    pub database: String,
+    pub table: String,
+    pub iteration: u64,
+    pub average_overlaps: f64,
+    pub average_depth: f64,
+    pub status: String,
+}
+
+impl SystemLogElement for ReclusterProgressLogElement {
+    const TABLE_NAME: &'static str = "recluster_progress";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("query_id", TableDataType::String),
+            TableField::new("event_time", TableDataType::Timestamp),
+            TableField::new("database", TableDataType::String),
+            TableField::new("table", TableDataType::String),
+            TableField::new("iteration", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "average_overlaps",
+                TableDataType::Number(NumberDataType::Float64),
+            ),
+            TableField::new(
+                "average_depth",
+                TableDataType::Number(NumberDataType::Float64),
+            ),
+            TableField::new("status", TableDataType::String),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let mut columns = columns.iter_mut();
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.query_id.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.event_time).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.database.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.table.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.iteration)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::Float64(self.average_overlaps.into())).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::Float64(self.average_depth.into())).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.status.as_bytes().to_vec()).as_ref());
+        Ok(())
+    }
+}
+
+pub type ReclusterProgressQueue = SystemLogQueue<ReclusterProgressLogElement>;
+pub type ReclusterProgressTable = SystemLogTable<ReclusterProgressLogElement>;
@@ -0,0 +1,140 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::NumberDataType;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_expression::TableSchemaRefExt;
+
+use crate::SystemLogElement;
+use crate::SystemLogQueue;
+use crate::SystemLogTable;
+
+/// A round of the replication of a table to its replication stage.
+///
+/// `lag_seconds` is the time from the commit of the snapshot to its replication, 0 if the
+/// secondary is already up to date, NULL if the replication failed.
+#[derive(Clone)]
+pub struct ReplicationHistoryLogElement {
+    pub event_time: i64,
+    pub database: String,
+    pub table: String,
+    pub stage: String,
+    pub snapshot_id: Option<String>,
+    pub snapshot_time: Option<i64>,
+    pub lag_seconds: Option<u64>,
+    pub copied_files: u64,
+    pub copied_bytes: u64,
+    pub status: String,
+    pub error_message: String,
+}
+
+impl SystemLogElement for ReplicationHistoryLogElement {
+    const TABLE_NAME: &'static str = "replication_history";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("event_time", TableDataType::Timestamp),
+            TableField::new("database", TableDataType::String),
+            TableField::new("table", TableDataType::String),
+            TableField::new("stage", TableDataType::String),
+            TableField::new(
+                "snapshot_id",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new(
+                "snapshot_time",
+                TableDataType::Nullable(Box::new(TableDataType::Timestamp)),
+            ),
+            TableField::new(
+                "lag_seconds",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64))),
+            ),
+            TableField::new(
+                "copied_files",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "copied_bytes",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("status", TableDataType::String),
+            TableField::new("error_message", TableDataType::String),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let mut columns = columns.iter_mut();
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.event_time).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.database.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.table.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.stage.as_bytes().to_vec()).as_ref());
+        columns.next().unwrap().push(
+            self.snapshot_id
+                .as_ref()
+                .map(|id| Scalar::String(id.as_bytes().to_vec()))
+                .unwrap_or(Scalar::Null)
+                .as_ref(),
+        );
+        columns.next().unwrap().push(
+            self.snapshot_time
+                .map(Scalar::Timestamp)
+                .unwrap_or(Scalar::Null)
+                .as_ref(),
+        );
+        columns.next().unwrap().push(
+            self.lag_seconds
+                .map(|lag| Scalar::Number(NumberScalar::UInt64(lag)))
+                .unwrap_or(Scalar::Null)
+                .as_ref(),
+        );
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.copied_files)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::UInt64(self.copied_bytes)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.status.as_bytes().to_vec()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.error_message.as_bytes().to_vec()).as_ref());
+        Ok(())
+    }
+}
+
+pub type ReplicationHistoryQueue = SystemLogQueue<ReplicationHistoryLogElement>;
+pub type ReplicationHistoryTable = SystemLogTable<ReplicationHistoryLogElement>;
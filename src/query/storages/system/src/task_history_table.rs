@@ -0,0 +1,131 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::types::UInt16Type;
+use common_expression::types::UInt64Type;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+/// The finished runs of the tasks, the latest runs of each task are kept in meta.
+pub struct TaskHistoryTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for TaskHistoryTable {
+    const NAME: &'static str = "system.task_history";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let runs = UserApiProvider::instance()
+            .get_task_runs(&tenant, None)
+            .await?;
+
+        let mut names = Vec::with_capacity(runs.len());
+        let mut triggers = Vec::with_capacity(runs.len());
+        let mut query_ids = Vec::with_capacity(runs.len());
+        let mut states = Vec::with_capacity(runs.len());
+        let mut scheduled_at = Vec::with_capacity(runs.len());
+        let mut started_at = Vec::with_capacity(runs.len());
+        let mut finished_at = Vec::with_capacity(runs.len());
+        let mut attempts = Vec::with_capacity(runs.len());
+        let mut error_codes = Vec::with_capacity(runs.len());
+        let mut error_messages = Vec::with_capacity(runs.len());
+        for run in runs {
+            let state = if run.succeeded { "SUCCEEDED" } else { "FAILED" };
+            names.push(run.task_name.into_bytes());
+            triggers.push(run.trigger.to_string().into_bytes());
+            query_ids.push(run.query_id.into_bytes());
+            states.push(state.as_bytes().to_vec());
+            scheduled_at.push(run.scheduled_at.timestamp_micros());
+            started_at.push(run.started_at.timestamp_micros());
+            finished_at.push(run.finished_at.timestamp_micros());
+            attempts.push(run.attempts);
+            error_codes.push(run.error_code);
+            error_messages.push(run.error_message.into_bytes());
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(triggers),
+            StringType::from_data(query_ids),
+            StringType::from_data(states),
+            TimestampType::from_data(scheduled_at),
+            TimestampType::from_data(started_at),
+            TimestampType::from_data(finished_at),
+            UInt64Type::from_data(attempts),
+            UInt16Type::from_data(error_codes),
+            StringType::from_data(error_messages),
+        ]))
+    }
+}
+
+impl TaskHistoryTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("trigger", TableDataType::String),
+            TableField::new("query_id", TableDataType::String),
+            TableField::new("state", TableDataType::String),
+            TableField::new("scheduled_at", TableDataType::Timestamp),
+            TableField::new("started_at", TableDataType::Timestamp),
+            TableField::new("finished_at", TableDataType::Timestamp),
+            TableField::new("attempts", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("error_code", TableDataType::Number(NumberDataType::UInt16)),
+            TableField::new("error_message", TableDataType::String),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'task_history'".to_string(),
+            name: "task_history".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemTaskHistory".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(TaskHistoryTable { table_info })
+    }
+}
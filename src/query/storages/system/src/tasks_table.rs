@@ -0,0 +1,138 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::types::UInt64Type;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::FromOptData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct TasksTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for TasksTable {
+    const NAME: &'static str = "system.tasks";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let tasks = UserApiProvider::instance().get_tasks(&tenant).await?;
+
+        let mut names = Vec::with_capacity(tasks.len());
+        let mut owners = Vec::with_capacity(tasks.len());
+        let mut databases = Vec::with_capacity(tasks.len());
+        let mut schedules = Vec::with_capacity(tasks.len());
+        let mut states = Vec::with_capacity(tasks.len());
+        let mut retry_attempts = Vec::with_capacity(tasks.len());
+        let mut definitions = Vec::with_capacity(tasks.len());
+        let mut comments = Vec::with_capacity(tasks.len());
+        let mut created_on = Vec::with_capacity(tasks.len());
+        let mut updated_on = Vec::with_capacity(tasks.len());
+        let mut next_scheduled_at = Vec::with_capacity(tasks.len());
+        for task in tasks {
+            names.push(task.name.into_bytes());
+            owners.push(task.owner.to_string().into_bytes());
+            databases.push(task.database.into_bytes());
+            schedules.push(task.schedule.to_string().into_bytes());
+            states.push(task.state.to_string().into_bytes());
+            retry_attempts.push(task.retry_attempts);
+            definitions.push(task.sql.into_bytes());
+            comments.push(task.comment.into_bytes());
+            created_on.push(task.created_on.timestamp_micros());
+            updated_on.push(task.updated_on.timestamp_micros());
+            next_scheduled_at.push(task.next_scheduled_at.map(|at| at.timestamp_micros()));
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(owners),
+            StringType::from_data(databases),
+            StringType::from_data(schedules),
+            StringType::from_data(states),
+            UInt64Type::from_data(retry_attempts),
+            StringType::from_data(definitions),
+            StringType::from_data(comments),
+            TimestampType::from_data(created_on),
+            TimestampType::from_data(updated_on),
+            TimestampType::from_opt_data(next_scheduled_at),
+        ]))
+    }
+}
+
+impl TasksTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("owner", TableDataType::String),
+            TableField::new("database", TableDataType::String),
+            TableField::new("schedule", TableDataType::String),
+            TableField::new("state", TableDataType::String),
+            TableField::new(
+                "retry_attempts",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("definition", TableDataType::String),
+            TableField::new("comment", TableDataType::String),
+            TableField::new("created_on", TableDataType::Timestamp),
+            TableField::new("updated_on", TableDataType::Timestamp),
+            // NULL while the task is suspended
+            TableField::new(
+                "next_scheduled_at",
+                TableDataType::Nullable(Box::new(TableDataType::Timestamp)),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'tasks'".to_string(),
+            name: "tasks".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemTasks".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(TasksTable { table_info })
+    }
+}
@@ -12,6 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
 use common_exception::ErrorCode;
 use common_exception::Result;
 use jwt_simple::algorithms::ECDSAP256PublicKeyLike;
@@ -19,9 +23,11 @@ use jwt_simple::algorithms::ES256PublicKey;
 use jwt_simple::algorithms::RS256PublicKey;
 use jwt_simple::algorithms::RSAPublicKeyLike;
 use jwt_simple::prelude::JWTClaims;
+use jwt_simple::prelude::VerificationOptions;
 use jwt_simple::token::Token;
 use serde::Deserialize;
 use serde::Serialize;
+use serde_json::Value;
 
 use super::jwk;
 
@@ -32,8 +38,15 @@ pub enum PubKey {
 }
 
 pub struct JwtAuthenticator {
-    // Todo(youngsofun): verify settings, like issuer
     key_stores: Vec<jwk::JwkKeyStore>,
+    // the `iss` claim of a token must be the issuer if it's set
+    issuer: Option<String>,
+    // the `aud` claim of a token must contain the audience if it's set
+    audience: Option<String>,
+    // the claim which holds the roles of the user, like `groups` or `realm_access.roles`
+    role_claim: Option<String>,
+    // claim value -> role, the claim values not mapped are ignored
+    role_mapping: HashMap<String, String>,
 }
 
 #[derive(Debug, Default, Deserialize, Serialize)]
@@ -46,6 +59,9 @@ pub struct CustomClaims {
     pub tenant_id: Option<String>,
     pub role: Option<String>,
     pub ensure_user: Option<EnsureUser>,
+    /// The other claims of the token, the roles of the user may be mapped from them.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
 }
 
 impl CustomClaims {
@@ -54,6 +70,7 @@ impl CustomClaims {
             tenant_id: None,
             role: None,
             ensure_user: None,
+            extra: BTreeMap::new(),
         }
     }
 
@@ -87,7 +104,119 @@ impl JwtAuthenticator {
         for u in jwt_key_files {
             key_stores.push(jwk::JwkKeyStore::new(u))
         }
-        Some(JwtAuthenticator { key_stores })
+        Some(JwtAuthenticator {
+            key_stores,
+            issuer: None,
+            audience: None,
+            role_claim: None,
+            role_mapping: HashMap::new(),
+        })
+    }
+
+    /// Trust the tokens of an OIDC issuer, the JWKS of it is discovered from the
+    /// OpenID configuration of the issuer.
+    pub fn create_oidc(issuer: &str) -> Self {
+        JwtAuthenticator {
+            key_stores: vec![jwk::JwkKeyStore::discover(issuer)],
+            issuer: Some(issuer.to_string()),
+            audience: None,
+            role_claim: None,
+            role_mapping: HashMap::new(),
+        }
+    }
+
+    pub fn with_issuer(mut self, issuer: &str) -> Self {
+        if !issuer.is_empty() {
+            self.issuer = Some(issuer.to_string());
+        }
+        self
+    }
+
+    /// The tokens of an issuer are signed by an external identity provider, their `tenant_id`,
+    /// `role` and `ensure_user.roles` claims are not trusted.
+    pub fn has_issuer(&self) -> bool {
+        self.issuer.is_some()
+    }
+
+    /// The audience is required if the issuer is set, the tokens the issuer signs for
+    /// the other applications are not accepted.
+    pub fn with_audience(mut self, audience: &str) -> Result<Self> {
+        if !audience.is_empty() {
+            self.audience = Some(audience.to_string());
+        } else if let Some(issuer) = &self.issuer {
+            return Err(ErrorCode::InvalidConfig(format!(
+                "jwt audience is required with the jwt issuer '{}'",
+                issuer
+            )));
+        }
+        Ok(self)
+    }
+
+    /// Map the values of `role_claim` to roles, each entry of `role_mapping` is
+    /// like `<claim value>=<role>`. The mapping is required, the claim values are never
+    /// taken as the roles themselves.
+    pub fn with_role_mapping(mut self, role_claim: &str, role_mapping: &[String]) -> Result<Self> {
+        if role_claim.is_empty() {
+            return Ok(self);
+        }
+        if role_mapping.is_empty() {
+            return Err(ErrorCode::InvalidConfig(format!(
+                "jwt role mapping is required with the jwt role claim '{}'",
+                role_claim
+            )));
+        }
+        self.role_claim = Some(role_claim.to_string());
+        for entry in role_mapping {
+            match entry.split_once('=') {
+                Some((value, role)) if !value.is_empty() && !role.is_empty() => {
+                    self.role_mapping
+                        .insert(value.trim().to_string(), role.trim().to_string());
+                }
+                _ => {
+                    return Err(ErrorCode::InvalidConfig(format!(
+                        "invalid jwt role mapping '{}', expect '<claim value>=<role>'",
+                        entry
+                    )));
+                }
+            }
+        }
+        Ok(self)
+    }
+
+    /// The roles mapped from the role claim of a token.
+    pub fn mapped_roles(&self, claims: &CustomClaims) -> Vec<String> {
+        let role_claim = match &self.role_claim {
+            Some(role_claim) => role_claim,
+            None => return vec![],
+        };
+        let mut path = role_claim.split('.');
+        let mut value = path.next().and_then(|name| claims.extra.get(name));
+        for name in path {
+            value = value.and_then(|v| v.get(name));
+        }
+        let values = match value {
+            Some(Value::String(v)) => vec![v.as_str()],
+            Some(Value::Array(vs)) => vs.iter().filter_map(|v| v.as_str()).collect(),
+            _ => vec![],
+        };
+
+        let mut roles = vec![];
+        for value in values {
+            if let Some(role) = self.role_mapping.get(value) {
+                if !roles.contains(role) {
+                    roles.push(role.clone());
+                }
+            }
+        }
+        roles
+    }
+
+    fn verification_options(&self) -> VerificationOptions {
+        VerificationOptions {
+            allowed_issuers: self.issuer.as_ref().map(|v| HashSet::from([v.clone()])),
+            allowed_audiences: self.audience.as_ref().map(|v| HashSet::from([v.clone()])),
+            ..Default::default()
+        }
     }
 
     // parse jwt claims from single source, if custom claim is not matching on desired, claim parsed would be empty
@@ -100,9 +229,10 @@ impl JwtAuthenticator {
         let metadata = Token::decode_metadata(token);
         let key_id = metadata.map_or(None, |e| e.key_id().map(|s| s.to_string()));
         let pub_key = key_store.get_key(key_id).await?;
+        let options = Some(self.verification_options());
         let r = match &pub_key {
-            PubKey::RSA256(pk) => pk.verify_token::<CustomClaims>(token, None),
-            PubKey::ES256(pk) => pk.verify_token::<CustomClaims>(token, None),
+            PubKey::RSA256(pk) => pk.verify_token::<CustomClaims>(token, options),
+            PubKey::ES256(pk) => pk.verify_token::<CustomClaims>(token, options),
         };
         let c = r.map_err(|err| ErrorCode::AuthenticateFailure(err.to_string()))?;
         match c.subject {
@@ -32,6 +32,9 @@ use serde::Serialize;
 use super::PubKey;
 
 const JWK_REFRESH_INTERVAL: u64 = 15;
+// The keys are reloaded at most once in this interval(in seconds) when a token
+// is signed by an unknown key, the provider may have rotated its keys.
+const JWK_RELOAD_ON_MISS_INTERVAL: u64 = 10;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct JwkKey {
@@ -92,8 +95,17 @@ pub struct JwkKeys {
     pub keys: Vec<JwkKey>,
 }
 
+/// The part of the OpenID provider metadata we need, see
+/// https://openid.net/specs/openid-connect-discovery-1_0.html#ProviderMetadata
+#[derive(Deserialize)]
+struct OidcProviderMetadata {
+    jwks_uri: String,
+}
+
 pub struct JwkKeyStore {
     pub(crate) url: String,
+    // `url` is the OpenID configuration of an issuer, the keys are loaded from the `jwks_uri` of it.
+    discovery: bool,
     keys: Arc<RwLock<HashMap<String, PubKey>>>,
     pub(crate) last_refreshed_at: RwLock<Option<Instant>>,
     pub(crate) refresh_interval: Duration,
@@ -105,11 +117,26 @@ impl JwkKeyStore {
         let keys = Arc::new(RwLock::new(HashMap::new()));
         Self {
             url,
+            discovery: false,
             keys,
             refresh_interval,
             last_refreshed_at: RwLock::new(None),
         }
     }
+
+    /// Create a key store of an OIDC issuer, the JWKS url is discovered from
+    /// `<issuer>/.well-known/openid-configuration`.
+    pub fn discover(issuer: &str) -> Self {
+        let url = format!(
+            "{}/.well-known/openid-configuration",
+            issuer.trim_end_matches('/')
+        );
+        Self {
+            discovery: true,
+            ..Self::new(url)
+        }
+    }
+
     pub fn url(&self) -> String {
         self.url.clone()
     }
@@ -117,8 +144,32 @@ impl JwkKeyStore {
 
 impl JwkKeyStore {
     #[async_backtrace::framed]
-    async fn load_keys(&self) -> Result<()> {
+    async fn jwks_url(&self) -> Result<String> {
+        if !self.discovery {
+            return Ok(self.url.clone());
+        }
         let response = reqwest::get(&self.url).await.map_err(|e| {
+            ErrorCode::AuthenticateFailure(format!(
+                "Could not download OpenID configuration: {}",
+                e
+            ))
+        })?;
+        let body = response.text().await.map_err(|e| {
+            ErrorCode::AuthenticateFailure(format!(
+                "Could not download OpenID configuration: {}",
+                e
+            ))
+        })?;
+        let metadata = serde_json::from_str::<OidcProviderMetadata>(&body).map_err(|e| {
+            ErrorCode::InvalidConfig(format!("Failed to parse OpenID configuration: {}", e))
+        })?;
+        Ok(metadata.jwks_uri)
+    }
+
+    #[async_backtrace::framed]
+    async fn load_keys(&self) -> Result<()> {
+        let url = self.jwks_url().await?;
+        let response = reqwest::get(&url).await.map_err(|e| {
             ErrorCode::AuthenticateFailure(format!("Could not download JWKS: {}", e))
         })?;
         let body = response.text().await.unwrap();
@@ -147,9 +198,28 @@ impl JwkKeyStore {
         Ok(())
     }
 
+    // Reload the keys if a key id is not found, unless they are just reloaded.
+    #[async_backtrace::framed]
+    async fn reload_keys_on_miss(&self, key_id: &Option<String>) -> Result<()> {
+        let missed = match key_id {
+            Some(kid) => !self.keys.read().contains_key(kid),
+            None => false,
+        };
+        let reloaded_recently = match *self.last_refreshed_at.read() {
+            Some(at) => at.elapsed() < Duration::from_secs(JWK_RELOAD_ON_MISS_INTERVAL),
+            None => false,
+        };
+        if missed && !reloaded_recently {
+            self.load_keys().await?;
+            self.last_refreshed_at.write().replace(Instant::now());
+        }
+        Ok(())
+    }
+
     #[async_backtrace::framed]
     pub(super) async fn get_key(&self, key_id: Option<String>) -> Result<PubKey> {
         self.maybe_reload_keys().await?;
+        self.reload_keys_on_miss(&key_id).await?;
         let keys = self.keys.read();
         match key_id {
             Some(kid) => match keys.get(&kid) {
@@ -34,9 +34,17 @@ mod jwt;
 mod role_mgr;
 mod user;
 mod user_api;
+mod user_compaction;
+mod user_dictionary;
 mod user_mgr;
+mod user_network_policy;
+mod user_notification;
+mod user_pipe;
+mod user_row_access_policy;
+mod user_sequence;
 mod user_setting;
 mod user_stage;
+mod user_task;
 mod user_udf;
 
 pub mod file_format;
@@ -50,3 +58,5 @@ pub use role_mgr::BUILTIN_ROLE_ACCOUNT_ADMIN;
 pub use role_mgr::BUILTIN_ROLE_PUBLIC;
 pub use user::CertifiedInfo;
 pub use user_api::UserApiProvider;
+pub use user_network_policy::parse_client_ip;
+pub use user_network_policy::parse_network_policy_ip;
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 
 use common_exception::ErrorCode;
@@ -165,6 +166,27 @@ impl UserApiProvider {
             .map_err(|e| e.add_message_back("(while revoke role privileges)"))
     }
 
+    /// Set and unset the settings attached to the role.
+    #[async_backtrace::framed]
+    pub async fn update_role_settings(
+        &self,
+        tenant: &str,
+        role: &String,
+        set_settings: BTreeMap<String, String>,
+        unset_settings: Vec<String>,
+    ) -> Result<Option<u64>> {
+        let client = self.get_role_api_client(tenant)?;
+        client
+            .update_role_with(role, MatchSeq::GE(1), |ri: &mut RoleInfo| {
+                ri.settings.extend(set_settings);
+                for name in &unset_settings {
+                    ri.settings.remove(name);
+                }
+            })
+            .await
+            .map_err(|e| e.add_message_back("(while set role settings)"))
+    }
+
     // the grant_role can not have cycle with target_role.
     #[async_backtrace::framed]
     pub async fn grant_role_to_role(
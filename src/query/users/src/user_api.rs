@@ -17,16 +17,32 @@ use std::sync::Arc;
 use common_base::base::GlobalInstance;
 use common_exception::Result;
 use common_grpc::RpcClientConf;
+use common_management::CompactionApi;
+use common_management::CompactionMgr;
+use common_management::DictionaryApi;
+use common_management::DictionaryMgr;
 use common_management::FileFormatApi;
 use common_management::FileFormatMgr;
+use common_management::NetworkPolicyApi;
+use common_management::NetworkPolicyMgr;
+use common_management::NotificationApi;
+use common_management::NotificationMgr;
+use common_management::PipeApi;
+use common_management::PipeMgr;
 use common_management::QuotaApi;
 use common_management::QuotaMgr;
 use common_management::RoleApi;
 use common_management::RoleMgr;
+use common_management::RowAccessPolicyApi;
+use common_management::RowAccessPolicyMgr;
+use common_management::SequenceApi;
+use common_management::SequenceMgr;
 use common_management::SettingApi;
 use common_management::SettingMgr;
 use common_management::StageApi;
 use common_management::StageMgr;
+use common_management::TaskApi;
+use common_management::TaskMgr;
 use common_management::UdfApi;
 use common_management::UdfMgr;
 use common_management::UserApi;
@@ -118,6 +134,56 @@ impl UserApiProvider {
         Ok(Arc::new(UdfMgr::create(self.client.clone(), tenant)?))
     }
 
+    pub fn get_dictionary_api_client(&self, tenant: &str) -> Result<Arc<dyn DictionaryApi>> {
+        Ok(Arc::new(DictionaryMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
+    pub fn get_row_access_policy_api_client(
+        &self,
+        tenant: &str,
+    ) -> Result<Arc<dyn RowAccessPolicyApi>> {
+        Ok(Arc::new(RowAccessPolicyMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
+    pub fn get_sequence_api_client(&self, tenant: &str) -> Result<Arc<dyn SequenceApi>> {
+        Ok(Arc::new(SequenceMgr::create(self.client.clone(), tenant)?))
+    }
+
+    pub fn get_compaction_api_client(&self, tenant: &str) -> Result<Arc<dyn CompactionApi>> {
+        Ok(Arc::new(CompactionMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
+    pub fn get_task_api_client(&self, tenant: &str) -> Result<Arc<dyn TaskApi>> {
+        Ok(Arc::new(TaskMgr::create(self.client.clone(), tenant)?))
+    }
+
+    pub fn get_network_policy_api_client(&self, tenant: &str) -> Result<Arc<dyn NetworkPolicyApi>> {
+        Ok(Arc::new(NetworkPolicyMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
+    pub fn get_notification_api_client(&self, tenant: &str) -> Result<Arc<dyn NotificationApi>> {
+        Ok(Arc::new(NotificationMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
+    pub fn get_pipe_api_client(&self, tenant: &str) -> Result<Arc<dyn PipeApi>> {
+        Ok(Arc::new(PipeMgr::create(self.client.clone(), tenant)?))
+    }
+
     pub fn get_tenant_quota_api_client(&self, tenant: &str) -> Result<Arc<dyn QuotaApi>> {
         Ok(Arc::new(QuotaMgr::create(self.client.clone(), tenant)?))
     }
@@ -0,0 +1,36 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_exception::Result;
+
+use crate::UserApiProvider;
+
+/// Background compaction operations.
+impl UserApiProvider {
+    // Take or renew the lease of the background compaction of the tenant.
+    #[async_backtrace::framed]
+    pub async fn renew_compactor_lease(
+        &self,
+        tenant: &str,
+        holder: &str,
+        ttl: Duration,
+    ) -> Result<bool> {
+        let compaction_api_client = self.get_compaction_api_client(tenant)?;
+        compaction_api_client
+            .renew_compactor_lease(holder, ttl)
+            .await
+    }
+}
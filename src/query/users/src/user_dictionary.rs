@@ -0,0 +1,81 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::DictionaryMeta;
+use common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+/// Dictionary operations.
+impl UserApiProvider {
+    // Add a new dictionary.
+    #[async_backtrace::framed]
+    pub async fn add_dictionary(
+        &self,
+        tenant: &str,
+        dictionary: DictionaryMeta,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        let dictionary_api_client = self.get_dictionary_api_client(tenant)?;
+        match dictionary_api_client.add_dictionary(dictionary).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::DICTIONARY_ALREADY_EXISTS {
+                    Ok(u64::MIN)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Get a dictionary by name.
+    #[async_backtrace::framed]
+    pub async fn get_dictionary(&self, tenant: &str, name: &str) -> Result<DictionaryMeta> {
+        let dictionary_api_client = self.get_dictionary_api_client(tenant)?;
+        let get_dictionary = dictionary_api_client.get_dictionary(name, MatchSeq::GE(0));
+        Ok(get_dictionary.await?.data)
+    }
+
+    // Get all dictionaries for the tenant.
+    #[async_backtrace::framed]
+    pub async fn get_dictionaries(&self, tenant: &str) -> Result<Vec<DictionaryMeta>> {
+        let dictionary_api_client = self.get_dictionary_api_client(tenant)?;
+        match dictionary_api_client.get_dictionaries().await {
+            Err(e) => Err(e.add_message_back("(while get dictionaries).")),
+            Ok(dictionaries) => Ok(dictionaries),
+        }
+    }
+
+    // Drop a dictionary by name.
+    #[async_backtrace::framed]
+    pub async fn drop_dictionary(&self, tenant: &str, name: &str, if_exists: bool) -> Result<()> {
+        let dictionary_api_client = self.get_dictionary_api_client(tenant)?;
+        match dictionary_api_client
+            .drop_dictionary(name, MatchSeq::GE(1))
+            .await
+        {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back("(while drop dictionary)"))
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,178 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::IpAddr;
+use std::net::SocketAddr;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::NetworkPolicy;
+use common_meta_app::principal::UserInfo;
+use common_meta_types::MatchSeq;
+use ipnet::IpNet;
+
+use crate::UserApiProvider;
+
+/// Network policy operations.
+impl UserApiProvider {
+    // Add a new network policy.
+    #[async_backtrace::framed]
+    pub async fn add_network_policy(
+        &self,
+        tenant: &str,
+        policy: NetworkPolicy,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        let policy_api_client = self.get_network_policy_api_client(tenant)?;
+        match policy_api_client.add_network_policy(policy).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::NETWORK_POLICY_ALREADY_EXISTS {
+                    Ok(u64::MIN)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Get a network policy by name.
+    #[async_backtrace::framed]
+    pub async fn get_network_policy(&self, tenant: &str, name: &str) -> Result<NetworkPolicy> {
+        let policy_api_client = self.get_network_policy_api_client(tenant)?;
+        let get_policy = policy_api_client.get_network_policy(name, MatchSeq::GE(0));
+        Ok(get_policy.await?.data)
+    }
+
+    // Get all network policies for the tenant.
+    #[async_backtrace::framed]
+    pub async fn get_network_policies(&self, tenant: &str) -> Result<Vec<NetworkPolicy>> {
+        let policy_api_client = self.get_network_policy_api_client(tenant)?;
+        match policy_api_client.get_network_policies().await {
+            Err(e) => Err(e.add_message_back("(while get network policies).")),
+            Ok(policies) => Ok(policies),
+        }
+    }
+
+    // Drop a network policy by name, a policy attached to users can not be dropped.
+    // A user may still attach it right after the check, its logins fail then since
+    // enforce_network_policy rejects the users of a missing policy.
+    #[async_backtrace::framed]
+    pub async fn drop_network_policy(
+        &self,
+        tenant: &str,
+        name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let users = self.get_users(tenant).await?;
+        if let Some(user) = users
+            .iter()
+            .find(|u| u.option.network_policy().map(|p| p.as_str()) == Some(name))
+        {
+            return Err(ErrorCode::NetworkPolicyIsUsedByUser(format!(
+                "network policy {} is used by user {}",
+                name,
+                user.identity()
+            )));
+        }
+
+        let policy_api_client = self.get_network_policy_api_client(tenant)?;
+        match policy_api_client
+            .drop_network_policy(name, MatchSeq::GE(1))
+            .await
+        {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back("(while drop network policy)"))
+                }
+            }
+        }
+    }
+
+    // Check the client address of a user against the network policy attached to it, this must
+    // be done after the credential of the user is verified. The error tells nothing about the
+    // policy, and a missing policy (e.g. dropped while a user attaches it) rejects any address.
+    #[async_backtrace::framed]
+    pub async fn enforce_network_policy(
+        &self,
+        tenant: &str,
+        user: &UserInfo,
+        client_ip: Option<&str>,
+    ) -> Result<()> {
+        let policy_name = match user.option.network_policy() {
+            Some(policy_name) => policy_name,
+            None => return Ok(()),
+        };
+        let policy = self.get_network_policy(tenant, policy_name).await.ok();
+        let client_ip = client_ip.and_then(parse_client_ip);
+
+        let allowed = match (policy, client_ip) {
+            (Some(policy), Some(client_ip)) => {
+                let matches = |list: &[String]| {
+                    list.iter().any(|ip| match parse_network_policy_ip(ip) {
+                        Ok(net) => net.contains(&client_ip),
+                        Err(_) => false,
+                    })
+                };
+                !matches(&policy.blocked_ip_list) && matches(&policy.allowed_ip_list)
+            }
+            _ => false,
+        };
+        if !allowed {
+            tracing::warn!(
+                "user {} from {:?} is rejected by network policy {}",
+                user.identity(),
+                client_ip,
+                policy_name
+            );
+            return Err(ErrorCode::AuthenticateFailure(
+                "client address is not allowed",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Parses the address of a client, either an ip or a socket address like `[::1]:3307`.
+/// The IPv4-mapped IPv6 addresses are converted to IPv4, so that they match the IPv4
+/// entries of the network policies.
+pub fn parse_client_ip(addr: &str) -> Option<IpAddr> {
+    let ip = match addr.parse::<SocketAddr>() {
+        Ok(addr) => addr.ip(),
+        Err(_) => addr.parse::<IpAddr>().ok()?,
+    };
+    match ip {
+        IpAddr::V6(v6) => Some(v6.to_ipv4_mapped().map_or(ip, IpAddr::V4)),
+        IpAddr::V4(_) => Some(ip),
+    }
+}
+
+/// Parses an entry of the ip lists of a network policy, which is either an
+/// address like `192.168.1.10` or a CIDR block like `192.168.1.0/24`.
+pub fn parse_network_policy_ip(ip: &str) -> Result<IpNet> {
+    let parsed = if ip.contains('/') {
+        ip.parse::<IpNet>().ok()
+    } else {
+        ip.parse::<IpAddr>().ok().map(IpNet::from)
+    };
+    parsed.ok_or_else(|| {
+        ErrorCode::BadArguments(format!(
+            "invalid ip address or CIDR block '{}' in network policy",
+            ip
+        ))
+    })
+}
@@ -0,0 +1,101 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::NotificationIntegration;
+use common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+/// Notification integration operations.
+impl UserApiProvider {
+    // Add a new notification integration.
+    #[async_backtrace::framed]
+    pub async fn add_notification_integration(
+        &self,
+        tenant: &str,
+        integration: NotificationIntegration,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        let notification_api_client = self.get_notification_api_client(tenant)?;
+        match notification_api_client
+            .add_notification_integration(integration)
+            .await
+        {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::NOTIFICATION_INTEGRATION_ALREADY_EXISTS {
+                    Ok(u64::MIN)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Get a notification integration by name.
+    #[async_backtrace::framed]
+    pub async fn get_notification_integration(
+        &self,
+        tenant: &str,
+        name: &str,
+    ) -> Result<NotificationIntegration> {
+        let notification_api_client = self.get_notification_api_client(tenant)?;
+        let get_integration =
+            notification_api_client.get_notification_integration(name, MatchSeq::GE(0));
+        Ok(get_integration.await?.data)
+    }
+
+    // Get all notification integrations for the tenant.
+    #[async_backtrace::framed]
+    pub async fn get_notification_integrations(
+        &self,
+        tenant: &str,
+    ) -> Result<Vec<NotificationIntegration>> {
+        let notification_api_client = self.get_notification_api_client(tenant)?;
+        match notification_api_client
+            .get_notification_integrations()
+            .await
+        {
+            Err(e) => Err(e.add_message_back("(while get notification integrations).")),
+            Ok(integrations) => Ok(integrations),
+        }
+    }
+
+    // Drop a notification integration by name, the tables subscribed to it are not
+    // notified anymore.
+    #[async_backtrace::framed]
+    pub async fn drop_notification_integration(
+        &self,
+        tenant: &str,
+        name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let notification_api_client = self.get_notification_api_client(tenant)?;
+        match notification_api_client
+            .drop_notification_integration(name, MatchSeq::GE(1))
+            .await
+        {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_NOTIFICATION_INTEGRATION {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back("(while drop notification integration)"))
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,150 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::PipeBatch;
+use common_meta_app::principal::PipeInfo;
+use common_meta_app::principal::PipeLoad;
+use common_meta_types::MatchSeq;
+use common_meta_types::SeqV;
+
+use crate::UserApiProvider;
+
+/// Pipe operations.
+impl UserApiProvider {
+    // Add a new pipe.
+    #[async_backtrace::framed]
+    pub async fn add_pipe(&self, tenant: &str, pipe: PipeInfo, if_not_exists: bool) -> Result<u64> {
+        let pipe_api_client = self.get_pipe_api_client(tenant)?;
+        match pipe_api_client.add_pipe(pipe).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::PIPE_ALREADY_EXISTS {
+                    Ok(u64::MIN)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Get a pipe by name, with the seq to update it.
+    #[async_backtrace::framed]
+    pub async fn get_pipe(&self, tenant: &str, name: &str) -> Result<SeqV<PipeInfo>> {
+        let pipe_api_client = self.get_pipe_api_client(tenant)?;
+        pipe_api_client.get_pipe(name, MatchSeq::GE(0)).await
+    }
+
+    // Get all pipes for the tenant.
+    #[async_backtrace::framed]
+    pub async fn get_pipes(&self, tenant: &str) -> Result<Vec<PipeInfo>> {
+        let pipe_api_client = self.get_pipe_api_client(tenant)?;
+        match pipe_api_client.get_pipes().await {
+            Err(e) => Err(e.add_message_back("(while get pipes).")),
+            Ok(pipes) => Ok(pipes),
+        }
+    }
+
+    // Update a pipe if it's not updated since read with `seq`.
+    #[async_backtrace::framed]
+    pub async fn update_pipe(&self, tenant: &str, pipe: PipeInfo, seq: u64) -> Result<u64> {
+        let pipe_api_client = self.get_pipe_api_client(tenant)?;
+        pipe_api_client
+            .update_pipe(pipe, MatchSeq::Exact(seq))
+            .await
+    }
+
+    // Remove the deduplicate label of a loaded batch of a pipe.
+    #[async_backtrace::framed]
+    pub async fn remove_pipe_batch_label(
+        &self,
+        tenant: &str,
+        pipe: &PipeInfo,
+        batch: &PipeBatch,
+    ) -> Result<()> {
+        let pipe_api_client = self.get_pipe_api_client(tenant)?;
+        pipe_api_client.remove_batch_label(pipe, batch).await
+    }
+
+    // Drop a pipe by name.
+    #[async_backtrace::framed]
+    pub async fn drop_pipe(&self, tenant: &str, name: &str, if_exists: bool) -> Result<()> {
+        let pipe_api_client = self.get_pipe_api_client(tenant)?;
+        match pipe_api_client.drop_pipe(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_PIPE {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back("(while drop pipe)"))
+                }
+            }
+        }
+    }
+
+    // Add the files of a pipe from a stage to copy.
+    #[async_backtrace::framed]
+    pub async fn add_pipe_files(&self, tenant: &str, name: &str, files: &[String]) -> Result<()> {
+        let pipe_api_client = self.get_pipe_api_client(tenant)?;
+        pipe_api_client.add_pipe_files(name, files).await
+    }
+
+    // Get the files of a pipe waiting to be copied.
+    #[async_backtrace::framed]
+    pub async fn get_pipe_files(&self, tenant: &str, name: &str) -> Result<Vec<String>> {
+        let pipe_api_client = self.get_pipe_api_client(tenant)?;
+        pipe_api_client.get_pipe_files(name).await
+    }
+
+    // Remove the files of a pipe once they are copied.
+    #[async_backtrace::framed]
+    pub async fn remove_pipe_files(
+        &self,
+        tenant: &str,
+        name: &str,
+        files: &[String],
+    ) -> Result<()> {
+        let pipe_api_client = self.get_pipe_api_client(tenant)?;
+        pipe_api_client.remove_pipe_files(name, files).await
+    }
+
+    // Record a load of a pipe.
+    #[async_backtrace::framed]
+    pub async fn add_pipe_load(&self, tenant: &str, load: PipeLoad) -> Result<()> {
+        let pipe_api_client = self.get_pipe_api_client(tenant)?;
+        pipe_api_client.add_pipe_load(load).await
+    }
+
+    // Get the loads of a pipe, or of all the pipes of the tenant.
+    #[async_backtrace::framed]
+    pub async fn get_pipe_loads(&self, tenant: &str, name: Option<&str>) -> Result<Vec<PipeLoad>> {
+        let pipe_api_client = self.get_pipe_api_client(tenant)?;
+        pipe_api_client.get_pipe_loads(name).await
+    }
+
+    // Take or renew the lease of the pipe runner of the tenant.
+    #[async_backtrace::framed]
+    pub async fn renew_pipe_runner_lease(
+        &self,
+        tenant: &str,
+        holder: &str,
+        ttl: Duration,
+    ) -> Result<bool> {
+        let pipe_api_client = self.get_pipe_api_client(tenant)?;
+        pipe_api_client.renew_runner_lease(holder, ttl).await
+    }
+}
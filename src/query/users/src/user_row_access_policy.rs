@@ -0,0 +1,90 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::RowAccessPolicyMeta;
+use common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+/// Row access policy operations.
+impl UserApiProvider {
+    // Add a new row access policy.
+    #[async_backtrace::framed]
+    pub async fn add_row_access_policy(
+        &self,
+        tenant: &str,
+        policy: RowAccessPolicyMeta,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        let policy_api_client = self.get_row_access_policy_api_client(tenant)?;
+        match policy_api_client.add_row_access_policy(policy).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::ROW_ACCESS_POLICY_ALREADY_EXISTS {
+                    Ok(u64::MIN)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Get a row access policy by name.
+    #[async_backtrace::framed]
+    pub async fn get_row_access_policy(
+        &self,
+        tenant: &str,
+        name: &str,
+    ) -> Result<RowAccessPolicyMeta> {
+        let policy_api_client = self.get_row_access_policy_api_client(tenant)?;
+        let get_policy = policy_api_client.get_row_access_policy(name, MatchSeq::GE(0));
+        Ok(get_policy.await?.data)
+    }
+
+    // Get all row access policies for the tenant.
+    #[async_backtrace::framed]
+    pub async fn get_row_access_policies(&self, tenant: &str) -> Result<Vec<RowAccessPolicyMeta>> {
+        let policy_api_client = self.get_row_access_policy_api_client(tenant)?;
+        match policy_api_client.get_row_access_policies().await {
+            Err(e) => Err(e.add_message_back("(while get row access policies).")),
+            Ok(policies) => Ok(policies),
+        }
+    }
+
+    // Drop a row access policy by name.
+    #[async_backtrace::framed]
+    pub async fn drop_row_access_policy(
+        &self,
+        tenant: &str,
+        name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let policy_api_client = self.get_row_access_policy_api_client(tenant)?;
+        match policy_api_client
+            .drop_row_access_policy(name, MatchSeq::GE(1))
+            .await
+        {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back("(while drop row access policy)"))
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::SequenceMeta;
+use common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+/// Sequence operations.
+impl UserApiProvider {
+    // Add a new sequence.
+    #[async_backtrace::framed]
+    pub async fn add_sequence(
+        &self,
+        tenant: &str,
+        sequence: SequenceMeta,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        let sequence_api_client = self.get_sequence_api_client(tenant)?;
+        match sequence_api_client.add_sequence(sequence).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::SEQUENCE_ALREADY_EXISTS {
+                    Ok(u64::MIN)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Get a sequence by name.
+    #[async_backtrace::framed]
+    pub async fn get_sequence(&self, tenant: &str, name: &str) -> Result<SequenceMeta> {
+        let sequence_api_client = self.get_sequence_api_client(tenant)?;
+        let get_sequence = sequence_api_client.get_sequence(name, MatchSeq::GE(0));
+        Ok(get_sequence.await?.data)
+    }
+
+    // Get all sequences for the tenant.
+    #[async_backtrace::framed]
+    pub async fn get_sequences(&self, tenant: &str) -> Result<Vec<SequenceMeta>> {
+        let sequence_api_client = self.get_sequence_api_client(tenant)?;
+        match sequence_api_client.get_sequences().await {
+            Err(e) => Err(e.add_message_back("(while get sequences).")),
+            Ok(sequences) => Ok(sequences),
+        }
+    }
+
+    // Allocate `count` values of a sequence, returns the first one and the increment.
+    #[async_backtrace::framed]
+    pub async fn next_sequence_values(
+        &self,
+        tenant: &str,
+        name: &str,
+        count: u64,
+    ) -> Result<(u64, u64)> {
+        let sequence_api_client = self.get_sequence_api_client(tenant)?;
+        sequence_api_client.next_sequence_values(name, count).await
+    }
+
+    // Drop a sequence by name.
+    #[async_backtrace::framed]
+    pub async fn drop_sequence(&self, tenant: &str, name: &str, if_exists: bool) -> Result<()> {
+        let sequence_api_client = self.get_sequence_api_client(tenant)?;
+        match sequence_api_client
+            .drop_sequence(name, MatchSeq::GE(1))
+            .await
+        {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back("(while drop sequence)"))
+                }
+            }
+        }
+    }
+}
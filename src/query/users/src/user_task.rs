@@ -0,0 +1,111 @@
+// Copyright 2023 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::TaskMeta;
+use common_meta_app::principal::TaskRun;
+use common_meta_types::MatchSeq;
+use common_meta_types::SeqV;
+
+use crate::UserApiProvider;
+
+/// Task operations.
+impl UserApiProvider {
+    // Add a new task.
+    #[async_backtrace::framed]
+    pub async fn add_task(&self, tenant: &str, task: TaskMeta, if_not_exists: bool) -> Result<u64> {
+        let task_api_client = self.get_task_api_client(tenant)?;
+        match task_api_client.add_task(task).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::TASK_ALREADY_EXISTS {
+                    Ok(u64::MIN)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Get a task by name, with the seq to update it.
+    #[async_backtrace::framed]
+    pub async fn get_task(&self, tenant: &str, name: &str) -> Result<SeqV<TaskMeta>> {
+        let task_api_client = self.get_task_api_client(tenant)?;
+        task_api_client.get_task(name, MatchSeq::GE(0)).await
+    }
+
+    // Get all tasks for the tenant.
+    #[async_backtrace::framed]
+    pub async fn get_tasks(&self, tenant: &str) -> Result<Vec<TaskMeta>> {
+        let task_api_client = self.get_task_api_client(tenant)?;
+        match task_api_client.get_tasks().await {
+            Err(e) => Err(e.add_message_back("(while get tasks).")),
+            Ok(tasks) => Ok(tasks),
+        }
+    }
+
+    // Update a task if it's not updated since read with `seq`.
+    #[async_backtrace::framed]
+    pub async fn update_task(&self, tenant: &str, task: TaskMeta, seq: u64) -> Result<u64> {
+        let task_api_client = self.get_task_api_client(tenant)?;
+        task_api_client
+            .update_task(task, MatchSeq::Exact(seq))
+            .await
+    }
+
+    // Drop a task by name.
+    #[async_backtrace::framed]
+    pub async fn drop_task(&self, tenant: &str, name: &str, if_exists: bool) -> Result<()> {
+        let task_api_client = self.get_task_api_client(tenant)?;
+        match task_api_client.drop_task(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_TASK {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back("(while drop task)"))
+                }
+            }
+        }
+    }
+
+    // Record a finished run of a task.
+    #[async_backtrace::framed]
+    pub async fn add_task_run(&self, tenant: &str, run: TaskRun) -> Result<()> {
+        let task_api_client = self.get_task_api_client(tenant)?;
+        task_api_client.add_task_run(run).await
+    }
+
+    // Get the runs of a task, or of all the tasks of the tenant.
+    #[async_backtrace::framed]
+    pub async fn get_task_runs(&self, tenant: &str, name: Option<&str>) -> Result<Vec<TaskRun>> {
+        let task_api_client = self.get_task_api_client(tenant)?;
+        task_api_client.get_task_runs(name).await
+    }
+
+    // Take or renew the lease of the task scheduler of the tenant.
+    #[async_backtrace::framed]
+    pub async fn renew_task_scheduler_lease(
+        &self,
+        tenant: &str,
+        holder: &str,
+        ttl: Duration,
+    ) -> Result<bool> {
+        let task_api_client = self.get_task_api_client(tenant)?;
+        task_api_client.renew_scheduler_lease(holder, ttl).await
+    }
+}
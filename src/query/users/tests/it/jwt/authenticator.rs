@@ -67,3 +67,55 @@ async fn test_parse_non_custom_claim() -> Result<()> {
     assert_eq!(res.custom.role, None);
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_mapped_roles() -> Result<()> {
+    let (pair1, pbkey1) = get_jwks_file_rs256("test_kid");
+    let template1 = ResponseTemplate::new(200).set_body_raw(pbkey1, "application/json");
+    let server = MockServer::start().await;
+    let json_path = "/jwks.json";
+    Mock::given(method("GET"))
+        .and(path(json_path))
+        .respond_with(template1)
+        .expect(1..)
+        .mount(&server)
+        .await;
+    let first_url = format!("http://{}{}", server.address(), json_path);
+
+    let invalid = JwtAuthenticator::create(first_url.clone(), vec![])
+        .unwrap()
+        .with_role_mapping("groups", &["analysts".to_string()]);
+    assert!(invalid.is_err());
+
+    // the claim values are never taken as the roles themselves
+    let invalid = JwtAuthenticator::create(first_url.clone(), vec![])
+        .unwrap()
+        .with_role_mapping("groups", &[]);
+    assert!(invalid.is_err());
+
+    // the audience is required with the issuer
+    let invalid = JwtAuthenticator::create_oidc("http://issuer").with_audience("");
+    assert!(invalid.is_err());
+    let valid = JwtAuthenticator::create_oidc("http://issuer").with_audience("databend");
+    assert!(valid.is_ok());
+
+    let auth = JwtAuthenticator::create(first_url, vec![])
+        .unwrap()
+        .with_role_mapping("realm_access.roles", &[
+            "analysts=analyst".to_string(),
+            "admins=account_admin".to_string(),
+        ])?;
+    let claims = Claims::with_custom_claims(
+        serde_json::json!({"realm_access": {"roles": ["admins", "analysts", "others"]}}),
+        Duration::from_hours(2),
+    )
+    .with_subject("test-user".to_string());
+    let token = pair1.sign(claims)?;
+
+    let res = auth.parse_jwt_claims(token.as_str()).await?;
+    assert_eq!(auth.mapped_roles(&res.custom), vec![
+        "account_admin".to_string(),
+        "analyst".to_string()
+    ]);
+    Ok(())
+}
@@ -16,4 +16,5 @@ mod jwt;
 mod role_cache_mgr;
 mod role_mgr;
 mod user_mgr;
+mod user_network_policy;
 mod user_udf;
@@ -0,0 +1,95 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Utc;
+use common_base::base::tokio;
+use common_exception::Result;
+use common_grpc::RpcClientConf;
+use common_meta_app::principal::AuthInfo;
+use common_meta_app::principal::NetworkPolicy;
+use common_meta_app::principal::UserInfo;
+use common_users::UserApiProvider;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_user_network_policy() -> Result<()> {
+    let conf = RpcClientConf::default();
+    let user_mgr = UserApiProvider::try_create_simple(conf).await?;
+    let tenant = "test";
+
+    let policy = NetworkPolicy {
+        name: "office".to_string(),
+        allowed_ip_list: vec!["192.168.1.0/24".to_string(), "10.0.0.1".to_string()],
+        blocked_ip_list: vec!["192.168.1.99".to_string()],
+        comment: "".to_string(),
+        created_on: Utc::now(),
+    };
+    user_mgr.add_network_policy(tenant, policy, false).await?;
+
+    let mut user = UserInfo::new("u1", "%", AuthInfo::None);
+    // Users without a network policy can connect from anywhere.
+    user_mgr
+        .enforce_network_policy(tenant, &user, Some("8.8.8.8"))
+        .await?;
+
+    user.option.set_network_policy(Some("office".to_string()));
+    user_mgr.add_user(tenant, user.clone(), false).await?;
+
+    // socket addresses and IPv4-mapped IPv6 addresses are checked by their IPv4 address.
+    for ip in [
+        "192.168.1.10",
+        "10.0.0.1",
+        "192.168.1.10:3307",
+        "::ffff:192.168.1.10",
+        "[::ffff:10.0.0.1]:3307",
+    ] {
+        user_mgr
+            .enforce_network_policy(tenant, &user, Some(ip))
+            .await?;
+    }
+    for ip in [
+        Some("192.168.1.99"),
+        Some("10.0.0.2"),
+        Some("::ffff:192.168.1.99"),
+        Some("[::1]:3307"),
+        Some("unknown"),
+        None,
+    ] {
+        let err = user_mgr
+            .enforce_network_policy(tenant, &user, ip)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), 1051);
+        // the error tells nothing about the policy.
+        assert!(!err.message().contains("office"));
+    }
+
+    // The policy can not be dropped while it is attached to a user.
+    let res = user_mgr.drop_network_policy(tenant, "office", false).await;
+    assert_eq!(res.unwrap_err().code(), 2615);
+
+    user_mgr.drop_user(tenant, user.identity(), false).await?;
+    user_mgr
+        .drop_network_policy(tenant, "office", false)
+        .await?;
+    let res = user_mgr.get_network_policy(tenant, "office").await;
+    assert_eq!(res.unwrap_err().code(), 2613);
+
+    // the users of a missing policy are rejected.
+    let res = user_mgr
+        .enforce_network_policy(tenant, &user, Some("192.168.1.10"))
+        .await;
+    assert_eq!(res.unwrap_err().code(), 1051);
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+
+## Create table t12_0005 and its clone
+echo "create table t12_0005(c int)" | $MYSQL_CLIENT_CONNECT
+echo "insert into t12_0005 values(1),(2)" | $MYSQL_CLIENT_CONNECT
+echo "create table t12_0005_clone clone t12_0005" | $MYSQL_CLIENT_CONNECT
+
+echo "two insertions into the clone"
+echo "insert into t12_0005_clone values(3)" | $MYSQL_CLIENT_CONNECT
+echo "insert into t12_0005_clone values(4)" | $MYSQL_CLIENT_CONNECT
+
+## The latest snapshot of the clone no longer references the blocks of the source table
+echo "optimize table t12_0005_clone compact" | $MYSQL_CLIENT_CONNECT
+
+## Purge the source table
+echo "truncate table t12_0005 purge" | $MYSQL_CLIENT_CONNECT
+
+echo "latest snapshot of the clone should contain 4 rows"
+echo "select count(*) from t12_0005_clone" | $MYSQL_CLIENT_CONNECT
+
+## Get the snapshot of the first insertion into the clone
+SNAPSHOT_ID=$(echo "select snapshot_id from fuse_snapshot('default','t12_0005_clone') where row_count=3" | $MYSQL_CLIENT_CONNECT)
+
+echo "counting the data set of the first insertion into the clone, which should contain 3 rows"
+echo "select count(*) from t12_0005_clone at (snapshot => '$SNAPSHOT_ID')" | $MYSQL_CLIENT_CONNECT
+
+## Drop tables.
+echo "drop table t12_0005_clone" | $MYSQL_CLIENT_CONNECT
+echo "drop table t12_0005" | $MYSQL_CLIENT_CONNECT
@@ -0,0 +1,64 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export TEST_USER_PASSWORD="password"
+export TEST_USER_CONNECT="mysql --defaults-extra-file=password.out --port ${QUERY_MYSQL_HANDLER_PORT} -s"
+echo -e "[mysql]\nhost=${QUERY_MYSQL_HANDLER_HOST}\nuser=test-user\npassword=${TEST_USER_PASSWORD}" >> password.out
+
+## create user
+echo "create user 'test-user'@'$QUERY_MYSQL_HANDLER_HOST' IDENTIFIED BY '$TEST_USER_PASSWORD'" | $MYSQL_CLIENT_CONNECT
+## create table
+echo "create table default.t20_0014(c1 int, c2 int)" | $MYSQL_CLIENT_CONNECT
+echo "insert into default.t20_0014 values(1, 10),(2, 20)" | $MYSQL_CLIENT_CONNECT
+
+## grant SELECT on one column
+echo "GRANT SELECT(c1) ON default.t20_0014 TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "SHOW GRANTS FOR 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "select c1 from default.t20_0014 order by c1" | $TEST_USER_CONNECT
+echo "select count(*) from default.t20_0014 where c1 > 1" | $TEST_USER_CONNECT
+echo "select c2 from default.t20_0014" | $TEST_USER_CONNECT
+echo "select c1 from default.t20_0014 where c2 > 10" | $TEST_USER_CONNECT
+echo "select * from default.t20_0014" | $TEST_USER_CONNECT
+
+## grant SELECT on the other column
+echo "GRANT SELECT(c2) ON default.t20_0014 TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "select * from default.t20_0014 order by c1" | $TEST_USER_CONNECT
+
+## revoke SELECT on a column
+echo "REVOKE SELECT(c1) ON default.t20_0014 FROM 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "select c2 from default.t20_0014 order by c2" | $TEST_USER_CONNECT
+echo "select c1 from default.t20_0014" | $TEST_USER_CONNECT
+
+## unknown column
+echo "GRANT SELECT(c3) ON default.t20_0014 TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+
+## grant SELECT through the roles granted to the default role of the user
+echo "create role 'r20_0014_parent'" | $MYSQL_CLIENT_CONNECT
+echo "create role 'r20_0014_child'" | $MYSQL_CLIENT_CONNECT
+echo "GRANT SELECT(c1) ON default.t20_0014 TO ROLE 'r20_0014_parent'" | $MYSQL_CLIENT_CONNECT
+echo "GRANT ROLE 'r20_0014_parent' TO ROLE 'r20_0014_child'" | $MYSQL_CLIENT_CONNECT
+echo "GRANT ROLE 'r20_0014_child' TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "ALTER USER 'test-user'@'$QUERY_MYSQL_HANDLER_HOST' WITH DEFAULT_ROLE = 'r20_0014_child'" | $MYSQL_CLIENT_CONNECT
+echo "select c1, c2 from default.t20_0014 order by c1" | $TEST_USER_CONNECT
+echo "REVOKE SELECT(c2) ON default.t20_0014 FROM 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "select c2 from default.t20_0014" | $TEST_USER_CONNECT
+echo "create role 'r20_0014_table'" | $MYSQL_CLIENT_CONNECT
+echo "GRANT SELECT ON default.t20_0014 TO ROLE 'r20_0014_table'" | $MYSQL_CLIENT_CONNECT
+echo "GRANT ROLE 'r20_0014_table' TO ROLE 'r20_0014_parent'" | $MYSQL_CLIENT_CONNECT
+## only the column grants are inherited, SELECT on the table needs to be granted to the current role
+echo "select * from default.t20_0014 order by c1" | $TEST_USER_CONNECT
+echo "GRANT ROLE 'r20_0014_table' TO 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+echo "ALTER USER 'test-user'@'$QUERY_MYSQL_HANDLER_HOST' WITH DEFAULT_ROLE = 'r20_0014_table'" | $MYSQL_CLIENT_CONNECT
+echo "select * from default.t20_0014 order by c1" | $TEST_USER_CONNECT
+echo "drop role 'r20_0014_child'" | $MYSQL_CLIENT_CONNECT
+echo "drop role 'r20_0014_parent'" | $MYSQL_CLIENT_CONNECT
+echo "drop role 'r20_0014_table'" | $MYSQL_CLIENT_CONNECT
+
+## Drop table.
+echo "drop table default.t20_0014 all" | $MYSQL_CLIENT_CONNECT
+
+## Drop user
+echo "drop user 'test-user'@'$QUERY_MYSQL_HANDLER_HOST'" | $MYSQL_CLIENT_CONNECT
+rm -rf password.out
@@ -0,0 +1,22 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+DATADIR="fs://${CURDIR}/../../../data/avro/"
+
+echo "drop stage if exists avro_fs;" | $MYSQL_CLIENT_CONNECT
+echo "drop table if exists avro_orders;" | $MYSQL_CLIENT_CONNECT
+
+echo "create stage avro_fs url = '${DATADIR}' FILE_FORMAT = (type = AVRO);" | $MYSQL_CLIENT_CONNECT
+echo "create table avro_orders(id int, name string null, price decimal(10, 2), created timestamp, day date, note string null);" | $MYSQL_CLIENT_CONNECT
+
+for codec in "deflate" "snappy"; do
+	echo "--- codec=${codec}"
+	echo "copy into avro_orders from @avro_fs files=('orders_${codec}.avro');" | $MYSQL_CLIENT_CONNECT
+	echo "select * from avro_orders order by id" | $MYSQL_CLIENT_CONNECT
+	echo "truncate table avro_orders" | $MYSQL_CLIENT_CONNECT
+done
+
+echo "drop table avro_orders;" | $MYSQL_CLIENT_CONNECT
+echo "drop stage avro_fs;" | $MYSQL_CLIENT_CONNECT
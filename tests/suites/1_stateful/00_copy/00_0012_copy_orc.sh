@@ -0,0 +1,25 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+DATADIR="fs://${CURDIR}/../../../data/orc/"
+
+echo "drop stage if exists orc_fs;" | $MYSQL_CLIENT_CONNECT
+echo "drop table if exists orc_nested;" | $MYSQL_CLIENT_CONNECT
+
+echo "create stage orc_fs url = '${DATADIR}' FILE_FORMAT = (type = ORC);" | $MYSQL_CLIENT_CONNECT
+echo "create table orc_nested(id bigint, name string, tags array(int), point tuple(x int, y int), attrs map(string, int));" | $MYSQL_CLIENT_CONNECT
+
+echo "--- copy"
+echo "copy into orc_nested from @orc_fs files=('nested.orc');" | $MYSQL_CLIENT_CONNECT
+echo "select * from orc_nested order by id" | $MYSQL_CLIENT_CONNECT
+
+echo "--- select with stripe pruning"
+echo "select id, name from @orc_fs (files => ('nested.orc')) where id >= 10 order by id" | $MYSQL_CLIENT_CONNECT
+
+echo "--- infer_schema"
+echo "select * from infer_schema(location => '@orc_fs/nested.orc')" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table orc_nested;" | $MYSQL_CLIENT_CONNECT
+echo "drop stage orc_fs;" | $MYSQL_CLIENT_CONNECT
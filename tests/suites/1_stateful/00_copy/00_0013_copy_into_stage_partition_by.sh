@@ -0,0 +1,35 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists sales;" | $MYSQL_CLIENT_CONNECT
+echo "drop table if exists sales_eu;" | $MYSQL_CLIENT_CONNECT
+echo "drop stage if exists s_part;" | $MYSQL_CLIENT_CONNECT
+echo "create stage s_part;" | $MYSQL_CLIENT_CONNECT
+
+echo "create table sales(id int, amount int, region string null, day date);" | $MYSQL_CLIENT_CONNECT
+echo "create table sales_eu(id int, amount int, day date);" | $MYSQL_CLIENT_CONNECT
+echo "insert into sales values
+	(1, 10, 'eu', '2022-01-03'),
+	(2, 20, 'eu', '2023-02-04'),
+	(3, 30, 'us', '2023-03-05'),
+	(4, 40, 'us/west', '2023-04-06'),
+	(5, 50, null, '2022-05-07'),
+	(6, 60, 'eu', '2022-06-08');" | $MYSQL_CLIENT_CONNECT
+
+echo "--- unload"
+echo "copy into @s_part/sales/ from (select id, amount, day, region from sales) FILE_FORMAT = (type = PARQUET) PARTITION BY (region, to_year(day) AS y);" | $MYSQL_CLIENT_CONNECT
+echo "list @s_part;" | $MYSQL_CLIENT_CONNECT | cut -f1 | sed 's/data_.*$//' | LC_ALL=C sort -u
+
+echo "--- load back one partition"
+echo "copy into sales_eu from @s_part/sales/ pattern = '.*region=eu/.*' FILE_FORMAT = (type = PARQUET);" | $MYSQL_CLIENT_CONNECT > /dev/null
+echo "select * from sales_eu order by id;" | $MYSQL_CLIENT_CONNECT
+
+echo "--- errors"
+echo "copy into @s_part/bad/ from sales FILE_FORMAT = (type = PARQUET) PARTITION BY (amount + 1);" | $MYSQL_CLIENT_CONNECT 2>&1 | grep -o "must be a column or have an alias"
+echo "copy into @s_part/bad/ from sales FILE_FORMAT = (type = PARQUET) SINGLE = true PARTITION BY (region);" | $MYSQL_CLIENT_CONNECT 2>&1 | grep -o "can not be used together with SINGLE = TRUE"
+
+echo "drop table sales;" | $MYSQL_CLIENT_CONNECT
+echo "drop table sales_eu;" | $MYSQL_CLIENT_CONNECT
+echo "drop stage s_part;" | $MYSQL_CLIENT_CONNECT
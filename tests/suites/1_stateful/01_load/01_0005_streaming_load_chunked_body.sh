@@ -0,0 +1,29 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists books;" | $MYSQL_CLIENT_CONNECT
+## create book table
+echo "CREATE TABLE books
+(
+    title VARCHAR NULL,
+    author VARCHAR NULL,
+    date VARCHAR NULL,
+    publish_time TIMESTAMP NULL
+);" | $MYSQL_CLIENT_CONNECT
+
+# load csv from the chunked request body
+curl -H "insert_sql:insert into books file_format = (type = CSV)" -H "Transfer-Encoding: chunked" --data-binary "@${CURDIR}/books.csv" -u root: -XPUT "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/streaming_load" > /dev/null 2>&1
+echo "select count(), count_if(title is null), count_if(author is null), count_if(date is null), count_if(publish_time is null) from books " |  $MYSQL_CLIENT_CONNECT
+
+# load gzipped ndjson, the compression is detected by the file name
+cat << EOF | gzip > /tmp/books.ndjson.gz
+{"title": "Transaction Processing", "author": "Jim Gray", "date": "1992", "publish_time": "2020-01-01 11:11:11"}
+{"title": "Readings in Database Systems", "author": "Michael Stonebraker", "date": "2004", "publish_time": "2020-01-01 11:11:11"}
+EOF
+curl -H "insert_sql:insert into books file_format = (type = NDJSON)" -H "file_name: books.ndjson.gz" -H "Transfer-Encoding: chunked" --data-binary "@/tmp/books.ndjson.gz" -u root: -XPUT "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/streaming_load" > /dev/null 2>&1
+echo "select count(), count_if(title is null), count_if(author is null), count_if(date is null), count_if(publish_time is null) from books " |  $MYSQL_CLIENT_CONNECT
+
+echo "drop table books;" | $MYSQL_CLIENT_CONNECT
+rm -f /tmp/books.ndjson.gz